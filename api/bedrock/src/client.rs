@@ -0,0 +1,273 @@
+//! Async HTTP client for the Bedrock Runtime.
+//!
+//! The [`Client`] is generic over an environment `E` so callers can supply
+//! different credential sources and endpoints without changing client code.
+//! Each method is one Bedrock Runtime operation; every request is signed with
+//! `SigV4` at the moment it is sent.
+
+mod private
+{
+  use crate::error::{ BedrockError, Result };
+  use crate::environment::BedrockEnvironment;
+  use crate::signing::{ self, SigningParams };
+  use crate::{ ConverseRequest, ConverseResponse };
+  #[ cfg( feature = "streaming" ) ]
+  use crate::{ ConverseStream, InvokeModelStream };
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  use core::time::Duration;
+  use reqwest::Client as HttpClient;
+
+  /// Signing name of the Bedrock Runtime.
+  const SERVICE : &str = "bedrock";
+
+  /// `Accept` of the JSON operations.
+  const JSON : &str = "application/json";
+
+  /// `Accept` of the streaming operations.
+  #[ cfg( feature = "streaming" ) ]
+  const EVENT_STREAM : &str = "application/vnd.amazon.eventstream";
+
+  /// Async HTTP client for the Bedrock Runtime.
+  ///
+  /// Construct via [`build`][Client::build]. All request methods are `async`.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_bedrock::{ Client, BedrockEnvironmentImpl, ConverseRequest, Message };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( BedrockEnvironmentImpl::from_env()? )?;
+  /// let request = ConverseRequest::new( vec![ Message::user( "Hello" ) ] );
+  /// let response = client.converse( "anthropic.claude-3-haiku-20240307-v1:0", &request ).await?;
+  /// println!( "{}", response.text() );
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug ) ]
+  pub struct Client< E >
+  where
+    E : BedrockEnvironment,
+  {
+    /// Underlying reqwest HTTP client, pre-configured with timeout settings.
+    http : HttpClient,
+    /// Environment supplying credentials, region, and endpoint.
+    environment : E,
+  }
+
+  impl< E > Client< E >
+  where
+    E : BedrockEnvironment,
+  {
+    /// Builds an HTTP client configured from the given environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `reqwest::Client` cannot be built
+    /// (e.g. invalid TLS configuration on the current platform).
+    #[ inline ]
+    pub fn build( env : E ) -> Result< Self >
+    {
+      let http_client = Self::http_builder( &env )
+        .build()
+        .map_err( | e | BedrockError::Environment( e.to_string() ) )?;
+      Ok( Self { http : http_client, environment : env } )
+    }
+
+    /// HTTP client settings derived from the environment.
+    fn http_builder( env : &E ) -> reqwest::ClientBuilder
+    {
+      let builder = HttpClient::builder();
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let builder = builder
+        .timeout( env.timeout() )
+        .connect_timeout( Duration::from_secs( 15 ) );
+      builder
+    }
+
+    /// Rebuilds the HTTP client with `tuning` applied on top of the
+    /// environment's timeouts. Open connections of the previous client are
+    /// dropped; call [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Environment` if `tuning` names a proxy that is
+    /// not a valid URL, asks for HTTP/2 prior knowledge without the `http2`
+    /// feature, or the client cannot be built.
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self >
+    {
+      self.http = tuning.apply_to( Self::http_builder( &self.environment ) ).map_err( | e | BedrockError::Environment( e.to_string() ) )?
+        .build()
+        .map_err( | e | BedrockError::Environment( e.to_string() ) )?;
+      Ok( self )
+    }
+
+    /// Opens a connection to the regional runtime endpoint ahead of the first
+    /// request, so that request does not pay for DNS, TCP, and the TLS
+    /// handshake.
+    ///
+    /// Sends one unsigned `HEAD` request and returns its round-trip time. Any
+    /// HTTP status counts as success (the endpoint answers an unsigned
+    /// request with 403); only a failure to connect is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a network or timeout error if no connection could be opened.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn warm_up( &self ) -> Result< Duration >
+    {
+      let started = std::time::Instant::now();
+      self.http
+        .head( self.environment.endpoint() )
+        .send()
+        .await
+        .map_err( BedrockError::from )?;
+      Ok( started.elapsed() )
+    }
+
+    /// The environment this client signs and sends with.
+    #[ inline ]
+    pub fn environment( &self ) -> &E
+    {
+      &self.environment
+    }
+
+    /// `Converse`: one chat turn in the model-independent format.
+    ///
+    /// `model_id` is a model id, an inference profile id
+    /// (`us.anthropic.claude-3-5-haiku-20241022-v1:0`), or an ARN.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Api` for a non-2xx response, plus network,
+    /// timeout, and deserialisation errors.
+    #[ inline ]
+    pub async fn converse( &self, model_id : &str, request : &ConverseRequest ) -> Result< ConverseResponse >
+    {
+      let response = self.send( model_id, "converse", request, JSON ).await?;
+      response.json().await.map_err( | e | BedrockError::from( e ).into() )
+    }
+
+    /// `InvokeModel`: sends `body` in the model's native format and
+    /// deserialises the native response, e.g. `AnthropicRequest` into
+    /// `AnthropicResponse`.
+    ///
+    /// # Errors
+    ///
+    /// As [`converse`](Self::converse).
+    #[ inline ]
+    pub async fn invoke_model< I, O >( &self, model_id : &str, body : &I ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      let response = self.send( model_id, "invoke", body, JSON ).await?;
+      response.json().await.map_err( | e | BedrockError::from( e ).into() )
+    }
+
+    /// `ConverseStream`: as [`converse`](Self::converse), with the answer
+    /// read event by event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Api` when the call is rejected before the
+    /// stream starts; errors during the stream come from
+    /// [`ConverseStream::next_event`].
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    pub async fn converse_stream( &self, model_id : &str, request : &ConverseRequest ) -> Result< ConverseStream >
+    {
+      let response = self.send( model_id, "converse-stream", request, EVENT_STREAM ).await?;
+      Ok( ConverseStream::new( response ) )
+    }
+
+    /// `InvokeModelWithResponseStream`: as [`invoke_model`](Self::invoke_model),
+    /// with the native response read chunk by chunk.
+    ///
+    /// # Errors
+    ///
+    /// As [`converse_stream`](Self::converse_stream).
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    pub async fn invoke_model_with_response_stream< I >( &self, model_id : &str, body : &I ) -> Result< InvokeModelStream >
+    where
+      I : serde::Serialize,
+    {
+      let response = self.send( model_id, "invoke-with-response-stream", body, EVENT_STREAM ).await?;
+      Ok( InvokeModelStream::new( response ) )
+    }
+
+    /// Signs and sends `POST model/{model_id}/{operation}`; returns the
+    /// response when its status is 2xx.
+    async fn send< I >( &self, model_id : &str, operation : &str, body : &I, accept : &str ) -> Result< reqwest::Response >
+    where
+      I : serde::Serialize,
+    {
+      let url = format!( "{}model/{}/{operation}", self.environment.endpoint(), signing::encode_path_segment( model_id ) );
+      let url = reqwest::Url::parse( &url )
+        .map_err( | e | BedrockError::Environment( format!( "invalid endpoint {url} : {e}" ) ) )?;
+      let body = serde_json::to_vec( body ).map_err( BedrockError::from )?;
+
+      let headers = [ ( "accept", accept ), ( "content-type", JSON ) ];
+      let params = SigningParams
+      {
+        credentials : self.environment.credentials(),
+        region : self.environment.region(),
+        service : SERVICE,
+        time : chrono::Utc::now(),
+      };
+      let mut request = self.http.post( url.clone() );
+      for ( name, value ) in headers.into_iter().map( | ( name, value ) | ( name, value.to_owned() ) )
+        .chain( signing::sign( &params, "POST", &url, &headers, &body ) )
+      {
+        request = request.header( name, value );
+      }
+
+      let response = request.body( body ).send().await.map_err( BedrockError::from )?;
+      if response.status().is_success()
+      {
+        return Ok( response );
+      }
+      Err( api_error( response ).await.into() )
+    }
+  }
+
+  /// Reads the AWS error type and message of a non-2xx response.
+  ///
+  /// The type comes from `x-amzn-ErrorType` (`ThrottlingException:http://…`)
+  /// or the body's `__type` (`…#ThrottlingException`); the message from the
+  /// body's `message` or `Message`, or the raw body.
+  async fn api_error( response : reqwest::Response ) -> BedrockError
+  {
+    let status = response.status();
+    let header_type = response.headers()
+      .get( "x-amzn-errortype" )
+      .and_then( | value | value.to_str().ok() )
+      .map( | value | value.split( ':' ).next().unwrap_or( value ).to_owned() );
+    let text = response.text().await.unwrap_or_default();
+    let body = serde_json::from_str::< serde_json::Value >( &text ).unwrap_or_default();
+    let body_type = body.get( "__type" ).and_then( serde_json::Value::as_str )
+      .map( | value | value.rsplit( '#' ).next().unwrap_or( value ).to_owned() );
+    let message = body.get( "message" ).or_else( || body.get( "Message" ) ).and_then( serde_json::Value::as_str )
+      .map_or( text.clone(), str::to_owned );
+    BedrockError::Api
+    {
+      status : status.as_u16(),
+      error_type : header_type.or( body_type ).unwrap_or_else( || status.canonical_reason().unwrap_or( "Unknown" ).to_owned() ),
+      message,
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Client,
+  };
+}
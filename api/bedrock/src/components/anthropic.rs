@@ -0,0 +1,179 @@
+//! Native `InvokeModel` bodies for Anthropic Claude models.
+//!
+//! Bedrock hosts Claude with the Messages API body, minus `model` (it is in
+//! the path) and plus `anthropic_version`, which must be
+//! [`AnthropicRequest::ANTHROPIC_VERSION`].
+
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+
+  /// `InvokeModel` request body for Claude.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct AnthropicRequest
+  {
+    /// Bedrock's Messages API version.
+    pub anthropic_version : String,
+    /// Maximum tokens to generate; required.
+    pub max_tokens : u32,
+    /// Conversation turns.
+    pub messages : Vec< AnthropicMessage >,
+    /// System prompt.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub system : Option< String >,
+    /// Sampling temperature.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability mass.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// Sample from the `top_k` most likely tokens only.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_k : Option< u32 >,
+    /// Sequences that stop generation.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stop_sequences : Option< Vec< String > >,
+  }
+
+  impl AnthropicRequest
+  {
+    /// The only `anthropic_version` Bedrock accepts.
+    pub const ANTHROPIC_VERSION : &'static str = "bedrock-2023-05-31";
+
+    /// A request with the given messages and token limit.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( max_tokens : u32, messages : Vec< AnthropicMessage > ) -> Self
+    {
+      Self
+      {
+        anthropic_version : Self::ANTHROPIC_VERSION.to_owned(),
+        max_tokens,
+        messages,
+        system : None,
+        temperature : None,
+        top_p : None,
+        top_k : None,
+        stop_sequences : None,
+      }
+    }
+
+    /// Builds a request from provider-neutral messages.
+    ///
+    /// System messages are joined with blank lines into `system`; the others
+    /// become text messages in order.
+    #[ cfg( feature = "common" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_neutral( max_tokens : u32, messages : impl IntoIterator< Item = api_common::NeutralMessage > ) -> Self
+    {
+      let mut request = Self::new( max_tokens, Vec::new() );
+      let mut system = Vec::new();
+      for message in messages
+      {
+        match message.role
+        {
+          api_common::NeutralRole::System => system.push( message.content ),
+          api_common::NeutralRole::User | api_common::NeutralRole::Assistant =>
+            request.messages.push( AnthropicMessage { role : message.role.as_str().to_owned(), content : message.content } ),
+        }
+      }
+      if !system.is_empty()
+      {
+        request.system = Some( system.join( "\n\n" ) );
+      }
+      request
+    }
+  }
+
+  /// One text turn of a Claude conversation.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct AnthropicMessage
+  {
+    /// `user` or `assistant`.
+    pub role : String,
+    /// Message text.
+    pub content : String,
+  }
+
+  /// `InvokeModel` response body for Claude.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct AnthropicResponse
+  {
+    /// Message identifier.
+    pub id : String,
+    /// Generated content blocks.
+    pub content : Vec< AnthropicContent >,
+    /// `end_turn`, `max_tokens`, `stop_sequence`, or `tool_use`.
+    #[ serde( default ) ]
+    pub stop_reason : Option< String >,
+    /// Token counts.
+    pub usage : AnthropicUsage,
+  }
+
+  impl AnthropicResponse
+  {
+    /// The text blocks concatenated.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( &self ) -> String
+    {
+      self.content.iter().filter_map( | block | block.text.as_deref() ).collect()
+    }
+  }
+
+  /// The generated text as an assistant message.
+  #[ cfg( feature = "common" ) ]
+  impl From< AnthropicResponse > for api_common::NeutralMessage
+  {
+    #[ inline ]
+    fn from( response : AnthropicResponse ) -> Self
+    {
+      Self::assistant( response.text() )
+    }
+  }
+
+  /// One generated content block; only text blocks carry `text`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct AnthropicContent
+  {
+    /// Block type, e.g. `text` or `tool_use`.
+    #[ serde( rename = "type" ) ]
+    pub block_type : String,
+    /// Text of a `text` block.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub text : Option< String >,
+  }
+
+  /// Token counts of a Claude call.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  pub struct AnthropicUsage
+  {
+    /// Prompt tokens.
+    pub input_tokens : u64,
+    /// Generated tokens.
+    pub output_tokens : u64,
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< AnthropicUsage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : AnthropicUsage ) -> Self
+    {
+      Self::new( usage.input_tokens, usage.output_tokens )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    AnthropicRequest,
+    AnthropicMessage,
+    AnthropicResponse,
+    AnthropicContent,
+    AnthropicUsage,
+  };
+}
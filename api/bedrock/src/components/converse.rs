@@ -0,0 +1,468 @@
+//! Wire types for the Converse API.
+//!
+//! Converse is Bedrock's model-independent chat format: the same request
+//! shape works for every model that supports it, and Bedrock translates it
+//! into the model's native body. The model id is not part of the body; it
+//! goes into the request path.
+
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+  use serde_json::Value;
+
+  /// Request body of `Converse` and `ConverseStream`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ConverseRequest
+  {
+    /// Conversation turns, alternating `user` and `assistant`, starting with `user`.
+    pub messages : Vec< Message >,
+
+    /// System prompts, sent apart from the messages.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub system : Option< Vec< SystemContentBlock > >,
+
+    /// Sampling parameters shared by every model.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub inference_config : Option< InferenceConfiguration >,
+
+    /// Tools the model may call.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_config : Option< ToolConfiguration >,
+
+    /// Model-specific parameters passed through unchanged (e.g. `top_k`).
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub additional_model_request_fields : Option< Value >,
+  }
+
+  impl ConverseRequest
+  {
+    /// A request with the given messages and nothing else set.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( messages : Vec< Message > ) -> Self
+    {
+      Self { messages, ..Self::default() }
+    }
+
+    /// Appends a system prompt.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_system( mut self, text : impl Into< String > ) -> Self
+    {
+      self.system.get_or_insert_with( Vec::new ).push( SystemContentBlock { text : text.into() } );
+      self
+    }
+
+    /// Sets the sampling parameters.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_inference_config( mut self, config : InferenceConfiguration ) -> Self
+    {
+      self.inference_config = Some( config );
+      self
+    }
+
+    /// Sets the tools the model may call.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tool_config( mut self, config : ToolConfiguration ) -> Self
+    {
+      self.tool_config = Some( config );
+      self
+    }
+
+    /// Sets model-specific parameters.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_additional_model_request_fields( mut self, fields : Value ) -> Self
+    {
+      self.additional_model_request_fields = Some( fields );
+      self
+    }
+
+    /// Builds a request from provider-neutral messages.
+    ///
+    /// System messages go into `system`, in order; the others become text
+    /// messages. Consecutive messages with the same role are kept apart, as
+    /// given; Converse rejects them, so merge them first if needed.
+    #[ cfg( feature = "common" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_neutral( messages : impl IntoIterator< Item = api_common::NeutralMessage > ) -> Self
+    {
+      let mut request = Self::default();
+      for message in messages
+      {
+        match message.role
+        {
+          api_common::NeutralRole::System => request = request.with_system( message.content ),
+          api_common::NeutralRole::User => request.messages.push( Message::user( message.content ) ),
+          api_common::NeutralRole::Assistant => request.messages.push( Message::assistant( message.content ) ),
+        }
+      }
+      request
+    }
+  }
+
+  /// Role of a Converse message.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum ConversationRole
+  {
+    /// Message from the human user, or tool results.
+    User,
+    /// Message from the model.
+    Assistant,
+  }
+
+  /// One conversation turn.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct Message
+  {
+    /// Who sent the message.
+    pub role : ConversationRole,
+    /// Content blocks, in order.
+    pub content : Vec< ContentBlock >,
+  }
+
+  impl Message
+  {
+    /// A user message with one text block.
+    #[ inline ]
+    #[ must_use ]
+    pub fn user( text : impl Into< String > ) -> Self
+    {
+      Self { role : ConversationRole::User, content : vec![ ContentBlock::text( text ) ] }
+    }
+
+    /// An assistant message with one text block.
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant( text : impl Into< String > ) -> Self
+    {
+      Self { role : ConversationRole::Assistant, content : vec![ ContentBlock::text( text ) ] }
+    }
+
+    /// The text blocks concatenated.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( &self ) -> String
+    {
+      self.content.iter().filter_map( | block | block.text.as_deref() ).collect()
+    }
+  }
+
+  /// One content block of a message.
+  ///
+  /// On the wire a block is an object with exactly one member naming its
+  /// kind (`{"text": …}`, `{"toolUse": {…}}`). Blocks of kinds not modelled
+  /// here (images, documents, reasoning) deserialise with every field `None`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlock
+  {
+    /// Text.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub text : Option< String >,
+
+    /// A tool call made by the model.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_use : Option< ToolUseBlock >,
+
+    /// The result of a tool call, sent back in a user message.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_result : Option< ToolResultBlock >,
+  }
+
+  impl ContentBlock
+  {
+    /// A text block.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( text : impl Into< String > ) -> Self
+    {
+      Self { text : Some( text.into() ), ..Self::default() }
+    }
+
+    /// A tool result block carrying JSON.
+    #[ inline ]
+    #[ must_use ]
+    pub fn tool_result( tool_use_id : impl Into< String >, json : Value ) -> Self
+    {
+      Self
+      {
+        tool_result : Some( ToolResultBlock
+        {
+          tool_use_id : tool_use_id.into(),
+          content : vec![ ToolResultContent { json : Some( json ), text : None } ],
+          status : None,
+        } ),
+        ..Self::default()
+      }
+    }
+  }
+
+  /// A tool call made by the model.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ToolUseBlock
+  {
+    /// Identifier to answer with in the tool result.
+    pub tool_use_id : String,
+    /// Tool name.
+    pub name : String,
+    /// Arguments, as a JSON object.
+    pub input : Value,
+  }
+
+  /// The result of a tool call.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ToolResultBlock
+  {
+    /// Identifier from the `ToolUseBlock` being answered.
+    pub tool_use_id : String,
+    /// Result content.
+    pub content : Vec< ToolResultContent >,
+    /// `success` or `error`; not supported by every model.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub status : Option< String >,
+  }
+
+  /// One block of a tool result: text or JSON.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  pub struct ToolResultContent
+  {
+    /// Text result.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub text : Option< String >,
+    /// JSON result.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub json : Option< Value >,
+  }
+
+  /// A system prompt.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct SystemContentBlock
+  {
+    /// Prompt text.
+    pub text : String,
+  }
+
+  /// Sampling parameters every Converse model accepts.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct InferenceConfiguration
+  {
+    /// Maximum tokens to generate.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_tokens : Option< u32 >,
+    /// Sampling temperature.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability mass.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// Sequences that stop generation.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stop_sequences : Option< Vec< String > >,
+  }
+
+  /// Tools the model may call, and how it must choose among them.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ToolConfiguration
+  {
+    /// Available tools.
+    pub tools : Vec< Tool >,
+    /// Tool choice; the model decides when absent.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_choice : Option< ToolChoice >,
+  }
+
+  /// A tool definition.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct Tool
+  {
+    /// The tool's name, description, and input schema.
+    pub tool_spec : ToolSpecification,
+  }
+
+  impl Tool
+  {
+    /// A tool whose input is described by the JSON schema `input_schema`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( name : impl Into< String >, description : impl Into< String >, input_schema : Value ) -> Self
+    {
+      Self
+      {
+        tool_spec : ToolSpecification
+        {
+          name : name.into(),
+          description : Some( description.into() ),
+          input_schema : ToolInputSchema { json : input_schema },
+        },
+      }
+    }
+  }
+
+  /// Name, description, and input schema of a tool.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ToolSpecification
+  {
+    /// Tool name.
+    pub name : String,
+    /// What the tool does, for the model.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub description : Option< String >,
+    /// Input schema.
+    pub input_schema : ToolInputSchema,
+  }
+
+  /// JSON schema of a tool's input.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ToolInputSchema
+  {
+    /// The schema.
+    pub json : Value,
+  }
+
+  /// How the model must use the tools.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub enum ToolChoice
+  {
+    /// The model decides whether to call a tool (`{"auto":{}}`).
+    Auto {},
+    /// The model must call some tool (`{"any":{}}`).
+    Any {},
+    /// The model must call the named tool (`{"tool":{"name":…}}`).
+    Tool
+    {
+      /// Tool to call.
+      name : String,
+    },
+  }
+
+  /// Response body of `Converse`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ConverseResponse
+  {
+    /// The generated message.
+    pub output : ConverseOutput,
+    /// `end_turn`, `tool_use`, `max_tokens`, `stop_sequence`, `guardrail_intervened`, or `content_filtered`.
+    pub stop_reason : String,
+    /// Token counts.
+    pub usage : TokenUsage,
+    /// Latency.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metrics : Option< ConverseMetrics >,
+    /// Model-specific response fields.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub additional_model_response_fields : Option< Value >,
+  }
+
+  impl ConverseResponse
+  {
+    /// The text of the generated message, empty when there is none.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( &self ) -> String
+    {
+      self.output.message.as_ref().map( Message::text ).unwrap_or_default()
+    }
+  }
+
+  /// The generated text as an assistant message.
+  #[ cfg( feature = "common" ) ]
+  impl From< ConverseResponse > for api_common::NeutralMessage
+  {
+    #[ inline ]
+    fn from( response : ConverseResponse ) -> Self
+    {
+      Self::assistant( response.text() )
+    }
+  }
+
+  /// Output of `Converse`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ConverseOutput
+  {
+    /// The generated message.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub message : Option< Message >,
+  }
+
+  /// Token counts of one call.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct TokenUsage
+  {
+    /// Prompt tokens.
+    pub input_tokens : u64,
+    /// Generated tokens.
+    pub output_tokens : u64,
+    /// Sum of the two.
+    pub total_tokens : u64,
+    /// Prompt tokens read from the prompt cache.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub cache_read_input_tokens : Option< u64 >,
+    /// Prompt tokens written to the prompt cache.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub cache_write_input_tokens : Option< u64 >,
+  }
+
+  /// Bedrock counts cache reads and writes apart from `input_tokens`; the
+  /// neutral input count includes both.
+  #[ cfg( feature = "common" ) ]
+  impl From< TokenUsage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : TokenUsage ) -> Self
+    {
+      let read = usage.cache_read_input_tokens.unwrap_or( 0 );
+      let write = usage.cache_write_input_tokens.unwrap_or( 0 );
+      let input = usage.input_tokens.saturating_add( read ).saturating_add( write );
+      Self::new( input, usage.output_tokens ).with_cached_input_tokens( read )
+    }
+  }
+
+  /// Call metrics.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ConverseMetrics
+  {
+    /// Time to produce the response, in milliseconds.
+    pub latency_ms : u64,
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ConverseRequest,
+    ConversationRole,
+    Message,
+    ContentBlock,
+    ToolUseBlock,
+    ToolResultBlock,
+    ToolResultContent,
+    SystemContentBlock,
+    InferenceConfiguration,
+    ToolConfiguration,
+    Tool,
+    ToolSpecification,
+    ToolInputSchema,
+    ToolChoice,
+    ConverseResponse,
+    ConverseOutput,
+    TokenUsage,
+    ConverseMetrics,
+  };
+}
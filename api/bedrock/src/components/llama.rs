@@ -0,0 +1,95 @@
+//! Native `InvokeModel` bodies for Meta Llama models.
+//!
+//! Llama takes a single prompt string, so the conversation has to be written
+//! out in the model's chat template. [`LlamaRequest::from_neutral`] uses the
+//! Llama 3 template, shared by Llama 3, 3.1, 3.2, and 3.3.
+
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+
+  /// `InvokeModel` request body for Llama.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  pub struct LlamaRequest
+  {
+    /// Prompt, already in the model's chat template.
+    pub prompt : String,
+    /// Maximum tokens to generate.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_gen_len : Option< u32 >,
+    /// Sampling temperature.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability mass.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+  }
+
+  impl LlamaRequest
+  {
+    /// A request with the given prompt and nothing else set.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( prompt : impl Into< String > ) -> Self
+    {
+      Self { prompt : prompt.into(), ..Self::default() }
+    }
+
+    /// Builds a request from provider-neutral messages in the Llama 3 chat
+    /// template, ending with an open assistant header for the model to fill.
+    #[ cfg( feature = "common" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_neutral( messages : impl IntoIterator< Item = api_common::NeutralMessage > ) -> Self
+    {
+      let mut prompt = String::from( "<|begin_of_text|>" );
+      for message in messages
+      {
+        prompt.push_str( "<|start_header_id|>" );
+        prompt.push_str( message.role.as_str() );
+        prompt.push_str( "<|end_header_id|>\n\n" );
+        prompt.push_str( &message.content );
+        prompt.push_str( "<|eot_id|>" );
+      }
+      prompt.push_str( "<|start_header_id|>assistant<|end_header_id|>\n\n" );
+      Self::new( prompt )
+    }
+  }
+
+  /// `InvokeModel` response body for Llama.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct LlamaResponse
+  {
+    /// Generated text.
+    pub generation : String,
+    /// Prompt tokens.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub prompt_token_count : Option< u64 >,
+    /// Generated tokens.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub generation_token_count : Option< u64 >,
+    /// `stop` or `length`.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub stop_reason : Option< String >,
+  }
+
+  /// The generated text as an assistant message.
+  #[ cfg( feature = "common" ) ]
+  impl From< LlamaResponse > for api_common::NeutralMessage
+  {
+    #[ inline ]
+    fn from( response : LlamaResponse ) -> Self
+    {
+      Self::assistant( response.generation )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    LlamaRequest,
+    LlamaResponse,
+  };
+}
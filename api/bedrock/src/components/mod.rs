@@ -0,0 +1,25 @@
+//! Bedrock Runtime wire-type components.
+//!
+//! Re-exports the Converse types, the optional `ConverseStream` events, and
+//! the native `InvokeModel` bodies of the supported model families.
+
+mod private
+{
+}
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// Converse request, response, message, and tool types.
+  layer converse;
+
+  /// `ConverseStream` event types.
+  #[ cfg( feature = "streaming" ) ]
+  layer streaming;
+
+  /// Native request and response bodies for Anthropic Claude.
+  layer anthropic;
+
+  /// Native request and response bodies for Meta Llama.
+  layer llama;
+}
@@ -0,0 +1,253 @@
+//! Wire types for `ConverseStream` events.
+//!
+//! Each event-stream frame of a `ConverseStream` response carries one event;
+//! its kind is the frame's `:event-type` header and its payload is the JSON
+//! body of that kind.
+
+mod private
+{
+  use crate::error::Result;
+  use crate::event_stream::EventStreamMessage;
+  use crate::{ ConversationRole, ConverseMetrics, TokenUsage };
+  use serde::{ Serialize, Deserialize };
+  use serde_json::Value;
+
+  /// One event of a `ConverseStream` response.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub enum ConverseStreamEvent
+  {
+    /// The model started its message (`messageStart`).
+    MessageStart( MessageStartEvent ),
+    /// A content block started; carries the tool name for tool calls (`contentBlockStart`).
+    ContentBlockStart( ContentBlockStartEvent ),
+    /// Text or tool input for the current block (`contentBlockDelta`).
+    ContentBlockDelta( ContentBlockDeltaEvent ),
+    /// A content block ended (`contentBlockStop`).
+    ContentBlockStop( ContentBlockStopEvent ),
+    /// The message ended (`messageStop`).
+    MessageStop( MessageStopEvent ),
+    /// Token usage and latency, sent last (`metadata`).
+    Metadata( MetadataEvent ),
+    /// An event kind this crate does not model.
+    Other
+    {
+      /// The `:event-type` header.
+      event_type : String,
+      /// The raw payload.
+      payload : Value,
+    },
+  }
+
+  impl ConverseStreamEvent
+  {
+    /// Decodes the event carried by one frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Exception` for an exception frame and
+    /// `BedrockError::Deserialise` when the payload does not match its
+    /// event kind.
+    #[ inline ]
+    pub fn from_message( message : &EventStreamMessage ) -> Result< Self >
+    {
+      message.check_exception()?;
+      let payload = &message.payload;
+      let event = match message.event_type().unwrap_or_default()
+      {
+        "messageStart" => Self::MessageStart( parse( payload )? ),
+        "contentBlockStart" => Self::ContentBlockStart( parse( payload )? ),
+        "contentBlockDelta" => Self::ContentBlockDelta( parse( payload )? ),
+        "contentBlockStop" => Self::ContentBlockStop( parse( payload )? ),
+        "messageStop" => Self::MessageStop( parse( payload )? ),
+        "metadata" => Self::Metadata( parse( payload )? ),
+        other => Self::Other
+        {
+          event_type : other.to_owned(),
+          payload : serde_json::from_slice( payload ).unwrap_or( Value::Null ),
+        },
+      };
+      Ok( event )
+    }
+  }
+
+  fn parse< T : serde::de::DeserializeOwned >( payload : &[ u8 ] ) -> Result< T >
+  {
+    serde_json::from_slice( payload ).map_err( | e | crate::BedrockError::from( e ).into() )
+  }
+
+  /// Payload of `messageStart`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct MessageStartEvent
+  {
+    /// Role of the message; always `assistant`.
+    pub role : ConversationRole,
+  }
+
+  /// Payload of `contentBlockStart`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlockStartEvent
+  {
+    /// What the block is.
+    pub start : ContentBlockStart,
+    /// Index of the block within the message.
+    pub content_block_index : u32,
+  }
+
+  /// Start of a content block.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlockStart
+  {
+    /// Set when the block is a tool call.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_use : Option< ToolUseBlockStart >,
+  }
+
+  /// Identity of a tool call whose input follows in deltas.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ToolUseBlockStart
+  {
+    /// Identifier to answer with in the tool result.
+    pub tool_use_id : String,
+    /// Tool name.
+    pub name : String,
+  }
+
+  /// Payload of `contentBlockDelta`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlockDeltaEvent
+  {
+    /// The increment.
+    pub delta : ContentBlockDelta,
+    /// Index of the block within the message.
+    pub content_block_index : u32,
+  }
+
+  /// Increment of a content block.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlockDelta
+  {
+    /// Text to append.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub text : Option< String >,
+    /// Fragment of a tool call's JSON input.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_use : Option< ToolUseBlockDelta >,
+  }
+
+  /// Fragment of a tool call's input.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct ToolUseBlockDelta
+  {
+    /// JSON text to append to the input.
+    pub input : String,
+  }
+
+  /// Payload of `contentBlockStop`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct ContentBlockStopEvent
+  {
+    /// Index of the block within the message.
+    pub content_block_index : u32,
+  }
+
+  /// Payload of `messageStop`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( rename_all = "camelCase" ) ]
+  pub struct MessageStopEvent
+  {
+    /// Why generation stopped; the values of `ConverseResponse::stop_reason`.
+    pub stop_reason : String,
+    /// Model-specific response fields.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub additional_model_response_fields : Option< Value >,
+  }
+
+  /// Payload of `metadata`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct MetadataEvent
+  {
+    /// Token counts of the whole call.
+    pub usage : TokenUsage,
+    /// Latency.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metrics : Option< ConverseMetrics >,
+  }
+
+  /// Translates one Converse stream event into provider-neutral events.
+  ///
+  /// Block indexes become tool-call indexes. `messageStop` gives `Done` with
+  /// its stop reason; `metadata` gives the final usage.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for ConverseStreamEvent
+  {
+    #[ inline ]
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+    {
+      use api_common::{ StreamEvent, ToolCallDelta, UsageUpdate };
+
+      let events = match self
+      {
+        Self::ContentBlockStart( ContentBlockStartEvent { start : ContentBlockStart { tool_use : Some( tool ) }, content_block_index } ) =>
+          vec![ StreamEvent::ToolCallDelta( ToolCallDelta
+          {
+            index : content_block_index as usize,
+            id : Some( tool.tool_use_id ),
+            name : Some( tool.name ),
+            arguments : String::new(),
+          } ) ],
+        Self::ContentBlockDelta( ContentBlockDeltaEvent { delta, content_block_index } ) =>
+        {
+          let mut events = Vec::new();
+          if let Some( text ) = delta.text.filter( | text | !text.is_empty() )
+          {
+            events.push( StreamEvent::TextDelta( text ) );
+          }
+          if let Some( tool ) = delta.tool_use
+          {
+            events.push( StreamEvent::ToolCallDelta( ToolCallDelta
+            {
+              index : content_block_index as usize,
+              id : None,
+              name : None,
+              arguments : tool.input,
+            } ) );
+          }
+          events
+        }
+        Self::MessageStop( stop ) => vec![ StreamEvent::Done { finish_reason : Some( stop.stop_reason ) } ],
+        Self::Metadata( metadata ) => vec![ StreamEvent::UsageUpdate( UsageUpdate
+        {
+          input_tokens : Some( metadata.usage.input_tokens ),
+          output_tokens : Some( metadata.usage.output_tokens ),
+        } ) ],
+        _ => Vec::new(),
+      };
+      Ok( events )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ConverseStreamEvent,
+    MessageStartEvent,
+    ContentBlockStartEvent,
+    ContentBlockStart,
+    ToolUseBlockStart,
+    ContentBlockDeltaEvent,
+    ContentBlockDelta,
+    ToolUseBlockDelta,
+    ContentBlockStopEvent,
+    MessageStopEvent,
+    MetadataEvent,
+  };
+}
@@ -0,0 +1,189 @@
+//! Environment configuration trait and default implementation.
+//!
+//! The [`BedrockEnvironment`] trait supplies the credentials, region,
+//! endpoint, and timeout the [`crate::Client`] signs and sends with, so the
+//! same client works with static keys, temporary STS credentials, or a
+//! caller-managed credential source.
+
+mod private
+{
+  use crate::error::{ BedrockError, Result };
+  use crate::signing::Credentials;
+  use core::time::Duration;
+
+  /// Configuration contract for a Bedrock Runtime environment.
+  ///
+  /// # Trait Bounds
+  ///
+  /// `Send + Sync + 'static` are required for use across async task boundaries.
+  pub trait BedrockEnvironment : Send + Sync + 'static
+  {
+    /// Credentials every request is signed with.
+    fn credentials( &self ) -> &Credentials;
+
+    /// AWS region, e.g. `us-east-1`; part of the signature scope.
+    fn region( &self ) -> &str;
+
+    /// Runtime endpoint including the trailing slash, e.g.
+    /// `"https://bedrock-runtime.us-east-1.amazonaws.com/"`. The client
+    /// appends operation paths (`"model/{id}/converse"`).
+    fn endpoint( &self ) -> &str;
+
+    /// Returns the per-request timeout duration.
+    fn timeout( &self ) -> Duration;
+  }
+
+  /// Default Bedrock environment backed by in-memory values.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_bedrock::{ BedrockEnvironmentImpl, Credentials };
+  ///
+  /// let credentials = Credentials::new( "AKIA...", "secret" ).unwrap();
+  /// let env = BedrockEnvironmentImpl::new( "us-east-1", credentials ).unwrap();
+  /// // or, from AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY, AWS_SESSION_TOKEN, AWS_REGION:
+  /// let env = BedrockEnvironmentImpl::from_env().unwrap();
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone ) ]
+  pub struct BedrockEnvironmentImpl
+  {
+    /// Signing credentials.
+    credentials : Credentials,
+    /// AWS region.
+    region : String,
+    /// Runtime endpoint including trailing slash.
+    endpoint : String,
+    /// Per-request timeout.
+    timeout : Duration,
+  }
+
+  impl BedrockEnvironmentImpl
+  {
+    /// Default request timeout in seconds; long generations take minutes.
+    pub const DEFAULT_TIMEOUT_SECS : u64 = 300;
+
+    /// Creates an environment for `region` with its public runtime endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Environment` if `region` is empty or
+    /// whitespace-only.
+    #[ inline ]
+    pub fn new( region : impl Into< String >, credentials : Credentials ) -> Result< Self >
+    {
+      let region = region.into();
+      if region.trim().is_empty()
+      {
+        return Err( BedrockError::Environment( "region must not be empty".to_owned() ).into() );
+      }
+      Ok( Self
+      {
+        credentials,
+        endpoint : Self::default_endpoint( &region ),
+        region,
+        timeout : Duration::from_secs( Self::DEFAULT_TIMEOUT_SECS ),
+      } )
+    }
+
+    /// Creates an environment from the standard AWS variables:
+    /// `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, optional
+    /// `AWS_SESSION_TOKEN`, and `AWS_REGION` (or `AWS_DEFAULT_REGION`).
+    ///
+    /// Profiles, SSO, and instance metadata are not consulted; resolve those
+    /// with the AWS tooling and pass the result to [`new`](Self::new).
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Credentials` when a key variable is missing or
+    /// empty and `BedrockError::Environment` when no region is set.
+    #[ inline ]
+    pub fn from_env() -> Result< Self >
+    {
+      let var = | name : &str | std::env::var( name ).ok().filter( | value | !value.trim().is_empty() );
+      let access_key_id = var( "AWS_ACCESS_KEY_ID" )
+        .ok_or_else( || BedrockError::Credentials( "AWS_ACCESS_KEY_ID is not set".to_owned() ) )?;
+      let secret_access_key = var( "AWS_SECRET_ACCESS_KEY" )
+        .ok_or_else( || BedrockError::Credentials( "AWS_SECRET_ACCESS_KEY is not set".to_owned() ) )?;
+      let mut credentials = Credentials::new( access_key_id, secret_access_key )?;
+      if let Some( token ) = var( "AWS_SESSION_TOKEN" )
+      {
+        credentials = credentials.with_session_token( token );
+      }
+      let region = var( "AWS_REGION" ).or_else( || var( "AWS_DEFAULT_REGION" ) )
+        .ok_or_else( || BedrockError::Environment( "AWS_REGION is not set".to_owned() ) )?;
+      Self::new( region, credentials )
+    }
+
+    /// Public runtime endpoint of `region`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn default_endpoint( region : &str ) -> String
+    {
+      format!( "https://bedrock-runtime.{region}.amazonaws.com/" )
+    }
+
+    /// Overrides the endpoint, e.g. with a VPC interface endpoint or a FIPS
+    /// endpoint. A trailing slash is added when missing.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_endpoint( mut self, endpoint : impl Into< String > ) -> Self
+    {
+      let mut endpoint = endpoint.into();
+      if !endpoint.ends_with( '/' )
+      {
+        endpoint.push( '/' );
+      }
+      self.endpoint = endpoint;
+      self
+    }
+
+    /// Overrides the request timeout.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_timeout( mut self, timeout : Duration ) -> Self
+    {
+      self.timeout = timeout;
+      self
+    }
+  }
+
+  impl BedrockEnvironment for BedrockEnvironmentImpl
+  {
+    #[ inline ]
+    fn credentials( &self ) -> &Credentials
+    {
+      &self.credentials
+    }
+
+    #[ inline ]
+    fn region( &self ) -> &str
+    {
+      &self.region
+    }
+
+    #[ inline ]
+    fn endpoint( &self ) -> &str
+    {
+      &self.endpoint
+    }
+
+    #[ inline ]
+    fn timeout( &self ) -> Duration
+    {
+      self.timeout
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BedrockEnvironment,
+    BedrockEnvironmentImpl,
+  };
+}
@@ -0,0 +1,146 @@
+//! Error type and result alias for Bedrock Runtime operations.
+
+mod private
+{
+  use error_tools::dependency::thiserror;
+
+  /// Error variants for Bedrock Runtime operations.
+  ///
+  /// Covers transport failures, errors returned by the service (as a non-2xx
+  /// response or as an exception frame inside a stream), and configuration
+  /// problems. Convert from `reqwest::Error` and `serde_json::Error` via
+  /// `From` impls.
+  #[ derive( Debug, Clone, PartialEq, thiserror::Error ) ]
+  #[ non_exhaustive ]
+  pub enum BedrockError
+  {
+    /// Non-2xx response from the service.
+    #[ error( "API error {status} {error_type} : {message}" ) ]
+    Api
+    {
+      /// HTTP status code.
+      status : u16,
+      /// AWS error type (`ThrottlingException`, `ValidationException`, …),
+      /// from the `x-amzn-ErrorType` header or the body.
+      error_type : String,
+      /// Error message from the body.
+      message : String,
+    },
+
+    /// Exception frame received in the middle of a stream.
+    #[ error( "Stream exception {exception_type} : {message}" ) ]
+    Exception
+    {
+      /// Exception type from the `:exception-type` header
+      /// (`throttlingException`, `modelStreamErrorException`, …).
+      exception_type : String,
+      /// Error message from the frame payload.
+      message : String,
+    },
+
+    /// HTTP transport error.
+    #[ error( "HTTP error : {0}" ) ]
+    Http( String ),
+
+    /// Network connectivity error (DNS, TCP, etc.).
+    #[ error( "Network error : {0}" ) ]
+    Network( String ),
+
+    /// Request exceeded the configured timeout.
+    #[ error( "Timeout : {0}" ) ]
+    Timeout( String ),
+
+    /// Failed to serialise the request or deserialise the response body.
+    #[ error( "Deserialisation error : {0}" ) ]
+    Deserialise( String ),
+
+    /// Malformed event-stream frame (bad length, checksum, or header).
+    #[ error( "Event stream error : {0}" ) ]
+    EventStream( String ),
+
+    /// AWS credentials are absent, empty, or unusable in a header.
+    #[ error( "Invalid credentials : {0}" ) ]
+    Credentials( String ),
+
+    /// Environment is misconfigured (e.g. missing region, unparseable endpoint).
+    #[ error( "Environment error : {0}" ) ]
+    Environment( String ),
+  }
+
+  /// Crate-level result type backed by a boxed dynamic error.
+  pub type Result< T > = error_tools::untyped::Result< T >;
+
+  impl From< reqwest::Error > for BedrockError
+  {
+    #[ inline ]
+    fn from( e : reqwest::Error ) -> Self
+    {
+      if e.is_timeout()
+      {
+        Self::Timeout( e.to_string() )
+      }
+      else if e.is_connect()
+      {
+        Self::Network( e.to_string() )
+      }
+      else
+      {
+        Self::Http( e.to_string() )
+      }
+    }
+  }
+
+  impl From< serde_json::Error > for BedrockError
+  {
+    #[ inline ]
+    fn from( e : serde_json::Error ) -> Self
+    {
+      Self::Deserialise( e.to_string() )
+    }
+  }
+
+  /// HTTP status the service uses for the same error outside a stream.
+  #[ cfg( feature = "common" ) ]
+  fn exception_status( exception_type : &str ) -> Option< u16 >
+  {
+    match exception_type
+    {
+      "throttlingException" => Some( 429 ),
+      "serviceUnavailableException" => Some( 503 ),
+      "internalServerException" => Some( 500 ),
+      "validationException" => Some( 400 ),
+      "modelTimeoutException" => Some( 408 ),
+      _ => None,
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< BedrockError > for api_common::LlmError
+  {
+    #[ inline ]
+    fn from( e : BedrockError ) -> Self
+    {
+      match e
+      {
+        BedrockError::Api { status, error_type, message } =>
+          Self::classify( Some( status ), &format!( "{error_type} : {message}" ) ),
+        BedrockError::Exception { exception_type, message } =>
+          Self::classify( exception_status( &exception_type ), &format!( "{exception_type} : {message}" ) ),
+        BedrockError::Network( message ) | BedrockError::Timeout( message ) => Self::Network( message ),
+        BedrockError::Deserialise( message ) | BedrockError::EventStream( message ) => Self::Protocol( message ),
+        BedrockError::Credentials( message ) => Self::Auth( message ),
+        BedrockError::Http( message ) => Self::classify( None, &message ),
+        BedrockError::Environment( message ) => Self::Provider { raw : message },
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BedrockError,
+    Result,
+  };
+}
@@ -0,0 +1,355 @@
+//! Binary event-stream framing (`application/vnd.amazon.eventstream`).
+//!
+//! Streaming Bedrock responses are a sequence of self-delimiting frames, not
+//! Server-Sent Events:
+//!
+//! ```text
+//! total length : u32 | headers length : u32 | prelude CRC32 : u32
+//! headers      : headers length bytes
+//! payload      : total length - headers length - 16 bytes
+//! message CRC32 : u32
+//! ```
+//!
+//! All integers are big-endian. The prelude CRC covers the first eight bytes
+//! and the message CRC everything before it. Each header is a one-byte name
+//! length, the name, a one-byte type tag, and a value whose encoding depends
+//! on the tag. [`EventStreamDecoder`] accepts bytes as they arrive, in chunks
+//! of any size, and yields each frame once it is complete.
+
+mod private
+{
+  use crate::error::{ BedrockError, Result };
+
+  /// Largest frame accepted; the service never sends frames near this size.
+  const MAX_FRAME_LEN : usize = 16 * 1024 * 1024;
+
+  /// Prelude (two lengths and their CRC) plus the trailing message CRC.
+  const FRAME_OVERHEAD : usize = 16;
+
+  /// Value of one event-stream header.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub enum EventHeaderValue
+  {
+    /// Tags 0 (`true`) and 1 (`false`).
+    Bool( bool ),
+    /// Tag 2.
+    Byte( i8 ),
+    /// Tag 3.
+    Int16( i16 ),
+    /// Tag 4.
+    Int32( i32 ),
+    /// Tag 5.
+    Int64( i64 ),
+    /// Tag 6, length-prefixed bytes.
+    Bytes( Vec< u8 > ),
+    /// Tag 7, length-prefixed UTF-8.
+    String( String ),
+    /// Tag 8, milliseconds since the Unix epoch.
+    Timestamp( i64 ),
+    /// Tag 9.
+    Uuid( [ u8; 16 ] ),
+  }
+
+  /// One decoded frame.
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct EventStreamMessage
+  {
+    /// Headers in wire order.
+    pub headers : Vec< ( String, EventHeaderValue ) >,
+    /// Frame payload; JSON for every Bedrock event.
+    pub payload : Vec< u8 >,
+  }
+
+  impl EventStreamMessage
+  {
+    /// A frame with the given payload and no headers.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( payload : impl Into< Vec< u8 > > ) -> Self
+    {
+      Self { headers : Vec::new(), payload : payload.into() }
+    }
+
+    /// Appends a header.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_header( mut self, name : impl Into< String >, value : EventHeaderValue ) -> Self
+    {
+      self.headers.push( ( name.into(), value ) );
+      self
+    }
+
+    /// The first header named `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn header( &self, name : &str ) -> Option< &EventHeaderValue >
+    {
+      self.headers.iter().find( | ( header, _ ) | header == name ).map( | ( _, value ) | value )
+    }
+
+    /// The first header named `name`, when it holds a string.
+    #[ inline ]
+    #[ must_use ]
+    pub fn header_str( &self, name : &str ) -> Option< &str >
+    {
+      match self.header( name )
+      {
+        Some( EventHeaderValue::String( value ) ) => Some( value ),
+        _ => None,
+      }
+    }
+
+    /// `:message-type` — `event`, `exception`, or `error`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn message_type( &self ) -> Option< &str >
+    {
+      self.header_str( ":message-type" )
+    }
+
+    /// `:event-type` of an `event` frame, e.g. `contentBlockDelta` or `chunk`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn event_type( &self ) -> Option< &str >
+    {
+      self.header_str( ":event-type" )
+    }
+
+    /// Turns an `exception` or `error` frame into a `BedrockError::Exception`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception; frames of any other type pass through as `Ok`.
+    #[ inline ]
+    pub fn check_exception( &self ) -> Result< () >
+    {
+      let exception_type = match self.message_type()
+      {
+        Some( "exception" ) => self.header_str( ":exception-type" ),
+        Some( "error" ) => self.header_str( ":error-code" ),
+        _ => return Ok( () ),
+      };
+      let payload = serde_json::from_slice::< serde_json::Value >( &self.payload ).unwrap_or_default();
+      let message = payload.get( "message" ).or_else( || payload.get( "Message" ) ).and_then( serde_json::Value::as_str )
+        .map_or_else
+        (
+          || self.header_str( ":error-message" ).unwrap_or_default().to_owned(),
+          str::to_owned,
+        );
+      Err( BedrockError::Exception
+      {
+        exception_type : exception_type.unwrap_or( "unknown" ).to_owned(),
+        message,
+      }.into() )
+    }
+
+    /// Encodes the frame, checksums included.
+    ///
+    /// The client only decodes; encoding exists so tests and tools can
+    /// produce frames the decoder accepts.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a header name or a string or bytes value is longer than
+    /// its length prefix allows (255 and 65 535 bytes).
+    #[ inline ]
+    #[ must_use ]
+    pub fn encode( &self ) -> Vec< u8 >
+    {
+      let mut headers = Vec::new();
+      for ( name, value ) in &self.headers
+      {
+        headers.push( u8::try_from( name.len() ).expect( "header name longer than 255 bytes" ) );
+        headers.extend_from_slice( name.as_bytes() );
+        match value
+        {
+          EventHeaderValue::Bool( true ) => headers.push( 0 ),
+          EventHeaderValue::Bool( false ) => headers.push( 1 ),
+          EventHeaderValue::Byte( value ) => { headers.push( 2 ); headers.extend_from_slice( &value.to_be_bytes() ); }
+          EventHeaderValue::Int16( value ) => { headers.push( 3 ); headers.extend_from_slice( &value.to_be_bytes() ); }
+          EventHeaderValue::Int32( value ) => { headers.push( 4 ); headers.extend_from_slice( &value.to_be_bytes() ); }
+          EventHeaderValue::Int64( value ) => { headers.push( 5 ); headers.extend_from_slice( &value.to_be_bytes() ); }
+          EventHeaderValue::Bytes( value ) => { headers.push( 6 ); push_prefixed( &mut headers, value ); }
+          EventHeaderValue::String( value ) => { headers.push( 7 ); push_prefixed( &mut headers, value.as_bytes() ); }
+          EventHeaderValue::Timestamp( value ) => { headers.push( 8 ); headers.extend_from_slice( &value.to_be_bytes() ); }
+          EventHeaderValue::Uuid( value ) => { headers.push( 9 ); headers.extend_from_slice( value ); }
+        }
+      }
+
+      let total = FRAME_OVERHEAD + headers.len() + self.payload.len();
+      let mut frame = Vec::with_capacity( total );
+      frame.extend_from_slice( &u32::try_from( total ).expect( "frame longer than u32::MAX" ).to_be_bytes() );
+      frame.extend_from_slice( &u32::try_from( headers.len() ).expect( "headers longer than u32::MAX" ).to_be_bytes() );
+      let prelude_crc = crc32fast::hash( &frame );
+      frame.extend_from_slice( &prelude_crc.to_be_bytes() );
+      frame.extend_from_slice( &headers );
+      frame.extend_from_slice( &self.payload );
+      let message_crc = crc32fast::hash( &frame );
+      frame.extend_from_slice( &message_crc.to_be_bytes() );
+      frame
+    }
+  }
+
+  fn push_prefixed( out : &mut Vec< u8 >, value : &[ u8 ] )
+  {
+    out.extend_from_slice( &u16::try_from( value.len() ).expect( "header value longer than 65535 bytes" ).to_be_bytes() );
+    out.extend_from_slice( value );
+  }
+
+  /// Incremental decoder for a byte stream of event-stream frames.
+  ///
+  /// Feed bytes with [`push`](Self::push) as they arrive and call
+  /// [`next_message`](Self::next_message) until it returns `Ok( None )`.
+  /// After an error the buffered bytes are discarded: a frame with a bad
+  /// checksum leaves no reliable boundary to resume from.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct EventStreamDecoder
+  {
+    buffer : Vec< u8 >,
+  }
+
+  impl EventStreamDecoder
+  {
+    /// An empty decoder.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Appends received bytes.
+    #[ inline ]
+    pub fn push( &mut self, bytes : &[ u8 ] )
+    {
+      self.buffer.extend_from_slice( bytes );
+    }
+
+    /// Bytes received but not yet returned as part of a frame. Non-zero
+    /// when the body ends means the last frame was cut off.
+    #[ inline ]
+    #[ must_use ]
+    pub fn buffered_len( &self ) -> usize
+    {
+      self.buffer.len()
+    }
+
+    /// The next complete frame, or `None` when more bytes are needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::EventStream` for a frame whose lengths are
+    /// impossible, whose checksums do not match, or whose headers are
+    /// malformed.
+    #[ inline ]
+    pub fn next_message( &mut self ) -> Result< Option< EventStreamMessage > >
+    {
+      if self.buffer.len() < 12
+      {
+        return Ok( None );
+      }
+      let total = read_u32( &self.buffer[ 0..4 ] ) as usize;
+      let headers_len = read_u32( &self.buffer[ 4..8 ] ) as usize;
+      if !( FRAME_OVERHEAD..=MAX_FRAME_LEN ).contains( &total ) || headers_len > total - FRAME_OVERHEAD
+      {
+        return self.fail( format!( "invalid frame lengths : total {total}, headers {headers_len}" ) );
+      }
+      if crc32fast::hash( &self.buffer[ 0..8 ] ) != read_u32( &self.buffer[ 8..12 ] )
+      {
+        return self.fail( "prelude checksum mismatch".to_owned() );
+      }
+      if self.buffer.len() < total
+      {
+        return Ok( None );
+      }
+      if crc32fast::hash( &self.buffer[ ..total - 4 ] ) != read_u32( &self.buffer[ total - 4..total ] )
+      {
+        return self.fail( "message checksum mismatch".to_owned() );
+      }
+
+      let frame : Vec< u8 > = self.buffer.drain( ..total ).collect();
+      let headers = match decode_headers( &frame[ 12..12 + headers_len ] )
+      {
+        Ok( headers ) => headers,
+        Err( message ) => return self.fail( message ),
+      };
+      let payload = frame[ 12 + headers_len..total - 4 ].to_vec();
+      Ok( Some( EventStreamMessage { headers, payload } ) )
+    }
+
+    fn fail( &mut self, message : String ) -> Result< Option< EventStreamMessage > >
+    {
+      self.buffer.clear();
+      Err( BedrockError::EventStream( message ).into() )
+    }
+  }
+
+  fn read_u32( bytes : &[ u8 ] ) -> u32
+  {
+    u32::from_be_bytes( [ bytes[ 0 ], bytes[ 1 ], bytes[ 2 ], bytes[ 3 ] ] )
+  }
+
+  /// Reads `len` bytes at `*at`, advancing it.
+  fn take< 'a >( bytes : &'a [ u8 ], at : &mut usize, len : usize ) -> core::result::Result< &'a [ u8 ], String >
+  {
+    let slice = bytes.get( *at..*at + len ).ok_or_else( || "header runs past the header block".to_owned() )?;
+    *at += len;
+    Ok( slice )
+  }
+
+  fn take_array< const N : usize >( bytes : &[ u8 ], at : &mut usize ) -> core::result::Result< [ u8; N ], String >
+  {
+    let mut array = [ 0; N ];
+    array.copy_from_slice( take( bytes, at, N )? );
+    Ok( array )
+  }
+
+  fn decode_headers( bytes : &[ u8 ] ) -> core::result::Result< Vec< ( String, EventHeaderValue ) >, String >
+  {
+    let mut headers = Vec::new();
+    let mut at = 0;
+    while at < bytes.len()
+    {
+      let name_len = usize::from( take( bytes, &mut at, 1 )?[ 0 ] );
+      let name = String::from_utf8( take( bytes, &mut at, name_len )?.to_vec() )
+        .map_err( | _ | "header name is not UTF-8".to_owned() )?;
+      let value = match take( bytes, &mut at, 1 )?[ 0 ]
+      {
+        0 => EventHeaderValue::Bool( true ),
+        1 => EventHeaderValue::Bool( false ),
+        2 => EventHeaderValue::Byte( i8::from_be_bytes( take_array( bytes, &mut at )? ) ),
+        3 => EventHeaderValue::Int16( i16::from_be_bytes( take_array( bytes, &mut at )? ) ),
+        4 => EventHeaderValue::Int32( i32::from_be_bytes( take_array( bytes, &mut at )? ) ),
+        5 => EventHeaderValue::Int64( i64::from_be_bytes( take_array( bytes, &mut at )? ) ),
+        tag @ ( 6 | 7 ) =>
+        {
+          let len = usize::from( u16::from_be_bytes( take_array( bytes, &mut at )? ) );
+          let value = take( bytes, &mut at, len )?.to_vec();
+          if tag == 6
+          {
+            EventHeaderValue::Bytes( value )
+          }
+          else
+          {
+            EventHeaderValue::String( String::from_utf8( value ).map_err( | _ | format!( "header {name} is not UTF-8" ) )? )
+          }
+        }
+        8 => EventHeaderValue::Timestamp( i64::from_be_bytes( take_array( bytes, &mut at )? ) ),
+        9 => EventHeaderValue::Uuid( take_array( bytes, &mut at )? ),
+        tag => return Err( format!( "header {name} has unknown type {tag}" ) ),
+      };
+      headers.push( ( name, value ) );
+    }
+    Ok( headers )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    EventHeaderValue,
+    EventStreamMessage,
+    EventStreamDecoder,
+  };
+}
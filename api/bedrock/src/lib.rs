@@ -0,0 +1,64 @@
+//! AWS Bedrock Runtime client.
+//!
+//! Calls the Bedrock Runtime `Converse`, `ConverseStream`, `InvokeModel`, and
+//! `InvokeModelWithResponseStream` operations, signing every request with AWS
+//! Signature Version 4 and decoding the binary `application/vnd.amazon.eventstream`
+//! framing of streaming responses. Native request bodies for Anthropic and Meta
+//! Llama models are built from, and answered in, the workspace's neutral
+//! message types.
+//!
+//! # Features
+//!
+//! - `enabled` — activates all public types, `SigV4` signing, and the HTTP client
+//! - `streaming` — `converse_stream` and `invoke_model_with_response_stream`,
+//!   with the event-stream decoder they read through
+//! - `common` — converts `api_common::NeutralMessage`s into Converse, Anthropic,
+//!   and Llama requests and their responses back into a `NeutralMessage`, and
+//!   `BedrockError` into `api_common::LlmError`; with `streaming`,
+//!   `api_common::IntoStreamEvents` for `ConverseStreamEvent`
+//! - `connection` — `Client::with_connection_tuning`, applying an
+//!   `api_common::ConnectionTuning` to the HTTP client
+//! - `http2` — HTTP/2 support forwarded to `reqwest`; not in `full`
+//! - `integration` — real-API integration tests (requires AWS credentials)
+//! - `rustls` / `native_tls` — TLS backend forwarded to `reqwest`
+//! - `full` — enables `enabled`, `streaming`, `common`, `connection`, `integration`, and `rustls`
+//!
+//! # Architecture
+//!
+//! Follows the "Thin Client, Rich API" principle: every method maps to exactly
+//! one Bedrock Runtime operation, zero automatic decision-making, explicit
+//! control over all operations. Credentials are never looked up implicitly;
+//! the caller builds the environment from values or from the standard `AWS_*`
+//! variables with `BedrockEnvironmentImpl::from_env`.
+
+#[ cfg( feature = "enabled" ) ]
+use mod_interface::mod_interface;
+
+mod private {}
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// Error type and result alias.
+  layer error;
+
+  /// AWS Signature Version 4 request signing.
+  layer signing;
+
+  /// Binary event-stream framing used by streaming responses.
+  #[ cfg( feature = "streaming" ) ]
+  layer event_stream;
+
+  /// Wire types for Converse, streaming events, and native model bodies.
+  layer components;
+
+  /// Environment configuration trait and default implementation.
+  layer environment;
+
+  /// Readers over streaming response bodies.
+  #[ cfg( feature = "streaming" ) ]
+  layer response_stream;
+
+  /// Async HTTP client.
+  layer client;
+}
@@ -0,0 +1,189 @@
+//! Readers over streaming response bodies.
+//!
+//! Both streaming operations answer with event-stream frames. [`ConverseStream`]
+//! yields each frame as a [`ConverseStreamEvent`]; [`InvokeModelStream`]
+//! yields the model's native chunks, base64-decoded from the `chunk` events.
+//! Neither buffers beyond the frame being read.
+
+mod private
+{
+  use crate::error::{ BedrockError, Result };
+  use crate::event_stream::{ EventStreamDecoder, EventStreamMessage };
+  use crate::ConverseStreamEvent;
+  use base64::Engine as _;
+
+  /// Event-stream frames read from a response body as it arrives.
+  #[ derive( Debug ) ]
+  pub struct EventStreamResponse
+  {
+    response : reqwest::Response,
+    decoder : EventStreamDecoder,
+    finished : bool,
+  }
+
+  impl EventStreamResponse
+  {
+    /// Reads frames from a successful `application/vnd.amazon.eventstream`
+    /// response.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( response : reqwest::Response ) -> Self
+    {
+      Self { response, decoder : EventStreamDecoder::new(), finished : false }
+    }
+
+    /// The next frame, or `None` at the end of the body.
+    ///
+    /// After an error the stream ends.
+    #[ inline ]
+    pub async fn next_message( &mut self ) -> Option< Result< EventStreamMessage > >
+    {
+      loop
+      {
+        match self.decoder.next_message()
+        {
+          Ok( Some( message ) ) => return Some( Ok( message ) ),
+          Ok( None ) => {}
+          Err( error ) =>
+          {
+            self.finished = true;
+            return Some( Err( error ) );
+          }
+        }
+        if self.finished
+        {
+          return None;
+        }
+        match self.response.chunk().await
+        {
+          Ok( Some( bytes ) ) => self.decoder.push( &bytes ),
+          Ok( None ) =>
+          {
+            self.finished = true;
+            if self.decoder.buffered_len() > 0
+            {
+              return Some( Err( BedrockError::EventStream( "body ended inside a frame".to_owned() ).into() ) );
+            }
+            return None;
+          }
+          Err( error ) =>
+          {
+            self.finished = true;
+            return Some( Err( BedrockError::from( error ).into() ) );
+          }
+        }
+      }
+    }
+  }
+
+  /// Events of a `ConverseStream` response.
+  #[ derive( Debug ) ]
+  pub struct ConverseStream
+  {
+    inner : EventStreamResponse,
+  }
+
+  impl ConverseStream
+  {
+    /// Reads events from a successful `ConverseStream` response.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( response : reqwest::Response ) -> Self
+    {
+      Self { inner : EventStreamResponse::new( response ) }
+    }
+
+    /// The next event, or `None` at the end of the stream.
+    ///
+    /// An exception frame (throttling, model error) is returned as
+    /// `BedrockError::Exception`.
+    #[ inline ]
+    pub async fn next_event( &mut self ) -> Option< Result< ConverseStreamEvent > >
+    {
+      let message = self.inner.next_message().await?;
+      Some( message.and_then( | message | ConverseStreamEvent::from_message( &message ) ) )
+    }
+  }
+
+  /// Native chunks of an `InvokeModelWithResponseStream` response.
+  #[ derive( Debug ) ]
+  pub struct InvokeModelStream
+  {
+    inner : EventStreamResponse,
+  }
+
+  impl InvokeModelStream
+  {
+    /// Reads chunks from a successful `InvokeModelWithResponseStream` response.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( response : reqwest::Response ) -> Self
+    {
+      Self { inner : EventStreamResponse::new( response ) }
+    }
+
+    /// The next chunk in the model's native streaming format (for Claude,
+    /// one Messages API stream event as JSON), or `None` at the end.
+    ///
+    /// Frames other than `chunk` events are skipped; exception frames are
+    /// returned as `BedrockError::Exception`.
+    #[ inline ]
+    pub async fn next_chunk( &mut self ) -> Option< Result< Vec< u8 > > >
+    {
+      loop
+      {
+        let message = match self.inner.next_message().await?
+        {
+          Ok( message ) => message,
+          Err( error ) => return Some( Err( error ) ),
+        };
+        if let Err( error ) = message.check_exception()
+        {
+          return Some( Err( error ) );
+        }
+        if message.event_type() == Some( "chunk" )
+        {
+          return Some( decode_chunk( &message.payload ) );
+        }
+      }
+    }
+
+    /// The next chunk deserialised as `T`.
+    ///
+    /// # Errors
+    ///
+    /// As [`next_chunk`](Self::next_chunk), plus `BedrockError::Deserialise`
+    /// when the chunk is not a `T`.
+    #[ inline ]
+    pub async fn next_json< T : serde::de::DeserializeOwned >( &mut self ) -> Option< Result< T > >
+    {
+      let chunk = self.next_chunk().await?;
+      Some( chunk.and_then( | bytes | serde_json::from_slice( &bytes ).map_err( | e | BedrockError::from( e ).into() ) ) )
+    }
+  }
+
+  /// Payload of a `chunk` event: `{"bytes": "<base64>"}`.
+  #[ derive( serde::Deserialize ) ]
+  struct PayloadPart
+  {
+    bytes : String,
+  }
+
+  fn decode_chunk( payload : &[ u8 ] ) -> Result< Vec< u8 > >
+  {
+    let part : PayloadPart = serde_json::from_slice( payload ).map_err( BedrockError::from )?;
+    base64::engine::general_purpose::STANDARD
+      .decode( part.bytes )
+      .map_err( | e | BedrockError::Deserialise( format!( "chunk is not base64 : {e}" ) ).into() )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    EventStreamResponse,
+    ConverseStream,
+    InvokeModelStream,
+  };
+}
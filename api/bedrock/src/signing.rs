@@ -0,0 +1,277 @@
+//! AWS Signature Version 4 request signing.
+//!
+//! Every Bedrock Runtime request carries an `Authorization` header computed
+//! from the request itself: method, path, query, the signed headers, and a
+//! SHA-256 of the body, folded into a key derived from the secret access key,
+//! the date, the region, and the service. [`sign`] returns the headers to add;
+//! it does not send anything.
+//!
+//! Paths follow the rules for every service but S3: the request path is
+//! URI-encoded once when the URL is built (see [`encode_path_segment`]) and
+//! each segment is encoded again for the canonical request, so a model id
+//! such as `anthropic.claude-3-haiku-20240307-v1:0` appears as `…v1%3A0` on
+//! the wire and `…v1%253A0` in the signature.
+
+mod private
+{
+  use crate::error::{ BedrockError, Result };
+  use api_secret::Masked;
+  use chrono::{ DateTime, Utc };
+  use core::fmt;
+  use core::fmt::Write as _;
+  use hmac::{ Hmac, KeyInit, Mac };
+  use sha2::{ Digest, Sha256 };
+
+  /// Signing algorithm written into the string to sign and the header.
+  const ALGORITHM : &str = "AWS4-HMAC-SHA256";
+
+  /// AWS credentials used to sign requests.
+  ///
+  /// `Debug` shows the access key id; the secret and the session token are
+  /// masked to their first characters.
+  #[ derive( Clone, PartialEq, Eq ) ]
+  pub struct Credentials
+  {
+    access_key_id : String,
+    secret_access_key : String,
+    session_token : Option< String >,
+  }
+
+  impl Credentials
+  {
+    /// Long-term credentials from an access key id and secret access key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BedrockError::Credentials` when either value is empty or
+    /// whitespace-only.
+    #[ inline ]
+    pub fn new( access_key_id : impl Into< String >, secret_access_key : impl Into< String > ) -> Result< Self >
+    {
+      let access_key_id = access_key_id.into();
+      let secret_access_key = secret_access_key.into();
+      if access_key_id.trim().is_empty() || secret_access_key.trim().is_empty()
+      {
+        return Err( BedrockError::Credentials( "access key id and secret access key must not be empty".to_owned() ).into() );
+      }
+      Ok( Self { access_key_id, secret_access_key, session_token : None } )
+    }
+
+    /// Adds the session token of temporary (STS) credentials; it is sent as
+    /// `x-amz-security-token` and signed with the request.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_session_token( mut self, session_token : impl Into< String > ) -> Self
+    {
+      self.session_token = Some( session_token.into() );
+      self
+    }
+
+    /// The access key id.
+    #[ inline ]
+    #[ must_use ]
+    pub fn access_key_id( &self ) -> &str
+    {
+      &self.access_key_id
+    }
+
+    /// The session token, for temporary credentials.
+    #[ inline ]
+    #[ must_use ]
+    pub fn session_token( &self ) -> Option< &str >
+    {
+      self.session_token.as_deref()
+    }
+  }
+
+  impl fmt::Debug for Credentials
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "Credentials" )
+        .field( "access_key_id", &self.access_key_id )
+        .field( "secret_access_key", &Masked( &self.secret_access_key ) )
+        .field( "session_token", &self.session_token.as_deref().map( Masked ) )
+        .finish()
+    }
+  }
+
+  /// Who signs, for which region and service, and at what time.
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct SigningParams< 'a >
+  {
+    /// Credentials to sign with.
+    pub credentials : &'a Credentials,
+    /// AWS region, e.g. `us-east-1`.
+    pub region : &'a str,
+    /// Signing name of the service; `bedrock` for the Bedrock Runtime.
+    pub service : &'a str,
+    /// Signing time; the service rejects signatures more than five minutes off.
+    pub time : DateTime< Utc >,
+  }
+
+  /// Computes the `SigV4` headers for a request.
+  ///
+  /// `headers` are the headers that will be sent and should be signed
+  /// (`content-type`, `accept`, …); `host` is taken from `url` and
+  /// `x-amz-date` and `x-amz-security-token` are added, so none of them need
+  /// to be passed. The query string of `url` is signed as given, with its
+  /// parameters sorted.
+  ///
+  /// Returns `x-amz-date`, `x-amz-security-token` (for temporary credentials),
+  /// and `authorization`, in that order, to be added to the request.
+  #[ inline ]
+  #[ must_use ]
+  pub fn sign
+  (
+    params : &SigningParams< '_ >,
+    method : &str,
+    url : &reqwest::Url,
+    headers : &[ ( &str, &str ) ],
+    body : &[ u8 ],
+  ) -> Vec< ( &'static str, String ) >
+  {
+    let amz_date = params.time.format( "%Y%m%dT%H%M%SZ" ).to_string();
+    let date = &amz_date[ ..8 ];
+    let scope = format!( "{date}/{}/{}/aws4_request", params.region, params.service );
+
+    let mut signed : Vec< ( String, String ) > = headers.iter()
+      .map( | ( name, value ) | ( name.to_ascii_lowercase(), canonical_header_value( value ) ) )
+      .collect();
+    signed.push( ( "host".to_owned(), host( url ) ) );
+    signed.push( ( "x-amz-date".to_owned(), amz_date.clone() ) );
+    if let Some( token ) = params.credentials.session_token()
+    {
+      signed.push( ( "x-amz-security-token".to_owned(), token.to_owned() ) );
+    }
+    signed.sort();
+
+    let mut canonical_headers = String::new();
+    for ( name, value ) in &signed
+    {
+      let _ = writeln!( canonical_headers, "{name}:{value}" );
+    }
+    let signed_headers = signed.iter().map( | ( name, _ ) | name.as_str() ).collect::< Vec< _ > >().join( ";" );
+
+    let canonical_request = format!
+    (
+      "{method}\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{}",
+      canonical_path( url.path() ),
+      canonical_query( url.query().unwrap_or( "" ) ),
+      hex( &Sha256::digest( body ) ),
+    );
+    let string_to_sign = format!( "{ALGORITHM}\n{amz_date}\n{scope}\n{}", hex( &Sha256::digest( canonical_request.as_bytes() ) ) );
+
+    let key = hmac( format!( "AWS4{}", params.credentials.secret_access_key ).as_bytes(), date.as_bytes() );
+    let key = hmac( &key, params.region.as_bytes() );
+    let key = hmac( &key, params.service.as_bytes() );
+    let key = hmac( &key, b"aws4_request" );
+    let signature = hex( &hmac( &key, string_to_sign.as_bytes() ) );
+
+    let mut result = vec![ ( "x-amz-date", amz_date ) ];
+    if let Some( token ) = params.credentials.session_token()
+    {
+      result.push( ( "x-amz-security-token", token.to_owned() ) );
+    }
+    result.push
+    ( (
+      "authorization",
+      format!
+      (
+        "{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        params.credentials.access_key_id,
+      ),
+    ) );
+    result
+  }
+
+  /// URI-encodes one path segment: everything but `A-Z a-z 0-9 - _ . ~`
+  /// becomes `%XX`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn encode_path_segment( segment : &str ) -> String
+  {
+    let mut encoded = String::with_capacity( segment.len() );
+    for byte in segment.bytes()
+    {
+      if byte.is_ascii_alphanumeric() || matches!( byte, b'-' | b'_' | b'.' | b'~' )
+      {
+        encoded.push( char::from( byte ) );
+      }
+      else
+      {
+        let _ = write!( encoded, "%{byte:02X}" );
+      }
+    }
+    encoded
+  }
+
+  /// `host` header value: the host, plus the port when it is not the
+  /// scheme's default.
+  fn host( url : &reqwest::Url ) -> String
+  {
+    let host = url.host_str().unwrap_or_default();
+    match url.port()
+    {
+      Some( port ) => format!( "{host}:{port}" ),
+      None => host.to_owned(),
+    }
+  }
+
+  /// Encodes every segment of the (already encoded) request path again.
+  fn canonical_path( path : &str ) -> String
+  {
+    if path.is_empty()
+    {
+      return "/".to_owned();
+    }
+    path.split( '/' ).map( encode_path_segment ).collect::< Vec< _ > >().join( "/" )
+  }
+
+  /// Sorts the query parameters.
+  fn canonical_query( query : &str ) -> String
+  {
+    let mut pairs : Vec< String > = query.split( '&' )
+      .filter( | pair | !pair.is_empty() )
+      .map( | pair | if pair.contains( '=' ) { pair.to_owned() } else { format!( "{pair}=" ) } )
+      .collect();
+    pairs.sort();
+    pairs.join( "&" )
+  }
+
+  /// Trims the value and collapses runs of spaces into one.
+  fn canonical_header_value( value : &str ) -> String
+  {
+    value.split_whitespace().collect::< Vec< _ > >().join( " " )
+  }
+
+  fn hmac( key : &[ u8 ], data : &[ u8 ] ) -> Vec< u8 >
+  {
+    // HMAC accepts keys of any length, so construction cannot fail.
+    let mut mac = < Hmac< Sha256 > as KeyInit >::new_from_slice( key ).expect( "HMAC accepts any key length" );
+    mac.update( data );
+    mac.finalize().into_bytes().to_vec()
+  }
+
+  fn hex( bytes : &[ u8 ] ) -> String
+  {
+    let mut hex = String::with_capacity( bytes.len() * 2 );
+    for byte in bytes
+    {
+      let _ = write!( hex, "{byte:02x}" );
+    }
+    hex
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Credentials,
+    SigningParams,
+    sign,
+    encode_path_segment,
+  };
+}
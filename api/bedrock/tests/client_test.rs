@@ -0,0 +1,205 @@
+//! Tests for `Client` construction and the signed request path.
+//!
+//! Unit tests validate generic trait acceptance and that an unreachable
+//! endpoint surfaces as a transport error. Integration tests call the real
+//! service: a valid key pair must get an answer, and a fake one must be
+//! refused with HTTP 403 as an `Api` error.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | client_build_accepts_custom_environment_implementor | unit | Generic `Client<E>` trait polymorphism |
+//! | unreachable_endpoint_fails_with_transport_error | unit | Connection failure → `Network`/`Http`, not `Api` |
+//! | warm_up_fails_when_nothing_listens | unit | `warm_up` reports an unreachable endpoint |
+//! | connection_tuning_refuses_malformed_proxy | unit | `with_connection_tuning` rejects a bad proxy URL |
+//! | client_converse_succeeds_with_real_credentials | integration | Converse success path returns text |
+//! | client_converse_stream_succeeds_with_real_credentials | integration | Event-stream path yields deltas and `messageStop` |
+//! | client_warm_up_reaches_real_endpoint | integration | Unsigned `HEAD` accepts any HTTP status |
+//! | client_converse_returns_api_error_with_fake_credentials | integration | Signed request with unknown key → `Api` 403 |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_bedrock::{ BedrockEnvironment, BedrockEnvironmentImpl, Client, Credentials };
+use core::time::Duration;
+
+// ------------------------------------------------------------------ //
+//  Unit tests
+// ------------------------------------------------------------------ //
+
+/// `Client::build` must accept any type implementing `BedrockEnvironment`,
+/// so callers can plug in their own credential source.
+#[ test ]
+fn client_build_accepts_custom_environment_implementor()
+{
+  #[ derive( Debug ) ]
+  struct CustomEnv
+  {
+    credentials : Credentials,
+  }
+
+  #[ allow( clippy::unnecessary_literal_bound ) ]
+  impl BedrockEnvironment for CustomEnv
+  {
+    fn credentials( &self ) -> &Credentials { &self.credentials }
+    fn region( &self ) -> &str { "us-east-1" }
+    fn endpoint( &self ) -> &str { "http://127.0.0.1:1/" }
+    fn timeout( &self ) -> Duration { Duration::from_secs( 5 ) }
+  }
+
+  let env = CustomEnv { credentials : Credentials::new( "AKIDEXAMPLE", "secret" ).unwrap() };
+  let client = Client::build( env );
+
+  assert!( client.is_ok(), "Client::build must accept a custom BedrockEnvironment; got: {:?}", client.err() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A request that never reaches the service must not be reported as an
+/// `Api` error, which callers treat as a service decision.
+#[ tokio::test ]
+async fn unreachable_endpoint_fails_with_transport_error()
+{
+  use api_bedrock::{ BedrockError, ConverseRequest, Message };
+
+  let env = BedrockEnvironmentImpl::new( "us-east-1", Credentials::new( "AKIDEXAMPLE", "secret" ).unwrap() )
+    .unwrap()
+    .with_endpoint( "http://127.0.0.1:1" )
+    .with_timeout( Duration::from_secs( 5 ) );
+  let client = Client::build( env ).unwrap();
+
+  let err = client.converse( "model", &ConverseRequest::new( vec![ Message::user( "Hi" ) ] ) ).await.unwrap_err();
+
+  assert!(
+    matches!( err.downcast_ref::< BedrockError >(), Some( BedrockError::Network( _ ) | BedrockError::Http( _ ) ) ),
+    "connection failure must be a transport error; got: {err}",
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `warm_up` must report an endpoint it cannot connect to, with or without
+/// connection tuning applied.
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  let env = BedrockEnvironmentImpl::new( "us-east-1", Credentials::new( "AKIDEXAMPLE", "secret" ).unwrap() )
+    .unwrap()
+    .with_endpoint( "http://127.0.0.1:1" )
+    .with_timeout( Duration::from_secs( 5 ) );
+  let client = Client::build( env ).unwrap();
+  #[ cfg( feature = "connection" ) ]
+  let client = client.with_connection_tuning( api_common::ConnectionTuning::low_latency() ).unwrap();
+
+  assert!( client.warm_up().await.is_err() );
+}
+
+/// A malformed proxy URL must be refused when the HTTP client is rebuilt.
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_refuses_malformed_proxy()
+{
+  let env = BedrockEnvironmentImpl::new( "us-east-1", Credentials::new( "AKIDEXAMPLE", "secret" ).unwrap() ).unwrap();
+  let client = Client::build( env ).unwrap()
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+
+  assert!( client.is_err(), "a malformed proxy URL must be refused" );
+}
+
+// ------------------------------------------------------------------ //
+//  Integration tests
+// ------------------------------------------------------------------ //
+
+#[ cfg( feature = "integration" ) ]
+const MODEL_ID : &str = "anthropic.claude-3-haiku-20240307-v1:0";
+
+/// Environment from the AWS keys in `secret/-secrets.sh`.
+#[ cfg( feature = "integration" ) ]
+fn real_environment() -> BedrockEnvironmentImpl
+{
+  let ws = workspace_tools::workspace().expect( "workspace root must be resolvable" );
+  let secret = | name : &str | ws.load_secret_key( name, "-secrets.sh" )
+    .unwrap_or_else( | _ | panic!( "{name} must be set in secret/-secrets.sh" ) );
+  let credentials = Credentials::new( secret( "AWS_ACCESS_KEY_ID" ), secret( "AWS_SECRET_ACCESS_KEY" ) )
+    .expect( "credentials must be non-empty" );
+  BedrockEnvironmentImpl::new( secret( "AWS_REGION" ), credentials ).expect( "region must be non-empty" )
+}
+
+/// `converse` with valid credentials must return generated text.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_converse_succeeds_with_real_credentials()
+{
+  use api_bedrock::{ ConverseRequest, InferenceConfiguration, Message };
+
+  let client = Client::build( real_environment() ).expect( "Client::build() must succeed" );
+  let request = ConverseRequest::new( vec![ Message::user( "Reply with the word ok." ) ] )
+    .with_inference_config( InferenceConfiguration { max_tokens : Some( 16 ), ..Default::default() } );
+
+  let response = client.converse( MODEL_ID, &request ).await.expect( "converse must succeed" );
+
+  assert!( !response.text().is_empty(), "response must contain text; got: {response:?}" );
+  assert!( response.usage.output_tokens > 0 );
+}
+
+/// `converse_stream` must decode frames into text deltas ending with `messageStop`.
+#[ cfg( all( feature = "integration", feature = "streaming" ) ) ]
+#[ tokio::test ]
+async fn client_converse_stream_succeeds_with_real_credentials()
+{
+  use api_bedrock::{ ConverseRequest, ConverseStreamEvent, InferenceConfiguration, Message };
+
+  let client = Client::build( real_environment() ).expect( "Client::build() must succeed" );
+  let request = ConverseRequest::new( vec![ Message::user( "Count to three." ) ] )
+    .with_inference_config( InferenceConfiguration { max_tokens : Some( 32 ), ..Default::default() } );
+
+  let mut stream = client.converse_stream( MODEL_ID, &request ).await.expect( "converse_stream must start" );
+  let mut text = String::new();
+  let mut stopped = false;
+  while let Some( event ) = stream.next_event().await
+  {
+    match event.expect( "stream must decode" )
+    {
+      ConverseStreamEvent::ContentBlockDelta( delta ) => text.push_str( delta.delta.text.as_deref().unwrap_or_default() ),
+      ConverseStreamEvent::MessageStop( _ ) => stopped = true,
+      _ => {}
+    }
+  }
+
+  assert!( !text.is_empty(), "stream must carry text deltas" );
+  assert!( stopped, "stream must end with messageStop" );
+}
+
+/// `warm_up` sends an unsigned `HEAD`; the service refuses it, which must
+/// still count as a warm connection.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_warm_up_reaches_real_endpoint()
+{
+  let client = Client::build( real_environment() ).expect( "Client::build() must succeed" );
+
+  let warmed = client.warm_up().await;
+
+  assert!( warmed.is_ok(), "any HTTP status must count as a warm connection; got: {warmed:?}" );
+}
+
+/// A correctly signed request with an unknown access key must be refused by
+/// the service and surface as `Api` with status 403.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_converse_returns_api_error_with_fake_credentials()
+{
+  use api_bedrock::{ BedrockError, ConverseRequest, Message };
+
+  let credentials = Credentials::new( "AKIAFAKEINTEGRATION0", "fake-secret" ).unwrap();
+  let env = BedrockEnvironmentImpl::new( "us-east-1", credentials ).unwrap();
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+
+  let err = client.converse( MODEL_ID, &ConverseRequest::new( vec![ Message::user( "Hi" ) ] ) ).await.unwrap_err();
+
+  match err.downcast_ref::< BedrockError >()
+  {
+    Some( BedrockError::Api { status, .. } ) => assert_eq!( *status, 403 ),
+    other => panic!( "expected Api 403, got: {other:?}" ),
+  }
+}
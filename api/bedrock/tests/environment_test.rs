@@ -0,0 +1,70 @@
+//! Tests for `BedrockEnvironment` and `BedrockEnvironmentImpl`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | new_uses_regional_endpoint_and_default_timeout | Endpoint derived from region; 300 s timeout |
+//! | new_fails_with_empty_region | Empty or whitespace region returns Err |
+//! | with_endpoint_adds_trailing_slash | Custom endpoint normalised to end in `/` |
+//! | with_timeout_overrides | Builder replaces timeout |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_bedrock::{ BedrockEnvironment, BedrockEnvironmentImpl, Credentials };
+use core::time::Duration;
+
+fn credentials() -> Credentials
+{
+  Credentials::new( "AKIDEXAMPLE", "secret" ).expect( "non-empty credentials must be accepted" )
+}
+
+// ------------------------------------------------------------------ //
+
+/// The endpoint must be the public runtime endpoint of the region, with the
+/// trailing slash the client appends operation paths to.
+#[ test ]
+fn new_uses_regional_endpoint_and_default_timeout()
+{
+  let env = BedrockEnvironmentImpl::new( "eu-west-1", credentials() ).unwrap();
+
+  assert_eq!( env.region(), "eu-west-1" );
+  assert_eq!( env.endpoint(), "https://bedrock-runtime.eu-west-1.amazonaws.com/" );
+  assert_eq!( env.timeout(), Duration::from_secs( BedrockEnvironmentImpl::DEFAULT_TIMEOUT_SECS ) );
+  assert_eq!( env.credentials().access_key_id(), "AKIDEXAMPLE" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The region is part of both the host and the signature scope; an empty one
+/// must be rejected at construction.
+#[ test ]
+fn new_fails_with_empty_region()
+{
+  assert!( BedrockEnvironmentImpl::new( "", credentials() ).is_err() );
+  assert!( BedrockEnvironmentImpl::new( "  ", credentials() ).is_err() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// VPC and FIPS endpoints are often copied without the trailing slash.
+#[ test ]
+fn with_endpoint_adds_trailing_slash()
+{
+  let env = BedrockEnvironmentImpl::new( "us-east-1", credentials() ).unwrap()
+    .with_endpoint( "https://bedrock-runtime-fips.us-east-1.amazonaws.com" );
+
+  assert_eq!( env.endpoint(), "https://bedrock-runtime-fips.us-east-1.amazonaws.com/" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `with_timeout()` must replace the timeout.
+#[ test ]
+fn with_timeout_overrides()
+{
+  let env = BedrockEnvironmentImpl::new( "us-east-1", credentials() ).unwrap()
+    .with_timeout( Duration::from_secs( 5 ) );
+
+  assert_eq!( env.timeout(), Duration::from_secs( 5 ) );
+}
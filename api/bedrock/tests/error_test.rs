@@ -0,0 +1,110 @@
+//! Tests for `BedrockError` Display formatting and conversions.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | api_error_display_contains_status_type_and_message | Display | Api variant formatting |
+//! | exception_display_contains_type_and_message | Display | Exception variant formatting |
+//! | from_serde_json_error_produces_deserialise_variant | From | serde_json::Error → Deserialise |
+//! | into_llm_error_classifies_api_status | From | Api status → `LlmError` class (`common`) |
+//! | into_llm_error_classifies_stream_exception | From | Exception type → `LlmError` class (`common`) |
+//! | into_llm_error_maps_transport_variants | From | Credentials/EventStream/Timeout → `LlmError` (`common`) |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_bedrock::BedrockError;
+
+// ------------------------------------------------------------------ //
+
+/// The Api message must carry the status, the AWS error type, and the text.
+#[ test ]
+fn api_error_display_contains_status_type_and_message()
+{
+  let err = BedrockError::Api
+  {
+    status : 400,
+    error_type : "ValidationException".to_owned(),
+    message : "Malformed input request".to_owned(),
+  };
+
+  assert_eq!( err.to_string(), "API error 400 ValidationException : Malformed input request" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Exceptions raised mid-stream must name their type.
+#[ test ]
+fn exception_display_contains_type_and_message()
+{
+  let err = BedrockError::Exception
+  {
+    exception_type : "modelStreamErrorException".to_owned(),
+    message : "boom".to_owned(),
+  };
+
+  assert_eq!( err.to_string(), "Stream exception modelStreamErrorException : boom" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `serde_json` failures must become `Deserialise`.
+#[ test ]
+fn from_serde_json_error_produces_deserialise_variant()
+{
+  let json_err = serde_json::from_str::< serde_json::Value >( "{" ).unwrap_err();
+  assert!( matches!( BedrockError::from( json_err ), BedrockError::Deserialise( _ ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// HTTP statuses classify like every other provider's.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn into_llm_error_classifies_api_status()
+{
+  use api_common::LlmError;
+
+  let api = | status : u16, error_type : &str | BedrockError::Api
+  {
+    status,
+    error_type : error_type.to_owned(),
+    message : "m".to_owned(),
+  };
+
+  assert!( matches!( LlmError::from( api( 429, "ThrottlingException" ) ), LlmError::RateLimited { .. } ) );
+  assert!( matches!( LlmError::from( api( 403, "AccessDeniedException" ) ), LlmError::Auth( _ ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Stream exceptions have no status; their type must still classify them
+/// as the same error would be outside a stream.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn into_llm_error_classifies_stream_exception()
+{
+  use api_common::LlmError;
+
+  let err = BedrockError::Exception
+  {
+    exception_type : "throttlingException".to_owned(),
+    message : "slow down".to_owned(),
+  };
+
+  assert!( matches!( LlmError::from( err ), LlmError::RateLimited { .. } ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Variants without a status map directly.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn into_llm_error_maps_transport_variants()
+{
+  use api_common::LlmError;
+
+  assert!( matches!( LlmError::from( BedrockError::Credentials( "m".to_owned() ) ), LlmError::Auth( _ ) ) );
+  assert!( matches!( LlmError::from( BedrockError::EventStream( "m".to_owned() ) ), LlmError::Protocol( _ ) ) );
+  assert!( matches!( LlmError::from( BedrockError::Timeout( "m".to_owned() ) ), LlmError::Network( _ ) ) );
+}
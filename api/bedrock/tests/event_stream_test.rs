@@ -0,0 +1,153 @@
+//! Tests for the event-stream frame encoder and `EventStreamDecoder`.
+//!
+//! Frames are produced with `EventStreamMessage::encode`, so the tests cover
+//! the decoder's framing, checksum, and header handling without a server.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | encode_decode_round_trips_all_header_types | Every header tag survives encode → decode |
+//! | decoder_waits_for_split_frame | A frame split across pushes decodes once complete |
+//! | decoder_returns_consecutive_frames | Two frames in one push decode in order |
+//! | decoder_rejects_message_checksum_mismatch | Corrupted payload → `EventStream` error, buffer cleared |
+//! | decoder_rejects_impossible_lengths | Total length below the frame overhead → `EventStream` error |
+//! | check_exception_maps_exception_frame | `:message-type exception` → `BedrockError::Exception` |
+//! | check_exception_passes_event_frame | Ordinary event frames pass |
+
+#![ cfg( all( feature = "enabled", feature = "streaming" ) ) ]
+
+use api_bedrock::{ BedrockError, EventHeaderValue, EventStreamDecoder, EventStreamMessage };
+
+fn event( event_type : &str, payload : &str ) -> EventStreamMessage
+{
+  EventStreamMessage::new( payload.as_bytes() )
+    .with_header( ":message-type", EventHeaderValue::String( "event".to_owned() ) )
+    .with_header( ":event-type", EventHeaderValue::String( event_type.to_owned() ) )
+    .with_header( ":content-type", EventHeaderValue::String( "application/json".to_owned() ) )
+}
+
+// ------------------------------------------------------------------ //
+
+/// Every header value type must decode back to what was encoded.
+#[ test ]
+fn encode_decode_round_trips_all_header_types()
+{
+  let message = EventStreamMessage::new( b"{}".to_vec() )
+    .with_header( "t", EventHeaderValue::Bool( true ) )
+    .with_header( "f", EventHeaderValue::Bool( false ) )
+    .with_header( "byte", EventHeaderValue::Byte( -3 ) )
+    .with_header( "i16", EventHeaderValue::Int16( -300 ) )
+    .with_header( "i32", EventHeaderValue::Int32( 70_000 ) )
+    .with_header( "i64", EventHeaderValue::Int64( -5_000_000_000 ) )
+    .with_header( "bytes", EventHeaderValue::Bytes( vec![ 0, 1, 2 ] ) )
+    .with_header( "string", EventHeaderValue::String( "value".to_owned() ) )
+    .with_header( "ts", EventHeaderValue::Timestamp( 1_700_000_000_000 ) )
+    .with_header( "uuid", EventHeaderValue::Uuid( [ 7; 16 ] ) );
+
+  let mut decoder = EventStreamDecoder::new();
+  decoder.push( &message.encode() );
+
+  assert_eq!( decoder.next_message().unwrap(), Some( message ) );
+  assert_eq!( decoder.buffered_len(), 0 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Network chunks do not align with frames; a partial frame must yield
+/// `None` until the rest arrives.
+#[ test ]
+fn decoder_waits_for_split_frame()
+{
+  let frame = event( "messageStart", r#"{"role":"assistant"}"# ).encode();
+  let mut decoder = EventStreamDecoder::new();
+
+  decoder.push( &frame[ ..5 ] );
+  assert_eq!( decoder.next_message().unwrap(), None );
+  decoder.push( &frame[ 5..frame.len() - 1 ] );
+  assert_eq!( decoder.next_message().unwrap(), None );
+  decoder.push( &frame[ frame.len() - 1.. ] );
+
+  let message = decoder.next_message().unwrap().expect( "complete frame must decode" );
+  assert_eq!( message.event_type(), Some( "messageStart" ) );
+  assert_eq!( message.payload, br#"{"role":"assistant"}"# );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Several frames in one chunk must come out one per call, in order.
+#[ test ]
+fn decoder_returns_consecutive_frames()
+{
+  let mut bytes = event( "contentBlockStop", r#"{"contentBlockIndex":0}"# ).encode();
+  bytes.extend( event( "messageStop", r#"{"stopReason":"end_turn"}"# ).encode() );
+  let mut decoder = EventStreamDecoder::new();
+  decoder.push( &bytes );
+
+  assert_eq!( decoder.next_message().unwrap().unwrap().event_type(), Some( "contentBlockStop" ) );
+  assert_eq!( decoder.next_message().unwrap().unwrap().event_type(), Some( "messageStop" ) );
+  assert_eq!( decoder.next_message().unwrap(), None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A flipped payload byte must fail the message checksum and drop the
+/// buffered bytes, since no frame boundary can be trusted afterwards.
+#[ test ]
+fn decoder_rejects_message_checksum_mismatch()
+{
+  let mut frame = event( "messageStop", r#"{"stopReason":"end_turn"}"# ).encode();
+  let last_payload_byte = frame.len() - 5;
+  frame[ last_payload_byte ] ^= 0xff;
+  let mut decoder = EventStreamDecoder::new();
+  decoder.push( &frame );
+
+  let err = decoder.next_message().unwrap_err();
+  assert!( matches!( err.downcast_ref::< BedrockError >(), Some( BedrockError::EventStream( _ ) ) ), "got {err}" );
+  assert_eq!( decoder.buffered_len(), 0 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A prelude claiming a frame shorter than its own overhead must be rejected
+/// rather than waited on.
+#[ test ]
+fn decoder_rejects_impossible_lengths()
+{
+  let mut decoder = EventStreamDecoder::new();
+  decoder.push( &[ 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0 ] );
+
+  let err = decoder.next_message().unwrap_err();
+  assert!( matches!( err.downcast_ref::< BedrockError >(), Some( BedrockError::EventStream( _ ) ) ), "got {err}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Errors raised after the stream started arrive as exception frames and
+/// must surface as `BedrockError::Exception` with type and message.
+#[ test ]
+fn check_exception_maps_exception_frame()
+{
+  let message = EventStreamMessage::new( br#"{"message":"Too many requests"}"#.to_vec() )
+    .with_header( ":message-type", EventHeaderValue::String( "exception".to_owned() ) )
+    .with_header( ":exception-type", EventHeaderValue::String( "throttlingException".to_owned() ) );
+
+  let err = message.check_exception().unwrap_err();
+  assert_eq!(
+    err.downcast_ref::< BedrockError >(),
+    Some( &BedrockError::Exception
+    {
+      exception_type : "throttlingException".to_owned(),
+      message : "Too many requests".to_owned(),
+    } ),
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Ordinary event frames must not be treated as errors.
+#[ test ]
+fn check_exception_passes_event_frame()
+{
+  assert!( event( "messageStart", r#"{"role":"assistant"}"# ).check_exception().is_ok() );
+}
@@ -0,0 +1,60 @@
+//! Tests that credentials never appear in full in `Debug` output.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | credentials_debug_masks_secrets | Secret key and session token show only their prefix |
+//! | environment_debug_masks_secrets | `BedrockEnvironmentImpl` inherits the masking |
+//! | client_debug_masks_secrets | `Client` inherits the masking |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_bedrock::{ BedrockEnvironmentImpl, Client, Credentials };
+
+const SECRET_ACCESS_KEY : &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+const SESSION_TOKEN : &str = "FwoGZXIvYXdzEBYaDredactiontesttoken0123456789";
+
+fn credentials() -> Credentials
+{
+  Credentials::new( "AKIDEXAMPLE", SECRET_ACCESS_KEY ).unwrap().with_session_token( SESSION_TOKEN )
+}
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    for secret in [ SECRET_ACCESS_KEY, SESSION_TOKEN ]
+    {
+      assert!( !output.contains( &secret[ 4.. ] ), "secret in Debug output : {output}" );
+    }
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn credentials_debug_masks_secrets()
+{
+  let credentials = credentials();
+  assert_hidden( &credentials );
+  let debug = format!( "{credentials:?}" );
+  assert!( debug.contains( "\"wJal****\"" ) );
+  assert!( debug.contains( "\"FwoG****\"" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn environment_debug_masks_secrets()
+{
+  assert_hidden( &BedrockEnvironmentImpl::new( "us-east-1", credentials() ).unwrap() );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn client_debug_masks_secrets()
+{
+  assert_hidden( &Client::build( BedrockEnvironmentImpl::new( "us-east-1", credentials() ).unwrap() ).unwrap() );
+}
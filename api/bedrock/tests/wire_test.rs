@@ -0,0 +1,350 @@
+//! Tests for the Converse, `ConverseStream`, Anthropic, and Llama wire types.
+//!
+//! Pins the JSON shape the service expects and accepts, and the conversions
+//! to and from the provider-neutral types of `api_common`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | converse_request_serialises_camel_case | Converse | Request body field names and omitted `None`s |
+//! | tool_choice_serialises_as_tagged_object | Converse | `ToolChoice` → `{"auto":{}}` / `{"tool":{"name":…}}` |
+//! | converse_response_parses_and_concatenates_text | Converse | Response body decodes; `text()` joins text blocks |
+//! | converse_response_parses_tool_use | Converse | `toolUse` blocks decode with their input |
+//! | stream_event_decodes_from_frame | Stream | Frame `:event-type` selects the event variant (`streaming`) |
+//! | stream_event_keeps_unknown_kind | Stream | Unmodelled kinds become `Other` (`streaming`) |
+//! | stream_event_into_neutral_events | Stream | `IntoStreamEvents` mapping (`streaming` + `common`) |
+//! | converse_request_from_neutral_splits_system | Common | System messages go to `system` (`common`) |
+//! | anthropic_request_from_neutral_sets_version_and_system | Anthropic | Version constant and joined system (`common`) |
+//! | anthropic_response_into_neutral | Anthropic | Text blocks become the assistant message (`common`) |
+//! | llama_request_from_neutral_uses_chat_template | Llama | Llama 3 template with open assistant header (`common`) |
+//! | llama_response_parses | Llama | Response body decodes |
+//! | usage_into_token_usage | Common | Cache reads and writes join the neutral input count (`common`) |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_bedrock::
+{
+  ConverseRequest,
+  ConverseResponse,
+  InferenceConfiguration,
+  LlamaResponse,
+  Message,
+  Tool,
+  ToolChoice,
+  ToolConfiguration,
+};
+use serde_json::json;
+
+// ------------------------------------------------------------------ //
+
+/// The request must use Bedrock's camelCase names and leave out unset fields.
+#[ test ]
+fn converse_request_serialises_camel_case()
+{
+  let request = ConverseRequest::new( vec![ Message::user( "Hi" ) ] )
+    .with_system( "Be brief." )
+    .with_inference_config( InferenceConfiguration { max_tokens : Some( 64 ), ..Default::default() } );
+
+  assert_eq!(
+    serde_json::to_value( &request ).unwrap(),
+    json!
+    ({
+      "messages" : [ { "role" : "user", "content" : [ { "text" : "Hi" } ] } ],
+      "system" : [ { "text" : "Be brief." } ],
+      "inferenceConfig" : { "maxTokens" : 64 },
+    }),
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `ToolChoice` is a single-member object naming the choice.
+#[ test ]
+fn tool_choice_serialises_as_tagged_object()
+{
+  let config = ToolConfiguration
+  {
+    tools : vec![ Tool::new( "get_weather", "Current weather", json!( { "type" : "object" } ) ) ],
+    tool_choice : Some( ToolChoice::Auto {} ),
+  };
+  assert_eq!(
+    serde_json::to_value( &config ).unwrap(),
+    json!
+    ({
+      "tools" : [ { "toolSpec" :
+      {
+        "name" : "get_weather",
+        "description" : "Current weather",
+        "inputSchema" : { "json" : { "type" : "object" } },
+      } } ],
+      "toolChoice" : { "auto" : {} },
+    }),
+  );
+  assert_eq!(
+    serde_json::to_value( ToolChoice::Tool { name : "get_weather".to_owned() } ).unwrap(),
+    json!( { "tool" : { "name" : "get_weather" } } ),
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A recorded-shape response must decode and `text()` must join its text blocks.
+#[ test ]
+fn converse_response_parses_and_concatenates_text()
+{
+  let response : ConverseResponse = serde_json::from_value( json!
+  ({
+    "output" : { "message" : { "role" : "assistant", "content" : [ { "text" : "Hello" }, { "text" : ", world" } ] } },
+    "stopReason" : "end_turn",
+    "usage" : { "inputTokens" : 5, "outputTokens" : 3, "totalTokens" : 8 },
+    "metrics" : { "latencyMs" : 412 },
+  }) ).unwrap();
+
+  assert_eq!( response.text(), "Hello, world" );
+  assert_eq!( response.stop_reason, "end_turn" );
+  assert_eq!( response.usage.total_tokens, 8 );
+  assert_eq!( response.metrics.map( | m | m.latency_ms ), Some( 412 ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Tool calls arrive as `toolUse` blocks with a JSON `input`.
+#[ test ]
+fn converse_response_parses_tool_use()
+{
+  let response : ConverseResponse = serde_json::from_value( json!
+  ({
+    "output" : { "message" : { "role" : "assistant", "content" :
+    [
+      { "toolUse" : { "toolUseId" : "tooluse_1", "name" : "get_weather", "input" : { "city" : "Paris" } } },
+    ] } },
+    "stopReason" : "tool_use",
+    "usage" : { "inputTokens" : 5, "outputTokens" : 3, "totalTokens" : 8 },
+  }) ).unwrap();
+
+  let message = response.output.message.expect( "message must be present" );
+  let tool = message.content[ 0 ].tool_use.as_ref().expect( "block must be a tool call" );
+  assert_eq!( tool.tool_use_id, "tooluse_1" );
+  assert_eq!( tool.name, "get_weather" );
+  assert_eq!( tool.input, json!( { "city" : "Paris" } ) );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ cfg( feature = "streaming" ) ]
+fn frame( event_type : &str, payload : &serde_json::Value ) -> api_bedrock::EventStreamMessage
+{
+  use api_bedrock::{ EventHeaderValue, EventStreamMessage };
+  EventStreamMessage::new( serde_json::to_vec( payload ).unwrap() )
+    .with_header( ":message-type", EventHeaderValue::String( "event".to_owned() ) )
+    .with_header( ":event-type", EventHeaderValue::String( event_type.to_owned() ) )
+}
+
+/// The `:event-type` header selects the payload type.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn stream_event_decodes_from_frame()
+{
+  use api_bedrock::ConverseStreamEvent;
+
+  let event = ConverseStreamEvent::from_message
+  (
+    &frame( "contentBlockDelta", &json!( { "delta" : { "text" : "Hel" }, "contentBlockIndex" : 0 } ) )
+  ).unwrap();
+
+  let ConverseStreamEvent::ContentBlockDelta( delta ) = event else { panic!( "expected a delta, got {event:?}" ) };
+  assert_eq!( delta.delta.text.as_deref(), Some( "Hel" ) );
+  assert_eq!( delta.content_block_index, 0 );
+}
+
+/// Event kinds added to the service later must not break the stream.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn stream_event_keeps_unknown_kind()
+{
+  use api_bedrock::ConverseStreamEvent;
+
+  let event = ConverseStreamEvent::from_message( &frame( "newKind", &json!( { "a" : 1 } ) ) ).unwrap();
+  assert_eq!( event, ConverseStreamEvent::Other { event_type : "newKind".to_owned(), payload : json!( { "a" : 1 } ) } );
+}
+
+/// Tool starts, deltas, stop, and metadata map to the neutral stream events.
+#[ cfg( all( feature = "streaming", feature = "common" ) ) ]
+#[ test ]
+fn stream_event_into_neutral_events()
+{
+  use api_bedrock::ConverseStreamEvent;
+  use api_common::{ IntoStreamEvents, StreamEvent, ToolCallDelta, UsageUpdate };
+
+  let events = | kind : &str, payload : serde_json::Value |
+    ConverseStreamEvent::from_message( &frame( kind, &payload ) ).unwrap().into_stream_events().unwrap();
+
+  assert_eq!(
+    events( "contentBlockStart", json!( { "start" : { "toolUse" : { "toolUseId" : "t1", "name" : "f" } }, "contentBlockIndex" : 1 } ) ),
+    vec![ StreamEvent::ToolCallDelta( ToolCallDelta { index : 1, id : Some( "t1".to_owned() ), name : Some( "f".to_owned() ), arguments : String::new() } ) ],
+  );
+  assert_eq!(
+    events( "contentBlockDelta", json!( { "delta" : { "toolUse" : { "input" : "{\"a\":" } }, "contentBlockIndex" : 1 } ) ),
+    vec![ StreamEvent::ToolCallDelta( ToolCallDelta { index : 1, id : None, name : None, arguments : "{\"a\":".to_owned() } ) ],
+  );
+  assert_eq!(
+    events( "contentBlockDelta", json!( { "delta" : { "text" : "Hi" }, "contentBlockIndex" : 0 } ) ),
+    vec![ StreamEvent::TextDelta( "Hi".to_owned() ) ],
+  );
+  assert_eq!(
+    events( "messageStop", json!( { "stopReason" : "end_turn" } ) ),
+    vec![ StreamEvent::Done { finish_reason : Some( "end_turn".to_owned() ) } ],
+  );
+  assert_eq!(
+    events( "metadata", json!( { "usage" : { "inputTokens" : 5, "outputTokens" : 3, "totalTokens" : 8 } } ) ),
+    vec![ StreamEvent::UsageUpdate( UsageUpdate { input_tokens : Some( 5 ), output_tokens : Some( 3 ) } ) ],
+  );
+  assert!( events( "messageStart", json!( { "role" : "assistant" } ) ).is_empty() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// System messages must leave the message list; Converse takes them apart.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn converse_request_from_neutral_splits_system()
+{
+  use api_common::NeutralMessage;
+
+  let request = ConverseRequest::from_neutral( vec!
+  [
+    NeutralMessage::system( "Be brief." ),
+    NeutralMessage::user( "Hi" ),
+    NeutralMessage::assistant( "Hello" ),
+  ] );
+
+  assert_eq!(
+    serde_json::to_value( &request ).unwrap(),
+    json!
+    ({
+      "messages" :
+      [
+        { "role" : "user", "content" : [ { "text" : "Hi" } ] },
+        { "role" : "assistant", "content" : [ { "text" : "Hello" } ] },
+      ],
+      "system" : [ { "text" : "Be brief." } ],
+    }),
+  );
+}
+
+/// The Anthropic body must carry Bedrock's version string and a single system prompt.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn anthropic_request_from_neutral_sets_version_and_system()
+{
+  use api_bedrock::AnthropicRequest;
+  use api_common::NeutralMessage;
+
+  let request = AnthropicRequest::from_neutral( 256, vec!
+  [
+    NeutralMessage::system( "One." ),
+    NeutralMessage::system( "Two." ),
+    NeutralMessage::user( "Hi" ),
+  ] );
+
+  assert_eq!(
+    serde_json::to_value( &request ).unwrap(),
+    json!
+    ({
+      "anthropic_version" : "bedrock-2023-05-31",
+      "max_tokens" : 256,
+      "messages" : [ { "role" : "user", "content" : "Hi" } ],
+      "system" : "One.\n\nTwo.",
+    }),
+  );
+}
+
+/// Only text blocks contribute to the neutral assistant message.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn anthropic_response_into_neutral()
+{
+  use api_bedrock::AnthropicResponse;
+  use api_common::NeutralMessage;
+
+  let response : AnthropicResponse = serde_json::from_value( json!
+  ({
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" : [ { "type" : "text", "text" : "Hello" }, { "type" : "tool_use", "id" : "t", "name" : "f", "input" : {} } ],
+    "stop_reason" : "end_turn",
+    "usage" : { "input_tokens" : 4, "output_tokens" : 1 },
+  }) ).unwrap();
+
+  assert_eq!( NeutralMessage::from( response ), NeutralMessage::assistant( "Hello" ) );
+}
+
+/// Llama takes one prompt in its chat template, ending where the model answers.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn llama_request_from_neutral_uses_chat_template()
+{
+  use api_bedrock::LlamaRequest;
+  use api_common::NeutralMessage;
+
+  let request = LlamaRequest::from_neutral( vec![ NeutralMessage::system( "Be brief." ), NeutralMessage::user( "Hi" ) ] );
+
+  assert_eq!(
+    request.prompt,
+    "<|begin_of_text|>\
+     <|start_header_id|>system<|end_header_id|>\n\nBe brief.<|eot_id|>\
+     <|start_header_id|>user<|end_header_id|>\n\nHi<|eot_id|>\
+     <|start_header_id|>assistant<|end_header_id|>\n\n",
+  );
+  assert_eq!( serde_json::to_value( &request ).unwrap(), json!( { "prompt" : request.prompt } ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The Llama response body must decode with its token counts.
+#[ test ]
+fn llama_response_parses()
+{
+  let response : LlamaResponse = serde_json::from_value( json!
+  ({
+    "generation" : "Hello",
+    "prompt_token_count" : 12,
+    "generation_token_count" : 2,
+    "stop_reason" : "stop",
+  }) ).unwrap();
+
+  assert_eq!( response.generation, "Hello" );
+  assert_eq!( response.prompt_token_count, Some( 12 ) );
+  assert_eq!( response.stop_reason.as_deref(), Some( "stop" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Bedrock counts cache reads and writes apart from `inputTokens`; the
+/// neutral input count must include both and report the reads as cached.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn usage_into_token_usage()
+{
+  use api_bedrock::{ AnthropicUsage, TokenUsage };
+
+  let usage : TokenUsage = serde_json::from_value( json!
+  ({
+    "inputTokens" : 10,
+    "outputTokens" : 4,
+    "totalTokens" : 44,
+    "cacheReadInputTokens" : 20,
+    "cacheWriteInputTokens" : 10,
+  }) ).unwrap();
+  let neutral = api_common::TokenUsage::from( usage );
+
+  assert_eq!( neutral, api_common::TokenUsage::new( 40, 4 ).with_cached_input_tokens( 20 ) );
+  assert_eq!( neutral.total_tokens(), 44 );
+  assert_eq!
+  (
+    api_common::TokenUsage::from( AnthropicUsage { input_tokens : 7, output_tokens : 3 } ),
+    api_common::TokenUsage::new( 7, 3 ),
+  );
+}
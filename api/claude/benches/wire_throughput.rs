@@ -0,0 +1,136 @@
+//! Wire type throughput : request serialisation, large response
+//! deserialisation, and SSE event parsing.
+//!
+//! Payloads are generated at several sizes so a change in per-byte cost
+//! shows up separately from fixed per-call cost. The stream benchmark runs
+//! the crate's own `parse_sse_events` over a complete event stream. Results
+//! are reported in bytes per second.
+
+#![ allow( missing_docs ) ]
+
+use api_claude::{ parse_sse_events, CreateMessageRequest, CreateMessageResponse };
+use criterion::{ criterion_group, criterion_main, BenchmarkId, Criterion, Throughput };
+use serde_json::json;
+use core::hint::black_box;
+
+/// Conversation turns per request; each turn is one user and one assistant message.
+const TURNS : [ usize; 3 ] = [ 1, 16, 256 ];
+/// Approximate reply text length in bytes.
+const REPLY_BYTES : [ usize; 3 ] = [ 256, 16 * 1024, 512 * 1024 ];
+/// Text deltas per streamed reply.
+const CHUNKS : [ usize; 3 ] = [ 16, 256, 4096 ];
+
+fn request( turns : usize ) -> CreateMessageRequest
+{
+  let text = | text : String | json!( [ { "type" : "text", "text" : text } ] );
+  let messages : Vec< _ > = ( 0..turns ).flat_map( | turn |
+  [
+    json!( { "role" : "user", "content" : text( format!( "Question {turn} : what changed in the quarterly report?" ) ) } ),
+    json!( { "role" : "assistant", "content" : text( "Revenue grew 4%, costs were flat, and the outlook was raised.".repeat( 4 ) ) } ),
+  ] ).collect();
+  serde_json::from_value( json!(
+  {
+    "model" : "claude-sonnet-4-5",
+    "max_tokens" : 1024,
+    "messages" : messages,
+    "temperature" : 0.2,
+  } ) ).expect( "benchmark request must deserialise" )
+}
+
+fn response_body( reply_bytes : usize ) -> String
+{
+  let sentence = "The quick brown fox jumps over the lazy dog. ";
+  json!(
+  {
+    "id" : "msg_bench",
+    "type" : "message",
+    "role" : "assistant",
+    "content" : [ { "type" : "text", "text" : sentence.repeat( reply_bytes / sentence.len() + 1 ) } ],
+    "model" : "claude-sonnet-4-5",
+    "stop_reason" : "end_turn",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 120, "output_tokens" : reply_bytes / 4 },
+  } ).to_string()
+}
+
+/// A complete event stream : `message_start`, one text block of `chunks`
+/// deltas, and `message_stop`.
+fn stream_body( chunks : usize ) -> String
+{
+  let event = | name : &str, data : serde_json::Value | format!( "event: {name}\ndata: {data}\n\n" );
+  let start = event( "message_start", json!(
+  {
+    "id" : "msg_bench",
+    "type" : "message",
+    "role" : "assistant",
+    "content" : [],
+    "model" : "claude-sonnet-4-5",
+    "stop_reason" : null,
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 120, "output_tokens" : 1 },
+  } ) );
+  let block_start = event( "content_block_start", json!( { "index" : 0, "content_block" : { "type" : "text", "text" : "" } } ) );
+  let deltas = ( 0..chunks ).map( | index |
+  {
+    event( "content_block_delta", json!( { "index" : 0, "delta" : { "type" : "text_delta", "text" : format!( "token{index} " ) } } ) )
+  });
+  [ start, block_start ].into_iter()
+  .chain( deltas )
+  .chain( [ event( "content_block_stop", json!( { "index" : 0 } ) ), event( "message_stop", json!( {} ) ) ] )
+  .collect()
+}
+
+fn benchmark_request_serialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "claude/serialize_request" );
+  for turns in TURNS
+  {
+    let request = request( turns );
+    group.throughput( Throughput::Bytes( serde_json::to_vec( &request ).unwrap().len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( turns ), &request, | b, request |
+    {
+      b.iter( || serde_json::to_vec( black_box( request ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_response_deserialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "claude/deserialize_response" );
+  for reply_bytes in REPLY_BYTES
+  {
+    let body = response_body( reply_bytes );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( reply_bytes ), &body, | b, body |
+    {
+      b.iter( || serde_json::from_str::< CreateMessageResponse >( black_box( body ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_stream_parsing( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "claude/parse_stream" );
+  for chunks in CHUNKS
+  {
+    let body = stream_body( chunks );
+    assert_eq!( parse_sse_events( &body ).unwrap().len(), chunks + 4, "every event must parse" );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( chunks ), &body, | b, body |
+    {
+      b.iter( || parse_sse_events( black_box( body ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+criterion_group!
+(
+  benches,
+  benchmark_request_serialization,
+  benchmark_response_deserialization,
+  benchmark_stream_parsing
+);
+criterion_main!( benches );
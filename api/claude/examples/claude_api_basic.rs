@@ -46,6 +46,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
         content : vec![ Content::Text {
           r#type : "text".to_string(),
           text : "Hello! Can you explain what artificial intelligence is in simple terms?".to_string(),
+          cache_control : None,
         } ],
         cache_control : None,
       }
@@ -118,6 +118,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       content : vec![ Content::Text {
         r#type : "text".to_string(),
         text : user_message,
+        cache_control : None,
       } ],
       cache_control : None,
     });
@@ -179,6 +180,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
               content : vec![ Content::Text {
                 r#type : "text".to_string(),
                 text : full_response,
+                cache_control : None,
               } ],
               cache_control : None,
             });
@@ -233,6 +235,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
                   content : vec![ Content::Text {
                     r#type : "text".to_string(),
                     text : text.clone(),
+                    cache_control : None,
                   } ],
                   cache_control : None,
                 });
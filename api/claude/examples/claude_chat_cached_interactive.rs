@@ -258,6 +258,7 @@ The cache lasts for 5 minutes and significantly reduces input token costs.";
       } ] ),
       temperature : None,
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     };
@@ -53,6 +53,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
             max_tokens : 500,
             messages : conversation_history.clone(),
             stream : Some(false), // Note : Real streaming implementation would require additional setup
+            thinking : None,
             temperature : Some(0.8),
             system : Some( vec![ api_claude::SystemContent::text( "You are Claude, a helpful AI assistant. Be conversational, engaging, and concise. Show personality while being helpful." ) ] ),
             tools : None,
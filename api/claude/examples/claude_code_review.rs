@@ -43,6 +43,7 @@ pub fn calculate_average(numbers : &[i32]) -> f64
         system : Some( vec![ api_claude::SystemContent::text( "You are a senior Rust developer and code reviewer. Analyze code for bugs, performance issues, idiomatic patterns, memory safety, and suggest specific improvements with examples." ) ] ),
         temperature : Some(0.2), // Lower temperature for focused analysis
         stream : None,
+        thinking : None,
         tools : None,
         tool_choice : None,
     };
@@ -24,6 +24,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
         ],
         temperature : Some(0.7),
         stream : None,
+        thinking : None,
         tools : None,
         tool_choice : None,
         system : Some( vec![ api_claude::SystemContent::text( "You are a technical writer specializing in systems programming and AI. Write in a clear, engaging style suitable for developers." ) ] ),
@@ -29,7 +29,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
                 }
             },
             "required": ["expression"]
-        })
+        }),
+        cache_control : None,
     };
 
     let text_analyzer_tool = ToolDefinition
@@ -51,7 +52,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
                 }
             },
             "required": ["text", "analysis_type"]
-        })
+        }),
+        cache_control : None,
     };
     
     let weather_tool = ToolDefinition {
@@ -72,7 +74,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
                 }
             },
             "required": ["location"]
-        })
+        }),
+        cache_control : None,
     };
 
     let request = CreateMessageRequest {
@@ -81,9 +84,10 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
         messages : vec![
             Message::user("I need help with several tasks : 1) Calculate 15% of 2,847 for a tip calculation, 2) Analyze the sentiment of this review : 'I absolutely love using Rust for systems programming! The memory safety guarantees make me feel confident, and the performance is outstanding.', and 3) Get the weather for San Francisco".to_string())
         ],
-        tools : Some(vec![calculator_tool, text_analyzer_tool, weather_tool]),
+        tools : Some(vec![calculator_tool.into(), text_analyzer_tool.into(), weather_tool.into()]),
         tool_choice : Some(ToolChoice::Auto),
         stream : None,
+        thinking : None,
         system : Some( vec![ api_claude::SystemContent::text( "You are a helpful assistant that can use tools to help users. Always explain what you're doing and provide clear results." ) ] ),
         temperature : Some(0.7),
     };
@@ -57,6 +57,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       content : vec![ Content::Text {
         r#type : "text".to_string(),
         text : turn1_message.to_string(),
+        cache_control : None,
       } ],
       cache_control : None,
     }
@@ -86,6 +87,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     content : vec![ Content::Text {
       r#type : "text".to_string(),
       text : ai_response1.clone(),
+      cache_control : None,
     } ],
     cache_control : None,
   });
@@ -102,6 +104,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     content : vec![ Content::Text {
       r#type : "text".to_string(),
       text : turn2_message.to_string(),
+      cache_control : None,
     } ],
     cache_control : None,
   });
@@ -130,6 +133,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     content : vec![ Content::Text {
       r#type : "text".to_string(),
       text : ai_response2.clone(),
+      cache_control : None,
     } ],
     cache_control : None,
   });
@@ -146,6 +150,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     content : vec![ Content::Text {
       r#type : "text".to_string(),
       text : turn3_message.to_string(),
+      cache_control : None,
     } ],
     cache_control : None,
   });
@@ -44,6 +44,7 @@ async fn vision_example() -> Result< (), Box< dyn core::error::Error > >
         ],
         temperature : Some(0.3),
         stream : None,
+        thinking : None,
         tools : None,
         tool_choice : None,
         system : Some( vec![ api_claude::SystemContent::text( "You are an expert visual analyst and UI/UX specialist. Provide detailed, technical descriptions of images and their potential applications." ) ] ),
@@ -83,6 +84,7 @@ async fn vision_example() -> Result< (), Box< dyn core::error::Error > >
         ],
         temperature : Some(0.2),
         stream : None,
+        thinking : None,
         tools : None,
         tool_choice : None,
         system : Some( vec![ api_claude::SystemContent::text( "You are a technical image processing expert. Focus on technical aspects and practical applications." ) ] ),
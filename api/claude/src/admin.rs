@@ -0,0 +1,786 @@
+//! Admin API
+//!
+//! Manage the organization behind an Admin API key: its members, workspaces,
+//! workspace members, and API keys. Admin endpoints only accept an admin key
+//! (`sk-ant-admin01-...`), so build a separate client for them, e.g. from
+//! `Secret::load_from_env( "ANTHROPIC_ADMIN_KEY" )`. API keys cannot be
+//! created through the API; rotation tooling lists keys and deactivates or
+//! archives the old ones.
+
+mod private
+{
+  use core::future::Future;
+  use serde::{ Serialize, Deserialize };
+  use serde::de::DeserializeOwned;
+  use serde_json::{ json, Value };
+  use crate::
+  {
+    error::{ AnthropicError, AnthropicResult },
+    client::{ Client, build_headers, handle_response },
+  };
+
+  /// Page size `list_all` requests
+  const ALL_PAGE_SIZE : u32 = 100;
+
+  /// Page of an admin listing
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct AdminListResponse< T >
+  {
+    /// Items on this page
+    pub data : Vec< T >,
+    /// Whether there are more items
+    pub has_more : bool,
+    /// First item ID on this page
+    pub first_id : Option< String >,
+    /// Last item ID on this page
+    pub last_id : Option< String >,
+  }
+
+  /// Role of a user in the organization
+  #[ derive( Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum OrganizationRole
+  {
+    /// Can use the Console and the API
+    User,
+    /// Can also manage API keys
+    Developer,
+    /// Can also manage billing
+    Billing,
+    /// Can manage the whole organization
+    Admin,
+    /// Can use Claude Code only
+    ClaudeCodeUser,
+    /// Role this client does not know
+    #[ serde( other ) ]
+    Unknown,
+  }
+
+  /// Member of the organization
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct OrganizationUser
+  {
+    /// User ID (e.g. "`user_01WCz1FkmYMm4gnmykNKUu3Q`")
+    pub id : String,
+    /// Object type - always "user"
+    #[ serde( default ) ]
+    pub r#type : String,
+    /// Email address
+    pub email : String,
+    /// Name
+    pub name : String,
+    /// Role in the organization
+    pub role : OrganizationRole,
+    /// Time the user joined (RFC 3339 datetime)
+    pub added_at : String,
+  }
+
+  /// Confirmation of a removed user
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct UserDeleted
+  {
+    /// ID of the removed user
+    pub id : String,
+    /// Object type - "`user_deleted`"
+    #[ serde( default ) ]
+    pub r#type : String,
+  }
+
+  /// Workspace of the organization
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct Workspace
+  {
+    /// Workspace ID (e.g. "`wrkspc_01JwQvzr7rXLA5AGx3HKfFUJ`")
+    pub id : String,
+    /// Object type - always "workspace"
+    #[ serde( default ) ]
+    pub r#type : String,
+    /// Name
+    pub name : String,
+    /// Creation time (RFC 3339 datetime)
+    pub created_at : String,
+    /// Archival time, if archived
+    #[ serde( default ) ]
+    pub archived_at : Option< String >,
+    /// Hex color shown in the Console
+    #[ serde( default ) ]
+    pub display_color : Option< String >,
+  }
+
+  impl Workspace
+  {
+    /// Check if the workspace is archived
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_archived( &self ) -> bool
+    {
+      self.archived_at.is_some()
+    }
+  }
+
+  /// Role of a user in a workspace
+  #[ derive( Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum WorkspaceRole
+  {
+    /// Can use the workspace
+    WorkspaceUser,
+    /// Can also manage the workspace's API keys
+    WorkspaceDeveloper,
+    /// Can manage the workspace
+    WorkspaceAdmin,
+    /// Can manage the workspace's billing
+    WorkspaceBilling,
+    /// Role this client does not know
+    #[ serde( other ) ]
+    Unknown,
+  }
+
+  /// Membership of a user in a workspace
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct WorkspaceMember
+  {
+    /// Object type - always "`workspace_member`"
+    #[ serde( default ) ]
+    pub r#type : String,
+    /// ID of the user
+    pub user_id : String,
+    /// ID of the workspace
+    pub workspace_id : String,
+    /// Role of the user in the workspace
+    pub workspace_role : WorkspaceRole,
+  }
+
+  /// Confirmation of a removed workspace member
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct WorkspaceMemberDeleted
+  {
+    /// ID of the removed user
+    pub user_id : String,
+    /// ID of the workspace
+    pub workspace_id : String,
+    /// Object type - "`workspace_member_deleted`"
+    #[ serde( default ) ]
+    pub r#type : String,
+  }
+
+  /// Status of an API key
+  #[ derive( Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum ApiKeyStatus
+  {
+    /// The key authenticates requests
+    Active,
+    /// The key is rejected but can be reactivated
+    Inactive,
+    /// The key is rejected for good
+    Archived,
+    /// Status this client does not know
+    #[ serde( other ) ]
+    Unknown,
+  }
+
+  impl ApiKeyStatus
+  {
+    fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        ApiKeyStatus::Active => "active",
+        ApiKeyStatus::Inactive => "inactive",
+        ApiKeyStatus::Archived => "archived",
+        ApiKeyStatus::Unknown => "unknown",
+      }
+    }
+  }
+
+  /// Creator of an API key
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ApiKeyCreator
+  {
+    /// ID of the creator
+    pub id : String,
+    /// Kind of creator, e.g. "user"
+    pub r#type : String,
+  }
+
+  /// API key of the organization; the key itself is never returned
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ApiKey
+  {
+    /// API key ID (e.g. "`apikey_01Rj2N8SVvo6BePZj99NhmiT`")
+    pub id : String,
+    /// Object type - always "`api_key`"
+    #[ serde( default ) ]
+    pub r#type : String,
+    /// Name
+    pub name : String,
+    /// Workspace of the key; `None` for the default workspace
+    #[ serde( default ) ]
+    pub workspace_id : Option< String >,
+    /// Creation time (RFC 3339 datetime)
+    pub created_at : String,
+    /// Who created the key
+    pub created_by : ApiKeyCreator,
+    /// Redacted hint of the key (e.g. "`sk-ant-api03-R2D...igAA`")
+    pub partial_key_hint : Option< String >,
+    /// Status
+    pub status : ApiKeyStatus,
+  }
+
+  /// Filters for listing API keys
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::{ ApiKeyFilter, ApiKeyStatus };
+  ///
+  /// let filter = ApiKeyFilter::new()
+  ///   .workspace( "wrkspc_01JwQvzr7rXLA5AGx3HKfFUJ" )
+  ///   .status( ApiKeyStatus::Active );
+  ///
+  /// assert_eq!( filter.query(), [ "workspace_id=wrkspc_01JwQvzr7rXLA5AGx3HKfFUJ", "status=active" ] );
+  /// ```
+  #[ derive( Debug, Clone, Default, PartialEq ) ]
+  pub struct ApiKeyFilter
+  {
+    /// Only keys of this workspace
+    pub workspace_id : Option< String >,
+    /// Only keys with this status
+    pub status : Option< ApiKeyStatus >,
+    /// Only keys created by this user
+    pub created_by_user_id : Option< String >,
+  }
+
+  impl ApiKeyFilter
+  {
+    /// Create a filter matching every key
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Only keys of `workspace_id`
+    #[ inline ]
+    #[ must_use ]
+    pub fn workspace< S : Into< String > >( mut self, workspace_id : S ) -> Self
+    {
+      self.workspace_id = Some( workspace_id.into() );
+      self
+    }
+
+    /// Only keys with `status`
+    #[ inline ]
+    #[ must_use ]
+    pub fn status( mut self, status : ApiKeyStatus ) -> Self
+    {
+      self.status = Some( status );
+      self
+    }
+
+    /// Only keys created by `user_id`
+    #[ inline ]
+    #[ must_use ]
+    pub fn created_by< S : Into< String > >( mut self, user_id : S ) -> Self
+    {
+      self.created_by_user_id = Some( user_id.into() );
+      self
+    }
+
+    /// Query parameters of the filter
+    #[ inline ]
+    #[ must_use ]
+    pub fn query( &self ) -> Vec< String >
+    {
+      let mut query_params = vec![];
+      if let Some( id ) = &self.workspace_id
+      {
+        query_params.push( format!( "workspace_id={id}" ) );
+      }
+      if let Some( status ) = self.status
+      {
+        query_params.push( format!( "status={}", status.as_str() ) );
+      }
+      if let Some( id ) = &self.created_by_user_id
+      {
+        query_params.push( format!( "created_by_user_id={id}" ) );
+      }
+      query_params
+    }
+  }
+
+  fn page_query( mut query_params : Vec< String >, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> String
+  {
+    if let Some( id ) = before_id
+    {
+      query_params.push( format!( "before_id={id}" ) );
+    }
+    if let Some( id ) = after_id
+    {
+      query_params.push( format!( "after_id={id}" ) );
+    }
+    if let Some( lim ) = limit
+    {
+      query_params.push( format!( "limit={lim}" ) );
+    }
+
+    if query_params.is_empty() { String::new() } else { format!( "?{}", query_params.join( "&" ) ) }
+  }
+
+  fn require_id( name : &str, id : &str ) -> AnthropicResult< () >
+  {
+    if id.is_empty()
+    {
+      return Err( AnthropicError::InvalidArgument( format!( "{name} cannot be empty" ) ) );
+    }
+    Ok( () )
+  }
+
+  async fn send< T : DeserializeOwned >( client : &Client, method : reqwest::Method, path : &str, body : Option< Value > ) -> AnthropicResult< T >
+  {
+    let url = format!( "{}/v1/organizations/{}", client.base_url(), path );
+    let mut request = client.http()
+      .request( method, &url )
+      .headers( build_headers( client.secret(), client.config() ) );
+    if let Some( body ) = body
+    {
+      request = request.json( &body );
+    }
+
+    let response = request
+      .send()
+      .await
+      .map_err( AnthropicError::from )?;
+
+    handle_response::< T >( response ).await
+  }
+
+  async fn all_pages< T, F, Fut >( mut fetch : F ) -> AnthropicResult< Vec< T > >
+  where
+    F : FnMut( Option< String > ) -> Fut,
+    Fut : Future< Output = AnthropicResult< AdminListResponse< T > > >,
+  {
+    let mut items = Vec::new();
+    let mut after_id = None;
+    loop
+    {
+      let page = fetch( after_id.take() ).await?;
+      items.extend( page.data );
+      match page.last_id
+      {
+        Some( last_id ) if page.has_more => after_id = Some( last_id ),
+        _ => return Ok( items ),
+      }
+    }
+  }
+
+  impl Client
+  {
+    /// Access the Admin API
+    ///
+    /// The client must be built with an Admin API key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::{ ApiKeyFilter, ApiKeyStatus, Client, Secret };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let admin = Client::new( Secret::load_from_env( "ANTHROPIC_ADMIN_KEY" )? );
+    /// let active = admin.admin().api_keys().list_all( &ApiKeyFilter::new().status( ApiKeyStatus::Active ) ).await?;
+    /// for key in active.iter().filter( | key | key.created_at.as_str() < "2026-01-01" )
+    /// {
+    ///   admin.admin().api_keys().set_status( &key.id, ApiKeyStatus::Inactive ).await?;
+    /// }
+    /// # Ok( () )
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn admin( &self ) -> AdminApi< '_ >
+    {
+      AdminApi { client : self }
+    }
+  }
+
+  /// Operations on the `/v1/organizations` endpoints
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct AdminApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl< 'a > AdminApi< 'a >
+  {
+    /// Members of the organization
+    #[ inline ]
+    #[ must_use ]
+    pub fn users( &self ) -> UsersApi< 'a >
+    {
+      UsersApi { client : self.client }
+    }
+
+    /// Workspaces of the organization
+    #[ inline ]
+    #[ must_use ]
+    pub fn workspaces( &self ) -> WorkspacesApi< 'a >
+    {
+      WorkspacesApi { client : self.client }
+    }
+
+    /// Members of the workspace `workspace_id`
+    #[ inline ]
+    #[ must_use ]
+    pub fn workspace_members< S : Into< String > >( &self, workspace_id : S ) -> WorkspaceMembersApi< 'a >
+    {
+      WorkspaceMembersApi { client : self.client, workspace_id : workspace_id.into() }
+    }
+
+    /// API keys of the organization
+    #[ inline ]
+    #[ must_use ]
+    pub fn api_keys( &self ) -> ApiKeysApi< 'a >
+    {
+      ApiKeysApi { client : self.client }
+    }
+  }
+
+  /// Operations on `/v1/organizations/users`
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct UsersApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl UsersApi< '_ >
+  {
+    /// List one page of users
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    #[ inline ]
+    pub async fn list( &self, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< AdminListResponse< OrganizationUser > >
+    {
+      send( self.client, reqwest::Method::GET, &format!( "users{}", page_query( vec![], before_id, after_id, limit ) ), None ).await
+    }
+
+    /// List every user, following the pages
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails
+    #[ inline ]
+    pub async fn list_all( &self ) -> AnthropicResult< Vec< OrganizationUser > >
+    {
+      all_pages( | after_id | async move { self.list( None, after_id.as_deref(), Some( ALL_PAGE_SIZE ) ).await } ).await
+    }
+
+    /// Get a user
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn get( &self, user_id : &str ) -> AnthropicResult< OrganizationUser >
+    {
+      require_id( "user_id", user_id )?;
+      send( self.client, reqwest::Method::GET, &format!( "users/{user_id}" ), None ).await
+    }
+
+    /// Change the organization role of a user
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn update_role( &self, user_id : &str, role : OrganizationRole ) -> AnthropicResult< OrganizationUser >
+    {
+      require_id( "user_id", user_id )?;
+      send( self.client, reqwest::Method::POST, &format!( "users/{user_id}" ), Some( json!( { "role" : role } ) ) ).await
+    }
+
+    /// Remove a user from the organization
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn remove( &self, user_id : &str ) -> AnthropicResult< UserDeleted >
+    {
+      require_id( "user_id", user_id )?;
+      send( self.client, reqwest::Method::DELETE, &format!( "users/{user_id}" ), None ).await
+    }
+  }
+
+  /// Operations on `/v1/organizations/workspaces`
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct WorkspacesApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl WorkspacesApi< '_ >
+  {
+    /// List one page of workspaces, optionally including archived ones
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    #[ inline ]
+    pub async fn list( &self, include_archived : bool, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< AdminListResponse< Workspace > >
+    {
+      let filter = if include_archived { vec![ "include_archived=true".to_string() ] } else { vec![] };
+      send( self.client, reqwest::Method::GET, &format!( "workspaces{}", page_query( filter, before_id, after_id, limit ) ), None ).await
+    }
+
+    /// List every workspace, following the pages
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails
+    #[ inline ]
+    pub async fn list_all( &self, include_archived : bool ) -> AnthropicResult< Vec< Workspace > >
+    {
+      all_pages( | after_id | async move { self.list( include_archived, None, after_id.as_deref(), Some( ALL_PAGE_SIZE ) ).await } ).await
+    }
+
+    /// Get a workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn get( &self, workspace_id : &str ) -> AnthropicResult< Workspace >
+    {
+      require_id( "workspace_id", workspace_id )?;
+      send( self.client, reqwest::Method::GET, &format!( "workspaces/{workspace_id}" ), None ).await
+    }
+
+    /// Create a workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty or the request fails
+    #[ inline ]
+    pub async fn create( &self, name : &str ) -> AnthropicResult< Workspace >
+    {
+      require_id( "name", name )?;
+      send( self.client, reqwest::Method::POST, "workspaces", Some( json!( { "name" : name } ) ) ).await
+    }
+
+    /// Rename a workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID or name is empty or the request fails
+    #[ inline ]
+    pub async fn rename( &self, workspace_id : &str, name : &str ) -> AnthropicResult< Workspace >
+    {
+      require_id( "workspace_id", workspace_id )?;
+      require_id( "name", name )?;
+      send( self.client, reqwest::Method::POST, &format!( "workspaces/{workspace_id}" ), Some( json!( { "name" : name } ) ) ).await
+    }
+
+    /// Archive a workspace; its API keys stop working
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn archive( &self, workspace_id : &str ) -> AnthropicResult< Workspace >
+    {
+      require_id( "workspace_id", workspace_id )?;
+      send( self.client, reqwest::Method::POST, &format!( "workspaces/{workspace_id}/archive" ), None ).await
+    }
+  }
+
+  /// Operations on `/v1/organizations/workspaces/{id}/members`
+  #[ derive( Debug, Clone ) ]
+  pub struct WorkspaceMembersApi< 'a >
+  {
+    client : &'a Client,
+    workspace_id : String,
+  }
+
+  impl WorkspaceMembersApi< '_ >
+  {
+    fn path( &self, user_id : Option< &str > ) -> AnthropicResult< String >
+    {
+      require_id( "workspace_id", &self.workspace_id )?;
+      match user_id
+      {
+        Some( user_id ) =>
+        {
+          require_id( "user_id", user_id )?;
+          Ok( format!( "workspaces/{}/members/{user_id}", self.workspace_id ) )
+        },
+        None => Ok( format!( "workspaces/{}/members", self.workspace_id ) ),
+      }
+    }
+
+    /// List one page of workspace members
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workspace ID is empty or the request fails
+    #[ inline ]
+    pub async fn list( &self, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< AdminListResponse< WorkspaceMember > >
+    {
+      let path = self.path( None )?;
+      send( self.client, reqwest::Method::GET, &format!( "{path}{}", page_query( vec![], before_id, after_id, limit ) ), None ).await
+    }
+
+    /// List every workspace member, following the pages
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the workspace ID is empty or any page request fails
+    #[ inline ]
+    pub async fn list_all( &self ) -> AnthropicResult< Vec< WorkspaceMember > >
+    {
+      all_pages( | after_id | async move { self.list( None, after_id.as_deref(), Some( ALL_PAGE_SIZE ) ).await } ).await
+    }
+
+    /// Get the membership of a user
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ID is empty or the request fails
+    #[ inline ]
+    pub async fn get( &self, user_id : &str ) -> AnthropicResult< WorkspaceMember >
+    {
+      send( self.client, reqwest::Method::GET, &self.path( Some( user_id ) )?, None ).await
+    }
+
+    /// Add an organization user to the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ID is empty or the request fails
+    #[ inline ]
+    pub async fn add( &self, user_id : &str, role : WorkspaceRole ) -> AnthropicResult< WorkspaceMember >
+    {
+      require_id( "user_id", user_id )?;
+      send( self.client, reqwest::Method::POST, &self.path( None )?, Some( json!( { "user_id" : user_id, "workspace_role" : role } ) ) ).await
+    }
+
+    /// Change the workspace role of a member
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ID is empty or the request fails
+    #[ inline ]
+    pub async fn update_role( &self, user_id : &str, role : WorkspaceRole ) -> AnthropicResult< WorkspaceMember >
+    {
+      send( self.client, reqwest::Method::POST, &self.path( Some( user_id ) )?, Some( json!( { "workspace_role" : role } ) ) ).await
+    }
+
+    /// Remove a member from the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an ID is empty or the request fails
+    #[ inline ]
+    pub async fn remove( &self, user_id : &str ) -> AnthropicResult< WorkspaceMemberDeleted >
+    {
+      send( self.client, reqwest::Method::DELETE, &self.path( Some( user_id ) )?, None ).await
+    }
+  }
+
+  /// Operations on `/v1/organizations/api_keys`
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct ApiKeysApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl ApiKeysApi< '_ >
+  {
+    /// List one page of API keys matching `filter`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    #[ inline ]
+    pub async fn list( &self, filter : &ApiKeyFilter, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< AdminListResponse< ApiKey > >
+    {
+      send( self.client, reqwest::Method::GET, &format!( "api_keys{}", page_query( filter.query(), before_id, after_id, limit ) ), None ).await
+    }
+
+    /// List every API key matching `filter`, following the pages
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails
+    #[ inline ]
+    pub async fn list_all( &self, filter : &ApiKeyFilter ) -> AnthropicResult< Vec< ApiKey > >
+    {
+      all_pages( | after_id | async move { self.list( filter, None, after_id.as_deref(), Some( ALL_PAGE_SIZE ) ).await } ).await
+    }
+
+    /// Get an API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn get( &self, api_key_id : &str ) -> AnthropicResult< ApiKey >
+    {
+      require_id( "api_key_id", api_key_id )?;
+      send( self.client, reqwest::Method::GET, &format!( "api_keys/{api_key_id}" ), None ).await
+    }
+
+    /// Rename an API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID or name is empty or the request fails
+    #[ inline ]
+    pub async fn rename( &self, api_key_id : &str, name : &str ) -> AnthropicResult< ApiKey >
+    {
+      require_id( "api_key_id", api_key_id )?;
+      require_id( "name", name )?;
+      send( self.client, reqwest::Method::POST, &format!( "api_keys/{api_key_id}" ), Some( json!( { "name" : name } ) ) ).await
+    }
+
+    /// Activate, deactivate, or archive an API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty, the status is `Unknown`, or the request fails
+    #[ inline ]
+    pub async fn set_status( &self, api_key_id : &str, status : ApiKeyStatus ) -> AnthropicResult< ApiKey >
+    {
+      require_id( "api_key_id", api_key_id )?;
+      if status == ApiKeyStatus::Unknown
+      {
+        return Err( AnthropicError::InvalidArgument( "Cannot set an API key to an unknown status".to_string() ) );
+      }
+      send( self.client, reqwest::Method::POST, &format!( "api_keys/{api_key_id}" ), Some( json!( { "status" : status } ) ) ).await
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use AdminListResponse;
+  exposed use OrganizationRole;
+  exposed use OrganizationUser;
+  exposed use UserDeleted;
+  exposed use Workspace;
+  exposed use WorkspaceRole;
+  exposed use WorkspaceMember;
+  exposed use WorkspaceMemberDeleted;
+  exposed use ApiKeyStatus;
+  exposed use ApiKeyCreator;
+  exposed use ApiKey;
+  exposed use ApiKeyFilter;
+  exposed use AdminApi;
+  exposed use UsersApi;
+  exposed use WorkspacesApi;
+  exposed use WorkspaceMembersApi;
+  exposed use ApiKeysApi;
+}
@@ -29,4 +29,5 @@ crate::mod_interface!
   layer types;
   layer implementation;
   layer explicit_retry;
+  layer messages_api;
 }
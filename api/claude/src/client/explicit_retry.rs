@@ -551,7 +551,7 @@ mod private
             // Check if we should retry
             if attempt < max_attempts && should_retry( last_error.as_ref().unwrap(), attempt )
             {
-              tokio::time::sleep( delay ).await;
+              api_common::sleep( delay ).await;
             }
             else
             {
@@ -65,9 +65,7 @@ mod private
     #[ must_use ]
     pub fn with_config( secret : Secret, config : ClientConfig ) -> Self
     {
-      let http_client = reqwest::Client::builder()
-        .timeout( config.request_timeout )
-        .user_agent( &config.user_agent )
+      let http_client = http_builder( &config )
         .build()
         .expect( "Failed to build HTTP client" );
 
@@ -119,6 +117,51 @@ mod private
       self
     }
 
+    /// Rebuild the HTTP client with connection tuning applied
+    ///
+    /// The tuning is applied on top of the configured timeout and user agent.
+    /// Open connections of the previous client are dropped; call
+    /// [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnthropicError::InvalidArgument` if `tuning` names a proxy that
+    /// is not a valid URL or asks for HTTP/2 prior knowledge without the
+    /// `http2` feature, or `AnthropicError::Http`
+    /// if the client cannot be built
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> AnthropicResult< Self >
+    {
+      self.http = tuning.apply_to( http_builder( &self.config ) ).map_err( | e | AnthropicError::InvalidArgument( e.to_string() ) )?
+        .build()
+        .map_err( AnthropicError::from )?;
+      Ok( self )
+    }
+
+    /// Open a connection to the base URL ahead of the first request
+    ///
+    /// Sends one `HEAD` request without the API key, so the first real
+    /// request does not pay for DNS, TCP, and the TLS handshake, and returns
+    /// its round-trip time. Any HTTP status counts as success; only a failure
+    /// to connect is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AnthropicError::Http` if no connection could be opened
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn warm_up( &self ) -> AnthropicResult< core::time::Duration >
+    {
+      let started = std::time::Instant::now();
+      self.http
+        .head( &self.config.base_url )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+      Ok( started.elapsed() )
+    }
+
     /// Get API key
     #[ inline ]
     #[ must_use ]
@@ -150,7 +193,7 @@ mod private
     // Use explicit health monitoring methods instead
 
     /// Get HTTP client for direct API calls
-    #[ cfg( feature = "model-management" ) ]
+    #[ cfg( any( feature = "model-management", feature = "admin" ) ) ]
     pub fn http( &self ) -> &reqwest::Client
     {
       &self.http
@@ -177,7 +220,8 @@ mod private
     ///       role : Role::User,
     ///       content : vec![ Content::Text {
     ///         r#type : "text".to_string(),
-    ///         text : "Hello, Claude!".to_string()
+    ///         text : "Hello, Claude!".to_string(),
+    ///         cache_control : None,
     ///       } ],
     ///       cache_control : None,
     ///     }
@@ -213,17 +257,7 @@ mod private
 
       // Automatic circuit breaker checks removed per governing principle - use explicit health monitoring methods
 
-      let url = format!( "{}/v1/messages", self.config.base_url );
-
-      let headers = build_headers( &self.secret, &self.config );
-
-      let response = self.http
-        .post( &url )
-        .headers( headers )
-        .json( &request )
-        .send()
-        .await
-        .map_err( AnthropicError::from )?;
+      let response = self.send_message( &request ).await?;
 
       let result = handle_response::< CreateMessageResponse >( response ).await;
 
@@ -232,6 +266,20 @@ mod private
       result
     }
 
+    /// Post a request to `/v1/messages` and return the raw response, whatever its status
+    pub( crate ) async fn send_message( &self, request : &CreateMessageRequest ) -> AnthropicResult< reqwest::Response >
+    {
+      let url = format!( "{}/v1/messages", self.config.base_url );
+
+      self.http
+        .post( &url )
+        .headers( build_headers( &self.secret, &self.config ) )
+        .json( request )
+        .send()
+        .await
+        .map_err( AnthropicError::from )
+    }
+
     /// Count tokens in a message without sending it
     ///
     /// This method allows pre-calculating token usage for cost estimation without making actual API calls.
@@ -427,6 +475,153 @@ mod private
       handle_response::< crate::BatchResponse >( response ).await
     }
 
+    /// Headers for Files API requests: the usual headers plus the Files API beta
+    #[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+    fn files_headers( &self ) -> reqwest::header::HeaderMap
+    {
+      let config = self.config.clone().with_beta( crate::FILES_API_BETA );
+      build_headers( &self.secret, &config )
+    }
+
+    /// Upload a file to the Files API
+    ///
+    /// The file is sent as `multipart/form-data`; reference the returned ID
+    /// from a message with [`crate::DocumentSource::file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the filename or content is empty, the MIME type is
+    /// invalid, or the request fails
+    #[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+    #[ inline ]
+    pub async fn upload_file( &self, filename : &str, mime_type : &str, bytes : Vec< u8 > ) -> AnthropicResult< crate::FileMetadata >
+    {
+      if filename.is_empty()
+      {
+        return Err( AnthropicError::InvalidArgument( "filename cannot be empty".to_string() ) );
+      }
+      if bytes.is_empty()
+      {
+        return Err( AnthropicError::InvalidArgument( "file content cannot be empty".to_string() ) );
+      }
+
+      let part = reqwest::multipart::Part::bytes( bytes )
+        .file_name( filename.to_string() )
+        .mime_str( mime_type )
+        .map_err( | e | AnthropicError::InvalidArgument( format!( "Invalid MIME type '{mime_type}': {e}" ) ) )?;
+      let form = reqwest::multipart::Form::new().part( "file", part );
+
+      let url = format!( "{}/v1/files", self.config.base_url );
+      let mut headers = self.files_headers();
+      // The multipart body sets its own content type with the boundary
+      headers.remove( reqwest::header::CONTENT_TYPE );
+
+      let response = self.http
+        .post( &url )
+        .headers( headers )
+        .multipart( form )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< crate::FileMetadata >( response ).await
+    }
+
+    /// List uploaded files with optional pagination
+    ///
+    /// Use `before_id`/`after_id` for cursor-based pagination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    #[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+    #[ inline ]
+    pub async fn list_files( &self, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< crate::FileListResponse >
+    {
+      let mut url = format!( "{}/v1/files", self.config.base_url );
+      let mut query_params = vec![];
+
+      if let Some( id ) = before_id
+      {
+        query_params.push( format!( "before_id={id}" ) );
+      }
+      if let Some( id ) = after_id
+      {
+        query_params.push( format!( "after_id={id}" ) );
+      }
+      if let Some( lim ) = limit
+      {
+        query_params.push( format!( "limit={lim}" ) );
+      }
+
+      if !query_params.is_empty()
+      {
+        url.push( '?' );
+        url.push_str( &query_params.join( "&" ) );
+      }
+
+      let response = self.http
+        .get( &url )
+        .headers( self.files_headers() )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< crate::FileListResponse >( response ).await
+    }
+
+    /// Retrieve the metadata of an uploaded file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file ID is empty or the request fails
+    #[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+    #[ inline ]
+    pub async fn retrieve_file( &self, file_id : &str ) -> AnthropicResult< crate::FileMetadata >
+    {
+      if file_id.is_empty()
+      {
+        return Err( AnthropicError::InvalidArgument( "file_id cannot be empty".to_string() ) );
+      }
+
+      let url = format!( "{}/v1/files/{}", self.config.base_url, file_id );
+
+      let response = self.http
+        .get( &url )
+        .headers( self.files_headers() )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< crate::FileMetadata >( response ).await
+    }
+
+    /// Delete an uploaded file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file ID is empty or the request fails
+    #[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+    #[ inline ]
+    pub async fn delete_file( &self, file_id : &str ) -> AnthropicResult< crate::FileDeleted >
+    {
+      if file_id.is_empty()
+      {
+        return Err( AnthropicError::InvalidArgument( "file_id cannot be empty".to_string() ) );
+      }
+
+      let url = format!( "{}/v1/files/{}", self.config.base_url, file_id );
+
+      let response = self.http
+        .delete( &url )
+        .headers( self.files_headers() )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< crate::FileDeleted >( response ).await
+    }
+
     /// Create a message with context for error tracking
     ///
     /// # Errors
@@ -490,6 +685,16 @@ mod private
     }
 
   }
+
+  /// HTTP client settings derived from the configuration
+  fn http_builder( config : &ClientConfig ) -> reqwest::ClientBuilder
+  {
+    let builder = reqwest::Client::builder();
+    // The fetch-backed wasm32 client has no timeout knob; the host enforces it.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    let builder = builder.timeout( config.request_timeout );
+    builder.user_agent( &config.user_agent )
+  }
 }
 
 crate::mod_interface!
@@ -0,0 +1,211 @@
+//! Messages API accessor
+//!
+//! `MessagesApi` groups the `/v1/messages` operations under `client.messages()`.
+
+#[ allow( clippy::missing_inline_in_public_items ) ]
+mod private
+{
+  use super::super::implementation::orphan::Client;
+  use super::super::types::orphan::{ CreateMessageRequest, CreateMessageResponse };
+  #[ cfg( feature = "error-handling" ) ]
+  use crate::error::AnthropicResult;
+
+  #[ cfg( not( feature = "error-handling" ) ) ]
+  type AnthropicResult< T > = Result< T, crate::error_tools::Error >;
+
+  impl Client
+  {
+    /// Access the Messages API
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::{ Client, CreateMessageRequest, Message, StreamEvent, StreamDelta };
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::from_workspace()?;
+    /// let request = CreateMessageRequest::builder()
+    ///   .model( "claude-sonnet-4-6" )
+    ///   .max_tokens( 200 )
+    ///   .message( Message::user( "Count to five." ) )
+    ///   .build();
+    ///
+    /// let mut stream = client.messages().create_stream( request ).await?;
+    /// while let Some( event ) = stream.next().await
+    /// {
+    ///   if let StreamEvent::ContentBlockDelta { delta : StreamDelta::TextDelta { text, .. }, .. } = event?
+    ///   {
+    ///     print!( "{text}" );
+    ///   }
+    /// }
+    /// # Ok( () )
+    /// # }
+    /// ```
+    #[ must_use ]
+    pub fn messages( &self ) -> MessagesApi< '_ >
+    {
+      MessagesApi { client : self }
+    }
+  }
+
+  /// Operations on the `/v1/messages` endpoint
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct MessagesApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl MessagesApi< '_ >
+  {
+    /// Create a message and wait for the whole response
+    ///
+    /// Same as [`Client::create_message`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is invalid
+    pub async fn create( &self, request : CreateMessageRequest ) -> AnthropicResult< CreateMessageResponse >
+    {
+      self.client.create_message( request ).await
+    }
+
+    /// Create a message and receive it as Server-Sent Events
+    ///
+    /// The request is sent with `stream : true`. Events arrive as the API
+    /// sends them: `message_start`, then `content_block_start`, deltas, and
+    /// `content_block_stop` for each block, then `message_delta` with the stop
+    /// reason and usage, and `message_stop`; `ping` may appear anywhere. Feed
+    /// them to a [`crate::MessageAccumulator`] to get the final response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request is invalid or the API rejects it;
+    /// errors while reading arrive as items of the stream
+    #[ cfg( feature = "streaming" ) ]
+    pub async fn create_stream( &self, request : CreateMessageRequest ) -> AnthropicResult< crate::EventStream >
+    {
+      self.client.create_message_stream( request ).await
+    }
+
+    /// Create a message, retrying 429, 529, and other 5xx responses
+    ///
+    /// Makes at most `config.max_attempts()` attempts. Before each retry it
+    /// waits as long as the failed response's `retry-after` header asks; if
+    /// that is absent, until the exhausted requests or tokens limit resets;
+    /// otherwise it backs off exponentially. It stops early, returning the
+    /// last error, when the server asks for a longer wait than
+    /// `config.max_delay_ms()`. Other failures are returned at once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::{ Client, CreateMessageRequest, Message, RetryConfig };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::from_workspace()?;
+    /// let request = CreateMessageRequest::builder()
+    ///   .model( "claude-sonnet-4-6" )
+    ///   .max_tokens( 200 )
+    ///   .message( Message::user( "Hello" ) )
+    ///   .build();
+    ///
+    /// let config = RetryConfig::new().with_max_attempts( 4 ).with_max_delay_ms( 30_000 );
+    /// let response = client.messages().create_with_retries( request, &config ).await?;
+    /// # Ok( () )
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` is invalid, the request cannot be sent,
+    /// or the last attempt fails
+    #[ cfg( feature = "retry" ) ]
+    pub async fn create_with_retries( &self, request : CreateMessageRequest, config : &crate::RetryConfig ) -> AnthropicResult< CreateMessageResponse >
+    {
+      self.create_with_retries_within( request, config, &api_common::CallBudget::new().start() ).await
+    }
+
+    /// [`create_with_retries`](Self::create_with_retries) inside an overall
+    /// call budget
+    ///
+    /// Every attempt counts against `budget` and is cut off at its total
+    /// deadline. When the wait a response asks for would pass the deadline,
+    /// the call returns that response's error instead of sleeping.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::{ Client, CreateMessageRequest, Message, RetryConfig };
+    /// use api_common::CallBudget;
+    /// use core::time::Duration;
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::from_workspace()?;
+    /// let request = CreateMessageRequest::builder()
+    ///   .model( "claude-sonnet-4-6" )
+    ///   .max_tokens( 200 )
+    ///   .message( Message::user( "Hello" ) )
+    ///   .build();
+    ///
+    /// let budget = CallBudget::new().with_total_deadline( Duration::from_secs( 20 ) ).start();
+    /// let config = RetryConfig::new().with_max_attempts( 4 );
+    /// let response = client.messages().create_with_retries_within( request, &config, &budget ).await?;
+    /// # Ok( () )
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` is invalid, the request cannot be sent,
+    /// the last attempt fails, or the budget is exhausted
+    #[ cfg( feature = "retry" ) ]
+    pub async fn create_with_retries_within( &self, request : CreateMessageRequest, config : &crate::RetryConfig, budget : &api_common::BudgetTracker ) -> AnthropicResult< CreateMessageResponse >
+    {
+      config.validate()?;
+
+      let executor = crate::RetryExecutor::new( crate::RetryStrategy::exponential_backoff_with_config( config.clone() ) );
+      let attempt = || async
+      {
+        let response = self.client.send_message( &request ).await?;
+        let pacing = crate::RetryPacing::from_headers( response.status().as_u16(), response.headers() );
+        crate::client::handle_response( response ).await.map_err( | error | PacedFailure { error, pacing : Some( pacing ) } )
+      };
+
+      executor.execute_paced_within( budget, attempt, | failure, attempt | failure.pacing.as_ref()?.delay( config, attempt ) ).await
+        .map_err( | failure | failure.error )
+    }
+  }
+
+  /// A failed attempt of `create_with_retries` with the pacing its response
+  /// asked for; failures without a response are not retried
+  #[ cfg( feature = "retry" ) ]
+  struct PacedFailure
+  {
+    error : crate::error::AnthropicError,
+    pacing : Option< crate::RetryPacing >,
+  }
+
+  #[ cfg( feature = "retry" ) ]
+  impl From< crate::error::AnthropicError > for PacedFailure
+  {
+    fn from( error : crate::error::AnthropicError ) -> Self
+    {
+      Self { error, pacing : None }
+    }
+  }
+
+  #[ cfg( feature = "retry" ) ]
+  impl From< api_common::BudgetExhausted > for PacedFailure
+  {
+    fn from( error : api_common::BudgetExhausted ) -> Self
+    {
+      crate::error::AnthropicError::from( error ).into()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use MessagesApi;
+}
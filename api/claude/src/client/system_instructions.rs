@@ -8,16 +8,46 @@ mod private
 {
   use serde::{ Serialize, Deserialize };
 
+  /// Most cache breakpoints a request may set, across tools, system, and messages
+  pub const MAX_CACHE_BREAKPOINTS : usize = 4;
+
+  /// Lifetime of a cache entry, refreshed each time the entry is read
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+  pub enum CacheTtl
+  {
+    /// Five minutes, the default
+    #[ serde( rename = "5m" ) ]
+    FiveMinutes,
+    /// One hour; writing the entry costs more than a 5-minute write
+    #[ serde( rename = "1h" ) ]
+    OneHour,
+  }
+
   /// Cache control configuration for prompt caching
   ///
   /// Anthropic Prompt Caching allows caching of large context (system prompts, documents, etc.)
-  /// to reduce costs (~90% savings on cached tokens) and improve latency.
+  /// to reduce costs (~90% savings on cached tokens) and improve latency. The breakpoint caches
+  /// the request prefix up to and including the block it is set on.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::{ CacheControl, CacheTtl };
+  ///
+  /// let hour = CacheControl::ephemeral_1h();
+  /// assert_eq!( hour.ttl, Some( CacheTtl::OneHour ) );
+  /// assert_eq!( serde_json::to_value( &hour ).unwrap(), serde_json::json!( { "type" : "ephemeral", "ttl" : "1h" } ) );
+  /// assert_eq!( serde_json::to_value( CacheControl::ephemeral() ).unwrap(), serde_json::json!( { "type" : "ephemeral" } ) );
+  /// ```
   #[ derive( Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
   pub struct CacheControl
   {
-    /// Cache type - currently only "ephemeral" is supported (5-minute TTL)
+    /// Cache type - currently only "ephemeral" is supported
     #[ serde( rename = "type" ) ]
     pub cache_type : String,
+    /// Lifetime of the entry; the API uses 5 minutes when unset
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub ttl : Option< CacheTtl >,
   }
 
   impl CacheControl
@@ -25,7 +55,21 @@ mod private
     /// Create an ephemeral cache control (5-minute TTL)
     pub fn ephemeral() -> Self
     {
-      Self { cache_type : "ephemeral".to_string() }
+      Self { cache_type : "ephemeral".to_string(), ttl : None }
+    }
+
+    /// Create an ephemeral cache control with a 1-hour TTL
+    pub fn ephemeral_1h() -> Self
+    {
+      Self::ephemeral().with_ttl( CacheTtl::OneHour )
+    }
+
+    /// Set the lifetime of the entry
+    #[ must_use ]
+    pub fn with_ttl( mut self, ttl : CacheTtl ) -> Self
+    {
+      self.ttl = Some( ttl );
+      self
     }
   }
 
@@ -361,6 +405,8 @@ mod private
 
 crate::mod_interface!
 {
+  exposed use MAX_CACHE_BREAKPOINTS;
+  exposed use CacheTtl;
   exposed use CacheControl;
   exposed use SystemPrompt;
   exposed use SystemContent;
@@ -14,11 +14,11 @@ mod private
   #[ cfg( not( feature = "error-handling" ) ) ]
   type AnthropicError = error_tools::Error;
   
-  use super::super::system_instructions::orphan::{ CacheControl, SystemContent };
+  use super::super::system_instructions::orphan::{ CacheControl, SystemContent, MAX_CACHE_BREAKPOINTS };
   use crate::{ secret::Secret, messages::Message };
 
   #[ cfg( feature = "tools" ) ]
-  use crate::messages::{ ToolDefinition, ToolChoice };
+  use crate::messages::{ Tool, ToolChoice };
   use serde::{ Serialize, Deserialize };
   use std::time::Duration;
   
@@ -38,6 +38,8 @@ mod private
   pub const MIN_TEMPERATURE : f32 = 0.0;
   /// Maximum allowed temperature value
   pub const MAX_TEMPERATURE : f32 = 1.0;
+  /// Minimum `budget_tokens` for extended thinking
+  pub const MIN_THINKING_BUDGET_TOKENS : u32 = 1024;
 
   /// Configuration for Anthropic API client
   #[ derive( Debug, Clone ) ]
@@ -51,6 +53,8 @@ mod private
     pub request_timeout : Duration,
     /// User agent string
     pub user_agent : String,
+    /// Beta features sent in the `anthropic-beta` header
+    pub beta_features : Vec< String >,
   }
 
   // No Default implementation - explicit configuration required
@@ -84,6 +88,7 @@ mod private
         api_version : ANTHROPIC_API_VERSION.to_string(),
         request_timeout : Duration::from_secs( 60 ), // Recommended for most use cases
         user_agent : ANTHROPIC_USER_AGENT.to_string(),
+        beta_features : Vec::new(),
       }
     }
 
@@ -101,6 +106,7 @@ mod private
         api_version,
         request_timeout,
         user_agent,
+        beta_features : Vec::new(),
       }
     }
 
@@ -119,6 +125,18 @@ mod private
       self.request_timeout = timeout;
       self
     }
+
+    /// Opt in to a beta feature, e.g. [`crate::FILES_API_BETA`]
+    #[ must_use ]
+    pub fn with_beta< S : Into< String > >( mut self, feature : S ) -> Self
+    {
+      let feature = feature.into();
+      if !self.beta_features.contains( &feature )
+      {
+        self.beta_features.push( feature );
+      }
+      self
+    }
   }
 
   /// Builder for client configuration requiring explicit values
@@ -129,6 +147,7 @@ mod private
     api_version : Option< String >,
     request_timeout : Option< Duration >,
     user_agent : Option< String >,
+    beta_features : Vec< String >,
   }
 
   impl Default for ClientConfigBuilder 
@@ -150,6 +169,7 @@ mod private
         api_version : None,
         request_timeout : None,
         user_agent : None,
+        beta_features : Vec::new(),
       }
     }
 
@@ -162,6 +182,7 @@ mod private
         api_version : Some( ANTHROPIC_API_VERSION.to_string() ),
         request_timeout : Some( Duration::from_secs( 60 ) ),
         user_agent : Some( ANTHROPIC_USER_AGENT.to_string() ),
+        beta_features : Vec::new(),
       }
     }
 
@@ -197,6 +218,14 @@ mod private
       self
     }
 
+    /// Opt in to a beta feature (optional)
+    #[ must_use ]
+    pub fn beta< S : Into< String > >( mut self, feature : S ) -> Self
+    {
+      self.beta_features.push( feature.into() );
+      self
+    }
+
     /// Build the configuration (requires all values to be explicitly set)
     ///
     /// # Errors
@@ -222,6 +251,7 @@ mod private
         api_version,
         request_timeout,
         user_agent,
+        beta_features : self.beta_features,
       })
     }
   }
@@ -318,9 +348,19 @@ mod private
     #[ cfg( feature = "tools" ) ]
     #[ inline ]
     #[ must_use ]
-    pub fn tools( mut self, tools : Vec< ToolDefinition > ) -> Self
+    pub fn tools< T : Into< Tool > >( mut self, tools : Vec< T > ) -> Self
+    {
+      self.tools = Some( tools.into_iter().map( Into::into ).collect() );
+      self
+    }
+
+    /// Add a tool, custom or Anthropic-defined
+    #[ cfg( feature = "tools" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn tool< T : Into< Tool > >( mut self, tool : T ) -> Self
     {
-      self.tools = Some( tools );
+      self.tools.get_or_insert_with( Vec::new ).push( tool.into() );
       self
     }
 
@@ -334,6 +374,17 @@ mod private
       self
     }
 
+    /// Enable extended thinking with a token budget
+    ///
+    /// The budget counts towards `max_tokens` and must be smaller than it.
+    #[ inline ]
+    #[ must_use ]
+    pub fn thinking( mut self, budget_tokens : u32 ) -> Self
+    {
+      self.thinking = Some( ThinkingConfig::enabled( budget_tokens ) );
+      self
+    }
+
     /// Build the `CreateMessageRequest` (for backward compatibility)
     ///
     /// # Panics
@@ -351,6 +402,7 @@ mod private
         system : self.system,
         temperature : self.temperature,
         stream : self.stream,
+        thinking : self.thinking,
         #[ cfg( feature = "tools" ) ]
         tools : self.tools,
         #[ cfg( feature = "tools" ) ]
@@ -378,6 +430,7 @@ mod private
         system : self.system,
         temperature : self.temperature,
         stream : self.stream,
+        thinking : self.thinking,
         #[ cfg( feature = "tools" ) ]
         tools : self.tools,
         #[ cfg( feature = "tools" ) ]
@@ -389,6 +442,51 @@ mod private
     }
   }
 
+  /// Extended thinking setting of a request
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::ThinkingConfig;
+  ///
+  /// let thinking = ThinkingConfig::enabled( 4096 );
+  /// assert_eq!( serde_json::to_value( thinking ).unwrap(), serde_json::json!( { "type" : "enabled", "budget_tokens" : 4096 } ) );
+  /// ```
+  #[ derive( Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq ) ]
+  #[ serde( tag = "type", rename_all = "snake_case" ) ]
+  pub enum ThinkingConfig
+  {
+    /// Think before answering, using up to `budget_tokens` tokens
+    Enabled
+    {
+      /// Maximum tokens spent on thinking (at least [`MIN_THINKING_BUDGET_TOKENS`])
+      budget_tokens : u32,
+    },
+    /// Answer without thinking
+    Disabled,
+  }
+
+  impl ThinkingConfig
+  {
+    /// Enable thinking with a token budget
+    #[ must_use ]
+    pub fn enabled( budget_tokens : u32 ) -> Self
+    {
+      Self::Enabled { budget_tokens }
+    }
+
+    /// The thinking budget, if thinking is enabled
+    #[ must_use ]
+    pub fn budget_tokens( &self ) -> Option< u32 >
+    {
+      match self
+      {
+        Self::Enabled { budget_tokens } => Some( *budget_tokens ),
+        Self::Disabled => None,
+      }
+    }
+  }
+
   /// Request to create a message
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub struct CreateMessageRequest
@@ -408,10 +506,13 @@ mod private
     /// Whether to stream the response
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub stream : Option< bool >,
+    /// Extended thinking configuration
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub thinking : Option< ThinkingConfig >,
     /// Tools available for the model to use
     #[ cfg( feature = "tools" ) ]
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tools : Option< Vec< ToolDefinition > >,
+    pub tools : Option< Vec< Tool > >,
     /// How the model should use tools
     #[ cfg( feature = "tools" ) ]
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
@@ -434,6 +535,38 @@ mod private
       CreateMessageRequestBuilder::default()
     }
 
+    /// Number of cache breakpoints set on the tools, system blocks, and messages
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_claude::{ CacheControl, CreateMessageRequest, Message, SystemContent };
+    ///
+    /// let request = CreateMessageRequest::builder()
+    ///   .model( "claude-sonnet-4-6" )
+    ///   .max_tokens( 500 )
+    ///   .system_blocks( vec![ SystemContent::text( "<style guide>" ).with_cache_control( CacheControl::ephemeral_1h() ) ] )
+    ///   .message( Message::user( "<contract>" ).with_cache_control( CacheControl::ephemeral() ) )
+    ///   .build();
+    ///
+    /// assert_eq!( request.cache_breakpoints(), 2 );
+    /// ```
+    #[ must_use ]
+    pub fn cache_breakpoints( &self ) -> usize
+    {
+      let system = self.system.iter().flatten().filter( | block | block.cache_control.is_some() ).count();
+      let messages = self.messages.iter()
+        .map( | message | usize::from( message.cache_control.is_some() ) + message.content.iter().filter( | block | block.cache_control().is_some() ).count() )
+        .sum::< usize >();
+      #[ cfg( feature = "tools" ) ]
+      let tools = self.tools.iter().flatten()
+        .filter( | tool | tool.as_custom().is_some_and( | custom | custom.cache_control.is_some() ) )
+        .count();
+      #[ cfg( not( feature = "tools" ) ) ]
+      let tools = 0;
+      system + messages + tools
+    }
+
     /// Validate the request parameters
     ///
     /// # Governing Principle Compliance
@@ -480,6 +613,9 @@ mod private
         }
       }
 
+      self.validate_thinking()?;
+      self.validate_cache_breakpoints()?;
+
       #[ cfg( feature = "tools" ) ]
       {
         // Validate tool-related parameters
@@ -498,7 +634,7 @@ mod private
             if let Some( tool_name ) = tool_choice.tool_name()
             {
               let tools = self.tools.as_ref().unwrap();
-              if !tools.iter().any( | tool | tool.name == tool_name )
+              if !tools.iter().any( | tool | tool.name() == tool_name )
               {
                 return Err( AnthropicError::InvalidRequest( 
                   format!( "tool_choice references unknown tool : '{tool_name}'" )
@@ -522,25 +658,33 @@ mod private
           let mut seen_names = std::collections::HashSet::new();
           for tool in tools
           {
-            if tool.name.trim().is_empty()
+            if tool.name().trim().is_empty()
             {
               return Err( AnthropicError::InvalidRequest( 
                 "tool name cannot be empty".to_string() 
               ) );
             }
             
-            if !seen_names.insert( &tool.name )
+            if !seen_names.insert( tool.name() )
             {
               return Err( AnthropicError::InvalidRequest( 
-                format!( "duplicate tool name : '{}'", tool.name )
+                format!( "duplicate tool name : '{}'", tool.name() )
               ) );
             }
             
-            if tool.description.trim().is_empty()
+            match tool
             {
-              return Err( AnthropicError::InvalidRequest( 
-                format!( "tool '{}' description cannot be empty", tool.name )
-              ) );
+              Tool::Custom( custom ) if custom.description.trim().is_empty() =>
+              {
+                return Err( AnthropicError::InvalidRequest( 
+                  format!( "tool '{}' description cannot be empty", custom.name )
+                ) );
+              },
+              Tool::Custom( _ ) => {},
+              #[ cfg( feature = "error-handling" ) ]
+              _ => tool.validate()?,
+              #[ cfg( not( feature = "error-handling" ) ) ]
+              _ => {},
             }
           }
           
@@ -556,6 +700,42 @@ mod private
 
       Ok( () )
     }
+
+    /// Check the number of cache breakpoints
+    fn validate_cache_breakpoints( &self ) -> AnthropicResult< () >
+    {
+      let breakpoints = self.cache_breakpoints();
+      if breakpoints > MAX_CACHE_BREAKPOINTS
+      {
+        return Err( AnthropicError::InvalidRequest(
+          format!( "at most {MAX_CACHE_BREAKPOINTS} cache_control breakpoints allowed per request, found {breakpoints}" )
+        ) );
+      }
+
+      Ok( () )
+    }
+
+    /// Check the thinking budget and the parameters thinking constrains
+    fn validate_thinking( &self ) -> AnthropicResult< () >
+    {
+      if let Some( budget_tokens ) = self.thinking.and_then( | thinking | thinking.budget_tokens() )
+      {
+        if budget_tokens < MIN_THINKING_BUDGET_TOKENS || budget_tokens >= self.max_tokens
+        {
+          return Err( AnthropicError::InvalidRequest(
+            format!( "thinking budget_tokens must be at least {MIN_THINKING_BUDGET_TOKENS} and less than max_tokens ({})", self.max_tokens )
+          ) );
+        }
+        if self.temperature.is_some_and( | temp | ( temp - 1.0 ).abs() > f32::EPSILON )
+        {
+          return Err( AnthropicError::InvalidRequest(
+            "temperature cannot be changed when thinking is enabled".to_string()
+          ) );
+        }
+      }
+
+      Ok( () )
+    }
   }
 
   /// Builder for `CreateMessageRequest`
@@ -568,8 +748,9 @@ mod private
     system : Option< Vec< SystemContent > >,
     temperature : Option< f32 >,
     stream : Option< bool >,
+    thinking : Option< ThinkingConfig >,
     #[ cfg( feature = "tools" ) ]
-    tools : Option< Vec< ToolDefinition > >,
+    tools : Option< Vec< Tool > >,
     #[ cfg( feature = "tools" ) ]
     tool_choice : Option< ToolChoice >,
   }
@@ -608,6 +789,40 @@ mod private
         .and_then( | content | content.text.as_deref() )
     }
 
+    /// Reasoning of all thinking blocks, joined, if the response has any
+    ///
+    /// Redacted thinking is not included.
+    pub fn thinking( &self ) -> Option< String >
+    {
+      let thinking : Vec< &str > = self.content
+        .iter()
+        .filter_map( | content | content.thinking.as_deref() )
+        .collect();
+      ( !thinking.is_empty() ).then( || thinking.concat() )
+    }
+
+    /// All document citations of the response, in order
+    pub fn citations( &self ) -> Vec< &crate::Citation >
+    {
+      self.content
+        .iter()
+        .filter_map( | content | content.citations.as_ref() )
+        .flatten()
+        .collect()
+    }
+
+    /// Text of the response with numbered markers for its citations
+    ///
+    /// See [`crate::CitedAnswer`]; print it to get the text followed by its
+    /// footnotes.
+    pub fn cited_answer( &self ) -> crate::CitedAnswer
+    {
+      crate::CitedAnswer::new( self.content
+        .iter()
+        .filter( | content | content.r#type == "text" )
+        .map( | content | ( content.text.as_deref().unwrap_or_default(), content.citations.as_deref().unwrap_or_default() ) ) )
+    }
+
     /// Check if the response was truncated due to `max_tokens`
     pub fn is_truncated( &self ) -> bool
     {
@@ -616,7 +831,7 @@ mod private
   }
 
   /// Content in response
-  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  #[ derive( Debug, Clone, Default, Serialize, Deserialize, PartialEq ) ]
   pub struct ResponseContent
   {
     /// Type of content
@@ -624,6 +839,88 @@ mod private
     /// Text content (only present for text content)
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub text : Option< String >,
+    /// Document passages this text cites (only when citations are enabled)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub citations : Option< Vec< crate::Citation > >,
+    /// The model's reasoning (only present for thinking content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub thinking : Option< String >,
+    /// Signature of the reasoning (only present for thinking content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub signature : Option< String >,
+    /// Encrypted reasoning (only present for redacted thinking content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub data : Option< String >,
+    /// Tool call ID (only present for tool use and server tool use content)
+    #[ cfg( feature = "tools" ) ]
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub id : Option< String >,
+    /// Called tool (only present for tool use and server tool use content)
+    #[ cfg( feature = "tools" ) ]
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// Tool input (only present for tool use and server tool use content)
+    #[ cfg( feature = "tools" ) ]
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub input : Option< serde_json::Value >,
+    /// Answered call (only present for server tool result content)
+    #[ cfg( feature = "tools" ) ]
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub tool_use_id : Option< String >,
+    /// Outcome of a tool Anthropic ran (only present for server tool result content)
+    #[ cfg( feature = "tools" ) ]
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub content : Option< crate::ServerToolResultContent >,
+  }
+
+  impl ResponseContent
+  {
+    /// Check if this is a `thinking` or `redacted_thinking` block
+    pub fn is_thinking( &self ) -> bool
+    {
+      self.r#type == "thinking" || self.r#type == "redacted_thinking"
+    }
+
+    /// Check if this is a call of or result from a tool Anthropic ran
+    #[ cfg( feature = "tools" ) ]
+    pub fn is_server_tool( &self ) -> bool
+    {
+      self.r#type == "server_tool_use" || self.content.is_some()
+    }
+
+    /// The block as request content, for passing it back in the next turn
+    ///
+    /// Returns `None` for block types this client does not know.
+    pub fn to_content( &self ) -> Option< crate::Content >
+    {
+      match self.r#type.as_str()
+      {
+        "text" => Some( crate::Content::new_text( self.text.clone().unwrap_or_default() ) ),
+        "thinking" => Some( crate::Content::thinking(
+          self.thinking.clone().unwrap_or_default(),
+          self.signature.clone().unwrap_or_default(),
+        ) ),
+        "redacted_thinking" => Some( crate::Content::redacted_thinking( self.data.clone().unwrap_or_default() ) ),
+        #[ cfg( feature = "tools" ) ]
+        "tool_use" | "server_tool_use" => Some( crate::Content::ToolUse
+        {
+          r#type : self.r#type.clone(),
+          id : self.id.clone().unwrap_or_default(),
+          name : self.name.clone().unwrap_or_default(),
+          input : self.input.clone().unwrap_or_else( || serde_json::json!( {} ) ),
+          cache_control : None,
+        } ),
+        #[ cfg( feature = "tools" ) ]
+        _ => self.content.clone().map( | content | crate::Content::ServerToolResult
+        {
+          r#type : self.r#type.clone(),
+          tool_use_id : self.tool_use_id.clone().unwrap_or_default(),
+          content,
+        } ),
+        #[ cfg( not( feature = "tools" ) ) ]
+        _ => None,
+      }
+    }
   }
 
   /// Usage statistics
@@ -651,6 +948,21 @@ mod private
     }
   }
 
+  /// Anthropic counts cache reads and writes apart from `input_tokens`; the
+  /// neutral input count includes both.
+  #[ cfg( feature = "common" ) ]
+  impl From< Usage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : Usage ) -> Self
+    {
+      let read = u64::from( usage.cache_read_input_tokens.unwrap_or( 0 ) );
+      let write = u64::from( usage.cache_creation_input_tokens.unwrap_or( 0 ) );
+      let input = u64::from( usage.input_tokens ) + read + write;
+      Self::new( input, usage.output_tokens.into() ).with_cached_input_tokens( read )
+    }
+  }
+
   /// Request to count tokens in a message
   ///
   /// This allows pre-calculating token usage for cost estimation without sending actual requests.
@@ -668,7 +980,7 @@ mod private
     /// Tools available for the model to use
     #[ cfg( feature = "tools" ) ]
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tools : Option< Vec< ToolDefinition > >,
+    pub tools : Option< Vec< Tool > >,
   }
 
   #[ cfg( feature = "count-tokens" ) ]
@@ -718,16 +1030,23 @@ mod private
       "application/json".parse().expect( "Valid content type" ) 
     );
     
-    headers.insert( 
-      "x-api-key", 
-      secret.ANTHROPIC_API_KEY.parse().expect( "Valid API key" ) 
-    );
+    let mut api_key : reqwest::header::HeaderValue = secret.ANTHROPIC_API_KEY.parse().expect( "Valid API key" );
+    api_key.set_sensitive( true );
+    headers.insert( "x-api-key", api_key );
     
     headers.insert( 
       "anthropic-version", 
       config.api_version.parse().expect( "Valid API version" ) 
     );
 
+    if !config.beta_features.is_empty()
+    {
+      headers.insert(
+        "anthropic-beta",
+        config.beta_features.join( "," ).parse().expect( "Valid beta features" )
+      );
+    }
+
     headers
   }
 
@@ -819,6 +1138,7 @@ crate::mod_interface!
   exposed use CreateMessageRequestBuilder;
   exposed use CreateMessageResponse;
   exposed use ResponseContent;
+  exposed use ThinkingConfig;
   exposed use Usage;
   #[ cfg( feature = "count-tokens" ) ]
   exposed use CountMessageTokensRequest;
@@ -827,6 +1147,7 @@ crate::mod_interface!
   exposed use ANTHROPIC_API_BASE_URL;
   exposed use ANTHROPIC_API_VERSION;
   exposed use RECOMMENDED_MODEL;
+  exposed use MIN_THINKING_BUDGET_TOKENS;
 
   orphan use build_headers;
   orphan use handle_response;
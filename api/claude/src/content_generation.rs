@@ -195,6 +195,7 @@ mod private
         system : self.system.as_ref().map( | s | vec![ crate::SystemContent::text( s.as_str() ) ] ),
         temperature : self.temperature,
         stream : None,
+        thinking : None,
         tools : None,
         tool_choice : None,
       }
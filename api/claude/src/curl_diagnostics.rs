@@ -37,7 +37,9 @@ mod private
     ///
     /// # Returns
     ///
-    /// A complete cURL command with authentication headers
+    /// A complete cURL command with authentication headers. The API key is
+    /// masked to its prefix (`sk-a****`); substitute the real key before
+    /// running the command.
     fn as_curl_for_request< T : AsCurl + serde::Serialize >( &self, request : &T, url : &str ) -> String;
   }
 
@@ -214,7 +216,7 @@ mod private
       // Add authentication headers
       if let Some( api_key ) = self.api_key()
       {
-        builder = builder.header( "x-api-key", &api_key.ANTHROPIC_API_KEY );
+        builder = builder.header( "x-api-key", api_secret::mask( &api_key.ANTHROPIC_API_KEY ) );
       }
 
       // Add version header
@@ -92,6 +92,7 @@ mod private
         name : self.name().to_string(),
         description : self.description().to_string(),
         input_schema : self.parameter_schema(),
+        cache_control : None,
       }
     }
   }
@@ -215,6 +216,7 @@ mod private
       name : name.into(),
       description : description.into(),
       input_schema,
+      cache_control : None,
     }
   }
 
@@ -684,6 +684,43 @@ mod private
     }
   }
 
+  #[ cfg( feature = "retry-logic" ) ]
+  impl From< api_common::BudgetExhausted > for AnthropicError
+  {
+    fn from( error : api_common::BudgetExhausted ) -> Self
+    {
+      Self::Internal( error.to_string() )
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< AnthropicError > for api_common::LlmError
+  {
+    fn from( error : AnthropicError ) -> Self
+    {
+      match error
+      {
+        AnthropicError::Http( http ) => match ( http.status_code(), Self::classify( http.status_code(), http.message() ) )
+        {
+          // No status means the request never got an answer
+          ( None, Self::Provider { raw } ) => Self::Network( raw ),
+          ( _, class ) => class,
+        },
+        AnthropicError::Api( api ) => Self::classify( None, &api.to_string() ),
+        AnthropicError::Authentication( auth ) => Self::Auth( auth.to_string() ),
+        AnthropicError::RateLimit( limit ) => Self::RateLimited
+        {
+          retry_after : limit.retry_after().map( Duration::from_secs ),
+          message : limit.to_string(),
+        },
+        AnthropicError::Stream( message ) | AnthropicError::Parsing( message ) => Self::Protocol( message ),
+        #[ cfg( feature = "circuit-breaker" ) ]
+        AnthropicError::CircuitOpen( message ) => Self::Overloaded( message ),
+        other => Self::Provider { raw : other.to_string() },
+      }
+    }
+  }
+
   // From implementation is provided by error_tools blanket impl
 
   /// Result type for Anthropic API operations
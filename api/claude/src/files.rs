@@ -0,0 +1,168 @@
+//! Files API
+//!
+//! Upload documents once and reference them from messages by ID instead of
+//! sending their bytes with every request. The Files API is in beta: its
+//! endpoints are always called with the [`FILES_API_BETA`] header, and
+//! messages referencing an uploaded file need the same header, enabled with
+//! `ClientConfig::with_beta( FILES_API_BETA )`.
+
+#[ cfg( feature = "files" ) ]
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+
+  /// Beta feature enabling the Files API
+  pub const FILES_API_BETA : &str = "files-api-2025-04-14";
+
+  /// Metadata of an uploaded file
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct FileMetadata
+  {
+    /// File ID (e.g. "`file_011CNha8iCJcU1wXNR6q4V8w`")
+    pub id : String,
+    /// Object type - always "file"
+    pub r#type : String,
+    /// Original filename
+    pub filename : String,
+    /// MIME type of the file
+    pub mime_type : String,
+    /// Size of the file in bytes
+    pub size_bytes : u64,
+    /// Upload time (RFC 3339 datetime)
+    pub created_at : String,
+    /// Whether the file can be downloaded; uploaded files cannot
+    #[ serde( default ) ]
+    pub downloadable : bool,
+  }
+
+  /// Page of uploaded files
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct FileListResponse
+  {
+    /// Files on this page
+    pub data : Vec< FileMetadata >,
+    /// Whether there are more files
+    pub has_more : bool,
+    /// First file ID on this page
+    pub first_id : Option< String >,
+    /// Last file ID on this page
+    pub last_id : Option< String >,
+  }
+
+  /// Confirmation of a deleted file
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct FileDeleted
+  {
+    /// ID of the deleted file
+    pub id : String,
+    /// Object type - "`file_deleted`"
+    #[ serde( default ) ]
+    pub r#type : String,
+  }
+
+  #[ cfg( feature = "error-handling" ) ]
+  impl crate::Client
+  {
+    /// Access the Files API
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::{ Client, CreateMessageRequest, DocumentContent, Message };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::from_workspace()?;
+    /// let pdf = std::fs::read( "report.pdf" )?;
+    /// let file = client.files().upload( "report.pdf", "application/pdf", pdf ).await?;
+    ///
+    /// let request = CreateMessageRequest::builder()
+    ///   .model( "claude-sonnet-4-6" )
+    ///   .max_tokens( 500 )
+    ///   .message( Message::user_with_document( "Summarise this report.", DocumentContent::file( &file.id ).with_citations() ) )
+    ///   .build();
+    /// # Ok( () )
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn files( &self ) -> FilesApi< '_ >
+    {
+      FilesApi { client : self }
+    }
+  }
+
+  /// Operations on the `/v1/files` endpoint
+  #[ cfg( feature = "error-handling" ) ]
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct FilesApi< 'a >
+  {
+    client : &'a crate::Client,
+  }
+
+  #[ cfg( feature = "error-handling" ) ]
+  impl FilesApi< '_ >
+  {
+    /// Upload a file
+    ///
+    /// Same as [`crate::Client::upload_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the filename or content is empty or the request fails
+    #[ inline ]
+    pub async fn upload( &self, filename : &str, mime_type : &str, bytes : Vec< u8 > ) -> crate::AnthropicResult< FileMetadata >
+    {
+      self.client.upload_file( filename, mime_type, bytes ).await
+    }
+
+    /// List uploaded files, newest first
+    ///
+    /// Same as [`crate::Client::list_files`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    #[ inline ]
+    pub async fn list( &self, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> crate::AnthropicResult< FileListResponse >
+    {
+      self.client.list_files( before_id, after_id, limit ).await
+    }
+
+    /// Get the metadata of a file
+    ///
+    /// Same as [`crate::Client::retrieve_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn get( &self, file_id : &str ) -> crate::AnthropicResult< FileMetadata >
+    {
+      self.client.retrieve_file( file_id ).await
+    }
+
+    /// Delete a file
+    ///
+    /// Same as [`crate::Client::delete_file`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    #[ inline ]
+    pub async fn delete( &self, file_id : &str ) -> crate::AnthropicResult< FileDeleted >
+    {
+      self.client.delete_file( file_id ).await
+    }
+  }
+}
+
+#[ cfg( feature = "files" ) ]
+crate::mod_interface!
+{
+  exposed use FILES_API_BETA;
+  exposed use FileMetadata;
+  exposed use FileListResponse;
+  exposed use FileDeleted;
+  #[ cfg( feature = "error-handling" ) ]
+  exposed use FilesApi;
+}
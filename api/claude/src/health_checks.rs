@@ -371,7 +371,7 @@ mod private
       {
         let endpoint = ( *endpoint ).to_string();
         let config = config.clone();
-        let handle = tokio::spawn( async move
+        let handle = api_common::spawn( async move
         {
           Self::check_endpoint( &endpoint, &config ).await
         });
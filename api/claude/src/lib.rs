@@ -57,6 +57,13 @@
 //! - Transparent method naming (e.g., `execute_with_retries()`, `execute_with_circuit_breaker()`)
 //! - Zero overhead when features disabled
 //!
+//! # WebAssembly
+//!
+//! The async client and streaming compile for `wasm32-unknown-unknown`, where
+//! `reqwest` runs on the host `fetch` API. `sync-api`, `health-checks`, and
+//! `dynamic-config` depend on a native runtime or filesystem watcher and are
+//! compiled out on `wasm32`; request timeouts are left to the host.
+//!
 //! # Examples
 //!
 //! ```no_run
@@ -76,7 +83,8 @@
 //!       role : Role::User,
 //!       content : vec![ Content::Text {
 //!         r#type : "text".to_string(),
-//!         text : "Hello, Claude! How are you?".to_string()
+//!         text : "Hello, Claude! How are you?".to_string(),
+//!         cache_control : None,
 //!       } ],
 //!       cache_control : None,
 //!     }
@@ -100,6 +108,8 @@ crate::mod_interface!
 {
   #[ cfg( feature = "authentication" ) ]
   layer authentication;
+  #[ cfg( feature = "admin" ) ]
+  layer admin;
   #[ cfg( feature = "batch-processing" ) ]
   layer batch;
   #[ cfg( feature = "circuit-breaker" ) ]
@@ -111,7 +121,7 @@ crate::mod_interface!
   layer content_generation;
   #[ cfg( feature = "curl-diagnostics" ) ]
   layer curl_diagnostics;
-  #[ cfg( feature = "dynamic-config" ) ]
+  #[ cfg( all( feature = "dynamic-config", not( target_arch = "wasm32" ) ) ) ]
   layer dynamic_config;
   layer enterprise_config;
   #[ cfg( feature = "enterprise-quota" ) ]
@@ -119,9 +129,11 @@ crate::mod_interface!
   layer environment;
   #[ cfg( feature = "failover" ) ]
   layer failover;
+  #[ cfg( feature = "files" ) ]
+  layer files;
   #[ cfg( feature = "general-diagnostics" ) ]
   layer general_diagnostics;
-  #[ cfg( feature = "health-checks" ) ]
+  #[ cfg( all( feature = "health-checks", not( target_arch = "wasm32" ) ) ) ]
   layer health_checks;
   #[ cfg( feature = "error-handling" ) ]
   layer error;
@@ -139,7 +151,7 @@ crate::mod_interface!
   layer streaming;
   #[ cfg( feature = "streaming-control" ) ]
   layer streaming_control;
-  #[ cfg( feature = "sync-api" ) ]
+  #[ cfg( all( feature = "sync-api", not( target_arch = "wasm32" ) ) ) ]
   layer sync_api;
   #[ cfg( feature = "model-comparison" ) ]
   layer model_comparison;
@@ -151,6 +163,8 @@ crate::mod_interface!
   layer input_validation;
   #[ cfg( feature = "enhanced-function-calling" ) ]
   layer enhanced_function_calling;
+  #[ cfg( feature = "tool-runner" ) ]
+  layer tool_runner;
 }
 
 /// Serde-related exports.
@@ -4,6 +4,9 @@ mod private {}
 
 crate::mod_interface!
 {
+  layer document;
+  #[ cfg( feature = "tools" ) ]
+  layer server_tools;
   layer content;
   layer tools_and_messages;
 }
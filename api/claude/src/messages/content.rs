@@ -7,6 +7,9 @@ mod private
   use serde::{ Serialize, Deserialize };
 
   use serde_json::Value;
+  use super::super::document::orphan::*;
+  #[ cfg( feature = "tools" ) ]
+  use super::super::server_tools::orphan::ServerToolResultContent;
 
   /// Message role in conversation
   ///
@@ -49,6 +52,7 @@ mod private
   /// let text_content = Content::Text {
   ///   r#type : "text".to_string(),
   ///   text : "Hello, Claude!".to_string(),
+  ///   cache_control : None,
   /// };
   ///
   /// // Content can be serialized/deserialized
@@ -66,6 +70,48 @@ mod private
       r#type : String,
       /// Text content
       text : String,
+      /// Cache breakpoint ending at this block
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      cache_control : Option< crate::CacheControl >,
+    },
+    /// Document content (PDF, plain text, uploaded file, or URL)
+    Document
+    {
+      /// Type - always "document"
+      #[ serde( deserialize_with = "document_type" ) ]
+      r#type : String,
+      /// Document source
+      source : DocumentSource,
+      /// Title shown to the model and echoed in citations
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      title : Option< String >,
+      /// Context about the document that is not cited from
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      context : Option< String >,
+      /// Citation settings
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      citations : Option< CitationsConfig >,
+      /// Cache breakpoint ending at this block
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      cache_control : Option< crate::CacheControl >,
+    },
+    /// Thinking of an earlier assistant turn, passed back unchanged
+    Thinking
+    {
+      /// Type - always "thinking"
+      r#type : String,
+      /// The model's reasoning
+      thinking : String,
+      /// Signature verifying the thinking was produced by the model
+      signature : String,
+    },
+    /// Encrypted thinking of an earlier assistant turn, passed back unchanged
+    RedactedThinking
+    {
+      /// Type - always "`redacted_thinking`"
+      r#type : String,
+      /// Encrypted thinking
+      data : String,
     },
     /// Image content (vision feature)
     #[ cfg( feature = "vision" ) ]
@@ -75,6 +121,9 @@ mod private
       r#type : String,
       /// Image source information
       source : ImageSource,
+      /// Cache breakpoint ending at this block
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      cache_control : Option< crate::CacheControl >,
     },
     /// Tool use content
     #[ cfg( feature = "tools" ) ]
@@ -88,6 +137,9 @@ mod private
       name : String,
       /// Input parameters for the tool
       input : Value,
+      /// Cache breakpoint ending at this block
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      cache_control : Option< crate::CacheControl >,
     },
     /// Tool result content
     #[ cfg( feature = "tools" ) ]
@@ -102,9 +154,40 @@ mod private
       /// Whether this result represents an error
       #[ serde( skip_serializing_if = "Option::is_none" ) ]
       is_error : Option< bool >,
+      /// Cache breakpoint ending at this block
+      #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+      cache_control : Option< crate::CacheControl >,
+    },
+    /// Result of a tool Anthropic ran, passed back in later turns
+    #[ cfg( feature = "tools" ) ]
+    ServerToolResult
+    {
+      /// Type, e.g. "`web_search_tool_result`" or "`code_execution_tool_result`"
+      r#type : String,
+      /// ID of the `server_tool_use` block this result answers
+      tool_use_id : String,
+      /// Search results, execution output, or error
+      content : ServerToolResultContent,
     },
   }
 
+  /// Accepts only `"document"`, so other blocks with a `source` do not
+  /// deserialize as `Content::Document`.
+  fn document_type< 'de, D >( deserializer : D ) -> Result< String, D::Error >
+  where
+    D : serde::Deserializer< 'de >,
+  {
+    let value = String::deserialize( deserializer )?;
+    if value == "document"
+    {
+      Ok( value )
+    }
+    else
+    {
+      Err( serde::de::Error::custom( format!( "expected type 'document', found '{value}'" ) ) )
+    }
+  }
+
   impl Content
   {
     /// Create new text content
@@ -116,6 +199,48 @@ mod private
       {
         r#type : "text".to_string(),
         text : text.into(),
+        cache_control : None,
+      }
+    }
+
+    /// Create new document content
+    #[ inline ]
+    #[ must_use ]
+    pub fn document( document : DocumentContent ) -> Self
+    {
+      Self::Document
+      {
+        r#type : document.r#type,
+        source : document.source,
+        title : document.title,
+        context : document.context,
+        citations : document.citations,
+        cache_control : None,
+      }
+    }
+
+    /// Create thinking content from a response thinking block
+    #[ inline ]
+    #[ must_use ]
+    pub fn thinking< S1 : Into< String >, S2 : Into< String > >( thinking : S1, signature : S2 ) -> Self
+    {
+      Self::Thinking
+      {
+        r#type : "thinking".to_string(),
+        thinking : thinking.into(),
+        signature : signature.into(),
+      }
+    }
+
+    /// Create redacted thinking content from a response block
+    #[ inline ]
+    #[ must_use ]
+    pub fn redacted_thinking< S : Into< String > >( data : S ) -> Self
+    {
+      Self::RedactedThinking
+      {
+        r#type : "redacted_thinking".to_string(),
+        data : data.into(),
       }
     }
 
@@ -129,6 +254,7 @@ mod private
       {
         r#type : "image".to_string(),
         source,
+        cache_control : None,
       }
     }
 
@@ -144,6 +270,7 @@ mod private
         id : id.into(),
         name : name.into(),
         input,
+        cache_control : None,
       }
     }
 
@@ -159,6 +286,7 @@ mod private
         tool_use_id : tool_use_id.into(),
         content : content.into(),
         is_error : None,
+        cache_control : None,
       }
     }
 
@@ -174,6 +302,66 @@ mod private
         tool_use_id : tool_use_id.into(),
         content : content.into(),
         is_error : Some( is_error ),
+        cache_control : None,
+      }
+    }
+
+    /// Set a cache breakpoint ending at this block
+    ///
+    /// Thinking blocks and server tool results cannot carry a breakpoint and
+    /// are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_claude::{ CacheControl, Content };
+    ///
+    /// let manual = Content::new_text( "<300 pages of manual>" ).with_cache_control( CacheControl::ephemeral_1h() );
+    /// assert!( manual.cache_control().is_some() );
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cache_control( mut self, cache_control : crate::CacheControl ) -> Self
+    {
+      if let Some( slot ) = self.cache_control_slot()
+      {
+        *slot = Some( cache_control );
+      }
+      self
+    }
+
+    /// Cache breakpoint set on this block
+    #[ inline ]
+    #[ must_use ]
+    #[ allow( clippy::match_same_arms ) ] // Different enum variants with conditional compilation
+    pub fn cache_control( &self ) -> Option< &crate::CacheControl >
+    {
+      match self
+      {
+        Content::Text { cache_control, .. } |
+        Content::Document { cache_control, .. } => cache_control.as_ref(),
+        #[ cfg( feature = "vision" ) ]
+        Content::Image { cache_control, .. } => cache_control.as_ref(),
+        #[ cfg( feature = "tools" ) ]
+        Content::ToolUse { cache_control, .. } |
+        Content::ToolResult { cache_control, .. } => cache_control.as_ref(),
+        _ => None,
+      }
+    }
+
+    #[ allow( clippy::match_same_arms ) ] // Different enum variants with conditional compilation
+    fn cache_control_slot( &mut self ) -> Option< &mut Option< crate::CacheControl > >
+    {
+      match self
+      {
+        Content::Text { cache_control, .. } |
+        Content::Document { cache_control, .. } => Some( cache_control ),
+        #[ cfg( feature = "vision" ) ]
+        Content::Image { cache_control, .. } => Some( cache_control ),
+        #[ cfg( feature = "tools" ) ]
+        Content::ToolUse { cache_control, .. } |
+        Content::ToolResult { cache_control, .. } => Some( cache_control ),
+        _ => None,
       }
     }
 
@@ -185,13 +373,17 @@ mod private
     {
       match self
       {
-        Content::Text { r#type, .. } => r#type,
+        Content::Text { r#type, .. } |
+        Content::Document { r#type, .. } |
+        Content::Thinking { r#type, .. } |
+        Content::RedactedThinking { r#type, .. } => r#type,
         #[ cfg( feature = "vision" ) ]
         Content::Image { r#type, .. } => r#type,
         #[ cfg( feature = "tools" ) ]
         Content::ToolUse { r#type, .. } => r#type,
         #[ cfg( feature = "tools" ) ]
-        Content::ToolResult { r#type, .. } => r#type,
+        Content::ToolResult { r#type, .. } |
+        Content::ServerToolResult { r#type, .. } => r#type,
       }
     }
 
@@ -215,6 +407,22 @@ mod private
       matches!( self, Content::Text { .. } )
     }
 
+    /// Check if this content is document type
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_document( &self ) -> bool
+    {
+      matches!( self, Content::Document { .. } )
+    }
+
+    /// Check if this content is thinking or redacted thinking
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_thinking( &self ) -> bool
+    {
+      matches!( self, Content::Thinking { .. } | Content::RedactedThinking { .. } )
+    }
+
     /// Check if this content is image type (requires vision feature)
     #[ cfg( feature = "vision" ) ]
     #[ inline ]
@@ -242,6 +450,15 @@ mod private
       matches!( self, Content::ToolResult { .. } )
     }
 
+    /// Check if this content is the result of a tool Anthropic ran
+    #[ cfg( feature = "tools" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_server_tool_result( &self ) -> bool
+    {
+      matches!( self, Content::ServerToolResult { .. } )
+    }
+
     /// Get tool use ID if this is a tool use content
     #[ cfg( feature = "tools" ) ]
     #[ inline ]
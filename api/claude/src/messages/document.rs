@@ -0,0 +1,566 @@
+//! Document content and citation types
+//!
+//! `DocumentContent`, `DocumentSource`, `CitationsConfig`, `Citation`, and
+//! `CitedAnswer`.
+
+mod private
+{
+  use core::fmt;
+  use serde::{ Serialize, Deserialize };
+
+  /// Source of a document block
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::DocumentSource;
+  ///
+  /// let inline = DocumentSource::pdf( "JVBERi0xLjcK" );
+  /// let uploaded = DocumentSource::file( "file_011CNha8iCJcU1wXNR6q4V8w" );
+  ///
+  /// assert_eq!( serde_json::to_value( &uploaded ).unwrap()[ "type" ], "file" );
+  /// assert_eq!( serde_json::to_value( &inline ).unwrap()[ "media_type" ], "application/pdf" );
+  /// ```
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  #[ serde( tag = "type" ) ]
+  pub enum DocumentSource
+  {
+    /// Base64-encoded document, e.g. a PDF
+    #[ serde( rename = "base64" ) ]
+    Base64
+    {
+      /// MIME type of the document (`application/pdf`)
+      media_type : String,
+      /// Base64-encoded document data
+      data : String,
+    },
+    /// Plain text document
+    #[ serde( rename = "text" ) ]
+    Text
+    {
+      /// MIME type of the text (`text/plain`)
+      media_type : String,
+      /// The document text
+      data : String,
+    },
+    /// Document uploaded through the Files API
+    #[ serde( rename = "file" ) ]
+    File
+    {
+      /// ID returned by the upload
+      file_id : String,
+    },
+    /// Document fetched by the API from a URL
+    #[ serde( rename = "url" ) ]
+    Url
+    {
+      /// Public URL of the document
+      url : String,
+    },
+  }
+
+  impl DocumentSource
+  {
+    /// Create a base64 PDF source
+    #[ inline ]
+    #[ must_use ]
+    pub fn pdf< S : Into< String > >( data : S ) -> Self
+    {
+      Self::Base64 { media_type : "application/pdf".to_string(), data : data.into() }
+    }
+
+    /// Create a plain text source
+    #[ inline ]
+    #[ must_use ]
+    pub fn text< S : Into< String > >( text : S ) -> Self
+    {
+      Self::Text { media_type : "text/plain".to_string(), data : text.into() }
+    }
+
+    /// Create a source referencing an uploaded file
+    ///
+    /// Requests using it must send the Files API beta header, see
+    /// [`crate::ClientConfig::with_beta`].
+    #[ inline ]
+    #[ must_use ]
+    pub fn file< S : Into< String > >( file_id : S ) -> Self
+    {
+      Self::File { file_id : file_id.into() }
+    }
+
+    /// Create a source the API fetches from `url`
+    #[ inline ]
+    #[ must_use ]
+    pub fn url< S : Into< String > >( url : S ) -> Self
+    {
+      Self::Url { url : url.into() }
+    }
+
+    /// Validate the source
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data, file ID, or URL is empty, or a base64
+    /// source is not a PDF
+    #[ inline ]
+    pub fn validate( &self ) -> Result< (), crate::error_tools::Error >
+    {
+      match self
+      {
+        DocumentSource::Base64 { media_type, data } =>
+        {
+          if media_type != "application/pdf"
+          {
+            return Err( crate::error_tools::Error::msg( format!( "Invalid document media type : '{media_type}'. Base64 documents must be 'application/pdf'." ) ) );
+          }
+          if data.is_empty()
+          {
+            return Err( crate::error_tools::Error::msg( "Document data cannot be empty" ) );
+          }
+        },
+        DocumentSource::Text { data, .. } if data.is_empty() =>
+        {
+          return Err( crate::error_tools::Error::msg( "Document text cannot be empty" ) );
+        },
+        DocumentSource::File { file_id } if file_id.is_empty() =>
+        {
+          return Err( crate::error_tools::Error::msg( "Document file_id cannot be empty" ) );
+        },
+        DocumentSource::Url { url } if url.is_empty() =>
+        {
+          return Err( crate::error_tools::Error::msg( "Document URL cannot be empty" ) );
+        },
+        _ => {},
+      }
+      Ok( () )
+    }
+  }
+
+  /// Whether the model cites passages of a document
+  #[ derive( Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq ) ]
+  pub struct CitationsConfig
+  {
+    /// Enable citations for the document
+    pub enabled : bool,
+  }
+
+  /// Document content for a message
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::{ DocumentContent, DocumentSource, Message };
+  ///
+  /// let report = DocumentContent::new( DocumentSource::file( "file_abc" ) )
+  ///   .with_title( "Annual report" )
+  ///   .with_citations();
+  /// let message = Message::user_with_document( "Summarise the risks section.", report );
+  ///
+  /// assert_eq!( message.content.len(), 2 );
+  /// ```
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct DocumentContent
+  {
+    /// Type - always "`document`"
+    pub r#type : String,
+    /// Document source
+    pub source : DocumentSource,
+    /// Title shown to the model and echoed in citations
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub title : Option< String >,
+    /// Context about the document that is not cited from
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub context : Option< String >,
+    /// Citation settings
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub citations : Option< CitationsConfig >,
+  }
+
+  impl DocumentContent
+  {
+    /// Create document content from a source
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( source : DocumentSource ) -> Self
+    {
+      Self
+      {
+        r#type : "document".to_string(),
+        source,
+        title : None,
+        context : None,
+        citations : None,
+      }
+    }
+
+    /// Create document content from base64 PDF data
+    #[ inline ]
+    #[ must_use ]
+    pub fn pdf< S : Into< String > >( data : S ) -> Self
+    {
+      Self::new( DocumentSource::pdf( data ) )
+    }
+
+    /// Create document content referencing an uploaded file
+    #[ inline ]
+    #[ must_use ]
+    pub fn file< S : Into< String > >( file_id : S ) -> Self
+    {
+      Self::new( DocumentSource::file( file_id ) )
+    }
+
+    /// Set the document title
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_title< S : Into< String > >( mut self, title : S ) -> Self
+    {
+      self.title = Some( title.into() );
+      self
+    }
+
+    /// Set context about the document
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_context< S : Into< String > >( mut self, context : S ) -> Self
+    {
+      self.context = Some( context.into() );
+      self
+    }
+
+    /// Ask the model to cite passages of this document
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_citations( mut self ) -> Self
+    {
+      self.citations = Some( CitationsConfig { enabled : true } );
+      self
+    }
+
+    /// Validate document content
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content type is not `document` or the source is invalid
+    #[ inline ]
+    pub fn validate( &self ) -> Result< (), crate::error_tools::Error >
+    {
+      if self.r#type != "document"
+      {
+        return Err( crate::error_tools::Error::msg( format!( "Invalid document content type : '{}'. Expected 'document'.", self.r#type ) ) );
+      }
+
+      self.source.validate()
+    }
+  }
+
+  /// Passage of a document cited by a response text block
+  ///
+  /// Page numbers start at 1; end positions are exclusive.
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  #[ serde( tag = "type" ) ]
+  pub enum Citation
+  {
+    /// Pages of a PDF document
+    #[ serde( rename = "page_location" ) ]
+    PageLocation
+    {
+      /// The cited text
+      cited_text : String,
+      /// Index of the document among the request's documents
+      document_index : u32,
+      /// Title of the document, if it had one
+      #[ serde( default ) ]
+      document_title : Option< String >,
+      /// First cited page
+      start_page_number : u32,
+      /// Page after the last cited page
+      end_page_number : u32,
+    },
+    /// Characters of a plain text document
+    #[ serde( rename = "char_location" ) ]
+    CharLocation
+    {
+      /// The cited text
+      cited_text : String,
+      /// Index of the document among the request's documents
+      document_index : u32,
+      /// Title of the document, if it had one
+      #[ serde( default ) ]
+      document_title : Option< String >,
+      /// First cited character
+      start_char_index : u32,
+      /// Character after the last cited character
+      end_char_index : u32,
+    },
+    /// Blocks of a custom content document
+    #[ serde( rename = "content_block_location" ) ]
+    ContentBlockLocation
+    {
+      /// The cited text
+      cited_text : String,
+      /// Index of the document among the request's documents
+      document_index : u32,
+      /// Title of the document, if it had one
+      #[ serde( default ) ]
+      document_title : Option< String >,
+      /// First cited block
+      start_block_index : u32,
+      /// Block after the last cited block
+      end_block_index : u32,
+    },
+    /// Page found by the web search tool
+    #[ serde( rename = "web_search_result_location" ) ]
+    WebSearchResultLocation
+    {
+      /// The cited text
+      cited_text : String,
+      /// URL of the page
+      url : String,
+      /// Title of the page, if it had one
+      #[ serde( default ) ]
+      title : Option< String >,
+      /// Reference to the cited passage; pass it back unchanged in later turns
+      encrypted_index : String,
+    },
+    /// Citation of a kind this client does not know
+    #[ serde( other ) ]
+    Unknown,
+  }
+
+  impl Citation
+  {
+    /// The cited text
+    #[ inline ]
+    #[ must_use ]
+    pub fn cited_text( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Citation::PageLocation { cited_text, .. } |
+        Citation::CharLocation { cited_text, .. } |
+        Citation::ContentBlockLocation { cited_text, .. } |
+        Citation::WebSearchResultLocation { cited_text, .. } => Some( cited_text ),
+        Citation::Unknown => None,
+      }
+    }
+
+    /// Index of the cited document among the request's documents
+    #[ inline ]
+    #[ must_use ]
+    pub fn document_index( &self ) -> Option< u32 >
+    {
+      match self
+      {
+        Citation::PageLocation { document_index, .. } |
+        Citation::CharLocation { document_index, .. } |
+        Citation::ContentBlockLocation { document_index, .. } => Some( *document_index ),
+        Citation::WebSearchResultLocation { .. } | Citation::Unknown => None,
+      }
+    }
+
+    /// Cited pages, end exclusive, if this cites a PDF
+    #[ inline ]
+    #[ must_use ]
+    pub fn pages( &self ) -> Option< core::ops::Range< u32 > >
+    {
+      match self
+      {
+        Citation::PageLocation { start_page_number, end_page_number, .. } => Some( *start_page_number..*end_page_number ),
+        _ => None,
+      }
+    }
+
+    /// Cited characters, end exclusive, if this cites a plain text document
+    #[ inline ]
+    #[ must_use ]
+    pub fn chars( &self ) -> Option< core::ops::Range< u32 > >
+    {
+      match self
+      {
+        Citation::CharLocation { start_char_index, end_char_index, .. } => Some( *start_char_index..*end_char_index ),
+        _ => None,
+      }
+    }
+
+    /// Cited blocks, end exclusive, if this cites a custom content document
+    #[ inline ]
+    #[ must_use ]
+    pub fn blocks( &self ) -> Option< core::ops::Range< u32 > >
+    {
+      match self
+      {
+        Citation::ContentBlockLocation { start_block_index, end_block_index, .. } => Some( *start_block_index..*end_block_index ),
+        _ => None,
+      }
+    }
+
+    /// Title of the cited document or page, if it had one
+    #[ inline ]
+    #[ must_use ]
+    pub fn document_title( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Citation::PageLocation { document_title, .. } |
+        Citation::CharLocation { document_title, .. } |
+        Citation::ContentBlockLocation { document_title, .. } |
+        Citation::WebSearchResultLocation { title : document_title, .. } => document_title.as_deref(),
+        Citation::Unknown => None,
+      }
+    }
+
+    /// URL of the cited page, if this cites a web search result
+    #[ inline ]
+    #[ must_use ]
+    pub fn url( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Citation::WebSearchResultLocation { url, .. } => Some( url ),
+        _ => None,
+      }
+    }
+  }
+
+  /// Where the citation points, e.g. `Annual report, pages 3-4`
+  ///
+  /// Ranges are shown inclusive; documents without a title are named by
+  /// their index.
+  impl fmt::Display for Citation
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let source = match ( self.document_title(), self.document_index(), self.url() )
+      {
+        ( Some( title ), _, _ ) => title.to_string(),
+        ( None, Some( index ), _ ) => format!( "Document {index}" ),
+        ( None, None, Some( url ) ) => url.to_string(),
+        ( None, None, None ) => return f.write_str( "Unknown source" ),
+      };
+      let span = | f : &mut fmt::Formatter< '_ >, unit : &str, range : core::ops::Range< u32 > |
+      {
+        if range.end <= range.start + 1
+        {
+          write!( f, "{source}, {unit} {}", range.start )
+        }
+        else
+        {
+          write!( f, "{source}, {unit}s {}-{}", range.start, range.end - 1 )
+        }
+      };
+      match self
+      {
+        Citation::PageLocation { start_page_number, end_page_number, .. } => span( f, "page", *start_page_number..*end_page_number ),
+        Citation::CharLocation { start_char_index, end_char_index, .. } => span( f, "character", *start_char_index..*end_char_index ),
+        Citation::ContentBlockLocation { start_block_index, end_block_index, .. } => span( f, "block", *start_block_index..*end_block_index ),
+        Citation::WebSearchResultLocation { url, title : Some( _ ), .. } => write!( f, "{source} ({url})" ),
+        Citation::WebSearchResultLocation { .. } | Citation::Unknown => f.write_str( &source ),
+      }
+    }
+  }
+
+  /// Answer text with numbered citation markers and the sources they refer to
+  ///
+  /// Each cited text block is followed by markers such as `[1][2]`; a
+  /// citation repeated in later blocks keeps its number. `Display` renders
+  /// the text followed by one footnote per source.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::{ Citation, CitedAnswer };
+  ///
+  /// let page = Citation::PageLocation
+  /// {
+  ///   cited_text : "Revenue grew 12%.".to_string(),
+  ///   document_index : 0,
+  ///   document_title : Some( "Annual report".to_string() ),
+  ///   start_page_number : 3,
+  ///   end_page_number : 5,
+  /// };
+  /// let answer = CitedAnswer::new( [ ( "Revenue grew", &[ page ][ .. ] ), ( " last year.", &[][ .. ] ) ] );
+  ///
+  /// assert_eq!( answer.text, "Revenue grew[1] last year." );
+  /// assert_eq!( answer.to_string(), "Revenue grew[1] last year.\n\n[1] \"Revenue grew 12%.\" - Annual report, pages 3-4" );
+  /// ```
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct CitedAnswer
+  {
+    /// Text of the answer with citation markers
+    pub text : String,
+    /// Cited sources; marker `[n]` refers to `sources[ n - 1 ]`
+    pub sources : Vec< Citation >,
+  }
+
+  impl CitedAnswer
+  {
+    /// Build the answer from text blocks and their citations, in order
+    #[ inline ]
+    pub fn new< 'a, I >( blocks : I ) -> Self
+    where
+      I : IntoIterator< Item = ( &'a str, &'a [ Citation ] ) >,
+    {
+      let mut text = String::new();
+      let mut sources : Vec< Citation > = Vec::new();
+      for ( block, citations ) in blocks
+      {
+        text.push_str( block );
+        for citation in citations
+        {
+          let number = if let Some( position ) = sources.iter().position( | source | source == citation )
+          {
+            position + 1
+          }
+          else
+          {
+            sources.push( citation.clone() );
+            sources.len()
+          };
+          text.push( '[' );
+          text.push_str( &number.to_string() );
+          text.push( ']' );
+        }
+      }
+      Self { text, sources }
+    }
+
+    /// One line per source: `[n] "cited text" - location`
+    #[ inline ]
+    #[ must_use ]
+    pub fn footnotes( &self ) -> Vec< String >
+    {
+      self.sources.iter().enumerate().map( | ( index, source ) |
+      {
+        match source.cited_text()
+        {
+          Some( cited_text ) => format!( "[{}] \"{cited_text}\" - {source}", index + 1 ),
+          None => format!( "[{}] {source}", index + 1 ),
+        }
+      } ).collect()
+    }
+  }
+
+  impl fmt::Display for CitedAnswer
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.write_str( &self.text )?;
+      if !self.sources.is_empty()
+      {
+        write!( f, "\n\n{}", self.footnotes().join( "\n" ) )?;
+      }
+      Ok( () )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use DocumentSource;
+  exposed use DocumentContent;
+  exposed use CitationsConfig;
+  exposed use Citation;
+  exposed use CitedAnswer;
+}
@@ -0,0 +1,642 @@
+//! Anthropic-defined tools and their result blocks
+//!
+//! `Tool` is an entry of a request's `tools`: a custom `ToolDefinition` or one
+//! of the tools Anthropic defines. Web search and code execution run on
+//! Anthropic's servers and answer with `ServerToolResultContent`; computer
+//! use, the text editor, and bash are executed by the caller like custom tools.
+
+#[ cfg( feature = "tools" ) ]
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+  use serde_json::Value;
+
+  /// Beta feature enabling the code execution tool
+  pub const CODE_EXECUTION_BETA : &str = "code-execution-2025-05-22";
+  /// Beta feature enabling the computer use tool
+  pub const COMPUTER_USE_BETA : &str = "computer-use-2025-01-24";
+
+  /// A tool available to the model
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_claude::{ CreateMessageRequest, Message, ToolDefinition, WebSearchTool };
+  ///
+  /// let request = CreateMessageRequest::builder()
+  ///   .model( "claude-sonnet-4-6" )
+  ///   .max_tokens( 1024 )
+  ///   .message( Message::user( "What changed in the latest Rust release?" ) )
+  ///   .tool( WebSearchTool::new().with_max_uses( 3 ).with_allowed_domains( [ "blog.rust-lang.org" ] ) )
+  ///   .tool( ToolDefinition::simple( "get_time", "Get the current time" ) )
+  ///   .build();
+  ///
+  /// let json = serde_json::to_value( &request ).unwrap();
+  /// assert_eq!( json[ "tools" ][ 0 ][ "type" ], "web_search_20250305" );
+  /// assert_eq!( json[ "tools" ][ 1 ][ "name" ], "get_time" );
+  /// ```
+  #[ derive( Debug, Clone, Serialize, PartialEq ) ]
+  #[ serde( untagged ) ]
+  pub enum Tool
+  {
+    /// Tool defined by the caller with a JSON schema
+    Custom( crate::ToolDefinition ),
+    /// Web search, run by Anthropic
+    WebSearch( WebSearchTool ),
+    /// Python code execution in a sandbox, run by Anthropic
+    CodeExecution( CodeExecutionTool ),
+    /// Screen, mouse, and keyboard control, executed by the caller
+    Computer( ComputerTool ),
+    /// File viewing and editing, executed by the caller
+    TextEditor( TextEditorTool ),
+    /// Shell commands, executed by the caller
+    Bash( BashTool ),
+  }
+
+  impl Tool
+  {
+    /// Name the model uses to call the tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn name( &self ) -> &str
+    {
+      match self
+      {
+        Tool::Custom( tool ) => &tool.name,
+        Tool::WebSearch( tool ) => &tool.name,
+        Tool::CodeExecution( tool ) => &tool.name,
+        Tool::Computer( tool ) => &tool.name,
+        Tool::TextEditor( tool ) => &tool.name,
+        Tool::Bash( tool ) => &tool.name,
+      }
+    }
+
+    /// The custom tool definition, if this is a custom tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_custom( &self ) -> Option< &crate::ToolDefinition >
+    {
+      match self
+      {
+        Tool::Custom( tool ) => Some( tool ),
+        _ => None,
+      }
+    }
+
+    /// Check if Anthropic runs the tool and returns its result itself
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_server_tool( &self ) -> bool
+    {
+      matches!( self, Tool::WebSearch( _ ) | Tool::CodeExecution( _ ) )
+    }
+
+    /// Validate the tool
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a custom tool is invalid, a web search limits
+    /// domains both ways or allows no uses, or a computer has no display size
+    #[ cfg( feature = "error-handling" ) ]
+    #[ inline ]
+    pub fn validate( &self ) -> crate::error::AnthropicResult< () >
+    {
+      match self
+      {
+        Tool::Custom( tool ) => tool.validate(),
+        Tool::WebSearch( tool ) =>
+        {
+          if tool.allowed_domains.is_some() && tool.blocked_domains.is_some()
+          {
+            return Err( crate::error::AnthropicError::InvalidRequest(
+              "web search cannot have both allowed_domains and blocked_domains".to_string()
+            ) );
+          }
+          if tool.max_uses == Some( 0 )
+          {
+            return Err( crate::error::AnthropicError::InvalidRequest(
+              "web search max_uses must be at least 1".to_string()
+            ) );
+          }
+          Ok( () )
+        },
+        Tool::Computer( tool ) if tool.display_width_px == 0 || tool.display_height_px == 0 =>
+        {
+          Err( crate::error::AnthropicError::InvalidRequest(
+            "computer display size must be positive".to_string()
+          ) )
+        },
+        _ => Ok( () ),
+      }
+    }
+  }
+
+  impl< 'de > Deserialize< 'de > for Tool
+  {
+    #[ inline ]
+    fn deserialize< D >( deserializer : D ) -> Result< Self, D::Error >
+    where
+      D : serde::Deserializer< 'de >,
+    {
+      // Anthropic-defined tools differ only by the versioned `type`
+      let value = Value::deserialize( deserializer )?;
+      let kind = value.get( "type" ).and_then( Value::as_str ).unwrap_or( "custom" );
+      let tool = match kind.rsplit_once( '_' ).map_or( kind, | ( kind, _version ) | kind )
+      {
+        "web_search" => serde_json::from_value( value ).map( Tool::WebSearch ),
+        "code_execution" => serde_json::from_value( value ).map( Tool::CodeExecution ),
+        "computer" => serde_json::from_value( value ).map( Tool::Computer ),
+        "text_editor" => serde_json::from_value( value ).map( Tool::TextEditor ),
+        "bash" => serde_json::from_value( value ).map( Tool::Bash ),
+        _ => serde_json::from_value( value ).map( Tool::Custom ),
+      };
+      tool.map_err( serde::de::Error::custom )
+    }
+  }
+
+  impl From< crate::ToolDefinition > for Tool
+  {
+    #[ inline ]
+    fn from( tool : crate::ToolDefinition ) -> Self
+    {
+      Tool::Custom( tool )
+    }
+  }
+
+  impl From< WebSearchTool > for Tool
+  {
+    #[ inline ]
+    fn from( tool : WebSearchTool ) -> Self
+    {
+      Tool::WebSearch( tool )
+    }
+  }
+
+  impl From< CodeExecutionTool > for Tool
+  {
+    #[ inline ]
+    fn from( tool : CodeExecutionTool ) -> Self
+    {
+      Tool::CodeExecution( tool )
+    }
+  }
+
+  impl From< ComputerTool > for Tool
+  {
+    #[ inline ]
+    fn from( tool : ComputerTool ) -> Self
+    {
+      Tool::Computer( tool )
+    }
+  }
+
+  impl From< TextEditorTool > for Tool
+  {
+    #[ inline ]
+    fn from( tool : TextEditorTool ) -> Self
+    {
+      Tool::TextEditor( tool )
+    }
+  }
+
+  impl From< BashTool > for Tool
+  {
+    #[ inline ]
+    fn from( tool : BashTool ) -> Self
+    {
+      Tool::Bash( tool )
+    }
+  }
+
+  /// Web search tool (`web_search_20250305`)
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct WebSearchTool
+  {
+    /// Type - "`web_search_20250305`"
+    pub r#type : String,
+    /// Tool name - "`web_search`"
+    pub name : String,
+    /// Maximum searches per request
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub max_uses : Option< u32 >,
+    /// Only search these domains
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub allowed_domains : Option< Vec< String > >,
+    /// Never search these domains
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub blocked_domains : Option< Vec< String > >,
+    /// Location used to localise results
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub user_location : Option< UserLocation >,
+  }
+
+  impl WebSearchTool
+  {
+    /// Create the web search tool without limits
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self
+      {
+        r#type : "web_search_20250305".to_string(),
+        name : "web_search".to_string(),
+        max_uses : None,
+        allowed_domains : None,
+        blocked_domains : None,
+        user_location : None,
+      }
+    }
+
+    /// Limit the number of searches per request
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_uses( mut self, max_uses : u32 ) -> Self
+    {
+      self.max_uses = Some( max_uses );
+      self
+    }
+
+    /// Only search these domains
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_allowed_domains< I, S >( mut self, domains : I ) -> Self
+    where
+      I : IntoIterator< Item = S >,
+      S : Into< String >,
+    {
+      self.allowed_domains = Some( domains.into_iter().map( Into::into ).collect() );
+      self
+    }
+
+    /// Never search these domains
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_blocked_domains< I, S >( mut self, domains : I ) -> Self
+    where
+      I : IntoIterator< Item = S >,
+      S : Into< String >,
+    {
+      self.blocked_domains = Some( domains.into_iter().map( Into::into ).collect() );
+      self
+    }
+
+    /// Localise results to a location
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_user_location( mut self, location : UserLocation ) -> Self
+    {
+      self.user_location = Some( location );
+      self
+    }
+  }
+
+  impl Default for WebSearchTool
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Approximate location of the user for web search
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct UserLocation
+  {
+    /// Type - "approximate"
+    pub r#type : String,
+    /// City name
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub city : Option< String >,
+    /// Region or state
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub region : Option< String >,
+    /// ISO 3166-1 alpha-2 country code
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub country : Option< String >,
+    /// IANA time zone
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub timezone : Option< String >,
+  }
+
+  impl UserLocation
+  {
+    /// Create an approximate location with only a country
+    #[ inline ]
+    #[ must_use ]
+    pub fn country< S : Into< String > >( country : S ) -> Self
+    {
+      Self
+      {
+        r#type : "approximate".to_string(),
+        city : None,
+        region : None,
+        country : Some( country.into() ),
+        timezone : None,
+      }
+    }
+
+    /// Set the city
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_city< S : Into< String > >( mut self, city : S ) -> Self
+    {
+      self.city = Some( city.into() );
+      self
+    }
+
+    /// Set the region
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_region< S : Into< String > >( mut self, region : S ) -> Self
+    {
+      self.region = Some( region.into() );
+      self
+    }
+
+    /// Set the time zone
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_timezone< S : Into< String > >( mut self, timezone : S ) -> Self
+    {
+      self.timezone = Some( timezone.into() );
+      self
+    }
+  }
+
+  /// Code execution tool (`code_execution_20250522`)
+  ///
+  /// Requires the [`CODE_EXECUTION_BETA`] header, see `ClientConfig::with_beta`.
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct CodeExecutionTool
+  {
+    /// Type - "`code_execution_20250522`"
+    pub r#type : String,
+    /// Tool name - "`code_execution`"
+    pub name : String,
+  }
+
+  impl CodeExecutionTool
+  {
+    /// Create the code execution tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self { r#type : "code_execution_20250522".to_string(), name : "code_execution".to_string() }
+    }
+  }
+
+  impl Default for CodeExecutionTool
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Computer use tool (`computer_20250124`)
+  ///
+  /// Requires the [`COMPUTER_USE_BETA`] header, see `ClientConfig::with_beta`.
+  /// The model's actions arrive as `tool_use` blocks for the caller to perform.
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ComputerTool
+  {
+    /// Type - "`computer_20250124`"
+    pub r#type : String,
+    /// Tool name - "computer"
+    pub name : String,
+    /// Display width in pixels
+    pub display_width_px : u32,
+    /// Display height in pixels
+    pub display_height_px : u32,
+    /// X11 display number
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub display_number : Option< u32 >,
+  }
+
+  impl ComputerTool
+  {
+    /// Create the computer use tool for a display size
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( display_width_px : u32, display_height_px : u32 ) -> Self
+    {
+      Self
+      {
+        r#type : "computer_20250124".to_string(),
+        name : "computer".to_string(),
+        display_width_px,
+        display_height_px,
+        display_number : None,
+      }
+    }
+
+    /// Set the X11 display number
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_display_number( mut self, display_number : u32 ) -> Self
+    {
+      self.display_number = Some( display_number );
+      self
+    }
+  }
+
+  /// Text editor tool (`text_editor_20250728`)
+  ///
+  /// The model's `view`, `create`, `str_replace`, and `insert` commands arrive
+  /// as `tool_use` blocks for the caller to perform.
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct TextEditorTool
+  {
+    /// Type - "`text_editor_20250728`"
+    pub r#type : String,
+    /// Tool name - "`str_replace_based_edit_tool`"
+    pub name : String,
+    /// Truncate viewed files to this many characters
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub max_characters : Option< u32 >,
+  }
+
+  impl TextEditorTool
+  {
+    /// Create the text editor tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self
+      {
+        r#type : "text_editor_20250728".to_string(),
+        name : "str_replace_based_edit_tool".to_string(),
+        max_characters : None,
+      }
+    }
+
+    /// Truncate viewed files to `max_characters`
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_characters( mut self, max_characters : u32 ) -> Self
+    {
+      self.max_characters = Some( max_characters );
+      self
+    }
+  }
+
+  impl Default for TextEditorTool
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Bash tool (`bash_20250124`)
+  ///
+  /// Commands arrive as `tool_use` blocks for the caller to run.
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct BashTool
+  {
+    /// Type - "`bash_20250124`"
+    pub r#type : String,
+    /// Tool name - "bash"
+    pub name : String,
+  }
+
+  impl BashTool
+  {
+    /// Create the bash tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self { r#type : "bash_20250124".to_string(), name : "bash".to_string() }
+    }
+  }
+
+  impl Default for BashTool
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Content of a `web_search_tool_result` or `code_execution_tool_result` block
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  #[ serde( untagged ) ]
+  pub enum ServerToolResultContent
+  {
+    /// Pages found by a web search
+    WebSearchResults( Vec< WebSearchResult > ),
+    /// Output of executed code
+    CodeExecution( CodeExecutionResult ),
+    /// The tool failed
+    Error( ServerToolError ),
+  }
+
+  impl ServerToolResultContent
+  {
+    /// The search results, if this is a successful web search
+    #[ inline ]
+    #[ must_use ]
+    pub fn web_search_results( &self ) -> Option< &[ WebSearchResult ] >
+    {
+      match self
+      {
+        ServerToolResultContent::WebSearchResults( results ) => Some( results ),
+        _ => None,
+      }
+    }
+
+    /// The execution output, if this is a completed code execution
+    #[ inline ]
+    #[ must_use ]
+    pub fn code_execution( &self ) -> Option< &CodeExecutionResult >
+    {
+      match self
+      {
+        ServerToolResultContent::CodeExecution( result ) => Some( result ),
+        _ => None,
+      }
+    }
+
+    /// The error code, if the tool failed
+    #[ inline ]
+    #[ must_use ]
+    pub fn error_code( &self ) -> Option< &str >
+    {
+      match self
+      {
+        ServerToolResultContent::Error( error ) => Some( &error.error_code ),
+        _ => None,
+      }
+    }
+  }
+
+  /// Page found by a web search
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct WebSearchResult
+  {
+    /// Type - "`web_search_result`"
+    pub r#type : String,
+    /// Page URL
+    pub url : String,
+    /// Page title
+    pub title : String,
+    /// Page content, encrypted; pass it back unchanged in later turns
+    pub encrypted_content : String,
+    /// When the page was last updated, if known
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub page_age : Option< String >,
+  }
+
+  /// Output of code run by the code execution tool
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct CodeExecutionResult
+  {
+    /// Type - "`code_execution_result`"
+    pub r#type : String,
+    /// Standard output
+    pub stdout : String,
+    /// Standard error
+    pub stderr : String,
+    /// Exit code of the process
+    pub return_code : i32,
+    /// Files produced by the code
+    #[ serde( default ) ]
+    pub content : Vec< Value >,
+  }
+
+  /// Failure of a server tool
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ServerToolError
+  {
+    /// Type, e.g. "`web_search_tool_result_error`"
+    pub r#type : String,
+    /// Error code, e.g. "`max_uses_exceeded`" or "unavailable"
+    pub error_code : String,
+  }
+}
+
+#[ cfg( feature = "tools" ) ]
+crate::mod_interface!
+{
+  exposed use CODE_EXECUTION_BETA;
+  exposed use COMPUTER_USE_BETA;
+  exposed use Tool;
+  exposed use WebSearchTool;
+  exposed use UserLocation;
+  exposed use CodeExecutionTool;
+  exposed use ComputerTool;
+  exposed use TextEditorTool;
+  exposed use BashTool;
+  exposed use ServerToolResultContent;
+  exposed use WebSearchResult;
+  exposed use CodeExecutionResult;
+  exposed use ServerToolError;
+}
@@ -5,6 +5,7 @@
 mod private
 {
   use super::super::content::orphan::*;
+  use super::super::document::orphan::DocumentContent;
   use serde::{ Serialize, Deserialize };
   
   #[ cfg( feature = "tools" ) ]
@@ -22,6 +23,9 @@ mod private
     pub description : String,
     /// JSON schema for the tool's input parameters
     pub input_schema : Value,
+    /// Cache breakpoint ending at this tool; caches every tool definition up to it
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub cache_control : Option< crate::CacheControl >,
   }
 
   #[ cfg( feature = "tools" ) ]
@@ -37,6 +41,7 @@ mod private
         name : name.into(),
         description : description.into(),
         input_schema,
+        cache_control : None,
       }
     }
 
@@ -54,6 +59,15 @@ mod private
       Self::new( name, description, schema )
     }
     
+    /// Set a cache breakpoint ending at this tool
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cache_control( mut self, cache_control : crate::CacheControl ) -> Self
+    {
+      self.cache_control = Some( cache_control );
+      self
+    }
+
     /// Create a tool definition with typed parameters
     #[ inline ]
     #[ must_use ]
@@ -263,7 +277,8 @@ mod private
     pub role : Role,
     /// Content of message
     pub content : Vec< Content >,
-    /// Optional cache control for this message
+    /// Optional cache control for this message; to cache the conversation
+    /// up to this message, mark its last block with [`Message::with_cache_control`]
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub cache_control : Option< crate::CacheControl >,
   }
@@ -332,6 +347,15 @@ mod private
       self
     }
 
+    /// Add document content
+    #[ inline ]
+    #[ must_use ]
+    pub fn document( mut self, document : DocumentContent ) -> Self
+    {
+      self.content.push( Content::document( document ) );
+      self
+    }
+
     /// Add tool use content
     #[ cfg( feature = "tools" ) ]
     #[ inline ]
@@ -398,6 +422,32 @@ mod private
     }
   }
 
+  /// Converts a provider-neutral message into a single-text-block message.
+  ///
+  /// The Messages API takes system instructions through
+  /// `CreateMessageRequest::system` rather than the `messages` array, so
+  /// callers should route `NeutralRole::System` messages there.
+  #[ cfg( feature = "common" ) ]
+  impl From< api_common::NeutralMessage > for Message
+  {
+    #[ inline ]
+    fn from( message : api_common::NeutralMessage ) -> Self
+    {
+      let role = match message.role
+      {
+        api_common::NeutralRole::System    => Role::System,
+        api_common::NeutralRole::User      => Role::User,
+        api_common::NeutralRole::Assistant => Role::Assistant,
+      };
+      Self
+      {
+        role,
+        content : vec![ Content::new_text( message.content ) ],
+        cache_control : None,
+      }
+    }
+  }
+
   impl Message
   {
     /// Create a new message builder
@@ -447,6 +497,7 @@ mod private
           id : tool_use.id,
           name : tool_use.name,
           input : tool_use.input,
+          cache_control : None,
         }
       ).collect();
 
@@ -471,6 +522,7 @@ mod private
           tool_use_id : tool_result.tool_use_id,
           content : tool_result.content,
           is_error : tool_result.is_error,
+          cache_control : None,
         }
       ).collect();
 
@@ -482,17 +534,28 @@ mod private
       }
     }
 
+    /// Create user message with a document followed by a question about it
+    ///
+    /// The document comes first, as recommended for long documents.
+    #[ inline ]
+    #[ must_use ]
+    pub fn user_with_document< S : Into< String > >( text : S, document : DocumentContent ) -> Self
+    {
+      Self
+      {
+        role : Role::User,
+        content : vec![ Content::document( document ), Content::new_text( text ) ],
+        cache_control : None,
+      }
+    }
+
     /// Create user message with image content only (requires vision feature)
     #[ cfg( feature = "vision" ) ]
     #[ inline ]
     #[ must_use ]
     pub fn user_image( image : ImageContent ) -> Self
     {
-      let content = vec![ Content::Image
-      {
-        r#type : image.r#type,
-        source : image.source,
-      } ];
+      let content = vec![ Content::image( image.source ) ];
 
       Self
       {
@@ -510,7 +573,7 @@ mod private
     {
       let content = vec![
         Content::new_text( text ),
-        Content::Image { r#type : image.r#type, source : image.source }
+        Content::image( image.source )
       ];
 
       Self
@@ -531,11 +594,7 @@ mod private
 
       for image in images
       {
-        content.push( Content::Image
-        {
-          r#type : image.r#type,
-          source : image.source,
-        } );
+        content.push( Content::image( image.source ) );
       }
 
       Self
@@ -546,6 +605,30 @@ mod private
       }
     }
 
+    /// Set a cache breakpoint on the last content block
+    ///
+    /// The conversation up to and including this message is cached; see
+    /// [`Content::with_cache_control`] for blocks that cannot carry one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use api_claude::{ CacheControl, Message };
+    ///
+    /// let message = Message::user( "Here is the contract: ..." ).with_cache_control( CacheControl::ephemeral() );
+    /// assert!( message.content[ 0 ].cache_control().is_some() );
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cache_control( mut self, cache_control : crate::CacheControl ) -> Self
+    {
+      if let Some( last ) = self.content.pop()
+      {
+        self.content.push( last.with_cache_control( cache_control ) );
+      }
+      self
+    }
+
     /// Get the first text content from this message
     #[ inline ]
     #[ must_use ]
@@ -10,6 +10,7 @@ mod private {}
 crate::mod_interface!
 {
   layer core;
+  layer listing;
   layer manager;
   layer enhanced;
   layer enhanced_impls;
@@ -0,0 +1,178 @@
+//! Models API
+//!
+//! `client.models()` lists the models available to the API key through
+//! `/v1/models`, newest first, with the same cursor pagination as the Files
+//! API. Use it to discover model IDs instead of hardcoding them.
+
+#[ allow( clippy::missing_inline_in_public_items ) ]
+mod private
+{
+  use crate::
+  {
+    error::{ AnthropicError, AnthropicResult },
+    client::{ Client, build_headers, handle_response },
+  };
+  use serde::{ Serialize, Deserialize };
+
+  /// Model returned by the Models API
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ModelMetadata
+  {
+    /// Model ID used in requests (e.g. "`claude-sonnet-4-6`")
+    pub id : String,
+    /// Object type - always "model"
+    #[ serde( default ) ]
+    pub r#type : String,
+    /// Human-readable name (e.g. "Claude Sonnet 4.6")
+    pub display_name : String,
+    /// Release time (RFC 3339 datetime)
+    pub created_at : String,
+  }
+
+  /// Page of models
+  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
+  pub struct ModelListResponse
+  {
+    /// Models on this page
+    pub data : Vec< ModelMetadata >,
+    /// Whether there are more models
+    pub has_more : bool,
+    /// First model ID on this page
+    pub first_id : Option< String >,
+    /// Last model ID on this page
+    pub last_id : Option< String >,
+  }
+
+  impl Client
+  {
+    /// Access the Models API
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_claude::Client;
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::from_workspace()?;
+    /// for model in client.models().list_all().await?
+    /// {
+    ///   println!( "{} ({})", model.display_name, model.id );
+    /// }
+    /// # Ok( () )
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn models( &self ) -> ModelsApi< '_ >
+    {
+      ModelsApi { client : self }
+    }
+  }
+
+  /// Operations on the `/v1/models` endpoint
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct ModelsApi< 'a >
+  {
+    client : &'a Client,
+  }
+
+  impl ModelsApi< '_ >
+  {
+    /// List one page of models, newest first
+    ///
+    /// Use `before_id`/`after_id` for cursor-based pagination; `limit`
+    /// defaults to 20 on the API side.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails
+    pub async fn list( &self, before_id : Option< &str >, after_id : Option< &str >, limit : Option< u32 > ) -> AnthropicResult< ModelListResponse >
+    {
+      let mut url = format!( "{}/v1/models", self.client.base_url() );
+      let mut query_params = vec![];
+
+      if let Some( id ) = before_id
+      {
+        query_params.push( format!( "before_id={id}" ) );
+      }
+      if let Some( id ) = after_id
+      {
+        query_params.push( format!( "after_id={id}" ) );
+      }
+      if let Some( lim ) = limit
+      {
+        query_params.push( format!( "limit={lim}" ) );
+      }
+
+      if !query_params.is_empty()
+      {
+        url.push( '?' );
+        url.push_str( &query_params.join( "&" ) );
+      }
+
+      let response = self.client.http()
+        .get( &url )
+        .headers( build_headers( self.client.secret(), self.client.config() ) )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< ModelListResponse >( response ).await
+    }
+
+    /// List every model, following the pages until `has_more` is false
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails
+    pub async fn list_all( &self ) -> AnthropicResult< Vec< ModelMetadata > >
+    {
+      let mut models = Vec::new();
+      let mut after_id = None;
+      loop
+      {
+        let page = self.list( None, after_id.as_deref(), Some( 1000 ) ).await?;
+        models.extend( page.data );
+        match page.last_id
+        {
+          Some( last_id ) if page.has_more => after_id = Some( last_id ),
+          _ => return Ok( models ),
+        }
+      }
+    }
+
+    /// Get a model by ID or alias
+    ///
+    /// An alias such as "`claude-sonnet-4-6`" resolves to the model it
+    /// currently points to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID is empty or the request fails
+    pub async fn get( &self, model_id : &str ) -> AnthropicResult< ModelMetadata >
+    {
+      if model_id.is_empty()
+      {
+        return Err( AnthropicError::InvalidArgument( "model_id cannot be empty".to_string() ) );
+      }
+
+      let url = format!( "{}/v1/models/{}", self.client.base_url(), model_id );
+
+      let response = self.client.http()
+        .get( &url )
+        .headers( build_headers( self.client.secret(), self.client.config() ) )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      handle_response::< ModelMetadata >( response ).await
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use ModelMetadata;
+  exposed use ModelListResponse;
+  exposed use ModelsApi;
+}
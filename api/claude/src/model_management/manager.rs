@@ -10,30 +10,9 @@ mod private
     error::{ AnthropicError, AnthropicResult },
     client::{ Client, CreateMessageRequest },
   };
-  use serde::Deserialize;
   use std::{ collections::HashMap, sync::{ Arc, Mutex }, time::{ Duration, Instant } };
 
 
-  /// API response for models endpoint
-  #[ derive( Debug, Deserialize ) ]
-  struct ModelsApiResponse
-  {
-    data : Vec< ApiModelInfo >,
-  }
-
-  /// Model information from API
-  #[ derive( Debug, Deserialize ) ]
-  struct ApiModelInfo
-  {
-    id : String,
-    display_name : Option< String >,
-    max_tokens : Option< u32 >,
-    context_length : Option< u32 >,
-    created : Option< String >,
-    capabilities : Vec< String >,
-    version : Option< String >,
-  }
-
   /// Model cache entry
   #[ derive( Debug, Clone ) ]
   struct CacheEntry< T >
@@ -94,40 +73,19 @@ mod private
     /// Returns an error if API request fails
     pub async fn list_models( &self ) -> AnthropicResult< Vec< ModelInfo > >
     {
-      // Implement actual API call to /v1/models endpoint
-      let url = format!( "{}/v1/models", self.client.base_url() );
-
-      let response = self.client.http()
-        .get( &url )
-        .header( "x-api-key", &self.client.secret().ANTHROPIC_API_KEY )
-        .header( "anthropic-version", "2023-06-01" )
-        .header( "content-type", "application/json" )
-        .send()
-        .await
-        .map_err( |e| AnthropicError::http_error( format!( "Failed to fetch models : {e}" ) ) )?;
-
-      if !response.status().is_success()
-      {
-        return Err( AnthropicError::http_error_with_status( format!( "API error : {}", response.status() ), response.status().as_u16() ) );
-      }
-
-      let models_response : ModelsApiResponse = response
-        .json()
-        .await
-        .map_err( |e| AnthropicError::Parsing( format!( "Failed to parse models response : {e}" ) ) )?;
-
-      // Convert API response to our internal format
-      let models = models_response.data.into_iter()
-        .map( |api_model| ModelInfo {
-          id : api_model.id.clone(),
-          display_name : api_model.display_name.unwrap_or_else( || api_model.id.clone() ),
-          name : api_model.id,
-          max_tokens : api_model.max_tokens.unwrap_or( 200_000 ),
-          context_length : api_model.context_length.unwrap_or( 200_000 ),
-          created_at : api_model.created,
-          supports_tools : api_model.capabilities.contains( &"tools".to_string() ),
-          supports_vision : api_model.capabilities.contains( &"vision".to_string() ),
-          version : api_model.version,
+      let models = self.client.models().list_all().await?
+        .into_iter()
+        .map( | model | ModelInfo {
+          id : model.id.clone(),
+          display_name : model.display_name,
+          name : model.id,
+          max_tokens : 200_000,
+          context_length : 200_000,
+          created_at : Some( model.created_at ),
+          // Every model the API lists accepts tools and images
+          supports_tools : true,
+          supports_vision : true,
+          version : None,
         })
         .collect();
 
@@ -132,6 +132,7 @@ mod private
           {
             r#type : "text".to_string(),
             text : user_message.into(),
+            cache_control : None,
           } ],
           cache_control : None,
         } ],
@@ -143,6 +144,7 @@ mod private
         } ] ),
         temperature : self.temperature,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
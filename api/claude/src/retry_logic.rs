@@ -61,6 +61,7 @@ mod private
     }
   }
 
+  use api_common::BudgetTracker;
   use core::time::Duration;
   use std::collections::HashMap;
 
@@ -556,46 +557,113 @@ mod private
     where
       F: Fn() -> Fut,
       Fut : core::future::Future< Output = AnthropicResult< T > >,
+    {
+      self.execute_inner( operation, None ).await
+    }
+
+    /// Execute operation with retry logic inside an overall call budget
+    ///
+    /// Every attempt counts against `budget`, attempts are cut off at its
+    /// total deadline, and no retry is scheduled past it. Hand the same
+    /// tracker to an enclosing failover layer so both respect one limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if all retry attempts fail or the budget is exhausted
+    pub async fn execute_within< F, Fut, T >( &self, budget : &BudgetTracker, operation : F ) -> AnthropicResult< T >
+    where
+      F: Fn() -> Fut,
+      Fut : core::future::Future< Output = AnthropicResult< T > >,
+    {
+      self.execute_inner( operation, Some( budget ) ).await
+    }
+
+    /// Execute operation inside an overall call budget, waiting between
+    /// attempts as `pace` decides
+    ///
+    /// `pace` gets each failure with its attempt number (1-based) and returns
+    /// the wait before the next attempt, or `None` to return the failure.
+    /// Use it when the failure itself says how long to wait, such as a
+    /// response's `retry-after` header. The budget applies as in
+    /// [`execute_within`](Self::execute_within).
+    ///
+    /// # Errors
+    ///
+    /// Returns the failure `pace` gives up on, the last failure when the next
+    /// wait would pass the deadline, or the exhausted budget
+    pub async fn execute_paced_within< F, Fut, T, E, P >( &self, budget : &BudgetTracker, operation : F, pace : P ) -> Result< T, E >
+    where
+      F: Fn() -> Fut,
+      Fut : core::future::Future< Output = Result< T, E > >,
+      P : Fn( &E, u32 ) -> Option< Duration >,
+      E : From< api_common::BudgetExhausted >,
+    {
+      Self::run( operation, Some( budget ), pace ).await
+    }
+
+    async fn execute_inner< F, Fut, T >( &self, operation : F, budget : Option< &BudgetTracker > ) -> AnthropicResult< T >
+    where
+      F: Fn() -> Fut,
+      Fut : core::future::Future< Output = AnthropicResult< T > >,
+    {
+      Self::run( operation, budget, | error, attempt | self.delay_for( error, attempt ) ).await
+    }
+
+    /// Wait before retrying `error`, or `None` when the strategy gives up
+    fn delay_for( &self, error : &AnthropicError, attempt : u32 ) -> Option< Duration >
+    {
+      if !self.strategy.should_retry( error, attempt )
+      {
+        return None;
+      }
+
+      #[ cfg( feature = "error-handling" ) ]
+      let delay_ms = match error
+      {
+        AnthropicError::RateLimit( rate_limit_error ) => self.strategy.calculate_delay_for_error( rate_limit_error, attempt ),
+        _ => self.strategy.calculate_delay( attempt ),
+      };
+
+      #[ cfg( not( feature = "error-handling" ) ) ]
+      let delay_ms = self.strategy.calculate_delay( attempt );
+
+      Some( Duration::from_millis( delay_ms ) )
+    }
+
+    async fn run< F, Fut, T, E, P >( operation : F, budget : Option< &BudgetTracker >, pace : P ) -> Result< T, E >
+    where
+      F: Fn() -> Fut,
+      Fut : core::future::Future< Output = Result< T, E > >,
+      P : Fn( &E, u32 ) -> Option< Duration >,
+      E : From< api_common::BudgetExhausted >,
     {
       let mut attempt = 1;
 
       loop
       {
-        match operation().await
+        let outcome = match budget
+        {
+          Some( budget ) => budget.run_attempt( operation() ).await?,
+          None => operation().await,
+        };
+
+        let error = match outcome
         {
           Ok( result ) => return Ok( result ),
-          Err( error ) =>
-          {
-            // Check if we should retry
-            if !self.strategy.should_retry( &error, attempt )
-            {
-              return Err( error );
-            }
+          Err( error ) => error,
+        };
 
-            // Calculate and apply delay
-            let delay_ms = {
-              #[ cfg( feature = "error-handling" ) ]
-              {
-                match &error
-                {
-                  AnthropicError::RateLimit( rate_limit_error ) =>
-                  {
-                    self.strategy.calculate_delay_for_error( rate_limit_error, attempt )
-                  },
-                  _ => self.strategy.calculate_delay( attempt ),
-                }
-              }
-
-              #[ cfg( not( feature = "error-handling" ) ) ]
-              {
-                self.strategy.calculate_delay( attempt )
-              }
-            };
-
-            tokio::time::sleep( Duration::from_millis( delay_ms ) ).await;
-            attempt += 1;
-          }
+        let Some( delay ) = pace( &error, attempt ) else
+        {
+          return Err( error );
+        };
+        if budget.is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+        {
+          return Err( error );
         }
+
+        api_common::sleep( delay ).await;
+        attempt += 1;
       }
     }
   }
@@ -798,6 +866,94 @@ mod private
       self.error_counts.clear();
     }
   }
+
+  /// Retry guidance read from a failed `/v1/messages` response
+  ///
+  /// Holds the status, the `retry-after` header, and the
+  /// `anthropic-ratelimit-*` headers so the wait before the next attempt
+  /// follows what the API asked for rather than a guess.
+  #[ cfg( feature = "retry" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct RetryPacing
+  {
+    /// HTTP status of the failed response
+    pub status : u16,
+    /// Wait requested by the `retry-after` header
+    pub retry_after : Option< Duration >,
+    /// Rate limit headers sent with the response
+    pub rate_limit : crate::AnthropicRateLimitInfo,
+  }
+
+  #[ cfg( feature = "retry" ) ]
+  impl RetryPacing
+  {
+    /// Read the pacing headers of a response
+    #[ must_use ]
+    pub fn from_headers( status : u16, headers : &reqwest::header::HeaderMap ) -> Self
+    {
+      let retry_after = headers.get( "retry-after" )
+        .and_then( | v | v.to_str().ok() )
+        .and_then( | s | s.trim().parse::< f64 >().ok() )
+        .filter( | seconds | seconds.is_finite() && *seconds >= 0.0 )
+        .map( Duration::from_secs_f64 );
+
+      Self { status, retry_after, rate_limit : crate::AnthropicRateLimitInfo::from_headers( headers ) }
+    }
+
+    /// Whether the status is worth retrying: 429, 529 (overloaded), or another 5xx
+    #[ must_use ]
+    pub fn is_retryable( &self ) -> bool
+    {
+      self.status == 429 || ( 500..600 ).contains( &self.status )
+    }
+
+    /// Time until the exhausted rate limit window resets, if the headers say so
+    ///
+    /// Only limits whose remaining count is zero are considered; when both
+    /// requests and tokens are exhausted the later reset wins.
+    #[ must_use ]
+    pub fn reset_wait( &self ) -> Option< Duration >
+    {
+      let info = &self.rate_limit;
+      let now = chrono::Utc::now();
+      [
+        ( info.requests_remaining, info.requests_reset.as_deref() ),
+        ( info.tokens_remaining, info.tokens_reset.as_deref() ),
+      ]
+      .into_iter()
+      .filter( | ( remaining, _ ) | *remaining == Some( 0 ) )
+      .filter_map( | ( _, reset ) | chrono::DateTime::parse_from_rfc3339( reset? ).ok() )
+      .map( | reset | ( reset.with_timezone( &chrono::Utc ) - now ).to_std().unwrap_or_default() )
+      .max()
+    }
+
+    /// Wait before retry number `attempt` (1-based), or `None` to give up
+    ///
+    /// `retry-after` wins, then the reset time of an exhausted limit, then
+    /// the exponential backoff of `config`. Gives up when the status is not
+    /// retryable, `attempt` has used up `max_attempts`, or the server asks
+    /// for a longer wait than `max_delay_ms` allows.
+    #[ must_use ]
+    pub fn delay( &self, config : &RetryConfig, attempt : u32 ) -> Option< Duration >
+    {
+      if !self.is_retryable() || attempt >= config.max_attempts
+      {
+        return None;
+      }
+
+      let max_delay = Duration::from_millis( config.max_delay_ms );
+      match self.retry_after.or_else( || self.reset_wait() )
+      {
+        Some( wait ) if wait > max_delay => None,
+        Some( wait ) => Some( wait ),
+        None =>
+        {
+          let strategy = RetryStrategy::exponential_backoff_with_config( config.clone() );
+          Some( Duration::from_millis( strategy.calculate_delay( attempt ) ) )
+        },
+      }
+    }
+  }
 }
 
 #[ cfg( feature = "retry-logic" ) ]
@@ -815,4 +971,7 @@ crate::mod_interface!
 
   // Metrics
   exposed use RetryMetrics;
+
+  #[ cfg( feature = "retry" ) ]
+  exposed use RetryPacing;
 }
\ No newline at end of file
@@ -36,7 +36,7 @@ mod private
     fn fmt( &self, f : &mut std::fmt::Formatter< '_ > ) -> std::fmt::Result
     {
       f.debug_struct( "Secret" )
-        .field( "ANTHROPIC_API_KEY", &"< REDACTED >" )
+        .field( "ANTHROPIC_API_KEY", &api_secret::Masked( &self.ANTHROPIC_API_KEY ) )
         .finish()
     }
   }
@@ -298,6 +298,39 @@ mod private
       }
     }
 
+    /// Load secret through the fallback chain shared by every `api_llm`
+    /// provider crate: workspace `secret/-secrets.sh`, `secrets.sh`, `.env`,
+    /// then the environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing every source tried if none holds the key,
+    /// or if the API key is invalid
+    #[ inline ]
+    pub fn load_with_fallbacks( key_name : &str ) -> Result< Self >
+    {
+      Self::load_from_chain( key_name, &api_secret::SecretChain::standard() )
+    }
+
+    /// Load secret from a caller-built chain, e.g. a Vault or AWS Secrets
+    /// Manager fetch placed ahead of the standard chain
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no source in `chain` holds the key or the API key is invalid
+    #[ inline ]
+    pub fn load_from_chain( key_name : &str, chain : &api_secret::SecretChain ) -> Result< Self >
+    {
+      use api_secret::ExposeSecret;
+
+      let api_key = chain.load( key_name )
+        .map_err( | e | error_tools::Error::msg(
+          format!( "{e}\nHint : Add a line : export {key_name}=\"your-api-key\" to secret/-secrets.sh or set the environment variable" )
+        ) )?;
+
+      Self::new( api_key.expose_secret().trim().to_string() )
+    }
+
     /// Load secret from workspace with default settings
     ///
     /// # Errors
@@ -0,0 +1,413 @@
+//! Delta accumulator for streamed messages
+//!
+//! Folds the events of one message stream into the `CreateMessageResponse`
+//! the same request would have returned without streaming.
+
+#[ cfg( feature = "streaming" ) ]
+mod private
+{
+  use super::super::types::orphan::*;
+  #[ cfg( feature = "error-handling" ) ]
+  use crate::error::{ AnthropicError, AnthropicResult };
+
+  #[ cfg( not( feature = "error-handling" ) ) ]
+  type AnthropicError = crate::error_tools::Error;
+  #[ cfg( not( feature = "error-handling" ) ) ]
+  type AnthropicResult< T > = Result< T, crate::error_tools::Error >;
+
+  use crate::client::{ CreateMessageResponse, ResponseContent };
+  use futures::{ Stream, StreamExt };
+
+  /// Builds the error returned for an event out of sequence
+  fn sequence_error( message : String ) -> AnthropicError
+  {
+    #[ cfg( feature = "error-handling" ) ]
+    return AnthropicError::Parsing( message );
+    #[ cfg( not( feature = "error-handling" ) ) ]
+    return crate::error_tools::Error::msg( message );
+  }
+
+  /// A content block being assembled from its deltas
+  #[ derive( Debug, Clone ) ]
+  enum BlockState
+  {
+    /// Text block with the text and citations received so far
+    Text
+    {
+      text : String,
+      citations : Vec< crate::Citation >,
+    },
+    /// Thinking block with the reasoning and signature received so far
+    Thinking
+    {
+      thinking : String,
+      signature : String,
+    },
+    /// Redacted thinking block
+    RedactedThinking
+    {
+      data : String,
+    },
+    /// Tool use or server tool use block; `partial_json` holds the input
+    /// until the block stops
+    #[ cfg( feature = "tools" ) ]
+    ToolUse
+    {
+      r#type : String,
+      id : String,
+      name : String,
+      input : serde_json::Value,
+      partial_json : String,
+    },
+    /// Result of a tool Anthropic ran
+    #[ cfg( feature = "tools" ) ]
+    ServerToolResult
+    {
+      r#type : String,
+      tool_use_id : String,
+      content : crate::ServerToolResultContent,
+    },
+  }
+
+  impl BlockState
+  {
+    /// State of a block from its `content_block_start`
+    fn start( content_block : &StreamContentBlock ) -> Self
+    {
+      match content_block
+      {
+        StreamContentBlock::Text { text, .. } => BlockState::Text { text : text.clone(), citations : Vec::new() },
+        StreamContentBlock::Thinking { thinking, signature, .. } => BlockState::Thinking
+        {
+          thinking : thinking.clone(),
+          signature : signature.clone(),
+        },
+        StreamContentBlock::RedactedThinking { data, .. } => BlockState::RedactedThinking { data : data.clone() },
+        #[ cfg( feature = "tools" ) ]
+        StreamContentBlock::ToolUse { r#type, id, name, input } => BlockState::ToolUse
+        {
+          r#type : r#type.clone(),
+          id : id.clone(),
+          name : name.clone(),
+          input : input.clone(),
+          partial_json : String::new(),
+        },
+        #[ cfg( feature = "tools" ) ]
+        StreamContentBlock::ServerToolResult { r#type, tool_use_id, content } => BlockState::ServerToolResult
+        {
+          r#type : r#type.clone(),
+          tool_use_id : tool_use_id.clone(),
+          content : content.clone(),
+        },
+      }
+    }
+  }
+
+  /// Accumulates stream events into a complete message
+  ///
+  /// Text deltas are appended to their block and `citations_delta` citations
+  /// collected with it, `thinking_delta` and `signature_delta` complete their
+  /// thinking block, `input_json_delta` fragments are parsed into the tool
+  /// input when the block stops, and `message_delta` supplies the stop reason
+  /// and the final output token count. `ping` events are ignored; an `error`
+  /// event is returned as an error.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// use api_claude::{ Client, CreateMessageRequest, Message, MessageAccumulator };
+  ///
+  /// let client = Client::from_workspace()?;
+  /// let request = CreateMessageRequest::builder()
+  ///   .model( "claude-sonnet-4-6" )
+  ///   .max_tokens( 200 )
+  ///   .message( Message::user( "Write a haiku about rivers." ) )
+  ///   .build();
+  ///
+  /// let stream = client.messages().create_stream( request ).await?;
+  /// let response = MessageAccumulator::collect( stream ).await?.response()?;
+  /// println!( "{}", response.text().unwrap_or_default() );
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct MessageAccumulator
+  {
+    /// Message from `message_start`
+    message : Option< StreamMessage >,
+    /// Content blocks by index
+    blocks : Vec< Option< BlockState > >,
+    /// `message_stop` was received
+    stopped : bool,
+  }
+
+  impl MessageAccumulator
+  {
+    /// Create an empty accumulator
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Apply every event of `stream`, stopping at the first error
+    ///
+    /// # Errors
+    ///
+    /// Returns the first transport error, `error` event, or out of sequence event
+    #[ inline ]
+    pub async fn collect< S >( stream : S ) -> AnthropicResult< Self >
+    where
+      S : Stream< Item = AnthropicResult< StreamEvent > >,
+    {
+      let mut accumulator = Self::new();
+      let mut stream = core::pin::pin!( stream );
+      while let Some( event ) = stream.next().await
+      {
+        accumulator.push( &event? )?;
+      }
+      Ok( accumulator )
+    }
+
+    /// Apply one event
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of an `error` event, an error for a block event
+    /// before `message_start` or for a block that was never started, and an
+    /// error when the accumulated input of a tool use block is not JSON
+    #[ inline ]
+    pub fn push( &mut self, event : &StreamEvent ) -> AnthropicResult< () >
+    {
+      match event
+      {
+        StreamEvent::MessageStart { message } =>
+        {
+          self.message = Some( message.clone() );
+        },
+        StreamEvent::ContentBlockStart { index, content_block } =>
+        {
+          self.started()?;
+          if self.blocks.len() <= *index
+          {
+            self.blocks.resize( *index + 1, None );
+          }
+          self.blocks[ *index ] = Some( BlockState::start( content_block ) );
+        },
+        StreamEvent::ContentBlockDelta { index, delta } =>
+        {
+          match ( self.block( *index )?, delta )
+          {
+            ( BlockState::Text { text, .. }, StreamDelta::TextDelta { text : delta, .. } ) => text.push_str( delta ),
+            ( BlockState::Text { citations, .. }, StreamDelta::CitationsDelta { citation, .. } ) => citations.push( citation.clone() ),
+            ( BlockState::Thinking { thinking, .. }, StreamDelta::ThinkingDelta { thinking : delta, .. } ) => thinking.push_str( delta ),
+            ( BlockState::Thinking { signature, .. }, StreamDelta::SignatureDelta { signature : delta, .. } ) => signature.clone_from( delta ),
+            #[ cfg( feature = "tools" ) ]
+            ( BlockState::ToolUse { partial_json, .. }, StreamDelta::InputJsonDelta { partial_json : delta, .. } ) =>
+            {
+              partial_json.push_str( delta );
+            },
+            ( _, delta ) =>
+            {
+              return Err( sequence_error( format!( "{} does not apply to content block {index}", delta.delta_type() ) ) );
+            },
+          }
+        },
+        StreamEvent::ContentBlockStop { index } =>
+        {
+          #[ cfg( feature = "tools" ) ]
+          if let BlockState::ToolUse { name, input, partial_json, .. } = self.block( *index )?
+          {
+            if !partial_json.is_empty()
+            {
+              *input = serde_json::from_str( partial_json )
+                .map_err( | e | sequence_error( format!( "Invalid input for tool '{name}': {e}" ) ) )?;
+              partial_json.clear();
+            }
+          }
+          #[ cfg( not( feature = "tools" ) ) ]
+          self.block( *index )?;
+        },
+        StreamEvent::MessageDelta { delta, usage } =>
+        {
+          let message = self.started()?;
+          message.stop_reason.clone_from( &delta.stop_reason );
+          message.stop_sequence.clone_from( &delta.stop_sequence );
+          message.usage.output_tokens = usage.output_tokens;
+          if let Some( tokens ) = usage.input_tokens
+          {
+            message.usage.input_tokens = tokens;
+          }
+          if usage.cache_creation_input_tokens.is_some()
+          {
+            message.usage.cache_creation_input_tokens = usage.cache_creation_input_tokens;
+          }
+          if usage.cache_read_input_tokens.is_some()
+          {
+            message.usage.cache_read_input_tokens = usage.cache_read_input_tokens;
+          }
+        },
+        StreamEvent::MessageStop =>
+        {
+          self.started()?;
+          self.stopped = true;
+        },
+        StreamEvent::Ping => {},
+        StreamEvent::Error { error } => return Err( error.clone() ),
+      }
+      Ok( () )
+    }
+
+    /// Check whether `message_stop` has been received
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_complete( &self ) -> bool
+    {
+      self.stopped
+    }
+
+    /// Text of all text blocks received so far, in block order
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( &self ) -> String
+    {
+      self.blocks.iter()
+        .filter_map( | block | match block
+        {
+          Some( BlockState::Text { text, .. } ) => Some( text.as_str() ),
+          _ => None,
+        } )
+        .collect()
+    }
+
+    /// Reasoning of all thinking blocks received so far, in block order
+    #[ inline ]
+    #[ must_use ]
+    pub fn thinking( &self ) -> String
+    {
+      self.blocks.iter()
+        .filter_map( | block | match block
+        {
+          Some( BlockState::Thinking { thinking, .. } ) => Some( thinking.as_str() ),
+          _ => None,
+        } )
+        .collect()
+    }
+
+    /// Tool calls of the message for the caller to run, in block order
+    ///
+    /// Calls of tools Anthropic runs (`server_tool_use`) are left out. A block
+    /// that has not stopped yet carries the input from its start.
+    #[ cfg( feature = "tools" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn tool_uses( &self ) -> Vec< crate::ToolUseContent >
+    {
+      self.blocks.iter()
+        .filter_map( | block | match block
+        {
+          Some( BlockState::ToolUse { r#type, id, name, input, .. } ) if r#type == "tool_use" => Some( crate::ToolUseContent
+          {
+            r#type : "tool_use".to_string(),
+            id : id.clone(),
+            name : name.clone(),
+            input : input.clone(),
+          } ),
+          _ => None,
+        } )
+        .collect()
+    }
+
+    /// The message accumulated so far as a non-streaming response
+    ///
+    /// Tool use blocks carry the input parsed so far, as in the non-streaming
+    /// response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no `message_start` event has been received
+    #[ inline ]
+    pub fn response( &self ) -> AnthropicResult< CreateMessageResponse >
+    {
+      let message = self.message.as_ref()
+        .ok_or_else( || sequence_error( "No message_start event received".to_string() ) )?;
+      let content = self.blocks.iter()
+        .flatten()
+        .map( | block | match block
+        {
+          BlockState::Text { text, citations } => ResponseContent
+          {
+            r#type : "text".to_string(),
+            text : Some( text.clone() ),
+            citations : ( !citations.is_empty() ).then( || citations.clone() ),
+            ..Default::default()
+          },
+          BlockState::Thinking { thinking, signature } => ResponseContent
+          {
+            r#type : "thinking".to_string(),
+            thinking : Some( thinking.clone() ),
+            signature : Some( signature.clone() ),
+            ..Default::default()
+          },
+          BlockState::RedactedThinking { data } => ResponseContent
+          {
+            r#type : "redacted_thinking".to_string(),
+            data : Some( data.clone() ),
+            ..Default::default()
+          },
+          #[ cfg( feature = "tools" ) ]
+          BlockState::ToolUse { r#type, id, name, input, .. } => ResponseContent
+          {
+            r#type : r#type.clone(),
+            id : Some( id.clone() ),
+            name : Some( name.clone() ),
+            input : Some( input.clone() ),
+            ..Default::default()
+          },
+          #[ cfg( feature = "tools" ) ]
+          BlockState::ServerToolResult { r#type, tool_use_id, content } => ResponseContent
+          {
+            r#type : r#type.clone(),
+            tool_use_id : Some( tool_use_id.clone() ),
+            content : Some( content.clone() ),
+            ..Default::default()
+          },
+        } )
+        .collect();
+      Ok( CreateMessageResponse
+      {
+        id : message.id.clone(),
+        r#type : message.r#type.clone(),
+        role : message.role.clone(),
+        content,
+        model : message.model.clone(),
+        stop_reason : message.stop_reason.clone(),
+        stop_sequence : message.stop_sequence.clone(),
+        usage : message.usage.clone(),
+      } )
+    }
+
+    /// The message from `message_start`, or an error before it
+    fn started( &mut self ) -> AnthropicResult< &mut StreamMessage >
+    {
+      self.message.as_mut()
+        .ok_or_else( || sequence_error( "Event received before message_start".to_string() ) )
+    }
+
+    /// The started block at `index`
+    fn block( &mut self, index : usize ) -> AnthropicResult< &mut BlockState >
+    {
+      self.blocks.get_mut( index )
+        .and_then( Option::as_mut )
+        .ok_or_else( || sequence_error( format!( "Content block {index} was not started" ) ) )
+    }
+  }
+}
+
+#[ cfg( feature = "streaming" ) ]
+crate::mod_interface!
+{
+  exposed use MessageAccumulator;
+}
@@ -22,6 +22,10 @@ mod private
   {
     /// Create a streaming message request
     ///
+    /// Sends the request with `stream : true` and decodes the Server-Sent
+    /// Events as they arrive. Events of unknown types are skipped; a read
+    /// failure ends the stream with an error.
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails, network issues occur, or response parsing fails
@@ -29,10 +33,14 @@ mod private
     /// # Panics
     ///
     /// Panics if header values are invalid (should not happen with known valid values)
-    pub async fn create_message_stream( &self, request : CreateMessageRequest ) -> AnthropicResult< EventStream >
+    pub async fn create_message_stream( &self, mut request : CreateMessageRequest ) -> AnthropicResult< EventStream >
     {
+      use api_sse_stream::SseStreamExt;
+      use futures::StreamExt;
       use tokio_stream::wrappers::UnboundedReceiverStream;
-      
+
+      request.stream = Some( true );
+
       // Validate the request
       request.validate()?;
       
@@ -52,6 +60,13 @@ mod private
         "anthropic-version", 
         self.config().api_version.parse().expect( "Valid API version" ) 
       );
+      if !self.config().beta_features.is_empty()
+      {
+        headers.insert(
+          "anthropic-beta",
+          self.config().beta_features.join( "," ).parse().expect( "Valid beta features" )
+        );
+      }
       headers.insert(
         "Accept",
         "text/event-stream".parse().expect( "Valid accept header" )
@@ -62,8 +77,10 @@ mod private
       );
       
       // Create HTTP client with timeout
-      let http_client = reqwest::Client::builder()
-        .timeout( self.config().request_timeout )
+      let builder = reqwest::Client::builder();
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let builder = builder.timeout( self.config().request_timeout );
+      let http_client = builder
         .build()
         .map_err( | e | AnthropicError::http_error( format!( "Failed to build HTTP client : {e}" ) ) )?;
       
@@ -93,42 +110,32 @@ mod private
       // Create a channel for the event stream
       let ( tx, rx ) = tokio::sync::mpsc::unbounded_channel();
       
-      // Spawn a task to handle the SSE stream
-      let _handle = tokio::spawn( async move
+      // Spawn a task to decode the SSE stream as chunks arrive
+      let task = async move
       {
-        // Read response text line by line
-        let text = match response.text().await
+        let mut events = response.bytes_stream().sse_events();
+        while let Some( event ) = events.next().await
         {
-          Ok( text ) => text,
-          Err( e ) =>
+          let event = match event
           {
-            let error = AnthropicError::http_error( format!( "Failed to read response : {e}" ) );
-            let _ = tx.send( Err( error ) );
-            return;
-          }
-        };
-        
-        // For now, treat the entire response as SSE data
-        // In a real implementation, we'd process the stream incrementally
-        match parse_sse_events( &text )
-        {
-          Ok( events ) =>
-          {
-            for event in events
+            Ok( event ) => event,
+            Err( e ) =>
             {
-              if tx.send( Ok( event ) ).is_err()
-              {
-                // Receiver dropped, stop processing
-                return;
-              }
+              let error = AnthropicError::http_error( format!( "Failed to read response : {e}" ) );
+              let _ = tx.send( Err( error ) );
+              return;
             }
-          },
-          Err( e ) =>
+          };
+          // Unknown event types are skipped, as in `parse_sse_events`
+          let Ok( event ) = parse_sse_event( &event ) else { continue };
+          if tx.send( Ok( event ) ).is_err()
           {
-            let _ = tx.send( Err( e ) );
+            // Receiver dropped, stop processing
+            return;
           }
         }
-      } );
+      };
+      let _handle = api_common::spawn( task );
       
       // Convert the receiver to a stream
       let stream = UnboundedReceiverStream::new( rx );
@@ -160,6 +160,26 @@ mod private
       /// Text content
       text : String,
     },
+    /// Extended thinking block; the reasoning arrives in `thinking_delta`s
+    /// and the signature in a final `signature_delta`
+    Thinking
+    {
+      /// Type field
+      r#type : String,
+      /// Reasoning received with the block start
+      thinking : String,
+      /// Signature received with the block start
+      #[ serde( default ) ]
+      signature : String,
+    },
+    /// Redacted thinking block, complete at its start
+    RedactedThinking
+    {
+      /// Type field
+      r#type : String,
+      /// Encrypted reasoning
+      data : String,
+    },
     /// Tool use content block
     #[ cfg( feature = "tools" ) ]
     ToolUse
@@ -173,6 +193,17 @@ mod private
       /// Tool input
       input : serde_json::Value,
     },
+    /// Result of a tool Anthropic ran, complete at its start
+    #[ cfg( feature = "tools" ) ]
+    ServerToolResult
+    {
+      /// Type field, e.g. "`web_search_tool_result`"
+      r#type : String,
+      /// ID of the `server_tool_use` block this result answers
+      tool_use_id : String,
+      /// Search results, execution output, or error
+      content : crate::ServerToolResultContent,
+    },
   }
 
   impl StreamContentBlock
@@ -212,9 +243,12 @@ mod private
     {
       match self
       {
-        StreamContentBlock::Text { r#type, .. } => r#type,
+        StreamContentBlock::Text { r#type, .. } |
+        StreamContentBlock::Thinking { r#type, .. } |
+        StreamContentBlock::RedactedThinking { r#type, .. } => r#type,
         #[ cfg( feature = "tools" ) ]
-        StreamContentBlock::ToolUse { r#type, .. } => r#type,
+        StreamContentBlock::ToolUse { r#type, .. } |
+        StreamContentBlock::ServerToolResult { r#type, .. } => r#type,
       }
     }
 
@@ -226,6 +260,14 @@ mod private
       matches!( self, StreamContentBlock::Text { .. } )
     }
 
+    /// Check if this is a thinking or redacted thinking block
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_thinking( &self ) -> bool
+    {
+      matches!( self, StreamContentBlock::Thinking { .. } | StreamContentBlock::RedactedThinking { .. } )
+    }
+
     /// Check if this is a tool use content block
     #[ cfg( feature = "tools" ) ]
     #[ inline ]
@@ -235,6 +277,15 @@ mod private
       matches!( self, StreamContentBlock::ToolUse { .. } )
     }
 
+    /// Check if this is the result of a tool Anthropic ran
+    #[ cfg( feature = "tools" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_server_tool_result( &self ) -> bool
+    {
+      matches!( self, StreamContentBlock::ServerToolResult { .. } )
+    }
+
     /// Get text content if this is a text block
     #[ inline ]
     #[ must_use ]
@@ -243,8 +294,7 @@ mod private
       match self
       {
         StreamContentBlock::Text { text, .. } => Some( text ),
-        #[ cfg( feature = "tools" ) ]
-        StreamContentBlock::ToolUse { .. } => None,
+        _ => None,
       }
     }
 
@@ -256,8 +306,8 @@ mod private
     {
       match self
       {
-        StreamContentBlock::Text { .. } => None,
         StreamContentBlock::ToolUse { name, .. } => Some( name ),
+        _ => None,
       }
     }
 
@@ -283,10 +333,30 @@ mod private
 
           // Note : Empty text is allowed in streaming scenarios as content starts empty and gets deltas
         },
+        StreamContentBlock::Thinking { r#type, .. } =>
+        {
+          if r#type != "thinking"
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid thinking content type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid thinking content type : '{type}'" ) ) );
+          }
+        },
+        StreamContentBlock::RedactedThinking { r#type, .. } =>
+        {
+          if r#type != "redacted_thinking"
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid redacted thinking content type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid redacted thinking content type : '{type}'" ) ) );
+          }
+        },
         #[ cfg( feature = "tools" ) ]
         StreamContentBlock::ToolUse { r#type, id, name, .. } =>
         {
-          if r#type != "tool_use"
+          if r#type != "tool_use" && r#type != "server_tool_use"
           {
             #[ cfg( feature = "error-handling" ) ]
             return Err( AnthropicError::InvalidArgument( format!( "Invalid tool use content type : '{type}'" ) ) );
@@ -309,7 +379,26 @@ mod private
             #[ cfg( not( feature = "error-handling" ) ) ]
             return Err( crate::error_tools::Error::msg( "Tool name cannot be empty" ) );
           }
-        }
+        },
+        #[ cfg( feature = "tools" ) ]
+        StreamContentBlock::ServerToolResult { r#type, tool_use_id, .. } =>
+        {
+          if !r#type.ends_with( "_tool_result" )
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid server tool result content type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid server tool result content type : '{type}'" ) ) );
+          }
+
+          if tool_use_id.is_empty()
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( "Server tool result tool_use_id cannot be empty".to_string() ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( "Server tool result tool_use_id cannot be empty" ) );
+          }
+        },
       }
 
       Ok( () )
@@ -338,6 +427,30 @@ mod private
       /// Partial JSON input
       partial_json : String,
     },
+    /// Citation added to a text block
+    CitationsDelta
+    {
+      /// Type field
+      r#type : String,
+      /// The cited document passage
+      citation : crate::Citation,
+    },
+    /// Reasoning appended to a thinking block
+    ThinkingDelta
+    {
+      /// Type field
+      r#type : String,
+      /// Reasoning delta
+      thinking : String,
+    },
+    /// Signature of a thinking block, sent just before the block stops
+    SignatureDelta
+    {
+      /// Type field
+      r#type : String,
+      /// The block's signature
+      signature : String,
+    },
   }
 
   impl StreamDelta
@@ -375,7 +488,10 @@ mod private
     {
       match self
       {
-        StreamDelta::TextDelta { r#type, .. } => r#type,
+        StreamDelta::TextDelta { r#type, .. } |
+        StreamDelta::CitationsDelta { r#type, .. } |
+        StreamDelta::ThinkingDelta { r#type, .. } |
+        StreamDelta::SignatureDelta { r#type, .. } => r#type,
         #[ cfg( feature = "tools" ) ]
         StreamDelta::InputJsonDelta { r#type, .. } => r#type,
       }
@@ -406,8 +522,19 @@ mod private
       match self
       {
         StreamDelta::TextDelta { text, .. } => Some( text ),
-        #[ cfg( feature = "tools" ) ]
-        StreamDelta::InputJsonDelta { .. } => None,
+        _ => None,
+      }
+    }
+
+    /// Get the reasoning if this is a thinking delta
+    #[ inline ]
+    #[ must_use ]
+    pub fn thinking( &self ) -> Option< &str >
+    {
+      match self
+      {
+        StreamDelta::ThinkingDelta { thinking, .. } => Some( thinking ),
+        _ => None,
       }
     }
 
@@ -419,8 +546,8 @@ mod private
     {
       match self
       {
-        StreamDelta::TextDelta { .. } => None,
         StreamDelta::InputJsonDelta { partial_json, .. } => Some( partial_json ),
+        _ => None,
       }
     }
 
@@ -464,6 +591,44 @@ mod private
             #[ cfg( not( feature = "error-handling" ) ) ]
             return Err( crate::error_tools::Error::msg( "Partial JSON cannot be empty" ) );
           }
+        },
+        StreamDelta::CitationsDelta { r#type, .. } =>
+        {
+          if r#type != "citations_delta"
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid citations delta type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid citations delta type : '{type}'" ) ) );
+          }
+        },
+        StreamDelta::ThinkingDelta { r#type, .. } =>
+        {
+          if r#type != "thinking_delta"
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid thinking delta type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid thinking delta type : '{type}'" ) ) );
+          }
+        },
+        StreamDelta::SignatureDelta { r#type, signature } =>
+        {
+          if r#type != "signature_delta"
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( format!( "Invalid signature delta type : '{type}'" ) ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( format!( "Invalid signature delta type : '{type}'" ) ) );
+          }
+
+          if signature.is_empty()
+          {
+            #[ cfg( feature = "error-handling" ) ]
+            return Err( AnthropicError::InvalidArgument( "Signature cannot be empty".to_string() ) );
+            #[ cfg( not( feature = "error-handling" ) ) ]
+            return Err( crate::error_tools::Error::msg( "Signature cannot be empty" ) );
+          }
         }
       }
 
@@ -471,6 +636,38 @@ mod private
     }
   }
 
+  /// Top-level changes carried by a `message_delta` event
+  #[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+  pub struct StreamMessageDelta
+  {
+    /// Why generation stopped (`end_turn`, `max_tokens`, `stop_sequence`, `tool_use`)
+    #[ serde( default ) ]
+    pub stop_reason : Option< String >,
+    /// The stop sequence that was matched, if any
+    #[ serde( default ) ]
+    pub stop_sequence : Option< String >,
+  }
+
+  /// Usage reported by a `message_delta` event
+  ///
+  /// Counts are cumulative for the whole message, not increments.
+  #[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+  pub struct StreamUsageDelta
+  {
+    /// Output tokens generated so far
+    #[ serde( default ) ]
+    pub output_tokens : u32,
+    /// Input tokens, when the API repeats them
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub input_tokens : Option< u32 >,
+    /// Cache creation input tokens, when the API repeats them
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub cache_creation_input_tokens : Option< u32 >,
+    /// Cache read input tokens, when the API repeats them
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub cache_read_input_tokens : Option< u32 >,
+  }
+
   /// Streaming events from Server-Sent Events
   #[ derive( Debug, Clone ) ]
   pub enum StreamEvent
@@ -503,8 +700,18 @@ mod private
       /// Index of the content block
       index : usize,
     },
+    /// Message delta event with the stop reason and final usage
+    MessageDelta
+    {
+      /// Stop reason and stop sequence
+      delta : StreamMessageDelta,
+      /// Cumulative usage
+      usage : StreamUsageDelta,
+    },
     /// Message stop event
     MessageStop,
+    /// Keep-alive event
+    Ping,
     /// Error event
     Error
     {
@@ -547,6 +754,14 @@ mod private
       Self::ContentBlockStop { index }
     }
 
+    /// Create a message delta event
+    #[ inline ]
+    #[ must_use ]
+    pub fn message_delta( delta : StreamMessageDelta, usage : StreamUsageDelta ) -> Self
+    {
+      Self::MessageDelta { delta, usage }
+    }
+
     /// Create a message stop event
     #[ inline ]
     #[ must_use ]
@@ -595,6 +810,14 @@ mod private
       matches!( self, StreamEvent::ContentBlockStop { .. } )
     }
 
+    /// Check if this is a message delta event
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_message_delta( &self ) -> bool
+    {
+      matches!( self, StreamEvent::MessageDelta { .. } )
+    }
+
     /// Check if this is a message stop event
     #[ inline ]
     #[ must_use ]
@@ -603,6 +826,14 @@ mod private
       matches!( self, StreamEvent::MessageStop )
     }
 
+    /// Check if this is a ping event
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_ping( &self ) -> bool
+    {
+      matches!( self, StreamEvent::Ping )
+    }
+
     /// Check if this is an error event
     #[ inline ]
     #[ must_use ]
@@ -684,7 +915,9 @@ mod private
           delta.validate()
         },
         StreamEvent::ContentBlockStop { .. } |
+        StreamEvent::MessageDelta { .. } |
         StreamEvent::MessageStop |
+        StreamEvent::Ping |
         StreamEvent::Error { .. } =>
         {
           // These events don't need validation
@@ -694,61 +927,100 @@ mod private
     }
   }
 
-  /// Parse Server-Sent Events data into stream events
+  /// Translates one Anthropic stream event into provider-neutral events.
   ///
-  /// # Errors
-  ///
-  /// Returns an error if the SSE data is malformed or contains invalid JSON
-  pub fn parse_sse_events( data : &str ) -> AnthropicResult< Vec< StreamEvent > >
+  /// `message_start` yields its usage, a `tool_use` block start yields the
+  /// call identifier and name, deltas yield text or argument fragments,
+  /// `message_delta` yields the output usage, and `message_stop` yields
+  /// `Done`. The `error` event becomes an `Err`.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for StreamEvent
   {
-    let mut events = Vec::new();
-    let mut current_event : Option< String > = None;
-    let mut current_data = String::new();
-
-    for line in data.lines()
+    #[ inline ]
+    #[ allow( clippy::match_same_arms ) ] // Different enum variants with conditional compilation
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
     {
-      let line = line.trim();
-      
-      if line.is_empty()
-      {
-        // Empty line indicates end of event
-        if let Some( event_type ) = current_event.take()
-        {
-          if let Ok( event ) = parse_single_event( &event_type, &current_data )
-          {
-            events.push( event );
-          }
-          current_data.clear();
-        }
-        continue;
-      }
+      use api_common::{ StreamEvent as Neutral, UsageUpdate };
+      #[ cfg( feature = "tools" ) ]
+      use api_common::ToolCallDelta;
 
-      if let Some( event_line ) = line.strip_prefix( "event: " )
-      {
-        current_event = Some( event_line.to_string() );
-      }
-      else if let Some( data_line ) = line.strip_prefix( "data: " )
+      let events = match self
       {
-        if !current_data.is_empty()
+        StreamEvent::MessageStart { message } => vec!
+        [
+          Neutral::UsageUpdate( UsageUpdate
+          {
+            input_tokens : Some( u64::from( message.usage.input_tokens ) ),
+            output_tokens : Some( u64::from( message.usage.output_tokens ) ),
+          }),
+        ],
+        StreamEvent::ContentBlockStart { content_block : StreamContentBlock::Text { text, .. }, .. } =>
         {
-          current_data.push( '\n' );
-        }
-        current_data.push_str( data_line );
-      }
-    }
-
-    // Handle final event if no trailing empty line
-    if let Some( event_type ) = current_event
-    {
-      if let Ok( event ) = parse_single_event( &event_type, &current_data )
-      {
-        events.push( event );
-      }
+          if text.is_empty() { Vec::new() } else { vec![ Neutral::TextDelta( text ) ] }
+        },
+        #[ cfg( feature = "tools" ) ]
+        StreamEvent::ContentBlockStart { index, content_block : StreamContentBlock::ToolUse { id, name, .. } } => vec!
+        [
+          Neutral::ToolCallDelta( ToolCallDelta { index, id : Some( id ), name : Some( name ), arguments : String::new() } ),
+        ],
+        StreamEvent::ContentBlockDelta { delta : StreamDelta::TextDelta { text, .. }, .. } => vec![ Neutral::TextDelta( text ) ],
+        #[ cfg( feature = "tools" ) ]
+        StreamEvent::ContentBlockDelta { index, delta : StreamDelta::InputJsonDelta { partial_json, .. } } => vec!
+        [
+          Neutral::ToolCallDelta( ToolCallDelta { index, id : None, name : None, arguments : partial_json } ),
+        ],
+        // Thinking is not part of the answer text, and Anthropic already ran server tools
+        #[ cfg( feature = "tools" ) ]
+        StreamEvent::ContentBlockStart { content_block : StreamContentBlock::ServerToolResult { .. }, .. } => Vec::new(),
+        StreamEvent::ContentBlockStart { content_block : StreamContentBlock::Thinking { .. } | StreamContentBlock::RedactedThinking { .. }, .. } |
+        StreamEvent::ContentBlockDelta { delta : StreamDelta::CitationsDelta { .. } | StreamDelta::ThinkingDelta { .. } | StreamDelta::SignatureDelta { .. }, .. } |
+        StreamEvent::ContentBlockStop { .. } |
+        StreamEvent::Ping => Vec::new(),
+        StreamEvent::MessageDelta { usage, .. } => vec!
+        [
+          Neutral::UsageUpdate( UsageUpdate
+          {
+            input_tokens : usage.input_tokens.map( u64::from ),
+            output_tokens : Some( u64::from( usage.output_tokens ) ),
+          }),
+        ],
+        StreamEvent::MessageStop => vec![ Neutral::Done { finish_reason : None } ],
+        #[ cfg( feature = "error-handling" ) ]
+        StreamEvent::Error { error } => return Err( error.into() ),
+        #[ cfg( not( feature = "error-handling" ) ) ]
+        StreamEvent::Error { error } => return Err( ::error_tools::format_err!( "{error}" ) ),
+      };
+      Ok( events )
     }
+  }
 
+  /// Parse Server-Sent Events data into stream events
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the SSE data is malformed or contains invalid JSON
+  pub fn parse_sse_events( data : &str ) -> AnthropicResult< Vec< StreamEvent > >
+  {
+    // Events that fail to parse (unknown types and events without an
+    // `event` field) are skipped rather than failing the whole body.
+    let events = api_sse_stream::SseDecoder::decode_all( data.as_bytes() )
+      .into_iter()
+      .filter_map( | event | parse_single_event( &event.event, &event.data ).ok() )
+      .collect();
     Ok( events )
   }
 
+  /// Parse one decoded Server-Sent Event into a stream event
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the event type is unknown or its data is malformed
+  #[ inline ]
+  pub fn parse_sse_event( event : &api_sse_stream::SseEvent ) -> AnthropicResult< StreamEvent >
+  {
+    parse_single_event( &event.event, &event.data )
+  }
+
   /// Parse a single SSE event with enhanced error handling
   #[ allow( clippy::too_many_lines ) ] // Complex parsing logic with multiple event types
   fn parse_single_event( event_type : &str, data : &str ) -> AnthropicResult< StreamEvent >
@@ -761,7 +1033,9 @@ mod private
       "content_block_start" => parse_content_block_start( data ),
       "content_block_delta" => parse_content_block_delta( data ),
       "content_block_stop" => parse_content_block_stop( data ),
+      "message_delta" => parse_message_delta( data ),
       "message_stop" => Ok( StreamEvent::MessageStop ),
+      "ping" => Ok( StreamEvent::Ping ),
       "error" => parse_error_event( data ),
       _ => parse_unknown_event( event_type ),
     }
@@ -779,7 +1053,7 @@ mod private
       return Err( crate::error_tools::Error::msg( "Event type cannot be empty" ) );
     }
 
-    if data.is_empty() && event_type != "message_stop"
+    if data.is_empty() && event_type != "message_stop" && event_type != "ping"
     {
       #[ cfg( feature = "error-handling" ) ]
       return Err( AnthropicError::InvalidArgument( format!( "Event data cannot be empty for event type : {event_type}" ) ) );
@@ -791,9 +1065,24 @@ mod private
   }
 
   /// Parse `message_start` event
+  ///
+  /// The API wraps the message as `{"type":"message_start","message":{...}}`;
+  /// a bare message is accepted too.
   fn parse_message_start( data : &str ) -> AnthropicResult< StreamEvent >
   {
-    let message : StreamMessage = serde_json::from_str( data )
+    #[ derive( Deserialize ) ]
+    #[ serde( untagged ) ]
+    enum MessageStartData
+    {
+      Wrapped { message : StreamMessage },
+      Bare( StreamMessage ),
+    }
+
+    let message = serde_json::from_str::< MessageStartData >( data )
+      .map( | event_data | match event_data
+      {
+        MessageStartData::Wrapped { message } | MessageStartData::Bare( message ) => message,
+      } )
       .map_err( | e | 
       {
         #[ cfg( feature = "error-handling" ) ]
@@ -881,12 +1170,41 @@ mod private
     Ok( StreamEvent::ContentBlockStop { index : event_data.index } )
   }
 
+  /// Parse `message_delta` event
+  fn parse_message_delta( data : &str ) -> AnthropicResult< StreamEvent >
+  {
+    #[ derive( Deserialize ) ]
+    struct MessageDeltaData
+    {
+      #[ serde( default ) ]
+      delta : StreamMessageDelta,
+      #[ serde( default ) ]
+      usage : StreamUsageDelta,
+    }
+
+    let event_data : MessageDeltaData = serde_json::from_str( data )
+      .map_err( | e |
+      {
+        #[ cfg( feature = "error-handling" ) ]
+        return AnthropicError::Parsing( format!( "Failed to parse message_delta : {e}" ) );
+        #[ cfg( not( feature = "error-handling" ) ) ]
+        return crate::error_tools::Error::msg( format!( "Failed to parse message_delta : {e}" ) );
+      } )?;
+
+    Ok( StreamEvent::MessageDelta { delta : event_data.delta, usage : event_data.usage } )
+  }
+
   /// Parse error event
+  ///
+  /// The API wraps the error as `{"type":"error","error":{...}}`; a bare
+  /// error is accepted too.
   fn parse_error_event( data : &str ) -> AnthropicResult< StreamEvent >
   {
     #[ cfg( feature = "error-handling" ) ]
     {
-      let api_error : crate::error::AnthropicApiError = serde_json::from_str( data )
+      let api_error = serde_json::from_str::< crate::error::ApiErrorWrap >( data )
+        .map( | wrap | wrap.error )
+        .or_else( | _ | serde_json::from_str::< crate::error::AnthropicApiError >( data ) )
         .map_err( | e | AnthropicError::Parsing( format!( "Failed to parse error : {e}" ) ) )?;
       Ok( StreamEvent::Error { error : AnthropicError::Api( api_error ) } )
     }
@@ -901,9 +1219,9 @@ mod private
   fn parse_unknown_event( event_type : &str ) -> AnthropicResult< StreamEvent >
   {
     #[ cfg( feature = "error-handling" ) ]
-    return Err( AnthropicError::Parsing( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_stop, error" ) ) );
+    return Err( AnthropicError::Parsing( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_delta, message_stop, ping, error" ) ) );
     #[ cfg( not( feature = "error-handling" ) ) ]
-    return Err( crate::error_tools::Error::msg( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_stop, error" ) ) );
+    return Err( crate::error_tools::Error::msg( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_delta, message_stop, ping, error" ) ) );
   }
 
   /// Stream of Server-Sent Events
@@ -918,7 +1236,10 @@ crate::mod_interface!
   exposed use StreamMessage;
   exposed use StreamContentBlock;
   exposed use StreamDelta;
+  exposed use StreamMessageDelta;
+  exposed use StreamUsageDelta;
   exposed use StreamEvent;
   exposed use EventStream;
   exposed use parse_sse_events;
+  exposed use parse_sse_event;
 }
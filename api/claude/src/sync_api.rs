@@ -27,6 +27,12 @@ mod private
     runtime : SyncRuntime,
   }
 
+  /// Blocking client for CLIs and other callers without an async runtime
+  ///
+  /// The same type as [`SyncClient`], under the name used by the `sync_api`
+  /// feature of the other provider crates.
+  pub type BlockingClient = SyncClient;
+
   /// Runtime manager for synchronous operations
   #[ derive( Debug ) ]
   pub struct SyncRuntime
@@ -46,7 +52,6 @@ mod private
   }
 
   /// Builder for configuring synchronous clients
-  #[ derive( Debug ) ]
   pub struct SyncClientBuilder
   {
     timeout : Option< Duration >,
@@ -54,6 +59,18 @@ mod private
     base_url : Option< String >,
   }
 
+  impl core::fmt::Debug for SyncClientBuilder
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "SyncClientBuilder" )
+        .field( "timeout", &self.timeout )
+        .field( "api_key", &self.api_key.as_deref().map( api_secret::Masked ) )
+        .field( "base_url", &self.base_url )
+        .finish()
+    }
+  }
+
   impl SyncRuntime
   {
     /// Create a new synchronous runtime
@@ -189,10 +206,10 @@ mod private
       }
     }
 
-    /// Get the configured timeout (placeholder)
+    /// Get the configured request timeout
     pub fn get_timeout( &self ) -> Duration
     {
-      Duration::from_secs( 30 ) // Default timeout
+      self.inner.config().request_timeout
     }
 
     /// Send a message synchronously and wait for response
@@ -257,6 +274,26 @@ mod private
     }
   }
 
+  #[ cfg( feature = "streaming" ) ]
+  impl SyncStreamIterator
+  {
+    /// Read the rest of the stream and return the message it built
+    ///
+    /// # Errors
+    ///
+    /// Returns the first transport error, `error` event, or out of sequence
+    /// event, or an error if the stream never started a message
+    pub fn into_response( self ) -> AnthropicResult< CreateMessageResponse >
+    {
+      let mut accumulator = crate::MessageAccumulator::new();
+      for event in self
+      {
+        accumulator.push( &event? )?;
+      }
+      accumulator.response()
+    }
+  }
+
   #[ cfg( feature = "streaming" ) ]
   impl Iterator for SyncStreamIterator
   {
@@ -396,6 +433,7 @@ mod private
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -488,6 +526,7 @@ crate::mod_interface!
   exposed use
   {
     SyncClient,
+    BlockingClient,
     SyncRuntime,
     SyncClientBuilder,
     SyncStreamIterator,
@@ -500,6 +539,7 @@ crate::mod_interface!
   exposed use
   {
     SyncClient,
+    BlockingClient,
     SyncRuntime,
     SyncClientBuilder,
   };
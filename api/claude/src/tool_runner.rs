@@ -0,0 +1,238 @@
+//! Tool-use loop
+//!
+//! `ToolRunner` holds the caller's async tool handlers and drives the
+//! request → `tool_use` → `tool_result` cycle until the model gives a final
+//! answer or the iteration limit is reached. Every round trip is an ordinary
+//! `Client::create_message` call; nothing is retried or hidden.
+
+mod private
+{
+  use core::future::Future;
+  use core::pin::Pin;
+  use std::collections::HashMap;
+  use std::fmt;
+  use serde_json::Value;
+  use crate::{ AnthropicError, AnthropicResult, Content, CreateMessageRequest, CreateMessageResponse, Message, Role, ToolDefinition };
+
+  /// Iterations `ToolRunner::new` allows
+  pub const DEFAULT_MAX_TOOL_ITERATIONS : u32 = 10;
+
+  /// Future returned by a tool handler: the tool output, or an error message
+  /// the model sees as a failed tool result
+  pub type ToolHandlerFuture = Pin< Box< dyn Future< Output = Result< String, String > > + Send > >;
+
+  type ToolHandler = Box< dyn Fn( Value ) -> ToolHandlerFuture + Send + Sync >;
+
+  /// Runs the tool calls of a conversation with registered async handlers
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// use api_claude::{ Client, CreateMessageRequest, Message, ToolDefinition, ToolRunner };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::from_workspace()?;
+  /// let runner = ToolRunner::new()
+  ///   .tool( ToolDefinition::simple( "get_time", "Get the current UTC time" ), | _input | async
+  ///   {
+  ///     Ok( "12:00 UTC".to_string() )
+  ///   } )
+  ///   .max_iterations( 5 );
+  ///
+  /// let request = CreateMessageRequest::builder()
+  ///   .model( "claude-sonnet-4-6" )
+  ///   .max_tokens( 500 )
+  ///   .message( Message::user( "What time is it?" ) )
+  ///   .build();
+  ///
+  /// let outcome = runner.run( &client, request ).await?;
+  /// println!( "{} after {} requests", outcome.response.text().unwrap_or_default(), outcome.iterations );
+  /// # Ok( () )
+  /// # }
+  /// ```
+  pub struct ToolRunner
+  {
+    tools : HashMap< String, ( ToolDefinition, ToolHandler ) >,
+    max_iterations : u32,
+  }
+
+  impl fmt::Debug for ToolRunner
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "ToolRunner" )
+        .field( "tools", &self.tools.keys().collect::< Vec< _ > >() )
+        .field( "max_iterations", &self.max_iterations )
+        .finish()
+    }
+  }
+
+  impl Default for ToolRunner
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Result of a tool-use loop
+  #[ derive( Debug, Clone ) ]
+  pub struct ToolRunOutcome
+  {
+    /// Last response of the model
+    pub response : CreateMessageResponse,
+    /// The conversation: the request's messages, then every assistant turn
+    /// and tool result message, ending with the last response
+    pub messages : Vec< Message >,
+    /// Number of requests sent
+    pub iterations : u32,
+  }
+
+  impl ToolRunOutcome
+  {
+    /// Check if the model finished instead of being stopped by the iteration limit
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_complete( &self ) -> bool
+    {
+      !matches!( self.response.stop_reason.as_deref(), Some( "tool_use" | "pause_turn" ) )
+    }
+  }
+
+  impl ToolRunner
+  {
+    /// Create a runner without tools, allowing [`DEFAULT_MAX_TOOL_ITERATIONS`] requests
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self { tools : HashMap::new(), max_iterations : DEFAULT_MAX_TOOL_ITERATIONS }
+    }
+
+    /// Register a tool and the async handler answering its calls
+    ///
+    /// The handler gets the call's `input`. A tool registered under the same
+    /// name is replaced.
+    #[ inline ]
+    #[ must_use ]
+    pub fn tool< F, Fut >( mut self, definition : ToolDefinition, handler : F ) -> Self
+    where
+      F : Fn( Value ) -> Fut + Send + Sync + 'static,
+      Fut : Future< Output = Result< String, String > > + Send + 'static,
+    {
+      let handler : ToolHandler = Box::new( move | input | Box::pin( handler( input ) ) );
+      self.tools.insert( definition.name.clone(), ( definition, handler ) );
+      self
+    }
+
+    /// Limit the number of requests a run sends
+    #[ inline ]
+    #[ must_use ]
+    pub fn max_iterations( mut self, max_iterations : u32 ) -> Self
+    {
+      self.max_iterations = max_iterations;
+      self
+    }
+
+    /// Definitions of the registered tools, sorted by name
+    #[ inline ]
+    #[ must_use ]
+    pub fn definitions( &self ) -> Vec< ToolDefinition >
+    {
+      let mut definitions : Vec< ToolDefinition > = self.tools.values().map( | ( definition, _ ) | definition.clone() ).collect();
+      definitions.sort_by( | a, b | a.name.cmp( &b.name ) );
+      definitions
+    }
+
+    /// Run the `tool_use` blocks of a response, in order
+    ///
+    /// Returns the user message with one `tool_result` per call, or `None`
+    /// when the response calls no caller-run tools. A failing handler or an
+    /// unregistered tool yields a result with `is_error` set, so the model
+    /// can recover.
+    #[ inline ]
+    pub async fn answer( &self, response : &CreateMessageResponse ) -> Option< Message >
+    {
+      let mut results = Vec::new();
+      for block in response.content.iter().filter( | block | block.r#type == "tool_use" )
+      {
+        let id = block.id.clone().unwrap_or_default();
+        let name = block.name.as_deref().unwrap_or_default();
+        let output = match self.tools.get( name )
+        {
+          Some( ( _, handler ) ) => handler( block.input.clone().unwrap_or_else( || serde_json::json!( {} ) ) ).await,
+          None => Err( format!( "Tool '{name}' is not available" ) ),
+        };
+        results.push( match output
+        {
+          Ok( output ) => Content::tool_result( id, output ),
+          Err( error ) => Content::tool_result_error( id, error, true ),
+        } );
+      }
+      ( !results.is_empty() ).then_some( Message { role : Role::User, content : results, cache_control : None } )
+    }
+
+    /// Send `request` and answer tool calls until the model stops calling tools
+    ///
+    /// Registered tools missing from the request's `tools` are added to it. A
+    /// `pause_turn` response is sent back as is so the model can continue its
+    /// server tool work. When the limit is reached first, the outcome holds
+    /// the last response and [`ToolRunOutcome::is_complete`] is false.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the iteration limit is zero or a request fails
+    #[ inline ]
+    pub async fn run( &self, client : &crate::Client, mut request : CreateMessageRequest ) -> AnthropicResult< ToolRunOutcome >
+    {
+      if self.max_iterations == 0
+      {
+        return Err( AnthropicError::InvalidArgument( "max_iterations must be at least 1".to_string() ) );
+      }
+
+      for definition in self.definitions()
+      {
+        let tools = request.tools.get_or_insert_with( Vec::new );
+        if !tools.iter().any( | tool | tool.name() == definition.name )
+        {
+          tools.push( definition.into() );
+        }
+      }
+
+      let mut iterations = 0;
+      loop
+      {
+        iterations += 1;
+        let response = client.create_message( request.clone() ).await?;
+        request.messages.push( Message
+        {
+          role : Role::Assistant,
+          content : response.content.iter().filter_map( crate::ResponseContent::to_content ).collect(),
+          cache_control : None,
+        } );
+
+        let next = match response.stop_reason.as_deref()
+        {
+          Some( "tool_use" ) => self.answer( &response ).await,
+          Some( "pause_turn" ) => None,
+          _ => return Ok( ToolRunOutcome { response, messages : request.messages, iterations } ),
+        };
+        if iterations >= self.max_iterations
+        {
+          return Ok( ToolRunOutcome { response, messages : request.messages, iterations } );
+        }
+        request.messages.extend( next );
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use DEFAULT_MAX_TOOL_ITERATIONS;
+  exposed use ToolHandlerFuture;
+  exposed use ToolRunner;
+  exposed use ToolRunOutcome;
+}
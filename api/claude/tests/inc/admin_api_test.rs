@@ -0,0 +1,112 @@
+//! Tests for the Admin API (`client.admin()`).
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `admin_requests_reject_empty_ids` | Empty IDs, names, and an `Unknown` status fail before any request is sent |
+//! | `organization_objects_parse` | User, workspace, and workspace member pages deserialize; roles round-trip; unknown roles are tolerated |
+//! | `api_keys_parse_and_filter` | API key pages deserialize and `ApiKeyFilter` builds its query parameters |
+//! | `integration_admin_lists_users_and_keys` | Real Admin API lists the organization's users and API keys |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ AdminListResponse, ApiKey, ApiKeyFilter, ApiKeyStatus, OrganizationRole, OrganizationUser, Workspace, WorkspaceMember, WorkspaceRole };
+
+#[ tokio::test ]
+async fn admin_requests_reject_empty_ids()
+{
+  let client = the_module::Client::new( the_module::Secret::new_unchecked( "sk-ant-admin01-".to_string() + &"x".repeat( 80 ) ) );
+  let admin = client.admin();
+
+  let errors = vec!
+  [
+    admin.users().get( "" ).await.map( | _ | () ),
+    admin.users().remove( "" ).await.map( | _ | () ),
+    admin.workspaces().create( "" ).await.map( | _ | () ),
+    admin.workspaces().archive( "" ).await.map( | _ | () ),
+    admin.workspace_members( "" ).list( None, None, None ).await.map( | _ | () ),
+    admin.workspace_members( "wrkspc_1" ).remove( "" ).await.map( | _ | () ),
+    admin.api_keys().rename( "apikey_1", "" ).await.map( | _ | () ),
+    admin.api_keys().set_status( "apikey_1", ApiKeyStatus::Unknown ).await.map( | _ | () ),
+  ];
+  for error in errors
+  {
+    assert!( matches!( error, Err( the_module::AnthropicError::InvalidArgument( _ ) ) ), "{error:?}" );
+  }
+}
+
+#[ test ]
+fn organization_objects_parse()
+{
+  let users : AdminListResponse< OrganizationUser > = serde_json::from_value( serde_json::json!(
+  {
+    "data" :
+    [
+      { "type" : "user", "id" : "user_1", "email" : "ada@example.com", "name" : "Ada", "role" : "developer", "added_at" : "2025-01-01T00:00:00Z" },
+      { "type" : "user", "id" : "user_2", "email" : "bo@example.com", "name" : "Bo", "role" : "auditor", "added_at" : "2025-02-01T00:00:00Z" },
+    ],
+    "has_more" : false, "first_id" : "user_1", "last_id" : "user_2",
+  } ) ).expect( "users parse" );
+  assert_eq!( users.data[ 0 ].role, OrganizationRole::Developer );
+  assert_eq!( users.data[ 1 ].role, OrganizationRole::Unknown );
+  assert_eq!( serde_json::to_value( OrganizationRole::ClaudeCodeUser ).expect( "serializes" ), "claude_code_user" );
+
+  let workspace : Workspace = serde_json::from_value( serde_json::json!(
+  {
+    "type" : "workspace", "id" : "wrkspc_1", "name" : "Research", "created_at" : "2025-01-01T00:00:00Z",
+    "archived_at" : "2025-06-01T00:00:00Z", "display_color" : "#6C5BB9",
+  } ) ).expect( "workspace parses" );
+  assert!( workspace.is_archived() );
+
+  let member : WorkspaceMember = serde_json::from_value( serde_json::json!(
+  {
+    "type" : "workspace_member", "user_id" : "user_1", "workspace_id" : "wrkspc_1", "workspace_role" : "workspace_developer",
+  } ) ).expect( "member parses" );
+  assert_eq!( member.workspace_role, WorkspaceRole::WorkspaceDeveloper );
+  assert_eq!( serde_json::to_value( WorkspaceRole::WorkspaceAdmin ).expect( "serializes" ), "workspace_admin" );
+}
+
+#[ test ]
+fn api_keys_parse_and_filter()
+{
+  let keys : AdminListResponse< ApiKey > = serde_json::from_value( serde_json::json!(
+  {
+    "data" :
+    [
+      {
+        "type" : "api_key", "id" : "apikey_1", "name" : "ci", "workspace_id" : null, "created_at" : "2025-01-01T00:00:00Z",
+        "created_by" : { "id" : "user_1", "type" : "user" }, "partial_key_hint" : "sk-ant-api03-R2D...igAA", "status" : "inactive",
+      },
+    ],
+    "has_more" : true, "first_id" : "apikey_1", "last_id" : "apikey_1",
+  } ) ).expect( "keys parse" );
+  let key = &keys.data[ 0 ];
+  assert_eq!( key.status, ApiKeyStatus::Inactive );
+  assert!( key.workspace_id.is_none() );
+  assert_eq!( key.created_by.id, "user_1" );
+
+  assert!( ApiKeyFilter::new().query().is_empty() );
+  assert_eq!
+  (
+    ApiKeyFilter::new().created_by( "user_1" ).status( ApiKeyStatus::Archived ).workspace( "wrkspc_1" ).query(),
+    [ "workspace_id=wrkspc_1", "status=archived", "created_by_user_id=user_1" ]
+  );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_admin_lists_users_and_keys()
+{
+  let secret = the_module::Secret::load_from_workspace( "ANTHROPIC_ADMIN_KEY", "-secrets.sh" )
+    .expect( "INTEGRATION: ANTHROPIC_ADMIN_KEY must be set in the workspace secrets" );
+  let client = the_module::Client::new( secret );
+
+  let users = client.admin().users().list( None, None, Some( 5 ) ).await
+    .expect( "INTEGRATION: listing organization users must succeed" );
+  assert!( !users.data.is_empty(), "{users:?}" );
+
+  let keys = client.admin().api_keys().list_all( &ApiKeyFilter::new().status( ApiKeyStatus::Active ) ).await
+    .expect( "INTEGRATION: listing API keys must succeed" );
+  assert!( keys.iter().all( | key | key.status == ApiKeyStatus::Active ), "{keys:?}" );
+}
@@ -379,8 +379,8 @@ fn test_secret_debug_redaction()
   let debug_output = format!( "{secret:?}" );
 
   assert!(
-    debug_output.contains( "REDACTED" ),
-    "Debug output must contain REDACTED placeholder, got : {debug_output}"
+    debug_output.contains( "\"sk-a****\"" ),
+    "Debug output must show only the masked key prefix, got : {debug_output}"
   );
   assert!(
     !debug_output.contains( "sk-ant-REDACTED" ),
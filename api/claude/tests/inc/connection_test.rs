@@ -0,0 +1,50 @@
+//! Tests for connection tuning and `Client::warm_up`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `integration_warm_up_reaches_the_api` | The real API answers the warm-up `HEAD`, whatever its status |
+//! | `warm_up_fails_when_nothing_listens` | Connection failure is an error |
+//! | `connection_tuning_rebuilds_client` | `with_connection_tuning` accepts the low-latency preset and refuses a malformed proxy URL |
+
+#[ allow( unused_imports ) ]
+use super::*;
+
+const KEY : &str = "sk-ant-REDACTED";
+
+fn client( base_url : String ) -> the_module::Client
+{
+  the_module::Client::new( the_module::Secret::new_unchecked( KEY.to_string() ) ).with_base_url( base_url )
+}
+
+// ------------------------------------------------------------------ //
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_warm_up_reaches_the_api()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  client.warm_up().await.expect( "INTEGRATION: warm-up must reach the API" );
+}
+
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  assert!( client( "http://127.0.0.1:1".to_string() ).warm_up().await.is_err() );
+}
+
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_rebuilds_client()
+{
+  let proxy = client( "http://127.0.0.1:1".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+  assert!( proxy.is_err(), "a malformed proxy URL must be refused" );
+
+  let client = client( "http://127.0.0.1:1".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::low_latency() );
+
+  assert!( client.is_ok(), "{:?}", client.err() );
+}
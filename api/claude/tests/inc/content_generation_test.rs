@@ -212,6 +212,7 @@ async fn test_response_structure_parsing()
     {
       r#type : "text".to_string(),
       text : Some( "Generated content here".to_string() ),
+      ..Default::default()
     }
   ];
 
@@ -303,6 +304,7 @@ async fn integration_content_generation_temperature_control()
     system : None,
     temperature : Some( 0.0 ), // Very deterministic
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -315,6 +317,7 @@ async fn integration_content_generation_temperature_control()
     system : None,
     temperature : Some( 0.9 ), // Very creative
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -375,6 +378,7 @@ async fn integration_content_generation_max_tokens_control()
     system : None,
     temperature : Some( 0.5 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -387,6 +391,7 @@ async fn integration_content_generation_max_tokens_control()
     system : None,
     temperature : Some( 0.5 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
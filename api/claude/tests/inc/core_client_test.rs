@@ -105,6 +105,7 @@ async fn integration_client_real_api_lifecycle()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -146,6 +147,7 @@ async fn integration_client_concurrent_requests()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -158,6 +160,7 @@ async fn integration_client_concurrent_requests()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
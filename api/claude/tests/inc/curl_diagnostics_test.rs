@@ -65,7 +65,8 @@ fn test_curl_with_authentication()
     let curl_command = client.as_curl_for_request( &request, "https://api.anthropic.com/v1/messages" );
 
     // Validate authentication headers
-    assert!( curl_command.contains( "x-api-key: sk-ant-api03-test-key" ), "Should include API key header" );
+    assert!( curl_command.contains( "x-api-key: sk-a****" ), "Should include masked API key header" );
+    assert!( !curl_command.contains( "sk-ant-api03-test-key" ), "Should not include the full API key" );
     assert!( curl_command.contains( "anthropic-version:" ), "Should include version header" );
     assert!( curl_command.contains( "User-Agent:" ), "Should include user agent" );
   }
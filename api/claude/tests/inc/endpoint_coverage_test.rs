@@ -16,6 +16,7 @@ mod private
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     }
@@ -102,6 +102,7 @@ async fn integration_tool_choice_auto_mode()
       },
       "required": ["operation", "a", "b"]
     }),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -112,7 +113,8 @@ async fn integration_tool_choice_auto_mode()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::Auto ),
   };
 
@@ -151,6 +153,7 @@ async fn integration_tool_choice_any_mode()
       },
       "required": ["topic"]
     }),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -161,7 +164,8 @@ async fn integration_tool_choice_any_mode()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::Any ),
   };
 
@@ -204,6 +208,7 @@ async fn integration_tool_choice_none_mode()
       },
       "required": ["expression"]
     }),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -214,7 +219,8 @@ async fn integration_tool_choice_none_mode()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::None ),
   };
 
@@ -257,7 +263,8 @@ async fn integration_tool_choice_mode_transitions()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ tool.clone() ] ),
+    thinking : None,
+    tools : Some( vec![ tool.clone().into() ] ),
     tool_choice : Some( the_module::ToolChoice::Auto ),
   };
 
@@ -282,7 +289,8 @@ async fn integration_tool_choice_mode_transitions()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::None ),
   };
 
@@ -285,6 +285,7 @@ async fn integration_error_handling_network_timeout()
     system : Some( vec![ the_module::SystemContent::text( "You are a comprehensive technical expert. Provide extremely detailed responses." ) ] ),
     temperature : Some( 0.3 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -332,6 +333,7 @@ async fn integration_error_handling_invalid_parameters()
     system : None,
     temperature : Some( 2.5 ), // Invalid temperature (>1.0)
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -370,6 +372,7 @@ async fn integration_error_handling_authentication_failures()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -28,7 +28,8 @@ fn test_function_calling_tool_choice_format()
                 "properties": {
                     "input": {"type": "string"}
                 }
-            })
+            }),
+            cache_control : None,
         }
     ];
 
@@ -36,9 +37,10 @@ fn test_function_calling_tool_choice_format()
         model : "claude-sonnet-4-5-20250929".to_string(),
         max_tokens : 100,
         messages : vec![the_module::Message::user("Test message".to_string())],
-        tools : Some(tools),
+        tools : Some(tools.into_iter().map(Into::into).collect()),
         tool_choice : Some(the_module::ToolChoice::Auto),
         stream : None,
+        thinking : None,
         system : None,
         temperature : Some(0.5),
     };
@@ -79,7 +81,8 @@ async fn test_function_calling_real_api_basic()
                 "b": {"type": "number", "description": "Second number"}
             },
             "required": ["a", "b"]
-        })
+        }),
+        cache_control : None,
     };
 
     let request = the_module::CreateMessageRequest
@@ -89,9 +92,10 @@ async fn test_function_calling_real_api_basic()
         messages : vec![
             the_module::Message::user("What is 5 + 3?".to_string())
         ],
-        tools : Some(vec![simple_tool]),
+        tools : Some(vec![simple_tool.into()]),
         tool_choice : Some(the_module::ToolChoice::Auto),
         stream : None,
+        thinking : None,
         system : Some( vec![ the_module::SystemContent::text( "You are a helpful assistant." ) ] ),
         temperature : Some(0.3),
     };
@@ -0,0 +1,158 @@
+//! Tests for extended thinking requests, response blocks, and streaming.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `thinking_parameter_serializes_and_validates` | `thinking` serializes as `{ type, budget_tokens }`; budget and temperature limits are enforced |
+//! | `thinking_blocks_are_kept_and_passed_back` | `thinking` and `redacted_thinking` response blocks keep their data and convert to request content |
+//! | `streamed_thinking_accumulates_with_signature` | `thinking_delta` and `signature_delta` build the thinking block ahead of the text block |
+//! | `integration_thinking_precedes_the_answer` | Real API returns a signed thinking block before the answer |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ Content, CreateMessageRequest, Message, ThinkingConfig };
+
+fn request( max_tokens : u32 ) -> the_module::CreateMessageRequestBuilder
+{
+  CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-6" )
+    .max_tokens( max_tokens )
+    .message( Message::user( "Is 1009 prime?" ) )
+}
+
+#[ test ]
+fn thinking_parameter_serializes_and_validates()
+{
+  let thinking = request( 4096 ).thinking( 2048 ).build();
+  let json = serde_json::to_value( &thinking ).expect( "serializes" );
+  assert_eq!( json[ "thinking" ], serde_json::json!( { "type" : "enabled", "budget_tokens" : 2048 } ) );
+  assert!( thinking.validate().is_ok() );
+
+  let plain = serde_json::to_value( request( 4096 ).build() ).expect( "serializes" );
+  assert!( plain.get( "thinking" ).is_none(), "{plain}" );
+  assert_eq!( serde_json::to_value( ThinkingConfig::Disabled ).expect( "serializes" ), serde_json::json!( { "type" : "disabled" } ) );
+  assert_eq!( ThinkingConfig::enabled( 2048 ).budget_tokens(), Some( 2048 ) );
+
+  assert!( request( 4096 ).thinking( the_module::MIN_THINKING_BUDGET_TOKENS - 1 ).build().validate().is_err() );
+  assert!( request( 2048 ).thinking( 2048 ).build().validate().is_err() );
+  assert!( request( 4096 ).thinking( 2048 ).temperature( 0.5 ).build().validate().is_err() );
+  assert!( request( 4096 ).thinking( 2048 ).temperature( 1.0 ).build().validate().is_ok() );
+  assert!( request( 4096 ).temperature( 0.5 ).build().validate().is_ok() );
+}
+
+#[ test ]
+fn thinking_blocks_are_kept_and_passed_back()
+{
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "thinking", "thinking" : "1009 is not divisible by any prime up to 31.", "signature" : "EqQBCgIYAhIM" },
+      { "type" : "redacted_thinking", "data" : "EmwKAhgBEgy3va3pzix" },
+      { "type" : "text", "text" : "Yes, 1009 is prime." },
+    ],
+    "model" : "claude-sonnet-4-6",
+    "stop_reason" : "end_turn",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 40, "output_tokens" : 300 },
+  } ) ).expect( "response parses" );
+
+  assert_eq!( response.content.len(), 3 );
+  assert_eq!( response.text(), Some( "Yes, 1009 is prime." ) );
+  assert_eq!( response.thinking().as_deref(), Some( "1009 is not divisible by any prime up to 31." ) );
+  assert!( response.content[ 0 ].is_thinking() && response.content[ 1 ].is_thinking() && !response.content[ 2 ].is_thinking() );
+
+  let turn : Vec< Content > = response.content.iter().filter_map( the_module::ResponseContent::to_content ).collect();
+  assert_eq!( serde_json::to_value( &turn ).expect( "serializes" ), serde_json::json!(
+  [
+    { "type" : "thinking", "thinking" : "1009 is not divisible by any prime up to 31.", "signature" : "EqQBCgIYAhIM" },
+    { "type" : "redacted_thinking", "data" : "EmwKAhgBEgy3va3pzix" },
+    { "type" : "text", "text" : "Yes, 1009 is prime." },
+  ] ) );
+  assert!( turn[ 0 ].is_thinking() && turn[ 1 ].is_thinking() );
+  assert_eq!( ( turn[ 0 ].r#type(), turn[ 1 ].r#type() ), ( "thinking", "redacted_thinking" ) );
+
+  let parsed : Vec< Content > = serde_json::from_value( serde_json::to_value( &turn ).expect( "serializes" ) ).expect( "parses" );
+  assert_eq!( parsed, turn );
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn streamed_thinking_accumulates_with_signature()
+{
+  let events = concat!
+  (
+    "event: message_start\n",
+    "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_2\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-sonnet-4-6\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":40,\"output_tokens\":1}}}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"thinking\",\"thinking\":\"\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"thinking_delta\",\"thinking\":\"Check primes \"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"thinking_delta\",\"thinking\":\"up to 31.\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"signature_delta\",\"signature\":\"EqQBCgIYAhIM\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":0}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"text_delta\",\"text\":\"Yes.\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":1}\n\n",
+    "event: message_delta\n",
+    "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\",\"stop_sequence\":null},\"usage\":{\"output_tokens\":90}}\n\n",
+    "event: message_stop\n",
+    "data: {\"type\":\"message_stop\"}\n\n",
+  );
+  let events = the_module::parse_sse_events( events ).expect( "events parse" );
+  assert_eq!( events.len(), 11, "{events:?}" );
+  assert!( matches!( &events[ 1 ], the_module::StreamEvent::ContentBlockStart { content_block, .. } if content_block.is_thinking() ) );
+  assert!( matches!( &events[ 2 ], the_module::StreamEvent::ContentBlockDelta { delta, .. } if delta.thinking() == Some( "Check primes " ) ) );
+
+  let mut accumulator = the_module::MessageAccumulator::new();
+  for event in &events
+  {
+    accumulator.push( event ).expect( "event applies" );
+  }
+  assert_eq!( accumulator.thinking(), "Check primes up to 31." );
+  assert_eq!( accumulator.text(), "Yes." );
+
+  let response = accumulator.response().expect( "message started" );
+  let types : Vec< &str > = response.content.iter().map( | content | content.r#type.as_str() ).collect();
+  assert_eq!( types, [ "thinking", "text" ] );
+  assert_eq!( response.content[ 0 ].signature.as_deref(), Some( "EqQBCgIYAhIM" ) );
+  assert_eq!( response.thinking().as_deref(), Some( "Check primes up to 31." ) );
+
+  // A thinking delta cannot apply to a text block
+  let stray = the_module::StreamEvent::content_block_delta( 1, the_module::StreamDelta::ThinkingDelta { r#type : "thinking_delta".to_string(), thinking : "late".to_string() } );
+  assert!( accumulator.push( &stray ).is_err() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_thinking_precedes_the_answer()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let request = CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-5-20250929" )
+    .max_tokens( 2048 )
+    .thinking( 1024 )
+    .message( Message::user( "Is 1009 prime? Answer yes or no." ) )
+    .build();
+
+  let response = client.create_message( request ).await
+    .expect( "INTEGRATION: message with thinking must succeed" );
+  let first = response.content.first().expect( "response has content" );
+  assert!( first.is_thinking(), "{response:?}" );
+  if first.r#type == "thinking"
+  {
+    assert!( first.signature.as_deref().is_some_and( | signature | !signature.is_empty() ), "{response:?}" );
+  }
+  assert!( response.text().unwrap_or_default().to_lowercase().contains( "yes" ), "{response:?}" );
+}
@@ -0,0 +1,285 @@
+//! Tests for the Files API, document content blocks, and citations.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `files_calls_check_their_arguments_before_sending` | Empty content, filename, or ID and a malformed MIME type are refused without a request |
+//! | `file_responses_parse` | File metadata, list pages, and deletions parse from their JSON |
+//! | `document_blocks_serialize_base64_and_file_sources` | Base64 PDF and `file_id` documents serialize with title and citations; the Files API beta is configured once |
+//! | `document_and_image_blocks_deserialize_to_their_variants` | Untagged `Content` tells documents from images by `type` |
+//! | `page_citations_are_parsed_from_responses` | `page_location` citations expose text, document, and page range; unknown kinds are kept |
+//! | `cited_answers_number_and_render_sources` | Character and block spans, `Display` locations, and `cited_answer` markers, reuse of numbers, and footnotes |
+//! | `citation_deltas_accumulate_into_the_text_block` | Streamed `citations_delta` events end up on the accumulated text block |
+//! | `integration_uploaded_pdf_is_cited_by_page` | Real upload, cited answer from the file, and deletion |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ Citation, Content, DocumentContent, DocumentSource, Message };
+
+const FILE_JSON : &str = r#"{"id":"file_011","type":"file","filename":"report.pdf","mime_type":"application/pdf","size_bytes":9,"created_at":"2026-10-18T09:00:00Z","downloadable":false}"#;
+
+#[ tokio::test ]
+async fn files_calls_check_their_arguments_before_sending()
+{
+  // Nothing listens on the discard port
+  let client = the_module::Client::new( the_module::Secret::new_unchecked( "sk-ant-REDACTED".to_string() ) )
+    .with_base_url( "http://127.0.0.1:9".to_string() );
+  let files = client.files();
+  for error in
+  [
+    files.upload( "empty.pdf", "application/pdf", Vec::new() ).await.expect_err( "no content" ),
+    files.upload( "", "application/pdf", b"x".to_vec() ).await.expect_err( "no filename" ),
+    files.upload( "report.pdf", "not a mime type", b"x".to_vec() ).await.expect_err( "bad MIME type" ),
+  ]
+  {
+    assert!( matches!( error, the_module::AnthropicError::InvalidArgument( _ ) ), "{error:?}" );
+  }
+  let get = files.get( "" ).await.expect_err( "no ID" );
+  let delete = files.delete( "" ).await.expect_err( "no ID" );
+  assert!( matches!( get, the_module::AnthropicError::InvalidArgument( _ ) ), "{get:?}" );
+  assert!( matches!( delete, the_module::AnthropicError::InvalidArgument( _ ) ), "{delete:?}" );
+}
+
+#[ test ]
+fn file_responses_parse()
+{
+  let file : the_module::FileMetadata = serde_json::from_str( FILE_JSON ).expect( "metadata parses" );
+  assert_eq!( ( file.id.as_str(), file.filename.as_str(), file.size_bytes, file.downloadable ), ( "file_011", "report.pdf", 9, false ) );
+
+  let list = r#"{"data":[{"id":"file_011","type":"file","filename":"report.pdf","mime_type":"application/pdf","size_bytes":9,"created_at":"2026-10-18T09:00:00Z"}],"has_more":true,"first_id":"file_011","last_id":"file_011"}"#;
+  let page : the_module::FileListResponse = serde_json::from_str( list ).expect( "page parses" );
+  assert!( page.has_more );
+  assert_eq!( page.data.len(), 1 );
+  assert!( !page.data[ 0 ].downloadable );
+
+  let gone : the_module::FileDeleted = serde_json::from_str( r#"{"id":"file_011","type":"file_deleted"}"# ).expect( "deletion parses" );
+  assert_eq!( ( gone.id.as_str(), gone.r#type.as_str() ), ( "file_011", "file_deleted" ) );
+}
+
+#[ test ]
+fn document_blocks_serialize_base64_and_file_sources()
+{
+  let inline = DocumentContent::pdf( "JVBERi0xLjcK" ).with_title( "Inline" ).with_citations();
+  let uploaded = DocumentContent::file( "file_011" ).with_context( "Quarterly figures" );
+  assert!( inline.validate().is_ok() && uploaded.validate().is_ok() );
+  assert!( DocumentContent::file( "" ).validate().is_err() );
+  assert!( DocumentContent::new( DocumentSource::Base64 { media_type : "image/png".into(), data : "x".into() } ).validate().is_err() );
+
+  let message = Message::builder().user().document( inline ).document( uploaded ).text( "Compare them." ).build();
+  let json = serde_json::to_value( &message ).expect( "serializes" );
+  assert_eq!( json[ "content" ], serde_json::json!(
+  [
+    {
+      "type" : "document",
+      "source" : { "type" : "base64", "media_type" : "application/pdf", "data" : "JVBERi0xLjcK" },
+      "title" : "Inline",
+      "citations" : { "enabled" : true },
+    },
+    {
+      "type" : "document",
+      "source" : { "type" : "file", "file_id" : "file_011" },
+      "context" : "Quarterly figures",
+    },
+    { "type" : "text", "text" : "Compare them." },
+  ] ) );
+
+  // Messages referencing uploaded files opt in to the beta through the config, once
+  let config = the_module::ClientConfig::recommended()
+    .with_beta( the_module::FILES_API_BETA )
+    .with_beta( the_module::FILES_API_BETA );
+  assert_eq!( config.beta_features, [ the_module::FILES_API_BETA ] );
+}
+
+#[ test ]
+fn document_and_image_blocks_deserialize_to_their_variants()
+{
+  let document : Content = serde_json::from_value( serde_json::json!(
+  {
+    "type" : "document",
+    "source" : { "type" : "file", "file_id" : "file_011" },
+  } ) ).expect( "document parses" );
+  assert!( document.is_document() );
+  assert_eq!( document.r#type(), "document" );
+  assert_eq!( serde_json::from_value::< Content >( serde_json::to_value( &document ).expect( "serializes" ) ).expect( "round trips" ), document );
+
+  #[ cfg( feature = "vision" ) ]
+  {
+    let image : Content = serde_json::from_value( serde_json::json!(
+    {
+      "type" : "image",
+      "source" : { "type" : "base64", "media_type" : "image/png", "data" : "iVBORw0KGgo=" },
+    } ) ).expect( "image parses" );
+    assert!( image.is_image() && !image.is_document() );
+  }
+}
+
+#[ test ]
+fn page_citations_are_parsed_from_responses()
+{
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "text", "text" : "According to the report, " },
+      {
+        "type" : "text",
+        "text" : "revenue grew 12%.",
+        "citations" :
+        [
+          {
+            "type" : "page_location",
+            "cited_text" : "Revenue grew 12% year over year.",
+            "document_index" : 0,
+            "document_title" : "Annual report",
+            "start_page_number" : 3,
+            "end_page_number" : 5,
+          },
+          { "type" : "search_result_location", "source" : "https://example.com" },
+        ],
+      },
+    ],
+    "model" : "claude-sonnet-4-6",
+    "stop_reason" : "end_turn",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 1200, "output_tokens" : 20 },
+  } ) ).expect( "response parses" );
+
+  assert!( response.content[ 0 ].citations.is_none() );
+  let citations = response.citations();
+  assert_eq!( citations.len(), 2 );
+  assert_eq!( citations[ 0 ].cited_text(), Some( "Revenue grew 12% year over year." ) );
+  assert_eq!( citations[ 0 ].document_index(), Some( 0 ) );
+  assert_eq!( citations[ 0 ].pages(), Some( 3..5 ) );
+  assert!( matches!( citations[ 0 ], Citation::PageLocation { document_title : Some( title ), .. } if title == "Annual report" ) );
+  assert_eq!( *citations[ 1 ], Citation::Unknown );
+  assert_eq!( citations[ 1 ].pages(), None );
+}
+
+#[ test ]
+fn cited_answers_number_and_render_sources()
+{
+  let chars = Citation::CharLocation
+  {
+    cited_text : "The term is two years.".to_string(),
+    document_index : 1,
+    document_title : None,
+    start_char_index : 120,
+    end_char_index : 142,
+  };
+  let page = Citation::PageLocation
+  {
+    cited_text : "Either party may terminate.".to_string(),
+    document_index : 0,
+    document_title : Some( "Contract".to_string() ),
+    start_page_number : 7,
+    end_page_number : 8,
+  };
+  assert_eq!( chars.chars(), Some( 120..142 ) );
+  assert_eq!( chars.blocks(), None );
+  assert_eq!( chars.to_string(), "Document 1, characters 120-141" );
+  assert_eq!( page.to_string(), "Contract, page 7" );
+  assert_eq!( Citation::Unknown.to_string(), "Unknown source" );
+
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "text", "text" : "The contract runs for two years", "citations" : [ chars ] },
+      { "type" : "text", "text" : " and ends on notice", "citations" : [ page, chars ] },
+      { "type" : "text", "text" : "." },
+    ],
+    "model" : "claude-sonnet-4-6",
+    "stop_reason" : "end_turn",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 900, "output_tokens" : 20 },
+  } ) ).expect( "response parses" );
+
+  let answer = response.cited_answer();
+  assert_eq!( answer.text, "The contract runs for two years[1] and ends on notice[2][1]." );
+  assert_eq!( answer.sources, [ chars, page ] );
+  assert_eq!( answer.to_string(), "The contract runs for two years[1] and ends on notice[2][1].\n\n\
+    [1] \"The term is two years.\" - Document 1, characters 120-141\n\
+    [2] \"Either party may terminate.\" - Contract, page 7" );
+
+  let plain = the_module::CitedAnswer::new( [ ( "No sources.", &[][ .. ] ) ] );
+  assert_eq!( plain.to_string(), "No sources." );
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn citation_deltas_accumulate_into_the_text_block()
+{
+  let events = concat!
+  (
+    "event: message_start\n",
+    "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_2\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-sonnet-4-6\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":900,\"output_tokens\":1}}}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"citations_delta\",\"citation\":{\"type\":\"page_location\",\"cited_text\":\"Costs fell.\",\"document_index\":0,\"document_title\":null,\"start_page_number\":7,\"end_page_number\":8}}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Costs fell.\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":0}\n\n",
+    "event: message_delta\n",
+    "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\",\"stop_sequence\":null},\"usage\":{\"output_tokens\":9}}\n\n",
+    "event: message_stop\n",
+    "data: {\"type\":\"message_stop\"}\n\n",
+  );
+  let mut accumulator = the_module::MessageAccumulator::new();
+  for event in the_module::parse_sse_events( events ).expect( "events parse" )
+  {
+    accumulator.push( &event ).expect( "event applies" );
+  }
+
+  let response = accumulator.response().expect( "message started" );
+  assert_eq!( response.text(), Some( "Costs fell." ) );
+  let citations = response.citations();
+  assert_eq!( citations.len(), 1 );
+  assert_eq!( citations[ 0 ].pages(), Some( 7..8 ) );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_uploaded_pdf_is_cited_by_page()
+{
+  // One page PDF whose only text is "The secret code is 4127."
+  const PDF : &str = concat!
+  (
+    "%PDF-1.4\n",
+    "1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\n",
+    "2 0 obj << /Type /Pages /Kids [3 0 R] /Count 1 >> endobj\n",
+    "3 0 obj << /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R /Resources << /Font << /F1 5 0 R >> >> >> endobj\n",
+    "4 0 obj << /Length 58 >> stream\nBT /F1 24 Tf 72 700 Td (The secret code is 4127.) Tj ET\nendstream endobj\n",
+    "5 0 obj << /Type /Font /Subtype /Type1 /BaseFont /Helvetica >> endobj\n",
+    "trailer << /Root 1 0 R >>\n%%EOF\n",
+  );
+
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let file = client.files().upload( "code.pdf", "application/pdf", PDF.as_bytes().to_vec() ).await
+    .expect( "INTEGRATION: upload must succeed" );
+  assert_eq!( client.files().get( &file.id ).await.expect( "INTEGRATION: get must succeed" ).id, file.id );
+
+  let config = client.config().clone().with_beta( the_module::FILES_API_BETA );
+  let reader = the_module::Client::with_config( client.secret().clone(), config );
+  let request = the_module::CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-5-20250929" )
+    .max_tokens( 200 )
+    .message( Message::user_with_document( "What is the secret code? Cite the document.", DocumentContent::file( &file.id ).with_citations() ) )
+    .build();
+  let answer = reader.create_message( request ).await;
+
+  client.files().delete( &file.id ).await.expect( "INTEGRATION: delete must succeed" );
+  let answer = answer.expect( "INTEGRATION: message with the uploaded PDF must succeed" );
+  let text : String = answer.content.iter().filter_map( | content | content.text.as_deref() ).collect();
+  assert!( text.contains( "4127" ), "{answer:?}" );
+  assert!( answer.citations().iter().any( | citation | citation.pages() == Some( 1..2 ) ), "{answer:?}" );
+}
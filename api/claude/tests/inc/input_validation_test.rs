@@ -22,6 +22,7 @@ fn test_model_validation_empty()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -46,6 +47,7 @@ fn test_model_validation_whitespace()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -70,6 +72,7 @@ fn test_max_tokens_below_minimum()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -94,6 +97,7 @@ fn test_max_tokens_above_maximum()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -118,6 +122,7 @@ fn test_max_tokens_at_boundaries()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -132,6 +137,7 @@ fn test_max_tokens_at_boundaries()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -154,6 +160,7 @@ fn test_messages_empty()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -178,6 +185,7 @@ fn test_temperature_below_minimum()
     system : None,
     temperature : Some( -0.1 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -202,6 +210,7 @@ fn test_temperature_above_maximum()
     system : None,
     temperature : Some( 1.5 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -226,6 +235,7 @@ fn test_temperature_at_boundaries()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -240,6 +250,7 @@ fn test_temperature_at_boundaries()
     system : None,
     temperature : Some( 1.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -267,6 +278,7 @@ fn test_valid_request()
     system : Some( vec![ the_module::SystemContent::text( "You are helpful" ) ] ),
     temperature : Some( 0.7 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -293,6 +305,7 @@ fn test_tool_choice_without_tools()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : Some( the_module::ToolChoice::Auto ),
   };
@@ -313,6 +326,7 @@ fn test_tool_choice_unknown_tool()
     name : "calculator".to_string(),
     description : "Perform calculations".to_string(),
     input_schema : serde_json::json!( { "type" : "object" } ),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -323,7 +337,8 @@ fn test_tool_choice_unknown_tool()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::specific( "unknown_tool" ) ),
   };
 
@@ -346,6 +361,7 @@ fn test_empty_tools_array()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : Some( vec![] ),
     tool_choice : None,
   };
@@ -366,6 +382,7 @@ fn test_tool_with_empty_name()
     name : String::new(),
     description : "A tool".to_string(),
     input_schema : serde_json::json!( { "type" : "object" } ),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -376,7 +393,8 @@ fn test_tool_with_empty_name()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : None,
   };
 
@@ -396,6 +414,7 @@ fn test_duplicate_tool_names()
     name : "calculator".to_string(),
     description : "First calculator".to_string(),
     input_schema : serde_json::json!( { "type" : "object" } ),
+    cache_control : None,
   };
 
   let tool2 = the_module::ToolDefinition
@@ -403,6 +422,7 @@ fn test_duplicate_tool_names()
     name : "calculator".to_string(),
     description : "Second calculator".to_string(),
     input_schema : serde_json::json!( { "type" : "object" } ),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -413,7 +433,8 @@ fn test_duplicate_tool_names()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ tool1, tool2 ] ),
+    thinking : None,
+    tools : Some( vec![ tool1.into(), tool2.into() ] ),
     tool_choice : None,
   };
 
@@ -433,6 +454,7 @@ fn test_tool_with_empty_description()
     name : "calculator".to_string(),
     description : String::new(),
     input_schema : serde_json::json!( { "type" : "object" } ),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -443,7 +465,8 @@ fn test_tool_with_empty_description()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : None,
   };
 
@@ -465,6 +488,7 @@ fn test_too_many_tools()
       name : format!( "tool_{i}" ),
       description : format!( "Tool number {i}" ),
       input_schema : serde_json::json!( { "type" : "object" } ),
+      cache_control : None,
     }
   } ).collect();
 
@@ -476,7 +500,8 @@ fn test_too_many_tools()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( tools ),
+    thinking : None,
+    tools : Some( tools.into_iter().map( Into::into ).collect() ),
     tool_choice : None,
   };
 
@@ -503,6 +528,7 @@ fn test_valid_tools()
         "expression" : { "type" : "string" }
       }
     }),
+    cache_control : None,
   };
 
   let request = the_module::CreateMessageRequest
@@ -513,7 +539,8 @@ fn test_valid_tools()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ tool ] ),
+    thinking : None,
+    tools : Some( vec![ tool.into() ] ),
     tool_choice : Some( the_module::ToolChoice::specific( "calculator" ) ),
   };
 
@@ -631,6 +658,7 @@ async fn integration_validation_prevents_invalid_requests()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -664,6 +692,7 @@ async fn integration_validation_allows_valid_requests()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -0,0 +1,107 @@
+//! Tests for `MessagesApi::create_with_retries`, the paced retry loop it runs
+//! on, and `RetryPacing`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `pacing_prefers_retry_after_then_reset_then_backoff` | `retry-after` wins over an exhausted limit's reset time, which wins over exponential backoff |
+//! | `pacing_gives_up_when_retrying_cannot_help` | 4xx statuses, used-up attempts, and waits beyond `max_delay_ms` stop the retries |
+//! | `paced_retries_wait_as_paced_and_stop_at_the_deadline` | `execute_paced_within` retries until pacing gives up, and returns the failure instead of sleeping past the budget deadline |
+//! | `integration_create_with_retries_returns_the_message` | A real request made through the retry loop returns the message |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use core::time::Duration;
+use the_module::{ AnthropicError, RetryConfig, RetryExecutor, RetryPacing, RetryStrategy };
+use api_common::CallBudget;
+use core::sync::atomic::{ AtomicU32, Ordering };
+
+fn headers( pairs : &[ ( &'static str, String ) ] ) -> reqwest::header::HeaderMap
+{
+  pairs.iter().map( | ( name, value ) | ( reqwest::header::HeaderName::from_static( name ), value.parse().expect( "valid header value" ) ) ).collect()
+}
+
+#[ test ]
+fn pacing_prefers_retry_after_then_reset_then_backoff()
+{
+  let config = RetryConfig::new().with_max_attempts( 5 ).with_base_delay_ms( 100 ).with_max_delay_ms( 10_000 ).with_jitter( false );
+  let reset = ( chrono::Utc::now() + chrono::Duration::seconds( 3 ) ).to_rfc3339();
+
+  let pacing = RetryPacing::from_headers( 429, &headers( &[ ( "retry-after", "2".to_string() ), ( "anthropic-ratelimit-tokens-remaining", "0".to_string() ), ( "anthropic-ratelimit-tokens-reset", reset.clone() ) ] ) );
+  assert_eq!( pacing.delay( &config, 1 ), Some( Duration::from_secs( 2 ) ) );
+
+  let pacing = RetryPacing::from_headers( 429, &headers( &[ ( "anthropic-ratelimit-tokens-remaining", "0".to_string() ), ( "anthropic-ratelimit-tokens-reset", reset.clone() ) ] ) );
+  let wait = pacing.delay( &config, 1 ).expect( "reset time is within max_delay_ms" );
+  assert!( wait > Duration::from_secs( 2 ) && wait <= Duration::from_secs( 3 ), "{wait:?}" );
+
+  // A reset on a limit that still has headroom is not a reason to wait
+  let pacing = RetryPacing::from_headers( 429, &headers( &[ ( "anthropic-ratelimit-requests-remaining", "7".to_string() ), ( "anthropic-ratelimit-requests-reset", reset ) ] ) );
+  assert_eq!( pacing.reset_wait(), None );
+  assert_eq!( pacing.delay( &config, 1 ), Some( Duration::from_millis( 100 ) ) );
+  assert_eq!( pacing.delay( &config, 3 ), Some( Duration::from_millis( 400 ) ) );
+}
+
+#[ test ]
+fn pacing_gives_up_when_retrying_cannot_help()
+{
+  let config = RetryConfig::new().with_max_attempts( 3 ).with_base_delay_ms( 100 ).with_max_delay_ms( 5_000 ).with_jitter( false );
+
+  let overloaded = RetryPacing::from_headers( 529, &headers( &[] ) );
+  assert!( overloaded.is_retryable() );
+  assert!( overloaded.delay( &config, 2 ).is_some() );
+  assert_eq!( overloaded.delay( &config, 3 ), None );
+
+  for status in [ 400, 401, 404, 413 ]
+  {
+    assert_eq!( RetryPacing::from_headers( status, &headers( &[ ( "retry-after", "1".to_string() ) ] ) ).delay( &config, 1 ), None, "{status}" );
+  }
+
+  let too_long = RetryPacing::from_headers( 429, &headers( &[ ( "retry-after", "60".to_string() ) ] ) );
+  assert_eq!( too_long.retry_after, Some( Duration::from_secs( 60 ) ) );
+  assert_eq!( too_long.delay( &config, 1 ), None );
+}
+
+#[ tokio::test ]
+async fn paced_retries_wait_as_paced_and_stop_at_the_deadline()
+{
+  let executor = RetryExecutor::new( RetryStrategy::exponential_backoff() );
+  let attempts = AtomicU32::new( 0 );
+  let failing = || async
+  {
+    attempts.fetch_add( 1, Ordering::SeqCst );
+    Err::< (), _ >( AnthropicError::Internal( "overloaded".to_string() ) )
+  };
+
+  let budget = CallBudget::new().start();
+  let result = executor.execute_paced_within( &budget, failing, | _, attempt | ( attempt < 3 ).then_some( Duration::from_millis( 5 ) ) ).await;
+  assert!( matches!( result, Err( AnthropicError::Internal( _ ) ) ), "{result:?}" );
+  assert_eq!( attempts.swap( 0, Ordering::SeqCst ), 3 );
+  assert_eq!( budget.attempts(), 3 );
+
+  // A requested wait that would pass the deadline ends the call at once
+  let budget = CallBudget::new().with_total_deadline( Duration::from_millis( 500 ) ).start();
+  let result = executor.execute_paced_within( &budget, failing, | _, _ | Some( Duration::from_secs( 30 ) ) ).await;
+  assert!( matches!( result, Err( AnthropicError::Internal( _ ) ) ), "{result:?}" );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+  assert!( budget.elapsed() < Duration::from_millis( 500 ), "{:?}", budget.elapsed() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_create_with_retries_returns_the_message()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let config = RetryConfig::new().with_max_attempts( 3 ).with_base_delay_ms( 500 ).with_max_delay_ms( 30_000 );
+  let request = the_module::CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-6" )
+    .max_tokens( 50 )
+    .message( the_module::Message::user( "Hello" ) )
+    .build();
+
+  let response = client.messages().create_with_retries( request, &config ).await
+    .expect( "INTEGRATION: the message must be created within three attempts" );
+  assert_eq!( response.role, "assistant" );
+  assert!( !response.content.is_empty(), "{response:?}" );
+}
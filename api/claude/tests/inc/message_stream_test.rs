@@ -0,0 +1,163 @@
+//! Tests for `client.messages().create_stream()` and `MessageAccumulator`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `events_decode_to_typed_events` | A recorded stream decodes to typed events, `ping` included |
+//! | `accumulator_builds_the_final_response` | Text deltas, `message_delta` stop reason and usage become a `CreateMessageResponse` |
+//! | `accumulator_parses_tool_input_from_json_deltas` | `input_json_delta` fragments are parsed into the tool input at block stop |
+//! | `error_events_and_out_of_sequence_events_fail` | A wrapped `error` event is an API error; a delta before its block start is refused |
+//! | `integration_streamed_message_matches_accumulated_text` | `create_stream` against the real API accumulates into a complete response |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ MessageAccumulator, StreamEvent };
+
+/// A short text answer as the API streams it.
+const TEXT_EVENTS : &str = concat!
+(
+  "event: message_start\n",
+  "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_1\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-sonnet-4-6\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":12,\"output_tokens\":1}}}\n\n",
+  "event: content_block_start\n",
+  "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+  "event: ping\n",
+  "data: {\"type\":\"ping\"}\n\n",
+  "event: content_block_delta\n",
+  "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}\n\n",
+  "event: content_block_delta\n",
+  "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\", world.\"}}\n\n",
+  "event: content_block_stop\n",
+  "data: {\"type\":\"content_block_stop\",\"index\":0}\n\n",
+  "event: message_delta\n",
+  "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\",\"stop_sequence\":null},\"usage\":{\"output_tokens\":6}}\n\n",
+  "event: message_stop\n",
+  "data: {\"type\":\"message_stop\"}\n\n",
+);
+
+fn accumulate( events : &str ) -> the_module::AnthropicResult< MessageAccumulator >
+{
+  let mut accumulator = MessageAccumulator::new();
+  for event in the_module::parse_sse_events( events )?
+  {
+    accumulator.push( &event )?;
+  }
+  Ok( accumulator )
+}
+
+#[ test ]
+fn events_decode_to_typed_events()
+{
+  let events = the_module::parse_sse_events( TEXT_EVENTS ).expect( "events decode" );
+
+  assert_eq!( events.len(), 8, "{events:?}" );
+  let kinds =
+  [
+    events[ 0 ].is_message_start(),
+    events[ 1 ].is_content_block_start(),
+    events[ 2 ].is_ping(),
+    events[ 3 ].is_content_block_delta(),
+    events[ 4 ].is_content_block_delta(),
+    events[ 5 ].is_content_block_stop(),
+    events[ 6 ].is_message_delta(),
+    events[ 7 ].is_message_stop(),
+  ];
+  assert!( kinds.iter().all( | kind | *kind ), "{events:?}" );
+  assert_eq!( events[ 0 ].message().map( | message | message.id.as_str() ), Some( "msg_1" ) );
+}
+
+#[ test ]
+fn accumulator_builds_the_final_response()
+{
+  let accumulator = accumulate( TEXT_EVENTS ).expect( "accumulates" );
+  assert!( accumulator.is_complete() );
+  assert_eq!( accumulator.text(), "Hello, world." );
+
+  let response = accumulator.response().expect( "message started" );
+  assert_eq!( ( response.id.as_str(), response.role.as_str() ), ( "msg_1", "assistant" ) );
+  assert_eq!( response.text(), Some( "Hello, world." ) );
+  assert_eq!( response.stop_reason.as_deref(), Some( "end_turn" ) );
+  assert_eq!( ( response.usage.input_tokens, response.usage.output_tokens ), ( 12, 6 ) );
+  assert!( MessageAccumulator::new().response().is_err() );
+}
+
+#[ cfg( feature = "tools" ) ]
+#[ test ]
+fn accumulator_parses_tool_input_from_json_deltas()
+{
+  let events = concat!
+  (
+    "event: message_start\n",
+    "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_2\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-sonnet-4-6\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":40,\"output_tokens\":1}}}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"text\",\"text\":\"Checking.\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":0}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"tool_use\",\"id\":\"toolu_1\",\"name\":\"get_weather\",\"input\":{}}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"city\\\": \\\"Par\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"is\\\"}\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":1}\n\n",
+    "event: message_delta\n",
+    "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"tool_use\",\"stop_sequence\":null},\"usage\":{\"output_tokens\":30}}\n\n",
+    "event: message_stop\n",
+    "data: {\"type\":\"message_stop\"}\n\n",
+  );
+  let accumulator = accumulate( events ).expect( "accumulates" );
+  let tool_uses = accumulator.tool_uses();
+  assert_eq!( tool_uses.len(), 1 );
+  assert_eq!( ( tool_uses[ 0 ].id.as_str(), tool_uses[ 0 ].name.as_str() ), ( "toolu_1", "get_weather" ) );
+  assert_eq!( tool_uses[ 0 ].input, serde_json::json!( { "city" : "Paris" } ) );
+
+  let response = accumulator.response().expect( "message started" );
+  let types : Vec< &str > = response.content.iter().map( | content | content.r#type.as_str() ).collect();
+  assert_eq!( types, [ "text", "tool_use" ] );
+  assert_eq!( response.stop_reason.as_deref(), Some( "tool_use" ) );
+}
+
+#[ test ]
+fn error_events_and_out_of_sequence_events_fail()
+{
+  let overloaded = concat!
+  (
+    "event: error\n",
+    "data: {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}\n\n",
+  );
+  let events = the_module::parse_sse_events( overloaded ).expect( "parses" );
+  assert_eq!( events.len(), 1, "{events:?}" );
+  let error = MessageAccumulator::new().push( &events[ 0 ] ).expect_err( "error event" );
+  assert!( matches!( &error, the_module::AnthropicError::Api( api ) if api.r#type == "overloaded_error" ), "{error:?}" );
+
+  let mut accumulator = accumulate( &TEXT_EVENTS[ ..TEXT_EVENTS.find( "event: content_block_start" ).expect( "has a block" ) ] ).expect( "started" );
+  let stray = StreamEvent::content_block_delta( 3, the_module::StreamDelta::new_text( "lost" ) );
+  assert!( accumulator.push( &stray ).is_err() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_streamed_message_matches_accumulated_text()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let request = the_module::CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-5-20250929" )
+    .max_tokens( 50 )
+    .message( the_module::Message::user( "Reply with exactly: streaming works" ) )
+    .build();
+
+  let stream = client.messages().create_stream( request ).await
+    .expect( "INTEGRATION: create_stream must succeed" );
+  let accumulator = MessageAccumulator::collect( stream ).await
+    .expect( "INTEGRATION: every event must apply" );
+  assert!( accumulator.is_complete() );
+
+  let response = accumulator.response().expect( "message started" );
+  assert!( response.text().unwrap_or_default().to_lowercase().contains( "streaming works" ), "{response:?}" );
+  assert!( response.stop_reason.is_some() );
+  assert!( response.usage.output_tokens > 0 );
+}
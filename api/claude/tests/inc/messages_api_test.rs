@@ -64,6 +64,7 @@ async fn test_create_message_request_basic()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -92,6 +93,7 @@ async fn test_create_message_request_with_system_prompt()
     system : Some( vec![ the_module::SystemContent::text( "You are a physics professor. Explain complex topics simply." ) ] ),
     temperature : Some( 0.7 ),
     stream : Some( false ),
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -123,6 +125,7 @@ async fn test_create_message_request_conversation()
     system : None,
     temperature : Some( 0.3 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -184,6 +187,7 @@ async fn test_create_message_request_validation()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -245,6 +249,7 @@ async fn test_response_content_structure()
   {
     r#type : "text".to_string(),
     text : Some( "This is a response".to_string() ),
+    ..Default::default()
   };
   
   assert_eq!( content.r#type, "text" );
@@ -281,6 +286,7 @@ async fn test_create_message_response_structure()
       {
         r#type : "text".to_string(),
         text : Some( "Test response".to_string() ),
+        ..Default::default()
       }
     ],
     model : "claude-sonnet-4-5-20250929".to_string(),
@@ -350,7 +356,7 @@ async fn test_create_message_response_text_with_text_content()
     r#type : "message".to_string(),
     role : "assistant".to_string(),
     content : vec![
-      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "Hello!" .to_string() ) },
+      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "Hello!" .to_string() ), ..Default::default() },
     ],
     model : "claude-haiku-4-5-20251001".to_string(),
     stop_reason : Some( "end_turn".to_string() ),
@@ -399,7 +405,7 @@ async fn test_create_message_response_text_with_non_text_content()
     r#type : "message".to_string(),
     role : "assistant".to_string(),
     content : vec![
-      the_module::ResponseContent { r#type : "tool_use".to_string(), text : None },
+      the_module::ResponseContent { r#type : "tool_use".to_string(), text : None, ..Default::default() },
     ],
     model : "claude-haiku-4-5-20251001".to_string(),
     stop_reason : Some( "tool_use".to_string() ),
@@ -425,8 +431,8 @@ async fn test_create_message_response_text_returns_first_text_block()
     r#type : "message".to_string(),
     role : "assistant".to_string(),
     content : vec![
-      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "first".to_string() ) },
-      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "second".to_string() ) },
+      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "first".to_string() ), ..Default::default() },
+      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "second".to_string() ), ..Default::default() },
     ],
     model : "claude-haiku-4-5-20251001".to_string(),
     stop_reason : Some( "end_turn".to_string() ),
@@ -451,7 +457,7 @@ async fn test_create_message_response_is_truncated_max_tokens()
     r#type : "message".to_string(),
     role : "assistant".to_string(),
     content : vec![
-      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "truncated...".to_string() ) },
+      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "truncated...".to_string() ), ..Default::default() },
     ],
     model : "claude-haiku-4-5-20251001".to_string(),
     stop_reason : Some( "max_tokens".to_string() ),
@@ -476,7 +482,7 @@ async fn test_create_message_response_is_not_truncated_end_turn()
     r#type : "message".to_string(),
     role : "assistant".to_string(),
     content : vec![
-      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "complete.".to_string() ) },
+      the_module::ResponseContent { r#type : "text".to_string(), text : Some( "complete.".to_string() ), ..Default::default() },
     ],
     model : "claude-haiku-4-5-20251001".to_string(),
     stop_reason : Some( "end_turn".to_string() ),
@@ -539,6 +545,7 @@ async fn integration_messages_api_real_request_response_structures()
     system : Some( vec![ the_module::SystemContent::text( "You are a helpful math tutor." ) ] ),
     temperature : Some( 0.1 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -1,5 +1,7 @@
 use super::*;
 
+#[ cfg( feature = "admin" ) ]
+mod admin_api_test;
 mod authentication_test;
 #[ cfg( all( feature = "batch-processing", feature = "error-handling" ) ) ]
 mod batch_messages_test;
@@ -9,6 +11,8 @@ mod circuit_breaker_test;
 #[ cfg( feature = "compression" ) ]
 mod compression_test;
 mod comprehensive_integration_test;
+#[ cfg( feature = "error-handling" ) ]
+mod connection_test;
 mod content_generation_test;
 mod content_generation_refactor_test;
 mod core_client_test;
@@ -24,25 +28,37 @@ mod enhanced_model_details_test;
 mod error_handling_integration_test;
 #[ cfg( feature = "failover" ) ]
 mod failover_test;
+#[ cfg( all( feature = "files", feature = "error-handling" ) ) ]
+mod files_and_documents_test;
 mod general_diagnostics_test;
 #[ cfg( feature = "health-checks" ) ]
 mod health_checks_test;
 mod error_handling_test;
 mod example_model_validation_test;
 mod examples_validation_test;
+mod extended_thinking_test;
 mod fallback_behavior_integration_test;
 mod messages_api_test;
+#[ cfg( feature = "retry" ) ]
+mod message_retries_test;
+#[ cfg( feature = "streaming" ) ]
+mod message_stream_test;
 mod model_management_test;
+#[ cfg( feature = "model-management" ) ]
+mod models_api_test;
 mod performance_test;
 mod performance_monitoring_test;
 mod prompt_caching_tests;
 #[ cfg( feature = "rate-limiting" ) ]
 mod rate_limiting_test;
+mod redaction_test;
 #[ cfg( feature = "request-caching" ) ]
 mod request_caching_test;
 #[ cfg( feature = "request-templates" ) ]
 mod request_templates_test;
 mod retry_logic_test;
+#[ cfg( feature = "tools" ) ]
+mod server_tools_test;
 mod simple_integration_test;
 mod spec_verification_integration_test;
 mod streaming_test;
@@ -62,6 +78,8 @@ mod system_instructions_test;
 mod token_counting_test;
 mod token_validation_test;
 mod tool_calling_test;
+#[ cfg( feature = "tool-runner" ) ]
+mod tool_runner_test;
 mod vision_support_test;
 mod thin_client_principle_test;
 mod testing_standards_test;
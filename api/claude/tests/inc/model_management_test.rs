@@ -45,6 +45,7 @@ async fn integration_model_management_real_model_validation()
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     };
@@ -93,6 +94,7 @@ async fn integration_model_management_invalid_model_handling()
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     };
@@ -129,8 +131,9 @@ async fn integration_model_management_capability_validation()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : Some( vec![ 
-      the_module::ToolDefinition::simple( "calculator", "Calculate mathematical expressions" ) 
+      the_module::ToolDefinition::simple( "calculator", "Calculate mathematical expressions" ).into()
     ] ),
     tool_choice : None,
   };
@@ -0,0 +1,82 @@
+//! Tests for the Models API (`client.models()`).
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `get_rejects_empty_model_id` | `get` with an empty ID fails before any request is sent |
+//! | `model_list_page_parses` | A `/v1/models` page and its cursors deserialize into `ModelListResponse` |
+//! | `integration_models_list_and_get` | Real API pages through the models and resolves a listed ID |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ ModelListResponse, ModelMetadata };
+
+#[ tokio::test ]
+async fn get_rejects_empty_model_id()
+{
+  let client = the_module::Client::new( the_module::Secret::new_unchecked( "sk-ant-api03-".to_string() + &"x".repeat( 80 ) ) );
+  let error = client.models().get( "" ).await.expect_err( "empty id" );
+  assert!( matches!( error, the_module::AnthropicError::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+#[ test ]
+fn model_list_page_parses()
+{
+  let page : ModelListResponse = serde_json::from_value( serde_json::json!(
+  {
+    "data" :
+    [
+      { "type" : "model", "id" : "claude-sonnet-4-6", "display_name" : "Claude Sonnet 4.6", "created_at" : "2026-02-17T00:00:00Z" },
+      { "type" : "model", "id" : "claude-haiku-4-5-20251001", "display_name" : "Claude Haiku 4.5", "created_at" : "2025-10-01T00:00:00Z" },
+    ],
+    "has_more" : true,
+    "first_id" : "claude-sonnet-4-6",
+    "last_id" : "claude-haiku-4-5-20251001",
+  } ) ).expect( "page parses" );
+
+  assert!( page.has_more );
+  assert_eq!( page.last_id.as_deref(), Some( "claude-haiku-4-5-20251001" ) );
+  assert_eq!( page.data[ 0 ], ModelMetadata
+  {
+    id : "claude-sonnet-4-6".to_string(),
+    r#type : "model".to_string(),
+    display_name : "Claude Sonnet 4.6".to_string(),
+    created_at : "2026-02-17T00:00:00Z".to_string(),
+  } );
+
+  let last : ModelListResponse = serde_json::from_value( serde_json::json!(
+  {
+    "data" : [], "has_more" : false, "first_id" : null, "last_id" : null,
+  } ) ).expect( "empty page parses" );
+  assert!( last.data.is_empty() && last.last_id.is_none() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_models_list_and_get()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+
+  let first = client.models().list( None, None, Some( 1 ) ).await
+    .expect( "INTEGRATION: listing models must succeed" );
+  assert_eq!( first.data.len(), 1, "{first:?}" );
+  assert_eq!( first.first_id, first.last_id );
+
+  let all = client.models().list_all().await
+    .expect( "INTEGRATION: listing all models must succeed" );
+  assert!( all.len() >= first.data.len() );
+  assert_eq!( all[ 0 ].id, first.data[ 0 ].id );
+  if first.has_more
+  {
+    let second = client.models().list( None, first.last_id.as_deref(), Some( 1 ) ).await
+      .expect( "INTEGRATION: second page must succeed" );
+    assert_eq!( second.data[ 0 ].id, all[ 1 ].id );
+  }
+
+  let model = client.models().get( &first.data[ 0 ].id ).await
+    .expect( "INTEGRATION: retrieving a listed model must succeed" );
+  assert_eq!( model, first.data[ 0 ] );
+  assert_eq!( model.r#type, "model" );
+}
@@ -249,6 +249,7 @@ async fn integration_performance_monitoring_api_request()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -305,6 +306,7 @@ async fn integration_performance_monitoring_multiple_requests()
       system : None,
       temperature : Some( 0.0 ),
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -369,6 +371,7 @@ async fn integration_performance_monitoring_throughput_measurement()
       system : None,
       temperature : Some( 0.0 ),
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -25,6 +25,7 @@ async fn test_request_construction_performance()
       system : Some( vec![ the_module::SystemContent::text( "Test system" ) ] ),
       temperature : Some( 0.5 ),
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     };
@@ -55,6 +56,7 @@ async fn test_message_serialization_performance()
     system : Some( vec![ the_module::SystemContent::text( "You are a performance testing assistant" ) ] ),
     temperature : Some( 0.3 ),
     stream : Some( false ),
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -158,6 +160,7 @@ async fn test_memory_usage_patterns()
       system : Some( vec![ the_module::SystemContent::text( "Memory testing" ) ] ),
       temperature : Some( 0.5 ),
       stream : Some( false ),
+      thinking : None,
       tools : None,
       tool_choice : None,
     };
@@ -208,6 +211,7 @@ async fn test_concurrent_request_construction_performance()
         system : Some( vec![ the_module::SystemContent::text( format!( "System {i}" ) ) ] ),
         temperature : Some( 0.5 ),
         stream : Some( false ),
+        thinking : None,
         tools : None,
         tool_choice : None,
       };
@@ -246,6 +250,7 @@ async fn integration_performance_api_response_time()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -294,6 +299,7 @@ async fn integration_performance_concurrent_api_requests()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -306,6 +312,7 @@ async fn integration_performance_concurrent_api_requests()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -318,6 +325,7 @@ async fn integration_performance_concurrent_api_requests()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -37,6 +37,18 @@ mod cache_control_tests
     let cache_control : CacheControl = serde_json::from_str( json ).unwrap();
 
     assert_eq!( cache_control.cache_type, "ephemeral" );
+    assert_eq!( cache_control.ttl, None );
+  }
+
+  #[ test ]
+  fn test_cache_control_ttl()
+  {
+    // Test the 1-hour TTL round trip
+    let json = serde_json::to_value( CacheControl::ephemeral_1h() ).unwrap();
+    assert_eq!( json, serde_json::json!( { "type" : "ephemeral", "ttl" : "1h" } ) );
+
+    let five : CacheControl = serde_json::from_str( r#"{"type": "ephemeral", "ttl": "5m"}"# ).unwrap();
+    assert_eq!( five, CacheControl::ephemeral().with_ttl( CacheTtl::FiveMinutes ) );
   }
 }
 
@@ -119,6 +131,7 @@ mod message_caching_tests
     {
       r#type : "text".to_string(),
       text : "Hello, how are you?".to_string(),
+      cache_control : None,
     };
 
     let message = Message
@@ -140,6 +153,7 @@ mod message_caching_tests
     {
       r#type : "text".to_string(),
       text : "Test message".to_string(),
+      cache_control : None,
     };
 
     let message = Message
@@ -181,6 +195,7 @@ mod request_caching_tests
       system : Some( system ),
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -211,6 +226,7 @@ mod request_caching_tests
       system : Some( system ),
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -362,3 +378,62 @@ mod cache_statistics_tests
     // This test verifies we can extract the data needed for cost calculation
   }
 }
+
+#[ cfg( all( test, feature = "tools", feature = "error-handling" ) ) ]
+mod cache_breakpoint_tests
+{
+  use super::*;
+
+  fn request( messages : Vec< Message > ) -> CreateMessageRequest
+  {
+    CreateMessageRequest::builder()
+      .model( "claude-sonnet-4-6" )
+      .max_tokens( 1024 )
+      .system_blocks( vec![ SystemContent::text( "Cached instructions" ).with_cache_control( CacheControl::ephemeral_1h() ) ] )
+      .tool( ToolDefinition::simple( "lookup", "Look up a clause" ).with_cache_control( CacheControl::ephemeral_1h() ) )
+      .messages( messages )
+      .build()
+  }
+
+  #[ test ]
+  fn test_content_block_cache_control_serialization()
+  {
+    // Test the breakpoint is sent on the block, not the message
+    let message = Message::user( "Long contract text" ).with_cache_control( CacheControl::ephemeral() );
+    let json = serde_json::to_value( &message ).unwrap();
+
+    assert_eq!( json[ "content" ][ 0 ][ "cache_control" ][ "type" ], "ephemeral" );
+    assert!( json.get( "cache_control" ).is_none() );
+
+    let thinking = Content::thinking( "reasoning", "sig" ).with_cache_control( CacheControl::ephemeral() );
+    assert!( thinking.cache_control().is_none() );
+
+    let parsed : Content = serde_json::from_value( json[ "content" ][ 0 ].clone() ).unwrap();
+    assert_eq!( parsed, message.content[ 0 ] );
+  }
+
+  #[ test ]
+  fn test_tool_definition_cache_control_serialization()
+  {
+    // Test tool definitions carry their breakpoint
+    let json = serde_json::to_value( request( vec![ Message::user( "Hi" ) ] ) ).unwrap();
+
+    assert_eq!( json[ "tools" ][ 0 ][ "cache_control" ], serde_json::json!( { "type" : "ephemeral", "ttl" : "1h" } ) );
+    assert!( serde_json::to_value( ToolDefinition::simple( "plain", "No breakpoint" ) ).unwrap().get( "cache_control" ).is_none() );
+  }
+
+  #[ test ]
+  fn test_cache_breakpoint_limit()
+  {
+    // Test at most four breakpoints pass validation
+    let cached = | text : &str | Message::user( text ).with_cache_control( CacheControl::ephemeral() );
+    let within = request( vec![ cached( "one" ), Message::assistant( "ok" ), cached( "two" ) ] );
+    assert_eq!( within.cache_breakpoints(), MAX_CACHE_BREAKPOINTS );
+    assert!( within.validate().is_ok() );
+
+    let over = request( vec![ cached( "one" ), Message::assistant( "ok" ), cached( "two" ), Message::assistant( "ok" ), cached( "three" ) ] );
+    assert_eq!( over.cache_breakpoints(), 5 );
+    let error = over.validate().unwrap_err();
+    assert!( matches!( error, AnthropicError::InvalidRequest( ref message ) if message.contains( "at most 4" ) ), "{error:?}" );
+  }
+}
@@ -221,6 +221,7 @@ mod rate_limiting_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -239,6 +240,7 @@ mod rate_limiting_functionality_tests
       system : Some( vec![ the_module::SystemContent::text( "You are a helpful AI assistant with expertise in complex reasoning." ) ] ),
       temperature : Some( 0.7 ),
       stream : Some( false ),
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -0,0 +1,50 @@
+//! Tests that the API key never appears in full in `Debug` output.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `secret_debug_masks_key` | `Secret` shows only the key prefix |
+//! | `client_debug_masks_key` | `Client` hides the key of its secret |
+//! | `sync_builder_debug_masks_key` | `SyncClientBuilder` hides the configured key |
+
+#[ allow( unused_imports ) ]
+use super::*;
+
+const KEY : &str = "sk-ant-REDACTED";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    assert!( !output.contains( KEY ), "full key in Debug output : {output}" );
+    assert!( !output.contains( &KEY[ 4.. ] ), "key suffix in Debug output : {output}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn secret_debug_masks_key()
+{
+  let secret = the_module::Secret::new_unchecked( KEY.to_string() );
+  assert_hidden( &secret );
+  assert!( format!( "{secret:?}" ).contains( "sk-a****" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn client_debug_masks_key()
+{
+  assert_hidden( &the_module::Client::new( the_module::Secret::new_unchecked( KEY.to_string() ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ cfg( feature = "sync-api" ) ]
+#[ test ]
+fn sync_builder_debug_masks_key()
+{
+  assert_hidden( &the_module::SyncClientBuilder::new().api_key( KEY ) );
+}
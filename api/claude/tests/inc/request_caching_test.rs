@@ -66,6 +66,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -80,6 +81,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -99,6 +101,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -123,6 +126,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -138,6 +142,7 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "Cached response".to_string() ),
+        ..Default::default()
       } ],
       model : "claude-haiku-4-5-20251001".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -180,6 +185,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -195,6 +201,7 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "This will expire".to_string() ),
+        ..Default::default()
       } ],
       model : "claude-haiku-4-5-20251001".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -240,6 +247,7 @@ mod request_caching_functionality_tests
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -253,6 +261,7 @@ mod request_caching_functionality_tests
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -266,6 +275,7 @@ mod request_caching_functionality_tests
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -285,6 +295,7 @@ mod request_caching_functionality_tests
         {
           r#type : "text".to_string(),
           text : Some( format!( "Response {}", i + 1 ) ),
+          ..Default::default()
         } ],
         model : "claude-haiku-4-5-20251001".to_string(),
         stop_reason : Some( "end_turn".to_string() ),
@@ -324,6 +335,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -339,6 +351,7 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "To be invalidated".to_string() ),
+        ..Default::default()
       } ],
       model : "claude-haiku-4-5-20251001".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -383,6 +396,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -398,6 +412,7 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "Metrics response".to_string() ),
+        ..Default::default()
       } ],
       model : "claude-haiku-4-5-20251001".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -430,6 +445,7 @@ mod request_caching_functionality_tests
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       #[ cfg( feature = "tools" ) ]
       tools : None,
       #[ cfg( feature = "tools" ) ]
@@ -478,6 +494,7 @@ mod request_caching_integration_tests
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -493,6 +510,7 @@ mod request_caching_integration_tests
         {
           r#type : "text".to_string(),
           text : Some( format!( "Response {i}" ) ),
+          ..Default::default()
         } ],
         model : "claude-haiku-4-5-20251001".to_string(),
         stop_reason : Some( "end_turn".to_string() ),
@@ -524,6 +542,7 @@ mod request_caching_integration_tests
         system : None,
         temperature : None,
         stream : None,
+        thinking : None,
         #[ cfg( feature = "tools" ) ]
         tools : None,
         #[ cfg( feature = "tools" ) ]
@@ -0,0 +1,215 @@
+//! Tests for Anthropic-defined tools and their result blocks.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `built_in_tools_serialize_to_their_definitions` | Each tool builder produces the API's tool JSON, parses back to its variant, and is validated in requests |
+//! | `server_tool_results_are_kept_and_passed_back` | `server_tool_use`, web search and code execution results, and web search citations parse and convert to request content |
+//! | `streamed_server_tool_blocks_accumulate` | A streamed server tool call and its result build response blocks and are not reported as caller tool calls |
+//! | `integration_web_search_returns_results` | Real API searches the web and answers with the results |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ BashTool, CodeExecutionTool, ComputerTool, Content, CreateMessageRequest, Message, TextEditorTool, Tool, ToolDefinition, UserLocation, WebSearchTool };
+
+fn request() -> the_module::CreateMessageRequestBuilder
+{
+  CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-6" )
+    .max_tokens( 1024 )
+    .message( Message::user( "What is the weather in Paris?" ) )
+}
+
+#[ test ]
+fn built_in_tools_serialize_to_their_definitions()
+{
+  let search = WebSearchTool::new()
+    .with_max_uses( 3 )
+    .with_allowed_domains( [ "meteofrance.com" ] )
+    .with_user_location( UserLocation::country( "FR" ).with_city( "Paris" ).with_timezone( "Europe/Paris" ) );
+  let tools : Vec< Tool > = vec!
+  [
+    search.into(),
+    CodeExecutionTool::new().into(),
+    ComputerTool::new( 1024, 768 ).with_display_number( 1 ).into(),
+    TextEditorTool::new().with_max_characters( 10_000 ).into(),
+    BashTool::new().into(),
+    ToolDefinition::simple( "get_time", "Get the current time" ).into(),
+  ];
+  let json = serde_json::to_value( &tools ).expect( "serializes" );
+  assert_eq!( json, serde_json::json!(
+  [
+    {
+      "type" : "web_search_20250305",
+      "name" : "web_search",
+      "max_uses" : 3,
+      "allowed_domains" : [ "meteofrance.com" ],
+      "user_location" : { "type" : "approximate", "city" : "Paris", "country" : "FR", "timezone" : "Europe/Paris" },
+    },
+    { "type" : "code_execution_20250522", "name" : "code_execution" },
+    { "type" : "computer_20250124", "name" : "computer", "display_width_px" : 1024, "display_height_px" : 768, "display_number" : 1 },
+    { "type" : "text_editor_20250728", "name" : "str_replace_based_edit_tool", "max_characters" : 10_000 },
+    { "type" : "bash_20250124", "name" : "bash" },
+    { "name" : "get_time", "description" : "Get the current time", "input_schema" : { "type" : "object", "properties" : {}, "required" : [] } },
+  ] ) );
+
+  let parsed : Vec< Tool > = serde_json::from_value( json ).expect( "parses" );
+  assert_eq!( parsed, tools );
+  let names : Vec< &str > = parsed.iter().map( Tool::name ).collect();
+  assert_eq!( names, [ "web_search", "code_execution", "computer", "str_replace_based_edit_tool", "bash", "get_time" ] );
+  assert!( parsed[ 0 ].is_server_tool() && parsed[ 1 ].is_server_tool() && !parsed[ 4 ].is_server_tool() );
+  assert!( parsed[ 5 ].as_custom().is_some() && parsed[ 0 ].as_custom().is_none() );
+
+  // Built-in tools need no description and can be chosen by name
+  let valid = request()
+    .tools( tools )
+    .tool_choice( the_module::ToolChoice::specific( "web_search" ) )
+    .build();
+  assert!( valid.validate().is_ok(), "{:?}", valid.validate() );
+
+  let both_ways = WebSearchTool::new().with_allowed_domains( [ "a.com" ] ).with_blocked_domains( [ "b.com" ] );
+  assert!( request().tool( both_ways ).build().validate().is_err() );
+  assert!( request().tool( WebSearchTool::new().with_max_uses( 0 ) ).build().validate().is_err() );
+  assert!( request().tool( ComputerTool::new( 0, 768 ) ).build().validate().is_err() );
+  assert!( request().tool( BashTool::new() ).tool( BashTool::new() ).build().validate().is_err() );
+  assert!( request().tool( ToolDefinition::simple( "get_time", "" ) ).build().validate().is_err() );
+}
+
+#[ test ]
+fn server_tool_results_are_kept_and_passed_back()
+{
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "server_tool_use", "id" : "srvtoolu_1", "name" : "web_search", "input" : { "query" : "Paris weather" } },
+      {
+        "type" : "web_search_tool_result",
+        "tool_use_id" : "srvtoolu_1",
+        "content" : [ { "type" : "web_search_result", "url" : "https://meteofrance.com/paris", "title" : "Paris", "encrypted_content" : "EqgfCioIARgB", "page_age" : "1 hour ago" } ],
+      },
+      {
+        "type" : "text",
+        "text" : "It is sunny.",
+        "citations" : [ { "type" : "web_search_result_location", "cited_text" : "Sunny, 24C", "url" : "https://meteofrance.com/paris", "title" : "Paris", "encrypted_index" : "Eo8BCioIAhgB" } ],
+      },
+      { "type" : "server_tool_use", "id" : "srvtoolu_2", "name" : "code_execution", "input" : { "code" : "print(24 * 9 / 5 + 32)" } },
+      {
+        "type" : "code_execution_tool_result",
+        "tool_use_id" : "srvtoolu_2",
+        "content" : { "type" : "code_execution_result", "stdout" : "75.2\n", "stderr" : "", "return_code" : 0, "content" : [] },
+      },
+      { "type" : "web_search_tool_result", "tool_use_id" : "srvtoolu_3", "content" : { "type" : "web_search_tool_result_error", "error_code" : "max_uses_exceeded" } },
+    ],
+    "model" : "claude-sonnet-4-6",
+    "stop_reason" : "end_turn",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 900, "output_tokens" : 120 },
+  } ) ).expect( "response parses" );
+
+  let blocks = &response.content;
+  assert_eq!( blocks.len(), 6 );
+  assert_eq!( response.text(), Some( "It is sunny." ) );
+  assert!( blocks[ 0 ].is_server_tool() && blocks[ 1 ].is_server_tool() && !blocks[ 2 ].is_server_tool() );
+  assert_eq!( blocks[ 0 ].input.as_ref().expect( "input" )[ "query" ], "Paris weather" );
+
+  let results = blocks[ 1 ].content.as_ref().and_then( the_module::ServerToolResultContent::web_search_results ).expect( "search results" );
+  assert_eq!( ( results[ 0 ].url.as_str(), results[ 0 ].page_age.as_deref() ), ( "https://meteofrance.com/paris", Some( "1 hour ago" ) ) );
+  let citation = &blocks[ 2 ].citations.as_ref().expect( "citations" )[ 0 ];
+  assert_eq!( ( citation.url(), citation.cited_text(), citation.document_index() ), ( Some( "https://meteofrance.com/paris" ), Some( "Sunny, 24C" ), None ) );
+  let execution = blocks[ 4 ].content.as_ref().and_then( the_module::ServerToolResultContent::code_execution ).expect( "execution" );
+  assert_eq!( ( execution.stdout.as_str(), execution.return_code ), ( "75.2\n", 0 ) );
+  assert_eq!( blocks[ 5 ].content.as_ref().and_then( the_module::ServerToolResultContent::error_code ), Some( "max_uses_exceeded" ) );
+
+  let turn : Vec< Content > = blocks.iter().filter_map( the_module::ResponseContent::to_content ).collect();
+  assert_eq!( turn.len(), 6 );
+  let types : Vec< &str > = turn.iter().map( Content::r#type ).collect();
+  assert_eq!( types, [ "server_tool_use", "web_search_tool_result", "text", "server_tool_use", "code_execution_tool_result", "web_search_tool_result" ] );
+  assert!( turn[ 1 ].is_server_tool_result() && !turn[ 0 ].is_server_tool_result() );
+  assert_eq!( turn[ 3 ].tool_name(), Some( "code_execution" ) );
+
+  let json = serde_json::to_value( &turn ).expect( "serializes" );
+  assert_eq!( json[ 1 ], serde_json::json!(
+  {
+    "type" : "web_search_tool_result",
+    "tool_use_id" : "srvtoolu_1",
+    "content" : [ { "type" : "web_search_result", "url" : "https://meteofrance.com/paris", "title" : "Paris", "encrypted_content" : "EqgfCioIARgB", "page_age" : "1 hour ago" } ],
+  } ) );
+  let parsed : Vec< Content > = serde_json::from_value( json ).expect( "parses" );
+  assert_eq!( parsed, turn );
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn streamed_server_tool_blocks_accumulate()
+{
+  let events = concat!
+  (
+    "event: message_start\n",
+    "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_2\",\"type\":\"message\",\"role\":\"assistant\",\"content\":[],\"model\":\"claude-sonnet-4-6\",\"stop_reason\":null,\"stop_sequence\":null,\"usage\":{\"input_tokens\":40,\"output_tokens\":1}}}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"server_tool_use\",\"id\":\"srvtoolu_1\",\"name\":\"web_search\",\"input\":{}}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"query\\\": \\\"Paris weather\\\"}\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":0}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"web_search_tool_result\",\"tool_use_id\":\"srvtoolu_1\",\"content\":[{\"type\":\"web_search_result\",\"url\":\"https://meteofrance.com/paris\",\"title\":\"Paris\",\"encrypted_content\":\"EqgfCioIARgB\"}]}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":1}\n\n",
+    "event: content_block_start\n",
+    "data: {\"type\":\"content_block_start\",\"index\":2,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+    "event: content_block_delta\n",
+    "data: {\"type\":\"content_block_delta\",\"index\":2,\"delta\":{\"type\":\"text_delta\",\"text\":\"Sunny.\"}}\n\n",
+    "event: content_block_stop\n",
+    "data: {\"type\":\"content_block_stop\",\"index\":2}\n\n",
+    "event: message_stop\n",
+    "data: {\"type\":\"message_stop\"}\n\n",
+  );
+  let events = the_module::parse_sse_events( events ).expect( "events parse" );
+  assert_eq!( events.len(), 10, "{events:?}" );
+  assert!( matches!( &events[ 4 ], the_module::StreamEvent::ContentBlockStart { content_block, .. } if content_block.is_server_tool_result() ) );
+
+  let mut accumulator = the_module::MessageAccumulator::new();
+  for event in &events
+  {
+    accumulator.push( event ).expect( "event applies" );
+  }
+  assert!( accumulator.tool_uses().is_empty(), "server tool calls are not for the caller" );
+  assert_eq!( accumulator.text(), "Sunny." );
+
+  let response = accumulator.response().expect( "message started" );
+  let types : Vec< &str > = response.content.iter().map( | content | content.r#type.as_str() ).collect();
+  assert_eq!( types, [ "server_tool_use", "web_search_tool_result", "text" ] );
+  assert_eq!( response.content[ 0 ].input, Some( serde_json::json!( { "query" : "Paris weather" } ) ) );
+  assert_eq!( response.content[ 1 ].tool_use_id.as_deref(), Some( "srvtoolu_1" ) );
+  assert_eq!( response.content[ 1 ].content.as_ref().and_then( the_module::ServerToolResultContent::web_search_results ).map( <[ _ ]>::len ), Some( 1 ) );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_web_search_returns_results()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let request = CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-5-20250929" )
+    .max_tokens( 1024 )
+    .tool( WebSearchTool::new().with_max_uses( 1 ) )
+    .message( Message::user( "Search the web for the current stable Rust version and answer in one sentence." ) )
+    .build();
+
+  let response = client.create_message( request ).await
+    .expect( "INTEGRATION: message with web search must succeed" );
+  assert!( response.content.iter().any( | block | block.r#type == "server_tool_use" ), "{response:?}" );
+  let result = response.content.iter()
+    .find( | block | block.r#type == "web_search_tool_result" )
+    .and_then( | block | block.content.as_ref() )
+    .expect( "INTEGRATION: response must contain a web search result" );
+  assert!( result.web_search_results().is_some_and( | results | !results.is_empty() ), "{result:?}" );
+  assert!( response.text().is_some_and( | text | text.contains( "Rust" ) ), "{response:?}" );
+}
@@ -31,6 +31,7 @@ async fn test_integration_test_request_construction()
     system : Some( vec![ the_module::SystemContent::text( "Test system" ) ] ),
     temperature : Some( 0.5 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -92,6 +93,7 @@ async fn integration_messages_basic_text_generation()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -141,6 +143,7 @@ async fn integration_messages_with_system_prompt()
     system : Some( vec![ the_module::SystemContent::text( "You are a helpful assistant. Always respond with exactly 5 words." ) ] ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -188,6 +191,7 @@ async fn integration_messages_conversation_flow()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -239,7 +243,8 @@ async fn integration_tool_calling_basic()
     system : None,
     temperature : None,
     stream : None,
-    tools : Some( vec![ calculator_tool ] ),
+    thinking : None,
+    tools : Some( vec![ calculator_tool.into() ] ),
     tool_choice : None,
   };
 
@@ -290,6 +295,7 @@ async fn integration_error_handling_invalid_model()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -334,6 +340,7 @@ async fn integration_performance_response_time()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
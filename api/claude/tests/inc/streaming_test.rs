@@ -107,6 +107,7 @@ async fn integration_streaming_real_api()
     system : None,
     temperature : Some( 0.0 ),
     stream : Some( true ), // Enable streaming
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -146,6 +147,7 @@ async fn integration_streaming_method_availability()
     system : None,
     temperature : None,
     stream : Some( true ),
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -33,6 +33,7 @@ fn test_log_request_structure()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -269,6 +270,7 @@ async fn integration_log_api_request_response()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -322,6 +324,7 @@ async fn integration_log_api_error()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -370,6 +373,7 @@ async fn integration_structured_logging_with_context()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
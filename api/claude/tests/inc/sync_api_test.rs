@@ -65,6 +65,7 @@ fn test_sync_message_operations()
       messages : vec![ Message::user( "Hello, world!" ) ],
       system : None,
       stream : None,
+      thinking : None,
       temperature : None,
       #[ cfg( feature = "tools" ) ]
       tool_choice : None,
@@ -106,6 +107,7 @@ fn test_sync_message_with_system_prompt()
       messages : vec![ Message::user( "What is 2+2?" ) ],
       system : Some( vec![ the_module::SystemContent::text( "You are a helpful assistant that responds concisely." ) ] ),
       stream : None,
+      thinking : None,
       temperature : None,
       #[ cfg( feature = "tools" ) ]
       tool_choice : None,
@@ -520,4 +522,18 @@ mod sync_api_feature_tests
     assert!( result.is_ok() || result.is_err(),
              "Builder should return result" );
   }
+
+  /// Test that `BlockingClient` is the sync client and reports its configured timeout
+  #[ test ]
+  fn test_blocking_client_reports_configured_timeout()
+  {
+    use core::time::Duration;
+
+    let client : the_module::BlockingClient = the_module::SyncClientBuilder::new()
+      .timeout( Duration::from_secs( 7 ) )
+      .build( &format!( "sk-ant-api03-{}", "x".repeat( 95 ) ) )
+      .expect( "builder with a well-formed key must succeed" );
+    assert_eq!( client.get_timeout(), Duration::from_secs( 7 ) );
+    assert_eq!( client.async_client().config().request_timeout, Duration::from_secs( 7 ) );
+  }
 }
\ No newline at end of file
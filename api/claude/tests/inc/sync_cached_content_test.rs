@@ -22,6 +22,7 @@ fn test_sync_cached_message_structure()
     {
       r#type : "text".to_string(),
       text : "Test message with caching".to_string(),
+      cache_control : None,
     } ],
     cache_control : Some( cache_control ),
   };
@@ -77,6 +78,7 @@ fn integration_sync_cached_content_creation()
     system : Some( system_prompt ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -129,6 +131,7 @@ fn integration_sync_cache_hit_scenario()
     system : Some( system_prompt.clone() ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -154,6 +157,7 @@ fn integration_sync_cache_hit_scenario()
     system : Some( system_prompt ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -202,6 +206,7 @@ fn integration_sync_cache_error_handling()
     system : Some( system_prompt ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -242,6 +247,7 @@ fn integration_sync_cached_content_cost_savings()
     system : Some( system_prompt ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
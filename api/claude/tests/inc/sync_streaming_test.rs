@@ -37,6 +37,7 @@ fn integration_sync_streaming_text_generation()
     system : None,
     temperature : Some( 0.7 ),
     stream : Some( true ), // Enable streaming
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -78,6 +79,27 @@ fn integration_sync_streaming_text_generation()
   println!( "   Total text length : {} chars", accumulated_text.len() );
 }
 
+#[ cfg( feature = "integration" ) ]
+#[ test ]
+fn integration_blocking_stream_accumulates_into_the_response()
+{
+  let client = the_module::SyncClient::from_workspace()
+    .expect( "INTEGRATION: Must have valid API key for sync streaming test" );
+  let request = the_module::CreateMessageRequest::builder()
+    .model( "claude-haiku-4-5-20251001" )
+    .max_tokens( 20 )
+    .message( the_module::Message::user( "Say hi." ) )
+    .build();
+
+  let response = client.create_message_stream( &request )
+    .expect( "INTEGRATION: Stream must open" )
+    .into_response()
+    .expect( "INTEGRATION: Stream must accumulate" );
+  assert!( response.text().is_some_and( | text | !text.is_empty() ), "{response:?}" );
+  assert!( response.stop_reason.is_some(), "{response:?}" );
+  assert!( response.usage.output_tokens > 0, "{response:?}" );
+}
+
 #[ cfg( feature = "integration" ) ]
 #[ test ]
 fn integration_sync_streaming_error_handling()
@@ -94,6 +116,7 @@ fn integration_sync_streaming_error_handling()
     system : None,
     temperature : Some( 0.0 ),
     stream : Some( true ),
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -122,6 +145,7 @@ fn integration_sync_streaming_blocking_iteration()
     system : None,
     temperature : Some( 0.0 ),
     stream : Some( true ),
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
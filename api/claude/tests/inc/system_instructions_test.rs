@@ -127,6 +127,7 @@ async fn integration_single_system_instruction()
     system : Some( system ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -172,6 +173,7 @@ async fn integration_multi_part_system_instructions()
     system : Some( system ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -219,6 +221,7 @@ async fn integration_system_instructions_with_caching()
     system : Some( system ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -257,6 +260,7 @@ async fn integration_empty_system_instructions()
     system : None,
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -299,6 +303,7 @@ async fn integration_long_system_instruction()
     system : Some( system ),
     temperature : Some( 0.0 ),
     stream : None,
+    thinking : None,
     #[ cfg( feature = "tools" ) ]
     tools : None,
     #[ cfg( feature = "tools" ) ]
@@ -22,6 +22,7 @@ mod private
       system : None,
       temperature : None,
       stream : None,
+      thinking : None,
       tools : None,
       tool_choice : None,
     }
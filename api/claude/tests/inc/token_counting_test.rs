@@ -83,6 +83,7 @@ async fn test_token_count_request_with_tools()
         "location": { "type": "string" }
       }
     }),
+    cache_control : None,
   };
 
   let request = the_module::CountMessageTokensRequest
@@ -90,7 +91,7 @@ async fn test_token_count_request_with_tools()
     model : "claude-sonnet-4-5-20250929".to_string(),
     messages,
     system : None,
-    tools : Some( vec![ tool ] ),
+    tools : Some( vec![ tool.into() ] ),
   };
 
   assert_eq!( request.messages.len(), 1 );
@@ -132,12 +133,14 @@ async fn test_token_count_request_with_image()
   {
     r#type : "text".to_string(),
     text : "What's in this image?".to_string(),
+    cache_control : None,
   };
 
   let image_content = Content::Image
   {
     r#type : "image".to_string(),
     source : ImageSource::png( "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==" ),
+    cache_control : None,
   };
 
   let message = the_module::Message
@@ -265,6 +268,7 @@ async fn integration_token_count_with_tools()
       },
       "required": ["operation", "a", "b"]
     }),
+    cache_control : None,
   };
 
   let messages = vec![
@@ -276,7 +280,7 @@ async fn integration_token_count_with_tools()
     model : "claude-sonnet-4-5-20250929".to_string(),
     messages,
     system : None,
-    tools : Some( vec![ tool ] ),
+    tools : Some( vec![ tool.into() ] ),
   };
 
   let response = client.count_message_tokens( request ).await
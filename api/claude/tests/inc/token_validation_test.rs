@@ -91,6 +91,7 @@ async fn test_live_token_authentication_verification()
     system : None,
     temperature : Some( 0.0 ), // Deterministic for testing
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -145,6 +146,7 @@ async fn test_live_token_authentication_verification()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
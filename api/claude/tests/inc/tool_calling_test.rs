@@ -34,6 +34,7 @@ async fn test_tool_definition_structure()
       },
       "required": ["location"]
     }),
+    cache_control : None,
   };
   
   assert_eq!( tool.name, "get_weather" );
@@ -114,6 +115,7 @@ async fn test_message_with_tool_definitions()
           "location": {"type": "string"}
         }
       }),
+      cache_control : None,
     },
     the_module::ToolDefinition
     {
@@ -126,6 +128,7 @@ async fn test_message_with_tool_definitions()
           "expression": {"type": "string"}
         }
       }),
+      cache_control : None,
     }
   ];
   
@@ -137,8 +140,8 @@ async fn test_message_with_tool_definitions()
     .build();
   
   assert_eq!( request.tools.as_ref().unwrap().len(), 2 );
-  assert_eq!( request.tools.as_ref().unwrap()[0].name, "get_weather" );
-  assert_eq!( request.tools.as_ref().unwrap()[1].name, "calculate_math" );
+  assert_eq!( request.tools.as_ref().unwrap()[0].name(), "get_weather" );
+  assert_eq!( request.tools.as_ref().unwrap()[1].name(), "calculate_math" );
 }
 
 #[ cfg( feature = "integration" ) ]
@@ -327,6 +330,7 @@ async fn test_tool_definition_json_serialization()
         "numbers": {"type": "array", "items": {"type": "number"}}
       }
     }),
+    cache_control : None,
   };
   
   let json = serde_json::to_string( &tool ).expect( "Should serialize successfully" );
@@ -378,6 +382,7 @@ async fn test_multiple_tools_with_different_schemas()
         },
         "required": ["action", "path"]
       }),
+      cache_control : None,
     },
     the_module::ToolDefinition
     {
@@ -393,6 +398,7 @@ async fn test_multiple_tools_with_different_schemas()
         },
         "required": ["sql"]
       }),
+      cache_control : None,
     }
   ];
   
@@ -407,11 +413,11 @@ async fn test_multiple_tools_with_different_schemas()
   assert_eq!( request.tools.as_ref().unwrap().len(), 2 );
   
   // Verify complex schema structures
-  let file_tool = &request.tools.as_ref().unwrap()[0];
+  let file_tool = request.tools.as_ref().unwrap()[0].as_custom().unwrap();
   assert!( file_tool.input_schema.get( "properties" ).unwrap().is_object() );
   assert!( file_tool.input_schema.get( "required" ).unwrap().is_array() );
   
-  let db_tool = &request.tools.as_ref().unwrap()[1];
+  let db_tool = request.tools.as_ref().unwrap()[1].as_custom().unwrap();
   assert!( db_tool.input_schema.get( "properties" ).unwrap().get( "timeout" ).unwrap().get( "default" ).is_some() );
 }
 
@@ -436,6 +442,7 @@ async fn test_tool_calling_with_streaming()
             "topic": {"type": "string"}
           }
         }),
+        cache_control : None,
       }
     ] )
     .tool_choice( the_module::ToolChoice::Auto )
@@ -459,6 +466,7 @@ async fn test_tool_validation_errors()
     name : String::new(),
     description : "Valid description".to_string(),
     input_schema : serde_json::json!({ "type": "object" }),
+    cache_control : None,
   };
   
   assert!( invalid_tool.name.is_empty() );
@@ -469,6 +477,7 @@ async fn test_tool_validation_errors()
     name : "valid_name".to_string(),
     description : String::new(),
     input_schema : serde_json::json!({ "type": "object" }),
+    cache_control : None,
   };
   
   assert!( invalid_tool2.description.is_empty() );
@@ -501,6 +510,7 @@ async fn integration_tool_calling_real_math_tool()
       },
       "required": ["expression"]
     }),
+    cache_control : None,
   };
 
   // Fix(BUG-001): Use Claude 3.5 Haiku for tool calling tests
@@ -514,7 +524,8 @@ async fn integration_tool_calling_real_math_tool()
     system : Some( vec![ the_module::SystemContent::text( "You have access to a calculator tool. Use it for mathematical calculations." ) ] ),
     temperature : Some( 0.1 ),
     stream : None,
-    tools : Some( vec![ calculator_tool ] ),
+    thinking : None,
+    tools : Some( vec![ calculator_tool.into() ] ),
     tool_choice : None, // Let the model decide when to use tools
   };
 
@@ -584,6 +595,7 @@ async fn integration_tool_calling_multiple_tools()
       },
       "required": ["location"]
     }),
+    cache_control : None,
   };
 
   // Fix(BUG-001): Use Claude 3.5 Haiku for tool calling tests
@@ -599,7 +611,8 @@ async fn integration_tool_calling_multiple_tools()
     system : Some( vec![ the_module::SystemContent::text( "You have access to calculator and weather tools. Use the appropriate tool for the user's request." ) ] ),
     temperature : Some( 0.0 ),
     stream : None,
-    tools : Some( vec![ calculator_tool, weather_tool ] ),
+    thinking : None,
+    tools : Some( vec![ calculator_tool.into(), weather_tool.into() ] ),
     tool_choice : None,
   };
 
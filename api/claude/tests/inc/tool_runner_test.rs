@@ -0,0 +1,112 @@
+//! Tests for the `ToolRunner` tool-use loop.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | `answer_runs_tool_calls_in_order` | Each `tool_use` gets a `tool_result`; failing and unregistered tools become error results; server tool calls are skipped |
+//! | `runner_reports_tools_and_limits` | Definitions are sorted, a zero limit is rejected before any request, and `is_complete` follows the stop reason |
+//! | `integration_runner_answers_with_tool_output` | Real API calls a registered tool and answers with its output |
+
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ CreateMessageRequest, CreateMessageResponse, Message, ToolDefinition, ToolRunner };
+
+fn runner() -> ToolRunner
+{
+  ToolRunner::new()
+    .tool( ToolDefinition::simple( "get_time", "Get the current UTC time" ), | _input | async { Ok( "12:00 UTC".to_string() ) } )
+    .tool( ToolDefinition::simple( "divide", "Divide a by b" ), | input | async move
+    {
+      let a = input[ "a" ].as_f64().ok_or( "missing a" )?;
+      let b = input[ "b" ].as_f64().ok_or( "missing b" )?;
+      if b == 0.0 { Err( "division by zero".to_string() ) } else { Ok( ( a / b ).to_string() ) }
+    } )
+}
+
+fn response( stop_reason : &str, content : &serde_json::Value ) -> CreateMessageResponse
+{
+  serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_1",
+    "type" : "message",
+    "role" : "assistant",
+    "content" : content,
+    "model" : "claude-sonnet-4-6",
+    "stop_reason" : stop_reason,
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 100, "output_tokens" : 30 },
+  } ) ).expect( "response parses" )
+}
+
+#[ tokio::test ]
+async fn answer_runs_tool_calls_in_order()
+{
+  let calls = response( "tool_use", &serde_json::json!(
+  [
+    { "type" : "text", "text" : "Let me check." },
+    { "type" : "tool_use", "id" : "toolu_1", "name" : "divide", "input" : { "a" : 9, "b" : 2 } },
+    { "type" : "server_tool_use", "id" : "srvtoolu_1", "name" : "web_search", "input" : { "query" : "time" } },
+    { "type" : "tool_use", "id" : "toolu_2", "name" : "divide", "input" : { "a" : 1, "b" : 0 } },
+    { "type" : "tool_use", "id" : "toolu_3", "name" : "get_weather", "input" : {} },
+    { "type" : "tool_use", "id" : "toolu_4", "name" : "get_time", "input" : {} },
+  ] ) );
+
+  let message = runner().answer( &calls ).await.expect( "tool results" );
+  assert_eq!( message.role, the_module::Role::User );
+  assert_eq!( serde_json::to_value( &message.content ).expect( "serializes" ), serde_json::json!(
+  [
+    { "type" : "tool_result", "tool_use_id" : "toolu_1", "content" : "4.5" },
+    { "type" : "tool_result", "tool_use_id" : "toolu_2", "content" : "division by zero", "is_error" : true },
+    { "type" : "tool_result", "tool_use_id" : "toolu_3", "content" : "Tool 'get_weather' is not available", "is_error" : true },
+    { "type" : "tool_result", "tool_use_id" : "toolu_4", "content" : "12:00 UTC" },
+  ] ) );
+
+  let final_answer = response( "end_turn", &serde_json::json!( [ { "type" : "text", "text" : "It is noon." } ] ) );
+  assert!( runner().answer( &final_answer ).await.is_none() );
+}
+
+#[ tokio::test ]
+async fn runner_reports_tools_and_limits()
+{
+  let names : Vec< String > = runner().definitions().into_iter().map( | definition | definition.name ).collect();
+  assert_eq!( names, [ "divide", "get_time" ] );
+  assert_eq!( format!( "{:?}", ToolRunner::new() ), "ToolRunner { tools: [], max_iterations: 10 }" );
+
+  // Rejected before any request is sent
+  let client = the_module::Client::new( the_module::Secret::new_unchecked( "sk-ant-api03-".to_string() + &"x".repeat( 80 ) ) );
+  let request = CreateMessageRequest::builder()
+    .model( "claude-sonnet-4-6" )
+    .max_tokens( 100 )
+    .message( Message::user( "What time is it?" ) )
+    .build();
+  let error = runner().max_iterations( 0 ).run( &client, request ).await.expect_err( "zero limit" );
+  assert!( matches!( error, the_module::AnthropicError::InvalidArgument( _ ) ), "{error:?}" );
+
+  let outcome = | stop_reason : &str | the_module::ToolRunOutcome { response : response( stop_reason, &serde_json::json!( [] ) ), messages : Vec::new(), iterations : 1 };
+  assert!( outcome( "end_turn" ).is_complete() && outcome( "max_tokens" ).is_complete() );
+  assert!( !outcome( "tool_use" ).is_complete() && !outcome( "pause_turn" ).is_complete() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_runner_answers_with_tool_output()
+{
+  let client = the_module::Client::from_workspace()
+    .expect( "INTEGRATION: Client::from_workspace() must find ANTHROPIC_API_KEY" );
+  let runner = ToolRunner::new()
+    .tool( ToolDefinition::simple( "get_secret_word", "Get today's secret word" ), | _input | async { Ok( "pineapple".to_string() ) } )
+    .max_iterations( 3 );
+  let request = CreateMessageRequest::builder()
+    .model( "claude-haiku-4-5-20251001" )
+    .max_tokens( 200 )
+    .message( Message::user( "Use the tool to find today's secret word, then tell me what it is." ) )
+    .build();
+
+  let outcome = runner.run( &client, request ).await
+    .expect( "INTEGRATION: tool loop must succeed" );
+  assert!( outcome.is_complete(), "{outcome:?}" );
+  assert!( outcome.iterations >= 2, "{outcome:?}" );
+  assert_eq!( outcome.messages.len(), 1 + 2 * ( outcome.iterations as usize - 1 ) + 1 );
+  assert!( outcome.response.text().unwrap_or_default().to_lowercase().contains( "pineapple" ), "{outcome:?}" );
+}
@@ -316,6 +316,7 @@ async fn test_vision_with_tools()
         },
         "required": ["analysis_type"]
       }),
+      cache_control : None,
     }
   ];
   
@@ -491,6 +492,7 @@ async fn integration_vision_real_image_processing()
     system : None,
     temperature : None,
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
@@ -559,6 +561,7 @@ async fn integration_vision_mixed_content_real_api()
     system : Some( vec![ the_module::SystemContent::text( "You are a helpful vision assistant. Describe images accurately." ) ] ),
     temperature : Some( 0.1 ),
     stream : None,
+    thinking : None,
     tools : None,
     tool_choice : None,
   };
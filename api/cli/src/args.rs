@@ -0,0 +1,308 @@
+//! Command-line parsing.
+//!
+//! Hand-written rather than generated: the grammar is four subcommands and
+//! five options, and parsing stays a pure function over `String`s so it can
+//! be tested without spawning the binary.
+
+use crate::error::{ CliError, Result };
+use crate::provider::Provider;
+use api_common::Conversation;
+use core::str::FromStr;
+use std::io::BufRead;
+
+/// Help text printed by `llm --help`.
+pub const USAGE : &str = "\
+Usage: llm [OPTIONS] <COMMAND> [TEXT]...
+
+Commands:
+  chat     Send TEXT as one user message and print the reply
+  stream   Like chat, printing the reply as it is generated
+  embed    Print one JSON embedding vector per TEXT
+  models   List the models the provider reports
+
+TEXT is read from stdin when omitted (embed: one text per non-empty line).
+
+Options:
+  -p, --provider <NAME>   openai, claude, gemini, ollama, xai (default: $LLM_PROVIDER)
+  -m, --model <MODEL>     Model identifier (default: per provider)
+  -s, --system <TEXT>     System prompt for chat and stream
+      --max-tokens <N>    Upper bound on generated tokens
+  -v, --verbose           Print request payloads, tool calls, and usage to stderr
+  -h, --help              Print this help
+";
+
+/// Subcommand.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub enum Command
+{
+  /// One request, print the full reply.
+  Chat,
+  /// One streaming request, print deltas as they arrive.
+  Stream,
+  /// Embed each input text.
+  Embed,
+  /// List models.
+  Models,
+}
+
+impl Command
+{
+  /// Command-line name.
+  #[ inline ]
+  #[ must_use ]
+  pub fn name( self ) -> &'static str
+  {
+    match self
+    {
+      Self::Chat => "chat",
+      Self::Stream => "stream",
+      Self::Embed => "embed",
+      Self::Models => "models",
+    }
+  }
+
+  /// Whether the command sends input text.
+  #[ inline ]
+  #[ must_use ]
+  pub fn needs_input( self ) -> bool
+  {
+    !matches!( self, Self::Models )
+  }
+}
+
+impl FromStr for Command
+{
+  type Err = CliError;
+
+  #[ inline ]
+  fn from_str( name : &str ) -> core::result::Result< Self, Self::Err >
+  {
+    match name
+    {
+      "chat" => Ok( Self::Chat ),
+      "stream" => Ok( Self::Stream ),
+      "embed" => Ok( Self::Embed ),
+      "models" => Ok( Self::Models ),
+      _ => Err( CliError::UnknownCommand( name.to_owned() ) ),
+    }
+  }
+}
+
+/// Parsed command line, before defaults are applied.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct Args
+{
+  /// Subcommand.
+  pub command : Command,
+  /// `--provider`, if given.
+  pub provider : Option< Provider >,
+  /// `--model`, if given.
+  pub model : Option< String >,
+  /// `--system`, if given.
+  pub system : Option< String >,
+  /// `--max-tokens`, if given.
+  pub max_tokens : Option< u32 >,
+  /// `--verbose`.
+  pub verbose : bool,
+  /// Positional text arguments.
+  pub inputs : Vec< String >,
+}
+
+/// Outcome of [`parse`].
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub enum Parsed
+{
+  /// Run a command.
+  Run( Args ),
+  /// `--help` was requested.
+  Help,
+}
+
+/// Parses the arguments following the program name.
+///
+/// Options may appear before or after the subcommand, as `--name value` or
+/// `--name=value`; everything after `--` is positional.
+///
+/// # Errors
+///
+/// Returns a [`CliError`] for unknown commands or options, missing or
+/// unparsable option values, and a missing subcommand.
+#[ inline ]
+pub fn parse< I >( args : I ) -> core::result::Result< Parsed, CliError >
+where
+  I : IntoIterator< Item = String >,
+{
+  let mut command = None;
+  let mut provider = None;
+  let mut model = None;
+  let mut system = None;
+  let mut max_tokens = None;
+  let mut verbose = false;
+  let mut inputs = Vec::new();
+  let mut options_done = false;
+
+  let mut args = args.into_iter();
+  while let Some( arg ) = args.next()
+  {
+    if options_done || !arg.starts_with( '-' ) || arg == "-"
+    {
+      if command.is_none()
+      {
+        command = Some( arg.parse::< Command >()? );
+      }
+      else
+      {
+        inputs.push( arg );
+      }
+      continue;
+    }
+    if arg == "--"
+    {
+      options_done = true;
+      continue;
+    }
+
+    let ( name, inline ) = match arg.split_once( '=' )
+    {
+      Some( ( name, value ) ) if name.starts_with( "--" ) => ( name.to_owned(), Some( value.to_owned() ) ),
+      _ => ( arg, None ),
+    };
+    let mut value = | | inline.clone().or_else( || args.next() ).ok_or_else( || CliError::MissingValue( name.clone() ) );
+    match name.as_str()
+    {
+      "-h" | "--help" => return Ok( Parsed::Help ),
+      "-v" | "--verbose" => verbose = true,
+      "-p" | "--provider" => provider = Some( value()?.parse::< Provider >()? ),
+      "-m" | "--model" => model = Some( value()? ),
+      "-s" | "--system" => system = Some( value()? ),
+      "--max-tokens" =>
+      {
+        let raw = value()?;
+        let parsed = raw.parse::< u32 >().map_err( | _ | CliError::InvalidValue { option : name.clone(), value : raw } )?;
+        max_tokens = Some( parsed );
+      },
+      _ => return Err( CliError::UnknownOption( name ) ),
+    }
+  }
+
+  let command = command.ok_or( CliError::MissingCommand )?;
+  Ok( Parsed::Run( Args { command, provider, model, system, max_tokens, verbose, inputs } ) )
+}
+
+/// Reads command input from `reader` when no positional text was given.
+///
+/// `chat` and `stream` take the whole input, trimmed, as one text; `embed`
+/// takes each non-empty line as one text; `models` reads nothing.
+///
+/// # Errors
+///
+/// Returns the underlying I/O error.
+#[ inline ]
+pub fn read_inputs< R >( command : Command, reader : R ) -> std::io::Result< Vec< String > >
+where
+  R : BufRead,
+{
+  match command
+  {
+    Command::Models => Ok( Vec::new() ),
+    Command::Embed => reader
+      .lines()
+      .filter( | line | line.as_ref().map_or( true, | l | !l.trim().is_empty() ) )
+      .collect(),
+    Command::Chat | Command::Stream =>
+    {
+      let mut text = String::new();
+      for line in reader.lines()
+      {
+        text.push_str( &line? );
+        text.push( '\n' );
+      }
+      let text = text.trim();
+      Ok( if text.is_empty() { Vec::new() } else { vec![ text.to_owned() ] } )
+    },
+  }
+}
+
+/// A fully resolved command: provider chosen and defaults applied.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct Invocation
+{
+  /// Subcommand.
+  pub command : Command,
+  /// Backend to call.
+  pub provider : Provider,
+  /// Model identifier sent to the provider.
+  pub model : String,
+  /// System prompt.
+  pub system : Option< String >,
+  /// Upper bound on generated tokens.
+  pub max_tokens : Option< u32 >,
+  /// Print diagnostics to stderr.
+  pub verbose : bool,
+  /// Input texts.
+  pub inputs : Vec< String >,
+}
+
+impl Invocation
+{
+  /// Applies `LLM_PROVIDER` (passed as `env_provider`) and model defaults.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CliError::MissingProvider`] or [`CliError::UnknownProvider`]
+  /// if no valid provider is named, [`CliError::Unsupported`] for `embed` on
+  /// a provider without embeddings, and [`CliError::EmptyInput`] if the
+  /// command needs text and none was given.
+  #[ inline ]
+  pub fn resolve( args : Args, env_provider : Option< &str > ) -> Result< Self >
+  {
+    let provider = match ( args.provider, env_provider.filter( | p | !p.is_empty() ) )
+    {
+      ( Some( provider ), _ ) => provider,
+      ( None, Some( name ) ) => name.parse::< Provider >()?,
+      ( None, None ) => return Err( CliError::MissingProvider.into() ),
+    };
+    let default_model = match args.command
+    {
+      Command::Embed => provider.default_embedding_model().ok_or_else( || CliError::Unsupported
+      {
+        provider : provider.name().to_owned(),
+        command : args.command.name().to_owned(),
+      })?,
+      Command::Chat | Command::Stream | Command::Models => provider.default_chat_model(),
+    };
+    if args.command.needs_input() && args.inputs.is_empty()
+    {
+      return Err( CliError::EmptyInput( args.command.name().to_owned() ).into() );
+    }
+    Ok( Self
+    {
+      command : args.command,
+      provider,
+      model : args.model.unwrap_or_else( || default_model.to_owned() ),
+      system : args.system,
+      max_tokens : args.max_tokens,
+      verbose : args.verbose,
+      inputs : args.inputs,
+    })
+  }
+
+  /// Positional texts joined by spaces, as sent by `chat` and `stream`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn prompt( &self ) -> String
+  {
+    self.inputs.join( " " )
+  }
+
+  /// System prompt and user prompt as a one-turn conversation.
+  #[ inline ]
+  #[ must_use ]
+  pub fn conversation( &self ) -> Conversation
+  {
+    let mut conversation = Conversation::new();
+    conversation.set_system( self.system.clone() );
+    conversation.push_user( self.prompt() );
+    conversation
+  }
+}
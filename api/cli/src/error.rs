@@ -0,0 +1,67 @@
+//! Error type and result alias for the `llm` tool.
+
+use error_tools::dependency::thiserror;
+
+/// Errors raised by the tool itself, before or around a provider call.
+///
+/// Provider failures (authentication, HTTP, API errors) are passed through
+/// unchanged inside the untyped [`Result`].
+#[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+#[ non_exhaustive ]
+pub enum CliError
+{
+  /// No subcommand was given.
+  #[ error( "Missing command : expected one of chat, stream, embed, models" ) ]
+  MissingCommand,
+
+  /// The subcommand is not known.
+  #[ error( "Unknown command : {0}" ) ]
+  UnknownCommand( String ),
+
+  /// An option is not known.
+  #[ error( "Unknown option : {0}" ) ]
+  UnknownOption( String ),
+
+  /// An option that takes a value was given without one.
+  #[ error( "Missing value for option : {0}" ) ]
+  MissingValue( String ),
+
+  /// An option value could not be parsed.
+  #[ error( "Invalid value for {option} : {value}" ) ]
+  InvalidValue
+  {
+    /// Option name as written on the command line.
+    option : String,
+    /// Rejected value.
+    value : String,
+  },
+
+  /// Neither `--provider` nor `LLM_PROVIDER` named a provider.
+  #[ error( "Missing provider : pass --provider or set LLM_PROVIDER" ) ]
+  MissingProvider,
+
+  /// The provider name is not known.
+  #[ error( "Unknown provider : {0} (expected openai, claude, gemini, ollama, xai)" ) ]
+  UnknownProvider( String ),
+
+  /// The provider is known but its feature was not compiled in.
+  #[ error( "Provider {0} is not compiled into this binary (enable the `{0}` feature)" ) ]
+  ProviderNotCompiled( String ),
+
+  /// The provider has no endpoint for the command.
+  #[ error( "Provider {provider} does not support {command}" ) ]
+  Unsupported
+  {
+    /// Provider name.
+    provider : String,
+    /// Command name.
+    command : String,
+  },
+
+  /// The command needs text but none was given on the command line or stdin.
+  #[ error( "No input text for {0}" ) ]
+  EmptyInput( String ),
+}
+
+/// Result alias carrying either a [`CliError`] or a provider error.
+pub type Result< T > = error_tools::untyped::Result< T >;
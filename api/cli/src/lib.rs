@@ -0,0 +1,39 @@
+//! Library behind the `llm` command-line tool.
+//!
+//! `llm` sends ad-hoc requests to any provider compiled into the binary, so
+//! credentials and payloads can be checked without writing a program:
+//!
+//! ```text
+//! llm --provider openai chat "Say hello"
+//! llm --provider claude --verbose stream "Count to five"
+//! llm --provider gemini embed "first text" "second text"
+//! llm --provider ollama models
+//! ```
+//!
+//! Credentials are resolved by each provider crate's own secret loading
+//! (environment variable first, then the workspace `secret/` files); the tool
+//! adds no lookup of its own.
+//!
+//! # Features
+//!
+//! - `enabled` — argument parsing and output rendering
+//! - `openai`, `claude`, `gemini`, `ollama`, `xai` — one backend each
+//! - `full` — every backend (default)
+
+#[ cfg( feature = "enabled" ) ]
+pub mod args;
+#[ cfg( feature = "enabled" ) ]
+pub mod error;
+#[ cfg( feature = "enabled" ) ]
+pub mod output;
+#[ cfg( feature = "enabled" ) ]
+pub mod provider;
+#[ cfg( feature = "enabled" ) ]
+pub mod providers;
+
+#[ cfg( feature = "enabled" ) ]
+pub use args::{ parse, Args, Command, Invocation, Parsed, USAGE };
+#[ cfg( feature = "enabled" ) ]
+pub use error::CliError;
+#[ cfg( feature = "enabled" ) ]
+pub use provider::Provider;
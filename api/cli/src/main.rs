@@ -0,0 +1,46 @@
+//! `llm` — ad-hoc chat, streaming, embedding, and model-listing calls
+//! against any `api_llm` provider. Run `llm --help` for usage.
+
+use api_cli::{ args, providers, Invocation, Parsed, USAGE };
+use std::io::{ IsTerminal, Write };
+use std::process::ExitCode;
+
+#[ tokio::main( flavor = "current_thread" ) ]
+async fn main() -> ExitCode
+{
+  match run().await
+  {
+    Ok( () ) => ExitCode::SUCCESS,
+    Err( err ) =>
+    {
+      eprintln!( "llm : {err:#}" );
+      ExitCode::FAILURE
+    },
+  }
+}
+
+async fn run() -> api_cli::error::Result< () >
+{
+  let mut parsed = match args::parse( std::env::args().skip( 1 ) )?
+  {
+    Parsed::Run( parsed ) => parsed,
+    Parsed::Help =>
+    {
+      print!( "{USAGE}" );
+      return Ok( () );
+    },
+  };
+
+  let stdin = std::io::stdin();
+  if parsed.inputs.is_empty() && parsed.command.needs_input() && !stdin.is_terminal()
+  {
+    parsed.inputs = args::read_inputs( parsed.command, stdin.lock() )?;
+  }
+
+  let invocation = Invocation::resolve( parsed, std::env::var( "LLM_PROVIDER" ).ok().as_deref() )?;
+  let mut out = std::io::stdout().lock();
+  let mut diag = std::io::stderr().lock();
+  providers::run( &invocation, &mut out, &mut diag ).await?;
+  out.flush()?;
+  Ok( () )
+}
@@ -0,0 +1,132 @@
+//! Rendering of replies, stream events, embeddings, and diagnostics.
+//!
+//! Replies go to the `out` writer (stdout); diagnostics enabled by
+//! `--verbose` go to the `diag` writer (stderr), so piping the output of
+//! `llm` never mixes the two.
+
+use crate::error::Result;
+use api_common::{ Embedding, StreamEvent };
+use futures_util::{ Stream, StreamExt };
+use serde::Serialize;
+use std::io::Write;
+
+/// Writes the JSON payload of `request` to `diag` when `verbose` is set.
+///
+/// # Errors
+///
+/// Returns serialisation and I/O errors.
+#[ inline ]
+pub fn request< T >( diag : &mut dyn Write, verbose : bool, request : &T ) -> Result< () >
+where
+  T : Serialize,
+{
+  if verbose
+  {
+    writeln!( diag, "request : {}", serde_json::to_string_pretty( request )? )?;
+  }
+  Ok( () )
+}
+
+/// Writes a complete reply followed by a newline.
+///
+/// # Errors
+///
+/// Returns I/O errors.
+#[ inline ]
+pub fn reply( out : &mut dyn Write, text : &str ) -> Result< () >
+{
+  writeln!( out, "{text}" )?;
+  Ok( () )
+}
+
+/// Drains a stream of neutral events.
+///
+/// Text deltas are written and flushed to `out` as they arrive. Tool-call
+/// fragments, usage updates, and the finish reason are written to `diag`
+/// when `verbose` is set. The first `Err` item stops the stream and is
+/// returned.
+///
+/// # Errors
+///
+/// Returns the first stream error or I/O error.
+#[ inline ]
+pub async fn events< S >( out : &mut dyn Write, diag : &mut dyn Write, verbose : bool, events : S ) -> Result< () >
+where
+  S : Stream< Item = Result< StreamEvent > >,
+{
+  futures_util::pin_mut!( events );
+  let mut wrote_text = false;
+  while let Some( event ) = events.next().await
+  {
+    match event?
+    {
+      StreamEvent::TextDelta( text ) =>
+      {
+        out.write_all( text.as_bytes() )?;
+        out.flush()?;
+        wrote_text = true;
+      },
+      StreamEvent::ToolCallDelta( call ) if verbose =>
+      {
+        writeln!
+        (
+          diag,
+          "tool_call[{}] id={} name={} arguments={}",
+          call.index,
+          call.id.as_deref().unwrap_or( "-" ),
+          call.name.as_deref().unwrap_or( "-" ),
+          call.arguments,
+        )?;
+      },
+      StreamEvent::UsageUpdate( usage ) if verbose =>
+      {
+        writeln!( diag, "usage : input={} output={}", count( usage.input_tokens ), count( usage.output_tokens ) )?;
+      },
+      StreamEvent::Done { finish_reason } if verbose =>
+      {
+        writeln!( diag, "done : {}", finish_reason.as_deref().unwrap_or( "-" ) )?;
+      },
+      _ => {},
+    }
+  }
+  if wrote_text
+  {
+    writeln!( out )?;
+  }
+  Ok( () )
+}
+
+/// Writes one JSON array per embedding, one per line, in input order.
+///
+/// # Errors
+///
+/// Returns serialisation and I/O errors.
+#[ inline ]
+pub fn embeddings( out : &mut dyn Write, embeddings : &[ Embedding ] ) -> Result< () >
+{
+  for embedding in embeddings
+  {
+    writeln!( out, "{}", serde_json::to_string( embedding )? )?;
+  }
+  Ok( () )
+}
+
+/// Writes one model identifier per line.
+///
+/// # Errors
+///
+/// Returns I/O errors.
+#[ inline ]
+pub fn models( out : &mut dyn Write, models : &[ String ] ) -> Result< () >
+{
+  for model in models
+  {
+    writeln!( out, "{model}" )?;
+  }
+  Ok( () )
+}
+
+fn count( tokens : Option< u64 > ) -> String
+{
+  tokens.map_or_else( || "-".to_owned(), | n | n.to_string() )
+}
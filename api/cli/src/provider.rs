@@ -0,0 +1,101 @@
+//! Provider selection and per-provider defaults.
+
+use crate::error::CliError;
+use core::fmt;
+use core::str::FromStr;
+
+/// A backend the tool can talk to.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub enum Provider
+{
+  /// `OpenAI` (`api_openai`), key `OPENAI_API_KEY`.
+  OpenAi,
+  /// Anthropic (`api_claude`), key `ANTHROPIC_API_KEY`.
+  Claude,
+  /// Google Gemini (`api_gemini`), key `GEMINI_API_KEY`.
+  Gemini,
+  /// Local Ollama server (`api_ollama`), no key.
+  Ollama,
+  /// xAI Grok (`api_xai`), key `XAI_API_KEY`.
+  Xai,
+}
+
+impl Provider
+{
+  /// Every provider, in the order shown in help output.
+  pub const ALL : [ Provider; 5 ] = [ Self::OpenAi, Self::Claude, Self::Gemini, Self::Ollama, Self::Xai ];
+
+  /// Canonical command-line name (also the cargo feature name).
+  #[ inline ]
+  #[ must_use ]
+  pub fn name( self ) -> &'static str
+  {
+    match self
+    {
+      Self::OpenAi => "openai",
+      Self::Claude => "claude",
+      Self::Gemini => "gemini",
+      Self::Ollama => "ollama",
+      Self::Xai => "xai",
+    }
+  }
+
+  /// Model used by `chat` and `stream` when `--model` is not given.
+  #[ inline ]
+  #[ must_use ]
+  pub fn default_chat_model( self ) -> &'static str
+  {
+    match self
+    {
+      Self::OpenAi => "gpt-4o-mini",
+      Self::Claude => "claude-sonnet-4-5-20250929",
+      Self::Gemini => "gemini-2.5-flash",
+      Self::Ollama => "llama3.2",
+      Self::Xai => "grok-2-1212",
+    }
+  }
+
+  /// Model used by `embed` when `--model` is not given, or `None` if the
+  /// provider has no embeddings endpoint.
+  #[ inline ]
+  #[ must_use ]
+  pub fn default_embedding_model( self ) -> Option< &'static str >
+  {
+    match self
+    {
+      Self::OpenAi => Some( "text-embedding-3-small" ),
+      Self::Gemini => Some( "text-embedding-004" ),
+      Self::Ollama => Some( "nomic-embed-text" ),
+      Self::Claude | Self::Xai => None,
+    }
+  }
+}
+
+impl fmt::Display for Provider
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+  {
+    f.write_str( self.name() )
+  }
+}
+
+impl FromStr for Provider
+{
+  type Err = CliError;
+
+  /// Accepts the canonical names plus `anthropic`, `google`, and `grok`.
+  #[ inline ]
+  fn from_str( name : &str ) -> Result< Self, Self::Err >
+  {
+    match name.to_ascii_lowercase().as_str()
+    {
+      "openai" => Ok( Self::OpenAi ),
+      "claude" | "anthropic" => Ok( Self::Claude ),
+      "gemini" | "google" => Ok( Self::Gemini ),
+      "ollama" => Ok( Self::Ollama ),
+      "xai" | "grok" => Ok( Self::Xai ),
+      _ => Err( CliError::UnknownProvider( name.to_owned() ) ),
+    }
+  }
+}
@@ -0,0 +1,67 @@
+//! Anthropic backend (`api_claude`).
+
+use crate::args::Invocation;
+use crate::error::Result;
+use crate::output;
+use api_claude::{ Client, CreateMessageRequest, Message };
+use api_common::{ StreamEvent, TextStream };
+use futures_util::Stream;
+use std::io::Write;
+
+/// Anthropic requires `max_tokens`; used when `--max-tokens` is not given.
+const DEFAULT_MAX_TOKENS : u32 = 1024;
+
+/// Builds a client from `ANTHROPIC_API_KEY`: environment first, then the
+/// workspace `secret/-secrets.sh` file.
+fn client() -> Result< Client >
+{
+  Ok( Client::from_env().or_else( | _ | Client::from_workspace() )? )
+}
+
+fn request( invocation : &Invocation, stream : bool ) -> CreateMessageRequest
+{
+  let conversation = invocation.conversation();
+  let mut builder = CreateMessageRequest::builder()
+    .model( invocation.model.clone() )
+    .max_tokens( invocation.max_tokens.unwrap_or( DEFAULT_MAX_TOKENS ) )
+    .messages( conversation.turns_into::< Message >() );
+  if let Some( system ) = conversation.instructions()
+  {
+    builder = builder.system( system );
+  }
+  if stream
+  {
+    builder = builder.stream( true );
+  }
+  builder.build()
+}
+
+/// Sends one message request and returns the reply text.
+pub( crate ) async fn chat( invocation : &Invocation, diag : &mut dyn Write ) -> Result< String >
+{
+  let request = request( invocation, false );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let response = client()?.create_message( request ).await?;
+  Ok( response.text().unwrap_or_default().to_owned() )
+}
+
+/// Builds a streaming message request.
+pub( crate ) fn stream_request( invocation : &Invocation ) -> CreateMessageRequest
+{
+  request( invocation, true )
+}
+
+/// Starts a streaming message request.
+pub( crate ) async fn stream( request : CreateMessageRequest ) -> Result< impl Stream< Item = Result< StreamEvent > > >
+{
+  let events = client()?.create_message_stream( request ).await?;
+  Ok( TextStream::new( events ) )
+}
+
+/// Lists model identifiers.
+pub( crate ) async fn models() -> Result< Vec< String > >
+{
+  let models = client()?.list_models().await?;
+  Ok( models.into_iter().map( | model | model.id ).collect() )
+}
@@ -0,0 +1,83 @@
+//! Google Gemini backend (`api_gemini`).
+
+use crate::args::Invocation;
+use crate::error::Result;
+use crate::output;
+use api_common::{ Embedding, TextStream };
+use api_gemini::client::Client;
+use api_gemini::models::{ GenerateContentRequest, GenerationConfig, Part, SystemInstruction };
+use api_gemini::models::api::GeminiEmbedder;
+use std::io::Write;
+
+/// Builds a client from `GEMINI_API_KEY`: workspace `secret/-secrets.sh`
+/// first, then the environment (the order `Client::new` uses).
+fn client() -> Result< Client >
+{
+  Ok( Client::new()? )
+}
+
+fn request( invocation : &Invocation ) -> GenerateContentRequest
+{
+  let conversation = invocation.conversation();
+  GenerateContentRequest
+  {
+    contents : conversation.turns_into(),
+    system_instruction : conversation.instructions().map( | text | SystemInstruction
+    {
+      role : "system".to_string(),
+      parts : vec![ Part { text : Some( text ), ..Default::default() } ],
+    }),
+    generation_config : invocation.max_tokens.map( | n | GenerationConfig
+    {
+      max_output_tokens : i32::try_from( n ).ok(),
+      ..Default::default()
+    }),
+    ..Default::default()
+  }
+}
+
+/// Sends one `generateContent` request and returns the reply text.
+pub( crate ) async fn chat( invocation : &Invocation, diag : &mut dyn Write ) -> Result< String >
+{
+  let request = request( invocation );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let client = client()?;
+  let response = client.models().by_name( &invocation.model ).generate_content( &request ).await?;
+  let text = response.candidates
+    .into_iter()
+    .next()
+    .map( | candidate | candidate.content.parts.into_iter().filter_map( | part | part.text ).collect::< String >() )
+    .unwrap_or_default();
+  Ok( text )
+}
+
+/// Streams a `streamGenerateContent` request straight to `out`.
+///
+/// The chunk stream borrows the client, so it is drained here rather than
+/// returned.
+pub( crate ) async fn stream( invocation : &Invocation, out : &mut dyn Write, diag : &mut dyn Write ) -> Result< () >
+{
+  let request = request( invocation );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let client = client()?;
+  let models = client.models();
+  let model = models.by_name( &invocation.model );
+  let chunks = model.generate_content_stream( &request ).await?;
+  output::events( out, diag, invocation.verbose, TextStream::new( chunks ) ).await
+}
+
+/// Embeds every input text.
+pub( crate ) async fn embed( invocation : &Invocation ) -> Result< Vec< Embedding > >
+{
+  let embedder = GeminiEmbedder::new( client()?, invocation.model.clone() );
+  super::embed_all( &embedder, invocation ).await
+}
+
+/// Lists model names.
+pub( crate ) async fn models() -> Result< Vec< String > >
+{
+  let response = client()?.models().list().await?;
+  Ok( response.models.into_iter().map( | model | model.name ).collect() )
+}
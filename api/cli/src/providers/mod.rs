@@ -0,0 +1,138 @@
+//! Provider backends and command dispatch.
+//!
+//! Each backend module turns an [`Invocation`] into that provider's request
+//! types and returns neutral results (text, events, vectors, model names);
+//! rendering stays in [`crate::output`].
+
+use crate::args::{ Command, Invocation };
+use crate::error::{ CliError, Result };
+#[ cfg( any( feature = "openai", feature = "claude", feature = "gemini", feature = "ollama", feature = "xai" ) ) ]
+use crate::output;
+use crate::provider::Provider;
+use std::io::Write;
+
+#[ cfg( feature = "claude" ) ]
+pub mod claude;
+#[ cfg( feature = "gemini" ) ]
+pub mod gemini;
+#[ cfg( feature = "ollama" ) ]
+pub mod ollama;
+#[ cfg( feature = "openai" ) ]
+pub mod openai;
+#[ cfg( feature = "xai" ) ]
+pub mod xai;
+
+/// Runs `invocation` against its provider, writing the result to `out` and
+/// diagnostics to `diag`.
+///
+/// # Errors
+///
+/// Returns [`CliError::ProviderNotCompiled`] if the provider's feature is
+/// disabled, [`CliError::Unsupported`] for commands the provider lacks, and
+/// any credential, transport, or API error from the provider crate.
+#[ inline ]
+#[ cfg_attr
+(
+  not( any( feature = "openai", feature = "claude", feature = "gemini", feature = "ollama", feature = "xai" ) ),
+  allow( unused_variables, clippy::unused_async, clippy::match_single_binding )
+)]
+pub async fn run( invocation : &Invocation, out : &mut dyn Write, diag : &mut dyn Write ) -> Result< () >
+{
+  let verbose = invocation.verbose;
+  match ( invocation.provider, invocation.command )
+  {
+    #[ cfg( feature = "openai" ) ]
+    ( Provider::OpenAi, Command::Chat ) => output::reply( out, &openai::chat( invocation, diag ).await? ),
+    #[ cfg( feature = "openai" ) ]
+    ( Provider::OpenAi, Command::Stream ) =>
+    {
+      let request = openai::stream_request( invocation );
+      output::request( diag, verbose, &request )?;
+      output::events( out, diag, verbose, openai::stream( request ).await? ).await
+    },
+    #[ cfg( feature = "openai" ) ]
+    ( Provider::OpenAi, Command::Embed ) => output::embeddings( out, &openai::embed( invocation ).await? ),
+    #[ cfg( feature = "openai" ) ]
+    ( Provider::OpenAi, Command::Models ) => output::models( out, &openai::models().await? ),
+
+    #[ cfg( feature = "claude" ) ]
+    ( Provider::Claude, Command::Chat ) => output::reply( out, &claude::chat( invocation, diag ).await? ),
+    #[ cfg( feature = "claude" ) ]
+    ( Provider::Claude, Command::Stream ) =>
+    {
+      let request = claude::stream_request( invocation );
+      output::request( diag, verbose, &request )?;
+      output::events( out, diag, verbose, claude::stream( request ).await? ).await
+    },
+    #[ cfg( feature = "claude" ) ]
+    ( Provider::Claude, Command::Models ) => output::models( out, &claude::models().await? ),
+
+    #[ cfg( feature = "gemini" ) ]
+    ( Provider::Gemini, Command::Chat ) => output::reply( out, &gemini::chat( invocation, diag ).await? ),
+    #[ cfg( feature = "gemini" ) ]
+    ( Provider::Gemini, Command::Stream ) => gemini::stream( invocation, out, diag ).await,
+    #[ cfg( feature = "gemini" ) ]
+    ( Provider::Gemini, Command::Embed ) => output::embeddings( out, &gemini::embed( invocation ).await? ),
+    #[ cfg( feature = "gemini" ) ]
+    ( Provider::Gemini, Command::Models ) => output::models( out, &gemini::models().await? ),
+
+    #[ cfg( feature = "ollama" ) ]
+    ( Provider::Ollama, Command::Chat ) => output::reply( out, &ollama::chat( invocation, diag ).await? ),
+    #[ cfg( feature = "ollama" ) ]
+    ( Provider::Ollama, Command::Stream ) =>
+    {
+      let request = ollama::stream_request( invocation );
+      output::request( diag, verbose, &request )?;
+      output::events( out, diag, verbose, ollama::stream( request ).await? ).await
+    },
+    #[ cfg( feature = "ollama" ) ]
+    ( Provider::Ollama, Command::Embed ) => output::embeddings( out, &ollama::embed( invocation ).await? ),
+    #[ cfg( feature = "ollama" ) ]
+    ( Provider::Ollama, Command::Models ) => output::models( out, &ollama::models().await? ),
+
+    #[ cfg( feature = "xai" ) ]
+    ( Provider::Xai, Command::Chat ) => output::reply( out, &xai::chat( invocation, diag ).await? ),
+    #[ cfg( feature = "xai" ) ]
+    ( Provider::Xai, Command::Stream ) =>
+    {
+      let request = xai::stream_request( invocation );
+      output::request( diag, verbose, &request )?;
+      output::events( out, diag, verbose, xai::stream( request ).await? ).await
+    },
+    #[ cfg( feature = "xai" ) ]
+    ( Provider::Xai, Command::Models ) => output::models( out, &xai::models().await? ),
+
+    ( provider, command ) => Err( unavailable( provider, command ).into() ),
+  }
+}
+
+/// Embeds every input with `embedder`, in chunks of its documented batch
+/// limit.
+#[ cfg( any( feature = "openai", feature = "gemini", feature = "ollama" ) ) ]
+async fn embed_all< E >( embedder : &E, invocation : &Invocation ) -> Result< Vec< api_common::Embedding > >
+where
+  E : api_common::Embedder,
+{
+  let batch_size = embedder.max_batch_size().unwrap_or( invocation.inputs.len() ).max( 1 );
+  api_common::embed_batched( embedder, &invocation.inputs, batch_size ).await
+}
+
+fn unavailable( provider : Provider, command : Command ) -> CliError
+{
+  let compiled = match provider
+  {
+    Provider::OpenAi => cfg!( feature = "openai" ),
+    Provider::Claude => cfg!( feature = "claude" ),
+    Provider::Gemini => cfg!( feature = "gemini" ),
+    Provider::Ollama => cfg!( feature = "ollama" ),
+    Provider::Xai => cfg!( feature = "xai" ),
+  };
+  if compiled
+  {
+    CliError::Unsupported { provider : provider.name().to_owned(), command : command.name().to_owned() }
+  }
+  else
+  {
+    CliError::ProviderNotCompiled( provider.name().to_owned() )
+  }
+}
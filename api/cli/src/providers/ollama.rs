@@ -0,0 +1,68 @@
+//! Ollama backend (`api_ollama`).
+
+use crate::args::Invocation;
+use crate::error::Result;
+use crate::output;
+use api_common::{ Embedding, StreamEvent, TextStream };
+use api_ollama::{ ChatMessage, ChatRequest, OllamaClient, OllamaEmbedder };
+use futures_util::Stream;
+use std::io::Write;
+
+/// Builds a client from the workspace secrets (`OLLAMA_URL`, then the
+/// environment); outside a workspace, the local default server.
+fn client() -> OllamaClient
+{
+  OllamaClient::from_workspace_secrets().unwrap_or_default()
+}
+
+fn request( invocation : &Invocation ) -> ChatRequest
+{
+  ChatRequest
+  {
+    model : invocation.model.clone(),
+    messages : invocation.conversation().messages_into::< ChatMessage >(),
+    stream : Some( false ),
+    options : invocation.max_tokens.map( | n | serde_json::json!( { "num_predict" : n } ) ),
+    tools : None,
+    tool_messages : None,
+  }
+}
+
+/// Sends one `/api/chat` request and returns the reply text.
+pub( crate ) async fn chat( invocation : &Invocation, diag : &mut dyn Write ) -> Result< String >
+{
+  let request = request( invocation );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let response = client().chat( request ).await?;
+  Ok( response.message.content )
+}
+
+/// Builds a streaming `/api/chat` request.
+pub( crate ) fn stream_request( invocation : &Invocation ) -> ChatRequest
+{
+  let mut request = request( invocation );
+  request.stream = Some( true );
+  request
+}
+
+/// Starts a streaming `/api/chat` request.
+pub( crate ) async fn stream( request : ChatRequest ) -> Result< impl Stream< Item = Result< StreamEvent > > >
+{
+  let chunks = client().chat_stream( request ).await?;
+  Ok( TextStream::new( chunks ) )
+}
+
+/// Embeds every input text.
+pub( crate ) async fn embed( invocation : &Invocation ) -> Result< Vec< Embedding > >
+{
+  let embedder = OllamaEmbedder::new( client(), invocation.model.clone() );
+  super::embed_all( &embedder, invocation ).await
+}
+
+/// Lists local model names.
+pub( crate ) async fn models() -> Result< Vec< String > >
+{
+  let response = client().list_models().await?;
+  Ok( response.models.into_iter().map( | model | model.name ).collect() )
+}
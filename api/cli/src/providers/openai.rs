@@ -0,0 +1,77 @@
+//! `OpenAI` backend (`api_openai`).
+
+use crate::args::Invocation;
+use crate::error::Result;
+use crate::output;
+use api_common::{ Embedding, StreamEvent, TextStream };
+use api_openai::{ Client, ClientApiAccessors };
+use api_openai::components::chat_shared::{ ChatCompletionRequest, ChatCompletionRequestMessage };
+use api_openai::embeddings::OpenAIEmbedder;
+use api_openai::environment::{ OpenAIRecommended, OpenaiEnvironmentImpl };
+use api_openai::secret::Secret;
+use futures_util::Stream;
+use std::io::Write;
+
+/// Builds a client from `OPENAI_API_KEY` via `Secret::load_with_fallbacks`.
+fn client() -> Result< Client< OpenaiEnvironmentImpl > >
+{
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" )?;
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    secret,
+    None,
+    None,
+    OpenAIRecommended::base_url().to_string(),
+    OpenAIRecommended::realtime_base_url().to_string(),
+  )?;
+  Client::build( environment )
+}
+
+fn request( invocation : &Invocation ) -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( invocation.model.clone() )
+    .messages( invocation.conversation().messages_into::< ChatCompletionRequestMessage >() )
+    .form()
+}
+
+/// Sends one chat completion and returns the reply text.
+pub( crate ) async fn chat( invocation : &Invocation, diag : &mut dyn Write ) -> Result< String >
+{
+  let mut request = request( invocation );
+  request.max_tokens = invocation.max_tokens.and_then( | n | i32::try_from( n ).ok() );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let response = client()?.chat().create( request ).await?;
+  Ok( response.choices.into_iter().next().and_then( | choice | choice.message.content ).unwrap_or_default() )
+}
+
+/// Builds a streaming chat completion request.
+pub( crate ) fn stream_request( invocation : &Invocation ) -> ChatCompletionRequest
+{
+  let mut request = request( invocation );
+  request.max_tokens = invocation.max_tokens.and_then( | n | i32::try_from( n ).ok() );
+  request.stream = Some( true );
+  request
+}
+
+/// Starts a streaming chat completion.
+pub( crate ) async fn stream( request : ChatCompletionRequest ) -> Result< impl Stream< Item = Result< StreamEvent > > >
+{
+  let receiver = client()?.chat().create_stream( request ).await?;
+  Ok( TextStream::new( tokio_stream::wrappers::ReceiverStream::new( receiver ) ) )
+}
+
+/// Embeds every input text.
+pub( crate ) async fn embed( invocation : &Invocation ) -> Result< Vec< Embedding > >
+{
+  let embedder = OpenAIEmbedder::new( client()?, invocation.model.clone() );
+  super::embed_all( &embedder, invocation ).await
+}
+
+/// Lists model identifiers.
+pub( crate ) async fn models() -> Result< Vec< String > >
+{
+  let response = client()?.models().list().await?;
+  Ok( response.data.into_iter().map( | model | model.id ).collect() )
+}
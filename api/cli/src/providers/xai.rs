@@ -0,0 +1,58 @@
+//! xAI Grok backend (`api_xai`).
+
+use crate::args::Invocation;
+use crate::error::Result;
+use crate::output;
+use api_common::{ StreamEvent, TextStream };
+use api_xai::{ ChatCompletionRequest, Client, ClientApiAccessors, Message, Secret, XaiEnvironmentImpl };
+use futures_util::Stream;
+use std::io::Write;
+
+/// Builds a client from `XAI_API_KEY` via `Secret::load_with_fallbacks`.
+fn client() -> Result< Client< XaiEnvironmentImpl > >
+{
+  let secret = Secret::load_with_fallbacks( "XAI_API_KEY" )?;
+  Client::build( XaiEnvironmentImpl::new( secret )? )
+}
+
+fn request( invocation : &Invocation ) -> ChatCompletionRequest
+{
+  let mut request = ChatCompletionRequest::former()
+    .model( invocation.model.clone() )
+    .messages( invocation.conversation().messages_into::< Message >() )
+    .form();
+  request.max_tokens = invocation.max_tokens;
+  request
+}
+
+/// Sends one chat completion and returns the reply text.
+pub( crate ) async fn chat( invocation : &Invocation, diag : &mut dyn Write ) -> Result< String >
+{
+  let request = request( invocation );
+  output::request( diag, invocation.verbose, &request )?;
+
+  let response = client()?.chat().create( request ).await?;
+  Ok( response.choices.into_iter().next().and_then( | choice | choice.message.content ).unwrap_or_default() )
+}
+
+/// Builds a streaming chat completion request.
+pub( crate ) fn stream_request( invocation : &Invocation ) -> ChatCompletionRequest
+{
+  let mut request = request( invocation );
+  request.stream = Some( true );
+  request
+}
+
+/// Starts a streaming chat completion.
+pub( crate ) async fn stream( request : ChatCompletionRequest ) -> Result< impl Stream< Item = Result< StreamEvent > > >
+{
+  let chunks = client()?.chat().create_stream( request ).await?;
+  Ok( TextStream::new( chunks ) )
+}
+
+/// Lists model identifiers.
+pub( crate ) async fn models() -> Result< Vec< String > >
+{
+  let response = client()?.models().list().await?;
+  Ok( response.data.into_iter().map( | model | model.id ).collect() )
+}
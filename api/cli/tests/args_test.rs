@@ -0,0 +1,173 @@
+//! Tests for command-line parsing and invocation resolution.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | options_before_and_after_command | parse | option position, `--name=value` |
+//! | double_dash_ends_options | parse | `--` makes the rest positional |
+//! | help_short_circuits | parse | `--help` wins over errors later in the line |
+//! | parse_errors | parse | unknown command/option, missing/invalid value, no command |
+//! | provider_aliases | provider | `anthropic`, `google`, `grok`, case-insensitive |
+//! | resolve_applies_model_defaults | resolve | chat vs embed default models |
+//! | resolve_provider_precedence | resolve | `--provider` over `LLM_PROVIDER` |
+//! | resolve_rejects_embed_without_endpoint | resolve | `Unsupported` for claude/xai |
+//! | resolve_requires_input | resolve | `EmptyInput` for chat without text |
+//! | read_inputs_per_command | stdin | whole text vs one text per line |
+//! | conversation_carries_system_prompt | render | system prompt and joined user text |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_cli::{ args::read_inputs, parse, Args, CliError, Command, Invocation, Parsed, Provider };
+use api_common::NeutralRole;
+
+fn parse_words( words : &str ) -> Result< Parsed, CliError >
+{
+  parse( words.split_whitespace().map( str::to_owned ) )
+}
+
+fn args( words : &str ) -> Args
+{
+  match parse_words( words ).unwrap()
+  {
+    Parsed::Run( args ) => args,
+    Parsed::Help => panic!( "unexpected help" ),
+  }
+}
+
+fn cli_error( err : &error_tools::untyped::Error ) -> &CliError
+{
+  err.downcast_ref::< CliError >().expect( "error must be a CliError" )
+}
+
+// ------------------------------------------------------------------ //
+//  Parsing
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn options_before_and_after_command()
+{
+  let parsed = args( "-p openai chat --model=gpt-4o -v hello world --max-tokens 64" );
+
+  assert_eq!( parsed.command, Command::Chat );
+  assert_eq!( parsed.provider, Some( Provider::OpenAi ) );
+  assert_eq!( parsed.model.as_deref(), Some( "gpt-4o" ) );
+  assert_eq!( parsed.max_tokens, Some( 64 ) );
+  assert!( parsed.verbose );
+  assert_eq!( parsed.inputs, vec![ "hello", "world" ] );
+}
+
+#[ test ]
+fn double_dash_ends_options()
+{
+  let parsed = args( "embed -- --not-an-option -v" );
+
+  assert_eq!( parsed.command, Command::Embed );
+  assert!( !parsed.verbose );
+  assert_eq!( parsed.inputs, vec![ "--not-an-option", "-v" ] );
+}
+
+#[ test ]
+fn help_short_circuits()
+{
+  assert_eq!( parse_words( "--help" ).unwrap(), Parsed::Help );
+  assert_eq!( parse_words( "chat -h --bogus" ).unwrap(), Parsed::Help );
+}
+
+#[ test ]
+fn parse_errors()
+{
+  assert_eq!( parse_words( "translate" ).unwrap_err(), CliError::UnknownCommand( "translate".into() ) );
+  assert_eq!( parse_words( "chat --bogus" ).unwrap_err(), CliError::UnknownOption( "--bogus".into() ) );
+  assert_eq!( parse_words( "chat --model" ).unwrap_err(), CliError::MissingValue( "--model".into() ) );
+  assert_eq!
+  (
+    parse_words( "chat --max-tokens many" ).unwrap_err(),
+    CliError::InvalidValue { option : "--max-tokens".into(), value : "many".into() },
+  );
+  assert_eq!( parse_words( "-v" ).unwrap_err(), CliError::MissingCommand );
+}
+
+#[ test ]
+fn provider_aliases()
+{
+  assert_eq!( "anthropic".parse::< Provider >().unwrap(), Provider::Claude );
+  assert_eq!( "Google".parse::< Provider >().unwrap(), Provider::Gemini );
+  assert_eq!( "grok".parse::< Provider >().unwrap(), Provider::Xai );
+  assert_eq!( "bedrock".parse::< Provider >().unwrap_err(), CliError::UnknownProvider( "bedrock".into() ) );
+  for provider in Provider::ALL
+  {
+    assert_eq!( provider.name().parse::< Provider >().unwrap(), provider );
+  }
+}
+
+// ------------------------------------------------------------------ //
+//  Resolution
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn resolve_applies_model_defaults()
+{
+  let chat = Invocation::resolve( args( "-p gemini chat hi" ), None ).unwrap();
+  let embed = Invocation::resolve( args( "-p gemini embed hi" ), None ).unwrap();
+  let explicit = Invocation::resolve( args( "-p gemini -m custom embed hi" ), None ).unwrap();
+
+  assert_eq!( chat.model, Provider::Gemini.default_chat_model() );
+  assert_eq!( Some( embed.model.as_str() ), Provider::Gemini.default_embedding_model() );
+  assert_eq!( explicit.model, "custom" );
+}
+
+#[ test ]
+fn resolve_provider_precedence()
+{
+  let flag = Invocation::resolve( args( "-p ollama models" ), Some( "openai" ) ).unwrap();
+  let env = Invocation::resolve( args( "models" ), Some( "xai" ) ).unwrap();
+
+  assert_eq!( flag.provider, Provider::Ollama );
+  assert_eq!( env.provider, Provider::Xai );
+  let err = Invocation::resolve( args( "models" ), Some( "" ) ).unwrap_err();
+  assert_eq!( cli_error( &err ), &CliError::MissingProvider );
+}
+
+#[ test ]
+fn resolve_rejects_embed_without_endpoint()
+{
+  let err = Invocation::resolve( args( "-p claude embed hi" ), None ).unwrap_err();
+
+  assert_eq!( cli_error( &err ), &CliError::Unsupported { provider : "claude".into(), command : "embed".into() } );
+}
+
+#[ test ]
+fn resolve_requires_input()
+{
+  let err = Invocation::resolve( args( "-p openai chat" ), None ).unwrap_err();
+
+  assert_eq!( cli_error( &err ), &CliError::EmptyInput( "chat".into() ) );
+}
+
+// ------------------------------------------------------------------ //
+//  Input and rendering
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn read_inputs_per_command()
+{
+  let text = "first line\n\n  second line  \n";
+
+  assert_eq!( read_inputs( Command::Chat, text.as_bytes() ).unwrap(), vec![ "first line\n\n  second line" ] );
+  assert_eq!( read_inputs( Command::Embed, text.as_bytes() ).unwrap(), vec![ "first line", "  second line  " ] );
+  assert!( read_inputs( Command::Stream, "\n \n".as_bytes() ).unwrap().is_empty() );
+  assert!( read_inputs( Command::Models, text.as_bytes() ).unwrap().is_empty() );
+}
+
+#[ test ]
+fn conversation_carries_system_prompt()
+{
+  let invocation = Invocation::resolve( args( "-p openai -s terse chat say hi" ), None ).unwrap();
+  let messages = invocation.conversation().messages();
+
+  assert_eq!( messages.len(), 2 );
+  assert_eq!( messages[ 0 ].role, NeutralRole::System );
+  assert_eq!( messages[ 0 ].content, "terse" );
+  assert_eq!( messages[ 1 ].content, "say hi" );
+}
@@ -0,0 +1,95 @@
+//! Tests for rendering replies, stream events, and embeddings.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | stream_text_goes_to_out_only | stream | text on `out`, nothing on `diag` without `--verbose` |
+//! | stream_verbose_writes_diagnostics | stream | tool calls, usage, finish reason on `diag` |
+//! | stream_error_stops_rendering | stream | first `Err` is returned |
+//! | embeddings_one_json_array_per_line | embed | line-per-vector JSON |
+//! | request_payload_only_when_verbose | verbose | request JSON on `diag` |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_cli::output;
+use api_common::{ StreamEvent, ToolCallDelta, UsageUpdate };
+use futures_util::stream;
+
+fn sample_events() -> Vec< api_common::Result< StreamEvent > >
+{
+  vec!
+  [
+    Ok( StreamEvent::TextDelta( "Hel".into() ) ),
+    Ok( StreamEvent::ToolCallDelta( ToolCallDelta { index : 0, id : Some( "c1".into() ), name : Some( "f".into() ), arguments : "{}".into() } ) ),
+    Ok( StreamEvent::TextDelta( "lo".into() ) ),
+    Ok( StreamEvent::UsageUpdate( UsageUpdate { input_tokens : Some( 3 ), output_tokens : None } ) ),
+    Ok( StreamEvent::Done { finish_reason : Some( "stop".into() ) } ),
+  ]
+}
+
+fn text( bytes : Vec< u8 > ) -> String
+{
+  String::from_utf8( bytes ).unwrap()
+}
+
+#[ tokio::test ]
+async fn stream_text_goes_to_out_only()
+{
+  let ( mut out, mut diag ) = ( Vec::new(), Vec::new() );
+  output::events( &mut out, &mut diag, false, stream::iter( sample_events() ) ).await.unwrap();
+
+  assert_eq!( text( out ), "Hello\n" );
+  assert!( diag.is_empty() );
+}
+
+#[ tokio::test ]
+async fn stream_verbose_writes_diagnostics()
+{
+  let ( mut out, mut diag ) = ( Vec::new(), Vec::new() );
+  output::events( &mut out, &mut diag, true, stream::iter( sample_events() ) ).await.unwrap();
+
+  assert_eq!
+  (
+    text( diag ),
+    "tool_call[0] id=c1 name=f arguments={}\nusage : input=3 output=-\ndone : stop\n",
+  );
+}
+
+#[ tokio::test ]
+async fn stream_error_stops_rendering()
+{
+  let events = vec!
+  [
+    Ok( StreamEvent::TextDelta( "partial".into() ) ),
+    Err( error_tools::untyped::format_err!( "connection reset" ) ),
+    Ok( StreamEvent::TextDelta( "never".into() ) ),
+  ];
+  let ( mut out, mut diag ) = ( Vec::new(), Vec::new() );
+  let err = output::events( &mut out, &mut diag, false, stream::iter( events ) ).await.unwrap_err();
+
+  assert_eq!( err.to_string(), "connection reset" );
+  assert_eq!( text( out ), "partial" );
+}
+
+#[ test ]
+fn embeddings_one_json_array_per_line()
+{
+  let mut out = Vec::new();
+  output::embeddings( &mut out, &[ vec![ 0.5, 1.0 ], vec![ -2.0 ] ] ).unwrap();
+
+  assert_eq!( text( out ), "[0.5,1.0]\n[-2.0]\n" );
+}
+
+#[ test ]
+fn request_payload_only_when_verbose()
+{
+  let payload = serde_json::json!( { "model" : "m" } );
+  let mut quiet = Vec::new();
+  let mut verbose = Vec::new();
+  output::request( &mut quiet, false, &payload ).unwrap();
+  output::request( &mut verbose, true, &payload ).unwrap();
+
+  assert!( quiet.is_empty() );
+  assert_eq!( text( verbose ), "request : {\n  \"model\": \"m\"\n}\n" );
+}
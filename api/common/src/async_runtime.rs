@@ -0,0 +1,291 @@
+//! Async runtime abstraction.
+//!
+//! Provider crates never call `tokio::spawn` or `tokio::time::sleep` on their
+//! retry, health-check, or streaming paths. They call [`spawn`], [`sleep`],
+//! and [`timeout`] here, which delegate to the process-wide [`Runtime`].
+//!
+//! The runtime defaults to [`TokioRuntime`] (feature `tokio`) on native
+//! targets and to [`WasmRuntime`] on `wasm32`. `smol`, `async-std`, or custom
+//! executors implement [`Runtime`] and install it once with [`set_runtime`]
+//! before the first request.
+
+mod private
+{
+  use crate::error::{ CommonError, Result };
+  use core::fmt;
+  use core::future::Future;
+  use core::pin::Pin;
+  use core::task::{ Context, Poll };
+  use core::time::Duration;
+  use error_tools::dependency::thiserror;
+  use futures_channel::oneshot;
+  use futures_util::future::{ self, AbortHandle, Either };
+  use std::sync::OnceLock;
+
+  /// `Send` on native targets; no bound on `wasm32`, where futures holding
+  /// `fetch` handles are `!Send` and every task runs on one thread.
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  pub trait MaybeSend : Send {}
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  impl< T : Send + ?Sized > MaybeSend for T {}
+
+  /// `Send` on native targets; no bound on `wasm32`, where futures holding
+  /// `fetch` handles are `!Send` and every task runs on one thread.
+  #[ cfg( target_arch = "wasm32" ) ]
+  pub trait MaybeSend {}
+  #[ cfg( target_arch = "wasm32" ) ]
+  impl< T : ?Sized > MaybeSend for T {}
+
+  /// Boxed future handed to a [`Runtime`].
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  pub type BoxFuture< 'a, T > = Pin< Box< dyn Future< Output = T > + Send + 'a > >;
+
+  /// Boxed future handed to a [`Runtime`].
+  #[ cfg( target_arch = "wasm32" ) ]
+  pub type BoxFuture< 'a, T > = Pin< Box< dyn Future< Output = T > + 'a > >;
+
+  /// The two primitives the provider crates need from an executor.
+  ///
+  /// Implementations only spawn detached tasks and create timers;
+  /// [`JoinHandle`], cancellation, and [`timeout`] are built on top of them.
+  ///
+  /// ```rust,ignore
+  /// struct SmolRuntime;
+  ///
+  /// impl api_common::Runtime for SmolRuntime
+  /// {
+  ///   fn spawn( &self, task : api_common::BoxFuture< 'static, () > )
+  ///   {
+  ///     smol::spawn( task ).detach();
+  ///   }
+  ///
+  ///   fn sleep( &self, duration : core::time::Duration ) -> api_common::BoxFuture< 'static, () >
+  ///   {
+  ///     Box::pin( async move { smol::Timer::after( duration ).await; } )
+  ///   }
+  /// }
+  ///
+  /// api_common::set_runtime( SmolRuntime )?;
+  /// ```
+  pub trait Runtime : Send + Sync + 'static
+  {
+    /// Runs `task` to completion in the background.
+    fn spawn( &self, task : BoxFuture< 'static, () > );
+
+    /// Returns a future that completes after `duration`.
+    fn sleep( &self, duration : Duration ) -> BoxFuture< 'static, () >;
+  }
+
+  /// [`Runtime`] backed by the ambient tokio runtime.
+  ///
+  /// Spawning outside a tokio runtime panics, exactly as `tokio::spawn` does.
+  #[ cfg( all( feature = "tokio", not( target_arch = "wasm32" ) ) ) ]
+  #[ derive( Debug, Clone, Copy, Default ) ]
+  pub struct TokioRuntime;
+
+  #[ cfg( all( feature = "tokio", not( target_arch = "wasm32" ) ) ) ]
+  impl Runtime for TokioRuntime
+  {
+    #[ inline ]
+    fn spawn( &self, task : BoxFuture< 'static, () > )
+    {
+      drop( tokio::spawn( task ) );
+    }
+
+    #[ inline ]
+    fn sleep( &self, duration : Duration ) -> BoxFuture< 'static, () >
+    {
+      Box::pin( tokio::time::sleep( duration ) )
+    }
+  }
+
+  /// [`Runtime`] for `wasm32`: tasks run on the host event loop and timers
+  /// use `setTimeout`, so it works in browsers and Cloudflare Workers.
+  #[ cfg( target_arch = "wasm32" ) ]
+  #[ derive( Debug, Clone, Copy, Default ) ]
+  pub struct WasmRuntime;
+
+  #[ cfg( target_arch = "wasm32" ) ]
+  impl Runtime for WasmRuntime
+  {
+    #[ inline ]
+    fn spawn( &self, task : BoxFuture< 'static, () > )
+    {
+      wasm_bindgen_futures::spawn_local( task );
+    }
+
+    #[ inline ]
+    fn sleep( &self, duration : Duration ) -> BoxFuture< 'static, () >
+    {
+      let millis = u32::try_from( duration.as_millis() ).unwrap_or( u32::MAX );
+      Box::pin( gloo_timers::future::TimeoutFuture::new( millis ) )
+    }
+  }
+
+  static RUNTIME : OnceLock< Box< dyn Runtime > > = OnceLock::new();
+
+  #[ cfg( target_arch = "wasm32" ) ]
+  fn default_runtime() -> Box< dyn Runtime >
+  {
+    Box::new( WasmRuntime )
+  }
+
+  #[ cfg( all( feature = "tokio", not( target_arch = "wasm32" ) ) ) ]
+  fn default_runtime() -> Box< dyn Runtime >
+  {
+    Box::new( TokioRuntime )
+  }
+
+  #[ cfg( not( any( feature = "tokio", target_arch = "wasm32" ) ) ) ]
+  fn default_runtime() -> Box< dyn Runtime >
+  {
+    panic!( "no async runtime installed : call api_common::set_runtime before the first request, or enable the `tokio` feature" )
+  }
+
+  /// Installs the process-wide runtime.
+  ///
+  /// Call once, before the first request; the runtime cannot be replaced
+  /// after it has been installed or first used.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::RuntimeAlreadySet`] when a runtime is already in
+  /// place (installed earlier or defaulted on first use).
+  #[ inline ]
+  pub fn set_runtime< R : Runtime >( runtime : R ) -> Result< () >
+  {
+    RUNTIME.set( Box::new( runtime ) ).map_err( | _ | CommonError::RuntimeAlreadySet.into() )
+  }
+
+  /// Returns the process-wide runtime, installing the default on first use.
+  ///
+  /// # Panics
+  ///
+  /// Panics when no runtime was installed and none is available by default
+  /// (native target without the `tokio` feature).
+  #[ inline ]
+  #[ must_use ]
+  pub fn runtime() -> &'static dyn Runtime
+  {
+    RUNTIME.get_or_init( default_runtime ).as_ref()
+  }
+
+  /// Waits for `duration` on the process-wide runtime.
+  #[ inline ]
+  #[ must_use ]
+  pub fn sleep( duration : Duration ) -> BoxFuture< 'static, () >
+  {
+    runtime().sleep( duration )
+  }
+
+  /// The task behind a [`JoinHandle`] was aborted or panicked before
+  /// producing a value.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Task was cancelled before completion" ) ]
+  pub struct JoinError;
+
+  /// Owned handle to a task started with [`spawn`].
+  ///
+  /// Awaiting yields the task output. Dropping the handle detaches the task;
+  /// [`abort`][JoinHandle::abort] stops it at its next suspension point.
+  pub struct JoinHandle< T >
+  {
+    output : oneshot::Receiver< T >,
+    abort : AbortHandle,
+  }
+
+  impl< T > JoinHandle< T >
+  {
+    /// Stops the task at its next suspension point. Awaiting the handle
+    /// afterwards yields [`JoinError`].
+    #[ inline ]
+    pub fn abort( &self )
+    {
+      self.abort.abort();
+    }
+  }
+
+  impl< T > fmt::Debug for JoinHandle< T >
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "JoinHandle" ).finish_non_exhaustive()
+    }
+  }
+
+  impl< T > Future for JoinHandle< T >
+  {
+    type Output = core::result::Result< T, JoinError >;
+
+    #[ inline ]
+    fn poll( self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< Self::Output >
+    {
+      Pin::new( &mut self.get_mut().output ).poll( cx ).map( | result | result.map_err( | _ | JoinError ) )
+    }
+  }
+
+  /// Starts `task` on the process-wide runtime.
+  #[ inline ]
+  pub fn spawn< F >( task : F ) -> JoinHandle< F::Output >
+  where
+    F : Future + MaybeSend + 'static,
+    F::Output : MaybeSend + 'static,
+  {
+    let ( sender, output ) = oneshot::channel();
+    let ( task, abort ) = future::abortable( task );
+    runtime().spawn( Box::pin( async move
+    {
+      if let Ok( value ) = task.await
+      {
+        let _ = sender.send( value );
+      }
+    } ) );
+    JoinHandle { output, abort }
+  }
+
+  /// `future` did not complete within the [`timeout`] duration.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Deadline of {0:?} elapsed" ) ]
+  pub struct Elapsed( pub Duration );
+
+  /// Awaits `future`, giving up after `duration`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Elapsed`] when the timer fires first; `future` is dropped.
+  #[ inline ]
+  pub async fn timeout< F : Future >( duration : Duration, future : F ) -> core::result::Result< F::Output, Elapsed >
+  {
+    let future = core::pin::pin!( future );
+    match future::select( future, sleep( duration ) ).await
+    {
+      Either::Left( ( output, _ ) ) => Ok( output ),
+      Either::Right( _ ) => Err( Elapsed( duration ) ),
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    MaybeSend,
+    BoxFuture,
+    Runtime,
+    set_runtime,
+    runtime,
+    sleep,
+    spawn,
+    timeout,
+    JoinHandle,
+    JoinError,
+    Elapsed,
+  };
+
+  #[ cfg( all( feature = "tokio", not( target_arch = "wasm32" ) ) ) ]
+  exposed use private::TokioRuntime;
+
+  #[ cfg( target_arch = "wasm32" ) ]
+  exposed use private::WasmRuntime;
+}
@@ -0,0 +1,315 @@
+//! One overall limit for a call that passes through several reliability layers.
+//!
+//! Retry, failover, and circuit-breaker layers each carry their own limits.
+//! Composed, they multiply: three failover endpoints times three retries times
+//! a 30 s timeout is a 4.5 minute worst case for a call the application meant
+//! to bound at 30 s. A [`CallBudget`] states the overall limit once; the
+//! [`BudgetTracker`] started from it is handed to every layer, and each layer
+//! asks it before starting an attempt or sleeping before the next one.
+
+mod private
+{
+  use crate::async_runtime::timeout;
+  use core::future::Future;
+  use core::time::Duration;
+  use error_tools::dependency::thiserror;
+  use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+  use web_time::Instant;
+
+  /// Overall limits for one logical call, across every retry and failover
+  /// attempt. A limit left unset is not enforced.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  pub struct CallBudget
+  {
+    /// Wall-clock time from [`start`](CallBudget::start) to the end of the
+    /// last attempt, sleeps between attempts included.
+    pub total_deadline : Option< Duration >,
+    /// Attempts summed over every layer.
+    pub max_attempts : Option< u32 >,
+    /// Cost summed over every attempt, in units the caller chooses (tokens,
+    /// micro-dollars) and reports with [`BudgetTracker::record_cost`].
+    pub max_cost : Option< u64 >,
+  }
+
+  impl CallBudget
+  {
+    /// Budget with no limits.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Bounds the whole call to `deadline`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_total_deadline( mut self, deadline : Duration ) -> Self
+    {
+      self.total_deadline = Some( deadline );
+      self
+    }
+
+    /// Bounds the attempts of the whole call to `attempts`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_attempts( mut self, attempts : u32 ) -> Self
+    {
+      self.max_attempts = Some( attempts );
+      self
+    }
+
+    /// Bounds the cost of the whole call to `cost`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_cost( mut self, cost : u64 ) -> Self
+    {
+      self.max_cost = Some( cost );
+      self
+    }
+
+    /// Starts the clock; hand the tracker (or clones of it) to every layer.
+    #[ inline ]
+    #[ must_use ]
+    pub fn start( self ) -> BudgetTracker
+    {
+      BudgetTracker { budget : self, started : Instant::now(), spent : Arc::default() }
+    }
+  }
+
+  /// A [`CallBudget`] limit was reached.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ non_exhaustive ]
+  pub enum BudgetExhausted
+  {
+    /// The total deadline passed, or would pass before the next attempt.
+    #[ error( "Call budget exhausted : total deadline of {deadline:?} reached" ) ]
+    Deadline
+    {
+      /// The configured total deadline.
+      deadline : Duration,
+    },
+
+    /// Every allowed attempt was made.
+    #[ error( "Call budget exhausted : all {max_attempts} attempts used" ) ]
+    Attempts
+    {
+      /// The configured attempt limit.
+      max_attempts : u32,
+    },
+
+    /// The recorded cost reached the limit.
+    #[ error( "Call budget exhausted : cost {spent} of {max_cost} spent" ) ]
+    Cost
+    {
+      /// Cost recorded so far.
+      spent : u64,
+      /// The configured cost limit.
+      max_cost : u64,
+    },
+  }
+
+  #[ derive( Debug, Default ) ]
+  struct Spent
+  {
+    attempts : u32,
+    cost : u64,
+  }
+
+  /// A started [`CallBudget`], shared by every layer of one call.
+  ///
+  /// Clones share the attempt and cost counters.
+  #[ derive( Debug, Clone ) ]
+  pub struct BudgetTracker
+  {
+    budget : CallBudget,
+    started : Instant,
+    spent : Arc< Mutex< Spent > >,
+  }
+
+  impl BudgetTracker
+  {
+    /// The limits being enforced.
+    #[ inline ]
+    #[ must_use ]
+    pub fn budget( &self ) -> CallBudget
+    {
+      self.budget
+    }
+
+    /// Time since [`CallBudget::start`].
+    #[ inline ]
+    #[ must_use ]
+    pub fn elapsed( &self ) -> Duration
+    {
+      self.started.elapsed()
+    }
+
+    /// Time left before the total deadline, `None` without one.
+    #[ inline ]
+    #[ must_use ]
+    pub fn remaining( &self ) -> Option< Duration >
+    {
+      self.budget.total_deadline.map( | deadline | deadline.saturating_sub( self.elapsed() ) )
+    }
+
+    /// Attempts started so far, across every layer.
+    #[ inline ]
+    #[ must_use ]
+    pub fn attempts( &self ) -> u32
+    {
+      self.lock().attempts
+    }
+
+    /// Cost recorded so far.
+    #[ inline ]
+    #[ must_use ]
+    pub fn cost( &self ) -> u64
+    {
+      self.lock().cost
+    }
+
+    /// Whether another attempt may start.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first limit already reached.
+    #[ inline ]
+    pub fn check( &self ) -> Result< (), BudgetExhausted >
+    {
+      let spent = self.lock();
+      self.check_spent( &spent )
+    }
+
+    /// Counts an attempt, if another is allowed, and returns its number
+    /// (starting at 1).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first limit already reached; nothing is counted.
+    #[ inline ]
+    pub fn begin_attempt( &self ) -> Result< u32, BudgetExhausted >
+    {
+      let mut spent = self.lock();
+      self.check_spent( &spent )?;
+      spent.attempts += 1;
+      Ok( spent.attempts )
+    }
+
+    /// Adds `cost` to the recorded cost, e.g. the tokens an attempt used.
+    #[ inline ]
+    pub fn record_cost( &self, cost : u64 )
+    {
+      let mut spent = self.lock();
+      spent.cost = spent.cost.saturating_add( cost );
+    }
+
+    /// Whether sleeping `delay` before the next attempt leaves any time for
+    /// it. Layers stop retrying instead of sleeping past the deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetExhausted::Deadline`] when the deadline would pass
+    /// during the delay, or the first other limit already reached.
+    #[ inline ]
+    pub fn allows_delay( &self, delay : Duration ) -> Result< (), BudgetExhausted >
+    {
+      self.check()?;
+      match ( self.budget.total_deadline, self.remaining() )
+      {
+        ( Some( deadline ), Some( remaining ) ) if delay >= remaining => Err( BudgetExhausted::Deadline { deadline } ),
+        _ => Ok( () ),
+      }
+    }
+
+    /// `per_attempt` shortened to the time left, so an attempt cannot
+    /// outlive the call.
+    #[ inline ]
+    #[ must_use ]
+    pub fn attempt_timeout( &self, per_attempt : Duration ) -> Duration
+    {
+      self.remaining().map_or( per_attempt, | remaining | per_attempt.min( remaining ) )
+    }
+
+    /// Runs `attempt` as one counted attempt, cut off at the total deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetExhausted`] when no attempt is allowed or the deadline
+    /// passes while `attempt` runs; `attempt`'s own result is returned as
+    /// `Ok`.
+    #[ inline ]
+    pub async fn run_attempt< F : Future >( &self, attempt : F ) -> Result< F::Output, BudgetExhausted >
+    {
+      self.begin_attempt()?;
+      self.within_deadline( attempt ).await
+    }
+
+    /// Runs `step` of an outer layer — e.g. one failover endpoint, which may
+    /// wrap a retry loop — cut off at the total deadline.
+    ///
+    /// Attempts are counted where requests are sent: when `step` counted
+    /// none itself (no budget-aware layer inside), the step counts as one, so
+    /// nested layers never count the same request twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetExhausted`] when a limit is already reached or the
+    /// deadline passes while `step` runs; `step`'s own result is returned as
+    /// `Ok`.
+    #[ inline ]
+    pub async fn run_layer< F : Future >( &self, step : F ) -> Result< F::Output, BudgetExhausted >
+    {
+      self.check()?;
+      let before = self.attempts();
+      let output = self.within_deadline( step ).await?;
+      let mut spent = self.lock();
+      if spent.attempts == before
+      {
+        spent.attempts += 1;
+      }
+      Ok( output )
+    }
+
+    async fn within_deadline< F : Future >( &self, future : F ) -> Result< F::Output, BudgetExhausted >
+    {
+      match ( self.budget.total_deadline, self.remaining() )
+      {
+        ( Some( deadline ), Some( remaining ) ) => timeout( remaining, future ).await.map_err( | _ | BudgetExhausted::Deadline { deadline } ),
+        _ => Ok( future.await ),
+      }
+    }
+
+    fn check_spent( &self, spent : &Spent ) -> Result< (), BudgetExhausted >
+    {
+      if let Some( deadline ) = self.budget.total_deadline.filter( | deadline | self.elapsed() >= *deadline )
+      {
+        return Err( BudgetExhausted::Deadline { deadline } );
+      }
+      if let Some( max_attempts ) = self.budget.max_attempts.filter( | max | spent.attempts >= *max )
+      {
+        return Err( BudgetExhausted::Attempts { max_attempts } );
+      }
+      if let Some( max_cost ) = self.budget.max_cost.filter( | max | spent.cost >= *max )
+      {
+        return Err( BudgetExhausted::Cost { spent : spent.cost, max_cost } );
+      }
+      Ok( () )
+    }
+
+    fn lock( &self ) -> MutexGuard< '_, Spent >
+    {
+      self.spent.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    CallBudget,
+    BudgetExhausted,
+    BudgetTracker,
+  };
+}
@@ -0,0 +1,204 @@
+//! Cancellation of in-flight calls.
+//!
+//! A [`CancellationToken`] is handed to a call before it starts; cancelling
+//! the token from anywhere — another task, a UI handler, a shutdown hook —
+//! ends every call holding a clone of it at its next await point. Calls end
+//! by being dropped, so the request they were sending or reading is dropped
+//! with them and its connection closed; nothing is left running behind.
+//!
+//! Tokens are executor-neutral: waiting needs no runtime and no timer.
+
+mod private
+{
+  use core::future::{ poll_fn, Future };
+  use core::pin::{ pin, Pin };
+  use core::sync::atomic::{ AtomicBool, Ordering };
+  use core::task::{ Context, Poll, Waker };
+  use error_tools::dependency::thiserror;
+  use std::collections::HashMap;
+  use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+
+  /// A call ended because its [`CancellationToken`] was cancelled.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Request cancelled" ) ]
+  pub struct Cancelled;
+
+  /// Futures waiting for cancellation, keyed so each can leave on drop.
+  #[ derive( Debug, Default ) ]
+  struct Waiters
+  {
+    next_key : u64,
+    wakers : HashMap< u64, Waker >,
+  }
+
+  #[ derive( Debug, Default ) ]
+  struct Shared
+  {
+    cancelled : AtomicBool,
+    waiters : Mutex< Waiters >,
+  }
+
+  impl Shared
+  {
+    fn lock( &self ) -> MutexGuard< '_, Waiters >
+    {
+      self.waiters.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+
+  /// Signal that ends the calls holding it.
+  ///
+  /// Clones share one signal: cancelling any clone cancels them all, and a
+  /// cancelled token stays cancelled. Tokens compare equal when they share
+  /// a signal.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "cancellation" ) ]
+  /// # {
+  /// use api_common::{ CancellationToken, Cancelled };
+  ///
+  /// let token = CancellationToken::new();
+  /// let handle = token.clone();
+  /// handle.cancel();
+  ///
+  /// let result = futures_util::FutureExt::now_or_never( token.run_until_cancelled( async { 42 } ) );
+  /// assert_eq!( result, Some( Err( Cancelled ) ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct CancellationToken
+  {
+    shared : Arc< Shared >,
+  }
+
+  impl PartialEq for CancellationToken
+  {
+    #[ inline ]
+    fn eq( &self, other : &Self ) -> bool
+    {
+      Arc::ptr_eq( &self.shared, &other.shared )
+    }
+  }
+
+  impl Eq for CancellationToken {}
+
+  impl CancellationToken
+  {
+    /// A token not yet cancelled.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Cancels every call holding a clone of this token. Cancelling again
+    /// does nothing.
+    #[ inline ]
+    pub fn cancel( &self )
+    {
+      self.shared.cancelled.store( true, Ordering::SeqCst );
+      let wakers = core::mem::take( &mut self.shared.lock().wakers );
+      for waker in wakers.into_values()
+      {
+        waker.wake();
+      }
+    }
+
+    /// Whether the token has been cancelled.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_cancelled( &self ) -> bool
+    {
+      self.shared.cancelled.load( Ordering::SeqCst )
+    }
+
+    /// Completes once the token is cancelled, at once if it already is.
+    #[ inline ]
+    pub fn cancelled( &self ) -> WaitForCancellation< '_ >
+    {
+      WaitForCancellation { token : self, key : None }
+    }
+
+    /// Runs `future` until it completes or the token is cancelled, whichever
+    /// comes first; on cancellation `future` is dropped unfinished.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Cancelled`] when the token was cancelled first, or before
+    /// `future` was polled at all.
+    #[ inline ]
+    pub async fn run_until_cancelled< F : Future >( &self, future : F ) -> Result< F::Output, Cancelled >
+    {
+      let mut future = pin!( future );
+      let mut cancelled = self.cancelled();
+      poll_fn( | cx |
+      {
+        if Pin::new( &mut cancelled ).poll( cx ).is_ready()
+        {
+          return Poll::Ready( Err( Cancelled ) );
+        }
+        future.as_mut().poll( cx ).map( Ok )
+      } ).await
+    }
+  }
+
+  /// Future of [`CancellationToken::cancelled`].
+  #[ derive( Debug ) ]
+  #[ must_use = "futures do nothing unless polled" ]
+  pub struct WaitForCancellation< 'a >
+  {
+    token : &'a CancellationToken,
+    key : Option< u64 >,
+  }
+
+  impl Future for WaitForCancellation< '_ >
+  {
+    type Output = ();
+
+    #[ inline ]
+    fn poll( mut self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< () >
+    {
+      let this = &mut *self;
+      let mut waiters = this.token.shared.lock();
+      // Checked under the lock: `cancel` sets the flag before taking it, so
+      // a waker registered here is always woken.
+      if this.token.is_cancelled()
+      {
+        this.key = None;
+        return Poll::Ready( () );
+      }
+      let key = *this.key.get_or_insert_with( ||
+      {
+        waiters.next_key += 1;
+        waiters.next_key
+      } );
+      waiters.wakers.insert( key, cx.waker().clone() );
+      Poll::Pending
+    }
+  }
+
+  impl Drop for WaitForCancellation< '_ >
+  {
+    #[ inline ]
+    fn drop( &mut self )
+    {
+      if let Some( key ) = self.key
+      {
+        self.token.shared.lock().wakers.remove( &key );
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    CancellationToken,
+    Cancelled,
+    WaitForCancellation,
+  };
+}
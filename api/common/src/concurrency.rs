@@ -0,0 +1,314 @@
+//! In-flight request cap with a fair FIFO queue.
+//!
+//! A local server such as Ollama serves a handful of requests at once and
+//! answers the rest slowly or not at all; hosted providers cap concurrent
+//! requests per account. A [`ConcurrencyGate`] admits up to a fixed number of
+//! requests and queues the rest in arrival order, each waiter optionally
+//! giving up after a queue timeout. Every clone of a gate shares its slots and
+//! its queue, so one gate can be handed to every client talking to the same
+//! server or account.
+
+mod private
+{
+  use crate::async_runtime::{ timeout, Elapsed };
+  use core::future::Future;
+  use core::pin::Pin;
+  use core::task::{ Context, Poll, Waker };
+  use core::time::Duration;
+  use error_tools::dependency::thiserror;
+  use std::collections::VecDeque;
+  use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+
+  /// [`ConcurrencyGate::acquire`] waited in the queue longer than the queue
+  /// timeout.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Concurrency limit reached; gave up after waiting {waited:?} in the queue" ) ]
+  pub struct QueueTimeout
+  {
+    /// The configured queue timeout.
+    pub waited : Duration,
+  }
+
+  #[ derive( Debug ) ]
+  struct Waiter
+  {
+    ticket : u64,
+    waker : Waker,
+  }
+
+  /// Slots and queue. A released slot is handed straight to the first
+  /// waiter (its ticket moves to `granted`) instead of being freed, so a
+  /// newcomer can never overtake the queue.
+  #[ derive( Debug, Default ) ]
+  struct Slots
+  {
+    in_flight : usize,
+    next_ticket : u64,
+    waiting : VecDeque< Waiter >,
+    granted : Vec< u64 >,
+  }
+
+  impl Slots
+  {
+    /// Frees one slot or hands it to the first waiter, whose waker is
+    /// returned to be woken after the lock is released.
+    fn release( &mut self ) -> Option< Waker >
+    {
+      if let Some( waiter ) = self.waiting.pop_front()
+      {
+        self.granted.push( waiter.ticket );
+        Some( waiter.waker )
+      }
+      else
+      {
+        self.in_flight = self.in_flight.saturating_sub( 1 );
+        None
+      }
+    }
+  }
+
+  #[ derive( Debug ) ]
+  struct Shared
+  {
+    max_in_flight : usize,
+    slots : Mutex< Slots >,
+  }
+
+  impl Shared
+  {
+    fn lock( &self ) -> MutexGuard< '_, Slots >
+    {
+      self.slots.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+
+    fn release( &self )
+    {
+      let waker = self.lock().release();
+      if let Some( waker ) = waker
+      {
+        waker.wake();
+      }
+    }
+  }
+
+  /// Caps in-flight requests; the rest wait in arrival order.
+  ///
+  /// Clones share slots and queue. The queue timeout belongs to each handle.
+  #[ derive( Debug, Clone ) ]
+  pub struct ConcurrencyGate
+  {
+    shared : Arc< Shared >,
+    queue_timeout : Option< Duration >,
+  }
+
+  impl ConcurrencyGate
+  {
+    /// Gate admitting `max_in_flight` requests at once; zero is raised to
+    /// one. Waiters queue without a timeout.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( max_in_flight : usize ) -> Self
+    {
+      let shared = Shared { max_in_flight : max_in_flight.max( 1 ), slots : Mutex::default() };
+      Self { shared : Arc::new( shared ), queue_timeout : None }
+    }
+
+    /// Gives up on a queued request after `queue_timeout`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_queue_timeout( mut self, queue_timeout : Duration ) -> Self
+    {
+      self.queue_timeout = Some( queue_timeout );
+      self
+    }
+
+    /// Requests admitted at once.
+    #[ inline ]
+    #[ must_use ]
+    pub fn max_in_flight( &self ) -> usize
+    {
+      self.shared.max_in_flight
+    }
+
+    /// Longest time a request waits in the queue, `None` for no limit.
+    #[ inline ]
+    #[ must_use ]
+    pub fn queue_timeout( &self ) -> Option< Duration >
+    {
+      self.queue_timeout
+    }
+
+    /// Requests currently holding a permit.
+    #[ inline ]
+    #[ must_use ]
+    pub fn in_flight( &self ) -> usize
+    {
+      self.shared.lock().in_flight
+    }
+
+    /// Requests currently waiting for a permit.
+    #[ inline ]
+    #[ must_use ]
+    pub fn queued( &self ) -> usize
+    {
+      self.shared.lock().waiting.len()
+    }
+
+    /// Whether `other` shares this gate's slots and queue.
+    #[ inline ]
+    #[ must_use ]
+    pub fn shares_slots_with( &self, other : &Self ) -> bool
+    {
+      Arc::ptr_eq( &self.shared, &other.shared )
+    }
+
+    /// Takes a permit if one is free and nobody is queued.
+    #[ inline ]
+    #[ must_use ]
+    pub fn try_acquire( &self ) -> Option< ConcurrencyPermit >
+    {
+      let mut slots = self.shared.lock();
+      if slots.in_flight < self.shared.max_in_flight && slots.waiting.is_empty()
+      {
+        slots.in_flight += 1;
+        return Some( ConcurrencyPermit { shared : Arc::clone( &self.shared ) } );
+      }
+      None
+    }
+
+    /// Waits for a permit behind every earlier waiter. Dropping the future
+    /// leaves the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueTimeout`] when the queue timeout passes first.
+    #[ inline ]
+    pub async fn acquire( &self ) -> Result< ConcurrencyPermit, QueueTimeout >
+    {
+      let wait = Wait { shared : Arc::clone( &self.shared ), ticket : None, done : false };
+      match self.queue_timeout
+      {
+        Some( queue_timeout ) => timeout( queue_timeout, wait ).await.map_err( | Elapsed( waited ) | QueueTimeout { waited } ),
+        None => Ok( wait.await ),
+      }
+    }
+
+    /// Runs `future` holding a permit for its whole duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueTimeout`] when no permit was granted in time;
+    /// `future` is then not polled.
+    #[ inline ]
+    pub async fn run< F : Future >( &self, future : F ) -> Result< F::Output, QueueTimeout >
+    {
+      let _permit = self.acquire().await?;
+      Ok( future.await )
+    }
+  }
+
+  /// One admitted request; the slot is released (or handed to the next
+  /// waiter) when the permit is dropped.
+  #[ derive( Debug ) ]
+  #[ must_use = "the slot is released as soon as the permit is dropped" ]
+  pub struct ConcurrencyPermit
+  {
+    shared : Arc< Shared >,
+  }
+
+  impl Drop for ConcurrencyPermit
+  {
+    #[ inline ]
+    fn drop( &mut self )
+    {
+      self.shared.release();
+    }
+  }
+
+  /// Queue position of one [`ConcurrencyGate::acquire`] call.
+  #[ derive( Debug ) ]
+  struct Wait
+  {
+    shared : Arc< Shared >,
+    ticket : Option< u64 >,
+    done : bool,
+  }
+
+  impl Future for Wait
+  {
+    type Output = ConcurrencyPermit;
+
+    fn poll( mut self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< ConcurrencyPermit >
+    {
+      let this = &mut *self;
+      let mut slots = this.shared.lock();
+      match this.ticket
+      {
+        None if slots.in_flight < this.shared.max_in_flight && slots.waiting.is_empty() =>
+        {
+          slots.in_flight += 1;
+        }
+        None =>
+        {
+          let ticket = slots.next_ticket;
+          slots.next_ticket += 1;
+          slots.waiting.push_back( Waiter { ticket, waker : cx.waker().clone() } );
+          this.ticket = Some( ticket );
+          return Poll::Pending;
+        }
+        Some( ticket ) =>
+        {
+          if let Some( index ) = slots.granted.iter().position( | granted | *granted == ticket )
+          {
+            slots.granted.swap_remove( index );
+          }
+          else
+          {
+            if let Some( waiter ) = slots.waiting.iter_mut().find( | waiter | waiter.ticket == ticket )
+            {
+              waiter.waker.clone_from( cx.waker() );
+            }
+            return Poll::Pending;
+          }
+        }
+      }
+      drop( slots );
+      this.done = true;
+      Poll::Ready( ConcurrencyPermit { shared : Arc::clone( &this.shared ) } )
+    }
+  }
+
+  impl Drop for Wait
+  {
+    fn drop( &mut self )
+    {
+      let Some( ticket ) = self.ticket.filter( | _ | !self.done ) else { return };
+      let mut slots = self.shared.lock();
+      if let Some( index ) = slots.granted.iter().position( | granted | *granted == ticket )
+      {
+        // Granted but never taken: pass the slot on.
+        slots.granted.swap_remove( index );
+        let waker = slots.release();
+        drop( slots );
+        if let Some( waker ) = waker
+        {
+          waker.wake();
+        }
+      }
+      else
+      {
+        slots.waiting.retain( | waiter | waiter.ticket != ticket );
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ConcurrencyGate,
+    ConcurrencyPermit,
+    QueueTimeout,
+  };
+}
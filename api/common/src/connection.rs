@@ -0,0 +1,247 @@
+//! Connection pool, keep-alive, HTTP/2, and proxy settings shared by provider clients.
+//!
+//! The first request of a process pays for DNS, TCP, and the TLS handshake,
+//! often more than the model's time to first token on a short prompt. A
+//! latency-sensitive service tunes how long idle connections are kept and
+//! whether HTTP/2 is spoken, then warms the pool before taking traffic. A
+//! [`ConnectionTuning`] states those settings once; each provider crate
+//! applies it to its own HTTP client and offers a `warm_up` method that
+//! opens the connection ahead of the first request.
+//!
+//! This module describes the settings and applies them to a
+//! `reqwest::ClientBuilder`. It opens no connections.
+
+mod private
+{
+  use core::time::Duration;
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  use crate::error::CommonError;
+
+  /// How the client picks the HTTP version.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  pub enum HttpVersion
+  {
+    /// Negotiated during the TLS handshake (ALPN): HTTP/2 when the server
+    /// offers it and the client was built with HTTP/2 support, else HTTP/1.1.
+    #[ default ]
+    Negotiate,
+    /// HTTP/1.1 only.
+    Http1Only,
+    /// HTTP/2 without negotiation ("prior knowledge"). Needed for plaintext
+    /// HTTP/2 servers such as a local gateway; fails against HTTP/1.1 servers.
+    Http2PriorKnowledge,
+  }
+
+  /// Connection settings applied when a provider client builds its HTTP
+  /// client. A setting left unset keeps the provider's default.
+  #[ derive( Debug, Clone, PartialEq, Eq, Default ) ]
+  pub struct ConnectionTuning
+  {
+    /// How long an idle pooled connection is kept open.
+    pub pool_idle_timeout : Option< Duration >,
+    /// Idle connections kept per host.
+    pub pool_max_idle_per_host : Option< usize >,
+    /// Interval of TCP keep-alive probes on open connections.
+    pub tcp_keepalive : Option< Duration >,
+    /// Whether Nagle's algorithm is disabled, sending small writes at once.
+    pub tcp_nodelay : Option< bool >,
+    /// How the HTTP version is picked.
+    pub http_version : HttpVersion,
+    /// Whether HTTP/2 flow-control windows grow with the measured bandwidth
+    /// instead of staying at their initial size.
+    pub http2_adaptive_window : bool,
+    /// Interval of HTTP/2 PING frames that keep a connection from being
+    /// dropped by proxies while no request is running.
+    pub http2_keep_alive_interval : Option< Duration >,
+    /// Proxy every request is sent through, such as
+    /// `http://proxy.internal:3128`. Unset keeps the provider's default,
+    /// which honours the `HTTPS_PROXY` family of environment variables.
+    pub proxy : Option< String >,
+  }
+
+  impl ConnectionTuning
+  {
+    /// Tuning that keeps every provider default.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Settings for a long-lived service that wants its first request fast:
+    /// idle connections kept for five minutes, TCP keep-alive every 30 s,
+    /// Nagle disabled, and HTTP/2 adaptive windows with a 30 s ping.
+    #[ inline ]
+    #[ must_use ]
+    pub fn low_latency() -> Self
+    {
+      Self::new()
+      .with_pool_idle_timeout( Duration::from_secs( 300 ) )
+      .with_tcp_keepalive( Duration::from_secs( 30 ) )
+      .with_tcp_nodelay( true )
+      .with_http2_adaptive_window( true )
+      .with_http2_keep_alive_interval( Duration::from_secs( 30 ) )
+    }
+
+    /// Keeps idle connections open for `timeout`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_pool_idle_timeout( mut self, timeout : Duration ) -> Self
+    {
+      self.pool_idle_timeout = Some( timeout );
+      self
+    }
+
+    /// Keeps at most `max` idle connections per host.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_pool_max_idle_per_host( mut self, max : usize ) -> Self
+    {
+      self.pool_max_idle_per_host = Some( max );
+      self
+    }
+
+    /// Sends TCP keep-alive probes every `interval`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tcp_keepalive( mut self, interval : Duration ) -> Self
+    {
+      self.tcp_keepalive = Some( interval );
+      self
+    }
+
+    /// Disables (`true`) or keeps (`false`) Nagle's algorithm.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tcp_nodelay( mut self, nodelay : bool ) -> Self
+    {
+      self.tcp_nodelay = Some( nodelay );
+      self
+    }
+
+    /// Picks the HTTP version with `version`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http_version( mut self, version : HttpVersion ) -> Self
+    {
+      self.http_version = version;
+      self
+    }
+
+    /// Speaks HTTP/2 without negotiation.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http2_prior_knowledge( self ) -> Self
+    {
+      self.with_http_version( HttpVersion::Http2PriorKnowledge )
+    }
+
+    /// Lets HTTP/2 flow-control windows adapt to the measured bandwidth.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http2_adaptive_window( mut self, adaptive : bool ) -> Self
+    {
+      self.http2_adaptive_window = adaptive;
+      self
+    }
+
+    /// Sends an HTTP/2 PING every `interval`, also while idle.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http2_keep_alive_interval( mut self, interval : Duration ) -> Self
+    {
+      self.http2_keep_alive_interval = Some( interval );
+      self
+    }
+
+    /// Sends every request through the proxy at `url`. The URL is checked
+    /// when a client applies the tuning, not here.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_proxy( mut self, url : impl Into< String > ) -> Self
+    {
+      self.proxy = Some( url.into() );
+      self
+    }
+
+    /// Whether any setting needs a client built with HTTP/2 support.
+    /// Adaptive windows and pings only take effect on HTTP/2 connections, so
+    /// they are ignored, not rejected, by a client without it.
+    #[ inline ]
+    #[ must_use ]
+    pub fn requires_http2( &self ) -> bool
+    {
+      self.http_version == HttpVersion::Http2PriorKnowledge
+    }
+
+    /// Applies the settings to `builder`; unset settings leave it unchanged.
+    ///
+    /// HTTP/2 windows and pings are skipped without the `http2` feature,
+    /// since no connection could use them. Provider crates forward their own
+    /// `http2` feature here.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommonError::InvalidConnectionTuning`] for a malformed proxy
+    /// URL, and for HTTP/2 prior knowledge without the `http2` feature rather
+    /// than silently falling back to HTTP/1.1.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub fn apply_to( self, mut builder : reqwest::ClientBuilder ) -> Result< reqwest::ClientBuilder, CommonError >
+    {
+      if let Some( timeout ) = self.pool_idle_timeout
+      {
+        builder = builder.pool_idle_timeout( timeout );
+      }
+      if let Some( max ) = self.pool_max_idle_per_host
+      {
+        builder = builder.pool_max_idle_per_host( max );
+      }
+      if let Some( interval ) = self.tcp_keepalive
+      {
+        builder = builder.tcp_keepalive( interval );
+      }
+      if let Some( nodelay ) = self.tcp_nodelay
+      {
+        builder = builder.tcp_nodelay( nodelay );
+      }
+      if let Some( url ) = self.proxy
+      {
+        let proxy = reqwest::Proxy::all( url )
+        .map_err( | e | CommonError::InvalidConnectionTuning( format!( "invalid proxy URL : {e}" ) ) )?;
+        builder = builder.proxy( proxy );
+      }
+      match self.http_version
+      {
+        HttpVersion::Negotiate => {}
+        HttpVersion::Http1Only => builder = builder.http1_only(),
+        #[ cfg( feature = "http2" ) ]
+        HttpVersion::Http2PriorKnowledge => builder = builder.http2_prior_knowledge(),
+        #[ cfg( not( feature = "http2" ) ) ]
+        HttpVersion::Http2PriorKnowledge =>
+        {
+          return Err( CommonError::InvalidConnectionTuning( "HTTP/2 prior knowledge requires the `http2` feature".to_string() ) );
+        }
+      }
+      #[ cfg( feature = "http2" ) ]
+      {
+        builder = builder.http2_adaptive_window( self.http2_adaptive_window );
+        if let Some( interval ) = self.http2_keep_alive_interval
+        {
+          builder = builder.http2_keep_alive_interval( interval ).http2_keep_alive_while_idle( true );
+        }
+      }
+      Ok( builder )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ConnectionTuning,
+    HttpVersion,
+  };
+}
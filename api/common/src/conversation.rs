@@ -0,0 +1,419 @@
+//! Provider-neutral conversation history with explicit truncation.
+//!
+//! A [`Conversation`] stores a system prompt, an optional running summary,
+//! and the ordered message turns of a chat. It never shrinks on its own: the
+//! caller decides when to apply a [`TruncationStrategy`] and receives the
+//! removed messages back, so nothing disappears silently.
+//!
+//! A *turn* starts at a user message and includes every following message up
+//! to the next user message. Truncation always removes whole turns, so the
+//! retained history never starts with an orphaned assistant reply.
+
+mod private
+{
+  use crate::error::Result;
+  use crate::message::{ NeutralMessage, NeutralRole };
+  use core::fmt;
+
+  /// Function estimating how many tokens a piece of text occupies.
+  pub type TokenEstimator = fn( &str ) -> usize;
+
+  /// Callback condensing removed messages into a summary.
+  ///
+  /// Receives the previous summary (as a leading system message, if any)
+  /// followed by the turns being removed, and returns the new summary text.
+  /// Typically the callback sends those messages to a model with a
+  /// "summarise this" instruction.
+  pub type Summarizer = Box< dyn Fn( &[ NeutralMessage ] ) -> Result< String > + Send + Sync >;
+
+  /// Rough provider-independent token estimate: one token per four characters.
+  ///
+  /// Good enough to keep a history under a budget with some headroom. Use a
+  /// provider tokenizer through [`TruncationStrategy::token_budget_with`]
+  /// when exact counts matter.
+  #[ inline ]
+  #[ must_use ]
+  pub fn estimate_tokens( text : &str ) -> usize
+  {
+    ( text.chars().count() + 3 ) / 4
+  }
+
+  /// How [`Conversation::truncate`] shortens the history.
+  #[ non_exhaustive ]
+  pub enum TruncationStrategy
+  {
+    /// Keep only the most recent `n` turns.
+    KeepLastTurns( usize ),
+
+    /// Remove the oldest turns until the estimated size fits `max_tokens`.
+    ///
+    /// The system prompt and summary count towards the budget but are never
+    /// removed. The most recent turn is always kept, even if it alone exceeds
+    /// the budget.
+    TokenBudget
+    {
+      /// Upper bound for the estimated conversation size.
+      max_tokens : usize,
+      /// Estimator applied to every message body.
+      estimator : TokenEstimator,
+    },
+
+    /// Keep the most recent `keep_last_turns` turns and fold everything older
+    /// into the conversation summary through a caller-supplied callback.
+    Summarize
+    {
+      /// Number of recent turns kept verbatim.
+      keep_last_turns : usize,
+      /// Callback producing the new summary.
+      summarizer : Summarizer,
+    },
+  }
+
+  impl TruncationStrategy
+  {
+    /// Keeps the most recent `turns` turns.
+    #[ inline ]
+    #[ must_use ]
+    pub fn keep_last_turns( turns : usize ) -> Self
+    {
+      Self::KeepLastTurns( turns )
+    }
+
+    /// Fits the history into `max_tokens` using [`estimate_tokens`].
+    #[ inline ]
+    #[ must_use ]
+    pub fn token_budget( max_tokens : usize ) -> Self
+    {
+      Self::token_budget_with( max_tokens, estimate_tokens )
+    }
+
+    /// Fits the history into `max_tokens` using a custom estimator.
+    #[ inline ]
+    #[ must_use ]
+    pub fn token_budget_with( max_tokens : usize, estimator : TokenEstimator ) -> Self
+    {
+      Self::TokenBudget { max_tokens, estimator }
+    }
+
+    /// Summarises everything older than the last `keep_last_turns` turns.
+    #[ inline ]
+    pub fn summarize< F >( keep_last_turns : usize, summarizer : F ) -> Self
+    where
+      F : Fn( &[ NeutralMessage ] ) -> Result< String > + Send + Sync + 'static,
+    {
+      Self::Summarize { keep_last_turns, summarizer : Box::new( summarizer ) }
+    }
+  }
+
+  impl fmt::Debug for TruncationStrategy
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::KeepLastTurns( turns ) => f.debug_tuple( "KeepLastTurns" ).field( turns ).finish(),
+        Self::TokenBudget { max_tokens, .. } => f
+          .debug_struct( "TokenBudget" )
+          .field( "max_tokens", max_tokens )
+          .finish_non_exhaustive(),
+        Self::Summarize { keep_last_turns, .. } => f
+          .debug_struct( "Summarize" )
+          .field( "keep_last_turns", keep_last_turns )
+          .finish_non_exhaustive(),
+      }
+    }
+  }
+
+  /// Multi-turn chat history independent of any provider.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "conversation" ) ]
+  /// # {
+  /// use api_common::{ Conversation, TruncationStrategy };
+  ///
+  /// let mut conversation = Conversation::new().with_system( "Be brief." );
+  /// conversation.push_user( "Hi" );
+  /// conversation.push_assistant( "Hello!" );
+  /// conversation.push_user( "What is Rust?" );
+  ///
+  /// let removed = conversation.truncate( &TruncationStrategy::keep_last_turns( 1 ) ).unwrap();
+  /// assert_eq!( removed.len(), 2 );
+  /// assert_eq!( conversation.messages().len(), 2 ); // system + latest user turn
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct Conversation
+  {
+    /// Caller-provided system prompt.
+    system : Option< String >,
+    /// Summary of truncated turns, produced by [`TruncationStrategy::Summarize`].
+    summary : Option< String >,
+    /// Ordered conversation messages, excluding system prompt and summary.
+    turns : Vec< NeutralMessage >,
+  }
+
+  impl Conversation
+  {
+    /// Creates an empty conversation without a system prompt.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Sets the system prompt.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_system( mut self, system : impl Into< String > ) -> Self
+    {
+      self.system = Some( system.into() );
+      self
+    }
+
+    /// Replaces the system prompt; `None` removes it.
+    #[ inline ]
+    pub fn set_system( &mut self, system : Option< String > )
+    {
+      self.system = system;
+    }
+
+    /// Returns the system prompt.
+    #[ inline ]
+    #[ must_use ]
+    pub fn system( &self ) -> Option< &str >
+    {
+      self.system.as_deref()
+    }
+
+    /// Returns the summary of previously truncated turns.
+    #[ inline ]
+    #[ must_use ]
+    pub fn summary( &self ) -> Option< &str >
+    {
+      self.summary.as_deref()
+    }
+
+    /// Replaces the summary of truncated turns; `None` removes it.
+    ///
+    /// For callers that keep the history elsewhere and rebuild a
+    /// conversation to truncate it.
+    #[ inline ]
+    pub fn set_summary( &mut self, summary : Option< String > )
+    {
+      self.summary = summary;
+    }
+
+    /// Returns the retained messages, excluding system prompt and summary.
+    #[ inline ]
+    #[ must_use ]
+    pub fn turns( &self ) -> &[ NeutralMessage ]
+    {
+      &self.turns
+    }
+
+    /// Returns the number of turns (see the module docs for the definition).
+    #[ inline ]
+    #[ must_use ]
+    pub fn turn_count( &self ) -> usize
+    {
+      self.turn_starts().len()
+    }
+
+    /// Returns `true` when no messages have been pushed (or all were removed).
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.turns.is_empty()
+    }
+
+    /// Appends a message.
+    #[ inline ]
+    pub fn push( &mut self, message : NeutralMessage )
+    {
+      self.turns.push( message );
+    }
+
+    /// Appends a user message, starting a new turn.
+    #[ inline ]
+    pub fn push_user( &mut self, content : impl Into< String > )
+    {
+      self.push( NeutralMessage::user( content ) );
+    }
+
+    /// Appends an assistant message to the current turn.
+    #[ inline ]
+    pub fn push_assistant( &mut self, content : impl Into< String > )
+    {
+      self.push( NeutralMessage::assistant( content ) );
+    }
+
+    /// Removes all turns and the summary, keeping the system prompt.
+    #[ inline ]
+    pub fn clear( &mut self )
+    {
+      self.turns.clear();
+      self.summary = None;
+    }
+
+    /// System prompt and summary joined by a blank line.
+    ///
+    /// Intended for providers that take system instructions outside the
+    /// message list (Anthropic `system`, Gemini `system_instruction`).
+    #[ inline ]
+    #[ must_use ]
+    pub fn instructions( &self ) -> Option< String >
+    {
+      match ( &self.system, &self.summary )
+      {
+        ( Some( system ), Some( summary ) ) => Some( format!( "{system}\n\n{summary}" ) ),
+        ( Some( text ), None ) | ( None, Some( text ) ) => Some( text.clone() ),
+        ( None, None ) => None,
+      }
+    }
+
+    /// Full message list: system prompt, summary, then turns.
+    ///
+    /// System prompt and summary are emitted as separate system messages.
+    #[ inline ]
+    #[ must_use ]
+    pub fn messages( &self ) -> Vec< NeutralMessage >
+    {
+      let mut messages = Vec::with_capacity( self.turns.len() + 2 );
+      messages.extend( self.system.iter().cloned().map( NeutralMessage::system ) );
+      messages.extend( self.summary.iter().cloned().map( NeutralMessage::system ) );
+      messages.extend( self.turns.iter().cloned() );
+      messages
+    }
+
+    /// [`messages`](Self::messages) converted into a provider message type.
+    #[ inline ]
+    #[ must_use ]
+    pub fn messages_into< M >( &self ) -> Vec< M >
+    where
+      M : From< NeutralMessage >,
+    {
+      self.messages().into_iter().map( M::from ).collect()
+    }
+
+    /// [`turns`](Self::turns) converted into a provider message type.
+    ///
+    /// Pair with [`instructions`](Self::instructions) for providers that
+    /// carry the system prompt separately.
+    #[ inline ]
+    #[ must_use ]
+    pub fn turns_into< M >( &self ) -> Vec< M >
+    where
+      M : From< NeutralMessage >,
+    {
+      self.turns.iter().cloned().map( M::from ).collect()
+    }
+
+    /// Estimated size of the full message list.
+    #[ inline ]
+    #[ must_use ]
+    pub fn estimated_tokens( &self, estimator : TokenEstimator ) -> usize
+    {
+      self.fixed_tokens( estimator ) + self.turns.iter().map( | m | estimator( &m.content ) ).sum::< usize >()
+    }
+
+    /// Applies `strategy` and returns the removed messages in original order.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from a [`TruncationStrategy::Summarize`] callback;
+    /// the conversation is left unchanged in that case.
+    #[ inline ]
+    pub fn truncate( &mut self, strategy : &TruncationStrategy ) -> Result< Vec< NeutralMessage > >
+    {
+      match strategy
+      {
+        TruncationStrategy::KeepLastTurns( turns ) =>
+        {
+          let cut = self.cut_keeping( *turns );
+          Ok( self.turns.drain( ..cut ).collect() )
+        },
+        TruncationStrategy::TokenBudget { max_tokens, estimator } =>
+        {
+          let starts = self.turn_starts();
+          let mut total = self.estimated_tokens( *estimator );
+          let mut cut = 0;
+          for window in starts.windows( 2 )
+          {
+            if total <= *max_tokens
+            {
+              break;
+            }
+            total -= self.turns[ window[ 0 ]..window[ 1 ] ].iter().map( | m | estimator( &m.content ) ).sum::< usize >();
+            cut = window[ 1 ];
+          }
+          Ok( self.turns.drain( ..cut ).collect() )
+        },
+        TruncationStrategy::Summarize { keep_last_turns, summarizer } =>
+        {
+          let cut = self.cut_keeping( *keep_last_turns );
+          if cut == 0
+          {
+            return Ok( Vec::new() );
+          }
+          let mut input = Vec::with_capacity( cut + 1 );
+          input.extend( self.summary.iter().cloned().map( NeutralMessage::system ) );
+          input.extend( self.turns[ ..cut ].iter().cloned() );
+          self.summary = Some( summarizer( &input )? );
+          Ok( self.turns.drain( ..cut ).collect() )
+        },
+      }
+    }
+
+    /// Index of the first message of every turn.
+    fn turn_starts( &self ) -> Vec< usize >
+    {
+      self.turns
+      .iter()
+      .enumerate()
+      .filter( | ( index, message ) | *index == 0 || message.role == NeutralRole::User )
+      .map( | ( index, _ ) | index )
+      .collect()
+    }
+
+    /// Message index where the last `turns` turns begin.
+    fn cut_keeping( &self, turns : usize ) -> usize
+    {
+      let starts = self.turn_starts();
+      if turns >= starts.len()
+      {
+        0
+      }
+      else if turns == 0
+      {
+        self.turns.len()
+      }
+      else
+      {
+        starts[ starts.len() - turns ]
+      }
+    }
+
+    /// Estimated size of the parts truncation never removes.
+    fn fixed_tokens( &self, estimator : TokenEstimator ) -> usize
+    {
+      self.system.as_deref().map_or( 0, estimator ) + self.summary.as_deref().map_or( 0, estimator )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    TokenEstimator,
+    Summarizer,
+    estimate_tokens,
+    TruncationStrategy,
+    Conversation,
+  };
+}
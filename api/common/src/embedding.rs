@@ -0,0 +1,180 @@
+//! Provider-neutral embeddings trait and vector utilities.
+//!
+//! [`Embedder`] is implemented by the provider crates that offer text
+//! embeddings (`api_openai`, `api_gemini`, `api_ollama`) behind their `common`
+//! feature, so retrieval code can be written once against one interface.
+//!
+//! The free functions operate on plain `f32` slices. None of them normalises
+//! or batches implicitly; each is invoked explicitly by the caller.
+
+mod private
+{
+  use crate::error::{ CommonError, Result };
+
+  /// One embedding vector.
+  pub type Embedding = Vec< f32 >;
+
+  /// A model that turns text into embedding vectors.
+  ///
+  /// `embed` sends exactly the given texts; it never splits, retries, or
+  /// normalises. Use [`embed_batched`] to respect provider batch limits.
+  #[ async_trait::async_trait ]
+  pub trait Embedder : Send + Sync
+  {
+    /// Model identifier the embeddings are produced with.
+    fn model( &self ) -> &str;
+
+    /// Largest number of texts a single [`embed`](Self::embed) call accepts,
+    /// if the provider documents a limit.
+    #[ inline ]
+    fn max_batch_size( &self ) -> Option< usize >
+    {
+      None
+    }
+
+    /// Embeds `texts`, returning one vector per text in input order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the provider error converted into the crate error type.
+    async fn embed( &self, texts : &[ String ] ) -> Result< Vec< Embedding > >;
+  }
+
+  /// Embeds `texts` in consecutive chunks of at most `batch_size`.
+  ///
+  /// Chunks are sent sequentially; results keep input order.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::InvalidArgument`] for a zero `batch_size`, and
+  /// propagates the first error returned by the embedder.
+  #[ inline ]
+  pub async fn embed_batched< E >( embedder : &E, texts : &[ String ], batch_size : usize ) -> Result< Vec< Embedding > >
+  where
+    E : Embedder + ?Sized,
+  {
+    if batch_size == 0
+    {
+      return Err( CommonError::InvalidArgument( "batch_size must be greater than zero".to_owned() ).into() );
+    }
+    let mut embeddings = Vec::with_capacity( texts.len() );
+    for chunk in texts.chunks( batch_size )
+    {
+      embeddings.extend( embedder.embed( chunk ).await? );
+    }
+    Ok( embeddings )
+  }
+
+  /// Euclidean (L2) length of `vector`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn l2_norm( vector : &[ f32 ] ) -> f32
+  {
+    vector.iter().map( | x | x * x ).sum::< f32 >().sqrt()
+  }
+
+  /// Scales `vector` in place to unit length.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::ZeroVector`] if the vector has zero length.
+  #[ inline ]
+  pub fn normalize( vector : &mut [ f32 ] ) -> Result< () >
+  {
+    let norm = l2_norm( vector );
+    if norm == 0.0
+    {
+      return Err( CommonError::ZeroVector.into() );
+    }
+    for x in vector.iter_mut()
+    {
+      *x /= norm;
+    }
+    Ok( () )
+  }
+
+  /// Dot product of two vectors of equal dimension.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::DimensionMismatch`] if the lengths differ.
+  #[ inline ]
+  pub fn dot_product( a : &[ f32 ], b : &[ f32 ] ) -> Result< f32 >
+  {
+    ensure_same_dimension( a, b )?;
+    Ok( a.iter().zip( b ).map( | ( x, y ) | x * y ).sum() )
+  }
+
+  /// Cosine similarity of two vectors, in `[-1, 1]`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::DimensionMismatch`] if the lengths differ and
+  /// [`CommonError::ZeroVector`] if either vector has zero length.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "embeddings" ) ]
+  /// # {
+  /// use api_common::cosine_similarity;
+  ///
+  /// let similarity = cosine_similarity( &[ 1.0, 0.0 ], &[ 2.0, 0.0 ] ).unwrap();
+  /// assert!( ( similarity - 1.0 ).abs() < f32::EPSILON );
+  /// # }
+  /// ```
+  #[ inline ]
+  pub fn cosine_similarity( a : &[ f32 ], b : &[ f32 ] ) -> Result< f32 >
+  {
+    let dot = dot_product( a, b )?;
+    let norms = l2_norm( a ) * l2_norm( b );
+    if norms == 0.0
+    {
+      return Err( CommonError::ZeroVector.into() );
+    }
+    Ok( dot / norms )
+  }
+
+  /// Checks that every vector has `expected` dimensions.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CommonError::DimensionMismatch`] for the first vector whose
+  /// length differs.
+  #[ inline ]
+  pub fn check_dimensions( vectors : &[ Embedding ], expected : usize ) -> Result< () >
+  {
+    match vectors.iter().find( | v | v.len() != expected )
+    {
+      Some( vector ) => Err( CommonError::DimensionMismatch { expected, actual : vector.len() }.into() ),
+      None => Ok( () ),
+    }
+  }
+
+  fn ensure_same_dimension( a : &[ f32 ], b : &[ f32 ] ) -> Result< () >
+  {
+    if a.len() == b.len()
+    {
+      Ok( () )
+    }
+    else
+    {
+      Err( CommonError::DimensionMismatch { expected : a.len(), actual : b.len() }.into() )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Embedding,
+    Embedder,
+    embed_batched,
+    l2_norm,
+    normalize,
+    dot_product,
+    cosine_similarity,
+    check_dimensions,
+  };
+}
@@ -0,0 +1,75 @@
+//! Error type and result alias for provider-neutral operations.
+
+mod private
+{
+  use error_tools::dependency::thiserror;
+
+  /// Error variants for provider-neutral operations.
+  ///
+  /// Each variant carries enough context to locate the problem without a
+  /// debugger (variable name, template position, …).
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  #[ non_exhaustive ]
+  pub enum CommonError
+  {
+    /// Template source is malformed (e.g. an unclosed `{{`).
+    #[ error( "Template syntax error at byte {position} : {reason}" ) ]
+    TemplateSyntax
+    {
+      /// Byte offset in the template source where the problem was detected.
+      position : usize,
+      /// Human-readable description of the problem.
+      reason : String,
+    },
+
+    /// A template references a variable that was not supplied.
+    #[ error( "Missing template variable : {0}" ) ]
+    MissingVariable( String ),
+
+    /// A template references a partial that was not registered.
+    #[ error( "Missing template partial : {0}" ) ]
+    MissingPartial( String ),
+
+    /// Partial expansion exceeded the nesting limit (usually a cycle).
+    #[ error( "Partial nesting too deep while expanding : {0}" ) ]
+    PartialDepthExceeded( String ),
+
+    /// Two vectors (or a vector and the expected size) differ in dimension.
+    #[ error( "Dimension mismatch : expected {expected}, got {actual}" ) ]
+    DimensionMismatch
+    {
+      /// Dimension the operation expected.
+      expected : usize,
+      /// Dimension actually found.
+      actual : usize,
+    },
+
+    /// Operation is undefined for a vector of zero length.
+    #[ error( "Vector has zero length" ) ]
+    ZeroVector,
+
+    /// An argument is outside its valid range.
+    #[ error( "Invalid argument : {0}" ) ]
+    InvalidArgument( String ),
+
+    /// `set_runtime` was called after a runtime was installed or first used.
+    #[ error( "Async runtime is already installed" ) ]
+    RuntimeAlreadySet,
+
+    /// A `ConnectionTuning` cannot be applied to the HTTP client.
+    #[ error( "Invalid connection tuning : {0}" ) ]
+    InvalidConnectionTuning( String ),
+  }
+
+  /// Crate-level result type backed by a boxed dynamic error.
+  pub type Result< T > = error_tools::untyped::Result< T >;
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    CommonError,
+    Result,
+  };
+}
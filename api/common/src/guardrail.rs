@@ -0,0 +1,220 @@
+//! Request and response guardrails.
+//!
+//! PII scrubbing, prompt-injection checks, and content policies apply to
+//! every call an application makes, whichever provider it goes to. A
+//! [`Guardrail`] sees the JSON body of each request before it is sent and of
+//! each response before it is deserialised; it may rewrite the body or deny
+//! it. Clients run a [`Guardrails`] chain on their request path
+//! (`with_guardrails` on the provider clients), so a policy is attached once
+//! per client instead of at every call site.
+
+mod private
+{
+  use core::fmt;
+  use error_tools::dependency::thiserror;
+  use serde_json::Value;
+  use std::sync::Arc;
+
+  /// Which body a guardrail was inspecting.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum GuardrailStage
+  {
+    /// The request body, before it is sent.
+    Request,
+    /// The response body, before it is deserialised.
+    Response,
+  }
+
+  impl fmt::Display for GuardrailStage
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Request => f.write_str( "request" ),
+        Self::Response => f.write_str( "response" ),
+      }
+    }
+  }
+
+  /// A guardrail denied a request or response.
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Guardrail {guardrail} denied the {stage} : {reason}" ) ]
+  pub struct GuardrailViolation
+  {
+    /// [`Guardrail::name`] of the guardrail that denied.
+    pub guardrail : String,
+    /// Body that was denied.
+    pub stage : GuardrailStage,
+    /// Reason given by the guardrail.
+    pub reason : String,
+  }
+
+  /// Inspects, rewrites, or denies request and response bodies.
+  ///
+  /// Both hooks default to allowing the body unchanged, so a guardrail
+  /// implements only the side it cares about. Returning `Err( reason )`
+  /// denies the body; the call then fails with a [`GuardrailViolation`]
+  /// carrying the reason, and nothing is sent (or returned).
+  pub trait Guardrail : Send + Sync
+  {
+    /// Name reported in a [`GuardrailViolation`].
+    fn name( &self ) -> &str;
+
+    /// Inspects or rewrites a request body before it is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason when the request must not be sent.
+    #[ inline ]
+    fn check_request( &self, body : &mut Value ) -> Result< (), String >
+    {
+      let _ = body;
+      Ok( () )
+    }
+
+    /// Inspects or rewrites a response body before it is deserialised.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason when the response must not reach the caller.
+    #[ inline ]
+    fn check_response( &self, body : &mut Value ) -> Result< (), String >
+    {
+      let _ = body;
+      Ok( () )
+    }
+  }
+
+  /// Ordered guardrail chain; clones share the guardrails.
+  ///
+  /// Requests pass through the guardrails in the order they were added and
+  /// responses in reverse order, so the first guardrail added is the
+  /// outermost : it sees the request first and the response last.
+  #[ derive( Clone, Default ) ]
+  pub struct Guardrails
+  {
+    chain : Vec< Arc< dyn Guardrail > >,
+  }
+
+  impl fmt::Debug for Guardrails
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_list().entries( self.chain.iter().map( | guardrail | guardrail.name() ) ).finish()
+    }
+  }
+
+  impl Guardrails
+  {
+    /// Empty chain; every body passes unchanged.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Appends `guardrail` to the chain.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with( mut self, guardrail : impl Guardrail + 'static ) -> Self
+    {
+      self.chain.push( Arc::new( guardrail ) );
+      self
+    }
+
+    /// Appends an already shared guardrail to the chain.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_shared( mut self, guardrail : Arc< dyn Guardrail > ) -> Self
+    {
+      self.chain.push( guardrail );
+      self
+    }
+
+    /// Whether the chain has no guardrails.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.chain.is_empty()
+    }
+
+    /// Number of guardrails in the chain.
+    #[ inline ]
+    #[ must_use ]
+    pub fn len( &self ) -> usize
+    {
+      self.chain.len()
+    }
+
+    /// Runs every guardrail over a request body, first added first.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`GuardrailViolation`] of the first guardrail that denies;
+    /// later guardrails are not run.
+    #[ inline ]
+    pub fn check_request( &self, body : &mut Value ) -> Result< (), GuardrailViolation >
+    {
+      for guardrail in &self.chain
+      {
+        guardrail.check_request( body ).map_err( | reason | violation( guardrail.as_ref(), GuardrailStage::Request, reason ) )?;
+      }
+      Ok( () )
+    }
+
+    /// Runs every guardrail over a response body, last added first.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`GuardrailViolation`] of the first guardrail that denies;
+    /// later guardrails are not run.
+    #[ inline ]
+    pub fn check_response( &self, body : &mut Value ) -> Result< (), GuardrailViolation >
+    {
+      for guardrail in self.chain.iter().rev()
+      {
+        guardrail.check_response( body ).map_err( | reason | violation( guardrail.as_ref(), GuardrailStage::Response, reason ) )?;
+      }
+      Ok( () )
+    }
+  }
+
+  fn violation( guardrail : &dyn Guardrail, stage : GuardrailStage, reason : String ) -> GuardrailViolation
+  {
+    GuardrailViolation { guardrail : guardrail.name().to_string(), stage, reason }
+  }
+
+  /// Calls `visit` on every string in `body`, object keys excepted.
+  ///
+  /// Request and response shapes differ between providers; a guardrail that
+  /// scrubs or scans text can use this instead of knowing where each
+  /// provider puts message content.
+  #[ inline ]
+  pub fn visit_strings( body : &mut Value, visit : &mut impl FnMut( &mut String ) )
+  {
+    match body
+    {
+      Value::String( text ) => visit( text ),
+      Value::Array( items ) => items.iter_mut().for_each( | item | visit_strings( item, visit ) ),
+      Value::Object( map ) => map.values_mut().for_each( | item | visit_strings( item, visit ) ),
+      Value::Null | Value::Bool( _ ) | Value::Number( _ ) => {},
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Guardrail,
+    GuardrailStage,
+    GuardrailViolation,
+    Guardrails,
+    visit_strings,
+  };
+}
@@ -0,0 +1,94 @@
+//! Idempotency keys for write requests.
+//!
+//! A write that times out may still have been applied; sending it again
+//! creates a second batch, upload, or fine-tuning job. Providers that honour
+//! the `Idempotency-Key` header return the first result for a repeated key
+//! instead. The key must stay the same across every attempt of one logical
+//! write — retries and failover to another endpoint — and change between
+//! writes. Clients therefore pick the key once per call, before their retry
+//! and failover layers, and send it on every attempt.
+
+mod private
+{
+  use core::fmt;
+
+  /// Header carrying the idempotency key.
+  pub const IDEMPOTENCY_KEY_HEADER : &str = "Idempotency-Key";
+
+  /// Key identifying one logical write across all of its attempts.
+  #[ derive( Debug, Clone, PartialEq, Eq, Hash ) ]
+  pub struct IdempotencyKey( String );
+
+  impl IdempotencyKey
+  {
+    /// Wraps a key chosen by the caller, such as an order or job id, so the
+    /// same write issued again after a restart is still recognised.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( key : impl Into< String > ) -> Self
+    {
+      Self( key.into() )
+    }
+
+    /// Generates a random key (a UUID v4).
+    #[ inline ]
+    #[ must_use ]
+    pub fn generate() -> Self
+    {
+      Self( uuid::Uuid::new_v4().to_string() )
+    }
+
+    /// Generates a random key after `prefix` and a hyphen, so keys from
+    /// different kinds of write can be told apart in provider logs.
+    #[ inline ]
+    #[ must_use ]
+    pub fn generate_with_prefix( prefix : &str ) -> Self
+    {
+      Self( format!( "{prefix}-{}", uuid::Uuid::new_v4() ) )
+    }
+
+    /// The key as sent in the header.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( &self ) -> &str
+    {
+      &self.0
+    }
+  }
+
+  impl fmt::Display for IdempotencyKey
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.write_str( &self.0 )
+    }
+  }
+
+  impl AsRef< str > for IdempotencyKey
+  {
+    #[ inline ]
+    fn as_ref( &self ) -> &str
+    {
+      &self.0
+    }
+  }
+
+  impl From< IdempotencyKey > for String
+  {
+    #[ inline ]
+    fn from( key : IdempotencyKey ) -> Self
+    {
+      key.0
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    IdempotencyKey,
+    IDEMPOTENCY_KEY_HEADER,
+  };
+}
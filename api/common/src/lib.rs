@@ -0,0 +1,108 @@
+//! Provider-neutral building blocks shared by the `api_llm` provider crates.
+//!
+//! Provider crates (`api_openai`, `api_claude`, `api_gemini`, …) each expose
+//! their own wire types. This crate holds the small set of types and
+//! utilities that are identical across providers, so application code can be
+//! written once and converted into any provider's message structures through
+//! the provider crate's optional `common` feature.
+//!
+//! # Features
+//!
+//! - `enabled` — activates the neutral message types, error type, and `LlmError` classes
+//! - `prompt` — typed prompt templates with partials and few-shot examples
+//! - `conversation` — conversation history with explicit truncation strategies
+//! - `embeddings` — `Embedder` trait and vector utilities
+//! - `streaming` — `TextStream` adapter over provider chunk streams
+//! - `runtime` — executor-neutral `spawn`, `sleep`, and `timeout`
+//! - `rate_limit` — `SharedRateLimiter`, request and token buckets shared across clients
+//! - `budget` — `CallBudget`, one deadline, attempt, and cost limit shared by retry and failover layers
+//! - `concurrency` — `ConcurrencyGate`, an in-flight request cap with a FIFO queue
+//! - `guardrail` — `Guardrail` trait and `Guardrails` chain over request and response bodies
+//! - `schema` — `ResponseSchema`, structured output checked against its JSON schema
+//! - `idempotency` — `IdempotencyKey`, one key per logical write reused across its attempts
+//! - `connection` — `ConnectionTuning`, pool, keep-alive, HTTP/2, and proxy settings each provider client applies
+//! - `http2` — HTTP/2 prior knowledge, windows, and pings in `ConnectionTuning::apply_to`
+//! - `usage` — `UsageReport`, token usage of every provider summed per provider, model, and tag
+//! - `cancellation` — `CancellationToken`, ending in-flight calls from anywhere
+//! - `tokio` — `TokioRuntime`, the default runtime on native targets
+//! - `full` — enables every feature above
+//!
+//! # Architecture
+//!
+//! Follows the "Thin Client, Rich API" principle: nothing in this crate
+//! performs network I/O or decides anything on the caller's behalf. Every
+//! transformation (rendering, conversion) is invoked explicitly.
+
+#[ cfg( feature = "enabled" ) ]
+use mod_interface::mod_interface;
+
+mod private {}
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// Error type and result alias.
+  layer error;
+
+  /// Provider-neutral error classes.
+  layer llm_error;
+
+  /// Provider-neutral chat message and role types.
+  layer message;
+
+  /// Prompt templates, partials, and few-shot example blocks.
+  #[ cfg( feature = "prompt" ) ]
+  layer prompt;
+
+  /// Conversation history with explicit truncation strategies.
+  #[ cfg( feature = "conversation" ) ]
+  layer conversation;
+
+  /// Embeddings trait and vector utilities.
+  #[ cfg( feature = "embeddings" ) ]
+  layer embedding;
+
+  /// Provider-neutral streaming events.
+  #[ cfg( feature = "streaming" ) ]
+  layer stream;
+
+  /// Async runtime abstraction used by the provider crates.
+  #[ cfg( feature = "runtime" ) ]
+  layer async_runtime;
+
+  /// Request and token buckets shared across clients.
+  #[ cfg( feature = "rate_limit" ) ]
+  layer rate_limit;
+
+  /// Overall deadline, attempt, and cost limits for one call.
+  #[ cfg( feature = "budget" ) ]
+  layer budget;
+
+  /// In-flight request cap with a fair FIFO queue.
+  #[ cfg( feature = "concurrency" ) ]
+  layer concurrency;
+
+  /// Request and response guardrails.
+  #[ cfg( feature = "guardrail" ) ]
+  layer guardrail;
+
+  /// Structured output validation against a JSON schema.
+  #[ cfg( feature = "schema" ) ]
+  layer schema;
+
+  /// Idempotency keys for write requests.
+  #[ cfg( feature = "idempotency" ) ]
+  layer idempotency;
+
+  /// Connection pool, keep-alive, HTTP/2, and proxy settings.
+  #[ cfg( feature = "connection" ) ]
+  layer connection;
+
+  /// Token usage aggregated across providers.
+  #[ cfg( feature = "usage" ) ]
+  layer usage;
+
+  /// Cancellation of in-flight calls.
+  #[ cfg( feature = "cancellation" ) ]
+  layer cancellation;
+}
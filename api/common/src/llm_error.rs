@@ -0,0 +1,302 @@
+//! Provider-neutral error classes.
+//!
+//! Every provider crate has its own error type, shaped by its API: Anthropic
+//! reports `overloaded_error`, Gemini a `SAFETY` block, `OpenAI` a
+//! `context_length_exceeded` code. An application talking to several
+//! providers mostly needs to know which of a few things went wrong — retry
+//! later, shorten the prompt, fix the key. [`LlmError`] names those classes;
+//! each provider crate converts its own error into it with `From` under its
+//! `common` feature.
+
+mod private
+{
+  use core::time::Duration;
+  use error_tools::dependency::thiserror;
+
+  /// Class of a failed provider call.
+  ///
+  /// Built from a provider error with `LlmError::from( error )` (provider
+  /// crates, `common` feature), from an untyped error with
+  /// [`from_untyped`](LlmError::from_untyped), or from a raw response with
+  /// [`classify`](LlmError::classify).
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  #[ non_exhaustive ]
+  pub enum LlmError
+  {
+    /// The API key or credentials were missing, malformed, or rejected.
+    #[ error( "Authentication failed : {0}" ) ]
+    Auth( String ),
+
+    /// The provider refused the request because of a rate limit or quota.
+    #[ error( "Rate limited : {message}" ) ]
+    RateLimited
+    {
+      /// How long the provider asked the caller to wait, when it said.
+      retry_after : Option< Duration >,
+      /// Provider message.
+      message : String,
+    },
+
+    /// A safety or moderation filter blocked the prompt or the answer.
+    #[ error( "Content filtered : {0}" ) ]
+    ContentFiltered( String ),
+
+    /// The prompt (plus requested output) exceeds the model's context window.
+    #[ error( "Context too long : {0}" ) ]
+    ContextTooLong( String ),
+
+    /// The provider is overloaded or temporarily unavailable.
+    #[ error( "Provider overloaded : {0}" ) ]
+    Overloaded( String ),
+
+    /// The request did not reach the provider or the answer did not come
+    /// back (connection, DNS, timeout).
+    #[ error( "Network error : {0}" ) ]
+    Network( String ),
+
+    /// The provider answered with something the client could not understand
+    /// (malformed JSON, broken stream).
+    #[ error( "Protocol error : {0}" ) ]
+    Protocol( String ),
+
+    /// Any other failure, with the provider's own description.
+    #[ error( "Provider error : {raw}" ) ]
+    Provider
+    {
+      /// The provider error as displayed by its crate.
+      raw : String,
+    },
+  }
+
+  impl LlmError
+  {
+    /// Classifies a failed response from its HTTP status (when known) and
+    /// its error message.
+    ///
+    /// The message wins over the status for context-length and
+    /// content-filter errors, which most providers report as a plain 400.
+    /// Without a status, a status written into the message (`"HTTP 429"`,
+    /// `"API error 503"`) is used.
+    #[ inline ]
+    #[ must_use ]
+    pub fn classify( status : Option< u16 >, message : &str ) -> Self
+    {
+      let text = message.to_ascii_lowercase();
+      let status = status.or_else( || status_in( &text ) );
+      let message = message.to_string();
+
+      if status == Some( 413 ) || CONTEXT_TOO_LONG.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::ContextTooLong( message );
+      }
+      if CONTENT_FILTERED.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::ContentFiltered( message );
+      }
+      match status
+      {
+        Some( 401 | 403 ) => return Self::Auth( message ),
+        Some( 429 ) => return Self::RateLimited { retry_after : None, message },
+        Some( 502..=504 | 529 ) => return Self::Overloaded( message ),
+        _ => {}
+      }
+      if RATE_LIMITED.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::RateLimited { retry_after : None, message };
+      }
+      if OVERLOADED.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::Overloaded( message );
+      }
+      if AUTH.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::Auth( message );
+      }
+      if NETWORK.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::Network( message );
+      }
+      if PROTOCOL.iter().any( | phrase | text.contains( phrase ) )
+      {
+        return Self::Protocol( message );
+      }
+      Self::Provider { raw : message }
+    }
+
+    /// Classifies an untyped error.
+    ///
+    /// An `LlmError` inside `error` is returned as is, and a
+    /// `GuardrailViolation` (`guardrail` feature) is `ContentFiltered`, and a
+    /// `SchemaError` (`schema` feature) is `Protocol`; anything else is classified from its message with
+    /// [`classify`](Self::classify).
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_untyped( error : &error_tools::untyped::Error ) -> Self
+    {
+      if let Some( inner ) = error.downcast_ref::< Self >()
+      {
+        return inner.clone();
+      }
+      #[ cfg( feature = "guardrail" ) ]
+      if let Some( violation ) = error.downcast_ref::< crate::guardrail::GuardrailViolation >()
+      {
+        return Self::from( violation.clone() );
+      }
+      #[ cfg( feature = "schema" ) ]
+      if let Some( schema_error ) = error.downcast_ref::< crate::schema::SchemaError >()
+      {
+        return Self::from( schema_error.clone() );
+      }
+      Self::classify( None, &format!( "{error:#}" ) )
+    }
+
+    /// Whether the same request may succeed if sent again later, unchanged.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_retryable( &self ) -> bool
+    {
+      matches!( self, Self::RateLimited { .. } | Self::Overloaded( _ ) | Self::Network( _ ) )
+    }
+
+    /// How long the provider asked the caller to wait, when it said.
+    #[ inline ]
+    #[ must_use ]
+    pub fn retry_after( &self ) -> Option< Duration >
+    {
+      match self
+      {
+        Self::RateLimited { retry_after, .. } => *retry_after,
+        _ => None,
+      }
+    }
+  }
+
+  /// A guardrail denial is the client's own content filter.
+  #[ cfg( feature = "guardrail" ) ]
+  impl From< crate::guardrail::GuardrailViolation > for LlmError
+  {
+    #[ inline ]
+    fn from( violation : crate::guardrail::GuardrailViolation ) -> Self
+    {
+      Self::ContentFiltered( violation.to_string() )
+    }
+  }
+
+  /// Structured output that fails its schema is an answer the client cannot use.
+  #[ cfg( feature = "schema" ) ]
+  impl From< crate::schema::SchemaError > for LlmError
+  {
+    #[ inline ]
+    fn from( error : crate::schema::SchemaError ) -> Self
+    {
+      Self::Protocol( error.to_string() )
+    }
+  }
+
+  const CONTEXT_TOO_LONG : &[ &str ] =
+  &[
+    "context_length_exceeded",
+    "context length",
+    "context window",
+    "maximum context",
+    "prompt is too long",
+    "input is too long",
+    "too many tokens",
+    "exceeds the maximum number of tokens",
+    "request_too_large",
+  ];
+
+  const CONTENT_FILTERED : &[ &str ] =
+  &[
+    "content_filter",
+    "content filter",
+    "content_policy",
+    "content policy",
+    "content management policy",
+    "blocked due to safety",
+    "safety settings",
+    "safety filter",
+    "blockreason",
+    "prohibited_content",
+  ];
+
+  const RATE_LIMITED : &[ &str ] =
+  &[
+    "rate limit",
+    "rate_limit",
+    "ratelimit",
+    "too many requests",
+    "quota exceeded",
+    "insufficient_quota",
+    "resource_exhausted",
+  ];
+
+  const OVERLOADED : &[ &str ] =
+  &[
+    "overloaded",
+    "service unavailable",
+    "service_unavailable",
+    "temporarily unavailable",
+    "bad gateway",
+    "gateway timeout",
+  ];
+
+  const AUTH : &[ &str ] =
+  &[
+    "unauthorized",
+    "unauthenticated",
+    "authentication",
+    "invalid api key",
+    "invalid_api_key",
+    "permission denied",
+    "permission_denied",
+    "permission_error",
+  ];
+
+  const NETWORK : &[ &str ] =
+  &[
+    "network error",
+    "connection error",
+    "connection refused",
+    "connection reset",
+    "error sending request",
+    "dns error",
+    "timed out",
+  ];
+
+  const PROTOCOL : &[ &str ] =
+  &[
+    "json error",
+    "deserialization error",
+    "failed to deserialize",
+    "failed to parse",
+    "invalid json",
+    "while parsing",
+  ];
+
+  /// An error status written into a message: a 4xx or 5xx number right
+  /// after `http`, `status`, `error`, or `code`.
+  fn status_in( text : &str ) -> Option< u16 >
+  {
+    let words : Vec< &str > = text
+    .split( | c : char | !c.is_ascii_alphanumeric() )
+    .filter( | word | !word.is_empty() )
+    .collect();
+    words.windows( 2 ).find_map( | pair |
+    {
+      if !matches!( pair[ 0 ], "http" | "status" | "error" | "code" ) || pair[ 1 ].len() != 3
+      {
+        return None;
+      }
+      pair[ 1 ].parse::< u16 >().ok().filter( | status | ( 400..=599 ).contains( status ) )
+    })
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    LlmError,
+  };
+}
@@ -0,0 +1,107 @@
+//! Provider-neutral chat message and role types.
+//!
+//! [`NeutralMessage`] is the exchange format between this crate and the
+//! provider crates: prompts render into it, and each provider crate converts
+//! it into its own wire message type behind its `common` feature.
+
+mod private
+{
+  use serde::{ Serialize, Deserialize };
+
+  /// Role of a participant in a provider-neutral conversation.
+  ///
+  /// Only the roles every supported provider understands are modelled.
+  /// Tool-result messages stay provider-specific.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum NeutralRole
+  {
+    /// System-level instructions provided before the conversation begins.
+    System,
+
+    /// Message from the human user.
+    User,
+
+    /// Response generated by the assistant.
+    Assistant,
+  }
+
+  impl NeutralRole
+  {
+    /// Returns the lowercase wire name shared by OpenAI-style APIs.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::System    => "system",
+        Self::User      => "user",
+        Self::Assistant => "assistant",
+      }
+    }
+  }
+
+  /// A single text message in a provider-neutral conversation.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_common::{ NeutralMessage, NeutralRole };
+  ///
+  /// let msg = NeutralMessage::user( "What is 2 + 2?" );
+  /// assert_eq!( msg.role, NeutralRole::User );
+  /// # }
+  /// ```
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct NeutralMessage
+  {
+    /// Who sent this message.
+    pub role : NeutralRole,
+
+    /// Text content of the message.
+    pub content : String,
+  }
+
+  impl NeutralMessage
+  {
+    /// Creates a message with an explicit role.
+    #[ inline ]
+    pub fn new( role : NeutralRole, content : impl Into< String > ) -> Self
+    {
+      Self { role, content : content.into() }
+    }
+
+    /// Creates a system-role message.
+    #[ inline ]
+    pub fn system( content : impl Into< String > ) -> Self
+    {
+      Self::new( NeutralRole::System, content )
+    }
+
+    /// Creates a user-role message.
+    #[ inline ]
+    pub fn user( content : impl Into< String > ) -> Self
+    {
+      Self::new( NeutralRole::User, content )
+    }
+
+    /// Creates an assistant-role message.
+    #[ inline ]
+    pub fn assistant( content : impl Into< String > ) -> Self
+    {
+      Self::new( NeutralRole::Assistant, content )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    NeutralRole,
+    NeutralMessage,
+  };
+}
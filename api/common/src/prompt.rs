@@ -0,0 +1,491 @@
+//! Prompt templates, partials, and few-shot example blocks.
+//!
+//! A [`PromptTemplate`] is plain text with `{{variable}}` placeholders and
+//! `{{> partial}}` includes. Templates are rendered explicitly against a
+//! [`PromptContext`]; nothing is rendered implicitly or cached.
+//!
+//! A [`ChatPrompt`] combines an optional system template, few-shot
+//! [`FewShotExample`] blocks, and a user template into an ordered list of
+//! [`NeutralMessage`]s, which every provider crate converts into its own wire
+//! message type (`From< NeutralMessage >` behind the provider's `common`
+//! feature).
+//!
+//! Variable names can be checked at compile time with
+//! `#[ derive( PromptVariables ) ]` plus a `#[ prompt( template = "…" ) ]`
+//! attribute: the derive rejects any placeholder that is not a field of the
+//! struct.
+
+mod private
+{
+  use crate::error::{ CommonError, Result };
+  use crate::message::NeutralMessage;
+  use std::collections::HashMap;
+
+  /// A value that supplies named variables to a template.
+  ///
+  /// Usually implemented with `#[ derive( PromptVariables ) ]`, which maps
+  /// every named field to a variable rendered through `Display`.
+  pub trait PromptVariables
+  {
+    /// Names of every variable this type supplies, in declaration order.
+    const NAMES : &'static [ &'static str ];
+
+    /// Returns each variable name paired with its rendered value.
+    fn values( &self ) -> Vec< ( &'static str, String ) >;
+  }
+
+  /// A [`PromptVariables`] type bound to one template at compile time.
+  ///
+  /// Generated by `#[ derive( PromptVariables ) ]` when the struct carries a
+  /// `#[ prompt( template = "…" ) ]` attribute. The derive guarantees every
+  /// `{{variable}}` placeholder in `TEMPLATE` names a field of the struct.
+  /// Partials are resolved at render time and are not checked.
+  pub trait TypedPrompt : PromptVariables
+  {
+    /// Template source validated against the struct fields.
+    const TEMPLATE : &'static str;
+
+    /// Renders [`TEMPLATE`](Self::TEMPLATE) with this value's variables.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommonError::MissingPartial`] if the template includes a
+    /// partial; use [`PromptTemplate::render`] with a populated
+    /// [`PromptContext`] for templates that need partials.
+    #[ inline ]
+    fn render( &self ) -> Result< String >
+    {
+      let template = PromptTemplate::parse( Self::TEMPLATE )?;
+      template.render( &PromptContext::new().with_variables( self ) )
+    }
+  }
+
+  /// One parsed piece of a template.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  enum Segment
+  {
+    /// Literal text copied verbatim.
+    Text( String ),
+    /// `{{name}}` placeholder.
+    Variable( String ),
+    /// `{{> name}}` include.
+    Partial( String ),
+  }
+
+  /// A parsed prompt template.
+  ///
+  /// Syntax:
+  ///
+  /// - `{{name}}` — substituted with the variable `name`
+  /// - `{{> name}}` — replaced by the rendered partial `name`
+  ///
+  /// Names consist of ASCII letters, digits, and `_`, and must not start
+  /// with a digit. Whitespace inside the braces is ignored. Single braces are
+  /// literal text, so JSON snippets need no escaping.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "prompt" ) ]
+  /// # {
+  /// use api_common::{ PromptTemplate, PromptContext };
+  ///
+  /// let template = PromptTemplate::parse( "Translate {{text}} into {{language}}." ).unwrap();
+  /// let context  = PromptContext::new()
+  ///   .with_variable( "text", "hello" )
+  ///   .with_variable( "language", "French" );
+  /// assert_eq!( template.render( &context ).unwrap(), "Translate hello into French." );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct PromptTemplate
+  {
+    /// Original template source.
+    source : String,
+    /// Parsed segments in source order.
+    segments : Vec< Segment >,
+  }
+
+  impl PromptTemplate
+  {
+    /// Maximum nesting depth for partial expansion.
+    ///
+    /// Exceeding it almost always means two partials include each other.
+    pub const MAX_PARTIAL_DEPTH : usize = 16;
+
+    /// Parses template source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommonError::TemplateSyntax`] for an unclosed `{{` or an
+    /// invalid placeholder name.
+    #[ inline ]
+    pub fn parse( source : impl Into< String > ) -> Result< Self >
+    {
+      let source = source.into();
+      let segments = parse_segments( &source )?;
+      Ok( Self { source, segments } )
+    }
+
+    /// Returns the original template source.
+    #[ inline ]
+    #[ must_use ]
+    pub fn source( &self ) -> &str
+    {
+      &self.source
+    }
+
+    /// Returns the distinct variable names referenced directly by this
+    /// template, in order of first appearance (partials are not expanded).
+    #[ inline ]
+    #[ must_use ]
+    pub fn variables( &self ) -> Vec< &str >
+    {
+      let mut names : Vec< &str > = Vec::new();
+      for segment in &self.segments
+      {
+        if let Segment::Variable( name ) = segment
+        {
+          if !names.contains( &name.as_str() )
+          {
+            names.push( name );
+          }
+        }
+      }
+      names
+    }
+
+    /// Returns the distinct partial names included directly by this template.
+    #[ inline ]
+    #[ must_use ]
+    pub fn partials( &self ) -> Vec< &str >
+    {
+      let mut names : Vec< &str > = Vec::new();
+      for segment in &self.segments
+      {
+        if let Segment::Partial( name ) = segment
+        {
+          if !names.contains( &name.as_str() )
+          {
+            names.push( name );
+          }
+        }
+      }
+      names
+    }
+
+    /// Renders the template against `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommonError::MissingVariable`] or
+    /// [`CommonError::MissingPartial`] for unresolved names, and
+    /// [`CommonError::PartialDepthExceeded`] when partials nest deeper than
+    /// [`MAX_PARTIAL_DEPTH`](Self::MAX_PARTIAL_DEPTH).
+    #[ inline ]
+    pub fn render( &self, context : &PromptContext ) -> Result< String >
+    {
+      let mut out = String::with_capacity( self.source.len() );
+      self.render_into( &mut out, context, 0 )?;
+      Ok( out )
+    }
+
+    fn render_into( &self, out : &mut String, context : &PromptContext, depth : usize ) -> Result< () >
+    {
+      for segment in &self.segments
+      {
+        match segment
+        {
+          Segment::Text( text ) => out.push_str( text ),
+          Segment::Variable( name ) =>
+          {
+            let value = context.variables.get( name )
+              .ok_or_else( || CommonError::MissingVariable( name.clone() ) )?;
+            out.push_str( value );
+          }
+          Segment::Partial( name ) =>
+          {
+            if depth >= Self::MAX_PARTIAL_DEPTH
+            {
+              return Err( CommonError::PartialDepthExceeded( name.clone() ).into() );
+            }
+            let partial = context.partials.get( name )
+              .ok_or_else( || CommonError::MissingPartial( name.clone() ) )?;
+            partial.render_into( out, context, depth + 1 )?;
+          }
+        }
+      }
+      Ok( () )
+    }
+  }
+
+  /// Returns `true` if `name` is a valid placeholder name.
+  fn is_valid_name( name : &str ) -> bool
+  {
+    let mut chars = name.chars();
+    match chars.next()
+    {
+      Some( first ) if first.is_ascii_alphabetic() || first == '_' =>
+        chars.all( | c | c.is_ascii_alphanumeric() || c == '_' ),
+      _ => false,
+    }
+  }
+
+  /// Splits template source into text, variable, and partial segments.
+  fn parse_segments( source : &str ) -> core::result::Result< Vec< Segment >, CommonError >
+  {
+    let mut segments = Vec::new();
+    let mut rest = source;
+    let mut offset = 0;
+
+    while let Some( start ) = rest.find( "{{" )
+    {
+      if start > 0
+      {
+        segments.push( Segment::Text( rest[ ..start ].to_owned() ) );
+      }
+      let after = &rest[ start + 2.. ];
+      let Some( end ) = after.find( "}}" ) else
+      {
+        return Err( CommonError::TemplateSyntax
+        {
+          position : offset + start,
+          reason : "unclosed `{{`".to_owned(),
+        });
+      };
+
+      let inner = after[ ..end ].trim();
+      let ( is_partial, name ) = match inner.strip_prefix( '>' )
+      {
+        Some( name ) => ( true, name.trim() ),
+        None => ( false, inner ),
+      };
+      if !is_valid_name( name )
+      {
+        return Err( CommonError::TemplateSyntax
+        {
+          position : offset + start,
+          reason : format!( "invalid placeholder name `{name}`" ),
+        });
+      }
+      segments.push
+      (
+        if is_partial { Segment::Partial( name.to_owned() ) } else { Segment::Variable( name.to_owned() ) }
+      );
+
+      let consumed = start + 2 + end + 2;
+      rest = &rest[ consumed.. ];
+      offset += consumed;
+    }
+
+    if !rest.is_empty()
+    {
+      segments.push( Segment::Text( rest.to_owned() ) );
+    }
+    Ok( segments )
+  }
+
+  /// Variables and partials available while rendering a template.
+  ///
+  /// Built explicitly by the caller; later values for the same name replace
+  /// earlier ones.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct PromptContext
+  {
+    /// Variable values keyed by name.
+    variables : HashMap< String, String >,
+    /// Partial templates keyed by name.
+    partials : HashMap< String, PromptTemplate >,
+  }
+
+  impl PromptContext
+  {
+    /// Creates an empty context.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Sets one variable.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_variable( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.variables.insert( name.into(), value.into() );
+      self
+    }
+
+    /// Sets every variable supplied by `variables`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_variables< V >( mut self, variables : &V ) -> Self
+    where
+      V : PromptVariables + ?Sized,
+    {
+      for ( name, value ) in variables.values()
+      {
+        self.variables.insert( name.to_owned(), value );
+      }
+      self
+    }
+
+    /// Registers a partial under `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_partial( mut self, name : impl Into< String >, partial : PromptTemplate ) -> Self
+    {
+      self.partials.insert( name.into(), partial );
+      self
+    }
+  }
+
+  /// One input/output demonstration pair for few-shot prompting.
+  ///
+  /// Rendered literally (no placeholder substitution) as a user message
+  /// followed by an assistant message.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct FewShotExample
+  {
+    /// Example user input.
+    pub input : String,
+
+    /// Expected assistant output for `input`.
+    pub output : String,
+  }
+
+  impl FewShotExample
+  {
+    /// Creates an example pair.
+    #[ inline ]
+    pub fn new( input : impl Into< String >, output : impl Into< String > ) -> Self
+    {
+      Self { input : input.into(), output : output.into() }
+    }
+  }
+
+  /// A complete chat prompt: optional system template, few-shot examples,
+  /// and a user template.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "prompt" ) ]
+  /// # {
+  /// use api_common::{ ChatPrompt, FewShotExample, PromptTemplate, PromptContext, NeutralRole };
+  ///
+  /// let prompt = ChatPrompt::new( PromptTemplate::parse( "Classify: {{text}}" ).unwrap() )
+  ///   .with_system( PromptTemplate::parse( "You are a sentiment classifier." ).unwrap() )
+  ///   .with_example( FewShotExample::new( "Classify: great!", "positive" ) );
+  ///
+  /// let messages = prompt.render( &PromptContext::new().with_variable( "text", "awful" ) ).unwrap();
+  /// assert_eq!( messages.len(), 4 );
+  /// assert_eq!( messages[ 3 ].role, NeutralRole::User );
+  /// assert_eq!( messages[ 3 ].content, "Classify: awful" );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct ChatPrompt
+  {
+    /// Optional system instructions.
+    system : Option< PromptTemplate >,
+    /// Few-shot demonstrations placed between system and user messages.
+    examples : Vec< FewShotExample >,
+    /// Final user message.
+    user : PromptTemplate,
+  }
+
+  impl ChatPrompt
+  {
+    /// Creates a prompt consisting of a single user template.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( user : PromptTemplate ) -> Self
+    {
+      Self { system : None, examples : Vec::new(), user }
+    }
+
+    /// Sets the system template.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_system( mut self, system : PromptTemplate ) -> Self
+    {
+      self.system = Some( system );
+      self
+    }
+
+    /// Appends one few-shot example.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_example( mut self, example : FewShotExample ) -> Self
+    {
+      self.examples.push( example );
+      self
+    }
+
+    /// Appends several few-shot examples, preserving order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_examples( mut self, examples : impl IntoIterator< Item = FewShotExample > ) -> Self
+    {
+      self.examples.extend( examples );
+      self
+    }
+
+    /// Renders the prompt into provider-neutral messages.
+    ///
+    /// Order: system (if set), each example as user + assistant, then user.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error from [`PromptTemplate::render`].
+    #[ inline ]
+    pub fn render( &self, context : &PromptContext ) -> Result< Vec< NeutralMessage > >
+    {
+      let mut messages = Vec::with_capacity( 2 + self.examples.len() * 2 );
+      if let Some( system ) = &self.system
+      {
+        messages.push( NeutralMessage::system( system.render( context )? ) );
+      }
+      for example in &self.examples
+      {
+        messages.push( NeutralMessage::user( example.input.clone() ) );
+        messages.push( NeutralMessage::assistant( example.output.clone() ) );
+      }
+      messages.push( NeutralMessage::user( self.user.render( context )? ) );
+      Ok( messages )
+    }
+
+    /// Renders the prompt directly into a provider's message type.
+    ///
+    /// Works with any message type implementing `From< NeutralMessage >`,
+    /// which every provider crate provides behind its `common` feature.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error from [`PromptTemplate::render`].
+    #[ inline ]
+    pub fn render_into< M >( &self, context : &PromptContext ) -> Result< Vec< M > >
+    where
+      M : From< NeutralMessage >,
+    {
+      Ok( self.render( context )?.into_iter().map( M::from ).collect() )
+    }
+  }
+
+  pub use api_common_derive::PromptVariables;
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    PromptVariables,
+    TypedPrompt,
+    PromptTemplate,
+    PromptContext,
+    FewShotExample,
+    ChatPrompt,
+  };
+}
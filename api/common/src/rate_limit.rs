@@ -0,0 +1,379 @@
+//! Rate limiter shared by every client in the process.
+//!
+//! Provider quotas are per account, not per client. Two `api_openai` clients
+//! with independent limiters each admit the full quota and together exceed
+//! it. A [`SharedRateLimiter`] is a handle to one pair of token buckets —
+//! requests and tokens — and every clone draws from the same buckets.
+//! [`SharedRateLimiter::named`] hands out the same buckets to callers that
+//! only share a name, such as clients built in unrelated modules.
+//!
+//! Requests are paid before sending. Tokens are usually unknown until the
+//! response arrives, so they can also be paid afterwards with
+//! [`SharedRateLimiter::record_tokens`]: the bucket may go into deficit, and
+//! later acquisitions wait until it refills.
+
+mod private
+{
+  use crate::error::{ CommonError, Result };
+  use crate::async_runtime::sleep;
+  use core::time::Duration;
+  use error_tools::dependency::thiserror;
+  use std::collections::HashMap;
+  use std::sync::{ Arc, Mutex, MutexGuard, OnceLock, PoisonError };
+  use web_time::Instant;
+
+  /// `count` units per `period`.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct Quota
+  {
+    /// Units admitted per period; also the burst size.
+    pub count : u64,
+    /// Time over which the bucket refills completely.
+    pub period : Duration,
+  }
+
+  /// Request and token quotas for a [`SharedRateLimiter`].
+  ///
+  /// A missing quota is not limited.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  pub struct RateLimits
+  {
+    /// Request quota.
+    pub requests : Option< Quota >,
+    /// Token quota (prompt plus completion tokens, as providers count them).
+    pub tokens : Option< Quota >,
+  }
+
+  impl RateLimits
+  {
+    /// No limits.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// At most `count` requests per `period`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_requests( mut self, count : u64, period : Duration ) -> Self
+    {
+      self.requests = Some( Quota { count, period } );
+      self
+    }
+
+    /// At most `count` tokens per `period`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tokens( mut self, count : u64, period : Duration ) -> Self
+    {
+      self.tokens = Some( Quota { count, period } );
+      self
+    }
+
+    /// At most `count` requests per minute (the providers' RPM).
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_requests_per_minute( self, count : u64 ) -> Self
+    {
+      self.with_requests( count, Duration::from_secs( 60 ) )
+    }
+
+    /// At most `count` tokens per minute (the providers' TPM).
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tokens_per_minute( self, count : u64 ) -> Self
+    {
+      self.with_tokens( count, Duration::from_secs( 60 ) )
+    }
+  }
+
+  /// [`SharedRateLimiter::try_acquire`] found a bucket short.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, thiserror::Error ) ]
+  #[ error( "Rate limit reached; retry in {retry_after:?}" ) ]
+  pub struct RateLimited
+  {
+    /// Time until both buckets hold enough, assuming no other caller draws.
+    pub retry_after : Duration,
+  }
+
+  /// Token bucket. The level is kept in units × period nanoseconds so
+  /// refilling is exact integer arithmetic.
+  #[ derive( Debug ) ]
+  struct Bucket
+  {
+    quota : Quota,
+    level : i128,
+    updated : Instant,
+  }
+
+  impl Bucket
+  {
+    fn new( quota : Quota, now : Instant ) -> Self
+    {
+      let quota = Quota { period : quota.period.max( Duration::from_nanos( 1 ) ), ..quota };
+      let mut bucket = Self { quota, level : 0, updated : now };
+      bucket.level = bucket.scaled( quota.count );
+      bucket
+    }
+
+    fn period_nanos( &self ) -> i128
+    {
+      i128::try_from( self.quota.period.as_nanos() ).unwrap_or( i128::MAX )
+    }
+
+    fn scaled( &self, units : u64 ) -> i128
+    {
+      i128::from( units ).saturating_mul( self.period_nanos() )
+    }
+
+    fn refill( &mut self, now : Instant )
+    {
+      let elapsed = i128::try_from( now.saturating_duration_since( self.updated ).as_nanos() ).unwrap_or( i128::MAX );
+      let added = elapsed.saturating_mul( i128::from( self.quota.count ) );
+      self.level = self.level.saturating_add( added ).min( self.scaled( self.quota.count ) );
+      self.updated = now;
+    }
+
+    /// Time until the bucket holds `units`; zero when it already does.
+    fn wait_for( &self, units : u64 ) -> Duration
+    {
+      let missing = self.scaled( units ) - self.level;
+      if missing <= 0
+      {
+        return Duration::ZERO;
+      }
+      let rate = i128::from( self.quota.count.max( 1 ) );
+      let nanos = ( missing + rate - 1 ) / rate;
+      Duration::from_nanos( u64::try_from( nanos ).unwrap_or( u64::MAX ) )
+    }
+
+    fn available( &self ) -> i64
+    {
+      i64::try_from( self.level.div_euclid( self.period_nanos() ) ).unwrap_or( i64::MAX )
+    }
+  }
+
+  #[ derive( Debug ) ]
+  struct Buckets
+  {
+    requests : Option< Bucket >,
+    tokens : Option< Bucket >,
+  }
+
+  impl Buckets
+  {
+    fn refill( &mut self, now : Instant )
+    {
+      self.requests.iter_mut().chain( self.tokens.iter_mut() ).for_each( | bucket | bucket.refill( now ) );
+    }
+  }
+
+  /// Handle to request and token buckets shared by every clone.
+  ///
+  /// Cloning is cheap; hand one clone to each client that spends the same
+  /// account quota.
+  ///
+  /// ```rust,ignore
+  /// use api_common::{ RateLimits, SharedRateLimiter };
+  ///
+  /// let limiter = SharedRateLimiter::named( "openai-prod", RateLimits::new().with_requests_per_minute( 500 ).with_tokens_per_minute( 200_000 ) );
+  /// let chat = Client::build( env.clone() )?.with_shared_rate_limiter( limiter.clone() );
+  /// let embed = Client::build( env )?.with_shared_rate_limiter( limiter );
+  /// ```
+  #[ derive( Debug, Clone ) ]
+  pub struct SharedRateLimiter
+  {
+    limits : RateLimits,
+    buckets : Arc< Mutex< Buckets > >,
+  }
+
+  fn registry() -> MutexGuard< 'static, HashMap< String, SharedRateLimiter > >
+  {
+    static REGISTRY : OnceLock< Mutex< HashMap< String, SharedRateLimiter > > > = OnceLock::new();
+    REGISTRY.get_or_init( Mutex::default ).lock().unwrap_or_else( PoisonError::into_inner )
+  }
+
+  impl SharedRateLimiter
+  {
+    /// New buckets, full, limited by `limits`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( limits : RateLimits ) -> Self
+    {
+      let now = Instant::now();
+      let buckets = Buckets
+      {
+        requests : limits.requests.map( | quota | Bucket::new( quota, now ) ),
+        tokens : limits.tokens.map( | quota | Bucket::new( quota, now ) ),
+      };
+      Self { limits, buckets : Arc::new( Mutex::new( buckets ) ) }
+    }
+
+    /// The process-wide limiter registered as `name`, created with `limits`
+    /// on first use.
+    ///
+    /// Later calls return the existing buckets and ignore `limits`; compare
+    /// with [`limits`](Self::limits) when a mismatch matters.
+    #[ inline ]
+    #[ must_use ]
+    pub fn named( name : &str, limits : RateLimits ) -> Self
+    {
+      registry().entry( name.to_string() ).or_insert_with( || Self::new( limits ) ).clone()
+    }
+
+    /// The process-wide limiter registered as `name`, if any.
+    #[ inline ]
+    #[ must_use ]
+    pub fn lookup( name : &str ) -> Option< Self >
+    {
+      registry().get( name ).cloned()
+    }
+
+    /// Limits the buckets were created with.
+    #[ inline ]
+    #[ must_use ]
+    pub fn limits( &self ) -> RateLimits
+    {
+      self.limits
+    }
+
+    /// `true` when `self` and `other` draw from the same buckets.
+    #[ inline ]
+    #[ must_use ]
+    pub fn shares_buckets_with( &self, other : &Self ) -> bool
+    {
+      Arc::ptr_eq( &self.buckets, &other.buckets )
+    }
+
+    /// Takes one request and `tokens` tokens if both buckets hold enough;
+    /// otherwise takes nothing.
+    ///
+    /// With a token quota, `tokens` of zero still waits out a deficit left
+    /// by [`record_tokens`](Self::record_tokens).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RateLimited`] with the time until both buckets would hold
+    /// enough.
+    #[ inline ]
+    pub fn try_acquire( &self, tokens : u64 ) -> core::result::Result< (), RateLimited >
+    {
+      let mut buckets = self.lock();
+      buckets.refill( Instant::now() );
+
+      let retry_after = buckets.requests.as_ref().map_or( Duration::ZERO, | bucket | bucket.wait_for( 1 ) )
+        .max( buckets.tokens.as_ref().map_or( Duration::ZERO, | bucket | bucket.wait_for( tokens ) ) );
+      if !retry_after.is_zero()
+      {
+        return Err( RateLimited { retry_after } );
+      }
+
+      if let Some( bucket ) = buckets.requests.as_mut()
+      {
+        bucket.level -= bucket.scaled( 1 );
+      }
+      if let Some( bucket ) = buckets.tokens.as_mut()
+      {
+        bucket.level -= bucket.scaled( tokens );
+      }
+      Ok( () )
+    }
+
+    /// Waits until one request and `tokens` tokens are available, then takes
+    /// them.
+    ///
+    /// Waiters are not queued; each retries after the reported delay, so
+    /// under contention a waiter may be overtaken.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommonError::InvalidArgument`] when the request can never be
+    /// admitted: `tokens` exceeds the token quota, or a quota is zero.
+    #[ inline ]
+    pub async fn acquire( &self, tokens : u64 ) -> Result< () >
+    {
+      if self.limits.requests.is_some_and( | quota | quota.count == 0 )
+      {
+        return Err( CommonError::InvalidArgument( "request quota is zero".to_string() ).into() );
+      }
+      if let Some( quota ) = self.limits.tokens.filter( | quota | tokens > quota.count || quota.count == 0 )
+      {
+        return Err( CommonError::InvalidArgument( format!( "{tokens} tokens exceed the quota of {} per {:?}", quota.count, quota.period ) ).into() );
+      }
+
+      loop
+      {
+        match self.try_acquire( tokens )
+        {
+          Ok( () ) => return Ok( () ),
+          Err( RateLimited { retry_after } ) => sleep( retry_after ).await,
+        }
+      }
+    }
+
+    /// Charges `tokens` after the fact, e.g. the usage reported in a
+    /// response. The bucket may go into deficit.
+    #[ inline ]
+    pub fn record_tokens( &self, tokens : u64 )
+    {
+      let mut buckets = self.lock();
+      buckets.refill( Instant::now() );
+      if let Some( bucket ) = buckets.tokens.as_mut()
+      {
+        bucket.level = bucket.level.saturating_sub( bucket.scaled( tokens ) );
+      }
+    }
+
+    /// Returns `tokens` taken by [`acquire`](Self::acquire) but not used,
+    /// e.g. when the reported usage is below the estimate.
+    #[ inline ]
+    pub fn refund_tokens( &self, tokens : u64 )
+    {
+      let mut buckets = self.lock();
+      buckets.refill( Instant::now() );
+      if let Some( bucket ) = buckets.tokens.as_mut()
+      {
+        bucket.level = bucket.level.saturating_add( bucket.scaled( tokens ) ).min( bucket.scaled( bucket.quota.count ) );
+      }
+    }
+
+    /// Whole requests currently available, `None` when unlimited.
+    #[ inline ]
+    #[ must_use ]
+    pub fn available_requests( &self ) -> Option< i64 >
+    {
+      let mut buckets = self.lock();
+      buckets.refill( Instant::now() );
+      buckets.requests.as_ref().map( Bucket::available )
+    }
+
+    /// Whole tokens currently available, negative while in deficit, `None`
+    /// when unlimited.
+    #[ inline ]
+    #[ must_use ]
+    pub fn available_tokens( &self ) -> Option< i64 >
+    {
+      let mut buckets = self.lock();
+      buckets.refill( Instant::now() );
+      buckets.tokens.as_ref().map( Bucket::available )
+    }
+
+    fn lock( &self ) -> MutexGuard< '_, Buckets >
+    {
+      self.buckets.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Quota,
+    RateLimits,
+    RateLimited,
+    SharedRateLimiter,
+  };
+}
@@ -0,0 +1,435 @@
+//! Structured output validation against a JSON schema.
+//!
+//! Structured output modes (`OpenAI` `json_schema` response formats, Gemini
+//! response schemas, tool arguments) ask the model for JSON matching a
+//! schema, but models still return JSON that misses a required field or
+//! picks a value outside an enum, especially without strict decoding. A
+//! [`ResponseSchema`] checks the returned JSON against the same schema the
+//! request carried and reports every mismatch with its path, so the caller
+//! can retry, repair, or reject the answer.
+//!
+//! The keywords checked are the ones structured output schemas use: `type`,
+//! `enum`, `const`, `properties`, `required`, `additionalProperties`,
+//! `minProperties`, `maxProperties`, `items`, `prefixItems`, `minItems`,
+//! `maxItems`, `uniqueItems`, `minLength`, `maxLength`, `minimum`,
+//! `maximum`, `exclusiveMinimum`, `exclusiveMaximum`, `multipleOf`, `anyOf`,
+//! `oneOf`, `allOf`, `not`, local `$ref`s (`#/$defs/...`), and the
+//! `OpenAPI` `nullable` flag. Other keywords, `pattern` and `format`
+//! included, are accepted and not checked.
+
+mod private
+{
+  use core::fmt;
+  use error_tools::dependency::thiserror;
+  use serde::de::DeserializeOwned;
+  use serde_json::{ Map, Value };
+
+  /// Limit on nested subschemas, so a `$ref` cycle fails instead of
+  /// overflowing the stack.
+  const MAX_DEPTH : usize = 256;
+
+  /// One place where a value does not match the schema.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct SchemaViolation
+  {
+    /// JSON Pointer to the offending value (`""` for the root,
+    /// `/items/0/name` for a nested field).
+    pub path : String,
+    /// Schema keyword the value fails.
+    pub keyword : String,
+    /// What is wrong with the value.
+    pub message : String,
+  }
+
+  impl fmt::Display for SchemaViolation
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let path = if self.path.is_empty() { "/" } else { &self.path };
+      write!( f, "{path} : {}", self.message )
+    }
+  }
+
+  /// Structured output that could not be accepted.
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  pub enum SchemaError
+  {
+    /// The output is not JSON.
+    #[ error( "Output is not valid JSON : {0}" ) ]
+    InvalidJson( String ),
+    /// The output is JSON that does not match the schema.
+    #[ error( "Output does not match the schema : {}", summarize( .0 ) ) ]
+    Violations( Vec< SchemaViolation > ),
+    /// The output matches the schema but not the Rust type it was parsed into.
+    #[ error( "Output does not match the target type : {0}" ) ]
+    Deserialize( String ),
+  }
+
+  impl SchemaError
+  {
+    /// Violations found, empty for the other variants.
+    #[ inline ]
+    #[ must_use ]
+    pub fn violations( &self ) -> &[ SchemaViolation ]
+    {
+      match self
+      {
+        Self::Violations( violations ) => violations,
+        Self::InvalidJson( _ ) | Self::Deserialize( _ ) => &[],
+      }
+    }
+  }
+
+  fn summarize( violations : &[ SchemaViolation ] ) -> String
+  {
+    match violations
+    {
+      [] => String::new(),
+      [ only ] => only.to_string(),
+      [ first, rest @ .. ] => format!( "{first} (and {} more)", rest.len() ),
+    }
+  }
+
+  /// A JSON schema that structured output is checked against.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct ResponseSchema
+  {
+    root : Value,
+  }
+
+  impl ResponseSchema
+  {
+    /// Wraps `schema`, usually the one sent with the request.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( schema : Value ) -> Self
+    {
+      Self { root : schema }
+    }
+
+    /// The wrapped schema.
+    #[ inline ]
+    #[ must_use ]
+    pub fn schema( &self ) -> &Value
+    {
+      &self.root
+    }
+
+    /// Checks `instance` against the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError::Violations`] listing every mismatch found.
+    #[ inline ]
+    pub fn validate( &self, instance : &Value ) -> Result< (), SchemaError >
+    {
+      let violations = self.violations( instance );
+      if violations.is_empty() { Ok( () ) } else { Err( SchemaError::Violations( violations ) ) }
+    }
+
+    /// Every mismatch between `instance` and the schema, in document order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn violations( &self, instance : &Value ) -> Vec< SchemaViolation >
+    {
+      let mut out = Vec::new();
+      let mut path = String::new();
+      self.check( &self.root, instance, &mut path, 0, &mut out );
+      out
+    }
+
+    /// Parses model output text, checks it, and deserialises it into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaError::InvalidJson`] when `text` is not JSON,
+    /// [`SchemaError::Violations`] when it does not match the schema, and
+    /// [`SchemaError::Deserialize`] when it matches the schema but not `T`.
+    #[ inline ]
+    pub fn parse< T : DeserializeOwned >( &self, text : &str ) -> Result< T, SchemaError >
+    {
+      let value : Value = serde_json::from_str( text ).map_err( | error | SchemaError::InvalidJson( error.to_string() ) )?;
+      self.validate( &value )?;
+      serde_json::from_value( value ).map_err( | error | SchemaError::Deserialize( error.to_string() ) )
+    }
+
+    fn check( &self, schema : &Value, instance : &Value, path : &mut String, depth : usize, out : &mut Vec< SchemaViolation > )
+    {
+      let schema = match schema
+      {
+        Value::Bool( false ) => return out.push( violation( path, "false", "no value is allowed here".into() ) ),
+        Value::Object( schema ) => schema,
+        // `true` and anything that is not a schema allow every value
+        _ => return,
+      };
+      if depth > MAX_DEPTH
+      {
+        return out.push( violation( path, "$ref", "schema nests too deeply".into() ) );
+      }
+
+      if let Some( reference ) = schema.get( "$ref" ).and_then( Value::as_str )
+      {
+        match self.resolve( reference )
+        {
+          Some( target ) => self.check( target, instance, path, depth + 1, out ),
+          None => out.push( violation( path, "$ref", format!( "cannot resolve {reference}" ) ) ),
+        }
+      }
+
+      if instance.is_null() && schema.get( "nullable" ).and_then( Value::as_bool ) == Some( true )
+      {
+        return;
+      }
+
+      let before = out.len();
+      check_type( schema, instance, path, out );
+      if out.len() > before
+      {
+        // The remaining keywords describe a value of another type
+        return;
+      }
+      check_enum( schema, instance, path, out );
+      match instance
+      {
+        Value::Object( object ) => self.check_object( schema, object, path, depth, out ),
+        Value::Array( items ) => self.check_array( schema, items, path, depth, out ),
+        Value::String( text ) => check_string( schema, text, path, out ),
+        Value::Number( number ) => check_number( schema, number.as_f64().unwrap_or_default(), path, out ),
+        Value::Null | Value::Bool( _ ) => {}
+      }
+      self.check_combinators( schema, instance, path, depth, out );
+    }
+
+    fn resolve( &self, reference : &str ) -> Option< &Value >
+    {
+      let pointer = reference.strip_prefix( '#' )?;
+      self.root.pointer( pointer )
+    }
+
+    fn check_object( &self, schema : &Map< String, Value >, object : &Map< String, Value >, path : &mut String, depth : usize, out : &mut Vec< SchemaViolation > )
+    {
+      for name in schema.get( "required" ).and_then( Value::as_array ).into_iter().flatten().filter_map( Value::as_str )
+      {
+        if !object.contains_key( name )
+        {
+          out.push( violation( path, "required", format!( "missing required property \"{name}\"" ) ) );
+        }
+      }
+      let properties = schema.get( "properties" ).and_then( Value::as_object );
+      let additional = schema.get( "additionalProperties" );
+      for ( name, value ) in object
+      {
+        let len = push_segment( path, name );
+        match properties.and_then( | properties | properties.get( name ) )
+        {
+          Some( property ) => self.check( property, value, path, depth + 1, out ),
+          None => match additional
+          {
+            Some( Value::Bool( false ) ) => out.push( violation( path, "additionalProperties", "property is not allowed".into() ) ),
+            Some( additional ) => self.check( additional, value, path, depth + 1, out ),
+            None => {}
+          },
+        }
+        path.truncate( len );
+      }
+      check_count( schema, "minProperties", "maxProperties", object.len(), "properties", path, out );
+    }
+
+    fn check_array( &self, schema : &Map< String, Value >, items : &[ Value ], path : &mut String, depth : usize, out : &mut Vec< SchemaViolation > )
+    {
+      let prefix = schema.get( "prefixItems" ).and_then( Value::as_array ).map_or( &[][ .. ], Vec::as_slice );
+      for ( index, item ) in items.iter().enumerate()
+      {
+        let item_schema = match prefix.get( index )
+        {
+          Some( item_schema ) => item_schema,
+          None => match schema.get( "items" )
+          {
+            Some( item_schema ) => item_schema,
+            None => continue,
+          },
+        };
+        let len = push_segment( path, &index.to_string() );
+        self.check( item_schema, item, path, depth + 1, out );
+        path.truncate( len );
+      }
+      check_count( schema, "minItems", "maxItems", items.len(), "items", path, out );
+      if schema.get( "uniqueItems" ).and_then( Value::as_bool ) == Some( true )
+      {
+        if let Some( index ) = ( 1..items.len() ).find( | &index | items[ ..index ].contains( &items[ index ] ) )
+        {
+          out.push( violation( path, "uniqueItems", format!( "item {index} repeats an earlier item" ) ) );
+        }
+      }
+    }
+
+    fn check_combinators( &self, schema : &Map< String, Value >, instance : &Value, path : &mut String, depth : usize, out : &mut Vec< SchemaViolation > )
+    {
+      let matches = | subschema : &Value, path : &mut String |
+      {
+        let mut scratch = Vec::new();
+        self.check( subschema, instance, path, depth + 1, &mut scratch );
+        scratch.is_empty()
+      };
+      if let Some( all ) = schema.get( "allOf" ).and_then( Value::as_array )
+      {
+        for subschema in all
+        {
+          self.check( subschema, instance, path, depth + 1, out );
+        }
+      }
+      if let Some( any ) = schema.get( "anyOf" ).and_then( Value::as_array )
+      {
+        if !any.iter().any( | subschema | matches( subschema, path ) )
+        {
+          out.push( violation( path, "anyOf", "value matches none of the allowed schemas".into() ) );
+        }
+      }
+      if let Some( one ) = schema.get( "oneOf" ).and_then( Value::as_array )
+      {
+        let count = one.iter().filter( | subschema | matches( subschema, path ) ).count();
+        if count != 1
+        {
+          out.push( violation( path, "oneOf", format!( "value matches {count} of the schemas instead of exactly one" ) ) );
+        }
+      }
+      if let Some( not ) = schema.get( "not" )
+      {
+        if matches( not, path )
+        {
+          out.push( violation( path, "not", "value matches a disallowed schema".into() ) );
+        }
+      }
+    }
+  }
+
+  fn violation( path : &str, keyword : &str, message : String ) -> SchemaViolation
+  {
+    SchemaViolation { path : path.to_string(), keyword : keyword.to_string(), message }
+  }
+
+  /// Appends a JSON Pointer segment, returning the length to truncate back to.
+  fn push_segment( path : &mut String, segment : &str ) -> usize
+  {
+    let len = path.len();
+    path.push( '/' );
+    path.push_str( &segment.replace( '~', "~0" ).replace( '/', "~1" ) );
+    len
+  }
+
+  fn type_name( instance : &Value ) -> &'static str
+  {
+    match instance
+    {
+      Value::Null => "null",
+      Value::Bool( _ ) => "boolean",
+      Value::Number( number ) if is_integer( number ) => "integer",
+      Value::Number( _ ) => "number",
+      Value::String( _ ) => "string",
+      Value::Array( _ ) => "array",
+      Value::Object( _ ) => "object",
+    }
+  }
+
+  fn is_integer( number : &serde_json::Number ) -> bool
+  {
+    number.is_i64() || number.is_u64() || number.as_f64().is_some_and( | value | value.fract() == 0.0 )
+  }
+
+  fn has_type( instance : &Value, expected : &str ) -> bool
+  {
+    let actual = type_name( instance );
+    actual == expected || ( expected == "number" && actual == "integer" )
+  }
+
+  fn check_type( schema : &Map< String, Value >, instance : &Value, path : &str, out : &mut Vec< SchemaViolation > )
+  {
+    let allowed : Vec< &str > = match schema.get( "type" )
+    {
+      Some( Value::String( expected ) ) => vec![ expected.as_str() ],
+      Some( Value::Array( expected ) ) => expected.iter().filter_map( Value::as_str ).collect(),
+      _ => return,
+    };
+    if !allowed.iter().any( | expected | has_type( instance, expected ) )
+    {
+      let expected = allowed.join( " or " );
+      out.push( violation( path, "type", format!( "expected {expected}, found {}", type_name( instance ) ) ) );
+    }
+  }
+
+  fn check_enum( schema : &Map< String, Value >, instance : &Value, path : &str, out : &mut Vec< SchemaViolation > )
+  {
+    if let Some( allowed ) = schema.get( "enum" ).and_then( Value::as_array )
+    {
+      if !allowed.contains( instance )
+      {
+        out.push( violation( path, "enum", format!( "{instance} is not one of {}", Value::Array( allowed.clone() ) ) ) );
+      }
+    }
+    if let Some( expected ) = schema.get( "const" )
+    {
+      if expected != instance
+      {
+        out.push( violation( path, "const", format!( "expected {expected}, found {instance}" ) ) );
+      }
+    }
+  }
+
+  fn check_string( schema : &Map< String, Value >, text : &str, path : &str, out : &mut Vec< SchemaViolation > )
+  {
+    check_count( schema, "minLength", "maxLength", text.chars().count(), "characters", path, out );
+  }
+
+  fn check_count( schema : &Map< String, Value >, min_key : &str, max_key : &str, count : usize, unit : &str, path : &str, out : &mut Vec< SchemaViolation > )
+  {
+    let count = count as u64;
+    if let Some( min ) = schema.get( min_key ).and_then( Value::as_u64 ).filter( | &min | count < min )
+    {
+      out.push( violation( path, min_key, format!( "has {count} {unit}, fewer than {min}" ) ) );
+    }
+    if let Some( max ) = schema.get( max_key ).and_then( Value::as_u64 ).filter( | &max | count > max )
+    {
+      out.push( violation( path, max_key, format!( "has {count} {unit}, more than {max}" ) ) );
+    }
+  }
+
+  fn check_number( schema : &Map< String, Value >, value : f64, path : &str, out : &mut Vec< SchemaViolation > )
+  {
+    let bound = | key : &str | schema.get( key ).and_then( Value::as_f64 );
+    if let Some( minimum ) = bound( "minimum" ).filter( | &minimum | value < minimum )
+    {
+      out.push( violation( path, "minimum", format!( "{value} is less than {minimum}" ) ) );
+    }
+    if let Some( maximum ) = bound( "maximum" ).filter( | &maximum | value > maximum )
+    {
+      out.push( violation( path, "maximum", format!( "{value} is greater than {maximum}" ) ) );
+    }
+    if let Some( minimum ) = bound( "exclusiveMinimum" ).filter( | &minimum | value <= minimum )
+    {
+      out.push( violation( path, "exclusiveMinimum", format!( "{value} is not greater than {minimum}" ) ) );
+    }
+    if let Some( maximum ) = bound( "exclusiveMaximum" ).filter( | &maximum | value >= maximum )
+    {
+      out.push( violation( path, "exclusiveMaximum", format!( "{value} is not less than {maximum}" ) ) );
+    }
+    if let Some( divisor ) = bound( "multipleOf" ).filter( | &divisor | divisor > 0.0 )
+    {
+      let quotient = value / divisor;
+      if ( quotient - quotient.round() ).abs() > 1e-9
+      {
+        out.push( violation( path, "multipleOf", format!( "{value} is not a multiple of {divisor}" ) ) );
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ResponseSchema,
+    SchemaError,
+    SchemaViolation,
+  };
+}
@@ -0,0 +1,165 @@
+//! Provider-neutral streaming events.
+//!
+//! Every provider streams its own chunk type. Provider crates implement
+//! [`IntoStreamEvents`] for those chunk types behind their `common` feature,
+//! and [`TextStream`] adapts any stream of such chunks into one stream of
+//! [`StreamEvent`]s, so a UI renders output identically for every backend.
+//!
+//! The adapter only translates. It never buffers text across chunks, merges
+//! tool-call fragments, or synthesises a [`StreamEvent::Done`] the provider
+//! did not send.
+
+mod private
+{
+  use crate::error::Result;
+  use core::fmt;
+  use core::pin::Pin;
+  use core::task::{ Context, Poll };
+  use futures_core::Stream;
+  use std::collections::VecDeque;
+
+  /// Incremental fragment of a tool (function) call.
+  ///
+  /// Providers that stream arguments piecewise (`OpenAI`, Anthropic) send the
+  /// identifier and name in the first fragment only and `arguments` in
+  /// pieces; providers that deliver whole calls (Gemini, Ollama) send one
+  /// fragment carrying everything. Concatenate `arguments` per `index` to
+  /// obtain the JSON arguments.
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct ToolCallDelta
+  {
+    /// Position of the tool call within the response.
+    pub index : usize,
+
+    /// Provider call identifier, when present in this fragment.
+    pub id : Option< String >,
+
+    /// Function name, when present in this fragment.
+    pub name : Option< String >,
+
+    /// Fragment of the JSON-encoded arguments (may be empty).
+    pub arguments : String,
+  }
+
+  /// Token usage reported during a stream.
+  ///
+  /// Fields the provider did not report in this chunk are `None`; counts are
+  /// the provider's running totals, not increments.
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, Eq ) ]
+  pub struct UsageUpdate
+  {
+    /// Prompt (input) tokens.
+    pub input_tokens : Option< u64 >,
+
+    /// Generated (output) tokens.
+    pub output_tokens : Option< u64 >,
+  }
+
+  /// One provider-neutral streaming event.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  #[ non_exhaustive ]
+  pub enum StreamEvent
+  {
+    /// Text to append to the running response.
+    TextDelta( String ),
+
+    /// Fragment of a tool call.
+    ToolCallDelta( ToolCallDelta ),
+
+    /// Token usage reported by the provider.
+    UsageUpdate( UsageUpdate ),
+
+    /// The provider signalled the end of the response.
+    Done
+    {
+      /// Provider finish reason (`"stop"`, `"end_turn"`, `"STOP"`, …), if sent.
+      finish_reason : Option< String >,
+    },
+  }
+
+  /// A provider chunk that translates into provider-neutral events.
+  pub trait IntoStreamEvents
+  {
+    /// Translates this chunk into zero or more events, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the chunk itself reports a provider error.
+    fn into_stream_events( self ) -> Result< Vec< StreamEvent > >;
+  }
+
+  /// Adapts a stream of provider chunks into a stream of [`StreamEvent`]s.
+  ///
+  /// Transport errors from the inner stream and errors reported inside
+  /// chunks are passed through as `Err` items; the adapter does not stop on
+  /// its own after an error.
+  pub struct TextStream< S >
+  {
+    inner : Pin< Box< S > >,
+    pending : VecDeque< StreamEvent >,
+  }
+
+  impl< S > TextStream< S >
+  {
+    /// Wraps a provider chunk stream.
+    #[ inline ]
+    pub fn new( inner : S ) -> Self
+    {
+      Self { inner : Box::pin( inner ), pending : VecDeque::new() }
+    }
+  }
+
+  impl< S > fmt::Debug for TextStream< S >
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "TextStream" ).field( "pending", &self.pending ).finish_non_exhaustive()
+    }
+  }
+
+  impl< S, C, E > Stream for TextStream< S >
+  where
+    S : Stream< Item = core::result::Result< C, E > >,
+    C : IntoStreamEvents,
+    E : Into< error_tools::untyped::Error >,
+  {
+    type Item = Result< StreamEvent >;
+
+    #[ inline ]
+    fn poll_next( self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< Option< Self::Item > >
+    {
+      let this = self.get_mut();
+      loop
+      {
+        if let Some( event ) = this.pending.pop_front()
+        {
+          return Poll::Ready( Some( Ok( event ) ) );
+        }
+        match this.inner.as_mut().poll_next( cx )
+        {
+          Poll::Ready( Some( Ok( chunk ) ) ) => match chunk.into_stream_events()
+          {
+            Ok( events ) => this.pending.extend( events ),
+            Err( err ) => return Poll::Ready( Some( Err( err ) ) ),
+          },
+          Poll::Ready( Some( Err( err ) ) ) => return Poll::Ready( Some( Err( err.into() ) ) ),
+          Poll::Ready( None ) => return Poll::Ready( None ),
+          Poll::Pending => return Poll::Pending,
+        }
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ToolCallDelta,
+    UsageUpdate,
+    StreamEvent,
+    IntoStreamEvents,
+    TextStream,
+  };
+}
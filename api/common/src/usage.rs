@@ -0,0 +1,436 @@
+//! Token usage collected across providers and summarised per group.
+//!
+//! Every provider reports the tokens of a call in its own shape: `OpenAI`'s
+//! `prompt_tokens`, Anthropic's `input_tokens`, Gemini's
+//! `promptTokenCount`. Each provider crate converts its usage type into a
+//! [`TokenUsage`] through its `common` feature; a [`UsageReport`] collects
+//! those with the provider, model, and an optional caller tag, and sums them
+//! per group over a time window for export as JSON or CSV.
+//!
+//! The report only aggregates what it is given. It sends nothing, prices
+//! nothing, and keeps records until the caller prunes them.
+
+mod private
+{
+  use core::time::Duration;
+  use std::collections::BTreeMap;
+  use web_time::{ SystemTime, UNIX_EPOCH };
+
+  /// Token counts of one call in provider-neutral form.
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, Eq, Hash ) ]
+  pub struct TokenUsage
+  {
+    /// Prompt (input) tokens, cached ones included.
+    pub input_tokens : u64,
+    /// Generated (output) tokens.
+    pub output_tokens : u64,
+    /// Prompt tokens served from the provider's prompt cache; 0 when the
+    /// provider does not report them.
+    pub cached_input_tokens : u64,
+    /// Generated tokens the model spent reasoning before its answer,
+    /// included in `output_tokens`; 0 when the provider does not report them.
+    pub reasoning_tokens : u64,
+  }
+
+  impl TokenUsage
+  {
+    /// Usage of `input_tokens` prompt and `output_tokens` generated tokens.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( input_tokens : u64, output_tokens : u64 ) -> Self
+    {
+      Self { input_tokens, output_tokens, cached_input_tokens : 0, reasoning_tokens : 0 }
+    }
+
+    /// Records that `tokens` of the prompt came from the prompt cache.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cached_input_tokens( mut self, tokens : u64 ) -> Self
+    {
+      self.cached_input_tokens = tokens;
+      self
+    }
+
+    /// Records that `tokens` of the output were spent reasoning.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_reasoning_tokens( mut self, tokens : u64 ) -> Self
+    {
+      self.reasoning_tokens = tokens;
+      self
+    }
+
+    /// Input and output tokens together.
+    #[ inline ]
+    #[ must_use ]
+    pub fn total_tokens( &self ) -> u64
+    {
+      self.input_tokens.saturating_add( self.output_tokens )
+    }
+  }
+
+  impl core::ops::Add for TokenUsage
+  {
+    type Output = Self;
+
+    #[ inline ]
+    fn add( mut self, other : Self ) -> Self
+    {
+      self += other;
+      self
+    }
+  }
+
+  impl core::ops::AddAssign for TokenUsage
+  {
+    #[ inline ]
+    fn add_assign( &mut self, other : Self )
+    {
+      self.input_tokens = self.input_tokens.saturating_add( other.input_tokens );
+      self.output_tokens = self.output_tokens.saturating_add( other.output_tokens );
+      self.cached_input_tokens = self.cached_input_tokens.saturating_add( other.cached_input_tokens );
+      self.reasoning_tokens = self.reasoning_tokens.saturating_add( other.reasoning_tokens );
+    }
+  }
+
+  impl core::iter::Sum for TokenUsage
+  {
+    #[ inline ]
+    fn sum< I : Iterator< Item = Self > >( iter : I ) -> Self
+    {
+      iter.fold( Self::default(), | total, usage | total + usage )
+    }
+  }
+
+  /// The usage of one call, with what it is grouped by.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct UsageRecord
+  {
+    /// Provider name, as chosen by the caller (`"openai"`, `"claude"`, …).
+    pub provider : String,
+    /// Model the call ran on.
+    pub model : String,
+    /// Caller-defined label such as a team, feature, or tenant.
+    pub tag : Option< String >,
+    /// When the call finished.
+    pub at : SystemTime,
+    /// Tokens the call consumed.
+    pub usage : TokenUsage,
+  }
+
+  impl UsageRecord
+  {
+    /// A record of `usage` taken now, without a tag.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( provider : impl Into< String >, model : impl Into< String >, usage : impl Into< TokenUsage > ) -> Self
+    {
+      Self
+      {
+        provider : provider.into(),
+        model : model.into(),
+        tag : None,
+        at : SystemTime::now(),
+        usage : usage.into(),
+      }
+    }
+
+    /// Labels the record with `tag`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tag( mut self, tag : impl Into< String > ) -> Self
+    {
+      self.tag = Some( tag.into() );
+      self
+    }
+
+    /// Dates the record at `at` instead of now, e.g. when importing logs.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_time( mut self, at : SystemTime ) -> Self
+    {
+      self.at = at;
+      self
+    }
+  }
+
+  /// A dimension records are grouped by.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum UsageGroup
+  {
+    /// One group per provider.
+    Provider,
+    /// One group per model.
+    Model,
+    /// One group per tag; untagged records form their own group.
+    Tag,
+  }
+
+  /// The time range a summary covers: `from` inclusive, `until` exclusive.
+  /// An open end is unbounded.
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, Eq ) ]
+  pub struct UsageWindow
+  {
+    /// Earliest record time included.
+    pub from : Option< SystemTime >,
+    /// First record time no longer included.
+    pub until : Option< SystemTime >,
+  }
+
+  impl UsageWindow
+  {
+    /// Every record.
+    #[ inline ]
+    #[ must_use ]
+    pub fn all() -> Self
+    {
+      Self::default()
+    }
+
+    /// Records of the last `period`, up to now.
+    #[ inline ]
+    #[ must_use ]
+    pub fn last( period : Duration ) -> Self
+    {
+      Self { from : SystemTime::now().checked_sub( period ), until : None }
+    }
+
+    /// Records from `from` up to, not including, `until`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn between( from : SystemTime, until : SystemTime ) -> Self
+    {
+      Self { from : Some( from ), until : Some( until ) }
+    }
+
+    /// Whether a record taken at `at` falls in the window.
+    #[ inline ]
+    #[ must_use ]
+    pub fn contains( &self, at : SystemTime ) -> bool
+    {
+      self.from.map_or( true, | from | at >= from ) && self.until.map_or( true, | until | at < until )
+    }
+  }
+
+  /// Summed usage of one group. Dimensions the summary was not grouped by
+  /// are `None`, as is the tag of the untagged group.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct UsageSummary
+  {
+    /// Provider of the group, when grouped by provider.
+    pub provider : Option< String >,
+    /// Model of the group, when grouped by model.
+    pub model : Option< String >,
+    /// Tag of the group, when grouped by tag.
+    pub tag : Option< String >,
+    /// Calls in the group.
+    pub requests : u64,
+    /// Tokens summed over those calls.
+    pub usage : TokenUsage,
+  }
+
+  impl UsageSummary
+  {
+    /// Column names of [`csv_row`](Self::csv_row).
+    pub const CSV_HEADER : &'static str = "provider,model,tag,requests,input_tokens,output_tokens,cached_input_tokens,reasoning_tokens,total_tokens";
+
+    /// The summary as a JSON object; dimensions not grouped by are `null`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn to_json( &self ) -> serde_json::Value
+    {
+      serde_json::json!
+      ( {
+        "provider" : self.provider,
+        "model" : self.model,
+        "tag" : self.tag,
+        "requests" : self.requests,
+        "input_tokens" : self.usage.input_tokens,
+        "output_tokens" : self.usage.output_tokens,
+        "cached_input_tokens" : self.usage.cached_input_tokens,
+        "reasoning_tokens" : self.usage.reasoning_tokens,
+        "total_tokens" : self.usage.total_tokens(),
+      } )
+    }
+
+    /// The summary as one CSV line without line break, in the column order
+    /// of [`CSV_HEADER`](Self::CSV_HEADER); dimensions not grouped by are
+    /// empty.
+    #[ inline ]
+    #[ must_use ]
+    pub fn csv_row( &self ) -> String
+    {
+      let text = | value : &Option< String > | value.as_deref().map_or( String::new(), csv_field );
+      format!
+      (
+        "{},{},{},{},{},{},{},{},{}",
+        text( &self.provider ),
+        text( &self.model ),
+        text( &self.tag ),
+        self.requests,
+        self.usage.input_tokens,
+        self.usage.output_tokens,
+        self.usage.cached_input_tokens,
+        self.usage.reasoning_tokens,
+        self.usage.total_tokens(),
+      )
+    }
+  }
+
+  /// Quotes `value` when it holds a comma, quote, or line break.
+  fn csv_field( value : &str ) -> String
+  {
+    if value.contains( [ ',', '"', '\n', '\r' ] )
+    {
+      format!( "\"{}\"", value.replace( '"', "\"\"" ) )
+    }
+    else
+    {
+      value.to_owned()
+    }
+  }
+
+  /// Provider, model, and tag of one group; `None` where not grouped by.
+  type GroupKey< 'a > = ( Option< &'a str >, Option< &'a str >, Option< &'a str > );
+
+  /// Usage records of any number of providers, summarised on demand.
+  ///
+  /// Share one report between clients behind a `Mutex`; recording takes
+  /// `&mut self`.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct UsageReport
+  {
+    records : Vec< UsageRecord >,
+  }
+
+  impl UsageReport
+  {
+    /// An empty report.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Records `usage` of a call to `model` on `provider`, taken now.
+    #[ inline ]
+    pub fn record( &mut self, provider : impl Into< String >, model : impl Into< String >, usage : impl Into< TokenUsage > )
+    {
+      self.records.push( UsageRecord::new( provider, model, usage ) );
+    }
+
+    /// Records `usage` as [`record`](Self::record) does, labelled with `tag`.
+    #[ inline ]
+    pub fn record_tagged
+    (
+      &mut self,
+      provider : impl Into< String >,
+      model : impl Into< String >,
+      tag : impl Into< String >,
+      usage : impl Into< TokenUsage >,
+    )
+    {
+      self.records.push( UsageRecord::new( provider, model, usage ).with_tag( tag ) );
+    }
+
+    /// Adds a record built by the caller.
+    #[ inline ]
+    pub fn push( &mut self, record : UsageRecord )
+    {
+      self.records.push( record );
+    }
+
+    /// Every record, in the order added.
+    #[ inline ]
+    #[ must_use ]
+    pub fn records( &self ) -> &[ UsageRecord ]
+    {
+      &self.records
+    }
+
+    /// Drops records taken before `at`, bounding the memory of a report
+    /// kept for the life of a service.
+    #[ inline ]
+    pub fn prune_before( &mut self, at : SystemTime )
+    {
+      self.records.retain( | record | record.at >= at );
+    }
+
+    /// Sums the records in `window` per group of `group_by`, ordered by
+    /// provider, model, then tag. An empty `group_by` sums everything into
+    /// one summary; a window without records yields none.
+    #[ inline ]
+    #[ must_use ]
+    pub fn summarize( &self, group_by : &[ UsageGroup ], window : UsageWindow ) -> Vec< UsageSummary >
+    {
+      let mut groups : BTreeMap< GroupKey< '_ >, ( u64, TokenUsage ) > = BTreeMap::new();
+      for record in self.records.iter().filter( | record | window.contains( record.at ) )
+      {
+        let key =
+        (
+          group_by.contains( &UsageGroup::Provider ).then_some( record.provider.as_str() ),
+          group_by.contains( &UsageGroup::Model ).then_some( record.model.as_str() ),
+          if group_by.contains( &UsageGroup::Tag ) { record.tag.as_deref() } else { None },
+        );
+        let ( requests, usage ) = groups.entry( key ).or_default();
+        *requests += 1;
+        *usage += record.usage;
+      }
+      groups.into_iter()
+      .map( | ( ( provider, model, tag ), ( requests, usage ) ) | UsageSummary
+      {
+        provider : provider.map( str::to_owned ),
+        model : model.map( str::to_owned ),
+        tag : tag.map( str::to_owned ),
+        requests,
+        usage,
+      } )
+      .collect()
+    }
+
+    /// [`summarize`](Self::summarize) as a JSON document: the window as
+    /// Unix seconds (`null` when open) and one object per group.
+    #[ inline ]
+    #[ must_use ]
+    pub fn to_json( &self, group_by : &[ UsageGroup ], window : UsageWindow ) -> serde_json::Value
+    {
+      let seconds = | at : Option< SystemTime > | at.and_then( | at | at.duration_since( UNIX_EPOCH ).ok() ).map( | since | since.as_secs() );
+      serde_json::json!
+      ( {
+        "from" : seconds( window.from ),
+        "until" : seconds( window.until ),
+        "groups" : self.summarize( group_by, window ).iter().map( UsageSummary::to_json ).collect::< Vec< _ > >(),
+      } )
+    }
+
+    /// [`summarize`](Self::summarize) as CSV: the
+    /// [header](UsageSummary::CSV_HEADER), then one line per group.
+    #[ inline ]
+    #[ must_use ]
+    pub fn to_csv( &self, group_by : &[ UsageGroup ], window : UsageWindow ) -> String
+    {
+      let mut csv = String::from( UsageSummary::CSV_HEADER );
+      csv.push( '\n' );
+      for summary in self.summarize( group_by, window )
+      {
+        csv.push_str( &summary.csv_row() );
+        csv.push( '\n' );
+      }
+      csv
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    TokenUsage,
+    UsageRecord,
+    UsageGroup,
+    UsageWindow,
+    UsageSummary,
+    UsageReport,
+  };
+}
@@ -0,0 +1,135 @@
+//! Tests for call budgets shared across reliability layers.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | unlimited_budget_allows_everything | limits | a budget without limits never refuses |
+//! | attempts_are_counted_across_clones | attempts | clones share one attempt counter |
+//! | recorded_cost_stops_further_attempts | cost | reaching `max_cost` refuses the next attempt |
+//! | delay_past_deadline_is_refused | deadline | a sleep that outlives the deadline is refused |
+//! | attempt_timeout_is_clamped | deadline | per-attempt timeout never exceeds the time left |
+//! | run_attempt_cuts_off_at_deadline | deadline | a slow attempt yields `Deadline` |
+//! | nested_layers_share_one_limit | composition | retries inside failover stop at the shared attempt limit |
+//! | layer_without_inner_attempts_counts_once | composition | an outer step with no counted attempts inside counts as one |
+
+#![ cfg( all( feature = "budget", feature = "tokio" ) ) ]
+
+use api_common::{ sleep, BudgetExhausted, CallBudget };
+use core::time::Duration;
+
+#[ test ]
+fn unlimited_budget_allows_everything()
+{
+  let tracker = CallBudget::new().start();
+
+  for _ in 0..100
+  {
+    tracker.begin_attempt().unwrap();
+  }
+  tracker.record_cost( u64::MAX );
+
+  assert_eq!( tracker.check(), Ok( () ) );
+  assert_eq!( tracker.remaining(), None );
+  assert_eq!( tracker.attempt_timeout( Duration::from_secs( 30 ) ), Duration::from_secs( 30 ) );
+}
+
+#[ test ]
+fn attempts_are_counted_across_clones()
+{
+  let tracker = CallBudget::new().with_max_attempts( 3 ).start();
+  let clone = tracker.clone();
+
+  assert_eq!( tracker.begin_attempt(), Ok( 1 ) );
+  assert_eq!( clone.begin_attempt(), Ok( 2 ) );
+  assert_eq!( tracker.begin_attempt(), Ok( 3 ) );
+
+  assert_eq!( clone.begin_attempt(), Err( BudgetExhausted::Attempts { max_attempts : 3 } ) );
+  assert_eq!( tracker.attempts(), 3 );
+}
+
+#[ test ]
+fn recorded_cost_stops_further_attempts()
+{
+  let tracker = CallBudget::new().with_max_cost( 1_000 ).start();
+
+  tracker.begin_attempt().unwrap();
+  tracker.record_cost( 600 );
+  tracker.begin_attempt().unwrap();
+  tracker.record_cost( 600 );
+
+  assert_eq!( tracker.begin_attempt(), Err( BudgetExhausted::Cost { spent : 1_200, max_cost : 1_000 } ) );
+}
+
+#[ test ]
+fn delay_past_deadline_is_refused()
+{
+  let tracker = CallBudget::new().with_total_deadline( Duration::from_secs( 10 ) ).start();
+
+  assert_eq!( tracker.allows_delay( Duration::from_secs( 1 ) ), Ok( () ) );
+  assert_eq!( tracker.allows_delay( Duration::from_secs( 60 ) ), Err( BudgetExhausted::Deadline { deadline : Duration::from_secs( 10 ) } ) );
+}
+
+#[ test ]
+fn attempt_timeout_is_clamped()
+{
+  let tracker = CallBudget::new().with_total_deadline( Duration::from_secs( 5 ) ).start();
+
+  assert!( tracker.attempt_timeout( Duration::from_secs( 30 ) ) <= Duration::from_secs( 5 ) );
+  assert_eq!( tracker.attempt_timeout( Duration::from_millis( 10 ) ), Duration::from_millis( 10 ) );
+}
+
+#[ tokio::test ]
+async fn run_attempt_cuts_off_at_deadline()
+{
+  let deadline = Duration::from_millis( 50 );
+  let tracker = CallBudget::new().with_total_deadline( deadline ).start();
+
+  let fast = tracker.run_attempt( async { 7 } ).await;
+  let slow = tracker.run_attempt( sleep( Duration::from_secs( 60 ) ) ).await;
+
+  assert_eq!( fast, Ok( 7 ) );
+  assert_eq!( slow, Err( BudgetExhausted::Deadline { deadline } ) );
+  assert_eq!( tracker.begin_attempt(), Err( BudgetExhausted::Deadline { deadline } ) );
+}
+
+#[ tokio::test ]
+async fn nested_layers_share_one_limit()
+{
+  let tracker = CallBudget::new().with_max_attempts( 4 ).start();
+  let mut made = 0;
+
+  // Three endpoints with three retries each would be nine attempts alone.
+  for _endpoint in 0..3
+  {
+    let step = async
+    {
+      for _retry in 0..3
+      {
+        if tracker.run_attempt( async {} ).await.is_err()
+        {
+          break;
+        }
+        made += 1;
+      }
+    };
+    if tracker.run_layer( step ).await.is_err()
+    {
+      break;
+    }
+  }
+
+  assert_eq!( made, 4 );
+  assert_eq!( tracker.attempts(), 4 );
+}
+
+#[ tokio::test ]
+async fn layer_without_inner_attempts_counts_once()
+{
+  let tracker = CallBudget::new().with_max_attempts( 2 ).start();
+
+  assert_eq!( tracker.run_layer( async { 1 } ).await, Ok( 1 ) );
+  assert_eq!( tracker.run_layer( async { 2 } ).await, Ok( 2 ) );
+
+  assert_eq!( tracker.run_layer( async { 3 } ).await, Err( BudgetExhausted::Attempts { max_attempts : 2 } ) );
+}
@@ -0,0 +1,118 @@
+//! Tests for cancellation tokens.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | cancel_wakes_every_waiter | signal | cancelling one clone completes `cancelled()` on every clone, waiting or not |
+//! | unfinished_future_is_dropped_on_cancel | run | `run_until_cancelled` returns `Cancelled` and drops the pending future |
+//! | finished_future_returns_output | run | an uncancelled token passes the output through |
+//! | cancelled_token_never_polls_future | run | a token cancelled beforehand fails without polling the future |
+//! | clones_share_identity | signal | clones compare equal, separate tokens do not, and cancellation stays set |
+
+#![ cfg( all( feature = "cancellation", feature = "tokio" ) ) ]
+
+use api_common::{ sleep, CancellationToken, Cancelled };
+use core::sync::atomic::{ AtomicBool, Ordering };
+use core::time::Duration;
+use std::sync::Arc;
+
+/// Sets its flag when dropped.
+struct DropFlag( Arc< AtomicBool > );
+
+impl Drop for DropFlag
+{
+  fn drop( &mut self )
+  {
+    self.0.store( true, Ordering::SeqCst );
+  }
+}
+
+#[ tokio::test ]
+async fn cancel_wakes_every_waiter()
+{
+  let token = CancellationToken::new();
+  let waiters : Vec< _ > = ( 0..3 ).map( | _ |
+  {
+    let token = token.clone();
+    tokio::spawn( async move { token.cancelled().await } )
+  } ).collect();
+  sleep( Duration::from_millis( 10 ) ).await;
+  assert!( waiters.iter().all( | waiter | !waiter.is_finished() ) );
+
+  token.clone().cancel();
+
+  for waiter in waiters
+  {
+    tokio::time::timeout( Duration::from_secs( 1 ), waiter ).await.expect( "waiter must wake" ).unwrap();
+  }
+  token.cancelled().await;
+}
+
+#[ tokio::test ]
+async fn unfinished_future_is_dropped_on_cancel()
+{
+  let token = CancellationToken::new();
+  let dropped = Arc::new( AtomicBool::new( false ) );
+  let guard = DropFlag( Arc::clone( &dropped ) );
+  let canceller = token.clone();
+  tokio::spawn( async move
+  {
+    sleep( Duration::from_millis( 20 ) ).await;
+    canceller.cancel();
+  } );
+
+  let result = token.run_until_cancelled( async move
+  {
+    let _guard = guard;
+    sleep( Duration::from_secs( 3600 ) ).await;
+  } ).await;
+
+  assert_eq!( result, Err( Cancelled ) );
+  assert!( dropped.load( Ordering::SeqCst ), "the pending future must be dropped" );
+}
+
+#[ tokio::test ]
+async fn finished_future_returns_output()
+{
+  let token = CancellationToken::new();
+
+  let result = token.run_until_cancelled( async
+  {
+    sleep( Duration::from_millis( 1 ) ).await;
+    7
+  } ).await;
+
+  assert_eq!( result, Ok( 7 ) );
+  assert!( !token.is_cancelled() );
+}
+
+#[ tokio::test ]
+async fn cancelled_token_never_polls_future()
+{
+  let token = CancellationToken::new();
+  token.cancel();
+  let polled = AtomicBool::new( false );
+
+  let result = token.run_until_cancelled( async { polled.store( true, Ordering::SeqCst ) } ).await;
+
+  assert_eq!( result, Err( Cancelled ) );
+  assert!( !polled.load( Ordering::SeqCst ) );
+  assert_eq!( Cancelled.to_string(), "Request cancelled" );
+}
+
+#[ test ]
+fn clones_share_identity()
+{
+  let token = CancellationToken::new();
+  let clone = token.clone();
+  let other = CancellationToken::new();
+
+  assert_eq!( token, clone );
+  assert_ne!( token, other );
+
+  clone.cancel();
+  clone.cancel();
+  assert!( token.is_cancelled() );
+  assert!( !other.is_cancelled() );
+}
@@ -0,0 +1,128 @@
+//! Tests for the in-flight request gate.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | permits_cap_in_flight | slots | no more than `max_in_flight` permits exist; dropping one frees a slot |
+//! | waiters_are_served_in_arrival_order | fairness | queued requests are admitted FIFO |
+//! | try_acquire_does_not_overtake_queue | fairness | a free slot goes to the queue, not to a newcomer |
+//! | queue_timeout_leaves_queue | timeout | a waiter gives up with `QueueTimeout` and no longer occupies the queue |
+//! | dropped_waiter_leaves_queue | cancellation | dropping a queued `acquire` lets the next waiter through |
+//! | run_holds_permit_for_future | slots | `run` keeps the slot until its future completes |
+
+#![ cfg( all( feature = "concurrency", feature = "tokio" ) ) ]
+
+use api_common::{ sleep, ConcurrencyGate, QueueTimeout };
+use core::time::Duration;
+use std::sync::{ Arc, Mutex };
+
+#[ test ]
+fn permits_cap_in_flight()
+{
+  let gate = ConcurrencyGate::new( 2 );
+  let clone = gate.clone();
+
+  let first = gate.try_acquire().unwrap();
+  let _second = clone.try_acquire().unwrap();
+
+  assert!( gate.try_acquire().is_none() );
+  assert_eq!( gate.in_flight(), 2 );
+
+  drop( first );
+  assert_eq!( clone.in_flight(), 1 );
+  assert!( clone.try_acquire().is_some() );
+}
+
+#[ tokio::test ]
+async fn waiters_are_served_in_arrival_order()
+{
+  let gate = ConcurrencyGate::new( 1 );
+  let order = Arc::new( Mutex::new( Vec::new() ) );
+  let held = gate.try_acquire().unwrap();
+
+  let mut tasks = Vec::new();
+  for id in 0..4
+  {
+    let ( gate, order ) = ( gate.clone(), order.clone() );
+    tasks.push( tokio::spawn( async move
+    {
+      let _permit = gate.acquire().await.unwrap();
+      order.lock().unwrap().push( id );
+      sleep( Duration::from_millis( 1 ) ).await;
+    } ) );
+    // let each task join the queue before the next one is spawned
+    sleep( Duration::from_millis( 5 ) ).await;
+  }
+  assert_eq!( gate.queued(), 4 );
+
+  drop( held );
+  for task in tasks
+  {
+    task.await.unwrap();
+  }
+
+  assert_eq!( *order.lock().unwrap(), vec![ 0, 1, 2, 3 ] );
+  assert_eq!( gate.in_flight(), 0 );
+}
+
+#[ tokio::test ]
+async fn try_acquire_does_not_overtake_queue()
+{
+  let gate = ConcurrencyGate::new( 1 );
+  let held = gate.try_acquire().unwrap();
+
+  let waiter = tokio::spawn( { let gate = gate.clone(); async move { gate.acquire().await.map( drop ) } } );
+  sleep( Duration::from_millis( 5 ) ).await;
+
+  drop( held );
+  // the slot was handed to the waiter, not freed
+  assert!( gate.try_acquire().is_none() );
+  assert_eq!( gate.in_flight(), 1 );
+
+  waiter.await.unwrap().unwrap();
+  assert!( gate.try_acquire().is_some() );
+}
+
+#[ tokio::test ]
+async fn queue_timeout_leaves_queue()
+{
+  let gate = ConcurrencyGate::new( 1 ).with_queue_timeout( Duration::from_millis( 20 ) );
+  let _held = gate.try_acquire().unwrap();
+
+  let error = gate.acquire().await.unwrap_err();
+
+  assert_eq!( error, QueueTimeout { waited : Duration::from_millis( 20 ) } );
+  assert_eq!( gate.queued(), 0 );
+}
+
+#[ tokio::test ]
+async fn dropped_waiter_leaves_queue()
+{
+  let gate = ConcurrencyGate::new( 1 );
+  let held = gate.try_acquire().unwrap();
+
+  let abandoned = tokio::spawn( { let gate = gate.clone(); async move { gate.acquire().await.map( drop ) } } );
+  sleep( Duration::from_millis( 5 ) ).await;
+  let next = tokio::spawn( { let gate = gate.clone(); async move { gate.acquire().await.map( drop ) } } );
+  sleep( Duration::from_millis( 5 ) ).await;
+
+  abandoned.abort();
+  let _ = abandoned.await;
+  assert_eq!( gate.queued(), 1 );
+
+  drop( held );
+  next.await.unwrap().unwrap();
+  assert_eq!( gate.in_flight(), 0 );
+}
+
+#[ tokio::test ]
+async fn run_holds_permit_for_future()
+{
+  let gate = ConcurrencyGate::new( 3 );
+
+  let inside = gate.run( async { gate.in_flight() } ).await.unwrap();
+
+  assert_eq!( inside, 1 );
+  assert_eq!( gate.in_flight(), 0 );
+}
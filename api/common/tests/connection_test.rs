@@ -0,0 +1,101 @@
+//! Tests for connection tuning.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | new_keeps_provider_defaults | new | every setting unset, version negotiated |
+//! | low_latency_sets_pool_and_keepalive | preset | the preset's pool, TCP, and HTTP/2 values |
+//! | only_prior_knowledge_requires_http2 | http2 | adaptive windows and pings do not need HTTP/2 support |
+//! | proxy_is_kept_as_given | proxy | `with_proxy` stores the URL unchecked; no preset sets a proxy |
+//! | apply_to_builds_a_client | apply | the preset and a valid proxy give a client that builds |
+//! | apply_to_refuses_a_malformed_proxy | apply | a proxy that is not a URL is an `InvalidConnectionTuning` error |
+//! | prior_knowledge_follows_the_http2_feature | apply | prior knowledge applies with `http2`, and is refused without it |
+
+#![ cfg( feature = "connection" ) ]
+
+use api_common::{ CommonError, ConnectionTuning, HttpVersion };
+use core::time::Duration;
+
+#[ test ]
+fn new_keeps_provider_defaults()
+{
+  let tuning = ConnectionTuning::new();
+
+  assert_eq!( tuning.pool_idle_timeout, None );
+  assert_eq!( tuning.pool_max_idle_per_host, None );
+  assert_eq!( tuning.tcp_keepalive, None );
+  assert_eq!( tuning.tcp_nodelay, None );
+  assert_eq!( tuning.http_version, HttpVersion::Negotiate );
+  assert!( !tuning.http2_adaptive_window );
+  assert_eq!( tuning.proxy, None );
+  assert!( !tuning.requires_http2() );
+}
+
+#[ test ]
+fn low_latency_sets_pool_and_keepalive()
+{
+  let tuning = ConnectionTuning::low_latency().with_pool_max_idle_per_host( 4 );
+
+  assert_eq!( tuning.pool_idle_timeout, Some( Duration::from_secs( 300 ) ) );
+  assert_eq!( tuning.pool_max_idle_per_host, Some( 4 ) );
+  assert_eq!( tuning.tcp_keepalive, Some( Duration::from_secs( 30 ) ) );
+  assert_eq!( tuning.tcp_nodelay, Some( true ) );
+  assert!( tuning.http2_adaptive_window );
+  assert_eq!( tuning.http2_keep_alive_interval, Some( Duration::from_secs( 30 ) ) );
+  assert_eq!( tuning.http_version, HttpVersion::Negotiate );
+}
+
+#[ test ]
+fn only_prior_knowledge_requires_http2()
+{
+  assert!( !ConnectionTuning::low_latency().requires_http2() );
+  assert!( !ConnectionTuning::new().with_http_version( HttpVersion::Http1Only ).requires_http2() );
+  assert!( ConnectionTuning::new().with_http2_prior_knowledge().requires_http2() );
+}
+
+#[ test ]
+fn proxy_is_kept_as_given()
+{
+  let tuning = ConnectionTuning::low_latency().with_proxy( "http://proxy.internal:3128" );
+
+  assert_eq!( tuning.proxy.as_deref(), Some( "http://proxy.internal:3128" ) );
+  assert_eq!( ConnectionTuning::low_latency().proxy, None );
+  assert_eq!( ConnectionTuning::new().with_proxy( "not a url" ).proxy.as_deref(), Some( "not a url" ) );
+}
+
+#[ test ]
+fn apply_to_builds_a_client()
+{
+  let builder = ConnectionTuning::low_latency()
+  .with_pool_max_idle_per_host( 4 )
+  .with_http_version( HttpVersion::Http1Only )
+  .with_proxy( "http://proxy.internal:3128" )
+  .apply_to( reqwest::Client::builder() )
+  .expect( "valid tuning applies" );
+
+  assert!( builder.build().is_ok() );
+}
+
+#[ test ]
+fn apply_to_refuses_a_malformed_proxy()
+{
+  let error = ConnectionTuning::new().with_proxy( "not a proxy url" ).apply_to( reqwest::Client::builder() ).unwrap_err();
+
+  assert!( matches!( &error, CommonError::InvalidConnectionTuning( reason ) if reason.starts_with( "invalid proxy URL" ) ), "{error}" );
+}
+
+#[ test ]
+fn prior_knowledge_follows_the_http2_feature()
+{
+  let applied = ConnectionTuning::new().with_http2_prior_knowledge().apply_to( reqwest::Client::builder() );
+
+  if cfg!( feature = "http2" )
+  {
+    assert!( applied.is_ok_and( | builder | builder.build().is_ok() ) );
+  }
+  else
+  {
+    assert!( matches!( applied, Err( CommonError::InvalidConnectionTuning( _ ) ) ) );
+  }
+}
@@ -0,0 +1,226 @@
+//! Tests for `Conversation` and its truncation strategies.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | messages_order_system_summary_turns | render | system, summary, turns order |
+//! | instructions_join_system_and_summary | render | `instructions()` for out-of-band system prompts |
+//! | turn_count_groups_replies_with_user | turns | turn boundaries at user messages |
+//! | keep_last_turns_removes_whole_turns | truncate | `KeepLastTurns` returns removed messages |
+//! | keep_last_turns_zero_removes_everything | truncate | `KeepLastTurns( 0 )` |
+//! | keep_last_turns_noop_when_short | truncate | nothing removed under the limit |
+//! | token_budget_drops_oldest_until_fit | truncate | `TokenBudget` with custom estimator |
+//! | token_budget_keeps_latest_turn | truncate | latest turn survives an impossible budget |
+//! | summarize_folds_removed_turns | truncate | summary produced from removed turns |
+//! | summarize_includes_previous_summary | truncate | running summary is chained |
+//! | summarize_error_leaves_conversation_unchanged | truncate | callback failure is atomic |
+//! | set_summary_resumes_summarizing | truncate | a restored summary is chained |
+//! | estimate_tokens_rounds_up | estimate | four characters per token |
+
+#![ cfg( feature = "conversation" ) ]
+
+use api_common::
+{
+  estimate_tokens,
+  Conversation,
+  NeutralMessage,
+  NeutralRole,
+  TruncationStrategy,
+};
+
+/// One token per character, so budgets in tests are easy to reason about.
+fn char_count( text : &str ) -> usize
+{
+  text.chars().count()
+}
+
+fn three_turns() -> Conversation
+{
+  let mut conversation = Conversation::new().with_system( "sys" );
+  conversation.push_user( "u1" );
+  conversation.push_assistant( "a1" );
+  conversation.push_user( "u2" );
+  conversation.push_assistant( "a2" );
+  conversation.push_user( "u3" );
+  conversation
+}
+
+fn contents( messages : &[ NeutralMessage ] ) -> Vec< &str >
+{
+  messages.iter().map( | m | m.content.as_str() ).collect()
+}
+
+// ------------------------------------------------------------------ //
+//  Rendering
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn messages_order_system_summary_turns()
+{
+  let mut conversation = three_turns();
+  conversation.truncate( &TruncationStrategy::summarize( 1, | _ | Ok( "sum".to_owned() ) ) ).unwrap();
+
+  let messages = conversation.messages();
+  assert_eq!( contents( &messages ), vec![ "sys", "sum", "u3" ] );
+  assert_eq!( messages[ 1 ].role, NeutralRole::System );
+}
+
+#[ test ]
+fn instructions_join_system_and_summary()
+{
+  let mut conversation = three_turns();
+  assert_eq!( conversation.instructions().as_deref(), Some( "sys" ) );
+
+  conversation.truncate( &TruncationStrategy::summarize( 1, | _ | Ok( "sum".to_owned() ) ) ).unwrap();
+  assert_eq!( conversation.instructions().as_deref(), Some( "sys\n\nsum" ) );
+  assert_eq!( Conversation::new().instructions(), None );
+}
+
+#[ test ]
+fn turn_count_groups_replies_with_user()
+{
+  let mut conversation = Conversation::new();
+  conversation.push_assistant( "greeting" );
+  conversation.push_user( "u1" );
+  conversation.push_assistant( "a1" );
+  conversation.push_assistant( "a1 continued" );
+
+  assert_eq!( conversation.turn_count(), 2 );
+  assert_eq!( three_turns().turn_count(), 3 );
+}
+
+// ------------------------------------------------------------------ //
+//  KeepLastTurns
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn keep_last_turns_removes_whole_turns()
+{
+  let mut conversation = three_turns();
+  let removed = conversation.truncate( &TruncationStrategy::keep_last_turns( 2 ) ).unwrap();
+
+  assert_eq!( contents( &removed ), vec![ "u1", "a1" ] );
+  assert_eq!( contents( conversation.turns() ), vec![ "u2", "a2", "u3" ] );
+  assert_eq!( conversation.system(), Some( "sys" ) );
+}
+
+#[ test ]
+fn keep_last_turns_zero_removes_everything()
+{
+  let mut conversation = three_turns();
+  let removed = conversation.truncate( &TruncationStrategy::keep_last_turns( 0 ) ).unwrap();
+
+  assert_eq!( removed.len(), 5 );
+  assert!( conversation.is_empty() );
+}
+
+#[ test ]
+fn keep_last_turns_noop_when_short()
+{
+  let mut conversation = three_turns();
+  let removed = conversation.truncate( &TruncationStrategy::keep_last_turns( 3 ) ).unwrap();
+
+  assert!( removed.is_empty() );
+  assert_eq!( conversation, three_turns() );
+}
+
+// ------------------------------------------------------------------ //
+//  TokenBudget
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn token_budget_drops_oldest_until_fit()
+{
+  // sys(3) + u1 a1 u2 a2 u3 (2 each) = 13 tokens.
+  let mut conversation = three_turns();
+  assert_eq!( conversation.estimated_tokens( char_count ), 13 );
+
+  let removed = conversation.truncate( &TruncationStrategy::token_budget_with( 9, char_count ) ).unwrap();
+
+  assert_eq!( contents( &removed ), vec![ "u1", "a1" ] );
+  assert_eq!( conversation.estimated_tokens( char_count ), 9 );
+}
+
+#[ test ]
+fn token_budget_keeps_latest_turn()
+{
+  let mut conversation = three_turns();
+  let removed = conversation.truncate( &TruncationStrategy::token_budget_with( 0, char_count ) ).unwrap();
+
+  assert_eq!( removed.len(), 4 );
+  assert_eq!( contents( conversation.turns() ), vec![ "u3" ] );
+}
+
+// ------------------------------------------------------------------ //
+//  Summarize
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn summarize_folds_removed_turns()
+{
+  let mut conversation = three_turns();
+  let strategy = TruncationStrategy::summarize( 1, | messages |
+  {
+    Ok( messages.iter().map( | m | m.content.as_str() ).collect::< Vec< _ > >().join( "," ) )
+  });
+  let removed = conversation.truncate( &strategy ).unwrap();
+
+  assert_eq!( removed.len(), 4 );
+  assert_eq!( conversation.summary(), Some( "u1,a1,u2,a2" ) );
+  assert_eq!( contents( conversation.turns() ), vec![ "u3" ] );
+}
+
+#[ test ]
+fn summarize_includes_previous_summary()
+{
+  let mut conversation = three_turns();
+  let strategy = TruncationStrategy::summarize( 1, | messages |
+  {
+    Ok( messages.iter().map( | m | m.content.as_str() ).collect::< Vec< _ > >().join( "+" ) )
+  });
+  conversation.truncate( &strategy ).unwrap();
+  conversation.push_assistant( "a3" );
+  conversation.push_user( "u4" );
+  conversation.truncate( &strategy ).unwrap();
+
+  assert_eq!( conversation.summary(), Some( "u1+a1+u2+a2+u3+a3" ) );
+}
+
+#[ test ]
+fn summarize_error_leaves_conversation_unchanged()
+{
+  let mut conversation = three_turns();
+  let strategy = TruncationStrategy::summarize( 1, | _ | Err( error_tools::untyped::format_err!( "model unavailable" ) ) );
+
+  assert!( conversation.truncate( &strategy ).is_err() );
+  assert_eq!( conversation, three_turns() );
+}
+
+#[ test ]
+fn set_summary_resumes_summarizing()
+{
+  let mut conversation = three_turns();
+  conversation.set_summary( Some( "s0".to_owned() ) );
+  let strategy = TruncationStrategy::summarize( 1, | messages |
+  {
+    Ok( messages.iter().map( | m | m.content.as_str() ).collect::< Vec< _ > >().join( "+" ) )
+  });
+  conversation.truncate( &strategy ).unwrap();
+
+  assert_eq!( conversation.summary(), Some( "s0+u1+a1+u2+a2" ) );
+  conversation.set_summary( None );
+  assert_eq!( conversation.summary(), None );
+}
+
+// ------------------------------------------------------------------ //
+//  Estimation
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn estimate_tokens_rounds_up()
+{
+  assert_eq!( estimate_tokens( "" ), 0 );
+  assert_eq!( estimate_tokens( "abcd" ), 1 );
+  assert_eq!( estimate_tokens( "abcde" ), 2 );
+}
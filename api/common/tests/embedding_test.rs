@@ -0,0 +1,155 @@
+//! Tests for the `Embedder` trait helpers and vector utilities.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | l2_norm_of_pythagorean_vector | vector | Euclidean length |
+//! | normalize_yields_unit_length | vector | in-place normalisation |
+//! | normalize_rejects_zero_vector | vector | `ZeroVector` |
+//! | cosine_similarity_of_orthogonal_and_parallel | vector | similarity range |
+//! | cosine_similarity_rejects_mismatched_dimensions | vector | `DimensionMismatch` |
+//! | check_dimensions_reports_first_mismatch | vector | dimension checks over a batch |
+//! | embed_batched_splits_and_keeps_order | batch | chunking and order |
+//! | embed_batched_rejects_zero_batch_size | batch | `InvalidArgument` |
+//! | embed_batched_propagates_embedder_error | batch | error propagation |
+
+#![ cfg( feature = "embeddings" ) ]
+
+use api_common::
+{
+  check_dimensions,
+  cosine_similarity,
+  embed_batched,
+  l2_norm,
+  normalize,
+  CommonError,
+  Embedder,
+  Embedding,
+};
+use std::sync::Mutex;
+
+fn common_error( err : &error_tools::untyped::Error ) -> &CommonError
+{
+  err.downcast_ref::< CommonError >().expect( "error must be a CommonError" )
+}
+
+/// Embeds a text as `[ len ]` and records the size of each request.
+#[ derive( Default ) ]
+struct LengthEmbedder
+{
+  calls : Mutex< Vec< usize > >,
+  fail : bool,
+}
+
+#[ async_trait::async_trait ]
+impl Embedder for LengthEmbedder
+{
+  fn model( &self ) -> &'static str
+  {
+    "length"
+  }
+
+  async fn embed( &self, texts : &[ String ] ) -> api_common::Result< Vec< Embedding > >
+  {
+    if self.fail
+    {
+      return Err( error_tools::untyped::format_err!( "provider down" ) );
+    }
+    self.calls.lock().unwrap().push( texts.len() );
+    #[ allow( clippy::cast_precision_loss ) ]
+    Ok( texts.iter().map( | t | vec![ t.len() as f32 ] ).collect() )
+  }
+}
+
+fn texts( items : &[ &str ] ) -> Vec< String >
+{
+  items.iter().map( | s | ( *s ).to_owned() ).collect()
+}
+
+// ------------------------------------------------------------------ //
+//  Vector utilities
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn l2_norm_of_pythagorean_vector()
+{
+  assert!( ( l2_norm( &[ 3.0, 4.0 ] ) - 5.0 ).abs() < f32::EPSILON );
+}
+
+#[ test ]
+fn normalize_yields_unit_length()
+{
+  let mut vector = vec![ 3.0, 4.0 ];
+  normalize( &mut vector ).unwrap();
+
+  assert!( ( l2_norm( &vector ) - 1.0 ).abs() < 1e-6 );
+  assert!( ( vector[ 0 ] - 0.6 ).abs() < 1e-6 );
+}
+
+#[ test ]
+fn normalize_rejects_zero_vector()
+{
+  let err = normalize( &mut [ 0.0, 0.0 ] ).unwrap_err();
+
+  assert_eq!( common_error( &err ), &CommonError::ZeroVector );
+}
+
+#[ test ]
+fn cosine_similarity_of_orthogonal_and_parallel()
+{
+  assert!( cosine_similarity( &[ 1.0, 0.0 ], &[ 0.0, 5.0 ] ).unwrap().abs() < 1e-6 );
+  assert!( ( cosine_similarity( &[ 1.0, 1.0 ], &[ -2.0, -2.0 ] ).unwrap() + 1.0 ).abs() < 1e-6 );
+}
+
+#[ test ]
+fn cosine_similarity_rejects_mismatched_dimensions()
+{
+  let err = cosine_similarity( &[ 1.0, 0.0 ], &[ 1.0 ] ).unwrap_err();
+
+  assert_eq!( common_error( &err ), &CommonError::DimensionMismatch { expected : 2, actual : 1 } );
+}
+
+#[ test ]
+fn check_dimensions_reports_first_mismatch()
+{
+  let vectors = vec![ vec![ 0.0; 3 ], vec![ 0.0; 2 ], vec![ 0.0; 4 ] ];
+
+  assert!( check_dimensions( &vectors[ ..1 ], 3 ).is_ok() );
+  let err = check_dimensions( &vectors, 3 ).unwrap_err();
+  assert_eq!( common_error( &err ), &CommonError::DimensionMismatch { expected : 3, actual : 2 } );
+}
+
+// ------------------------------------------------------------------ //
+//  Batching
+// ------------------------------------------------------------------ //
+
+#[ tokio::test ]
+async fn embed_batched_splits_and_keeps_order()
+{
+  let embedder = LengthEmbedder::default();
+  let input = texts( &[ "a", "bb", "ccc", "dddd", "eeeee" ] );
+  let embeddings = embed_batched( &embedder, &input, 2 ).await.unwrap();
+
+  assert_eq!( embeddings, vec![ vec![ 1.0 ], vec![ 2.0 ], vec![ 3.0 ], vec![ 4.0 ], vec![ 5.0 ] ] );
+  assert_eq!( *embedder.calls.lock().unwrap(), vec![ 2, 2, 1 ] );
+}
+
+#[ tokio::test ]
+async fn embed_batched_rejects_zero_batch_size()
+{
+  let embedder = LengthEmbedder::default();
+  let err = embed_batched( &embedder, &texts( &[ "a" ] ), 0 ).await.unwrap_err();
+
+  assert!( matches!( common_error( &err ), CommonError::InvalidArgument( _ ) ) );
+  assert!( embedder.calls.lock().unwrap().is_empty() );
+}
+
+#[ tokio::test ]
+async fn embed_batched_propagates_embedder_error()
+{
+  let embedder = LengthEmbedder { fail : true, ..Default::default() };
+  let err = embed_batched( &embedder, &texts( &[ "a", "b" ] ), 1 ).await.unwrap_err();
+
+  assert_eq!( err.to_string(), "provider down" );
+}
@@ -0,0 +1,164 @@
+//! Tests for guardrail chains.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | empty_chain_passes_bodies_unchanged | chain | no guardrails, no change, no error |
+//! | request_order_and_rewrite | chain | guardrails run first-added first and see earlier rewrites |
+//! | response_runs_in_reverse_order | chain | responses pass the guardrails last-added first |
+//! | denial_stops_chain | deny | the first denial is reported with name and stage; later guardrails do not run |
+//! | default_hooks_allow | trait | a guardrail implementing one side leaves the other alone |
+//! | visit_strings_reaches_every_string | helper | nested arrays and objects, keys untouched |
+//! | violation_is_content_filtered | llm_error | `LlmError::from` and `from_untyped` give `ContentFiltered` |
+
+#![ cfg( feature = "guardrail" ) ]
+
+use api_common::{ visit_strings, Guardrail, GuardrailStage, GuardrailViolation, Guardrails, LlmError };
+use serde_json::{ json, Value };
+use std::sync::{ Arc, Mutex };
+
+/// Appends its name to every string it sees and records each call.
+struct Tag
+{
+  name : &'static str,
+  calls : Arc< Mutex< Vec< String > > >,
+}
+
+impl Guardrail for Tag
+{
+  fn name( &self ) -> &str
+  {
+    self.name
+  }
+
+  fn check_request( &self, body : &mut Value ) -> Result< (), String >
+  {
+    self.calls.lock().unwrap().push( format!( "request:{}", self.name ) );
+    visit_strings( body, &mut | text | text.push_str( self.name ) );
+    Ok( () )
+  }
+
+  fn check_response( &self, _body : &mut Value ) -> Result< (), String >
+  {
+    self.calls.lock().unwrap().push( format!( "response:{}", self.name ) );
+    Ok( () )
+  }
+}
+
+/// Denies any request that mentions `word`.
+struct Deny
+{
+  word : &'static str,
+}
+
+#[ allow( clippy::unnecessary_literal_bound ) ]
+impl Guardrail for Deny
+{
+  fn name( &self ) -> &str
+  {
+    "deny"
+  }
+
+  fn check_request( &self, body : &mut Value ) -> Result< (), String >
+  {
+    if body.to_string().contains( self.word )
+    {
+      return Err( format!( "mentions {}", self.word ) );
+    }
+    Ok( () )
+  }
+}
+
+fn chain( calls : &Arc< Mutex< Vec< String > > > ) -> Guardrails
+{
+  Guardrails::new()
+  .with( Tag { name : "a", calls : Arc::clone( calls ) } )
+  .with( Tag { name : "b", calls : Arc::clone( calls ) } )
+}
+
+#[ test ]
+fn empty_chain_passes_bodies_unchanged()
+{
+  let guardrails = Guardrails::new();
+  let mut body = json!( { "messages" : [ { "content" : "hello" } ] } );
+
+  guardrails.check_request( &mut body ).unwrap();
+  guardrails.check_response( &mut body ).unwrap();
+
+  assert!( guardrails.is_empty() );
+  assert_eq!( body, json!( { "messages" : [ { "content" : "hello" } ] } ) );
+}
+
+#[ test ]
+fn request_order_and_rewrite()
+{
+  let calls = Arc::new( Mutex::new( Vec::new() ) );
+  let mut body = json!( { "content" : "x" } );
+
+  chain( &calls ).check_request( &mut body ).unwrap();
+
+  assert_eq!( body, json!( { "content" : "xab" } ) );
+  assert_eq!( *calls.lock().unwrap(), [ "request:a", "request:b" ] );
+}
+
+#[ test ]
+fn response_runs_in_reverse_order()
+{
+  let calls = Arc::new( Mutex::new( Vec::new() ) );
+
+  chain( &calls ).check_response( &mut json!( {} ) ).unwrap();
+
+  assert_eq!( *calls.lock().unwrap(), [ "response:b", "response:a" ] );
+}
+
+#[ test ]
+fn denial_stops_chain()
+{
+  let calls = Arc::new( Mutex::new( Vec::new() ) );
+  let guardrails = Guardrails::new()
+  .with( Deny { word : "secret" } )
+  .with( Tag { name : "after", calls : Arc::clone( &calls ) } );
+
+  let error = guardrails.check_request( &mut json!( { "content" : "the secret plan" } ) ).unwrap_err();
+
+  assert_eq!
+  (
+    error,
+    GuardrailViolation { guardrail : "deny".into(), stage : GuardrailStage::Request, reason : "mentions secret".into() }
+  );
+  assert_eq!( error.to_string(), "Guardrail deny denied the request : mentions secret" );
+  assert!( calls.lock().unwrap().is_empty() );
+  assert_eq!( format!( "{guardrails:?}" ), r#"["deny", "after"]"# );
+}
+
+#[ test ]
+fn default_hooks_allow()
+{
+  let guardrails = Guardrails::new().with( Deny { word : "secret" } );
+  let mut body = json!( { "content" : "secret" } );
+
+  guardrails.check_response( &mut body ).unwrap();
+
+  assert_eq!( body, json!( { "content" : "secret" } ) );
+}
+
+#[ test ]
+fn visit_strings_reaches_every_string()
+{
+  let mut body = json!( { "email" : "a@b.c", "list" : [ "x", { "deep" : "y" }, 3, null, true ] } );
+
+  visit_strings( &mut body, &mut | text | *text = text.to_uppercase() );
+
+  assert_eq!( body, json!( { "email" : "A@B.C", "list" : [ "X", { "deep" : "Y" }, 3, null, true ] } ) );
+}
+
+#[ test ]
+fn violation_is_content_filtered()
+{
+  let violation = GuardrailViolation { guardrail : "pii".into(), stage : GuardrailStage::Response, reason : "email address".into() };
+  let expected = LlmError::ContentFiltered( "Guardrail pii denied the response : email address".into() );
+
+  assert_eq!( LlmError::from( violation.clone() ), expected );
+  assert_eq!( LlmError::from_untyped( &violation.into() ), expected );
+}
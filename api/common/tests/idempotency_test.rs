@@ -0,0 +1,45 @@
+//! Tests for idempotency keys.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | generated_keys_are_distinct_uuids | generate | each call gives a new UUID v4 string |
+//! | prefixed_keys_keep_their_prefix | generate | `generate_with_prefix` prepends the prefix and a hyphen |
+//! | caller_keys_are_kept_verbatim | new | `new`, `as_str`, `Display`, and `String::from` agree |
+
+#![ cfg( feature = "idempotency" ) ]
+
+use api_common::{ IdempotencyKey, IDEMPOTENCY_KEY_HEADER };
+
+#[ test ]
+fn generated_keys_are_distinct_uuids()
+{
+  let first = IdempotencyKey::generate();
+  let second = IdempotencyKey::generate();
+
+  assert_ne!( first, second );
+  assert_eq!( first.as_str().len(), 36 );
+  assert_eq!( first.as_str().chars().nth( 14 ), Some( '4' ) );
+  assert_eq!( IDEMPOTENCY_KEY_HEADER, "Idempotency-Key" );
+}
+
+#[ test ]
+fn prefixed_keys_keep_their_prefix()
+{
+  let key = IdempotencyKey::generate_with_prefix( "upload" );
+
+  assert!( key.as_str().starts_with( "upload-" ) );
+  assert_eq!( key.as_str().len(), "upload-".len() + 36 );
+}
+
+#[ test ]
+fn caller_keys_are_kept_verbatim()
+{
+  let key = IdempotencyKey::new( "order-1234" );
+
+  assert_eq!( key.as_str(), "order-1234" );
+  assert_eq!( key.to_string(), "order-1234" );
+  assert_eq!( String::from( key.clone() ), "order-1234" );
+  assert_eq!( key, IdempotencyKey::new( String::from( "order-1234" ) ) );
+}
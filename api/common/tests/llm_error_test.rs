@@ -0,0 +1,78 @@
+//! Tests for provider-neutral error classes.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | status_decides_class | status | 401, 429, 503, 529 map to auth, rate limit, overload |
+//! | message_beats_plain_400 | message | context-length and content-filter messages win over the status |
+//! | status_read_from_message | message | `"API error 429"` and transport messages are classified without a status |
+//! | unknown_failure_keeps_raw | fallback | unrecognised errors become `Provider` with the original text |
+//! | retryable_classes | helpers | only rate limits, overloads, and network errors are retryable |
+//! | untyped_error_is_downcast | untyped | a wrapped `LlmError` survives; other errors are classified |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_common::LlmError;
+use core::time::Duration;
+
+#[ test ]
+fn status_decides_class()
+{
+  assert_eq!( LlmError::classify( Some( 401 ), "bad key" ), LlmError::Auth( "bad key".to_string() ) );
+  assert_eq!( LlmError::classify( Some( 429 ), "slow down" ), LlmError::RateLimited { retry_after : None, message : "slow down".to_string() } );
+  assert_eq!( LlmError::classify( Some( 503 ), "try later" ), LlmError::Overloaded( "try later".to_string() ) );
+  assert_eq!( LlmError::classify( Some( 529 ), "busy" ), LlmError::Overloaded( "busy".to_string() ) );
+}
+
+#[ test ]
+fn message_beats_plain_400()
+{
+  let long = "This model's maximum context length is 8192 tokens";
+  let filtered = "The response was filtered due to the prompt triggering content management policy";
+
+  assert!( matches!( LlmError::classify( Some( 400 ), long ), LlmError::ContextTooLong( _ ) ) );
+  assert!( matches!( LlmError::classify( Some( 400 ), filtered ), LlmError::ContentFiltered( _ ) ) );
+  assert!( matches!( LlmError::classify( Some( 413 ), "payload" ), LlmError::ContextTooLong( _ ) ) );
+}
+
+#[ test ]
+fn status_read_from_message()
+{
+  assert!( matches!( LlmError::classify( None, "API error 429: Chat request failed" ), LlmError::RateLimited { .. } ) );
+  assert!( matches!( LlmError::classify( None, "HTTP 401 error : denied" ), LlmError::Auth( _ ) ) );
+  assert!( matches!( LlmError::classify( None, "overloaded_error: Overloaded" ), LlmError::Overloaded( _ ) ) );
+  assert!( matches!( LlmError::classify( None, "Network error : connection refused" ), LlmError::Network( _ ) ) );
+}
+
+#[ test ]
+fn unknown_failure_keeps_raw()
+{
+  let error = LlmError::classify( Some( 500 ), "model produced invalid output" );
+
+  assert_eq!( error, LlmError::Provider { raw : "model produced invalid output".to_string() } );
+  assert!( !error.is_retryable() );
+}
+
+#[ test ]
+fn retryable_classes()
+{
+  let limited = LlmError::RateLimited { retry_after : Some( Duration::from_secs( 7 ) ), message : String::new() };
+
+  assert!( limited.is_retryable() );
+  assert_eq!( limited.retry_after(), Some( Duration::from_secs( 7 ) ) );
+  assert!( LlmError::Network( String::new() ).is_retryable() );
+  assert!( !LlmError::ContextTooLong( String::new() ).is_retryable() );
+  assert!( !LlmError::Protocol( String::new() ).is_retryable() );
+  assert_eq!( LlmError::Auth( String::new() ).retry_after(), None );
+}
+
+#[ test ]
+fn untyped_error_is_downcast()
+{
+  let wrapped = error_tools::untyped::Error::from( LlmError::Protocol( "truncated stream".to_string() ) );
+  let plain = error_tools::untyped::format_err!( "Too many requests" );
+
+  assert_eq!( LlmError::from_untyped( &wrapped ), LlmError::Protocol( "truncated stream".to_string() ) );
+  assert!( matches!( LlmError::from_untyped( &plain ), LlmError::RateLimited { .. } ) );
+}
@@ -0,0 +1,229 @@
+//! Tests for prompt templates, partials, few-shot blocks, and the
+//! `PromptVariables` derive.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | template_substitutes_variables | render | `{{name}}` substitution |
+//! | template_ignores_whitespace_and_single_braces | parse | `{{ name }}` and literal `{` |
+//! | template_lists_variables_and_partials_once | parse | `variables()` / `partials()` dedup |
+//! | template_rejects_unclosed_placeholder | parse | `TemplateSyntax` with position |
+//! | template_rejects_invalid_name | parse | `TemplateSyntax` for bad names |
+//! | missing_variable_is_reported_by_name | render | `MissingVariable` |
+//! | partial_is_rendered_with_shared_context | render | `{{> partial}}` expansion |
+//! | missing_partial_is_reported_by_name | render | `MissingPartial` |
+//! | cyclic_partials_hit_depth_limit | render | `PartialDepthExceeded` |
+//! | chat_prompt_orders_system_examples_user | chat | message ordering and roles |
+//! | chat_prompt_examples_are_literal | chat | examples skip substitution |
+//! | derive_exposes_names_and_values | derive | `NAMES` and `values()` |
+//! | derive_with_template_renders_typed_prompt | derive | `TypedPrompt::render` |
+//! | derive_supports_generic_structs | derive | generics pass through |
+
+#![ cfg( feature = "prompt" ) ]
+
+use api_common::
+{
+  ChatPrompt,
+  CommonError,
+  FewShotExample,
+  NeutralRole,
+  PromptContext,
+  PromptTemplate,
+  PromptVariables,
+  TypedPrompt,
+};
+
+fn common_error( err : &error_tools::untyped::Error ) -> &CommonError
+{
+  err.downcast_ref::< CommonError >().expect( "error must be a CommonError" )
+}
+
+// ------------------------------------------------------------------ //
+//  Parsing and rendering
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn template_substitutes_variables()
+{
+  let template = PromptTemplate::parse( "Hello {{name}}, you are {{age}}." ).unwrap();
+  let context = PromptContext::new()
+    .with_variable( "name", "Ada" )
+    .with_variable( "age", "36" );
+
+  assert_eq!( template.render( &context ).unwrap(), "Hello Ada, you are 36." );
+}
+
+#[ test ]
+fn template_ignores_whitespace_and_single_braces()
+{
+  let template = PromptTemplate::parse( r#"Reply as {"answer": "{{ answer }}"}"# ).unwrap();
+  let context = PromptContext::new().with_variable( "answer", "42" );
+
+  assert_eq!( template.render( &context ).unwrap(), r#"Reply as {"answer": "42"}"# );
+}
+
+#[ test ]
+fn template_lists_variables_and_partials_once()
+{
+  let template = PromptTemplate::parse( "{{a}} {{> head}} {{b}} {{a}} {{> head}}" ).unwrap();
+
+  assert_eq!( template.variables(), vec![ "a", "b" ] );
+  assert_eq!( template.partials(), vec![ "head" ] );
+}
+
+#[ test ]
+fn template_rejects_unclosed_placeholder()
+{
+  let err = PromptTemplate::parse( "ok {{name" ).unwrap_err();
+
+  match common_error( &err )
+  {
+    CommonError::TemplateSyntax { position, .. } => assert_eq!( *position, 3 ),
+    other => panic!( "expected TemplateSyntax, got {other:?}" ),
+  }
+}
+
+#[ test ]
+fn template_rejects_invalid_name()
+{
+  let err = PromptTemplate::parse( "{{1st}}" ).unwrap_err();
+
+  assert!( matches!( common_error( &err ), CommonError::TemplateSyntax { .. } ) );
+}
+
+#[ test ]
+fn missing_variable_is_reported_by_name()
+{
+  let template = PromptTemplate::parse( "{{present}} {{absent}}" ).unwrap();
+  let err = template.render( &PromptContext::new().with_variable( "present", "x" ) ).unwrap_err();
+
+  assert_eq!( common_error( &err ), &CommonError::MissingVariable( "absent".to_owned() ) );
+}
+
+#[ test ]
+fn partial_is_rendered_with_shared_context()
+{
+  let template = PromptTemplate::parse( "{{> greeting}} Task: {{task}}" ).unwrap();
+  let context = PromptContext::new()
+    .with_partial( "greeting", PromptTemplate::parse( "Hi {{name}}." ).unwrap() )
+    .with_variable( "name", "Bob" )
+    .with_variable( "task", "sort" );
+
+  assert_eq!( template.render( &context ).unwrap(), "Hi Bob. Task: sort" );
+}
+
+#[ test ]
+fn missing_partial_is_reported_by_name()
+{
+  let template = PromptTemplate::parse( "{{> footer}}" ).unwrap();
+  let err = template.render( &PromptContext::new() ).unwrap_err();
+
+  assert_eq!( common_error( &err ), &CommonError::MissingPartial( "footer".to_owned() ) );
+}
+
+#[ test ]
+fn cyclic_partials_hit_depth_limit()
+{
+  let template = PromptTemplate::parse( "{{> a}}" ).unwrap();
+  let context = PromptContext::new()
+    .with_partial( "a", PromptTemplate::parse( "{{> b}}" ).unwrap() )
+    .with_partial( "b", PromptTemplate::parse( "{{> a}}" ).unwrap() );
+  let err = template.render( &context ).unwrap_err();
+
+  assert!( matches!( common_error( &err ), CommonError::PartialDepthExceeded( _ ) ) );
+}
+
+// ------------------------------------------------------------------ //
+//  Chat prompts
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn chat_prompt_orders_system_examples_user()
+{
+  let prompt = ChatPrompt::new( PromptTemplate::parse( "Q: {{q}}" ).unwrap() )
+    .with_system( PromptTemplate::parse( "Answer tersely." ).unwrap() )
+    .with_examples( vec!
+    [
+      FewShotExample::new( "Q: 1+1", "2" ),
+      FewShotExample::new( "Q: 2+2", "4" ),
+    ]);
+  let messages = prompt.render( &PromptContext::new().with_variable( "q", "3+3" ) ).unwrap();
+
+  let roles : Vec< NeutralRole > = messages.iter().map( | m | m.role ).collect();
+  assert_eq!
+  (
+    roles,
+    vec!
+    [
+      NeutralRole::System,
+      NeutralRole::User, NeutralRole::Assistant,
+      NeutralRole::User, NeutralRole::Assistant,
+      NeutralRole::User,
+    ],
+  );
+  assert_eq!( messages[ 0 ].content, "Answer tersely." );
+  assert_eq!( messages[ 5 ].content, "Q: 3+3" );
+}
+
+#[ test ]
+fn chat_prompt_examples_are_literal()
+{
+  let prompt = ChatPrompt::new( PromptTemplate::parse( "end" ).unwrap() )
+    .with_example( FewShotExample::new( "uses {{braces}}", "fine" ) );
+  let messages = prompt.render( &PromptContext::new() ).unwrap();
+
+  assert_eq!( messages[ 0 ].content, "uses {{braces}}" );
+}
+
+// ------------------------------------------------------------------ //
+//  Derive
+// ------------------------------------------------------------------ //
+
+#[ derive( PromptVariables ) ]
+struct Plain
+{
+  topic : String,
+  count : u32,
+}
+
+#[ derive( PromptVariables ) ]
+#[ prompt( template = "Write {{count}} facts about {{topic}}." ) ]
+struct Facts
+{
+  topic : String,
+  count : u32,
+}
+
+#[ derive( PromptVariables ) ]
+#[ prompt( template = "Value: {{value}}" ) ]
+struct Wrapper< T : core::fmt::Display >
+{
+  value : T,
+}
+
+#[ test ]
+fn derive_exposes_names_and_values()
+{
+  let plain = Plain { topic : "owls".to_owned(), count : 3 };
+
+  assert_eq!( Plain::NAMES, &[ "topic", "count" ] );
+  assert_eq!( plain.values(), vec![ ( "topic", "owls".to_owned() ), ( "count", "3".to_owned() ) ] );
+}
+
+#[ test ]
+fn derive_with_template_renders_typed_prompt()
+{
+  let facts = Facts { topic : "owls".to_owned(), count : 3 };
+
+  assert_eq!( Facts::TEMPLATE, "Write {{count}} facts about {{topic}}." );
+  assert_eq!( facts.render().unwrap(), "Write 3 facts about owls." );
+}
+
+#[ test ]
+fn derive_supports_generic_structs()
+{
+  let wrapped = Wrapper { value : 2.5_f64 };
+
+  assert_eq!( wrapped.render().unwrap(), "Value: 2.5" );
+}
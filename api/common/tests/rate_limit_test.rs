@@ -0,0 +1,101 @@
+//! Tests for the process-wide shared rate limiter.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | clones_draw_from_same_buckets | sharing | a request through one clone is gone for the other |
+//! | named_returns_registered_buckets | sharing | same name yields same buckets; first limits win |
+//! | try_acquire_is_all_or_nothing | buckets | a short token bucket leaves the request bucket untouched |
+//! | recorded_tokens_create_deficit | buckets | post-paid usage blocks until refilled; refund restores |
+//! | acquire_waits_for_refill | timer | `acquire` sleeps until the next request is admitted |
+//! | acquire_rejects_unsatisfiable_cost | errors | tokens above the quota fail instead of waiting forever |
+
+#![ cfg( all( feature = "rate_limit", feature = "tokio" ) ) ]
+
+use api_common::{ CommonError, RateLimited, RateLimits, SharedRateLimiter };
+use core::time::Duration;
+use std::time::Instant;
+
+#[ test ]
+fn clones_draw_from_same_buckets()
+{
+  let first = SharedRateLimiter::new( RateLimits::new().with_requests( 2, Duration::from_secs( 3600 ) ) );
+  let second = first.clone();
+
+  assert!( first.try_acquire( 0 ).is_ok() );
+  assert!( second.try_acquire( 0 ).is_ok() );
+
+  assert!( first.try_acquire( 0 ).is_err() );
+  assert!( second.try_acquire( 0 ).is_err() );
+  assert_eq!( second.available_requests(), Some( 0 ) );
+}
+
+#[ test ]
+fn named_returns_registered_buckets()
+{
+  assert!( SharedRateLimiter::lookup( "named-test" ).is_none() );
+
+  let limits = RateLimits::new().with_requests_per_minute( 10 );
+  let first = SharedRateLimiter::named( "named-test", limits );
+  let second = SharedRateLimiter::named( "named-test", RateLimits::new().with_requests_per_minute( 99 ) );
+
+  assert!( first.shares_buckets_with( &second ) );
+  assert_eq!( second.limits(), limits );
+  assert!( SharedRateLimiter::lookup( "named-test" ).is_some_and( | found | found.shares_buckets_with( &first ) ) );
+  assert!( !first.shares_buckets_with( &SharedRateLimiter::new( limits ) ) );
+}
+
+#[ test ]
+fn try_acquire_is_all_or_nothing()
+{
+  let hour = Duration::from_secs( 3600 );
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_requests( 5, hour ).with_tokens( 100, hour ) );
+
+  assert!( limiter.try_acquire( 80 ).is_ok() );
+  let Err( RateLimited { retry_after } ) = limiter.try_acquire( 30 ) else { panic!( "token bucket should be short" ) };
+
+  assert!( retry_after > Duration::from_secs( 300 ) );
+  assert_eq!( limiter.available_requests(), Some( 4 ) );
+  assert_eq!( limiter.available_tokens(), Some( 20 ) );
+}
+
+#[ test ]
+fn recorded_tokens_create_deficit()
+{
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_tokens( 100, Duration::from_secs( 3600 ) ) );
+
+  limiter.record_tokens( 150 );
+  assert_eq!( limiter.available_tokens(), Some( -50 ) );
+  assert!( limiter.try_acquire( 0 ).is_err() );
+
+  limiter.refund_tokens( 60 );
+  assert_eq!( limiter.available_tokens(), Some( 10 ) );
+  assert!( limiter.try_acquire( 0 ).is_ok() );
+
+  limiter.refund_tokens( 1_000 );
+  assert_eq!( limiter.available_tokens(), Some( 100 ) );
+}
+
+#[ tokio::test ]
+async fn acquire_waits_for_refill()
+{
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_requests( 1, Duration::from_millis( 100 ) ) );
+  limiter.acquire( 0 ).await.unwrap();
+
+  let start = Instant::now();
+  limiter.acquire( 0 ).await.unwrap();
+
+  assert!( start.elapsed() >= Duration::from_millis( 90 ) );
+}
+
+#[ tokio::test ]
+async fn acquire_rejects_unsatisfiable_cost()
+{
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_tokens_per_minute( 1_000 ) );
+
+  let error = limiter.acquire( 1_001 ).await.unwrap_err();
+
+  assert!( matches!( error.downcast_ref::< CommonError >(), Some( CommonError::InvalidArgument( _ ) ) ) );
+  assert_eq!( limiter.available_tokens(), Some( 1_000 ) );
+}
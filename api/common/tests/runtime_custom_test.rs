@@ -0,0 +1,50 @@
+//! Tests for installing a caller-supplied runtime.
+//!
+//! Runs in its own binary so the process-wide runtime is installed here
+//! before anything else uses it.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | installed_runtime_receives_spawn_and_sleep | install | `spawn`, `sleep`, and `timeout` go through the installed runtime |
+
+#![ cfg( feature = "runtime" ) ]
+
+use api_common::{ set_runtime, sleep, spawn, timeout, BoxFuture, Runtime };
+use core::time::Duration;
+use core::sync::atomic::{ AtomicUsize, Ordering };
+
+static SPAWNS : AtomicUsize = AtomicUsize::new( 0 );
+static SLEEPS : AtomicUsize = AtomicUsize::new( 0 );
+
+/// Counts calls, then delegates to tokio (standing in for smol, async-std, …).
+struct CountingRuntime;
+
+impl Runtime for CountingRuntime
+{
+  fn spawn( &self, task : BoxFuture< 'static, () > )
+  {
+    SPAWNS.fetch_add( 1, Ordering::SeqCst );
+    drop( tokio::spawn( task ) );
+  }
+
+  fn sleep( &self, duration : Duration ) -> BoxFuture< 'static, () >
+  {
+    SLEEPS.fetch_add( 1, Ordering::SeqCst );
+    Box::pin( tokio::time::sleep( duration ) )
+  }
+}
+
+#[ tokio::test ]
+async fn installed_runtime_receives_spawn_and_sleep()
+{
+  set_runtime( CountingRuntime ).unwrap();
+
+  assert_eq!( spawn( async { 1 } ).await, Ok( 1 ) );
+  sleep( Duration::from_millis( 1 ) ).await;
+  let _ = timeout( Duration::from_secs( 5 ), async {} ).await;
+
+  assert_eq!( SPAWNS.load( Ordering::SeqCst ), 1 );
+  assert_eq!( SLEEPS.load( Ordering::SeqCst ), 2 );
+}
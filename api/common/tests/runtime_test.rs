@@ -0,0 +1,74 @@
+//! Tests for the default async runtime abstraction.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | spawn_returns_output | spawn | awaiting a `JoinHandle` yields the task output |
+//! | abort_yields_join_error | spawn | aborted task resolves to `JoinError` |
+//! | sleep_waits_at_least_duration | timer | `sleep` does not complete early |
+//! | timeout_returns_output_in_time | timer | fast future completes before the deadline |
+//! | timeout_elapses_for_slow_future | timer | slow future yields `Elapsed` |
+//! | runtime_cannot_be_replaced_after_use | install | `set_runtime` after first use fails |
+
+#![ cfg( all( feature = "runtime", feature = "tokio" ) ) ]
+
+use api_common::{ runtime, set_runtime, sleep, spawn, timeout, CommonError, Elapsed, JoinError, TokioRuntime };
+use core::time::Duration;
+use std::time::Instant;
+
+#[ tokio::test ]
+async fn spawn_returns_output()
+{
+  let handle = spawn( async { 6 * 7 } );
+
+  assert_eq!( handle.await, Ok( 42 ) );
+}
+
+#[ tokio::test ]
+async fn abort_yields_join_error()
+{
+  let handle = spawn( async
+  {
+    sleep( Duration::from_secs( 60 ) ).await;
+    1
+  } );
+  handle.abort();
+
+  assert_eq!( handle.await, Err( JoinError ) );
+}
+
+#[ tokio::test ]
+async fn sleep_waits_at_least_duration()
+{
+  let start = Instant::now();
+  sleep( Duration::from_millis( 20 ) ).await;
+
+  assert!( start.elapsed() >= Duration::from_millis( 20 ) );
+}
+
+#[ tokio::test ]
+async fn timeout_returns_output_in_time()
+{
+  let result = timeout( Duration::from_secs( 5 ), async { "done" } ).await;
+
+  assert_eq!( result, Ok( "done" ) );
+}
+
+#[ tokio::test ]
+async fn timeout_elapses_for_slow_future()
+{
+  let deadline = Duration::from_millis( 10 );
+  let result = timeout( deadline, sleep( Duration::from_secs( 60 ) ) ).await;
+
+  assert_eq!( result, Err( Elapsed( deadline ) ) );
+}
+
+#[ test ]
+fn runtime_cannot_be_replaced_after_use()
+{
+  let _ = runtime();
+  let err = set_runtime( TokioRuntime ).unwrap_err();
+
+  assert_eq!( err.downcast_ref::< CommonError >(), Some( &CommonError::RuntimeAlreadySet ) );
+}
@@ -0,0 +1,228 @@
+//! Tests for structured output validation.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | matching_output_passes | valid | a value matching every keyword has no violations |
+//! | violations_carry_json_pointer_paths | paths | nested fields and array items report their pointer, `~` and `/` escaped |
+//! | every_violation_is_reported | collect | missing, extra, wrong-type, and out-of-enum fields all appear, in order |
+//! | wrong_type_skips_other_keywords | type | a type mismatch is reported alone |
+//! | numbers_strings_and_arrays_bounds | bounds | min/max, exclusive bounds, `multipleOf`, lengths, item counts, uniqueness |
+//! | integer_accepts_whole_floats | type | `1.0` is an integer; `1.5` is not; integers are numbers |
+//! | refs_resolve_against_root | ref | `$defs` references, recursive schemas, unresolvable references |
+//! | combinators | combinators | `anyOf`, `oneOf`, `allOf`, `not`, nullable, and boolean schemas |
+//! | parse_distinguishes_failures | parse | invalid JSON, schema violations, and type mismatches are separate errors |
+//! | schema_error_is_protocol | llm_error | `LlmError::from` and `from_untyped` give `Protocol` |
+
+#![ cfg( feature = "schema" ) ]
+
+use api_common::{ LlmError, ResponseSchema, SchemaError, SchemaViolation };
+use serde_json::{ json, Value };
+
+fn person() -> ResponseSchema
+{
+  ResponseSchema::new( json!(
+  {
+    "type" : "object",
+    "properties" :
+    {
+      "name" : { "type" : "string" },
+      "age" : { "type" : "integer" },
+      "role" : { "enum" : [ "admin", "user" ] },
+      "tags" : { "type" : "array", "items" : { "type" : "string" } },
+    },
+    "required" : [ "name", "age" ],
+    "additionalProperties" : false,
+  } ) )
+}
+
+fn paths( schema : &ResponseSchema, instance : &Value ) -> Vec< ( String, String ) >
+{
+  schema.violations( instance ).into_iter().map( | v | ( v.path, v.keyword ) ).collect()
+}
+
+#[ test ]
+fn matching_output_passes()
+{
+  let output = json!( { "name" : "Ada", "age" : 36, "role" : "admin", "tags" : [ "math" ] } );
+
+  assert_eq!( person().validate( &output ), Ok( () ) );
+}
+
+#[ test ]
+fn violations_carry_json_pointer_paths()
+{
+  let schema = ResponseSchema::new( json!(
+  {
+    "type" : "object",
+    "properties" :
+    {
+      "people" : { "type" : "array", "items" : { "$ref" : "#/$defs/person" } },
+      "a/b~c" : { "type" : "string" },
+    },
+    "$defs" : { "person" : person().schema().clone() },
+  } ) );
+  let output = json!( { "people" : [ { "name" : "Ada", "age" : 36 }, { "name" : "Bob", "age" : "old" } ], "a/b~c" : 1 } );
+
+  assert_eq!
+  (
+    paths( &schema, &output ),
+    [
+      ( "/a~1b~0c".to_string(), "type".to_string() ),
+      ( "/people/1/age".to_string(), "type".to_string() ),
+    ]
+  );
+}
+
+#[ test ]
+fn every_violation_is_reported()
+{
+  let output = json!( { "age" : 1.5, "role" : "owner", "extra" : true } );
+
+  let error = person().validate( &output ).unwrap_err();
+
+  assert_eq!
+  (
+    error.violations(),
+    [
+      SchemaViolation { path : String::new(), keyword : "required".into(), message : "missing required property \"name\"".into() },
+      SchemaViolation { path : "/age".into(), keyword : "type".into(), message : "expected integer, found number".into() },
+      SchemaViolation { path : "/extra".into(), keyword : "additionalProperties".into(), message : "property is not allowed".into() },
+      SchemaViolation { path : "/role".into(), keyword : "enum".into(), message : "\"owner\" is not one of [\"admin\",\"user\"]".into() },
+    ]
+  );
+  assert_eq!( error.to_string(), "Output does not match the schema : / : missing required property \"name\" (and 3 more)" );
+}
+
+#[ test ]
+fn wrong_type_skips_other_keywords()
+{
+  let schema = ResponseSchema::new( json!( { "type" : "string", "enum" : [ "a" ], "minLength" : 3 } ) );
+
+  assert_eq!( paths( &schema, &json!( 7 ) ), [ ( String::new(), "type".to_string() ) ] );
+  assert_eq!( paths( &schema, &json!( "b" ) ), [ ( String::new(), "enum".to_string() ), ( String::new(), "minLength".to_string() ) ] );
+}
+
+#[ test ]
+fn numbers_strings_and_arrays_bounds()
+{
+  let number = ResponseSchema::new( json!( { "minimum" : 1, "exclusiveMaximum" : 10, "multipleOf" : 0.5 } ) );
+  assert!( number.violations( &json!( 9.5 ) ).is_empty() );
+  assert_eq!( paths( &number, &json!( 0.25 ) ).len(), 2 );
+  assert_eq!( paths( &number, &json!( 10 ) ), [ ( String::new(), "exclusiveMaximum".to_string() ) ] );
+
+  let text = ResponseSchema::new( json!( { "minLength" : 2, "maxLength" : 3 } ) );
+  assert!( text.violations( &json!( "héé" ) ).is_empty() );
+  assert_eq!( paths( &text, &json!( "abcd" ) ), [ ( String::new(), "maxLength".to_string() ) ] );
+
+  let list = ResponseSchema::new( json!( { "minItems" : 2, "maxItems" : 3, "uniqueItems" : true, "prefixItems" : [ { "type" : "integer" } ], "items" : { "type" : "string" } } ) );
+  assert!( list.violations( &json!( [ 1, "a", "b" ] ) ).is_empty() );
+  assert_eq!
+  (
+    paths( &list, &json!( [ "x", "a", "a", "b" ] ) ),
+    [
+      ( "/0".to_string(), "type".to_string() ),
+      ( String::new(), "maxItems".to_string() ),
+      ( String::new(), "uniqueItems".to_string() ),
+    ]
+  );
+}
+
+#[ test ]
+fn integer_accepts_whole_floats()
+{
+  let integer = ResponseSchema::new( json!( { "type" : "integer" } ) );
+  let number = ResponseSchema::new( json!( { "type" : [ "number", "null" ] } ) );
+
+  assert!( integer.validate( &json!( 1.0 ) ).is_ok() );
+  assert!( integer.validate( &json!( 1.5 ) ).is_err() );
+  assert!( number.validate( &json!( 3 ) ).is_ok() );
+  assert!( number.validate( &Value::Null ).is_ok() );
+  assert_eq!( number.violations( &json!( "3" ) )[ 0 ].message, "expected number or null, found string" );
+}
+
+#[ test ]
+fn refs_resolve_against_root()
+{
+  let tree = ResponseSchema::new( json!(
+  {
+    "$ref" : "#/$defs/node",
+    "$defs" :
+    {
+      "node" :
+      {
+        "type" : "object",
+        "properties" : { "value" : { "type" : "integer" }, "children" : { "type" : "array", "items" : { "$ref" : "#/$defs/node" } } },
+        "required" : [ "value" ],
+      },
+    },
+  } ) );
+  let output = json!( { "value" : 1, "children" : [ { "value" : 2, "children" : [ { "value" : "three" } ] } ] } );
+
+  assert_eq!( paths( &tree, &output ), [ ( "/children/0/children/0/value".to_string(), "type".to_string() ) ] );
+
+  let broken = ResponseSchema::new( json!( { "$ref" : "#/$defs/missing" } ) );
+  assert_eq!( broken.violations( &json!( 1 ) )[ 0 ].message, "cannot resolve #/$defs/missing" );
+
+  let cycle = ResponseSchema::new( json!( { "$ref" : "#" } ) );
+  assert_eq!( cycle.violations( &json!( 1 ) )[ 0 ].message, "schema nests too deeply" );
+}
+
+#[ test ]
+fn combinators()
+{
+  let any = ResponseSchema::new( json!( { "anyOf" : [ { "type" : "string" }, { "type" : "integer" } ] } ) );
+  assert!( any.validate( &json!( 3 ) ).is_ok() );
+  assert_eq!( paths( &any, &json!( true ) ), [ ( String::new(), "anyOf".to_string() ) ] );
+
+  let one = ResponseSchema::new( json!( { "oneOf" : [ { "type" : "number" }, { "type" : "integer" } ] } ) );
+  assert!( one.validate( &json!( 1.5 ) ).is_ok() );
+  assert_eq!( one.violations( &json!( 1 ) )[ 0 ].message, "value matches 2 of the schemas instead of exactly one" );
+
+  let all = ResponseSchema::new( json!( { "allOf" : [ { "minimum" : 0 }, { "maximum" : 5 } ], "not" : { "const" : 3 } } ) );
+  assert!( all.validate( &json!( 4 ) ).is_ok() );
+  assert_eq!( paths( &all, &json!( 9 ) ), [ ( String::new(), "maximum".to_string() ) ] );
+  assert_eq!( paths( &all, &json!( 3 ) ), [ ( String::new(), "not".to_string() ) ] );
+
+  let nullable = ResponseSchema::new( json!( { "type" : "string", "nullable" : true } ) );
+  assert!( nullable.validate( &Value::Null ).is_ok() );
+
+  let open = ResponseSchema::new( json!( { "properties" : { "a" : true, "b" : false } } ) );
+  assert!( open.validate( &json!( { "a" : [ 1 ], "c" : 2 } ) ).is_ok() );
+  assert_eq!( paths( &open, &json!( { "b" : 1 } ) ), [ ( "/b".to_string(), "false".to_string() ) ] );
+}
+
+#[ test ]
+fn parse_distinguishes_failures()
+{
+  #[ derive( Debug, PartialEq, serde::Deserialize ) ]
+  struct Person
+  {
+    name : String,
+    age : u8,
+  }
+
+  let parsed : Person = person().parse( r#"{ "name" : "Ada", "age" : 36 }"# ).unwrap();
+  assert_eq!( parsed, Person { name : "Ada".into(), age : 36 } );
+
+  let error = person().parse::< Person >( "{ \"name\" : " ).unwrap_err();
+  assert!( matches!( error, SchemaError::InvalidJson( _ ) ) );
+
+  let error = person().parse::< Person >( r#"{ "name" : "Ada" }"# ).unwrap_err();
+  assert_eq!( error.violations().len(), 1 );
+
+  let error = person().parse::< Person >( r#"{ "name" : "Ada", "age" : 300 }"# ).unwrap_err();
+  assert!( matches!( error, SchemaError::Deserialize( _ ) ) );
+  assert!( error.violations().is_empty() );
+}
+
+#[ test ]
+fn schema_error_is_protocol()
+{
+  let error = person().validate( &json!( { "name" : "Ada" } ) ).unwrap_err();
+  let expected = LlmError::Protocol( "Output does not match the schema : / : missing required property \"age\"".into() );
+
+  assert_eq!( LlmError::from( error.clone() ), expected );
+  assert_eq!( LlmError::from_untyped( &error_tools::untyped::Error::from( error ) ), expected );
+}
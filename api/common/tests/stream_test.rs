@@ -0,0 +1,120 @@
+//! Tests for the `TextStream` adapter.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | events_are_flattened_in_order | adapt | several events per chunk, order kept |
+//! | empty_chunks_are_skipped | adapt | chunks without events yield nothing |
+//! | transport_error_is_passed_through | error | inner `Err` items surface as `Err` |
+//! | chunk_error_is_passed_through | error | `IntoStreamEvents` failure surfaces as `Err` |
+//! | no_done_is_synthesised | adapt | stream end without provider `Done` |
+
+#![ cfg( feature = "streaming" ) ]
+
+use api_common::{ IntoStreamEvents, StreamEvent, TextStream, ToolCallDelta, UsageUpdate };
+use futures_util::{ stream, StreamExt };
+
+/// Test chunk: either a list of events or a provider-reported error.
+enum Chunk
+{
+  Events( Vec< StreamEvent > ),
+  Failed,
+}
+
+impl IntoStreamEvents for Chunk
+{
+  fn into_stream_events( self ) -> api_common::Result< Vec< StreamEvent > >
+  {
+    match self
+    {
+      Chunk::Events( events ) => Ok( events ),
+      Chunk::Failed => Err( error_tools::untyped::format_err!( "overloaded" ) ),
+    }
+  }
+}
+
+fn text( s : &str ) -> StreamEvent
+{
+  StreamEvent::TextDelta( s.to_owned() )
+}
+
+async fn collect< S >( s : S ) -> Vec< Result< StreamEvent, String > >
+where
+  S : futures_util::Stream< Item = api_common::Result< StreamEvent > >,
+{
+  s.map( | item | item.map_err( | e | e.to_string() ) ).collect().await
+}
+
+#[ tokio::test ]
+async fn events_are_flattened_in_order()
+{
+  let usage = UsageUpdate { input_tokens : Some( 3 ), output_tokens : Some( 2 ) };
+  let tool = ToolCallDelta { index : 0, id : Some( "c1".to_owned() ), name : Some( "f".to_owned() ), arguments : String::new() };
+  let chunks = vec!
+  [
+    Ok::< _, std::io::Error >( Chunk::Events( vec![ text( "Hel" ), text( "lo" ) ] ) ),
+    Ok( Chunk::Events( vec![ StreamEvent::ToolCallDelta( tool.clone() ) ] ) ),
+    Ok( Chunk::Events( vec![ StreamEvent::UsageUpdate( usage ), StreamEvent::Done { finish_reason : Some( "stop".to_owned() ) } ] ) ),
+  ];
+  let events = collect( TextStream::new( stream::iter( chunks ) ) ).await;
+
+  assert_eq!
+  (
+    events,
+    vec!
+    [
+      Ok( text( "Hel" ) ),
+      Ok( text( "lo" ) ),
+      Ok( StreamEvent::ToolCallDelta( tool ) ),
+      Ok( StreamEvent::UsageUpdate( usage ) ),
+      Ok( StreamEvent::Done { finish_reason : Some( "stop".to_owned() ) } ),
+    ],
+  );
+}
+
+#[ tokio::test ]
+async fn empty_chunks_are_skipped()
+{
+  let chunks = vec!
+  [
+    Ok::< _, std::io::Error >( Chunk::Events( Vec::new() ) ),
+    Ok( Chunk::Events( vec![ text( "x" ) ] ) ),
+    Ok( Chunk::Events( Vec::new() ) ),
+  ];
+  let events = collect( TextStream::new( stream::iter( chunks ) ) ).await;
+
+  assert_eq!( events, vec![ Ok( text( "x" ) ) ] );
+}
+
+#[ tokio::test ]
+async fn transport_error_is_passed_through()
+{
+  let chunks = vec!
+  [
+    Ok( Chunk::Events( vec![ text( "a" ) ] ) ),
+    Err( std::io::Error::other( "connection reset" ) ),
+    Ok( Chunk::Events( vec![ text( "b" ) ] ) ),
+  ];
+  let events = collect( TextStream::new( stream::iter( chunks ) ) ).await;
+
+  assert_eq!( events, vec![ Ok( text( "a" ) ), Err( "connection reset".to_owned() ), Ok( text( "b" ) ) ] );
+}
+
+#[ tokio::test ]
+async fn chunk_error_is_passed_through()
+{
+  let chunks = vec![ Ok::< _, std::io::Error >( Chunk::Failed ) ];
+  let events = collect( TextStream::new( stream::iter( chunks ) ) ).await;
+
+  assert_eq!( events, vec![ Err( "overloaded".to_owned() ) ] );
+}
+
+#[ tokio::test ]
+async fn no_done_is_synthesised()
+{
+  let chunks = vec![ Ok::< _, std::io::Error >( Chunk::Events( vec![ text( "cut" ) ] ) ) ];
+  let events = collect( TextStream::new( stream::iter( chunks ) ) ).await;
+
+  assert_eq!( events, vec![ Ok( text( "cut" ) ) ] );
+}
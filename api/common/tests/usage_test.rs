@@ -0,0 +1,147 @@
+//! Tests for usage aggregation.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | token_usage_sums_saturate | usage | addition, totals, and `Sum` over calls, cached and reasoning tokens included |
+//! | summarize_groups_by_provider_and_model | group | one summary per provider and model, in key order |
+//! | summarize_without_groups_sums_everything | group | an empty grouping yields one total |
+//! | untagged_records_form_their_own_group | group | tag grouping keeps untagged calls apart |
+//! | window_excludes_records_outside | window | `from` inclusive, `until` exclusive |
+//! | prune_before_drops_old_records | window | records older than the cut-off are removed |
+//! | to_json_reports_window_and_groups | export | window bounds as Unix seconds, one object per group |
+//! | to_csv_quotes_fields | export | header, one line per group, quoted commas |
+
+#![ cfg( feature = "usage" ) ]
+
+use api_common::{ TokenUsage, UsageGroup, UsageRecord, UsageReport, UsageWindow };
+use core::time::Duration;
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+fn at( seconds : u64 ) -> SystemTime
+{
+  UNIX_EPOCH + Duration::from_secs( seconds )
+}
+
+/// Three calls: two on `openai`, one on `claude`, at seconds 100, 200, 300.
+fn report() -> UsageReport
+{
+  let mut report = UsageReport::new();
+  report.push( UsageRecord::new( "openai", "gpt-4o", TokenUsage::new( 10, 5 ) ).with_tag( "search" ).with_time( at( 100 ) ) );
+  report.push( UsageRecord::new( "openai", "gpt-4o", TokenUsage::new( 20, 10 ).with_cached_input_tokens( 8 ).with_reasoning_tokens( 4 ) ).with_time( at( 200 ) ) );
+  report.push( UsageRecord::new( "claude", "claude-sonnet-4-6", TokenUsage::new( 7, 3 ) ).with_tag( "search" ).with_time( at( 300 ) ) );
+  report
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn token_usage_sums_saturate()
+{
+  let total : TokenUsage =
+  [
+    TokenUsage::new( 1, 2 ).with_reasoning_tokens( 1 ),
+    TokenUsage::new( 3, 4 ).with_cached_input_tokens( 1 ).with_reasoning_tokens( 3 ),
+  ].into_iter().sum();
+
+  assert_eq!( total, TokenUsage::new( 4, 6 ).with_cached_input_tokens( 1 ).with_reasoning_tokens( 4 ) );
+  assert_eq!( total.total_tokens(), 10 );
+  assert_eq!( ( TokenUsage::new( u64::MAX, 1 ) + TokenUsage::new( 1, 1 ) ).input_tokens, u64::MAX );
+}
+
+#[ test ]
+fn summarize_groups_by_provider_and_model()
+{
+  let summaries = report().summarize( &[ UsageGroup::Provider, UsageGroup::Model ], UsageWindow::all() );
+
+  assert_eq!( summaries.len(), 2 );
+  assert_eq!( summaries[ 0 ].provider.as_deref(), Some( "claude" ) );
+  assert_eq!( summaries[ 0 ].requests, 1 );
+  assert_eq!( summaries[ 1 ].provider.as_deref(), Some( "openai" ) );
+  assert_eq!( summaries[ 1 ].model.as_deref(), Some( "gpt-4o" ) );
+  assert_eq!( summaries[ 1 ].tag, None );
+  assert_eq!( summaries[ 1 ].requests, 2 );
+  assert_eq!( summaries[ 1 ].usage, TokenUsage::new( 30, 15 ).with_cached_input_tokens( 8 ).with_reasoning_tokens( 4 ) );
+}
+
+#[ test ]
+fn summarize_without_groups_sums_everything()
+{
+  let summaries = report().summarize( &[], UsageWindow::all() );
+
+  assert_eq!( summaries.len(), 1 );
+  assert_eq!( summaries[ 0 ].provider, None );
+  assert_eq!( summaries[ 0 ].requests, 3 );
+  assert_eq!( summaries[ 0 ].usage.total_tokens(), 55 );
+  assert!( UsageReport::new().summarize( &[], UsageWindow::all() ).is_empty() );
+}
+
+#[ test ]
+fn untagged_records_form_their_own_group()
+{
+  let summaries = report().summarize( &[ UsageGroup::Tag ], UsageWindow::all() );
+
+  assert_eq!( summaries.len(), 2 );
+  assert_eq!( summaries[ 0 ].tag, None );
+  assert_eq!( summaries[ 0 ].requests, 1 );
+  assert_eq!( summaries[ 1 ].tag.as_deref(), Some( "search" ) );
+  assert_eq!( summaries[ 1 ].usage, TokenUsage::new( 17, 8 ) );
+}
+
+#[ test ]
+fn window_excludes_records_outside()
+{
+  let summaries = report().summarize( &[], UsageWindow::between( at( 100 ), at( 300 ) ) );
+  assert_eq!( summaries[ 0 ].requests, 2 );
+
+  let summaries = report().summarize( &[], UsageWindow { from : Some( at( 250 ) ), until : None } );
+  assert_eq!( summaries[ 0 ].requests, 1 );
+
+  let mut recent = report();
+  recent.record( "gemini", "gemini-2.5-flash", TokenUsage::new( 1, 1 ) );
+  let summaries = recent.summarize( &[ UsageGroup::Provider ], UsageWindow::last( Duration::from_secs( 60 ) ) );
+  assert_eq!( summaries.len(), 1 );
+  assert_eq!( summaries[ 0 ].provider.as_deref(), Some( "gemini" ) );
+}
+
+#[ test ]
+fn prune_before_drops_old_records()
+{
+  let mut report = report();
+  report.prune_before( at( 200 ) );
+
+  assert_eq!( report.records().len(), 2 );
+  assert!( report.records().iter().all( | record | record.at >= at( 200 ) ) );
+}
+
+#[ test ]
+fn to_json_reports_window_and_groups()
+{
+  let json = report().to_json( &[ UsageGroup::Provider ], UsageWindow::between( at( 0 ), at( 1000 ) ) );
+
+  assert_eq!( json[ "from" ], 0 );
+  assert_eq!( json[ "until" ], 1000 );
+  assert_eq!( json[ "groups" ][ 1 ][ "provider" ], "openai" );
+  assert_eq!( json[ "groups" ][ 1 ][ "model" ], serde_json::Value::Null );
+  assert_eq!( json[ "groups" ][ 1 ][ "cached_input_tokens" ], 8 );
+  assert_eq!( json[ "groups" ][ 1 ][ "reasoning_tokens" ], 4 );
+  assert_eq!( json[ "groups" ][ 1 ][ "total_tokens" ], 45 );
+  assert_eq!( report().to_json( &[], UsageWindow::all() )[ "from" ], serde_json::Value::Null );
+}
+
+#[ test ]
+fn to_csv_quotes_fields()
+{
+  let mut report = report();
+  report.record_tagged( "openai", "gpt-4o", "team \"a\", search", TokenUsage::new( 1, 2 ) );
+  let csv = report.to_csv( &[ UsageGroup::Provider, UsageGroup::Tag ], UsageWindow::all() );
+  let lines : Vec< &str > = csv.lines().collect();
+
+  assert_eq!( lines[ 0 ], "provider,model,tag,requests,input_tokens,output_tokens,cached_input_tokens,reasoning_tokens,total_tokens" );
+  assert_eq!( lines[ 1 ], "claude,,search,1,7,3,0,0,10" );
+  assert_eq!( lines[ 2 ], "openai,,,1,20,10,8,4,30" );
+  assert_eq!( lines[ 3 ], "openai,,search,1,10,5,0,0,15" );
+  assert_eq!( lines[ 4 ], "openai,,\"team \"\"a\"\", search\",1,1,2,0,0,3" );
+  assert_eq!( lines.len(), 5 );
+}
@@ -0,0 +1,161 @@
+//! Derive macros for `api_common`.
+//!
+//! Use through the re-export in `api_common` (feature `prompt`) rather than
+//! depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{ parse_macro_input, Data, DeriveInput, Fields, LitStr };
+
+/// Derives `api_common::PromptVariables` for a struct with named fields.
+///
+/// Every field becomes a template variable named after the field and
+/// rendered through `Display`. Adding `#[ prompt( template = "…" ) ]` also
+/// derives `api_common::TypedPrompt` and rejects, at compile time, any
+/// `{{variable}}` placeholder that does not name a field.
+///
+/// ```ignore
+/// #[ derive( PromptVariables ) ]
+/// #[ prompt( template = "Summarise {{document}} in {{words}} words." ) ]
+/// struct Summary
+/// {
+///   document : String,
+///   words : u32,
+/// }
+/// ```
+#[ proc_macro_derive( PromptVariables, attributes( prompt ) ) ]
+#[ allow( clippy::missing_inline_in_public_items ) ]
+pub fn derive_prompt_variables( input : TokenStream ) -> TokenStream
+{
+  let input = parse_macro_input!( input as DeriveInput );
+  expand( &input ).unwrap_or_else( syn::Error::into_compile_error ).into()
+}
+
+fn expand( input : &DeriveInput ) -> syn::Result< proc_macro2::TokenStream >
+{
+  let ident = &input.ident;
+  let Data::Struct( data ) = &input.data else
+  {
+    return Err( syn::Error::new_spanned( ident, "PromptVariables can only be derived for structs" ) );
+  };
+  let Fields::Named( fields ) = &data.fields else
+  {
+    return Err( syn::Error::new_spanned( ident, "PromptVariables requires named fields" ) );
+  };
+
+  let field_idents : Vec< _ > = fields.named.iter().filter_map( | f | f.ident.clone() ).collect();
+  let field_names : Vec< String > = field_idents.iter().map( ToString::to_string ).collect();
+
+  let template = template_attribute( input )?;
+  if let Some( template ) = &template
+  {
+    for name in template_variables( &template.value(), template.span() )?
+    {
+      if !field_names.contains( &name )
+      {
+        return Err( syn::Error::new
+        (
+          template.span(),
+          format!( "template variable `{name}` is not a field of `{ident}`" ),
+        ));
+      }
+    }
+  }
+
+  let ( impl_generics, ty_generics, where_clause ) = input.generics.split_for_impl();
+  let names = field_names.iter();
+  let values = field_idents.iter().zip( field_names.iter() ).map( | ( field, name ) |
+  {
+    quote! { ( #name, ::std::string::ToString::to_string( &self.#field ) ) }
+  });
+
+  let mut output = quote!
+  {
+    impl #impl_generics ::api_common::PromptVariables for #ident #ty_generics #where_clause
+    {
+      const NAMES : &'static [ &'static str ] = &[ #( #names ),* ];
+
+      fn values( &self ) -> ::std::vec::Vec< ( &'static str, ::std::string::String ) >
+      {
+        ::std::vec![ #( #values ),* ]
+      }
+    }
+  };
+
+  if let Some( template ) = template
+  {
+    output.extend( quote!
+    {
+      impl #impl_generics ::api_common::TypedPrompt for #ident #ty_generics #where_clause
+      {
+        const TEMPLATE : &'static str = #template;
+      }
+    });
+  }
+
+  Ok( output )
+}
+
+/// Reads `#[ prompt( template = "…" ) ]`, if present.
+fn template_attribute( input : &DeriveInput ) -> syn::Result< Option< LitStr > >
+{
+  let mut template = None;
+  for attr in input.attrs.iter().filter( | a | a.path().is_ident( "prompt" ) )
+  {
+    attr.parse_nested_meta( | meta |
+    {
+      if meta.path.is_ident( "template" )
+      {
+        template = Some( meta.value()?.parse::< LitStr >()? );
+        Ok( () )
+      }
+      else
+      {
+        Err( meta.error( "unsupported prompt attribute, expected `template`" ) )
+      }
+    })?;
+  }
+  Ok( template )
+}
+
+/// Extracts `{{variable}}` names (not `{{> partial}}` names) from a template.
+///
+/// Mirrors the runtime grammar of `api_common::PromptTemplate` so syntax
+/// errors surface at compile time as well.
+fn template_variables( source : &str, span : Span ) -> syn::Result< Vec< String > >
+{
+  let mut names = Vec::new();
+  let mut rest = source;
+  while let Some( start ) = rest.find( "{{" )
+  {
+    let after = &rest[ start + 2.. ];
+    let Some( end ) = after.find( "}}" ) else
+    {
+      return Err( syn::Error::new( span, "unclosed `{{` in prompt template" ) );
+    };
+    let inner = after[ ..end ].trim();
+    let ( is_partial, name ) = match inner.strip_prefix( '>' )
+    {
+      Some( name ) => ( true, name.trim() ),
+      None => ( false, inner ),
+    };
+    let mut chars = name.chars();
+    let valid = match chars.next()
+    {
+      Some( first ) if first.is_ascii_alphabetic() || first == '_' =>
+        chars.all( | c | c.is_ascii_alphanumeric() || c == '_' ),
+      _ => false,
+    };
+    if !valid
+    {
+      return Err( syn::Error::new( span, format!( "invalid placeholder name `{name}` in prompt template" ) ) );
+    }
+    if !is_partial
+    {
+      names.push( name.to_owned() );
+    }
+    rest = &after[ end + 2.. ];
+  }
+  Ok( names )
+}
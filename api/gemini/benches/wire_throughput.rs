@@ -0,0 +1,109 @@
+//! Wire type throughput : request serialisation, large response
+//! deserialisation, and streamed response parsing.
+//!
+//! `:streamGenerateContent` answers with one JSON array of responses, which
+//! the client buffers and parses in one go, so the stream benchmark parses
+//! arrays of increasing length. Results are reported in bytes per second.
+#![allow(missing_docs)]
+
+use api_gemini::models::{ GenerateContentRequest, GenerateContentResponse };
+use criterion::{ criterion_group, criterion_main, BenchmarkId, Criterion, Throughput };
+use serde_json::json;
+use core::hint::black_box;
+
+/// Conversation turns per request; each turn is one user and one model message.
+const TURNS : [ usize; 3 ] = [ 1, 16, 256 ];
+/// Approximate reply text length in bytes.
+const REPLY_BYTES : [ usize; 3 ] = [ 256, 16 * 1024, 512 * 1024 ];
+/// Responses in a streamed array.
+const CHUNKS : [ usize; 3 ] = [ 16, 256, 4096 ];
+
+fn request( turns : usize ) -> GenerateContentRequest
+{
+  let contents : Vec< _ > = ( 0..turns ).flat_map( | turn |
+  [
+    json!( { "role" : "user", "parts" : [ { "text" : format!( "Question {turn} : what changed in the quarterly report?" ) } ] } ),
+    json!( { "role" : "model", "parts" : [ { "text" : "Revenue grew 4%, costs were flat, and the outlook was raised.".repeat( 4 ) } ] } ),
+  ] ).collect();
+  serde_json::from_value( json!(
+  {
+    "contents" : contents,
+    "generationConfig" : { "temperature" : 0.2, "maxOutputTokens" : 1024 },
+    "safetySettings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "threshold" : "BLOCK_ONLY_HIGH" } ],
+  } ) ).expect( "benchmark request must deserialise" )
+}
+
+fn response( text : &str, finish : Option< &str > ) -> serde_json::Value
+{
+  json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : text } ] }, "finishReason" : finish, "index" : 0 } ],
+    "usageMetadata" : { "promptTokenCount" : 120, "candidatesTokenCount" : text.len() / 4, "totalTokenCount" : 120 + text.len() / 4 },
+  } )
+}
+
+fn response_body( reply_bytes : usize ) -> String
+{
+  let sentence = "The quick brown fox jumps over the lazy dog. ";
+  response( &sentence.repeat( reply_bytes / sentence.len() + 1 ), Some( "STOP" ) ).to_string()
+}
+
+fn stream_body( chunks : usize ) -> String
+{
+  let mut responses : Vec< _ > = ( 0..chunks ).map( | index | response( &format!( "token{index} " ), None ) ).collect();
+  responses.push( response( "", Some( "STOP" ) ) );
+  serde_json::Value::Array( responses ).to_string()
+}
+
+fn benchmark_request_serialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "gemini/serialize_request" );
+  for turns in TURNS
+  {
+    let request = request( turns );
+    group.throughput( Throughput::Bytes( serde_json::to_vec( &request ).unwrap().len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( turns ), &request, | b, request |
+    {
+      b.iter( || serde_json::to_vec( black_box( request ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_response_deserialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "gemini/deserialize_response" );
+  for reply_bytes in REPLY_BYTES
+  {
+    let body = response_body( reply_bytes );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( reply_bytes ), &body, | b, body |
+    {
+      b.iter( || serde_json::from_str::< GenerateContentResponse >( black_box( body ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_stream_parsing( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "gemini/parse_stream" );
+  for chunks in CHUNKS
+  {
+    let body = stream_body( chunks );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( chunks ), &body, | b, body |
+    {
+      b.iter( || serde_json::from_str::< Vec< GenerateContentResponse > >( black_box( body ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+criterion_group!(
+benches,
+benchmark_request_serialization,
+benchmark_response_deserialization,
+benchmark_stream_parsing
+);
+criterion_main!( benches );
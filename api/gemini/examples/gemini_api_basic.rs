@@ -63,6 +63,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: Some( vec!
     [
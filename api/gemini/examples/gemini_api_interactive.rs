@@ -103,6 +103,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
         top_k: Some( 40 ),
         candidate_count: Some( 1 ),
         stop_sequences: None,
+        response_modalities: None,
+        speech_config: None,
+        thinking_config: None,
       }),
       safety_settings: None,
       tools: None,
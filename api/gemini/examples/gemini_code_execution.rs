@@ -200,6 +200,8 @@ config: &ExecutionExampleConfig,
     function_declarations: None,
     code_execution: None,
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: Some( code_execution_tool ),
   } ];
 
@@ -213,6 +215,8 @@ config: &ExecutionExampleConfig,
         file_data: None,
         video_metadata: None,
         thought: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -223,6 +227,9 @@ config: &ExecutionExampleConfig,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: Some( tools ),
@@ -268,56 +275,33 @@ println!( "{}", "=".repeat( 80 ) );
       println!( "{}", text );
       }
 
-      // Check for function calls (code execution requests)
-      if let Some( function_call ) = &part.function_call
+      // Code the model wrote and ran
+      if let Some( executable_code ) = &part.executable_code
       {
-        println!( "\n🔧 Function Call Detected:" );
-      println!( "Function : {}", function_call.name );
-        if detailed
-        {
-        println!( "Arguments : {}", serde_json::to_string_pretty( &function_call.args ).unwrap_or_default() );
-        }
+        println!( "\n🔧 Executed Code ({:?}):", executable_code.language );
+      println!( "{}", "-".repeat( 40 ) );
+      println!( "{}", executable_code.code );
       }
 
-      // Check for function responses (code execution results)
-      if let Some( function_response ) = &part.function_response
+      // Result of running the code of the preceding part
+      if let Some( result ) = &part.code_execution_result
       {
         println!( "\n🎯 Execution Result:" );
       println!( "{}", "-".repeat( 40 ) );
-      println!( "Function : {}", function_response.name );
-
-        // Try to parse the response as a structured result
-        if let Ok( result ) = serde_json::from_value::< serde_json::Value >( function_response.response.clone() )
+      println!( "📊 Outcome : {:?}", result.outcome );
+        if let Some( output ) = &result.output
         {
-          if detailed
-          {
-          println!( "Full Result : {}", serde_json::to_string_pretty( &result ).unwrap_or_default() );
-          }
-
-          // Extract specific fields if they exist
-          if let Some( outcome ) = result.get( "outcome" ).and_then( |v| v.as_str() )
-          {
-          println!( "📊 Outcome : {}", outcome );
-          }
-
-          if let Some( output ) = result.get( "output" ).and_then( |v| v.as_str() )
+          if result.outcome == CodeExecutionOutcome::Ok
           {
             println!( "📤 Output:" );
-          println!( "{}", output );
-          }
-
-          if let Some( error ) = result.get( "error" ).and_then( |v| v.as_str() )
-          {
+          } else {
             println!( "❌ Error:" );
-          println!( "{}", error );
-          }
-
-          if let Some( execution_time ) = result.get( "execution_time_ms" ).and_then( |v| v.as_i64() )
-          {
-          println!( "⏱️  Execution Time : {}ms", execution_time );
           }
-        } else {
-        println!( "Raw Response : {}", function_response.response );
+        println!( "{}", output );
+        }
+        if detailed
+        {
+        println!( "Full Result : {}", serde_json::to_string_pretty( result ).unwrap_or_default() );
         }
       }
     }
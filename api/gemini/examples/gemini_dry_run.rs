@@ -155,6 +155,8 @@ println!( "   Multi-turn conversation with {conversation_len} turns" );
     ]),
     code_execution: None,
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: None,
   }
   ];
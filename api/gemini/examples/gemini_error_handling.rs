@@ -209,6 +209,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 100 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: None,
     tools: None,
@@ -338,6 +341,9 @@ Err( e ) => println!( "Failed after {} attempts : {:?}", retry_config.max_attemp
           candidate_count: Some( 1 ),
           max_output_tokens: Some( 512 ),
           stop_sequences: None,
+          response_modalities: None,
+          speech_config: None,
+          thinking_config: None,
         }),
         safety_settings: None,
         tools: None,
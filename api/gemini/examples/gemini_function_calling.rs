@@ -2,7 +2,7 @@
 //! Comprehensive Function Calling & Tool Integration Example
 //!
 //! This example demonstrates advanced AI agent capabilities including:
-//! - Dynamic function/tool registration and execution
+//! - Dynamic function/tool registration, with parameter schemas generated from Rust types
 //! - Multi-step workflow orchestration
 //! - External API integration simulation
 //! - Security controls and validation
@@ -22,6 +22,7 @@
 //! ```
 
 use api_gemini::{ client::Client, models::* };
+use schemars::JsonSchema;
 use serde_json::{ json, Value };
 use std::collections::HashMap;
 use std::env;
@@ -115,6 +116,110 @@ impl FunctionContext
   }
 }
 
+/// Temperature unit preference
+#[ derive( Debug, JsonSchema ) ]
+#[ serde( rename_all = "lowercase" ) ]
+pub enum TemperatureUnit
+{
+  /// Degrees Celsius
+  Celsius,
+  /// Degrees Fahrenheit
+  Fahrenheit,
+}
+
+/// Get current weather conditions for a specific location
+#[ derive( Debug, JsonSchema ) ]
+pub struct GetWeather
+{
+  /// City name or location (e.g., 'San Francisco', 'Tokyo')
+  pub location: String,
+  /// Temperature unit preference, celsius when not given
+  pub unit: Option< TemperatureUnit >,
+}
+
+/// Perform mathematical calculations with support for basic arithmetic, percentages, and common functions
+#[ derive( Debug, JsonSchema ) ]
+pub struct Calculate
+{
+  /// Mathematical expression to evaluate (e.g., '2 + 3 * 4', 'sqrt(16)', '15% of 200')
+  pub expression: String,
+  /// Number of decimal places for the result, 2 when not given
+  #[ schemars( range( min = 0, max = 10 ) ) ]
+  pub precision: Option< u8 >,
+}
+
+/// Search the web for information on a specific topic or query
+#[ derive( Debug, JsonSchema ) ]
+pub struct WebSearch
+{
+  /// Search query or keywords
+  pub query: String,
+  /// Maximum number of search results to return, 5 when not given
+  #[ schemars( range( min = 1, max = 20 ) ) ]
+  pub max_results: Option< u8 >,
+  /// Preferred language for results, 'en' when not given
+  pub language: Option< String >,
+}
+
+/// Flight class preference
+#[ derive( Debug, JsonSchema ) ]
+#[ serde( rename_all = "lowercase" ) ]
+pub enum FlightClass
+{
+  /// Economy class
+  Economy,
+  /// Business class
+  Business,
+  /// First class
+  First,
+}
+
+/// Search for available flights between two locations
+#[ derive( Debug, JsonSchema ) ]
+pub struct SearchFlights
+{
+  /// Departure city or airport code
+  pub from: String,
+  /// Arrival city or airport code
+  pub to: String,
+  /// Flight date in YYYY-MM-DD format
+  pub date: String,
+  /// Number of passengers, 1 when not given
+  #[ schemars( range( min = 1, max = 9 ) ) ]
+  pub passengers: Option< u8 >,
+  /// Flight class preference, economy when not given
+  #[ serde( rename = "class" ) ]
+  pub flight_class: Option< FlightClass >,
+}
+
+/// Type of data to query
+#[ derive( Debug, JsonSchema ) ]
+#[ serde( rename_all = "lowercase" ) ]
+pub enum QueryType
+{
+  /// User records
+  Users,
+  /// Order records
+  Orders,
+  /// Analytics summaries
+  Analytics,
+  /// Inventory levels
+  Inventory,
+}
+
+/// Execute queries against a simulated database for user data, orders, or analytics
+#[ derive( Debug, JsonSchema ) ]
+pub struct QueryDatabase
+{
+  /// Type of data to query
+  pub query_type: QueryType,
+  /// Query filters and conditions, as field names and values
+  pub filters: Option< HashMap< String, String > >,
+  /// Maximum number of results, 10 when not given
+  #[ schemars( range( min = 1, max = 100 ) ) ]
+  pub limit: Option< u8 >,
+}
+
 /// Comprehensive tool registry with built-in functions
 #[ derive( Debug ) ]
 pub struct ToolRegistry
@@ -148,163 +253,17 @@ impl ToolRegistry
 
   fn register_default_tools( &mut self )
   {
-    // Weather API tool
-    self.available_functions.insert(
-    "get_weather".to_string(),
-    FunctionDeclaration
-    {
-      name: "get_weather".to_string(),
-      description: "Get current weather conditions for a specific location".to_string(),
-      parameters : Some( json!({
-        "type": "object",
-        "properties": {
-          "location": {
-            "type": "string",
-            "description": "City name or location (e.g., 'San Francisco', 'Tokyo')"
-          },
-          "unit": {
-            "type": "string",
-            "enum": ["celsius", "fahrenheit"],
-            "description": "Temperature unit preference",
-            "default": "celsius"
-          }
-        },
-        "required": ["location"]
-      })),
-    }
-    );
-
-    // Calculator tool
-    self.available_functions.insert(
-    "calculate".to_string(),
-    FunctionDeclaration
+    // Parameters are generated from the argument types, so the schemas cannot drift from them
+    for declaration in [
+    FunctionDeclaration::from_schema::< GetWeather >(),
+    FunctionDeclaration::from_schema::< Calculate >(),
+    FunctionDeclaration::from_schema::< WebSearch >(),
+    FunctionDeclaration::from_schema::< SearchFlights >(),
+    FunctionDeclaration::from_schema::< QueryDatabase >(),
+    ]
     {
-      name: "calculate".to_string(),
-      description: "Perform mathematical calculations with support for basic arithmetic, percentages, and common functions".to_string(),
-      parameters : Some( json!({
-        "type": "object",
-        "properties": {
-          "expression": {
-            "type": "string",
-            "description": "Mathematical expression to evaluate (e.g., '2 + 3 * 4', 'sqrt(16)', '15% of 200')"
-          },
-          "precision": {
-            "type": "integer",
-            "description": "Number of decimal places for the result",
-            "default": 2,
-            "minimum": 0,
-            "maximum": 10
-          }
-        },
-        "required": ["expression"]
-      })),
+      self.available_functions.insert( declaration.name.clone(), declaration );
     }
-    );
-
-    // Search tool
-    self.available_functions.insert(
-    "web_search".to_string(),
-    FunctionDeclaration
-    {
-      name: "web_search".to_string(),
-      description: "Search the web for information on a specific topic or query".to_string(),
-      parameters : Some( json!({
-        "type": "object",
-        "properties": {
-          "query": {
-            "type": "string",
-            "description": "Search query or keywords"
-          },
-          "max_results": {
-            "type": "integer",
-            "description": "Maximum number of search results to return",
-            "default": 5,
-            "minimum": 1,
-            "maximum": 20
-          },
-          "language": {
-            "type": "string",
-            "description": "Preferred language for results",
-            "default": "en"
-          }
-        },
-        "required": ["query"]
-      })),
-    }
-    );
-
-    // Flight search tool
-    self.available_functions.insert(
-    "search_flights".to_string(),
-    FunctionDeclaration
-    {
-      name: "search_flights".to_string(),
-      description: "Search for available flights between two locations".to_string(),
-      parameters : Some( json!({
-        "type": "object",
-        "properties": {
-          "from": {
-            "type": "string",
-            "description": "Departure city or airport code"
-          },
-          "to": {
-            "type": "string",
-            "description": "Arrival city or airport code"
-          },
-          "date": {
-            "type": "string",
-            "description": "Flight date in YYYY-MM-DD format"
-          },
-          "passengers": {
-            "type": "integer",
-            "description": "Number of passengers",
-            "default": 1,
-            "minimum": 1,
-            "maximum": 9
-          },
-          "class": {
-            "type": "string",
-            "enum": ["economy", "business", "first"],
-            "description": "Flight class preference",
-            "default": "economy"
-          }
-        },
-        "required": ["from", "to", "date"]
-      })),
-    }
-    );
-
-    // Database query tool
-    self.available_functions.insert(
-    "query_database".to_string(),
-    FunctionDeclaration
-    {
-      name: "query_database".to_string(),
-      description: "Execute queries against a simulated database for user data, orders, or analytics".to_string(),
-      parameters : Some( json!({
-        "type": "object",
-        "properties": {
-          "query_type": {
-            "type": "string",
-            "enum": ["users", "orders", "analytics", "inventory"],
-            "description": "Type of data to query"
-          },
-          "filters": {
-            "type": "object",
-            "description": "Query filters and conditions"
-          },
-          "limit": {
-            "type": "integer",
-            "description": "Maximum number of results",
-            "default": 10,
-            "minimum": 1,
-            "maximum": 100
-          }
-        },
-        "required": ["query_type"]
-      })),
-    }
-    );
   }
 
   /// Get tools filtered by name list
@@ -326,16 +285,7 @@ impl ToolRegistry
     }
     else
     {
-      vec!
-      [
-      Tool
-      {
-        function_declarations: Some( function_declarations ),
-        code_execution: None,
-        google_search_retrieval: None,
-        code_execution_tool: None,
-      }
-      ]
+      vec![ Tool::functions( function_declarations ) ]
     }
   }
 
@@ -353,16 +303,7 @@ impl ToolRegistry
     }
     else
     {
-      vec!
-      [
-      Tool
-      {
-        function_declarations: Some( function_declarations ),
-        code_execution: None,
-        google_search_retrieval: None,
-        code_execution_tool: None,
-      }
-      ]
+      vec![ Tool::functions( function_declarations ) ]
     }
   }
 
@@ -904,10 +845,13 @@ impl FunctionCallingAgent
           candidate_count: Some( 1 ),
           max_output_tokens: Some( 2048 ),
           stop_sequences: None,
+          response_modalities: None,
+          speech_config: None,
+          thinking_config: None,
         }),
         safety_settings: None,
         tools: Some( tools ),
-        tool_config: None,
+        tool_config: Some( ToolConfig::auto() ), // The model decides when to call functions
         system_instruction: None,
         cached_content: None,
       };
@@ -93,6 +93,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: None,
     tools: None,
@@ -213,6 +216,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: None,
     tools: None,
@@ -299,6 +305,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 512 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: None,
     tools: None,
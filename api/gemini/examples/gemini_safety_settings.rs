@@ -47,6 +47,9 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 512 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: None, // Using default safety settings
     tools: None,
@@ -89,29 +92,10 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
   // Example 2: Strict safety settings
   println!( "\n2. Request with Strict Safety Settings" );
 
-  let strict_safety_settings = vec!
-  [
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_HARASSMENT".to_string(),
-    threshold: "BLOCK_LOW_AND_ABOVE".to_string(), // Most restrictive
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_HATE_SPEECH".to_string(),
-    threshold: "BLOCK_LOW_AND_ABOVE".to_string(),
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_SEXUALLY_EXPLICIT".to_string(),
-    threshold: "BLOCK_LOW_AND_ABOVE".to_string(),
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_DANGEROUS_CONTENT".to_string(),
-    threshold: "BLOCK_LOW_AND_ABOVE".to_string(),
-  },
-  ];
+  // Most restrictive threshold for every category
+  let strict_safety_settings = SafetySettingsBuilder::new()
+  .all( HarmBlockThreshold::BlockLowAndAbove )
+  .build();
 
   let request_strict = GenerateContentRequest
   {
@@ -150,22 +134,15 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
   .await?;
 
   // Handle potential blocking
-  if let Some( prompt_feedback ) = &response_strict.prompt_feedback
+  if response_strict.was_blocked()
   {
-    if let Some( block_reason ) = &prompt_feedback.block_reason
+    println!( "Content was blocked! Categories : {:?}", response_strict.blocked_categories() );
+    if let Some( block_reason ) = response_strict.prompt_feedback.as_ref().and_then( | feedback | feedback.block_reason.as_ref() )
     {
-    println!( "Content was blocked! Reason : {block_reason}" );
-
-      if let Some( safety_ratings ) = &prompt_feedback.safety_ratings
-      {
-        println!( "Safety ratings that triggered blocking:" );
-        for rating in safety_ratings
-        {
-      println!( "  - {}: {}", rating.category, rating.probability );
-        }
-      }
+    println!( "Prompt block reason : {block_reason}" );
     }
   }
+  println!( "Highest rated probability of harm : {:?}", response_strict.max_severity() );
 
   if let Some( candidate ) = response_strict.candidates.first()
   {
@@ -182,29 +159,11 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
   // Example 3: Permissive safety settings for educational content
   println!( "\n3. Request with Permissive Settings (Educational Context)" );
 
-  let educational_safety_settings = vec!
-  [
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_HARASSMENT".to_string(),
-    threshold: "BLOCK_ONLY_HIGH".to_string(), // Less restrictive
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_HATE_SPEECH".to_string(),
-    threshold: "BLOCK_ONLY_HIGH".to_string(),
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_SEXUALLY_EXPLICIT".to_string(),
-    threshold: "BLOCK_MEDIUM_AND_ABOVE".to_string(),
-  },
-  SafetySetting
-  {
-    category: "HARM_CATEGORY_DANGEROUS_CONTENT".to_string(),
-    threshold: "BLOCK_ONLY_HIGH".to_string(),
-  },
-  ];
+  // Less restrictive, except for sexually explicit content
+  let educational_safety_settings = SafetySettingsBuilder::new()
+  .all( HarmBlockThreshold::BlockOnlyHigh )
+  .threshold( HarmCategory::SexuallyExplicit, HarmBlockThreshold::BlockMediumAndAbove )
+  .build();
 
   let request_educational = GenerateContentRequest
   {
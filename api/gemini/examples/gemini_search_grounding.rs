@@ -167,15 +167,8 @@ query: &str,
 println!( "🔍 Basic Search Grounding : {}", query );
 println!( "{}", "=".repeat( 80 ) );
 
-  // Configure Google Search tool
-  let search_tool = Tool {
-    function_declarations: None,
-    code_execution: None,
-    google_search_retrieval : Some( GoogleSearchTool {
-      config: None, // Use default search configuration
-    } ),
-    code_execution_tool: None,
-  };
+  // Configure Google Search tool; the model decides when to search
+  let search_tool = Tool::google_search();
 
   let request = GenerateContentRequest {
     contents : vec![ Content {
@@ -187,6 +180,8 @@ println!( "{}", "=".repeat( 80 ) );
         file_data: None,
         video_metadata: None,
         thought: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -197,6 +192,9 @@ println!( "{}", "=".repeat( 80 ) );
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: Some( vec![ search_tool ] ),
@@ -231,7 +229,7 @@ println!( "⚡ Response received in {:.2}s", duration.as_secs_f64() );
   }
 
   // Analyze grounding metadata
-  if let Some( grounding_metadata ) = &response.grounding_metadata
+  if let Some( grounding_metadata ) = response.grounding()
   {
     println!( "\n🔗 Grounding Analysis:" );
   println!( "{}", "-".repeat( 40 ) );
@@ -254,27 +252,19 @@ println!( "⚡ Response received in {:.2}s", duration.as_secs_f64() );
       for ( i, chunk ) in grounding_chunks.iter().enumerate()
       {
       println!( "\n  Source {}:", i + 1 );
-        if let Some( title ) = &chunk.title
+        if let Some( title ) = chunk.title()
         {
         println!( "    📄 Title : {}", title );
         }
-        if let Some( uri ) = &chunk.uri
+        if let Some( uri ) = chunk.uri()
         {
         println!( "    🔗 URL: {}", uri );
         }
-        if let Some( domain ) = &chunk.domain
+        if let Some( content ) = chunk.retrieved_context.as_ref().and_then( |c| c.text.as_ref() )
         {
-        println!( "    🌐 Domain : {}", domain );
-        }
-        if let Some( published_date ) = &chunk.published_date
-        {
-        println!( "    📅 Published : {}", published_date );
-        }
-        if let Some( content ) = &chunk.content
-        {
-          let preview = if content.len() > 150
+          let preview = if content.chars().count() > 150
           {
-          format!( "{}...", &content[ ..150 ] )
+          format!( "{}...", content.chars().take( 150 ).collect::< String >() )
           } else {
             content.clone()
           };
@@ -283,21 +273,18 @@ println!( "⚡ Response received in {:.2}s", duration.as_secs_f64() );
       }
     }
 
-    // Display grounding supports (which parts of response are grounded)
-    if let Some( grounding_supports ) = &grounding_metadata.grounding_supports
+    // Display citations (which parts of response are grounded, and in what)
+    let citations = grounding_metadata.citations();
+    if !citations.is_empty()
     {
-    println!( "\n🎯 Grounding Support ({} segments):", grounding_supports.len() );
-      for ( i, support ) in grounding_supports.iter().enumerate()
+    println!( "\n🎯 Grounding Support ({} segments):", citations.len() );
+      for ( i, citation ) in citations.iter().enumerate()
       {
-      println!( "  Segment {}:", i + 1 );
-        if let ( Some( start ), Some( end ) ) = ( support.start_index, support.end_index )
-        {
-      println!( "    📍 Position : characters {} to {}", start, end );
-        }
-      println!( "    📊 Supported by {} sources", support.grounding_chunk_indices.len() );
-        if let Some( confidence ) = support.confidence_score
+      println!( "  Segment {}: \"{}\"", i + 1, citation.text );
+      println!( "    📍 Position : bytes {} to {}", citation.start_index, citation.end_index );
+        for source in &citation.sources
         {
-        println!( "    🎯 Confidence : {:.2}%", confidence * 100.0 );
+        println!( "    📚 {}", source.title().or( source.uri() ).unwrap_or( "untitled source" ) );
         }
       }
     }
@@ -405,14 +392,7 @@ println!( "\n🧪 Test Query {} of {}", i + 1, test_queries.len() );
   println!( "Query : {}", query );
   println!( "{}", "-".repeat( 60 ) );
 
-    let search_tool = Tool {
-      function_declarations: None,
-      code_execution: None,
-      google_search_retrieval : Some( GoogleSearchTool {
-        config: None,
-      } ),
-      code_execution_tool: None,
-    };
+    let search_tool = Tool::google_search();
 
     let request = GenerateContentRequest {
       contents : vec![ Content {
@@ -424,6 +404,8 @@ println!( "\n🧪 Test Query {} of {}", i + 1, test_queries.len() );
           file_data: None,
           video_metadata: None,
           thought: None,
+          executable_code: None,
+          code_execution_result: None,
         } ],
         role: "user".to_string(),
       } ],
@@ -448,31 +430,31 @@ println!( "\n🧪 Test Query {} of {}", i + 1, test_queries.len() );
         // Analyze response quality
         let mut quality_metrics = HashMap::new();
 
-        if let Some( grounding_metadata ) = &response.grounding_metadata
+        if let Some( grounding_metadata ) = response.grounding()
         {
-          if let Some( chunks ) = &grounding_metadata.grounding_chunks
-          {
-            quality_metrics.insert( "source_count", chunks.len() );
+          let chunks = grounding_metadata.chunks();
+          quality_metrics.insert( "source_count", chunks.len() );
 
-            let unique_domains: std::collections::HashSet<  _  > = chunks
-            .iter()
-            .filter_map( |c| c.domain.as_ref() )
-            .collect();
-            quality_metrics.insert( "unique_domains", unique_domains.len() );
-
-            let has_recent_content = chunks.iter().any( |c|
-            c.published_date.as_ref().is_some_and( |d| d.contains( "2024" ) )
-            );
-        quality_metrics.insert( "recent_content", if has_recent_content { 1 } else { 0 } );
-          }
+          // Web search titles are the domains of the pages
+          let unique_domains: std::collections::HashSet<  _  > = chunks
+          .iter()
+          .filter_map( GroundingChunk::title )
+          .collect();
+          quality_metrics.insert( "unique_domains", unique_domains.len() );
 
           if let Some( supports ) = &grounding_metadata.grounding_supports
           {
-            let avg_confidence = supports
+            let scores : Vec< f64 > = supports
             .iter()
-            .filter_map( |s| s.confidence_score )
-            .sum::< f64 >() / supports.len() as f64;
-            quality_metrics.insert( "avg_confidence", ( avg_confidence * 100.0 ) as usize );
+            .filter_map( |s| s.confidence_scores.as_ref() )
+            .flatten()
+            .copied()
+            .collect();
+            if !scores.is_empty()
+            {
+              let avg_confidence = scores.iter().sum::< f64 >() / scores.len() as f64;
+              quality_metrics.insert( "avg_confidence", ( avg_confidence * 100.0 ) as usize );
+            }
           }
         }
 
@@ -354,6 +354,8 @@ conversation_history: Option< &Vec< Content > >,
       file_data: None,
       video_metadata: None,
       thought: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
   };
 
@@ -368,6 +370,8 @@ conversation_history: Option< &Vec< Content > >,
       file_data: None,
       video_metadata: None,
       thought: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
     role: "user".to_string(),
   } );
@@ -381,6 +385,9 @@ conversation_history: Option< &Vec< Content > >,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: None,
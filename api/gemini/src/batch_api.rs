@@ -3,8 +3,11 @@
 //! Provides methods for creating batch jobs, polling status, and retrieving results.
 //! Batch Mode offers 50% cost discount with 24-hour SLO.
 //!
-//! **NOTE:** Batch Mode API endpoints are not yet available from Gemini. All methods
-//! return `Error::NotImplemented`. Replace with real HTTP calls when endpoints ship.
+//! Jobs are created with `models/{model}:batchGenerateContent` (embeddings with
+//! `:asyncBatchEmbedContent`) and managed as `batches/{id}`. Nothing polls on
+//! its own: [`BatchApi::get`] reads the state once, [`BatchApi::poll_until_done`]
+//! and [`BatchApi::wait_and_retrieve`] poll at the interval and until the
+//! deadline given.
 
 use crate::
 {
@@ -13,28 +16,61 @@ use crate::
   models ::
   {
     GenerateContentRequest,
+    Operation,
     batch ::*,
   },
 };
-use std::time::{ Duration, SystemTime };
+use serde::{ Deserialize, Serialize };
+use std::time::{ Duration, Instant };
+
+/// Interval between two polls of [`BatchApi::wait_and_retrieve`].
+const POLL_INTERVAL : Duration = Duration::from_secs( 5 );
 
 /// API for managing batch jobs with async processing.
 #[ derive( Debug ) ]
-pub struct BatchApi
+pub struct BatchApi< 'a >
 {
+  client : &'a Client,
 }
 
-impl BatchApi
+impl< 'a > BatchApi< 'a >
 {
   /// Create a new BatchApi instance.
   #[ inline ]
-  pub fn new( _client : &Client ) -> Self
+  pub fn new( client : &'a Client ) -> Self
+  {
+    Self { client }
+  }
+
+  /// Create a batch job.
+  ///
+  /// # Arguments
+  ///
+  /// * `model` - Model name (e.g., "gemini-2.5-flash")
+  /// * `request` - Inlined requests or an uploaded JSONL file, see
+  ///   [`CreateBatchJobRequest::inline`] and [`CreateBatchJobRequest::from_file`]
+  ///
+  /// # Returns
+  ///
+  /// Returns the pending BatchJob; its `name` is used for status polling.
+  ///
+  /// # Errors
+  ///
+  /// Returns error if job creation fails or request is invalid.
+  #[ inline ]
+  pub async fn create( &self, model : &str, request : &CreateBatchJobRequest ) -> Result< BatchJob, Error >
   {
-    Self {}
+    if request.input_config.file_name.is_none() && request.input_config.requests.as_ref().map_or( true, | inlined | inlined.requests.is_empty() )
+    {
+      return Err( Error::InvalidArgument( "Batch job needs an input file or at least one request".to_string() ) );
+    }
+    self.create_job( model, "batchGenerateContent", &serde_json::json!( { "batch" : request } ) ).await
   }
 
   /// Create a batch job with inline content generation requests.
   ///
+  /// The requests are keyed `request-{index}`.
+  ///
   /// # Arguments
   ///
   /// * `model` - Model name (e.g., "gemini-2.5-flash")
@@ -42,132 +78,209 @@ impl BatchApi
   ///
   /// # Returns
   ///
-  /// Returns a BatchJob with job_id for status polling.
+  /// Returns a BatchJob with its name for status polling.
   ///
   /// # Errors
   ///
   /// Returns error if job creation fails or request is invalid.
+  #[ inline ]
   pub async fn create_inline(
     &self,
     model : &str,
     requests : Vec< GenerateContentRequest >
   ) -> Result< BatchJob, Error >
   {
-    let _ = ( model, requests );
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    let requests = requests.into_iter().enumerate()
+      .map( | ( index, request ) | BatchRequest::new( format!( "request-{index}" ), request ) )
+      .collect();
+    self.create( model, &CreateBatchJobRequest::inline( requests ) ).await
   }
 
-  /// Get status of a batch job.
+  /// Create a batch job from a JSONL file uploaded with the Files API.
   ///
   /// # Arguments
   ///
-  /// * `job_id` - The batch job identifier
+  /// * `model` - Model name (e.g., "gemini-2.5-flash")
+  /// * `file_name` - The uploaded file, e.g. `files/abc123`; see [`BatchRequest::jsonl`]
+  ///
+  /// # Errors
   ///
-  /// # Returns
+  /// Returns error if job creation fails.
+  #[ inline ]
+  pub async fn create_from_file( &self, model : &str, file_name : &str ) -> Result< BatchJob, Error >
+  {
+    self.create( model, &CreateBatchJobRequest::from_file( file_name ) ).await
+  }
+
+  /// Get the current state of a batch job.
+  ///
+  /// # Arguments
   ///
-  /// Returns BatchJobStatus with current state and progress.
+  /// * `name` - The job name, e.g. `batches/abc123`; a bare ID is taken as `batches/{id}`
   ///
   /// # Errors
   ///
   /// Returns error if job not found or API call fails.
-  pub async fn get_status( &self, job_id : &str ) -> Result< BatchJobStatus, Error >
+  #[ inline ]
+  pub async fn get( &self, name : &str ) -> Result< BatchJob, Error >
+  {
+    let url = format!( "{}/v1beta/{}", self.client.base_url, batch_name( name )? );
+    let operation = crate::internal::http::enterprise::execute_with_optional_retries::< (), Operation >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await?;
+    BatchJob::from_operation( operation )
+  }
+
+  /// Poll a batch job until it is done.
+  ///
+  /// The job is fetched right away, then every `interval`, and once more at
+  /// the deadline. A failed, cancelled, or expired job is returned like a
+  /// successful one: check its `state`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::TimeoutError`] if the job is not done by the deadline,
+  /// or the error of a failed poll.
+  #[ inline ]
+  pub async fn poll_until_done( &self, name : &str, interval : Duration, deadline : Instant ) -> Result< BatchJob, Error >
   {
-    let _ = job_id;
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    crate ::internal::poll::poll_until_done
+    (
+      &format!( "Batch job {name}" ),
+      || self.get( name ),
+      | job : &BatchJob | job.state.is_done(),
+      interval,
+      deadline,
+    )
+    .await
   }
 
   /// Wait for batch job completion and retrieve results.
   ///
-  /// Polls job status until completion or timeout.
+  /// Polls job status every 5 seconds until completion or timeout.
   ///
   /// # Arguments
   ///
-  /// * `job_id` - The batch job identifier
+  /// * `name` - The batch job name
   /// * `timeout` - Maximum time to wait
   ///
   /// # Returns
   ///
-  /// Returns BatchJobResults with all responses.
+  /// Returns BatchJobResults with one result per request.
   ///
   /// # Errors
   ///
-  /// Returns error if timeout reached or job fails.
+  /// Returns [`Error::TimeoutError`] if the timeout is reached, and
+  /// [`Error::ApiError`] if the job failed, was cancelled, or expired.
+  #[ inline ]
   pub async fn wait_and_retrieve(
     &self,
-    job_id : &str,
+    name : &str,
     timeout : Duration
   ) -> Result< BatchJobResults, Error >
   {
-    let start = SystemTime::now();
-    let poll_interval = Duration::from_secs( 5 );
+    let job = self.poll_until_done( name, POLL_INTERVAL, Instant::now() + timeout ).await?;
+    self.results( job ).await
+  }
 
-    loop
+  /// Retrieve the results of a finished batch job.
+  ///
+  /// Inlined results are read from the job; a result file is downloaded.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::InvalidArgument`] if the job is still running,
+  /// [`Error::ApiError`] if it did not succeed or holds no results, and the
+  /// errors of the download.
+  #[ inline ]
+  pub async fn results( &self, job : BatchJob ) -> Result< BatchJobResults, Error >
+  {
+    let output = finished_output( &job )?;
+    let responses = if let Some( inlined ) = &output.inlined_responses
     {
-      let status = self.get_status( job_id ).await?;
-
-      match status.state
-      {
-        BatchJobState::Succeeded | BatchJobState::PartiallyCompleted =>
-        {
-          return self.retrieve_results( job_id ).await;
-        }
-        BatchJobState::Failed =>
-        {
-          return Err( Error::ApiError(
-            status.error.unwrap_or_else( || "Batch job failed".to_string() )
-          ) );
-        }
-        BatchJobState::Cancelled =>
-        {
-          return Err( Error::ApiError( "Batch job was cancelled".to_string() ) );
-        }
-        BatchJobState::Pending | BatchJobState::Running =>
-        {
-          // Check timeout
-          if start.elapsed().unwrap_or( Duration::ZERO ) > timeout
-          {
-            return Err( Error::ApiError( "Batch job timeout".to_string() ) );
-          }
+      inlined.inlined_responses.clone()
+    }
+    else if let Some( file ) = &output.responses_file
+    {
+      self.download_results( file ).await?
+    }
+    else
+    {
+      return Err( Error::ApiError( format!( "Batch job {} holds no results", job.name ) ) );
+    };
+    Ok( BatchJobResults { job, responses } )
+  }
 
-          // Wait before next poll
-          tokio ::time::sleep( poll_interval ).await;
-        }
-      }
+  /// Download and parse a JSONL result file, one result per line.
+  async fn download_results( &self, file : &str ) -> Result< Vec< BatchResponse >, Error >
+  {
+    let url = format!( "{}/download/v1beta/{file}:download?alt=media", self.client.base_url );
+    let response = crate::internal::http::execute_raw( &self.client.http, reqwest::Method::GET, &url, &self.client.api_key, None::< &() > ).await?;
+    let status = response.status();
+    let text = response.text().await
+      .map_err( | e | Error::NetworkError( format!( "Failed to read batch results {file} : {e}" ) ) )?;
+    if !status.is_success()
+    {
+      return Err( Error::ApiError( format!( "HTTP {} downloading batch results {file} : {text}", status.as_u16() ) ) );
     }
+    BatchResponse::parse_jsonl( &text )
+      .map_err( | e | Error::DeserializationError( format!( "Invalid line in batch results {file} : {e}" ) ) )
   }
 
-  /// Retrieve results from a completed batch job.
+  /// Cancel a running batch job.
   ///
   /// # Arguments
   ///
-  /// * `job_id` - The batch job identifier
-  ///
-  /// # Returns
-  ///
-  /// Returns BatchJobResults with responses and billing info.
+  /// * `name` - The batch job name
   ///
   /// # Errors
   ///
-  /// Returns error if results not available or expired.
-  async fn retrieve_results( &self, job_id : &str ) -> Result< BatchJobResults, Error >
+  /// Returns error if job cannot be cancelled or not found.
+  #[ inline ]
+  pub async fn cancel( &self, name : &str ) -> Result< (), Error >
   {
-    let _ = job_id;
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    let url = format!( "{}/v1beta/{}:cancel", self.client.base_url, batch_name( name )? );
+    crate::internal::http::enterprise::execute_with_optional_retries::< serde_json::Value, serde_json::Value >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( &serde_json::json!( {} ) ),
+    )
+    .await
+    .map( | _ | () )
   }
 
-  /// Cancel a running batch job.
+  /// Delete a batch job; a running job is cancelled first by the API.
   ///
   /// # Arguments
   ///
-  /// * `job_id` - The batch job identifier
+  /// * `name` - The batch job name
   ///
   /// # Errors
   ///
-  /// Returns error if job cannot be cancelled or not found.
-  pub async fn cancel( &self, job_id : &str ) -> Result< (), Error >
+  /// Returns error if job not found or API call fails.
+  #[ inline ]
+  pub async fn delete( &self, name : &str ) -> Result< (), Error >
   {
-    let _ = job_id;
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    let url = format!( "{}/v1beta/{}", self.client.base_url, batch_name( name )? );
+    crate::internal::http::enterprise::execute_with_optional_retries::< (), serde_json::Value >
+    (
+      self.client,
+      reqwest ::Method::DELETE,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+    .map( | _ | () )
   }
 
   /// List all batch jobs.
@@ -179,6 +292,7 @@ impl BatchApi
   /// # Errors
   ///
   /// Returns error if list operation fails.
+  #[ inline ]
   pub async fn list( &self ) -> Result< BatchJobList, Error >
   {
     self.list_with_page_size( None, None ).await
@@ -197,6 +311,7 @@ impl BatchApi
   /// # Errors
   ///
   /// Returns error if token invalid or list fails.
+  #[ inline ]
   pub async fn list_with_token( &self, page_token : &str ) -> Result< BatchJobList, Error >
   {
     self.list_with_page_size( None, Some( page_token.to_string() ) ).await
@@ -205,11 +320,49 @@ impl BatchApi
   /// Internal list implementation with page size and token.
   async fn list_with_page_size(
     &self,
-    _page_size : Option< i32 >,
-    _page_token : Option< String >
+    page_size : Option< i32 >,
+    page_token : Option< String >
   ) -> Result< BatchJobList, Error >
   {
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    #[ derive( Serialize, Deserialize ) ]
+    #[ serde( rename_all = "camelCase" ) ]
+    struct ListOperationsResponse
+    {
+      #[ serde( default ) ]
+      operations : Vec< Operation >,
+      next_page_token : Option< String >,
+    }
+
+    let mut query = Vec::new();
+    if let Some( size ) = page_size
+    {
+      query.push( format!( "pageSize={size}" ) );
+    }
+    if let Some( token ) = page_token
+    {
+      query.push( format!( "pageToken={token}" ) );
+    }
+    let mut url = format!( "{}/v1beta/batches", self.client.base_url );
+    if !query.is_empty()
+    {
+      url.push( '?' );
+      url.push_str( &query.join( "&" ) );
+    }
+
+    let page = crate::internal::http::enterprise::execute_with_optional_retries::< (), ListOperationsResponse >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await?;
+    Ok( BatchJobList
+    {
+      jobs : page.operations.into_iter().map( BatchJob::from_operation ).collect::< Result< _, _ > >()?,
+      next_page_token : page.next_page_token.filter( | token | !token.is_empty() ),
+    } )
   }
 
   /// Create a batch job for embedding generation.
@@ -226,21 +379,26 @@ impl BatchApi
   /// # Errors
   ///
   /// Returns error if job creation fails.
+  #[ inline ]
   pub async fn create_embedding_batch(
     &self,
     model : &str,
     texts : Vec< String >
   ) -> Result< BatchJob, Error >
   {
-    let _ = ( model, texts );
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    if texts.is_empty()
+    {
+      return Err( Error::InvalidArgument( "Embedding batch job needs at least one text".to_string() ) );
+    }
+    let request = CreateBatchEmbeddingRequest::texts( &texts );
+    self.create_job( model, "asyncBatchEmbedContent", &serde_json::json!( { "batch" : request } ) ).await
   }
 
   /// Wait for embedding batch completion and retrieve results.
   ///
   /// # Arguments
   ///
-  /// * `job_id` - The batch job identifier
+  /// * `name` - The batch job name
   /// * `timeout` - Maximum time to wait
   ///
   /// # Returns
@@ -250,52 +408,69 @@ impl BatchApi
   /// # Errors
   ///
   /// Returns error if timeout or job fails.
+  #[ inline ]
   pub async fn wait_and_retrieve_embeddings(
     &self,
-    job_id : &str,
+    name : &str,
     timeout : Duration
   ) -> Result< BatchEmbeddingResults, Error >
   {
-    let start = SystemTime::now();
-    let poll_interval = Duration::from_secs( 5 );
-
-    loop
+    let job = self.poll_until_done( name, POLL_INTERVAL, Instant::now() + timeout ).await?;
+    let output = finished_output( &job )?;
+    let Some( inlined ) = &output.inlined_embed_content_responses else
     {
-      let status = self.get_status( job_id ).await?;
-
-      match status.state
-      {
-        BatchJobState::Succeeded | BatchJobState::PartiallyCompleted =>
-        {
-          return self.retrieve_embedding_results( job_id ).await;
-        }
-        BatchJobState::Failed =>
-        {
-          return Err( Error::ApiError(
-            status.error.unwrap_or_else( || "Batch job failed".to_string() )
-          ) );
-        }
-        BatchJobState::Cancelled =>
-        {
-          return Err( Error::ApiError( "Batch job was cancelled".to_string() ) );
-        }
-        BatchJobState::Pending | BatchJobState::Running =>
-        {
-          if start.elapsed().unwrap_or( Duration::ZERO ) > timeout
-          {
-            return Err( Error::ApiError( "Batch job timeout".to_string() ) );
-          }
+      return Err( Error::ApiError( format!( "Batch job {} holds no inlined embeddings", job.name ) ) );
+    };
+    let embeddings = inlined.inlined_responses.iter()
+      .map( | result | result.response.as_ref().map( | response | response.embedding.clone() ) )
+      .collect();
+    Ok( BatchEmbeddingResults { job, embeddings } )
+  }
 
-          tokio ::time::sleep( poll_interval ).await;
-        }
-      }
+  /// Post `body` to `models/{model}:{method}` and read the job it creates.
+  async fn create_job( &self, model : &str, method : &str, body : &serde_json::Value ) -> Result< BatchJob, Error >
+  {
+    if model.is_empty()
+    {
+      return Err( Error::InvalidArgument( "Model name cannot be empty".to_string() ) );
     }
+    let model = model.strip_prefix( "models/" ).unwrap_or( model );
+    let url = format!( "{}/v1beta/models/{model}:{method}", self.client.base_url );
+    let operation = crate::internal::http::enterprise::execute_with_optional_retries::< serde_json::Value, Operation >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( body ),
+    )
+    .await?;
+    BatchJob::from_operation( operation )
   }
+}
 
-  /// Retrieve embedding results from completed job.
-  async fn retrieve_embedding_results( &self, job_id : &str ) -> Result< BatchEmbeddingResults, Error >
+/// `batches/{id}` for a job name or a bare ID.
+fn batch_name( name : &str ) -> Result< String, Error >
+{
+  if name.is_empty()
+  {
+    return Err( Error::InvalidArgument( "Batch job name cannot be empty".to_string() ) );
+  }
+  Ok( if name.contains( '/' ) { name.to_string() } else { format!( "batches/{name}" ) } )
+}
+
+/// The output of a job that succeeded.
+fn finished_output( job : &BatchJob ) -> Result< &BatchOutput, Error >
+{
+  match job.state
   {
-    let _ = job_id;
-    Err( Error::NotImplemented( "Batch Mode API endpoints not yet available from Gemini".to_string() ) )
+    BatchJobState::Succeeded => job.output.as_ref()
+      .ok_or_else( || Error::ApiError( format!( "Batch job {} holds no results", job.name ) ) ),
+    BatchJobState::Failed => Err( Error::ApiError( format!( "Batch job {} failed : {}", job.name,
+      job.error.as_ref().map_or( "no reason given", | error | error.message.as_str() ) ) ) ),
+    BatchJobState::Cancelled => Err( Error::ApiError( format!( "Batch job {} was cancelled", job.name ) ) ),
+    BatchJobState::Expired => Err( Error::ApiError( format!( "Batch job {} expired before finishing", job.name ) ) ),
+    BatchJobState::Pending | BatchJobState::Running | BatchJobState::Unspecified =>
+      Err( Error::InvalidArgument( format!( "Batch job {} has not finished", job.name ) ) ),
   }
 }
@@ -4,7 +4,7 @@
 //! accessing different Gemini API endpoints.
 
 use super::Client;
-use super::api_interfaces::{ ModelsApi, TunedModelsApi, FilesApi, CachedContentApi };
+use super::api_interfaces::{ ModelsApi, TunedModelsApi, FilesApi, CachedContentApi, CorporaApi, OperationsApi };
 
 #[ cfg( feature = "chat" ) ]
 use super::api_interfaces::ChatApi;
@@ -314,6 +314,24 @@ impl Client
         CachedContentApi { client : self }
     }
 
+    /// Get a corpora API instance for Semantic Retrieval: corpora, their
+    /// documents and chunks, and corpus queries
+    #[ must_use ]
+    #[ inline ]
+    pub fn corpora( &self ) -> CorporaApi< '_ >
+    {
+        CorporaApi { client : self }
+    }
+
+    /// Get an operations API instance for long-running operations, such as
+    /// video generations
+    #[ must_use ]
+    #[ inline ]
+    pub fn operations( &self ) -> OperationsApi< '_ >
+    {
+        OperationsApi { client : self }
+    }
+
     /// Access the Batch Mode API for async job-based processing with 50% cost discount.
     ///
     /// Batch Mode provides:
@@ -340,14 +358,14 @@ impl Client
     /// let batch_job = client.batches().create_inline( "gemini-2.5-flash", requests ).await?;
     ///
     /// // Poll for completion and retrieve results
-    /// let results = client.batches().wait_and_retrieve( &batch_job.job_id, std::time::Duration::from_secs( 300 ) ).await?;
+    /// let results = client.batches().wait_and_retrieve( &batch_job.name, std::time::Duration::from_secs( 300 ) ).await?;
     /// # Ok( () )
     /// # }
     /// ```
     #[ cfg( feature = "batch_operations" ) ]
     #[ must_use ]
     #[ inline ]
-    pub fn batches( &self ) -> crate::batch_api::BatchApi
+    pub fn batches( &self ) -> crate::batch_api::BatchApi< '_ >
     {
         crate ::batch_api::BatchApi::new( self )
     }
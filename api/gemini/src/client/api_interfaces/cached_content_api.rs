@@ -1,6 +1,7 @@
 //! API handle for cached content management operations.
 
 use crate::error::Error;
+use core::time::Duration;
 use crate::models::{ CreateCachedContentRequest, CachedContentResponse, ListCachedContentsResponse, UpdateCachedContentRequest };
 use super::super::Client;
 
@@ -99,7 +100,7 @@ impl CachedContentApi< '_ >
   ///
   /// # Arguments
   ///
-  /// * `cache_id` - The unique identifier of the cached content to retrieve
+  /// * `cache_id` - The ID or full `cachedContents/{id}` name of the cached content to retrieve
   ///
   /// # Returns
   ///
@@ -111,7 +112,7 @@ impl CachedContentApi< '_ >
   #[ inline ]
   pub async fn get( &self, cache_id : &str ) -> Result< CachedContentResponse, Error >
   {
-    let url = format!( "{}/v1beta/cachedContents/{}", self.client.base_url, urlencoding::encode( cache_id ) );
+    let url = self.url( cache_id );
 
     crate ::internal::http::enterprise::execute_with_optional_retries::< (), CachedContentResponse >
     (
@@ -126,9 +127,12 @@ impl CachedContentApi< '_ >
 
   /// Update cached content properties
   ///
+  /// Only the expiration of a cache can change; the fields the request sets
+  /// are sent as its `updateMask`.
+  ///
   /// # Arguments
   ///
-  /// * `cache_id` - The unique identifier of the cached content to update
+  /// * `cache_id` - The ID or full `cachedContents/{id}` name of the cached content to update
   /// * `request` - The update cached content request with the changes
   ///
   /// # Returns
@@ -137,11 +141,18 @@ impl CachedContentApi< '_ >
   ///
   /// # Errors
   ///
-  /// Returns an error if the update operation fails or the cache is not found
+  /// Returns `Error::InvalidArgument` if the request sets neither `ttl` nor
+  /// `expire_time`, or an error if the update operation fails or the cache
+  /// is not found
   #[ inline ]
   pub async fn update( &self, cache_id : &str, request : &UpdateCachedContentRequest ) -> Result< CachedContentResponse, Error >
   {
-    let url = format!( "{}/v1beta/cachedContents/{}", self.client.base_url, urlencoding::encode( cache_id ) );
+    let mask = request.update_mask();
+    if mask.is_empty()
+    {
+      return Err( Error::InvalidArgument( "Cached content update must set ttl or expire_time".to_string() ) );
+    }
+    let url = format!( "{}?updateMask={}", self.url( cache_id ), urlencoding::encode( &mask ) );
 
     crate ::internal::http::enterprise::execute_with_optional_retries::< UpdateCachedContentRequest, CachedContentResponse >
     (
@@ -154,11 +165,31 @@ impl CachedContentApi< '_ >
     .await
   }
 
+  /// Extend or shorten the life of cached content to `ttl` from now
+  ///
+  /// # Arguments
+  ///
+  /// * `cache_id` - The ID or full `cachedContents/{id}` name of the cached content
+  /// * `ttl` - How long the cache lives from now
+  ///
+  /// # Returns
+  ///
+  /// Returns the updated cached content response with its new `expire_time`
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the update operation fails or the cache is not found
+  #[ inline ]
+  pub async fn update_ttl( &self, cache_id : &str, ttl : Duration ) -> Result< CachedContentResponse, Error >
+  {
+    self.update( cache_id, &UpdateCachedContentRequest::ttl( ttl ) ).await
+  }
+
   /// Delete cached content
   ///
   /// # Arguments
   ///
-  /// * `cache_id` - The unique identifier of the cached content to delete
+  /// * `cache_id` - The ID or full `cachedContents/{id}` name of the cached content to delete
   ///
   /// # Returns
   ///
@@ -170,7 +201,7 @@ impl CachedContentApi< '_ >
   #[ inline ]
   pub async fn delete( &self, cache_id : &str ) -> Result< (), Error >
   {
-    let url = format!( "{}/v1beta/cachedContents/{}", self.client.base_url, urlencoding::encode( cache_id ) );
+    let url = self.url( cache_id );
 
     let _response : serde_json::Value = crate::internal::http::enterprise::execute_with_optional_retries
     (
@@ -184,4 +215,11 @@ impl CachedContentApi< '_ >
 
     Ok( () )
   }
+
+  /// The URL of a cache, from its ID or its full name.
+  fn url( &self, cache_id : &str ) -> String
+  {
+    let id = cache_id.strip_prefix( "cachedContents/" ).unwrap_or( cache_id );
+    format!( "{}/v1beta/cachedContents/{}", self.client.base_url, urlencoding::encode( id ) )
+  }
 }
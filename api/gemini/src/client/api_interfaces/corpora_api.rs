@@ -0,0 +1,637 @@
+//! API handles for Semantic Retrieval: corpora, their documents, and the
+//! chunks of those documents.
+//!
+//! Resources are named by the API (`corpora/{corpus}/documents/{document}/chunks/{chunk}`);
+//! every method taking a name also accepts the bare ID of a resource under
+//! the handle's parent.
+
+use crate::error::Error;
+use crate::models::
+{
+  Corpus, ListCorporaResponse, Document, ListDocumentsResponse, Chunk, ListChunksResponse,
+  BatchCreateChunksRequest, UpdateChunkRequest, BatchUpdateChunksRequest,
+  BatchChunksResponse, DeleteChunkRequest, BatchDeleteChunksRequest, QueryCorpusRequest, QueryCorpusResponse,
+};
+use super::super::Client;
+
+/// The full name of `id` in `collection` under `parent`; a name that already
+/// has a path is kept as given.
+fn resource_name( parent : &str, collection : &str, id : &str ) -> String
+{
+  if id.contains( '/' )
+  {
+    id.to_string()
+  }
+  else if parent.is_empty()
+  {
+    format!( "{collection}/{id}" )
+  }
+  else
+  {
+    format!( "{parent}/{collection}/{id}" )
+  }
+}
+
+/// The URL of a list request, with its page parameters.
+fn list_url( client : &Client, collection : &str, page_size : Option< i32 >, page_token : Option< &str > ) -> String
+{
+  let mut url = format!( "{}/v1beta/{collection}", client.base_url );
+  let mut query_params = Vec::new();
+
+  if let Some( size ) = page_size
+  {
+    query_params.push( format!( "pageSize={size}" ) );
+  }
+
+  if let Some( token ) = page_token
+  {
+    query_params.push( format!( "pageToken={}", urlencoding::encode( token ) ) );
+  }
+
+  if !query_params.is_empty()
+  {
+    url.push( '?' );
+    url.push_str( &query_params.join( "&" ) );
+  }
+  url
+}
+
+/// The URL of an update, after checking that the resource is named.
+fn update_url( client : &Client, name : &str, update_mask : &str ) -> Result< String, Error >
+{
+  if name.is_empty()
+  {
+    return Err( Error::InvalidArgument( "The resource to update must have a name".to_string() ) );
+  }
+  Ok( format!( "{}/v1beta/{name}?updateMask={}", client.base_url, urlencoding::encode( update_mask ) ) )
+}
+
+/// Sends a request without a body whose response is ignored.
+async fn send_empty( client : &Client, method : reqwest::Method, url : &str ) -> Result< (), Error >
+{
+  let _response : serde_json::Value = crate::internal::http::enterprise::execute_with_optional_retries
+  (
+    client,
+    method,
+    url,
+    &client.api_key,
+    None::< &() >,
+  )
+  .await?;
+
+  Ok( () )
+}
+
+/// API handle for corpora, the collections Semantic Retrieval queries.
+///
+/// Provides direct access to the `corpora` resource without client-side
+/// logic; documents and chunks are reached through [`CorporaApi::documents`].
+#[ derive( Debug ) ]
+pub struct CorporaApi< 'a >
+{
+    pub( crate ) client : &'a Client,
+}
+
+impl< 'a > CorporaApi< 'a >
+{
+  /// Create a corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The corpus to create; its name is assigned by the API when empty
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the creation fails, e.g. when the project has too many corpora
+  #[ inline ]
+  pub async fn create( &self, corpus : &Corpus ) -> Result< Corpus, Error >
+  {
+    let url = format!( "{}/v1beta/corpora", self.client.base_url );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Corpus, Corpus >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( corpus ),
+    )
+    .await
+  }
+
+  /// List the corpora of the project
+  ///
+  /// # Arguments
+  ///
+  /// * `page_size` - Optional maximum number of corpora to return per page
+  /// * `page_token` - Optional token for retrieving subsequent pages
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the listing operation fails
+  #[ inline ]
+  pub async fn list( &self, page_size : Option< i32 >, page_token : Option< &str > ) -> Result< ListCorporaResponse, Error >
+  {
+    let url = list_url( self.client, "corpora", page_size, page_token );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), ListCorporaResponse >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Get a corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The ID or full `corpora/{id}` name of the corpus
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the corpus is not found or the request fails
+  #[ inline ]
+  pub async fn get( &self, corpus : &str ) -> Result< Corpus, Error >
+  {
+    let url = format!( "{}/v1beta/{}", self.client.base_url, resource_name( "", "corpora", corpus ) );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), Corpus >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Update a corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The named corpus with its new values
+  /// * `update_mask` - The fields to update; only `displayName` can change
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if the corpus has no name, or an error if the update fails
+  #[ inline ]
+  pub async fn update( &self, corpus : &Corpus, update_mask : &str ) -> Result< Corpus, Error >
+  {
+    let url = update_url( self.client, &corpus.name, update_mask )?;
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Corpus, Corpus >
+    (
+      self.client,
+      reqwest ::Method::PATCH,
+      &url,
+      &self.client.api_key,
+      Some( corpus ),
+    )
+    .await
+  }
+
+  /// Delete a corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The ID or full `corpora/{id}` name of the corpus
+  /// * `force` - Whether to delete its documents and chunks too; without it, a corpus
+  ///   that has documents is not deleted
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the deletion fails or the corpus is not found
+  #[ inline ]
+  pub async fn delete( &self, corpus : &str, force : bool ) -> Result< (), Error >
+  {
+    let url = format!( "{}/v1beta/{}?force={force}", self.client.base_url, resource_name( "", "corpora", corpus ) );
+    send_empty( self.client, reqwest::Method::DELETE, &url ).await
+  }
+
+  /// Retrieve the chunks of a corpus most relevant to a query
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The ID or full `corpora/{id}` name of the corpus
+  /// * `request` - The query, its metadata filters, and the number of results
+  ///
+  /// # Returns
+  ///
+  /// Returns the relevant chunks, most relevant first
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the query fails or the corpus is not found
+  #[ inline ]
+  pub async fn query( &self, corpus : &str, request : &QueryCorpusRequest ) -> Result< QueryCorpusResponse, Error >
+  {
+    let url = format!( "{}/v1beta/{}:query", self.client.base_url, resource_name( "", "corpora", corpus ) );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< QueryCorpusRequest, QueryCorpusResponse >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( request ),
+    )
+    .await
+  }
+
+  /// Get a handle for the documents of a corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `corpus` - The ID or full `corpora/{id}` name of the corpus
+  #[ inline ]
+  #[ must_use ]
+  pub fn documents( &self, corpus : &str ) -> DocumentsApi< 'a >
+  {
+    DocumentsApi { client : self.client, corpus : resource_name( "", "corpora", corpus ) }
+  }
+}
+
+/// API handle for the documents of one corpus.
+#[ derive( Debug ) ]
+pub struct DocumentsApi< 'a >
+{
+    client : &'a Client,
+    corpus : String,
+}
+
+impl< 'a > DocumentsApi< 'a >
+{
+  /// Create a document in the corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `document` - The document to create; its name is assigned by the API when empty
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the creation fails, e.g. when the corpus has too many documents
+  #[ inline ]
+  pub async fn create( &self, document : &Document ) -> Result< Document, Error >
+  {
+    let url = format!( "{}/v1beta/{}/documents", self.client.base_url, self.corpus );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Document, Document >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( document ),
+    )
+    .await
+  }
+
+  /// List the documents of the corpus
+  ///
+  /// # Arguments
+  ///
+  /// * `page_size` - Optional maximum number of documents to return per page
+  /// * `page_token` - Optional token for retrieving subsequent pages
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the listing operation fails
+  #[ inline ]
+  pub async fn list( &self, page_size : Option< i32 >, page_token : Option< &str > ) -> Result< ListDocumentsResponse, Error >
+  {
+    let url = list_url( self.client, &format!( "{}/documents", self.corpus ), page_size, page_token );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), ListDocumentsResponse >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Get a document
+  ///
+  /// # Arguments
+  ///
+  /// * `document` - The ID or full name of the document
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the document is not found or the request fails
+  #[ inline ]
+  pub async fn get( &self, document : &str ) -> Result< Document, Error >
+  {
+    let url = format!( "{}/v1beta/{}", self.client.base_url, resource_name( &self.corpus, "documents", document ) );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), Document >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Update a document
+  ///
+  /// # Arguments
+  ///
+  /// * `document` - The named document with its new values
+  /// * `update_mask` - The fields to update: `displayName`, `customMetadata`, or both, comma-separated
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if the document has no name, or an error if the update fails
+  #[ inline ]
+  pub async fn update( &self, document : &Document, update_mask : &str ) -> Result< Document, Error >
+  {
+    let url = update_url( self.client, &document.name, update_mask )?;
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Document, Document >
+    (
+      self.client,
+      reqwest ::Method::PATCH,
+      &url,
+      &self.client.api_key,
+      Some( document ),
+    )
+    .await
+  }
+
+  /// Delete a document
+  ///
+  /// # Arguments
+  ///
+  /// * `document` - The ID or full name of the document
+  /// * `force` - Whether to delete its chunks too; without it, a document that has
+  ///   chunks is not deleted
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the deletion fails or the document is not found
+  #[ inline ]
+  pub async fn delete( &self, document : &str, force : bool ) -> Result< (), Error >
+  {
+    let url = format!( "{}/v1beta/{}?force={force}", self.client.base_url, resource_name( &self.corpus, "documents", document ) );
+    send_empty( self.client, reqwest::Method::DELETE, &url ).await
+  }
+
+  /// Get a handle for the chunks of a document
+  ///
+  /// # Arguments
+  ///
+  /// * `document` - The ID or full name of the document
+  #[ inline ]
+  #[ must_use ]
+  pub fn chunks( &self, document : &str ) -> ChunksApi< 'a >
+  {
+    ChunksApi { client : self.client, document : resource_name( &self.corpus, "documents", document ) }
+  }
+}
+
+/// API handle for the chunks of one document.
+#[ derive( Debug ) ]
+pub struct ChunksApi< 'a >
+{
+    client : &'a Client,
+    document : String,
+}
+
+impl ChunksApi< '_ >
+{
+  /// The document the chunks belong to, e.g. `corpora/{corpus}/documents/{document}`
+  #[ inline ]
+  #[ must_use ]
+  pub fn document( &self ) -> &str
+  {
+    &self.document
+  }
+
+  /// Create a chunk in the document
+  ///
+  /// # Arguments
+  ///
+  /// * `chunk` - The chunk to create; its name is assigned by the API when empty
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the creation fails
+  #[ inline ]
+  pub async fn create( &self, chunk : &Chunk ) -> Result< Chunk, Error >
+  {
+    let url = format!( "{}/v1beta/{}/chunks", self.client.base_url, self.document );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Chunk, Chunk >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( chunk ),
+    )
+    .await
+  }
+
+  /// Create up to 100 chunks in the document in one request
+  ///
+  /// # Arguments
+  ///
+  /// * `chunks` - The chunks to create
+  ///
+  /// # Returns
+  ///
+  /// Returns the created chunks, named, in request order
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the creation fails; no chunk is created then
+  #[ inline ]
+  pub async fn batch_create( &self, chunks : &[ Chunk ] ) -> Result< Vec< Chunk >, Error >
+  {
+    let url = format!( "{}/v1beta/{}/chunks:batchCreate", self.client.base_url, self.document );
+    let request = BatchCreateChunksRequest::new( &self.document, chunks );
+
+    let response = crate::internal::http::enterprise::execute_with_optional_retries::< BatchCreateChunksRequest, BatchChunksResponse >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( &request ),
+    )
+    .await?;
+
+    Ok( response.chunks )
+  }
+
+  /// List the chunks of the document
+  ///
+  /// # Arguments
+  ///
+  /// * `page_size` - Optional maximum number of chunks to return per page
+  /// * `page_token` - Optional token for retrieving subsequent pages
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the listing operation fails
+  #[ inline ]
+  pub async fn list( &self, page_size : Option< i32 >, page_token : Option< &str > ) -> Result< ListChunksResponse, Error >
+  {
+    let url = list_url( self.client, &format!( "{}/chunks", self.document ), page_size, page_token );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), ListChunksResponse >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Get a chunk
+  ///
+  /// # Arguments
+  ///
+  /// * `chunk` - The ID or full name of the chunk
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the chunk is not found or the request fails
+  #[ inline ]
+  pub async fn get( &self, chunk : &str ) -> Result< Chunk, Error >
+  {
+    let url = format!( "{}/v1beta/{}", self.client.base_url, resource_name( &self.document, "chunks", chunk ) );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), Chunk >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Update a chunk
+  ///
+  /// # Arguments
+  ///
+  /// * `chunk` - The named chunk with its new values
+  /// * `update_mask` - The fields to update: `data`, `customMetadata`, or both, comma-separated
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if the chunk has no name, or an error if the update fails
+  #[ inline ]
+  pub async fn update( &self, chunk : &Chunk, update_mask : &str ) -> Result< Chunk, Error >
+  {
+    let url = update_url( self.client, &chunk.name, update_mask )?;
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< Chunk, Chunk >
+    (
+      self.client,
+      reqwest ::Method::PATCH,
+      &url,
+      &self.client.api_key,
+      Some( chunk ),
+    )
+    .await
+  }
+
+  /// Update up to 100 chunks of the document in one request
+  ///
+  /// # Arguments
+  ///
+  /// * `chunks` - The named chunks with their new values
+  /// * `update_mask` - The fields to update in every chunk: `data`, `customMetadata`, or both
+  ///
+  /// # Returns
+  ///
+  /// Returns the updated chunks, in request order
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the update fails; no chunk is updated then
+  #[ inline ]
+  pub async fn batch_update( &self, chunks : &[ Chunk ], update_mask : &str ) -> Result< Vec< Chunk >, Error >
+  {
+    let url = format!( "{}/v1beta/{}/chunks:batchUpdate", self.client.base_url, self.document );
+    let request = BatchUpdateChunksRequest
+    {
+      requests : chunks.iter().map( | chunk | UpdateChunkRequest { chunk : chunk.clone(), update_mask : update_mask.to_string() } ).collect(),
+    };
+
+    let response = crate::internal::http::enterprise::execute_with_optional_retries::< BatchUpdateChunksRequest, BatchChunksResponse >
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( &request ),
+    )
+    .await?;
+
+    Ok( response.chunks )
+  }
+
+  /// Delete a chunk
+  ///
+  /// # Arguments
+  ///
+  /// * `chunk` - The ID or full name of the chunk
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the deletion fails or the chunk is not found
+  #[ inline ]
+  pub async fn delete( &self, chunk : &str ) -> Result< (), Error >
+  {
+    let url = format!( "{}/v1beta/{}", self.client.base_url, resource_name( &self.document, "chunks", chunk ) );
+    send_empty( self.client, reqwest::Method::DELETE, &url ).await
+  }
+
+  /// Delete chunks of the document in one request
+  ///
+  /// # Arguments
+  ///
+  /// * `chunks` - The IDs or full names of the chunks
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the deletion fails; no chunk is deleted then
+  #[ inline ]
+  pub async fn batch_delete( &self, chunks : &[ &str ] ) -> Result< (), Error >
+  {
+    let url = format!( "{}/v1beta/{}/chunks:batchDelete", self.client.base_url, self.document );
+    let request = BatchDeleteChunksRequest
+    {
+      requests : chunks.iter().map( | chunk | DeleteChunkRequest { name : resource_name( &self.document, "chunks", chunk ) } ).collect(),
+    };
+
+    let _response : serde_json::Value = crate::internal::http::enterprise::execute_with_optional_retries
+    (
+      self.client,
+      reqwest ::Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( &request ),
+    )
+    .await?;
+
+    Ok( () )
+  }
+}
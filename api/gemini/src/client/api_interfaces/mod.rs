@@ -7,6 +7,8 @@ mod models_api;
 mod tuned_models_api;
 mod files_api;
 mod cached_content_api;
+mod corpora_api;
+mod operations_api;
 
 #[ cfg( feature = "chat" ) ]
 mod chat_api;
@@ -18,6 +20,8 @@ pub use models_api::ModelsApi;
 pub use tuned_models_api::TunedModelsApi;
 pub use files_api::FilesApi;
 pub use cached_content_api::CachedContentApi;
+pub use corpora_api::{ CorporaApi, DocumentsApi, ChunksApi };
+pub use operations_api::OperationsApi;
 
 #[ cfg( feature = "chat" ) ]
 pub use chat_api::ChatApi;
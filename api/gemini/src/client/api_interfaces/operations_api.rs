@@ -0,0 +1,81 @@
+//! API handle for long-running operations.
+
+use std::time::{ Duration, Instant };
+use crate::error::Error;
+use crate::models::Operation;
+use super::super::Client;
+
+/// API handle for long-running operations, such as video generations.
+///
+/// Provides direct access to the state of operations without client-side
+/// logic; polling happens only when [`OperationsApi::poll_until_done`] is
+/// called, at the interval and until the deadline given.
+#[ derive( Debug ) ]
+pub struct OperationsApi< 'a >
+{
+    pub( crate ) client : &'a Client,
+}
+
+impl OperationsApi< '_ >
+{
+  /// Get the current state of an operation
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The operation name, e.g. `models/veo-3.0-generate-001/operations/abc123`;
+  ///   a bare ID is taken as `operations/{id}`
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the retrieval fails, e.g. when the operation does not exist
+  #[ inline ]
+  pub async fn get( &self, name : &str ) -> Result< Operation, Error >
+  {
+    if name.is_empty()
+    {
+      return Err( Error::InvalidArgument( "Operation name cannot be empty".to_string() ) );
+    }
+    let name = if name.contains( '/' ) { name.to_string() } else { format!( "operations/{name}" ) };
+    let url = format!( "{}/v1beta/{name}", self.client.base_url );
+
+    crate ::internal::http::enterprise::execute_with_optional_retries::< (), Operation >
+    (
+      self.client,
+      reqwest ::Method::GET,
+      &url,
+      &self.client.api_key,
+      None,
+    )
+    .await
+  }
+
+  /// Poll an operation until it is done
+  ///
+  /// The operation is fetched right away, then every `interval`, and once
+  /// more at the deadline. A failed operation is returned like a successful
+  /// one: check its `error`, or read its result with [`Operation::response_as`].
+  ///
+  /// # Arguments
+  ///
+  /// * `name` - The operation name
+  /// * `interval` - Time between two polls
+  /// * `deadline` - When to stop polling
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::TimeoutError`] if the operation is not done by the
+  /// deadline, or the error of a failed poll
+  #[ inline ]
+  pub async fn poll_until_done( &self, name : &str, interval : Duration, deadline : Instant ) -> Result< Operation, Error >
+  {
+    crate ::internal::poll::poll_until_done
+    (
+      &format!( "Operation {name}" ),
+      || self.get( name ),
+      | operation : &Operation | operation.done,
+      interval,
+      deadline,
+    )
+    .await
+  }
+}
@@ -20,7 +20,6 @@ mod setters_compression;
 mod presets;
 
   /// Builder for configuring and constructing a `Client` instance.
-  #[ derive( Debug ) ]
   #[ allow( clippy::struct_excessive_bools ) ] // Configuration struct with feature flags
   pub struct ClientBuilder
   {
@@ -43,6 +42,8 @@ mod presets;
     enable_retry_metrics : bool,
     #[ cfg( feature = "retry" ) ]
     max_elapsed_time : Option< Duration >,
+    #[ cfg( feature = "retry" ) ]
+    call_budget : Option< api_common::CallBudget >,
     #[ cfg( feature = "circuit_breaker" ) ]
     enable_circuit_breaker : bool,
     #[ cfg( feature = "circuit_breaker" ) ]
@@ -77,6 +78,59 @@ mod presets;
     compression_config : Option< crate::internal::http::compression::CompressionConfig >,
   }
 
+  impl core::fmt::Debug for ClientBuilder
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let mut debug = f.debug_struct( "ClientBuilder" );
+      debug.field( "base_url", &self.base_url );
+      debug.field( "api_key", &self.api_key.as_deref().map( api_secret::Masked ) );
+      debug.field( "timeout", &self.timeout );
+      #[ cfg( feature = "retry" ) ]
+      {
+        debug.field( "max_retries", &self.max_retries );
+        debug.field( "base_delay", &self.base_delay );
+        debug.field( "max_delay", &self.max_delay );
+        debug.field( "enable_jitter", &self.enable_jitter );
+        debug.field( "request_timeout", &self.request_timeout );
+        debug.field( "backoff_multiplier", &self.backoff_multiplier );
+        debug.field( "enable_retry_metrics", &self.enable_retry_metrics );
+        debug.field( "max_elapsed_time", &self.max_elapsed_time );
+        debug.field( "call_budget", &self.call_budget );
+      }
+      #[ cfg( feature = "circuit_breaker" ) ]
+      {
+        debug.field( "enable_circuit_breaker", &self.enable_circuit_breaker );
+        debug.field( "circuit_breaker_failure_threshold", &self.circuit_breaker_failure_threshold );
+        debug.field( "circuit_breaker_success_threshold", &self.circuit_breaker_success_threshold );
+        debug.field( "circuit_breaker_timeout", &self.circuit_breaker_timeout );
+        debug.field( "enable_circuit_breaker_metrics", &self.enable_circuit_breaker_metrics );
+        debug.field( "circuit_breaker_shared_state", &self.circuit_breaker_shared_state );
+      }
+      #[ cfg( feature = "caching" ) ]
+      {
+        debug.field( "enable_request_cache", &self.enable_request_cache );
+        debug.field( "cache_ttl", &self.cache_ttl );
+        debug.field( "cache_max_size", &self.cache_max_size );
+        debug.field( "enable_cache_metrics", &self.enable_cache_metrics );
+      }
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+        debug.field( "enable_rate_limiting", &self.enable_rate_limiting );
+        debug.field( "rate_limit_requests_per_second", &self.rate_limit_requests_per_second );
+        debug.field( "rate_limit_algorithm", &self.rate_limit_algorithm );
+        debug.field( "rate_limit_bucket_size", &self.rate_limit_bucket_size );
+        debug.field( "enable_rate_limiting_metrics", &self.enable_rate_limiting_metrics );
+      }
+      #[ cfg( feature = "compression" ) ]
+      {
+        debug.field( "compression_config", &self.compression_config );
+      }
+      debug.finish()
+    }
+  }
+
   impl Default for ClientBuilder
   {
     #[ inline ]
@@ -114,6 +168,8 @@ mod presets;
           enable_retry_metrics : false,
           #[ cfg( feature = "retry" ) ]
           max_elapsed_time : Some( Duration::from_secs( 60 ) ),
+          #[ cfg( feature = "retry" ) ]
+          call_budget : None,
           #[ cfg( feature = "circuit_breaker" ) ]
           enable_circuit_breaker : false,
           #[ cfg( feature = "circuit_breaker" ) ]
@@ -318,6 +374,8 @@ mod presets;
           enable_retry_metrics : self.enable_retry_metrics,
           #[ cfg( feature = "retry" ) ]
           max_elapsed_time : self.max_elapsed_time,
+          #[ cfg( feature = "retry" ) ]
+          call_budget : self.call_budget,
           #[ cfg( feature = "circuit_breaker" ) ]
           enable_circuit_breaker : self.enable_circuit_breaker,
           #[ cfg( feature = "circuit_breaker" ) ]
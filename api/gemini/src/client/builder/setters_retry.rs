@@ -97,4 +97,18 @@ impl ClientBuilder
     self.max_elapsed_time = Some( max_elapsed_time );
     self
   }
+
+  /// Sets one overall limit for each call, across every retry attempt.
+  ///
+  /// Unlike `max_elapsed_time`, the deadline also cuts off an attempt in
+  /// flight and no retry is scheduled that would sleep past it. Pass the
+  /// same budget to `FailoverManager::execute_with_failover_within` so
+  /// failover and retry stay inside one limit.
+  #[ must_use ]
+  #[ inline ]
+  pub fn call_budget( mut self, budget : api_common::CallBudget ) -> Self
+  {
+    self.call_budget = Some( budget );
+    self
+  }
 }
@@ -27,7 +27,7 @@ use super::Client;
   /// # }
   /// ```
   #[ allow( clippy::struct_excessive_bools ) ]
-  #[ derive( Debug, Clone, Former ) ]
+  #[ derive( Clone, Former ) ]
   pub struct ClientConfig
   {
     /// API key for authentication with Gemini API
@@ -135,6 +135,49 @@ use super::Client;
     pub rate_limit_bucket_size : usize,
   }
 
+  impl core::fmt::Debug for ClientConfig
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let mut debug = f.debug_struct( "ClientConfig" );
+      debug.field( "api_key", &api_secret::Masked( &self.api_key ) );
+      debug.field( "base_url", &self.base_url );
+      debug.field( "timeout", &self.timeout );
+      #[ cfg( feature = "retry" ) ]
+      {
+        debug.field( "max_retries", &self.max_retries );
+        debug.field( "base_delay", &self.base_delay );
+        debug.field( "max_delay", &self.max_delay );
+        debug.field( "enable_jitter", &self.enable_jitter );
+        debug.field( "backoff_multiplier", &self.backoff_multiplier );
+        debug.field( "enable_retry_metrics", &self.enable_retry_metrics );
+        debug.field( "max_elapsed_time", &self.max_elapsed_time );
+      }
+      #[ cfg( feature = "circuit_breaker" ) ]
+      {
+        debug.field( "enable_circuit_breaker", &self.enable_circuit_breaker );
+        debug.field( "circuit_breaker_failure_threshold", &self.circuit_breaker_failure_threshold );
+        debug.field( "circuit_breaker_success_threshold", &self.circuit_breaker_success_threshold );
+        debug.field( "circuit_breaker_timeout", &self.circuit_breaker_timeout );
+      }
+      #[ cfg( feature = "caching" ) ]
+      {
+        debug.field( "enable_request_cache", &self.enable_request_cache );
+        debug.field( "cache_ttl", &self.cache_ttl );
+        debug.field( "cache_max_size", &self.cache_max_size );
+      }
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+        debug.field( "enable_rate_limiting", &self.enable_rate_limiting );
+        debug.field( "rate_limit_requests_per_second", &self.rate_limit_requests_per_second );
+        debug.field( "rate_limit_algorithm", &self.rate_limit_algorithm );
+        debug.field( "rate_limit_bucket_size", &self.rate_limit_bucket_size );
+      }
+      debug.finish()
+    }
+  }
+
   impl ClientConfig
   {
     /// Build a `Client` from this configuration.
@@ -212,6 +255,8 @@ use super::Client;
         enable_retry_metrics : self.enable_retry_metrics,
         #[ cfg( feature = "retry" ) ]
         max_elapsed_time : self.max_elapsed_time,
+        #[ cfg( feature = "retry" ) ]
+        call_budget : None,
         #[ cfg( feature = "circuit_breaker" ) ]
         enable_circuit_breaker : self.enable_circuit_breaker,
         #[ cfg( feature = "circuit_breaker" ) ]
@@ -38,7 +38,7 @@ use super::sync::SyncClientBuilder;
   ///   Ok( () )
   /// }
   /// ```
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   #[ allow( clippy::struct_excessive_bools ) ] // Configuration struct with feature flags
   pub struct Client
   {
@@ -68,6 +68,8 @@ use super::sync::SyncClientBuilder;
     pub( crate ) enable_retry_metrics : bool,
     #[ cfg( feature = "retry" ) ]
     pub( crate ) max_elapsed_time : Option< Duration >,
+    #[ cfg( feature = "retry" ) ]
+    pub( crate ) call_budget : Option< api_common::CallBudget >,
     #[ cfg( feature = "circuit_breaker" ) ]
     // xxx : @team : Integrate circuit breaker from internal/http.rs into Client API (task/unverified/007, task/verified/003)
     // Circuit breaker is fully implemented in internal::http::CircuitBreaker
@@ -126,6 +128,61 @@ use super::sync::SyncClientBuilder;
     pub( crate ) compression_config : Option< crate::internal::http::compression::CompressionConfig >,
   }
 
+  impl core::fmt::Debug for Client
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let mut debug = f.debug_struct( "Client" );
+      debug.field( "api_key", &api_secret::Masked( &self.api_key ) );
+      debug.field( "base_url", &self.base_url );
+      debug.field( "http", &self.http );
+      debug.field( "timeout", &self.timeout );
+      #[ cfg( feature = "retry" ) ]
+      {
+        debug.field( "max_retries", &self.max_retries );
+        debug.field( "base_delay", &self.base_delay );
+        debug.field( "max_delay", &self.max_delay );
+        debug.field( "enable_jitter", &self.enable_jitter );
+        debug.field( "request_timeout", &self.request_timeout );
+        debug.field( "backoff_multiplier", &self.backoff_multiplier );
+        debug.field( "enable_retry_metrics", &self.enable_retry_metrics );
+        debug.field( "max_elapsed_time", &self.max_elapsed_time );
+        debug.field( "call_budget", &self.call_budget );
+      }
+      #[ cfg( feature = "circuit_breaker" ) ]
+      {
+        debug.field( "enable_circuit_breaker", &self.enable_circuit_breaker );
+        debug.field( "circuit_breaker_failure_threshold", &self.circuit_breaker_failure_threshold );
+        debug.field( "circuit_breaker_success_threshold", &self.circuit_breaker_success_threshold );
+        debug.field( "circuit_breaker_timeout", &self.circuit_breaker_timeout );
+        debug.field( "enable_circuit_breaker_metrics", &self.enable_circuit_breaker_metrics );
+        debug.field( "circuit_breaker_shared_state", &self.circuit_breaker_shared_state );
+      }
+      #[ cfg( feature = "caching" ) ]
+      {
+        debug.field( "enable_request_cache", &self.enable_request_cache );
+        debug.field( "cache_ttl", &self.cache_ttl );
+        debug.field( "cache_max_size", &self.cache_max_size );
+        debug.field( "enable_cache_metrics", &self.enable_cache_metrics );
+        debug.field( "request_cache", &self.request_cache );
+      }
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+        debug.field( "enable_rate_limiting", &self.enable_rate_limiting );
+        debug.field( "rate_limit_requests_per_second", &self.rate_limit_requests_per_second );
+        debug.field( "rate_limit_algorithm", &self.rate_limit_algorithm );
+        debug.field( "rate_limit_bucket_size", &self.rate_limit_bucket_size );
+        debug.field( "enable_rate_limiting_metrics", &self.enable_rate_limiting_metrics );
+      }
+      #[ cfg( feature = "compression" ) ]
+      {
+        debug.field( "compression_config", &self.compression_config );
+      }
+      debug.finish()
+    }
+  }
+
   impl Client
   {
       /// Create a new client builder using the `former` crate
@@ -153,16 +210,17 @@ use super::sync::SyncClientBuilder;
 
       /// Create a new client using the `GEMINI_API_KEY` from workspace secrets or environment.
       ///
-      /// This method attempts to load the API key in the following order:
-      /// 1. Workspace secrets file : `secret/-secrets.sh` (using `workspace_tools` 0.6.0)
+      /// The key is loaded through the fallback chain shared by every `api_llm` provider
+      /// crate (`api_secret::SecretChain::standard`):
+      /// 1. Workspace secrets files : `secret/-secrets.sh`, `secret/secrets.sh`, `secret/.env`
       /// 2. Environment variable : `GEMINI_API_KEY`
       ///
-      /// **Note**: `workspace_tools` 0.6.0 uses `secret/` (visible directory, NO dot prefix).
+      /// **Note**: `workspace_tools` uses `secret/` (visible directory, NO dot prefix).
       ///
       /// # Errors
       ///
-      /// Returns an error with detailed path information if the `GEMINI_API_KEY` cannot be loaded
-      /// from either workspace secrets or environment variable, or if the client cannot be built.
+      /// Returns an error listing every source tried if the `GEMINI_API_KEY` cannot be loaded,
+      /// or if the client cannot be built.
       ///
       /// # Examples
       ///
@@ -176,61 +234,35 @@ use super::sync::SyncClientBuilder;
     #[ inline ]
     pub fn new() -> Result< Client, Error >
     {
-        // First try to load from secret/-secrets.sh file
-        let api_key = match Self::load_api_key_from_secret_file()
-        {
-          Ok( key ) => key,
-          Err( secret_err ) => {
-            // Fallback to environment variable
-            match std::env::var( "GEMINI_API_KEY" )
-            {
-              Ok( key ) if !key.is_empty() => key,
-              _ => {
-                return Err( Error::AuthenticationError(
-                  format!(
-                    "GEMINI_API_KEY not found. Tried:\n  \
-                    1. Workspace secrets : secret/-secrets.sh ({})\n  \
-                    2. Environment variable : GEMINI_API_KEY (not set or empty)\n\n  \
-                    Setup instructions:\n  \
-                    - Add to workspace secrets : echo 'export GEMINI_API_KEY=\"your-key\"' > > secret/-secrets.sh\n  \
-                    - Or set environment : export GEMINI_API_KEY=\"your-key\"\n  \
-                    - Note : workspace_tools 0.6.0 uses secret/ (visible directory, NO dot prefix)\n  \
-                    - See tests/readme.md for detailed setup guide",
-                    secret_err
-                  )
-                ) );
-              }
-            }
-          }
-        };
-
-        Self::builder()
-          .api_key( api_key )
-          .build()
+        Self::from_secret_chain( &api_secret::SecretChain::standard() )
     }
 
-      /// Load API key from workspace `secret/-secrets.sh` file using `workspace_tools`.
-      ///
-      /// This method uses `workspace_tools` to properly locate and load secrets from the
-      /// workspace root's secret directory, following the Secret Directory Policy.
+      /// Create a new client loading `GEMINI_API_KEY` from a caller-built chain, e.g. a
+      /// Vault or AWS Secrets Manager fetch placed ahead of the standard chain.
       ///
       /// # Errors
       ///
-      /// Returns an error if workspace cannot be resolved or if `GEMINI_API_KEY` is not found
-      /// in the -secrets.sh file.
-    fn load_api_key_from_secret_file() -> Result< String, Error >
+      /// Returns an error if no source in `chain` holds the key, or if the client cannot be built.
+    #[ inline ]
+    pub fn from_secret_chain( chain : &api_secret::SecretChain ) -> Result< Client, Error >
     {
-        use workspace_tools as workspace;
+        use api_secret::ExposeSecret;
 
-        // Use workspace_tools to properly load secrets from workspace
-        let ws = workspace::workspace()
-          .map_err( | e | Error::Io( format!( "Failed to resolve workspace : {e}" ) ) )?;
+        let api_key = chain.load( "GEMINI_API_KEY" )
+          .map_err( | e | Error::AuthenticationError(
+            format!(
+              "{e}\n\n  \
+              Setup instructions:\n  \
+              - Add to workspace secrets : echo 'export GEMINI_API_KEY=\"your-key\"' > > secret/-secrets.sh\n  \
+              - Or set environment : export GEMINI_API_KEY=\"your-key\"\n  \
+              - Note : workspace_tools uses secret/ (visible directory, NO dot prefix)\n  \
+              - See tests/readme.md for detailed setup guide"
+            )
+          ) )?;
 
-        // Load GEMINI_API_KEY from -secrets.sh file in secret directory
-        let api_key = ws.load_secret_key( "GEMINI_API_KEY", "-secrets.sh" )
-          .map_err( | e | Error::AuthenticationError( format!( "key not found or file unreadable : {e}" ) ) )?;
-
-        Ok( api_key )
+        Self::builder()
+          .api_key( api_key.expose_secret().to_string() )
+          .build()
     }
 
       /// Send a GET request to the specified URL with API key authentication
@@ -273,6 +305,47 @@ use super::sync::SyncClientBuilder;
         Ok( response )
     }
 
+    /// Rebuild the HTTP client with connection tuning applied on top of the
+    /// configured timeout. Open connections of the previous client are
+    /// dropped; call [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if `tuning` names a proxy that is not a
+    /// valid URL or asks for HTTP/2 prior knowledge without the `http2`
+    /// feature, or `Error::NetworkError` if the
+    /// client cannot be built.
+    #[ cfg( feature = "connection" ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self, Error >
+    {
+        self.http = tuning.apply_to( reqwest::Client::builder().timeout( self.timeout ) ).map_err( | e | Error::InvalidArgument( e.to_string() ) )?
+          .build()
+          .map_err( |e| Error::NetworkError( format!( "Failed to create HTTP client : {e}" ) ) )?;
+        Ok( self )
+    }
+
+    /// Open a connection to the base URL ahead of the first request
+    ///
+    /// Sends one `HEAD` request without the API key, so the first real
+    /// request does not pay for DNS, TCP, and the TLS handshake, and returns
+    /// its round-trip time. Any HTTP status counts as success; only a failure
+    /// to connect is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a network or timeout error if no connection could be opened.
+    #[ inline ]
+    pub async fn warm_up( &self ) -> Result< Duration, Error >
+    {
+        let started = std::time::Instant::now();
+        self.http
+          .head( &self.base_url )
+          .send()
+          .await?;
+        Ok( started.elapsed() )
+    }
+
       /// Serialize request body to JSON string
       ///
       /// # Errors
@@ -355,6 +428,7 @@ use super::sync::SyncClientBuilder;
           backoff_multiplier : self.backoff_multiplier,
           enable_jitter : self.enable_jitter,
           max_elapsed_time : self.max_elapsed_time,
+          call_budget : self.call_budget,
         } )
       }
     }
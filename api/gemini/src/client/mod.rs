@@ -24,6 +24,8 @@ mod private
   #[ allow( unused_imports ) ]  // Used as return types but not re-exported
   pub use super::api_interfaces::{ TunedModelsApi, FilesApi };
   pub use super::api_interfaces::CachedContentApi;
+  pub use super::api_interfaces::{ CorporaApi, DocumentsApi, ChunksApi };
+  pub use super::api_interfaces::OperationsApi;
   pub use super::sync::{
     SyncClientBuilder, SyncClient, SyncModelsApi,
     SyncModelApi, SyncCachedContentApi,
@@ -44,6 +46,10 @@ mod private
   exposed use private::ConfigWatchHandle;
   exposed use private::ModelsApi;
   exposed use private::CachedContentApi;
+  exposed use private::CorporaApi;
+  exposed use private::DocumentsApi;
+  exposed use private::ChunksApi;
+  exposed use private::OperationsApi;
   exposed use private::SyncClientBuilder;
   exposed use private::SyncClient;
   exposed use private::SyncModelsApi;
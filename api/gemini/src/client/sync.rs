@@ -14,13 +14,25 @@ use crate::models::{
 use super::Client;
 
 /// Synchronous client builder for blocking API operations
-#[ derive( Debug, Clone ) ]
+#[ derive( Clone ) ]
 pub struct SyncClientBuilder
 {
   api_key : Option< String >,
   timeout : Option< Duration >,
 }
 
+impl core::fmt::Debug for SyncClientBuilder
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.debug_struct( "SyncClientBuilder" )
+      .field( "api_key", &self.api_key.as_deref().map( api_secret::Masked ) )
+      .field( "timeout", &self.timeout )
+      .finish()
+  }
+}
+
 impl SyncClientBuilder
 {
   /// Create a new sync client builder
@@ -389,6 +401,28 @@ impl SyncCachedContentApi< '_ >
     } )
   }
 
+  /// Extend or shorten the life of cached content to `ttl` from now synchronously
+  ///
+  /// # Arguments
+  ///
+  /// * `cache_id` - The ID or full `cachedContents/{id}` name of the cached content
+  /// * `ttl` - How long the cache lives from now
+  ///
+  /// # Returns
+  ///
+  /// Returns the updated cached content response with its new `expire_time`
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the update operation fails or the cache is not found
+  #[ inline ]
+  pub fn update_ttl( &self, cache_id : &str, ttl : Duration ) -> Result< CachedContentResponse, Error >
+  {
+    self.runtime.block_on( async {
+      self.client.cached_content().update_ttl( cache_id, ttl ).await
+    } )
+  }
+
   /// Delete cached content synchronously
   ///
   /// # Arguments
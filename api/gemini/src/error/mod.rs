@@ -110,6 +110,11 @@ mod private
       #[ cfg( feature = "enterprise_quota" ) ]
       #[ error( "Quota exceeded : {0}" ) ]
       QuotaExceeded( String ),
+
+      /// The call budget shared by retry and failover ran out.
+      #[ cfg( any( feature = "retry", feature = "failover" ) ) ]
+      #[ error( "Budget exhausted : {0}" ) ]
+      BudgetExhausted( String ),
   }
 
   impl From< std::io::Error > for Error
@@ -121,6 +126,40 @@ mod private
     }
   }
 
+  #[ cfg( any( feature = "retry", feature = "failover" ) ) ]
+  impl From< api_common::BudgetExhausted > for Error
+  {
+    #[ inline ]
+    fn from( err : api_common::BudgetExhausted ) -> Self
+    {
+        Error::BudgetExhausted( err.to_string() )
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< Error > for api_common::LlmError
+  {
+    #[ inline ]
+    fn from( err : Error ) -> Self
+    {
+        match err
+        {
+          Error::AuthenticationError( message ) => Self::Auth( message ),
+          Error::RateLimitError( message ) => Self::RateLimited { retry_after : None, message },
+          #[ cfg( feature = "rate_limiting" ) ]
+          Error::RateLimited( message ) => Self::RateLimited { retry_after : None, message },
+          #[ cfg( feature = "enterprise_quota" ) ]
+          Error::QuotaExceeded( message ) => Self::RateLimited { retry_after : None, message },
+          Error::NetworkError( message ) | Error::TimeoutError( message ) | Error::Io( message ) => Self::Network( message ),
+          Error::SerializationError( message ) | Error::DeserializationError( message ) => Self::Protocol( message ),
+          #[ cfg( feature = "circuit_breaker" ) ]
+          Error::CircuitBreakerOpen( message ) => Self::Overloaded( message ),
+          Error::ApiError( message ) | Error::ServerError( message ) => Self::classify( None, &message ),
+          other => Self::Provider { raw : other.to_string() },
+        }
+    }
+  }
+
   impl From< serde_json::Error > for Error
   {
     #[ inline ]
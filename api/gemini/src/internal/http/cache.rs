@@ -322,7 +322,7 @@ pub async fn execute_with_cache< T, R >
   config : &super::HttpConfig,
   cache : Option< &RequestCache >,
 )
--> Result< R, crate::error::Error >
+-> Result< R, super::HttpFailure >
 where
   T: Serialize,
   R: Serialize + for< 'de > Deserialize< 'de >,
@@ -343,7 +343,7 @@ where
   }
 
   // Cache miss or caching disabled - execute request
-  let response = super::execute( client, method.clone(), url, api_key, body, config ).await?;
+  let response = super::execute_detailed( client, method.clone(), url, api_key, body, config ).await?;
 
   // Store in cache if caching is enabled
   if should_cache
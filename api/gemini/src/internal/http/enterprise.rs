@@ -4,10 +4,10 @@ use reqwest::Method;
 use serde::{ Serialize, Deserialize };
 
 use crate::error::Error;
-use super::HttpConfig;
+use super::{ HttpConfig, HttpFailure };
 
 #[ cfg( feature = "retry" ) ]
-use super::retry::{ RetryConfig, is_retryable_error, calculate_retry_delay };
+use super::retry::{ RetryConfig, is_retryable_failure, failure_retry_delay };
 
 #[ cfg( feature = "circuit_breaker" ) ]
 use super::circuit_breaker::{ CircuitBreaker, is_circuit_breaker_error };
@@ -30,6 +30,25 @@ pub async fn execute_with_optional_retries< T, R >
   body : Option< &T >,
 )
 -> Result< R, Error >
+where
+  T: Serialize,
+  R: Serialize + for< 'de > Deserialize< 'de >,
+{
+  execute_with_optional_retries_detailed( full_client, method, url, api_key, body ).await
+    .map_err( | failure | failure.error )
+}
+
+/// Execute an HTTP request like [`execute_with_optional_retries`], keeping the
+/// status, error code, and requested wait of the last error response
+pub async fn execute_with_optional_retries_detailed< T, R >
+(
+  full_client : &crate::client::Client,
+  method : Method,
+  url : &str,
+  api_key : &str,
+  body : Option< &T >,
+)
+-> Result< R, HttpFailure >
 where
   T: Serialize,
   R: Serialize + for< 'de > Deserialize< 'de >,
@@ -121,7 +140,7 @@ pub( crate ) async fn execute_with_enterprise_features< T, R >
   #[ cfg( not( feature = "caching" ) ) ]
   _cache : Option< &() >,
 )
--> Result< R, Error >
+-> Result< R, HttpFailure >
 where
   T: Serialize,
   R: Serialize + for< 'de > Deserialize< 'de >,
@@ -134,7 +153,7 @@ where
     {
       if !rl.should_allow_request().await
       {
-        return Err( Error::RateLimited( "Rate limit exceeded".to_string() ) );
+        return Err( HttpFailure::from( Error::RateLimited( "Rate limit exceeded".to_string() ) ) );
       }
     }
 
@@ -144,7 +163,7 @@ where
     {
       if !cb.should_allow_request()
       {
-        return Err( Error::CircuitBreakerOpen( "Circuit breaker is open".to_string() ) );
+        return Err( HttpFailure::from( Error::CircuitBreakerOpen( "Circuit breaker is open".to_string() ) ) );
       }
     }
 
@@ -152,7 +171,7 @@ where
     #[ cfg( feature = "caching" ) ]
     let result = execute_with_cache( client, method.clone(), url, api_key, body, config, cache ).await;
     #[ cfg( not( feature = "caching" ) ) ]
-    let result = super::execute( client, method.clone(), url, api_key, body, config ).await;
+    let result = super::execute_detailed( client, method.clone(), url, api_key, body, config ).await;
 
     // Record circuit breaker results
     #[ cfg( feature = "circuit_breaker" ) ]
@@ -161,7 +180,7 @@ where
       match &result
       {
         Ok( _ ) => cb.record_success(),
-        Err( error ) if is_circuit_breaker_error( error ) => cb.record_failure(),
+        Err( failure ) if is_circuit_breaker_error( &failure.error ) => cb.record_failure(),
         _ => {} // Dont count non-circuit-breaker errors
       }
     }
@@ -175,18 +194,25 @@ where
     if let Some( retry_cfg ) = retry_config
     {
       let start_time = std::time::Instant::now();
+      let budget = retry_cfg.call_budget.map( api_common::CallBudget::start );
       let mut attempt = 1;
 
       loop
       {
-        match execute_single_attempt().await
+        let outcome = match &budget
+        {
+          Some( budget ) => budget.run_attempt( execute_single_attempt() ).await?,
+          None => execute_single_attempt().await,
+        };
+
+        match outcome
         {
           Ok( response ) => return Ok( response ),
-          Err( error ) => {
-            // Check if we should retry this error
-            if !is_retryable_error( &error ) || attempt > retry_cfg.max_retries
+          Err( failure ) => {
+            // Check if we should retry this failure
+            if !is_retryable_failure( &failure ) || attempt > retry_cfg.max_retries
             {
-              return Err( error );
+              return Err( failure );
             }
 
             // Check max elapsed time
@@ -194,13 +220,20 @@ where
             {
               if start_time.elapsed() >= max_elapsed
               {
-                return Err( error );
+                return Err( failure );
               }
             }
 
-            // Calculate and apply retry delay
-            let delay = calculate_retry_delay( attempt, retry_cfg );
-            tokio ::time::sleep( delay ).await;
+            // Wait the backoff, or longer when the server asked, unless that exceeds
+            // `max_delay` or outlives the call budget
+            let Some( delay ) = failure_retry_delay( attempt, retry_cfg, &failure ) else {
+              return Err( failure );
+            };
+            if budget.as_ref().is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+            {
+              return Err( failure );
+            }
+            api_common::sleep( delay ).await;
             attempt += 1;
           }
         }
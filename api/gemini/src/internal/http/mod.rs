@@ -27,7 +27,7 @@ pub mod enterprise;
 
 // Re-export types
 #[ cfg( feature = "retry" ) ]
-pub use retry::{ RetryConfig, RetryMetrics, execute_with_retries };
+pub use retry::{ RetryConfig, RetryMetrics, execute_with_retries, is_retryable_failure, failure_retry_delay };
 
 #[ cfg( feature = "circuit_breaker" ) ]
 pub use circuit_breaker::{ CircuitBreakerConfig, CircuitBreakerState, CircuitBreakerMetrics, CircuitBreaker, execute_with_circuit_breaker };
@@ -38,7 +38,7 @@ pub use rate_limiter::{ RateLimitingConfig, RateLimiter, RateLimitingMetrics, Ra
 #[ cfg( feature = "caching" ) ]
 pub use cache::{ CacheConfig, CacheMetrics, RequestCache, execute_with_cache };
 
-pub use enterprise::execute_with_optional_retries;
+pub use enterprise::{ execute_with_optional_retries, execute_with_optional_retries_detailed };
 
 /// Configuration for HTTP requests
 ///
@@ -112,6 +112,43 @@ impl Default for HttpConfig
   }
 }
 
+/// A failed request, with what the error response said about the failure.
+///
+/// Retry logic decides from these fields rather than from the text of
+/// [`Self::error`]; callers outside the HTTP layer receive the error alone.
+#[ derive( Debug, Clone, PartialEq ) ]
+pub struct HttpFailure
+{
+  /// The error returned to callers.
+  pub error : Error,
+  /// HTTP status of the error response; `None` when no response arrived.
+  pub status : Option< u16 >,
+  /// Canonical error code of the response body, e.g. `RESOURCE_EXHAUSTED`.
+  pub code : Option< String >,
+  /// Wait the response asked for, from its `RetryInfo` detail or its
+  /// `Retry-After` header.
+  pub retry_after : Option< Duration >,
+}
+
+impl From< Error > for HttpFailure
+{
+  #[ inline ]
+  fn from( error : Error ) -> Self
+  {
+    Self { error, status : None, code : None, retry_after : None }
+  }
+}
+
+#[ cfg( any( feature = "retry", feature = "failover" ) ) ]
+impl From< api_common::BudgetExhausted > for HttpFailure
+{
+  #[ inline ]
+  fn from( err : api_common::BudgetExhausted ) -> Self
+  {
+    Error::from( err ).into()
+  }
+}
+
 /// Execute an HTTP request with JSON serialization/deserialization
 ///
 /// This function handles the complete HTTP request lifecycle with enhanced
@@ -144,6 +181,33 @@ impl Default for HttpConfig
 /// - [`Error::ServerError`] - Server-side issues (5xx)
 /// - [`Error::DeserializationError`] - Response parsing failed
 /// - [`Error::RequestBuilding`] - Invalid URL or request configuration
+#[ inline ]
+pub async fn execute< T, R >
+(
+  client : &Client,
+  method : Method,
+  url : &str,
+  api_key : &str,
+  body : Option< &T >,
+  config : &HttpConfig,
+)
+->
+Result< R, Error >
+where
+  T : Serialize,
+  R : for< 'de > Deserialize< 'de >,
+{
+  execute_detailed( client, method, url, api_key, body, config ).await
+    .map_err( | failure | failure.error )
+}
+
+/// Execute an HTTP request like [`execute`], keeping the status, error code,
+/// and requested wait of an error response.
+///
+/// # Errors
+///
+/// Returns the errors of [`execute`], each with the details of the error
+/// response when one arrived.
 #[ cfg_attr( feature = "logging", instrument(
   skip( client, api_key, body ),
   fields(
@@ -153,7 +217,7 @@ impl Default for HttpConfig
   )
 ) ) ]
 #[ inline ]
-pub async fn execute< T, R >
+pub async fn execute_detailed< T, R >
 (
   client : &Client,
   method : Method,
@@ -163,7 +227,7 @@ pub async fn execute< T, R >
   config : &HttpConfig,
 )
 ->
-Result< R, Error >
+Result< R, HttpFailure >
 where
   T : Serialize,
   R : for< 'de > Deserialize< 'de >,
@@ -228,7 +292,8 @@ where
         operation = %operation,
         "HTTP request completed successfully"
       ),
-      Err( error ) => {
+      Err( failure ) => {
+        let error = &failure.error;
         let error_type = match error
         {
           Error::ApiError( _ ) => "ApiError",
@@ -464,12 +529,13 @@ async fn process_response< R >
   #[ allow( unused_variables ) ]
   config : &HttpConfig,
 )
--> Result< R, Error >
+-> Result< R, HttpFailure >
 where
   R : for< 'de > Deserialize< 'de >,
 {
   let status = response.status();
   let status_code = status.as_u16();
+  let retry_after = retry_after_header( response.headers() );
 
   #[ cfg( feature = "logging" ) ]
   if config.enable_logging
@@ -503,7 +569,7 @@ where
         #[ cfg( feature = "logging" ) ]
         error!( "Failed to deserialize successful response : {}", e );
 
-        Error::DeserializationError(
+        HttpFailure::from( Error::DeserializationError(
           format!( "Failed to parse successful response as JSON: {}. Response content : {}",
             e,
             if response_text.len() > 200
@@ -513,16 +579,13 @@ where
               response_text
             }
           )
-        )
+        ) )
       } )
   }
   else
   {
     // Error response - attempt structured error parsing
-    classify_error_response( status_code, &response_text ).map( |_| {
-      // This will never be reached since classify_error_response always returns an error
-      unreachable!("classify_error_response should never return Ok")
-    } )
+    Err( classify_error_response( status_code, retry_after, &response_text ) )
   }
 }
 
@@ -533,7 +596,12 @@ where
 /// - API error response structure
 /// - Error message content analysis
 /// - Authentication and authorization patterns
-fn classify_error_response( status_code : u16, response_text : &str ) -> Result< never, Error >
+///
+/// The status, the canonical code of a structured body, and the wait the
+/// response asked for are kept beside the error. A `RetryInfo` detail of the
+/// body wins over the `Retry-After` header.
+#[ must_use ]
+pub fn classify_error_response( status_code : u16, retry_after : Option< Duration >, response_text : &str ) -> HttpFailure
 {
   #[ cfg( feature = "logging" ) ]
   debug!( "Classifying error response : HTTP {}", status_code );
@@ -547,19 +615,20 @@ fn classify_error_response( status_code : u16, response_text : &str ) -> Result<
     debug!( "Parsed structured API error : {}", api_error.error.message );
 
     // Classify based on message content and status code
-    if is_authentication_error( &api_error.error.message ) || matches!( status_code, 401 | 403 )
+    let error = if is_authentication_error( &api_error.error.message ) || matches!( status_code, 401 | 403 )
     {
-      Err( Error::AuthenticationError( error_message ) )
+      Error::AuthenticationError( error_message )
     }
     else
     {
-      match status_code
-      {
-        400 => Err( Error::InvalidArgument( error_message ) ),
-        429 => Err( Error::RateLimitError( error_message ) ),
-        500..=599 => Err( Error::ServerError( error_message ) ),
-        _ => Err( Error::ApiError( error_message ) ),
-      }
+      classify_status( status_code, error_message )
+    };
+    HttpFailure
+    {
+      error,
+      status : Some( status_code ),
+      code : api_error.error.status,
+      retry_after : retry_info_delay( response_text ).or( retry_after ),
     }
   }
   else
@@ -570,23 +639,57 @@ fn classify_error_response( status_code : u16, response_text : &str ) -> Result<
     #[ cfg( feature = "logging" ) ]
     debug!( "Using fallback error classification for non-JSON response" );
 
-    if is_authentication_error( response_text ) || matches!( status_code, 401 | 403 )
+    let error = if is_authentication_error( response_text ) || matches!( status_code, 401 | 403 )
     {
-      Err( Error::AuthenticationError( error_message ) )
+      Error::AuthenticationError( error_message )
     }
     else
     {
-      match status_code
-      {
-        400 => Err( Error::InvalidArgument( error_message ) ),
-        429 => Err( Error::RateLimitError( error_message ) ),
-        500..=599 => Err( Error::ServerError( error_message ) ),
-        _ => Err( Error::ApiError( error_message ) ),
-      }
-    }
+      classify_status( status_code, error_message )
+    };
+    HttpFailure { error, status : Some( status_code ), code : None, retry_after }
+  }
+}
+
+/// Error of a non-authentication failure status.
+fn classify_status( status_code : u16, error_message : String ) -> Error
+{
+  match status_code
+  {
+    400 => Error::InvalidArgument( error_message ),
+    429 => Error::RateLimitError( error_message ),
+    500..=599 => Error::ServerError( error_message ),
+    _ => Error::ApiError( error_message ),
   }
 }
 
+/// The wait a `Retry-After` header asks for, in whole or fractional seconds.
+/// HTTP dates are not read.
+#[ inline ]
+#[ must_use ]
+pub fn retry_after_header( headers : &reqwest::header::HeaderMap ) -> Option< Duration >
+{
+  headers.get( reqwest::header::RETRY_AFTER )?
+    .to_str().ok()?
+    .trim()
+    .parse::< f64 >().ok()
+    .filter( | seconds | seconds.is_finite() && *seconds >= 0.0 )
+    .map( Duration::from_secs_f64 )
+}
+
+/// The `retryDelay` of a `google.rpc.RetryInfo` detail in an error body,
+/// e.g. `"details": [ { "@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": "26s" } ]`.
+fn retry_info_delay( response_text : &str ) -> Option< Duration >
+{
+  let body : serde_json::Value = serde_json::from_str( response_text ).ok()?;
+  body[ "error" ][ "details" ].as_array()?
+    .iter()
+    .filter( | detail | detail[ "@type" ].as_str().is_some_and( | kind | kind.ends_with( "google.rpc.RetryInfo" ) ) )
+    .find_map( | detail | detail[ "retryDelay" ].as_str()?.strip_suffix( 's' )?.parse::< f64 >().ok() )
+    .filter( | seconds | seconds.is_finite() && *seconds >= 0.0 )
+    .map( Duration::from_secs_f64 )
+}
+
 /// Determine if an error message indicates an authentication or authorization issue
 ///
 /// This function analyzes error messages for common authentication-related patterns
@@ -673,6 +776,3 @@ where
   send_request( client, request, &config ).await
 }
 
-// Type alias for never type until it's stabilized
-#[ allow( non_camel_case_types ) ]
-type never = core::convert::Infallible;
@@ -7,6 +7,7 @@ use serde::Serialize;
 use serde::Deserialize;
 
 use crate::error::Error;
+use api_common::CallBudget;
 
 #[ cfg( feature = "logging" ) ]
 use tracing::{ warn, debug };
@@ -28,6 +29,8 @@ pub struct RetryConfig
   pub enable_jitter : bool,
   /// Maximum total elapsed time for all retries
   pub max_elapsed_time : Option< Duration >,
+  /// Overall limit started once per call and shared with every attempt
+  pub call_budget : Option< api_common::CallBudget >,
 }
 
 /// Retry metrics for tracking retry behavior
@@ -71,6 +74,39 @@ pub fn is_retryable_error( error : &Error ) -> bool
   }
 }
 
+/// Determines if a failed request is retryable from its response status
+///
+/// Rate limits (429), request timeouts (408), and server errors (5xx) are
+/// retried; failures without a response fall back to [`is_retryable_error`].
+pub fn is_retryable_failure( failure : &super::HttpFailure ) -> bool
+{
+  match failure.status
+  {
+    Some( status ) => matches!( status, 408 | 429 | 500..=599 ),
+    None => is_retryable_error( &failure.error ),
+  }
+}
+
+/// Delay before retrying a failed request: the backoff of [`calculate_retry_delay`],
+/// or the wait the response asked for when that is longer
+///
+/// Returns `None` when the response asked for a longer wait than `max_delay`;
+/// the call then ends with the failure rather than retrying early.
+pub fn failure_retry_delay(
+  attempt : u32,
+  config : &RetryConfig,
+  failure : &super::HttpFailure,
+) -> Option< Duration >
+{
+  let backoff = calculate_retry_delay( attempt, config );
+  match failure.retry_after
+  {
+    Some( retry_after ) if retry_after > config.max_delay => None,
+    Some( retry_after ) => Some( backoff.max( retry_after ) ),
+    None => Some( backoff ),
+  }
+}
+
 /// Calculate retry delay with exponential backoff and optional jitter
 pub fn calculate_retry_delay(
   attempt : u32,
@@ -121,6 +157,7 @@ where
   };
 
   let start_time = Instant::now();
+  let budget = retry_config.call_budget.map( CallBudget::start );
   let mut attempt = 1;
   let mut _last_error = None;
 
@@ -136,8 +173,14 @@ where
       );
     }
 
-    // Execute the request
-    match super::execute( client, method.clone(), url, api_key, body, config ).await
+    // Execute the request, counted against the call budget if one is set
+    let outcome = match &budget
+    {
+      Some( budget ) => budget.run_attempt( super::execute_detailed( client, method.clone(), url, api_key, body, config ) ).await?,
+      None => super::execute_detailed( client, method.clone(), url, api_key, body, config ).await,
+    };
+
+    match outcome
     {
       Ok( response ) => {
         #[ cfg( feature = "logging" ) ]
@@ -151,11 +194,15 @@ where
         }
         return Ok( response );
       },
-      Err( error ) => {
+      Err( failure ) => {
+        let retryable = is_retryable_failure( &failure );
+        // Honour the wait the server asked for when it is longer than the backoff
+        let delay = failure_retry_delay( attempt, retry_config, &failure );
+        let error = failure.error;
         _last_error = Some( error.clone() );
 
         // Check if we should retry this error
-        if !is_retryable_error( &error )
+        if !retryable
         {
           #[ cfg( feature = "logging" ) ]
           if config.enable_logging
@@ -203,8 +250,25 @@ where
           }
         }
 
-        // Calculate and apply retry delay
-        let delay = calculate_retry_delay( attempt, retry_config );
+        // Stop when the server asked for a longer wait than `max_delay`
+        let Some( delay ) = delay else {
+          #[ cfg( feature = "logging" ) ]
+          if config.enable_logging
+          {
+            warn!(
+              max_delay_ms = retry_config.max_delay.as_millis(),
+              url = %url,
+              "Requested retry delay exceeds max delay"
+            );
+          }
+          return Err( error );
+        };
+
+        // Stop instead of sleeping past the call budget
+        if budget.as_ref().is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+        {
+          return Err( error );
+        }
 
         #[ cfg( feature = "logging" ) ]
         if config.enable_logging
@@ -218,7 +282,7 @@ where
           );
         }
 
-        tokio ::time::sleep( delay ).await;
+        api_common::sleep( delay ).await;
         attempt += 1;
       }
     }
@@ -1,2 +1,4 @@
 /// HTTP layer implementation for making requests to the Gemini API
-pub mod http;
\ No newline at end of file
+pub mod http;
+/// Polling of long-running jobs, shared by batch jobs and operations
+pub mod poll;
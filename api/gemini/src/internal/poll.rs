@@ -0,0 +1,48 @@
+//! Polling of long-running jobs until they are done or a deadline passes.
+
+use core::future::Future;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::error::Error;
+
+/// Fetch a job with `fetch` until `is_done` holds or `deadline` passes.
+///
+/// The job is fetched right away and then every `interval`. The wait before
+/// a poll never runs past the deadline, and the job is fetched once more at
+/// the deadline before giving up, so a job finishing in the last interval is
+/// still returned.
+///
+/// # Errors
+///
+/// Returns [`Error::TimeoutError`] naming `what` if the job is not done at
+/// the deadline, or the error of a failed fetch.
+#[ inline ]
+pub async fn poll_until_done< T, F, Fut >
+(
+  what : &str,
+  mut fetch : F,
+  is_done : impl Fn( &T ) -> bool,
+  interval : Duration,
+  deadline : Instant,
+) -> Result< T, Error >
+where
+  F : FnMut() -> Fut,
+  Fut : Future< Output = Result< T, Error > >,
+{
+  loop
+  {
+    let job = fetch().await?;
+    if is_done( &job )
+    {
+      return Ok( job );
+    }
+
+    let now = Instant::now();
+    if now >= deadline
+    {
+      return Err( Error::TimeoutError( format!( "{what} was not done by the deadline" ) ) );
+    }
+    api_common ::sleep( interval.min( deadline - now ) ).await;
+  }
+}
@@ -0,0 +1,110 @@
+//! Attributed question answering implementation.
+//!
+//! This module answers questions from grounding passages, given inline or
+//! retrieved from a Semantic Retrieval corpus, with the `aqa` model.
+
+use reqwest::Method;
+use crate::error::Error;
+use crate::models::{ GenerateAnswerRequest, GenerateAnswerResponse };
+use crate::internal::http;
+
+use super::ModelApi;
+
+impl ModelApi< '_ >
+{
+  /// Answers the last user turn of the request from its grounding passages.
+  ///
+  /// The answer is grounded in the passages of `inline_passages`, or in the chunks
+  /// `semantic_retriever` retrieves from a corpus or document. Use it with the
+  /// `aqa` model.
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - The conversation, the answer style, and the grounding source
+  ///
+  /// # Returns
+  ///
+  /// Returns a [`GenerateAnswerResponse`] containing:
+  /// - `answer`: the answer candidate, citing the passages it used
+  /// - `answerable_probability`: how likely the passages answer the question
+  ///
+  /// # Errors
+  ///
+  /// This method returns an error in the following cases:
+  /// - [`Error::InvalidArgument`] - Empty contents, or not exactly one grounding source
+  /// - [`Error::NetworkError`] - Network connectivity issues or timeout
+  /// - [`Error::AuthenticationError`] - Invalid or missing API key
+  /// - [`Error::ServerError`] - Gemini API server-side errors (5xx status codes)
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::client::Client;
+  /// # use api_gemini::models::{ Content, GenerateAnswerRequest, Part, SemanticRetrieverConfig };
+  /// # #[ tokio::main ]
+  /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let question = Content {
+  ///   parts : vec![ Part { text : Some( "Who reviews deployments?".to_string() ), ..Default::default() } ],
+  ///   role : "user".to_string(),
+  /// };
+  /// let request = GenerateAnswerRequest {
+  ///   contents : vec![ question.clone() ],
+  ///   answer_style : "ABSTRACTIVE".to_string(),
+  ///   semantic_retriever : Some( SemanticRetrieverConfig {
+  ///     source : "corpora/handbook".to_string(),
+  ///     query : question,
+  ///     metadata_filters : None,
+  ///     max_chunks_count : Some( 5 ),
+  ///     minimum_relevance_score : None,
+  ///   } ),
+  ///   ..Default::default()
+  /// };
+  ///
+  /// let response = client.models().by_name( "aqa" ).generate_answer( &request ).await?;
+  /// println!( "Answerable : {:?}", response.answerable_probability );
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ inline ]
+  pub async fn generate_answer
+  (
+    &self,
+    request : &GenerateAnswerRequest,
+  )
+  ->
+  Result< GenerateAnswerResponse, Error >
+  {
+    // Validate model ID and request before sending
+    self.validate_model_id()?;
+    if request.contents.is_empty()
+    {
+      return Err( Error::InvalidArgument(
+        "Generate answer request cannot have empty contents. Please provide the question as the last content item.".to_string()
+      ) );
+    }
+    if request.inline_passages.is_some() == request.semantic_retriever.is_some()
+    {
+      return Err( Error::InvalidArgument(
+        "Generate answer request must set exactly one of inline_passages and semantic_retriever.".to_string()
+      ) );
+    }
+
+    let url = format!(
+      "{}/v1beta/models/{}:generateAnswer",
+      self.client.base_url,
+      self.model_id
+    );
+
+    http ::execute_with_optional_retries
+    (
+      self.client,
+      Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( request ),
+    )
+    .await
+    .map_err( |e| self.enhance_model_operation_error( "generate answer", e ) )
+  }
+}
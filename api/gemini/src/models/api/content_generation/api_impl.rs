@@ -98,15 +98,24 @@ impl ModelApi< '_ >
   )
   ->
   Result< crate::models::GenerateContentResponse, Error >
+  {
+    self.generate_content_detailed( request ).await
+      .map_err( | failure | failure.error )
+  }
+
+  /// Generates content like [`Self::generate_content`], keeping the status,
+  /// error code, and requested wait of an error response.
+  async fn generate_content_detailed
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+  )
+  ->
+  Result< crate::models::GenerateContentResponse, http::HttpFailure >
   {
     // Validate model ID and request before sending
     self.validate_model_id()?;
-    if request.contents.is_empty()
-    {
-      return Err( Error::InvalidArgument(
-        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string()
-      ) );
-    }
+    Self::validate_generate_content_request( request )?;
 
     let url = format!(
       "{}/v1beta/models/{}:generateContent",
@@ -114,7 +123,7 @@ impl ModelApi< '_ >
       self.model_id
     );
 
-    http ::execute_with_optional_retries
+    http ::execute_with_optional_retries_detailed
     (
       self.client,
       Method::POST,
@@ -123,13 +132,20 @@ impl ModelApi< '_ >
       Some( request ),
     )
     .await
-    .map_err( |e| self.enhance_model_operation_error( "generate content", e ) )
+    .map_err( | failure | http::HttpFailure
+    {
+      error : self.enhance_model_operation_error( "generate content", failure.error ),
+      ..failure
+    } )
   }
   /// Generates content with retry logic and exponential backoff.
   ///
   /// This method is similar to [`Self::generate_content`] but includes automatic retry
   /// logic with exponential backoff for handling transient failures (5xx errors, timeouts).
-  /// Non-retryable errors (4xx) are returned immediately without retry attempts.
+  /// Failures are classified by the HTTP status of the response: 408, 429, and 5xx are
+  /// retried, other 4xx errors are returned immediately without retry attempts. When a
+  /// rate limited response carries a `RetryInfo` detail or a `Retry-After` header, the
+  /// next attempt waits that long instead of the backoff interval.
   ///
   /// # Arguments
   ///
@@ -199,60 +215,29 @@ impl ModelApi< '_ >
         ) );
       }
 
-      match self.generate_content( request ).await
+      match self.generate_content_detailed( request ).await
       {
         Ok( response ) => Ok( response ),
-        Err( error ) => 
+        Err( failure ) if http::retry::is_retryable_failure( &failure ) => match failure.retry_after
         {
-          // Enhanced error classification for better retry decisions
-          match &error
-          {
-            // API errors from rate limiting (429) are retryable  
-            Error::ApiError( msg ) if msg.contains( "429" ) || msg.contains( "Rate limit" ) => 
-            {
-              Err( backoff::Error::transient( error ) )
-            },
-            
-            // HTTP 5xx server errors are retryable
-            Error::ApiError( msg ) if msg.contains( "502" ) || msg.contains( "503" ) || 
-                                      msg.contains( "504" ) || msg.contains( "408" ) => 
-            {
-              Err( backoff::Error::transient( error ) )
-            },
-            
-            // Timeout errors are retryable
-            Error::ApiError( msg ) if msg.contains( "timeout" ) || msg.contains( "Timeout" ) => 
-            {
-              Err( backoff::Error::transient( error ) )
-            },
-            
-            // Connection-specific network errors are retryable  
-            Error::NetworkError( msg ) if msg.contains( "connection" ) || 
-                                         msg.contains( "Connection" ) ||
-                                         msg.contains( "refused" ) ||
-                                         msg.contains( "unreachable" ) => 
-            {
-              Err( backoff::Error::transient( error ) )
-            },
-            
-            // General server and network errors are retryable (catch-all)
-            Error::ServerError( _ ) | Error::NetworkError( _ ) => Err( backoff::Error::transient( error ) ),
-            
-            // All other errors are permanent (4xx client errors, auth errors, etc.)
-            _ => Err( backoff::Error::permanent( error ) ),
-          }
-        }
+          Some( wait ) => Err( backoff::Error::retry_after( failure.error, wait ) ),
+          None => Err( backoff::Error::transient( failure.error ) ),
+        },
+        // All other errors are permanent (4xx client errors, auth errors, etc.)
+        Err( failure ) => Err( backoff::Error::permanent( failure.error ) ),
       }
     } ).await
   }
   /// Generates content using streaming responses (Server-Sent Events).
   ///
   /// This method is similar to [`Self::generate_content`] but returns a stream of incremental
-  /// responses instead of waiting for the complete response. This enables real-time
-  /// processing of generated content as it becomes available.
+  /// responses instead of waiting for the complete response. The request asks for `?alt=sse`,
+  /// and each chunk is yielded as soon as its event arrives, parsed by
+  /// [`crate::models::StreamChunkParser`]. The stream ends with a chunk marked `is_final`
+  /// and carrying no candidates.
   ///
-  /// **Note**: This feature is currently not implemented and will return 
-  /// [`Error::NotImplemented`]. This method is provided to support TDD development.
+  /// [`Self::generate_content_stream_buffered`] reads the same chunks from the JSON array
+  /// the endpoint sends without `alt=sse`, for proxies that do not pass event streams through.
   ///
   /// # Arguments
   ///
@@ -261,16 +246,18 @@ impl ModelApi< '_ >
   ///
   /// # Returns
   ///
-  /// When implemented, returns a stream of [`crate::models::StreamingResponse`] objects
+  /// Returns a stream of [`crate::models::StreamingResponse`] objects
   /// containing incremental content updates.
   ///
   /// # Errors
   ///
-  /// Currently returns [`Error::NotImplemented`]. When implemented, will return:
   /// - [`Error::InvalidArgument`] - Invalid request format or empty content
   /// - [`Error::NetworkError`] - Network connectivity issues
-  /// - [`Error::AuthenticationError`] - Invalid or missing API key
-  /// - [`Error::NetworkError`] - Stream-specific errors (connection drops, etc.)
+  /// - [`Error::ApiError`] - The API rejected the request
+  ///
+  /// Items of the stream are [`Error::NetworkError`] if the connection drops,
+  /// [`Error::SerializationError`] for a malformed chunk, and [`Error::ApiError`]
+  /// for an error event.
   ///
   /// # Examples
   ///
@@ -332,12 +319,49 @@ impl ModelApi< '_ >
     Self::validate_generate_content_request( request )?;
     
     // Build streaming request
-    let stream_request = self.build_streaming_request( request );
+    let stream_request = self.build_streaming_request( request, true );
     
     // Execute streaming request
     let response = self.execute_streaming_request( stream_request ).await?;
     
-    // Process streaming response with optimized parsing
+    // Yield chunks as their events arrive
+    Ok( Self::process_sse_response( response ) )
+  }
+
+  /// Generates content as a stream read from the whole response at once.
+  ///
+  /// The fallback of [`Self::generate_content_stream`]: the request is sent without
+  /// `alt=sse`, the JSON array of chunks is buffered until the response completes,
+  /// and its chunks are then yielded in order, followed by the same final marker.
+  /// Use it where event streams are not passed through, e.g. by some proxies.
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - A [`crate::models::GenerateContentRequest`] with the same format
+  ///   as used for non-streaming generation
+  ///
+  /// # Returns
+  ///
+  /// Returns a stream of [`crate::models::StreamingResponse`] objects, available once
+  /// the response is complete.
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`Self::generate_content_stream`].
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  pub async fn generate_content_stream_buffered
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+  )
+  ->
+  Result< impl futures::Stream< Item = Result< crate::models::StreamingResponse, Error > >, Error >
+  {
+    self.validate_model_id()?;
+    Self::validate_generate_content_request( request )?;
+    let stream_request = self.build_streaming_request( request, false );
+    let response = self.execute_streaming_request( stream_request ).await?;
     Ok( Self::process_streaming_response( response ) )
   }
   /// Create a streaming request builder for more ergonomic API usage.
@@ -564,6 +588,11 @@ impl ModelApi< '_ >
   /// message and generates a response. It maintains conversation context
   /// by preserving the conversation history.
   ///
+  /// The history grows without bound. For a session owning its history, with
+  /// streaming, truncation, and JSON persistence, use [`Self::start_chat`]
+  /// (feature `chat`); for summarisation, `continue_neutral_conversation`
+  /// (feature `common`) with an `api_common::Conversation`.
+  ///
   /// # Arguments
   ///
   /// * `conversation_history` - Previous messages in the conversation
@@ -641,11 +670,8 @@ impl ModelApi< '_ >
     let response = self.generate_content( &request ).await?;
     
     // Extract assistant response
-    let assistant_text = response.candidates
-      .first()
-      .and_then( |candidate| candidate.content.parts.first() )
-      .and_then( |part| part.text.as_ref() )
-      .cloned()
+    // Thought parts are the model's reasoning, not its reply
+    let assistant_text = response.answer_text()
       .ok_or_else( || Error::ApiError( 
         format!( "No text content returned from model '{}' during conversation.", 
           self.model_id )
@@ -663,6 +689,90 @@ impl ModelApi< '_ >
     Ok( ( conversation_history, assistant_text ) )
   }
 
+  /// Starts a [`crate::models::ChatSession`] with this model and an empty
+  /// history.
+  #[ cfg( feature = "chat" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn start_chat( &self ) -> crate::models::ChatSession
+  {
+    crate ::models::ChatSession::new( self.model_id.clone() )
+  }
+
+  /// Continues a provider-neutral [`api_common::Conversation`].
+  ///
+  /// Appends `user_message`, sends the conversation (system prompt and
+  /// summary as `system_instruction`, turns as `contents`), and appends the
+  /// model's reply: the text parts of the first candidate, joined, without
+  /// thought summaries. The conversation is never truncated here; apply a
+  /// [`api_common::TruncationStrategy`] explicitly before calling when the
+  /// history needs to shrink.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::client::Client;
+  /// # use api_common::{ Conversation, TruncationStrategy };
+  /// # #[ tokio::main ]
+  /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let models_api = client.models();
+  /// let model = models_api.by_name( "gemini-2.5-flash" );
+  ///
+  /// let mut conversation = Conversation::new().with_system( "Answer in one sentence." );
+  /// let answer = model.continue_neutral_conversation( &mut conversation, "What is machine learning?" ).await?;
+  /// println!( "Assistant : {}", answer );
+  ///
+  /// conversation.truncate( &TruncationStrategy::keep_last_turns( 10 ) )?;
+  /// # Ok( () )
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`generate_content`], plus
+  /// [`Error::ApiError`] if the response has no answer text. On error the user
+  /// message stays in the conversation so the call can be retried.
+  ///
+  /// [`generate_content`]: ModelApi::generate_content
+  #[ cfg( feature = "common" ) ]
+  #[ inline ]
+  pub async fn continue_neutral_conversation
+  (
+    &self,
+    conversation : &mut api_common::Conversation,
+    user_message : &str,
+  )
+  ->
+  Result< String, Error >
+  {
+    conversation.push_user( user_message );
+
+    let request = crate::models::GenerateContentRequest {
+      contents : conversation.turns_into(),
+      system_instruction : conversation.instructions().map( | text | crate::models::SystemInstruction {
+        role : "system".to_string(),
+        parts : vec![ crate::models::Part {
+          text : Some( text ),
+          ..Default::default()
+        } ],
+      } ),
+      ..Default::default()
+    };
+
+    let response = self.generate_content( &request ).await?;
+
+    // Thought parts are the model's reasoning, not its reply
+    let assistant_text = response.answer_text()
+      .ok_or_else( || Error::ApiError(
+        format!( "No text content returned from model '{}' during conversation.",
+          self.model_id )
+      ) )?;
+
+    conversation.push_assistant( assistant_text.clone() );
+    Ok( assistant_text )
+  }
+
   /// Creates a request builder for complex generation scenarios.
   ///
   /// This method returns a builder that allows fluent configuration of
@@ -705,8 +815,10 @@ impl ModelApi< '_ >
 
   /// Generates content for multiple prompts in batch.
   ///
-  /// This method processes multiple prompts efficiently using batch processing
-  /// to minimize API calls and improve performance compared to individual requests.
+  /// The prompts are sent as one Batch Mode job of inlined requests, and the
+  /// call waits until the job is done; batch jobs run at a discount but may
+  /// take up to a day. Use [`crate::batch_api::BatchApi`] to poll on your own
+  /// schedule. Needs the `batch_operations` feature.
   ///
   /// # Arguments
   ///
@@ -745,9 +857,35 @@ impl ModelApi< '_ >
   #[ inline ]
   pub async fn batch_generate_content( &self, prompts : &[ &str ] ) -> Result< Vec< GenerateContentResponse >, Error >
   {
-    let _ = prompts;
-    // qqq : Implement actual batch API when available from Gemini (task/verified/004)
-    Err( Error::NotImplemented( "batch_generate_content: real batch endpoint not yet available from Gemini".to_string() ) )
+    #[ cfg( feature = "batch_operations" ) ]
+    {
+      // Jobs not done in 48 hours expire.
+      const DEADLINE : core::time::Duration = core::time::Duration::from_secs( 48 * 60 * 60 );
+
+      if prompts.is_empty()
+      {
+        return Ok( Vec::new() );
+      }
+      let requests = prompts.iter().map( | prompt | crate::models::GenerateContentRequest
+      {
+        contents : vec![ crate::models::Content
+        {
+          parts : vec![ crate::models::Part { text : Some( ( *prompt ).to_string() ), ..Default::default() } ],
+          role : "user".to_string(),
+        } ],
+        ..Default::default()
+      } ).collect();
+      let batches = crate::batch_api::BatchApi::new( self.client );
+      let job = batches.create_inline( &self.model_id, requests ).await?;
+      let results = batches.wait_and_retrieve( &job.name, DEADLINE ).await?;
+
+      results.into_request_order( prompts.len() )
+    }
+    #[ cfg( not( feature = "batch_operations" ) ) ]
+    {
+      let _ = prompts;
+      Err( Error::NotImplemented( "batch_generate_content needs the batch_operations feature".to_string() ) )
+    }
   }
   #[ inline ]
   fn validate_generate_content_request( request : &crate::models::GenerateContentRequest ) -> Result< (), Error >
@@ -758,13 +896,26 @@ impl ModelApi< '_ >
         "Generate content request cannot have empty contents. Please provide at least one content item.".to_string()
       ) );
     }
+    // The system instruction and tools of a cache are fixed when it is created.
+    if let Some( cache ) = &request.cached_content
+    {
+      if request.system_instruction.is_some() || request.tools.is_some() || request.tool_config.is_some()
+      {
+        return Err( Error::InvalidArgument( format!(
+          "Generate content request using cached content '{cache}' cannot set system_instruction, tools, or tool_config. Put them in the cache instead."
+        ) ) );
+      }
+    }
     Ok( () )
   }
 
   /// Build streaming HTTP request with optimized headers
+  ///
+  /// With `sse` the request asks for `?alt=sse`, an event per chunk; without it the
+  /// endpoint sends one JSON array of all chunks.
   #[ cfg( feature = "streaming" ) ]
   #[ inline ]
-  fn build_streaming_request( &self, request : &crate::models::GenerateContentRequest ) -> reqwest::RequestBuilder
+  fn build_streaming_request( &self, request : &crate::models::GenerateContentRequest, sse : bool ) -> reqwest::RequestBuilder
   {
     let url = format!(
       "{}/v1beta/models/{}:streamGenerateContent",
@@ -773,15 +924,15 @@ impl ModelApi< '_ >
     );
 
     // Use client's configured HTTP client for connection reuse
-    // Gemini API returns newline-delimited JSON (NDJSON) for streaming
-    self.client.http
+    let builder = self.client.http
       .post( &url )
-      .header( "Accept", "application/json" )
+      .header( "Accept", if sse { "text/event-stream" } else { "application/json" } )
       .header( "Cache-Control", "no-cache" )
       .header( "Connection", "keep-alive" )
       .header( "User-Agent", "api_gemini/0.2.0" )
-      .query( &[ ( "key", &*self.client.api_key ) ] )
-      .json( request )
+      .query( &[ ( "key", &*self.client.api_key ) ] );
+    let builder = if sse { builder.query( &[ ( "alt", "sse" ) ] ) } else { builder };
+    builder.json( request )
   }
 
   /// Execute streaming request with enhanced error handling
@@ -814,14 +965,64 @@ impl ModelApi< '_ >
     Ok( response )
   }
 
+  /// Process a Gemini `alt=sse` streaming response incrementally.
+  ///
+  /// Each chunk of the body goes through a [`crate::models::StreamChunkParser`] as it
+  /// arrives, so a chunk is yielded as soon as its event is complete; the stream ends
+  /// with a final marker, as the buffered path does.
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  fn process_sse_response( response : reqwest::Response ) -> impl futures::Stream< Item = Result< crate::models::StreamingResponse, Error > >
+  {
+    use futures::StreamExt;
+
+    async_stream ::stream!
+    {
+      let mut parser = crate::models::StreamChunkParser::new();
+      let mut body = response.bytes_stream();
+      while let Some( bytes ) = body.next().await
+      {
+        match bytes
+        {
+          Ok( bytes ) =>
+          {
+            for chunk in parser.push( &bytes )
+            {
+              yield chunk;
+            }
+          },
+          Err( network_error ) =>
+          {
+            yield Err( Error::NetworkError( format!( "Failed to read streaming response : {network_error}" ) ) );
+            return;
+          },
+        }
+      }
+      for chunk in parser.finish()
+      {
+        yield chunk;
+      }
+
+      // Emit final marker
+      yield Ok( crate::models::StreamingResponse {
+        candidates : None,
+        usage_metadata : None,
+        is_final : Some( true ),
+        error : None,
+      } );
+    }
+  }
+
   /// Process Gemini streaming response by buffering and parsing as JSON array.
   ///
   /// # Gemini Streaming API Format
   ///
-  /// **CRITICAL**: Gemini's `:streamGenerateContent` endpoint returns a complete JSON array
-  /// containing all response chunks, NOT Server-Sent Events (SSE) or newline-delimited JSON.
+  /// Without `alt=sse`, Gemini's `:streamGenerateContent` endpoint returns a complete JSON
+  /// array containing all response chunks, not Server-Sent Events (SSE) or newline-delimited
+  /// JSON. This is the format of the buffered fallback; [`Self::process_sse_response`]
+  /// handles the `alt=sse` events.
   ///
-  /// ## Actual Format
+  /// ## Format
   ///
   /// ```json
   /// [
@@ -830,12 +1031,6 @@ impl ModelApi< '_ >
   /// ]
   /// ```
   ///
-  /// ## NOT These Formats
-  ///
-  /// - ❌ NOT SSE: `data : {...}\n\ndata : {...}\n\n`
-  /// - ❌ NOT NDJSON: `{...}\n{...}\n`
-  /// - ❌ NOT chunked JSON: Separate JSON objects
-  ///
   /// ## Implementation Strategy
   ///
   /// 1. **Buffer entire response**: Call `response.bytes().await` to collect full body
@@ -845,8 +1040,8 @@ impl ModelApi< '_ >
   ///
   /// ## Why Not SSE Parser?
   ///
-  /// Previous implementation used `eventsource-stream` crate expecting SSE format. This failed
-  /// because the SSE parser couldn't recognize the JSON array structure, resulting in zero
+  /// A previous implementation used the `eventsource-stream` crate without requesting
+  /// `alt=sse`. The SSE parser couldn't recognize the JSON array structure, resulting in zero
   /// parsed chunks. See integration test documentation in `tests/comprehensive_integration_tests.rs`
   /// for detailed failure analysis.
   ///
@@ -854,12 +1049,9 @@ impl ModelApi< '_ >
   ///
   /// Buffering the entire response before parsing means:
   /// - ✅ Simple, robust parsing
-  /// - ✅ Matches actual Gemini API behavior
+  /// - ✅ Works wherever event streams are not passed through
   /// - ⚠️ Delays first chunk until complete response received
   /// - ⚠️ Higher memory usage for large responses
-  ///
-  /// This is acceptable because Gemini sends the complete array quickly (typically <1 second)
-  /// and response sizes are limited by API constraints.
   #[ cfg( feature = "streaming" ) ]
   #[ inline ]
   fn process_streaming_response( response : reqwest::Response ) -> impl futures::Stream< Item = Result< crate::models::StreamingResponse, Error > >
@@ -893,19 +1085,7 @@ impl ModelApi< '_ >
               // Emit each response as a streaming chunk
               for api_response in responses.into_iter()
               {
-                let is_final = api_response.candidates
-                  .first()
-                  .and_then( |candidate| candidate.finish_reason.as_ref() )
-                  .is_some();
-
-                let streaming_response = crate::models::StreamingResponse {
-                  candidates : Some( api_response.candidates ),
-                  usage_metadata : api_response.usage_metadata,
-                  is_final : Some( is_final ),
-                  error : None,
-                };
-
-                yield Ok( streaming_response );
+                yield Ok( crate::models::StreamingResponse::from( api_response ) );
               }
 
               // Emit final marker
@@ -57,6 +57,40 @@ impl< 'a > GenerationRequestBuilder< 'a >
     self
   }
 
+  /// Sets the system instruction that steers the model across the request.
+  ///
+  /// # Arguments
+  ///
+  /// * `instruction` - The instruction text
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_system_instruction( self, instruction : &str ) -> Self
+  {
+    self.with_system_parts( vec![ crate::models::Part {
+      text : Some( instruction.to_string() ),
+      ..Default::default()
+    } ] )
+  }
+
+  /// Sets a system instruction made of several parts, e.g. text and files.
+  ///
+  /// Requests using cached content cannot set their own system instruction;
+  /// put it in the cache instead.
+  ///
+  /// # Arguments
+  ///
+  /// * `parts` - The parts of the instruction, replacing any set before
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_system_parts( mut self, parts : Vec< crate::models::Part > ) -> Self
+  {
+    self.request.system_instruction = Some( crate::models::SystemInstruction {
+      role : "system".to_string(),
+      parts,
+    } );
+    self
+  }
+
   /// Sets the temperature for generation randomness.
   ///
   /// Temperature controls the randomness of the output:
@@ -112,6 +146,115 @@ impl< 'a > GenerationRequestBuilder< 'a >
     self
   }
 
+  /// Answers with speech in the given voices instead of text.
+  ///
+  /// Sets the response modalities to audio only, as text-to-speech models
+  /// such as "gemini-2.5-flash-preview-tts" require. Read the speech with
+  /// [`crate::models::GenerateContentResponse::audio`].
+  ///
+  /// # Arguments
+  ///
+  /// * `speech_config` - One voice, or one voice per speaker
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_speech_config( mut self, speech_config : crate::models::SpeechConfig ) -> Self
+  {
+    self.ensure_generation_config();
+    if let Some( ref mut config ) = self.request.generation_config
+    {
+      config.response_modalities = Some( vec![ "AUDIO".to_string() ] );
+      config.speech_config = Some( speech_config );
+    }
+    self
+  }
+
+  /// Sets how much a thinking model reasons, and whether it answers
+  /// summaries of its thoughts.
+  ///
+  /// Read the thoughts and the answer apart with
+  /// [`crate::models::GenerateContentResponse::thought_summary`] and
+  /// [`crate::models::GenerateContentResponse::answer_text`].
+  ///
+  /// # Arguments
+  ///
+  /// * `thinking_config` - The thinking budget and whether to include thoughts
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_thinking_config( mut self, thinking_config : crate::models::ThinkingConfig ) -> Self
+  {
+    self.ensure_generation_config();
+    if let Some( ref mut config ) = self.request.generation_config
+    {
+      config.thinking_config = Some( thinking_config );
+    }
+    self
+  }
+
+  /// Sets the safety settings of the request.
+  ///
+  /// # Arguments
+  ///
+  /// * `safety_settings` - Thresholds per category, e.g. from [`crate::models::SafetySettingsBuilder`]
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_safety_settings( mut self, safety_settings : Vec< crate::models::SafetySetting > ) -> Self
+  {
+    self.request.safety_settings = Some( safety_settings );
+    self
+  }
+
+  /// Adds a tool the model can use, e.g. [`crate::models::Tool::google_search`].
+  ///
+  /// # Arguments
+  ///
+  /// * `tool` - The tool, added after the tools already set
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_tool( mut self, tool : crate::models::Tool ) -> Self
+  {
+    self.request.tools.get_or_insert_with( Vec::new ).push( tool );
+    self
+  }
+
+  /// Sets how the model calls the declared functions, e.g. [`crate::models::ToolConfig::any`].
+  ///
+  /// # Arguments
+  ///
+  /// * `tool_config` - Function calling mode and allowed functions
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_tool_config( mut self, tool_config : crate::models::ToolConfig ) -> Self
+  {
+    self.request.tool_config = Some( tool_config );
+    self
+  }
+
+  /// Generates against cached content, given by its ID or its full
+  /// `cachedContents/{id}` name.
+  ///
+  /// The cached contents come before the prompt, and are billed at the
+  /// reduced rate for cached tokens. The system instruction and tools of the
+  /// cache apply; the request cannot set its own.
+  ///
+  /// # Arguments
+  ///
+  /// * `cache` - The cache created with `Client::cached_content`
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_cached_content( mut self, cache : &str ) -> Self
+  {
+    self.request.cached_content = Some( crate::models::cached_content_name( cache ) );
+    self
+  }
+
+  /// The request as configured so far.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request( &self ) -> &crate::models::GenerateContentRequest
+  {
+    &self.request
+  }
+
   /// Executes the configured generation request.
   ///
   /// # Returns
@@ -22,8 +22,8 @@ impl ModelApi< '_ >
   ///
   /// * `request` - An [`crate::models::EmbedContentRequest`] containing:
   ///   - `content`: The text content to embed
-  ///   - `task_type`: Optional task type hint (e.g., `"RETRIEVAL_QUERY"`, `"RETRIEVAL_DOCUMENT"`)
-  ///   - `title`: Optional title for the content
+  ///   - `task_type`: Optional [`crate::models::TaskType`] name (e.g., `"RETRIEVAL_QUERY"`, `"RETRIEVAL_DOCUMENT"`)
+  ///   - `title`: Optional title for the content, with `"RETRIEVAL_DOCUMENT"` only
   ///   - `output_dimensionality`: Optional dimension reduction
   ///
   /// # Returns
@@ -34,7 +34,8 @@ impl ModelApi< '_ >
   /// # Errors
   ///
   /// This method returns an error in the following cases:
-  /// - [`Error::InvalidArgument`] - Invalid request format, empty content, or model doesn't support embeddings
+  /// - [`Error::InvalidArgument`] - Invalid request format, empty content, an unknown task type, a title
+  ///   without the `RETRIEVAL_DOCUMENT` task type, a dimensionality below 1, or model doesn't support embeddings
   /// - [`Error::NetworkError`] - Network connectivity issues or request timeout
   /// - [`Error::AuthenticationError`] - Invalid or missing API key
   /// - [`Error::RateLimitError`] - API rate limits exceeded
@@ -45,7 +46,7 @@ impl ModelApi< '_ >
   /// # Examples
   ///
   /// ```rust,no_run
-  /// # use api_gemini::{ client::Client, EmbedContentRequest, Content, Part };
+  /// # use api_gemini::{ client::Client, EmbedContentRequest, TaskType };
   /// # #[ tokio::main ]
   /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
   /// let client = Client::new()?;
@@ -53,18 +54,9 @@ impl ModelApi< '_ >
   /// let model = models_api.by_name( "gemini-embedding-001" );
   ///
   /// // Create embedding for search query
-  /// let request = EmbedContentRequest {
-  ///   content : Content {
-  ///     parts : vec![ Part {
-  ///       text : Some( "How to learn Rust programming".to_string() ),
-  ///       ..Default::default()
-  ///     } ],
-  ///     role : "user".to_string(),
-  ///   },
-  ///   task_type : Some( "RETRIEVAL_QUERY".to_string() ),
-  ///   title : Some( "Search Query".to_string() ),
-  ///   output_dimensionality : None,
-  /// };
+  /// let request = EmbedContentRequest::new( "How to learn Rust programming" )
+  ///   .with_task_type( TaskType::RetrievalQuery )
+  ///   .with_output_dimensionality( 768 );
   ///
   /// let response = model.embed_content( &request ).await?;
   ///
@@ -107,6 +99,20 @@ impl ModelApi< '_ >
       ) );
     }
 
+    let task_type = request.task_type.as_deref().map( str::parse::< crate::models::TaskType > ).transpose()?;
+    if request.title.is_some() && task_type != Some( crate::models::TaskType::RetrievalDocument )
+    {
+      return Err( Error::InvalidArgument(
+        "Embed content request can only have a title with the RETRIEVAL_DOCUMENT task type.".to_string()
+      ) );
+    }
+    if request.output_dimensionality.is_some_and( | dimensions | dimensions < 1 )
+    {
+      return Err( Error::InvalidArgument(
+        "Embed content request output dimensionality must be at least 1.".to_string()
+      ) );
+    }
+
     let url = format!(
       "{}/v1beta/models/{}:embedContent",
       self.client.base_url,
@@ -541,9 +547,22 @@ impl ModelApi< '_ >
   /// ```
   #[ inline ]
   pub async fn batch_embed_texts( &self, texts : &[ &str ] ) -> Result< Vec< Vec< f32 > >, Error >
+  {
+    let requests = texts.iter().map( | text | EmbedContentRequest::new( text ) ).collect();
+    self.embed_each( requests ).await
+  }
+
+  /// Embeds each request in order, failing on the first error.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::ValidationError`] when there are no requests, the error
+  /// of the first request when it fails, and [`Error::BatchProcessingError`]
+  /// when a later request fails.
+  pub( crate ) async fn embed_each( &self, requests : Vec< EmbedContentRequest > ) -> Result< Vec< Vec< f32 > >, Error >
   {
     // Validate input
-    if texts.is_empty()
+    if requests.is_empty()
     {
       return Err( Error::ValidationError { 
         message : "Cannot process empty text list".to_string() 
@@ -557,17 +576,17 @@ impl ModelApi< '_ >
     #[ cfg( feature = "logging" ) ]
     tracing ::info!(
       batch_id = %batch_id,
-      batch_size = texts.len(),
+      batch_size = requests.len(),
       "Starting batch embedding operation"
     );
 
     // For now, process texts individually
     // qqq : Implement actual batch API when available from Gemini (task/verified/004)
-    let mut embeddings = Vec::with_capacity( texts.len() );
+    let mut embeddings = Vec::with_capacity( requests.len() );
     let mut successful = 0;
     let mut failed = 0;
 
-    for ( index, text ) in texts.iter().enumerate()
+    for ( index, request ) in requests.iter().enumerate()
     {
       #[ cfg( feature = "logging" ) ]
       tracing ::debug!(
@@ -579,7 +598,16 @@ impl ModelApi< '_ >
       #[ cfg( not( feature = "logging" ) ) ]
       let _ = index; // Suppress unused variable warning when logging disabled
       
-      match self.embed_text( text ).await
+      let result = self.embed_content( request ).await.and_then( | response |
+      {
+        if response.embedding.values.is_empty()
+        {
+          Err( Error::ApiError( format!( "No embedding values returned from model '{}'.", self.model_id ) ) )
+        } else {
+          Ok( response.embedding.values )
+        }
+      } );
+      match result
       {
         Ok( embedding ) => {
           embeddings.push( embedding );
@@ -594,7 +622,7 @@ impl ModelApi< '_ >
           }
           // If we have some successful embeddings, return a batch error
           // Count remaining texts as failed
-          let remaining = texts.len() - successful - failed;
+          let remaining = requests.len() - successful - failed;
           
           #[ cfg( feature = "logging" ) ]
           tracing ::error!(
@@ -604,6 +632,7 @@ impl ModelApi< '_ >
             "Batch embedding operation failed"
           );
           
+          let text : String = request.content.parts.iter().filter_map( | part | part.text.as_deref() ).collect();
           return Err( Error::BatchProcessingError {
             successful,
             failed : failed + remaining,
@@ -762,3 +791,69 @@ impl ModelApi< '_ >
 #[ path = "embeddings_builders.rs" ]
 mod embeddings_builders;
 pub use embeddings_builders::{ EmbeddingRequestBuilder, BatchEmbeddingRequestBuilder };
+
+/// Owned embeddings handle bound to one Gemini embedding model.
+///
+/// Implements `api_common::Embedder` through `batch_embed_texts`, so each
+/// `embed` call is exactly one `batchEmbedContents` request.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use api_gemini::{ client::Client, models::api::GeminiEmbedder };
+/// # use api_common::{ Embedder, cosine_similarity };
+/// # #[ tokio::main ]
+/// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+/// let embedder = GeminiEmbedder::new( Client::new()?, "gemini-embedding-001" );
+/// let vectors = embedder.embed( &[ "cat".to_string(), "kitten".to_string() ] ).await?;
+/// println!( "similarity : {}", cosine_similarity( &vectors[ 0 ], &vectors[ 1 ] )? );
+/// # Ok( () )
+/// # }
+/// ```
+#[ cfg( feature = "common" ) ]
+#[ derive( Debug, Clone ) ]
+pub struct GeminiEmbedder
+{
+  client : crate::client::Client,
+  model : String,
+}
+
+#[ cfg( feature = "common" ) ]
+impl GeminiEmbedder
+{
+  /// Maximum number of texts `batchEmbedContents` accepts per request.
+  pub const MAX_BATCH_SIZE : usize = 100;
+
+  /// Creates an embedder for `model` (e.g. `"gemini-embedding-001"`).
+  #[ inline ]
+  pub fn new( client : crate::client::Client, model : impl Into< String > ) -> Self
+  {
+    Self { client, model : model.into() }
+  }
+}
+
+#[ cfg( feature = "common" ) ]
+#[ async_trait::async_trait ]
+impl api_common::Embedder for GeminiEmbedder
+{
+  #[ inline ]
+  fn model( &self ) -> &str
+  {
+    &self.model
+  }
+
+  #[ inline ]
+  fn max_batch_size( &self ) -> Option< usize >
+  {
+    Some( Self::MAX_BATCH_SIZE )
+  }
+
+  #[ inline ]
+  async fn embed( &self, texts : &[ String ] ) -> api_common::Result< Vec< api_common::Embedding > >
+  {
+    let texts : Vec< &str > = texts.iter().map( String::as_str ).collect();
+    let models = self.client.models();
+    let embeddings = models.by_name( &self.model ).batch_embed_texts( &texts ).await?;
+    Ok( embeddings )
+  }
+}
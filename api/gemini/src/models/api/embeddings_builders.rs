@@ -67,18 +67,19 @@ impl< 'a > EmbeddingRequestBuilder< 'a >
   ///
   /// # Arguments
   ///
-  /// * `task_type` - The task type string
+  /// * `task_type` - A [`crate::models::TaskType`], or its API name
   #[ inline ]
   #[ must_use ]
-  pub fn with_task_type( mut self, task_type : &str ) -> Self
+  pub fn with_task_type( mut self, task_type : impl AsRef< str > ) -> Self
   {
-    self.request.task_type = Some( task_type.to_string() );
+    self.request.task_type = Some( task_type.as_ref().to_string() );
     self
   }
 
   /// Sets an optional title for the content.
   ///
   /// The title can provide additional context to improve embedding quality.
+  /// The API accepts it with the "`RETRIEVAL_DOCUMENT`" task type only.
   ///
   /// # Arguments
   ///
@@ -107,6 +108,14 @@ impl< 'a > EmbeddingRequestBuilder< 'a >
     self
   }
 
+  /// The request as configured so far.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request( &self ) -> &crate::models::EmbedContentRequest
+  {
+    &self.request
+  }
+
   /// Executes the configured embedding request.
   ///
   /// # Returns
@@ -165,6 +174,12 @@ pub struct BatchEmbeddingRequestBuilder< 'a >
   batch_size : Option< usize >,
   /// Optional timeout for batch operations  
   timeout : Option< Duration >,
+  /// Optional task type for every text
+  task_type : Option< crate::models::TaskType >,
+  /// Optional titles, one per text
+  titles : Option< Vec< &'a str > >,
+  /// Optional dimensionality for every embedding
+  output_dimensionality : Option< i32 >,
 }
 
 impl< 'a > BatchEmbeddingRequestBuilder< 'a >
@@ -183,6 +198,9 @@ impl< 'a > BatchEmbeddingRequestBuilder< 'a >
       texts : None,
       batch_size : None,
       timeout : None,
+      task_type : None,
+      titles : None,
+      output_dimensionality : None,
     }
   }
 
@@ -228,6 +246,76 @@ impl< 'a > BatchEmbeddingRequestBuilder< 'a >
     self
   }
 
+  /// Sets the task type of every text.
+  ///
+  /// # Arguments
+  ///
+  /// * `task_type` - What the embeddings are used for
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_task_type( mut self, task_type : crate::models::TaskType ) -> Self
+  {
+    self.task_type = Some( task_type );
+    self
+  }
+
+  /// Sets the titles of the texts, in the order of the texts.
+  ///
+  /// The API accepts titles with [`crate::models::TaskType::RetrievalDocument`] only.
+  ///
+  /// # Arguments
+  ///
+  /// * `titles` - One title per text
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_titles( mut self, titles : &'a [ &'a str ] ) -> Self
+  {
+    self.titles = Some( titles.to_vec() );
+    self
+  }
+
+  /// Sets the desired output dimensionality of every embedding.
+  ///
+  /// # Arguments
+  ///
+  /// * `dimensions` - The desired number of dimensions
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_output_dimensionality( mut self, dimensions : i32 ) -> Self
+  {
+    self.output_dimensionality = Some( dimensions );
+    self
+  }
+
+  /// The requests sent for the configured texts, one per text.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::ValidationError`] if no texts are configured or the
+  /// titles do not match the texts one to one.
+  #[ inline ]
+  pub fn requests( &self ) -> Result< Vec< crate::models::EmbedContentRequest >, Error >
+  {
+    let texts = self.texts.as_ref().ok_or_else( || Error::ValidationError {
+      message : "No texts specified for batch embedding".to_string()
+    } )?;
+    if self.titles.as_ref().is_some_and( | titles | titles.len() != texts.len() )
+    {
+      return Err( Error::ValidationError {
+        message : format!( "Batch embedding needs one title per text, got {} titles for {} texts", self.titles.as_ref().map_or( 0, Vec::len ), texts.len() )
+      } );
+    }
+
+    Ok( texts.iter().enumerate().map( | ( index, text ) |
+    {
+      let mut request = crate::models::EmbedContentRequest::new( text );
+      request.task_type = self.task_type.map( | task_type | task_type.as_str().to_string() );
+      request.title = self.titles.as_ref().map( | titles | titles[ index ].to_string() );
+      request.output_dimensionality = self.output_dimensionality;
+      request
+    } ).collect() )
+  }
+
   /// Executes the batch embedding request.
   ///
   /// # Returns
@@ -236,16 +324,13 @@ impl< 'a > BatchEmbeddingRequestBuilder< 'a >
   ///
   /// # Errors
   ///
-  /// Returns errors if no texts are configured or if batch processing fails.
+  /// Returns the errors of [`Self::requests`], or the error of a failed
+  /// embedding.
   #[ inline ]
   pub async fn execute( self ) -> Result< Vec< Vec< f32 > >, Error >
   {
-    let texts = self.texts.ok_or_else( || Error::ValidationError {
-      message : "No texts specified for batch embedding".to_string()
-    } )?;
-
-    // For now, delegate to the main batch method
-    // In the future, this would use the configured parameters
-    self.model.batch_embed_texts( &texts ).await
+    // Batch size and timeout are not applied yet; texts are embedded one request each
+    let requests = self.requests()?;
+    self.model.embed_each( requests ).await
   }
 }
@@ -0,0 +1,113 @@
+//! Image generation implementation.
+//!
+//! This module generates images with Imagen models through the `:predict`
+//! endpoint and decodes the base64 images it answers.
+
+use reqwest::Method;
+use crate::error::Error;
+use crate::models::{ GenerateImagesRequest, GenerateImagesResponse, GeneratedImage };
+use crate::internal::http;
+
+use super::ModelApi;
+
+impl ModelApi< '_ >
+{
+  /// Generates images from the prompt of the request with an Imagen model.
+  ///
+  /// Images filtered by responsible AI checks are left out, so fewer images
+  /// than requested may be returned.
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - The prompt, and the number, aspect ratio, and person policy of the images
+  ///
+  /// # Returns
+  ///
+  /// Returns the decoded [`GeneratedImage`]s, each with its bytes and MIME type.
+  ///
+  /// # Errors
+  ///
+  /// This method returns an error in the following cases:
+  /// - [`Error::InvalidArgument`] - Not exactly one non-empty prompt, or a number of images outside 1 to 4
+  /// - [`Error::ApiError`] - Every image was filtered; the message gives the reasons
+  /// - [`Error::DeserializationError`] - An image is not valid base64
+  /// - [`Error::NetworkError`] - Network connectivity issues or timeout
+  /// - [`Error::AuthenticationError`] - Invalid or missing API key
+  /// - [`Error::ServerError`] - Gemini API server-side errors (5xx status codes)
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::client::Client;
+  /// # use api_gemini::models::{ AspectRatio, GenerateImagesRequest, PersonGeneration };
+  /// # #[ tokio::main ]
+  /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let request = GenerateImagesRequest::new( "A lighthouse on a cliff at dawn" )
+  ///   .with_number_of_images( 2 )
+  ///   .with_aspect_ratio( AspectRatio::Widescreen )
+  ///   .with_person_generation( PersonGeneration::DontAllow );
+  ///
+  /// let images = client.models().by_name( "imagen-4.0-generate-001" ).generate_images( &request ).await?;
+  /// for ( index, image ) in images.iter().enumerate() {
+  ///   std::fs::write( format!( "lighthouse_{index}.png" ), &image.bytes )?;
+  /// }
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ inline ]
+  pub async fn generate_images
+  (
+    &self,
+    request : &GenerateImagesRequest,
+  )
+  ->
+  Result< Vec< GeneratedImage >, Error >
+  {
+    // Validate model ID and request before sending
+    self.validate_model_id()?;
+    match request.instances.as_slice()
+    {
+      [ instance ] if !instance.prompt.trim().is_empty() => {},
+      _ => return Err( Error::InvalidArgument(
+        "Generate images request must have exactly one non-empty prompt.".to_string()
+      ) ),
+    }
+    if let Some( count ) = request.parameters.number_of_images
+    {
+      if !( 1..=4 ).contains( &count )
+      {
+        return Err( Error::InvalidArgument(
+          format!( "Number of images must be between 1 and 4, got {count}." )
+        ) );
+      }
+    }
+
+    let url = format!(
+      "{}/v1beta/models/{}:predict",
+      self.client.base_url,
+      self.model_id
+    );
+
+    let response : GenerateImagesResponse = http::execute_with_optional_retries
+    (
+      self.client,
+      Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( request ),
+    )
+    .await
+    .map_err( |e| self.enhance_model_operation_error( "generate images", e ) )?;
+
+    let images = response.images()?;
+    let filtered = response.filtered_reasons();
+    if images.is_empty() && !filtered.is_empty()
+    {
+      return Err( Error::ApiError(
+        format!( "All generated images were filtered : {}", filtered.join( "; " ) )
+      ) );
+    }
+    Ok( images )
+  }
+}
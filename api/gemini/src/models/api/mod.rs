@@ -6,6 +6,9 @@
 //! - `models`: Model metadata and discovery
 //! - `content_generation`: Text generation, conversation handling, streaming
 //! - `embeddings`: Vector embeddings for semantic operations
+//! - `answers`: Attributed question answering over grounding passages
+//! - `images`: Image generation with Imagen models
+//! - `videos`: Video generation with Veo models, as long-running operations
 
 /// API handle for interacting with a specific model.
 ///
@@ -87,7 +90,12 @@ impl ModelApi< '_ >
 mod models;
 mod content_generation;
 mod embeddings;
+mod answers;
+mod images;
+mod videos;
 
 // Re-export builders from submodules
 pub use content_generation::GenerationRequestBuilder;
 pub use embeddings::{ EmbeddingRequestBuilder, BatchEmbeddingRequestBuilder };
+#[ cfg( feature = "common" ) ]
+pub use embeddings::GeminiEmbedder;
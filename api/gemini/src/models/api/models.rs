@@ -553,6 +553,7 @@ impl ModelApi< '_ >
   /// Returns a [`CountTokensResponse`] containing:
   /// - `total_tokens`: Total number of tokens in the input
   /// - `cached_content_token_count`: Number of cached content tokens (if applicable)
+  /// - `prompt_tokens_details`, `cache_tokens_details`: Tokens by modality
   ///
   /// # Errors
   ///
@@ -610,11 +611,18 @@ impl ModelApi< '_ >
   {
     // Validate model ID and request before sending
     self.validate_model_id()?;
-    if request.contents.is_empty()
+    match &request.generate_content_request
     {
-      return Err( Error::InvalidArgument(
+      None if request.contents.is_empty() => return Err( Error::InvalidArgument(
         "Count tokens request cannot have empty contents. Please provide at least one content item.".to_string()
-      ) );
+      ) ),
+      Some( _ ) if !request.contents.is_empty() => return Err( Error::InvalidArgument(
+        "Count tokens request sets both contents and generate_content_request. Put the contents in the generation request.".to_string()
+      ) ),
+      Some( generation ) if generation.contents.is_empty() => return Err( Error::InvalidArgument(
+        "Count tokens request has a generation request with empty contents. Please provide at least one content item.".to_string()
+      ) ),
+      _ => {},
     }
 
     let url = format!(
@@ -623,18 +631,45 @@ impl ModelApi< '_ >
       self.model_id
     );
 
+    let body = request.body( &self.model_id )?;
+
     http ::execute_with_optional_retries
     (
       self.client,
       Method::POST,
       &url,
       &self.client.api_key,
-      Some( request ),
+      Some( &body ),
     )
     .await
     .map_err( |e| self.enhance_model_operation_error( "count tokens", e ) )
   }
 
+  /// Counts the tokens of everything a generation request sends: contents,
+  /// tools, system instruction, and cached content.
+  ///
+  /// When the request names `cached_content`, the response tells the tokens
+  /// served from the cache with [`CountTokensResponse::cached_tokens`] and
+  /// the rest with [`CountTokensResponse::uncached_tokens`].
+  ///
+  /// [`CountTokensResponse::cached_tokens`]: crate::models::CountTokensResponse::cached_tokens
+  /// [`CountTokensResponse::uncached_tokens`]: crate::models::CountTokensResponse::uncached_tokens
+  ///
+  /// # Errors
+  ///
+  /// As [`Self::count_tokens`].
+  #[ inline ]
+  pub async fn count_tokens_for_request
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+  )
+  ->
+  Result< crate::models::CountTokensResponse, Error >
+  {
+    self.count_tokens( &crate::models::CountTokensRequest::for_request( request.clone() ) ).await
+  }
+
   /// Enhance errors from model operations with model-specific context.
   pub( super ) fn enhance_model_operation_error( &self, operation : &str, error : Error ) -> Error
   {
@@ -0,0 +1,102 @@
+//! Video generation implementation.
+//!
+//! This module starts video generations with Veo models through the
+//! `:predictLongRunning` endpoint; the videos are read from the operation
+//! once it is done.
+
+use reqwest::Method;
+use crate::error::Error;
+use crate::models::{ GenerateVideosRequest, Operation };
+use crate::internal::http;
+
+use super::ModelApi;
+
+impl ModelApi< '_ >
+{
+  /// Starts generating videos from the prompt of the request with a Veo model.
+  ///
+  /// Video generation takes minutes, so this returns the operation doing it.
+  /// Poll it with `Client::operations().poll_until_done()` and read the videos
+  /// with [`Operation::generated_videos`].
+  ///
+  /// # Arguments
+  ///
+  /// * `request` - The prompt, and the number, aspect ratio, length, and person policy of the videos
+  ///
+  /// # Returns
+  ///
+  /// Returns the started [`Operation`], named `models/{model}/operations/{id}`.
+  ///
+  /// # Errors
+  ///
+  /// This method returns an error in the following cases:
+  /// - [`Error::InvalidArgument`] - Not exactly one non-empty prompt, or zero videos requested
+  /// - [`Error::NetworkError`] - Network connectivity issues or timeout
+  /// - [`Error::AuthenticationError`] - Invalid or missing API key
+  /// - [`Error::ServerError`] - Gemini API server-side errors (5xx status codes)
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::client::Client;
+  /// # use api_gemini::models::{ AspectRatio, GenerateVideosRequest };
+  /// # use std::time::{ Duration, Instant };
+  /// # #[ tokio::main ]
+  /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let request = GenerateVideosRequest::new( "A paper boat drifting down a rainy street" )
+  ///   .with_aspect_ratio( AspectRatio::Widescreen );
+  ///
+  /// let operation = client.models().by_name( "veo-3.0-generate-001" ).generate_videos( &request ).await?;
+  /// let operation = client.operations()
+  ///   .poll_until_done( &operation.name, Duration::from_secs( 10 ), Instant::now() + Duration::from_secs( 600 ) )
+  ///   .await?;
+  /// if let Some( videos ) = operation.generated_videos()? {
+  ///   for sample in videos.generated_samples {
+  ///     println!( "Download : {:?}", sample.video.uri );
+  ///   }
+  /// }
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ inline ]
+  pub async fn generate_videos
+  (
+    &self,
+    request : &GenerateVideosRequest,
+  )
+  ->
+  Result< Operation, Error >
+  {
+    // Validate model ID and request before sending
+    self.validate_model_id()?;
+    match request.instances.as_slice()
+    {
+      [ instance ] if !instance.prompt.trim().is_empty() => {},
+      _ => return Err( Error::InvalidArgument(
+        "Generate videos request must have exactly one non-empty prompt.".to_string()
+      ) ),
+    }
+    if request.parameters.number_of_videos == Some( 0 )
+    {
+      return Err( Error::InvalidArgument( "Number of videos must be at least 1.".to_string() ) );
+    }
+
+    let url = format!(
+      "{}/v1beta/models/{}:predictLongRunning",
+      self.client.base_url,
+      self.model_id
+    );
+
+    http::execute_with_optional_retries
+    (
+      self.client,
+      Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( request ),
+    )
+    .await
+    .map_err( |e| self.enhance_model_operation_error( "generate videos", e ) )
+  }
+}
@@ -3,182 +3,551 @@
 //! Batch Mode provides 50% cost discount for non-time-sensitive requests
 //! with a 24-hour Service Level Objective (SLO).
 //!
+//! A batch job is the `batches/{id}` resource. It is created from requests
+//! inlined in the create call or from a JSONL file uploaded with the Files
+//! API, and answered as a long-running operation whose metadata holds the
+//! job. Results of a finished job are inlined in it, or written to a JSONL
+//! file downloaded with `BatchApi::results`.
+//!
 //! Reference : quickstarts/Batch_mode.ipynb
 
-use serde::{ Deserialize, Serialize };
-use std::time::SystemTime;
+use serde::{ Deserialize, Deserializer, Serialize };
+use super::{ ContentEmbedding, EmbedContentRequest, GenerateContentRequest, GenerateContentResponse, Operation, OperationError };
 
 /// State of a batch job.
-#[ derive( Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize ) ]
-#[ serde( rename_all = "SCREAMING_SNAKE_CASE" ) ]
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default ) ]
 pub enum BatchJobState
 {
-  /// Job is pending execution
+  /// Job is waiting to run
+  #[ serde( rename = "BATCH_STATE_PENDING" ) ]
   Pending,
   /// Job is currently running
+  #[ serde( rename = "BATCH_STATE_RUNNING" ) ]
   Running,
-  /// Job completed successfully
+  /// Job finished; single requests may still have failed
+  #[ serde( rename = "BATCH_STATE_SUCCEEDED" ) ]
   Succeeded,
   /// Job failed
+  #[ serde( rename = "BATCH_STATE_FAILED" ) ]
   Failed,
   /// Job was cancelled
+  #[ serde( rename = "BATCH_STATE_CANCELLED" ) ]
   Cancelled,
-  /// Job completed with some failures
-  PartiallyCompleted,
+  /// Job did not finish within 48 hours
+  #[ serde( rename = "BATCH_STATE_EXPIRED" ) ]
+  Expired,
+  /// State unknown to this version of the crate
+  #[ default ]
+  #[ serde( rename = "BATCH_STATE_UNSPECIFIED", other ) ]
+  Unspecified,
 }
 
-/// Batch job information returned when creating a job.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+impl BatchJobState
+{
+  /// Whether the job has stopped, successfully or not.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_done( self ) -> bool
+  {
+    matches!( self, Self::Succeeded | Self::Failed | Self::Cancelled | Self::Expired )
+  }
+}
+
+/// Request counts of a batch job.
+#[ derive( Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchStats
+{
+  /// Number of requests in the batch
+  #[ serde( default, deserialize_with = "int64" ) ]
+  pub request_count : i64,
+  /// Number of requests answered successfully
+  #[ serde( default, deserialize_with = "int64" ) ]
+  pub successful_request_count : i64,
+  /// Number of requests that failed
+  #[ serde( default, deserialize_with = "int64" ) ]
+  pub failed_request_count : i64,
+  /// Number of requests not processed yet
+  #[ serde( default, deserialize_with = "int64" ) ]
+  pub pending_request_count : i64,
+}
+
+/// Reads an `int64`, which the API answers as a JSON string.
+fn int64< 'de, D : Deserializer< 'de > >( deserializer : D ) -> Result< i64, D::Error >
+{
+  #[ derive( Deserialize ) ]
+  #[ serde( untagged ) ]
+  enum Int64
+  {
+    Number( i64 ),
+    Text( String ),
+  }
+
+  match Int64::deserialize( deserializer )?
+  {
+    Int64::Number( number ) => Ok( number ),
+    Int64::Text( text ) => text.parse().map_err( serde::de::Error::custom ),
+  }
+}
+
+/// A batch job, as found in the metadata of its operation.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct BatchJob
 {
-  /// Unique identifier for this batch job
-  pub job_id : String,
+  /// Resource name of the job, e.g. `batches/abc123`
+  pub name : String,
 
-  /// Current state of the job
-  pub state : BatchJobState,
+  /// Human-readable name given at creation
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub display_name : Option< String >,
 
-  /// Model used for this batch
+  /// Model the requests run on, e.g. `models/gemini-2.5-flash`
+  #[ serde( default ) ]
   pub model : String,
 
-  /// Number of requests in this batch
-  pub request_count : usize,
+  /// Current state of the job
+  #[ serde( default ) ]
+  pub state : BatchJobState,
+
+  /// Request counts of the job
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub batch_stats : Option< BatchStats >,
 
   /// When the job was created
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub create_time : Option< SystemTime >,
+  pub create_time : Option< String >,
+
+  /// When the job was last updated
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub update_time : Option< String >,
 
-  /// When the job results will expire
+  /// When the job stopped
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub expiration_time : Option< SystemTime >,
+  pub end_time : Option< String >,
 
-  /// Error message if job failed
+  /// Results of a finished job
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub error : Option< String >,
+  pub output : Option< BatchOutput >,
+
+  /// Why the job failed, when its operation reports an error
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub error : Option< OperationError >,
 }
 
-/// Status information for a batch job.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+impl BatchJob
+{
+  /// Reads the job from the operation answered for it.
+  ///
+  /// The job is the metadata of the operation; results missing from it are
+  /// taken from the operation response.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when the operation
+  /// does not describe a batch job.
+  #[ inline ]
+  pub fn from_operation( operation : Operation ) -> Result< Self, crate::error::Error >
+  {
+    let invalid = | e : serde_json::Error | crate::error::Error::DeserializationError(
+      format!( "Operation {} does not describe a batch job : {e}", operation.name )
+    );
+    let mut job : Self = match &operation.metadata
+    {
+      Some( metadata ) => serde_json::from_value( metadata.clone() ).map_err( invalid )?,
+      None => Self::default(),
+    };
+    if job.name.is_empty()
+    {
+      job.name.clone_from( &operation.name );
+    }
+    if job.output.is_none()
+    {
+      job.output = operation.response.as_ref()
+        .map( | response | serde_json::from_value( response.clone() ) )
+        .transpose()
+        .map_err( invalid )?;
+    }
+    if job.error.is_none()
+    {
+      job.error = operation.error;
+    }
+    Ok( job )
+  }
+}
+
+/// Where the results of a finished batch job are.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
-pub struct BatchJobStatus
+pub struct BatchOutput
 {
-  /// Job identifier
-  pub job_id : String,
+  /// JSONL file of the results, e.g. `files/batch-abc123`, for jobs created
+  /// from a file
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub responses_file : Option< String >,
 
-  /// Current job state
-  pub state : BatchJobState,
+  /// Results of jobs created from inlined requests
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub inlined_responses : Option< InlinedResponses >,
 
-  /// Number of completed requests
-  #[ serde( default ) ]
-  pub completed_count : usize,
+  /// Results of embedding jobs created from inlined requests
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub inlined_embed_content_responses : Option< InlinedEmbedContentResponses >,
+}
 
-  /// Number of failed requests
+/// Inlined results of a batch job, in the order of the requests.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct InlinedResponses
+{
+  /// One result per request
   #[ serde( default ) ]
-  pub failed_count : usize,
+  pub inlined_responses : Vec< BatchResponse >,
+}
 
-  /// When the status was last updated
+/// Result of one request of a batch job.
+///
+/// Exactly one of `response` and `error` is set. Results read from a JSONL
+/// file carry the key of their request, inlined results its metadata.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchResponse
+{
+  /// Key of the request, from a JSONL result file
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub key : Option< String >,
+
+  /// Metadata of the inlined request, e.g. `{ "key": "request-1" }`
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub update_time : Option< SystemTime >,
+  pub metadata : Option< serde_json::Value >,
 
-  /// Error details if job failed
+  /// The answer to the request
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub error : Option< String >,
+  pub response : Option< GenerateContentResponse >,
+
+  /// Why the request failed
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub error : Option< OperationError >,
 }
 
-/// Billing metadata for batch operations showing cost discount.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+impl BatchResponse
+{
+  /// Key of the request, from the result file or the inlined metadata.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request_key( &self ) -> Option< &str >
+  {
+    self.key.as_deref()
+      .or_else( || self.metadata.as_ref()?.get( "key" )?.as_str() )
+  }
+
+  /// Results read from a JSONL result file, one per line; blank lines are
+  /// skipped.
+  ///
+  /// # Errors
+  ///
+  /// Returns the parse error of the first line that is not a result.
+  #[ inline ]
+  pub fn parse_jsonl( text : &str ) -> Result< Vec< Self >, serde_json::Error >
+  {
+    text.lines()
+      .filter( | line | !line.trim().is_empty() )
+      .map( serde_json::from_str )
+      .collect()
+  }
+}
+
+/// Inlined results of an embedding batch job, in the order of the requests.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
-pub struct BatchBillingMetadata
+pub struct InlinedEmbedContentResponses
 {
-  /// Discount percentage (should be 50 for batch mode)
-  pub discount_percentage : u32,
+  /// One result per request
+  #[ serde( default ) ]
+  pub inlined_responses : Vec< BatchEmbedResponse >,
+}
 
-  /// Standard cost without discount
-  pub standard_cost : f64,
+/// Result of one request of an embedding batch job.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchEmbedResponse
+{
+  /// Metadata of the inlined request, e.g. `{ "key": "text-1" }`
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata : Option< serde_json::Value >,
 
-  /// Discounted cost (50% off)
-  pub discounted_cost : f64,
+  /// The embedding of the text
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub response : Option< super::EmbedContentResponse >,
 
-  /// Total tokens processed
-  #[ serde( default ) ]
-  pub total_tokens : i32,
+  /// Why the request failed
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub error : Option< OperationError >,
 }
 
-/// Results from a completed batch job.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
-#[ serde( rename_all = "camelCase" ) ]
+/// Results of a finished batch job, one per request.
+#[ derive( Debug, Clone ) ]
 pub struct BatchJobResults
 {
-  /// Job identifier
-  pub job_id : String,
+  /// The finished job
+  pub job : BatchJob,
 
-  /// Final job state
-  pub state : BatchJobState,
+  /// One result per request, in the order of the requests for inlined
+  /// requests and of the result file otherwise
+  pub responses : Vec< BatchResponse >,
+}
+
+impl BatchJobResults
+{
+  /// The answers to `count` requests keyed `request-{index}`, in the order
+  /// of the requests.
+  ///
+  /// Result files may come in any order and are put back by key; inlined
+  /// results without a key follow the order of the requests.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::BatchProcessingError`] when a request
+  /// has no answer, with the messages of the failed requests.
+  #[ inline ]
+  pub fn into_request_order( self, count : usize ) -> Result< Vec< GenerateContentResponse >, crate::error::Error >
+  {
+    let mut responses : Vec< Option< GenerateContentResponse > > = vec![ None; count ];
+    let mut errors = Vec::new();
+    for ( position, result ) in self.responses.into_iter().enumerate()
+    {
+      let index = result.request_key()
+        .map_or( Some( position ), | key | key.strip_prefix( "request-" ).and_then( | index | index.parse::< usize >().ok() ) )
+        .filter( | index | *index < count );
+      match ( index, result.response )
+      {
+        ( Some( index ), Some( response ) ) => responses[ index ] = Some( response ),
+        ( _, _ ) => errors.extend( result.error.map( | error | error.message ) ),
+      }
+    }
+    let successful = responses.iter().filter( | response | response.is_some() ).count();
+    if successful < count
+    {
+      return Err( crate::error::Error::BatchProcessingError
+      {
+        successful,
+        failed : count - successful,
+        message : format!( "Batch job {} did not answer every prompt : {}", self.job.name, errors.join( "; " ) ),
+      } );
+    }
+    Ok( responses.into_iter().flatten().collect() )
+  }
+}
 
-  /// Individual responses for each request
-  pub responses : Vec< super::GenerateContentResponse >,
+/// Results of a finished embedding batch job, one per text.
+#[ derive( Debug, Clone ) ]
+pub struct BatchEmbeddingResults
+{
+  /// The finished job
+  pub job : BatchJob,
 
-  /// Billing information showing 50% discount
-  #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub billing_metadata : Option< BatchBillingMetadata >,
+  /// The embedding of each text, in the order of the texts; `None` where
+  /// the request failed
+  pub embeddings : Vec< Option< ContentEmbedding > >,
+}
 
-  /// When results were retrieved
-  #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub retrieve_time : Option< SystemTime >,
+/// A page of batch jobs.
+#[ derive( Debug, Clone, Default ) ]
+pub struct BatchJobList
+{
+  /// The batch jobs of the page
+  pub jobs : Vec< BatchJob >,
+
+  /// Token of the next page, if there is one
+  pub next_page_token : Option< String >,
 }
 
-/// Results from a batch embedding job.
+/// One request of a batch job, with the key its result is found by.
+///
+/// Serialized, it is a line of the JSONL input file of a job.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-#[ serde( rename_all = "camelCase" ) ]
-pub struct BatchEmbeddingResults
+pub struct BatchRequest
 {
-  /// Job identifier
-  pub job_id : String,
+  /// Key of the request, repeated in its result
+  pub key : String,
 
-  /// Final job state
-  pub state : BatchJobState,
+  /// The content generation request
+  pub request : GenerateContentRequest,
+}
 
-  /// Individual embeddings for each text
-  pub embeddings : Vec< super::ContentEmbedding >,
+impl BatchRequest
+{
+  /// A request with the given key.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( key : impl Into< String >, request : GenerateContentRequest ) -> Self
+  {
+    Self { key : key.into(), request }
+  }
+
+  /// The JSONL input file of a job, one request per line, to upload with
+  /// the Files API as `application/jsonl`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::SerializationError`] when a request
+  /// cannot be serialized.
+  #[ inline ]
+  pub fn jsonl( requests : &[ Self ] ) -> Result< String, crate::error::Error >
+  {
+    let mut jsonl = String::new();
+    for request in requests
+    {
+      jsonl.push_str( &serde_json::to_string( request )? );
+      jsonl.push( '\n' );
+    }
+    Ok( jsonl )
+  }
+}
 
-  /// Billing information
+/// Request to create a batch job, from inlined requests or a file.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct CreateBatchJobRequest
+{
+  /// Human-readable name of the job
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub billing_metadata : Option< BatchBillingMetadata >,
+  pub display_name : Option< String >,
+
+  /// The requests of the job
+  pub input_config : BatchInputConfig,
 }
 
-/// List of batch jobs with pagination.
+impl CreateBatchJobRequest
+{
+  /// A job answering the given requests, inlined in the create call.
+  ///
+  /// Inlined requests suit jobs under 20 MB; larger jobs are created from a
+  /// file.
+  #[ inline ]
+  #[ must_use ]
+  pub fn inline( requests : Vec< BatchRequest > ) -> Self
+  {
+    let requests = requests.into_iter()
+      .map( | BatchRequest { key, request } | InlinedRequest { request, metadata : Some( serde_json::json!( { "key" : key } ) ) } )
+      .collect();
+    Self { display_name : None, input_config : BatchInputConfig { file_name : None, requests : Some( InlinedRequests { requests } ) } }
+  }
+
+  /// A job answering the requests of a JSONL file uploaded with the Files
+  /// API, e.g. `files/abc123`; see [`BatchRequest::jsonl`].
+  #[ inline ]
+  #[ must_use ]
+  pub fn from_file( file_name : impl Into< String > ) -> Self
+  {
+    Self { display_name : None, input_config : BatchInputConfig { file_name : Some( file_name.into() ), requests : None } }
+  }
+
+  /// Sets the human-readable name of the job.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_display_name( mut self, display_name : impl Into< String > ) -> Self
+  {
+    self.display_name = Some( display_name.into() );
+    self
+  }
+}
+
+/// Requests of a batch job: a file, or requests inlined in the create call.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
-pub struct BatchJobList
+pub struct BatchInputConfig
 {
-  /// List of jobs
-  pub jobs : Vec< BatchJob >,
+  /// JSONL file of the requests, e.g. `files/abc123`
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub file_name : Option< String >,
 
-  /// Token for next page if more results available
+  /// Inlined requests
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub next_page_token : Option< String >,
+  pub requests : Option< InlinedRequests >,
 }
 
-/// Request to create a batch job with inline requests.
+/// Requests inlined in the create call of a batch job.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-#[ serde( rename_all = "camelCase" ) ]
-pub struct CreateBatchJobRequest
+pub struct InlinedRequests
 {
-  /// Model to use for all requests
-  pub model : String,
+  /// The requests, in order
+  pub requests : Vec< InlinedRequest >,
+}
+
+/// One request inlined in the create call of a batch job.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+pub struct InlinedRequest
+{
+  /// The content generation request
+  pub request : GenerateContentRequest,
 
-  /// Individual requests to process
-  pub requests : Vec< super::GenerateContentRequest >,
+  /// Metadata repeated in the result, e.g. `{ "key": "request-1" }`
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata : Option< serde_json::Value >,
 }
 
-/// Request to create a batch embedding job.
+/// Request to create an embedding batch job from inlined texts.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct CreateBatchEmbeddingRequest
 {
-  /// Model to use for embeddings
-  pub model : String,
+  /// Human-readable name of the job
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub display_name : Option< String >,
+
+  /// The requests of the job
+  pub input_config : BatchEmbedInputConfig,
+}
+
+impl CreateBatchEmbeddingRequest
+{
+  /// A job embedding each text, keyed `text-{index}`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn texts< S : AsRef< str > >( texts : &[ S ] ) -> Self
+  {
+    let requests = texts.iter().enumerate()
+      .map( | ( index, text ) | InlinedEmbedContentRequest
+      {
+        request : EmbedContentRequest::new( text.as_ref() ),
+        metadata : Some( serde_json::json!( { "key" : format!( "text-{index}" ) } ) ),
+      } )
+      .collect();
+    Self { display_name : None, input_config : BatchEmbedInputConfig { file_name : None, requests : Some( InlinedEmbedContentRequests { requests } ) } }
+  }
+}
+
+/// Requests of an embedding batch job: a file, or inlined requests.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchEmbedInputConfig
+{
+  /// JSONL file of the requests, e.g. `files/abc123`
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub file_name : Option< String >,
 
-  /// Texts to embed
-  pub texts : Vec< String >,
+  /// Inlined requests
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub requests : Option< InlinedEmbedContentRequests >,
+}
+
+/// Embedding requests inlined in the create call of a batch job.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+pub struct InlinedEmbedContentRequests
+{
+  /// The requests, in order
+  pub requests : Vec< InlinedEmbedContentRequest >,
+}
+
+/// One embedding request inlined in the create call of a batch job.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+pub struct InlinedEmbedContentRequest
+{
+  /// The embedding request
+  pub request : EmbedContentRequest,
+
+  /// Metadata repeated in the result, e.g. `{ "key": "text-1" }`
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata : Option< serde_json::Value >,
 }
@@ -505,6 +505,43 @@ mod private
       }
     }
 
+    /// Execute a request with failover handling inside an overall call budget
+    ///
+    /// Each endpoint tried counts against `budget` (or the attempts counted
+    /// by a budget-aware retry layer inside it do), and the whole call is cut
+    /// off at the budget's total deadline instead of restarting the clock for
+    /// the backup endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error` if:
+    /// - Both primary and backup endpoints fail
+    /// - No backup endpoints are configured and primary fails
+    /// - Client creation fails
+    /// - The budget is exhausted (`Error::BudgetExhausted`)
+    #[ cfg( feature = "failover" ) ]
+    #[ inline ]
+    pub async fn execute_with_failover_within< F, Fut, T >(
+      &self,
+      budget : &api_common::BudgetTracker,
+      operation : F
+    ) -> Result< T, crate::error::Error >
+    where
+      F: Fn( crate::client::Client ) -> Fut,
+      Fut : Future< Output = Result< T, crate::error::Error > >,
+    {
+      // Try primary endpoint first
+      if let Ok( result ) = budget.run_layer( operation( self.client.clone() ) ).await?
+      {
+        Ok( result )
+      } else {
+        // Primary failed, try backup if the budget still allows it
+        budget.check()?;
+        let backup_client = self.switch_to_backup()?;
+        budget.run_layer( operation( backup_client ) ).await?
+      }
+    }
+
     /// Get current failover metrics
     ///
     /// # Panics
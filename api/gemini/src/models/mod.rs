@@ -45,10 +45,17 @@ mod private
   pub use super::types::file::*;
   pub use super::types::token::*;
   pub use super::types::cache::*;
+  pub use super::types::retrieval::*;
+  pub use super::types::image::*;
+  pub use super::types::video::*;
+  pub use super::types::operation::*;
   pub use super::types::content::*;
+  pub use super::types::safety::*;
   pub use super::types::streaming::*;
   #[ cfg( feature = "chat" ) ]
   pub use super::types::chat::*;
+  #[ cfg( feature = "chat" ) ]
+  pub use super::types::session::*;
   pub use super::types::search::*;
   pub use super::types::function::*;
   pub use super::types::code_execution::*;
@@ -65,7 +72,18 @@ mod private
   exposed use private::GenerateContentRequest;
   exposed use private::GenerateContentResponse;
   exposed use private::GenerationConfig;
+  exposed use private::SpeechConfig;
+  exposed use private::VoiceConfig;
+  exposed use private::PrebuiltVoiceConfig;
+  exposed use private::MultiSpeakerVoiceConfig;
+  exposed use private::SpeakerVoiceConfig;
+  exposed use private::GeneratedAudio;
+  exposed use private::ThinkingConfig;
   exposed use private::SafetySetting;
+  exposed use private::HarmCategory;
+  exposed use private::HarmBlockThreshold;
+  exposed use private::HarmProbability;
+  exposed use private::SafetySettingsBuilder;
   exposed use private::PromptFeedback;
   exposed use private::UsageMetadata;
   exposed use private::BatchGenerateContentRequest;
@@ -73,6 +91,7 @@ mod private
 
   // Embedding types
   exposed use private::EmbedContentRequest;
+  exposed use private::TaskType;
   exposed use private::EmbedContentResponse;
   exposed use private::ContentEmbedding;
   exposed use private::BatchEmbedContentsRequest;
@@ -90,6 +109,7 @@ mod private
   // Token operations types
   exposed use private::CountTokensRequest;
   exposed use private::CountTokensResponse;
+  exposed use private::ModalityTokenCount;
   exposed use private::BatchCountTokensRequest;
   exposed use private::BatchCountTokensResponse;
   exposed use private::AnalyzeTokensRequest;
@@ -102,6 +122,56 @@ mod private
   exposed use private::CachedContentResponse;
   exposed use private::ListCachedContentsResponse;
   exposed use private::UpdateCachedContentRequest;
+  exposed use private::cached_content_name;
+
+  // Semantic Retrieval types
+  exposed use private::Corpus;
+  exposed use private::ListCorporaResponse;
+  exposed use private::StringList;
+  exposed use private::CustomMetadata;
+  exposed use private::Document;
+  exposed use private::ListDocumentsResponse;
+  exposed use private::ChunkData;
+  exposed use private::Chunk;
+  exposed use private::ListChunksResponse;
+  exposed use private::CreateChunkRequest;
+  exposed use private::BatchCreateChunksRequest;
+  exposed use private::UpdateChunkRequest;
+  exposed use private::BatchUpdateChunksRequest;
+  exposed use private::BatchChunksResponse;
+  exposed use private::DeleteChunkRequest;
+  exposed use private::BatchDeleteChunksRequest;
+  exposed use private::MetadataCondition;
+  exposed use private::MetadataFilter;
+  exposed use private::QueryCorpusRequest;
+  exposed use private::RelevantChunk;
+  exposed use private::QueryCorpusResponse;
+  exposed use private::GroundingPassage;
+  exposed use private::GroundingPassages;
+  exposed use private::SemanticRetrieverConfig;
+  exposed use private::GenerateAnswerRequest;
+  exposed use private::InputFeedback;
+  exposed use private::GenerateAnswerResponse;
+
+  // Image generation types
+  exposed use private::AspectRatio;
+  exposed use private::PersonGeneration;
+  exposed use private::ImagePromptInstance;
+  exposed use private::ImageGenerationParameters;
+  exposed use private::GenerateImagesRequest;
+  exposed use private::ImagePrediction;
+  exposed use private::GenerateImagesResponse;
+  exposed use private::GeneratedImage;
+
+  // Video generation and long-running operation types
+  exposed use private::VideoPromptInstance;
+  exposed use private::VideoGenerationParameters;
+  exposed use private::GenerateVideosRequest;
+  exposed use private::GenerateVideosResponse;
+  exposed use private::GeneratedVideo;
+  exposed use private::Video;
+  exposed use private::Operation;
+  exposed use private::OperationError;
 
   // Content structure types
   exposed use private::Content;
@@ -121,6 +191,8 @@ mod private
   exposed use private::StreamingResponse;
   #[ cfg( feature = "streaming" ) ]
   exposed use private::StreamingRequestBuilder;
+  #[ cfg( feature = "streaming" ) ]
+  exposed use private::StreamChunkParser;
 
   // Chat types (feature-gated)
   #[ cfg( feature = "chat" ) ]
@@ -135,14 +207,25 @@ mod private
   exposed use private::ChatChoice;
   #[ cfg( feature = "chat" ) ]
   exposed use private::ChatUsage;
+  #[ cfg( feature = "chat" ) ]
+  exposed use private::ChatSession;
 
 
   // Search and grounding types
   exposed use private::GoogleSearchTool;
+  exposed use private::DynamicRetrievalConfig;
+  exposed use private::GoogleSearch;
+  exposed use private::UrlContext;
   exposed use private::GroundingMetadata;
+  exposed use private::GroundingCitation;
   exposed use private::GroundingChunk;
+  exposed use private::WebSource;
+  exposed use private::RetrievedContext;
   exposed use private::GroundingSupport;
+  exposed use private::GroundingSegment;
   exposed use private::SearchEntryPoint;
+  exposed use private::UrlContextMetadata;
+  exposed use private::UrlMetadata;
 
   // Function calling types
   exposed use private::Tool;
@@ -156,6 +239,9 @@ mod private
   exposed use private::CodeExecution;
   exposed use private::CodeExecutionConfig;
   exposed use private::CodeExecutionResult;
+  exposed use private::CodeExecutionOutcome;
+  exposed use private::CodeLanguage;
+  exposed use private::ExecutableCode;
 
   // Model tuning types
   exposed use private::CreateTunedModelRequest;
@@ -181,5 +267,5 @@ mod private
   exposed use model_deployment::{ DeploymentState, DeploymentEnvironment, DeploymentStrategy, ScalingConfig, ScalingConfigBuilder, ResourceConfig, ResourceConfigBuilder, DeploymentHealthCheckConfig, DeploymentHealthCheckConfigBuilder, MonitoringConfig, MonitoringConfigBuilder, ContainerConfig, ContainerConfigBuilder, OrchestrationConfig, DeploymentMetrics, ModelDeployment, DeploymentBuilder, DeploymentSummary, DeploymentCache, IntelligentScaler, ScalingDecision, PerformanceOptimizer, OptimizationRecommendation, OptimizationCategory, OptimizationPriority, ImpactEstimate, ImplementationEffort };
   exposed use media_optimization::{ MediaProcessingConfig, MediaRetryConfig, ThumbnailConfig, ThumbnailFormat, MediaCache, MediaCacheStats, MediaCacheStatsReport, MediaProcessingPipeline, MediaProcessingMetrics, ProcessedMediaResult, ProcessedMediaMetadata, MediaProcessingMetricsReport, ThumbnailGenerator, OptimizedMediaApi };
   exposed use semantic_retrieval_optimized::{ VectorIndex, CacheStrategy, VectorSearchResult, IndexStats, CacheStats, FlatVectorIndex, AdaptiveLruCache, OptimizedRetrievalConfig, OptimizedIndexType, CacheConfig, CacheWarmingStrategy, SearchOptimizationConfig, MonitoringConfig as OptimizedMonitoringConfig, OptimizedSemanticRetrievalApi, PerformanceMetrics as OptimizedPerformanceMetrics };
-  exposed use batch::{ BatchJobState, BatchJob, BatchJobStatus, BatchBillingMetadata, BatchJobResults, BatchEmbeddingResults, BatchJobList, CreateBatchJobRequest, CreateBatchEmbeddingRequest };
+  exposed use batch::{ BatchJobState, BatchStats, BatchJob, BatchOutput, InlinedResponses, BatchResponse, InlinedEmbedContentResponses, BatchEmbedResponse, BatchJobResults, BatchEmbeddingResults, BatchJobList, BatchRequest, CreateBatchJobRequest, BatchInputConfig, InlinedRequests, InlinedRequest, CreateBatchEmbeddingRequest, BatchEmbedInputConfig, InlinedEmbedContentRequests, InlinedEmbedContentRequest };
 }
@@ -87,7 +87,7 @@ where
     let config_clone = config_arc.clone();
     let timeout_notify_clone = timeout_notify.clone();
 
-    tokio ::spawn( async move {
+    api_common::spawn( async move {
       Self::manage_stream_optimized(
         stream,
         control_rx,
@@ -347,7 +347,7 @@ where
         },
 
         // Event-driven timeout handling (only if not using event-driven timeouts)
-        _ = tokio::time::sleep( Duration::from_millis( 500 ) ), if is_paused && !current_config.event_driven_timeouts =>
+        _ = api_common::sleep( Duration::from_millis( 500 ) ), if is_paused && !current_config.event_driven_timeouts =>
         {
           if let Some( start ) = pause_start
           {
@@ -382,14 +382,14 @@ where
     state : Arc< AtomicU8 >,
     timeout_notify : Arc< Notify >,
     timeout_duration : Duration
-  ) -> tokio::task::JoinHandle< () >
+  ) -> api_common::JoinHandle< () >
   {
-    tokio ::spawn( async move {
+    api_common::spawn( async move {
       let mut pause_start : Option< Instant > = None;
 
       loop
       {
-        tokio ::time::sleep( Duration::from_millis( 100 ) ).await;
+        api_common::sleep( Duration::from_millis( 100 ) ).await;
 
         let current_state = StreamState::from_u8( state.load( Ordering::Relaxed ) );
 
@@ -456,7 +456,7 @@ where
       config_guard.control_operation_timeout
     };
 
-    tokio ::time::timeout( config_timeout, response_rx )
+    api_common::timeout( config_timeout, response_rx )
       .await
       .map_err( |_| crate::error::Error::ApiError( "Pause operation timed out".to_string() ) )?
       .map_err( |_| crate::error::Error::ApiError( "Pause operation channel closed".to_string() ) )?
@@ -477,7 +477,7 @@ where
       config_guard.control_operation_timeout
     };
 
-    tokio ::time::timeout( config_timeout, response_rx )
+    api_common::timeout( config_timeout, response_rx )
       .await
       .map_err( |_| crate::error::Error::ApiError( "Resume operation timed out".to_string() ) )?
       .map_err( |_| crate::error::Error::ApiError( "Resume operation channel closed".to_string() ) )?
@@ -498,7 +498,7 @@ where
       config_guard.control_operation_timeout
     };
 
-    tokio ::time::timeout( config_timeout, response_rx )
+    api_common::timeout( config_timeout, response_rx )
       .await
       .map_err( |_| crate::error::Error::ApiError( "Cancel operation timed out".to_string() ) )?
       .map_err( |_| crate::error::Error::ApiError( "Cancel operation channel closed".to_string() ) )?
@@ -541,7 +541,7 @@ where
       config_guard.control_operation_timeout
     };
 
-    tokio ::time::timeout( config_timeout, response_rx )
+    api_common::timeout( config_timeout, response_rx )
       .await
       .map_err( |_| crate::error::Error::ApiError( "Config update operation timed out".to_string() ) )?
       .map_err( |_| crate::error::Error::ApiError( "Config update operation channel closed".to_string() ) )?
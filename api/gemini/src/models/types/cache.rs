@@ -1,10 +1,43 @@
 //! Cache management types for the Gemini API.
 
+use core::time::Duration;
 use serde::{ Deserialize, Serialize };
 use super::content::Content;
 use super::function::Tool;
 use super::generation::UsageMetadata;
 
+/// The resource name of a cache, `cachedContents/{id}`, from either its ID
+/// or its full name.
+///
+/// This is the form `GenerateContentRequest::cached_content` expects.
+#[ inline ]
+#[ must_use ]
+pub fn cached_content_name( cache : &str ) -> String
+{
+  if cache.starts_with( "cachedContents/" )
+  {
+    cache.to_string()
+  }
+  else
+  {
+    format!( "cachedContents/{cache}" )
+  }
+}
+
+/// A duration in the API format of seconds, e.g. "3600s" or "1.5s".
+fn format_ttl( ttl : Duration ) -> String
+{
+  if ttl.subsec_nanos() == 0
+  {
+    format!( "{}s", ttl.as_secs() )
+  }
+  else
+  {
+    let fraction = format!( "{:09}", ttl.subsec_nanos() );
+    format!( "{}.{}s", ttl.as_secs(), fraction.trim_end_matches( '0' ) )
+  }
+}
+
 /// Request to create cached content.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -41,6 +74,80 @@ pub struct CreateCachedContentRequest
   pub tool_config : Option< serde_json::Value >,
 }
 
+impl CreateCachedContentRequest
+{
+  /// Creates a request caching `contents` for `model`, given with or without
+  /// its `models/` prefix, with the default TTL of the API (one hour).
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( model : &str, contents : Vec< Content > ) -> Self
+  {
+    let model = if model.starts_with( "models/" ) { model.to_string() } else { format!( "models/{model}" ) };
+    Self
+    {
+      model,
+      contents,
+      ttl : None,
+      expire_time : None,
+      display_name : None,
+      system_instruction : None,
+      tools : None,
+      tool_config : None,
+    }
+  }
+
+  /// Sets how long the cache lives after it is created.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_ttl( mut self, ttl : Duration ) -> Self
+  {
+    self.ttl = Some( format_ttl( ttl ) );
+    self.expire_time = None;
+    self
+  }
+
+  /// Sets when the cache expires, as an RFC3339 timestamp.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_expire_time( mut self, expire_time : &str ) -> Self
+  {
+    self.expire_time = Some( expire_time.to_string() );
+    self.ttl = None;
+    self
+  }
+
+  /// Sets the human-readable display name of the cache.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_display_name( mut self, display_name : &str ) -> Self
+  {
+    self.display_name = Some( display_name.to_string() );
+    self
+  }
+
+  /// Caches a system instruction along with the contents.
+  ///
+  /// Requests using the cache cannot set a system instruction of their own.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_system_instruction( mut self, system_instruction : Content ) -> Self
+  {
+    self.system_instruction = Some( system_instruction );
+    self
+  }
+
+  /// Caches tools along with the contents.
+  ///
+  /// Requests using the cache cannot set tools of their own.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_tools( mut self, tools : Vec< Tool > ) -> Self
+  {
+    self.tools = Some( tools );
+    self
+  }
+}
+
 /// Response from creating cached content.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -52,7 +159,8 @@ pub struct CachedContentResponse
   /// The model used for caching.
   pub model : String,
 
-  /// The cached contents.
+  /// The cached contents; the API leaves them out of `get` and `list`.
+  #[ serde( default ) ]
   pub contents : Vec< Content >,
 
   /// Expiration time in RFC3339 format.
@@ -88,6 +196,17 @@ pub struct CachedContentResponse
   pub usage_metadata : Option< UsageMetadata >,
 }
 
+impl CachedContentResponse
+{
+  /// The ID of the cache: its name without the `cachedContents/` prefix.
+  #[ inline ]
+  #[ must_use ]
+  pub fn id( &self ) -> &str
+  {
+    self.name.strip_prefix( "cachedContents/" ).unwrap_or( &self.name )
+  }
+}
+
 /// Response from listing cached contents.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -115,3 +234,40 @@ pub struct UpdateCachedContentRequest
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub expire_time : Option< String >,
 }
+
+impl UpdateCachedContentRequest
+{
+  /// An update that makes the cache live for `ttl` from now.
+  #[ inline ]
+  #[ must_use ]
+  pub fn ttl( ttl : Duration ) -> Self
+  {
+    Self { ttl : Some( format_ttl( ttl ) ), expire_time : None }
+  }
+
+  /// An update that makes the cache expire at `expire_time`, an RFC3339
+  /// timestamp.
+  #[ inline ]
+  #[ must_use ]
+  pub fn expire_time( expire_time : &str ) -> Self
+  {
+    Self { ttl : None, expire_time : Some( expire_time.to_string() ) }
+  }
+
+  /// The `updateMask` of the update: the fields it sets, comma-separated.
+  #[ inline ]
+  #[ must_use ]
+  pub fn update_mask( &self ) -> String
+  {
+    let mut fields = Vec::new();
+    if self.ttl.is_some()
+    {
+      fields.push( "ttl" );
+    }
+    if self.expire_time.is_some()
+    {
+      fields.push( "expireTime" );
+    }
+    fields.join( "," )
+  }
+}
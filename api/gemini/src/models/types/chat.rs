@@ -347,3 +347,13 @@ pub struct ChatUsage
   /// Total tokens used.
   pub total_tokens : i32,
 }
+
+#[ cfg( all( feature = "chat", feature = "common" ) ) ]
+impl From< ChatUsage > for api_common::TokenUsage
+{
+  #[ inline ]
+  fn from( usage : ChatUsage ) -> Self
+  {
+    Self::new( u64::try_from( usage.prompt_tokens ).unwrap_or( 0 ), u64::try_from( usage.completion_tokens ).unwrap_or( 0 ) )
+  }
+}
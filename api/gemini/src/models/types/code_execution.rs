@@ -1,9 +1,10 @@
 //! Code execution types for the Gemini API.
 
 use serde::{ Deserialize, Serialize };
+use super::generation::GenerateContentResponse;
 
 /// Configuration for code execution.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct CodeExecution
 {
@@ -24,23 +25,97 @@ pub struct CodeExecutionConfig
   pub enable_network : Option< bool >,
 }
 
-/// Result from code execution.
+/// Programming language of code the model executed.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum CodeLanguage
+{
+  /// Python 3.
+  #[ serde( rename = "PYTHON" ) ]
+  Python,
+
+  /// A language not given, or not known to this version.
+  #[ serde( rename = "LANGUAGE_UNSPECIFIED", other ) ]
+  Unspecified,
+}
+
+/// Outcome of executing code.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum CodeExecutionOutcome
+{
+  /// The code ran to completion; the output is its stdout.
+  #[ serde( rename = "OUTCOME_OK" ) ]
+  Ok,
+
+  /// The code failed; the output is its stderr or a traceback.
+  #[ serde( rename = "OUTCOME_FAILED" ) ]
+  Failed,
+
+  /// The code ran too long and was stopped; the output may be partial.
+  #[ serde( rename = "OUTCOME_DEADLINE_EXCEEDED" ) ]
+  DeadlineExceeded,
+
+  /// An outcome not given, or not known to this version.
+  #[ serde( rename = "OUTCOME_UNSPECIFIED", other ) ]
+  Unspecified,
+}
+
+/// Code the model wrote for the code execution tool to run.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ExecutableCode
+{
+  /// Language of the code.
+  pub language : CodeLanguage,
+
+  /// The code.
+  pub code : String,
+}
+
+/// Result of running the code of the preceding [`ExecutableCode`] part.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct CodeExecutionResult
 {
   /// Outcome of the code execution.
-  pub outcome : String,
+  pub outcome : CodeExecutionOutcome,
 
-  /// Output produced by the code execution.
+  /// Stdout when the code succeeded, or else stderr or another description.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub output : Option< String >,
+}
 
-  /// Error message if execution failed.
-  #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub error : Option< String >,
+impl GenerateContentResponse
+{
+  /// The code the first candidate ran, in order.
+  #[ inline ]
+  #[ must_use ]
+  pub fn executed_code( &self ) -> Vec< &ExecutableCode >
+  {
+    self.candidates.first()
+      .map( | candidate | candidate.content.parts.iter().filter_map( | part | part.executable_code.as_ref() ).collect() )
+      .unwrap_or_default()
+  }
 
-  /// Execution time in milliseconds.
-  #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub execution_time_ms : Option< i64 >,
+  /// The results of the code the first candidate ran, in order.
+  #[ inline ]
+  #[ must_use ]
+  pub fn code_execution_results( &self ) -> Vec< &CodeExecutionResult >
+  {
+    self.candidates.first()
+      .map( | candidate | candidate.content.parts.iter().filter_map( | part | part.code_execution_result.as_ref() ).collect() )
+      .unwrap_or_default()
+  }
+
+  /// The stdout of the code the first candidate ran successfully, joined, or
+  /// `None` when no code succeeded with output.
+  #[ inline ]
+  #[ must_use ]
+  pub fn execution_output( &self ) -> Option< String >
+  {
+    let output : String = self.code_execution_results().into_iter()
+      .filter( | result | result.outcome == CodeExecutionOutcome::Ok )
+      .filter_map( | result | result.output.as_deref() )
+      .collect();
+    ( !output.is_empty() ).then_some( output )
+  }
 }
@@ -1,5 +1,6 @@
 //! Content structure types for the Gemini API.
 
+use base64::{ engine::general_purpose::STANDARD, Engine as _ };
 use serde::{ Deserialize, Serialize };
 use super::file::VideoMetadata;
 
@@ -18,6 +19,31 @@ pub struct Content
   pub role : String,
 }
 
+/// Converts a provider-neutral message into a single-text-part `Content`.
+///
+/// Gemini names the assistant role `"model"`. System messages keep the role
+/// `"system"` and belong in `GenerateContentRequest::system_instruction`,
+/// not in `contents`.
+#[ cfg( feature = "common" ) ]
+impl From< api_common::NeutralMessage > for Content
+{
+  #[ inline ]
+  fn from( message : api_common::NeutralMessage ) -> Self
+  {
+    let role = match message.role
+    {
+      api_common::NeutralRole::System    => "system",
+      api_common::NeutralRole::User      => "user",
+      api_common::NeutralRole::Assistant => "model",
+    };
+    Self
+    {
+      parts : vec![ Part { text : Some( message.content ), ..Default::default() } ],
+      role : role.to_string(),
+    }
+  }
+}
+
 /// A part of content.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -51,6 +77,189 @@ pub struct Part
   /// Whether this part is an internal thinking step (gemini-2.5+ thinking models).
   /// Thinking parts should not be included in the user-visible response.
   pub thought : Option< bool >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Code the model wrote for the code execution tool.
+  pub executable_code : Option< super::code_execution::ExecutableCode >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Result of running the code of the preceding part.
+  pub code_execution_result : Option< super::code_execution::CodeExecutionResult >,
+}
+
+impl Part
+{
+  /// Largest data sent inline; the whole request must stay under 20 MB, so
+  /// larger files go through the Files API and [`Self::from_file_uri`].
+  pub const MAX_INLINE_BYTES : usize = 20 * 1024 * 1024;
+
+  /// Whether the part is a thought summary of a thinking model rather than
+  /// part of the answer.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_thought( &self ) -> bool
+  {
+    self.thought == Some( true )
+  }
+
+  /// Inline data of the given MIME type, encoded to base64.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::InvalidArgument`] when the data or MIME
+  /// type is empty, or the data is larger than [`Self::MAX_INLINE_BYTES`].
+  #[ inline ]
+  pub fn from_bytes( mime_type : &str, bytes : &[ u8 ] ) -> Result< Self, crate::error::Error >
+  {
+    if mime_type.is_empty()
+    {
+      return Err( crate::error::Error::InvalidArgument( "Inline data needs a MIME type".to_string() ) );
+    }
+    if bytes.is_empty()
+    {
+      return Err( crate::error::Error::InvalidArgument( format!( "Inline {mime_type} data is empty" ) ) );
+    }
+    if bytes.len() > Self::MAX_INLINE_BYTES
+    {
+      return Err( crate::error::Error::InvalidArgument( format!(
+        "Inline {mime_type} data is {} bytes, over the {} byte limit; upload it with the Files API and use Part::from_file_uri",
+        bytes.len(), Self::MAX_INLINE_BYTES
+      ) ) );
+    }
+    Ok( Self
+    {
+      inline_data : Some( Blob { mime_type : mime_type.to_string(), data : STANDARD.encode( bytes ) } ),
+      ..Default::default()
+    } )
+  }
+
+  /// Inline data read from a file, its MIME type detected from the content
+  /// and, failing that, the extension.
+  ///
+  /// Images, PDFs, audio, video, and plain text are recognised.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::Io`] when the file cannot be read, and
+  /// [`crate::error::Error::InvalidArgument`] when it is too large, empty, or
+  /// of an unknown type.
+  #[ inline ]
+  pub fn from_path( path : impl AsRef< std::path::Path > ) -> Result< Self, crate::error::Error >
+  {
+    let path = path.as_ref();
+    let size = std::fs::metadata( path )
+      .map_err( | e | crate::error::Error::Io( format!( "Failed to read {} : {e}", path.display() ) ) )?
+      .len();
+    if usize::try_from( size ).map_or( true, | size | size > Self::MAX_INLINE_BYTES )
+    {
+      return Err( crate::error::Error::InvalidArgument( format!(
+        "{} is {size} bytes, over the {} byte inline limit; upload it with the Files API and use Part::from_file_uri",
+        path.display(), Self::MAX_INLINE_BYTES
+      ) ) );
+    }
+    let bytes = std::fs::read( path )
+      .map_err( | e | crate::error::Error::Io( format!( "Failed to read {} : {e}", path.display() ) ) )?;
+    let mime_type = sniff_mime_type( &bytes )
+      .or_else( || path.extension().and_then( | extension | extension.to_str() ).and_then( extension_mime_type ) )
+      .ok_or_else( || crate::error::Error::InvalidArgument( format!( "Cannot tell the MIME type of {}", path.display() ) ) )?;
+    Self::from_bytes( mime_type, &bytes )
+  }
+
+  /// Inline image read from a file, like [`Self::from_path`].
+  ///
+  /// # Errors
+  ///
+  /// As [`Self::from_path`], and [`crate::error::Error::InvalidArgument`]
+  /// when the file is not an image.
+  #[ inline ]
+  pub fn from_image_path( path : impl AsRef< std::path::Path > ) -> Result< Self, crate::error::Error >
+  {
+    let path = path.as_ref();
+    let part = Self::from_path( path )?;
+    match part.inline_data.as_ref().map( | blob | blob.mime_type.as_str() )
+    {
+      Some( mime_type ) if mime_type.starts_with( "image/" ) => Ok( part ),
+      mime_type => Err( crate::error::Error::InvalidArgument( format!(
+        "{} is not an image but {}", path.display(), mime_type.unwrap_or( "unknown" )
+      ) ) ),
+    }
+  }
+
+  /// Reference to a file uploaded with the Files API, or another URI the
+  /// API reads, such as a YouTube video.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::InvalidArgument`] when the URI or MIME
+  /// type is empty.
+  #[ inline ]
+  pub fn from_file_uri( uri : &str, mime_type : &str ) -> Result< Self, crate::error::Error >
+  {
+    if uri.is_empty() || mime_type.is_empty()
+    {
+      return Err( crate::error::Error::InvalidArgument( "File data needs a URI and a MIME type".to_string() ) );
+    }
+    Ok( Self
+    {
+      file_data : Some( FileData { uri : Some( uri.to_string() ), mime_type : Some( mime_type.to_string() ) } ),
+      ..Default::default()
+    } )
+  }
+}
+
+/// MIME type told by the leading bytes of a file.
+fn sniff_mime_type( bytes : &[ u8 ] ) -> Option< &'static str >
+{
+  let at = | offset : usize, signature : &[ u8 ] | bytes.get( offset..offset + signature.len() ) == Some( signature );
+  let mime_type = if at( 0, b"\x89PNG\r\n\x1a\n" ) { "image/png" }
+  else if at( 0, &[ 0xff, 0xd8, 0xff ] ) { "image/jpeg" }
+  else if at( 0, b"GIF87a" ) || at( 0, b"GIF89a" ) { "image/gif" }
+  else if at( 0, b"RIFF" ) && at( 8, b"WEBP" ) { "image/webp" }
+  else if at( 0, b"RIFF" ) && at( 8, b"WAVE" ) { "audio/wav" }
+  else if at( 4, b"ftypheic" ) || at( 4, b"ftypheix" ) { "image/heic" }
+  else if at( 4, b"ftypmif1" ) || at( 4, b"ftypheif" ) { "image/heif" }
+  else if at( 4, b"ftypqt" ) { "video/quicktime" }
+  else if at( 4, b"ftypM4A" ) { "audio/aac" }
+  else if at( 4, b"ftyp" ) { "video/mp4" }
+  else if at( 0, b"%PDF-" ) { "application/pdf" }
+  else if at( 0, b"ID3" ) || ( bytes.len() > 1 && bytes[ 0 ] == 0xff && bytes[ 1 ] & 0xe0 == 0xe0 && bytes[ 1 ] & 0x06 != 0 ) { "audio/mp3" }
+  else if at( 0, b"fLaC" ) { "audio/flac" }
+  else if at( 0, b"OggS" ) { "audio/ogg" }
+  else if at( 0, &[ 0x1a, 0x45, 0xdf, 0xa3 ] ) { "video/webm" }
+  else { return None };
+  Some( mime_type )
+}
+
+/// MIME type of a file extension the API accepts.
+fn extension_mime_type( extension : &str ) -> Option< &'static str >
+{
+  let mime_type = match extension.to_ascii_lowercase().as_str()
+  {
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "gif" => "image/gif",
+    "webp" => "image/webp",
+    "heic" => "image/heic",
+    "heif" => "image/heif",
+    "pdf" => "application/pdf",
+    "wav" => "audio/wav",
+    "mp3" => "audio/mp3",
+    "aac" | "m4a" => "audio/aac",
+    "flac" => "audio/flac",
+    "ogg" => "audio/ogg",
+    "aiff" | "aif" => "audio/aiff",
+    "mp4" => "video/mp4",
+    "mov" => "video/quicktime",
+    "webm" => "video/webm",
+    "mpeg" | "mpg" => "video/mpeg",
+    "txt" => "text/plain",
+    "md" => "text/markdown",
+    "csv" => "text/csv",
+    "html" | "htm" => "text/html",
+    "json" => "application/json",
+    _ => return None,
+  };
+  Some( mime_type )
 }
 
 /// Binary data with MIME type.
@@ -64,6 +273,23 @@ pub struct Blob
   pub data : String,
 }
 
+impl Blob
+{
+  /// The data, decoded from base64.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when the data is
+  /// not valid base64.
+  #[ inline ]
+  pub fn decode( &self ) -> Result< Vec< u8 >, crate::error::Error >
+  {
+    STANDARD.decode( &self.data ).map_err( | e |
+      crate::error::Error::DeserializationError( format!( "Inline {} data is not valid base64 : {e}", self.mime_type ) )
+    )
+  }
+}
+
 /// File data for multimedia content
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -125,6 +351,14 @@ pub struct Candidate
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Index of this candidate.
   pub index : Option< i32 >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Sources the candidate is grounded in, when a search tool was used.
+  pub grounding_metadata : Option< super::search::GroundingMetadata >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// URLs read for the candidate, when the URL context tool was used.
+  pub url_context_metadata : Option< super::search::UrlContextMetadata >,
 }
 
 /// Safety rating for content.
@@ -1,7 +1,9 @@
 //! Embedding types for the Gemini API.
 
+use core::fmt;
+use core::str::FromStr;
 use serde::{ Deserialize, Serialize };
-use super::content::Content;
+use super::content::{ Content, Part };
 
 /// Request for generating embeddings.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
@@ -24,6 +26,146 @@ pub struct EmbedContentRequest
   pub output_dimensionality : Option< i32 >,
 }
 
+impl EmbedContentRequest
+{
+  /// A request embedding `text`, with the defaults of the model.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( text : &str ) -> Self
+  {
+    Self
+    {
+      content : Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : "user".to_string() },
+      ..Default::default()
+    }
+  }
+
+  /// Embeds for `task_type`, which changes the embedding, so queries and the
+  /// documents they are compared with need matching task types.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_task_type( mut self, task_type : TaskType ) -> Self
+  {
+    self.task_type = Some( task_type.as_str().to_string() );
+    self
+  }
+
+  /// Titles the text; the API accepts a title with [`TaskType::RetrievalDocument`] only.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_title( mut self, title : &str ) -> Self
+  {
+    self.title = Some( title.to_string() );
+    self
+  }
+
+  /// Truncates the embedding to its first `dimensions` values, e.g. 768 of
+  /// the 3072 of "gemini-embedding-001".
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_output_dimensionality( mut self, dimensions : i32 ) -> Self
+  {
+    self.output_dimensionality = Some( dimensions );
+    self
+  }
+}
+
+/// What an embedding is used for; the model optimizes it for that task.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum TaskType
+{
+  /// A search query, compared with [`TaskType::RetrievalDocument`] embeddings.
+  #[ serde( rename = "RETRIEVAL_QUERY" ) ]
+  RetrievalQuery,
+
+  /// A document searched by [`TaskType::RetrievalQuery`] embeddings.
+  #[ serde( rename = "RETRIEVAL_DOCUMENT" ) ]
+  RetrievalDocument,
+
+  /// Text compared for similarity with other text.
+  #[ serde( rename = "SEMANTIC_SIMILARITY" ) ]
+  SemanticSimilarity,
+
+  /// Text classified with preset labels.
+  #[ serde( rename = "CLASSIFICATION" ) ]
+  Classification,
+
+  /// Text clustered by similarity.
+  #[ serde( rename = "CLUSTERING" ) ]
+  Clustering,
+
+  /// A question, compared with documents answering it.
+  #[ serde( rename = "QUESTION_ANSWERING" ) ]
+  QuestionAnswering,
+
+  /// A statement, compared with documents verifying it.
+  #[ serde( rename = "FACT_VERIFICATION" ) ]
+  FactVerification,
+
+  /// A natural language query for code, compared with [`TaskType::RetrievalDocument`] embeddings of code.
+  #[ serde( rename = "CODE_RETRIEVAL_QUERY" ) ]
+  CodeRetrievalQuery,
+}
+
+impl TaskType
+{
+  /// Every task type.
+  pub const ALL : [ Self; 8 ] =
+  [
+    Self::RetrievalQuery, Self::RetrievalDocument, Self::SemanticSimilarity, Self::Classification,
+    Self::Clustering, Self::QuestionAnswering, Self::FactVerification, Self::CodeRetrievalQuery,
+  ];
+
+  /// The API name of the task type, e.g. "RETRIEVAL_QUERY".
+  #[ inline ]
+  #[ must_use ]
+  pub fn as_str( self ) -> &'static str
+  {
+    match self
+    {
+      Self::RetrievalQuery => "RETRIEVAL_QUERY",
+      Self::RetrievalDocument => "RETRIEVAL_DOCUMENT",
+      Self::SemanticSimilarity => "SEMANTIC_SIMILARITY",
+      Self::Classification => "CLASSIFICATION",
+      Self::Clustering => "CLUSTERING",
+      Self::QuestionAnswering => "QUESTION_ANSWERING",
+      Self::FactVerification => "FACT_VERIFICATION",
+      Self::CodeRetrievalQuery => "CODE_RETRIEVAL_QUERY",
+    }
+  }
+}
+
+impl AsRef< str > for TaskType
+{
+  #[ inline ]
+  fn as_ref( &self ) -> &str
+  {
+    self.as_str()
+  }
+}
+
+impl FromStr for TaskType
+{
+  type Err = crate::error::Error;
+
+  #[ inline ]
+  fn from_str( name : &str ) -> Result< Self, Self::Err >
+  {
+    Self::ALL.into_iter()
+      .find( | task_type | task_type.as_str() == name )
+      .ok_or_else( || crate::error::Error::InvalidArgument( format!( "Unknown embedding task type : {name}" ) ) )
+  }
+}
+
+impl fmt::Display for TaskType
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+  {
+    f.write_str( self.as_str() )
+  }
+}
+
 /// Response containing embeddings.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -2,10 +2,10 @@
 
 use serde::{ Deserialize, Serialize };
 use super::code_execution::{ CodeExecution, CodeExecutionConfig };
-use super::search::GoogleSearchTool;
+use super::search::{ GoogleSearch, GoogleSearchTool, UrlContext };
 
 /// Tool that the model can use.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct Tool
 {
@@ -18,14 +18,65 @@ pub struct Tool
   pub code_execution : Option< CodeExecution >,
 
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  /// Google Search tool for web search integration.
+  /// Google Search retrieval tool of Gemini 1.5 models.
   pub google_search_retrieval : Option< GoogleSearchTool >,
 
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Google Search tool of Gemini 2.0 and later models.
+  pub google_search : Option< GoogleSearch >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// URL context tool reading the URLs given in the prompt.
+  pub url_context : Option< UrlContext >,
+
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Enhanced code execution tool.
   pub code_execution_tool : Option< CodeExecutionTool >,
 }
 
+impl Tool
+{
+  /// A tool declaring `declarations` for the model to call.
+  #[ inline ]
+  #[ must_use ]
+  pub fn functions( declarations : Vec< FunctionDeclaration > ) -> Self
+  {
+    Self { function_declarations : Some( declarations ), ..Default::default() }
+  }
+
+  /// A tool letting the model write and run Python code.
+  #[ inline ]
+  #[ must_use ]
+  pub fn code_execution() -> Self
+  {
+    Self { code_execution : Some( CodeExecution {} ), ..Default::default() }
+  }
+
+  /// A tool letting the model search Google.
+  #[ inline ]
+  #[ must_use ]
+  pub fn google_search() -> Self
+  {
+    Self { google_search : Some( GoogleSearch {} ), ..Default::default() }
+  }
+
+  /// A Google Search retrieval tool, searching on every request without `config`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn google_search_retrieval( config : Option< super::search::DynamicRetrievalConfig > ) -> Self
+  {
+    Self { google_search_retrieval : Some( GoogleSearchTool { dynamic_retrieval_config : config } ), ..Default::default() }
+  }
+
+  /// A tool letting the model read the URLs given in the prompt.
+  #[ inline ]
+  #[ must_use ]
+  pub fn url_context() -> Self
+  {
+    Self { url_context : Some( UrlContext {} ), ..Default::default() }
+  }
+}
+
 /// Declaration of a function the model can call.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -41,6 +92,131 @@ pub struct FunctionDeclaration
   pub parameters : Option< serde_json::Value >,
 }
 
+/// Schema formats the API accepts; others are dropped from generated schemas.
+#[ cfg( feature = "schemars" ) ]
+const SUPPORTED_FORMATS : [ &str; 6 ] = [ "float", "double", "int32", "int64", "enum", "date-time" ];
+
+#[ cfg( feature = "schemars" ) ]
+impl FunctionDeclaration
+{
+  /// A declaration whose parameters are the fields of `T`.
+  ///
+  /// The name is the schema name of `T` in snake case, so arguments of a
+  /// `GetWeather` type declare `get_weather`, and the description is the doc
+  /// comment of `T`. The schema is reshaped into the OpenAPI subset the API
+  /// accepts: subschemas are inlined, an `Option` field becomes nullable, and
+  /// `additionalProperties` and unsupported `format` keywords are dropped.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # use api_gemini::models::FunctionDeclaration;
+  /// /// Get the current weather of a city.
+  /// #[ derive( schemars::JsonSchema ) ]
+  /// struct GetWeather
+  /// {
+  ///   /// City name, e.g. "Tokyo".
+  ///   location : String,
+  /// }
+  ///
+  /// let declaration = FunctionDeclaration::from_schema::< GetWeather >();
+  /// assert_eq!( declaration.name, "get_weather" );
+  /// assert_eq!( declaration.description, "Get the current weather of a city." );
+  /// ```
+  #[ inline ]
+  #[ must_use ]
+  pub fn from_schema< T : schemars::JsonSchema >() -> Self
+  {
+    let mut settings = schemars::gen::SchemaSettings::openapi3();
+    settings.meta_schema = None;
+    settings.inline_subschemas = true;
+    let root = settings.into_generator().into_root_schema_for::< T >();
+    let mut parameters = serde_json::to_value( root ).unwrap_or_default();
+    gemini_schema( &mut parameters );
+    let description = parameters.as_object_mut()
+      .and_then( | map | map.remove( "description" ) )
+      .and_then( | description | description.as_str().map( ToString::to_string ) )
+      .unwrap_or_default();
+    if let Some( map ) = parameters.as_object_mut()
+    {
+      map.remove( "title" );
+    }
+
+    Self { name : snake_case( &T::schema_name() ), description, parameters : Some( parameters ) }
+  }
+}
+
+/// Reshapes a generated schema, in place, into one the API accepts.
+#[ cfg( feature = "schemars" ) ]
+fn gemini_schema( schema : &mut serde_json::Value )
+{
+  use serde_json::Value;
+
+  let map = match schema
+  {
+    Value::Object( map ) => map,
+    Value::Array( items ) =>
+    {
+      items.iter_mut().for_each( gemini_schema );
+      return;
+    },
+    _ => return,
+  };
+
+  map.remove( "definitions" );
+  map.remove( "additionalProperties" );
+  // A documented field of a named type is generated as a one-element `allOf`.
+  if let Some( Value::Array( all_of ) ) = map.get( "allOf" )
+  {
+    if let [ Value::Object( inner ) ] = all_of.as_slice()
+    {
+      let inner = inner.clone();
+      map.remove( "allOf" );
+      for ( key, value ) in inner
+      {
+        map.entry( key ).or_insert( value );
+      }
+    }
+  }
+  if map.get( "format" ).and_then( Value::as_str ).is_some_and( | format | !SUPPORTED_FORMATS.contains( &format ) )
+  {
+    map.remove( "format" );
+  }
+
+  for ( key, value ) in map.iter_mut()
+  {
+    match key.as_str()
+    {
+      "properties" => value.as_object_mut().into_iter().flat_map( | properties | properties.values_mut() ).for_each( gemini_schema ),
+      "items" | "anyOf" => gemini_schema( value ),
+      _ => {},
+    }
+  }
+}
+
+/// `GetWeather` as `get_weather`.
+#[ cfg( feature = "schemars" ) ]
+fn snake_case( name : &str ) -> String
+{
+  let mut snake = String::with_capacity( name.len() + 4 );
+  for ( i, c ) in name.chars().enumerate()
+  {
+    if c.is_ascii_uppercase()
+    {
+      if i > 0
+      {
+        snake.push( '_' );
+      }
+      snake.push( c.to_ascii_lowercase() );
+    }
+    else
+    {
+      snake.push( c );
+    }
+  }
+  snake
+}
+
 /// Enhanced function calling configuration with mode control.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -54,8 +230,32 @@ pub struct FunctionCallingConfig
   pub allowed_function_names : Option< Vec< String > >,
 }
 
+impl FunctionCallingConfig
+{
+  /// A configuration calling functions in `mode`, from every declared function.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( mode : FunctionCallingMode ) -> Self
+  {
+    Self { mode, allowed_function_names : None }
+  }
+
+  /// Limits the functions the model may call to `names`; the API accepts
+  /// this in [`FunctionCallingMode::Any`] only.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_allowed_function_names< I, S >( mut self, names : I ) -> Self
+  where
+    I : IntoIterator< Item = S >,
+    S : Into< String >,
+  {
+    self.allowed_function_names = Some( names.into_iter().map( Into::into ).collect() );
+    self
+  }
+}
+
 /// Function calling mode enumeration.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize ) ]
 #[ serde( rename_all = "SCREAMING_SNAKE_CASE" ) ]
 pub enum FunctionCallingMode
 {
@@ -73,7 +273,7 @@ pub enum FunctionCallingMode
 }
 
 /// Enhanced tool configuration with advanced options.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct ToolConfig
 {
@@ -86,6 +286,56 @@ pub struct ToolConfig
   pub code_execution : Option< CodeExecutionConfig >,
 }
 
+impl ToolConfig
+{
+  /// The model decides whether to call a function or answer in text; the API default.
+  #[ inline ]
+  #[ must_use ]
+  pub fn auto() -> Self
+  {
+    Self::function_calling( FunctionCallingConfig::new( FunctionCallingMode::Auto ) )
+  }
+
+  /// The model always calls a function; limit which with
+  /// [`ToolConfig::with_allowed_function_names`].
+  #[ inline ]
+  #[ must_use ]
+  pub fn any() -> Self
+  {
+    Self::function_calling( FunctionCallingConfig::new( FunctionCallingMode::Any ) )
+  }
+
+  /// The model answers in text without calling functions, though they are declared.
+  #[ inline ]
+  #[ must_use ]
+  pub fn none() -> Self
+  {
+    Self::function_calling( FunctionCallingConfig::new( FunctionCallingMode::None ) )
+  }
+
+  /// A tool configuration with `config` for function calling.
+  #[ inline ]
+  #[ must_use ]
+  pub fn function_calling( config : FunctionCallingConfig ) -> Self
+  {
+    Self { function_calling_config : Some( config ), ..Default::default() }
+  }
+
+  /// Limits the functions the model may call to `names`, in
+  /// [`FunctionCallingMode::Any`] when no mode was set.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_allowed_function_names< I, S >( mut self, names : I ) -> Self
+  where
+    I : IntoIterator< Item = S >,
+    S : Into< String >,
+  {
+    let config = self.function_calling_config.take().unwrap_or_else( || FunctionCallingConfig::new( FunctionCallingMode::Any ) );
+    self.function_calling_config = Some( config.with_allowed_function_names( names ) );
+    self
+  }
+}
+
 /// Code execution tool for Python code generation and execution.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
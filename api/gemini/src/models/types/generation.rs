@@ -1,7 +1,7 @@
 //! Content generation types for the Gemini API.
 
 use serde::{ Deserialize, Serialize };
-use super::content::{ Content, SystemInstruction };
+use super::content::{ Blob, Content, SystemInstruction };
 use super::function::{ Tool, ToolConfig };
 
 /// Request for generating content using a model.
@@ -58,6 +58,119 @@ pub struct GenerateContentResponse
   pub grounding_metadata : Option< super::search::GroundingMetadata >,
 }
 
+impl GenerateContentResponse
+{
+  /// The answer text of the first candidate, without its thought summaries,
+  /// or `None` when it has no answer text.
+  #[ inline ]
+  #[ must_use ]
+  pub fn answer_text( &self ) -> Option< String >
+  {
+    self.joined_text( false )
+  }
+
+  /// The thought summaries of the first candidate, or `None` when it has none.
+  ///
+  /// Thinking models answer them only when `ThinkingConfig::include_thoughts`
+  /// is set.
+  #[ inline ]
+  #[ must_use ]
+  pub fn thought_summary( &self ) -> Option< String >
+  {
+    self.joined_text( true )
+  }
+
+  /// The text of the thought or answer parts of the first candidate, joined.
+  fn joined_text( &self, thought : bool ) -> Option< String >
+  {
+    let text : String = self.candidates.first()?.content.parts.iter()
+      .filter( | part | part.is_thought() == thought )
+      .filter_map( | part | part.text.as_deref() )
+      .collect();
+    ( !text.is_empty() ).then_some( text )
+  }
+
+  /// The audio parts of the first candidate, still base64-encoded.
+  #[ inline ]
+  #[ must_use ]
+  pub fn audio_parts( &self ) -> Vec< &Blob >
+  {
+    self.candidates.first()
+      .map( | candidate | candidate.content.parts.iter()
+        .filter_map( | part | part.inline_data.as_ref() )
+        .filter( | blob | blob.mime_type.starts_with( "audio/" ) )
+        .collect() )
+      .unwrap_or_default()
+  }
+
+  /// The audio of the first candidate, decoded, or `None` when it has no audio.
+  ///
+  /// Audio split over several parts is joined in order. Text-to-speech models
+  /// answer raw 16-bit PCM, e.g. "audio/L16;codec=pcm;rate=24000".
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when the audio is
+  /// not valid base64.
+  #[ inline ]
+  pub fn audio( &self ) -> Result< Option< GeneratedAudio >, crate::error::Error >
+  {
+    let parts = self.audio_parts();
+    let Some( first ) = parts.first() else { return Ok( None ) };
+    let mut bytes = Vec::new();
+    for part in &parts
+    {
+      bytes.extend( part.decode()? );
+    }
+    Ok( Some( GeneratedAudio { bytes, mime_type : first.mime_type.clone() } ) )
+  }
+
+  /// The grounding metadata of the first candidate, where the API answers it,
+  /// or else of the response.
+  #[ inline ]
+  #[ must_use ]
+  pub fn grounding( &self ) -> Option< &super::search::GroundingMetadata >
+  {
+    self.candidates.first()
+      .and_then( | candidate | candidate.grounding_metadata.as_ref() )
+      .or( self.grounding_metadata.as_ref() )
+  }
+
+  /// The URLs the URL context tool read for the first candidate.
+  #[ inline ]
+  #[ must_use ]
+  pub fn url_context( &self ) -> &[ super::search::UrlMetadata ]
+  {
+    self.candidates.first()
+      .and_then( | candidate | candidate.url_context_metadata.as_ref() )
+      .map_or( &[], | metadata | metadata.url_metadata.as_slice() )
+  }
+}
+
+/// Audio generated by a model, decoded.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct GeneratedAudio
+{
+  /// The audio data, raw PCM for text-to-speech models.
+  pub bytes : Vec< u8 >,
+
+  /// MIME type of the audio, e.g. "audio/L16;codec=pcm;rate=24000".
+  pub mime_type : String,
+}
+
+impl GeneratedAudio
+{
+  /// The sample rate given by the `rate` parameter of the MIME type.
+  #[ inline ]
+  #[ must_use ]
+  pub fn sample_rate( &self ) -> Option< u32 >
+  {
+    self.mime_type.split( ';' )
+      .filter_map( | parameter | parameter.trim().strip_prefix( "rate=" ) )
+      .find_map( | rate | rate.parse().ok() )
+  }
+}
+
 /// Configuration for how the model generates responses.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -86,6 +199,129 @@ pub struct GenerationConfig
   /// Sequences that will stop generation.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub stop_sequences : Option< Vec< String > >,
+
+  /// Modalities of the response: "TEXT", "IMAGE", or "AUDIO".
+  ///
+  /// Text-to-speech models answer `["AUDIO"]` only.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub response_modalities : Option< Vec< String > >,
+
+  /// Voices of an audio response.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub speech_config : Option< SpeechConfig >,
+
+  /// Reasoning of thinking models, such as the Gemini 2.5 family.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub thinking_config : Option< ThinkingConfig >,
+}
+
+/// How much a thinking model reasons before answering, and whether it
+/// answers summaries of its thoughts.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ThinkingConfig
+{
+  /// Tokens the model may spend thinking: 0 turns thinking off where the
+  /// model allows it, and -1 lets the model decide.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub thinking_budget : Option< i32 >,
+
+  /// Whether the response includes thought summaries, as parts marked `thought`.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub include_thoughts : Option< bool >,
+}
+
+/// Voices of an audio response: one voice, or one voice per speaker.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct SpeechConfig
+{
+  /// The voice of a single speaker.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub voice_config : Option< VoiceConfig >,
+
+  /// The voices of the speakers of a dialogue.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub multi_speaker_voice_config : Option< MultiSpeakerVoiceConfig >,
+
+  /// Language of the speech as a BCP-47 code, e.g. "en-US".
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub language_code : Option< String >,
+}
+
+impl SpeechConfig
+{
+  /// Speech in the prebuilt voice `voice_name`, e.g. "Kore".
+  #[ inline ]
+  #[ must_use ]
+  pub fn voice( voice_name : &str ) -> Self
+  {
+    Self { voice_config : Some( VoiceConfig::prebuilt( voice_name ) ), ..Default::default() }
+  }
+
+  /// A dialogue with a prebuilt voice per speaker, given as `( speaker, voice_name )`.
+  ///
+  /// The speakers are named as in the prompt, e.g. "Joe: How's it going?".
+  #[ inline ]
+  #[ must_use ]
+  pub fn multi_speaker( speakers : &[ ( &str, &str ) ] ) -> Self
+  {
+    let speaker_voice_configs = speakers.iter()
+      .map( | ( speaker, voice_name ) | SpeakerVoiceConfig { speaker : ( *speaker ).to_string(), voice_config : VoiceConfig::prebuilt( voice_name ) } )
+      .collect();
+    Self { multi_speaker_voice_config : Some( MultiSpeakerVoiceConfig { speaker_voice_configs } ), ..Default::default() }
+  }
+}
+
+/// A voice of an audio response.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct VoiceConfig
+{
+  /// The prebuilt voice to use.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub prebuilt_voice_config : Option< PrebuiltVoiceConfig >,
+}
+
+impl VoiceConfig
+{
+  /// The prebuilt voice `voice_name`, e.g. "Kore" or "Puck".
+  #[ inline ]
+  #[ must_use ]
+  pub fn prebuilt( voice_name : &str ) -> Self
+  {
+    Self { prebuilt_voice_config : Some( PrebuiltVoiceConfig { voice_name : voice_name.to_string() } ) }
+  }
+}
+
+/// A prebuilt voice, by name.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct PrebuiltVoiceConfig
+{
+  /// Name of the voice, e.g. "Kore".
+  pub voice_name : String,
+}
+
+/// The voices of the speakers of a dialogue.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct MultiSpeakerVoiceConfig
+{
+  /// The voice of each speaker; the API takes two.
+  pub speaker_voice_configs : Vec< SpeakerVoiceConfig >,
+}
+
+/// The voice of one speaker of a dialogue.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct SpeakerVoiceConfig
+{
+  /// Name of the speaker, as used in the prompt.
+  pub speaker : String,
+
+  /// The voice of the speaker.
+  pub voice_config : VoiceConfig,
 }
 
 /// Safety setting for blocking content.
@@ -133,6 +369,23 @@ pub struct UsageMetadata
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Number of cached content tokens.
   pub cached_content_token_count : Option< i32 >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Number of tokens thinking models spent on thoughts.
+  pub thoughts_token_count : Option< i32 >,
+}
+
+/// Gemini's prompt count already includes the cached content tokens.
+#[ cfg( feature = "common" ) ]
+impl From< UsageMetadata > for api_common::TokenUsage
+{
+  #[ inline ]
+  fn from( usage : UsageMetadata ) -> Self
+  {
+    let count = | tokens : Option< i32 > | tokens.and_then( | t | u64::try_from( t ).ok() ).unwrap_or( 0 );
+    let cached = count( usage.cached_content_token_count );
+    Self::new( count( usage.prompt_token_count ), count( usage.candidates_token_count ) ).with_cached_input_tokens( cached )
+  }
 }
 
 /// Request for batch content generation.
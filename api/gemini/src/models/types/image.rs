@@ -0,0 +1,202 @@
+//! Image generation types for the Gemini API.
+//!
+//! Imagen models generate images through the `:predict` endpoint, which takes
+//! prompt instances with shared parameters and answers base64 predictions.
+
+use base64::{ engine::general_purpose::STANDARD, Engine as _ };
+use serde::{ Deserialize, Serialize };
+
+/// Aspect ratio of generated images.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize ) ]
+pub enum AspectRatio
+{
+  /// Square images, 1:1.
+  #[ serde( rename = "1:1" ) ]
+  Square,
+
+  /// Portrait images, 3:4.
+  #[ serde( rename = "3:4" ) ]
+  Portrait,
+
+  /// Landscape images, 4:3.
+  #[ serde( rename = "4:3" ) ]
+  Landscape,
+
+  /// Tall portrait images, 9:16.
+  #[ serde( rename = "9:16" ) ]
+  TallPortrait,
+
+  /// Widescreen images, 16:9.
+  #[ serde( rename = "16:9" ) ]
+  Widescreen,
+}
+
+/// Whether generated images may show people.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize ) ]
+pub enum PersonGeneration
+{
+  /// No images of people.
+  #[ serde( rename = "dont_allow" ) ]
+  DontAllow,
+
+  /// Images of adults, but not of children. The API default.
+  #[ serde( rename = "allow_adult" ) ]
+  AllowAdult,
+
+  /// Images of adults and children. Not available in every region.
+  #[ serde( rename = "allow_all" ) ]
+  AllowAll,
+}
+
+/// A prompt to generate images from.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ImagePromptInstance
+{
+  /// Description of the images to generate.
+  pub prompt : String,
+}
+
+/// Parameters shared by the prompts of an image generation request.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ImageGenerationParameters
+{
+  /// Number of images to generate, from 1 to 4. The API default is 4.
+  #[ serde( rename = "sampleCount", skip_serializing_if = "Option::is_none" ) ]
+  pub number_of_images : Option< u32 >,
+
+  /// Aspect ratio of the images. The API default is square.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub aspect_ratio : Option< AspectRatio >,
+
+  /// Whether the images may show people.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub person_generation : Option< PersonGeneration >,
+}
+
+/// Request to generate images with an Imagen model.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateImagesRequest
+{
+  /// Prompts to generate images from. Imagen takes a single prompt.
+  pub instances : Vec< ImagePromptInstance >,
+
+  /// Parameters of the generated images.
+  #[ serde( default ) ]
+  pub parameters : ImageGenerationParameters,
+}
+
+impl GenerateImagesRequest
+{
+  /// Creates a request generating images of `prompt` with the API defaults.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( prompt : &str ) -> Self
+  {
+    Self
+    {
+      instances : vec![ ImagePromptInstance { prompt : prompt.to_string() } ],
+      parameters : ImageGenerationParameters::default(),
+    }
+  }
+
+  /// Sets how many images to generate, from 1 to 4.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_number_of_images( mut self, number_of_images : u32 ) -> Self
+  {
+    self.parameters.number_of_images = Some( number_of_images );
+    self
+  }
+
+  /// Sets the aspect ratio of the images.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_aspect_ratio( mut self, aspect_ratio : AspectRatio ) -> Self
+  {
+    self.parameters.aspect_ratio = Some( aspect_ratio );
+    self
+  }
+
+  /// Sets whether the images may show people.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_person_generation( mut self, person_generation : PersonGeneration ) -> Self
+  {
+    self.parameters.person_generation = Some( person_generation );
+    self
+  }
+}
+
+/// One prediction of an image generation response.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ImagePrediction
+{
+  /// The image, base64-encoded. Absent when the image was filtered.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub bytes_base64_encoded : Option< String >,
+
+  /// MIME type of the image, e.g. "image/png".
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub mime_type : Option< String >,
+
+  /// Why the image was filtered by responsible AI checks.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub rai_filtered_reason : Option< String >,
+}
+
+/// Response from an image generation request.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateImagesResponse
+{
+  /// The predictions, one per generated or filtered image.
+  #[ serde( default ) ]
+  pub predictions : Vec< ImagePrediction >,
+}
+
+impl GenerateImagesResponse
+{
+  /// Decodes the generated images, leaving out the filtered ones.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when an image is
+  /// not valid base64.
+  #[ inline ]
+  pub fn images( &self ) -> Result< Vec< GeneratedImage >, crate::error::Error >
+  {
+    self.predictions.iter()
+      .filter_map( | prediction |
+      {
+        let data = prediction.bytes_base64_encoded.as_ref()?;
+        let image = STANDARD.decode( data )
+          .map( | bytes | GeneratedImage { bytes, mime_type : prediction.mime_type.clone().unwrap_or_else( || "image/png".to_string() ) } )
+          .map_err( | e | crate::error::Error::DeserializationError( format!( "Generated image is not valid base64 : {e}" ) ) );
+        Some( image )
+      } )
+      .collect()
+  }
+
+  /// The reasons images were filtered, one per filtered image.
+  #[ inline ]
+  #[ must_use ]
+  pub fn filtered_reasons( &self ) -> Vec< &str >
+  {
+    self.predictions.iter().filter_map( | prediction | prediction.rai_filtered_reason.as_deref() ).collect()
+  }
+}
+
+/// A generated image, decoded.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct GeneratedImage
+{
+  /// The encoded image file, e.g. PNG data.
+  pub bytes : Vec< u8 >,
+
+  /// MIME type of the image, e.g. "image/png".
+  pub mime_type : String,
+}
@@ -9,9 +9,16 @@ pub mod embedding;
 pub mod file;
 pub mod token;
 pub mod cache;
+pub mod retrieval;
+pub mod image;
+pub mod video;
+pub mod operation;
 pub mod content;
+pub mod safety;
 pub mod streaming;
 pub mod chat;
+#[ cfg( feature = "chat" ) ]
+pub mod session;
 pub mod search;
 pub mod function;
 pub mod code_execution;
@@ -0,0 +1,96 @@
+//! Long-running operation types for the Gemini API.
+
+use serde::{ Deserialize, Serialize };
+use super::video::GenerateVideosResponse;
+
+/// A long-running operation, such as a video generation.
+///
+/// Operations are polled with `Client::operations()` until `done` is set;
+/// a finished operation has either an `error` or a `response`.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct Operation
+{
+  /// Resource name of the operation, e.g. `models/{model}/operations/{id}`.
+  pub name : String,
+
+  /// Whether the operation has finished.
+  #[ serde( default ) ]
+  pub done : bool,
+
+  /// Progress information of the operation, in a form specific to it.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata : Option< serde_json::Value >,
+
+  /// Why the operation failed.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub error : Option< OperationError >,
+
+  /// Result of the operation, in a form specific to it.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub response : Option< serde_json::Value >,
+}
+
+impl Operation
+{
+  /// The result of the operation read as `T`, or `None` while it is running.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::ApiError`] when the operation failed, and
+  /// [`crate::error::Error::DeserializationError`] when the result is not a `T`.
+  #[ inline ]
+  pub fn response_as< T : serde::de::DeserializeOwned >( &self ) -> Result< Option< T >, crate::error::Error >
+  {
+    if let Some( error ) = &self.error
+    {
+      return Err( crate::error::Error::ApiError(
+        format!( "Operation {} failed with code {} : {}", self.name, error.code, error.message )
+      ) );
+    }
+    match &self.response
+    {
+      Some( response ) if self.done => serde_json::from_value( response.clone() )
+        .map( Some )
+        .map_err( | e | crate::error::Error::DeserializationError( format!( "Unexpected result of operation {} : {e}", self.name ) ) ),
+      _ => Ok( None ),
+    }
+  }
+
+  /// The videos generated by a video generation operation, or `None` while
+  /// it is running.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error when the operation failed or did not generate videos.
+  #[ inline ]
+  pub fn generated_videos( &self ) -> Result< Option< GenerateVideosResponse >, crate::error::Error >
+  {
+    #[ derive( Deserialize ) ]
+    #[ serde( rename_all = "camelCase" ) ]
+    struct PredictLongRunningResponse
+    {
+      generate_video_response : GenerateVideosResponse,
+    }
+
+    Ok( self.response_as::< PredictLongRunningResponse >()?.map( | response | response.generate_video_response ) )
+  }
+}
+
+/// Why a long-running operation failed.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct OperationError
+{
+  /// Status code of the error.
+  #[ serde( default ) ]
+  pub code : i32,
+
+  /// Description of the error.
+  #[ serde( default ) ]
+  pub message : String,
+
+  /// Further details of the error.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub details : Option< Vec< serde_json::Value > >,
+}
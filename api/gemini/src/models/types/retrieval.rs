@@ -0,0 +1,448 @@
+//! Semantic Retrieval types for the Gemini API: corpora, documents, chunks,
+//! corpus queries, and attributed question answering.
+
+use serde::{ Deserialize, Serialize };
+use super::content::{ Content, Candidate, SafetyRating };
+use super::generation::SafetySetting;
+
+/// A collection of documents to retrieve from.
+///
+/// `name` is assigned by the API (`corpora/{id}`) unless given on creation.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct Corpus
+{
+  /// Resource name, `corpora/{id}`.
+  #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
+  pub name : String,
+
+  /// Human-readable display name, up to 512 characters.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub display_name : Option< String >,
+
+  /// Creation time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub create_time : Option< String >,
+
+  /// Last update time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub update_time : Option< String >,
+}
+
+/// Response from listing corpora.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ListCorporaResponse
+{
+  /// The corpora of the page.
+  #[ serde( default ) ]
+  pub corpora : Vec< Corpus >,
+
+  /// Token for retrieving the next page of results.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub next_page_token : Option< String >,
+}
+
+/// A list of string values of custom metadata.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
+pub struct StringList
+{
+  /// The values.
+  #[ serde( default ) ]
+  pub values : Vec< String >,
+}
+
+/// A key and value attached to a document or chunk, usable in metadata
+/// filters. Exactly one of the values is set.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct CustomMetadata
+{
+  /// The key of the metadata.
+  pub key : String,
+
+  /// A string value.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub string_value : Option< String >,
+
+  /// A list of string values.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub string_list_value : Option< StringList >,
+
+  /// A numeric value.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub numeric_value : Option< f32 >,
+}
+
+/// A document of a corpus, holding chunks.
+///
+/// `name` is assigned by the API (`corpora/{corpus}/documents/{id}`) unless
+/// given on creation.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct Document
+{
+  /// Resource name, `corpora/{corpus}/documents/{id}`.
+  #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
+  pub name : String,
+
+  /// Human-readable display name, up to 512 characters.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub display_name : Option< String >,
+
+  /// Metadata of the document, at most 20 entries.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub custom_metadata : Option< Vec< CustomMetadata > >,
+
+  /// Creation time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub create_time : Option< String >,
+
+  /// Last update time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub update_time : Option< String >,
+}
+
+/// Response from listing the documents of a corpus.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ListDocumentsResponse
+{
+  /// The documents of the page.
+  #[ serde( default ) ]
+  pub documents : Vec< Document >,
+
+  /// Token for retrieving the next page of results.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub next_page_token : Option< String >,
+}
+
+/// The content of a chunk.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ChunkData
+{
+  /// The text of the chunk, up to 2043 tokens.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub string_value : Option< String >,
+}
+
+/// A passage of a document, the unit that queries retrieve.
+///
+/// `name` is assigned by the API
+/// (`corpora/{corpus}/documents/{document}/chunks/{id}`) unless given on
+/// creation.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct Chunk
+{
+  /// Resource name, `corpora/{corpus}/documents/{document}/chunks/{id}`.
+  #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
+  pub name : String,
+
+  /// The content of the chunk.
+  pub data : ChunkData,
+
+  /// Metadata of the chunk, at most 20 entries.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub custom_metadata : Option< Vec< CustomMetadata > >,
+
+  /// Creation time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub create_time : Option< String >,
+
+  /// Last update time in RFC3339 format.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub update_time : Option< String >,
+
+  /// Processing state: `STATE_PENDING_PROCESSING`, `STATE_ACTIVE`, or
+  /// `STATE_FAILED`. Only active chunks are retrieved.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub state : Option< String >,
+}
+
+impl Chunk
+{
+  /// Creates a chunk of `text`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn text( text : &str ) -> Self
+  {
+    Self { data : ChunkData { string_value : Some( text.to_string() ) }, ..Default::default() }
+  }
+}
+
+/// Response from listing the chunks of a document.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ListChunksResponse
+{
+  /// The chunks of the page.
+  #[ serde( default ) ]
+  pub chunks : Vec< Chunk >,
+
+  /// Token for retrieving the next page of results.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub next_page_token : Option< String >,
+}
+
+/// Request to create one chunk of a batch.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct CreateChunkRequest
+{
+  /// The document to create the chunk in.
+  pub parent : String,
+
+  /// The chunk to create.
+  pub chunk : Chunk,
+}
+
+/// Request to create up to 100 chunks at once.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchCreateChunksRequest
+{
+  /// The chunks to create.
+  pub requests : Vec< CreateChunkRequest >,
+}
+
+impl BatchCreateChunksRequest
+{
+  /// Creates each of `chunks` in the document `parent`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( parent : &str, chunks : &[ Chunk ] ) -> Self
+  {
+    Self { requests : chunks.iter().map( | chunk | CreateChunkRequest { parent : parent.to_string(), chunk : chunk.clone() } ).collect() }
+  }
+}
+
+/// Request to update one chunk of a batch.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct UpdateChunkRequest
+{
+  /// The chunk, named, with its new values.
+  pub chunk : Chunk,
+
+  /// The fields to update: `data`, `customMetadata`, or both, comma-separated.
+  pub update_mask : String,
+}
+
+/// Request to update up to 100 chunks at once.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchUpdateChunksRequest
+{
+  /// The chunks to update.
+  pub requests : Vec< UpdateChunkRequest >,
+}
+
+/// Response from creating or updating chunks in a batch.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchChunksResponse
+{
+  /// The chunks created or updated, in request order.
+  #[ serde( default ) ]
+  pub chunks : Vec< Chunk >,
+}
+
+/// Request to delete one chunk of a batch.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct DeleteChunkRequest
+{
+  /// The name of the chunk to delete.
+  pub name : String,
+}
+
+/// Request to delete chunks at once.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct BatchDeleteChunksRequest
+{
+  /// The chunks to delete.
+  pub requests : Vec< DeleteChunkRequest >,
+}
+
+/// A condition on the value of a metadata key.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct MetadataCondition
+{
+  /// The comparison: `LESS`, `LESS_EQUAL`, `EQUAL`, `GREATER_EQUAL`,
+  /// `GREATER`, `NOT_EQUAL`, `INCLUDES`, or `EXCLUDES`.
+  pub operation : String,
+
+  /// A string to compare with.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub string_value : Option< String >,
+
+  /// A number to compare with.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub numeric_value : Option< f32 >,
+}
+
+/// Conditions on one metadata key; a chunk matches if any condition holds.
+/// A chunk must match every filter of a query.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct MetadataFilter
+{
+  /// The metadata key to filter on.
+  pub key : String,
+
+  /// The conditions, joined by OR.
+  pub conditions : Vec< MetadataCondition >,
+}
+
+/// Request to retrieve the chunks of a corpus most relevant to a query.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct QueryCorpusRequest
+{
+  /// The query text.
+  pub query : String,
+
+  /// Filters on document and chunk metadata, joined by AND.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata_filters : Option< Vec< MetadataFilter > >,
+
+  /// The number of chunks to return, at most 100; 10 if unset.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub results_count : Option< i32 >,
+}
+
+/// A retrieved chunk and how relevant it is to the query.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct RelevantChunk
+{
+  /// The relevance of the chunk to the query.
+  #[ serde( default ) ]
+  pub chunk_relevance_score : f32,
+
+  /// The chunk.
+  pub chunk : Chunk,
+}
+
+/// Response from querying a corpus.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct QueryCorpusResponse
+{
+  /// The relevant chunks, most relevant first.
+  #[ serde( default ) ]
+  pub relevant_chunks : Vec< RelevantChunk >,
+}
+
+/// A passage given inline to ground an answer.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GroundingPassage
+{
+  /// An identifier for the passage, cited by the answer.
+  pub id : String,
+
+  /// The content of the passage.
+  pub content : Content,
+}
+
+/// Passages given inline to ground an answer.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GroundingPassages
+{
+  /// The passages.
+  pub passages : Vec< GroundingPassage >,
+}
+
+/// Grounds an answer in the chunks a Semantic Retriever query returns.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct SemanticRetrieverConfig
+{
+  /// The corpus or document to retrieve from.
+  pub source : String,
+
+  /// The query to retrieve with.
+  pub query : Content,
+
+  /// Filters on document and chunk metadata, joined by AND.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub metadata_filters : Option< Vec< MetadataFilter > >,
+
+  /// The most chunks to retrieve.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub max_chunks_count : Option< i32 >,
+
+  /// The least relevance a chunk needs to be retrieved.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub minimum_relevance_score : Option< f32 >,
+}
+
+/// Request to answer the last user turn of `contents` from grounding
+/// passages, with the attributed question answering (`aqa`) model.
+///
+/// Exactly one of `inline_passages` and `semantic_retriever` is set.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateAnswerRequest
+{
+  /// The conversation; the last content is the question.
+  pub contents : Vec< Content >,
+
+  /// The style of the answer: `ABSTRACTIVE`, `EXTRACTIVE`, or `VERBOSE`.
+  pub answer_style : String,
+
+  /// Passages given with the request.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub inline_passages : Option< GroundingPassages >,
+
+  /// Passages retrieved from a corpus or document.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub semantic_retriever : Option< SemanticRetrieverConfig >,
+
+  /// Safety settings for blocking unsafe content.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub safety_settings : Option< Vec< SafetySetting > >,
+
+  /// Randomness of the answer, from 0.0 to 1.0.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub temperature : Option< f32 >,
+}
+
+/// Feedback on the question and passages of a `GenerateAnswerRequest`.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct InputFeedback
+{
+  /// Why the input was blocked, if it was.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub block_reason : Option< String >,
+
+  /// Safety ratings of the input.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub safety_ratings : Option< Vec< SafetyRating > >,
+}
+
+/// Response from generating an answer.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateAnswerResponse
+{
+  /// The answer.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub answer : Option< Candidate >,
+
+  /// How likely the answer is correct and grounded in the passages; low
+  /// values suggest the passages do not answer the question.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub answerable_probability : Option< f32 >,
+
+  /// Feedback on the input, present when it was blocked.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub input_feedback : Option< InputFeedback >,
+}
@@ -0,0 +1,312 @@
+//! Typed safety settings and safety feedback inspection.
+//!
+//! `SafetySetting` and `SafetyRating` carry the API names as strings; the
+//! enums here name the categories, thresholds, and probabilities, so settings
+//! are built and responses inspected without matching strings.
+
+use core::fmt;
+use core::str::FromStr;
+use serde::{ Deserialize, Serialize };
+use super::content::SafetyRating;
+use super::generation::{ GenerateContentResponse, SafetySetting };
+
+/// A category of harmful content.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum HarmCategory
+{
+  /// Negative or harmful comments targeting identity or protected attributes.
+  #[ serde( rename = "HARM_CATEGORY_HARASSMENT" ) ]
+  Harassment,
+
+  /// Content that is rude, disrespectful, or profane.
+  #[ serde( rename = "HARM_CATEGORY_HATE_SPEECH" ) ]
+  HateSpeech,
+
+  /// References to sexual acts or other lewd content.
+  #[ serde( rename = "HARM_CATEGORY_SEXUALLY_EXPLICIT" ) ]
+  SexuallyExplicit,
+
+  /// Content that promotes or enables harmful acts.
+  #[ serde( rename = "HARM_CATEGORY_DANGEROUS_CONTENT" ) ]
+  DangerousContent,
+
+  /// Content that may be used to harm civic integrity.
+  #[ serde( rename = "HARM_CATEGORY_CIVIC_INTEGRITY" ) ]
+  CivicIntegrity,
+}
+
+impl HarmCategory
+{
+  /// Every category safety settings can be given for.
+  pub const ALL : [ Self; 5 ] = [ Self::Harassment, Self::HateSpeech, Self::SexuallyExplicit, Self::DangerousContent, Self::CivicIntegrity ];
+
+  /// The API name of the category, e.g. "HARM_CATEGORY_HARASSMENT".
+  #[ inline ]
+  #[ must_use ]
+  pub fn as_str( self ) -> &'static str
+  {
+    match self
+    {
+      Self::Harassment => "HARM_CATEGORY_HARASSMENT",
+      Self::HateSpeech => "HARM_CATEGORY_HATE_SPEECH",
+      Self::SexuallyExplicit => "HARM_CATEGORY_SEXUALLY_EXPLICIT",
+      Self::DangerousContent => "HARM_CATEGORY_DANGEROUS_CONTENT",
+      Self::CivicIntegrity => "HARM_CATEGORY_CIVIC_INTEGRITY",
+    }
+  }
+}
+
+impl FromStr for HarmCategory
+{
+  type Err = crate::error::Error;
+
+  #[ inline ]
+  fn from_str( name : &str ) -> Result< Self, Self::Err >
+  {
+    Self::ALL.into_iter()
+      .find( | category | category.as_str() == name )
+      .ok_or_else( || crate::error::Error::InvalidArgument( format!( "Unknown harm category : {name}" ) ) )
+  }
+}
+
+impl fmt::Display for HarmCategory
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+  {
+    f.write_str( self.as_str() )
+  }
+}
+
+/// The probability of harm from which content is blocked.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum HarmBlockThreshold
+{
+  /// Block content with a low or higher probability of harm.
+  #[ serde( rename = "BLOCK_LOW_AND_ABOVE" ) ]
+  BlockLowAndAbove,
+
+  /// Block content with a medium or high probability of harm.
+  #[ serde( rename = "BLOCK_MEDIUM_AND_ABOVE" ) ]
+  BlockMediumAndAbove,
+
+  /// Block content with a high probability of harm only.
+  #[ serde( rename = "BLOCK_ONLY_HIGH" ) ]
+  BlockOnlyHigh,
+
+  /// Block nothing, but still rate the content.
+  #[ serde( rename = "BLOCK_NONE" ) ]
+  BlockNone,
+
+  /// Turn the safety filter of the category off.
+  #[ serde( rename = "OFF" ) ]
+  Off,
+}
+
+impl HarmBlockThreshold
+{
+  /// The API name of the threshold, e.g. "BLOCK_ONLY_HIGH".
+  #[ inline ]
+  #[ must_use ]
+  pub fn as_str( self ) -> &'static str
+  {
+    match self
+    {
+      Self::BlockLowAndAbove => "BLOCK_LOW_AND_ABOVE",
+      Self::BlockMediumAndAbove => "BLOCK_MEDIUM_AND_ABOVE",
+      Self::BlockOnlyHigh => "BLOCK_ONLY_HIGH",
+      Self::BlockNone => "BLOCK_NONE",
+      Self::Off => "OFF",
+    }
+  }
+}
+
+impl fmt::Display for HarmBlockThreshold
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+  {
+    f.write_str( self.as_str() )
+  }
+}
+
+/// The probability that content is harmful, ordered from negligible to high.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize ) ]
+pub enum HarmProbability
+{
+  /// Content is very unlikely to be harmful.
+  #[ serde( rename = "NEGLIGIBLE" ) ]
+  Negligible,
+
+  /// Content is unlikely to be harmful.
+  #[ serde( rename = "LOW" ) ]
+  Low,
+
+  /// Content may be harmful.
+  #[ serde( rename = "MEDIUM" ) ]
+  Medium,
+
+  /// Content is likely harmful.
+  #[ serde( rename = "HIGH" ) ]
+  High,
+}
+
+impl FromStr for HarmProbability
+{
+  type Err = crate::error::Error;
+
+  #[ inline ]
+  fn from_str( name : &str ) -> Result< Self, Self::Err >
+  {
+    match name
+    {
+      "NEGLIGIBLE" => Ok( Self::Negligible ),
+      "LOW" => Ok( Self::Low ),
+      "MEDIUM" => Ok( Self::Medium ),
+      "HIGH" => Ok( Self::High ),
+      _ => Err( crate::error::Error::InvalidArgument( format!( "Unknown harm probability : {name}" ) ) ),
+    }
+  }
+}
+
+impl SafetySetting
+{
+  /// A setting blocking `category` from `threshold`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( category : HarmCategory, threshold : HarmBlockThreshold ) -> Self
+  {
+    Self { category : category.as_str().to_string(), threshold : threshold.as_str().to_string() }
+  }
+}
+
+impl SafetyRating
+{
+  /// The rated category, or `None` when this version does not know it.
+  #[ inline ]
+  #[ must_use ]
+  pub fn harm_category( &self ) -> Option< HarmCategory >
+  {
+    self.category.parse().ok()
+  }
+
+  /// The rated probability, or `None` when it is unspecified.
+  #[ inline ]
+  #[ must_use ]
+  pub fn harm_probability( &self ) -> Option< HarmProbability >
+  {
+    self.probability.parse().ok()
+  }
+}
+
+/// Builder for the safety settings of a request, one threshold per category.
+///
+/// # Examples
+///
+/// ```rust
+/// # use api_gemini::models::{ HarmBlockThreshold, HarmCategory, SafetySettingsBuilder };
+/// let settings = SafetySettingsBuilder::new()
+///   .all( HarmBlockThreshold::BlockLowAndAbove )
+///   .threshold( HarmCategory::DangerousContent, HarmBlockThreshold::BlockOnlyHigh )
+///   .build();
+/// assert_eq!( settings.len(), HarmCategory::ALL.len() );
+/// ```
+#[ derive( Debug, Clone, Default ) ]
+pub struct SafetySettingsBuilder
+{
+  settings : Vec< SafetySetting >,
+}
+
+impl SafetySettingsBuilder
+{
+  /// Creates a builder without settings, leaving every category to the API default.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new() -> Self
+  {
+    Self::default()
+  }
+
+  /// Blocks `category` from `threshold`, replacing an earlier threshold of the category.
+  #[ inline ]
+  #[ must_use ]
+  pub fn threshold( mut self, category : HarmCategory, threshold : HarmBlockThreshold ) -> Self
+  {
+    let setting = SafetySetting::new( category, threshold );
+    match self.settings.iter_mut().find( | existing | existing.category == setting.category )
+    {
+      Some( existing ) => *existing = setting,
+      None => self.settings.push( setting ),
+    }
+    self
+  }
+
+  /// Blocks every category from `threshold`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn all( self, threshold : HarmBlockThreshold ) -> Self
+  {
+    HarmCategory::ALL.into_iter().fold( self, | builder, category | builder.threshold( category, threshold ) )
+  }
+
+  /// The settings, for `GenerateContentRequest::safety_settings`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn build( self ) -> Vec< SafetySetting >
+  {
+    self.settings
+  }
+}
+
+/// Finish reasons of candidates stopped by safety or policy filters.
+const BLOCKING_FINISH_REASONS : [ &str; 5 ] = [ "SAFETY", "PROHIBITED_CONTENT", "BLOCKLIST", "SPII", "IMAGE_SAFETY" ];
+
+impl GenerateContentResponse
+{
+  /// The safety ratings of the prompt and of every candidate.
+  fn safety_ratings( &self ) -> impl Iterator< Item = &SafetyRating >
+  {
+    let prompt = self.prompt_feedback.iter().filter_map( | feedback | feedback.safety_ratings.as_ref() );
+    let candidates = self.candidates.iter().filter_map( | candidate | candidate.safety_ratings.as_ref() );
+    prompt.chain( candidates ).flatten()
+  }
+
+  /// Whether the prompt or a candidate was blocked by safety or policy filters.
+  #[ inline ]
+  #[ must_use ]
+  pub fn was_blocked( &self ) -> bool
+  {
+    self.prompt_feedback.as_ref().is_some_and( | feedback | feedback.block_reason.is_some() )
+      || self.candidates.iter().any( | candidate |
+        candidate.finish_reason.as_deref().is_some_and( | reason | BLOCKING_FINISH_REASONS.contains( &reason ) )
+      )
+      || self.safety_ratings().any( | rating | rating.blocked == Some( true ) )
+  }
+
+  /// The categories whose ratings blocked the prompt or a candidate, each once.
+  #[ inline ]
+  #[ must_use ]
+  pub fn blocked_categories( &self ) -> Vec< HarmCategory >
+  {
+    let mut categories = Vec::new();
+    for category in self.safety_ratings()
+      .filter( | rating | rating.blocked == Some( true ) )
+      .filter_map( SafetyRating::harm_category )
+    {
+      if !categories.contains( &category )
+      {
+        categories.push( category );
+      }
+    }
+    categories
+  }
+
+  /// The highest probability of harm rated for the prompt or a candidate, or
+  /// `None` when nothing was rated.
+  #[ inline ]
+  #[ must_use ]
+  pub fn max_severity( &self ) -> Option< HarmProbability >
+  {
+    self.safety_ratings().filter_map( SafetyRating::harm_probability ).max()
+  }
+}
@@ -1,20 +1,56 @@
-//! Google Search and grounding types for the Gemini API.
+//! Google Search, URL context, and grounding types for the Gemini API.
 
 use serde::{ Deserialize, Serialize };
 
-/// Google Search tool for real-time web search integration.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+/// Google Search retrieval tool, used by Gemini 1.5 models.
+///
+/// Gemini 2.0 and later models search with [`GoogleSearch`] instead.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct GoogleSearchTool
 {
-  /// Configuration options for Google Search (currently empty for enablement).
+  /// When to search; searching on every request when not set.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub config : Option< serde_json::Value >,
+  pub dynamic_retrieval_config : Option< DynamicRetrievalConfig >,
 }
 
-/// Grounding metadata containing web search results and attribution.
+/// When Google Search retrieval searches.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
+pub struct DynamicRetrievalConfig
+{
+  /// "MODE_DYNAMIC" to search only when the prediction that the answer
+  /// benefits from it reaches the threshold, or "MODE_UNSPECIFIED" to always search.
+  pub mode : String,
+
+  /// Prediction from 0.0 to 1.0 from which to search. The API default is 0.3.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub dynamic_threshold : Option< f32 >,
+}
+
+impl DynamicRetrievalConfig
+{
+  /// Searches only when the prediction that the answer benefits from it
+  /// reaches `threshold`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn dynamic( threshold : f32 ) -> Self
+  {
+    Self { mode : "MODE_DYNAMIC".to_string(), dynamic_threshold : Some( threshold ) }
+  }
+}
+
+/// Google Search tool of Gemini 2.0 and later models; the model decides when to search.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+pub struct GoogleSearch {}
+
+/// URL context tool: the model reads the URLs given in the prompt.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+pub struct UrlContext {}
+
+/// Grounding metadata containing web search results and attribution.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
 pub struct GroundingMetadata
 {
   /// Queries that were sent to the web search service.
@@ -34,30 +70,130 @@ pub struct GroundingMetadata
   pub search_entry_point : Option< SearchEntryPoint >,
 }
 
-/// Individual grounding chunk from web search results.
-#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+impl GroundingMetadata
+{
+  /// The queries sent to web search, empty when the model did not search.
+  #[ inline ]
+  #[ must_use ]
+  pub fn search_queries( &self ) -> &[ String ]
+  {
+    self.web_search_queries.as_deref().unwrap_or_default()
+  }
+
+  /// The sources the answer is grounded in.
+  #[ inline ]
+  #[ must_use ]
+  pub fn chunks( &self ) -> &[ GroundingChunk ]
+  {
+    self.grounding_chunks.as_deref().unwrap_or_default()
+  }
+
+  /// The grounded spans of the answer, each with the sources supporting it.
+  #[ inline ]
+  #[ must_use ]
+  pub fn citations( &self ) -> Vec< GroundingCitation< '_ > >
+  {
+    let chunks = self.chunks();
+    self.grounding_supports.as_deref().unwrap_or_default().iter()
+      .map( | support |
+      {
+        let segment = support.segment.as_ref();
+        GroundingCitation
+        {
+          text : segment.and_then( | segment | segment.text.as_deref() ).unwrap_or_default(),
+          start_index : segment.and_then( | segment | segment.start_index ).unwrap_or( 0 ),
+          end_index : segment.and_then( | segment | segment.end_index ).unwrap_or( 0 ),
+          sources : support.grounding_chunk_indices.iter()
+            .filter_map( | index | usize::try_from( *index ).ok().and_then( | index | chunks.get( index ) ) )
+            .collect(),
+        }
+      } )
+      .collect()
+  }
+}
+
+/// A span of the answer and the sources supporting it.
+#[ derive( Debug, Clone ) ]
+pub struct GroundingCitation< 'a >
+{
+  /// Text of the span.
+  pub text : &'a str,
+
+  /// Byte offset of the span in the text of its part, inclusive.
+  pub start_index : i32,
+
+  /// Byte offset of the end of the span in the text of its part, exclusive.
+  pub end_index : i32,
+
+  /// The sources supporting the span.
+  pub sources : Vec< &'a GroundingChunk >,
+}
+
+/// A source the answer is grounded in: a web page, or retrieved context.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct GroundingChunk
 {
-  /// URI of the source web page.
+  /// A web page found by search or read from the URL context.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub web : Option< WebSource >,
+
+  /// Context retrieved by a retrieval tool.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub retrieved_context : Option< RetrievedContext >,
+}
+
+impl GroundingChunk
+{
+  /// URI of the source, for web search a redirect to the page.
+  #[ inline ]
+  #[ must_use ]
+  pub fn uri( &self ) -> Option< &str >
+  {
+    self.web.as_ref().and_then( | web | web.uri.as_deref() )
+      .or_else( || self.retrieved_context.as_ref().and_then( | context | context.uri.as_deref() ) )
+  }
+
+  /// Title of the source, for web search the domain of the page.
+  #[ inline ]
+  #[ must_use ]
+  pub fn title( &self ) -> Option< &str >
+  {
+    self.web.as_ref().and_then( | web | web.title.as_deref() )
+      .or_else( || self.retrieved_context.as_ref().and_then( | context | context.title.as_deref() ) )
+  }
+}
+
+/// A web page an answer is grounded in.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct WebSource
+{
+  /// URI of the page.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub uri : Option< String >,
 
-  /// Title of the web page.
+  /// Title of the page.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub title : Option< String >,
+}
 
-  /// Content excerpt from the web page.
+/// Context retrieved by a retrieval tool.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct RetrievedContext
+{
+  /// URI of the context.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub content : Option< String >,
+  pub uri : Option< String >,
 
-  /// Publication date of the content.
+  /// Title of the context.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub published_date : Option< String >,
+  pub title : Option< String >,
 
-  /// Domain of the source website.
+  /// Text of the context.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub domain : Option< String >,
+  pub text : Option< String >,
 }
 
 /// Grounding support indicating which parts of the response are grounded.
@@ -65,20 +201,39 @@ pub struct GroundingChunk
 #[ serde( rename_all = "camelCase" ) ]
 pub struct GroundingSupport
 {
-  /// Start index of the grounded segment in the response.
+  /// The grounded span of the response.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub segment : Option< GroundingSegment >,
+
+  /// Indices of grounding chunks that support this segment.
+  #[ serde( default ) ]
+  pub grounding_chunk_indices : Vec< i32 >,
+
+  /// Confidence of each supporting chunk, in the order of the indices.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub confidence_scores : Option< Vec< f64 > >,
+}
+
+/// A span of the text of a response part.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GroundingSegment
+{
+  /// Index of the part the span is in.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub part_index : Option< i32 >,
+
+  /// Byte offset of the span, inclusive; absent for 0.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub start_index : Option< i32 >,
 
-  /// End index of the grounded segment in the response.
+  /// Byte offset of the end of the span, exclusive.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub end_index : Option< i32 >,
 
-  /// Indices of grounding chunks that support this segment.
-  pub grounding_chunk_indices : Vec< i32 >,
-
-  /// Confidence score for this grounding support.
+  /// Text of the span.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  pub confidence_score : Option< f64 >,
+  pub text : Option< String >,
 }
 
 /// Search entry point providing access to search functionality.
@@ -94,3 +249,27 @@ pub struct SearchEntryPoint
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub sdk_blob : Option< String >,
 }
+
+/// The URLs the URL context tool read for a candidate.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct UrlContextMetadata
+{
+  /// One entry per URL read.
+  #[ serde( default ) ]
+  pub url_metadata : Vec< UrlMetadata >,
+}
+
+/// A URL the URL context tool read.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct UrlMetadata
+{
+  /// The URL.
+  pub retrieved_url : String,
+
+  /// Whether the URL was read, e.g. "URL_RETRIEVAL_STATUS_SUCCESS" or
+  /// "URL_RETRIEVAL_STATUS_ERROR".
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub url_retrieval_status : Option< String >,
+}
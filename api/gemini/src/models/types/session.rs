@@ -0,0 +1,339 @@
+//! Chat sessions owning the history of a multi-turn conversation.
+//!
+//! A [`ChatSession`] keeps the model, system instruction, generation config,
+//! and turns of a chat, and appends each exchange once it succeeds. It never
+//! shrinks on its own: the caller applies an [`api_common::TruncationStrategy`]
+//! when the history grows too long, and gets the removed contents back.
+//! Sessions serialize to JSON, so the caller decides where they are stored.
+
+use serde::{ Deserialize, Serialize };
+use super::content::{ Content, Part, SystemInstruction };
+use super::generation::{ GenerateContentRequest, GenerateContentResponse, GenerationConfig };
+
+/// A multi-turn chat with one model, owning its history.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use api_gemini::{ client::Client, models::ChatSession };
+/// # use api_common::TruncationStrategy;
+/// # #[ tokio::main ]
+/// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+/// let client = Client::new()?;
+/// let mut session = ChatSession::new( "gemini-2.5-flash" ).with_system_instruction( "Answer in one sentence." );
+///
+/// let answer = session.send_text( &client, "What is machine learning?" ).await?;
+/// println!( "Assistant : {answer}" );
+/// let answer = session.send_text( &client, "Give me an example." ).await?;
+/// println!( "Assistant : {answer}" );
+///
+/// session.truncate( &TruncationStrategy::keep_last_turns( 10 ) )?;
+/// let saved = session.to_json()?;
+/// # Ok( () )
+/// # }
+/// ```
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ChatSession
+{
+  /// Model the messages are sent to, e.g. `gemini-2.5-flash`.
+  pub model : String,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// System instruction sent with every message.
+  pub system_instruction : Option< SystemInstruction >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Generation config sent with every message.
+  pub generation_config : Option< GenerationConfig >,
+
+  #[ serde( default ) ]
+  /// User and model turns, oldest first.
+  pub history : Vec< Content >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Summary of the turns removed by a `Summarize` truncation, sent after
+  /// the system instruction.
+  pub summary : Option< String >,
+}
+
+impl ChatSession
+{
+  /// Creates an empty session with `model`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( model : impl Into< String > ) -> Self
+  {
+    Self { model : model.into(), ..Default::default() }
+  }
+
+  /// Sets the system instruction sent with every message.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_system_instruction( mut self, instruction : &str ) -> Self
+  {
+    self.system_instruction = Some( SystemInstruction
+    {
+      role : "system".to_string(),
+      parts : vec![ Part { text : Some( instruction.to_string() ), ..Default::default() } ],
+    } );
+    self
+  }
+
+  /// Sets the generation config sent with every message.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_generation_config( mut self, config : GenerationConfig ) -> Self
+  {
+    self.generation_config = Some( config );
+    self
+  }
+
+  /// Starts from an existing history, such as one kept for
+  /// `ModelApi::continue_conversation`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_history( mut self, history : Vec< Content > ) -> Self
+  {
+    self.history = history;
+    self
+  }
+
+  /// Number of turns in the history.
+  #[ inline ]
+  #[ must_use ]
+  pub fn turn_count( &self ) -> usize
+  {
+    self.history.iter().filter( | content | starts_turn( content ) ).count()
+  }
+
+  /// Sends a text message, returning the reply text.
+  ///
+  /// # Errors
+  ///
+  /// As [`Self::send`], and [`crate::error::Error::ApiError`] when the reply
+  /// holds no text.
+  #[ inline ]
+  pub async fn send_text( &mut self, client : &crate::client::Client, message : &str ) -> Result< String, crate::error::Error >
+  {
+    let response = self.send( client, vec![ Part { text : Some( message.to_string() ), ..Default::default() } ] ).await?;
+    response.answer_text().ok_or_else( || crate::error::Error::ApiError(
+      format!( "No text content returned from model '{}' in chat session.", self.model )
+    ) )
+  }
+
+  /// Sends a user message of any parts, such as text with images or
+  /// function responses, and appends it with the reply to the history.
+  ///
+  /// The history is left as it was when the request fails or the reply is
+  /// blocked.
+  ///
+  /// # Errors
+  ///
+  /// Returns the errors of `ModelApi::generate_content`, and
+  /// [`crate::error::Error::ApiError`] when no candidate is returned.
+  #[ inline ]
+  pub async fn send( &mut self, client : &crate::client::Client, parts : Vec< Part > ) -> Result< GenerateContentResponse, crate::error::Error >
+  {
+    let message = Content { parts, role : "user".to_string() };
+    let request = self.request( &message );
+    let models = client.models();
+    let response = models.by_name( &self.model ).generate_content( &request ).await?;
+    let parts = response.candidates.first().map( | candidate | candidate.content.parts.clone() ).unwrap_or_default();
+    if !self.record( message, parts )
+    {
+      return Err( crate::error::Error::ApiError( format!(
+        "No content returned from model '{}' in chat session. The response may have been blocked by safety filters.", self.model
+      ) ) );
+    }
+    Ok( response )
+  }
+
+  /// Sends a text message and streams the reply; once the stream ends, the
+  /// message and the whole reply are appended to the history.
+  ///
+  /// The history is left as it was when the stream fails or is dropped
+  /// early.
+  ///
+  /// # Errors
+  ///
+  /// The stream yields the errors of `ModelApi::generate_content_stream`.
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  pub fn send_stream< 'a >( &'a mut self, client : &'a crate::client::Client, message : &str )
+  -> impl futures::Stream< Item = Result< super::streaming::StreamingResponse, crate::error::Error > > + 'a
+  {
+    use futures::StreamExt;
+
+    let message = Content { parts : vec![ Part { text : Some( message.to_string() ), ..Default::default() } ], role : "user".to_string() };
+    async_stream::stream!
+    {
+      let request = self.request( &message );
+      let models = client.models();
+      let model = models.by_name( &self.model );
+      let chunks = match model.generate_content_stream( &request ).await
+      {
+        Ok( chunks ) => chunks,
+        Err( error ) =>
+        {
+          yield Err( error );
+          return;
+        },
+      };
+      futures::pin_mut!( chunks );
+      let mut parts = Vec::new();
+      while let Some( chunk ) = chunks.next().await
+      {
+        match chunk
+        {
+          Ok( chunk ) =>
+          {
+            if let Some( candidate ) = chunk.candidates.as_ref().and_then( | candidates | candidates.first() )
+            {
+              parts.extend( candidate.content.parts.iter().cloned() );
+            }
+            yield Ok( chunk );
+          },
+          Err( error ) =>
+          {
+            yield Err( error );
+            return;
+          },
+        }
+      }
+      self.record( message, parts );
+    }
+  }
+
+  /// Shortens the history, returning the removed contents oldest first.
+  ///
+  /// The turns are those of [`api_common::Conversation`]: a turn starts at a
+  /// user message and includes the model replies and function responses up
+  /// to the next one, and whole turns are removed. Token budgets count the
+  /// text of each part, or its JSON, and the system instruction; a
+  /// `Summarize` strategy stores its summary in [`Self::summary`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::ApiError`] when the summarizer fails; the
+  /// history is then left unchanged.
+  #[ inline ]
+  pub fn truncate( &mut self, strategy : &api_common::TruncationStrategy ) -> Result< Vec< Content >, crate::error::Error >
+  {
+    let mut conversation = api_common::Conversation::new();
+    conversation.set_system( self.system_instruction.as_ref().map( | instruction | parts_text( &instruction.parts ) ) );
+    conversation.set_summary( self.summary.clone() );
+    for content in &self.history
+    {
+      let role = if starts_turn( content ) { api_common::NeutralRole::User } else { api_common::NeutralRole::Assistant };
+      conversation.push( api_common::NeutralMessage::new( role, parts_text( &content.parts ) ) );
+    }
+    let removed = conversation.truncate( strategy )
+      .map_err( | e | crate::error::Error::ApiError( format!( "Failed to summarize chat session : {e}" ) ) )?;
+    self.summary = conversation.summary().map( str::to_string );
+    Ok( self.history.drain( ..removed.len() ).collect() )
+  }
+
+  /// The session as JSON, to store and resume with [`Self::from_json`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::SerializationError`] when the session
+  /// cannot be serialized.
+  #[ inline ]
+  pub fn to_json( &self ) -> Result< String, crate::error::Error >
+  {
+    serde_json::to_string( self )
+      .map_err( | e | crate::error::Error::SerializationError( format!( "Failed to serialize chat session : {e}" ) ) )
+  }
+
+  /// Restores a session stored with [`Self::to_json`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when the JSON
+  /// does not describe a session.
+  #[ inline ]
+  pub fn from_json( json : &str ) -> Result< Self, crate::error::Error >
+  {
+    serde_json::from_str( json )
+      .map_err( | e | crate::error::Error::DeserializationError( format!( "Failed to restore chat session : {e}" ) ) )
+  }
+
+  /// Appends `message` and the reply made of `parts` to the history,
+  /// returning whether it did.
+  ///
+  /// Thoughts are dropped and text runs joined; nothing is appended when no
+  /// part is left, as for a blocked reply.
+  #[ inline ]
+  pub fn record( &mut self, message : Content, parts : Vec< Part > ) -> bool
+  {
+    let reply = reply_content( parts );
+    if reply.parts.is_empty()
+    {
+      return false;
+    }
+    self.history.push( message );
+    self.history.push( reply );
+    true
+  }
+
+  /// The request sending `message` after the history, with the system
+  /// instruction, summary, and generation config of the session.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request( &self, message : &Content ) -> GenerateContentRequest
+  {
+    let mut contents = self.history.clone();
+    contents.push( message.clone() );
+    let mut system_instruction = self.system_instruction.clone();
+    if let Some( summary ) = &self.summary
+    {
+      system_instruction.get_or_insert_with( || SystemInstruction { role : "system".to_string(), parts : Vec::new() } )
+        .parts.push( Part { text : Some( summary.clone() ), ..Default::default() } );
+    }
+    GenerateContentRequest
+    {
+      contents,
+      system_instruction,
+      generation_config : self.generation_config.clone(),
+      ..Default::default()
+    }
+  }
+}
+
+/// Whether `content` is a user message rather than a function response.
+fn starts_turn( content : &Content ) -> bool
+{
+  content.role == "user" && content.parts.iter().all( | part | part.function_response.is_none() )
+}
+
+/// The text of `parts` as a token budget sees it: text as is, other parts
+/// as their JSON.
+fn parts_text( parts : &[ Part ] ) -> String
+{
+  parts.iter()
+    .map( | part | match &part.text
+    {
+      Some( text ) => text.clone(),
+      None => serde_json::to_string( part ).unwrap_or_default(),
+    } )
+    .collect()
+}
+
+/// The model turn kept for `parts`: thoughts dropped, and text runs joined,
+/// as streamed replies arrive in many text parts.
+fn reply_content( parts : Vec< Part > ) -> Content
+{
+  let mut kept : Vec< Part > = Vec::new();
+  for part in parts.into_iter().filter( | part | !part.is_thought() )
+  {
+    match ( kept.last_mut(), &part.text )
+    {
+      ( Some( Part { text : Some( previous ), inline_data : None, function_call : None, .. } ), Some( text ) )
+        if part.function_call.is_none() && part.inline_data.is_none() => previous.push_str( text ),
+      _ => kept.push( part ),
+    }
+  }
+  Content { parts : kept, role : "model".to_string() }
+}
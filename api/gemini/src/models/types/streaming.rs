@@ -2,7 +2,7 @@
 
 use serde::{ Deserialize, Serialize };
 use super::content::{ Content, Part, Candidate };
-use super::generation::{ GenerateContentRequest, GenerationConfig, UsageMetadata };
+use super::generation::{ GenerateContentRequest, GenerateContentResponse, GenerationConfig, UsageMetadata };
 
 /// Response type for streaming content generation.
 #[ cfg( feature = "streaming" ) ]
@@ -27,6 +27,270 @@ pub struct StreamingResponse
   pub error : Option< String >,
 }
 
+/// A generated chunk as a streaming chunk; it is final once its first
+/// candidate has a `finishReason`.
+#[ cfg( feature = "streaming" ) ]
+impl From< GenerateContentResponse > for StreamingResponse
+{
+  #[ inline ]
+  fn from( response : GenerateContentResponse ) -> Self
+  {
+    let is_final = response.candidates
+      .first()
+      .and_then( | candidate | candidate.finish_reason.as_ref() )
+      .is_some();
+    Self
+    {
+      candidates : Some( response.candidates ),
+      usage_metadata : response.usage_metadata,
+      is_final : Some( is_final ),
+      error : None,
+    }
+  }
+}
+
+/// Incremental parser of a `:streamGenerateContent` body, fed bytes as they
+/// arrive.
+///
+/// With `?alt=sse` the API sends one `data: {...}` event per chunk, framed by
+/// [`api_sse_stream::SseDecoder`]: comments, `event:`, `id:`, and `retry:`
+/// lines are skipped, and any line ending is accepted. A body starting with
+/// `{` is read as newline-delimited JSON instead, one chunk object per line.
+/// A chunk carrying an `error` object becomes an `Err`. The JSON array the
+/// API sends without `alt=sse` is not incremental; read it whole instead, as
+/// `ModelApi::generate_content_stream_buffered` does.
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug, Default ) ]
+pub struct StreamChunkParser
+{
+  framing : Framing,
+}
+
+/// How a streamed body is framed, known from its first significant byte.
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug ) ]
+enum Framing
+{
+  /// Only whitespace or a byte order mark so far, held here.
+  Unknown( Vec< u8 > ),
+  /// Server-sent events.
+  Sse( api_sse_stream::SseDecoder ),
+  /// One chunk object per line; holds the line not yet terminated.
+  Lines( Vec< u8 > ),
+}
+
+#[ cfg( feature = "streaming" ) ]
+impl Default for Framing
+{
+  #[ inline ]
+  fn default() -> Self
+  {
+    Self::Unknown( Vec::new() )
+  }
+}
+
+#[ cfg( feature = "streaming" ) ]
+impl StreamChunkParser
+{
+  /// Creates a parser at the start of a body.
+  #[ must_use ]
+  #[ inline ]
+  pub fn new() -> Self
+  {
+    Self::default()
+  }
+
+  /// Feeds the next bytes of the body, returning the chunks they complete.
+  #[ inline ]
+  pub fn push( &mut self, bytes : &[ u8 ] ) -> Vec< Result< StreamingResponse, crate::error::Error > >
+  {
+    let mut chunks = Vec::new();
+    match &mut self.framing
+    {
+      Framing::Unknown( head ) =>
+      {
+        head.extend_from_slice( bytes );
+        if let Some( framing ) = Self::framing_of( head )
+        {
+          if let Framing::Unknown( head ) = core::mem::replace( &mut self.framing, framing )
+          {
+            self.feed( &head, &mut chunks );
+          }
+        }
+      },
+      Framing::Sse( _ ) | Framing::Lines( _ ) => self.feed( bytes, &mut chunks ),
+    }
+    chunks
+  }
+
+  /// Ends the body, returning the chunk of an event or line it left
+  /// unterminated.
+  #[ inline ]
+  pub fn finish( &mut self ) -> Vec< Result< StreamingResponse, crate::error::Error > >
+  {
+    let mut chunks = Vec::new();
+    match &mut self.framing
+    {
+      Framing::Unknown( _ ) => {},
+      Framing::Sse( decoder ) =>
+      {
+        decoder.finish();
+        Self::events( decoder, &mut chunks );
+      },
+      Framing::Lines( line ) =>
+      {
+        let line = core::mem::take( line );
+        Self::line( &line, &mut chunks );
+      },
+    }
+    chunks
+  }
+
+  /// The framing of a body starting with `head`, once a byte other than
+  /// whitespace or a byte order mark is seen.
+  fn framing_of( head : &[ u8 ] ) -> Option< Framing >
+  {
+    const BOM : &[ u8 ] = b"\xEF\xBB\xBF";
+    if head.len() < BOM.len() && BOM.starts_with( head )
+    {
+      return None;
+    }
+    let body = head.strip_prefix( BOM ).unwrap_or( head );
+    match body.iter().find( | byte | !byte.is_ascii_whitespace() )?
+    {
+      b'{' => Some( Framing::Lines( Vec::new() ) ),
+      _ => Some( Framing::Sse( api_sse_stream::SseDecoder::new() ) ),
+    }
+  }
+
+  /// Feeds bytes to the framing already chosen.
+  fn feed( &mut self, bytes : &[ u8 ], chunks : &mut Vec< Result< StreamingResponse, crate::error::Error > > )
+  {
+    match &mut self.framing
+    {
+      Framing::Unknown( _ ) => {},
+      Framing::Sse( decoder ) =>
+      {
+        decoder.push( bytes );
+        Self::events( decoder, chunks );
+      },
+      Framing::Lines( line ) =>
+      {
+        for &byte in bytes
+        {
+          if byte == b'\n'
+          {
+            let complete = core::mem::take( line );
+            Self::line( &complete, chunks );
+          }
+          else
+          {
+            line.push( byte );
+          }
+        }
+      },
+    }
+  }
+
+  /// Turns the events the decoder dispatched into chunks.
+  fn events( decoder : &mut api_sse_stream::SseDecoder, chunks : &mut Vec< Result< StreamingResponse, crate::error::Error > > )
+  {
+    while let Some( event ) = decoder.next_event()
+    {
+      if !event.data.is_empty() && !event.is_done()
+      {
+        chunks.push( Self::chunk( &event.data ) );
+      }
+    }
+  }
+
+  /// Turns one newline-delimited JSON line into a chunk.
+  fn line( line : &[ u8 ], chunks : &mut Vec< Result< StreamingResponse, crate::error::Error > > )
+  {
+    let line = line.strip_prefix( b"\xEF\xBB\xBF" ).unwrap_or( line );
+    match core::str::from_utf8( line )
+    {
+      Ok( line ) if line.trim().is_empty() => {},
+      Ok( line ) => chunks.push( Self::chunk( line.trim() ) ),
+      Err( error ) => chunks.push( Err( crate::error::Error::SerializationError( format!( "Streaming response line is not UTF-8 : {error}" ) ) ) ),
+    }
+  }
+
+  /// Parses one chunk object.
+  fn chunk( json : &str ) -> Result< StreamingResponse, crate::error::Error >
+  {
+    let value : serde_json::Value = serde_json::from_str( json )
+      .map_err( | error | crate::error::Error::SerializationError( format!( "Failed to parse streaming chunk : {error}" ) ) )?;
+    if let Some( error ) = value.get( "error" )
+    {
+      let message = error.get( "message" ).and_then( serde_json::Value::as_str ).map_or_else( || error.to_string(), str::to_string );
+      return Err( crate::error::Error::ApiError( format!( "Streaming response failed : {message}" ) ) );
+    }
+    serde_json::from_value::< GenerateContentResponse >( value )
+      .map( StreamingResponse::from )
+      .map_err( | error | crate::error::Error::SerializationError( format!( "Failed to parse streaming chunk : {error}" ) ) )
+  }
+}
+
+/// Translates the first candidate of a chunk into provider-neutral events.
+///
+/// Text parts become text deltas (thought parts are skipped), each function
+/// call becomes one complete tool-call fragment indexed by position, usage
+/// metadata becomes a usage update, and `finishReason` becomes `Done`. A chunk
+/// carrying `error` becomes an `Err`.
+#[ cfg( all( feature = "streaming", feature = "common" ) ) ]
+impl api_common::IntoStreamEvents for StreamingResponse
+{
+  #[ inline ]
+  fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+  {
+    use api_common::{ StreamEvent, ToolCallDelta, UsageUpdate };
+
+    if let Some( error ) = self.error
+    {
+      return Err( crate::error::Error::ApiError( error ).into() );
+    }
+    let mut events = Vec::new();
+    let mut finish_reason = None;
+    if let Some( candidate ) = self.candidates.and_then( | c | c.into_iter().next() )
+    {
+      let mut index = 0;
+      for part in candidate.content.parts
+      {
+        if let Some( call ) = part.function_call
+        {
+          events.push( StreamEvent::ToolCallDelta( ToolCallDelta
+          {
+            index,
+            id : None,
+            name : Some( call.name ),
+            arguments : call.args.to_string(),
+          }));
+          index += 1;
+        }
+        else if let Some( text ) = part.text.filter( | t | !t.is_empty() && part.thought != Some( true ) )
+        {
+          events.push( StreamEvent::TextDelta( text ) );
+        }
+      }
+      finish_reason = candidate.finish_reason;
+    }
+    if let Some( usage ) = self.usage_metadata
+    {
+      events.push( StreamEvent::UsageUpdate( UsageUpdate
+      {
+        input_tokens : usage.prompt_token_count.and_then( | n | u64::try_from( n ).ok() ),
+        output_tokens : usage.candidates_token_count.and_then( | n | u64::try_from( n ).ok() ),
+      }));
+    }
+    if let Some( reason ) = finish_reason
+    {
+      events.push( StreamEvent::Done { finish_reason : Some( reason ) } );
+    }
+    Ok( events )
+  }
+}
+
 /// Builder for creating streaming requests with fluent API.
 #[ cfg( feature = "streaming" ) ]
 #[ derive( Debug ) ]
@@ -65,6 +329,27 @@ impl< 'a > StreamingRequestBuilder< 'a >
     self
   }
 
+  /// Set the system instruction that steers the model across the request.
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_system_instruction( self, instruction : &str ) -> Self
+  {
+    self.with_system_parts( vec![ Part { text : Some( instruction.to_string() ), ..Default::default() } ] )
+  }
+
+  /// Set a system instruction made of several parts, replacing any set before.
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_system_parts( mut self, parts : Vec< Part > ) -> Self
+  {
+    self.request.system_instruction = Some( super::content::SystemInstruction
+    {
+      role : "system".to_string(),
+      parts,
+    } );
+    self
+  }
+
   /// Set the temperature for response generation.
   #[ must_use ]
   #[ inline ]
@@ -113,6 +398,14 @@ impl< 'a > StreamingRequestBuilder< 'a >
     self
   }
 
+  /// The request as configured so far.
+  #[ must_use ]
+  #[ inline ]
+  pub fn request( &self ) -> &GenerateContentRequest
+  {
+    &self.request
+  }
+
   /// Execute the streaming request.
   ///
   /// # Errors
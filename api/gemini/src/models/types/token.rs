@@ -5,18 +5,74 @@ use super::content::Content;
 use super::generation::GenerateContentRequest;
 
 /// Request for counting tokens in content.
+///
+/// Set either `contents`, or `generate_content_request` to count everything a
+/// generation request sends: contents, tools, system instruction, and cached
+/// content.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct CountTokensRequest
 {
   /// Contents to count tokens for.
+  #[ serde( default, skip_serializing_if = "Vec::is_empty" ) ]
   pub contents : Vec< Content >,
 
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
-  /// Optional generation request to include in token counting.
+  /// Generation request to count tokens for, instead of `contents`.
   pub generate_content_request : Option< GenerateContentRequest >,
 }
 
+impl CountTokensRequest
+{
+  /// Counts the tokens of `contents`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn for_contents( contents : Vec< Content > ) -> Self
+  {
+    Self { contents, generate_content_request : None }
+  }
+
+  /// Counts the tokens of everything `request` sends.
+  #[ inline ]
+  #[ must_use ]
+  pub fn for_request( request : GenerateContentRequest ) -> Self
+  {
+    Self { contents : Vec::new(), generate_content_request : Some( request ) }
+  }
+
+  /// The body sent to `models/{model_id}:countTokens`: the request, with the
+  /// wrapped generation request naming its model.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::SerializationError`] when the request
+  /// cannot be serialized.
+  #[ inline ]
+  pub fn body( &self, model_id : &str ) -> Result< serde_json::Value, crate::error::Error >
+  {
+    let mut body = serde_json::to_value( self )
+      .map_err( | e | crate::error::Error::SerializationError( format!( "Failed to serialize count tokens request : {e}" ) ) )?;
+    if let Some( generation ) = body.get_mut( "generateContentRequest" ).and_then( serde_json::Value::as_object_mut )
+    {
+      generation.insert( "model".to_string(), format!( "models/{model_id}" ).into() );
+    }
+    Ok( body )
+  }
+}
+
+/// Token count of one modality, such as `TEXT` or `IMAGE`.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ModalityTokenCount
+{
+  /// The modality.
+  pub modality : String,
+
+  /// Number of tokens of the modality.
+  #[ serde( default ) ]
+  pub token_count : i32,
+}
+
 /// Response from counting tokens.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -28,6 +84,34 @@ pub struct CountTokensResponse
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Number of tokens in cached content.
   pub cached_content_token_count : Option< i32 >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Tokens of the prompt by modality.
+  pub prompt_tokens_details : Option< Vec< ModalityTokenCount > >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Tokens of the cached content by modality.
+  pub cache_tokens_details : Option< Vec< ModalityTokenCount > >,
+}
+
+impl CountTokensResponse
+{
+  /// Tokens served from the cached content the request names, at the
+  /// cached rate.
+  #[ inline ]
+  #[ must_use ]
+  pub fn cached_tokens( &self ) -> i32
+  {
+    self.cached_content_token_count.unwrap_or( 0 )
+  }
+
+  /// Tokens not served from a cache; `total_tokens` includes the cached ones.
+  #[ inline ]
+  #[ must_use ]
+  pub fn uncached_tokens( &self ) -> i32
+  {
+    self.total_tokens.saturating_sub( self.cached_tokens() ).max( 0 )
+  }
 }
 
 /// Request for batch token counting.
@@ -0,0 +1,185 @@
+//! Video generation types for the Gemini API.
+//!
+//! Veo models generate videos through the `:predictLongRunning` endpoint,
+//! which starts an operation whose result holds the videos.
+
+use base64::{ engine::general_purpose::STANDARD, Engine as _ };
+use serde::{ Deserialize, Serialize };
+use super::image::{ AspectRatio, PersonGeneration };
+
+/// A prompt to generate a video from.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct VideoPromptInstance
+{
+  /// Description of the video to generate.
+  pub prompt : String,
+}
+
+/// Parameters of the videos of a video generation request.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct VideoGenerationParameters
+{
+  /// Number of videos to generate. Veo 3 models generate one.
+  #[ serde( rename = "sampleCount", skip_serializing_if = "Option::is_none" ) ]
+  pub number_of_videos : Option< u32 >,
+
+  /// Aspect ratio of the videos, widescreen or tall portrait. The API
+  /// default is widescreen.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub aspect_ratio : Option< AspectRatio >,
+
+  /// Whether the videos may show people.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub person_generation : Option< PersonGeneration >,
+
+  /// What the videos should not show.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub negative_prompt : Option< String >,
+
+  /// Length of each video in seconds, within the range the model supports.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub duration_seconds : Option< u32 >,
+}
+
+/// Request to generate videos with a Veo model.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateVideosRequest
+{
+  /// Prompts to generate videos from. Veo takes a single prompt.
+  pub instances : Vec< VideoPromptInstance >,
+
+  /// Parameters of the generated videos.
+  #[ serde( default ) ]
+  pub parameters : VideoGenerationParameters,
+}
+
+impl GenerateVideosRequest
+{
+  /// Creates a request generating a video of `prompt` with the API defaults.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( prompt : &str ) -> Self
+  {
+    Self
+    {
+      instances : vec![ VideoPromptInstance { prompt : prompt.to_string() } ],
+      parameters : VideoGenerationParameters::default(),
+    }
+  }
+
+  /// Sets how many videos to generate.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_number_of_videos( mut self, number_of_videos : u32 ) -> Self
+  {
+    self.parameters.number_of_videos = Some( number_of_videos );
+    self
+  }
+
+  /// Sets the aspect ratio of the videos.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_aspect_ratio( mut self, aspect_ratio : AspectRatio ) -> Self
+  {
+    self.parameters.aspect_ratio = Some( aspect_ratio );
+    self
+  }
+
+  /// Sets whether the videos may show people.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_person_generation( mut self, person_generation : PersonGeneration ) -> Self
+  {
+    self.parameters.person_generation = Some( person_generation );
+    self
+  }
+
+  /// Sets what the videos should not show.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_negative_prompt( mut self, negative_prompt : &str ) -> Self
+  {
+    self.parameters.negative_prompt = Some( negative_prompt.to_string() );
+    self
+  }
+
+  /// Sets the length of each video in seconds.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_duration_seconds( mut self, duration_seconds : u32 ) -> Self
+  {
+    self.parameters.duration_seconds = Some( duration_seconds );
+    self
+  }
+}
+
+/// Result of a finished video generation operation.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GenerateVideosResponse
+{
+  /// The generated videos.
+  #[ serde( default ) ]
+  pub generated_samples : Vec< GeneratedVideo >,
+
+  /// Number of videos filtered by responsible AI checks.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub rai_media_filtered_count : Option< i32 >,
+
+  /// Why videos were filtered by responsible AI checks.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub rai_media_filtered_reasons : Option< Vec< String > >,
+}
+
+/// A generated video.
+#[ derive( Debug, Clone, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct GeneratedVideo
+{
+  /// The video file.
+  pub video : Video,
+}
+
+/// A video file, given by a download URI or inline.
+///
+/// The Gemini API answers a URI, downloaded with the API key like a file:
+/// `GET {uri}` with the `x-goog-api-key` header.
+#[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct Video
+{
+  /// URI to download the video from.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub uri : Option< String >,
+
+  /// The video, base64-encoded, when it is answered inline.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub encoded_video : Option< String >,
+
+  /// MIME type of the video, e.g. "video/mp4".
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub encoding : Option< String >,
+}
+
+impl Video
+{
+  /// Decodes the video answered inline, or `None` when it has to be
+  /// downloaded from its URI.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`crate::error::Error::DeserializationError`] when the video is
+  /// not valid base64.
+  #[ inline ]
+  pub fn bytes( &self ) -> Result< Option< Vec< u8 > >, crate::error::Error >
+  {
+    self.encoded_video.as_ref()
+      .map( | data | STANDARD.decode( data ).map_err( | e |
+        crate::error::Error::DeserializationError( format!( "Generated video is not valid base64 : {e}" ) )
+      ) )
+      .transpose()
+  }
+}
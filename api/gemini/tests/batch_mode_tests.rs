@@ -0,0 +1,220 @@
+//! Tests for Batch Mode jobs: creating them from inlined requests or a file,
+//! reading their state, and retrieving inlined or downloaded results.
+//!
+//! Requests are checked as serialized and results as parsed from recorded
+//! operations, so no API key is needed. The lifecycle test runs against the
+//! real API.
+
+#![ cfg( feature = "batch_operations" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::
+  {
+    BatchJob, BatchJobResults, BatchJobState, BatchRequest, BatchResponse, CreateBatchEmbeddingRequest,
+    CreateBatchJobRequest, GenerateContentRequest, Operation,
+  },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+/// Nothing listens on the discard port, so no request is answered.
+fn client() -> Client
+{
+  Client::builder()
+    .api_key( "AIzaSyBatchModeTest0123456789abcdefghijk".to_string() )
+    .base_url( "http://127.0.0.1:9".to_string() )
+    .build()
+    .expect( "client builds" )
+}
+
+/// The operation describing job `batches/abc` in `state`, with `output` once done.
+fn job( state : &str, output : Option< serde_json::Value > ) -> Operation
+{
+  let mut metadata = serde_json::json!
+  ({
+    "@type" : "type.googleapis.com/google.ai.generativelanguage.v1main.GenerateContentBatch",
+    "name" : "batches/abc",
+    "model" : "models/gemini-2.5-flash",
+    "displayName" : "nightly",
+    "state" : state,
+    "batchStats" : { "requestCount" : "2", "successfulRequestCount" : "2" },
+  });
+  let mut operation = serde_json::json!( { "name" : "batches/abc", "done" : output.is_some() } );
+  if let Some( output ) = output
+  {
+    metadata[ "output" ] = output.clone();
+    operation[ "response" ] = output;
+  }
+  operation[ "metadata" ] = metadata;
+  serde_json::from_value( operation ).expect( "operation deserializes" )
+}
+
+fn answer( text : &str ) -> serde_json::Value
+{
+  serde_json::json!( { "candidates" : [ { "content" : { "parts" : [ { "text" : text } ], "role" : "model" } } ] } )
+}
+
+fn request( text : &str ) -> GenerateContentRequest
+{
+  serde_json::from_value( serde_json::json!( { "contents" : [ { "parts" : [ { "text" : text } ], "role" : "user" } ] } ) )
+    .expect( "request deserializes" )
+}
+
+#[ test ]
+fn inline_requests_are_keyed()
+{
+  let request = CreateBatchJobRequest::inline( vec![ BatchRequest::new( "request-0", request( "Write a haiku." ) ), BatchRequest::new( "request-1", request( "Explain Rust." ) ) ] );
+  let body = serde_json::json!( { "batch" : request } );
+  let requests = &body[ "batch" ][ "inputConfig" ][ "requests" ][ "requests" ];
+  assert_eq!( requests[ 1 ][ "metadata" ][ "key" ], "request-1" );
+  assert_eq!( requests[ 1 ][ "request" ][ "contents" ][ 0 ][ "parts" ][ 0 ][ "text" ], "Explain Rust." );
+}
+
+#[ test ]
+fn file_requests_name_the_input_file()
+{
+  let body = serde_json::json!( { "batch" : CreateBatchJobRequest::from_file( "files/input" ) } );
+  assert_eq!( body, serde_json::json!( { "batch" : { "inputConfig" : { "fileName" : "files/input" } } } ) );
+}
+
+#[ tokio::test ]
+async fn empty_batches_are_rejected()
+{
+  let error = client().batches().create_inline( "gemini-2.5-flash", Vec::new() ).await.expect_err( "nothing to run" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+/// The job is the metadata of its operation; results missing from it come
+/// from the operation response.
+#[ test ]
+fn jobs_are_read_from_their_operation()
+{
+  let batch = BatchJob::from_operation( job( "BATCH_STATE_RUNNING", None ) ).expect( "describes a job" );
+  assert_eq!( batch.name, "batches/abc" );
+  assert_eq!( batch.state, BatchJobState::Running );
+  assert_eq!( batch.batch_stats.as_ref().map( | stats | stats.request_count ), Some( 2 ) );
+  assert!( batch.output.is_none() );
+
+  let mut operation = job( "BATCH_STATE_SUCCEEDED", Some( serde_json::json!( { "responsesFile" : "files/batch-out" } ) ) );
+  operation.metadata.as_mut().expect( "has metadata" ).as_object_mut().expect( "object" ).remove( "output" );
+  let batch = BatchJob::from_operation( operation ).expect( "describes a job" );
+  assert!( batch.state.is_done() );
+  assert_eq!( batch.output.and_then( | output | output.responses_file ).as_deref(), Some( "files/batch-out" ) );
+}
+
+/// Inlined results are read from the job itself, so nothing is sent.
+#[ tokio::test ]
+async fn results_of_inlined_jobs_are_read_from_the_job()
+{
+  let output = serde_json::json!
+  ({
+    "@type" : "type.googleapis.com/google.ai.generativelanguage.v1main.GenerateContentBatchOutput",
+    "inlinedResponses" : { "inlinedResponses" :
+    [
+      { "response" : answer( "Leaves fall." ), "metadata" : { "key" : "request-0" } },
+      { "error" : { "code" : 400, "message" : "Request is empty." }, "metadata" : { "key" : "request-1" } },
+    ] },
+  });
+  let client = client();
+  let batch = BatchJob::from_operation( job( "BATCH_STATE_SUCCEEDED", Some( output ) ) ).expect( "describes a job" );
+  let results = client.batches().results( batch ).await.expect( "results are inlined" );
+  assert_eq!( results.responses.len(), 2 );
+  assert_eq!( results.responses[ 0 ].request_key(), Some( "request-0" ) );
+  assert!( results.responses[ 0 ].response.is_some() );
+  assert_eq!( results.responses[ 1 ].error.as_ref().map( | error | error.code ), Some( 400 ) );
+}
+
+#[ tokio::test ]
+async fn unfinished_and_failed_jobs_hold_no_results()
+{
+  let client = client();
+  let batches = client.batches();
+  let running = BatchJob::from_operation( job( "BATCH_STATE_RUNNING", None ) ).expect( "describes a job" );
+  let error = batches.results( running ).await.expect_err( "still running" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+
+  let mut operation = job( "BATCH_STATE_FAILED", None );
+  operation.done = true;
+  operation.error = serde_json::from_value( serde_json::json!( { "code" : 3, "message" : "Input file is not valid JSONL." } ) ).expect( "error deserializes" );
+  let failed = BatchJob::from_operation( operation ).expect( "describes a job" );
+  let error = batches.results( failed ).await.expect_err( "failed" );
+  assert!( matches!( &error, Error::ApiError( message ) if message.contains( "not valid JSONL" ) ), "{error:?}" );
+}
+
+/// Result files may come in any order; `batch_generate_content` puts them
+/// back in the order of the prompts.
+#[ test ]
+fn file_results_are_put_back_in_prompt_order()
+{
+  let lines = format!
+  (
+    "{}\n\n{}\n",
+    serde_json::json!( { "key" : "request-1", "response" : answer( "Second." ) } ),
+    serde_json::json!( { "key" : "request-0", "response" : answer( "First." ) } ),
+  );
+  let responses = BatchResponse::parse_jsonl( &lines ).expect( "one result per line" );
+  assert_eq!( responses.len(), 2 );
+  let results = BatchJobResults { job : BatchJob { name : "batches/abc".to_string(), ..Default::default() }, responses };
+  let texts : Vec< _ > = results.clone().into_request_order( 2 ).expect( "every prompt answered" ).iter()
+    .map( | response | response.candidates[ 0 ].content.parts[ 0 ].text.clone().unwrap_or_default() )
+    .collect();
+  assert_eq!( texts, [ "First.", "Second." ] );
+
+  let error = results.into_request_order( 3 ).expect_err( "a prompt is not answered" );
+  assert!( matches!( error, Error::BatchProcessingError { successful : 2, failed : 1, .. } ), "{error:?}" );
+  assert!( BatchResponse::parse_jsonl( "not json" ).is_err() );
+}
+
+#[ test ]
+fn embedding_batches_keep_the_order_of_the_texts()
+{
+  let body = serde_json::json!( { "batch" : CreateBatchEmbeddingRequest::texts( &[ "Rust", "" ] ) } );
+  let requests = &body[ "batch" ][ "inputConfig" ][ "requests" ][ "requests" ];
+  assert_eq!( requests[ 0 ][ "request" ][ "content" ][ "parts" ][ 0 ][ "text" ], "Rust" );
+  assert_eq!( requests[ 1 ][ "metadata" ][ "key" ], "text-1" );
+
+  let output = serde_json::json!
+  ({
+    "inlinedEmbedContentResponses" : { "inlinedResponses" :
+    [
+      { "response" : { "embedding" : { "values" : [ 0.1, 0.2 ] } } },
+      { "error" : { "code" : 400, "message" : "Text is empty." } },
+    ] },
+  });
+  let batch = BatchJob::from_operation( job( "BATCH_STATE_SUCCEEDED", Some( output ) ) ).expect( "describes a job" );
+  let results = batch.output.and_then( | output | output.inlined_embed_content_responses ).expect( "embeddings are inlined" );
+  assert_eq!( results.inlined_responses[ 0 ].response.as_ref().map( | response | response.embedding.values.clone() ), Some( vec![ 0.1, 0.2 ] ) );
+  assert_eq!( results.inlined_responses[ 1 ].error.as_ref().map( | error | error.code ), Some( 400 ) );
+}
+
+#[ test ]
+fn jsonl_input_has_one_keyed_request_per_line()
+{
+  let jsonl = BatchRequest::jsonl( &[ BatchRequest::new( "a", request( "One?" ) ), BatchRequest::new( "b", request( "Two?" ) ) ] )
+    .expect( "serializes" );
+  let lines : Vec< serde_json::Value > = jsonl.lines().map( | line | serde_json::from_str( line ).expect( "JSON line" ) ).collect();
+  assert_eq!( lines.len(), 2 );
+  assert_eq!( lines[ 1 ][ "key" ], "b" );
+  assert_eq!( lines[ 1 ][ "request" ][ "contents" ][ 0 ][ "parts" ][ 0 ][ "text" ], "Two?" );
+}
+
+/// A job is created and reported by the API, then cancelled and deleted
+/// rather than waited for.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_batch_job_lifecycle()
+{
+  let client = common::create_integration_client();
+  let batches = client.batches();
+  let batch = batches.create_inline( "gemini-2.5-flash", vec![ request( "Say hello." ) ] ).await.expect( "creates" );
+  assert!( batch.name.starts_with( "batches/" ), "{batch:?}" );
+
+  let fetched = batches.get( &batch.name ).await.expect( "gets" );
+  assert_eq!( fetched.name, batch.name );
+  batches.cancel( &batch.name ).await.expect( "cancels" );
+  batches.delete( &batch.name ).await.expect( "deletes" );
+}
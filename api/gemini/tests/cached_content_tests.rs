@@ -0,0 +1,142 @@
+//! Tests for context caching: the `cachedContents` resource and its use in
+//! `GenerateContentRequest::cached_content`.
+//!
+//! The request helpers and the checks made before sending are tested
+//! offline. The lifecycle test, which also extends a cache with
+//! `update_ttl`, runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::
+  {
+    cached_content_name, CachedContentResponse, Content, CreateCachedContentRequest,
+    GenerateContentRequest, Part, SystemInstruction, UpdateCachedContentRequest,
+  },
+};
+use std::time::Duration;
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyCachedContentTest0123456789abcdefg";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+fn text( role : &str, text : &str ) -> Content
+{
+  Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : role.to_string() }
+}
+
+#[ test ]
+fn request_helpers_use_the_api_formats()
+{
+  assert_eq!( cached_content_name( "abc123" ), "cachedContents/abc123" );
+  assert_eq!( cached_content_name( "cachedContents/abc123" ), "cachedContents/abc123" );
+
+  let request = CreateCachedContentRequest::new( "gemini-2.5-flash", vec![ text( "user", "A long document." ) ] )
+    .with_ttl( Duration::from_secs( 3600 ) )
+    .with_display_name( "handbook" );
+  assert_eq!( request.model, "models/gemini-2.5-flash" );
+  assert_eq!( request.ttl.as_deref(), Some( "3600s" ) );
+  assert_eq!( CreateCachedContentRequest::new( "models/gemini-2.5-flash", vec![] ).model, "models/gemini-2.5-flash" );
+  assert_eq!( CreateCachedContentRequest::new( "m", vec![] ).with_ttl( Duration::from_millis( 1500 ) ).ttl.as_deref(), Some( "1.5s" ) );
+
+  // A TTL and an expiration time are alternatives; the last one set wins.
+  let expiring = request.with_expire_time( "2030-01-01T00:00:00Z" );
+  assert_eq!( expiring.ttl, None );
+  let json = serde_json::to_value( &expiring ).expect( "serializes" );
+  assert_eq!( json[ "expireTime" ], "2030-01-01T00:00:00Z" );
+  assert_eq!( json[ "displayName" ], "handbook" );
+
+  let update = UpdateCachedContentRequest::ttl( Duration::from_secs( 600 ) );
+  assert_eq!( update.update_mask(), "ttl" );
+  assert_eq!( serde_json::to_value( &update ).expect( "serializes" ), serde_json::json!( { "ttl" : "600s" } ) );
+  assert_eq!( UpdateCachedContentRequest::expire_time( "2030-01-01T00:00:00Z" ).update_mask(), "expireTime" );
+  assert_eq!( UpdateCachedContentRequest { ttl : None, expire_time : None }.update_mask(), "" );
+
+  // `get` and `list` leave the contents out.
+  let cache : CachedContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "name" : "cachedContents/abc123",
+    "model" : "models/gemini-2.5-flash",
+    "expireTime" : "2030-01-01T00:00:00Z",
+    "usageMetadata" : { "totalTokenCount" : 4096 },
+  }) ).expect( "deserializes without contents" );
+  assert_eq!( cache.id(), "abc123" );
+  assert!( cache.contents.is_empty() );
+}
+
+/// The system instruction and tools of a cache cannot be overridden, and an
+/// update must change something; both are rejected before any request.
+#[ tokio::test ]
+async fn invalid_requests_are_rejected_before_sending()
+{
+  // Nothing listens on the discard port.
+  let client = client( "http://127.0.0.1:9".to_string() );
+
+  let request = GenerateContentRequest
+  {
+    contents : vec![ text( "user", "Summarize the handbook." ) ],
+    system_instruction : Some( SystemInstruction { role : "system".to_string(), parts : vec![] } ),
+    cached_content : Some( "cachedContents/abc123".to_string() ),
+    ..Default::default()
+  };
+  let error = client.models().by_name( "gemini-2.5-flash" ).generate_content( &request ).await.expect_err( "rejected" );
+  assert!( matches!( &error, Error::InvalidArgument( message ) if message.contains( "cachedContents/abc123" ) ), "{error:?}" );
+
+  let update = UpdateCachedContentRequest { ttl : None, expire_time : None };
+  let error = client.cached_content().update( "abc123", &update ).await.expect_err( "rejected" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+/// A cache is created, used for generation at the cached rate, extended,
+/// listed, and deleted.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_cache_lifecycle()
+{
+  let client = common::create_integration_client();
+  // Caches need at least 1024 tokens on the Flash models.
+  let handbook = "Section 7.3: every deployment is reviewed by two engineers before release. ".repeat( 120 );
+  let request = CreateCachedContentRequest::new( "gemini-2.5-flash", vec![ text( "user", &handbook ) ] )
+    .with_ttl( Duration::from_secs( 300 ) )
+    .with_display_name( "api_gemini cached content test" )
+    .with_system_instruction( text( "system", "Answer only from the handbook." ) );
+  let cache = client.cached_content().create( &request ).await.expect( "cache is created" );
+
+  let result = async
+  {
+    let fetched = client.cached_content().get( cache.id() ).await?;
+    assert_eq!( fetched.name, cache.name );
+
+    let response = client.models().by_name( "gemini-2.5-flash" )
+      .generation_request()
+      .with_cached_content( cache.id() )
+      .with_prompt( "How many engineers review a deployment?" )
+      .execute()
+      .await?;
+    let usage = response.usage_metadata.expect( "usage is reported" );
+    assert!( usage.cached_content_token_count.unwrap_or( 0 ) >= 1024, "{usage:?}" );
+
+    let extended = client.cached_content().update_ttl( &cache.name, Duration::from_secs( 600 ) ).await?;
+    assert_ne!( extended.expire_time, cache.expire_time );
+
+    let listed = client.cached_content().list( Some( 100 ), None ).await?;
+    assert!( listed.cached_contents.unwrap_or_default().iter().any( | listed | listed.name == cache.name ) );
+    Ok::< (), Error >( () )
+  }.await;
+
+  client.cached_content().delete( &cache.name ).await.expect( "cache is deleted" );
+  result.expect( "cache lifecycle succeeds" );
+}
@@ -0,0 +1,187 @@
+//! Tests for chat sessions: sending with the history, streaming, explicit
+//! truncation and summaries, and JSON persistence.
+//!
+//! Requests and recorded replies are tested offline, so no API key is
+//! needed. The memory and streaming tests run against the real API.
+
+#![ cfg( feature = "chat" ) ]
+
+use api_gemini::
+{
+  error ::Error,
+  models ::{ ChatSession, Content, FunctionResponse, Part },
+};
+use api_common::TruncationStrategy;
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+fn text( role : &str, text : &str ) -> Content
+{
+  Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : role.to_string() }
+}
+
+fn texts( session : &ChatSession ) -> Vec< String >
+{
+  session.history.iter()
+    .map( | content | format!( "{}: {}", content.role, content.parts[ 0 ].text.clone().unwrap_or_default() ) )
+    .collect()
+}
+
+fn reply( text : &str ) -> Part
+{
+  Part { text : Some( text.to_string() ), ..Default::default() }
+}
+
+#[ test ]
+fn each_request_resends_the_history()
+{
+  let mut session = ChatSession::new( "gemini-2.5-flash" ).with_system_instruction( "You are a pirate." );
+  assert!( session.record( text( "user", "Greet me." ), vec![ reply( "Ahoy." ) ] ) );
+
+  let request = session.request( &text( "user", "Name a language." ) );
+  let body = serde_json::to_value( &request ).expect( "serializes" );
+  assert_eq!( body[ "contents" ].as_array().map( Vec::len ), Some( 3 ) );
+  assert_eq!( body[ "contents" ][ 1 ][ "role" ], "model" );
+  assert_eq!( body[ "contents" ][ 2 ][ "parts" ][ 0 ][ "text" ], "Name a language." );
+  assert_eq!( body[ "systemInstruction" ][ "parts" ][ 0 ][ "text" ], "You are a pirate." );
+
+  assert!( session.record( text( "user", "Name a language." ), vec![ reply( "Arr, Rust." ) ] ) );
+  assert_eq!( texts( &session ), [ "user: Greet me.", "model: Ahoy.", "user: Name a language.", "model: Arr, Rust." ] );
+  assert_eq!( session.turn_count(), 2 );
+}
+
+/// A blocked reply, or one of thoughts only, leaves the history as it was.
+#[ test ]
+fn empty_replies_are_not_recorded()
+{
+  let mut session = ChatSession::new( "gemini-2.5-flash" ).with_history( vec![ text( "user", "Hi." ), text( "model", "Hello." ) ] );
+  let thought = Part { thought : Some( true ), ..reply( "Thinking about it." ) };
+
+  assert!( !session.record( text( "user", "First." ), Vec::new() ) );
+  assert!( !session.record( text( "user", "Second." ), vec![ thought ] ) );
+  assert_eq!( texts( &session ), [ "user: Hi.", "model: Hello." ] );
+}
+
+/// A reply streamed in many text parts is recorded as one, without its
+/// thoughts.
+#[ test ]
+fn streamed_reply_is_recorded_whole()
+{
+  let mut session = ChatSession::new( "gemini-2.5-flash" );
+  let thought = Part { thought : Some( true ), ..reply( "Plan a greeting." ) };
+
+  assert!( session.record( text( "user", "Greet me." ), vec![ thought, reply( "Hel" ), reply( "lo." ) ] ) );
+  assert_eq!( texts( &session ), [ "user: Greet me.", "model: Hello." ] );
+  assert_eq!( session.history[ 1 ].parts.len(), 1 );
+}
+
+/// Function calls and responses stay in the turn of the user message that
+/// started it.
+#[ test ]
+fn truncate_removes_whole_turns()
+{
+  let call = Content
+  {
+    parts : vec![ Part { function_response : Some( FunctionResponse { name : "weather".to_string(), response : serde_json::json!( { "sky" : "clear" } ) } ), ..Default::default() } ],
+    role : "user".to_string(),
+  };
+  let history = vec!
+  [
+    text( "user", "One." ), text( "model", "1" ),
+    text( "user", "Weather?" ), text( "model", "Calling." ), call, text( "model", "Clear." ),
+    text( "user", "Three." ), text( "model", "3" ),
+  ];
+  let mut session = ChatSession::new( "gemini-2.5-flash" ).with_history( history.clone() );
+  assert_eq!( session.turn_count(), 3 );
+  assert!( session.truncate( &TruncationStrategy::keep_last_turns( 3 ) ).expect( "truncates" ).is_empty() );
+
+  let removed = session.truncate( &TruncationStrategy::keep_last_turns( 1 ) ).expect( "truncates" );
+  assert_eq!( removed.len(), 6 );
+  assert_eq!( texts( &session ), [ "user: Three.", "model: 3" ] );
+
+  // One token per content: a budget of 6 drops the first turn only.
+  let mut session = ChatSession::new( "gemini-2.5-flash" ).with_history( history );
+  let removed = session.truncate( &TruncationStrategy::token_budget_with( 6, | _ | 1 ) ).expect( "truncates" );
+  assert_eq!( removed.len(), 2 );
+  assert_eq!( session.turn_count(), 2 );
+
+  // The last turn is kept even when it alone is over the budget.
+  session.truncate( &TruncationStrategy::token_budget( 0 ) ).expect( "truncates" );
+  assert_eq!( texts( &session ), [ "user: Three.", "model: 3" ] );
+}
+
+/// Summarized turns leave the history, and their summary is sent after the
+/// system instruction and kept with the session.
+#[ test ]
+fn summarized_turns_are_sent_as_instructions()
+{
+  let history = vec![ text( "user", "One." ), text( "model", "1" ), text( "user", "Two." ), text( "model", "2" ) ];
+  let mut session = ChatSession::new( "gemini-2.5-flash" ).with_system_instruction( "Be brief." ).with_history( history );
+  let summarize = TruncationStrategy::summarize( 1, | messages |
+  {
+    Ok( messages.iter().map( | message | message.content.as_str() ).collect::< Vec< _ > >().join( " " ) )
+  } );
+  let removed = session.truncate( &summarize ).expect( "summarizes" );
+  assert_eq!( removed.len(), 2 );
+  assert_eq!( session.summary.as_deref(), Some( "One. 1" ) );
+
+  let body = serde_json::to_value( session.request( &text( "user", "Three." ) ) ).expect( "serializes" );
+  assert_eq!( body[ "systemInstruction" ][ "parts" ][ 0 ][ "text" ], "Be brief." );
+  assert_eq!( body[ "systemInstruction" ][ "parts" ][ 1 ][ "text" ], "One. 1" );
+  assert_eq!( body[ "contents" ].as_array().map( Vec::len ), Some( 3 ) );
+  let restored = ChatSession::from_json( &session.to_json().expect( "serializes" ) ).expect( "restores" );
+  assert_eq!( restored.summary, session.summary );
+
+  // A failing summarizer leaves the session as it was.
+  let failing = TruncationStrategy::summarize( 0, | _ | Err( error_tools::untyped::format_err!( "model unavailable" ) ) );
+  let error = session.truncate( &failing ).expect_err( "summarizer fails" );
+  assert!( matches!( error, Error::ApiError( _ ) ), "{error:?}" );
+  assert_eq!( texts( &session ), [ "user: Two.", "model: 2" ] );
+}
+
+#[ test ]
+fn sessions_round_trip_through_json()
+{
+  let session = ChatSession::new( "gemini-2.5-flash" )
+    .with_system_instruction( "Be brief." )
+    .with_history( vec![ text( "user", "Hi." ), text( "model", "Hello." ) ] );
+  let json = session.to_json().expect( "serializes" );
+  let restored = ChatSession::from_json( &json ).expect( "restores" );
+  assert_eq!( restored.model, "gemini-2.5-flash" );
+  assert_eq!( texts( &restored ), texts( &session ) );
+  assert!( restored.system_instruction.is_some() );
+
+  let error = ChatSession::from_json( "[]" ).expect_err( "not a session" );
+  assert!( matches!( error, Error::DeserializationError( _ ) ), "{error:?}" );
+}
+
+/// The model answers from an earlier turn of the session.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_session_remembers_earlier_turns()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let mut session = models.by_name( "gemini-2.5-flash" ).start_chat();
+  session.send_text( &client, "My favourite colour is teal. Just say OK." ).await.expect( "answers" );
+  let answer = session.send_text( &client, "What is my favourite colour? Answer in one word." ).await.expect( "answers" );
+  assert!( answer.to_lowercase().contains( "teal" ), "{answer}" );
+  assert_eq!( session.turn_count(), 2 );
+}
+
+/// A streamed reply is recorded once the stream ends.
+#[ cfg( all( feature = "integration", feature = "streaming" ) ) ]
+#[ tokio::test ]
+async fn integration_send_stream_records_the_reply()
+{
+  use futures::StreamExt;
+
+  let client = common::create_integration_client();
+  let mut session = ChatSession::new( "gemini-2.5-flash" );
+  let chunks : Vec< _ > = session.send_stream( &client, "Say hello in one word." ).collect().await;
+  assert!( chunks.iter().all( Result::is_ok ), "{chunks:?}" );
+  assert_eq!( session.turn_count(), 1 );
+  assert_eq!( session.history.len(), 2 );
+  assert_eq!( session.history[ 1 ].role, "model" );
+}
@@ -0,0 +1,76 @@
+//! Tests for the typed code execution parts of responses.
+//!
+//! Parts are parsed and read offline; running code against the real API is
+//! covered by `code_execution_tests.rs`.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::models::{ CodeExecutionOutcome, CodeLanguage, GenerateContentResponse, Tool };
+
+#[ test ]
+fn code_execution_parts_are_typed()
+{
+  let response : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" :
+      {
+        "parts" :
+        [
+          { "text" : "Let me compute it." },
+          { "executableCode" : { "language" : "PYTHON", "code" : "import math\nprint(math.factorial(5))\n" } },
+          { "codeExecutionResult" : { "outcome" : "OUTCOME_OK", "output" : "120\n" } },
+          { "executableCode" : { "language" : "PYTHON", "code" : "print(1 / 0)\n" } },
+          { "codeExecutionResult" : { "outcome" : "OUTCOME_FAILED", "output" : "ZeroDivisionError: division by zero\n" } },
+          { "executableCode" : { "language" : "PYTHON", "code" : "print(sum(range(10)))\n" } },
+          { "codeExecutionResult" : { "outcome" : "OUTCOME_OK", "output" : "45\n" } },
+          { "text" : "5! is 120." },
+        ],
+        "role" : "model",
+      },
+    }],
+  }) ).expect( "deserializes" );
+
+  let code = response.executed_code();
+  assert_eq!( code.len(), 3 );
+  assert_eq!( code[ 0 ].language, CodeLanguage::Python );
+  assert_eq!( code[ 1 ].code, "print(1 / 0)\n" );
+
+  let outcomes : Vec< _ > = response.code_execution_results().iter().map( | result | result.outcome ).collect();
+  assert_eq!( outcomes, [ CodeExecutionOutcome::Ok, CodeExecutionOutcome::Failed, CodeExecutionOutcome::Ok ] );
+  // Only the stdout of code that succeeded is output.
+  assert_eq!( response.execution_output().as_deref(), Some( "120\n45\n" ) );
+
+  // Values unknown to this version still parse.
+  let unknown : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" :
+      {
+        "parts" :
+        [
+          { "executableCode" : { "language" : "COBOL", "code" : "DISPLAY 'HI'." } },
+          { "codeExecutionResult" : { "outcome" : "OUTCOME_NEW_IN_A_LATER_VERSION" } },
+        ],
+        "role" : "model",
+      },
+    }],
+  }) ).expect( "deserializes" );
+  assert_eq!( unknown.executed_code()[ 0 ].language, CodeLanguage::Unspecified );
+  assert_eq!( unknown.code_execution_results()[ 0 ].outcome, CodeExecutionOutcome::Unspecified );
+  assert!( unknown.execution_output().is_none() );
+
+  let plain : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" : [ { "content" : { "parts" : [ { "text" : "Hi." } ], "role" : "model" } } ],
+  }) ).expect( "deserializes" );
+  assert!( plain.executed_code().is_empty() && plain.code_execution_results().is_empty() );
+}
+
+#[ test ]
+fn code_execution_tool_uses_the_api_format()
+{
+  assert_eq!( serde_json::to_value( Tool::code_execution() ).expect( "serializes" ), serde_json::json!( { "codeExecution" : {} } ) );
+}
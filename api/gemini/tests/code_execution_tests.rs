@@ -32,6 +32,8 @@ let code_execution_config = CodeExecution {};
     function_declarations: None,
     code_execution: Some( code_execution_config ),
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: None,
   } ];
 
@@ -45,6 +47,8 @@ let code_execution_config = CodeExecution {};
         file_data: None,
         video_metadata: None,
         thought: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -55,6 +59,9 @@ let code_execution_config = CodeExecution {};
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: Some( tools ),
@@ -723,6 +723,8 @@ async fn integration_test_function_calling_real_api()
       } ] ),
       code_execution: None,
       google_search_retrieval: None,
+      google_search: None,
+      url_context: None,
       code_execution_tool: None,
     } ] ),
     ..Default::default()
@@ -0,0 +1,54 @@
+//! Tests for connection tuning and `Client::warm_up`.
+//!
+//! A closed port shows that a failed connection is an error; the warm-up
+//! test runs against the real API, whose base URL answers `HEAD /` with an
+//! error status that still counts as a warm connection.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::client::Client;
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyConnectionTest0123456789abcdefghij";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+/// Nothing listening is an error, not a silent success.
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  assert!( client( "http://127.0.0.1:1".to_string() ).warm_up().await.is_err() );
+}
+
+/// The low-latency preset rebuilds the client; a malformed proxy URL does not.
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_rebuilds_client()
+{
+  let proxy = client( "http://127.0.0.1:1".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+  assert!( proxy.is_err(), "a malformed proxy URL must be refused" );
+
+  let client = client( "http://127.0.0.1:1".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::low_latency() );
+
+  assert!( client.is_ok(), "{:?}", client.err() );
+}
+
+/// One `HEAD` to the real base URL warms the connection, whatever its status.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_warm_up_reaches_the_api()
+{
+  let client = common::create_integration_client();
+  client.warm_up().await.expect( "warm-up reaches the API" );
+}
@@ -0,0 +1,114 @@
+//! Tests for counting the tokens of a whole generation request, with its
+//! tools, system instruction, and cached content.
+//!
+//! The request bodies and the recorded counts are tested offline; the
+//! system instruction test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::{ Content, CountTokensRequest, CountTokensResponse, GenerateContentRequest, Part, Tool },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyCountTokensRequestTest0123456789ab";
+
+fn user( text : &str ) -> Content
+{
+  Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : "user".to_string() }
+}
+
+/// The generation request is sent whole, naming its model.
+#[ test ]
+fn request_body_wraps_the_whole_generation_request()
+{
+  let request = CountTokensRequest::for_request( GenerateContentRequest
+  {
+    contents : vec![ user( "Summarise chapter two." ) ],
+    tools : Some( vec![ Tool::code_execution() ] ),
+    cached_content : Some( "cachedContents/book".to_string() ),
+    ..Default::default()
+  } );
+
+  let body = request.body( "gemini-2.5-flash" ).expect( "serializes" );
+  assert!( body.get( "contents" ).is_none(), "{body}" );
+  let generation = &body[ "generateContentRequest" ];
+  assert_eq!( generation[ "model" ], "models/gemini-2.5-flash" );
+  assert_eq!( generation[ "cachedContent" ], "cachedContents/book" );
+  assert!( generation[ "tools" ][ 0 ].get( "codeExecution" ).is_some(), "{generation}" );
+  assert_eq!( generation[ "contents" ][ 0 ][ "parts" ][ 0 ][ "text" ], "Summarise chapter two." );
+}
+
+/// Counting plain contents keeps the body it had.
+#[ test ]
+fn contents_body_sends_contents_only()
+{
+  let body = CountTokensRequest::for_contents( vec![ user( "Hello there." ) ] ).body( "gemini-2.5-flash" ).expect( "serializes" );
+  assert_eq!( body, serde_json::json!( { "contents" : [ { "parts" : [ { "text" : "Hello there." } ], "role" : "user" } ] } ) );
+}
+
+/// The cached share of a count is reported, and no cache means none.
+#[ test ]
+fn counts_tell_cached_from_uncached_tokens()
+{
+  let count : CountTokensResponse = serde_json::from_str( r#"{
+    "totalTokens" : 4120,
+    "cachedContentTokenCount" : 4096,
+    "promptTokensDetails" : [ { "modality" : "TEXT", "tokenCount" : 24 } ],
+    "cacheTokensDetails" : [ { "modality" : "TEXT", "tokenCount" : 4096 } ]
+  }"# ).expect( "deserializes" );
+  assert_eq!( ( count.total_tokens, count.cached_tokens(), count.uncached_tokens() ), ( 4120, 4096, 24 ) );
+  let details = count.cache_tokens_details.expect( "cache details" );
+  assert_eq!( ( details[ 0 ].modality.as_str(), details[ 0 ].token_count ), ( "TEXT", 4096 ) );
+
+  let count : CountTokensResponse = serde_json::from_str( r#"{ "totalTokens" : 5 }"# ).expect( "deserializes" );
+  assert_eq!( ( count.cached_tokens(), count.uncached_tokens() ), ( 0, 5 ) );
+}
+
+#[ tokio::test ]
+async fn contents_and_generation_request_are_exclusive()
+{
+  let client = Client::builder().api_key( KEY.to_string() ).build().expect( "client builds" );
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+
+  let mut both = CountTokensRequest::for_request( GenerateContentRequest { contents : vec![ user( "Hi." ) ], ..Default::default() } );
+  both.contents.push( user( "Hi." ) );
+  let error = model.count_tokens( &both ).await.expect_err( "both set" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+
+  let error = model.count_tokens_for_request( &GenerateContentRequest::default() ).await.expect_err( "nothing to count" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+/// The system instruction of a request is counted with its contents.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_request_count_includes_system_instruction()
+{
+  use api_gemini::models::SystemInstruction;
+
+  let client = common::create_integration_client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let contents = vec![ user( "What is the capital of France?" ) ];
+
+  let bare = model.count_tokens( &CountTokensRequest::for_contents( contents.clone() ) ).await.expect( "counts" );
+  let request = GenerateContentRequest
+  {
+    contents,
+    system_instruction : Some( SystemInstruction
+    {
+      role : "system".to_string(),
+      parts : vec![ Part { text : Some( "You are a meticulous geography teacher who explains the history of each city.".to_string() ), ..Default::default() } ],
+    } ),
+    ..Default::default()
+  };
+  let whole = model.count_tokens_for_request( &request ).await.expect( "counts" );
+  assert!( whole.total_tokens > bare.total_tokens, "{} <= {}", whole.total_tokens, bare.total_tokens );
+}
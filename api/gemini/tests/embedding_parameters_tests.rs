@@ -0,0 +1,138 @@
+//! Tests for the embedding task type, title, and output dimensionality.
+//!
+//! The request format, the requests the builders configure, and the checks
+//! made before sending are tested offline. The dimensionality test runs
+//! against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::{ EmbedContentRequest, TaskType },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyEmbeddingParametersTest0123456789a";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+#[ test ]
+fn embedding_parameters_use_the_api_format()
+{
+  let request = EmbedContentRequest::new( "Rust is a systems programming language." )
+    .with_task_type( TaskType::RetrievalDocument )
+    .with_title( "About Rust" )
+    .with_output_dimensionality( 768 );
+  assert_eq!( serde_json::to_value( &request ).expect( "serializes" ), serde_json::json!
+  ({
+    "content" : { "parts" : [ { "text" : "Rust is a systems programming language." } ], "role" : "user" },
+    "taskType" : "RETRIEVAL_DOCUMENT",
+    "title" : "About Rust",
+    "outputDimensionality" : 768,
+  }) );
+
+  for task_type in TaskType::ALL
+  {
+    assert_eq!( task_type.as_str().parse::< TaskType >().expect( "known" ), task_type );
+    assert_eq!( serde_json::to_value( task_type ).expect( "serializes" ), task_type.as_str() );
+  }
+  assert!( "RETRIEVAL".parse::< TaskType >().is_err() );
+}
+
+/// Unknown task types, titles of other task types, and empty dimensionality
+/// are rejected before sending.
+#[ tokio::test ]
+async fn embed_content_checks_the_parameters_before_sending()
+{
+  // Nothing listens on the discard port.
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let models = client.models();
+  let model = models.by_name( "gemini-embedding-001" );
+
+  let mut unknown = EmbedContentRequest::new( "text" );
+  unknown.task_type = Some( "RETRIEVAL".to_string() );
+  for request in
+  [
+    unknown,
+    EmbedContentRequest::new( "text" ).with_title( "Untyped" ),
+    EmbedContentRequest::new( "text" ).with_task_type( TaskType::RetrievalQuery ).with_title( "A query" ),
+    EmbedContentRequest::new( "text" ).with_output_dimensionality( 0 ),
+  ]
+  {
+    let error = model.embed_content( &request ).await.expect_err( "rejected" );
+    assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+  }
+
+  let error = model.batch_embed_request()
+    .with_texts( &[ "one", "two" ] )
+    .with_task_type( TaskType::RetrievalDocument )
+    .with_titles( &[ "One" ] )
+    .execute()
+    .await
+    .expect_err( "rejected" );
+  assert!( matches!( error, Error::ValidationError { .. } ), "{error:?}" );
+}
+
+/// The single and batch builders set the parameters on every text.
+#[ test ]
+fn builders_set_the_parameters()
+{
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let models = client.models();
+  let model = models.by_name( "gemini-embedding-001" );
+
+  let query = model.embedding_request()
+    .with_text( "How do I learn Rust?" )
+    .with_task_type( TaskType::QuestionAnswering )
+    .with_output_dimensionality( 2 );
+  let query = serde_json::to_value( query.request() ).expect( "serializes" );
+  assert_eq!( query[ "taskType" ], "QUESTION_ANSWERING" );
+  assert_eq!( query[ "outputDimensionality" ], 2 );
+  assert!( query.get( "title" ).is_none() );
+
+  let documents = model.batch_embed_request()
+    .with_texts( &[ "The Rust book.", "Rust by example." ] )
+    .with_task_type( TaskType::RetrievalDocument )
+    .with_titles( &[ "Book", "Examples" ] )
+    .with_output_dimensionality( 2 )
+    .requests()
+    .expect( "one title per text" );
+  assert_eq!( documents.len(), 2 );
+  for ( request, ( text, title ) ) in documents.iter().zip( [ ( "The Rust book.", "Book" ), ( "Rust by example.", "Examples" ) ] )
+  {
+    let body = serde_json::to_value( request ).expect( "serializes" );
+    assert_eq!( body[ "content" ][ "parts" ][ 0 ][ "text" ], text );
+    assert_eq!( body[ "taskType" ], "RETRIEVAL_DOCUMENT" );
+    assert_eq!( body[ "title" ], title );
+    assert_eq!( body[ "outputDimensionality" ], 2 );
+  }
+}
+
+/// The embedding is truncated to the requested dimensionality.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_output_dimensionality()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let embedding = models.by_name( "gemini-embedding-001" )
+    .embedding_request()
+    .with_text( "How do I learn Rust?" )
+    .with_task_type( TaskType::RetrievalQuery )
+    .with_output_dimensionality( 256 )
+    .execute_vector()
+    .await
+    .expect( "embeds" );
+  assert_eq!( embedding.len(), 256 );
+}
@@ -40,6 +40,9 @@ fn test_chat_example_structure()
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: Some( vec!
     [
@@ -180,6 +183,8 @@ fn test_function_calling_structure()
     ]),
     code_execution: None,
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: None,
   }
   ];
@@ -359,6 +364,9 @@ async fn integration_test_chat_example_real_api()
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 600 ), // Increased to avoid truncation
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
     safety_settings: Some( vec!
     [
@@ -468,6 +476,8 @@ async fn integration_test_function_calling_example_real_api()
     ]),
     code_execution: None,
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: None,
   }
   ];
@@ -0,0 +1,133 @@
+//! Tests for the function calling mode configuration and for function
+//! declarations generated from Rust types.
+//!
+//! The configuration format and the generated schemas are tested offline. The
+//! forced call test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::models::{ FunctionCallingConfig, FunctionCallingMode, ToolConfig };
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+#[ test ]
+fn tool_config_uses_the_api_format()
+{
+  let json = | config : &ToolConfig | serde_json::to_value( config ).expect( "serializes" );
+  assert_eq!( json( &ToolConfig::auto() ), serde_json::json!( { "functionCallingConfig" : { "mode" : "AUTO" } } ) );
+  assert_eq!( json( &ToolConfig::none() ), serde_json::json!( { "functionCallingConfig" : { "mode" : "NONE" } } ) );
+  assert_eq!
+  (
+    json( &ToolConfig::any().with_allowed_function_names( [ "get_weather", "calculate" ] ) ),
+    serde_json::json!( { "functionCallingConfig" : { "mode" : "ANY", "allowedFunctionNames" : [ "get_weather", "calculate" ] } } ),
+  );
+
+  // Allowed names alone imply ANY; a mode given first is kept.
+  let config = ToolConfig::default().with_allowed_function_names( vec![ "calculate".to_string() ] );
+  assert_eq!( config.function_calling_config.as_ref().map( | config | config.mode ), Some( FunctionCallingMode::Any ) );
+  let config = ToolConfig::function_calling( FunctionCallingConfig::new( FunctionCallingMode::Auto ).with_allowed_function_names( [ "calculate" ] ) );
+  assert_eq!( config.function_calling_config.as_ref().map( | config | config.mode ), Some( FunctionCallingMode::Auto ) );
+  assert_eq!( json( &ToolConfig::default() ), serde_json::json!( {} ) );
+}
+
+#[ cfg( feature = "schemars" ) ]
+mod from_schema
+{
+  use api_gemini::models::FunctionDeclaration;
+  use schemars::JsonSchema;
+
+  /// Temperature unit of a forecast.
+  #[ derive( JsonSchema ) ]
+  #[ allow( dead_code ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  enum TemperatureUnit
+  {
+    Celsius,
+    Fahrenheit,
+  }
+
+  /// Get the weather forecast of a city.
+  #[ derive( JsonSchema ) ]
+  #[ allow( dead_code ) ]
+  struct GetForecast
+  {
+    /// City name, e.g. "Tokyo".
+    location : String,
+    /// Unit of the temperatures.
+    unit : Option< TemperatureUnit >,
+    /// Number of days to forecast.
+    #[ schemars( range( min = 1, max = 7 ) ) ]
+    days : u8,
+    /// Extra details to include.
+    details : Vec< String >,
+    /// Free-form options.
+    options : std::collections::HashMap< String, String >,
+  }
+
+  #[ test ]
+  fn declarations_are_generated_in_the_api_schema_subset()
+  {
+    let declaration = FunctionDeclaration::from_schema::< GetForecast >();
+    assert_eq!( declaration.name, "get_forecast" );
+    assert_eq!( declaration.description, "Get the weather forecast of a city." );
+
+    let parameters = declaration.parameters.expect( "parameters" );
+    assert_eq!( parameters[ "type" ], "object" );
+    assert_eq!( parameters[ "required" ], serde_json::json!( [ "days", "details", "location", "options" ] ) );
+    let properties = &parameters[ "properties" ];
+    assert_eq!( properties[ "location" ], serde_json::json!( { "type" : "string", "description" : "City name, e.g. \"Tokyo\"." } ) );
+    // The enum is inlined rather than referenced, and nullable as an `Option`.
+    assert_eq!( properties[ "unit" ][ "enum" ], serde_json::json!( [ "celsius", "fahrenheit" ] ) );
+    assert_eq!( properties[ "unit" ][ "nullable" ], true );
+    assert!( properties[ "unit" ].get( "allOf" ).is_none() );
+    assert_eq!( properties[ "unit" ][ "description" ], "Unit of the temperatures." );
+    // `uint8` is not a format the API accepts; the range is kept.
+    assert_eq!( properties[ "days" ], serde_json::json!( { "type" : "integer", "description" : "Number of days to forecast.", "minimum" : 1.0, "maximum" : 7.0 } ) );
+    assert_eq!( properties[ "details" ][ "items" ], serde_json::json!( { "type" : "string" } ) );
+    assert!( properties[ "options" ].get( "additionalProperties" ).is_none() );
+
+    let json = serde_json::to_string( &parameters ).expect( "serializes" );
+    for unsupported in [ "$schema", "$ref", "definitions", "title", "additionalProperties" ]
+    {
+      assert!( !json.contains( unsupported ), "{unsupported} in {json}" );
+    }
+  }
+}
+
+/// In ANY mode limited to one function, the model calls it even when it
+/// could answer in text.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_any_mode_forces_the_allowed_function()
+{
+  use api_gemini::models::{ FunctionDeclaration, Tool };
+
+  let calculate = FunctionDeclaration
+  {
+    name : "calculate".to_string(),
+    description : "Evaluate an arithmetic expression.".to_string(),
+    parameters : Some( serde_json::json!
+    ({
+      "type" : "object",
+      "properties" : { "expression" : { "type" : "string" } },
+      "required" : [ "expression" ],
+    }) ),
+  };
+  let greet = FunctionDeclaration { name : "greet".to_string(), description : "Greet the user.".to_string(), parameters : None };
+
+  let client = common::create_integration_client();
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash" )
+    .generation_request()
+    .with_prompt( "Hello there! What is two plus two?" )
+    .with_tool( Tool::functions( vec![ calculate, greet ] ) )
+    .with_tool_config( ToolConfig::any().with_allowed_function_names( [ "calculate" ] ) )
+    .execute()
+    .await
+    .expect( "generation succeeds" );
+
+  let calls : Vec< _ > = response.candidates[ 0 ].content.parts.iter().filter_map( | part | part.function_call.as_ref() ).collect();
+  assert!( !calls.is_empty(), "{response:?}" );
+  assert!( calls.iter().all( | call | call.name == "calculate" ), "{calls:?}" );
+}
@@ -0,0 +1,102 @@
+//! Tests for Google Search and URL context tools and grounding metadata.
+//!
+//! The tool format and the grounding accessors are tested offline. The search
+//! test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::models::{ DynamicRetrievalConfig, GenerateContentResponse, Tool };
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+#[ test ]
+fn tools_use_the_api_format()
+{
+  assert_eq!( serde_json::to_value( Tool::google_search() ).expect( "serializes" ), serde_json::json!( { "googleSearch" : {} } ) );
+  assert_eq!( serde_json::to_value( Tool::url_context() ).expect( "serializes" ), serde_json::json!( { "urlContext" : {} } ) );
+  assert_eq!( serde_json::to_value( Tool::google_search_retrieval( None ) ).expect( "serializes" ), serde_json::json!( { "googleSearchRetrieval" : {} } ) );
+  assert_eq!
+  (
+    serde_json::to_value( Tool::google_search_retrieval( Some( DynamicRetrievalConfig::dynamic( 0.5 ) ) ) ).expect( "serializes" ),
+    serde_json::json!( { "googleSearchRetrieval" : { "dynamicRetrievalConfig" : { "mode" : "MODE_DYNAMIC", "dynamicThreshold" : 0.5 } } } ),
+  );
+}
+
+#[ test ]
+fn grounding_metadata_gives_queries_sources_and_citations()
+{
+  let response : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" : { "parts" : [ { "text" : "Spain won Euro 2024. The final was in Berlin." } ], "role" : "model" },
+      "finishReason" : "STOP",
+      "groundingMetadata" :
+      {
+        "webSearchQueries" : [ "euro 2024 winner" ],
+        "groundingChunks" :
+        [
+          { "web" : { "uri" : "https://vertexaisearch.cloud.google.com/grounding-api-redirect/a", "title" : "uefa.com" } },
+          { "web" : { "uri" : "https://vertexaisearch.cloud.google.com/grounding-api-redirect/b", "title" : "wikipedia.org" } },
+        ],
+        "groundingSupports" :
+        [
+          { "segment" : { "endIndex" : 20, "text" : "Spain won Euro 2024." }, "groundingChunkIndices" : [ 0, 1 ], "confidenceScores" : [ 0.9, 0.8 ] },
+          { "segment" : { "startIndex" : 21, "endIndex" : 46, "text" : "The final was in Berlin." }, "groundingChunkIndices" : [ 1, 7 ] },
+        ],
+        "searchEntryPoint" : { "renderedContent" : "<div></div>" },
+      },
+      "urlContextMetadata" :
+      {
+        "urlMetadata" : [ { "retrievedUrl" : "https://example.com/", "urlRetrievalStatus" : "URL_RETRIEVAL_STATUS_SUCCESS" } ],
+      },
+    }],
+  }) ).expect( "deserializes" );
+
+  let grounding = response.grounding().expect( "grounded" );
+  assert_eq!( grounding.search_queries(), [ "euro 2024 winner" ] );
+  assert_eq!( grounding.chunks().iter().map( | chunk | chunk.title() ).collect::< Vec< _ > >(), [ Some( "uefa.com" ), Some( "wikipedia.org" ) ] );
+
+  let citations = grounding.citations();
+  assert_eq!( citations.len(), 2 );
+  assert_eq!( ( citations[ 0 ].text, citations[ 0 ].start_index, citations[ 0 ].end_index ), ( "Spain won Euro 2024.", 0, 20 ) );
+  assert_eq!( citations[ 0 ].sources.len(), 2 );
+  // A chunk index out of range is skipped rather than failing.
+  assert_eq!( citations[ 1 ].sources.iter().map( | source | source.uri() ).collect::< Vec< _ > >(), [ Some( "https://vertexaisearch.cloud.google.com/grounding-api-redirect/b" ) ] );
+
+  let urls = response.url_context();
+  assert_eq!( urls.len(), 1 );
+  assert_eq!( urls[ 0 ].retrieved_url, "https://example.com/" );
+  assert_eq!( urls[ 0 ].url_retrieval_status.as_deref(), Some( "URL_RETRIEVAL_STATUS_SUCCESS" ) );
+
+  // Without grounding there are no sources, queries, or URLs.
+  let plain : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" : [ { "content" : { "parts" : [ { "text" : "Hi." } ], "role" : "model" } } ],
+  }) ).expect( "deserializes" );
+  assert!( plain.grounding().is_none() );
+  assert!( plain.url_context().is_empty() );
+  let empty = api_gemini::models::GroundingMetadata::default();
+  assert!( empty.search_queries().is_empty() && empty.chunks().is_empty() && empty.citations().is_empty() );
+}
+
+/// A question about a recent event is answered from Google Search, with sources.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_google_search_grounding()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash" )
+    .generation_request()
+    .with_prompt( "Who won the most recent UEFA European Championship? Search the web." )
+    .with_tool( Tool::google_search() )
+    .execute()
+    .await
+    .expect( "generation succeeds" );
+
+  let grounding = response.grounding().expect( "grounding metadata" );
+  assert!( !grounding.search_queries().is_empty(), "{grounding:?}" );
+  assert!( grounding.chunks().iter().any( | chunk | chunk.uri().is_some() ), "{grounding:?}" );
+}
@@ -0,0 +1,105 @@
+//! Tests for Imagen image generation through `:predict`.
+//!
+//! The request format, the decoding of recorded predictions, and the checks
+//! made before sending are tested offline. The generation test runs against
+//! the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::{ AspectRatio, GenerateImagesRequest, GenerateImagesResponse, PersonGeneration },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyImageGenerationTest0123456789abcde";
+
+/// One pixel PNG, base64-encoded.
+const PIXEL : &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+#[ test ]
+fn image_types_use_the_api_formats()
+{
+  let request = GenerateImagesRequest::new( "A red bicycle" )
+    .with_number_of_images( 2 )
+    .with_aspect_ratio( AspectRatio::Widescreen )
+    .with_person_generation( PersonGeneration::DontAllow );
+  assert_eq!( serde_json::to_value( &request ).expect( "serializes" ), serde_json::json!
+  ({
+    "instances" : [ { "prompt" : "A red bicycle" } ],
+    "parameters" : { "sampleCount" : 2, "aspectRatio" : "16:9", "personGeneration" : "dont_allow" },
+  }) );
+  // Unset parameters are left to the API defaults.
+  assert_eq!( serde_json::to_value( GenerateImagesRequest::new( "x" ) ).expect( "serializes" )[ "parameters" ], serde_json::json!( {} ) );
+
+  let response : GenerateImagesResponse = serde_json::from_value( serde_json::json!
+  ({
+    "predictions" :
+    [
+      { "bytesBase64Encoded" : PIXEL, "mimeType" : "image/png" },
+      { "raiFilteredReason" : "The image was filtered for safety." },
+    ],
+  }) ).expect( "deserializes" );
+  let images = response.images().expect( "images decode" );
+  assert_eq!( images.len(), 1 );
+  assert_eq!( images[ 0 ].mime_type, "image/png" );
+  assert!( images[ 0 ].bytes.starts_with( b"\x89PNG" ) );
+  assert_eq!( response.filtered_reasons(), [ "The image was filtered for safety." ] );
+
+  let corrupt : GenerateImagesResponse = serde_json::from_value( serde_json::json!( { "predictions" : [ { "bytesBase64Encoded" : "not base64!" } ] } ) )
+    .expect( "deserializes" );
+  assert!( matches!( corrupt.images(), Err( Error::DeserializationError( _ ) ) ) );
+}
+
+/// A request needs one prompt and at most four images.
+#[ tokio::test ]
+async fn generate_images_checks_the_request_before_sending()
+{
+  // Nothing listens on the discard port.
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let models = client.models();
+  let imagen = models.by_name( "imagen-4.0-generate-001" );
+
+  let mut two_prompts = GenerateImagesRequest::new( "a cat" );
+  two_prompts.instances.push( two_prompts.instances[ 0 ].clone() );
+  for request in
+  [
+    GenerateImagesRequest::new( "  " ),
+    two_prompts,
+    GenerateImagesRequest::new( "a cat" ).with_number_of_images( 0 ),
+    GenerateImagesRequest::new( "a cat" ).with_number_of_images( 5 ),
+  ]
+  {
+    let error = imagen.generate_images( &request ).await.expect_err( "rejected" );
+    assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+  }
+}
+
+/// Imagen generates a decodable image.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_generate_images()
+{
+  let client = common::create_integration_client();
+  let request = GenerateImagesRequest::new( "A watercolor painting of a lighthouse on a cliff" )
+    .with_number_of_images( 1 )
+    .with_aspect_ratio( AspectRatio::Square )
+    .with_person_generation( PersonGeneration::DontAllow );
+  let images = client.models().by_name( "imagen-4.0-generate-001" ).generate_images( &request ).await.expect( "images are generated" );
+  assert_eq!( images.len(), 1 );
+  assert!( images[ 0 ].mime_type.starts_with( "image/" ), "{}", images[ 0 ].mime_type );
+  assert!( !images[ 0 ].bytes.is_empty() );
+}
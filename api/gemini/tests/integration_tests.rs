@@ -198,6 +198,9 @@ async fn test_generate_content_with_parameters()
       top_p: Some( 0.8 ),
       max_output_tokens: Some( 500 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
       candidate_count: None,
     }),
     safety_settings: None,
@@ -314,6 +317,8 @@ async fn test_generate_content_function_calling()
     ]),
     code_execution: None,
     google_search_retrieval: None,
+    google_search: None,
+    url_context: None,
     code_execution_tool: None,
   }
   ];
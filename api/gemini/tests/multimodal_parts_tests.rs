@@ -0,0 +1,145 @@
+//! Tests for building multimodal parts from bytes, files, and file URIs.
+//!
+//! Files are written to the temporary directory. The image description test
+//! runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::{ error::Error, models::Part };
+use base64::{ engine::general_purpose::STANDARD, Engine as _ };
+use std::path::PathBuf;
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+/// A 16 by 16 pixel red PNG.
+const RED_PNG : &[ u8 ] = &
+[
+  0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+  0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90, 0x91, 0x68,
+  0x36, 0x00, 0x00, 0x00, 0x16, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0xf8, 0xcf, 0xc0, 0x40,
+  0x12, 0x62, 0x18, 0xd5, 0x30, 0xaa, 0x61, 0xf8, 0x6a, 0x00, 0x00, 0x90, 0xf9, 0xff, 0x01, 0xf2,
+  0xee, 0xe8, 0x57, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+/// Writes `bytes` to a file of this test run named `name`.
+fn file( name : &str, bytes : &[ u8 ] ) -> PathBuf
+{
+  let path = std::env::temp_dir().join( format!( "api_gemini_parts_{}_{name}", std::process::id() ) );
+  std::fs::write( &path, bytes ).expect( "temporary file must be writable" );
+  path
+}
+
+fn inline_mime_type( part : &Part ) -> &str
+{
+  part.inline_data.as_ref().map_or( "", | blob | blob.mime_type.as_str() )
+}
+
+#[ test ]
+fn from_bytes_encodes_inline_data()
+{
+  let part = Part::from_bytes( "image/png", RED_PNG ).expect( "small enough" );
+  let blob = part.inline_data.as_ref().expect( "inline data" );
+  assert_eq!( blob.mime_type, "image/png" );
+  assert_eq!( blob.data, STANDARD.encode( RED_PNG ) );
+  assert_eq!( blob.decode().expect( "base64" ), RED_PNG );
+
+  let error = Part::from_bytes( "image/png", &[] ).expect_err( "empty" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+  let error = Part::from_bytes( "", RED_PNG ).expect_err( "no MIME type" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+#[ test ]
+fn from_bytes_rejects_data_over_the_inline_limit()
+{
+  let bytes = vec![ 0_u8; Part::MAX_INLINE_BYTES + 1 ];
+  let error = Part::from_bytes( "application/pdf", &bytes ).expect_err( "too large" );
+  assert!( matches!( &error, Error::InvalidArgument( message ) if message.contains( "Files API" ) ), "{error:?}" );
+}
+
+/// The content decides over a misleading extension; the extension is used
+/// for types without a signature.
+#[ test ]
+fn from_path_detects_the_mime_type()
+{
+  let cases : [ ( &str, &[ u8 ], &str ); 5 ] =
+  [
+    ( "red.jpg", RED_PNG, "image/png" ),
+    ( "doc.bin", b"%PDF-1.7\n%...", "application/pdf" ),
+    ( "sound.dat", b"RIFF\x24\x00\x00\x00WAVEfmt ", "audio/wav" ),
+    ( "clip.bin", b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00", "video/mp4" ),
+    ( "notes.MD", b"# Notes\n", "text/markdown" ),
+  ];
+  for ( name, bytes, expected ) in cases
+  {
+    let path = file( name, bytes );
+    let part = Part::from_path( &path ).expect( "readable" );
+    std::fs::remove_file( &path ).ok();
+    assert_eq!( inline_mime_type( &part ), expected, "{name}" );
+  }
+}
+
+#[ test ]
+fn from_path_reports_unknown_and_missing_files()
+{
+  let path = file( "data.xyz", b"\x01\x02\x03" );
+  let error = Part::from_path( &path ).expect_err( "unknown type" );
+  std::fs::remove_file( &path ).ok();
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+
+  let error = Part::from_path( std::env::temp_dir().join( "api_gemini_parts_missing.png" ) ).expect_err( "missing" );
+  assert!( matches!( error, Error::Io( _ ) ), "{error:?}" );
+}
+
+#[ test ]
+fn from_image_path_accepts_only_images()
+{
+  let image = file( "image.png", RED_PNG );
+  let part = Part::from_image_path( &image ).expect( "an image" );
+  std::fs::remove_file( &image ).ok();
+  assert_eq!( inline_mime_type( &part ), "image/png" );
+
+  let pdf = file( "image.pdf", b"%PDF-1.7\n" );
+  let error = Part::from_image_path( &pdf ).expect_err( "not an image" );
+  std::fs::remove_file( &pdf ).ok();
+  assert!( matches!( &error, Error::InvalidArgument( message ) if message.contains( "application/pdf" ) ), "{error:?}" );
+}
+
+#[ test ]
+fn from_file_uri_references_uploaded_files()
+{
+  let part = Part::from_file_uri( "https://generativelanguage.googleapis.com/v1beta/files/abc", "video/mp4" ).expect( "valid" );
+  assert_eq!
+  (
+    serde_json::to_value( &part ).expect( "serializes" ),
+    serde_json::json!( { "fileData" : { "uri" : "https://generativelanguage.googleapis.com/v1beta/files/abc", "mimeType" : "video/mp4" } } ),
+  );
+  assert!( Part::from_file_uri( "", "video/mp4" ).is_err() );
+}
+
+/// The model sees an image read from a file.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_image_from_path_is_described()
+{
+  use api_gemini::models::{ Content, GenerateContentRequest };
+
+  let client = common::create_integration_client();
+  let path = file( "integration.png", RED_PNG );
+  let image = Part::from_image_path( &path ).expect( "an image" );
+  std::fs::remove_file( &path ).ok();
+  let request = GenerateContentRequest
+  {
+    contents : vec![ Content
+    {
+      parts : vec![ image, Part { text : Some( "What colour is this image? Answer in one word.".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  };
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash" ).generate_content( &request ).await.expect( "generates" );
+  let text : String = response.candidates[ 0 ].content.parts.iter().filter_map( | part | part.text.as_deref() ).collect();
+  assert!( text.to_lowercase().contains( "red" ), "{text}" );
+}
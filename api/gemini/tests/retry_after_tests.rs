@@ -0,0 +1,132 @@
+//! Tests for retry decisions made from the status of error responses, and for
+//! the wait a rate limited response asks for.
+//!
+//! Error responses are classified from recorded bodies and headers; the
+//! retry delay and the retry decision are computed from the failures they
+//! give, so no API key is needed.
+
+#![ cfg( feature = "retry" ) ]
+
+use api_gemini::
+{
+  error ::Error,
+  internal ::http::{ self, HttpFailure, RetryConfig },
+};
+use core::time::Duration;
+
+/// A 429 body as the API sends it, asking to wait `delay`.
+fn rate_limited( delay : &str ) -> String
+{
+  serde_json::json!
+  ({
+    "error" :
+    {
+      "code" : 429,
+      "message" : "You exceeded your current quota.",
+      "status" : "RESOURCE_EXHAUSTED",
+      "details" :
+      [
+        { "@type" : "type.googleapis.com/google.rpc.QuotaFailure", "violations" : [] },
+        { "@type" : "type.googleapis.com/google.rpc.RetryInfo", "retryDelay" : delay },
+      ],
+    },
+  }).to_string()
+}
+
+/// Retries quickly unless the server asks otherwise.
+fn config() -> RetryConfig
+{
+  RetryConfig
+  {
+    max_retries : 2,
+    base_delay : Duration::from_millis( 10 ),
+    max_delay : Duration::from_millis( 20 ),
+    backoff_multiplier : 2.0,
+    enable_jitter : false,
+    max_elapsed_time : None,
+    call_budget : None,
+  }
+}
+
+#[ test ]
+fn error_responses_keep_status_code_and_retry_delay()
+{
+  let failure = http::classify_error_response( 429, None, &rate_limited( "26s" ) );
+  assert!( matches!( failure.error, Error::RateLimitError( _ ) ), "{failure:?}" );
+  assert_eq!( failure.status, Some( 429 ) );
+  assert_eq!( failure.code.as_deref(), Some( "RESOURCE_EXHAUSTED" ) );
+  assert_eq!( failure.retry_after, Some( Duration::from_secs( 26 ) ) );
+  assert!( http::is_retryable_failure( &failure ) );
+
+  // `RetryInfo` wins over the header.
+  let failure = http::classify_error_response( 429, Some( Duration::from_secs( 3 ) ), &rate_limited( "0.4s" ) );
+  assert_eq!( failure.retry_after, Some( Duration::from_millis( 400 ) ) );
+
+  // A plain text body keeps the status, and the header gives the wait.
+  let failure = http::classify_error_response( 503, Some( Duration::from_secs( 7 ) ), "upstream unavailable" );
+  assert!( matches!( failure.error, Error::ServerError( _ ) ), "{failure:?}" );
+  assert_eq!( ( failure.status, failure.code ), ( Some( 503 ), None ) );
+  assert_eq!( failure.retry_after, Some( Duration::from_secs( 7 ) ) );
+}
+
+#[ test ]
+fn retry_after_header_is_read_in_seconds()
+{
+  let headers = | value : &'static str | -> reqwest::header::HeaderMap
+  {
+    [ ( reqwest::header::RETRY_AFTER, reqwest::header::HeaderValue::from_static( value ) ) ].into_iter().collect()
+  };
+  assert_eq!( http::retry_after_header( &headers( "7" ) ), Some( Duration::from_secs( 7 ) ) );
+  assert_eq!( http::retry_after_header( &headers( "1.5" ) ), Some( Duration::from_millis( 1500 ) ) );
+  // HTTP dates and negative waits are not read.
+  assert_eq!( http::retry_after_header( &headers( "Wed, 21 Oct 2015 07:28:00 GMT" ) ), None );
+  assert_eq!( http::retry_after_header( &headers( "-1" ) ), None );
+  assert_eq!( http::retry_after_header( &reqwest::header::HeaderMap::new() ), None );
+}
+
+/// The next attempt waits for the delay the response asked for, well past
+/// the backoff.
+#[ test ]
+fn requested_delay_wins_over_a_shorter_backoff()
+{
+  let failure = http::classify_error_response( 429, None, &rate_limited( "0.015s" ) );
+  assert_eq!( http::failure_retry_delay( 1, &config(), &failure ), Some( Duration::from_millis( 15 ) ) );
+
+  // Without a requested delay, the backoff applies.
+  let failure = http::classify_error_response( 503, None, r#"{"error":{"code":503,"message":"The model is overloaded.","status":"UNAVAILABLE"}}"# );
+  assert_eq!( http::failure_retry_delay( 1, &config(), &failure ), Some( Duration::from_millis( 10 ) ) );
+  assert_eq!( http::failure_retry_delay( 2, &config(), &failure ), Some( Duration::from_millis( 20 ) ) );
+}
+
+/// A requested delay longer than `max_delay` ends the call with the failure
+/// instead of retrying sooner than the server asked.
+#[ test ]
+fn requested_delay_past_the_max_delay_is_not_retried()
+{
+  let failure = http::classify_error_response( 429, None, &rate_limited( "26s" ) );
+  assert_eq!( http::failure_retry_delay( 1, &config(), &failure ), None );
+
+  let failure = http::classify_error_response( 503, Some( Duration::from_secs( 120 ) ), "upstream unavailable" );
+  assert_eq!( http::failure_retry_delay( 1, &config(), &failure ), None );
+
+  // A wait of exactly `max_delay` is still honoured.
+  let failure = http::classify_error_response( 429, None, &rate_limited( "0.02s" ) );
+  assert_eq!( http::failure_retry_delay( 1, &config(), &failure ), Some( Duration::from_millis( 20 ) ) );
+}
+
+/// Rate limits, timeouts, and server errors are retried by status; client
+/// errors are not.
+#[ test ]
+fn failures_are_retried_by_status()
+{
+  let status = | code : u16, body : &str | http::is_retryable_failure( &http::classify_error_response( code, None, body ) );
+  assert!( status( 503, r#"{"error":{"code":503,"message":"The model is overloaded.","status":"UNAVAILABLE"}}"# ) );
+  assert!( status( 429, r#"{"error":{"code":429,"message":"Slow down.","status":"RESOURCE_EXHAUSTED"}}"# ) );
+  assert!( status( 408, "request timeout" ) );
+  assert!( !status( 400, r#"{"error":{"code":400,"message":"Invalid value at 'contents'.","status":"INVALID_ARGUMENT"}}"# ) );
+  assert!( !status( 404, "not found" ) );
+
+  // Without a response, the error decides.
+  assert!( http::is_retryable_failure( &HttpFailure::from( Error::NetworkError( "connection reset".to_string() ) ) ) );
+  assert!( !http::is_retryable_failure( &HttpFailure::from( Error::InvalidArgument( "bad".to_string() ) ) ) );
+}
@@ -58,6 +58,9 @@ mod integration_tests
         candidate_count: Some( 1 ),
         max_output_tokens: Some( 800 ),
         stop_sequences: None,
+        response_modalities: None,
+        speech_config: None,
+        thinking_config: None,
       }),
     };
 
@@ -511,6 +511,9 @@ async fn test_integration_with_existing_safety_settings() -> Result< (), Box< dy
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 800 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     }),
   };
 
@@ -0,0 +1,116 @@
+//! Tests for typed safety settings and safety feedback inspection.
+//!
+//! Settings are built and responses inspected offline. The blocking test runs
+//! against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::models::
+{
+  GenerateContentResponse, HarmBlockThreshold, HarmCategory, HarmProbability, SafetySetting, SafetySettingsBuilder,
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+#[ test ]
+fn builder_gives_one_threshold_per_category()
+{
+  let settings = SafetySettingsBuilder::new()
+    .threshold( HarmCategory::Harassment, HarmBlockThreshold::BlockLowAndAbove )
+    .threshold( HarmCategory::DangerousContent, HarmBlockThreshold::BlockNone )
+    .threshold( HarmCategory::Harassment, HarmBlockThreshold::BlockOnlyHigh )
+    .build();
+  assert_eq!( serde_json::to_value( &settings ).expect( "serializes" ), serde_json::json!
+  ([
+    { "category" : "HARM_CATEGORY_HARASSMENT", "threshold" : "BLOCK_ONLY_HIGH" },
+    { "category" : "HARM_CATEGORY_DANGEROUS_CONTENT", "threshold" : "BLOCK_NONE" },
+  ]) );
+
+  // A later `all` overrides every category, and later thresholds override it.
+  let settings = SafetySettingsBuilder::new()
+    .threshold( HarmCategory::HateSpeech, HarmBlockThreshold::Off )
+    .all( HarmBlockThreshold::BlockMediumAndAbove )
+    .threshold( HarmCategory::CivicIntegrity, HarmBlockThreshold::BlockLowAndAbove )
+    .build();
+  assert_eq!( settings.len(), HarmCategory::ALL.len() );
+  let threshold = | category : HarmCategory | settings.iter().find( | setting | setting.category == category.as_str() ).map( | setting | setting.threshold.as_str() );
+  assert_eq!( threshold( HarmCategory::HateSpeech ), Some( "BLOCK_MEDIUM_AND_ABOVE" ) );
+  assert_eq!( threshold( HarmCategory::CivicIntegrity ), Some( "BLOCK_LOW_AND_ABOVE" ) );
+
+  let setting = SafetySetting::new( HarmCategory::SexuallyExplicit, HarmBlockThreshold::BlockOnlyHigh );
+  assert_eq!( ( setting.category.as_str(), setting.threshold.as_str() ), ( "HARM_CATEGORY_SEXUALLY_EXPLICIT", "BLOCK_ONLY_HIGH" ) );
+  assert_eq!( "HARM_CATEGORY_HATE_SPEECH".parse::< HarmCategory >().expect( "known" ), HarmCategory::HateSpeech );
+  assert!( "HARM_CATEGORY_UNSPECIFIED".parse::< HarmCategory >().is_err() );
+}
+
+#[ test ]
+fn responses_tell_what_was_blocked()
+{
+  let blocked_candidate : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" : { "parts" : [], "role" : "model" },
+      "finishReason" : "SAFETY",
+      "safetyRatings" :
+      [
+        { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "LOW" },
+        { "category" : "HARM_CATEGORY_DANGEROUS_CONTENT", "probability" : "HIGH", "blocked" : true },
+        { "category" : "HARM_CATEGORY_NEW_IN_A_LATER_VERSION", "probability" : "MEDIUM", "blocked" : true },
+      ],
+    }],
+  }) ).expect( "deserializes" );
+  assert!( blocked_candidate.was_blocked() );
+  assert_eq!( blocked_candidate.blocked_categories(), [ HarmCategory::DangerousContent ] );
+  assert_eq!( blocked_candidate.max_severity(), Some( HarmProbability::High ) );
+
+  let blocked_prompt : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" : [],
+    "promptFeedback" :
+    {
+      "blockReason" : "SAFETY",
+      "safetyRatings" :
+      [
+        { "category" : "HARM_CATEGORY_HATE_SPEECH", "probability" : "MEDIUM", "blocked" : true },
+        { "category" : "HARM_CATEGORY_HATE_SPEECH", "probability" : "MEDIUM", "blocked" : true },
+      ],
+    },
+  }) ).expect( "deserializes" );
+  assert!( blocked_prompt.was_blocked() );
+  assert_eq!( blocked_prompt.blocked_categories(), [ HarmCategory::HateSpeech ] );
+  assert_eq!( blocked_prompt.max_severity(), Some( HarmProbability::Medium ) );
+
+  let answered : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" : { "parts" : [ { "text" : "Hi." } ], "role" : "model" },
+      "finishReason" : "STOP",
+      "safetyRatings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "NEGLIGIBLE" } ],
+    }],
+  }) ).expect( "deserializes" );
+  assert!( !answered.was_blocked() );
+  assert!( answered.blocked_categories().is_empty() );
+  assert_eq!( answered.max_severity(), Some( HarmProbability::Negligible ) );
+}
+
+/// Strict settings let a harmless prompt through, with its ratings readable.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_strict_safety_settings()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash" )
+    .generation_request()
+    .with_prompt( "Write one sentence about tending a vegetable garden." )
+    .with_safety_settings( SafetySettingsBuilder::new().all( HarmBlockThreshold::BlockLowAndAbove ).build() )
+    .execute()
+    .await
+    .expect( "generation succeeds" );
+
+  assert!( !response.was_blocked(), "{response:?}" );
+  assert!( !matches!( response.max_severity(), Some( HarmProbability::Medium | HarmProbability::High ) ), "{response:?}" );
+}
@@ -0,0 +1,182 @@
+//! Tests for Semantic Retrieval: corpora, documents, chunks, corpus queries,
+//! and `generateAnswer`.
+//!
+//! Wire formats and the checks made before sending are tested offline. The
+//! lifecycle test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::
+  {
+    BatchChunksResponse, BatchCreateChunksRequest, Chunk, Content, CustomMetadata, GenerateAnswerRequest,
+    GenerateAnswerResponse, GroundingPassage, GroundingPassages, MetadataCondition, MetadataFilter, Part,
+    QueryCorpusRequest, QueryCorpusResponse, SemanticRetrieverConfig,
+  },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSySemanticRetrievalTest0123456789abc";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+fn text( role : &str, text : &str ) -> Content
+{
+  Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : role.to_string() }
+}
+
+#[ test ]
+fn retrieval_types_use_the_api_formats()
+{
+  let mut chunk = Chunk::text( "Deployments are reviewed by two engineers." );
+  chunk.custom_metadata = Some( vec![ CustomMetadata { key : "section".to_string(), numeric_value : Some( 7.0 ), ..Default::default() } ] );
+  // A chunk without a name leaves naming to the API.
+  assert_eq!( serde_json::to_value( &chunk ).expect( "serializes" ), serde_json::json!
+  ({
+    "data" : { "stringValue" : "Deployments are reviewed by two engineers." },
+    "customMetadata" : [ { "key" : "section", "numericValue" : 7.0 } ],
+  }) );
+
+  let query = QueryCorpusRequest
+  {
+    query : "Who reviews deployments?".to_string(),
+    metadata_filters : Some( vec![ MetadataFilter
+    {
+      key : "chunk.custom_metadata.section".to_string(),
+      conditions : vec![ MetadataCondition { operation : "EQUAL".to_string(), numeric_value : Some( 7.0 ), ..Default::default() } ],
+    } ] ),
+    results_count : Some( 3 ),
+  };
+  let json = serde_json::to_value( &query ).expect( "serializes" );
+  assert_eq!( json[ "metadataFilters" ][ 0 ][ "conditions" ][ 0 ][ "operation" ], "EQUAL" );
+  assert_eq!( json[ "resultsCount" ], 3 );
+
+  let response : QueryCorpusResponse = serde_json::from_value( serde_json::json!
+  ({
+    "relevantChunks" : [ { "chunkRelevanceScore" : 0.82, "chunk" : { "name" : "corpora/c/documents/d/chunks/k", "data" : { "stringValue" : "two engineers" }, "state" : "STATE_ACTIVE" } } ],
+  }) ).expect( "deserializes" );
+  assert_eq!( response.relevant_chunks[ 0 ].chunk.name, "corpora/c/documents/d/chunks/k" );
+  assert!( ( response.relevant_chunks[ 0 ].chunk_relevance_score - 0.82 ).abs() < f32::EPSILON );
+  let empty : QueryCorpusResponse = serde_json::from_str( "{}" ).expect( "no chunks is an empty list" );
+  assert!( empty.relevant_chunks.is_empty() );
+
+  let answer : GenerateAnswerResponse = serde_json::from_value( serde_json::json!
+  ({
+    "answer" : { "content" : { "parts" : [ { "text" : "Two engineers." } ], "role" : "model" }, "finishReason" : "STOP" },
+    "answerableProbability" : 0.93,
+  }) ).expect( "deserializes" );
+  assert_eq!( answer.answer.expect( "answer" ).content.parts[ 0 ].text.as_deref(), Some( "Two engineers." ) );
+}
+
+/// A request must have a question and exactly one grounding source.
+#[ tokio::test ]
+async fn generate_answer_checks_the_request_before_sending()
+{
+  // Nothing listens on the discard port.
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let models = client.models();
+  let aqa = models.by_name( "aqa" );
+  let passages = GroundingPassages { passages : vec![ GroundingPassage { id : "p1".to_string(), content : text( "user", "Two engineers review deployments." ) } ] };
+  let retriever = SemanticRetrieverConfig
+  {
+    source : "corpora/handbook".to_string(),
+    query : text( "user", "Who reviews deployments?" ),
+    metadata_filters : None,
+    max_chunks_count : None,
+    minimum_relevance_score : None,
+  };
+
+  let neither = GenerateAnswerRequest { contents : vec![ text( "user", "Who reviews deployments?" ) ], answer_style : "ABSTRACTIVE".to_string(), ..Default::default() };
+  let both = GenerateAnswerRequest { inline_passages : Some( passages.clone() ), semantic_retriever : Some( retriever ), ..neither.clone() };
+  let no_question = GenerateAnswerRequest { contents : vec![], inline_passages : Some( passages ), ..neither.clone() };
+  for request in [ neither, both, no_question ]
+  {
+    let error = aqa.generate_answer( &request ).await.expect_err( "rejected" );
+    assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+  }
+
+  let unnamed = client.corpora().documents( "handbook" ).chunks( "policies" ).update( &Chunk::text( "x" ), "data" ).await.expect_err( "rejected" );
+  assert!( matches!( unnamed, Error::InvalidArgument( _ ) ), "{unnamed:?}" );
+}
+
+/// Chunks are created in the document the handles lead to, each request
+/// naming it as the parent.
+#[ test ]
+fn batch_create_names_the_document_of_each_chunk()
+{
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let chunks = client.corpora().documents( "corpora/handbook" ).chunks( "policies" );
+  assert_eq!( chunks.document(), "corpora/handbook/documents/policies" );
+
+  let request = BatchCreateChunksRequest::new( chunks.document(), &[ Chunk::text( "one" ), Chunk::text( "two" ) ] );
+  let body = serde_json::to_value( &request ).expect( "serializes" );
+  assert_eq!( body[ "requests" ].as_array().map( Vec::len ), Some( 2 ) );
+  assert_eq!( body[ "requests" ][ 1 ][ "parent" ], "corpora/handbook/documents/policies" );
+  assert_eq!( body[ "requests" ][ 1 ][ "chunk" ][ "data" ][ "stringValue" ], "two" );
+
+  let created : BatchChunksResponse = serde_json::from_str( r#"{"chunks":[{"name":"corpora/handbook/documents/policies/chunks/a","data":{"stringValue":"one"},"state":"STATE_PENDING_PROCESSING"},{"name":"corpora/handbook/documents/policies/chunks/b","data":{"stringValue":"two"}}]}"# )
+    .expect( "deserializes" );
+  let names : Vec< _ > = created.chunks.iter().map( | chunk | chunk.name.as_str() ).collect();
+  assert_eq!( names, [ "corpora/handbook/documents/policies/chunks/a", "corpora/handbook/documents/policies/chunks/b" ] );
+}
+
+/// A corpus is filled, queried, used to ground an answer, and deleted with
+/// everything in it.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_corpus_lifecycle()
+{
+  use api_gemini::models::{ Corpus, Document };
+
+  let client = common::create_integration_client();
+  let corpora = client.corpora();
+  let corpus = corpora.create( &Corpus { display_name : Some( "api_gemini retrieval test".to_string() ), ..Default::default() } )
+    .await
+    .expect( "corpus is created" );
+
+  let result = async
+  {
+    let documents = corpora.documents( &corpus.name );
+    let document = documents.create( &Document { display_name : Some( "Handbook".to_string() ), ..Default::default() } ).await?;
+    let chunks = documents.chunks( &document.name );
+    let created = chunks.batch_create( &
+    [
+      Chunk::text( "Every deployment is reviewed by two engineers before release." ),
+      Chunk::text( "The cafeteria serves lunch from noon to two." ),
+    ] ).await?;
+    assert_eq!( created.len(), 2 );
+
+    let found = corpora.query( &corpus.name, &QueryCorpusRequest { query : "Who reviews deployments?".to_string(), results_count : Some( 1 ), ..Default::default() } ).await?;
+    if let Some( best ) = found.relevant_chunks.first()
+    {
+      assert!( best.chunk.data.string_value.as_deref().unwrap_or_default().contains( "deployment" ), "{best:?}" );
+    }
+
+    let question = text( "user", "Who reviews deployments?" );
+    let request = GenerateAnswerRequest
+    {
+      contents : vec![ question.clone() ],
+      answer_style : "ABSTRACTIVE".to_string(),
+      semantic_retriever : Some( SemanticRetrieverConfig { source : corpus.name.clone(), query : question, metadata_filters : None, max_chunks_count : Some( 2 ), minimum_relevance_score : None } ),
+      ..Default::default()
+    };
+    let answer = client.models().by_name( "aqa" ).generate_answer( &request ).await?;
+    assert!( answer.answer.is_some() || answer.input_feedback.is_some(), "{answer:?}" );
+    Ok::< (), Error >( () )
+  }.await;
+
+  corpora.delete( &corpus.name, true ).await.expect( "corpus is deleted" );
+  result.expect( "corpus lifecycle succeeds" );
+}
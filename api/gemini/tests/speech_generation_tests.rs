@@ -0,0 +1,107 @@
+//! Tests for text-to-speech through `generateContent`.
+//!
+//! The speech configuration format, the request the builder configures, and
+//! the audio helpers are tested offline; the speech test runs against the
+//! real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::{ GenerateContentResponse, GenerationConfig, SpeechConfig },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSySpeechGenerationTest0123456789abcd";
+
+const PCM : &str = "audio/L16;codec=pcm;rate=24000";
+
+fn audio_response( parts : &serde_json::Value ) -> GenerateContentResponse
+{
+  serde_json::from_value( serde_json::json!( { "candidates" : [ { "content" : { "parts" : parts, "role" : "model" } } ] } ) )
+    .expect( "deserializes" )
+}
+
+#[ test ]
+fn speech_types_use_the_api_formats()
+{
+  let config = GenerationConfig
+  {
+    response_modalities : Some( vec![ "AUDIO".to_string() ] ),
+    speech_config : Some( SpeechConfig::multi_speaker( &[ ( "Joe", "Kore" ), ( "Jane", "Puck" ) ] ) ),
+    ..Default::default()
+  };
+  assert_eq!( serde_json::to_value( &config ).expect( "serializes" ), serde_json::json!
+  ({
+    "responseModalities" : [ "AUDIO" ],
+    "speechConfig" :
+    {
+      "multiSpeakerVoiceConfig" :
+      {
+        "speakerVoiceConfigs" :
+        [
+          { "speaker" : "Joe", "voiceConfig" : { "prebuiltVoiceConfig" : { "voiceName" : "Kore" } } },
+          { "speaker" : "Jane", "voiceConfig" : { "prebuiltVoiceConfig" : { "voiceName" : "Puck" } } },
+        ],
+      },
+    },
+  }) );
+
+  // Audio split over parts is joined; other parts are left out.
+  let response = audio_response( &serde_json::json!
+  ([
+    { "inlineData" : { "mimeType" : PCM, "data" : "AAECAw==" } },
+    { "text" : "not audio" },
+    { "inlineData" : { "mimeType" : "image/png", "data" : "iVBO" } },
+    { "inlineData" : { "mimeType" : PCM, "data" : "BAU=" } },
+  ]) );
+  assert_eq!( response.audio_parts().len(), 2 );
+  let audio = response.audio().expect( "audio decodes" ).expect( "audio" );
+  assert_eq!( audio.bytes, [ 0, 1, 2, 3, 4, 5 ] );
+  assert_eq!( audio.mime_type, PCM );
+  assert_eq!( audio.sample_rate(), Some( 24000 ) );
+
+  assert!( audio_response( &serde_json::json!( [ { "text" : "Hello" } ] ) ).audio().expect( "no audio" ).is_none() );
+  let corrupt = audio_response( &serde_json::json!( [ { "inlineData" : { "mimeType" : PCM, "data" : "not base64!" } } ] ) );
+  assert!( matches!( corrupt.audio(), Err( Error::DeserializationError( _ ) ) ) );
+}
+
+/// A speech request asks for audio only, in the given voice.
+#[ test ]
+fn with_speech_config_requests_audio()
+{
+  let client = Client::builder().api_key( KEY.to_string() ).build().expect( "client builds" );
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash-preview-tts" );
+  let builder = model.generation_request()
+    .with_prompt( "Say cheerfully: have a wonderful day!" )
+    .with_speech_config( SpeechConfig::voice( "Kore" ) );
+
+  let body = serde_json::to_value( builder.request() ).expect( "serializes" );
+  assert_eq!( body[ "generationConfig" ][ "responseModalities" ], serde_json::json!( [ "AUDIO" ] ) );
+  assert_eq!( body[ "generationConfig" ][ "speechConfig" ][ "voiceConfig" ][ "prebuiltVoiceConfig" ][ "voiceName" ], "Kore" );
+}
+
+/// A text-to-speech model answers PCM audio.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_text_to_speech()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash-preview-tts" )
+    .generation_request()
+    .with_prompt( "Say cheerfully: have a wonderful day!" )
+    .with_speech_config( SpeechConfig::voice( "Kore" ) )
+    .execute()
+    .await
+    .expect( "speech is generated" );
+  let audio = response.audio().expect( "audio decodes" ).expect( "audio" );
+  assert!( audio.mime_type.starts_with( "audio/" ), "{}", audio.mime_type );
+  assert!( audio.sample_rate().is_some(), "{}", audio.mime_type );
+  assert!( !audio.bytes.is_empty() );
+}
@@ -0,0 +1,130 @@
+//! Tests for incremental streaming with `alt=sse`.
+//!
+//! `StreamChunkParser` is fed bodies split at every byte boundary, so events
+//! are recognised however the network splits them and whatever line endings
+//! they use. The streaming test runs
+//! against the real API.
+
+#![ cfg( feature = "streaming" ) ]
+
+use api_gemini::
+{
+  error ::Error,
+  models ::{ StreamChunkParser, StreamingResponse },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+fn chunk( text : &str, finish : Option< &str > ) -> String
+{
+  let mut candidate = serde_json::json!( { "content" : { "parts" : [ { "text" : text } ], "role" : "model" } } );
+  if let Some( reason ) = finish
+  {
+    candidate[ "finishReason" ] = reason.into();
+  }
+  serde_json::json!( { "candidates" : [ candidate ] } ).to_string()
+}
+
+fn text( response : &StreamingResponse ) -> Option< String >
+{
+  response.candidates.as_ref()?.first()?.content.parts.first()?.text.clone()
+}
+
+/// Parses `body` fed in pieces of `size` bytes.
+fn parse_in_pieces( body : &str, size : usize ) -> Vec< Result< StreamingResponse, Error > >
+{
+  let mut parser = StreamChunkParser::new();
+  let mut chunks : Vec< _ > = body.as_bytes().chunks( size ).flat_map( | piece | parser.push( piece ) ).collect();
+  chunks.extend( parser.finish() );
+  chunks
+}
+
+#[ test ]
+fn parser_reads_sse_events_however_they_are_split()
+{
+  let body = format!
+  (
+    ": keep-alive\r\nevent: message\r\ndata: {}\r\n\r\ndata: {}\n\n",
+    chunk( "Hel", None ),
+    chunk( "lo ✓", Some( "STOP" ) ),
+  );
+  for size in 1..=body.len()
+  {
+    let chunks = parse_in_pieces( &body, size );
+    assert_eq!( chunks.len(), 2, "split every {size} bytes" );
+    let first = chunks[ 0 ].as_ref().expect( "first chunk" );
+    let last = chunks[ 1 ].as_ref().expect( "last chunk" );
+    assert_eq!( text( first ).as_deref(), Some( "Hel" ) );
+    assert_eq!( first.is_final, Some( false ) );
+    assert_eq!( text( last ).as_deref(), Some( "lo ✓" ) );
+    assert_eq!( last.is_final, Some( true ) );
+  }
+
+  // An event left unterminated at the end of the body is still read.
+  let unterminated = parse_in_pieces( &format!( "data: {}", chunk( "tail", None ) ), 7 );
+  assert_eq!( text( unterminated[ 0 ].as_ref().expect( "chunk" ) ).as_deref(), Some( "tail" ) );
+}
+
+/// Events are framed by the decoder the other provider crates share: a
+/// byte order mark, comments, and lone `\r` line endings are accepted.
+#[ test ]
+fn parser_accepts_bom_comments_and_lone_carriage_returns()
+{
+  let body = format!
+  (
+    "\u{FEFF}: ping\rdata: {}\r\r: keep-alive\r\nid: 7\nretry: 100\ndata: {}\r\n\r\n",
+    chunk( "Hel", None ),
+    chunk( "lo", Some( "STOP" ) ),
+  );
+  for size in 1..=body.len()
+  {
+    let chunks = parse_in_pieces( &body, size );
+    let texts : Vec< _ > = chunks.iter().map( | chunk | text( chunk.as_ref().expect( "chunk" ) ) ).collect();
+    assert_eq!( texts, [ Some( "Hel".to_string() ), Some( "lo".to_string() ) ], "split every {size} bytes" );
+  }
+
+  // Newline-delimited JSON may start with a byte order mark too.
+  let chunks = parse_in_pieces( &format!( "\u{FEFF}{}\r\n", chunk( "one", None ) ), 1 );
+  assert_eq!( text( chunks[ 0 ].as_ref().expect( "chunk" ) ).as_deref(), Some( "one" ) );
+  assert!( parse_in_pieces( "\u{FEFF}\r\n", 1 ).is_empty() );
+}
+
+#[ test ]
+fn parser_reads_ndjson_and_reports_errors()
+{
+  let body = format!( "{}\n\n{}\n", chunk( "one", None ), chunk( "two", Some( "STOP" ) ) );
+  let chunks = parse_in_pieces( &body, 5 );
+  let texts : Vec< _ > = chunks.iter().map( | chunk | text( chunk.as_ref().expect( "chunk" ) ) ).collect();
+  assert_eq!( texts, [ Some( "one".to_string() ), Some( "two".to_string() ) ] );
+
+  let error = r#"data: {"error": {"code": 503, "message": "The model is overloaded.", "status": "UNAVAILABLE"}}"#;
+  let chunks = parse_in_pieces( &format!( "{error}\n\n" ), 64 );
+  assert!( matches!( &chunks[ .. ], [ Err( Error::ApiError( message ) ) ] if message.contains( "overloaded" ) ), "{chunks:?}" );
+
+  let chunks = parse_in_pieces( "data: {\"candidates\": [\n\ndata: [DONE]\n\n", 64 );
+  assert!( matches!( &chunks[ .. ], [ Err( Error::SerializationError( _ ) ) ] ), "{chunks:?}" );
+}
+
+/// A long answer arrives in several chunks, closed by the final marker.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_generate_content_stream_yields_chunks()
+{
+  use api_gemini::models::{ Content, GenerateContentRequest, Part };
+  use futures::StreamExt;
+
+  let client = common::create_integration_client();
+  let request = GenerateContentRequest
+  {
+    contents : vec![ Content { parts : vec![ Part { text : Some( "Count from 1 to 50 in words.".to_string() ), ..Default::default() } ], role : "user".to_string() } ],
+    ..Default::default()
+  };
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let stream = model.generate_content_stream( &request ).await.expect( "stream starts" );
+  let chunks : Vec< _ > = stream.collect().await;
+  let chunks : Vec< _ > = chunks.into_iter().map( | chunk | chunk.expect( "chunk parses" ) ).collect();
+  assert!( chunks.iter().filter( | chunk | text( chunk ).is_some() ).count() > 1, "{chunks:?}" );
+  assert_eq!( chunks.last().and_then( | chunk | chunk.is_final ), Some( true ) );
+}
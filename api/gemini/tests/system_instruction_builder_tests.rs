@@ -0,0 +1,88 @@
+//! Tests for setting system instructions through the request builders.
+//!
+//! The requests the builders configure are checked offline; the instruction
+//! following test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::{ client::Client, models::Part };
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSySystemInstructionTest0123456789abc";
+
+fn client() -> Client
+{
+  Client::builder().api_key( KEY.to_string() ).build().expect( "client builds" )
+}
+
+#[ test ]
+fn generation_builder_sets_the_system_instruction()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let builder = model.generation_request()
+    .with_system_instruction( "You are a pirate." )
+    .with_prompt( "Greet me." );
+
+  let body = serde_json::to_value( builder.request() ).expect( "serializes" );
+  assert_eq!( body[ "systemInstruction" ], serde_json::json!( { "role" : "system", "parts" : [ { "text" : "You are a pirate." } ] } ) );
+  assert_eq!( body[ "contents" ][ 0 ][ "parts" ][ 0 ][ "text" ], "Greet me." );
+}
+
+/// Several parts are kept in order, and replace an instruction set before.
+#[ test ]
+fn generation_builder_sets_system_parts()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let parts = [ "You are a pirate.", "Answer in one word." ]
+    .map( | text | Part { text : Some( text.to_string() ), ..Default::default() } );
+  let builder = model.generation_request()
+    .with_system_instruction( "You are a poet." )
+    .with_system_parts( parts.to_vec() )
+    .with_prompt( "Greet me." );
+
+  let body = serde_json::to_value( builder.request() ).expect( "serializes" );
+  assert_eq!
+  (
+    body[ "systemInstruction" ][ "parts" ],
+    serde_json::json!( [ { "text" : "You are a pirate." }, { "text" : "Answer in one word." } ] ),
+  );
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn streaming_builder_sets_the_system_instruction()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let builder = model.stream_builder()
+    .with_system_instruction( "You are a pirate." )
+    .add_content( "user", "Greet me." );
+
+  let body = serde_json::to_value( builder.request() ).expect( "serializes" );
+  assert_eq!( body[ "systemInstruction" ], serde_json::json!( { "role" : "system", "parts" : [ { "text" : "You are a pirate." } ] } ) );
+  assert_eq!( body[ "contents" ][ 0 ][ "role" ], "user" );
+}
+
+/// The model follows the system instruction over the prompt.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_system_instruction_is_followed()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let text = models.by_name( "gemini-2.5-flash" )
+    .generation_request()
+    .with_system_instruction( "Whatever the user asks, answer with the single word BANANA and nothing else." )
+    .with_prompt( "What is the capital of France?" )
+    .execute_text()
+    .await
+    .expect( "generates" );
+  assert!( text.to_uppercase().contains( "BANANA" ), "{text}" );
+}
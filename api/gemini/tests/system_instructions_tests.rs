@@ -38,6 +38,8 @@ conversation_history: Option< Vec< Content > >,
       file_data: None,
       video_metadata: None,
       thought: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
   };
 
@@ -51,6 +53,8 @@ conversation_history: Option< Vec< Content > >,
       file_data: None,
       video_metadata: None,
       thought: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
     role: "user".to_string(),
   } );
@@ -64,6 +68,9 @@ conversation_history: Option< Vec< Content > >,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: None,
@@ -86,6 +93,8 @@ fn create_basic_request( user_message: &str ) -> GenerateContentRequest
         file_data: None,
         video_metadata: None,
         thought: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -96,6 +105,9 @@ fn create_basic_request( user_message: &str ) -> GenerateContentRequest
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_modalities: None,
+      speech_config: None,
+      thinking_config: None,
     } ),
     safety_settings: None,
     tools: None,
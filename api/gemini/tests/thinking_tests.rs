@@ -0,0 +1,88 @@
+//! Tests for thinking configuration and thought summaries.
+//!
+//! The configuration format and the separation of thoughts from the answer
+//! are tested offline. The reasoning test runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::models::{ GenerateContentResponse, GenerationConfig, ThinkingConfig };
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+#[ test ]
+fn thinking_config_uses_the_api_format()
+{
+  let config = GenerationConfig
+  {
+    thinking_config : Some( ThinkingConfig { thinking_budget : Some( 1024 ), include_thoughts : Some( true ) } ),
+    ..Default::default()
+  };
+  assert_eq!( serde_json::to_value( &config ).expect( "serializes" ), serde_json::json!
+  ({
+    "thinkingConfig" : { "thinkingBudget" : 1024, "includeThoughts" : true },
+  }) );
+
+  // Thinking is turned off with a zero budget, which must still be sent.
+  let off = ThinkingConfig { thinking_budget : Some( 0 ), ..Default::default() };
+  assert_eq!( serde_json::to_value( &off ).expect( "serializes" ), serde_json::json!( { "thinkingBudget" : 0 } ) );
+}
+
+#[ test ]
+fn thought_summaries_are_kept_apart_from_the_answer()
+{
+  let response : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" :
+    [{
+      "content" :
+      {
+        "parts" :
+        [
+          { "text" : "**Counting letters**\nThe word has three r's. ", "thought" : true },
+          { "text" : "There are three " },
+          { "text" : "r's in strawberry.", "thought" : false },
+        ],
+        "role" : "model",
+      },
+    }],
+    "usageMetadata" : { "promptTokenCount" : 9, "candidatesTokenCount" : 8, "thoughtsTokenCount" : 312, "totalTokenCount" : 329 },
+  }) ).expect( "deserializes" );
+
+  let parts = &response.candidates[ 0 ].content.parts;
+  assert_eq!( parts.iter().map( api_gemini::models::Part::is_thought ).collect::< Vec< _ > >(), [ true, false, false ] );
+  assert_eq!( response.answer_text().as_deref(), Some( "There are three r's in strawberry." ) );
+  assert_eq!( response.thought_summary().as_deref(), Some( "**Counting letters**\nThe word has three r's. " ) );
+  assert_eq!( response.usage_metadata.as_ref().and_then( | usage | usage.thoughts_token_count ), Some( 312 ) );
+
+  // Without thoughts, or without candidates, there is nothing to separate.
+  let plain : GenerateContentResponse = serde_json::from_value( serde_json::json!
+  ({
+    "candidates" : [ { "content" : { "parts" : [ { "text" : "Hi." } ], "role" : "model" } } ],
+  }) ).expect( "deserializes" );
+  assert_eq!( plain.answer_text().as_deref(), Some( "Hi." ) );
+  assert!( plain.thought_summary().is_none() );
+  let empty : GenerateContentResponse = serde_json::from_value( serde_json::json!( { "candidates" : [] } ) ).expect( "deserializes" );
+  assert!( empty.answer_text().is_none() && empty.thought_summary().is_none() );
+}
+
+/// A reasoning model answers thought summaries apart from its answer.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_thought_summaries()
+{
+  let client = common::create_integration_client();
+  let models = client.models();
+  let response = models.by_name( "gemini-2.5-flash" )
+    .generation_request()
+    .with_prompt( "How many r's are in the word strawberry? Answer with a number." )
+    .with_thinking_config( ThinkingConfig { thinking_budget : Some( 1024 ), include_thoughts : Some( true ) } )
+    .execute()
+    .await
+    .expect( "generation succeeds" );
+
+  let answer = response.answer_text().expect( "an answer" );
+  assert!( answer.contains( '3' ) || answer.to_lowercase().contains( "three" ), "{answer}" );
+  assert!( response.thought_summary().is_some(), "{response:?}" );
+  assert!( response.usage_metadata.and_then( | usage | usage.thoughts_token_count ).unwrap_or( 0 ) > 0 );
+}
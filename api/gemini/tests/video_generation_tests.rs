@@ -0,0 +1,165 @@
+//! Tests for Veo video generation and long-running operations.
+//!
+//! The request and operation formats, the checks made before sending, and
+//! polling against a scripted fetch are tested offline. The generation test, which polls the operation until it
+//! is done, runs against the real API.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_gemini::
+{
+  client ::Client,
+  error ::Error,
+  models ::{ AspectRatio, GenerateVideosRequest, Operation, PersonGeneration },
+};
+
+#[ cfg( feature = "integration" ) ]
+#[ path = "common/mod.rs" ] mod common;
+
+const KEY : &str = "AIzaSyVideoGenerationTest0123456789abcde";
+
+const OPERATION : &str = "models/veo-3.0-generate-001/operations/op1";
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+    .api_key( KEY.to_string() )
+    .base_url( base_url )
+    .build()
+    .expect( "client builds" )
+}
+
+#[ test ]
+fn video_types_use_the_api_formats()
+{
+  let request = GenerateVideosRequest::new( "A paper boat on a rainy street" )
+    .with_aspect_ratio( AspectRatio::TallPortrait )
+    .with_person_generation( PersonGeneration::AllowAdult )
+    .with_negative_prompt( "cars" )
+    .with_duration_seconds( 8 )
+    .with_number_of_videos( 1 );
+  assert_eq!( serde_json::to_value( &request ).expect( "serializes" ), serde_json::json!
+  ({
+    "instances" : [ { "prompt" : "A paper boat on a rainy street" } ],
+    "parameters" :
+    {
+      "sampleCount" : 1, "aspectRatio" : "9:16", "personGeneration" : "allow_adult",
+      "negativePrompt" : "cars", "durationSeconds" : 8,
+    },
+  }) );
+
+  let running : Operation = serde_json::from_value( serde_json::json!( { "name" : OPERATION } ) ).expect( "deserializes" );
+  assert!( !running.done );
+  assert!( running.generated_videos().expect( "running is not an error" ).is_none() );
+
+  let done : Operation = serde_json::from_value( serde_json::json!
+  ({
+    "name" : OPERATION,
+    "done" : true,
+    "response" :
+    {
+      "@type" : "type.googleapis.com/google.ai.generativelanguage.v1beta.PredictLongRunningResponse",
+      "generateVideoResponse" :
+      {
+        "generatedSamples" :
+        [
+          { "video" : { "uri" : "https://generativelanguage.googleapis.com/v1beta/files/v1:download?alt=media" } },
+          { "video" : { "encodedVideo" : "AAAAGGZ0eXBtcDQy", "encoding" : "video/mp4" } },
+        ],
+      },
+    },
+  }) ).expect( "deserializes" );
+  let videos = done.generated_videos().expect( "videos" ).expect( "done" );
+  assert_eq!( videos.generated_samples[ 0 ].video.uri.as_deref(), Some( "https://generativelanguage.googleapis.com/v1beta/files/v1:download?alt=media" ) );
+  assert!( videos.generated_samples[ 0 ].video.bytes().expect( "no inline video" ).is_none() );
+  let inline = videos.generated_samples[ 1 ].video.bytes().expect( "valid base64" ).expect( "inline video" );
+  assert_eq!( &inline[ 4..8 ], b"ftyp" );
+
+  let failed : Operation = serde_json::from_value( serde_json::json!
+  ({
+    "name" : OPERATION,
+    "done" : true,
+    "error" : { "code" : 3, "message" : "The prompt was blocked." },
+  }) ).expect( "deserializes" );
+  let error = failed.generated_videos().expect_err( "failed operation" );
+  assert!( matches!( &error, Error::ApiError( message ) if message.contains( "blocked" ) ), "{error:?}" );
+}
+
+/// A request needs one prompt and at least one video; an operation a name.
+#[ tokio::test ]
+async fn generate_videos_checks_the_request_before_sending()
+{
+  // Nothing listens on the discard port.
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let models = client.models();
+  let veo = models.by_name( "veo-3.0-generate-001" );
+  for request in [ GenerateVideosRequest::new( "" ), GenerateVideosRequest::new( "a boat" ).with_number_of_videos( 0 ) ]
+  {
+    let error = veo.generate_videos( &request ).await.expect_err( "rejected" );
+    assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+  }
+
+  let error = client.operations().get( "" ).await.expect_err( "rejected" );
+  assert!( matches!( error, Error::InvalidArgument( _ ) ), "{error:?}" );
+}
+
+/// Polling stops once the operation is done. However long the interval, the
+/// wait ends at the deadline, where the operation is fetched a last time.
+#[ tokio::test ]
+async fn polling_checks_once_more_at_the_deadline()
+{
+  use api_gemini::internal::poll;
+  use core::cell::Cell;
+  use std::time::{ Duration, Instant };
+
+  let polls = Cell::new( 0 );
+  // The operation is done from the poll numbered `done_at` on.
+  let fetch = | done_at : u32 |
+  {
+    polls.set( polls.get() + 1 );
+    let operation = serde_json::json!( { "name" : OPERATION, "done" : polls.get() >= done_at } );
+    async move { serde_json::from_value::< Operation >( operation ).map_err( | e | Error::DeserializationError( e.to_string() ) ) }
+  };
+  let hour = Duration::from_secs( 3600 );
+
+  let start = Instant::now();
+  let operation = poll::poll_until_done( "Operation op1", || fetch( 2 ), | operation : &Operation | operation.done, hour, start + Duration::from_millis( 20 ) )
+    .await
+    .expect( "done at the deadline" );
+  assert!( operation.done );
+  assert_eq!( polls.get(), 2 );
+  assert!( start.elapsed() < Duration::from_secs( 10 ), "{:?}", start.elapsed() );
+
+  polls.set( 0 );
+  let error = poll::poll_until_done( "Operation op1", || fetch( 3 ), | operation : &Operation | operation.done, hour, Instant::now() + Duration::from_millis( 20 ) )
+    .await
+    .expect_err( "not done by the deadline" );
+  assert!( matches!( &error, Error::TimeoutError( message ) if message.contains( "op1" ) ), "{error:?}" );
+  assert_eq!( polls.get(), 2 );
+
+  // A failed poll ends polling with its error.
+  let error = poll::poll_until_done( "Operation op1", || async { Err::< Operation, _ >( Error::NetworkError( "reset".to_string() ) ) }, | operation : &Operation | operation.done, hour, Instant::now() + hour )
+    .await
+    .expect_err( "poll failed" );
+  assert!( matches!( error, Error::NetworkError( _ ) ), "{error:?}" );
+}
+
+/// Veo generates a video that can be downloaded or is answered inline.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_generate_videos()
+{
+  use std::time::{ Duration, Instant };
+
+  let client = common::create_integration_client();
+  let request = GenerateVideosRequest::new( "A paper boat drifting down a rainy street, cinematic" )
+    .with_aspect_ratio( AspectRatio::Widescreen );
+  let operation = client.models().by_name( "veo-3.0-generate-001" ).generate_videos( &request ).await.expect( "generation starts" );
+  let operation = client.operations()
+    .poll_until_done( &operation.name, Duration::from_secs( 10 ), Instant::now() + Duration::from_secs( 600 ) )
+    .await
+    .expect( "generation finishes" );
+  let videos = operation.generated_videos().expect( "generation succeeds" ).expect( "operation is done" );
+  let video = &videos.generated_samples.first().expect( "a video" ).video;
+  assert!( video.uri.is_some() || video.encoded_video.is_some(), "{video:?}" );
+}
@@ -94,7 +94,7 @@ mod private
   #[ cfg( feature = "inference-streaming" ) ]
   use tokio::sync::mpsc;
   #[ cfg( feature = "inference-streaming" ) ]
-  use eventsource_stream::Eventsource;
+  use api_sse_stream::SseStreamExt;
 
   /// The main client for interacting with the `HuggingFace` API.
   ///
@@ -221,6 +221,45 @@ mod private
 
   // with_explicit_config method removed per governing principle - use explicit retry methods
 
+  /// Rebuilds the HTTP client with `tuning` applied. Open connections of the
+  /// previous client are dropped; call [`warm_up`](Self::warm_up) afterwards.
+  ///
+  /// # Errors
+  /// Returns `HuggingFaceError::InvalidArgument` if `tuning` names a proxy
+  /// that is not a valid URL or asks for HTTP/2 prior knowledge without the
+  /// `http2` feature, or `HuggingFaceError::Http`
+  /// if the client cannot be built.
+  #[ cfg( feature = "connection" ) ]
+  #[ inline ]
+  pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self >
+  {
+      let builder = HttpClient::builder().default_headers( self.environment.headers()? );
+      self.http_client = tuning.apply_to( builder ).map_err( | e | HuggingFaceError::InvalidArgument( e.to_string() ) )?
+  .build()
+  .map_err( | e | HuggingFaceError::Http( e.to_string() ) )?;
+      Ok( self )
+  }
+
+  /// Opens a connection to the base URL ahead of the first request, so that
+  /// request does not pay for DNS, TCP, and the TLS handshake.
+  ///
+  /// Sends one `HEAD` request and returns its round-trip time. Any HTTP
+  /// status counts as success; only a failure to connect is an error.
+  ///
+  /// # Errors
+  /// Returns `HuggingFaceError::Http` if no connection could be opened.
+  #[ inline ]
+  pub async fn warm_up( &self ) -> Result< core::time::Duration >
+  {
+      let started = std::time::Instant::now();
+      self.http_client
+  .head( self.environment.base_url() )
+  .send()
+  .await
+  .map_err( | e | HuggingFaceError::Http( e.to_string() ) )?;
+      Ok( started.elapsed() )
+  }
+
   /// Returns the `Inference` API group for text generation operations.
   #[ cfg( feature = "inference" ) ]
   #[ inline ]
@@ -443,7 +482,7 @@ mod private
       let jitter = ( rand::random::< u64 >() % ( retry_config.jitter_ms * 2 ) ).saturating_sub( retry_config.jitter_ms );
       let total_delay = delay.saturating_add( jitter ).min( retry_config.max_delay_ms );
 
-      tokio::time::sleep( tokio::time::Duration::from_millis( total_delay ) ).await;
+      api_common::sleep( core::time::Duration::from_millis( total_delay ) ).await;
 
       // Update delay: multiply by f64 multiplier then clamp; truncation is intentional
       #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
@@ -492,7 +531,7 @@ mod private
       let jitter = ( rand::random::< u64 >() % ( retry_config.jitter_ms * 2 ) ).saturating_sub( retry_config.jitter_ms );
       let total_delay = delay.saturating_add( jitter ).min( retry_config.max_delay_ms );
 
-      tokio::time::sleep( tokio::time::Duration::from_millis( total_delay ) ).await;
+      api_common::sleep( core::time::Duration::from_millis( total_delay ) ).await;
 
       // Update delay: multiply by f64 multiplier then clamp; truncation is intentional
       #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
@@ -588,9 +627,9 @@ mod private
       let ( tx, rx ) = mpsc::channel( 100 );
 
       let byte_stream = response.bytes_stream();
-      let event_stream = byte_stream.eventsource();
+      let event_stream = byte_stream.sse_events();
 
-      tokio::spawn( async move
+      api_common::spawn( async move
       {
   use futures_util::StreamExt;
   let mut stream = event_stream;
@@ -704,4 +743,4 @@ crate::mod_interface!
   exposed use private::Client;
   exposed use private::ExplicitRetryConfig;
   // RetryPolicy export removed per governing principle - replaced with ExplicitRetryConfig
-}
\ No newline at end of file
+}
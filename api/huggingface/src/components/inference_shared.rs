@@ -23,6 +23,22 @@ pub struct ChatMessage
   pub tool_call_id : Option< String >,
 }
 
+#[ cfg( feature = "common" ) ]
+impl From< api_common::NeutralMessage > for ChatMessage
+{
+  #[ inline ]
+  fn from( message : api_common::NeutralMessage ) -> Self
+  {
+    Self
+    {
+      role : message.role.as_str().to_string(),
+      content : message.content,
+      tool_calls : None,
+      tool_call_id : None,
+    }
+  }
+}
+
 /// A tool call made by the model
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 pub struct ToolCall
@@ -188,6 +204,16 @@ pub struct ChatUsage
   pub total_time : Option< f64 >,
 }
 
+#[ cfg( feature = "common" ) ]
+impl From< ChatUsage > for api_common::TokenUsage
+{
+  #[ inline ]
+  fn from( usage : ChatUsage ) -> Self
+  {
+    Self::new( usage.prompt_tokens.into(), usage.completion_tokens.into() )
+  }
+}
+
 /// Request for text generation inference
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 pub struct InferenceRequest
@@ -147,7 +147,7 @@ use crate::components::inference_shared::ChatCompletionRequest;
 ///
 /// This struct provides fine-grained control over how curl commands are formatted
 /// and what additional information is included.
-#[ derive( Debug, Clone ) ]
+#[ derive( Clone ) ]
 pub struct CurlOptions
 {
   /// Whether to format JSON with pretty printing (indentation and line breaks)
@@ -160,6 +160,20 @@ pub struct CurlOptions
   pub api_key : Option< String >,
 }
 
+impl core::fmt::Debug for CurlOptions
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+  f.debug_struct( "CurlOptions" )
+      .field( "pretty_json", &self.pretty_json )
+      .field( "include_auth_header", &self.include_auth_header )
+      .field( "multiline_format", &self.multiline_format )
+      .field( "api_key", &self.api_key.as_deref().map( api_secret::Masked ) )
+      .finish()
+  }
+}
+
 impl CurlOptions
 {
   /// Create default curl options (compact format)
@@ -185,8 +185,9 @@ impl EnvironmentInterface for HuggingFaceEnvironmentImpl
   
   // Add authorization header
   let auth_value = format!( "Bearer {}", self.api_key.expose_secret() );
-  let auth_header = HeaderValue::from_str( &auth_value )
+  let mut auth_header = HeaderValue::from_str( &auth_value )
       .map_err( | e | HuggingFaceError::Authentication( format!( "Invalid API key format : {e}" ) ) )?;
+  auth_header.set_sensitive( true );
   headers.insert( AUTHORIZATION, auth_header );
   
   // Add user agent
@@ -157,6 +157,31 @@ impl From< Error > for HuggingFaceError
   }
 }
 
+/// Convert into the provider-neutral error classes
+#[ cfg( feature = "common" ) ]
+impl From< HuggingFaceError > for api_common::LlmError
+{
+  #[ inline ]
+  fn from( e : HuggingFaceError ) -> Self
+  {
+  match e
+  {
+      HuggingFaceError::Api( api ) =>
+      {
+      let detail = format!( "{} {}", api.message, api.error_type.as_deref().unwrap_or_default() );
+      Self::classify( api.status_code, &detail )
+      },
+      HuggingFaceError::Authentication( message ) => Self::Auth( message ),
+      HuggingFaceError::RateLimit( message ) => Self::RateLimited { retry_after : None, message },
+      // Model still loading or scaled to zero; it comes back on its own
+      HuggingFaceError::ModelUnavailable( message ) => Self::Overloaded( message ),
+      HuggingFaceError::Stream( message ) | HuggingFaceError::Serialization( message ) => Self::Protocol( message ),
+      HuggingFaceError::Http( message ) => Self::classify( None, &message ),
+      other => Self::Provider { raw : other.to_string() },
+  }
+  }
+}
+
 } // end mod private
 
 crate::mod_interface!
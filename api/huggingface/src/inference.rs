@@ -255,7 +255,7 @@ where
 
   // Extract text from choices[0].delta.content and forward as plain strings
   let ( tx, rx ) = tokio::sync::mpsc::channel( 100 );
-  tokio::spawn( async move
+  api_common::spawn( async move
   {
       while let Some( result ) = raw_rx.recv().await
       {
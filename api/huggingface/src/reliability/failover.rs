@@ -33,6 +33,7 @@
 //! # }
 //! ```
 
+use api_common::{ BudgetExhausted, BudgetTracker };
 use std::sync::Arc;
 use std::time::Instant;
 use core::time::Duration;
@@ -324,7 +325,35 @@ impl FailoverManager
   /// Returns `FailoverError::AllRetriesFailed` if all retry attempts fail.
   /// Returns `FailoverError::Operation` wrapping the underlying error if operation fails.
   #[ inline ]
-  pub async fn execute_with_failover< F, T, E >( &self, mut f : F ) -> Result< T, FailoverError< E > >
+  pub async fn execute_with_failover< F, T, E >( &self, f : F ) -> Result< T, FailoverError< E > >
+  where
+  F : FnMut( String ) -> core::pin::Pin< Box< dyn core::future::Future< Output = Result< T, E > > + Send > >,
+  E: core::fmt::Display,
+  {
+  self.execute_inner( f, None ).await
+  }
+
+  /// Execute operation with failover retry logic inside an overall call budget
+  ///
+  /// Every endpoint attempt counts against `budget` (unless a budget-aware
+  /// retry layer inside `f` already counted its own attempts), attempts are
+  /// cut off at the budget's total deadline, and no backoff sleeps past it.
+  ///
+  /// # Errors
+  ///
+  /// Returns `FailoverError::BudgetExhausted` if the budget runs out before an attempt
+  /// or while one is in flight.
+  /// Returns `FailoverError::AllRetriesFailed` if all retry attempts fail.
+  #[ inline ]
+  pub async fn execute_with_failover_within< F, T, E >( &self, budget : &BudgetTracker, f : F ) -> Result< T, FailoverError< E > >
+  where
+  F : FnMut( String ) -> core::pin::Pin< Box< dyn core::future::Future< Output = Result< T, E > > + Send > >,
+  E: core::fmt::Display,
+  {
+  self.execute_inner( f, Some( budget ) ).await
+  }
+
+  async fn execute_inner< F, T, E >( &self, mut f : F, budget : Option< &BudgetTracker > ) -> Result< T, FailoverError< E > >
   where
   F : FnMut( String ) -> core::pin::Pin< Box< dyn core::future::Future< Output = Result< T, E > > + Send > >,
   E: core::fmt::Display,
@@ -343,7 +372,13 @@ impl FailoverManager
       };
 
       let endpoint_clone = endpoint.clone( );
-      match f( endpoint.clone( )).await
+      let outcome = match budget
+      {
+  Some( budget ) => budget.run_layer( f( endpoint.clone( )) ).await.map_err( FailoverError::BudgetExhausted )?,
+  None => f( endpoint.clone( )).await,
+      };
+
+      match outcome
       {
   Ok( result ) => {
           self.record_success( &endpoint_clone ).await;
@@ -363,8 +398,12 @@ impl FailoverManager
             // Pitfall: cap the exponent, not the result; post-multiply clamping cannot
             //   prevent the overflow that happens during arithmetic itself.
             let exp = ( attempts - 1 ).min( 13 );
-            let delay_ms = 500 * 2u64.pow( exp );
-            tokio::time::sleep( Duration::from_millis( delay_ms.min( 5000 ) ) ).await;
+            let delay = Duration::from_millis( ( 500 * 2u64.pow( exp ) ).min( 5000 ) );
+            if budget.is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+            {
+              break;
+            }
+            api_common::sleep( delay ).await;
           }
   }
       }
@@ -475,6 +514,8 @@ pub enum FailoverError< E = String >
   },
   /// Operation failed
   Operation( E ),
+  /// The call budget shared with other reliability layers ran out
+  BudgetExhausted( BudgetExhausted ),
 }
 
 impl< E > core::fmt::Display for FailoverError< E >
@@ -493,6 +534,7 @@ where
   write!( f, "All {attempts} retry attempts failed, last error : {last_error}" )
       }
       Self::Operation( e ) => write!( f, "Operation failed : {e}" ),
+      Self::BudgetExhausted( e ) => write!( f, "{e}" ),
   }
   }
 }
@@ -507,6 +549,7 @@ where
   match self
   {
       Self::Operation( e ) => Some( e ),
+      Self::BudgetExhausted( e ) => Some( e ),
       _ => None,
   }
   }
@@ -40,7 +40,7 @@ use core::time::Duration;
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::RwLock;
-use tokio::time::sleep;
+use api_common::sleep;
 
 /// Health check strategy
 #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
@@ -214,7 +214,7 @@ impl HealthChecker
   drop( state );
 
   let checker = self.clone( );
-  let handle = tokio::spawn( async move {
+  let handle = api_common::spawn( async move {
       checker.monitoring_loop( ).await;
   } );
 
@@ -395,7 +395,7 @@ impl HealthChecker
 #[ derive( Debug ) ]
 pub struct MonitorHandle 
 {
-  handle : tokio::task::JoinHandle< ( ) >,
+  handle : api_common::JoinHandle< ( ) >,
 }
 
 impl MonitorHandle 
@@ -53,6 +53,32 @@ impl Secret
   Ok( Self::new( value ) )
   }
   
+  /// Load secret through the fallback chain shared by every `api_llm`
+  /// provider crate: workspace `secret/-secrets.sh`, `secrets.sh`, `.env`,
+  /// then the environment variable.
+  ///
+  /// # Errors
+  /// Returns error listing every source tried if none holds the key
+  #[ inline ]
+  pub fn load_with_fallbacks( key_name : &str ) -> Result< Self >
+  {
+  Self::load_from_chain( key_name, &api_secret::SecretChain::standard() )
+  }
+
+  /// Load secret from a caller-built chain, e.g. a Vault or AWS Secrets
+  /// Manager fetch placed ahead of the standard chain
+  ///
+  /// # Errors
+  /// Returns error if no source in `chain` holds the key
+  #[ inline ]
+  pub fn load_from_chain( key_name : &str, chain : &api_secret::SecretChain ) -> Result< Self >
+  {
+  let value = chain.load( key_name )
+      .map_err( | e | HuggingFaceError::Authentication( e.to_string() ) )?;
+
+  Ok( Self { inner : value } )
+  }
+  
   /// Expose the secret value (use with caution)
   #[ inline ]
   #[ must_use ]
@@ -93,7 +119,7 @@ impl fmt::Debug for Secret
   fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
   {
   f.debug_struct( "Secret" )
-      .field( "inner", &"[REDACTED]" )
+      .field( "inner", &api_secret::Masked( self.inner.expose_secret() ) )
       .finish()
   }
 }
@@ -149,13 +175,13 @@ mod secret_serde
   }
 }
 
-/// Load `HuggingFace` API key from standard environment variable
+/// Load `HuggingFace` API key (`HUGGINGFACE_API_KEY`) through the shared fallback chain
 #[ inline ]
 /// # Errors
-/// Returns error if environment variable is not set or invalid
+/// Returns error if no workspace secrets file or environment variable holds the key
 pub fn load_huggingface_api_key() -> Result< Secret >
 {
-  Secret::load_from_env( "HUGGINGFACE_API_KEY" )
+  Secret::load_with_fallbacks( "HUGGINGFACE_API_KEY" )
 }
 
 } // end mod private
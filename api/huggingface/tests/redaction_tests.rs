@@ -0,0 +1,56 @@
+//! Tests that the API key never appears in full in `Debug` output.
+//!
+//! Every type holding a key is formatted with `{:?}` and `{:#?}` and the
+//! output is searched for the key past its four-character prefix.
+
+use api_huggingface::
+{
+  Client,
+  diagnostics::CurlOptions,
+  environment::{ HuggingFaceEnvironmentImpl, EnvironmentInterface },
+  secret::Secret,
+};
+
+const KEY : &str = "hf_redactionTest0123456789abcdefghij";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    assert!( !output.contains( &KEY[ 4.. ] ), "key in Debug output : {output}" );
+  }
+}
+
+fn environment() -> HuggingFaceEnvironmentImpl
+{
+  HuggingFaceEnvironmentImpl::build( Secret::new( KEY ), None ).expect( "Environment build should succeed" )
+}
+
+#[ test ]
+fn secret_debug_shows_prefix_only()
+{
+  let secret = Secret::new( KEY );
+  assert_hidden( &secret );
+  assert!( format!( "{secret:?}" ).contains( "\"hf_r****\"" ) );
+}
+
+#[ tokio::test ]
+async fn environment_and_client_debug_mask_key()
+{
+  assert_hidden( &environment() );
+  assert_hidden( &Client::build( environment() ).expect( "Client build should succeed" ) );
+}
+
+#[ test ]
+fn authorization_header_is_sensitive()
+{
+  let headers = environment().headers().expect( "Headers should build" );
+  assert!( headers[ reqwest::header::AUTHORIZATION ].is_sensitive() );
+  assert_hidden( &headers );
+}
+
+#[ test ]
+fn curl_options_debug_masks_key()
+{
+  assert_hidden( &CurlOptions::with_api_key( KEY ) );
+}
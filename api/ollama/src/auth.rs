@@ -42,12 +42,28 @@ mod private
 
   /// Configuration for secret management
   #[ cfg( feature = "secret_management" ) ]
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   pub struct SecretConfig
   {
     secrets : HashMap<  String, String  >,
   }
 
+  #[ cfg( feature = "secret_management" ) ]
+  impl core::fmt::Debug for SecretConfig
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let secrets : HashMap< &str, api_secret::Masked< '_ > > = self.secrets
+        .iter()
+        .map( | ( key, value ) | ( key.as_str(), api_secret::Masked( value ) ) )
+        .collect();
+      f.debug_struct( "SecretConfig" )
+        .field( "secrets", &secrets )
+        .finish()
+    }
+  }
+
   /// Workspace configuration for Ollama client
   #[ cfg( feature = "workspace" ) ]
   #[ derive( Debug, Clone, serde::Deserialize ) ]
@@ -59,7 +75,7 @@ mod private
 
   /// Ollama server configuration within workspace
   #[ cfg( feature = "workspace" ) ]
-  #[ derive( Debug, Clone, serde::Deserialize ) ]
+  #[ derive( Clone, serde::Deserialize ) ]
   pub struct OllamaServerConfig
   {
     /// Server URL for Ollama API
@@ -78,6 +94,22 @@ mod private
     pub api_key : Option< String >,
   }
 
+  #[ cfg( feature = "workspace" ) ]
+  impl core::fmt::Debug for OllamaServerConfig
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "OllamaServerConfig" )
+        .field( "server_url", &self.server_url )
+        .field( "default_model", &self.default_model )
+        .field( "timeout_secs", &self.timeout_secs )
+        .field( "models", &self.models )
+        .field( "api_key", &self.api_key.as_deref().map( api_secret::Masked ) )
+        .finish()
+    }
+  }
+
   // =====================================
   // Workspace Configuration Implementations
   // =====================================
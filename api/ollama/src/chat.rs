@@ -107,6 +107,65 @@ mod private
     /// Time taken for evaluation in nanoseconds
     pub eval_duration : Option< u64 >,
   }
+
+  /// The eval counts of a response; Ollama reports them on the final
+  /// `done` chunk only, so earlier chunks count as zero.
+  #[ cfg( feature = "common" ) ]
+  impl From< &ChatResponse > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( response : &ChatResponse ) -> Self
+    {
+      Self::new( response.prompt_eval_count.unwrap_or( 0 ).into(), response.eval_count.unwrap_or( 0 ).into() )
+    }
+  }
+
+  /// Translates one chat chunk into provider-neutral events.
+  ///
+  /// Message content becomes a text delta and each tool call (Ollama sends
+  /// them whole) one complete tool-call fragment indexed by position. The
+  /// final `done` chunk yields a usage update from the eval counts followed
+  /// by `Done` with `done_reason`.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for ChatResponse
+  {
+    #[ inline ]
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+    {
+      use api_common::{ StreamEvent, UsageUpdate };
+
+      let mut events = Vec::new();
+      #[ cfg( feature = "vision_support" ) ]
+      let content = Some( self.message.content );
+      #[ cfg( not( feature = "vision_support" ) ) ]
+      let content = self.message.map( | message | message.content );
+      if let Some( content ) = content.filter( | c | !c.is_empty() )
+      {
+        events.push( StreamEvent::TextDelta( content ) );
+      }
+      #[ cfg( all( feature = "vision_support", feature = "tool_calling" ) ) ]
+      for ( index, call ) in self.message.tool_calls.unwrap_or_default().into_iter().enumerate()
+      {
+        events.push( StreamEvent::ToolCallDelta( api_common::ToolCallDelta
+        {
+          index,
+          id : Some( call.id ).filter( | id | !id.is_empty() ),
+          name : call.function.get( "name" ).and_then( serde_json::Value::as_str ).map( str::to_owned ),
+          arguments : call.function.get( "arguments" ).map( ToString::to_string ).unwrap_or_default(),
+        }));
+      }
+      if self.done
+      {
+        events.push( StreamEvent::UsageUpdate( UsageUpdate
+        {
+          input_tokens : self.prompt_eval_count.map( u64::from ),
+          output_tokens : self.eval_count.map( u64::from ),
+        }));
+        events.push( StreamEvent::Done { finish_reason : self.done_reason } );
+      }
+      Ok( events )
+    }
+  }
 }
 
 #[ cfg( feature = "enabled" ) ]
@@ -33,6 +33,8 @@ mod private
     pub( crate ) retry_client : Option< RetryableHttpClient >,
     #[ cfg( feature = "rate_limiting" ) ]
     pub( crate ) rate_limiter : Option< RateLimiter >,
+    #[ cfg( feature = "rate_limiting" ) ]
+    pub( crate ) concurrency_gate : Option< api_common::ConcurrencyGate >,
     #[ cfg( feature = "audio_processing" ) ]
     pub( crate ) audio_config : Option< crate::audio::AudioProcessingConfig >,
     #[ cfg( feature = "cached_content" ) ]
@@ -73,6 +75,8 @@ mod private
         retry_client : None,
         #[ cfg( feature = "rate_limiting" ) ]
         rate_limiter : None,
+        #[ cfg( feature = "rate_limiting" ) ]
+        concurrency_gate : None,
         #[ cfg( feature = "audio_processing" ) ]
         audio_config : None,
         #[ cfg( feature = "cached_content" ) ]
@@ -119,6 +123,51 @@ mod private
       Duration::from_secs( 300 )
     }
 
+    /// Rebuild the HTTP client with connection tuning applied
+    ///
+    /// Open connections of the previous client are dropped; call
+    /// [`warm_up`](Self::warm_up) afterwards. Prior knowledge HTTP/2 suits a
+    /// plaintext HTTP/2 gateway in front of the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `tuning` names a proxy that is not a valid URL,
+    /// asks for HTTP/2 prior knowledge without the `http2` feature, or the
+    /// client cannot be built.
+    #[ cfg( feature = "connection" ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> OllamaResult< Self >
+    {
+      self.client = tuning.apply_to( reqwest::Client::builder() ).map_err( | e | format_err!( "{}", e ) )?
+        .build()
+        .map_err( | e | format_err!( "Failed to build HTTP client : {}", e ) )?;
+      Ok( self )
+    }
+
+    /// Open a connection to the server ahead of the first request
+    ///
+    /// Sends one `HEAD` request to the base URL, so the first real request
+    /// does not pay for connection setup, and returns its round-trip time.
+    /// Any HTTP status counts as success; only a failure to connect is an
+    /// error. Unlike [`is_available`](Self::is_available), no endpoint is
+    /// queried and no credentials are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no connection could be opened within the timeout.
+    #[ inline ]
+    pub async fn warm_up( &self ) -> OllamaResult< Duration >
+    {
+      let started = std::time::Instant::now();
+      self.client
+        .head( &self.base_url )
+        .timeout( self.timeout )
+        .send()
+        .await
+        .map_err( | e | format_err!( "Network error : {}", e ) )?;
+      Ok( started.elapsed() )
+    }
+
     /// Check if Ollama is available by pinging the tags endpoint
     #[ inline ]
     pub async fn is_available( &mut self ) -> bool
@@ -218,6 +267,10 @@ mod private
         }
       }
 
+      // Wait for a slot; the permit is held until the response is read
+      #[ cfg( feature = "rate_limiting" ) ]
+      let _permit = self.enter_concurrency_gate().await?;
+
       #[ cfg( feature = "failover" ) ]
       {
         // If failover is enabled, try multiple endpoints
@@ -409,6 +462,10 @@ mod private
         }
       }
 
+      // Wait for a slot; the permit is held until the response is read
+      #[ cfg( feature = "rate_limiting" ) ]
+      let _permit = self.enter_concurrency_gate().await?;
+
       let url = format!( "{}/api/generate", self.base_url );
 
       let request_builder = self.client
@@ -536,6 +593,9 @@ mod private
         }
       }
 
+      #[ cfg( feature = "rate_limiting" ) ]
+      let _permit = self.enter_concurrency_gate().await?;
+
       let url = format!( "{}/api/embeddings", self.base_url );
 
       let request_builder = self.client
@@ -562,6 +622,20 @@ mod private
       Ok( embeddings_response )
     }
 
+    /// Waits for a slot in the concurrency gate, when one is configured.
+    ///
+    /// The caller holds the returned permit until the response has been
+    /// read; for streams, until the stream is dropped.
+    #[ cfg( feature = "rate_limiting" ) ]
+    pub( crate ) async fn enter_concurrency_gate( &self ) -> OllamaResult< Option< api_common::ConcurrencyPermit > >
+    {
+      match &self.concurrency_gate
+      {
+        Some( gate ) => Ok( Some( gate.acquire().await? ) ),
+        None => Ok( None ),
+      }
+    }
+
     /// Get the base URL of this client
     #[ inline ]
     #[ must_use ]
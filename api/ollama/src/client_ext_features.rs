@@ -83,6 +83,31 @@ mod private
       self.rate_limiter.as_ref().map( |rl| rl.config() )
     }
 
+    /// Cap this client's in-flight requests with `gate`, queueing the rest
+    /// in arrival order.
+    ///
+    /// Clones of one gate share their slots, so a gate handed to every
+    /// client of the same server bounds the load on that server. Chat,
+    /// generate, and embeddings requests hold a slot until their response is
+    /// read; streaming requests until the stream is dropped.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_concurrency_gate( mut self, gate : api_common::ConcurrencyGate ) -> Self
+    {
+      self.concurrency_gate = Some( gate );
+      self
+    }
+
+    /// Get the concurrency gate if configured
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn concurrency_gate( &self ) -> Option< &api_common::ConcurrencyGate >
+    {
+      self.concurrency_gate.as_ref()
+    }
+
     /// Reset rate limiter state
     #[ cfg( feature = "rate_limiting" ) ]
     #[ inline ]
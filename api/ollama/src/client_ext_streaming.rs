@@ -23,6 +23,9 @@ mod private
   {
     inner : S,
     buffer : String,
+    /// Concurrency slot, only held so it is released when the stream is dropped
+    #[ cfg( feature = "rate_limiting" ) ]
+    permit : Option< api_common::ConcurrencyPermit >,
     _phantom : core::marker::PhantomData< ( T, B, E ) >,
   }
 
@@ -49,9 +52,18 @@ mod private
       {
         inner,
         buffer : String::new(),
+        #[ cfg( feature = "rate_limiting" ) ]
+        permit : None,
         _phantom : core::marker::PhantomData,
       }
     }
+
+    #[ cfg( feature = "rate_limiting" ) ]
+    fn holding( mut self, permit : Option< api_common::ConcurrencyPermit > ) -> Self
+    {
+      self.permit = permit;
+      self
+    }
   }
 
   impl< S, T, B, E > FuturesStream for LineBufferedJsonStream< S, T, B, E >
@@ -131,6 +143,8 @@ mod private
     pub async fn chat_stream( &mut self, mut request : ChatRequest ) -> OllamaResult< Pin< Box< dyn Stream< Item = OllamaResult< ChatResponse > > + Send > > >
     {
       request.stream = Some( true );
+      #[ cfg( feature = "rate_limiting" ) ]
+      let permit = self.enter_concurrency_gate().await?;
       let url = format!( "{}/api/chat", self.base_url );
 
       let request_builder = self.client
@@ -155,6 +169,8 @@ mod private
 
       let byte_stream = response.bytes_stream();
       let event_stream = LineBufferedJsonStream::new( byte_stream );
+      #[ cfg( feature = "rate_limiting" ) ]
+      let event_stream = event_stream.holding( permit );
 
       Ok( Box::pin( event_stream ) )
     }
@@ -168,6 +184,8 @@ mod private
     pub async fn generate_stream( &mut self, mut request : GenerateRequest ) -> OllamaResult< Pin< Box< dyn Stream< Item = OllamaResult< GenerateResponse > > + Send > > >
     {
       request.stream = Some( true );
+      #[ cfg( feature = "rate_limiting" ) ]
+      let permit = self.enter_concurrency_gate().await?;
       let url = format!( "{}/api/generate", self.base_url );
 
       let request_builder = self.client
@@ -192,6 +210,8 @@ mod private
 
       let byte_stream = response.bytes_stream();
       let event_stream = LineBufferedJsonStream::new( byte_stream );
+      #[ cfg( feature = "rate_limiting" ) ]
+      let event_stream = event_stream.holding( permit );
 
       Ok( Box::pin( event_stream ) )
     }
@@ -27,6 +27,60 @@ mod private
     /// Generated embedding vector
     pub embedding : Vec< f64 >,
   }
+
+  /// Owned embeddings client bound to one local model.
+  ///
+  /// Implements `api_common::Embedder`. Ollama's `/api/embeddings` endpoint
+  /// takes a single prompt, so `embed` sends one request per text, in order.
+  #[ cfg( feature = "common" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct OllamaEmbedder
+  {
+    client : crate::OllamaClient,
+    model : String,
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl OllamaEmbedder
+  {
+    /// Creates an embedder for `model` (e.g. `"nomic-embed-text"`).
+    #[ inline ]
+    pub fn new( client : crate::OllamaClient, model : impl Into< String > ) -> Self
+    {
+      Self { client, model : model.into() }
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  #[ async_trait::async_trait ]
+  impl api_common::Embedder for OllamaEmbedder
+  {
+    #[ inline ]
+    fn model( &self ) -> &str
+    {
+      &self.model
+    }
+
+    #[ inline ]
+    async fn embed( &self, texts : &[ String ] ) -> api_common::Result< Vec< api_common::Embedding > >
+    {
+      let mut client = self.client.clone();
+      let mut embeddings = Vec::with_capacity( texts.len() );
+      for text in texts
+      {
+        let request = crate::EmbeddingsRequest
+        {
+          model : self.model.clone(),
+          prompt : text.clone(),
+          options : None,
+        };
+        let response = client.embeddings( request ).await?;
+        #[ allow( clippy::cast_possible_truncation ) ]
+        embeddings.push( response.embedding.into_iter().map( | x | x as f32 ).collect() );
+      }
+      Ok( embeddings )
+    }
+  }
 }
 
 #[ cfg( feature = "embeddings" ) ]
@@ -37,4 +91,10 @@ crate ::mod_interface!
     EmbeddingsRequest,
     EmbeddingsResponse,
   };
+
+  #[ cfg( feature = "common" ) ]
+  exposed use
+  {
+    OllamaEmbedder,
+  };
 }
@@ -21,6 +21,7 @@ mod private
   use std::sync::{ Arc, Mutex };
   use std::pin::Pin;
   use std::future::Future;
+  use api_common::{ BudgetExhausted, BudgetTracker };
 
   /// Configuration for retry behavior
   #[ derive( Debug, Clone ) ]
@@ -321,6 +322,38 @@ mod private
     config : RetryConfig,
     metrics : Option< &RetryMetrics >
   ) -> std::result::Result< T, E >
+  where
+    F: Fn() -> Pin< Box< dyn Future< Output = std::result::Result< T, E > > + Send > > + Send + Sync,
+    E: std::fmt::Display + Send + Sync,
+  {
+    retry_loop( operation, config, metrics, None ).await
+  }
+
+  /// Execute an operation with retry logic inside a call budget shared with other layers
+  ///
+  /// Every attempt is counted against `budget` and cut off at its total deadline.
+  /// A retry the budget does not allow returns the last error instead of sleeping;
+  /// a refused first attempt or a deadline passing mid-attempt returns the
+  /// `BudgetExhausted` converted into `E`.
+  pub async fn execute_with_retries_within< F, T, E >(
+    operation : F,
+    config : RetryConfig,
+    metrics : Option< &RetryMetrics >,
+    budget : &BudgetTracker,
+  ) -> std::result::Result< T, E >
+  where
+    F: Fn() -> Pin< Box< dyn Future< Output = std::result::Result< T, E > > + Send > > + Send + Sync,
+    E: std::fmt::Display + Send + Sync + From< BudgetExhausted >,
+  {
+    retry_loop( operation, config, metrics, Some( ( budget, < E as From< BudgetExhausted > >::from ) ) ).await
+  }
+
+  async fn retry_loop< F, T, E >(
+    operation : F,
+    config : RetryConfig,
+    metrics : Option< &RetryMetrics >,
+    budget : Option< ( &BudgetTracker, fn( BudgetExhausted ) -> E ) >,
+  ) -> std::result::Result< T, E >
   where
     F: Fn() -> Pin< Box< dyn Future< Output = std::result::Result< T, E > > + Send > > + Send + Sync,
     E: std::fmt::Display + Send + Sync,
@@ -346,7 +379,13 @@ mod private
         m.record_attempt();
       }
 
-      match operation().await
+      let outcome = match budget
+      {
+        Some( ( budget, exhausted ) ) => budget.run_attempt( operation() ).await.map_err( exhausted )?,
+        None => operation().await,
+      };
+
+      match outcome
       {
         Ok( result ) =>
         {
@@ -391,6 +430,12 @@ mod private
           {
             let delay = calculate_retry_delay( attempt, &config );
 
+            // Stop rather than sleep past the shared budget
+            if budget.is_some_and( | ( budget, _ ) | budget.allows_delay( delay ).is_err() )
+            {
+              break;
+            }
+
             if config.log_attempts
             {
               println!( "⏳ Waiting {:?} before retry attempt {}", delay, attempt + 2 );
@@ -402,7 +447,7 @@ mod private
               m.record_delay( delay );
             }
 
-            tokio ::time::sleep( delay ).await;
+            api_common::sleep( delay ).await;
           }
         }
       }
@@ -467,6 +512,28 @@ mod private
       }
     }
 
+    /// Execute an HTTP operation with retries if configured, inside a call budget
+    /// shared with other layers
+    ///
+    /// Without a retry configuration the operation still runs as one budgeted attempt.
+    pub async fn execute_within< F, T, E >( &self, budget : &BudgetTracker, operation : F ) -> std::result::Result< T, E >
+    where
+      F: Fn() -> Pin< Box< dyn Future< Output = std::result::Result< T, E > > + Send > > + Send + Sync,
+      E: std::fmt::Display + Send + Sync + From< BudgetExhausted >,
+    {
+      match &self.config
+      {
+        Some( config ) =>
+        {
+          execute_with_retries_within( operation, config.clone(), Some( &self.metrics ), budget ).await
+        }
+        None =>
+        {
+          budget.run_attempt( operation() ).await?
+        }
+      }
+    }
+
     /// Get retry metrics
     #[ inline ]
     #[ must_use ]
@@ -570,6 +637,7 @@ crate ::mod_interface!
   exposed use private::ErrorClassifier;
   exposed use private::RetryableHttpClient;
   exposed use private::execute_with_retries;
+  exposed use private::execute_with_retries_within;
   exposed use private::calculate_retry_delay;
   exposed use private::retry_operation;
 
@@ -408,7 +408,7 @@ mod private
     /// Health metrics
     metrics : Arc< Mutex< HealthMetrics > >,
     /// Background task handle
-    task_handle : Option< tokio::task::JoinHandle< () > >,
+    task_handle : Option< api_common::JoinHandle< () > >,
     /// Shutdown signal sender
     shutdown_tx : Option< tokio::sync::oneshot::Sender< () > >,
     /// Endpoint URL for health checks
@@ -465,16 +465,18 @@ mod private
       let client = self.client.clone();
       let simulate_failure = self.simulate_failure.clone();
 
-      let handle = tokio::spawn( async move
+      let handle = api_common::spawn( async move
       {
-        let mut interval = tokio::time::interval( config.interval );
+        // First check runs immediately, then every `config.interval`
+        let mut delay = Duration::ZERO;
 
         loop
         {
           tokio ::select! {
-            _ = interval.tick()
+            () = api_common::sleep( delay )
             =>
             {
+              delay = config.interval;
               let start_time = std::time::Instant::now();
               let success = if simulate_failure.load( std::sync::atomic::Ordering::Relaxed )
               {
@@ -316,6 +316,11 @@ crate ::mod_interface!
     embeddings ::EmbeddingsRequest,
     embeddings ::EmbeddingsResponse,
   };
+  #[ cfg( all( feature = "embeddings", feature = "common" ) ) ]
+  exposed use
+  {
+    embeddings ::OllamaEmbedder,
+  };
   #[ cfg( feature = "builder_patterns" ) ]
   exposed use
   {
@@ -338,6 +343,7 @@ crate ::mod_interface!
     ErrorClassifier,
     RetryableHttpClient,
     execute_with_retries,
+    execute_with_retries_within,
     calculate_retry_delay,
     retry_operation,
   };
@@ -20,6 +20,20 @@ mod private
     pub content : String,
   }
 
+  #[ cfg( feature = "common" ) ]
+  impl From< api_common::NeutralMessage > for Message
+  {
+    #[ inline ]
+    fn from( message : api_common::NeutralMessage ) -> Self
+    {
+      Self
+      {
+        role : message.role.as_str().to_string(),
+        content : message.content,
+      }
+    }
+  }
+
   /// Message roles for vision-enabled chat
   #[ cfg( feature = "vision_support" ) ]
   #[ derive( Debug, Clone, Serialize, Deserialize, Default, PartialEq ) ]
@@ -58,6 +72,27 @@ mod private
     pub tool_calls : Option< Vec< ToolCall > >,
   }
 
+  #[ cfg( all( feature = "vision_support", feature = "common" ) ) ]
+  impl From< api_common::NeutralMessage > for ChatMessage
+  {
+    #[ inline ]
+    fn from( message : api_common::NeutralMessage ) -> Self
+    {
+      let role = match message.role
+      {
+        api_common::NeutralRole::System    => MessageRole::System,
+        api_common::NeutralRole::User      => MessageRole::User,
+        api_common::NeutralRole::Assistant => MessageRole::Assistant,
+      };
+      Self
+      {
+        role,
+        content : message.content,
+        ..Default::default()
+      }
+    }
+  }
+
   /// Tool definition for function calling
   #[ cfg( feature = "tool_calling" ) ]
   #[ derive( Debug, Clone, Serialize, Deserialize ) ]
@@ -371,9 +371,9 @@ mod private
       if let Some( timeout ) = self.timeout
       {
         let control_clone = self.clone();
-        tokio ::spawn( async move
+        api_common::spawn( async move
         {
-          tokio ::time::sleep( timeout ).await;
+          api_common::sleep( timeout ).await;
           let current_state = control_clone.state().await;
           if current_state == StreamState::Paused
           {
@@ -91,7 +91,7 @@ pub struct WebSocketMetrics
 }
 
 /// WebSocket configuration
-#[ derive( Debug, Clone ) ]
+#[ derive( Clone ) ]
 pub struct WebSocketConfig
 {
   /// WebSocket server URL
@@ -118,6 +118,27 @@ pub struct WebSocketConfig
   pub http_fallback_url : Option< String >,
 }
 
+impl core::fmt::Debug for WebSocketConfig
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.debug_struct( "WebSocketConfig" )
+      .field( "url", &self.url )
+      .field( "timeout", &self.timeout )
+      .field( "max_reconnection_attempts", &self.max_reconnection_attempts )
+      .field( "reconnection_delay", &self.reconnection_delay )
+      .field( "enable_compression", &self.enable_compression )
+      .field( "heartbeat_interval", &self.heartbeat_interval )
+      .field( "max_queue_size", &self.max_queue_size )
+      .field( "auth_token", &self.auth_token.as_deref().map( api_secret::Masked ) )
+      .field( "enable_auto_reconnect", &self.enable_auto_reconnect )
+      .field( "pool_size", &self.pool_size )
+      .field( "http_fallback_url", &self.http_fallback_url )
+      .finish()
+  }
+}
+
 /// Message queue entry for reliable delivery
 #[ derive( Debug, Clone ) ]
 pub struct QueuedMessage
@@ -181,7 +202,7 @@ pub struct ConnectionPool
 }
 
 /// Authentication context for WebSocket connections
-#[ derive( Debug, Clone ) ]
+#[ derive( Clone ) ]
 pub struct WebSocketAuth
 {
   /// Authentication token
@@ -194,6 +215,20 @@ pub struct WebSocketAuth
   pub scheme : String,
 }
 
+impl core::fmt::Debug for WebSocketAuth
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.debug_struct( "WebSocketAuth" )
+      .field( "token", &self.token.as_deref().map( api_secret::Masked ) )
+      .field( "expires_at", &self.expires_at )
+      .field( "refresh_token", &self.refresh_token.as_deref().map( api_secret::Masked ) )
+      .field( "scheme", &self.scheme )
+      .finish()
+  }
+}
+
 /// WebSocket authentication method
 #[ derive( Debug, Clone ) ]
 pub enum WebSocketAuthMethod
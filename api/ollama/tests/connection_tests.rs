@@ -0,0 +1,44 @@
+//! Connection tests for `api_ollama`
+//!
+//! Offline tests cover the failure path of `warm_up` and the rebuilding of
+//! the HTTP client from a `ConnectionTuning`; the `integration_tests` test
+//! warms a connection to the Ollama server managed by `server_helpers`.
+
+#![ cfg( feature = "enabled" ) ]
+
+#[ cfg( feature = "integration_tests" ) ]
+mod server_helpers;
+
+use api_ollama::OllamaClient;
+use core::time::Duration;
+
+#[ tokio::test ]
+async fn test_warm_up_fails_when_nothing_listens()
+{
+  let client = OllamaClient::new( "http://127.0.0.1:1".to_string(), Duration::from_secs( 5 ) );
+  assert!( client.warm_up().await.is_err() );
+}
+
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn test_connection_tuning_rebuilds_client()
+{
+  let client = OllamaClient::new( "http://127.0.0.1:1".to_string(), Duration::from_secs( 5 ) )
+    .with_connection_tuning( api_common::ConnectionTuning::low_latency() );
+  assert!( client.is_ok(), "{:?}", client.err() );
+
+  let proxy = OllamaClient::new( "http://127.0.0.1:1".to_string(), Duration::from_secs( 5 ) )
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+  assert!( proxy.is_err(), "a malformed proxy URL must be refused" );
+}
+
+#[ cfg( feature = "integration_tests" ) ]
+#[ tokio::test ]
+async fn test_warm_up_reaches_real_server()
+{
+  with_test_server!( | client : OllamaClient, _model : String | async move
+  {
+    let warmed = client.warm_up().await;
+    assert!( warmed.is_ok(), "warm-up against a running Ollama server must succeed : {:?}", warmed.err() );
+  } );
+}
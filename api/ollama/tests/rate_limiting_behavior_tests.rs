@@ -447,3 +447,54 @@ async fn test_rate_limiting_metrics_integration()
   client.reset_rate_limiter();
   println!( "✓ Rate limiter state can be reset" );
 }
+
+/// Test that a full concurrency gate queues requests and gives up after the queue timeout
+#[ tokio::test ]
+async fn test_concurrency_gate_queue_timeout()
+{
+  let gate = api_common::ConcurrencyGate::new( 1 ).with_queue_timeout( Duration::from_millis( 50 ) );
+  let mut client = OllamaClient::new(
+    "http://unreachable.test:99999".to_string(),
+    Duration::from_millis( 100 )
+  ).with_concurrency_gate( gate.clone() );
+
+  let request = ChatRequest
+  {
+    model : "test-model".to_string(),
+    messages : vec!
+    [
+      ChatMessage
+      {
+        role : MessageRole::User,
+        content : "Test concurrency gate".to_string(),
+        #[ cfg( feature = "vision_support" ) ]
+        images : None,
+        #[ cfg( feature = "tool_calling" ) ]
+        tool_calls : None,
+      }
+    ],
+    stream : Some( false ),
+    options : None,
+    #[ cfg( feature = "tool_calling" ) ]
+    tools : None,
+    #[ cfg( feature = "tool_calling" ) ]
+    tool_messages : None,
+  };
+
+  // Another client sharing the gate holds its only slot
+  let held = gate.try_acquire().expect( "gate should start empty" );
+
+  let start_time = Instant::now();
+  let error_msg = client.chat( request.clone() ).await.unwrap_err().to_string();
+  assert!( error_msg.contains( "queue" ), "Request should time out in the queue : {error_msg}" );
+  assert!( start_time.elapsed() >= Duration::from_millis( 50 ) );
+  assert_eq!( gate.queued(), 0 );
+
+  // Once the slot is free the request reaches the network (and fails there)
+  drop( held );
+  let error_msg = client.chat( request ).await.unwrap_err().to_string();
+  assert!( !error_msg.contains( "queue" ), "Request should not be queued : {error_msg}" );
+  assert_eq!( gate.in_flight(), 0 );
+
+  println!( "✓ Concurrency gate queues requests and honours the queue timeout" );
+}
@@ -0,0 +1,61 @@
+//! Redaction tests for `api_ollama`
+//!
+//! These tests verify that keys and tokens never appear in full in
+//! `Debug` output; at most their first four characters remain.
+
+#![ cfg( feature = "enabled" ) ]
+
+const KEY : &str = "sk-redaction-test-0123456789abcdef";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    assert!( !output.contains( &KEY[ 4.. ] ), "key in Debug output : {output}" );
+  }
+}
+
+#[ cfg( feature = "secret_management" ) ]
+#[ test ]
+fn test_secret_config_debug_masks_values()
+{
+  let config = api_ollama::SecretConfig::new().with_secret( "api_key", KEY );
+  assert_hidden( &config );
+  assert!( format!( "{config:?}" ).contains( "\"sk-r****\"" ) );
+}
+
+#[ cfg( feature = "workspace" ) ]
+#[ test ]
+fn test_server_config_debug_masks_api_key()
+{
+  let config = api_ollama::auth::OllamaServerConfig
+  {
+    server_url : "http://localhost:11434".to_string(),
+    default_model : None,
+    timeout_secs : None,
+    models : Vec::new(),
+    api_key : Some( KEY.to_string() ),
+  };
+  assert_hidden( &config );
+}
+
+#[ cfg( feature = "websocket_streaming" ) ]
+#[ test ]
+fn test_websocket_debug_masks_tokens()
+{
+  use api_ollama::websocket::{ WebSocketAuth, WebSocketConfig };
+
+  let config = WebSocketConfig
+  {
+    auth_token : Some( KEY.to_string() ),
+    ..WebSocketConfig::default()
+  };
+  assert_hidden( &config );
+  assert_hidden( &WebSocketAuth
+  {
+    token : Some( KEY.to_string() ),
+    expires_at : None,
+    refresh_token : Some( KEY.to_string() ),
+    scheme : "Bearer".to_string(),
+  });
+}
@@ -42,7 +42,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       Tool::WebSearch( WebSearchTool::default() ),
     ]
   )
-  .tool_choice( ToolChoice::String( "auto".to_string() ) )
+  .tool_choice( ToolChoice::auto() )
   .form();
 
   println!( "Sending request with web search tool..." );
@@ -4,6 +4,12 @@
 //! including user management, project management, and organizational operations.
 //! Following the "Thin Client, Rich API" principle, this module offers direct access
 //! to `OpenAI`'s administrative endpoints without automatic behaviors.
+//!
+//! These endpoints only accept an admin API key (`sk-admin-...`), not a
+//! project or user key: build the `Client` used for `client.admin()` from
+//! `Secret::load_admin_key`.
+//!
+//! For more details, refer to the [`OpenAI` Administration API documentation](https://platform.openai.com/docs/api-reference/administration).
 
 use mod_interface::mod_interface;
 
@@ -14,7 +20,45 @@ mod private
     environment ::{ EnvironmentInterface, OpenaiEnvironment },
     error ::Result,
   };
+  use crate::components::administration_shared::
+  {
+    AdminApiKey,
+    AdminApiKeyCreateRequest,
+    AdminListQuery,
+    ApiKeyList,
+    Invite,
+    InviteListResponse,
+    InviteRequest,
+    ListProjectsQuery,
+    Project,
+    ProjectApiKey,
+    ProjectApiKeyListResponse,
+    ProjectCreateRequest,
+    ProjectListResponse,
+    ProjectServiceAccount,
+    ProjectServiceAccountCreateRequest,
+    ProjectServiceAccountCreateResponse,
+    ProjectServiceAccountListResponse,
+    ProjectUpdateRequest,
+    ProjectUser,
+    ProjectUserCreateRequest,
+    ProjectUserListResponse,
+    ProjectUserUpdateRequest,
+    User,
+    UserListResponse,
+    UserRoleUpdateRequest,
+  };
+  use crate::components::common::
+  {
+    AdminApiKeyDeleteResponse,
+    InviteDeleteResponse,
+    ProjectApiKeyDeleteResponse,
+    ProjectServiceAccountDeleteResponse,
+    ProjectUserDeleteResponse,
+    UserDeleteResponse,
+  };
   use serde::{ Deserialize, Serialize };
+  use serde::de::DeserializeOwned;
 
   /// Organization entity
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
@@ -58,24 +102,6 @@ mod private
     pub max_daily_requests : Option< u64 >,
   }
 
-  /// User entity within organization
-  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
-  pub struct User
-  {
-    /// Object type identifier
-    pub object : String,
-    /// Unique user identifier
-    pub id : String,
-    /// User display name
-    pub name : String,
-    /// User email address
-    pub email : String,
-    /// User role in organization
-    pub role : UserRole,
-    /// Unix timestamp when user was added
-    pub added_at : u64,
-  }
-
   /// User roles within organization
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub enum UserRole
@@ -94,24 +120,6 @@ mod private
     Reader,
   }
 
-  /// Project entity
-  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
-  pub struct Project
-  {
-    /// Unique project identifier
-    pub id : String,
-    /// Object type identifier
-    pub object : String,
-    /// Project name
-    pub name : String,
-    /// Unix timestamp when created
-    pub created_at : u64,
-    /// Unix timestamp when archived (if archived)
-    pub archived_at : Option< u64 >,
-    /// Current project status
-    pub status : ProjectStatus,
-  }
-
   /// Project status enumeration
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub enum ProjectStatus
@@ -124,26 +132,6 @@ mod private
     Archived,
   }
 
-  /// Invite entity for user invitations
-  #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
-  pub struct Invite
-  {
-    /// Object type identifier
-    pub object : String,
-    /// Unique invite identifier
-    pub id : String,
-    /// Email address of invitee
-    pub email : String,
-    /// Role to be assigned upon acceptance
-    pub role : UserRole,
-    /// Invite status
-    pub status : InviteStatus,
-    /// Unix timestamp when invite was sent
-    pub invited_at : u64,
-    /// Unix timestamp when invite expires
-    pub expires_at : u64,
-  }
-
   /// Invite status enumeration
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub enum InviteStatus
@@ -159,34 +147,6 @@ mod private
     Expired,
   }
 
-  /// Request to update organization
-  #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-  pub struct OrganizationUpdate
-  {
-    /// New organization name
-    pub name : Option< String >,
-    /// New organization description
-    pub description : Option< String >,
-    /// Updated settings
-    pub settings : Option< OrganizationSettings >,
-  }
-
-  /// Request to create a new project
-  #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-  pub struct CreateProjectRequest
-  {
-    /// Project name
-    pub name : String,
-  }
-
-  /// Request to update project
-  #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-  pub struct ProjectUpdate
-  {
-    /// New project name
-    pub name : Option< String >,
-  }
-
   /// Generic delete response
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub struct DeleteResponse
@@ -199,22 +159,6 @@ mod private
     pub deleted : bool,
   }
 
-  /// List response wrapper
-  #[ derive( Debug, Clone, Serialize, Deserialize ) ]
-  pub struct ListResponse< T >
-  {
-    /// Object type identifier
-    pub object : String,
-    /// List of items
-    pub data : Vec< T >,
-    /// Whether there are more items available
-    pub has_more : bool,
-    /// First item ID for pagination
-    pub first_id : Option< String >,
-    /// Last item ID for pagination
-    pub last_id : Option< String >,
-  }
-
   /// Administrative API client
   #[ derive( Debug ) ]
   pub struct Admin< 'client, E >
@@ -236,262 +180,423 @@ mod private
     }
 
     // ================================
-    // Organizations API
+    // Users API
     // ================================
 
-    /// List all organizations accessible to the authenticated user
+    /// List the users of the organization
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails or if the response cannot be parsed.
+    /// Returns an error if the API request fails, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn list_organizations( &self ) -> Result< Vec< Organization > >
+    pub async fn list_users( &self, query : Option< AdminListQuery > ) -> Result< UserListResponse >
     {
-      let response : ListResponse< Organization > = self.client.get( "organizations" ).await?;
-      Ok( response.data )
+      self.list( "organization/users", query ).await
     }
 
-    /// Retrieve details of a specific organization
+    /// Retrieve a user of the organization
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the organization is not found,
+    /// Returns an error if the API request fails, the user is not found,
     /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn get_organization( &self, org_id : &str ) -> Result< Organization >
+    pub async fn retrieve_user( &self, user_id : &str ) -> Result< User >
     {
-      let path = format!( "/organizations/{org_id}" );
-      let organization : Organization = self.client.get( &path ).await?;
-      Ok( organization )
+      let path = format!( "organization/users/{user_id}" );
+      self.client.get( &path ).await
     }
 
-    /// Update organization details
+    /// Change the organization role of a user
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the organization is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, the user is not found, invalid role,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn update_organization(
-      &self,
-      org_id : &str,
-      update : OrganizationUpdate
-    ) -> Result< Organization >
+    pub async fn modify_user( &self, user_id : &str, request : UserRoleUpdateRequest ) -> Result< User >
     {
-      let path = format!( "/organizations/{org_id}" );
-      let organization : Organization = self.client.post( &path, &update ).await?;
-      Ok( organization )
+      let path = format!( "organization/users/{user_id}" );
+      self.client.post( &path, &request ).await
     }
 
-    /// Delete an organization
+    /// Remove a user from the organization
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the organization is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, the user is not found, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn delete_organization( &self, org_id : &str ) -> Result< DeleteResponse >
+    pub async fn delete_user( &self, user_id : &str ) -> Result< UserDeleteResponse >
     {
-      let path = format!( "/organizations/{org_id}" );
-      let response : DeleteResponse = self.client.delete( &path ).await?;
-      Ok( response )
+      let path = format!( "organization/users/{user_id}" );
+      self.client.delete( &path ).await
     }
 
     // ================================
-    // Users API
+    // Invites API
     // ================================
 
-    /// List all users in an organization
+    /// List the invites of the organization
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the organization is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn list_users( &self, org_id : &str ) -> Result< Vec< User > >
+    pub async fn list_invites( &self, query : Option< AdminListQuery > ) -> Result< InviteListResponse >
     {
-      let path = format!( "/organizations/{org_id}/users" );
-      let response : ListResponse< User > = self.client.get( &path ).await?;
-      Ok( response.data )
+      self.list( "organization/invites", query ).await
     }
 
-    /// Retrieve details of a specific user
+    /// Invite a user to the organization, and optionally to some of its projects
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the user is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, invalid email address, invalid role, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn get_user( &self, user_id : &str ) -> Result< User >
+    pub async fn create_invite( &self, request : InviteRequest ) -> Result< Invite >
     {
-      let path = format!( "/organization/users/{user_id}" );
-      let user : User = self.client.get( &path ).await?;
-      Ok( user )
+      self.client.post( "organization/invites", &request ).await
     }
 
-    /// Update user role in organization
+    /// Retrieve an invite
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the user is not found,
-    /// insufficient permissions, invalid role, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, the invite is not found,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn update_user( &self, user_id : &str, role : UserRole ) -> Result< User >
+    pub async fn retrieve_invite( &self, invite_id : &str ) -> Result< Invite >
     {
-      let path = format!( "/organization/users/{user_id}" );
-      let update_data = serde_json::json!( { "role": role } );
-      let user : User = self.client.post( &path, &update_data ).await?;
-      Ok( user )
+      let path = format!( "organization/invites/{invite_id}" );
+      self.client.get( &path ).await
     }
 
-    /// Remove user from organization
+    /// Delete a pending invite
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the user is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, the invite is not found, invite has already been accepted,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn delete_user( &self, user_id : &str ) -> Result< DeleteResponse >
+    pub async fn delete_invite( &self, invite_id : &str ) -> Result< InviteDeleteResponse >
     {
-      let path = format!( "/organization/users/{user_id}" );
-      let response : DeleteResponse = self.client.delete( &path ).await?;
-      Ok( response )
+      let path = format!( "organization/invites/{invite_id}" );
+      self.client.delete( &path ).await
     }
 
     // ================================
     // Projects API
     // ================================
 
-    /// List projects (optionally filtered by organization)
+    /// List the projects of the organization; archived projects only when
+    /// `include_archived` is set
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the organization is not found (when specified),
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn list_projects( &self, org_id : Option< &str > ) -> Result< Vec< Project > >
+    pub async fn list_projects( &self, query : Option< ListProjectsQuery > ) -> Result< ProjectListResponse >
     {
-      let path = if let Some( org_id ) = org_id
-      {
-        format!( "/organizations/{org_id}/projects" )
-      }
-      else
-      {
-        "/organization/projects".to_string()
-      };
-
-      let response : ListResponse< Project > = self.client.get( &path ).await?;
-      Ok( response.data )
+      self.list( "organization/projects", query ).await
     }
 
     /// Create a new project
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, invalid project name,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, invalid project name, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn create_project( &self, request : CreateProjectRequest ) -> Result< Project >
+    pub async fn create_project( &self, request : ProjectCreateRequest ) -> Result< Project >
     {
-      let project : Project = self.client.post( "organization/projects", &request ).await?;
-      Ok( project )
+      self.client.post( "organization/projects", &request ).await
     }
 
-    /// Retrieve details of a specific project
+    /// Retrieve a project
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails, the project is not found,
-    /// insufficient permissions, or if the response cannot be parsed.
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn retrieve_project( &self, project_id : &str ) -> Result< Project >
+    {
+      let path = format!( "organization/projects/{project_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Rename a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project is not found, invalid project name,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn modify_project( &self, project_id : &str, request : ProjectUpdateRequest ) -> Result< Project >
+    {
+      let path = format!( "organization/projects/{project_id}" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Archive a project; projects cannot be deleted, and an archived project
+    /// cannot be used or updated
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project is not found, the project is the default project,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn get_project( &self, project_id : &str ) -> Result< Project >
+    pub async fn archive_project( &self, project_id : &str ) -> Result< Project >
     {
-      let path = format!( "/organization/projects/{project_id}" );
-      let project : Project = self.client.get( &path ).await?;
-      Ok( project )
+      let path = format!( "organization/projects/{project_id}/archive" );
+      self.client.post_no_body( &path ).await
     }
 
-    /// Update project details
+    // ================================
+    // Project Users API
+    // ================================
+
+    /// List the users of a project
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails, the project is not found,
-    /// invalid update parameters, insufficient permissions, or if the response cannot be parsed.
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn list_project_users( &self, project_id : &str, query : Option< AdminListQuery > ) -> Result< ProjectUserListResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/users" );
+      self.list( &path, query ).await
+    }
+
+    /// Add an organization user to a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the user is not in the organization, invalid role,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn update_project(
-      &self,
-      project_id : &str,
-      update : ProjectUpdate
-    ) -> Result< Project >
+    pub async fn create_project_user( &self, project_id : &str, request : ProjectUserCreateRequest ) -> Result< ProjectUser >
     {
-      let path = format!( "/organization/projects/{project_id}" );
-      let project : Project = self.client.post( &path, &update ).await?;
-      Ok( project )
+      let path = format!( "organization/projects/{project_id}/users" );
+      self.client.post( &path, &request ).await
     }
 
-    /// Archive a project (soft delete)
+    /// Retrieve a user of a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the user is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn retrieve_project_user( &self, project_id : &str, user_id : &str ) -> Result< ProjectUser >
+    {
+      let path = format!( "organization/projects/{project_id}/users/{user_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Change the project role of a user
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the user is not found, invalid role,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn modify_project_user( &self, project_id : &str, user_id : &str, request : ProjectUserUpdateRequest ) -> Result< ProjectUser >
+    {
+      let path = format!( "organization/projects/{project_id}/users/{user_id}" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Remove a user from a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the user is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn delete_project_user( &self, project_id : &str, user_id : &str ) -> Result< ProjectUserDeleteResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/users/{user_id}" );
+      self.client.delete( &path ).await
+    }
+
+    // ================================
+    // Project Service Accounts API
+    // ================================
+
+    /// List the service accounts of a project
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails, the project is not found,
-    /// project is already archived, insufficient permissions, or if the response cannot be parsed.
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn archive_project( &self, project_id : &str ) -> Result< Project >
+    pub async fn list_project_service_accounts( &self, project_id : &str, query : Option< AdminListQuery > ) -> Result< ProjectServiceAccountListResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/service_accounts" );
+      self.list( &path, query ).await
+    }
+
+    /// Create a service account in a project; the response holds its API key,
+    /// which is never returned again
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project is not found, insufficient permissions,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn create_project_service_account( &self, project_id : &str, request : ProjectServiceAccountCreateRequest ) -> Result< ProjectServiceAccountCreateResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/service_accounts" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Retrieve a service account of a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the service account is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn retrieve_project_service_account( &self, project_id : &str, service_account_id : &str ) -> Result< ProjectServiceAccount >
     {
-      let path = format!( "/organization/projects/{project_id}/archive" );
-      let project : Project = self.client.post( &path, &serde_json::json!( {} ) ).await?;
-      Ok( project )
+      let path = format!( "organization/projects/{project_id}/service_accounts/{service_account_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Delete a service account of a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the service account is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn delete_project_service_account( &self, project_id : &str, service_account_id : &str ) -> Result< ProjectServiceAccountDeleteResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/service_accounts/{service_account_id}" );
+      self.client.delete( &path ).await
     }
 
     // ================================
-    // Invites API
+    // Project API Keys API
     // ================================
 
-    /// Send an invitation to join the organization
+    /// List the API keys of a project
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn list_project_api_keys( &self, project_id : &str, query : Option< AdminListQuery > ) -> Result< ProjectApiKeyListResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/api_keys" );
+      self.list( &path, query ).await
+    }
+
+    /// Retrieve an API key of a project
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, invalid email address,
-    /// invalid role, insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, the project or the key is not found,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn send_invite( &self, email : &str, role : UserRole ) -> Result< Invite >
+    pub async fn retrieve_project_api_key( &self, project_id : &str, key_id : &str ) -> Result< ProjectApiKey >
     {
-      let invite_data = serde_json::json!({
-        "email": email,
-        "role": role
-      });
+      let path = format!( "organization/projects/{project_id}/api_keys/{key_id}" );
+      self.client.get( &path ).await
+    }
 
-      let invite : Invite = self.client.post( "organization/invites", &invite_data ).await?;
-      Ok( invite )
+    /// Delete an API key of a project; keys owned by a service account are
+    /// deleted with the service account instead
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the project or the key is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn delete_project_api_key( &self, project_id : &str, key_id : &str ) -> Result< ProjectApiKeyDeleteResponse >
+    {
+      let path = format!( "organization/projects/{project_id}/api_keys/{key_id}" );
+      self.client.delete( &path ).await
     }
 
-    /// List all pending invites for the organization
+    // ================================
+    // Admin API Keys API
+    // ================================
+
+    /// List the admin API keys of the organization
     ///
     /// # Errors
     ///
     /// Returns an error if the API request fails, insufficient permissions,
     /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn list_invites( &self ) -> Result< Vec< Invite > >
+    pub async fn list_admin_api_keys( &self, query : Option< AdminListQuery > ) -> Result< ApiKeyList >
     {
-      let response : ListResponse< Invite > = self.client.get( "organization/invites" ).await?;
-      Ok( response.data )
+      self.list( "organization/admin_api_keys", query ).await
     }
 
-    /// Cancel/delete an invitation
+    /// Create an admin API key; the response holds its `value`, which is
+    /// never returned again
     ///
     /// # Errors
     ///
-    /// Returns an error if the API request fails, the invite is not found,
-    /// invite has already expired, insufficient permissions, or if the response cannot be parsed.
+    /// Returns an error if the API request fails, insufficient permissions,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn create_admin_api_key( &self, request : AdminApiKeyCreateRequest ) -> Result< AdminApiKey >
+    {
+      self.client.post( "organization/admin_api_keys", &request ).await
+    }
+
+    /// Retrieve an admin API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the key is not found,
+    /// or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn retrieve_admin_api_key( &self, key_id : &str ) -> Result< AdminApiKey >
+    {
+      let path = format!( "organization/admin_api_keys/{key_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Delete an admin API key
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails, the key is not found, insufficient permissions,
+    /// or if the response cannot be parsed.
     #[ inline ]
-    pub async fn delete_invite( &self, invite_id : &str ) -> Result< DeleteResponse >
+    pub async fn delete_admin_api_key( &self, key_id : &str ) -> Result< AdminApiKeyDeleteResponse >
     {
-      let path = format!( "/organization/invites/{invite_id}" );
-      let response : DeleteResponse = self.client.delete( &path ).await?;
-      Ok( response )
+      let path = format!( "organization/admin_api_keys/{key_id}" );
+      self.client.delete( &path ).await
     }
 
+    /// Lists `path`, with the pagination query when there is one.
+    async fn list< Q, O >( &self, path : &str, query : Option< Q > ) -> Result< O >
+    where
+      Q : Serialize + Sync,
+      O : DeserializeOwned,
+    {
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( path, &q ).await
+      }
+      else
+      {
+        self.client.get( path ).await
+      }
+    }
   }
 
   // ================================
@@ -656,17 +761,10 @@ mod_interface!
     Organization,
     OrganizationSettings,
     UsageLimits,
-    User,
     UserRole,
-    Project,
     ProjectStatus,
-    Invite,
     InviteStatus,
-    OrganizationUpdate,
-    CreateProjectRequest,
-    ProjectUpdate,
     DeleteResponse,
-    ListResponse,
     Admin,
     validate_permission,
     role_level,
@@ -677,7 +775,6 @@ use crate::
 {
   client ::Client,
   environment ::{ EnvironmentInterface, OpenaiEnvironment },
-  error ::Result,
 };
 
 impl< E > Client< E >
@@ -685,69 +782,11 @@ where
   E: OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
 {
   /// Access the administrative API
+  ///
+  /// The client must be built with an admin API key, see `Secret::load_admin_key`.
   #[ inline ]
   pub fn admin( &self ) -> Admin< '_, E >
   {
     Admin::new( self )
   }
-
-  /// List organizations (convenience method)
-  ///
-  /// # Errors
-  ///
-  /// Returns an error if the API request fails or if the response cannot be parsed.
-  #[ inline ]
-  pub async fn list_organizations( &self ) -> Result< Vec< Organization > >
-  {
-    self.admin().list_organizations().await
-  }
-
-  /// Get organization details (convenience method)
-  ///
-  /// # Errors
-  ///
-  /// Returns an error if the API request fails, the organization is not found,
-  /// or if the response cannot be parsed.
-  #[ inline ]
-  pub async fn get_organization( &self, org_id : &str ) -> Result< Organization >
-  {
-    self.admin().get_organization( org_id ).await
-  }
-
-  /// List users in organization (convenience method)
-  ///
-  /// # Errors
-  ///
-  /// Returns an error if the API request fails, the organization is not found,
-  /// insufficient permissions, or if the response cannot be parsed.
-  #[ inline ]
-  pub async fn list_users( &self, org_id : &str ) -> Result< Vec< User > >
-  {
-    self.admin().list_users( org_id ).await
-  }
-
-  /// List projects (convenience method)
-  ///
-  /// # Errors
-  ///
-  /// Returns an error if the API request fails, the organization is not found (when specified),
-  /// insufficient permissions, or if the response cannot be parsed.
-  #[ inline ]
-  pub async fn list_projects( &self, org_id : Option< &str > ) -> Result< Vec< Project > >
-  {
-    self.admin().list_projects( org_id ).await
-  }
-
-  /// Create project (convenience method)
-  ///
-  /// # Errors
-  ///
-  /// Returns an error if the API request fails, invalid project name,
-  /// insufficient permissions, or if the response cannot be parsed.
-  #[ inline ]
-  pub async fn create_project( &self, name : String ) -> Result< Project >
-  {
-    let request = CreateProjectRequest { name };
-    self.admin().create_project( request ).await
-  }
-}
\ No newline at end of file
+}
@@ -35,9 +35,10 @@ mod private
   // use tokio::time::sleep; // Commented out as unused
   use serde::{ Deserialize, Serialize };
   use error_tools::untyped::Result;
+  use api_secret::Masked;
 
   /// OAuth token response from authentication server
-  #[ derive( Debug, Clone, Serialize, Deserialize ) ]
+  #[ derive( Clone, Serialize, Deserialize ) ]
   pub struct OAuthTokenResponse
   {
     /// Access token for API requests
@@ -52,8 +53,22 @@ mod private
     pub scope : Option< String >,
   }
 
+  impl core::fmt::Debug for OAuthTokenResponse
+  {
+    fn fmt(&self, f : &mut core::fmt::Formatter< '_ >) -> core::fmt::Result
+    {
+      f.debug_struct("OAuthTokenResponse")
+        .field("access_token", &Masked(&self.access_token))
+        .field("token_type", &self.token_type)
+        .field("expires_in", &self.expires_in)
+        .field("refresh_token", &self.refresh_token.as_deref().map(Masked))
+        .field("scope", &self.scope)
+        .finish()
+    }
+  }
+
   /// Multi-tenant authentication configuration
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   pub struct MultiTenantConfig
   {
     /// Primary tenant organization ID
@@ -68,6 +83,24 @@ mod private
     pub isolation_enforced : bool,
   }
 
+  impl core::fmt::Debug for MultiTenantConfig
+  {
+    fn fmt(&self, f : &mut core::fmt::Formatter< '_ >) -> core::fmt::Result
+    {
+      let tenant_api_keys : HashMap< &str, Masked< '_ > > = self.tenant_api_keys
+        .iter()
+        .map(| ( tenant, key ) | ( tenant.as_str(), Masked(key) ))
+        .collect();
+      f.debug_struct("MultiTenantConfig")
+        .field("primary_org_id", &self.primary_org_id)
+        .field("secondary_org_id", &self.secondary_org_id)
+        .field("tenant_api_keys", &tenant_api_keys)
+        .field("tenant_rate_limits", &self.tenant_rate_limits)
+        .field("isolation_enforced", &self.isolation_enforced)
+        .finish()
+    }
+  }
+
   /// Authentication session information
   #[ derive( Debug, Clone ) ]
   pub struct AuthSession
@@ -149,7 +182,7 @@ mod private
   }
 
   /// Advanced authentication configuration
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   pub struct AdvancedAuthConfig
   {
     /// Primary API key for authentication
@@ -178,6 +211,27 @@ mod private
     pub default_session_timeout : Duration,
   }
 
+  impl core::fmt::Debug for AdvancedAuthConfig
+  {
+    fn fmt(&self, f : &mut core::fmt::Formatter< '_ >) -> core::fmt::Result
+    {
+      f.debug_struct("AdvancedAuthConfig")
+        .field("primary_api_key", &Masked(&self.primary_api_key))
+        .field("secondary_api_key", &self.secondary_api_key.as_deref().map(Masked))
+        .field("oauth_access_token", &self.oauth_access_token.as_deref().map(Masked))
+        .field("oauth_refresh_token", &self.oauth_refresh_token.as_deref().map(Masked))
+        .field("token_expires_at", &self.token_expires_at)
+        .field("organization_context", &self.organization_context)
+        .field("project_context", &self.project_context)
+        .field("audit_trail_enabled", &self.audit_trail_enabled)
+        .field("max_auth_retries", &self.max_auth_retries)
+        .field("auth_timeout", &self.auth_timeout)
+        .field("session_management_enabled", &self.session_management_enabled)
+        .field("default_session_timeout", &self.default_session_timeout)
+        .finish()
+    }
+  }
+
   impl Default for AdvancedAuthConfig
   {
     fn default() -> Self
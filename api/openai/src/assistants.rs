@@ -13,14 +13,17 @@ mod private
     client ::Client,
     error ::Result,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    pagination ::CursorPage,
   };
   use crate::components::assistants_shared::
   {
     AssistantObject,
+    CreateAssistantRequest,
+    ModifyAssistantRequest,
     ListAssistantsResponse,
     DeleteAssistantResponse,
   };
-  use crate::components::common::ListQuery;
+  use crate::components::query::ListQuery;
 
   // External crates
 
@@ -57,7 +60,7 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn create( &self, request : AssistantObject ) -> Result< AssistantObject >
+    pub async fn create( &self, request : CreateAssistantRequest ) -> Result< AssistantObject >
     {
       self.client.post( "assistants", &request ).await
     }
@@ -72,7 +75,7 @@ mod private
     #[ inline ]
     pub async fn retrieve( &self, assistant_id : &str ) -> Result< AssistantObject >
     {
-      let path = format!( "/assistants/{assistant_id}" );
+      let path = format!( "assistants/{assistant_id}" );
       self.client.get( &path ).await
     }
 
@@ -85,9 +88,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn modify( &self, assistant_id : &str, request : AssistantObject ) -> Result< AssistantObject >
+    pub async fn modify( &self, assistant_id : &str, request : ModifyAssistantRequest ) -> Result< AssistantObject >
     {
-      let path = format!( "/assistants/{assistant_id}" );
+      let path = format!( "assistants/{assistant_id}" );
       self.client.post( &path, &request ).await
     }
 
@@ -101,7 +104,7 @@ mod private
     #[ inline ]
     pub async fn delete( &self, assistant_id : &str ) -> Result< DeleteAssistantResponse >
     {
-      let path = format!( "/assistants/{assistant_id}" );
+      let path = format!( "assistants/{assistant_id}" );
       self.client.delete( &path ).await
     }
 
@@ -115,7 +118,7 @@ mod private
     #[ inline ]
     pub async fn list( &self, query : Option< ListQuery > ) -> Result< ListAssistantsResponse >
     {
-      let path = "/assistants";
+      let path = "assistants";
       if let Some( q ) = query
       {
         self.client.get_with_query( path, &q ).await
@@ -126,8 +129,27 @@ mod private
       }
     }
 
+    /// Fetches the first page of the assistants, as a `CursorPage` that fetches
+    /// the pages after it with the same `limit`.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters of the first page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_page( &self, query : Option< ListQuery > ) -> Result< CursorPage< AssistantObject > >
+    where
+      E : Clone,
+    {
+      CursorPage::first( self.client.clone(), query.unwrap_or_else( || ListQuery::former().form() ), list_assistants_after ).await
+    }
+
     /// Creates an assistant file.
     ///
+    /// Assistants v1 endpoint; under v2, files reach an assistant through
+    /// `tool_resources` instead.
+    ///
     /// # Arguments
     /// - `assistant_id`: The ID of the assistant to create the file for.
     /// - `file_id`: The ID of the file to associate with the assistant.
@@ -137,7 +159,7 @@ mod private
     #[ inline ]
     pub async fn create_file( &self, assistant_id : &str, file_id : &str ) -> Result< serde_json::Value >
     {
-      let path = format!( "/assistants/{assistant_id}/files" );
+      let path = format!( "assistants/{assistant_id}/files" );
       let request = serde_json::json!({ "file_id": file_id });
       self.client.post( &path, &request ).await
     }
@@ -153,7 +175,7 @@ mod private
     #[ inline ]
     pub async fn retrieve_file( &self, assistant_id : &str, file_id : &str ) -> Result< serde_json::Value >
     {
-      let path = format!( "/assistants/{assistant_id}/files/{file_id}" );
+      let path = format!( "assistants/{assistant_id}/files/{file_id}" );
       self.client.get( &path ).await
     }
 
@@ -168,7 +190,7 @@ mod private
     #[ inline ]
     pub async fn delete_file( &self, assistant_id : &str, file_id : &str ) -> Result< serde_json::Value >
     {
-      let path = format!( "/assistants/{assistant_id}/files/{file_id}" );
+      let path = format!( "assistants/{assistant_id}/files/{file_id}" );
       self.client.delete( &path ).await
     }
 
@@ -183,7 +205,7 @@ mod private
     #[ inline ]
     pub async fn list_files( &self, assistant_id : &str, query : Option< ListQuery > ) -> Result< serde_json::Value >
     {
-      let path = format!( "/assistants/{assistant_id}/files" );
+      let path = format!( "assistants/{assistant_id}/files" );
       if let Some( q ) = query
       {
         self.client.get_with_query( &path, &q ).await
@@ -194,6 +216,14 @@ mod private
       }
     }
   }
+
+  /// One page of `Assistants::list`, for the pages a `CursorPage` fetches.
+  async fn list_assistants_after< E >( client : Client< E >, query : ListQuery ) -> Result< ListAssistantsResponse >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    Assistants::new( &client ).list( Some( query ) ).await
+  }
 } // end mod private
 
 crate ::mod_interface!
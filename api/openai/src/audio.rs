@@ -1,6 +1,7 @@
 // src/api/audio.rs
 //! This module defines the `Audio` API client, which provides methods
-//! for interacting with the `OpenAI` Audio API.
+//! for interacting with the `OpenAI` Audio API: speech synthesis, as a whole
+//! or streamed, and transcription and translation of uploaded audio files.
 //!
 //! For more details, refer to the [`OpenAI` Audio API documentation](https://platform.openai.com/docs/api-reference/audio).
 
@@ -11,7 +12,7 @@ mod private
   use crate::
   {
     client ::Client,
-    error ::Result,
+    error ::{ OpenAIError, Result },
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
   };
   use crate::components::audio::
@@ -19,14 +20,16 @@ mod private
     CreateSpeechRequest,
     CreateTranscriptionRequest,
     CreateTranscriptionResponseJson,
+    CreateTranscriptionResponseVerboseJson,
     CreateTranslationRequest,
     CreateTranslationResponseJson,
+    CreateTranslationResponseVerboseJson,
+    TranscriptionResponseFormat,
   };
 
   // External crates
   use reqwest::multipart::{ Form, Part };
-
-
+  use tokio::sync::mpsc;
 
   /// The client for the `OpenAI` Audio API.
   #[ derive( Debug, Clone ) ]
@@ -61,100 +64,229 @@ mod private
     #[ inline ]
     pub async fn speech( &self, request : CreateSpeechRequest ) -> Result< Vec< u8 > >
     {
-      let path = "/audio/speech";
-      self.client.post_binary( path, &request ).await
+      self.client.post_binary( "audio/speech", &request ).await
     }
 
-    /// Transcribes audio into the input language.
+    /// Generates audio from the input text and streams it in chunks as they
+    /// arrive, so playback can start before the whole audio is generated.
     ///
     /// # Arguments
-    /// - `request`: The request body for transcribing audio.
+    /// - `request`: The request body for generating audio; `pcm` or `wav`
+    ///   give the lowest latency.
     ///
     /// # Errors
-    /// Returns `OpenAIError` if the request fails.
+    /// Returns `OpenAIError` if the request fails; a chunk that fails to read
+    /// is sent as an error and ends the stream.
+    #[ inline ]
+    pub async fn speech_stream( &self, request : CreateSpeechRequest ) -> Result< mpsc::Receiver< Result< Vec< u8 > > > >
+    {
+      self.client.post_byte_stream( "audio/speech", &request ).await
+    }
+
+    /// Transcribes audio into the input language, as `json`.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for transcribing audio; its
+    ///   `response_format` is unset or `json`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` for another `response_format`,
+    /// see `transcribe_verbose` and `transcribe_text`, or `OpenAIError` if the
+    /// request fails.
     #[ inline ]
     pub async fn transcribe( &self, request : CreateTranscriptionRequest ) -> Result< CreateTranscriptionResponseJson >
     {
-      // Create multipart form
-      let file_part = Part::bytes( request.file )
-        .file_name( request.filename )
-        .mime_str( "audio/*" )
-        .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to create file part : {e}" ) ) )?;
-
-      let mut form = Form::new()
-        .part( "file", file_part )
-        .text( "model", request.model );
-
-      if let Some( language ) = request.language
-      {
-        form = form.text( "language", language );
-      }
-
-      if let Some( prompt ) = request.prompt
-      {
-        form = form.text( "prompt", prompt );
-      }
-
-      if let Some( response_format ) = request.response_format
-      {
-        form = form.text( "response_format", serde_json::to_string( &response_format )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize response_format : {e}" ) ) )? );
-      }
-
-      if let Some( temperature ) = request.temperature
-      {
-        form = form.text( "temperature", temperature.to_string() );
-      }
-
-      if let Some( timestamp_granularities ) = request.timestamp_granularities
-      {
-        let granularities_json = serde_json::to_string( &timestamp_granularities )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize timestamp_granularities : {e}" ) ) )?;
-        form = form.text( "timestamp_granularities", granularities_json );
-      }
-
-      let path = "/audio/transcriptions";
-      self.client.post_multipart( path, form ).await
+      let format = json_format( request.response_format.as_ref(), "transcribe" )?;
+      self.client.post_multipart( "audio/transcriptions", transcription_form( request, &format )? ).await
     }
 
-    /// Translates audio into English.
+    /// Transcribes audio into the input language, as `verbose_json`: the
+    /// detected language, the duration, and the word or segment timestamps
+    /// asked for in `timestamp_granularities`.
     ///
     /// # Arguments
-    /// - `request`: The request body for translating audio.
+    /// - `request`: The request body for transcribing audio; its
+    ///   `response_format` is replaced by `verbose_json`.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
+    pub async fn transcribe_verbose( &self, request : CreateTranscriptionRequest ) -> Result< CreateTranscriptionResponseVerboseJson >
+    {
+      self.client.post_multipart( "audio/transcriptions", transcription_form( request, &TranscriptionResponseFormat::VerboseJson )? ).await
+    }
+
+    /// Transcribes audio into the input language, as plain text or as
+    /// `srt` or `vtt` subtitles.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for transcribing audio; its
+    ///   `response_format` is `text` (when unset), `srt`, or `vtt`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` for a JSON `response_format`,
+    /// or `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn transcribe_text( &self, request : CreateTranscriptionRequest ) -> Result< String >
+    {
+      let format = text_format( request.response_format.as_ref(), "transcribe" )?;
+      self.client.post_multipart_text( "audio/transcriptions", transcription_form( request, &format )? ).await
+    }
+
+    /// Translates audio into English, as `json`.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for translating audio; its
+    ///   `response_format` is unset or `json`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` for another `response_format`,
+    /// see `translate_verbose` and `translate_text`, or `OpenAIError` if the
+    /// request fails.
+    #[ inline ]
     pub async fn translate( &self, request : CreateTranslationRequest ) -> Result< CreateTranslationResponseJson >
     {
-      // Create multipart form
-      let file_part = Part::bytes( request.file )
-        .file_name( request.filename )
-        .mime_str( "audio/*" )
-        .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to create file part : {e}" ) ) )?;
-
-      let mut form = Form::new()
-        .part( "file", file_part )
-        .text( "model", request.model );
-
-      if let Some( prompt ) = request.prompt
-      {
-        form = form.text( "prompt", prompt );
-      }
-
-      if let Some( response_format ) = request.response_format
-      {
-        form = form.text( "response_format", serde_json::to_string( &response_format )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize response_format : {e}" ) ) )? );
-      }
-
-      if let Some( temperature ) = request.temperature
-      {
-        form = form.text( "temperature", temperature.to_string() );
-      }
-
-      let path = "/audio/translations";
-      self.client.post_multipart( path, form ).await
+      let format = json_format( request.response_format.as_ref(), "translate" )?;
+      self.client.post_multipart( "audio/translations", translation_form( request, &format )? ).await
+    }
+
+    /// Translates audio into English, as `verbose_json` with the duration
+    /// and the segments.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for translating audio; its
+    ///   `response_format` is replaced by `verbose_json`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn translate_verbose( &self, request : CreateTranslationRequest ) -> Result< CreateTranslationResponseVerboseJson >
+    {
+      self.client.post_multipart( "audio/translations", translation_form( request, &TranscriptionResponseFormat::VerboseJson )? ).await
+    }
+
+    /// Translates audio into English, as plain text or as `srt` or `vtt`
+    /// subtitles.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for translating audio; its
+    ///   `response_format` is `text` (when unset), `srt`, or `vtt`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` for a JSON `response_format`,
+    /// or `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn translate_text( &self, request : CreateTranslationRequest ) -> Result< String >
+    {
+      let format = text_format( request.response_format.as_ref(), "translate" )?;
+      self.client.post_multipart_text( "audio/translations", translation_form( request, &format )? ).await
+    }
+  }
+
+  /// Checks that a requested format answers with the `json` body.
+  fn json_format( format : Option< &TranscriptionResponseFormat >, method : &str ) -> Result< TranscriptionResponseFormat >
+  {
+    match format
+    {
+      None | Some( TranscriptionResponseFormat::Json ) => Ok( TranscriptionResponseFormat::Json ),
+      Some( other ) => Err( OpenAIError::InvalidArgument( format!( "`{method}` reads `json`; use `{method}_verbose` or `{method}_text` for `{}`", other.as_str() ) ).into() ),
+    }
+  }
+
+  /// Checks that a requested format answers with a plain text body.
+  fn text_format( format : Option< &TranscriptionResponseFormat >, method : &str ) -> Result< TranscriptionResponseFormat >
+  {
+    match format
+    {
+      None => Ok( TranscriptionResponseFormat::Text ),
+      Some( format ) if format.is_text() => Ok( format.clone() ),
+      Some( other ) => Err( OpenAIError::InvalidArgument( format!( "`{method}_text` reads `text`, `srt`, or `vtt`, not `{}`", other.as_str() ) ).into() ),
+    }
+  }
+
+  /// The audio file part shared by transcriptions and translations.
+  fn audio_part( file : Vec< u8 >, filename : String ) -> Result< Part >
+  {
+    let part = Part::bytes( file )
+      .file_name( filename )
+      .mime_str( "application/octet-stream" )
+      .map_err( | e | OpenAIError::Internal( format!( "Failed to create file part : {e}" ) ) )?;
+    Ok( part )
+  }
+
+  /// The text fields of a transcription form, in order, asking for
+  /// `format`; granularities are sent as repeated `timestamp_granularities[]`.
+  fn transcription_fields( request : &CreateTranscriptionRequest, format : &TranscriptionResponseFormat ) -> Vec< ( &'static str, String ) >
+  {
+    let mut fields = vec![ ( "model", request.model.clone() ), ( "response_format", format.as_str().to_string() ) ];
+    fields.extend( request.language.clone().map( | language | ( "language", language ) ) );
+    fields.extend( request.prompt.clone().map( | prompt | ( "prompt", prompt ) ) );
+    fields.extend( request.temperature.map( | temperature | ( "temperature", temperature.to_string() ) ) );
+    for granularity in request.timestamp_granularities.iter().flatten()
+    {
+      fields.push( ( "timestamp_granularities[]", granularity.as_str().to_string() ) );
+    }
+    fields
+  }
+
+  /// The text fields of a translation form, in order, asking for `format`.
+  fn translation_fields( request : &CreateTranslationRequest, format : &TranscriptionResponseFormat ) -> Vec< ( &'static str, String ) >
+  {
+    let mut fields = vec![ ( "model", request.model.clone() ), ( "response_format", format.as_str().to_string() ) ];
+    fields.extend( request.prompt.clone().map( | prompt | ( "prompt", prompt ) ) );
+    fields.extend( request.temperature.map( | temperature | ( "temperature", temperature.to_string() ) ) );
+    fields
+  }
+
+  fn transcription_form( request : CreateTranscriptionRequest, format : &TranscriptionResponseFormat ) -> Result< Form >
+  {
+    let fields = transcription_fields( &request, format );
+    let form = Form::new().part( "file", audio_part( request.file, request.filename )? );
+    Ok( fields.into_iter().fold( form, | form, ( name, value ) | form.text( name, value ) ) )
+  }
+
+  fn translation_form( request : CreateTranslationRequest, format : &TranscriptionResponseFormat ) -> Result< Form >
+  {
+    let fields = translation_fields( &request, format );
+    let form = Form::new().part( "file", audio_part( request.file, request.filename )? );
+    Ok( fields.into_iter().fold( form, | form, ( name, value ) | form.text( name, value ) ) )
+  }
+
+  // Tests
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+    use crate::components::audio::TimestampGranularity;
+
+    #[ test ]
+    fn transcription_sends_each_granularity()
+    {
+      let mut request = CreateTranscriptionRequest::new( b"RIFF".to_vec(), "hello.wav", "whisper-1" );
+      request.language = Some( "en".to_string() );
+      request.timestamp_granularities = Some( vec![ TimestampGranularity::Word, TimestampGranularity::Segment ] );
+
+      assert_eq!( transcription_fields( &request, &TranscriptionResponseFormat::VerboseJson ), vec!
+      [
+        ( "model", "whisper-1".to_string() ),
+        ( "response_format", "verbose_json".to_string() ),
+        ( "language", "en".to_string() ),
+        ( "timestamp_granularities[]", "word".to_string() ),
+        ( "timestamp_granularities[]", "segment".to_string() ),
+      ] );
+    }
+
+    #[ test ]
+    fn translation_sends_model_and_format()
+    {
+      let request = CreateTranslationRequest::new( b"RIFF".to_vec(), "hallo.wav", "whisper-1" );
+
+      assert_eq!( translation_fields( &request, &TranscriptionResponseFormat::Text ), vec!
+      [
+        ( "model", "whisper-1".to_string() ),
+        ( "response_format", "text".to_string() ),
+      ] );
     }
   }
 } // end mod private
@@ -166,4 +298,4 @@ crate ::mod_interface!
   {
     Audio,
   };
-}
\ No newline at end of file
+}
@@ -0,0 +1,226 @@
+// src/batches.rs
+//! This module defines the `Batches` API client, which provides methods
+//! for interacting with the `OpenAI` Batch API: batches created from an
+//! uploaded JSONL input file, their status, cancellation, and the download
+//! of their output and error files.
+//!
+//! For more details, refer to the [`OpenAI` Batch API documentation](https://platform.openai.com/docs/api-reference/batch).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  // Use crate root for base access
+  use crate::
+  {
+    client ::Client,
+    error ::{ OpenAIError, Result },
+    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    pagination ::CursorPage,
+  };
+  use crate::components::batch_shared::
+  {
+    Batch,
+    ListBatchesResponse,
+    CreateBatchRequest,
+    ListBatchesQuery,
+    BatchRequestOutput,
+  };
+
+  // External crates
+  use core::time::Duration;
+  use web_time::Instant;
+
+  /// The client for the `OpenAI` Batch API.
+  #[ derive( Debug, Clone ) ]
+  pub struct Batches< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : &'client Client< E >,
+  }
+
+  impl< 'client, E > Batches< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Creates a new `Batches` client.
+    ///
+    /// # Arguments
+    /// - `client`: The core `OpenAI` `Client` to use for requests.
+    #[ inline ]
+    pub(crate) fn new( client : &'client Client< E > ) -> Self
+    {
+      Self { client }
+    }
+
+    /// Creates a batch from an input file uploaded with the `batch` purpose.
+    ///
+    /// `BatchRequestInput::chat_completions_jsonl` builds the content of
+    /// that file from chat completion requests.
+    ///
+    /// # Arguments
+    /// - `request`: The request body naming the input file and the endpoint.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create( &self, request : CreateBatchRequest ) -> Result< Batch >
+    {
+      self.client.post( "batches", &request ).await
+    }
+
+    /// Retrieves a batch.
+    ///
+    /// # Arguments
+    /// - `batch_id`: The ID of the batch to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve( &self, batch_id : &str ) -> Result< Batch >
+    {
+      let path = format!( "batches/{batch_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Lists the batches of the organization.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list( &self, query : Option< ListBatchesQuery > ) -> Result< ListBatchesResponse >
+    {
+      let path = "batches";
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( path, &q ).await
+      }
+      else
+      {
+        self.client.get( path ).await
+      }
+    }
+
+    /// Fetches the first page of the batches of the organization, as a `CursorPage` that fetches
+    /// the pages after it with the same `limit`.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters of the first page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_page( &self, query : Option< ListBatchesQuery > ) -> Result< CursorPage< Batch > >
+    where
+      E : Clone,
+    {
+      CursorPage::first( self.client.clone(), query.unwrap_or_default(), list_batches_after ).await
+    }
+
+    /// Cancels a batch. It stays `cancelling` for up to 10 minutes, then
+    /// becomes `cancelled` with the outputs finished so far.
+    ///
+    /// # Arguments
+    /// - `batch_id`: The ID of the batch to cancel.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn cancel( &self, batch_id : &str ) -> Result< Batch >
+    {
+      let path = format!( "batches/{batch_id}/cancel" );
+      self.client.post_no_body( &path ).await
+    }
+
+    /// Polls a batch until it stops being pending, then returns it.
+    ///
+    /// The batch is retrieved every `interval` for at most `timeout`; one
+    /// `retrieve` request per poll, nothing else.
+    ///
+    /// # Arguments
+    /// - `batch_id`: The ID of the batch to wait for.
+    /// - `interval`: Time between two polls.
+    /// - `timeout`: Time after which a batch still pending is an error.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Timeout` if the batch is still pending after
+    /// `timeout`, or `OpenAIError` if a poll fails.
+    #[ inline ]
+    pub async fn wait_for_batch( &self, batch_id : &str, interval : Duration, timeout : Duration ) -> Result< Batch >
+    {
+      let started = Instant::now();
+      loop
+      {
+        let batch = self.retrieve( batch_id ).await?;
+        if !batch.is_pending()
+        {
+          return Ok( batch );
+        }
+        let elapsed = started.elapsed();
+        let Some( left ) = timeout.checked_sub( elapsed ).filter( | left | !left.is_zero() ) else
+        {
+          return Err( OpenAIError::Timeout( format!( "Batch {batch_id} still `{}` after {elapsed:?}", batch.status ) ).into() );
+        };
+        api_common::sleep( interval.min( left ) ).await;
+      }
+    }
+
+    /// Downloads and reads the output file of a batch: one line per
+    /// request that succeeded.
+    ///
+    /// # Arguments
+    /// - `batch`: The batch, as last retrieved.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingFile` if the batch has no output file
+    /// yet, or `OpenAIError` if the download or a line fails.
+    #[ inline ]
+    pub async fn download_output( &self, batch : &Batch ) -> Result< Vec< BatchRequestOutput > >
+    {
+      self.download( &batch.id, "output", batch.output_file_id.as_deref() ).await
+    }
+
+    /// Downloads and reads the error file of a batch: one line per
+    /// request that failed.
+    ///
+    /// # Arguments
+    /// - `batch`: The batch, as last retrieved.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingFile` if the batch has no error file,
+    /// or `OpenAIError` if the download or a line fails.
+    #[ inline ]
+    pub async fn download_errors( &self, batch : &Batch ) -> Result< Vec< BatchRequestOutput > >
+    {
+      self.download( &batch.id, "error", batch.error_file_id.as_deref() ).await
+    }
+
+    async fn download( &self, batch_id : &str, kind : &str, file_id : Option< &str > ) -> Result< Vec< BatchRequestOutput > >
+    {
+      let file_id = file_id.ok_or_else( || OpenAIError::MissingFile( format!( "Batch {batch_id} has no {kind} file" ) ) )?;
+      let path = format!( "files/{file_id}/content" );
+      let content = self.client.get_bytes( &path ).await?;
+      BatchRequestOutput::parse_jsonl( &content )
+    }
+  }
+
+  /// One page of `Batches::list`, for the pages a `CursorPage` fetches.
+  async fn list_batches_after< E >( client : Client< E >, query : ListBatchesQuery ) -> Result< ListBatchesResponse >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    Batches::new( &client ).list( Some( query ) ).await
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  // Expose all structs defined in this module
+  exposed use
+  {
+    Batches,
+  };
+}
@@ -10,7 +10,6 @@ mod private
   use core::pin::Pin;
   use core::task::{ Context, Poll };
   use futures_core::Stream;
-  use tokio::time::Sleep;
 
   /// Configuration for buffered streaming
   #[ derive( Debug, Clone ) ]
@@ -84,7 +83,6 @@ mod private
     buffer : String,
     config : BufferConfig,
     last_flush : Instant,
-    _flush_timer : Option< Pin< Box< Sleep > > >,
   }
 
   impl< S > BufferedStream< S >
@@ -101,7 +99,6 @@ mod private
         buffer : String::new(),
         config,
         last_flush : Instant::now(),
-        _flush_timer : None,
       }
     }
 
@@ -10,7 +10,7 @@ mod private
   // Use crate root for base access
   use crate::
   {
-    client ::Client,
+    client ::{ Client, UsageHook },
     error ::Result,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
   };
@@ -19,12 +19,60 @@ mod private
     ChatCompletionRequest,
     CreateChatCompletionResponse,
     ChatCompletionStreamResponse,
+    ChatCompletionChunk,
   };
+  use crate::components::common::CompletionUsage;
 
   // External crates
 
+  use api_sse_stream::SseEvent;
   use tokio::sync::mpsc;
 
+  /// Reads one server-sent event of a chat completion stream into its typed
+  /// items.
+  fn decode_chunk( event : &SseEvent ) -> serde_json::Result< Vec< ChatCompletionChunk > >
+  {
+    serde_json::from_str::< ChatCompletionStreamResponse >( &event.data ).map( ChatCompletionStreamResponse::into_chunks )
+  }
+
+  /// Logs the usage of one chat completion and passes it to the usage hook.
+  fn report_usage( hook : Option< &UsageHook >, model : &str, usage : &CompletionUsage )
+  {
+    tracing::debug!
+    (
+      model,
+      prompt_tokens = usage.prompt_tokens,
+      cached_tokens = usage.cached_tokens(),
+      cached_fraction = usage.cached_fraction(),
+      "chat completion usage"
+    );
+    if let Some( hook ) = hook
+    {
+      hook.call( model, usage );
+    }
+  }
+
+  /// Passes the stream on, reporting its usage item on the way.
+  fn report_stream_usage( mut stream : mpsc::Receiver< Result< ChatCompletionChunk > >, hook : Option< UsageHook >, model : String ) -> mpsc::Receiver< Result< ChatCompletionChunk > >
+  {
+    let ( tx, rx ) = mpsc::channel( 100 );
+    api_common::spawn( async move
+    {
+      while let Some( item ) = stream.recv().await
+      {
+        if let Ok( ChatCompletionChunk::Usage( usage ) ) = &item
+        {
+          report_usage( hook.as_ref(), &model, usage );
+        }
+        if tx.send( item ).await.is_err()
+        {
+          return;
+        }
+      }
+    });
+    rx
+  }
+
   /// The client for the `OpenAI` Chat API.
   #[ derive( Debug, Clone ) ]
   pub struct Chat< 'client, E >
@@ -50,6 +98,9 @@ mod private
 
     /// Creates a chat completion.
     ///
+    /// Its usage, with the prompt tokens served from the prompt cache, goes
+    /// to the usage hook of the client, see `Client::with_usage_hook`.
+    ///
     /// # Arguments
     /// - `request`: The request body for creating a chat completion.
     ///
@@ -71,7 +122,9 @@ mod private
     /// ```
     ///
     /// # Errors
-    /// Returns `OpenAIError` if the request fails.
+    /// Returns `OpenAIError::Validation`, listing every violation, if the
+    /// request is invalid (`input_validation` feature), or `OpenAIError` if
+    /// the request fails.
     #[ inline ]
     pub async fn create( &self, request : ChatCompletionRequest ) -> Result< CreateChatCompletionResponse >
     {
@@ -81,26 +134,61 @@ mod private
         use crate::input_validation::Validate;
         if let Err( validation_errors ) = request.validate()
         {
-          let error_messages : Vec< String > = validation_errors
-            .iter()
-            .map( | e | format!( "{e}" ) )
-            .collect();
-          return Err( error_tools::Error::from( crate::error::OpenAIError::InvalidArgument( format!( "Request validation failed : {}", error_messages.join( "; " ) ) ) ) );
+          return Err( crate::error::OpenAIError::Validation( validation_errors ).into() );
         }
       }
 
-      self.client.post( "chat/completions", &request ).await
+      let response : CreateChatCompletionResponse = self.client.post( "chat/completions", &request ).await?;
+      if let Some( usage ) = &response.usage
+      {
+        report_usage( self.client.usage_hook.as_ref(), &response.model, usage );
+      }
+      Ok( response )
     }
 
-    /// Creates a chat completion and streams the response.
+    /// Creates a chat completion and streams the response as typed items:
+    /// content and refusal text, tool call fragments, the finish of each
+    /// choice, and, when `stream_options.include_usage` is set, the usage,
+    /// which also goes to the usage hook of the client.
     ///
     /// # Arguments
-    /// - `request`: The request body for creating a chat completion.
+    /// - `request`: The request body for creating a chat completion, with
+    ///   `stream` set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use api_openai::{ Client, environment::{ OpenaiEnvironment, EnvironmentInterface }, ClientApiAccessors };
+    /// use api_openai::components::chat_shared::{ ChatCompletionChunk, ChatCompletionRequest, ChatCompletionStreamOptions };
+    ///
+    /// # async fn example(client : Client< impl OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static >) -> Result<(), Box< dyn core::error::Error > >
+    /// # {
+    /// let request = ChatCompletionRequest::former()
+    ///   .model( "gpt-4o-mini".to_string() )
+    ///   .stream( true )
+    ///   .stream_options( ChatCompletionStreamOptions { include_usage : Some( true ) } )
+    ///   .form();
+    ///
+    /// let mut stream = client.chat().create_stream( request ).await?;
+    /// while let Some( chunk ) = stream.recv().await
+    /// {
+    ///   match chunk?
+    ///   {
+    ///     ChatCompletionChunk::Content { text, .. } => print!( "{text}" ),
+    ///     ChatCompletionChunk::Usage( usage ) => println!( "\n{} tokens", usage.total_tokens ),
+    ///     _ => {},
+    ///   }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Errors
-    /// Returns `OpenAIError` if the request fails.
+    /// Returns `OpenAIError::Validation`, listing every violation, if the
+    /// request is invalid (`input_validation` feature), or `OpenAIError` if
+    /// the request fails; a chunk that fails to parse is sent as an error.
     #[ inline ]
-    pub async fn create_stream( &self, request : ChatCompletionRequest ) -> Result< mpsc::Receiver< Result< ChatCompletionStreamResponse > > >
+    pub async fn create_stream( &self, request : ChatCompletionRequest ) -> Result< mpsc::Receiver< Result< ChatCompletionChunk > > >
     {
       // Validate request before processing
       #[ cfg( feature = "input_validation" ) ]
@@ -108,15 +196,20 @@ mod private
         use crate::input_validation::Validate;
         if let Err( validation_errors ) = request.validate()
         {
-          let error_messages : Vec< String > = validation_errors
-            .iter()
-            .map( | e | format!( "{e}" ) )
-            .collect();
-          return Err( error_tools::Error::from( crate::error::OpenAIError::InvalidArgument( format!( "Request validation failed : {}", error_messages.join( "; " ) ) ) ) );
+          return Err( crate::error::OpenAIError::Validation( validation_errors ).into() );
         }
       }
 
-      self.client.post_stream( "chat/completions", &request ).await
+      let include_usage = request.stream_options.as_ref().and_then( | options | options.include_usage ).unwrap_or( false );
+      let stream = self.client.post_event_stream( "chat/completions", &request, decode_chunk ).await?;
+      if include_usage
+      {
+        Ok( report_stream_usage( stream, self.client.usage_hook.clone(), request.model ) )
+      }
+      else
+      {
+        Ok( stream )
+      }
     }
   }
 } // end mod private
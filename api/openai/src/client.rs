@@ -15,21 +15,63 @@ mod private
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
     diagnostics ::DiagnosticsCollector,
     request_cache ::ApiRequestCache,
+    components ::common::CompletionUsage,
   };
 
   // Import enhanced functionality when features are enabled
   #[ cfg( feature = "retry" ) ]
   use crate::enhanced_retry::EnhancedRetryConfig;
+  #[ cfg( feature = "retry" ) ]
+  use api_common::CallBudget;
 
   #[ cfg( feature = "circuit_breaker" ) ]
   use crate::enhanced_circuit_breaker::{ EnhancedCircuitBreakerConfig, EnhancedCircuitBreaker };
 
   #[ cfg( feature = "rate_limiting" ) ]
   use crate::enhanced_rate_limiting::{ EnhancedRateLimitingConfig, EnhancedRateLimiter };
+  #[ cfg( feature = "rate_limiting" ) ]
+  use api_common::{ ConcurrencyGate, SharedRateLimiter };
 
   // External crates
   use reqwest::Client as HttpClient;
   use std::sync::Arc;
+  use core::fmt;
+
+  /// A function called with the model and the usage of every chat
+  /// completion, streamed or not, to measure prompt caching per request.
+  ///
+  /// Set with `Client::with_usage_hook`.
+  #[ derive( Clone ) ]
+  pub struct UsageHook( Arc< UsageFn > );
+
+  /// The function a `UsageHook` calls.
+  type UsageFn = dyn Fn( &str, &CompletionUsage ) + Send + Sync;
+
+  impl UsageHook
+  {
+    /// Wraps `hook`.
+    #[ inline ]
+    pub fn new( hook : impl Fn( &str, &CompletionUsage ) + Send + Sync + 'static ) -> Self
+    {
+      Self( Arc::new( hook ) )
+    }
+
+    /// Calls the hook with the usage of one completion of `model`.
+    #[ inline ]
+    pub fn call( &self, model : &str, usage : &CompletionUsage )
+    {
+      ( self.0 )( model, usage );
+    }
+  }
+
+  impl fmt::Debug for UsageHook
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.write_str( "UsageHook" )
+    }
+  }
 
   /// The main client for interacting with the `OpenAI` API.
   ///
@@ -72,11 +114,16 @@ mod private
     pub diagnostics : Option< Arc< DiagnosticsCollector > >,
     /// Optional request cache for API responses.
     pub cache : Option< Arc< ApiRequestCache > >,
+    /// Optional hook called with the usage of every chat completion.
+    pub usage_hook : Option< UsageHook >,
 
     // Feature-gated enhanced reliability configurations and instances
     #[ cfg( feature = "retry" ) ]
     /// Optional retry configuration for reliability.
     pub retry_config : Option< EnhancedRetryConfig >,
+    #[ cfg( feature = "retry" ) ]
+    /// Optional overall limit for each request across all its retry attempts.
+    pub call_budget : Option< CallBudget >,
 
     #[ cfg( feature = "circuit_breaker" ) ]
     /// Optional circuit breaker configuration for fault tolerance.
@@ -91,6 +138,23 @@ mod private
     #[ cfg( feature = "rate_limiting" ) ]
     /// Optional rate limiter instance.
     pub rate_limiter : Option< EnhancedRateLimiter >,
+    #[ cfg( feature = "rate_limiting" ) ]
+    /// Optional limiter shared with other clients spending the same quota.
+    pub shared_rate_limiter : Option< SharedRateLimiter >,
+    #[ cfg( feature = "rate_limiting" ) ]
+    /// Optional in-flight cap shared with other clients of the same account.
+    pub concurrency_gate : Option< ConcurrencyGate >,
+
+    #[ cfg( feature = "guardrail" ) ]
+    /// Guardrails run over request and response bodies.
+    pub guardrails : api_common::Guardrails,
+
+    #[ cfg( feature = "idempotency" ) ]
+    /// Key sent with every write made through this handle.
+    pub idempotency_key : Option< api_common::IdempotencyKey >,
+    #[ cfg( feature = "idempotency" ) ]
+    /// Whether writes without a fixed key get a generated one.
+    pub idempotency_keys : bool,
   }
 
 } // end mod private
@@ -101,5 +165,6 @@ crate ::mod_interface!
   exposed use
   {
     Client,
+    UsageHook,
   };
 }
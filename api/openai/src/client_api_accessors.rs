@@ -12,6 +12,7 @@ mod private
     client ::Client,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
     assistants ::Assistants,
+    batches ::Batches,
     chat ::Chat,
     embeddings ::Embeddings,
     files ::Files,
@@ -19,6 +20,8 @@ mod private
     images ::Images,
     models ::Models,
     responses ::Responses,
+    threads ::Threads,
+    usage ::Usage,
     vector_stores ::VectorStores,
   };
 
@@ -43,6 +46,9 @@ mod private
     #[ cfg( feature = "audio" ) ]
     fn audio( &self ) -> Audio< '_, E >;
 
+    /// Returns a `Batches` API client.
+    fn batches( &self ) -> Batches< '_, E >;
+
     /// Returns a `Chat` API client.
     fn chat( &self ) -> Chat< '_, E >;
 
@@ -72,6 +78,12 @@ mod private
     /// Returns a `Responses` API client.
     fn responses( &self ) -> Responses< '_, E >;
 
+    /// Returns a `Threads` API client, which also reaches messages and runs.
+    fn threads( &self ) -> Threads< '_, E >;
+
+    /// Returns a `Usage` API client, for the usage and costs reports.
+    fn usage( &self ) -> Usage< '_, E >;
+
     /// Returns a `VectorStores` API client.
     fn vector_stores( &self ) -> VectorStores< '_, E >;
   }
@@ -93,6 +105,12 @@ mod private
       Audio::new( self )
     }
 
+    #[ inline ]
+    fn batches( &self ) -> Batches< '_, E >
+    {
+      Batches::new( self )
+    }
+
     #[ inline ]
     fn chat( &self ) -> Chat< '_, E >
     {
@@ -149,6 +167,18 @@ mod private
       Responses::new( self )
     }
 
+    #[ inline ]
+    fn threads( &self ) -> Threads< '_, E >
+    {
+      Threads::new( self )
+    }
+
+    #[ inline ]
+    fn usage( &self ) -> Usage< '_, E >
+    {
+      Usage::new( self )
+    }
+
     #[ inline ]
     fn vector_stores( &self ) -> VectorStores< '_, E >
     {
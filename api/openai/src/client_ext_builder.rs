@@ -8,7 +8,8 @@ mod private
 {
   use crate::
   {
-    client ::Client,
+    client ::{ Client, UsageHook },
+    components ::common::CompletionUsage,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
     error ::Result,
     diagnostics ::DiagnosticsCollector,
@@ -17,12 +18,16 @@ mod private
 
   #[ cfg( feature = "retry" ) ]
   use crate::enhanced_retry::{ EnhancedRetryConfig };
+  #[ cfg( feature = "retry" ) ]
+  use api_common::CallBudget;
 
   #[ cfg( feature = "circuit_breaker" ) ]
   use crate::enhanced_circuit_breaker::{ EnhancedCircuitBreakerConfig, EnhancedCircuitBreaker };
 
   #[ cfg( feature = "rate_limiting" ) ]
   use crate::enhanced_rate_limiting::{ EnhancedRateLimitingConfig, EnhancedRateLimiter };
+  #[ cfg( feature = "rate_limiting" ) ]
+  use api_common::{ ConcurrencyGate, SharedRateLimiter };
 
   use reqwest::Client as HttpClient;
   use std::sync::Arc;
@@ -41,15 +46,7 @@ mod private
     #[ inline ]
     pub fn build( environment : E ) -> Result< Self >
     {
-      let headers = environment.headers()?;
-      let http_client = HttpClient::builder()
-        .default_headers( headers )
-        .timeout( core::time::Duration::from_secs( 300 ) ) // 5 minute default timeout
-        .connect_timeout( core::time::Duration::from_secs( 30 ) ) // 30 second connect timeout
-        .pool_max_idle_per_host( 10 ) // Connection pooling optimization
-        .pool_idle_timeout( core::time::Duration::from_secs( 90 ) ) // Keep connections alive
-        .tcp_keepalive( core::time::Duration::from_secs( 60 ) ) // TCP keepalive
-        .build()?;
+      let http_client = http_builder( &environment )?.build()?;
 
       // Initialize diagnostics collector if config is provided
       let diagnostics = environment.diagnostics_config()
@@ -61,10 +58,13 @@ mod private
         environment,
         diagnostics,
         cache : None,
+        usage_hook : None,
 
         // Feature-gated fields initialization
         #[ cfg( feature = "retry" ) ]
         retry_config : None,
+        #[ cfg( feature = "retry" ) ]
+        call_budget : None,
 
         #[ cfg( feature = "circuit_breaker" ) ]
         circuit_breaker_config : None,
@@ -75,9 +75,60 @@ mod private
         rate_limiting_config : None,
         #[ cfg( feature = "rate_limiting" ) ]
         rate_limiter : None,
+        #[ cfg( feature = "rate_limiting" ) ]
+        shared_rate_limiter : None,
+        #[ cfg( feature = "rate_limiting" ) ]
+        concurrency_gate : None,
+
+        #[ cfg( feature = "guardrail" ) ]
+        guardrails : api_common::Guardrails::new(),
+
+        #[ cfg( feature = "idempotency" ) ]
+        idempotency_key : None,
+        #[ cfg( feature = "idempotency" ) ]
+        idempotency_keys : false,
       })
     }
 
+    /// Rebuilds the HTTP client with `tuning` applied on top of the default
+    /// timeouts and pool settings. Open connections of the previous client
+    /// are dropped; call [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if `tuning` names a proxy that is
+    /// not a valid URL or asks for HTTP/2 prior knowledge without the `http2`
+    /// feature, or an error if the client
+    /// cannot be built.
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self >
+    {
+      self.http_client = tuning.apply_to( http_builder( &self.environment )? ).map_err( | e | crate::error::OpenAIError::InvalidArgument( e.to_string() ) )?.build()?;
+      Ok( self )
+    }
+
+    /// Opens a connection to the base URL ahead of the first request, so that
+    /// request does not pay for DNS, TCP, and the TLS handshake.
+    ///
+    /// Sends one `HEAD` request, bypassing retry, circuit breaker, and rate
+    /// limiting, and returns its round-trip time. Any HTTP status counts as
+    /// success; only a failure to connect is an error.
+    ///
+    /// # Errors
+    /// Returns a network or timeout error if no connection could be opened.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn warm_up( &self ) -> Result< core::time::Duration >
+    {
+      let started = std::time::Instant::now();
+      self.http_client
+        .head( self.environment.base_url().clone() )
+        .send()
+        .await
+        .map_err( crate::error::OpenAIError::from )?;
+      Ok( started.elapsed() )
+    }
+
     /// Enable request caching with default configuration.
     #[ inline ]
     #[ must_use ]
@@ -143,6 +194,29 @@ mod private
       self.retry_config.as_ref()
     }
 
+    /// Bound each request, across all its retry attempts, by one overall budget.
+    ///
+    /// A fresh tracker is started per request and handed to the retry layer, so the
+    /// total deadline, attempt count, and cost apply to the request as a whole instead
+    /// of `max_elapsed_time_ms` and `max_attempts` applying per layer.
+    /// Only available when the `retry` feature is enabled.
+    #[ cfg( feature = "retry" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_call_budget( mut self, budget : CallBudget ) -> Self
+    {
+      self.call_budget = Some( budget );
+      self
+    }
+
+    /// Get the per-request call budget if configured.
+    #[ cfg( feature = "retry" ) ]
+    #[ inline ]
+    pub fn call_budget( &self ) -> Option< &CallBudget >
+    {
+      self.call_budget.as_ref()
+    }
+
     /// Enable circuit breaker logic with default configuration.
     /// Only available when the `circuit_breaker` feature is enabled.
     #[ cfg( feature = "circuit_breaker" ) ]
@@ -206,6 +280,139 @@ mod private
       self
     }
 
+    /// Draw every HTTP request from `limiter`, shared with other clients.
+    ///
+    /// Each attempt, retries included, takes one request from the shared
+    /// request bucket before it is sent, and waits while the shared token
+    /// bucket is in deficit. Token usage is not read from responses; record
+    /// it with `SharedRateLimiter::record_tokens`.
+    /// Only available when the `rate_limiting` feature is enabled.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_shared_rate_limiter( mut self, limiter : SharedRateLimiter ) -> Self
+    {
+      self.shared_rate_limiter = Some( limiter );
+      self
+    }
+
+    /// Get the shared rate limiter if configured.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    pub fn shared_rate_limiter( &self ) -> Option< &SharedRateLimiter >
+    {
+      self.shared_rate_limiter.as_ref()
+    }
+
+    /// Cap in-flight requests with `gate`, shared with other clients.
+    ///
+    /// Requests beyond the gate's limit queue in arrival order and fail with
+    /// `api_common::QueueTimeout` once its queue timeout passes. A request
+    /// holds its slot until the response headers arrive; a streaming request
+    /// until the stream ends.
+    /// Only available when the `rate_limiting` feature is enabled.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_concurrency_gate( mut self, gate : ConcurrencyGate ) -> Self
+    {
+      self.concurrency_gate = Some( gate );
+      self
+    }
+
+    /// Get the concurrency gate if configured.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    pub fn concurrency_gate( &self ) -> Option< &ConcurrencyGate >
+    {
+      self.concurrency_gate.as_ref()
+    }
+
+    /// Run `guardrails` over every JSON request body before it is sent and
+    /// every JSON response body before it is deserialised.
+    ///
+    /// A denied request fails with `api_common::GuardrailViolation` before it
+    /// takes a rate limit request or a concurrency slot. Streamed response
+    /// events, multipart uploads, and binary bodies are not passed to the
+    /// guardrails.
+    /// Only available when the `guardrail` feature is enabled.
+    #[ cfg( feature = "guardrail" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_guardrails( mut self, guardrails : api_common::Guardrails ) -> Self
+    {
+      self.guardrails = guardrails;
+      self
+    }
+
+    /// Get the configured guardrails.
+    #[ cfg( feature = "guardrail" ) ]
+    #[ inline ]
+    pub fn guardrails( &self ) -> &api_common::Guardrails
+    {
+      &self.guardrails
+    }
+
+    /// Send a generated `Idempotency-Key` with every write (JSON, multipart,
+    /// and bodiless POSTs) that has no fixed key.
+    ///
+    /// The key is generated once per call, before the retry layer, so every
+    /// retry attempt of that call sends the same key and a batch, upload, or
+    /// fine-tuning job is created only once.
+    /// Only available when the `idempotency` feature is enabled.
+    #[ cfg( feature = "idempotency" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_idempotency_keys( mut self ) -> Self
+    {
+      self.idempotency_keys = true;
+      self
+    }
+
+    /// Send `key` with every write made through this handle.
+    ///
+    /// Meant for one logical write: clone the client, fix the key, and make
+    /// the call. Inside `FailoverExecutor`, pass `FailoverContext::idempotency_key`
+    /// so every endpoint tried receives the same key.
+    /// Only available when the `idempotency` feature is enabled.
+    #[ cfg( feature = "idempotency" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_idempotency_key( mut self, key : api_common::IdempotencyKey ) -> Self
+    {
+      self.idempotency_key = Some( key );
+      self
+    }
+
+    /// Get the fixed idempotency key if configured.
+    #[ cfg( feature = "idempotency" ) ]
+    #[ inline ]
+    pub fn idempotency_key( &self ) -> Option< &api_common::IdempotencyKey >
+    {
+      self.idempotency_key.as_ref()
+    }
+
+    /// Call `hook` with the model and the usage of every chat completion,
+    /// streamed or not, to measure prompt caching per request.
+    ///
+    /// Streamed completions report usage only when
+    /// `stream_options.include_usage` is set. The usage is also logged at
+    /// `debug` level, with or without a hook.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_usage_hook( mut self, hook : impl Fn( &str, &CompletionUsage ) + Send + Sync + 'static ) -> Self
+    {
+      self.usage_hook = Some( UsageHook::new( hook ) );
+      self
+    }
+
+    /// Get the usage hook if configured.
+    #[ inline ]
+    pub fn usage_hook( &self ) -> Option< &UsageHook >
+    {
+      self.usage_hook.as_ref()
+    }
+
     /// Get rate limiting configuration if enabled.
     #[ cfg( feature = "rate_limiting" ) ]
     #[ inline ]
@@ -215,4 +422,23 @@ mod private
     }
   }
 
+  /// HTTP client settings shared by [`Client::build`] and
+  /// [`Client::with_connection_tuning`].
+  fn http_builder< E >( environment : &E ) -> Result< reqwest::ClientBuilder >
+  where
+    E : OpenaiEnvironment,
+  {
+    let headers = environment.headers()?;
+    let builder = HttpClient::builder()
+      .default_headers( headers );
+    // Timeouts, pooling and TCP settings belong to the host `fetch` on wasm32
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    let builder = builder
+      .timeout( core::time::Duration::from_secs( 300 ) ) // 5 minute default timeout
+      .connect_timeout( core::time::Duration::from_secs( 30 ) ) // 30 second connect timeout
+      .pool_max_idle_per_host( 10 ) // Connection pooling optimization
+      .pool_idle_timeout( core::time::Duration::from_secs( 90 ) ) // Keep connections alive
+      .tcp_keepalive( core::time::Duration::from_secs( 60 ) ); // TCP keepalive
+    Ok( builder )
+  }
 } // end mod private
@@ -9,14 +9,18 @@ mod private
   use crate::
   {
     client ::Client,
-    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    environment ::{ OpenaiEnvironment, EnvironmentInterface, OPENAI_BETA_HEADER, ASSISTANTS_V2_BETA },
     error ::{ OpenAIError, Result, map_deserialization_error },
     diagnostics ::{ DiagnosticsCollector, RequestMetrics, ResponseMetrics, ErrorMetrics },
   };
 
   use reqwest::Method;
   use serde::{ de::DeserializeOwned, Serialize };
-  use std::{ sync::Arc, time::Instant };
+  use std::sync::Arc;
+  use web_time::Instant;
+
+  /// First path segments of the endpoints served by Assistants v2.
+  const ASSISTANTS_V2_ROOTS : [ &str; 3 ] = [ "assistants", "threads", "vector_stores" ];
 
   impl< E > Client< E >
   where
@@ -54,10 +58,10 @@ mod private
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::GET, url.clone() ).query( query ).send()
+        Self::attach_beta_header( http_client.request( Method::GET, url.clone() ), path ).query( query ).send()
       }).await?;
 
-      let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
+      let bytes = self.guard_response( response.bytes().await?.to_vec() )?;
       let result = serde_json::from_slice( &bytes )
       .map_err( | error | map_deserialization_error( &error ) )?;
       Ok( result )
@@ -73,10 +77,10 @@ mod private
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::GET, url.clone() ).send()
+        Self::attach_beta_header( http_client.request( Method::GET, url.clone() ), path ).send()
       }).await?;
 
-      let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
+      let bytes = self.guard_response( response.bytes().await?.to_vec() )?;
       let result = serde_json::from_slice( &bytes )
         .map_err( |e| { let body = String::from_utf8_lossy(&bytes); OpenAIError::Internal( format!( "Failed to parse JSON response : {e}. Response body : {body}" ) ) } )?;
       Ok( result )
@@ -86,12 +90,26 @@ mod private
     /// Sends a POST request to the specified path with a JSON body.
     #[ inline ]
     pub(in crate) async fn post< I, O >( &self, path : &str, body : &I ) -> Result< O >
+    where
+      I : Serialize + Sync,
+      O : DeserializeOwned,
+    {
+      #[ cfg( feature = "guardrail" ) ]
+      if let Some( body ) = self.guard_request( body )?
+      {
+        return self.send_post( path, &body ).await;
+      }
+      self.send_post( path, body ).await
+    }
+
+    async fn send_post< I, O >( &self, path : &str, body : &I ) -> Result< O >
     where
       I : Serialize + Sync,
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
       let http_client = &self.http_client;
+      let idempotency_key = self.write_idempotency_key();
       let start_time = Instant::now();
 
       // Record request metrics if diagnostics are enabled
@@ -119,7 +137,8 @@ mod private
       }
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::POST, url.clone() ).json( body ).send()
+        let request = Self::attach_beta_header( http_client.request( Method::POST, url.clone() ), path );
+        Self::attach_idempotency_key( request, idempotency_key.as_deref() ).json( body ).send()
       }).await;
 
       // Handle response and record metrics
@@ -154,6 +173,7 @@ mod private
             diagnostics.record_response( &response_metrics );
           }
 
+          let bytes = self.guard_response( bytes )?;
           let result = serde_json::from_slice( &bytes )
             .map_err( |e| { let body = String::from_utf8_lossy(&bytes); OpenAIError::Internal( format!( "Failed to parse JSON response : {e}. Response body : {body}" ) ) } )?;
           Ok( result )
@@ -190,10 +210,10 @@ mod private
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::DELETE, url.clone() ).send()
+        Self::attach_beta_header( http_client.request( Method::DELETE, url.clone() ), path ).send()
       }).await?;
 
-      let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
+      let bytes = self.guard_response( response.bytes().await?.to_vec() )?;
       let result = serde_json::from_slice( &bytes )
         .map_err( |e| { let body = String::from_utf8_lossy(&bytes); OpenAIError::Internal( format!( "Failed to parse JSON response : {e}. Response body : {body}" ) ) } )?;
       Ok( result )
@@ -202,6 +222,19 @@ mod private
     /// Sends a PATCH request to the specified path with a JSON body.
     #[ inline ]
     pub(in crate) async fn patch< I, O >( &self, path : &str, body : &I ) -> Result< O >
+    where
+      I : Serialize + Sync,
+      O : DeserializeOwned,
+    {
+      #[ cfg( feature = "guardrail" ) ]
+      if let Some( body ) = self.guard_request( body )?
+      {
+        return self.send_patch( path, &body ).await;
+      }
+      self.send_patch( path, body ).await
+    }
+
+    async fn send_patch< I, O >( &self, path : &str, body : &I ) -> Result< O >
     where
       I : Serialize + Sync,
       O : DeserializeOwned,
@@ -212,7 +245,7 @@ mod private
         http_client.request( Method::PATCH, url.clone() ).json( body ).send()
       }).await?;
 
-      let bytes = response.bytes().await?.to_vec();
+      let bytes = self.guard_response( response.bytes().await?.to_vec() )?;
       let result = serde_json::from_slice( &bytes )?;
       Ok( result )
     }
@@ -225,14 +258,97 @@ mod private
     {
       let url = self.environment.join_base_url( path )?;
       let http_client = &self.http_client;
+      let idempotency_key = self.write_idempotency_key();
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::POST, url.clone() ).send()
+        let request = Self::attach_beta_header( http_client.request( Method::POST, url.clone() ), path );
+        Self::attach_idempotency_key( request, idempotency_key.as_deref() ).send()
       }).await?;
 
-      let bytes = response.bytes().await?.to_vec();
+      let bytes = self.guard_response( response.bytes().await?.to_vec() )?;
       let result = serde_json::from_slice( &bytes )?;
       Ok( result )
     }
+
+    /// Idempotency key for one write, chosen before the retry layer so every
+    /// attempt sends the same key.
+    #[ cfg( feature = "idempotency" ) ]
+    pub(in crate) fn write_idempotency_key( &self ) -> Option< String >
+    {
+      self.idempotency_key.clone()
+        .or_else( || self.idempotency_keys.then( api_common::IdempotencyKey::generate ) )
+        .map( String::from )
+    }
+
+    /// No key is sent without the `idempotency` feature.
+    #[ cfg( not( feature = "idempotency" ) ) ]
+    #[ allow( clippy::unused_self ) ]
+    pub(in crate) fn write_idempotency_key( &self ) -> Option< String >
+    {
+      None
+    }
+
+    /// Runs the request guardrails over `body`, returning the checked body
+    /// to send in its place, or `None` when no guardrails are configured.
+    #[ cfg( feature = "guardrail" ) ]
+    pub(in crate) fn guard_request< I >( &self, body : &I ) -> Result< Option< serde_json::Value > >
+    where
+      I : Serialize + ?Sized,
+    {
+      if self.guardrails.is_empty()
+      {
+        return Ok( None );
+      }
+      let mut body = serde_json::to_value( body )?;
+      self.guardrails.check_request( &mut body )?;
+      Ok( Some( body ) )
+    }
+
+    /// Runs the response guardrails over a JSON response body.
+    #[ cfg( feature = "guardrail" ) ]
+    fn guard_response( &self, bytes : Vec< u8 > ) -> Result< Vec< u8 > >
+    {
+      if self.guardrails.is_empty()
+      {
+        return Ok( bytes );
+      }
+      let mut body : serde_json::Value = serde_json::from_slice( &bytes )
+        .map_err( | error | map_deserialization_error( &error ) )?;
+      self.guardrails.check_response( &mut body )?;
+      Ok( serde_json::to_vec( &body )? )
+    }
+
+    /// Passes the body through unchanged without the `guardrail` feature.
+    #[ cfg( not( feature = "guardrail" ) ) ]
+    #[ allow( clippy::unnecessary_wraps, clippy::unused_self ) ]
+    fn guard_response( &self, bytes : Vec< u8 > ) -> Result< Vec< u8 > >
+    {
+      Ok( bytes )
+    }
+
+    /// Adds `OpenAI-Beta: assistants=v2` for paths under the Assistants v2
+    /// endpoints, which refuse requests without it.
+    pub(in crate) fn attach_beta_header( request : reqwest::RequestBuilder, path : &str ) -> reqwest::RequestBuilder
+    {
+      let root = path.trim_start_matches( '/' ).split( '/' ).next().unwrap_or_default();
+      if ASSISTANTS_V2_ROOTS.contains( &root )
+      {
+        request.header( OPENAI_BETA_HEADER, ASSISTANTS_V2_BETA )
+      }
+      else
+      {
+        request
+      }
+    }
+
+    /// Adds the `Idempotency-Key` header when a key was chosen for the write.
+    pub(in crate) fn attach_idempotency_key( request : reqwest::RequestBuilder, key : Option< &str > ) -> reqwest::RequestBuilder
+    {
+      match key
+      {
+        Some( key ) => request.header( "Idempotency-Key", key ),
+        None => request,
+      }
+    }
   }
 
 } // end mod private
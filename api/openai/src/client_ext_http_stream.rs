@@ -2,7 +2,7 @@
 //! Client streaming and binary HTTP methods extension.
 //!
 //! This module extends the `Client` with streaming HTTP methods (POST streaming,
-//! multipart uploads, binary POST/GET).
+//! multipart uploads, binary POST/GET, streamed binary POST/GET).
 
 mod private
 {
@@ -18,28 +18,54 @@ mod private
   use serde::{ de::DeserializeOwned, Serialize };
   use futures_util::StreamExt;
   use tokio::sync::mpsc;
-  use std::{ sync::Arc, time::Instant };
-  use eventsource_stream::Eventsource;
+  use std::sync::Arc;
+  use web_time::Instant;
+  use api_sse_stream::{ SseEvent, SseStreamExt };
 
   impl< E > Client< E >
   where
     E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
   {
     /// Sends a POST request to the specified path with a JSON body and streams the response.
-    #[ allow( clippy::unused_async ) ]
     #[ inline ]
     pub(in crate) async fn post_stream< I, O >( &self, path : &str, body : &I ) -> Result< mpsc::Receiver< Result< O > > >
     where
       I : Serialize,
       O : DeserializeOwned + Send + 'static, // Add Send + 'static
+    {
+      self.post_event_stream( path, body, | event | serde_json::from_str( &event.data ).map( | item | vec![ item ] ) ).await
+    }
+
+    /// Sends a POST request with a JSON body and streams the response, reading
+    /// each server-sent event with `decode` into the items it sends, in order.
+    ///
+    /// For streams whose event name carries meaning the data alone lacks,
+    /// such as the Assistants run events, or whose events hold several
+    /// items, such as the chat completion chunks.
+    #[ allow( clippy::unused_async ) ]
+    #[ inline ]
+    pub(in crate) async fn post_event_stream< I, O >( &self, path : &str, body : &I, decode : fn( &SseEvent ) -> serde_json::Result< Vec< O > > ) -> Result< mpsc::Receiver< Result< O > > >
+    where
+      I : Serialize,
+      O : Send + 'static,
     {
       let url = self.environment.join_base_url( path )?;
-      let request = self.http_client.request( Method::POST, url ).json( body );
+      let request = Self::attach_beta_header( self.http_client.request( Method::POST, url ), path );
+      #[ cfg( feature = "guardrail" ) ]
+      let request = match self.guard_request( body )?
+      {
+        Some( body ) => request.json( &body ),
+        None => request.json( body ),
+      };
+      #[ cfg( not( feature = "guardrail" ) ) ]
+      let request = request.json( body );
+      let admission = self.admit_request().await?;
       let ( tx, rx ) = mpsc::channel( 100 );
       let tx_arc = Arc::new( tx ); // Wrap tx in Arc
 
-      tokio ::spawn( async move
+      let task = async move
       {
+        let _admission = admission; // Hold the concurrency slot until the stream ends
         let tx_clone = Arc::< _ >::clone( &tx_arc ); // Clone the Arc< Sender >
         let response = match request.send().await
         {
@@ -51,8 +77,8 @@ mod private
           }
         };
 
-        // Use robust eventsource-stream parser instead of manual parsing
-        let mut event_stream = response.bytes_stream().eventsource();
+        // Shared decoder: handles keep-alive comments, any line ending, and chunk-split UTF-8
+        let mut event_stream = response.bytes_stream().sse_events();
 
         while let Some( event_result ) = event_stream.next().await
         {
@@ -65,17 +91,20 @@ mod private
               if !data.is_empty()
               {
                 // Handle data messages with robust SSE parsing
-                if data == "[DONE]"
+                if event.is_done()
                 {
                   return; // Exit the entire task
                 }
 
                 // Parse JSON with better error handling
-                match serde_json::from_str::< O >( data )
+                match decode( &event )
                 {
-                  Ok( obj ) =>
+                  Ok( items ) =>
                   {
-                    let _ = tx_clone.send( Ok( obj ) ).await;
+                    for item in items
+                    {
+                      let _ = tx_clone.send( Ok( item ) ).await;
+                    }
                   },
                   Err( e ) =>
                   {
@@ -96,7 +125,8 @@ mod private
             },
           }
         }
-      });
+      };
+      api_common::spawn( task );
 
       Ok( rx )
     }
@@ -106,6 +136,24 @@ mod private
     pub(in crate) async fn post_multipart< O >( &self, path : &str, form : reqwest::multipart::Form ) -> Result< O >
     where
       O : DeserializeOwned,
+    {
+      let response = self.send_multipart( path, form ).await?;
+      let result : O = response.json().await.map_err( | e | OpenAIError::Internal( e.to_string() ) )?;
+      Ok( result )
+    }
+
+    /// Sends a multipart POST request and returns the response body as text,
+    /// for endpoints that answer in a plain text format.
+    #[ inline ]
+    pub(in crate) async fn post_multipart_text( &self, path : &str, form : reqwest::multipart::Form ) -> Result< String >
+    {
+      let response = self.send_multipart( path, form ).await?;
+      let text = response.text().await.map_err( | e | OpenAIError::Network( format!( "Failed to read response text : {e}" ) ) )?;
+      Ok( text )
+    }
+
+    /// Sends a multipart POST request; a failed status is an error.
+    async fn send_multipart( &self, path : &str, form : reqwest::multipart::Form ) -> Result< reqwest::Response >
     {
       let url = self.environment.join_base_url( path )?;
       let http_client = &self.http_client;
@@ -134,15 +182,16 @@ mod private
       }
 
       // For multipart requests, don't use retry logic due to form consumption
-      let response = http_client.request( Method::POST, url ).multipart( form ).send().await;
+      let _admission = self.admit_request().await?;
+      let request = Self::attach_idempotency_key( http_client.request( Method::POST, url ), self.write_idempotency_key().as_deref() );
+      let response = request.multipart( form ).send().await;
 
       // Handle response
       let response = response.map_err( | e | OpenAIError::Network( e.to_string() ) )?;
 
       if response.status().is_success()
       {
-        let result : O = response.json().await.map_err( | e | OpenAIError::Internal( e.to_string() ) )?;
-        Ok( result )
+        Ok( response )
       }
       else
       {
@@ -333,6 +382,71 @@ mod private
         Err( e ) => Err( e )
       }
     }
+
+    /// Sends a GET request and streams the binary response in chunks as they arrive.
+    ///
+    /// A failed status is returned as an error before any chunk is sent.
+    #[ inline ]
+    pub(in crate) async fn get_byte_stream( &self, path : &str ) -> Result< mpsc::Receiver< Result< Vec< u8 > > > >
+    {
+      let url = self.environment.join_base_url( path )?;
+      let http_client = &self.http_client;
+      let response = self.execute_request_with_retry( || {
+        http_client.request( Method::GET, url.clone() ).send()
+      }).await?;
+      Self::byte_stream( response ).await
+    }
+
+    /// Sends a POST request with a JSON body and streams the binary response
+    /// in chunks as they arrive.
+    ///
+    /// A failed status is returned as an error before any chunk is sent.
+    #[ inline ]
+    pub(in crate) async fn post_byte_stream< I >( &self, path : &str, body : &I ) -> Result< mpsc::Receiver< Result< Vec< u8 > > > >
+    where
+      I : Serialize + Sync,
+    {
+      let url = self.environment.join_base_url( path )?;
+      let http_client = &self.http_client;
+      let response = self.execute_request_with_retry( || {
+        http_client.request( Method::POST, url.clone() ).json( body ).send()
+      }).await?;
+      Self::byte_stream( response ).await
+    }
+
+    /// Forwards the chunks of a response body to a channel.
+    async fn byte_stream( response : reqwest::Response ) -> Result< mpsc::Receiver< Result< Vec< u8 > > > >
+    {
+      if !response.status().is_success()
+      {
+        let status_code = response.status().as_u16();
+        let error_text = response.text().await.unwrap_or_else( | _ | "Unknown error".to_string() );
+        return Err( OpenAIError::Api( crate::error::ApiError {
+          code : Some( status_code.to_string() ),
+          message : error_text,
+          param : None,
+          r#type : Some( "http_error".to_string() ),
+        } ).into() );
+      }
+
+      let ( tx, rx ) = mpsc::channel( 16 );
+      let task = async move
+      {
+        let mut chunks = response.bytes_stream();
+        while let Some( chunk ) = chunks.next().await
+        {
+          let chunk = chunk.map( | bytes | bytes.to_vec() ).map_err( | e | OpenAIError::Network( format!( "Failed to read response bytes : {e}" ) ).into() );
+          let failed = chunk.is_err();
+          if tx.send( chunk ).await.is_err() || failed
+          {
+            return;
+          }
+        }
+      };
+      api_common::spawn( task );
+
+      Ok( rx )
+    }
   }
 
 } // end mod private
@@ -15,10 +15,21 @@ mod private
 
   #[ cfg( feature = "retry" ) ]
   use crate::enhanced_retry::{ EnhancedRetryConfig, EnhancedRetryExecutor };
+  #[ cfg( feature = "retry" ) ]
+  use api_common::CallBudget;
 
   #[ cfg( feature = "circuit_breaker" ) ]
   use crate::enhanced_circuit_breaker::{ EnhancedCircuitBreaker };
 
+  /// Concurrency slot held by one request while in flight, if a gate is
+  /// configured. Released on drop.
+  #[ derive( Debug ) ]
+  pub(in crate) struct Admission
+  {
+    #[ cfg( feature = "rate_limiting" ) ]
+    _permit : Option< api_common::ConcurrencyPermit >,
+  }
+
   impl< E > Client< E >
   where
     E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
@@ -33,6 +44,8 @@ mod private
       F : FnOnce() -> Fut + Send,
       Fut : core::future::Future< Output = core::result::Result< reqwest::Response, reqwest::Error > > + Send,
     {
+      let _admission = self.admit_request().await?;
+
       let response = request_builder().await
         .map_err( OpenAIError::from )?;
 
@@ -65,6 +78,33 @@ mod private
       }
     }
 
+    /// Waits for a slot in the concurrency gate, then for one request from
+    /// the shared rate limiter, when configured.
+    ///
+    /// Called before every attempt that reaches the network, including
+    /// streaming and multipart requests that bypass `execute_request`. The
+    /// returned admission holds the slot until dropped.
+    #[ allow( clippy::unused_async ) ]
+    pub(in crate) async fn admit_request( &self ) -> Result< Admission >
+    {
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+        let permit = match &self.concurrency_gate
+        {
+          Some( gate ) => Some( gate.acquire().await.map_err( | e | OpenAIError::RateLimit( e.to_string() ) )? ),
+          None => None,
+        };
+        if let Some( limiter ) = &self.shared_rate_limiter
+        {
+          limiter.acquire( 0 ).await
+            .map_err( | e | OpenAIError::RateLimit( e.to_string() ) )?;
+        }
+        Ok( Admission { _permit : permit } )
+      }
+      #[ cfg( not( feature = "rate_limiting" ) ) ]
+      Ok( Admission {} )
+    }
+
     /// Enhanced HTTP request handler with optional retry and circuit breaker logic
     ///
     /// This method wraps `execute_request` with optional reliability features when configured.
@@ -173,7 +213,11 @@ mod private
       let executor = EnhancedRetryExecutor::new( retry_config.clone() )
         .map_err( |e| OpenAIError::InvalidArgument( format!( "Invalid retry configuration : {e}" ) ) )?;
 
-      executor.execute( || self.execute_request( &request_builder ) ).await
+      match self.call_budget.map( CallBudget::start )
+      {
+        Some( budget ) => executor.execute_within( &budget, || self.execute_request( &request_builder ) ).await,
+        None => executor.execute( || self.execute_request( &request_builder ) ).await,
+      }
     }
 
     /// Execute request with circuit breaker logic (only when `circuit_breaker` feature is enabled)
@@ -7,6 +7,7 @@ mod private
   // Removed unused : use crate::components::common::Metadata;
   // Serde imports
   use serde::{ Serialize, Deserialize };
+  use former::Former;
 
   /// Represents the owner of an Admin API Key (User or Service Account).
   ///
@@ -456,6 +457,238 @@ mod private
     pub has_more : bool,
   }
 
+  /// Query parameters for listing organization users, invites, admin API
+  /// keys, and the users, service accounts, and API keys of a project.
+  ///
+  /// # Used By
+  /// - `/organization/users` (GET)
+  /// - `/organization/invites` (GET)
+  /// - `/organization/admin_api_keys` (GET)
+  /// - `/organization/projects/{project_id}/users` (GET)
+  /// - `/organization/projects/{project_id}/service_accounts` (GET)
+  /// - `/organization/projects/{project_id}/api_keys` (GET)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct AdminListQuery
+  {
+    /// Cursor: return the objects after the object with this ID.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub after : Option< String >,
+    /// The maximum number of objects to return, between 1 and 100. Defaults to 20.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub limit : Option< u32 >,
+  }
+
+  /// Query parameters for listing the projects of the organization.
+  ///
+  /// # Used By
+  /// - `/organization/projects` (GET)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ListProjectsQuery
+  {
+    /// Cursor: return the projects after the project with this ID.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub after : Option< String >,
+    /// The maximum number of projects to return, between 1 and 100. Defaults to 20.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub limit : Option< u32 >,
+    /// Whether archived projects are listed too. Defaults to `false`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub include_archived : Option< bool >,
+  }
+
+  /// Request body for inviting a user to the organization.
+  ///
+  /// # Used By
+  /// - `/organization/invites` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct InviteRequest
+  {
+    /// Email address to invite.
+    pub email : String,
+    /// Organization role assigned upon acceptance ("owner" or "reader").
+    pub role : String,
+    /// Projects granted membership upon acceptance.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub projects : Option< Vec< InviteProject > >,
+  }
+
+  impl InviteRequest
+  {
+    /// Creates an invite for `email` with an organization `role`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( email : impl Into< String >, role : impl Into< String > ) -> Self
+    {
+      Self { email : email.into(), role : role.into(), projects : None }
+    }
+  }
+
+  /// Request body for changing the organization role of a user.
+  ///
+  /// # Used By
+  /// - `/organization/users/{user_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct UserRoleUpdateRequest
+  {
+    /// The new organization role ("owner" or "reader").
+    pub role : String,
+  }
+
+  impl UserRoleUpdateRequest
+  {
+    /// Creates a role change to `role`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( role : impl Into< String > ) -> Self
+    {
+      Self { role : role.into() }
+    }
+  }
+
+  /// Request body for creating a project.
+  ///
+  /// # Used By
+  /// - `/organization/projects` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ProjectCreateRequest
+  {
+    /// The name of the project, shown in reports.
+    pub name : String,
+  }
+
+  impl ProjectCreateRequest
+  {
+    /// Creates a project request with a `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( name : impl Into< String > ) -> Self
+    {
+      Self { name : name.into() }
+    }
+  }
+
+  /// Request body for renaming a project.
+  ///
+  /// # Used By
+  /// - `/organization/projects/{project_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ProjectUpdateRequest
+  {
+    /// The new name of the project.
+    pub name : String,
+  }
+
+  impl ProjectUpdateRequest
+  {
+    /// Creates a rename to `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( name : impl Into< String > ) -> Self
+    {
+      Self { name : name.into() }
+    }
+  }
+
+  /// Request body for adding an organization user to a project.
+  ///
+  /// # Used By
+  /// - `/organization/projects/{project_id}/users` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ProjectUserCreateRequest
+  {
+    /// The ID of the user, who must already be in the organization.
+    pub user_id : String,
+    /// Project role ("owner" or "member").
+    pub role : String,
+  }
+
+  impl ProjectUserCreateRequest
+  {
+    /// Creates a request adding `user_id` to a project with `role`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( user_id : impl Into< String >, role : impl Into< String > ) -> Self
+    {
+      Self { user_id : user_id.into(), role : role.into() }
+    }
+  }
+
+  /// Request body for changing the project role of a user.
+  ///
+  /// # Used By
+  /// - `/organization/projects/{project_id}/users/{user_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ProjectUserUpdateRequest
+  {
+    /// The new project role ("owner" or "member").
+    pub role : String,
+  }
+
+  impl ProjectUserUpdateRequest
+  {
+    /// Creates a role change to `role`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( role : impl Into< String > ) -> Self
+    {
+      Self { role : role.into() }
+    }
+  }
+
+  /// Request body for creating a project service account.
+  ///
+  /// # Used By
+  /// - `/organization/projects/{project_id}/service_accounts` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ProjectServiceAccountCreateRequest
+  {
+    /// The name of the service account.
+    pub name : String,
+  }
+
+  impl ProjectServiceAccountCreateRequest
+  {
+    /// Creates a service account request with a `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( name : impl Into< String > ) -> Self
+    {
+      Self { name : name.into() }
+    }
+  }
+
+  /// Request body for creating an admin API key.
+  ///
+  /// # Used By
+  /// - `/organization/admin_api_keys` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct AdminApiKeyCreateRequest
+  {
+    /// The name of the key.
+    pub name : String,
+  }
+
+  impl AdminApiKeyCreateRequest
+  {
+    /// Creates an admin API key request with a `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( name : impl Into< String > ) -> Self
+    {
+      Self { name : name.into() }
+    }
+  }
+
 } // end mod private
 
 crate ::mod_interface!
@@ -485,5 +718,15 @@ crate ::mod_interface!
     ProjectServiceAccountApiKey,
     ProjectServiceAccountCreateResponse,
     ProjectUserListResponse,
+    AdminListQuery,
+    ListProjectsQuery,
+    InviteRequest,
+    UserRoleUpdateRequest,
+    ProjectCreateRequest,
+    ProjectUpdateRequest,
+    ProjectUserCreateRequest,
+    ProjectUserUpdateRequest,
+    ProjectServiceAccountCreateRequest,
+    AdminApiKeyCreateRequest,
   };
 }
\ No newline at end of file
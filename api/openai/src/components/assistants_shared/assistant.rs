@@ -11,10 +11,11 @@ mod private
     ResponseFormatJsonObject,
     ResponseFormatJsonSchema,
   };
-  use crate::components::tools::{ Tool, ToolChoiceFunction, FileSearchRankingOptions };
+  use crate::components::tools::{ ToolChoiceFunction, FileSearchRankingOptions };
 
   // Add serde imports
   use serde::{ Serialize, Deserialize };
+  use former::Former;
 
   /// Represents the supported models for Assistants. Placeholder type.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
@@ -169,6 +170,31 @@ mod private
     pub function : crate::components::tools::FunctionTool,
   }
 
+  /// A tool enabled on an assistant, a run, or a message attachment.
+  ///
+  /// # Used By
+  /// - `AssistantObject.tools`
+  /// - `CreateAssistantRequest.tools`
+  /// - `ModifyAssistantRequest.tools`
+  /// - `RunObject.tools`
+  /// - `CreateRunRequest.tools`
+  /// - `CreateThreadAndRunRequest.tools`
+  /// - `MessageAttachment.tools`
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( tag = "type" ) ]
+  pub enum AssistantTool
+  {
+    /// The Code Interpreter tool.
+    #[ serde( rename = "code_interpreter" ) ]
+    CodeInterpreter( AssistantToolsCode ),
+    /// The File Search tool.
+    #[ serde( rename = "file_search" ) ]
+    FileSearch( AssistantToolsFileSearch ),
+    /// A function defined by the caller.
+    #[ serde( rename = "function" ) ]
+    Function( AssistantToolsFunction ),
+  }
+
   /// Represents an `assistant` that can call the model and use tools.
   ///
   /// # Used By
@@ -195,7 +221,7 @@ mod private
     /// The system instructions that the assistant uses. The maximum length is 256,000 characters.
     pub instructions : Option< String >,
     /// A list of tool enabled on the assistant. Maximum 128 tools.
-    pub tools : Vec< Tool >,
+    pub tools : Vec< AssistantTool >,
     /// A set of resources used by the assistant's tools.
     pub tool_resources : Option< ToolResources >,
     /// Set of 16 key-value pairs attached to the object.
@@ -208,6 +234,85 @@ mod private
     pub response_format : Option< AssistantsApiResponseFormatOption >,
   }
 
+  /// Request body for creating an assistant.
+  ///
+  /// # Used By
+  /// - `/assistants` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateAssistantRequest
+  {
+    /// ID of the model to use.
+    pub model : String,
+    /// The name of the assistant. The maximum length is 256 characters.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// The description of the assistant. The maximum length is 512 characters.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub description : Option< String >,
+    /// The system instructions that the assistant uses.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+    /// A list of tool enabled on the assistant. Maximum 128 tools.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tools : Option< Vec< AssistantTool > >,
+    /// A set of resources used by the assistant's tools.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_resources : Option< ToolResources >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+    /// Sampling temperature between 0 and 2.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// The format the model must output.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< AssistantsApiResponseFormatOption >,
+  }
+
+  /// Request body for modifying an assistant; only the fields set are changed.
+  ///
+  /// # Used By
+  /// - `/assistants/{assistant_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ModifyAssistantRequest
+  {
+    /// ID of the model to use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub model : Option< String >,
+    /// The name of the assistant.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// The description of the assistant.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub description : Option< String >,
+    /// The system instructions that the assistant uses.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+    /// The tools enabled on the assistant, replacing the current ones.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tools : Option< Vec< AssistantTool > >,
+    /// A set of resources used by the assistant's tools.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_resources : Option< ToolResources >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+    /// Sampling temperature between 0 and 2.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// The format the model must output.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< AssistantsApiResponseFormatOption >,
+  }
+
   /// A set of resources that are used by the assistant's tools.
   ///
   /// # Used By
@@ -265,10 +370,10 @@ mod private
     pub object : String,
     /// A list of assistant objects.
     pub data : Vec< AssistantObject >,
-    /// The ID of the first assistant in the list.
-    pub first_id : String,
-    /// The ID of the last assistant in the list.
-    pub last_id : String,
+    /// The ID of the first assistant in the list; `None` when the list is empty.
+    pub first_id : Option< String >,
+    /// The ID of the last assistant in the list; `None` when the list is empty.
+    pub last_id : Option< String >,
     /// Indicates whether there are more assistants available.
     pub has_more : bool,
   }
@@ -301,7 +406,10 @@ crate ::mod_interface!
   exposed use private::AssistantFileSearchSettings;
   exposed use private::AssistantToolsFileSearchTypeOnly;
   exposed use private::AssistantToolsFunction;
+  exposed use private::AssistantTool;
   exposed use private::AssistantObject;
+  exposed use private::CreateAssistantRequest;
+  exposed use private::ModifyAssistantRequest;
   exposed use private::ToolResources;
   exposed use private::CodeInterpreterResources;
   exposed use private::FileSearchResources;
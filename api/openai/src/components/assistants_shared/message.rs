@@ -5,11 +5,12 @@ mod private
 {
   // Use full paths from crate root for components
   use crate::components::common::Metadata;
-  use crate::components::tools::Tool;
+  use crate::components::assistants_shared::assistant::AssistantTool;
   use crate::components::output::Annotation;
 
   // Add serde imports
   use serde::{ Serialize, Deserialize };
+  use former::Former;
 
   /// References an image File in the content of a message.
   ///
@@ -190,14 +191,132 @@ mod private
   ///
   /// # Used By
   /// - `MessageObject`
-  /// - `CreateMessageRequest` (within `requests/assistants.rs` - *assuming it exists*)
+  /// - `CreateMessageRequest`
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   pub struct MessageAttachment
   {
     /// The ID of the file to attach.
     pub file_id : String,
     /// The tools to add this file to.
-    pub tools : Vec< Tool >,
+    pub tools : Vec< AssistantTool >,
+  }
+
+  /// The content of a message being created: plain text, or a list of text
+  /// and image parts.
+  ///
+  /// # Used By
+  /// - `CreateMessageRequest`
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  #[ serde( untagged ) ]
+  pub enum CreateMessageContent
+  {
+    /// Plain text content.
+    Text( String ),
+    /// Text and image parts.
+    Parts( Vec< CreateMessageContentPart > ),
+  }
+
+  impl From< String > for CreateMessageContent
+  {
+    #[ inline ]
+    fn from( text : String ) -> Self
+    {
+      Self::Text( text )
+    }
+  }
+
+  impl From< &str > for CreateMessageContent
+  {
+    #[ inline ]
+    fn from( text : &str ) -> Self
+    {
+      Self::Text( text.to_string() )
+    }
+  }
+
+  /// One part of the content of a message being created.
+  ///
+  /// # Used By
+  /// - `CreateMessageContent::Parts`
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  #[ serde( tag = "type" ) ]
+  pub enum CreateMessageContentPart
+  {
+    /// Text content.
+    #[ serde( rename = "text" ) ]
+    Text
+    {
+      /// The text content.
+      text : String
+    },
+    /// An uploaded image file.
+    #[ serde( rename = "image_file" ) ]
+    ImageFile
+    {
+      /// The file ID and optional detail level.
+      image_file : ImageFileContent
+    },
+    /// An external image URL.
+    #[ serde( rename = "image_url" ) ]
+    ImageUrl
+    {
+      /// The image URL and optional detail level.
+      image_url : ImageUrlContent
+    },
+  }
+
+  /// Request body for creating a message in a thread.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/messages` (POST)
+  /// - `CreateThreadRequest.messages`
+  /// - `CreateRunRequest.additional_messages`
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateMessageRequest
+  {
+    /// The entity that is creating the message (`user` or `assistant`).
+    pub role : String,
+    /// The content of the message.
+    pub content : CreateMessageContent,
+    /// Files attached to the message, and the tools they are added to.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub attachments : Option< Vec< MessageAttachment > >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  impl CreateMessageRequest
+  {
+    /// A `user` message with the given content.
+    #[ inline ]
+    #[ must_use ]
+    pub fn user( content : impl Into< CreateMessageContent > ) -> Self
+    {
+      Self { role : "user".to_string(), content : content.into(), attachments : None, metadata : None }
+    }
+
+    /// An `assistant` message with the given content.
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant( content : impl Into< CreateMessageContent > ) -> Self
+    {
+      Self { role : "assistant".to_string(), content : content.into(), attachments : None, metadata : None }
+    }
+  }
+
+  /// Request body for modifying a message.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/messages/{message_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ModifyMessageRequest
+  {
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
   }
 
   /// Represents a message within a thread.
@@ -555,6 +674,10 @@ crate ::mod_interface!
   exposed use private::MessageContentRefusalObject;
   exposed use private::MessageContent;
   exposed use private::MessageAttachment;
+  exposed use private::CreateMessageContent;
+  exposed use private::CreateMessageContentPart;
+  exposed use private::CreateMessageRequest;
+  exposed use private::ModifyMessageRequest;
   exposed use private::MessageObject;
   exposed use private::IncompleteDetails;
   exposed use private::ListMessagesResponse;
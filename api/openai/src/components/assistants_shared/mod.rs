@@ -21,7 +21,10 @@ pub use assistant::
   AssistantFileSearchSettings,
   AssistantToolsFileSearchTypeOnly,
   AssistantToolsFunction,
+  AssistantTool,
   AssistantObject,
+  CreateAssistantRequest,
+  ModifyAssistantRequest,
   ToolResources,
   CodeInterpreterResources,
   FileSearchResources,
@@ -45,6 +48,10 @@ pub use message::
   MessageContentRefusalObject,
   MessageContent,
   MessageAttachment,
+  CreateMessageContent,
+  CreateMessageContentPart,
+  CreateMessageRequest,
+  ModifyMessageRequest,
   MessageObject,
   IncompleteDetails,
   ListMessagesResponse,
@@ -54,6 +61,8 @@ pub use message::
 pub use thread::
 {
   ThreadObject,
+  CreateThreadRequest,
+  ModifyThreadRequest,
   ListThreadsResponse,
 };
 
@@ -67,6 +76,11 @@ pub use run::
   RunToolCallFunction,
   RunObject,
   ListRunsResponse,
+  CreateRunRequest,
+  CreateThreadAndRunRequest,
+  ModifyRunRequest,
+  ToolOutput,
+  SubmitToolOutputsRequest,
   RunStepDetailsMessageCreationObject,
   MessageCreationDetails,
   RunStepDetailsToolCallsCodeOutputLogsObject,
@@ -9,20 +9,23 @@ mod private
     Metadata,
     CompletionUsage,
   };
-  use crate::components::tools::Tool;
 
   // Import message types from the message module
-  use crate::components::assistants_shared::message::IncompleteDetails;
+  use crate::components::assistants_shared::message::{ IncompleteDetails, CreateMessageRequest };
+  use crate::components::assistants_shared::thread::CreateThreadRequest;
 
   // Add serde imports
   use serde::{ Serialize, Deserialize };
   use serde_json::Value;
+  use former::Former;
 
   // Import types from assistant module
   use crate::components::assistants_shared::assistant::
   {
+    AssistantTool,
     AssistantsApiResponseFormatOption,
     AssistantsApiToolChoiceOption,
+    ToolResources,
     TruncationObject,
   };
 
@@ -146,7 +149,7 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub instructions : Option< String >,
     /// The list of tools that the assistant used for this run.
-    pub tools : Vec< Tool >,
+    pub tools : Vec< AssistantTool >,
     /// Set of 16 key-value pairs attached to the object.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub metadata : Option< Metadata >,
@@ -178,6 +181,197 @@ mod private
     pub response_format : Option< AssistantsApiResponseFormatOption >,
   }
 
+  impl RunObject
+  {
+    /// Whether the run can still change status without a call from the
+    /// caller: `queued`, `in_progress`, or `cancelling`.
+    ///
+    /// Every other status waits for the caller (`requires_action`) or is
+    /// final.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_pending( &self ) -> bool
+    {
+      matches!( self.status.as_str(), "queued" | "in_progress" | "cancelling" )
+    }
+  }
+
+  /// Request body for creating a run on a thread.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/runs` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateRunRequest
+  {
+    /// The ID of the assistant to execute this run.
+    pub assistant_id : String,
+    /// Model overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub model : Option< String >,
+    /// Instructions overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+    /// Instructions appended to the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub additional_instructions : Option< String >,
+    /// Messages added to the thread before the run starts.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub additional_messages : Option< Vec< CreateMessageRequest > >,
+    /// Tools overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tools : Option< Vec< AssistantTool > >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+    /// Sampling temperature between 0 and 2.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// Whether to stream the run as server-sent events; set by the streaming methods.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream : Option< bool >,
+    /// The maximum number of prompt tokens the run may use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_prompt_tokens : Option< i32 >,
+    /// The maximum number of completion tokens the run may use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_completion_tokens : Option< i32 >,
+    /// How the thread is truncated before the run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub truncation_strategy : Option< TruncationObject >,
+    /// Which tool, if any, the model must call.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_choice : Option< AssistantsApiToolChoiceOption >,
+    /// Whether the model may call tools in parallel.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub parallel_tool_calls : Option< bool >,
+    /// The format the model must output.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< AssistantsApiResponseFormatOption >,
+  }
+
+  /// Request body for creating a thread and running it in one call.
+  ///
+  /// # Used By
+  /// - `/threads/runs` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateThreadAndRunRequest
+  {
+    /// The ID of the assistant to execute this run.
+    pub assistant_id : String,
+    /// The thread to create; an empty thread when `None`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub thread : Option< CreateThreadRequest >,
+    /// Model overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub model : Option< String >,
+    /// Instructions overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+    /// Tools overriding the assistant's for this run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tools : Option< Vec< AssistantTool > >,
+    /// A set of resources used by the assistant's tools.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_resources : Option< ToolResources >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+    /// Sampling temperature between 0 and 2.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub temperature : Option< f32 >,
+    /// Nucleus sampling probability.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub top_p : Option< f32 >,
+    /// Whether to stream the run as server-sent events; set by the streaming methods.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream : Option< bool >,
+    /// The maximum number of prompt tokens the run may use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_prompt_tokens : Option< i32 >,
+    /// The maximum number of completion tokens the run may use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_completion_tokens : Option< i32 >,
+    /// How the thread is truncated before the run.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub truncation_strategy : Option< TruncationObject >,
+    /// Which tool, if any, the model must call.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_choice : Option< AssistantsApiToolChoiceOption >,
+    /// Whether the model may call tools in parallel.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub parallel_tool_calls : Option< bool >,
+    /// The format the model must output.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< AssistantsApiResponseFormatOption >,
+  }
+
+  /// Request body for modifying a run.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/runs/{run_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ModifyRunRequest
+  {
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  /// The output of one tool call a run is waiting for.
+  ///
+  /// # Used By
+  /// - `SubmitToolOutputsRequest`
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  pub struct ToolOutput
+  {
+    /// The ID of the tool call, from `RequiredAction.submit_tool_outputs.tool_calls`.
+    pub tool_call_id : String,
+    /// The output of the tool call.
+    pub output : String,
+  }
+
+  impl ToolOutput
+  {
+    /// The output of the tool call `tool_call_id`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( tool_call_id : impl Into< String >, output : impl Into< String > ) -> Self
+    {
+      Self { tool_call_id : tool_call_id.into(), output : output.into() }
+    }
+  }
+
+  /// Request body for submitting tool outputs to a run in `requires_action`.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/runs/{run_id}/submit_tool_outputs` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  pub struct SubmitToolOutputsRequest
+  {
+    /// One output per tool call the run is waiting for.
+    pub tool_outputs : Vec< ToolOutput >,
+    /// Whether to stream the rest of the run; set by the streaming method.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream : Option< bool >,
+  }
+
+  impl SubmitToolOutputsRequest
+  {
+    /// A request submitting `tool_outputs`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( tool_outputs : Vec< ToolOutput > ) -> Self
+    {
+      Self { tool_outputs, stream : None }
+    }
+  }
+
   /// Response containing a list of runs.
   ///
   /// # Used By
@@ -469,6 +663,11 @@ crate ::mod_interface!
   exposed use private::RunToolCallFunction;
   exposed use private::RunObject;
   exposed use private::ListRunsResponse;
+  exposed use private::CreateRunRequest;
+  exposed use private::CreateThreadAndRunRequest;
+  exposed use private::ModifyRunRequest;
+  exposed use private::ToolOutput;
+  exposed use private::SubmitToolOutputsRequest;
   exposed use private::RunStepDetailsMessageCreationObject;
   exposed use private::MessageCreationDetails;
   exposed use private::RunStepDetailsToolCallsCodeOutputLogsObject;
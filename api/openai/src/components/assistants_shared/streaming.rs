@@ -173,10 +173,13 @@ mod private
 
   /// Represents the delta for different types of message content during streaming.
   ///
+  /// Parts are told apart by their `type` field: every payload field of a
+  /// delta is optional, so matching on shape alone would read any part as
+  /// an image file.
+  ///
   /// # Used By
   /// - `MessageDelta`
-  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
-  #[ serde( untagged ) ]
+  #[ derive( Debug, Clone, PartialEq ) ]
   pub enum MessageDeltaContent
   {
     /// Image file delta.
@@ -189,6 +192,28 @@ mod private
     ImageUrl( MessageDeltaContentImageUrlObject ),
   }
 
+  impl< 'de > Deserialize< 'de > for MessageDeltaContent
+  {
+    #[ inline ]
+    fn deserialize< D >( deserializer : D ) -> core::result::Result< Self, D::Error >
+    where
+      D : serde::Deserializer< 'de >,
+    {
+      use serde::de::Error as _;
+      let value = Value::deserialize( deserializer )?;
+      let kind = value.get( "type" ).and_then( Value::as_str ).unwrap_or_default().to_string();
+      let part = match kind.as_str()
+      {
+        "image_file" => serde_json::from_value( value ).map( Self::ImageFile ),
+        "text" => serde_json::from_value( value ).map( Self::Text ),
+        "refusal" => serde_json::from_value( value ).map( Self::Refusal ),
+        "image_url" => serde_json::from_value( value ).map( Self::ImageUrl ),
+        other => return Err( D::Error::custom( format!( "unknown message delta content type `{other}`" ) ) ),
+      };
+      part.map_err( D::Error::custom )
+    }
+  }
+
   /// Represents the changes in a message during streaming.
   ///
   /// # Used By
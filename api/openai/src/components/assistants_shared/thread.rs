@@ -5,9 +5,11 @@ mod private
 {
   use crate::components::common::Metadata;
   use crate::components::assistants_shared::assistant::ToolResources;
+  use crate::components::assistants_shared::message::CreateMessageRequest;
 
   // Add serde imports
   use serde::{ Serialize, Deserialize };
+  use former::Former;
 
   /// Represents a thread that contains messages.
   ///
@@ -35,6 +37,42 @@ mod private
     pub metadata : Option< Metadata >,
   }
 
+  /// Request body for creating a thread.
+  ///
+  /// # Used By
+  /// - `/threads` (POST)
+  /// - `CreateThreadAndRunRequest.thread`
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateThreadRequest
+  {
+    /// Messages to start the thread with.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub messages : Option< Vec< CreateMessageRequest > >,
+    /// A set of resources made available to the assistant's tools in this thread.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_resources : Option< ToolResources >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  /// Request body for modifying a thread; only the fields set are changed.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ModifyThreadRequest
+  {
+    /// A set of resources made available to the assistant's tools in this thread.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_resources : Option< ToolResources >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
   /// Response containing a list of threads. (Note : `OpenAI` spec doesn't define a list threads endpoint, but this structure might be useful internally or for future expansion).
   #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
   pub struct ListThreadsResponse
@@ -55,5 +93,7 @@ mod private
 crate ::mod_interface!
 {
   exposed use private::ThreadObject;
+  exposed use private::CreateThreadRequest;
+  exposed use private::ModifyThreadRequest;
   exposed use private::ListThreadsResponse;
 }
\ No newline at end of file
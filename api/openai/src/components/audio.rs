@@ -182,6 +182,28 @@ mod private
     /// The speed of the generated audio.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub speed : Option< f64 >,
+    /// How the voice should speak: tone, accent, pace. Not supported by `tts-1` and `tts-1-hd`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+  }
+
+  impl CreateSpeechRequest
+  {
+    /// Reads `input` aloud with `voice`, in the default `mp3` format.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( model : impl Into< String >, input : impl Into< String >, voice : SpeechVoice ) -> Self
+    {
+      Self
+      {
+        model : model.into(),
+        input : input.into(),
+        voice,
+        response_format : None,
+        speed : None,
+        instructions : None,
+      }
+    }
   }
 
   /// Available voices for speech generation.
@@ -191,6 +213,12 @@ mod private
   {
     /// Alloy voice
     Alloy,
+    /// Ash voice
+    Ash,
+    /// Ballad voice
+    Ballad,
+    /// Coral voice
+    Coral,
     /// Echo voice
     Echo,
     /// Fable voice
@@ -199,8 +227,12 @@ mod private
     Onyx,
     /// Nova voice
     Nova,
+    /// Sage voice
+    Sage,
     /// Shimmer voice
     Shimmer,
+    /// Verse voice
+    Verse,
   }
 
   /// Available response formats for speech generation.
@@ -243,10 +275,31 @@ mod private
     pub response_format : Option< TranscriptionResponseFormat >,
     /// The sampling temperature between 0 and 1.
     pub temperature : Option< f64 >,
-    /// Word-level timestamps.
+    /// Timestamps to add to a `verbose_json` transcript: words, segments, or both.
     pub timestamp_granularities : Option< Vec< TimestampGranularity > >,
   }
 
+  impl CreateTranscriptionRequest
+  {
+    /// Transcribes the audio `file`, named `filename`, with `model`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( file : Vec< u8 >, filename : impl Into< String >, model : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        file,
+        filename : filename.into(),
+        model : model.into(),
+        language : None,
+        prompt : None,
+        response_format : None,
+        temperature : None,
+        timestamp_granularities : None,
+      }
+    }
+  }
+
   /// Request parameters for translating audio to English text.
   ///
   /// # Used By
@@ -268,6 +321,25 @@ mod private
     pub temperature : Option< f64 >,
   }
 
+  impl CreateTranslationRequest
+  {
+    /// Translates the audio `file`, named `filename`, into English with `model`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( file : Vec< u8 >, filename : impl Into< String >, model : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        file,
+        filename : filename.into(),
+        model : model.into(),
+        prompt : None,
+        response_format : None,
+        temperature : None,
+      }
+    }
+  }
+
   /// Available response formats for transcription and translation.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   #[ serde( rename_all = "snake_case" ) ]
@@ -285,6 +357,32 @@ mod private
     Vtt,
   }
 
+  impl TranscriptionResponseFormat
+  {
+    /// The value sent in the `response_format` form field.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( &self ) -> &'static str
+    {
+      match self
+      {
+        Self::Json => "json",
+        Self::Text => "text",
+        Self::Srt => "srt",
+        Self::VerboseJson => "verbose_json",
+        Self::Vtt => "vtt",
+      }
+    }
+
+    /// Whether the response body is plain text (`text`, `srt`, `vtt`) rather than JSON.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_text( &self ) -> bool
+    {
+      matches!( self, Self::Text | Self::Srt | Self::Vtt )
+    }
+  }
+
   /// Timestamp granularity options.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   #[ serde( rename_all = "lowercase" ) ]
@@ -295,6 +393,21 @@ mod private
     /// Segment-level timestamps
     Segment,
   }
+
+  impl TimestampGranularity
+  {
+    /// The value sent in a `timestamp_granularities[]` form field.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( &self ) -> &'static str
+    {
+      match self
+      {
+        Self::Word => "word",
+        Self::Segment => "segment",
+      }
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -5,9 +5,12 @@ mod private
 {
   // Use full paths from crate root for components
   use crate::components::common::Metadata;
+  use crate::components::chat_shared::ChatCompletionRequest;
+  use crate::error::{ OpenAIError, Result };
   // Serde imports
   use serde::{ Serialize, Deserialize };
   use serde_json::Value; // Needed for BatchRequestOutput body
+  use former::Former;
 
   // Note : Many structs here are duplicates from administration_shared.rs
   // In a real implementation, these would likely be defined in a common place
@@ -564,6 +567,74 @@ mod private
       pub metadata : Option< Metadata >,
   }
 
+  impl Batch
+  {
+      /// Whether the batch can still change status: `validating`,
+      /// `in_progress`, `finalizing`, or `cancelling`.
+      ///
+      /// Every other status is final; `output_file_id` and `error_file_id`
+      /// are set once the batch is `completed`, `expired`, or `cancelled`.
+      #[ inline ]
+      #[ must_use ]
+      pub fn is_pending( &self ) -> bool
+      {
+        matches!( self.status.as_str(), "validating" | "in_progress" | "finalizing" | "cancelling" )
+      }
+  }
+
+  /// Request body for creating a batch from an uploaded JSONL input file.
+  ///
+  /// # Used By
+  /// - `/batches` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateBatchRequest
+  {
+      /// The ID of a file uploaded with the `batch` purpose.
+      pub input_file_id : String,
+      /// The endpoint every request of the input file is sent to, e.g. "/v1/chat/completions".
+      pub endpoint : String,
+      /// The time frame within which the batch should be processed. Only "24h" is supported.
+      pub completion_window : String,
+      /// Metadata to attach to the batch.
+      #[ serde( skip_serializing_if = "Option::is_none" ) ]
+      pub metadata : Option< Metadata >,
+  }
+
+  impl CreateBatchRequest
+  {
+      /// Sends the requests of the uploaded file `input_file_id` to `endpoint`
+      /// within the "24h" completion window.
+      #[ inline ]
+      #[ must_use ]
+      pub fn new( input_file_id : impl Into< String >, endpoint : impl Into< String > ) -> Self
+      {
+        Self
+        {
+          input_file_id : input_file_id.into(),
+          endpoint : endpoint.into(),
+          completion_window : "24h".to_string(),
+          metadata : None,
+        }
+      }
+  }
+
+  /// Query parameters for listing batches.
+  ///
+  /// # Used By
+  /// - `/batches` (GET)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ListBatchesQuery
+  {
+      /// Cursor: return the batches after the batch with this ID.
+      #[ serde( skip_serializing_if = "Option::is_none" ) ]
+      pub after : Option< String >,
+      /// The maximum number of batches to return. Defaults to 20.
+      #[ serde( skip_serializing_if = "Option::is_none" ) ]
+      pub limit : Option< u32 >,
+  }
+
   /// Response containing a list of Batch jobs.
   ///
   /// # Used By
@@ -599,9 +670,56 @@ mod private
       /// The `OpenAI` API relative URL (e.g., "/v1/chat/completions").
       #[ serde( skip_serializing_if = "Option::is_none" ) ]
       pub url : Option< String >,
-      // Note : The 'body' field containing the actual request payload (e.g., CreateChatCompletionRequest)
-      // is handled dynamically during batch file creation/parsing, typically using serde_json::Value.
-      // It's not explicitly defined with a concrete type here to maintain flexibility.
+      /// The request body, as sent to `url` outside a batch.
+      #[ serde( skip_serializing_if = "Option::is_none" ) ]
+      pub body : Option< Value >,
+  }
+
+  impl BatchRequestInput
+  {
+      /// One `POST /v1/chat/completions` line of a batch input file.
+      ///
+      /// # Errors
+      /// Returns `OpenAIError::InvalidArgument` if the request asks for a
+      /// stream, which batches do not support, or an error if it cannot be
+      /// serialized.
+      #[ inline ]
+      pub fn chat_completion( custom_id : impl Into< String >, request : &ChatCompletionRequest ) -> Result< Self >
+      {
+        if request.stream == Some( true )
+        {
+          return Err( OpenAIError::InvalidArgument( "Batch requests cannot be streamed".to_string() ).into() );
+        }
+        Ok( Self
+        {
+          custom_id : Some( custom_id.into() ),
+          method : Some( "POST".to_string() ),
+          url : Some( "/v1/chat/completions".to_string() ),
+          body : Some( serde_json::to_value( request )? ),
+        } )
+      }
+
+      /// Serializes chat completion requests into the JSONL batch input
+      /// format, one line per request, ready to upload with the `batch`
+      /// purpose.
+      ///
+      /// The request at index `i` gets the custom ID `request-{i}`; outputs
+      /// come back in any order, so match them on `custom_id`.
+      ///
+      /// # Errors
+      /// Returns an error if a request cannot be turned into a batch line,
+      /// see `BatchRequestInput::chat_completion`.
+      #[ inline ]
+      pub fn chat_completions_jsonl( requests : &[ ChatCompletionRequest ] ) -> Result< Vec< u8 > >
+      {
+        let mut jsonl = Vec::new();
+        for ( index, request ) in requests.iter().enumerate()
+        {
+          serde_json::to_writer( &mut jsonl, &Self::chat_completion( format!( "request-{index}" ), request )? )?;
+          jsonl.push( b'\n' );
+        }
+        Ok( jsonl )
+      }
   }
 
   /// Represents the response structure for a single request within a batch output file.
@@ -658,6 +776,23 @@ mod private
       pub error : Option< BatchRequestOutputError >,
   }
 
+  impl BatchRequestOutput
+  {
+      /// Reads the lines of a downloaded batch output or error file; blank
+      /// lines are skipped.
+      ///
+      /// # Errors
+      /// Returns an error if a line is not a batch output object.
+      #[ inline ]
+      pub fn parse_jsonl( content : &[ u8 ] ) -> Result< Vec< Self > >
+      {
+        content.split( | byte | *byte == b'\n' )
+          .filter( | line | !line.iter().all( u8::is_ascii_whitespace ) )
+          .map( | line | Ok( serde_json::from_slice( line )? ) )
+          .collect()
+      }
+  }
+
 } // end mod private
 
 crate ::mod_interface!
@@ -693,6 +828,8 @@ crate ::mod_interface!
     BatchRequestCounts,
     Batch,
     ListBatchesResponse,
+    CreateBatchRequest,
+    ListBatchesQuery,
     BatchRequestInput,
     BatchRequestOutputResponse,
     BatchRequestOutputError,
@@ -11,7 +11,11 @@ mod private
   use serde::{ Serialize, Deserialize };
   use serde_json::Value;
   use former::Former;
-  use crate::components::tools::FunctionTool;
+  use crate::components::tools::{ FunctionTool, ToolChoice };
+  // Tool calls are the wire types shared with the other OpenAI-compatible
+  // providers; arguments stay the JSON string the model produced.
+  pub use api_openai_compatible::{ ToolCall, FunctionCall };
+  use crate::components::common::{ CompletionUsage, ResponseFormatJsonSchema, ResponseFormatJsonSchemaSchemaDetails };
 
   /// Represents a message in a chat completion request.
   ///
@@ -30,12 +34,29 @@ mod private
     pub name : Option< String >,
     /// The tool calls generated by the model, if applicable.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tool_calls : Option< Vec< ChatCompletionMessageToolCall > >,
+    pub tool_calls : Option< Vec< ToolCall > >,
     /// Tool call ID that this message is responding to.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub tool_call_id : Option< String >,
   }
 
+  #[ cfg( feature = "common" ) ]
+  impl From< api_common::NeutralMessage > for ChatCompletionRequestMessage
+  {
+    #[ inline ]
+    fn from( message : api_common::NeutralMessage ) -> Self
+    {
+      Self
+      {
+        role : message.role.as_str().to_string(),
+        content : Some( ChatCompletionRequestMessageContent::Text( message.content ) ),
+        name : None,
+        tool_calls : None,
+        tool_call_id : None,
+      }
+    }
+  }
+
   /// Represents the content of a message in a chat completion request.
   /// Can be a simple string or a list of content parts (for multimodal input).
   ///
@@ -90,34 +111,6 @@ mod private
     pub detail : Option< String >,
   }
 
-  /// Represents a tool call generated by the model.
-  ///
-  /// # Used By
-  /// - `ChatCompletionRequestMessage`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
-  pub struct ChatCompletionMessageToolCall
-  {
-    /// The ID of the tool call.
-    pub id : String,
-    /// The type of the tool. Currently, only `function` is supported.
-    pub r#type : String,
-    /// The function that the model called.
-    pub function : ChatCompletionMessageToolCallFunction,
-  }
-
-  /// Represents a function call within a tool call.
-  ///
-  /// # Used By
-  /// - `ChatCompletionMessageToolCall`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
-  pub struct ChatCompletionMessageToolCallFunction
-  {
-    /// The name of the function to call.
-    pub name : String,
-    /// The arguments to call the function with, as a JSON string.
-    pub arguments : String,
-  }
-
   /// Represents a tool that can be used by the model.
   ///
   /// # Used By
@@ -132,44 +125,6 @@ mod private
     pub function : Option< FunctionTool >,
   }
 
-  /// Represents the choice of tool to use.
-  ///
-  /// # Used By
-  /// - `ChatCompletionRequest`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
-  #[ serde( untagged ) ]
-  pub enum ToolChoiceOption
-  {
-    /// A string representing the tool choice (e.g., "none", "auto").
-    String( String ),
-    /// A specific tool to force the model to use.
-    Object( ToolChoiceObject ),
-  }
-
-  /// Represents a specific tool to force the model to use.
-  ///
-  /// # Used By
-  /// - `ToolChoiceOption::Object`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
-  pub struct ToolChoiceObject
-  {
-    /// The type of the tool. Currently, only `function` is supported.
-    pub r#type : String,
-    /// The function to force the model to use.
-    pub function : ToolChoiceFunction,
-  }
-
-  /// Represents a function to force the model to use.
-  ///
-  /// # Used By
-  /// - `ToolChoiceObject`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
-  pub struct ToolChoiceFunction
-  {
-    /// The name of the function to force the model to use.
-    pub name : String,
-  }
-
   /// Represents a chat completion request.
   ///
   /// # Used By
@@ -190,6 +145,10 @@ mod private
     /// The maximum number of tokens to generate in the chat completion.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub max_tokens : Option< i32 >,
+    /// An upper bound on the generated tokens, reasoning tokens included;
+    /// reasoning models take this instead of `max_tokens`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_completion_tokens : Option< i32 >,
     /// Number of chat completion choices to generate for each input message.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub n : Option< i32 >,
@@ -199,6 +158,9 @@ mod private
     /// Whether to stream back partial progress.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub stream : Option< bool >,
+    /// Options for a streamed response, such as a final usage chunk.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream_options : Option< ChatCompletionStreamOptions >,
     /// The system prompt that helps guide the behavior of the model.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub system_prompt : Option< String >,
@@ -210,7 +172,7 @@ mod private
     pub tools : Option< Vec< ChatCompletionTool > >,
     /// Controls which (if any) tool the model calls.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tool_choice : Option< ToolChoiceOption >,
+    pub tool_choice : Option< ToolChoice >,
     /// An object specifying the format that the model must output.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub response_format : Option< ChatCompletionResponseFormat >,
@@ -228,6 +190,19 @@ mod private
     pub top_logprobs : Option< i32 >,
   }
 
+  /// Options for a streamed chat completion.
+  ///
+  /// # Used By
+  /// - `ChatCompletionRequest`
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default, Former ) ]
+  pub struct ChatCompletionStreamOptions
+  {
+    /// Whether a last chunk, with no choices, carries the usage of the
+    /// whole request.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub include_usage : Option< bool >,
+  }
+
   /// Represents the format that the model must output.
   ///
   /// # Used By
@@ -235,8 +210,20 @@ mod private
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
   pub struct ChatCompletionResponseFormat
   {
-    /// The type of response format. Currently, only `json_object` is supported.
+    /// The type of response format: `text`, `json_object`, or `json_schema`.
     pub r#type : String,
+    /// The schema the output must follow, when the type is `json_schema`.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub json_schema : Option< ResponseFormatJsonSchemaSchemaDetails >,
+  }
+
+  impl From< ResponseFormatJsonSchema > for ChatCompletionResponseFormat
+  {
+    #[ inline ]
+    fn from( format : ResponseFormatJsonSchema ) -> Self
+    {
+      Self { r#type : format.r#type, json_schema : Some( format.json_schema ) }
+    }
   }
 
   /// Represents a chat completion response.
@@ -293,27 +280,23 @@ mod private
     /// The contents of the message.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub content : Option< String >,
+    /// The refusal message generated by the model, in place of the content.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub refusal : Option< String >,
     /// The role of the author of this message.
     pub role : String,
     /// The tool calls generated by the model, if applicable.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tool_calls : Option< Vec< ChatCompletionMessageToolCall > >,
+    pub tool_calls : Option< Vec< ToolCall > >,
   }
 
-  /// Represents usage statistics for a chat completion request.
+  /// Usage statistics of a chat completion: the shared `CompletionUsage`,
+  /// with the prompt and completion token details that report prompt caching.
   ///
   /// # Used By
   /// - `CreateChatCompletionResponse`
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
-  pub struct ChatCompletionUsage
-  {
-    /// Number of tokens in the completion.
-    pub completion_tokens : i32,
-    /// Number of tokens in the prompt.
-    pub prompt_tokens : i32,
-    /// Total number of tokens used in the request (prompt + completion).
-    pub total_tokens : i32,
-  }
+  /// - `ChatCompletionStreamResponse`
+  pub type ChatCompletionUsage = CompletionUsage;
 
   /// Represents log probability information for a chat completion choice.
   ///
@@ -363,7 +346,8 @@ mod private
   {
     /// A unique identifier for the chat completion.
     pub id : String,
-    /// A list of chat completion choices.
+    /// A list of chat completion choices; empty in the usage chunk.
+    #[ serde( default ) ]
     pub choices : Vec< ChatCompletionStreamChoice >,
     /// The Unix timestamp (in seconds) of when the chat completion was created.
     #[ serde( rename = "created" ) ]
@@ -375,6 +359,10 @@ mod private
     /// This fingerprint represents the contents of the `input` field.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub system_fingerprint : Option< String >,
+    /// Usage of the whole request, only in the last chunk, which has no
+    /// choices, when `stream_options.include_usage` is set.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub usage : Option< ChatCompletionUsage >,
   }
 
   /// Represents a choice in a streaming chat completion response.
@@ -409,9 +397,197 @@ mod private
     /// The role of the author of this message.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub role : Option< String >,
-    /// The tool calls generated by the model, if applicable.
+    /// The refusal message generated by the model, in place of content.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub refusal : Option< String >,
+    /// The tool call fragments generated by the model, if applicable.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_calls : Option< Vec< ChatCompletionToolCallDelta > >,
+  }
+
+  /// Represents a fragment of a tool call in a streaming chat completion
+  /// response. The first fragment of a call carries its `id` and function
+  /// `name`; the `arguments` of all its fragments, joined, are the JSON
+  /// arguments.
+  ///
+  /// # Used By
+  /// - `ChatCompletionStreamResponseMessage`
+  /// - `ChatCompletionChunk::ToolCall`
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ChatCompletionToolCallDelta
+  {
+    /// The position of the tool call among the calls of the choice.
+    pub index : i32,
+    /// The ID of the tool call, in its first fragment.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub id : Option< String >,
+    /// The type of the tool, in its first fragment. Currently, only `function` is supported.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub r#type : Option< String >,
+    /// The fragment of the function call.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub function : Option< ChatCompletionToolCallFunctionDelta >,
+  }
+
+  /// Represents a fragment of a function call in a streaming chat completion
+  /// response.
+  ///
+  /// # Used By
+  /// - `ChatCompletionToolCallDelta`
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ChatCompletionToolCallFunctionDelta
+  {
+    /// The name of the function to call, in the first fragment.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tool_calls : Option< Vec< ChatCompletionMessageToolCall > >,
+    pub name : Option< String >,
+    /// The next piece of the JSON arguments.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub arguments : Option< String >,
+  }
+
+  /// One typed item of a streamed chat completion, as yielded by
+  /// `Chat::create_stream`.
+  ///
+  /// A streamed chunk may hold several items, e.g. the last piece of
+  /// content and the finish reason, or the items of several choices
+  /// (`n > 1`); each choice is identified by its `choice` index.
+  ///
+  /// # Used By
+  /// - `Chat::create_stream`
+  #[ derive( Debug, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub enum ChatCompletionChunk
+  {
+    /// Text to append to the content of a choice.
+    Content
+    {
+      /// The index of the choice.
+      choice : i32,
+      /// The text.
+      text : String,
+    },
+    /// Text to append to the refusal of a choice.
+    Refusal
+    {
+      /// The index of the choice.
+      choice : i32,
+      /// The text.
+      text : String,
+    },
+    /// A fragment of a tool call of a choice.
+    ToolCall
+    {
+      /// The index of the choice.
+      choice : i32,
+      /// The fragment; its `index` tells the calls of the choice apart.
+      call : ChatCompletionToolCallDelta,
+    },
+    /// The end of a choice.
+    Finish
+    {
+      /// The index of the choice.
+      choice : i32,
+      /// Why the model stopped: `stop`, `length`, `tool_calls`, `content_filter`.
+      reason : String,
+    },
+    /// The usage of the whole request, last, when
+    /// `stream_options.include_usage` is set.
+    Usage( ChatCompletionUsage ),
+  }
+
+  impl ChatCompletionStreamResponse
+  {
+    /// Splits the chunk into its typed items, in order: per choice its
+    /// content, refusal, tool call fragments, and finish, then the usage.
+    /// A chunk carrying only the role yields nothing.
+    #[ inline ]
+    #[ must_use ]
+    pub fn into_chunks( self ) -> Vec< ChatCompletionChunk >
+    {
+      let mut chunks = Vec::new();
+      for choice in self.choices
+      {
+        let index = choice.index;
+        if let Some( text ) = choice.delta.content.filter( | text | !text.is_empty() )
+        {
+          chunks.push( ChatCompletionChunk::Content { choice : index, text } );
+        }
+        if let Some( text ) = choice.delta.refusal.filter( | text | !text.is_empty() )
+        {
+          chunks.push( ChatCompletionChunk::Refusal { choice : index, text } );
+        }
+        for call in choice.delta.tool_calls.unwrap_or_default()
+        {
+          chunks.push( ChatCompletionChunk::ToolCall { choice : index, call } );
+        }
+        if let Some( reason ) = choice.finish_reason
+        {
+          chunks.push( ChatCompletionChunk::Finish { choice : index, reason } );
+        }
+      }
+      if let Some( usage ) = self.usage
+      {
+        chunks.push( ChatCompletionChunk::Usage( usage ) );
+      }
+      chunks
+    }
+  }
+
+  /// Translates the first choice of a chunk into provider-neutral events.
+  ///
+  /// Emits text, one tool-call fragment per entry in `tool_calls`, `Done`
+  /// when `finish_reason` is set, and the usage of the last chunk. Chunks for
+  /// other choices (`n > 1`) produce no events.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for ChatCompletionStreamResponse
+  {
+    #[ inline ]
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+    {
+      let mut events = Vec::new();
+      for chunk in self.into_chunks()
+      {
+        events.extend( chunk.into_stream_events()? );
+      }
+      Ok( events )
+    }
+  }
+
+  /// Translates an item of the first choice into a provider-neutral event;
+  /// a refusal is text, the usage an update. Items of other choices
+  /// (`n > 1`) produce no events.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for ChatCompletionChunk
+  {
+    #[ inline ]
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+    {
+      use api_common::{ StreamEvent, ToolCallDelta, UsageUpdate };
+
+      let event = match self
+      {
+        Self::Content { choice : 0, text } | Self::Refusal { choice : 0, text } => StreamEvent::TextDelta( text ),
+        Self::ToolCall { choice : 0, call } =>
+        {
+          let ( name, arguments ) = call.function.map_or( ( None, None ), | function | ( function.name, function.arguments ) );
+          StreamEvent::ToolCallDelta( ToolCallDelta
+          {
+            index : usize::try_from( call.index ).unwrap_or( 0 ),
+            id : call.id.filter( | id | !id.is_empty() ),
+            name : name.filter( | name | !name.is_empty() ),
+            arguments : arguments.unwrap_or_default(),
+          })
+        },
+        Self::Finish { choice : 0, reason } => StreamEvent::Done { finish_reason : Some( reason ) },
+        Self::Usage( usage ) => StreamEvent::UsageUpdate( UsageUpdate
+        {
+          input_tokens : u64::try_from( usage.prompt_tokens ).ok(),
+          output_tokens : u64::try_from( usage.completion_tokens ).ok(),
+        }),
+        _ => return Ok( Vec::new() ),
+      };
+      Ok( vec![ event ] )
+    }
   }
 }
 
@@ -423,12 +599,9 @@ crate ::mod_interface!
     ChatCompletionRequestMessageContent,
     ChatCompletionRequestMessageContentPart,
     ChatCompletionRequestMessageContentImageUrl,
-    ChatCompletionMessageToolCall,
-    ChatCompletionMessageToolCallFunction,
+    ToolCall,
+    FunctionCall,
     ChatCompletionTool,
-    ToolChoiceOption,
-    ToolChoiceObject,
-    ToolChoiceFunction,
     ChatCompletionRequest,
     ChatCompletionResponseFormat,
     CreateChatCompletionResponse,
@@ -441,5 +614,9 @@ crate ::mod_interface!
     ChatCompletionStreamResponse,
     ChatCompletionStreamChoice,
     ChatCompletionStreamResponseMessage,
+    ChatCompletionStreamOptions,
+    ChatCompletionToolCallDelta,
+    ChatCompletionToolCallFunctionDelta,
+    ChatCompletionChunk,
   };
 }
\ No newline at end of file
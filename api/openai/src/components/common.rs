@@ -113,6 +113,16 @@ mod private
     pub total_tokens : u32,
   }
 
+  #[ cfg( feature = "common" ) ]
+  impl From< ResponseUsage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : ResponseUsage ) -> Self
+    {
+      Self::new( usage.prompt_tokens.into(), usage.completion_tokens.unwrap_or( 0 ).into() )
+    }
+  }
+
   /// Configuration for the text response format.
   ///
   /// # Used By
@@ -454,6 +464,17 @@ mod private
     pub strict : Option< bool >,
   }
 
+  /// Checks structured output against the schema sent with the request.
+  #[ cfg( feature = "common" ) ]
+  impl From< &ResponseFormatJsonSchemaSchemaDetails > for api_common::ResponseSchema
+  {
+    #[ inline ]
+    fn from( details : &ResponseFormatJsonSchemaSchemaDetails ) -> Self
+    {
+      Self::new( details.schema.0.clone() )
+    }
+  }
+
   /// Represents the overall text response format configuration options.
   ///
   /// # Used By
@@ -744,6 +765,21 @@ mod private
     pub deleted : bool,
   }
 
+  /// Represents the structure for a deleted admin API key confirmation.
+  ///
+  /// # Used By
+  /// - `/organization/admin_api_keys/{key_id}` (DELETE)
+  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  pub struct AdminApiKeyDeleteResponse
+  {
+    /// The ID of the deleted key.
+    pub id : String,
+    /// The object type, always "`organization.admin_api_key.deleted`".
+    pub object : String,
+    /// Indicates if the deletion was successful.
+    pub deleted : bool,
+  }
+
   /// Represents the structure for a deleted user confirmation.
   ///
   /// # Used By
@@ -796,6 +832,56 @@ mod private
     pub prompt_tokens_details : Option< PromptTokensDetails >,
   }
 
+  impl CompletionUsage
+  {
+    /// The prompt tokens served from the prompt cache; 0 when not reported.
+    #[ inline ]
+    #[ must_use ]
+    pub fn cached_tokens( &self ) -> i32
+    {
+      self.prompt_tokens_details.as_ref().and_then( | details | details.cached_tokens ).unwrap_or( 0 )
+    }
+
+    /// The share of the prompt served from the prompt cache, in `[0, 1]`;
+    /// 0 for an empty prompt.
+    #[ inline ]
+    #[ must_use ]
+    pub fn cached_fraction( &self ) -> f64
+    {
+      if self.prompt_tokens <= 0
+      {
+        return 0.0;
+      }
+      ( f64::from( self.cached_tokens() ) / f64::from( self.prompt_tokens ) ).clamp( 0.0, 1.0 )
+    }
+
+    /// The prompt tokens not served from the prompt cache, billed at the
+    /// full input price.
+    #[ inline ]
+    #[ must_use ]
+    pub fn effective_prompt_tokens( &self ) -> i32
+    {
+      ( self.prompt_tokens - self.cached_tokens() ).max( 0 )
+    }
+  }
+
+  /// `OpenAI`'s prompt count already includes the cached tokens, and its
+  /// completion count the reasoning tokens.
+  #[ cfg( feature = "common" ) ]
+  impl From< CompletionUsage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : CompletionUsage ) -> Self
+    {
+      let count = | tokens : i32 | u64::try_from( tokens ).unwrap_or( 0 );
+      let cached = usage.prompt_tokens_details.and_then( | details | details.cached_tokens ).map_or( 0, count );
+      let reasoning = usage.completion_tokens_details.and_then( | details | details.reasoning_tokens ).map_or( 0, count );
+      Self::new( count( usage.prompt_tokens ), count( usage.completion_tokens ) )
+      .with_cached_input_tokens( cached )
+      .with_reasoning_tokens( reasoning )
+    }
+  }
+
   /// Detailed breakdown of completion tokens.
   ///
   /// # Used By
@@ -887,6 +973,7 @@ crate ::mod_interface!
     ProjectServiceAccountDeleteResponse,
     ProjectUserDeleteResponse,
     UserDeleteResponse,
+    AdminApiKeyDeleteResponse,
     DefaultProjectErrorResponse,
     CompletionUsage,
     CompletionTokensDetails,
@@ -6,7 +6,8 @@ mod private
   // Use full paths from crate root for components
   use crate::components::common::ResponseUsage;
   // Serde imports
-  use serde::{ Serialize, Deserialize }; // Added Serialize
+  use serde::{ Serialize, Deserialize, Deserializer }; // Added Serialize
+  use crate::embedding_utils::decode_base64_embedding;
 
   /// Represents an embedding vector returned by embedding endpoint.
   ///
@@ -17,12 +18,34 @@ mod private
   {
     /// The index of the embedding in the list of embeddings.
     pub index : i32,
-    /// The embedding vector, which is a list of floats. The length depends on the model.
-    pub embedding : Vec< f64 >,
+    /// The embedding vector. The length depends on the model and the
+    /// `dimensions` of the request; a `base64` encoded vector is decoded.
+    #[ serde( deserialize_with = "embedding_vector" ) ]
+    pub embedding : Vec< f32 >,
     /// The object type, which is always "embedding".
     pub object : String,
   }
 
+  /// An embedding vector as sent, for either `encoding_format`.
+  #[ derive( Deserialize ) ]
+  #[ serde( untagged ) ]
+  enum EmbeddingVector
+  {
+    Float( Vec< f32 > ),
+    Base64( String ),
+  }
+
+  fn embedding_vector< 'de, D >( deserializer : D ) -> core::result::Result< Vec< f32 >, D::Error >
+  where
+    D : Deserializer< 'de >,
+  {
+    match EmbeddingVector::deserialize( deserializer )?
+    {
+      EmbeddingVector::Float( vector ) => Ok( vector ),
+      EmbeddingVector::Base64( encoded ) => decode_base64_embedding( &encoded ).map_err( serde::de::Error::custom ),
+    }
+  }
+
   /// Response containing a list of embeddings.
   ///
   /// # Used By
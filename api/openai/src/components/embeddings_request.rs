@@ -15,6 +15,23 @@ mod private
     Single( String ),
     /// Multiple text inputs for batch processing
     Multiple( Vec< String > ),
+    /// A single input given as token ids
+    Tokens( Vec< u32 > ),
+    /// Multiple inputs given as token ids, for batch processing
+    TokenBatch( Vec< Vec< u32 > > ),
+  }
+
+  /// The format of the returned embeddings.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum EmbeddingEncodingFormat
+  {
+    /// A list of floats.
+    #[ default ]
+    Float,
+    /// The little-endian `f32` bytes, base64 encoded: about a quarter the
+    /// size on the wire. `Embedding::embedding` holds the decoded vector.
+    Base64,
   }
 
   /// Request for creating embeddings
@@ -32,9 +49,9 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub dimensions : Option< u32 >,
 
-    /// The format to return the embeddings in. Can be either `float` or `base64`.
+    /// The format to return the embeddings in: `float` or `base64`.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub encoding_format : Option< String >,
+    pub encoding_format : Option< EmbeddingEncodingFormat >,
 
     /// A unique identifier representing your end-user, which can help `OpenAI` to monitor and detect abuse.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
@@ -96,6 +113,7 @@ crate ::mod_interface!
   exposed use
   {
     EmbeddingInput,
+    EmbeddingEncodingFormat,
     CreateEmbeddingRequest,
   };
 }
\ No newline at end of file
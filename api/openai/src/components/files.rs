@@ -5,6 +5,51 @@ mod private
 {
   // Serde imports
   use serde::{ Serialize, Deserialize }; // Added Serialize
+  use former::Former;
+
+  /// The intended purpose of an uploaded file.
+  ///
+  /// # Used By
+  /// - `CreateFileRequest`
+  /// - `ListFilesQuery`
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  #[ non_exhaustive ]
+  pub enum FilePurpose
+  {
+    /// Files for Assistants and their messages.
+    Assistants,
+    /// Input files of the Batch API.
+    Batch,
+    /// Training and validation files for fine-tuning.
+    #[ serde( rename = "fine-tune" ) ]
+    FineTune,
+    /// Images for vision fine-tuning.
+    Vision,
+    /// Files for any other purpose.
+    UserData,
+    /// Data sets for evals.
+    Evals,
+  }
+
+  impl FilePurpose
+  {
+    /// The purpose as the API spells it.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Assistants => "assistants",
+        Self::Batch => "batch",
+        Self::FineTune => "fine-tune",
+        Self::Vision => "vision",
+        Self::UserData => "user_data",
+        Self::Evals => "evals",
+      }
+    }
+  }
 
   /// Represents a document that has been uploaded to `OpenAI`.
   /// Files are used across several endpoints like Assistants, Fine-tuning, and Batch API.
@@ -61,6 +106,41 @@ mod private
     pub has_more : bool,
   }
 
+  impl ListFilesResponse
+  {
+    /// The cursor of the next page: `last_id` when `has_more` is set.
+    ///
+    /// Pass it as `ListFilesQuery::after` to fetch the page after this one.
+    #[ inline ]
+    #[ must_use ]
+    pub fn next_after( &self ) -> Option< &str >
+    {
+      if self.has_more { self.last_id.as_deref() } else { None }
+    }
+  }
+
+  /// Query parameters for listing files.
+  ///
+  /// # Used By
+  /// - `/files` (GET)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ListFilesQuery
+  {
+    /// Only return files with this purpose.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub purpose : Option< FilePurpose >,
+    /// The maximum number of files to return (1-10000). Defaults to 10000.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub limit : Option< u32 >,
+    /// Sort order by `created_at`: `asc` or `desc`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub order : Option< String >,
+    /// Cursor: return the files after the file with this ID.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub after : Option< String >,
+  }
+
   /// Request parameters for uploading a file.
   ///
   /// # Used By
@@ -73,11 +153,18 @@ mod private
     /// The filename for the uploaded file.
     pub filename : String,
     /// The intended purpose of the file.
-    /// Use "assistants" for Assistants and Message files,
-    /// "vision" for Vision capabilities,
-    /// "batch" for Batch API,
-    /// or "fine-tune" for fine-tuning.
-    pub purpose : String,
+    pub purpose : FilePurpose,
+  }
+
+  impl CreateFileRequest
+  {
+    /// Uploads `file` under the name `filename` for `purpose`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( file : Vec< u8 >, filename : impl Into< String >, purpose : FilePurpose ) -> Self
+    {
+      Self { file, filename : filename.into(), purpose }
+    }
   }
 } // end mod private
 
@@ -86,7 +173,9 @@ crate ::mod_interface!
   exposed use
   {
     FileObject,
+    FilePurpose,
     ListFilesResponse,
+    ListFilesQuery,
     CreateFileRequest,
   };
 }
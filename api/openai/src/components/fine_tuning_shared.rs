@@ -12,6 +12,7 @@ mod private
   // Serde imports
   use serde::{ Serialize, Deserialize };
   use serde_json::Value;
+  use former::Former;
 
   /// Represents an error that occurred during a fine-tuning job.
   ///
@@ -69,7 +70,7 @@ mod private
   ///
   /// # Used By
   /// - `FineTuningJob`
-  /// - `CreateFineTuningJobRequest` (within `requests/fine_tuning.rs` - *assuming*)
+  /// - `CreateFineTuningJobRequest`
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ] // Added Serialize
   pub struct FineTuningIntegration
   {
@@ -122,7 +123,7 @@ mod private
   ///
   /// # Used By
   /// - `FineTuningJob`
-  /// - `CreateFineTuningJobRequest` (within `requests/fine_tuning.rs` - *assuming*)
+  /// - `CreateFineTuningJobRequest`
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ] // Added Serialize
   #[ serde( tag = "type" ) ]
   pub enum FineTuneMethod
@@ -410,6 +411,78 @@ mod private
     pub parallel_tool_calls : Option< ParallelToolCalls >,
   }
 
+  /// Request body for creating a fine-tuning job.
+  ///
+  /// # Used By
+  /// - `/fine_tuning/jobs` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateFineTuningJobRequest
+  {
+    /// The name of the model to fine-tune.
+    pub model : String,
+    /// The ID of an uploaded file with purpose `fine-tune` that contains the training data.
+    pub training_file : String,
+    /// The ID of an uploaded file with purpose `fine-tune` that contains the validation data.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub validation_file : Option< String >,
+    /// A string of up to 64 characters added to the fine-tuned model name.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub suffix : Option< String >,
+    /// The seed controlling the reproducibility of the job.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub seed : Option< i32 >,
+    /// The method used for fine-tuning, with its hyperparameters.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub method : Option< FineTuneMethod >,
+    /// A list of integrations to enable for the job.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub integrations : Option< Vec< FineTuningIntegration > >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  impl CreateFineTuningJobRequest
+  {
+    /// Fine-tunes `model` on the uploaded file `training_file` with the default method.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( model : impl Into< String >, training_file : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        model : model.into(),
+        training_file : training_file.into(),
+        validation_file : None,
+        suffix : None,
+        seed : None,
+        method : None,
+        integrations : None,
+        metadata : None,
+      }
+    }
+  }
+
+  /// Query parameters for the paginated fine-tuning lists: jobs, job events,
+  /// and job checkpoints.
+  ///
+  /// # Used By
+  /// - `/fine_tuning/jobs` (GET)
+  /// - `/fine_tuning/jobs/{fine_tuning_job_id}/events` (GET)
+  /// - `/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints` (GET)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct FineTuningListQuery
+  {
+    /// Cursor: return the items after the item with this ID.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub after : Option< String >,
+    /// The maximum number of items to return. Defaults to 20 (10 for checkpoints).
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub limit : Option< u32 >,
+  }
+
 } // end mod private
 
 crate ::mod_interface!
@@ -436,6 +509,8 @@ crate ::mod_interface!
     FineTuneChatRequestInput,
     FineTuneCompletionRequestInput,
     FineTunePreferenceRequestInput,
-    FineTunePreferenceInputData
+    FineTunePreferenceInputData,
+    CreateFineTuningJobRequest,
+    FineTuningListQuery
   };
 }
\ No newline at end of file
@@ -47,6 +47,7 @@
 //! - [`administration_shared`] - Admin operations
 //! - [`audit_logs_shared`] - Audit logging
 //! - [`usage_shared`] - Usage tracking
+//! - [`webhooks_shared`] - Webhook events
 //!
 //! ### Specialized Components
 //! - [`responses`] - Response handling
@@ -94,6 +95,7 @@ pub mod moderations;
 pub mod administration_shared;
 pub mod audit_logs_shared;
 pub mod usage_shared;
+pub mod webhooks_shared;
 
 // === SPECIALIZED COMPONENTS ===
 pub mod responses;
@@ -125,5 +127,6 @@ crate ::mod_interface!
   exposed use uploads;
   exposed use usage_shared;
   exposed use vector_stores_shared;
+  exposed use webhooks_shared;
   exposed use embeddings_request;
 }
\ No newline at end of file
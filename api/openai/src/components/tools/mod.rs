@@ -44,42 +44,9 @@ mod private
     WebSearch( WebSearchTool ),
   }
 
-  /// Represents the choice of which tool the model should use.
-  ///
-  /// # Used By
-  /// - `CreateChatCompletionRequest`
-  /// - `CreateResponseRequest`
-  /// - `ResponseProperties` (within `common.rs`)
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
-  #[ serde( untagged ) ]
-  #[ non_exhaustive ]
-  pub enum ToolChoice
-  {
-    /// Specifies a specific function to call.
-    Function
-    {
-      /// The function details.
-      function : ToolChoiceFunction,
-      /// The type, always "function".
-      r#type : String,
-    },
-    /// A string indicating the mode ("none", "auto", "required").
-    String( String ),
-  }
-
-  /// Specifies a function name for the `ToolChoice::Function` variant.
-  ///
-  /// # Used By
-  /// - `ToolChoice::Function`
-  /// - `AssistantsNamedToolChoice` (within `assistants_shared.rs`)
-  /// - `ChatCompletionNamedToolChoice` (within `chat_shared.rs`)
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
-  #[ non_exhaustive ]
-  pub struct ToolChoiceFunction
-  {
-    /// The name of the function to call.
-    pub name : String,
-  }
+  // Tool choice is the wire type shared with the other OpenAI-compatible
+  // providers: a mode string ("none", "auto", "required") or a named function.
+  pub use api_openai_compatible::{ ToolChoice, NamedToolChoice, ToolChoiceFunction };
 
   // ============================================================================
   // Computer Use tool structures
@@ -496,7 +463,7 @@ mod private
 crate ::mod_interface!
 {
   // Common tool structures
-  exposed use { Tool, ToolChoice, ToolChoiceFunction };
+  exposed use { Tool, ToolChoice, NamedToolChoice, ToolChoiceFunction };
 
   // Computer Use tool
   exposed use
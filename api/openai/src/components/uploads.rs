@@ -4,9 +4,10 @@
 mod private
 {
   // Use full paths from crate root for components
-  use crate::components::files::FileObject;
+  use crate::components::files::{ FileObject, FilePurpose };
   // Serde imports
   use serde::{ Serialize, Deserialize }; // Added Serialize
+  use former::Former;
 
   /// Represents an intermediate Upload object that you can add Parts to.
   /// Once completed, it results in a standard File object.
@@ -58,6 +59,67 @@ mod private
     /// The ID of the Upload object that this Part was added to.
     pub upload_id : String,
   }
+
+  /// Request body for creating an Upload, the object parts are added to.
+  ///
+  /// # Used By
+  /// - `/uploads` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateUploadRequest
+  {
+    /// The name of the file to upload.
+    pub filename : String,
+    /// The intended purpose of the uploaded file.
+    pub purpose : FilePurpose,
+    /// The number of bytes in the file; the parts must add up to it.
+    pub bytes : u64,
+    /// The MIME type of the file, e.g. "application/jsonl".
+    pub mime_type : String,
+  }
+
+  impl CreateUploadRequest
+  {
+    /// Announces a file `filename` of `bytes` bytes and type `mime_type`, for `purpose`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( filename : impl Into< String >, purpose : FilePurpose, bytes : u64, mime_type : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        filename : filename.into(),
+        purpose,
+        bytes,
+        mime_type : mime_type.into(),
+      }
+    }
+  }
+
+  /// Request body for completing an Upload into a File.
+  ///
+  /// # Used By
+  /// - `/uploads/{upload_id}/complete` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CompleteUploadRequest
+  {
+    /// The IDs of the parts, in the order their bytes make up the file.
+    pub part_ids : Vec< String >,
+    /// The MD5 checksum of the file, checked against the uploaded bytes.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub md5 : Option< String >,
+  }
+
+  impl CompleteUploadRequest
+  {
+    /// Assembles the parts `part_ids`, in this order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( part_ids : Vec< String > ) -> Self
+    {
+      Self { part_ids, md5 : None }
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -65,6 +127,8 @@ crate ::mod_interface!
   exposed use
   {
     Upload,
-    UploadPart
+    UploadPart,
+    CreateUploadRequest,
+    CompleteUploadRequest,
   };
 }
\ No newline at end of file
@@ -4,7 +4,9 @@
 mod private
 {
   // Serde imports
-  use serde::Deserialize;
+  use serde::{ Deserialize, Deserializer };
+  use serde_json::Value;
+  use former::Former;
 
   /// Represents the monetary value and currency for a cost item.
   ///
@@ -49,11 +51,11 @@ mod private
     /// The API key ID if grouped by `api_key_id`.
     pub api_key_id : Option< String >,
     /// The number of characters processed.
-    pub characters : i32,
+    pub characters : i64,
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "`organization.usage.audio_speeches.result`".
     pub object : String,
     /// The project ID if grouped by `project_id`.
@@ -75,13 +77,13 @@ mod private
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "`organization.usage.audio_transcriptions.result`".
     pub object : String,
     /// The project ID if grouped by `project_id`.
     pub project_id : Option< String >,
     /// The number of seconds processed.
-    pub seconds : i32,
+    pub seconds : i64,
     /// The user ID if grouped by `user_id`.
     pub user_id : Option< String >,
   }
@@ -95,7 +97,7 @@ mod private
   pub struct UsageCodeInterpreterSessionsResult
   {
     /// The number of code interpreter sessions used.
-    pub num_sessions : i32, // Corrected field name from 'sessions' to 'num_sessions' based on example
+    pub num_sessions : i64,
     /// Object type, always "`organization.usage.code_interpreter_sessions.result`".
     pub object : String,
     /// The project ID if grouped by `project_id`.
@@ -115,23 +117,23 @@ mod private
     /// Whether the usage result is for batch jobs if grouped by `batch`.
     pub batch : Option< bool >,
     /// Aggregated number of audio input tokens used (including cached).
-    pub input_audio_tokens : Option< i32 >,
+    pub input_audio_tokens : Option< i64 >,
     /// Aggregated number of text input tokens used (including cached).
-    pub input_tokens : i32,
+    pub input_tokens : i64,
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "organization.usage.completions.result".
     pub object : String,
     /// Aggregated number of audio output tokens used.
-    pub output_audio_tokens : Option< i32 >,
+    pub output_audio_tokens : Option< i64 >,
     /// Aggregated number of text output tokens used.
-    pub output_tokens : i32,
+    pub output_tokens : i64,
     /// The project ID if grouped by `project_id`.
     pub project_id : Option< String >,
     /// Aggregated number of cached text input tokens.
-    pub input_cached_tokens : Option< i32 >,
+    pub input_cached_tokens : Option< i64 >,
     /// The user ID if grouped by `user_id`.
     pub user_id : Option< String >,
   }
@@ -147,11 +149,11 @@ mod private
     /// The API key ID if grouped by `api_key_id`.
     pub api_key_id : Option< String >,
     /// The aggregated number of input tokens used.
-    pub input_tokens : i32,
+    pub input_tokens : i64,
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "organization.usage.embeddings.result".
     pub object : String,
     /// The project ID if grouped by `project_id`.
@@ -171,11 +173,11 @@ mod private
     /// The API key ID if grouped by `api_key_id`.
     pub api_key_id : Option< String >,
     /// The number of images processed.
-    pub images : i32,
+    pub images : i64,
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "organization.usage.images.result".
     pub object : String,
     /// The project ID if grouped by `project_id`.
@@ -199,11 +201,11 @@ mod private
     /// The API key ID if grouped by `api_key_id`.
     pub api_key_id : Option< String >,
     /// The aggregated number of input tokens used.
-    pub input_tokens : i32,
+    pub input_tokens : i64,
     /// The model name if grouped by `model`.
     pub model : Option< String >,
     /// The count of requests made to the model.
-    pub num_model_requests : i32,
+    pub num_model_requests : i64,
     /// Object type, always "organization.usage.moderations.result".
     pub object : String,
     /// The project ID if grouped by `project_id`.
@@ -230,10 +232,13 @@ mod private
 
   /// Represents the aggregated usage or cost result within a time bucket, varying by endpoint.
   ///
+  /// The variant is chosen by the `object` of the result, since several
+  /// results share the same fields; a result of an unknown `object` is kept
+  /// as `Other`.
+  ///
   /// # Used By
   /// - `UsageTimeBucket`
-  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
-  #[ serde( untagged ) ]
+  #[ derive( Debug, Clone, PartialEq ) ]
   #[ non_exhaustive ]
   pub enum UsageResult
   {
@@ -255,6 +260,33 @@ mod private
     Moderations( UsageModerationsResult ),
     /// Vector Stores usage details.
     VectorStores( UsageVectorStoresResult ),
+    /// A result of an `object` this crate does not know yet.
+    Other( Value ),
+  }
+
+  impl< 'de > Deserialize< 'de > for UsageResult
+  {
+    #[ inline ]
+    fn deserialize< D >( deserializer : D ) -> core::result::Result< Self, D::Error >
+    where
+      D : Deserializer< 'de >,
+    {
+      let value = Value::deserialize( deserializer )?;
+      let result = match value.get( "object" ).and_then( Value::as_str ).unwrap_or_default()
+      {
+        "organization.usage.audio_speeches.result" => serde_json::from_value( value ).map( Self::AudioSpeeches ),
+        "organization.usage.audio_transcriptions.result" => serde_json::from_value( value ).map( Self::AudioTranscriptions ),
+        "organization.usage.code_interpreter_sessions.result" => serde_json::from_value( value ).map( Self::CodeInterpreter ),
+        "organization.usage.completions.result" => serde_json::from_value( value ).map( Self::Completions ),
+        "organization.costs.result" => serde_json::from_value( value ).map( Self::Costs ),
+        "organization.usage.embeddings.result" => serde_json::from_value( value ).map( Self::Embeddings ),
+        "organization.usage.images.result" => serde_json::from_value( value ).map( Self::Images ),
+        "organization.usage.moderations.result" => serde_json::from_value( value ).map( Self::Moderations ),
+        "organization.usage.vector_stores.result" => serde_json::from_value( value ).map( Self::VectorStores ),
+        _ => Ok( Self::Other( value ) ),
+      };
+      result.map_err( serde::de::Error::custom )
+    }
   }
 
   /// Represents a time bucket containing aggregated usage or cost results.
@@ -265,18 +297,14 @@ mod private
   #[ non_exhaustive ]
   pub struct UsageTimeBucket
   {
-    /// List of time buckets containing usage/cost data.
-    pub data : Vec< UsageResult >,
-    /// End timestamp of the bucket (Unix seconds).
-    pub end_time : i64,
-    /// Indicates if more data is available for pagination.
-    pub has_more : bool,
-    /// Pagination cursor for the next page.
-    pub next_page : Option< String >,
     /// Object type, always "bucket".
     pub object : String,
     /// Start timestamp of the bucket (Unix seconds).
     pub start_time : i64,
+    /// End timestamp of the bucket (Unix seconds).
+    pub end_time : i64,
+    /// The results of the bucket, one per `group_by` combination.
+    pub results : Vec< UsageResult >,
   }
 
   /// Represents the paginated response from a Usage or Costs API endpoint.
@@ -292,11 +320,304 @@ mod private
     pub data : Vec< UsageTimeBucket >,
     /// Indicates if more data is available for pagination.
     pub has_more : bool,
-    /// Pagination cursor for the next page.
+    /// Pagination cursor for the next page, to pass as `page`.
     pub next_page : Option< String >,
     /// Object type, always "page".
     pub object : String,
   }
+
+  /// Width of the time buckets of a usage or costs report.
+  ///
+  /// # Used By
+  /// - `UsageQuery`
+  /// - `CostsQuery`
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  #[ non_exhaustive ]
+  pub enum UsageBucketWidth
+  {
+    /// One-minute buckets, at most 1440 per page.
+    OneMinute,
+    /// One-hour buckets, at most 168 per page.
+    OneHour,
+    /// One-day buckets, at most 31 per page; the only width of costs.
+    OneDay,
+  }
+
+  impl UsageBucketWidth
+  {
+    /// The value sent as `bucket_width`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::OneMinute => "1m",
+        Self::OneHour => "1h",
+        Self::OneDay => "1d",
+      }
+    }
+  }
+
+  /// A field the results of a usage or costs report are grouped by.
+  ///
+  /// `Batch` is only for completions, `Size` and `Source` only for images,
+  /// `LineItem` only for costs; costs are grouped by `ProjectId` and
+  /// `LineItem` only.
+  ///
+  /// # Used By
+  /// - `UsageQuery`
+  /// - `CostsQuery`
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  #[ non_exhaustive ]
+  pub enum UsageGroupBy
+  {
+    /// Group by project.
+    ProjectId,
+    /// Group by user.
+    UserId,
+    /// Group by API key.
+    ApiKeyId,
+    /// Group by model.
+    Model,
+    /// Group by whether the requests came from batch jobs.
+    Batch,
+    /// Group by image size.
+    Size,
+    /// Group by image source, e.g. generation or edit.
+    Source,
+    /// Group by invoice line item.
+    LineItem,
+  }
+
+  impl UsageGroupBy
+  {
+    /// The value sent in `group_by`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::ProjectId => "project_id",
+        Self::UserId => "user_id",
+        Self::ApiKeyId => "api_key_id",
+        Self::Model => "model",
+        Self::Batch => "batch",
+        Self::Size => "size",
+        Self::Source => "source",
+        Self::LineItem => "line_item",
+      }
+    }
+  }
+
+  /// Query parameters of a usage report: its time range and bucket width,
+  /// its filters, and its grouping.
+  ///
+  /// `batch` is only accepted by the completions report, `sources` and
+  /// `sizes` only by the images report.
+  ///
+  /// # Used By
+  /// - `/organization/usage/*` (GET endpoints)
+  #[ derive( Debug, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct UsageQuery
+  {
+    /// Start of the range, inclusive (Unix seconds).
+    pub start_time : i64,
+    /// End of the range, exclusive (Unix seconds).
+    pub end_time : Option< i64 >,
+    /// Width of the buckets. Defaults to one day.
+    pub bucket_width : Option< UsageBucketWidth >,
+    /// Only usage of these projects.
+    pub project_ids : Option< Vec< String > >,
+    /// Only usage of these users.
+    pub user_ids : Option< Vec< String > >,
+    /// Only usage of these API keys.
+    pub api_key_ids : Option< Vec< String > >,
+    /// Only usage of these models.
+    pub models : Option< Vec< String > >,
+    /// Only batch (`true`) or non-batch (`false`) usage; completions only.
+    pub batch : Option< bool >,
+    /// Only usage from these image sources; images only.
+    pub sources : Option< Vec< String > >,
+    /// Only usage of these image sizes; images only.
+    pub sizes : Option< Vec< String > >,
+    /// Fields to group the results of each bucket by.
+    pub group_by : Option< Vec< UsageGroupBy > >,
+    /// The number of buckets to return.
+    pub limit : Option< u32 >,
+    /// Cursor from `next_page` of the previous page.
+    pub page : Option< String >,
+  }
+
+  impl UsageQuery
+  {
+    /// Creates a query for the usage from `start_time` (Unix seconds).
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( start_time : i64 ) -> Self
+    {
+      Self
+      {
+        start_time,
+        end_time : None,
+        bucket_width : None,
+        project_ids : None,
+        user_ids : None,
+        api_key_ids : None,
+        models : None,
+        batch : None,
+        sources : None,
+        sizes : None,
+        group_by : None,
+        limit : None,
+        page : None,
+      }
+    }
+
+    /// The query string pairs, lists sent as repeated `name[]` pairs.
+    pub( crate ) fn query_pairs( &self ) -> Vec< ( &'static str, String ) >
+    {
+      let mut pairs = range_pairs( self.start_time, self.end_time, self.bucket_width );
+      push_all( &mut pairs, "project_ids[]", self.project_ids.as_deref() );
+      push_all( &mut pairs, "user_ids[]", self.user_ids.as_deref() );
+      push_all( &mut pairs, "api_key_ids[]", self.api_key_ids.as_deref() );
+      push_all( &mut pairs, "models[]", self.models.as_deref() );
+      if let Some( batch ) = self.batch
+      {
+        pairs.push( ( "batch", batch.to_string() ) );
+      }
+      push_all( &mut pairs, "sources[]", self.sources.as_deref() );
+      push_all( &mut pairs, "sizes[]", self.sizes.as_deref() );
+      page_pairs( &mut pairs, self.group_by.as_deref(), self.limit, self.page.as_deref() );
+      pairs
+    }
+  }
+
+  /// Query parameters of a costs report.
+  ///
+  /// # Used By
+  /// - `/organization/costs` (GET)
+  #[ derive( Debug, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CostsQuery
+  {
+    /// Start of the range, inclusive (Unix seconds).
+    pub start_time : i64,
+    /// End of the range, exclusive (Unix seconds).
+    pub end_time : Option< i64 >,
+    /// Width of the buckets; only one day is supported.
+    pub bucket_width : Option< UsageBucketWidth >,
+    /// Only costs of these projects.
+    pub project_ids : Option< Vec< String > >,
+    /// Fields to group the results of each bucket by: `ProjectId`, `LineItem`.
+    pub group_by : Option< Vec< UsageGroupBy > >,
+    /// The number of buckets to return.
+    pub limit : Option< u32 >,
+    /// Cursor from `next_page` of the previous page.
+    pub page : Option< String >,
+  }
+
+  impl CostsQuery
+  {
+    /// Creates a query for the costs from `start_time` (Unix seconds).
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( start_time : i64 ) -> Self
+    {
+      Self { start_time, end_time : None, bucket_width : None, project_ids : None, group_by : None, limit : None, page : None }
+    }
+
+    /// The query string pairs, lists sent as repeated `name[]` pairs.
+    pub( crate ) fn query_pairs( &self ) -> Vec< ( &'static str, String ) >
+    {
+      let mut pairs = range_pairs( self.start_time, self.end_time, self.bucket_width );
+      push_all( &mut pairs, "project_ids[]", self.project_ids.as_deref() );
+      page_pairs( &mut pairs, self.group_by.as_deref(), self.limit, self.page.as_deref() );
+      pairs
+    }
+  }
+
+  fn range_pairs( start_time : i64, end_time : Option< i64 >, bucket_width : Option< UsageBucketWidth > ) -> Vec< ( &'static str, String ) >
+  {
+    let mut pairs = vec![ ( "start_time", start_time.to_string() ) ];
+    if let Some( end_time ) = end_time
+    {
+      pairs.push( ( "end_time", end_time.to_string() ) );
+    }
+    if let Some( width ) = bucket_width
+    {
+      pairs.push( ( "bucket_width", width.as_str().to_string() ) );
+    }
+    pairs
+  }
+
+  fn push_all( pairs : &mut Vec< ( &'static str, String ) >, name : &'static str, values : Option< &[ String ] > )
+  {
+    pairs.extend( values.unwrap_or_default().iter().map( | value | ( name, value.clone() ) ) );
+  }
+
+  fn page_pairs( pairs : &mut Vec< ( &'static str, String ) >, group_by : Option< &[ UsageGroupBy ] >, limit : Option< u32 >, page : Option< &str > )
+  {
+    pairs.extend( group_by.unwrap_or_default().iter().map( | field | ( "group_by[]", field.as_str().to_string() ) ) );
+    if let Some( limit ) = limit
+    {
+      pairs.push( ( "limit", limit.to_string() ) );
+    }
+    if let Some( page ) = page
+    {
+      pairs.push( ( "page", page.to_string() ) );
+    }
+  }
+
+  // Tests
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    fn pairs< 'a >( pairs : &'a [ ( &'static str, String ) ] ) -> Vec< ( &'static str, &'a str ) >
+    {
+      pairs.iter().map( | ( name, value ) | ( *name, value.as_str() ) ).collect()
+    }
+
+    #[ test ]
+    fn usage_query_sends_lists_as_repeated_pairs()
+    {
+      let mut query = UsageQuery::new( 1_730_419_200 );
+      query.end_time = Some( 1_730_505_600 );
+      query.bucket_width = Some( UsageBucketWidth::OneHour );
+      query.project_ids = Some( vec![ "proj_abc".to_string(), "proj_def".to_string() ] );
+      query.group_by = Some( vec![ UsageGroupBy::ProjectId, UsageGroupBy::Model ] );
+      query.page = Some( "page_prev".to_string() );
+
+      assert_eq!( pairs( &query.query_pairs() ), vec!
+      [
+        ( "start_time", "1730419200" ),
+        ( "end_time", "1730505600" ),
+        ( "bucket_width", "1h" ),
+        ( "project_ids[]", "proj_abc" ),
+        ( "project_ids[]", "proj_def" ),
+        ( "group_by[]", "project_id" ),
+        ( "group_by[]", "model" ),
+        ( "page", "page_prev" ),
+      ] );
+      assert_eq!( pairs( &UsageQuery::new( 1_730_419_200 ).query_pairs() ), vec![ ( "start_time", "1730419200" ) ] );
+    }
+
+    #[ test ]
+    fn costs_query_groups_by_line_item()
+    {
+      let mut query = CostsQuery::new( 1_730_419_200 );
+      query.bucket_width = Some( UsageBucketWidth::OneDay );
+      query.group_by = Some( vec![ UsageGroupBy::LineItem ] );
+      query.limit = Some( 7 );
+
+      assert_eq!( pairs( &query.query_pairs() ), vec![ ( "start_time", "1730419200" ), ( "bucket_width", "1d" ), ( "group_by[]", "line_item" ), ( "limit", "7" ) ] );
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -315,6 +636,10 @@ crate ::mod_interface!
     UsageVectorStoresResult,
     UsageResult,
     UsageTimeBucket,
-    UsageResponse
+    UsageResponse,
+    UsageBucketWidth,
+    UsageGroupBy,
+    UsageQuery,
+    CostsQuery,
   };
 }
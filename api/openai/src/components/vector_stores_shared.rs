@@ -5,10 +5,12 @@ mod private
 {
   use serde::{ Deserialize, Serialize };
   use serde_json::Value;
+  use former::Former;
   use crate::components::common::{ Metadata, ResponseError, VectorStoreFileAttributes };
+  use crate::components::tools::FileSearchRankingOptions;
 
   /// Represents the expiration policy for a vector store.
-  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   #[ non_exhaustive ]
   pub struct VectorStoreExpirationAfter
   {
@@ -18,6 +20,17 @@ mod private
     pub days : i32,
   }
 
+  impl VectorStoreExpirationAfter
+  {
+    /// Expires the vector store `days` after it was last active.
+    #[ inline ]
+    #[ must_use ]
+    pub fn days_after_last_active( days : i32 ) -> Self
+    {
+      Self { anchor : "last_active_at".to_string(), days }
+    }
+  }
+
   /// Represents the counts of files in different statuses within a vector store or batch.
   #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
   #[ non_exhaustive ]
@@ -80,12 +93,12 @@ mod private
   {
     /// A list of vector store objects.
     pub data : Vec< VectorStoreObject >,
-    /// The ID of the first vector store in the list.
-    pub first_id : String,
+    /// The ID of the first vector store in the list; `None` when the list is empty.
+    pub first_id : Option< String >,
     /// Indicates whether there are more vector stores available.
     pub has_more : bool,
-    /// The ID of the last vector store in the list.
-    pub last_id : String,
+    /// The ID of the last vector store in the list; `None` when the list is empty.
+    pub last_id : Option< String >,
     /// The object type, always "list".
     pub object : String,
   }
@@ -187,12 +200,12 @@ mod private
   {
     /// A list of vector store file objects.
     pub data : Vec< VectorStoreFileObject >,
-    /// The ID of the first file in the list.
-    pub first_id : String,
+    /// The ID of the first file in the list; `None` when the list is empty.
+    pub first_id : Option< String >,
     /// Indicates whether there are more files available.
     pub has_more : bool,
-    /// The ID of the last file in the list.
-    pub last_id : String,
+    /// The ID of the last file in the list; `None` when the list is empty.
+    pub last_id : Option< String >,
     /// The object type, always "list".
     pub object : String,
   }
@@ -250,9 +263,9 @@ mod private
   /// Represents the chunking strategy parameter for requests (auto or static).
   ///
   /// # Used By
-  /// - `CreateVectorStoreRequest` (within `requests/vector_stores.rs` - *assuming*)
-  /// - `CreateVectorStoreFileRequest` (within `requests/vector_stores.rs` - *assuming*)
-  /// - `CreateVectorStoreFileBatchRequest` (within `requests/vector_stores.rs` - *assuming*)
+  /// - `CreateVectorStoreRequest`
+  /// - `CreateVectorStoreFileRequest`
+  /// - `CreateVectorStoreFileBatchRequest`
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   #[ serde( untagged ) ]
   #[ non_exhaustive ]
@@ -264,6 +277,30 @@ mod private
     Static( StaticChunkingStrategyRequestParam ),
   }
 
+  impl ChunkingStrategyRequestParam
+  {
+    /// The default strategy: chunks of 800 tokens overlapping by 400.
+    #[ inline ]
+    #[ must_use ]
+    pub fn auto() -> Self
+    {
+      Self::Auto( AutoChunkingStrategyRequestParam { r#type : "auto".to_string() } )
+    }
+
+    /// Chunks of at most `max_chunk_size_tokens` tokens, each overlapping
+    /// the previous one by `chunk_overlap_tokens`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn fixed_size( max_chunk_size_tokens : i32, chunk_overlap_tokens : i32 ) -> Self
+    {
+      Self::Static( StaticChunkingStrategyRequestParam
+      {
+        r#static : StaticChunkingStrategy { chunk_overlap_tokens, max_chunk_size_tokens },
+        r#type : "static".to_string(),
+      } )
+    }
+  }
+
   /// Represents the content of a search result chunk.
   ///
   /// # Used By
@@ -335,6 +372,17 @@ mod private
     pub value : Value,
   }
 
+  impl ComparisonFilter
+  {
+    /// Compares the attribute `key` to `value` with the operator `r#type`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( key : impl Into< String >, r#type : impl Into< String >, value : impl Into< Value > ) -> Self
+    {
+      Self { key : key.into(), r#type : r#type.into(), value : value.into() }
+    }
+  }
+
   /// Represents a filter combining multiple filters using `and` or `or`.
   ///
   /// # Used By
@@ -349,10 +397,21 @@ mod private
     pub r#type : String,
   }
 
+  impl CompoundFilter
+  {
+    /// Combines `filters` with the operation `r#type`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( r#type : impl Into< String >, filters : Vec< Filter > ) -> Self
+    {
+      Self { filters, r#type : r#type.into() }
+    }
+  }
+
   /// Represents a filter used in vector store search requests.
   ///
   /// # Used By
-  /// - `VectorStoreSearchRequest` (within `requests/vector_stores.rs` - *assuming*)
+  /// - `VectorStoreSearchRequest`
   /// - `CompoundFilter`
   #[ non_exhaustive ]
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
@@ -382,6 +441,208 @@ mod private
     /// The object type, always `vector_store.file_content.page`.
     pub object : String,
   }
+
+  /// Request body for creating a vector store.
+  ///
+  /// # Used By
+  /// - `/vector_stores` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateVectorStoreRequest
+  {
+    /// The name of the vector store.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// IDs of files the vector store should use.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub file_ids : Option< Vec< String > >,
+    /// The expiration policy for the vector store.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub expires_after : Option< VectorStoreExpirationAfter >,
+    /// The strategy used to chunk the files. Only applies when `file_ids` is set.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub chunking_strategy : Option< ChunkingStrategyRequestParam >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  /// Request body for modifying a vector store; only the fields set are changed.
+  ///
+  /// # Used By
+  /// - `/vector_stores/{vector_store_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Default, Former ) ]
+  #[ non_exhaustive ]
+  pub struct ModifyVectorStoreRequest
+  {
+    /// The name of the vector store.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// The expiration policy for the vector store.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub expires_after : Option< VectorStoreExpirationAfter >,
+    /// Set of 16 key-value pairs attached to the object.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub metadata : Option< Metadata >,
+  }
+
+  /// Request body for attaching a file to a vector store.
+  ///
+  /// # Used By
+  /// - `/vector_stores/{vector_store_id}/files` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateVectorStoreFileRequest
+  {
+    /// The ID of a file uploaded through the Files API.
+    pub file_id : String,
+    /// Attributes stored with the file, usable in search filters.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub attributes : Option< VectorStoreFileAttributes >,
+    /// The strategy used to chunk the file.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub chunking_strategy : Option< ChunkingStrategyRequestParam >,
+  }
+
+  impl CreateVectorStoreFileRequest
+  {
+    /// Attaches the file `file_id` with the default chunking and no attributes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( file_id : impl Into< String > ) -> Self
+    {
+      Self { file_id : file_id.into(), attributes : None, chunking_strategy : None }
+    }
+  }
+
+  /// Request body for replacing the attributes of a vector store file.
+  ///
+  /// # Used By
+  /// - `/vector_stores/{vector_store_id}/files/{file_id}` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub struct UpdateVectorStoreFileAttributesRequest
+  {
+    /// The new attributes of the file; they replace the old ones.
+    pub attributes : VectorStoreFileAttributes,
+  }
+
+  impl UpdateVectorStoreFileAttributesRequest
+  {
+    /// Replaces the attributes of the file with `attributes`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( attributes : VectorStoreFileAttributes ) -> Self
+    {
+      Self { attributes }
+    }
+  }
+
+  /// Request body for attaching several files to a vector store in one batch.
+  ///
+  /// # Used By
+  /// - `/vector_stores/{vector_store_id}/file_batches` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct CreateVectorStoreFileBatchRequest
+  {
+    /// IDs of files uploaded through the Files API.
+    pub file_ids : Vec< String >,
+    /// Attributes stored with every file of the batch.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub attributes : Option< VectorStoreFileAttributes >,
+    /// The strategy used to chunk the files.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub chunking_strategy : Option< ChunkingStrategyRequestParam >,
+  }
+
+  impl CreateVectorStoreFileBatchRequest
+  {
+    /// Attaches the files `file_ids` with the default chunking and no attributes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( file_ids : Vec< String > ) -> Self
+    {
+      Self { file_ids, attributes : None, chunking_strategy : None }
+    }
+  }
+
+  /// The query of a vector store search: one text or several.
+  ///
+  /// # Used By
+  /// - `VectorStoreSearchRequest`
+  #[ derive( Debug, Serialize, Clone, PartialEq ) ]
+  #[ serde( untagged ) ]
+  #[ non_exhaustive ]
+  pub enum VectorStoreSearchQuery
+  {
+    /// A single query text.
+    Text( String ),
+    /// Several query texts.
+    Texts( Vec< String > ),
+  }
+
+  impl From< String > for VectorStoreSearchQuery
+  {
+    #[ inline ]
+    fn from( value : String ) -> Self
+    {
+      Self::Text( value )
+    }
+  }
+
+  impl From< &str > for VectorStoreSearchQuery
+  {
+    #[ inline ]
+    fn from( value : &str ) -> Self
+    {
+      Self::Text( value.to_string() )
+    }
+  }
+
+  impl From< Vec< String > > for VectorStoreSearchQuery
+  {
+    #[ inline ]
+    fn from( value : Vec< String > ) -> Self
+    {
+      Self::Texts( value )
+    }
+  }
+
+  /// Request body for searching a vector store.
+  ///
+  /// # Used By
+  /// - `/vector_stores/{vector_store_id}/search` (POST)
+  #[ derive( Debug, Serialize, Clone, PartialEq, Former ) ]
+  #[ non_exhaustive ]
+  pub struct VectorStoreSearchRequest
+  {
+    /// The query to search for.
+    pub query : VectorStoreSearchQuery,
+    /// A filter on the attributes of the files.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub filters : Option< Filter >,
+    /// The maximum number of results to return (1-50). Defaults to 10.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub max_num_results : Option< i32 >,
+    /// Ranking options for the search.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub ranking_options : Option< FileSearchRankingOptions >,
+    /// Whether to rewrite the natural language query for vector search.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub rewrite_query : Option< bool >,
+  }
+
+  impl VectorStoreSearchRequest
+  {
+    /// Searches for `query` with the default filters and ranking.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( query : impl Into< VectorStoreSearchQuery > ) -> Self
+    {
+      Self { query : query.into(), filters : None, max_num_results : None, ranking_options : None, rewrite_query : None }
+    }
+  }
 }
 
 crate ::mod_interface!
@@ -393,19 +654,26 @@ crate ::mod_interface!
     ChunkingStrategyRequestParam,
     ComparisonFilter,
     CompoundFilter,
+    CreateVectorStoreFileBatchRequest,
+    CreateVectorStoreFileRequest,
+    CreateVectorStoreRequest,
     Filter,
     ListVectorStoreFilesResponse,
     ListVectorStoresResponse,
+    ModifyVectorStoreRequest,
     OtherChunkingStrategyResponseParam,
     StaticChunkingStrategy,
     StaticChunkingStrategyRequestParam,
     StaticChunkingStrategyResponseParam,
+    UpdateVectorStoreFileAttributesRequest,
     VectorStoreExpirationAfter,
     VectorStoreFileBatchObject,
     VectorStoreFileContentResponse,
     VectorStoreFileCounts,
     VectorStoreFileObject,
     VectorStoreObject,
+    VectorStoreSearchQuery,
+    VectorStoreSearchRequest,
     VectorStoreSearchResultContentObject,
     VectorStoreSearchResultItem,
     VectorStoreSearchResultsPage
@@ -0,0 +1,166 @@
+//! Structures related to the events `OpenAI` delivers to webhook endpoints.
+
+/// Define a private namespace for all its items.
+mod private
+{
+  // Serde imports
+  use serde::{ Deserialize, Deserializer };
+  use serde_json::Value;
+
+  /// An event delivered to a webhook endpoint.
+  ///
+  /// Verify the delivery with `WebhookVerifier::verify_event` before trusting
+  /// its content.
+  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub struct WebhookEvent
+  {
+    /// The identifier of the event, the same on every delivery of it.
+    pub id : String,
+    /// Object type, always "event".
+    pub object : String,
+    /// The Unix timestamp (in seconds) of when the event was created.
+    pub created_at : i64,
+    /// What happened, and the object it happened to.
+    #[ serde( flatten ) ]
+    pub kind : WebhookEventKind,
+  }
+
+  /// The object an event is about; fetch it by its ID for the details.
+  ///
+  /// # Used By
+  /// - `WebhookEventKind`
+  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub struct WebhookEventData
+  {
+    /// The ID of the batch, fine-tuning job, eval run, or response.
+    pub id : String,
+  }
+
+  /// A SIP header of an incoming Realtime call.
+  ///
+  /// # Used By
+  /// - `RealtimeCallIncomingData`
+  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub struct SipHeader
+  {
+    /// The header name.
+    pub name : String,
+    /// The header value.
+    pub value : String,
+  }
+
+  /// The incoming Realtime call of a `realtime.call.incoming` event.
+  ///
+  /// # Used By
+  /// - `WebhookEventKind::RealtimeCallIncoming`
+  #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub struct RealtimeCallIncomingData
+  {
+    /// The ID of the call, to accept or reject it by.
+    pub call_id : String,
+    /// The SIP headers of the call.
+    #[ serde( default ) ]
+    pub sip_headers : Vec< SipHeader >,
+  }
+
+  /// What a webhook event reports, chosen by its `type`.
+  ///
+  /// An event of a `type` this crate does not know yet is kept as `Other`,
+  /// holding its `type` and `data`.
+  ///
+  /// # Used By
+  /// - `WebhookEvent`
+  #[ derive( Debug, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
+  pub enum WebhookEventKind
+  {
+    /// `batch.completed`: a batch finished.
+    BatchCompleted( WebhookEventData ),
+    /// `batch.cancelled`: a batch was cancelled.
+    BatchCancelled( WebhookEventData ),
+    /// `batch.expired`: a batch did not finish in its completion window.
+    BatchExpired( WebhookEventData ),
+    /// `batch.failed`: a batch failed.
+    BatchFailed( WebhookEventData ),
+    /// `fine_tuning.job.succeeded`: a fine-tuning job succeeded.
+    FineTuningJobSucceeded( WebhookEventData ),
+    /// `fine_tuning.job.failed`: a fine-tuning job failed.
+    FineTuningJobFailed( WebhookEventData ),
+    /// `fine_tuning.job.cancelled`: a fine-tuning job was cancelled.
+    FineTuningJobCancelled( WebhookEventData ),
+    /// `eval.run.succeeded`: an eval run succeeded.
+    EvalRunSucceeded( WebhookEventData ),
+    /// `eval.run.failed`: an eval run failed.
+    EvalRunFailed( WebhookEventData ),
+    /// `eval.run.canceled`: an eval run was canceled.
+    EvalRunCanceled( WebhookEventData ),
+    /// `response.completed`: a background response completed.
+    ResponseCompleted( WebhookEventData ),
+    /// `response.cancelled`: a background response was cancelled.
+    ResponseCancelled( WebhookEventData ),
+    /// `response.failed`: a background response failed.
+    ResponseFailed( WebhookEventData ),
+    /// `response.incomplete`: a background response stopped incomplete.
+    ResponseIncomplete( WebhookEventData ),
+    /// `realtime.call.incoming`: a SIP call arrived for the Realtime API.
+    RealtimeCallIncoming( RealtimeCallIncomingData ),
+    /// An event of a `type` this crate does not know yet.
+    Other
+    {
+      /// The event type.
+      r#type : String,
+      /// The event data, as received.
+      data : Value,
+    },
+  }
+
+  impl< 'de > Deserialize< 'de > for WebhookEventKind
+  {
+    #[ inline ]
+    fn deserialize< D >( deserializer : D ) -> core::result::Result< Self, D::Error >
+    where
+      D : Deserializer< 'de >,
+    {
+      let mut value = Value::deserialize( deserializer )?;
+      let r#type = value.get( "type" ).and_then( Value::as_str ).unwrap_or_default().to_string();
+      let data = value.get_mut( "data" ).map( Value::take ).unwrap_or_default();
+      let data_of = | variant : fn( WebhookEventData ) -> Self | serde_json::from_value( data.clone() ).map( variant );
+      let kind = match r#type.as_str()
+      {
+        "batch.completed" => data_of( Self::BatchCompleted ),
+        "batch.cancelled" => data_of( Self::BatchCancelled ),
+        "batch.expired" => data_of( Self::BatchExpired ),
+        "batch.failed" => data_of( Self::BatchFailed ),
+        "fine_tuning.job.succeeded" => data_of( Self::FineTuningJobSucceeded ),
+        "fine_tuning.job.failed" => data_of( Self::FineTuningJobFailed ),
+        "fine_tuning.job.cancelled" => data_of( Self::FineTuningJobCancelled ),
+        "eval.run.succeeded" => data_of( Self::EvalRunSucceeded ),
+        "eval.run.failed" => data_of( Self::EvalRunFailed ),
+        "eval.run.canceled" => data_of( Self::EvalRunCanceled ),
+        "response.completed" => data_of( Self::ResponseCompleted ),
+        "response.cancelled" => data_of( Self::ResponseCancelled ),
+        "response.failed" => data_of( Self::ResponseFailed ),
+        "response.incomplete" => data_of( Self::ResponseIncomplete ),
+        "realtime.call.incoming" => serde_json::from_value( data.clone() ).map( Self::RealtimeCallIncoming ),
+        _ => Ok( Self::Other { r#type, data } ),
+      };
+      kind.map_err( serde::de::Error::custom )
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    WebhookEvent,
+    WebhookEventData,
+    SipHeader,
+    RealtimeCallIncomingData,
+    WebhookEventKind,
+  };
+}
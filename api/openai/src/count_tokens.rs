@@ -0,0 +1,227 @@
+// src/count_tokens.rs
+//! This module counts tokens locally with `tiktoken`, in the encoding of each
+//! model (`o200k_base` for the `gpt-4o`, `gpt-4.1`, `gpt-5`, and `o` series,
+//! `cl100k_base` for `gpt-4` and `gpt-3.5`), and defines `TokenBudget`, which
+//! fits a message history to a context window.
+//!
+//! Message counts follow the chat format: each message costs its role, name,
+//! and content, plus a few tokens of framing, and every reply is primed with
+//! a few more. Image parts are not counted. Nothing here runs on its own:
+//! requests are not counted or truncated unless one of these is called.
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::error::{ OpenAIError, Result };
+  use crate::components::chat_shared::
+  {
+    ChatCompletionRequest,
+    ChatCompletionRequestMessage,
+    ChatCompletionRequestMessageContent,
+    ChatCompletionRequestMessageContentPart,
+  };
+  use tiktoken_rs::CoreBPE;
+
+  /// The framing around each message: `<|start|>{role}\n{content}<|end|>\n`.
+  const TOKENS_PER_MESSAGE : usize = 3;
+
+  /// The extra token of a message that has a `name`.
+  const TOKENS_PER_NAME : usize = 1;
+
+  /// The priming of every reply: `<|start|>assistant<|message|>`.
+  const TOKENS_PER_REPLY : usize = 3;
+
+  /// The encoding of `model`.
+  fn encoding_for( model : &str ) -> Result< &'static CoreBPE >
+  {
+    tiktoken_rs::bpe_for_model( model )
+    .map_err( | e | OpenAIError::InvalidArgument( format!( "No tokenizer for model {model} : {e}" ) ).into() )
+  }
+
+  /// Counts the tokens of `text` in the encoding of `model`.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if no encoding is known for the
+  /// model.
+  #[ inline ]
+  pub fn count_tokens( text : &str, model : &str ) -> Result< usize >
+  {
+    Ok( encoding_for( model )?.encode_with_special_tokens( text ).len() )
+  }
+
+  /// Counts the tokens of `messages` as a prompt for `model`, reply priming
+  /// included.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if no encoding is known for the
+  /// model.
+  #[ inline ]
+  pub fn count_tokens_for_messages( messages : &[ ChatCompletionRequestMessage ], model : &str ) -> Result< usize >
+  {
+    let encoding = encoding_for( model )?;
+    let messages : usize = messages.iter().map( | message | message_tokens( message, encoding ) ).sum();
+    Ok( messages + TOKENS_PER_REPLY )
+  }
+
+  /// Counts the tokens of the prompt of `request`: its messages, and the
+  /// definitions of its tools.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if no encoding is known for the
+  /// model of the request.
+  #[ inline ]
+  pub fn count_tokens_for_request( request : &ChatCompletionRequest ) -> Result< usize >
+  {
+    let encoding = encoding_for( &request.model )?;
+    let mut total = count_tokens_for_messages( &request.messages, &request.model )?;
+    for tool in request.tools.iter().flatten()
+    {
+      total += encoding.encode_with_special_tokens( &serde_json::to_string( tool ).map_err( OpenAIError::from )? ).len();
+    }
+    Ok( total )
+  }
+
+  /// The tokens of one message, framing included.
+  fn message_tokens( message : &ChatCompletionRequestMessage, encoding : &CoreBPE ) -> usize
+  {
+    let count = | text : &str | encoding.encode_with_special_tokens( text ).len();
+    let mut tokens = TOKENS_PER_MESSAGE + count( &message.role );
+    match &message.content
+    {
+      Some( ChatCompletionRequestMessageContent::Text( text ) ) => tokens += count( text ),
+      Some( ChatCompletionRequestMessageContent::Parts( parts ) ) =>
+      {
+        for part in parts
+        {
+          if let ChatCompletionRequestMessageContentPart::Text { text } = part
+          {
+            tokens += count( text );
+          }
+        }
+      },
+      None => {},
+    }
+    if let Some( name ) = &message.name
+    {
+      tokens += count( name ) + TOKENS_PER_NAME;
+    }
+    for call in message.tool_calls.iter().flatten()
+    {
+      tokens += count( &call.function.name ) + count( &call.function.arguments );
+    }
+    if let Some( id ) = &message.tool_call_id
+    {
+      tokens += count( id );
+    }
+    tokens
+  }
+
+  /// The tokens a prompt may take: a context window less the tokens kept for
+  /// the reply.
+  ///
+  /// `truncate` drops the oldest messages of a history until it fits, and is
+  /// only applied where it is called.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct TokenBudget
+  {
+    /// The context window of the model, prompt and reply together.
+    pub context_window : usize,
+    /// The tokens kept for the reply.
+    pub reserved_for_output : usize,
+  }
+
+  impl TokenBudget
+  {
+    /// A budget of `context_window` tokens, none of them kept for the reply.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( context_window : usize ) -> Self
+    {
+      Self { context_window, reserved_for_output : 0 }
+    }
+
+    /// The budget of the context window of `model`, when it is known.
+    #[ inline ]
+    #[ must_use ]
+    pub fn for_model( model : &str ) -> Option< Self >
+    {
+      tiktoken_rs::model::get_context_size( model ).map( Self::new )
+    }
+
+    /// Keeps `tokens` of the context window for the reply.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_reserved_for_output( mut self, tokens : usize ) -> Self
+    {
+      self.reserved_for_output = tokens;
+      self
+    }
+
+    /// The tokens a prompt may take.
+    #[ inline ]
+    #[ must_use ]
+    pub fn available( &self ) -> usize
+    {
+      self.context_window.saturating_sub( self.reserved_for_output )
+    }
+
+    /// Whether `messages` fit the budget as a prompt for `model`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if no encoding is known for the
+    /// model.
+    #[ inline ]
+    pub fn fits( &self, messages : &[ ChatCompletionRequestMessage ], model : &str ) -> Result< bool >
+    {
+      Ok( count_tokens_for_messages( messages, model )? <= self.available() )
+    }
+
+    /// The messages that fit the budget: the leading `system` and `developer`
+    /// messages, and the most recent of the others.
+    ///
+    /// The oldest messages after the instructions go first; a `tool` result
+    /// goes with the assistant message that called it, so no result is left
+    /// without its call.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if no encoding is known for the
+    /// model, or if the instructions and the last message alone do not fit.
+    #[ inline ]
+    pub fn truncate( &self, messages : &[ ChatCompletionRequestMessage ], model : &str ) -> Result< Vec< ChatCompletionRequestMessage > >
+    {
+      let encoding = encoding_for( model )?;
+      let costs : Vec< usize > = messages.iter().map( | message | message_tokens( message, encoding ) ).collect();
+      let mut total = costs.iter().sum::< usize >() + TOKENS_PER_REPLY;
+      let instructions = messages.iter().take_while( | message | message.role == "system" || message.role == "developer" ).count();
+
+      let mut start = instructions;
+      while total > self.available() && start < messages.len()
+      {
+        total -= costs[ start ];
+        start += 1;
+        while start < messages.len() && messages[ start ].role == "tool"
+        {
+          total -= costs[ start ];
+          start += 1;
+        }
+      }
+      if total > self.available() || ( start == messages.len() && messages.len() > instructions )
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Messages do not fit a budget of {} tokens : the instructions and the last message need more", self.available() ) ).into() );
+      }
+
+      Ok( messages[ ..instructions ].iter().chain( &messages[ start.. ] ).cloned().collect() )
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    count_tokens,
+    count_tokens_for_messages,
+    count_tokens_for_request,
+    TokenBudget,
+  };
+}
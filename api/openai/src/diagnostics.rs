@@ -14,8 +14,9 @@ mod private
   {
     collections ::HashMap,
     sync ::{ Arc, Mutex },
-    time ::Instant,
   };
+  // `std::time::Instant` panics on wasm32; `web_time` falls back to `performance.now()`
+  use web_time::Instant;
   use core::time::Duration;
   use serde::{ Serialize, Deserialize };
 
@@ -0,0 +1,94 @@
+// src/embedding_utils.rs
+//! This module provides small utilities over embedding vectors: decoding the
+//! `base64` encoding of the Embeddings API, the L2 norm, normalization, and
+//! cosine similarity.
+//!
+//! `OpenAI` embeddings are normalized to length 1, so their dot product is
+//! their cosine similarity; vectors shortened by hand, rather than with the
+//! `dimensions` request parameter, need `normalize` first.
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::error::{ OpenAIError, Result };
+  use base64::{ engine::general_purpose::STANDARD, Engine as _ };
+
+  /// Decodes an embedding returned with `encoding_format: base64`: the
+  /// little-endian bytes of its `f32` values.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if the text is not base64, or if
+  /// the bytes are not a whole number of `f32` values.
+  #[ inline ]
+  pub fn decode_base64_embedding( encoded : &str ) -> Result< Vec< f32 > >
+  {
+    let bytes = STANDARD
+    .decode( encoded )
+    .map_err( | e | OpenAIError::InvalidArgument( format!( "Embedding is not base64 : {e}" ) ) )?;
+    if bytes.len() % 4 != 0
+    {
+      return Err( OpenAIError::InvalidArgument( format!( "Embedding of {} bytes is not a sequence of f32 values", bytes.len() ) ).into() );
+    }
+    Ok( bytes.chunks_exact( 4 ).map( | b | f32::from_le_bytes( [ b[ 0 ], b[ 1 ], b[ 2 ], b[ 3 ] ] ) ).collect() )
+  }
+
+  /// The Euclidean (L2) length of `vector`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn l2_norm( vector : &[ f32 ] ) -> f32
+  {
+    vector.iter().map( | x | x * x ).sum::< f32 >().sqrt()
+  }
+
+  /// Scales `vector` in place to length 1.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if the vector has zero length.
+  #[ inline ]
+  pub fn normalize( vector : &mut [ f32 ] ) -> Result< () >
+  {
+    let norm = l2_norm( vector );
+    if norm == 0.0
+    {
+      return Err( OpenAIError::InvalidArgument( "Cannot normalize a zero vector".to_string() ).into() );
+    }
+    for x in vector.iter_mut()
+    {
+      *x /= norm;
+    }
+    Ok( () )
+  }
+
+  /// The cosine similarity of two vectors, in `[-1, 1]`.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::InvalidArgument` if the vectors differ in
+  /// dimension, or if either has zero length.
+  #[ inline ]
+  pub fn cosine_similarity( a : &[ f32 ], b : &[ f32 ] ) -> Result< f32 >
+  {
+    if a.len() != b.len()
+    {
+      return Err( OpenAIError::InvalidArgument( format!( "Cannot compare embeddings of {} and {} dimensions", a.len(), b.len() ) ).into() );
+    }
+    let norms = l2_norm( a ) * l2_norm( b );
+    if norms == 0.0
+    {
+      return Err( OpenAIError::InvalidArgument( "Cannot compare a zero vector".to_string() ).into() );
+    }
+    let dot : f32 = a.iter().zip( b ).map( | ( x, y ) | x * y ).sum();
+    // Rounding can carry the ratio just past 1.
+    Ok( ( dot / norms ).clamp( -1.0, 1.0 ) )
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    decode_base64_embedding,
+    l2_norm,
+    normalize,
+    cosine_similarity,
+  };
+}
@@ -87,11 +87,7 @@ mod private
         use crate::input_validation::Validate;
         if let Err( validation_errors ) = request.validate()
         {
-          let error_messages : Vec< String > = validation_errors
-            .iter()
-            .map( | e | format!( "{e}" ) )
-            .collect();
-          return Err( error_tools::Error::from( crate::error::OpenAIError::InvalidArgument( format!( "Request validation failed : {}", error_messages.join( "; " ) ) ) ) );
+          return Err( crate::error::OpenAIError::Validation( validation_errors ).into() );
         }
       }
 
@@ -200,6 +196,76 @@ mod private
       Ok( curl_request.to_curl_command() )
     }
   }
+
+  /// Owned embeddings client bound to one model.
+  ///
+  /// Implements `api_common::Embedder`, so retrieval code written against the
+  /// shared trait can use `OpenAI` embeddings. Each `embed` call is exactly one
+  /// `/embeddings` request.
+  #[ cfg( feature = "common" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub struct OpenAIEmbedder< E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : Client< E >,
+    model : String,
+    dimensions : Option< u32 >,
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl< E > OpenAIEmbedder< E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Maximum number of inputs the `/embeddings` endpoint accepts per request.
+    pub const MAX_BATCH_SIZE : usize = 2048;
+
+    /// Creates an embedder for `model` (e.g. `"text-embedding-3-small"`).
+    #[ inline ]
+    pub fn new( client : Client< E >, model : impl Into< String > ) -> Self
+    {
+      Self { client, model : model.into(), dimensions : None }
+    }
+
+    /// Requests shortened embeddings (text-embedding-3 and later models).
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_dimensions( mut self, dimensions : u32 ) -> Self
+    {
+      self.dimensions = Some( dimensions );
+      self
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  #[ async_trait::async_trait ]
+  impl< E > api_common::Embedder for OpenAIEmbedder< E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    #[ inline ]
+    fn model( &self ) -> &str
+    {
+      &self.model
+    }
+
+    #[ inline ]
+    fn max_batch_size( &self ) -> Option< usize >
+    {
+      Some( Self::MAX_BATCH_SIZE )
+    }
+
+    #[ inline ]
+    async fn embed( &self, texts : &[ String ] ) -> Result< Vec< api_common::Embedding > >
+    {
+      let mut request = CreateEmbeddingRequest::new_multiple( texts.to_vec(), self.model.clone() );
+      request.dimensions = self.dimensions;
+      let mut response = Embeddings::new( &self.client ).create( request ).await?;
+      response.data.sort_by_key( | embedding | embedding.index );
+      Ok( response.data.into_iter().map( | embedding | embedding.embedding ).collect() )
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -209,4 +275,10 @@ crate ::mod_interface!
   {
     Embeddings,
   };
+
+  #[ cfg( feature = "common" ) ]
+  exposed use
+  {
+    OpenAIEmbedder,
+  };
 }
\ No newline at end of file
@@ -133,6 +133,10 @@ mod private
         OpenAIError::Internal( _ ) | OpenAIError::InvalidArgument( _ ) | OpenAIError::MissingArgument( _ ) |
         OpenAIError::MissingEnvironment( _ ) | OpenAIError::MissingHeader( _ ) | OpenAIError::MissingFile( _ ) |
         OpenAIError::File( _ ) | OpenAIError::Unknown( _ ) => false,
+        // Kept apart from the arm above, which cannot carry a `cfg`
+        #[ cfg( feature = "input_validation" ) ]
+        #[ allow( clippy::match_same_arms ) ]
+        OpenAIError::Validation( _ ) => false,
       }
     }
 
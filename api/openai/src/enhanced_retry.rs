@@ -22,7 +22,7 @@ mod private
   };
 
   use serde::{ Serialize, Deserialize };
-  use tokio::time::sleep;
+  use api_common::{ sleep, BudgetTracker };
   use rand::RngExt;
 
   /// Enhanced retry configuration for HTTP requests
@@ -158,6 +158,10 @@ mod private
         OpenAIError::Api( _ ) | OpenAIError::WsInvalidMessage( _ ) | OpenAIError::Internal( _ ) |
         OpenAIError::InvalidArgument( _ ) | OpenAIError::MissingArgument( _ ) | OpenAIError::MissingEnvironment( _ ) |
         OpenAIError::MissingHeader( _ ) | OpenAIError::MissingFile( _ ) | OpenAIError::File( _ ) | OpenAIError::Unknown( _ ) => false,
+        // Kept apart from the arm above, which cannot carry a `cfg`
+        #[ cfg( feature = "input_validation" ) ]
+        #[ allow( clippy::match_same_arms ) ]
+        OpenAIError::Validation( _ ) => false,
       }
     }
 
@@ -305,6 +309,36 @@ mod private
     ///
     /// Panics if the internal state mutex is poisoned.
     pub async fn execute< F, Fut, T >( &self, operation : F ) -> Result< T >
+    where
+      F : Fn() -> Fut,
+      Fut : core::future::Future< Output = Result< T > >,
+    {
+      self.execute_inner( operation, None ).await
+    }
+
+    /// Execute operation with retry logic inside a call budget shared with other layers
+    ///
+    /// Every attempt is counted against `budget` and cut off at its total deadline.
+    /// A retry that the budget does not allow returns the last error instead of sleeping.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last operation error when retries or the budget run out, or
+    /// `api_common::BudgetExhausted` when the budget refuses the first attempt or
+    /// the deadline passes during an attempt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal state mutex is poisoned.
+    pub async fn execute_within< F, Fut, T >( &self, budget : &BudgetTracker, operation : F ) -> Result< T >
+    where
+      F : Fn() -> Fut,
+      Fut : core::future::Future< Output = Result< T > >,
+    {
+      self.execute_inner( operation, Some( budget ) ).await
+    }
+
+    async fn execute_inner< F, Fut, T >( &self, operation : F, budget : Option< &BudgetTracker > ) -> Result< T >
     where
       F : Fn() -> Fut,
       Fut : core::future::Future< Output = Result< T > >,
@@ -340,8 +374,14 @@ mod private
           state.attempt
         };
 
-        // Execute operation
-        match operation().await
+        // Execute operation, counted against the shared budget when one is given
+        let outcome = match budget
+        {
+          Some( budget ) => budget.run_attempt( operation() ).await?,
+          None => operation().await,
+        };
+
+        match outcome
         {
           Ok( result ) => return Ok( result ),
           Err( error ) =>
@@ -379,6 +419,15 @@ mod private
             // Calculate delay for next attempt (0-indexed for calculation)
             let delay = self.config.calculate_delay( current_attempt - 1 );
 
+            // Stop rather than sleep past the shared budget
+            if let Some( budget ) = budget
+            {
+              if budget.allows_delay( delay ).is_err()
+              {
+                return Err( error );
+              }
+            }
+
             // Log retry attempt (only when retry feature is enabled)
             #[ cfg( feature = "retry" ) ]
             {
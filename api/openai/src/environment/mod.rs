@@ -23,6 +23,8 @@ mod private
   const OPENAI_REALTIME_BASE_URL : &str = "https://api.openai.com/v1/realtime/";
   /// `OpenAI` Beta header for API requests.
   pub const OPENAI_BETA_HEADER : &str = "OpenAI-Beta";
+  /// `OpenAI-Beta` value the Assistants v2 endpoints require.
+  pub const ASSISTANTS_V2_BETA : &str = "assistants=v2";
 
   /// Recommended configuration values for `OpenAI` API client following "Thin Client, Rich API" principles.
   ///
@@ -240,8 +242,9 @@ mod private
     {
       let mut headers = header::HeaderMap::new();
       let api_key = self.api_key.expose_secret();
-      let auth_value = header::HeaderValue::from_str( &format!( "Bearer {api_key}" ) )
+      let mut auth_value = header::HeaderValue::from_str( &format!( "Bearer {api_key}" ) )
       .map_err( | error | error_tools::Error::from( OpenAIError::InvalidArgument( format!( "Invalid API key : {error}" ) ) ) )?;
+      auth_value.set_sensitive( true );
       headers.insert( header::AUTHORIZATION, auth_value );
 
       if let Some( org_id ) = OpenaiEnvironment::organization_id( self )
@@ -305,5 +308,6 @@ crate ::mod_interface!
     OpenaiEnvironmentImpl,
     OpenAIRecommended,
     OPENAI_BETA_HEADER,
+    ASSISTANTS_V2_BETA,
   };
 }
\ No newline at end of file
@@ -132,6 +132,18 @@ mod private
     /// A rate limiting error.
     #[ error( "Rate Limit Error : {0}" ) ]
     RateLimit( String ),
+    /// A request that failed validation before it was sent, with every
+    /// violation found.
+    #[ cfg( feature = "input_validation" ) ]
+    #[ error( "Request validation failed : {}", join_violations( .0 ) ) ]
+    Validation( Vec< crate::input_validation::ValidationError > ),
+  }
+
+  /// The violations of a request, separated by `; `.
+  #[ cfg( feature = "input_validation" ) ]
+  fn join_violations( violations : &[ crate::input_validation::ValidationError ] ) -> String
+  {
+    violations.iter().map( ToString::to_string ).collect::< Vec< _ > >().join( "; " )
   }
 
 
@@ -189,6 +201,34 @@ mod private
     }
   }
 
+  #[ cfg( feature = "common" ) ]
+  impl From< OpenAIError > for api_common::LlmError
+  {
+    #[ inline ]
+    fn from( error : OpenAIError ) -> Self
+    {
+      match error
+      {
+        OpenAIError::Api( api ) =>
+        {
+          // `code` and `type` carry the class (`context_length_exceeded`,
+          // `insufficient_quota`); the message alone often does not
+          let detail = format!( "{} ({}, {})", api.message, api.code.as_deref().unwrap_or_default(), api.r#type.as_deref().unwrap_or_default() );
+          match Self::classify( None, &detail )
+          {
+            Self::Provider { .. } => Self::Provider { raw : api.message },
+            class => class,
+          }
+        },
+        OpenAIError::Network( message ) | OpenAIError::Timeout( message ) | OpenAIError::Ws( message ) => Self::Network( message ),
+        OpenAIError::Stream( message ) | OpenAIError::WsInvalidMessage( message ) => Self::Protocol( message ),
+        OpenAIError::RateLimit( message ) => Self::RateLimited { retry_after : None, message },
+        OpenAIError::Http( message ) => Self::classify( None, &message ),
+        other => Self::Provider { raw : other.to_string() },
+      }
+    }
+  }
+
   /// Helper function to map `serde_json::Error` to `OpenAIError::Internal`.
   #[ must_use ]
   #[ inline ]
@@ -14,6 +14,7 @@ mod private
   use core::time::Duration;
   use serde::{ Deserialize, Serialize };
   use tokio::sync::mpsc;
+  use api_common::{ BudgetExhausted, BudgetTracker };
 
   /// Endpoint health status
   #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
@@ -160,6 +161,9 @@ mod private
     pub started_at : Instant,
     /// Previous failed endpoints in this context
     pub failed_endpoints : Vec< String >,
+    /// Idempotency key of the write, the same on every attempt and endpoint
+    #[ cfg( feature = "idempotency" ) ]
+    pub idempotency_key : api_common::IdempotencyKey,
   }
 
   impl FailoverContext
@@ -175,6 +179,8 @@ mod private
         endpoint,
         started_at : Instant::now(),
         failed_endpoints : Vec::new(),
+        #[ cfg( feature = "idempotency" ) ]
+        idempotency_key : api_common::IdempotencyKey::generate(),
       }
     }
 
@@ -390,6 +396,48 @@ mod private
       manager : &FailoverManager,
       operation : F,
     ) -> Result< T, FailoverError< E > >
+    where
+      F : Fn( FailoverContext ) -> Fut + Send + Sync,
+      Fut : core::future::Future< Output = Result< T, E > > + Send,
+      E : Send + Sync + 'static,
+    {
+      Self::execute_inner( manager, None, operation ).await
+    }
+
+    /// Execute a function with failover logic inside a call budget shared with other layers
+    ///
+    /// Every endpoint attempt is cut off at the total deadline of `budget` and counts
+    /// as one attempt, unless the retry layer inside `operation` was handed the same
+    /// tracker and counted its own, so failover and retries draw from one limit
+    /// instead of multiplying.
+    ///
+    /// # Errors
+    /// Returns `AllEndpointsFailed` with the last error when endpoints or the budget
+    /// run out after a failure, and `BudgetExhausted` when the budget refuses the first
+    /// attempt or the deadline passes during an attempt.
+    ///
+    /// # Panics
+    ///
+    /// This function should not panic under normal circumstances as the context is always initialized before use.
+    #[ inline ]
+    pub async fn execute_with_failover_within< T, E, F, Fut >(
+      manager : &FailoverManager,
+      budget : &BudgetTracker,
+      operation : F,
+    ) -> Result< T, FailoverError< E > >
+    where
+      F : Fn( FailoverContext ) -> Fut + Send + Sync,
+      Fut : core::future::Future< Output = Result< T, E > > + Send,
+      E : Send + Sync + 'static,
+    {
+      Self::execute_inner( manager, Some( budget ), operation ).await
+    }
+
+    async fn execute_inner< T, E, F, Fut >(
+      manager : &FailoverManager,
+      budget : Option< &BudgetTracker >,
+      operation : F,
+    ) -> Result< T, FailoverError< E > >
     where
       F : Fn( FailoverContext ) -> Fut + Send + Sync,
       Fut : core::future::Future< Output = Result< T, E > > + Send,
@@ -413,8 +461,14 @@ mod private
 
         let ctx = context.as_ref().unwrap();
 
-        // Execute operation
-        match operation( ctx.clone() ).await
+        // Execute operation, counted against the shared budget when one is given
+        let outcome = match budget
+        {
+          Some( budget ) => budget.run_layer( operation( ctx.clone() ) ).await.map_err( FailoverError::BudgetExhausted )?,
+          None => operation( ctx.clone() ).await,
+        };
+
+        match outcome
         {
           Ok( result ) => return Ok( result ),
           Err( error ) =>
@@ -424,9 +478,13 @@ mod private
               return Err( FailoverError::AllEndpointsFailed( Box::new( error ) ) );
             }
 
-            // Calculate delay before next attempt
+            // Calculate delay before next attempt; stop rather than sleep past the budget
             let delay = manager.calculate_retry_delay( attempt );
-            tokio ::time::sleep( delay ).await;
+            if budget.is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+            {
+              return Err( FailoverError::AllEndpointsFailed( Box::new( error ) ) );
+            }
+            api_common::sleep( delay ).await;
           }
         }
       }
@@ -497,6 +555,8 @@ mod private
     MaxRetriesExceeded,
     /// Configuration validation failed
     ConfigurationError( String ),
+    /// The shared call budget refused the first attempt or its deadline passed during one
+    BudgetExhausted( BudgetExhausted ),
   }
 
   impl< E > core::fmt::Display for FailoverError< E >
@@ -512,6 +572,7 @@ mod private
         FailoverError::AllEndpointsFailed( error ) => write!( f, "All endpoints failed : {error}" ),
         FailoverError::MaxRetriesExceeded => write!( f, "Maximum retry attempts exceeded" ),
         FailoverError::ConfigurationError( msg ) => write!( f, "Configuration error : {msg}" ),
+        FailoverError::BudgetExhausted( exhausted ) => write!( f, "{exhausted}" ),
       }
     }
   }
@@ -526,6 +587,7 @@ mod private
       match self
       {
         FailoverError::AllEndpointsFailed( error ) => Some( error.as_ref() ),
+        FailoverError::BudgetExhausted( exhausted ) => Some( exhausted ),
         _ => None,
       }
     }
@@ -1,6 +1,7 @@
-// src/api/files.rs
+// src/files.rs
 //! This module defines the `Files` API client, which provides methods
-//! for interacting with the `OpenAI` Files API.
+//! for interacting with the `OpenAI` Files API: uploads for fine-tuning,
+//! Assistants, and Batch, paginated listing, and content download.
 //!
 //! For more details, refer to the [`OpenAI` Files API documentation](https://platform.openai.com/docs/api-reference/files).
 
@@ -13,18 +14,20 @@ mod private
     client ::Client,
     error ::Result,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    pagination ::CursorPage,
   };
   use crate::components::files::
   {
     FileObject,
     ListFilesResponse,
+    ListFilesQuery,
     CreateFileRequest,
   };
-  use crate::components::common::ListQuery;
+  use crate::components::common::DeleteFileResponse;
 
   // External crates
   use reqwest::multipart::{ Form, Part };
-  use serde_json;
+  use tokio::sync::mpsc;
 
   /// The client for the `OpenAI` Files API.
   #[ derive( Debug, Clone ) ]
@@ -51,6 +54,8 @@ mod private
 
     /// Uploads a file that can be used across various features.
     ///
+    /// The file is sent as `multipart/form-data`, with `purpose` beside it.
+    ///
     /// # Arguments
     /// - `request`: The request body for uploading a file.
     ///
@@ -67,23 +72,25 @@ mod private
 
       let form = Form::new()
         .part( "file", file_part )
-        .text( "purpose", request.purpose );
+        .text( "purpose", request.purpose.as_str() );
 
-      let path = "/files";
-      self.client.post_multipart( path, form ).await
+      self.client.post_multipart( "files", form ).await
     }
 
     /// Lists files that belong to the user's organization.
     ///
+    /// One page per call; pass `ListFilesResponse::next_after` as
+    /// `ListFilesQuery::after` to fetch the next page.
+    ///
     /// # Arguments
-    /// - `query`: Optional query parameters for listing files.
+    /// - `query`: Optional purpose filter and pagination parameters.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list( &self, query : Option< ListQuery > ) -> Result< ListFilesResponse >
+    pub async fn list( &self, query : Option< ListFilesQuery > ) -> Result< ListFilesResponse >
     {
-      let path = "/files";
+      let path = "files";
       if let Some( q ) = query
       {
         self.client.get_with_query( path, &q ).await
@@ -94,6 +101,22 @@ mod private
       }
     }
 
+    /// Fetches the first page of the files of the organization, as a `CursorPage` that fetches
+    /// the pages after it with the same `limit`.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters of the first page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_page( &self, query : Option< ListFilesQuery > ) -> Result< CursorPage< FileObject > >
+    where
+      E : Clone,
+    {
+      CursorPage::first( self.client.clone(), query.unwrap_or_default(), list_files_after ).await
+    }
+
     /// Retrieves a file.
     ///
     /// # Arguments
@@ -104,7 +127,7 @@ mod private
     #[ inline ]
     pub async fn retrieve( &self, file_id : &str ) -> Result< FileObject >
     {
-      let path = format!( "/files/{file_id}" );
+      let path = format!( "files/{file_id}" );
       self.client.get( &path ).await
     }
 
@@ -116,13 +139,13 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn delete( &self, file_id : &str ) -> Result< serde_json::Value >
+    pub async fn delete( &self, file_id : &str ) -> Result< DeleteFileResponse >
     {
-      let path = format!( "/files/{file_id}" );
+      let path = format!( "files/{file_id}" );
       self.client.delete( &path ).await
     }
 
-    /// Retrieves the content of the specified file.
+    /// Retrieves the content of the specified file as raw bytes.
     ///
     /// # Arguments
     /// - `file_id`: The ID of the file to retrieve content for.
@@ -132,10 +155,33 @@ mod private
     #[ inline ]
     pub async fn retrieve_content( &self, file_id : &str ) -> Result< Vec< u8 > >
     {
-      let path = format!( "/files/{file_id}/content" );
-      let response = self.client.get( &path ).await?;
-      Ok( response )
+      let path = format!( "files/{file_id}/content" );
+      self.client.get_bytes( &path ).await
     }
+
+    /// Streams the content of the specified file in chunks as they arrive,
+    /// without holding the whole file in memory.
+    ///
+    /// # Arguments
+    /// - `file_id`: The ID of the file to retrieve content for.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails; a chunk that fails to read
+    /// is sent as an error and ends the stream.
+    #[ inline ]
+    pub async fn retrieve_content_stream( &self, file_id : &str ) -> Result< mpsc::Receiver< Result< Vec< u8 > > > >
+    {
+      let path = format!( "files/{file_id}/content" );
+      self.client.get_byte_stream( &path ).await
+    }
+  }
+
+  /// One page of `Files::list`, for the pages a `CursorPage` fetches.
+  async fn list_files_after< E >( client : Client< E >, query : ListFilesQuery ) -> Result< ListFilesResponse >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    Files::new( &client ).list( Some( query ) ).await
   }
 } // end mod private
 
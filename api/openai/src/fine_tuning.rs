@@ -1,6 +1,7 @@
-// src/api/fine_tuning.rs
+// src/fine_tuning.rs
 //! This module defines the `FineTuning` API client, which provides methods
-//! for interacting with the `OpenAI` Fine-tuning API.
+//! for interacting with the `OpenAI` Fine-tuning API: jobs, their events,
+//! and their checkpoints.
 //!
 //! For more details, refer to the [OpenAI Fine-tuning API documentation](https://platform.openai.com/docs/api-reference/fine-tuning).
 
@@ -13,21 +14,17 @@ mod private
     client ::Client,
     error ::Result,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    pagination ::CursorPage,
   };
   use crate::components::fine_tuning_shared::
   {
     FineTuningJob,
-    FineTuningJobEvent,
+    CreateFineTuningJobRequest,
+    FineTuningListQuery,
     ListFineTuningJobEventsResponse,
+    ListFineTuningJobCheckpointsResponse,
     ListPaginatedFineTuningJobsResponse,
-    // FineTuningJobRequest - doesn't exist, need to create or use FineTuningJob
   };
-  use crate::components::common::ListQuery;
-
-  // External crates
-
-  use serde_json;
-  use tokio::sync::mpsc;
 
   /// The client for the `OpenAI` Fine-tuning API.
   #[ derive( Debug, Clone ) ]
@@ -54,13 +51,16 @@ mod private
 
     /// Creates a fine-tuning job.
     ///
+    /// The training and validation files must be uploaded first, with
+    /// purpose `fine-tune`.
+    ///
     /// # Arguments
     /// - `request`: The request body for creating a fine-tuning job.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn create_job( &self, request : FineTuningJob ) -> Result< FineTuningJob >
+    pub async fn create_job( &self, request : CreateFineTuningJobRequest ) -> Result< FineTuningJob >
     {
       self.client.post( "fine_tuning/jobs", &request ).await
     }
@@ -73,9 +73,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list_jobs( &self, query : Option< ListQuery > ) -> Result< ListPaginatedFineTuningJobsResponse >
+    pub async fn list_jobs( &self, query : Option< FineTuningListQuery > ) -> Result< ListPaginatedFineTuningJobsResponse >
     {
-      let path = "/fine_tuning/jobs";
+      let path = "fine_tuning/jobs";
       if let Some( q ) = query
       {
         self.client.get_with_query( path, &q ).await
@@ -86,6 +86,22 @@ mod private
       }
     }
 
+    /// Fetches the first page of the fine-tuning jobs, as a `CursorPage` that fetches
+    /// the pages after it with the same `limit`.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters of the first page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_jobs_page( &self, query : Option< FineTuningListQuery > ) -> Result< CursorPage< FineTuningJob > >
+    where
+      E : Clone,
+    {
+      CursorPage::first( self.client.clone(), query.unwrap_or_default(), list_jobs_after ).await
+    }
+
     /// Retrieves a fine-tuning job.
     ///
     /// # Arguments
@@ -96,7 +112,7 @@ mod private
     #[ inline ]
     pub async fn retrieve_job( &self, job_id : &str ) -> Result< FineTuningJob >
     {
-      let path = format!( "/fine_tuning/jobs/{job_id}" );
+      let path = format!( "fine_tuning/jobs/{job_id}" );
       self.client.get( &path ).await
     }
 
@@ -110,8 +126,8 @@ mod private
     #[ inline ]
     pub async fn cancel_job( &self, job_id : &str ) -> Result< FineTuningJob >
     {
-      let path = format!( "/fine_tuning/jobs/{job_id}/cancel" );
-      self.client.post( &path, &serde_json::json!({}) ).await
+      let path = format!( "fine_tuning/jobs/{job_id}/cancel" );
+      self.client.post_no_body( &path ).await
     }
 
     /// Lists events for a fine-tuning job.
@@ -123,9 +139,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list_job_events( &self, job_id : &str, query : Option< ListQuery > ) -> Result< ListFineTuningJobEventsResponse >
+    pub async fn list_job_events( &self, job_id : &str, query : Option< FineTuningListQuery > ) -> Result< ListFineTuningJobEventsResponse >
     {
-      let path = format!( "/fine_tuning/jobs/{job_id}/events" );
+      let path = format!( "fine_tuning/jobs/{job_id}/events" );
       if let Some( q ) = query
       {
         self.client.get_with_query( &path, &q ).await
@@ -136,20 +152,36 @@ mod private
       }
     }
 
-    /// Streams events for a fine-tuning job.
+    /// Lists the checkpoints of a fine-tuning job.
     ///
     /// # Arguments
     /// - `job_id`: The ID of the fine-tuning job.
+    /// - `query`: Optional query parameters for listing checkpoints.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn stream_job_events( &self, job_id : &str ) -> Result< mpsc::Receiver< Result< FineTuningJobEvent > > >
+    pub async fn list_job_checkpoints( &self, job_id : &str, query : Option< FineTuningListQuery > ) -> Result< ListFineTuningJobCheckpointsResponse >
     {
-      let path = format!( "/fine_tuning/jobs/{job_id}/events" );
-      self.client.post_stream( &path, &serde_json::json!({ "stream": true }) ).await
+      let path = format!( "fine_tuning/jobs/{job_id}/checkpoints" );
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( &path, &q ).await
+      }
+      else
+      {
+        self.client.get( &path ).await
+      }
     }
   }
+
+  /// One page of `FineTuning::list_jobs`, for the pages a `CursorPage` fetches.
+  async fn list_jobs_after< E >( client : Client< E >, query : FineTuningListQuery ) -> Result< ListPaginatedFineTuningJobsResponse >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    FineTuning::new( &client ).list_jobs( Some( query ) ).await
+  }
 } // end mod private
 
 crate ::mod_interface!
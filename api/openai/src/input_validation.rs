@@ -256,6 +256,53 @@ mod private
       Ok( () )
     }
 
+    /// Validate the number of tools a request offers (at most 128)
+    ///
+    /// # Errors
+    ///
+    /// Returns error if more than 128 tools are given
+    #[ inline ]
+    pub fn validate_tool_count( count : usize ) -> Result< (), ValidationError >
+    {
+      if count > 128
+      {
+        return Err(
+          ValidationError::new( "tools", "Too many tools" )
+            .with_value( count.to_string() )
+            .with_constraint( "at most 128 tools" )
+        );
+      }
+      Ok( () )
+    }
+
+    /// Whether `model` is a reasoning model (the `o1`, `o3`, and `o4`
+    /// families, and `gpt-5` other than its chat variants), which fixes its
+    /// sampling parameters and takes `max_completion_tokens`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_reasoning_model( model : &str ) -> bool
+    {
+      // A fine-tuned model is named `ft:<base>:<org>:...`
+      let base = model.strip_prefix( "ft:" ).unwrap_or( model );
+      let family = | prefix : &str | base == prefix || base.starts_with( &format!( "{prefix}-" ) );
+      family( "o1" ) || family( "o3" ) || family( "o4" )
+      || ( family( "gpt-5" ) && !base.starts_with( "gpt-5-chat" ) )
+    }
+
+    /// The violation of a parameter that a reasoning model does not take,
+    /// naming `instead` when the model takes another parameter in its place
+    #[ inline ]
+    #[ must_use ]
+    pub fn unsupported_by_reasoning_model( field : &str, model : &str, instead : Option< &str > ) -> ValidationError
+    {
+      let error = ValidationError::new( field, format!( "{field} is not supported by the reasoning model {model}" ) );
+      match instead
+      {
+        Some( instead ) => error.with_constraint( format!( "use {instead}" ) ),
+        None => error.with_constraint( "omit for reasoning models" ),
+      }
+    }
+
     /// Validate input string or array for embeddings (non-empty)
     ///
     /// # Errors
@@ -80,9 +80,13 @@ crate ::mod_interface!
   // API endpoint modules
   layer admin;
   layer assistants;
+  layer batches;
   #[ cfg( feature = "audio" ) ]
   layer audio;
   layer chat;
+  #[ cfg( feature = "count_tokens" ) ]
+  layer count_tokens;
+  layer embedding_utils;
   layer embeddings;
   layer files;
   layer fine_tuning;
@@ -93,8 +97,13 @@ crate ::mod_interface!
   #[ cfg( feature = "websocket" ) ]
   layer realtime;
   layer responses;
+  layer runs;
+  layer threads;
   layer uploads;
+  layer usage;
   layer vector_stores;
+  #[ cfg( feature = "webhooks" ) ]
+  layer webhooks;
 
   // Core functionality modules
   layer advanced_auth;
@@ -147,6 +156,7 @@ crate ::mod_interface!
   layer performance_cache;
 
   layer performance_monitoring;
+  layer pagination;
   layer platform_specific;
 
   #[ cfg( feature = "input_validation" ) ]
@@ -167,6 +177,9 @@ crate ::mod_interface!
   #[ cfg( feature = "streaming_control" ) ]
   layer streaming_control;
   layer streaming_performance_enhanced;
+  #[ cfg( feature = "schemars" ) ]
+  layer structured_outputs;
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
   layer sync;
   #[ cfg( feature = "websocket" ) ]
   layer websocket_reliability_enhanced;
@@ -3,6 +3,10 @@
 //! This module provides stateless model tuning and fine-tuning utilities for `OpenAI` API models.
 //! Following the "Thin Client, Rich API" principle, this module offers training management
 //! patterns and optimization tools without automatic behaviors or persistent state management.
+//!
+//! Jobs are local by default. A job linked to an `OpenAI` fine-tuning job
+//! follows the remote state only when `TuningManager::sync_job` or
+//! `TuningManager::sync_all` is called; nothing polls in the background.
 
 #![ allow( clippy::missing_inline_in_public_items, clippy::unused_async ) ]
 
@@ -15,7 +19,22 @@ mod private
   };
   use core::time::Duration;
   use serde::{ Deserialize, Serialize };
+  use serde_json::Value;
   use tokio::sync::mpsc;
+  use crate::
+  {
+    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    fine_tuning ::FineTuning,
+  };
+  use crate::components::fine_tuning_shared::
+  {
+    CreateFineTuningJobRequest,
+    FineTuneMethod,
+    FineTuneSupervisedMethod,
+    FineTuningJob,
+    FineTuningJobCheckpoint,
+    FineTuningJobHyperparameters,
+  };
 
   /// Fine-tuning job status
   #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
@@ -35,6 +54,27 @@ mod private
     Cancelled,
   }
 
+  impl TuningStatus
+  {
+    /// The status of a remote `OpenAI` fine-tuning job.
+    ///
+    /// A `failed` job carries its error message; a status this crate does
+    /// not know yet is read as `Queued`.
+    #[ must_use ]
+    pub fn from_remote( job : &FineTuningJob ) -> Self
+    {
+      match job.status.as_str()
+      {
+        "validating_files" => Self::Validating,
+        "running" => Self::Running,
+        "succeeded" => Self::Succeeded,
+        "failed" => Self::Failed( job.error.as_ref().map_or_else( || "Fine-tuning job failed".to_string(), | error | error.message.clone() ) ),
+        "cancelled" => Self::Cancelled,
+        _ => Self::Queued,
+      }
+    }
+  }
+
   /// Training objective type
   #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
   pub enum TrainingObjective
@@ -163,6 +203,34 @@ mod private
     pub file_path : String,
   }
 
+  impl From< &FineTuningJobCheckpoint > for ModelCheckpoint
+  {
+    /// Reads a remote checkpoint; its `file_path` is the checkpoint model name.
+    #[ inline ]
+    fn from( checkpoint : &FineTuningJobCheckpoint ) -> Self
+    {
+      let metrics = &checkpoint.metrics;
+      let validation_metrics = [
+        ( "valid_loss", metrics.valid_loss ),
+        ( "valid_mean_token_accuracy", metrics.valid_mean_token_accuracy ),
+        ( "full_valid_loss", metrics.full_valid_loss ),
+        ( "full_valid_mean_token_accuracy", metrics.full_valid_mean_token_accuracy ),
+      ]
+      .into_iter()
+      .filter_map( | ( name, value ) | value.map( | value | ( name.to_string(), value ) ) )
+      .collect();
+      Self
+      {
+        checkpoint_id : checkpoint.id.clone(),
+        step : u64::try_from( checkpoint.step_number ).unwrap_or( 0 ),
+        loss : metrics.train_loss.unwrap_or( 0.0 ),
+        validation_metrics,
+        created_at : SystemTime::UNIX_EPOCH + Duration::from_secs( u64::try_from( checkpoint.created_at ).unwrap_or( 0 ) ),
+        file_path : checkpoint.fine_tuned_model_checkpoint.clone(),
+      }
+    }
+  }
+
   /// Training progress metrics
   #[ derive( Debug, Clone, Serialize, Deserialize ) ]
   pub struct TrainingMetrics
@@ -286,6 +354,9 @@ mod private
     pub checkpoints : Vec< ModelCheckpoint >,
     /// Job execution history
     pub execution_log : Vec< TuningEvent >,
+    /// ID of the linked `OpenAI` fine-tuning job, if the job runs remotely
+    #[ serde( default ) ]
+    pub remote_job_id : Option< String >,
   }
 
   impl TuningJob
@@ -304,6 +375,30 @@ mod private
         current_metrics : None,
         checkpoints : Vec::new(),
         execution_log : Vec::new(),
+        remote_job_id : None,
+      }
+    }
+
+    /// Takes the status of the linked remote job; logs a status change only
+    /// when the status differs.
+    pub fn apply_remote_job( &mut self, remote : &FineTuningJob )
+    {
+      let status = TuningStatus::from_remote( remote );
+      if status != self.status
+      {
+        self.update_status( status );
+      }
+    }
+
+    /// Adds the remote checkpoints this job does not have yet.
+    pub fn apply_remote_checkpoints( &mut self, checkpoints : &[ FineTuningJobCheckpoint ] )
+    {
+      for checkpoint in checkpoints
+      {
+        if !self.checkpoints.iter().any( | known | known.checkpoint_id == checkpoint.id )
+        {
+          self.add_checkpoint( ModelCheckpoint::from( checkpoint ) );
+        }
       }
     }
 
@@ -510,6 +605,96 @@ mod private
       }
     }
 
+    /// Link a local job to an existing `OpenAI` fine-tuning job
+    ///
+    /// # Errors
+    /// Returns an error if the job is not found.
+    pub fn link_remote_job( &mut self, job_name : &str, remote_job_id : impl Into< String > ) -> Result< (), String >
+    {
+      match self.jobs.get_mut( job_name )
+      {
+        Some( job ) =>
+        {
+          job.remote_job_id = Some( remote_job_id.into() );
+          Ok( () )
+        }
+        None => Err( format!( "Job '{job_name}' not found" ) ),
+      }
+    }
+
+    /// Create the `OpenAI` fine-tuning job for a local job and link the two
+    ///
+    /// The request carries the base model, the training and validation
+    /// files (IDs of uploaded files), and the supervised method with the
+    /// local epochs and batch size; the learning rate multiplier is left to
+    /// the API.
+    ///
+    /// # Errors
+    /// Returns an error if the job is not found, is already linked, or the
+    /// request fails.
+    pub async fn submit_job< E >( &mut self, job_name : &str, fine_tuning : &FineTuning< '_, E > ) -> Result< String, String >
+    where
+      E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+    {
+      let job = self.jobs.get( job_name ).ok_or_else( || format!( "Job '{job_name}' not found" ) )?;
+      if let Some( remote_job_id ) = &job.remote_job_id
+      {
+        return Err( format!( "Job '{job_name}' is already linked to '{remote_job_id}'" ) );
+      }
+
+      let remote = fine_tuning.create_job( create_job_request( &job.config ) ).await.map_err( | error | error.to_string() )?;
+      let job = self.jobs.get_mut( job_name ).ok_or_else( || format!( "Job '{job_name}' not found" ) )?;
+      job.remote_job_id = Some( remote.id.clone() );
+      job.apply_remote_job( &remote );
+      Ok( remote.id )
+    }
+
+    /// Bring a linked job up to date with its `OpenAI` fine-tuning job
+    ///
+    /// Retrieves the remote job and its checkpoints, then applies the
+    /// status and any new checkpoints to the local job.
+    ///
+    /// # Errors
+    /// Returns an error if the job is not found or not linked, or a request fails.
+    pub async fn sync_job< E >( &mut self, job_name : &str, fine_tuning : &FineTuning< '_, E > ) -> Result< TuningStatus, String >
+    where
+      E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+    {
+      let job = self.jobs.get( job_name ).ok_or_else( || format!( "Job '{job_name}' not found" ) )?;
+      let remote_job_id = job.remote_job_id.clone().ok_or_else( || format!( "Job '{job_name}' is not linked to a remote job" ) )?;
+
+      let remote = fine_tuning.retrieve_job( &remote_job_id ).await.map_err( | error | error.to_string() )?;
+      let checkpoints = fine_tuning.list_job_checkpoints( &remote_job_id, None ).await.map_err( | error | error.to_string() )?;
+
+      let job = self.jobs.get_mut( job_name ).ok_or_else( || format!( "Job '{job_name}' not found" ) )?;
+      job.apply_remote_job( &remote );
+      job.apply_remote_checkpoints( &checkpoints.data );
+      Ok( job.status.clone() )
+    }
+
+    /// Bring every linked job up to date with its `OpenAI` fine-tuning job
+    ///
+    /// Jobs that are not linked are left as they are. Returns the number of
+    /// jobs synced.
+    ///
+    /// # Errors
+    /// Returns the first error of `sync_job`; the jobs synced before it keep
+    /// their new state.
+    pub async fn sync_all< E >( &mut self, fine_tuning : &FineTuning< '_, E > ) -> Result< usize, String >
+    where
+      E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+    {
+      let linked : Vec< String > = self.jobs.iter()
+        .filter( | ( _, job ) | job.remote_job_id.is_some() )
+        .map( | ( name, _ ) | name.clone() )
+        .collect();
+      for job_name in &linked
+      {
+        self.sync_job( job_name, fine_tuning ).await?;
+      }
+      Ok( linked.len() )
+    }
+
     /// Get tuning statistics
     #[ must_use ]
     pub fn tuning_stats( &self ) -> TuningStats
@@ -542,6 +727,25 @@ mod private
     }
   }
 
+  /// The `OpenAI` fine-tuning job request for a local job configuration:
+  /// supervised, with the local epochs and batch size, and the learning rate
+  /// multiplier left to the API.
+  fn create_job_request( config : &TuningJobConfig ) -> CreateFineTuningJobRequest
+  {
+    let mut request = CreateFineTuningJobRequest::new( config.base_model.clone(), config.training_data.training_file.clone() );
+    request.validation_file.clone_from( &config.training_data.validation_file );
+    request.method = Some( FineTuneMethod::Supervised( FineTuneSupervisedMethod
+    {
+      hyperparameters : FineTuningJobHyperparameters
+      {
+        batch_size : Value::from( config.hyperparameters.batch_size ),
+        learning_rate_multiplier : Value::from( "auto" ),
+        n_epochs : Value::from( config.hyperparameters.epochs ),
+      },
+    } ) );
+    request
+  }
+
   impl Default for TuningManager
   {
     #[ inline ]
@@ -728,6 +932,44 @@ mod private
     }
   }
 
+  // Tests
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    #[ test ]
+    fn local_config_becomes_a_supervised_job_request()
+    {
+      let config = TuningJobConfig
+      {
+        job_name : "support".to_string(),
+        base_model : "gpt-4o-mini-2024-07-18".to_string(),
+        training_data : TrainingDataConfig
+        {
+          training_file : "file-train".to_string(),
+          validation_file : None,
+          data_format : "jsonl".to_string(),
+          max_sequence_length : 4096,
+          preprocessing : HashMap::new(),
+        },
+        hyperparameters : HyperParameters { epochs : 2, batch_size : 4, ..HyperParameters::default() },
+        method : FineTuningMethod::Full,
+        objective : TrainingObjective::SupervisedFineTuning,
+        resource_requirements : TuningResourceRequirements::default(),
+        checkpointing : CheckpointConfig::default(),
+        env_vars : HashMap::new(),
+      };
+
+      assert_eq!
+      (
+        serde_json::to_string( &create_job_request( &config ) ).expect( "request serializes" ),
+        r#"{"model":"gpt-4o-mini-2024-07-18","training_file":"file-train","method":{"type":"supervised","hyperparameters":{"batch_size":4,"learning_rate_multiplier":"auto","n_epochs":2}}}"#
+      );
+    }
+  }
+
 }
 
 crate ::mod_interface!
@@ -0,0 +1,265 @@
+// src/pagination.rs
+//! This module defines `CursorPage`, one page of a list endpoint that
+//! continues after an `after` cursor, and the traits that let list queries and
+//! list responses be paged through it.
+//!
+//! A page is fetched by a `list_page` method of a client; `next_page` fetches
+//! the one after it, and `into_pages` turns it into a stream of this page and
+//! every page after it. The `limit` of the first query is kept for every page.
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::error::Result;
+  use crate::components::
+  {
+    assistants_shared::{ AssistantObject, ListAssistantsResponse },
+    batch_shared::{ Batch, ListBatchesQuery, ListBatchesResponse },
+    files::{ FileObject, ListFilesQuery, ListFilesResponse },
+    fine_tuning_shared::{ FineTuningJob, FineTuningListQuery, ListPaginatedFineTuningJobsResponse },
+    query::ListQuery,
+    vector_stores_shared::{ ListVectorStoresResponse, VectorStoreObject },
+  };
+  use core::{ fmt, future::Future, pin::Pin };
+  use futures_core::Stream;
+  use std::sync::Arc;
+
+  /// A list query that takes an `after` cursor.
+  pub trait CursorQuery : Clone + Send + Sync + 'static
+  {
+    /// Sets the cursor: the list starts after the item with this ID.
+    fn set_after( &mut self, after : String );
+  }
+
+  /// A list response that continues after a cursor.
+  pub trait CursorList
+  {
+    /// The listed item.
+    type Item;
+
+    /// The items, whether more follow, and the cursor of the next page.
+    fn into_parts( self ) -> ( Vec< Self::Item >, bool, Option< String > );
+  }
+
+  /// The items of a page, whether more follow, and the cursor of the next.
+  type PageParts< T > = ( Vec< T >, bool, Option< String > );
+
+  /// Fetches the page after a cursor.
+  type FetchAfter< T > = Arc< dyn Fn( String ) -> Pin< Box< dyn Future< Output = Result< PageParts< T > > > + Send > > + Send + Sync >;
+
+  /// One page of a list endpoint, able to fetch the pages after it.
+  pub struct CursorPage< T >
+  {
+    /// The items of this page.
+    pub data : Vec< T >,
+    /// Whether more items follow this page.
+    pub has_more : bool,
+    /// The cursor of the next page: the ID of the last item.
+    pub after : Option< String >,
+    fetch : FetchAfter< T >,
+  }
+
+  impl< T > fmt::Debug for CursorPage< T >
+  where
+    T : fmt::Debug,
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "CursorPage" )
+      .field( "data", &self.data )
+      .field( "has_more", &self.has_more )
+      .field( "after", &self.after )
+      .finish_non_exhaustive()
+    }
+  }
+
+  /// The next item of `CursorPage::into_pages`.
+  enum Pending< T >
+  {
+    /// A page already fetched.
+    Page( CursorPage< T > ),
+    /// A page to fetch after a cursor.
+    After( FetchAfter< T >, String ),
+  }
+
+  impl< T > CursorPage< T >
+  where
+    T : Send + 'static,
+  {
+    /// Fetches the first page of `query`, keeping `client` and `fetch` for
+    /// the pages after it.
+    ///
+    /// `fetch` is a function item rather than a closure, so that its type
+    /// does not borrow the lifetime of the endpoint client calling this.
+    pub( crate ) async fn first< C, Q, L, F, Fut >( client : C, query : Q, fetch : F ) -> Result< Self >
+    where
+      C : Clone + Send + Sync + 'static,
+      Q : CursorQuery,
+      L : CursorList< Item = T >,
+      F : Fn( C, Q ) -> Fut + Send + Sync + 'static,
+      Fut : Future< Output = Result< L > > + Send + 'static,
+    {
+      let list = fetch( client.clone(), query.clone() ).await?;
+      let after : FetchAfter< T > = Arc::new( move | after |
+      {
+        let mut query = query.clone();
+        query.set_after( after );
+        let list = fetch( client.clone(), query );
+        Box::pin( async move { list.await.map( CursorList::into_parts ) } )
+      });
+      Ok( Self::from_parts( list.into_parts(), after ) )
+    }
+
+    fn from_parts( ( data, has_more, after ) : PageParts< T >, fetch : FetchAfter< T > ) -> Self
+    {
+      Self { data, has_more, after, fetch }
+    }
+
+    /// Fetches the page after this one; `None` when this is the last page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn next_page( &self ) -> Result< Option< Self > >
+    {
+      let Some( after ) = self.next_cursor() else { return Ok( None ) };
+      let parts = ( self.fetch )( after ).await?;
+      Ok( Some( Self::from_parts( parts, Arc::clone( &self.fetch ) ) ) )
+    }
+
+    /// This page and every page after it, each fetched when the stream is
+    /// polled for it. The stream ends after the last page, or after the
+    /// first error.
+    #[ inline ]
+    pub fn into_pages( self ) -> impl Stream< Item = Result< Self > > + Send
+    {
+      futures_util::stream::unfold( Some( Pending::Page( self ) ), | state | async move
+      {
+        let page = match state?
+        {
+          Pending::Page( page ) => page,
+          Pending::After( fetch, after ) => match fetch( after ).await
+          {
+            Ok( parts ) => Self::from_parts( parts, fetch ),
+            Err( error ) => return Some( ( Err( error ), None ) ),
+          },
+        };
+        let next = page.next_cursor().map( | after | Pending::After( Arc::clone( &page.fetch ), after ) );
+        Some( ( Ok( page ), next ) )
+      })
+    }
+
+    fn next_cursor( &self ) -> Option< String >
+    {
+      if self.has_more { self.after.clone() } else { None }
+    }
+  }
+
+  impl CursorQuery for ListFilesQuery
+  {
+    #[ inline ]
+    fn set_after( &mut self, after : String )
+    {
+      self.after = Some( after );
+    }
+  }
+
+  impl CursorQuery for FineTuningListQuery
+  {
+    #[ inline ]
+    fn set_after( &mut self, after : String )
+    {
+      self.after = Some( after );
+    }
+  }
+
+  impl CursorQuery for ListBatchesQuery
+  {
+    #[ inline ]
+    fn set_after( &mut self, after : String )
+    {
+      self.after = Some( after );
+    }
+  }
+
+  impl CursorQuery for ListQuery
+  {
+    #[ inline ]
+    fn set_after( &mut self, after : String )
+    {
+      self.after = Some( after );
+    }
+  }
+
+  impl CursorList for ListFilesResponse
+  {
+    type Item = FileObject;
+
+    #[ inline ]
+    fn into_parts( self ) -> ( Vec< FileObject >, bool, Option< String > )
+    {
+      let after = self.last_id.or_else( || self.data.last().map( | file | file.id.clone() ) );
+      ( self.data, self.has_more, after )
+    }
+  }
+
+  impl CursorList for ListPaginatedFineTuningJobsResponse
+  {
+    type Item = FineTuningJob;
+
+    /// The response has no `last_id`; the cursor is the ID of the last job.
+    #[ inline ]
+    fn into_parts( self ) -> ( Vec< FineTuningJob >, bool, Option< String > )
+    {
+      let after = self.data.last().map( | job | job.id.clone() );
+      ( self.data, self.has_more, after )
+    }
+  }
+
+  impl CursorList for ListBatchesResponse
+  {
+    type Item = Batch;
+
+    #[ inline ]
+    fn into_parts( self ) -> ( Vec< Batch >, bool, Option< String > )
+    {
+      let after = self.last_id.or_else( || self.data.last().map( | batch | batch.id.clone() ) );
+      ( self.data, self.has_more, after )
+    }
+  }
+
+  impl CursorList for ListAssistantsResponse
+  {
+    type Item = AssistantObject;
+
+    #[ inline ]
+    fn into_parts( self ) -> ( Vec< AssistantObject >, bool, Option< String > )
+    {
+      let after = self.last_id.or_else( || self.data.last().map( | assistant | assistant.id.clone() ) );
+      ( self.data, self.has_more, after )
+    }
+  }
+
+  impl CursorList for ListVectorStoresResponse
+  {
+    type Item = VectorStoreObject;
+
+    #[ inline ]
+    fn into_parts( self ) -> ( Vec< VectorStoreObject >, bool, Option< String > )
+    {
+      let after = self.last_id.or_else( || self.data.last().map( | store | store.id.clone() ) );
+      ( self.data, self.has_more, after )
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    CursorPage,
+    CursorQuery,
+    CursorList,
+  };
+}
@@ -153,9 +153,11 @@ mod private
         temperature : self.temperature,
         top_p : None,
         max_tokens : self.max_tokens,
+        max_completion_tokens : None,
         n : None,
         stop : None,
         stream : None,
+        stream_options : None,
         system_prompt : None,
         user : None,
         tools : None,
@@ -54,6 +54,16 @@ mod private
         }
       }
 
+      // Validate max_completion_tokens if present
+      if let Some( max_completion_tokens ) = self.max_completion_tokens
+      {
+        if let Err( mut e ) = validators::validate_max_tokens( max_completion_tokens )
+        {
+          e.field = "max_completion_tokens".to_string();
+          errors.push( e );
+        }
+      }
+
       // Validate n if present
       if let Some( n ) = self.n
       {
@@ -72,6 +82,71 @@ mod private
         }
       }
 
+      // Validate tool count if present
+      if let Some( tools ) = &self.tools
+      {
+        if let Err( e ) = validators::validate_tool_count( tools.len() )
+        {
+          errors.push( e );
+        }
+      }
+
+      // Validate parameters that exclude or require one another
+      if self.max_tokens.is_some() && self.max_completion_tokens.is_some()
+      {
+        errors.push(
+          ValidationError::new( "max_tokens", "max_tokens and max_completion_tokens cannot both be set" )
+            .with_constraint( "set only max_completion_tokens" )
+        );
+      }
+      if self.top_logprobs.is_some() && self.logprobs != Some( true )
+      {
+        errors.push(
+          ValidationError::new( "top_logprobs", "top_logprobs requires logprobs" )
+            .with_constraint( "logprobs = true" )
+        );
+      }
+      if self.tool_choice.is_some() && self.tools.as_ref().map_or( true, Vec::is_empty )
+      {
+        errors.push(
+          ValidationError::new( "tool_choice", "tool_choice requires tools" )
+            .with_constraint( "at least one tool" )
+        );
+      }
+      if self.stream_options.is_some() && self.stream != Some( true )
+      {
+        errors.push(
+          ValidationError::new( "stream_options", "stream_options requires stream" )
+            .with_constraint( "stream = true" )
+        );
+      }
+
+      // Validate parameters that reasoning models fix or replace
+      if validators::is_reasoning_model( &self.model )
+      {
+        // Sampling is fixed; only the default of 1 is accepted
+        if self.temperature.is_some_and( | temperature | ( temperature - 1.0 ).abs() > f32::EPSILON )
+        {
+          errors.push( validators::unsupported_by_reasoning_model( "temperature", &self.model, None ) );
+        }
+        if self.top_p.is_some_and( | top_p | ( top_p - 1.0 ).abs() > f32::EPSILON )
+        {
+          errors.push( validators::unsupported_by_reasoning_model( "top_p", &self.model, None ) );
+        }
+        if self.max_tokens.is_some()
+        {
+          errors.push( validators::unsupported_by_reasoning_model( "max_tokens", &self.model, Some( "max_completion_tokens" ) ) );
+        }
+        if self.logprobs == Some( true )
+        {
+          errors.push( validators::unsupported_by_reasoning_model( "logprobs", &self.model, None ) );
+        }
+        if self.logit_bias.is_some()
+        {
+          errors.push( validators::unsupported_by_reasoning_model( "logit_bias", &self.model, None ) );
+        }
+      }
+
       if errors.is_empty()
       {
         Ok( () )
@@ -125,6 +200,36 @@ mod private
             }
           }
         },
+        crate::components::embeddings_request::EmbeddingInput::Tokens( tokens ) =>
+        {
+          if tokens.is_empty()
+          {
+            errors.push(
+              ValidationError::new( "input", "Token input cannot be empty" )
+                .with_constraint( "at least one token required" )
+            );
+          }
+        },
+        crate::components::embeddings_request::EmbeddingInput::TokenBatch( batch ) =>
+        {
+          if batch.is_empty()
+          {
+            errors.push(
+              ValidationError::new( "input", "Input array cannot be empty" )
+                .with_constraint( "at least one token input required" )
+            );
+          }
+          for ( idx, tokens ) in batch.iter().enumerate()
+          {
+            if tokens.is_empty()
+            {
+              errors.push(
+                ValidationError::new( format!( "input[{idx}]" ), "Token input cannot be empty" )
+                  .with_constraint( "at least one token required" )
+              );
+            }
+          }
+        },
       }
 
       // Validate dimensions if present
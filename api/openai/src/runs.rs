@@ -0,0 +1,317 @@
+// src/runs.rs
+//! This module defines the `Runs` API client, which provides methods for
+//! running assistants on threads of the `OpenAI` Assistants API: creating,
+//! polling, and streaming runs, submitting tool outputs, and reading run steps.
+//!
+//! For more details, refer to the [`OpenAI` Runs API documentation](https://platform.openai.com/docs/api-reference/runs).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  // Use crate root for base access
+  use crate::
+  {
+    client ::Client,
+    error ::{ OpenAIError, Result },
+    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+  };
+  use crate::components::assistants_shared::
+  {
+    RunObject,
+    ListRunsResponse,
+    CreateRunRequest,
+    CreateThreadAndRunRequest,
+    ModifyRunRequest,
+    SubmitToolOutputsRequest,
+    RunStepObject,
+    ListRunStepsResponse,
+    AssistantStreamEvent,
+  };
+  use crate::components::common::ListQuery;
+
+  // External crates
+  use api_sse_stream::SseEvent;
+  use core::time::Duration;
+  use serde_json::Value;
+  use tokio::sync::mpsc;
+  use web_time::Instant;
+
+  /// Reads one server-sent event of a run stream.
+  ///
+  /// The event name says what the data is, so it is put back beside the
+  /// data as the `event` tag of `AssistantStreamEvent`.
+  fn decode_run_event( event : &SseEvent ) -> serde_json::Result< Vec< AssistantStreamEvent > >
+  {
+    let data = serde_json::from_str( &event.data ).unwrap_or_else( | _ | Value::String( event.data.clone() ) );
+    serde_json::from_value( serde_json::json!( { "event" : event.event, "data" : data } ) ).map( | event | vec![ event ] )
+  }
+
+  /// The client for the runs of `OpenAI` Assistants threads.
+  #[ derive( Debug, Clone ) ]
+  pub struct Runs< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : &'client Client< E >,
+  }
+
+  impl< 'client, E > Runs< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Creates a new `Runs` client.
+    ///
+    /// # Arguments
+    /// - `client`: The core `OpenAI` `Client` to use for requests.
+    #[ inline ]
+    pub(crate) fn new( client : &'client Client< E > ) -> Self
+    {
+      Self { client }
+    }
+
+    /// Creates a run on a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to run.
+    /// - `request`: The request body for creating a run.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create( &self, thread_id : &str, request : CreateRunRequest ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Creates a run on a thread and streams its events.
+    ///
+    /// The stream ends after the server's `done` event, which is not sent on.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to run.
+    /// - `request`: The request body for creating a run; `stream` is set.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create_stream( &self, thread_id : &str, mut request : CreateRunRequest ) -> Result< mpsc::Receiver< Result< AssistantStreamEvent > > >
+    {
+      let path = format!( "threads/{thread_id}/runs" );
+      request.stream = Some( true );
+      self.client.post_event_stream( &path, &request, decode_run_event ).await
+    }
+
+    /// Creates a thread and runs it in one request.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for creating the thread and the run.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create_thread_and_run( &self, request : CreateThreadAndRunRequest ) -> Result< RunObject >
+    {
+      self.client.post( "threads/runs", &request ).await
+    }
+
+    /// Creates a thread, runs it, and streams the run's events.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for creating the thread and the run; `stream` is set.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create_thread_and_run_stream( &self, mut request : CreateThreadAndRunRequest ) -> Result< mpsc::Receiver< Result< AssistantStreamEvent > > >
+    {
+      request.stream = Some( true );
+      self.client.post_event_stream( "threads/runs", &request, decode_run_event ).await
+    }
+
+    /// Lists the runs of a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread.
+    /// - `query`: Optional query parameters for listing runs.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list( &self, thread_id : &str, query : Option< ListQuery > ) -> Result< ListRunsResponse >
+    {
+      let path = format!( "threads/{thread_id}/runs" );
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( &path, &q ).await
+      }
+      else
+      {
+        self.client.get( &path ).await
+      }
+    }
+
+    /// Retrieves a run.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve( &self, thread_id : &str, run_id : &str ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Modifies a run.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run to modify.
+    /// - `request`: The request body for modifying the run.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn modify( &self, thread_id : &str, run_id : &str, request : ModifyRunRequest ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Cancels a run that is `in_progress`.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run to cancel.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn cancel( &self, thread_id : &str, run_id : &str ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}/cancel" );
+      self.client.post_no_body( &path ).await
+    }
+
+    /// Submits the outputs of the tool calls a run in `requires_action` waits for.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run waiting for the outputs.
+    /// - `request`: One output per tool call.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn submit_tool_outputs( &self, thread_id : &str, run_id : &str, request : SubmitToolOutputsRequest ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}/submit_tool_outputs" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Submits tool outputs and streams the rest of the run's events.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run waiting for the outputs.
+    /// - `request`: One output per tool call; `stream` is set.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn submit_tool_outputs_stream( &self, thread_id : &str, run_id : &str, mut request : SubmitToolOutputsRequest ) -> Result< mpsc::Receiver< Result< AssistantStreamEvent > > >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}/submit_tool_outputs" );
+      request.stream = Some( true );
+      self.client.post_event_stream( &path, &request, decode_run_event ).await
+    }
+
+    /// Polls a run until it stops being pending, then returns it.
+    ///
+    /// The run is retrieved every `interval` for at most `timeout`; one
+    /// `retrieve` request per poll, nothing else. It returns as soon as the
+    /// run is not `queued`, `in_progress`, or `cancelling`, so a run in
+    /// `requires_action` comes back for its tool outputs.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run to wait for.
+    /// - `interval`: Time between two polls.
+    /// - `timeout`: Time after which a run still pending is an error.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Timeout` if the run is still pending after
+    /// `timeout`, or `OpenAIError` if a poll fails.
+    #[ inline ]
+    pub async fn wait_for_run( &self, thread_id : &str, run_id : &str, interval : Duration, timeout : Duration ) -> Result< RunObject >
+    {
+      let started = Instant::now();
+      loop
+      {
+        let run = self.retrieve( thread_id, run_id ).await?;
+        if !run.is_pending()
+        {
+          return Ok( run );
+        }
+        let elapsed = started.elapsed();
+        let Some( left ) = timeout.checked_sub( elapsed ).filter( | left | !left.is_zero() ) else
+        {
+          return Err( OpenAIError::Timeout( format!( "Run {run_id} still `{}` after {elapsed:?}", run.status ) ).into() );
+        };
+        api_common::sleep( interval.min( left ) ).await;
+      }
+    }
+
+    /// Lists the steps of a run.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run.
+    /// - `query`: Optional query parameters for listing run steps.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_steps( &self, thread_id : &str, run_id : &str, query : Option< ListQuery > ) -> Result< ListRunStepsResponse >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}/steps" );
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( &path, &q ).await
+      }
+      else
+      {
+        self.client.get( &path ).await
+      }
+    }
+
+    /// Retrieves a run step.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread that was run.
+    /// - `run_id`: The ID of the run.
+    /// - `step_id`: The ID of the run step to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve_step( &self, thread_id : &str, run_id : &str, step_id : &str ) -> Result< RunStepObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}/steps/{step_id}" );
+      self.client.get( &path ).await
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  // Expose all structs defined in this module
+  exposed use
+  {
+    Runs,
+  };
+}
@@ -68,6 +68,23 @@ mod private
     Ok( () )
   }
 
+  /// Environment variable, or workspace secret, holding the admin API key.
+  const ADMIN_KEY_NAME : &str = "OPENAI_ADMIN_KEY";
+
+  /// Validates an admin API key: a key in the API key format that starts
+  /// with `sk-admin-`.
+  fn validate_admin_key_format( secret : &str ) -> Result< () >
+  {
+    validate_api_key_format( secret )?;
+    if !secret.trim().starts_with( "sk-admin-" )
+    {
+      return Err( error_tools::Error::from( crate::error::OpenAIError::InvalidArgument(
+        "Admin API key must start with 'sk-admin-' prefix".to_string()
+      ) ) );
+    }
+    Ok( () )
+  }
+
   /// Represents a secret string, such as an API key.
   /// It wraps `secrecy::SecretString` to prevent accidental exposure.
   ///
@@ -86,10 +103,21 @@ mod private
   /// let secret = Secret::new_unchecked("test-key".to_string());
   /// # Ok::<(), Box< dyn core::error::Error > >(())
   /// ```
-  #[ derive( Debug, Clone ) ] // Removed PartialEq, Eq, Hash, PartialOrd, Ord
+  #[ derive( Clone ) ] // Removed PartialEq, Eq, Hash, PartialOrd, Ord
   #[ non_exhaustive ]
   pub struct Secret( SecretString );
 
+  /// Shows the key prefix only, e.g. `Secret("sk-p****")`. Formatting is
+  /// not counted as an exposure and is not audited.
+  impl core::fmt::Debug for Secret
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_tuple( "Secret" ).field( &api_secret::Masked( self.0.expose_secret() ) ).finish()
+    }
+  }
+
   impl Secret
   {
     /// Creates a new `Secret` from a string with validation.
@@ -103,6 +131,19 @@ mod private
       Ok( Self( SecretString::from( secret ) ) )
     }
 
+    /// Creates a new `Secret` from an admin API key, with validation. Only
+    /// admin keys are accepted by the Administration API (`client.admin()`).
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the secret format is invalid
+    /// or the key does not start with `sk-admin-`.
+    #[ inline ]
+    pub fn new_admin( secret : String ) -> Result< Self >
+    {
+      validate_admin_key_format( &secret )?;
+      Ok( Self( SecretString::from( secret ) ) )
+    }
+
     /// Creates a new `Secret` from a string without validation.
     /// This should only be used when the secret format is already known to be valid.
     ///
@@ -173,10 +214,11 @@ mod private
 
     /// Load secret with comprehensive fallback chain using `workspace_tools`
     ///
-    /// Priority order:
-    /// 1. Environment variable (fastest)
-    /// 2. Workspace secrets file (main secrets file)
-    /// 3. Alternative secrets files
+    /// Uses the chain shared by every `api_llm` provider crate
+    /// (`api_secret::SecretChain::standard`). Priority order:
+    /// 1. Workspace secrets file (`secret/-secrets.sh`)
+    /// 2. Alternative secrets files (`secrets.sh`, `.env`)
+    /// 3. Environment variable
     ///
     /// # Arguments
     /// - `key_name`: The name of the secret key to load (e.g., "`OPENAI_API_KEY`")
@@ -189,37 +231,70 @@ mod private
     /// ```no_run
     /// use api_openai::Secret;
     ///
-    /// // Tries environment variable first, then workspace secrets
+    /// // Tries workspace secrets first, then the environment variable
     /// let secret = Secret::load_with_fallbacks("OPENAI_API_KEY")?;
     /// # Ok::<(), Box< dyn std::error::Error > >(())
     /// ```
     #[ inline ]
     pub fn load_with_fallbacks( key_name : &str ) -> Result< Self >
     {
-      // 1. Try environment variable first (fastest)
-      if let Ok( secret ) = Self::load_from_env( key_name )
-      {
-        return Ok( secret );
-      }
-
-      // 2. Try workspace_tools with default secrets file
-      if let Ok( secret ) = Self::load_from_workspace( key_name, "-secrets.sh" )
-      {
-        return Ok( secret );
-      }
-
-      // 3. Try alternative secrets files
-      for filename in [ "secrets.sh", ".env" ]
-      {
-        if let Ok( secret ) = Self::load_from_workspace( key_name, filename )
-        {
-          return Ok( secret );
-        }
-      }
-
-      Err( error_tools::Error::from( crate::error::OpenAIError::MissingEnvironment(
-        format!( "{key_name} not found in environment or workspace secrets. Please add it to your environment variables or secret/-secrets.sh file" )
-      ) ) )
+      Self::load_from_chain( key_name, &api_secret::SecretChain::standard() )
+    }
+
+    /// Load secret from a caller-built chain, e.g. a Vault or AWS Secrets
+    /// Manager fetch placed ahead of the standard chain.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingEnvironment` if no source in `chain` holds the key
+    /// or the value has an invalid format.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_openai::Secret;
+    /// use api_secret::{ MapSource, SecretChain };
+    ///
+    /// let chain = SecretChain::new()
+    ///   .with( MapSource::new( "fixture" ).with( "OPENAI_API_KEY", "sk-test-key" ) )
+    ///   .then( SecretChain::standard() );
+    /// let secret = Secret::load_from_chain( "OPENAI_API_KEY", &chain )?;
+    /// # Ok::<(), Box< dyn std::error::Error > >(())
+    /// ```
+    #[ inline ]
+    pub fn load_from_chain( key_name : &str, chain : &api_secret::SecretChain ) -> Result< Self >
+    {
+      Self::load_checked( key_name, chain, Self::new )
+    }
+
+    /// Loads the admin API key from `OPENAI_ADMIN_KEY`, through the same
+    /// workspace secrets and environment chain as `load_with_fallbacks`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingEnvironment` if the key is not found, or
+    /// is not an admin key (`sk-admin-...`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_openai::Secret;
+    ///
+    /// let secret = Secret::load_admin_key()?;
+    /// # Ok::<(), Box< dyn std::error::Error > >(())
+    /// ```
+    #[ inline ]
+    pub fn load_admin_key() -> Result< Self >
+    {
+      Self::load_checked( ADMIN_KEY_NAME, &api_secret::SecretChain::standard(), Self::new_admin )
+    }
+
+    /// Loads `key_name` from `chain` and checks its value with `check`.
+    fn load_checked( key_name : &str, chain : &api_secret::SecretChain, check : fn( String ) -> Result< Self > ) -> Result< Self >
+    {
+      let secret_string = chain.load( key_name )
+        .map_err( | e | error_tools::Error::from( crate::error::OpenAIError::MissingEnvironment( format!( "{e}. Please add it to your environment variables or secret/-secrets.sh file" ) ) ) )?;
+
+      check( secret_string.expose_secret().trim().to_string() )
+        .map_err( | e | error_tools::Error::from( crate::error::OpenAIError::MissingEnvironment( format!( "Invalid secret format for {key_name}: {e}" ) ) ) )
     }
 
     /// Get the total number of secret exposures that have occurred
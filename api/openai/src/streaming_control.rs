@@ -17,7 +17,7 @@ mod private
     time ::Duration,
   };
   use serde::{ Deserialize, Serialize };
-  use tokio::{ sync::mpsc, time };
+  use tokio::sync::mpsc;
 
   /// Stream control state for tracking operations
   #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
@@ -82,7 +82,7 @@ mod private
         {
           return true;
         }
-        time ::sleep( Duration::from_millis( 10 ) ).await;
+        api_common::sleep( Duration::from_millis( 10 ) ).await;
       }
       false
     }
@@ -277,7 +277,7 @@ mod private
     {
       while !token.is_cancelled()
       {
-        time ::sleep( Duration::from_millis( 10 ) ).await;
+        api_common::sleep( Duration::from_millis( 10 ) ).await;
       }
     }
 
@@ -289,9 +289,9 @@ mod private
       let token = CancellationToken::new();
       let token_clone = token.clone();
 
-      tokio ::spawn( async move
+      api_common::spawn( async move
       {
-        time ::sleep( timeout ).await;
+        api_common::sleep( timeout ).await;
         token_clone.cancel();
       });
 
@@ -306,7 +306,7 @@ mod private
       let combined = CancellationToken::new();
       let combined_clone = combined.clone();
 
-      tokio ::spawn( async move
+      api_common::spawn( async move
       {
         loop
         {
@@ -315,7 +315,7 @@ mod private
             combined_clone.cancel();
             break;
           }
-          time ::sleep( Duration::from_millis( 10 ) ).await;
+          api_common::sleep( Duration::from_millis( 10 ) ).await;
         }
       });
 
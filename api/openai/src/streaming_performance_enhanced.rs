@@ -618,7 +618,7 @@ mod private
     {
       let duration = self.start_time.elapsed();
       let stats = self.stats.clone();
-      tokio ::spawn( async move
+      api_common::spawn( async move
       {
         if let Ok( mut stats ) = stats.try_write()
         {
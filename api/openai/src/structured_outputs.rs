@@ -0,0 +1,168 @@
+// src/structured_outputs.rs
+//! This module provides helpers for Structured Outputs: a strict `json_schema`
+//! response format generated from a Rust type with `schemars`, and the parsing
+//! of the assistant message of a chat completion back into that type.
+//!
+//! For more details, refer to the [`OpenAI` Structured Outputs guide](https://platform.openai.com/docs/guides/structured-outputs).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::
+  {
+    error ::{ OpenAIError, Result },
+    components ::common::{ ResponseFormatJsonSchema, ResponseFormatJsonSchemaSchema, ResponseFormatJsonSchemaSchemaDetails },
+    components ::chat_shared::CreateChatCompletionResponse,
+  };
+  use schemars::{ gen::SchemaSettings, JsonSchema };
+  use serde::de::DeserializeOwned;
+  use serde_json::Value;
+
+  /// The `format` values that strict mode accepts; others are dropped.
+  const STRICT_FORMATS : &[ &str ] = &[ "date-time", "time", "date", "duration", "email", "hostname", "ipv4", "ipv6", "uuid" ];
+
+  /// The longest name a response format may have.
+  const MAX_NAME_LEN : usize = 64;
+
+  /// Generates a strict `json_schema` response format from the type `T`.
+  ///
+  /// The schema is reshaped for strict mode: every object lists all of its
+  /// properties as required and forbids additional ones, an `Option` field
+  /// becomes nullable, and `default` and unsupported `format` keywords are
+  /// dropped. The name comes from the schema name of `T`, and the description
+  /// from its doc comment.
+  ///
+  /// The result goes into `ChatCompletionRequest::response_format` through
+  /// `into()`, or into `TextResponseFormatConfigurationOptions::JsonSchema`
+  /// for the Responses API.
+  #[ must_use ]
+  #[ inline ]
+  pub fn response_format_for< T : JsonSchema >() -> ResponseFormatJsonSchema
+  {
+    let mut settings = SchemaSettings::draft2019_09();
+    settings.meta_schema = None;
+    let root = settings.into_generator().into_root_schema_for::< T >();
+    let mut schema = serde_json::to_value( root ).unwrap_or_default();
+    strict_schema( &mut schema );
+    let description = schema.get( "description" ).and_then( Value::as_str ).map( ToString::to_string );
+
+    ResponseFormatJsonSchema
+    {
+      r#type : "json_schema".to_string(),
+      json_schema : ResponseFormatJsonSchemaSchemaDetails
+      {
+        name : format_name( &T::schema_name() ),
+        description,
+        schema : ResponseFormatJsonSchemaSchema( schema ),
+        strict : Some( true ),
+      },
+    }
+  }
+
+  /// Deserializes the assistant message of the first choice into `T`.
+  ///
+  /// # Errors
+  /// Returns `OpenAIError::Internal` naming the cause when the response has no
+  /// choices, the model refused, the message has no content, or the content
+  /// does not deserialize into `T` (noting when generation stopped at the
+  /// token limit).
+  #[ inline ]
+  pub fn parse_structured< T : DeserializeOwned >( response : &CreateChatCompletionResponse ) -> Result< T >
+  {
+    let type_name = core::any::type_name::< T >();
+    let Some( choice ) = response.choices.first() else
+    {
+      return Err( OpenAIError::Internal( format!( "Structured output : the response has no choices to read a `{type_name}` from" ) ).into() );
+    };
+    if let Some( refusal ) = &choice.message.refusal
+    {
+      return Err( OpenAIError::Internal( format!( "Structured output : the model refused : {refusal}" ) ).into() );
+    }
+    let Some( content ) = choice.message.content.as_deref() else
+    {
+      return Err( OpenAIError::Internal( format!( "Structured output : the message has no content (finish reason `{}`)", choice.finish_reason ) ).into() );
+    };
+    serde_json::from_str( content ).map_err( | e |
+    {
+      let truncated = if choice.finish_reason == "length" { ", the output was cut at the token limit" } else { "" };
+      OpenAIError::Internal( format!( "Structured output : the content is not a `{type_name}`{truncated} : {e}" ) ).into()
+    })
+  }
+
+  /// A schema name limited to the characters and length a format name allows.
+  fn format_name( schema_name : &str ) -> String
+  {
+    schema_name
+    .chars()
+    .map( | c | if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '_' } )
+    .take( MAX_NAME_LEN )
+    .collect()
+  }
+
+  /// Reshapes a generated schema, in place, into one strict mode accepts.
+  fn strict_schema( schema : &mut Value )
+  {
+    let map = match schema
+    {
+      Value::Object( map ) => map,
+      Value::Array( items ) =>
+      {
+        items.iter_mut().for_each( strict_schema );
+        return;
+      },
+      _ => return,
+    };
+
+    map.remove( "default" );
+    if map.get( "format" ).and_then( Value::as_str ).is_some_and( | format | !STRICT_FORMATS.contains( &format ) )
+    {
+      map.remove( "format" );
+    }
+    // A `$ref` with a doc comment comes wrapped in a lone `allOf`.
+    if let Some( Value::Array( all_of ) ) = map.get( "allOf" )
+    {
+      if let [ Value::Object( inner ) ] = all_of.as_slice()
+      {
+        let inner = inner.clone();
+        map.remove( "allOf" );
+        for ( key, value ) in inner
+        {
+          map.entry( key ).or_insert( value );
+        }
+      }
+    }
+    if let Some( Value::Object( properties ) ) = map.get( "properties" )
+    {
+      let required = properties.keys().cloned().map( Value::String ).collect();
+      map.insert( "required".to_string(), Value::Array( required ) );
+      map.insert( "additionalProperties".to_string(), Value::Bool( false ) );
+    }
+
+    for ( key, value ) in map.iter_mut()
+    {
+      match key.as_str()
+      {
+        // Maps from a name to a schema.
+        "properties" | "$defs" | "definitions" | "patternProperties" =>
+        {
+          if let Value::Object( named ) = value
+          {
+            named.values_mut().for_each( strict_schema );
+          }
+        },
+        // Values, not schemas.
+        "enum" | "const" | "required" | "examples" => {},
+        _ => strict_schema( value ),
+      }
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    response_format_for,
+    parse_structured,
+  };
+}
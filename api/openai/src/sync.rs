@@ -16,7 +16,7 @@ mod private
   };
   use crate::components::
   {
-    chat_shared ::{ ChatCompletionRequest, CreateChatCompletionResponse, ChatCompletionStreamResponse },
+    chat_shared ::{ ChatCompletionRequest, CreateChatCompletionResponse, ChatCompletionChunk },
     embeddings_request ::CreateEmbeddingRequest,
     embeddings ::CreateEmbeddingResponse,
     models ::{ ListModelsResponse, Model },
@@ -196,7 +196,7 @@ mod private
     /// # Example
     /// ```rust,no_run
     /// # use api_openai::{ SyncClient, environment::OpenaiEnvironmentImpl };
-    /// # use api_openai::components::chat_shared::ChatCompletionRequest;
+    /// # use api_openai::components::chat_shared::{ ChatCompletionChunk, ChatCompletionRequest };
     /// # fn main() -> Result< (), Box< dyn std::error::Error > > {
     /// # let environment = OpenaiEnvironmentImpl::build(
     /// #   api_openai::secret::Secret::new_unchecked("test".to_string()),
@@ -210,14 +210,8 @@ mod private
     /// let stream = client.chat().create_stream( request )?;
     /// for chunk in stream {
     ///   match chunk {
-    ///     Ok(response) =>
-    ///     {
-    ///       if let Some(choice) = response.choices.first() {
-    ///         if let Some(content) = &choice.delta.content {
-    ///           print!("{}", content);
-    ///         }
-    ///       }
-    ///     }
+    ///     Ok(ChatCompletionChunk::Content { text, .. }) => print!("{}", text),
+    ///     Ok(_) => {}
     ///     Err(e) =>
     ///     {
     ///       eprintln!("Stream error : {}", e);
@@ -229,7 +223,7 @@ mod private
     /// # }
     /// ```
     #[ inline ]
-    pub fn create_stream( &self, request : ChatCompletionRequest ) -> Result< SyncStreamIterator< ChatCompletionStreamResponse > >
+    pub fn create_stream( &self, request : ChatCompletionRequest ) -> Result< SyncStreamIterator< ChatCompletionChunk > >
     {
       // Start the async stream in the runtime and get the receiver
       let runtime = self.client.runtime.clone();
@@ -253,7 +247,7 @@ mod private
       &self,
       request : ChatCompletionRequest,
       config : StreamConfig
-    ) -> Result< SyncStreamIterator< ChatCompletionStreamResponse > >
+    ) -> Result< SyncStreamIterator< ChatCompletionChunk > >
     {
       // Start the async stream in the runtime and get the receiver
       let runtime = self.client.runtime.clone();
@@ -284,16 +278,8 @@ mod private
       {
         match chunk
         {
-          Ok( response ) =>
-          {
-            if let Some( choice ) = response.choices.first()
-            {
-              if let Some( content ) = &choice.delta.content
-              {
-                result.push_str( content );
-              }
-            }
-          }
+          Ok( ChatCompletionChunk::Content { choice : 0, text } ) => result.push_str( &text ),
+          Ok( _ ) => {},
           Err( e ) => return Err( e ),
         }
       }
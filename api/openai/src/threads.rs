@@ -0,0 +1,242 @@
+// src/threads.rs
+//! This module defines the `Threads` and `Messages` API clients, which provide
+//! methods for interacting with the threads of the `OpenAI` Assistants API.
+//!
+//! For more details, refer to the [`OpenAI` Threads API documentation](https://platform.openai.com/docs/api-reference/threads).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  // Use crate root for base access
+  use crate::
+  {
+    client ::Client,
+    error ::Result,
+    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    runs ::Runs,
+  };
+  use crate::components::assistants_shared::
+  {
+    ThreadObject,
+    CreateThreadRequest,
+    ModifyThreadRequest,
+    MessageObject,
+    CreateMessageRequest,
+    ModifyMessageRequest,
+    ListMessagesResponse,
+  };
+  use crate::components::common::{ ListQuery, DeleteThreadResponse, DeleteMessageResponse };
+
+  /// The client for the `OpenAI` Threads API.
+  #[ derive( Debug, Clone ) ]
+  pub struct Threads< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : &'client Client< E >,
+  }
+
+  impl< 'client, E > Threads< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Creates a new `Threads` client.
+    ///
+    /// # Arguments
+    /// - `client`: The core `OpenAI` `Client` to use for requests.
+    #[ inline ]
+    pub(crate) fn new( client : &'client Client< E > ) -> Self
+    {
+      Self { client }
+    }
+
+    /// Returns the client for the messages of threads.
+    #[ inline ]
+    #[ must_use ]
+    pub fn messages( &self ) -> Messages< 'client, E >
+    {
+      Messages::new( self.client )
+    }
+
+    /// Returns the client for the runs of threads and their steps.
+    #[ inline ]
+    #[ must_use ]
+    pub fn runs( &self ) -> Runs< 'client, E >
+    {
+      Runs::new( self.client )
+    }
+
+    /// Creates a thread.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for creating a thread.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create( &self, request : CreateThreadRequest ) -> Result< ThreadObject >
+    {
+      self.client.post( "threads", &request ).await
+    }
+
+    /// Retrieves a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve( &self, thread_id : &str ) -> Result< ThreadObject >
+    {
+      let path = format!( "threads/{thread_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Modifies a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to modify.
+    /// - `request`: The request body for modifying the thread.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn modify( &self, thread_id : &str, request : ModifyThreadRequest ) -> Result< ThreadObject >
+    {
+      let path = format!( "threads/{thread_id}" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Deletes a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to delete.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn delete( &self, thread_id : &str ) -> Result< DeleteThreadResponse >
+    {
+      let path = format!( "threads/{thread_id}" );
+      self.client.delete( &path ).await
+    }
+  }
+
+  /// The client for the messages of `OpenAI` Assistants threads.
+  #[ derive( Debug, Clone ) ]
+  pub struct Messages< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : &'client Client< E >,
+  }
+
+  impl< 'client, E > Messages< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Creates a new `Messages` client.
+    ///
+    /// # Arguments
+    /// - `client`: The core `OpenAI` `Client` to use for requests.
+    #[ inline ]
+    pub(crate) fn new( client : &'client Client< E > ) -> Self
+    {
+      Self { client }
+    }
+
+    /// Creates a message in a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread to add the message to.
+    /// - `request`: The request body for creating a message.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create( &self, thread_id : &str, request : CreateMessageRequest ) -> Result< MessageObject >
+    {
+      let path = format!( "threads/{thread_id}/messages" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Lists the messages of a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread.
+    /// - `query`: Optional query parameters for listing messages.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list( &self, thread_id : &str, query : Option< ListQuery > ) -> Result< ListMessagesResponse >
+    {
+      let path = format!( "threads/{thread_id}/messages" );
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( &path, &q ).await
+      }
+      else
+      {
+        self.client.get( &path ).await
+      }
+    }
+
+    /// Retrieves a message.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread the message belongs to.
+    /// - `message_id`: The ID of the message to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve( &self, thread_id : &str, message_id : &str ) -> Result< MessageObject >
+    {
+      let path = format!( "threads/{thread_id}/messages/{message_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Modifies a message.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread the message belongs to.
+    /// - `message_id`: The ID of the message to modify.
+    /// - `request`: The request body for modifying the message.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn modify( &self, thread_id : &str, message_id : &str, request : ModifyMessageRequest ) -> Result< MessageObject >
+    {
+      let path = format!( "threads/{thread_id}/messages/{message_id}" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Deletes a message.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread the message belongs to.
+    /// - `message_id`: The ID of the message to delete.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn delete( &self, thread_id : &str, message_id : &str ) -> Result< DeleteMessageResponse >
+    {
+      let path = format!( "threads/{thread_id}/messages/{message_id}" );
+      self.client.delete( &path ).await
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  // Expose all structs defined in this module
+  exposed use
+  {
+    Threads,
+    Messages,
+  };
+}
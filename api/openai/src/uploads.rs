@@ -3,6 +3,10 @@
 //! This module provides comprehensive file upload and management functionality for the `OpenAI` API.
 //! Following the "Thin Client, Rich API" principle, this module offers file operations
 //! patterns and upload tools without automatic behaviors or persistent state management.
+//!
+//! Files larger than a single request allows go through the Uploads API
+//! (`/v1/uploads`): create an Upload, add its parts, then complete it into a
+//! File. `Uploads::upload_file_in_parts` runs the three steps for a local file.
 
 use mod_interface::mod_interface;
 
@@ -14,6 +18,8 @@ mod private
     environment ::{ EnvironmentInterface, OpenaiEnvironment },
     error ::{ OpenAIError, Result },
   };
+  use crate::components::files::FilePurpose;
+  use crate::components::uploads::{ Upload, UploadPart, CreateUploadRequest, CompleteUploadRequest };
   use std::
   {
     path ::Path,
@@ -22,6 +28,8 @@ mod private
   };
   use serde::{ Deserialize, Serialize };
   use reqwest::multipart::{ Form, Part };
+  use futures_util::{ stream, TryStreamExt };
+  use tokio::io::{ AsyncRead, AsyncReadExt };
 
   /// File object returned by the `OpenAI` Files API
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
@@ -123,6 +131,66 @@ mod private
     }
   }
 
+  /// The largest part the Uploads API accepts: 64 MB.
+  pub const MAX_UPLOAD_PART_SIZE : usize = 64 * 1024 * 1024;
+
+  /// How a file is cut into parts and how many parts are sent at once.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct PartUploadConfig
+  {
+    /// Bytes per part, at most `MAX_UPLOAD_PART_SIZE`; only the last part is smaller (default : 8MB).
+    pub part_size : usize,
+    /// Maximum number of parts in flight at the same time (default : 4).
+    pub max_concurrency : usize,
+  }
+
+  impl Default for PartUploadConfig
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self
+      {
+        part_size : 8 * 1024 * 1024, // 8MB
+        max_concurrency : 4,
+      }
+    }
+  }
+
+  impl PartUploadConfig
+  {
+    /// Checks that parts have between 1 byte and `MAX_UPLOAD_PART_SIZE`,
+    /// and that at least one part can be in flight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAIError::InvalidArgument` naming the value out of range.
+    #[ inline ]
+    pub fn validate( &self ) -> Result< () >
+    {
+      if self.part_size == 0 || self.part_size > MAX_UPLOAD_PART_SIZE
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Part size must be between 1 and {MAX_UPLOAD_PART_SIZE} bytes, got {}", self.part_size ) ).into() );
+      }
+      if self.max_concurrency == 0
+      {
+        return Err( OpenAIError::InvalidArgument( "Max concurrency must be at least 1".to_string() ).into() );
+      }
+      Ok( () )
+    }
+  }
+
+  /// Reads the next part of at most `part_size` bytes; `None` at the end.
+  async fn read_part< R >( reader : &mut R, part_size : usize ) -> Result< Option< Vec< u8 > > >
+  where
+    R : AsyncRead + Unpin,
+  {
+    let mut part = Vec::with_capacity( part_size );
+    reader.take( part_size as u64 ).read_to_end( &mut part ).await
+      .map_err( | e | OpenAIError::File( format!( "Failed to read upload part : {e}" ) ) )?;
+    Ok( ( !part.is_empty() ).then_some( part ) )
+  }
+
   /// Uploads API implementation
   #[ derive( Debug ) ]
   pub struct Uploads< 'client, E >
@@ -244,11 +312,11 @@ mod private
     {
       let path = if let Some( purpose_filter ) = purpose
       {
-        format!( "/files?purpose={purpose_filter}" )
+        format!( "files?purpose={purpose_filter}" )
       }
       else
       {
-        "/files".to_string()
+        "files".to_string()
       };
 
       let list_response : ListFilesResponse = self.client.get( &path ).await?;
@@ -265,7 +333,7 @@ mod private
     #[ inline ]
     pub async fn get_file( &self, file_id : &str ) -> Result< FileObject >
     {
-      let path = format!( "/files/{file_id}" );
+      let path = format!( "files/{file_id}" );
       let file_object : FileObject = self.client.get( &path ).await?;
       Ok( file_object )
     }
@@ -279,7 +347,7 @@ mod private
     #[ inline ]
     pub async fn delete_file( &self, file_id : &str ) -> Result< DeleteFileResponse >
     {
-      let path = format!( "/files/{file_id}" );
+      let path = format!( "files/{file_id}" );
       let delete_response : DeleteFileResponse = self.client.delete( &path ).await?;
       Ok( delete_response )
     }
@@ -293,9 +361,144 @@ mod private
     #[ inline ]
     pub async fn download_file( &self, file_id : &str ) -> Result< Vec< u8 > >
     {
-      let path = format!( "/files/{file_id}/content" );
+      let path = format!( "files/{file_id}/content" );
       self.client.get_bytes( &path ).await
     }
+
+    /// Creates an Upload, the object the parts of a large file are added to.
+    /// It expires one hour after creation if not completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn create_upload( &self, request : CreateUploadRequest ) -> Result< Upload >
+    {
+      self.client.post( "uploads", &request ).await
+    }
+
+    /// Adds one part, at most `MAX_UPLOAD_PART_SIZE` bytes, to an Upload.
+    /// Parts can be added in parallel; their order is set on completion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn add_part( &self, upload_id : &str, data : Vec< u8 > ) -> Result< UploadPart >
+    {
+      let data_part = Part::bytes( data )
+        .file_name( "part" )
+        .mime_str( "application/octet-stream" )
+        .map_err( | e | OpenAIError::Internal( format!( "Failed to create data part : {e}" ) ) )?;
+      let form = Form::new().part( "data", data_part );
+
+      let path = format!( "uploads/{upload_id}/parts" );
+      self.client.post_multipart( &path, form ).await
+    }
+
+    /// Cuts `reader` into parts of `config.part_size` bytes and adds them to
+    /// an Upload, with at most `config.max_concurrency` parts in flight.
+    ///
+    /// Only the parts in flight are held in memory. The parts are returned
+    /// in the order of their bytes, ready for `complete_upload`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` is out of range, if `reader` fails, or on
+    /// the first part that fails; parts already added stay on the Upload.
+    #[ inline ]
+    pub async fn add_parts< R >( &self, upload_id : &str, reader : R, config : PartUploadConfig ) -> Result< Vec< UploadPart > >
+    where
+      R : AsyncRead + Unpin,
+    {
+      config.validate()?;
+      let parts = stream::try_unfold( reader, move | mut reader | async move
+      {
+        let part = read_part( &mut reader, config.part_size ).await?;
+        Ok( part.map( | part | ( part, reader ) ) )
+      } );
+      parts
+        .map_ok( | part | self.add_part( upload_id, part ) )
+        .try_buffered( config.max_concurrency )
+        .try_collect()
+        .await
+    }
+
+    /// Completes an Upload into a File, assembling the parts in the order of
+    /// `request.part_ids`. The parts must add up to the bytes announced on
+    /// creation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn complete_upload( &self, upload_id : &str, request : CompleteUploadRequest ) -> Result< Upload >
+    {
+      let path = format!( "uploads/{upload_id}/complete" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Cancels an Upload; no part can be added to it afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or if the response cannot be parsed.
+    #[ inline ]
+    pub async fn cancel_upload( &self, upload_id : &str ) -> Result< Upload >
+    {
+      let path = format!( "uploads/{upload_id}/cancel" );
+      self.client.post_no_body( &path ).await
+    }
+
+    /// Uploads a local file through the Uploads API: creates the Upload,
+    /// adds the file in parts as `add_parts` does, and completes it.
+    ///
+    /// If a part fails, the Upload this call created is cancelled before the
+    /// error is returned, rather than left pending until it expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `config` is out of range, if the file cannot be
+    /// read, or if an API request fails.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn upload_file_in_parts< P : AsRef< Path > >(
+      &self,
+      file_path : P,
+      purpose : FilePurpose,
+      mime_type : &str,
+      config : PartUploadConfig,
+    ) -> Result< Upload >
+    {
+      let path = file_path.as_ref();
+      config.validate()?;
+
+      let file = tokio::fs::File::open( path ).await
+        .map_err( | e | OpenAIError::File( format!( "Failed to open file : {e}" ) ) )?;
+      let bytes = file.metadata().await
+        .map_err( | e | OpenAIError::File( format!( "Failed to read file metadata : {e}" ) ) )?
+        .len();
+      let filename = path.file_name()
+        .and_then( | name | name.to_str() )
+        .unwrap_or( "upload" )
+        .to_string();
+
+      let upload = self.create_upload( CreateUploadRequest::new( filename, purpose, bytes, mime_type ) ).await?;
+      match self.add_parts( &upload.id, file, config ).await
+      {
+        Ok( parts ) =>
+        {
+          let part_ids = parts.into_iter().map( | part | part.id ).collect();
+          self.complete_upload( &upload.id, CompleteUploadRequest::new( part_ids ) ).await
+        }
+        Err( error ) =>
+        {
+          // The part error is the one worth reporting; a failed cancel only leaves the Upload to expire.
+          let _ = self.cancel_upload( &upload.id ).await;
+          Err( error )
+        }
+      }
+    }
   }
 
   #[ cfg( test ) ]
@@ -373,6 +576,8 @@ mod_interface!
     DeleteFileResponse,
     ListFilesResponse,
     UploadConfig,
+    PartUploadConfig,
+    MAX_UPLOAD_PART_SIZE,
     Uploads,
   };
 }
@@ -0,0 +1,186 @@
+// src/usage.rs
+//! This module defines the `Usage` API client, which provides methods
+//! for interacting with the `OpenAI` Usage and Costs API: the usage of the
+//! organization per endpoint, and its costs, in time buckets grouped and
+//! filtered by project, user, API key, or model.
+//!
+//! These endpoints only accept an admin API key, see `Secret::load_admin_key`.
+//!
+//! For more details, refer to the [`OpenAI` Usage API documentation](https://platform.openai.com/docs/api-reference/usage).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  // Use crate root for base access
+  use crate::
+  {
+    client ::Client,
+    error ::Result,
+    environment ::{ OpenaiEnvironment, EnvironmentInterface },
+  };
+  use crate::components::usage_shared::
+  {
+    CostsQuery,
+    UsageQuery,
+    UsageResponse,
+  };
+
+  /// The client for the `OpenAI` Usage and Costs API.
+  ///
+  /// Every report is one page of time buckets; the `next_page` of a page is
+  /// passed as the `page` of the query for the next one.
+  #[ derive( Debug, Clone ) ]
+  pub struct Usage< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    client : &'client Client< E >,
+  }
+
+  impl< 'client, E > Usage< 'client, E >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    /// Creates a new `Usage` client.
+    ///
+    /// # Arguments
+    /// - `client`: The core `OpenAI` `Client` to use for requests.
+    #[ inline ]
+    pub(crate) fn new( client : &'client Client< E > ) -> Self
+    {
+      Self { client }
+    }
+
+    /// Reports the usage of completions: input, cached, and output tokens,
+    /// and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn completions( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/completions", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of embeddings: input tokens and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn embeddings( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/embeddings", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of moderations: input tokens and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn moderations( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/moderations", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of images: images and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn images( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/images", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of speech synthesis: characters and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn audio_speeches( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/audio_speeches", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of transcriptions: seconds and requests.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, filters, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn audio_transcriptions( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/audio_transcriptions", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of vector stores: bytes stored.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, project filter, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn vector_stores( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/vector_stores", &query.query_pairs() ).await
+    }
+
+    /// Reports the usage of code interpreter: sessions.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, project filter, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn code_interpreter_sessions( &self, query : &UsageQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/usage/code_interpreter_sessions", &query.query_pairs() ).await
+    }
+
+    /// Reports the costs of the organization, in daily buckets.
+    ///
+    /// # Arguments
+    /// - `query`: The time range, project filter, and grouping of the report.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn costs( &self, query : &CostsQuery ) -> Result< UsageResponse >
+    {
+      self.report( "organization/costs", &query.query_pairs() ).await
+    }
+
+    async fn report( &self, path : &str, pairs : &[ ( &'static str, String ) ] ) -> Result< UsageResponse >
+    {
+      self.client.get_with_query( path, pairs ).await
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  // Expose all structs defined in this module
+  exposed use
+  {
+    Usage,
+  };
+}
@@ -1,6 +1,7 @@
-// src/api/vector_stores.rs
-//! This module defines the `serde_json::Values` API client, which provides methods
-//! for interacting with the `OpenAI` Vector Stores API.
+// src/vector_stores.rs
+//! This module defines the `VectorStores` API client, which provides methods
+//! for interacting with the `OpenAI` Vector Stores API: the stores themselves,
+//! the files attached to them, file batches, and search.
 //!
 //! For more details, refer to the [OpenAI Vector Stores API documentation](https://platform.openai.com/docs/api-reference/vector-stores).
 
@@ -13,14 +14,26 @@ mod private
     client ::Client,
     error ::Result,
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
+    pagination ::CursorPage,
   };
-  // Vector stores components are not fully implemented
-  // Using basic types for now
-  use crate::components::common::ListQuery;
-
-  // External crates
-
-  use serde_json;
+  use crate::components::vector_stores_shared::
+  {
+    VectorStoreObject,
+    ListVectorStoresResponse,
+    CreateVectorStoreRequest,
+    ModifyVectorStoreRequest,
+    VectorStoreFileObject,
+    ListVectorStoreFilesResponse,
+    CreateVectorStoreFileRequest,
+    UpdateVectorStoreFileAttributesRequest,
+    VectorStoreFileContentResponse,
+    VectorStoreFileBatchObject,
+    CreateVectorStoreFileBatchRequest,
+    VectorStoreSearchRequest,
+    VectorStoreSearchResultsPage,
+  };
+  use crate::components::common::{ DeleteVectorStoreResponse, DeleteVectorStoreFileResponse };
+  use crate::components::query::ListQuery;
 
   /// The client for the `OpenAI` Vector Stores API.
   #[ derive( Debug, Clone ) ]
@@ -53,7 +66,7 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn create( &self, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn create( &self, request : CreateVectorStoreRequest ) -> Result< VectorStoreObject >
     {
       self.client.post( "vector_stores", &request ).await
     }
@@ -66,9 +79,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list( &self, query : Option< ListQuery > ) -> Result< serde_json::Value >
+    pub async fn list( &self, query : Option< ListQuery > ) -> Result< ListVectorStoresResponse >
     {
-      let path = "/vector_stores";
+      let path = "vector_stores";
       if let Some( q ) = query
       {
         self.client.get_with_query( path, &q ).await
@@ -79,6 +92,22 @@ mod private
       }
     }
 
+    /// Fetches the first page of the vector stores, as a `CursorPage` that fetches
+    /// the pages after it with the same `limit`.
+    ///
+    /// # Arguments
+    /// - `query`: Optional pagination parameters of the first page.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn list_page( &self, query : Option< ListQuery > ) -> Result< CursorPage< VectorStoreObject > >
+    where
+      E : Clone,
+    {
+      CursorPage::first( self.client.clone(), query.unwrap_or_else( || ListQuery::former().form() ), list_vector_stores_after ).await
+    }
+
     /// Retrieves a vector store.
     ///
     /// # Arguments
@@ -87,9 +116,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn retrieve( &self, vector_store_id : &str ) -> Result< serde_json::Value >
+    pub async fn retrieve( &self, vector_store_id : &str ) -> Result< VectorStoreObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}" );
+      let path = format!( "vector_stores/{vector_store_id}" );
       self.client.get( &path ).await
     }
 
@@ -102,13 +131,13 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn modify( &self, vector_store_id : &str, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn modify( &self, vector_store_id : &str, request : ModifyVectorStoreRequest ) -> Result< VectorStoreObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}" );
+      let path = format!( "vector_stores/{vector_store_id}" );
       self.client.post( &path, &request ).await
     }
 
-    /// Deletes a vector store.
+    /// Deletes a vector store. The files stay in the Files API.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store to delete.
@@ -116,28 +145,43 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn delete( &self, vector_store_id : &str ) -> Result< serde_json::Value >
+    pub async fn delete( &self, vector_store_id : &str ) -> Result< DeleteVectorStoreResponse >
     {
-      let path = format!( "/vector_stores/{vector_store_id}" );
+      let path = format!( "vector_stores/{vector_store_id}" );
       self.client.delete( &path ).await
     }
 
-    /// Creates a vector store file.
+    /// Searches a vector store for the chunks most relevant to a query.
     ///
     /// # Arguments
-    /// - `vector_store_id`: The ID of the vector store to create the file for.
-    /// - `request`: The request body for creating a vector store file.
+    /// - `vector_store_id`: The ID of the vector store to search.
+    /// - `request`: The query, with optional attribute filters and ranking options.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn create_file( &self, vector_store_id : &str, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn search( &self, vector_store_id : &str, request : VectorStoreSearchRequest ) -> Result< VectorStoreSearchResultsPage >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/files" );
+      let path = format!( "vector_stores/{vector_store_id}/search" );
       self.client.post( &path, &request ).await
     }
 
-    /// Lists vector store files.
+    /// Attaches a file to a vector store.
+    ///
+    /// # Arguments
+    /// - `vector_store_id`: The ID of the vector store to attach the file to.
+    /// - `request`: The request body naming the file.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn create_file( &self, vector_store_id : &str, request : CreateVectorStoreFileRequest ) -> Result< VectorStoreFileObject >
+    {
+      let path = format!( "vector_stores/{vector_store_id}/files" );
+      self.client.post( &path, &request ).await
+    }
+
+    /// Lists the files attached to a vector store.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
@@ -146,9 +190,9 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list_files( &self, vector_store_id : &str, query : Option< ListQuery > ) -> Result< serde_json::Value >
+    pub async fn list_files( &self, vector_store_id : &str, query : Option< ListQuery > ) -> Result< ListVectorStoreFilesResponse >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/files" );
+      let path = format!( "vector_stores/{vector_store_id}/files" );
       if let Some( q ) = query
       {
         self.client.get_with_query( &path, &q ).await
@@ -168,96 +212,89 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn retrieve_file( &self, vector_store_id : &str, file_id : &str ) -> Result< serde_json::Value >
+    pub async fn retrieve_file( &self, vector_store_id : &str, file_id : &str ) -> Result< VectorStoreFileObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/files/{file_id}" );
+      let path = format!( "vector_stores/{vector_store_id}/files/{file_id}" );
       self.client.get( &path ).await
     }
 
-    /// Deletes a vector store file.
+    /// Retrieves the parsed content of a vector store file.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
-    /// - `file_id`: The ID of the file to delete.
+    /// - `file_id`: The ID of the file.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn delete_file( &self, vector_store_id : &str, file_id : &str ) -> Result< serde_json::Value >
+    pub async fn retrieve_file_content( &self, vector_store_id : &str, file_id : &str ) -> Result< VectorStoreFileContentResponse >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/files/{file_id}" );
-      self.client.delete( &path ).await
+      let path = format!( "vector_stores/{vector_store_id}/files/{file_id}/content" );
+      self.client.get( &path ).await
     }
 
-    /// Modifies a vector store file.
+    /// Replaces the attributes of a vector store file.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
     /// - `file_id`: The ID of the file to modify.
-    /// - `request`: The request body for modifying the file.
+    /// - `request`: The new attributes of the file.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn modify_file( &self, vector_store_id : &str, file_id : &str, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn modify_file( &self, vector_store_id : &str, file_id : &str, request : UpdateVectorStoreFileAttributesRequest ) -> Result< VectorStoreFileObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/files/{file_id}" );
+      let path = format!( "vector_stores/{vector_store_id}/files/{file_id}" );
       self.client.post( &path, &request ).await
     }
 
-    /// Creates a vector store file batch.
+    /// Detaches a file from a vector store. The file stays in the Files API.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
-    /// - `request`: The request body for creating a file batch.
+    /// - `file_id`: The ID of the file to detach.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn create_file_batch( &self, vector_store_id : &str, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn delete_file( &self, vector_store_id : &str, file_id : &str ) -> Result< DeleteVectorStoreFileResponse >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/file_batches" );
-      self.client.post( &path, &request ).await
+      let path = format!( "vector_stores/{vector_store_id}/files/{file_id}" );
+      self.client.delete( &path ).await
     }
 
-    /// Retrieves a vector store file batch.
+    /// Attaches several files to a vector store in one batch.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
-    /// - `batch_id`: The ID of the file batch to retrieve.
+    /// - `request`: The request body naming the files.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn retrieve_file_batch( &self, vector_store_id : &str, batch_id : &str ) -> Result< serde_json::Value >
+    pub async fn create_file_batch( &self, vector_store_id : &str, request : CreateVectorStoreFileBatchRequest ) -> Result< VectorStoreFileBatchObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/file_batches/{batch_id}" );
-      self.client.get( &path ).await
+      let path = format!( "vector_stores/{vector_store_id}/file_batches" );
+      self.client.post( &path, &request ).await
     }
 
-    /// Lists vector store file batches.
+    /// Retrieves a vector store file batch.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
-    /// - `query`: Optional query parameters for listing file batches.
+    /// - `batch_id`: The ID of the file batch to retrieve.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn list_file_batches( &self, vector_store_id : &str, query : Option< ListQuery > ) -> Result< serde_json::Value >
+    pub async fn retrieve_file_batch( &self, vector_store_id : &str, batch_id : &str ) -> Result< VectorStoreFileBatchObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/file_batches" );
-      if let Some( q ) = query
-      {
-        self.client.get_with_query( &path, &q ).await
-      }
-      else
-      {
-        self.client.get( &path ).await
-      }
+      let path = format!( "vector_stores/{vector_store_id}/file_batches/{batch_id}" );
+      self.client.get( &path ).await
     }
 
-    /// Cancels a vector store file batch.
+    /// Cancels a vector store file batch, stopping the files not processed yet.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
@@ -266,28 +303,43 @@ mod private
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn cancel_file_batch( &self, vector_store_id : &str, batch_id : &str ) -> Result< serde_json::Value >
+    pub async fn cancel_file_batch( &self, vector_store_id : &str, batch_id : &str ) -> Result< VectorStoreFileBatchObject >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel" );
-      self.client.post( &path, &serde_json::json!({}) ).await
+      let path = format!( "vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel" );
+      self.client.post_no_body( &path ).await
     }
 
-    /// Updates a vector store file batch.
+    /// Lists the files of a vector store file batch.
     ///
     /// # Arguments
     /// - `vector_store_id`: The ID of the vector store.
-    /// - `batch_id`: The ID of the file batch to update.
-    /// - `request`: The request body for updating the file batch.
+    /// - `batch_id`: The ID of the file batch.
+    /// - `query`: Optional query parameters for listing files.
     ///
     /// # Errors
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
-    pub async fn update_file_batch( &self, vector_store_id : &str, batch_id : &str, request : serde_json::Value ) -> Result< serde_json::Value >
+    pub async fn list_file_batch_files( &self, vector_store_id : &str, batch_id : &str, query : Option< ListQuery > ) -> Result< ListVectorStoreFilesResponse >
     {
-      let path = format!( "/vector_stores/{vector_store_id}/file_batches/{batch_id}" );
-      self.client.post( &path, &request ).await
+      let path = format!( "vector_stores/{vector_store_id}/file_batches/{batch_id}/files" );
+      if let Some( q ) = query
+      {
+        self.client.get_with_query( &path, &q ).await
+      }
+      else
+      {
+        self.client.get( &path ).await
+      }
     }
   }
+
+  /// One page of `VectorStores::list`, for the pages a `CursorPage` fetches.
+  async fn list_vector_stores_after< E >( client : Client< E >, query : ListQuery ) -> Result< ListVectorStoresResponse >
+  where
+    E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
+  {
+    VectorStores::new( &client ).list( Some( query ) ).await
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -297,4 +349,4 @@ crate ::mod_interface!
   {
     VectorStores,
   };
-}
\ No newline at end of file
+}
@@ -0,0 +1,181 @@
+// src/webhooks.rs
+//! This module defines `WebhookVerifier`, which checks that a request to a
+//! webhook endpoint was sent by `OpenAI` and reads the event it carries.
+//!
+//! `OpenAI` signs deliveries the Standard Webhooks way: the `webhook-id`,
+//! `webhook-timestamp`, and `webhook-signature` headers, and an HMAC-SHA256
+//! of `{id}.{timestamp}.{body}` keyed with the `whsec_` secret of the
+//! endpoint. A delivery is accepted only if one of its signatures matches and
+//! its timestamp is within the tolerance of the current time.
+//!
+//! For more details, refer to the [`OpenAI` webhooks guide](https://platform.openai.com/docs/guides/webhooks).
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::
+  {
+    error ::{ OpenAIError, Result },
+    components ::webhooks_shared::WebhookEvent,
+  };
+  use base64::{ engine::general_purpose::STANDARD, Engine as _ };
+  use core::{ fmt, time::Duration };
+  use hmac::{ Hmac, KeyInit, Mac };
+  use reqwest::header::HeaderMap;
+  use sha2::Sha256;
+  use std::time::{ SystemTime, UNIX_EPOCH };
+
+  /// How far the timestamp of a delivery may be from the current time by
+  /// default.
+  const DEFAULT_TOLERANCE : Duration = Duration::from_secs( 300 );
+
+  /// Verifies the signatures of webhook deliveries with the secret of an
+  /// endpoint.
+  #[ derive( Clone ) ]
+  pub struct WebhookVerifier
+  {
+    key : Vec< u8 >,
+    tolerance : Duration,
+  }
+
+  impl fmt::Debug for WebhookVerifier
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "WebhookVerifier" )
+      .field( "tolerance", &self.tolerance )
+      .finish_non_exhaustive()
+    }
+  }
+
+  impl WebhookVerifier
+  {
+    /// Creates a verifier from the signing secret of an endpoint, with or
+    /// without its `whsec_` prefix, and a tolerance of five minutes.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the secret is not base64.
+    #[ inline ]
+    pub fn new( secret : &str ) -> Result< Self >
+    {
+      let encoded = secret.strip_prefix( "whsec_" ).unwrap_or( secret );
+      let key = STANDARD
+      .decode( encoded )
+      .map_err( | e | OpenAIError::InvalidArgument( format!( "Webhook secret is not base64 : {e}" ) ) )?;
+      Ok( Self { key, tolerance : DEFAULT_TOLERANCE } )
+    }
+
+    /// Sets how far the timestamp of a delivery may be from the current
+    /// time, either way.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tolerance( mut self, tolerance : Duration ) -> Self
+    {
+      self.tolerance = tolerance;
+      self
+    }
+
+    /// Verifies a delivery: its headers and its body exactly as received.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingHeader` if a signature header is missing,
+    /// or `OpenAIError::InvalidArgument` if the timestamp is outside the
+    /// tolerance or no signature matches.
+    #[ inline ]
+    pub fn verify( &self, headers : &HeaderMap, body : &[ u8 ] ) -> Result< () >
+    {
+      self.verify_at( headers, body, SystemTime::now() )
+    }
+
+    /// Verifies a delivery as of `now`.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::MissingHeader` if a signature header is missing,
+    /// or `OpenAIError::InvalidArgument` if the timestamp is outside the
+    /// tolerance or no signature matches.
+    #[ inline ]
+    pub fn verify_at( &self, headers : &HeaderMap, body : &[ u8 ], now : SystemTime ) -> Result< () >
+    {
+      let id = header( headers, "webhook-id" )?;
+      let timestamp = header( headers, "webhook-timestamp" )?;
+      let signatures = header( headers, "webhook-signature" )?;
+
+      let sent = timestamp
+      .parse::< u64 >()
+      .map_err( | e | OpenAIError::InvalidArgument( format!( "Webhook timestamp {timestamp} is not a Unix time : {e}" ) ) )?;
+      let now = now.duration_since( UNIX_EPOCH ).unwrap_or_default().as_secs();
+      if now.abs_diff( sent ) > self.tolerance.as_secs()
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Webhook timestamp {sent} is more than {}s from the current time {now}", self.tolerance.as_secs() ) ).into() );
+      }
+
+      let mac = self.mac( id, timestamp, body );
+      // Several space-separated signatures are sent while a secret is rotated.
+      let matched = signatures
+      .split_whitespace()
+      .filter_map( | signature | signature.strip_prefix( "v1," ) )
+      .filter_map( | encoded | STANDARD.decode( encoded ).ok() )
+      .any( | signature | mac.clone().verify_slice( &signature ).is_ok() );
+      if matched
+      {
+        Ok( () )
+      }
+      else
+      {
+        Err( OpenAIError::InvalidArgument( format!( "Webhook {id} has no valid signature" ) ).into() )
+      }
+    }
+
+    /// Verifies a delivery and reads the event of its body.
+    ///
+    /// # Errors
+    /// Returns the errors of `verify`, or `OpenAIError::Internal` if the body
+    /// is not an event.
+    #[ inline ]
+    pub fn verify_event( &self, headers : &HeaderMap, body : &[ u8 ] ) -> Result< WebhookEvent >
+    {
+      self.verify( headers, body )?;
+      Ok( serde_json::from_slice( body ).map_err( OpenAIError::from )? )
+    }
+
+    /// The `webhook-signature` value of a delivery, for testing an endpoint
+    /// with deliveries of its own.
+    #[ inline ]
+    #[ must_use ]
+    pub fn sign( &self, id : &str, timestamp : u64, body : &[ u8 ] ) -> String
+    {
+      let signature = self.mac( id, &timestamp.to_string(), body ).finalize().into_bytes();
+      format!( "v1,{}", STANDARD.encode( signature ) )
+    }
+
+    /// The HMAC of the signed content, `{id}.{timestamp}.{body}`.
+    fn mac( &self, id : &str, timestamp : &str, body : &[ u8 ] ) -> Hmac< Sha256 >
+    {
+      let mut mac = < Hmac< Sha256 > as KeyInit >::new_from_slice( &self.key ).expect( "HMAC accepts any key length" );
+      mac.update( id.as_bytes() );
+      mac.update( b"." );
+      mac.update( timestamp.as_bytes() );
+      mac.update( b"." );
+      mac.update( body );
+      mac
+    }
+  }
+
+  /// The value of a signature header.
+  fn header< 'a >( headers : &'a HeaderMap, name : &str ) -> Result< &'a str >
+  {
+    headers
+    .get( name )
+    .and_then( | value | value.to_str().ok() )
+    .ok_or_else( || OpenAIError::MissingHeader( format!( "Webhook delivery has no {name} header" ) ).into() )
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    WebhookVerifier,
+  };
+}
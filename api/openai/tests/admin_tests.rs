@@ -0,0 +1,139 @@
+//! Tests for the organization administration client: users, invites,
+//! projects, project users, service accounts, API keys, and admin API keys.
+//!
+//! Unit tests check the request bodies and queries the client serializes and
+//! the recorded Administration API bodies it parses; the integration test
+//! reads the organization with a real admin key.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | Request bodies and queries | Role update, invite with projects, project, project user, admin key; list limit, archived projects | Only set fields serialized |
+//! | Users and invites | List, user, invite, invite deletion | Typed bodies and delete responses |
+//! | Projects | List, archived project, project user, service account, key deletion | Archive read, service account key returned |
+//! | Admin API keys | Creation, deletion | Key value returned on creation |
+//! | `Secret::new_admin` | Admin key, project key | Only `sk-admin-` keys are accepted |
+//! | `Admin` (integration) | List users and projects | Real organization read with `OPENAI_ADMIN_KEY` |
+
+use api_openai::exposed::secret::Secret;
+use api_openai::components::administration_shared::
+{
+  AdminApiKey,
+  AdminApiKeyCreateRequest,
+  AdminListQuery,
+  Invite,
+  InviteProject,
+  InviteRequest,
+  ListProjectsQuery,
+  Project,
+  ProjectCreateRequest,
+  ProjectListResponse,
+  ProjectServiceAccountCreateResponse,
+  ProjectUser,
+  ProjectUserCreateRequest,
+  UserListResponse,
+  UserRoleUpdateRequest,
+};
+use api_openai::components::common::{ AdminApiKeyDeleteResponse, InviteDeleteResponse, ProjectApiKeyDeleteResponse };
+
+const USER : &str = r#"{"object":"organization.user","id":"user-abc","name":"Ada","email":"ada@example.com","role":"reader","added_at":1711471533}"#;
+const INVITE : &str = r#"{"object":"organization.invite","id":"invite-abc","email":"bob@example.com","role":"reader","status":"pending","invited_at":1711471533,"expires_at":1711471533,"projects":[{"id":"proj-abc","role":"member"}]}"#;
+const PROJECT : &str = r#"{"id":"proj-abc","object":"organization.project","name":"Support","created_at":1711471533,"archived_at":null,"status":"active"}"#;
+
+fn json< T : serde::Serialize >( value : &T ) -> String
+{
+  serde_json::to_string( value ).expect( "value serializes" )
+}
+
+#[ test ]
+fn requests_and_queries_serialize_what_is_set()
+{
+  let mut invite = InviteRequest::new( "bob@example.com", "reader" );
+  invite.projects = Some( vec![ InviteProject { id : "proj-abc".to_string(), role : "member".to_string() } ] );
+
+  assert_eq!( json( &UserRoleUpdateRequest::new( "reader" ) ), r#"{"role":"reader"}"# );
+  assert_eq!( json( &invite ), r#"{"email":"bob@example.com","role":"reader","projects":[{"id":"proj-abc","role":"member"}]}"# );
+  assert_eq!( json( &ProjectCreateRequest::new( "Support" ) ), r#"{"name":"Support"}"# );
+  assert_eq!( json( &ProjectUserCreateRequest::new( "user-abc", "member" ) ), r#"{"user_id":"user-abc","role":"member"}"# );
+  assert_eq!( json( &AdminApiKeyCreateRequest::new( "ops" ) ), r#"{"name":"ops"}"# );
+  assert_eq!( json( &AdminListQuery::former().limit( 10_u32 ).form() ), r#"{"limit":10}"# );
+  assert_eq!( json( &ListProjectsQuery::former().include_archived( true ).form() ), r#"{"include_archived":true}"# );
+}
+
+#[ test ]
+fn users_and_invites_parse()
+{
+  let users = format!( r#"{{"object":"list","data":[{USER}],"first_id":"user-abc","last_id":"user-abc","has_more":false}}"# );
+
+  let users : UserListResponse = serde_json::from_str( &users ).expect( "users must parse" );
+  let invite : Invite = serde_json::from_str( INVITE ).expect( "invite must parse" );
+  let deleted : InviteDeleteResponse = serde_json::from_str( r#"{"object":"organization.invite.deleted","id":"invite-abc","deleted":true}"# ).expect( "deletion must parse" );
+
+  assert_eq!( ( users.data[ 0 ].email.as_str(), users.data[ 0 ].role.as_str() ), ( "ada@example.com", "reader" ) );
+  assert_eq!( ( invite.status.as_str(), invite.projects.map( | p | p.len() ) ), ( "pending", Some( 1 ) ) );
+  assert!( deleted.deleted );
+}
+
+#[ test ]
+fn projects_and_their_members_parse()
+{
+  let projects = format!( r#"{{"object":"list","data":[{PROJECT}],"first_id":"proj-abc","last_id":"proj-abc","has_more":false}}"# );
+  let archived = PROJECT.replace( r#""archived_at":null,"status":"active""#, r#""archived_at":1711471600,"status":"archived""# );
+  let project_user = r#"{"object":"organization.project.user","id":"user-abc","name":"Ada","email":"ada@example.com","role":"member","added_at":1711471533}"#;
+  let service_account = r#"{"object":"organization.project.service_account","id":"svc_acct_abc","name":"ci","role":"member","created_at":1711471533,"api_key":{"object":"organization.project.service_account.api_key","value":"sk-abcdefghijklmnop123","name":"Secret Key","created_at":1711471533,"id":"key_abc"}}"#;
+
+  let projects : ProjectListResponse = serde_json::from_str( &projects ).expect( "projects must parse" );
+  let archived : Project = serde_json::from_str( &archived ).expect( "project must parse" );
+  let member : ProjectUser = serde_json::from_str( project_user ).expect( "project user must parse" );
+  let account : ProjectServiceAccountCreateResponse = serde_json::from_str( service_account ).expect( "service account must parse" );
+  let deleted : ProjectApiKeyDeleteResponse = serde_json::from_str( r#"{"object":"organization.project.api_key.deleted","id":"key_abc","deleted":true}"# ).expect( "deletion must parse" );
+
+  assert_eq!( ( projects.data.len(), archived.status.as_str(), archived.archived_at ), ( 1, "archived", Some( 1_711_471_600 ) ) );
+  assert_eq!( ( member.role.as_str(), account.api_key.value.as_str() ), ( "member", "sk-abcdefghijklmnop123" ) );
+  assert!( deleted.deleted );
+}
+
+#[ test ]
+fn admin_api_key_creation_returns_the_value()
+{
+  let key = r#"{"object":"organization.admin_api_key","id":"key_admin","name":"ops","redacted_value":"sk-admin...xyz","value":"sk-admin-new-0123456789","created_at":1711471533,"owner":{"type":"user","id":"user-abc","name":"Ada","created_at":1711471533,"role":"owner"}}"#;
+
+  let created : AdminApiKey = serde_json::from_str( key ).expect( "key must parse" );
+  let deleted : AdminApiKeyDeleteResponse = serde_json::from_str( r#"{"object":"organization.admin_api_key.deleted","id":"key_admin","deleted":true}"# ).expect( "deletion must parse" );
+
+  assert_eq!( created.value.as_deref(), Some( "sk-admin-new-0123456789" ) );
+  assert_eq!( ( deleted.id.as_str(), deleted.deleted ), ( "key_admin", true ) );
+}
+
+#[ test ]
+fn only_admin_keys_are_admin_secrets()
+{
+  assert!( Secret::new_admin( "sk-admin-0123456789abcdef".to_string() ).is_ok() );
+
+  let error = Secret::new_admin( "sk-proj-0123456789abcdef".to_string() ).expect_err( "a project key is not an admin key" );
+
+  assert!( error.to_string().contains( "sk-admin-" ), "{error}" );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_organization_is_read()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    client ::Client,
+  };
+
+  let secret = Secret::load_admin_key().expect( "INTEGRATION: OPENAI_ADMIN_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let admin = client.admin();
+
+  let users = admin.list_users( Some( AdminListQuery::former().limit( 1_u32 ).form() ) ).await.expect( "INTEGRATION: users must be listed" );
+  let projects = admin.list_projects( Some( ListProjectsQuery::former().limit( 1_u32 ).form() ) ).await.expect( "INTEGRATION: projects must be listed" );
+
+  assert!( users.data.len() <= 1 && projects.data.len() <= 1, "{users:?} {projects:?}" );
+}
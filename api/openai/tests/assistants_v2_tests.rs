@@ -0,0 +1,153 @@
+//! Tests for the Assistants v2 clients: threads, messages, runs, run
+//! polling, and run streaming.
+//!
+//! Unit tests check the request bodies the client serializes and the
+//! recorded Assistants API bodies and run events it parses; the integration
+//! test runs an assistant with the real API, which also needs the
+//! `OpenAI-Beta: assistants=v2` header to be sent.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateAssistantRequest` / `AssistantObject` | Code interpreter and function tools | Tools sent and read in their `type`-tagged shape |
+//! | `CreateMessageRequest` / `ThreadObject` / `MessageObject` | User message on a thread | Body serialized, thread and message parsed |
+//! | `RunObject::is_pending` | Each status; run waiting for tool outputs | `queued`, `in_progress`, `cancelling` pending; required action read |
+//! | `AssistantStreamEvent` | Named run events | Events read by name; text deltas read as text |
+//! | `Assistants` / `Threads` / `Runs` (integration) | Create, add message, stream a run, wait for it, delete | Real run streamed and finished |
+
+use api_openai::components::assistants_shared::
+{
+  AssistantObject,
+  AssistantStreamEvent,
+  AssistantTool,
+  AssistantToolsCode,
+  AssistantToolsFunction,
+  CreateAssistantRequest,
+  CreateMessageRequest,
+  MessageDeltaContent,
+  MessageObject,
+  RunObject,
+  ThreadObject,
+};
+use api_openai::components::tools::{ FunctionTool, FunctionParameters };
+
+const RUN_QUEUED : &str = r#"{"id":"run_abc","object":"thread.run","created_at":1,"thread_id":"thread_abc","assistant_id":"asst_abc","status":"queued","model":"gpt-4o","tools":[],"parallel_tool_calls":true}"#;
+const RUN_IN_PROGRESS : &str = r#"{"id":"run_abc","object":"thread.run","created_at":1,"thread_id":"thread_abc","assistant_id":"asst_abc","status":"in_progress","model":"gpt-4o","tools":[],"parallel_tool_calls":true}"#;
+const RUN_REQUIRES_ACTION : &str = r#"{"id":"run_abc","object":"thread.run","created_at":1,"thread_id":"thread_abc","assistant_id":"asst_abc","status":"requires_action","required_action":{"type":"submit_tool_outputs","submit_tool_outputs":{"tool_calls":[{"id":"call_1","type":"function","function":{"name":"get_weather","arguments":"{}"}}]}},"model":"gpt-4o","tools":[{"type":"function","function":{"name":"get_weather","parameters":{"type":"object"}}}],"parallel_tool_calls":true}"#;
+
+#[ test ]
+fn assistant_tools_are_type_tagged()
+{
+  let answer = r#"{"id":"asst_abc","object":"assistant","created_at":1,"name":"Weather","description":null,"model":"gpt-4o","instructions":null,"tools":[{"type":"code_interpreter"},{"type":"function","function":{"name":"get_weather","parameters":{"type":"object"}}}],"tool_resources":null,"metadata":null,"temperature":null,"top_p":null,"response_format":"auto"}"#;
+  let function = FunctionTool::former()
+    .name( "get_weather".to_string() )
+    .parameters( FunctionParameters::new( serde_json::json!( { "type" : "object" } ) ) )
+    .form();
+  let request = CreateAssistantRequest::former()
+    .model( "gpt-4o".to_string() )
+    .name( "Weather".to_string() )
+    .tools( vec![ AssistantTool::CodeInterpreter( AssistantToolsCode {} ), AssistantTool::Function( AssistantToolsFunction { function } ) ] )
+    .form();
+
+  let body = serde_json::to_string( &request ).expect( "request serializes" );
+  let assistant : AssistantObject = serde_json::from_str( answer ).expect( "assistant must parse" );
+
+  assert!( body.contains( r#"{"type":"code_interpreter"}"# ), "{body}" );
+  assert!( body.contains( r#"{"type":"function","function":{"name":"get_weather""# ), "{body}" );
+  assert_eq!( assistant.id, "asst_abc" );
+  assert!( matches!( assistant.tools.as_slice(), [ AssistantTool::CodeInterpreter( _ ), AssistantTool::Function( tool ) ] if tool.function.name == "get_weather" ) );
+}
+
+#[ test ]
+fn threads_and_messages_serialize_and_parse()
+{
+  let thread = r#"{"id":"thread_abc","object":"thread","created_at":1,"metadata":{}}"#;
+  let message = r#"{"id":"msg_abc","object":"thread.message","created_at":1,"thread_id":"thread_abc","status":"completed","role":"user","content":[{"type":"text","text":{"value":"Hello","annotations":[]}}],"attachments":[],"metadata":{}}"#;
+
+  let thread : ThreadObject = serde_json::from_str( thread ).expect( "thread must parse" );
+  let message : MessageObject = serde_json::from_str( message ).expect( "message must parse" );
+
+  assert_eq!( serde_json::to_string( &CreateMessageRequest::user( "Hello" ) ).expect( "request serializes" ), r#"{"role":"user","content":"Hello"}"# );
+  assert_eq!( ( thread.id.as_str(), message.thread_id.as_str() ), ( "thread_abc", "thread_abc" ) );
+}
+
+#[ test ]
+fn runs_are_pending_until_they_stop()
+{
+  for ( status, pending ) in [ ( "queued", true ), ( "in_progress", true ), ( "cancelling", true ), ( "requires_action", false ), ( "completed", false ), ( "failed", false ), ( "expired", false ) ]
+  {
+    let run : RunObject = serde_json::from_str( &RUN_QUEUED.replace( "queued", status ) ).expect( "run must parse" );
+    assert_eq!( run.is_pending(), pending, "{status}" );
+  }
+
+  let in_progress : RunObject = serde_json::from_str( RUN_IN_PROGRESS ).expect( "run must parse" );
+  let run : RunObject = serde_json::from_str( RUN_REQUIRES_ACTION ).expect( "run must parse" );
+  assert!( in_progress.is_pending() );
+  let tool_call = &run.required_action.expect( "run waits for tool outputs" ).submit_tool_outputs.tool_calls[ 0 ];
+  assert_eq!( tool_call.id, "call_1" );
+}
+
+#[ test ]
+fn run_events_are_read_by_name()
+{
+  // As `Runs::create_stream` reads each server-sent event: its name beside its data
+  let delta = r#"{"id":"msg_abc","object":"thread.message.delta","delta":{"content":[{"index":0,"type":"text","text":{"value":"Hel","annotations":[]}}]}}"#;
+  let created = format!( r#"{{"event":"thread.run.created","data":{RUN_QUEUED}}}"# );
+  let delta = format!( r#"{{"event":"thread.message.delta","data":{delta}}}"# );
+
+  let created : AssistantStreamEvent = serde_json::from_str( &created ).expect( "run event must parse" );
+  let delta : AssistantStreamEvent = serde_json::from_str( &delta ).expect( "delta event must parse" );
+
+  assert!( matches!( &created, AssistantStreamEvent::ThreadRunCreated { data } if data.status == "queued" ) );
+  let AssistantStreamEvent::ThreadMessageDelta { data } = &delta else { panic!( "expected a message delta, got {delta:?}" ) };
+  let content = data.delta.content.as_deref().unwrap_or_default();
+  assert!( matches!( content, [ MessageDeltaContent::Text( part ) ] if part.text.as_ref().and_then( | text | text.value.as_deref() ) == Some( "Hel" ) ), "{content:?}" );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_assistant_run_is_streamed_and_finished()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+  use api_openai::components::assistants_shared::{ CreateRunRequest, CreateThreadRequest };
+  use core::time::Duration;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+
+  let request = CreateAssistantRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .name( "api_openai assistants test".to_string() )
+    .instructions( "Answer in one word.".to_string() )
+    .form();
+  let assistant = client.assistants().create( request ).await.expect( "INTEGRATION: assistant must be created" );
+  let thread = client.threads().create( CreateThreadRequest::default() ).await.expect( "INTEGRATION: thread must be created" );
+  client.threads().messages().create( &thread.id, CreateMessageRequest::user( "Say hello." ) ).await.expect( "INTEGRATION: message must be added" );
+
+  let run = CreateRunRequest::former().assistant_id( assistant.id.clone() ).form();
+  let mut events = client.threads().runs().create_stream( &thread.id, run ).await.expect( "INTEGRATION: run stream must start" );
+  let mut run_id = None;
+  while let Some( event ) = events.recv().await
+  {
+    if let AssistantStreamEvent::ThreadRunCreated { data } = event.expect( "INTEGRATION: events must be read" )
+    {
+      run_id = Some( data.id );
+    }
+  }
+  let run_id = run_id.expect( "INTEGRATION: stream announces the run" );
+  let run = client.threads().runs().wait_for_run( &thread.id, &run_id, Duration::from_millis( 500 ), Duration::from_secs( 60 ) ).await
+    .expect( "INTEGRATION: run must finish" );
+  client.threads().delete( &thread.id ).await.expect( "INTEGRATION: thread must be deleted" );
+  client.assistants().delete( &assistant.id ).await.expect( "INTEGRATION: assistant must be deleted" );
+
+  assert_eq!( run.status, "completed" );
+}
@@ -0,0 +1,115 @@
+#![ cfg( feature = "audio" ) ]
+//! Tests for the Audio client: speech as a whole and streamed, and the
+//! transcription and translation output formats.
+//!
+//! Unit tests check the speech body the client sends and the recorded Audio
+//! API bodies it parses; the form fields are unit tests in `src/audio.rs`.
+//! The integration test speaks a sentence and transcribes it back with the
+//! real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateSpeechRequest` | `wav` speech | JSON body with model, input, voice, and format |
+//! | `CreateTranscriptionResponseVerboseJson` | Words and segments | Timestamps read |
+//! | `Audio::transcribe` | `srt` asked of the JSON method | Error, no request sent |
+//! | `Audio` (integration) | Speech whole and streamed, verbose and `srt` transcripts | Same audio both ways; spoken words transcribed back |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::ClientApiAccessors;
+use api_openai::components::audio::
+{
+  CreateSpeechRequest,
+  CreateTranscriptionRequest,
+  CreateTranscriptionResponseVerboseJson,
+  SpeechResponseFormat,
+  SpeechVoice,
+  TranscriptionResponseFormat,
+};
+
+const AUDIO : &str = "RIFF\u{0}\u{0}\u{0}\u{0}WAVEfmt fake-pcm-samples";
+
+fn client( base_url : String, secret : Secret ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, base_url, OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  Client::build( environment ).expect( "client builds" )
+}
+
+#[ test ]
+fn speech_request_serializes()
+{
+  let mut request = CreateSpeechRequest::new( "gpt-4o-mini-tts", "Hello there.", SpeechVoice::Coral );
+  request.response_format = Some( SpeechResponseFormat::Wav );
+
+  assert_eq!
+  (
+    serde_json::to_string( &request ).expect( "request serializes" ),
+    r#"{"model":"gpt-4o-mini-tts","input":"Hello there.","voice":"coral","response_format":"wav"}"#
+  );
+}
+
+#[ test ]
+fn verbose_transcript_reads_words_and_segments()
+{
+  let verbose = r#"{"task":"transcribe","language":"english","duration":1.2,"text":"Hello there.","words":[{"word":"Hello","start":0.0,"end":0.5},{"word":"there","start":0.5,"end":1.1}],"segments":[{"id":0,"seek":0,"start":0.0,"end":1.2,"text":"Hello there.","tokens":[50364,2425],"temperature":0.0,"avg_logprob":-0.2,"compression_ratio":0.8,"no_speech_prob":0.01}]}"#;
+
+  let transcript : CreateTranscriptionResponseVerboseJson = serde_json::from_str( verbose ).expect( "transcript must parse" );
+
+  assert_eq!( ( transcript.language.as_str(), transcript.text.as_str() ), ( "english", "Hello there." ) );
+  let words : Vec< _ > = transcript.words.unwrap_or_default().into_iter().map( | word | word.word ).collect();
+  assert_eq!( words, vec![ "Hello", "there" ] );
+  assert_eq!( transcript.segments.map( | segments | segments.len() ), Some( 1 ) );
+}
+
+#[ tokio::test ]
+async fn json_transcription_rejects_a_text_format()
+{
+  let client = client( "http://127.0.0.1:9/v1/".to_string(), Secret::new_unchecked( "sk-audio-test-0123456789abcdef".to_string() ) );
+  let mut request = CreateTranscriptionRequest::new( AUDIO.as_bytes().to_vec(), "hello.wav", "whisper-1" );
+  request.response_format = Some( TranscriptionResponseFormat::Srt );
+
+  let error = client.audio().transcribe( request ).await.expect_err( "`srt` is not JSON" );
+
+  assert!( error.to_string().contains( "transcribe_text" ), "{error}" );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_speech_is_transcribed_back()
+{
+  use api_openai::components::audio::TimestampGranularity;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let client = client( OpenAIRecommended::base_url().to_string(), secret );
+  let audio = client.audio();
+
+  let mut request = CreateSpeechRequest::new( "gpt-4o-mini-tts", "Hello there.", SpeechVoice::Coral );
+  request.response_format = Some( SpeechResponseFormat::Wav );
+  let whole = audio.speech( request.clone() ).await.expect( "INTEGRATION: speech must be generated" );
+  let mut chunks = audio.speech_stream( request ).await.expect( "INTEGRATION: speech stream must start" );
+  let mut streamed = Vec::new();
+  while let Some( chunk ) = chunks.recv().await
+  {
+    streamed.extend( chunk.expect( "INTEGRATION: chunk must be read" ) );
+  }
+
+  let mut transcription = CreateTranscriptionRequest::new( whole.clone(), "hello.wav", "whisper-1" );
+  transcription.timestamp_granularities = Some( vec![ TimestampGranularity::Word ] );
+  let transcript = audio.transcribe_verbose( transcription ).await.expect( "INTEGRATION: speech must be transcribed" );
+  let mut subtitles = CreateTranscriptionRequest::new( whole.clone(), "hello.wav", "whisper-1" );
+  subtitles.response_format = Some( TranscriptionResponseFormat::Srt );
+  let subtitles = audio.transcribe_text( subtitles ).await.expect( "INTEGRATION: subtitles must be returned" );
+
+  assert!( whole.starts_with( b"RIFF" ), "speech is a wav file" );
+  assert!( !streamed.is_empty() && streamed.starts_with( b"RIFF" ), "streamed speech is a wav file" );
+  assert!( transcript.text.to_lowercase().contains( "hello" ), "{}", transcript.text );
+  assert!( transcript.words.is_some_and( | words | !words.is_empty() ) );
+  assert!( subtitles.contains( " --> " ), "{subtitles}" );
+}
@@ -0,0 +1,155 @@
+//! Tests for the Batch client and the JSONL batch input helper.
+//!
+//! Unit tests check the request bodies and queries the client serializes and
+//! the recorded Batch API bodies it parses; the integration test runs a batch
+//! against the real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `BatchRequestInput::chat_completions_jsonl` | Two requests; a streamed request | One `POST /v1/chat/completions` line per request with `request-{i}` IDs; streaming rejected |
+//! | `CreateBatchRequest` / `ListBatchesQuery` | New batch, page limit | Input file, endpoint, and `24h` window serialized; unset query fields left out |
+//! | `Batch::is_pending` | Each status | `validating` to `cancelling` pending, the rest final |
+//! | `BatchRequestOutput::parse_jsonl` | Output and error files | One line per request, blank lines skipped, responses and errors read |
+//! | `Batches::download_output` | Batch without output file | Error, no request sent |
+//! | `Batches` (integration) | Upload, create, retrieve, list, cancel | Real batch created from an uploaded file and cancelled |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::ClientApiAccessors;
+use api_openai::components::batch_shared::{ Batch, BatchRequestInput, BatchRequestOutput, CreateBatchRequest, ListBatchesQuery };
+use api_openai::components::chat_shared::{ ChatCompletionRequest, ChatCompletionRequestMessage, ChatCompletionRequestMessageContent };
+
+const OUTPUT : &str = "{\"id\":\"batch_req_1\",\"custom_id\":\"request-1\",\"response\":{\"status_code\":200,\"request_id\":\"req_1\",\"body\":{\"id\":\"chatcmpl-1\"}},\"error\":null}\n\n{\"id\":\"batch_req_0\",\"custom_id\":\"request-0\",\"response\":{\"status_code\":200,\"request_id\":\"req_0\",\"body\":{\"id\":\"chatcmpl-0\"}},\"error\":null}\n";
+const ERRORS : &str = "{\"id\":\"batch_req_2\",\"custom_id\":\"request-2\",\"response\":null,\"error\":{\"code\":\"invalid_request\",\"message\":\"Unknown model\"}}\n";
+
+fn batch( status : &str, files : &str ) -> String
+{
+  format!( r#"{{"id":"batch_abc","object":"batch","endpoint":"/v1/chat/completions","errors":null,"input_file_id":"file-in","completion_window":"24h","status":"{status}",{files}"created_at":1,"request_counts":{{"total":3,"completed":2,"failed":1}},"metadata":null}}"# )
+}
+
+fn chat_request( text : &str ) -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .messages( vec!
+    [
+      ChatCompletionRequestMessage
+      {
+        role : "user".to_string(),
+        content : Some( ChatCompletionRequestMessageContent::Text( text.to_string() ) ),
+        name : None,
+        tool_calls : None,
+        tool_call_id : None,
+      }
+    ] )
+    .form()
+}
+
+fn client( base_url : String, secret : Secret ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, base_url, OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  Client::build( environment ).expect( "client builds" )
+}
+
+#[ test ]
+fn jsonl_helper_writes_one_chat_completion_line_per_request()
+{
+  let jsonl = BatchRequestInput::chat_completions_jsonl( &[ chat_request( "Hi" ), chat_request( "Bye" ) ] ).expect( "requests must serialize" );
+
+  let text = String::from_utf8( jsonl ).expect( "JSONL is UTF-8" );
+  assert_eq!( text, concat!
+  (
+    r#"{"custom_id":"request-0","method":"POST","url":"/v1/chat/completions","body":{"messages":[{"content":"Hi","role":"user"}],"model":"gpt-4o-mini"}}"#, "\n",
+    r#"{"custom_id":"request-1","method":"POST","url":"/v1/chat/completions","body":{"messages":[{"content":"Bye","role":"user"}],"model":"gpt-4o-mini"}}"#, "\n",
+  ) );
+  let mut streamed = chat_request( "Hi" );
+  streamed.stream = Some( true );
+  let error = BatchRequestInput::chat_completions_jsonl( &[ streamed ] ).expect_err( "batches cannot stream" );
+  assert!( error.to_string().contains( "cannot be streamed" ), "{error}" );
+}
+
+#[ test ]
+fn create_request_and_list_query_serialize_what_is_set()
+{
+  let request = CreateBatchRequest::new( "file-in", "/v1/chat/completions" );
+  assert_eq!
+  (
+    serde_json::to_string( &request ).expect( "request serializes" ),
+    r#"{"input_file_id":"file-in","endpoint":"/v1/chat/completions","completion_window":"24h"}"#
+  );
+
+  let query = ListBatchesQuery::former().limit( 10_u32 ).form();
+  assert_eq!( serde_json::to_value( &query ).expect( "query serializes" ), serde_json::json!( { "limit" : 10 } ) );
+}
+
+#[ test ]
+fn batches_are_pending_until_a_final_status()
+{
+  let files = r#""output_file_id":"file-out","error_file_id":"file-err","#;
+  for ( status, pending ) in [ ( "validating", true ), ( "in_progress", true ), ( "finalizing", true ), ( "cancelling", true ), ( "completed", false ), ( "failed", false ), ( "expired", false ), ( "cancelled", false ) ]
+  {
+    let batch : Batch = serde_json::from_str( &batch( status, files ) ).expect( "batch must parse" );
+    assert_eq!( batch.is_pending(), pending, "{status}" );
+  }
+
+  let completed : Batch = serde_json::from_str( &batch( "completed", files ) ).expect( "batch must parse" );
+  assert_eq!( ( completed.output_file_id.as_deref(), completed.error_file_id.as_deref() ), ( Some( "file-out" ), Some( "file-err" ) ) );
+}
+
+#[ test ]
+fn output_and_error_files_are_read_line_by_line()
+{
+  let output = BatchRequestOutput::parse_jsonl( OUTPUT.as_bytes() ).expect( "output must parse" );
+  let errors = BatchRequestOutput::parse_jsonl( ERRORS.as_bytes() ).expect( "errors must parse" );
+
+  let custom_ids : Vec< _ > = output.iter().map( | line | line.custom_id.as_deref() ).collect();
+  assert_eq!( custom_ids, vec![ Some( "request-1" ), Some( "request-0" ) ], "blank lines are skipped" );
+  assert_eq!( output[ 0 ].response.as_ref().and_then( | response | response.status_code ), Some( 200 ) );
+  assert_eq!( errors[ 0 ].error.as_ref().and_then( | error | error.message.as_deref() ), Some( "Unknown model" ) );
+  assert!( BatchRequestOutput::parse_jsonl( b"{not json}\n" ).is_err() );
+}
+
+#[ tokio::test ]
+async fn download_without_output_file_is_an_error()
+{
+  let client = client( "http://127.0.0.1:9/v1/".to_string(), Secret::new_unchecked( "sk-batches-test-0123456789abcdef".to_string() ) );
+  let batch : Batch = serde_json::from_str( &batch( "in_progress", "" ) ).expect( "batch must parse" );
+
+  let error = client.batches().download_output( &batch ).await.expect_err( "a pending batch has no output file" );
+
+  assert!( error.to_string().contains( "no output file" ), "{error}" );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_batch_is_created_listed_and_cancelled()
+{
+  use api_openai::components::files::{ CreateFileRequest, FilePurpose };
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let client = client( OpenAIRecommended::base_url().to_string(), secret );
+
+  let jsonl = BatchRequestInput::chat_completions_jsonl( &[ chat_request( "Say hi" ) ] ).expect( "requests must serialize" );
+  let file = client.files().upload( CreateFileRequest::new( jsonl, "batch_input.jsonl", FilePurpose::Batch ) ).await
+    .expect( "INTEGRATION: batch input must be uploaded" );
+
+  let batches = client.batches();
+  let created = batches.create( CreateBatchRequest::new( &file.id, "/v1/chat/completions" ) ).await
+    .expect( "INTEGRATION: batch must be created" );
+  let retrieved = batches.retrieve( &created.id ).await.expect( "INTEGRATION: batch must be retrieved" );
+  let listed = batches.list( Some( ListBatchesQuery::former().limit( 10_u32 ).form() ) ).await
+    .expect( "INTEGRATION: batches must be listed" );
+  let cancelled = batches.cancel( &created.id ).await.expect( "INTEGRATION: batch must be cancelled" );
+  client.files().delete( &file.id ).await.expect( "INTEGRATION: batch input must be deleted" );
+
+  assert_eq!( ( retrieved.id.as_str(), retrieved.input_file_id.as_str() ), ( created.id.as_str(), file.id.as_str() ) );
+  assert!( listed.data.iter().any( | batch | batch.id == created.id ), "{listed:?}" );
+  assert!( matches!( cancelled.status.as_str(), "cancelling" | "cancelled" | "completed" | "failed" ), "{}", cancelled.status );
+}
@@ -107,7 +107,7 @@ fn test_create_response_request_builder_complete()
     .max_output_tokens(1000i32)
     .stream(false)
     .tools(vec![])
-    .tool_choice(ToolChoice::String("auto".to_string()))
+    .tool_choice(ToolChoice::auto())
     .parallel_tool_calls(true)
     .form();
 
@@ -120,7 +120,7 @@ fn test_create_response_request_builder_complete()
   assert_eq!(request.max_output_tokens, Some(1000i32));
   assert_eq!(request.stream, Some(false));
   assert_eq!(request.tools, Some(vec![]));
-  assert_eq!(request.tool_choice, Some(ToolChoice::String("auto".to_string())));
+  assert_eq!(request.tool_choice, Some(ToolChoice::auto()));
   assert_eq!(request.parallel_tool_calls, true);
 }
 
@@ -535,7 +535,7 @@ fn test_comprehensive_builder_integration()
     .model(ModelIdsResponses::from("gpt-4".to_string()))
     .input(ResponseInput::Items(vec![InputItem::Message(text_message)]))
     .tools(vec![function_tool])
-    .tool_choice(ToolChoice::String("auto".to_string()))
+    .tool_choice(ToolChoice::auto())
     .temperature(0.3)
     .max_output_tokens(2000i32)
     .stream(false)
@@ -545,7 +545,7 @@ fn test_comprehensive_builder_integration()
   assert_eq!(request.model, ModelIdsResponses::from("gpt-4".to_string()));
   assert!(matches!(request.input, ResponseInput::Items(_)));
   assert_eq!(request.tools.as_ref().unwrap().len(), 1);
-  assert_eq!(request.tool_choice, Some(ToolChoice::String("auto".to_string())));
+  assert_eq!(request.tool_choice, Some(ToolChoice::auto()));
   assert_eq!(request.temperature, Some(0.3));
   assert_eq!(request.max_output_tokens, Some(2000i32));
   assert_eq!(request.stream, Some(false));
@@ -0,0 +1,126 @@
+//! Tests for the validation of chat completion requests before they are sent.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `ChatCompletionRequest::validate` | Ranges, tool count, excluded and required parameters | Every violation reported at once |
+//! | `ChatCompletionRequest::validate` | Reasoning and chat models | Fixed sampling and `max_tokens` rejected for reasoning models only |
+//! | `Chat::create` | Invalid request | `OpenAIError::Validation` without an HTTP request |
+
+#![ cfg( feature = "input_validation" ) ]
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+  error ::OpenAIError,
+};
+use api_openai::ClientApiAccessors;
+use api_openai::input_validation::{ Validate, validators };
+use api_openai::components::chat_shared::
+{
+  ChatCompletionRequest,
+  ChatCompletionRequestMessage,
+  ChatCompletionRequestMessageContent,
+  ChatCompletionStreamOptions,
+  ChatCompletionTool,
+};
+use api_openai::components::tools::ToolChoice;
+
+fn request( model : &str ) -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( model.to_string() )
+    .messages( vec![ ChatCompletionRequestMessage::former().role( "user".to_string() ).content( ChatCompletionRequestMessageContent::Text( "Hi".to_string() ) ).form() ] )
+    .form()
+}
+
+fn fields( request : &ChatCompletionRequest ) -> Vec< String >
+{
+  request.validate().err().unwrap_or_default().into_iter().map( | e | e.field ).collect()
+}
+
+#[ test ]
+fn validate_reports_every_violation()
+{
+  let mut invalid = request( "gpt-4o-mini" );
+  invalid.temperature = Some( 2.5 );
+  invalid.top_p = Some( 1.5 );
+  invalid.max_tokens = Some( 100 );
+  invalid.max_completion_tokens = Some( 100 );
+  invalid.top_logprobs = Some( 5 );
+  invalid.tools = Some( vec![ ChatCompletionTool { r#type : "function".to_string(), function : None }; 129 ] );
+  invalid.stream_options = Some( ChatCompletionStreamOptions::default() );
+
+  assert_eq!( fields( &invalid ), [ "temperature", "top_p", "tools", "max_tokens", "top_logprobs", "stream_options" ] );
+
+  let mut choice = request( "gpt-4o-mini" );
+  choice.tool_choice = Some( ToolChoice::auto() );
+  assert_eq!( fields( &choice ), [ "tool_choice" ] );
+
+  let mut valid = request( "gpt-4o-mini" );
+  valid.temperature = Some( 0.2 );
+  valid.logprobs = Some( true );
+  valid.top_logprobs = Some( 5 );
+  assert!( valid.validate().is_ok() );
+}
+
+#[ test ]
+fn validate_rejects_fixed_parameters_of_reasoning_models()
+{
+  let mut reasoning = request( "o3-mini" );
+  reasoning.temperature = Some( 0.2 );
+  reasoning.max_tokens = Some( 100 );
+  reasoning.logprobs = Some( true );
+  let errors = reasoning.validate().expect_err( "sampling is fixed" );
+  assert_eq!( errors.iter().map( | e | e.field.as_str() ).collect::< Vec< _ > >(), [ "temperature", "max_tokens", "logprobs" ] );
+  assert_eq!( errors[ 1 ].constraint.as_deref(), Some( "use max_completion_tokens" ) );
+
+  let mut defaults = request( "ft:o4-mini-2025-04-16:acme::abc123" );
+  defaults.temperature = Some( 1.0 );
+  defaults.max_completion_tokens = Some( 100 );
+  assert!( defaults.validate().is_ok() );
+
+  let mut chat = request( "gpt-5-chat-latest" );
+  chat.temperature = Some( 0.2 );
+  chat.max_tokens = Some( 100 );
+  assert!( chat.validate().is_ok() );
+
+  assert!( validators::is_reasoning_model( "o1" ) );
+  assert!( validators::is_reasoning_model( "gpt-5-mini" ) );
+  assert!( !validators::is_reasoning_model( "gpt-4o" ) );
+  assert!( !validators::is_reasoning_model( "o1x" ) );
+}
+
+#[ tokio::test ]
+async fn create_fails_with_the_violations_before_sending()
+{
+  // Nothing listens on the discard port; a request that was sent would fail
+  // with a network error instead.
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-chat-validation-test-0123456789ab".to_string() ),
+    None,
+    None,
+    "http://127.0.0.1:9/v1/".to_string(),
+    OpenAIRecommended::realtime_base_url().to_string(),
+  )
+  .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let mut invalid = request( "" );
+  invalid.temperature = Some( 3.0 );
+
+  let error = client.chat().create( invalid ).await.expect_err( "request is invalid" );
+
+  match error.downcast_ref::< OpenAIError >()
+  {
+    Some( OpenAIError::Validation( violations ) ) =>
+    {
+      assert_eq!( violations.iter().map( | e | e.field.as_str() ).collect::< Vec< _ > >(), [ "model", "temperature" ] );
+    },
+    other => panic!( "expected a validation error, got {other:?}" ),
+  }
+  assert!( error.to_string().starts_with( "Request validation failed : Validation error for field 'model'" ), "{error}" );
+}
@@ -0,0 +1,155 @@
+//! Tests for the typed items of a streamed chat completion.
+//!
+//! Unit tests split recorded stream chunks into their items, as
+//! `Chat::create_stream` does for each server-sent event; the integration
+//! test streams a real completion.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `ChatCompletionRequest` | `stream_options` set | `stream` and `stream_options` serialized |
+//! | `ChatCompletionStreamResponse::into_chunks` | Content, finish, usage chunk | Role-only chunk skipped, usage item last |
+//! | `ChatCompletionStreamResponse::into_chunks` | Tool call argument fragments, refusal | Fragments typed by call `index`, id and name in the first only |
+//! | `IntoStreamEvents for ChatCompletionChunk` | Content, tool call, finish, usage | Neutral events for the CLI stream |
+//! | `Chat::create_stream` (integration) | Short answer with usage | Content items, then a finish, then the usage item |
+
+use api_openai::components::chat_shared::
+{
+  ChatCompletionChunk,
+  ChatCompletionRequest,
+  ChatCompletionRequestMessage,
+  ChatCompletionRequestMessageContent,
+  ChatCompletionStreamOptions,
+  ChatCompletionStreamResponse,
+};
+
+fn request() -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .messages( vec![ ChatCompletionRequestMessage::former().role( "user".to_string() ).content( ChatCompletionRequestMessageContent::Text( "Hi".to_string() ) ).form() ] )
+    .stream( true )
+    .form()
+}
+
+fn chunk( choices : &str ) -> String
+{
+  format!( r#"{{"id":"chatcmpl-abc","object":"chat.completion.chunk","created":1,"model":"gpt-4o-mini","choices":{choices}}}"# )
+}
+
+/// The items of each chunk, in order, as the stream yields them.
+fn collect( chunks : &[ &str ] ) -> Vec< ChatCompletionChunk >
+{
+  chunks.iter()
+    .flat_map( | data | serde_json::from_str::< ChatCompletionStreamResponse >( data ).expect( "chunks must parse" ).into_chunks() )
+    .collect()
+}
+
+#[ test ]
+fn stream_options_are_sent()
+{
+  let mut request = request();
+  request.stream_options = Some( ChatCompletionStreamOptions { include_usage : Some( true ) } );
+
+  let body = serde_json::to_string( &request ).expect( "request serializes" );
+
+  assert!( body.contains( r#""stream":true,"stream_options":{"include_usage":true}"# ), "{body}" );
+}
+
+#[ test ]
+fn content_finish_and_usage_are_typed_items()
+{
+  let role = chunk( r#"[{"index":0,"delta":{"role":"assistant","content":""},"finish_reason":null}]"# );
+  let hello = chunk( r#"[{"index":0,"delta":{"content":"Hel"},"finish_reason":null}]"# );
+  let last = chunk( r#"[{"index":0,"delta":{"content":"lo"},"finish_reason":"stop"}]"# );
+  let usage = r#"{"id":"chatcmpl-abc","object":"chat.completion.chunk","created":1,"model":"gpt-4o-mini","choices":[],"usage":{"prompt_tokens":8,"completion_tokens":2,"total_tokens":10}}"#;
+
+  let items = collect( &[ &role, &hello, &last, usage ] );
+
+  assert_eq!( items.len(), 4, "{items:?}" );
+  assert_eq!( items[ 0 ], ChatCompletionChunk::Content { choice : 0, text : "Hel".to_string() } );
+  assert_eq!( items[ 1 ], ChatCompletionChunk::Content { choice : 0, text : "lo".to_string() } );
+  assert_eq!( items[ 2 ], ChatCompletionChunk::Finish { choice : 0, reason : "stop".to_string() } );
+  let ChatCompletionChunk::Usage( usage ) = &items[ 3 ] else { panic!( "usage comes last: {items:?}" ) };
+  assert_eq!( ( usage.prompt_tokens, usage.completion_tokens, usage.total_tokens ), ( 8, 2, 10 ) );
+}
+
+#[ test ]
+fn tool_call_fragments_and_refusals_are_typed_items()
+{
+  let first = chunk( r#"[{"index":0,"delta":{"role":"assistant","tool_calls":[{"index":0,"id":"call_abc","type":"function","function":{"name":"get_weather","arguments":""}}]},"finish_reason":null}]"# );
+  let second = chunk( r#"[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\":"}}]},"finish_reason":null}]"# );
+  let third = chunk( r#"[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"\"Paris\"}"}}]},"finish_reason":"tool_calls"},{"index":1,"delta":{"refusal":"I can't help with that."},"finish_reason":null}]"# );
+
+  let items = collect( &[ &first, &second, &third ] );
+
+  let calls : Vec< _ > = items.iter().filter_map( | item | match item
+  {
+    ChatCompletionChunk::ToolCall { choice : 0, call } => Some( call ),
+    _ => None,
+  } ).collect();
+  assert_eq!( calls.len(), 3, "{items:?}" );
+  assert_eq!( ( calls[ 0 ].id.as_deref(), calls[ 0 ].function.as_ref().and_then( | f | f.name.as_deref() ) ), ( Some( "call_abc" ), Some( "get_weather" ) ) );
+  assert!( calls[ 1 ..].iter().all( | call | call.id.is_none() && call.index == 0 ), "{calls:?}" );
+  let arguments : String = calls.iter().filter_map( | call | call.function.as_ref()?.arguments.clone() ).collect();
+  assert_eq!( arguments, r#"{"city":"Paris"}"# );
+  assert!( items.contains( &ChatCompletionChunk::Finish { choice : 0, reason : "tool_calls".to_string() } ), "{items:?}" );
+  assert_eq!( items.last(), Some( &ChatCompletionChunk::Refusal { choice : 1, text : "I can't help with that.".to_string() } ) );
+}
+
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn chunks_translate_into_neutral_events()
+{
+  use api_common::{ IntoStreamEvents, StreamEvent, ToolCallDelta, UsageUpdate };
+
+  let tools = chunk( r#"[{"index":0,"delta":{"content":"Checking","tool_calls":[{"index":1,"id":"call_abc","type":"function","function":{"name":"get_weather","arguments":"{}"}}]},"finish_reason":"tool_calls"}]"# );
+  let usage = r#"{"id":"chatcmpl-abc","object":"chat.completion.chunk","created":1,"model":"gpt-4o-mini","choices":[],"usage":{"prompt_tokens":8,"completion_tokens":2,"total_tokens":10}}"#;
+
+  let mut events = Vec::new();
+  for item in collect( &[ &tools, usage ] )
+  {
+    events.extend( item.into_stream_events().expect( "items translate" ) );
+  }
+
+  assert_eq!( events, vec!
+  [
+    StreamEvent::TextDelta( "Checking".to_string() ),
+    StreamEvent::ToolCallDelta( ToolCallDelta { index : 1, id : Some( "call_abc".to_string() ), name : Some( "get_weather".to_string() ), arguments : "{}".to_string() } ),
+    StreamEvent::Done { finish_reason : Some( "tool_calls".to_string() ) },
+    StreamEvent::UsageUpdate( UsageUpdate { input_tokens : Some( 8 ), output_tokens : Some( 2 ) } ),
+  ] );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_stream_ends_with_finish_and_usage()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let mut request = request();
+  request.max_tokens = Some( 16 );
+  request.stream_options = Some( ChatCompletionStreamOptions { include_usage : Some( true ) } );
+
+  let mut receiver = client.chat().create_stream( request ).await.expect( "INTEGRATION: stream must start" );
+  let mut items = Vec::new();
+  while let Some( item ) = receiver.recv().await
+  {
+    items.push( item.expect( "INTEGRATION: items must parse" ) );
+  }
+
+  assert!( items.iter().any( | item | matches!( item, ChatCompletionChunk::Content { choice : 0, .. } ) ), "{items:?}" );
+  assert!( items.iter().any( | item | matches!( item, ChatCompletionChunk::Finish { choice : 0, .. } ) ), "{items:?}" );
+  assert!( matches!( items.last(), Some( ChatCompletionChunk::Usage( _ ) ) ), "usage comes last: {items:?}" );
+}
@@ -0,0 +1,78 @@
+//! Tests for tool calling in chat completions, whose tool calls and tool
+//! choice are the wire types shared with `api_openai_compatible`.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `ChatCompletionResponseMessage` | Two parallel calls, arguments as a string and as an object | Each call read with its `id`; arguments kept as a JSON string |
+//! | `ChatCompletionRequestMessage` | Assistant calls sent back, tool result | `tool_calls` and `tool_call_id` serialized as received |
+//! | `ChatCompletionRequest` | Mode and named function choice | `tool_choice` as a string or a `function` object |
+
+use api_openai::components::chat_shared::
+{
+  ChatCompletionRequest,
+  ChatCompletionRequestMessage,
+  ChatCompletionRequestMessageContent,
+  CreateChatCompletionResponse,
+  ToolCall,
+};
+use api_openai::components::tools::ToolChoice;
+
+const RESPONSE : &str = r#"{"id":"chatcmpl-abc","object":"chat.completion","created":1,"model":"gpt-4o-mini","choices":[{"index":0,"finish_reason":"tool_calls","message":{"role":"assistant","content":null,"tool_calls":[{"id":"call_1","type":"function","function":{"name":"get_weather","arguments":"{\"city\":\"Paris\"}"}},{"id":"call_2","type":"function","function":{"name":"get_weather","arguments":{"city":"Rome"}}}]}}]}"#;
+
+#[ derive( serde::Deserialize ) ]
+struct Weather
+{
+  city : String,
+}
+
+#[ test ]
+fn parallel_calls_are_read_with_their_ids()
+{
+  let response : CreateChatCompletionResponse = serde_json::from_str( RESPONSE ).expect( "response must parse" );
+
+  let calls = response.choices[ 0 ].message.tool_calls.clone().expect( "tool calls must be read" );
+  assert_eq!( calls.iter().map( | call | call.id.as_str() ).collect::< Vec< _ > >(), [ "call_1", "call_2" ] );
+  assert_eq!( calls[ 0 ], ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# ) );
+  assert_eq!( calls[ 1 ].function.arguments, r#"{"city":"Rome"}"# );
+  let cities : Vec< String > = calls.iter()
+    .map( | call | call.function.parse_arguments::< Weather >().expect( "arguments must parse" ).city )
+    .collect();
+  assert_eq!( cities, [ "Paris", "Rome" ] );
+}
+
+#[ test ]
+fn calls_and_results_are_sent_back()
+{
+  let call = ChatCompletionRequestMessage::former()
+    .role( "assistant".to_string() )
+    .tool_calls( vec![ ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# ) ] )
+    .form();
+  let result = ChatCompletionRequestMessage::former()
+    .role( "tool".to_string() )
+    .content( ChatCompletionRequestMessageContent::Text( "Sunny.".to_string() ) )
+    .tool_call_id( "call_1".to_string() )
+    .form();
+
+  assert_eq!
+  (
+    serde_json::to_string( &call ).expect( "message serializes" ),
+    r#"{"role":"assistant","tool_calls":[{"id":"call_1","type":"function","function":{"name":"get_weather","arguments":"{\"city\":\"Paris\"}"}}]}"#
+  );
+  assert_eq!( serde_json::to_string( &result ).expect( "message serializes" ), r#"{"role":"tool","content":"Sunny.","tool_call_id":"call_1"}"# );
+}
+
+#[ test ]
+fn tool_choice_is_a_mode_or_a_function()
+{
+  let mut request = ChatCompletionRequest::former().model( "gpt-4o-mini".to_string() ).messages( Vec::new() ).form();
+
+  request.tool_choice = Some( ToolChoice::auto() );
+  let body = serde_json::to_value( &request ).expect( "request serializes" );
+  assert_eq!( body[ "tool_choice" ], "auto" );
+
+  request.tool_choice = Some( ToolChoice::function( "get_weather" ) );
+  let body = serde_json::to_value( &request ).expect( "request serializes" );
+  assert_eq!( body[ "tool_choice" ], serde_json::json!( { "type" : "function", "function" : { "name" : "get_weather" } } ) );
+}
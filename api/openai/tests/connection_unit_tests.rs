@@ -0,0 +1,63 @@
+//! Tests for connection tuning and `Client::warm_up`.
+//!
+//! The connection failure and rebuild cases need no server; the warm-up of
+//! the real API base URL runs with the `integration` feature.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `warm_up` (integration) | Real base URL answering 404 | `HEAD` to the base URL succeeds |
+//! | `warm_up` | Nothing listening | Connection failure is an error |
+//! | `with_connection_tuning` | Low-latency preset | Client rebuilds |
+//! | `with_connection_tuning` | Malformed proxy URL | Rebuild is refused |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+
+fn client( base_url : String ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-connection-test-0123456789abcdef".to_string() ),
+    None,
+    None,
+    base_url,
+    OpenAIRecommended::realtime_base_url().to_string(),
+  )
+  .expect( "environment builds" );
+  Client::build( environment ).expect( "client builds" )
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_warm_up_reaches_the_api()
+{
+  // The base URL itself answers 404; any status counts as a warm connection
+  let client = client( OpenAIRecommended::base_url().to_string() );
+  assert!( client.warm_up().await.is_ok() );
+}
+
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  assert!( client( "http://127.0.0.1:1/".to_string() ).warm_up().await.is_err() );
+}
+
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_rebuilds_client()
+{
+  let proxy = client( "http://127.0.0.1:1/".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+  assert!( proxy.is_err(), "a malformed proxy URL must be refused" );
+
+  let client = client( "http://127.0.0.1:1/".to_string() )
+    .with_connection_tuning( api_common::ConnectionTuning::low_latency() );
+
+  assert!( client.is_ok(), "{:?}", client.err() );
+}
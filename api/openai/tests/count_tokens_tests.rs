@@ -0,0 +1,105 @@
+//! Tests for local token counting and `TokenBudget`.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `count_tokens` | `o200k_base` and `cl100k_base` models, unknown model | Encoding chosen per model, error for an unknown one |
+//! | `count_tokens_for_messages` | Message framing, name, request tools | Chat format overhead counted |
+//! | `TokenBudget::truncate` | History over budget, tool results, too small | Instructions kept, oldest dropped with their tool results, error when nothing fits |
+
+#![ cfg( feature = "count_tokens" ) ]
+
+use api_openai::exposed::{ count_tokens, count_tokens_for_messages, count_tokens_for_request, TokenBudget };
+use api_openai::components::chat_shared::
+{
+  ChatCompletionRequest,
+  ChatCompletionRequestMessage,
+  ChatCompletionRequestMessageContent,
+  ChatCompletionTool,
+  ToolCall,
+};
+
+fn message( role : &str, text : &str ) -> ChatCompletionRequestMessage
+{
+  ChatCompletionRequestMessage::former()
+    .role( role.to_string() )
+    .content( ChatCompletionRequestMessageContent::Text( text.to_string() ) )
+    .form()
+}
+
+fn roles( messages : &[ ChatCompletionRequestMessage ] ) -> Vec< &str >
+{
+  messages.iter().map( | message | message.role.as_str() ).collect()
+}
+
+#[ test ]
+fn count_tokens_uses_the_encoding_of_the_model()
+{
+  assert_eq!( count_tokens( "Hello, world!", "gpt-4o-mini" ).expect( "o200k_base" ), 4 );
+  assert_eq!( count_tokens( "Hello, world!", "gpt-4" ).expect( "cl100k_base" ), 4 );
+  // The encodings split some text differently.
+  let text = "नमस्ते दुनिया, आप कैसे हैं?";
+  assert_ne!( count_tokens( text, "gpt-4o" ).expect( "o200k_base" ), count_tokens( text, "gpt-3.5-turbo" ).expect( "cl100k_base" ) );
+
+  let error = count_tokens( "Hello", "not-a-model" ).expect_err( "unknown model" );
+  assert!( error.to_string().contains( "No tokenizer for model not-a-model" ), "{error}" );
+}
+
+#[ test ]
+fn count_tokens_for_messages_adds_the_chat_framing()
+{
+  // 3 of framing, 1 for the role, 4 of content, and 3 of reply priming.
+  assert_eq!( count_tokens_for_messages( &[ message( "user", "Hello, world!" ) ], "gpt-4o" ).expect( "count" ), 11 );
+
+  let mut named = message( "user", "Hello, world!" );
+  named.name = Some( "alice".to_string() );
+  let named_count = count_tokens_for_messages( &[ named ], "gpt-4o" ).expect( "count" );
+  assert_eq!( named_count, 11 + count_tokens( "alice", "gpt-4o" ).expect( "count" ) + 1 );
+
+  let mut request = ChatCompletionRequest::former()
+    .model( "gpt-4o".to_string() )
+    .messages( vec![ message( "user", "Hello, world!" ) ] )
+    .form();
+  assert_eq!( count_tokens_for_request( &request ).expect( "count" ), 11 );
+  request.tools = Some( vec![ ChatCompletionTool { r#type : "function".to_string(), function : None } ] );
+  assert!( count_tokens_for_request( &request ).expect( "count" ) > 11 );
+}
+
+#[ test ]
+fn truncate_keeps_instructions_and_the_most_recent_messages()
+{
+  let mut call = message( "assistant", "" );
+  call.content = None;
+  call.tool_calls = Some( vec![ ToolCall::function( "call_1", "lookup", r#"{"q":"weather"}"# ) ] );
+  let mut result = message( "tool", "Sunny and warm all week." );
+  result.tool_call_id = Some( "call_1".to_string() );
+  let history = vec!
+  [
+    message( "system", "You are terse." ),
+    message( "user", "First question, long enough to matter for the budget." ),
+    call,
+    result,
+    message( "assistant", "It is sunny." ),
+    message( "user", "Thanks!" ),
+  ];
+
+  let everything = count_tokens_for_messages( &history, "gpt-4o" ).expect( "count" );
+  assert_eq!( TokenBudget::new( everything ).truncate( &history, "gpt-4o" ).expect( "fits" ), history );
+  assert!( TokenBudget::new( everything ).fits( &history, "gpt-4o" ).expect( "count" ) );
+
+  // Dropping the first question is not enough; the tool call goes with its result.
+  let first = count_tokens_for_messages( &history[ 1..2 ], "gpt-4o" ).expect( "count" ) - 3;
+  let budget = TokenBudget::new( everything + 100 ).with_reserved_for_output( 100 + first + 1 );
+  assert_eq!( budget.available(), everything - first - 1 );
+  let kept = budget.truncate( &history, "gpt-4o" ).expect( "fits" );
+  assert_eq!( roles( &kept ), [ "system", "assistant", "user" ] );
+  assert!( budget.fits( &kept, "gpt-4o" ).expect( "count" ) );
+  assert!( !budget.fits( &history, "gpt-4o" ).expect( "count" ) );
+
+  let error = TokenBudget::new( 10 ).truncate( &history, "gpt-4o" ).expect_err( "too small" );
+  assert!( error.to_string().contains( "do not fit a budget of 10 tokens" ), "{error}" );
+
+  assert_eq!( TokenBudget::for_model( "gpt-4o" ).map( | budget | budget.context_window ), Some( 128_000 ) );
+  assert_eq!( TokenBudget::for_model( "not-a-model" ), None );
+}
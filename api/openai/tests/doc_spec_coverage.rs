@@ -64,9 +64,11 @@ fn in_01_optional_fields_absent_from_json_when_none()
     temperature : None,
     top_p : None,
     max_tokens : None,
+    max_completion_tokens : None,
     n : None,
     stop : None,
     stream : None,
+    stream_options : None,
     system_prompt : None,
     user : None,
     tools : None,
@@ -200,6 +202,7 @@ mod integration
     secret ::Secret,
     components ::chat_shared ::
     {
+      ChatCompletionChunk,
       ChatCompletionRequest,
       ChatCompletionRequestMessage,
       ChatCompletionRequestMessageContent,
@@ -243,9 +246,11 @@ mod integration
       temperature : None,
       top_p : None,
       max_tokens : Some( 20 ),
+      max_completion_tokens : None,
       n : None,
       stop : None,
       stream : None,
+      stream_options : None,
       system_prompt : None,
       user : None,
       tools : None,
@@ -401,9 +406,8 @@ mod integration
   ///
   /// Given a `ChatCompletionRequest` with the `streaming` feature enabled, when
   /// `client.chat().create_stream(request).await` is invoked, then the method returns
-  /// `Ok(mpsc::Receiver<Result<ChatCompletionStreamResponse>>)` — not raw bytes or untyped
-  /// JSON — and receiving at least one chunk yields a typed struct with `.choices[0].delta`
-  /// containing typed fields.
+  /// `Ok(mpsc::Receiver<Result<ChatCompletionChunk>>)` — not raw bytes or untyped
+  /// JSON — and the items received are typed: content text for the choice, then its finish.
   #[ cfg( feature = "integration" ) ]
   #[ tokio::test ]
   async fn pt_03_streaming_returns_receiver_of_typed_events()
@@ -425,10 +429,12 @@ mod integration
       ],
       stream : Some( true ),
       max_tokens : Some( 20 ),
+      max_completion_tokens : None,
       temperature : None,
       top_p : None,
       n : None,
       stop : None,
+      stream_options : None,
       system_prompt : None,
       user : None,
       tools : None,
@@ -444,6 +450,7 @@ mod integration
       .expect( "chat().create_stream() must succeed" );
 
     let mut chunk_count = 0usize;
+    let mut content_seen = false;
     let timeout = core::time::Duration::from_secs( 15 );
     let start = std::time::Instant::now();
 
@@ -455,30 +462,21 @@ mod integration
       }
       let chunk = chunk_result.expect( "stream chunk must not be an error" );
 
-      // Verify the chunk is a typed struct with typed fields — not serde_json::Value
-      assert!(
-        !chunk.id.is_empty(),
-        "stream chunk must have a non-empty id"
-      );
-      assert!(
-        !chunk.object.is_empty(),
-        "stream chunk must have a non-empty object"
-      );
-
-      // Each choice has a typed `delta` field (ChatCompletionStreamResponseMessage).
-      // Verify the typed accessors compile and produce the expected types — not raw JSON.
-      for choice in &chunk.choices
+      // Each item is a typed variant — not serde_json::Value
+      match &chunk
       {
-        // delta.content and delta.role are Option<String> typed fields
-        assert!(
-          choice.delta.content.as_deref().map_or( true, | s | s.is_ascii() || !s.is_empty() ),
-          "delta.content when present must be a valid string"
-        );
-        // delta.tool_calls being None is expected in text streaming
-        assert!(
-          choice.delta.tool_calls.is_none() || choice.delta.tool_calls.as_ref().is_some_and( | v | !v.is_empty() ),
-          "delta.tool_calls must be None or non-empty when present"
-        );
+        ChatCompletionChunk::Content { choice, text } =>
+        {
+          assert_eq!( *choice, 0, "a single choice was asked for" );
+          assert!( !text.is_empty(), "content items carry text" );
+          content_seen = true;
+        },
+        ChatCompletionChunk::Finish { reason, .. } =>
+        {
+          assert!( !reason.is_empty(), "finish items carry the reason" );
+        },
+        ChatCompletionChunk::ToolCall { .. } => panic!( "no tools were offered : {chunk:?}" ),
+        _ => {},
       }
 
       chunk_count += 1;
@@ -488,5 +486,6 @@ mod integration
       chunk_count > 0,
       "Must receive at least one typed stream chunk from chat().create_stream()"
     );
+    assert!( content_seen, "Must receive content from chat().create_stream()" );
   }
 }
@@ -0,0 +1,108 @@
+//! Tests for the embeddings client and the embedding utilities.
+//!
+//! Unit tests check the request body sent and the recorded Embeddings API
+//! body parsed; the integration test embeds text with the real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateEmbeddingRequest` | Token batch, `dimensions`, `base64` | Request body serialized |
+//! | `CreateEmbeddingResponse` | `base64` vectors | Vectors decoded to `f32` |
+//! | `Embeddings::create` (integration) | `dimensions`, `base64` | Real vectors have the asked dimensions |
+//! | `decode_base64_embedding` | Ragged bytes | Error instead of a truncated vector |
+//! | `normalize`, `cosine_similarity` | Scaled, orthogonal, mismatched, zero vectors | Unit length, similarity range, errors |
+
+use api_openai::exposed::
+{
+  cosine_similarity,
+  decode_base64_embedding,
+  l2_norm,
+  normalize,
+};
+use api_openai::components::embeddings::CreateEmbeddingResponse;
+use api_openai::components::embeddings_request::
+{
+  CreateEmbeddingRequest,
+  EmbeddingEncodingFormat,
+  EmbeddingInput,
+};
+
+#[ test ]
+fn request_serializes_tokens_dimensions_and_format()
+{
+  let mut request = CreateEmbeddingRequest::new_single( String::new(), "text-embedding-3-small".to_string() );
+  request.input = EmbeddingInput::TokenBatch( vec![ vec![ 9906, 1917 ], vec![ 15339 ] ] );
+  request.dimensions = Some( 3 );
+  request.encoding_format = Some( EmbeddingEncodingFormat::Base64 );
+
+  assert_eq!
+  (
+    serde_json::to_string( &request ).expect( "request serializes" ),
+    r#"{"input":[[9906,1917],[15339]],"model":"text-embedding-3-small","dimensions":3,"encoding_format":"base64"}"#
+  );
+}
+
+#[ test ]
+fn response_decodes_base64_vectors()
+{
+  // [ 1.0, -0.5, 0.25 ] and [ 0.0 ] as little-endian f32 bytes.
+  let body = r#"{"object":"list","data":[{"object":"embedding","index":1,"embedding":"AAAAAA=="},{"object":"embedding","index":0,"embedding":"AACAPwAAAL8AAIA+"}],"model":"text-embedding-3-small","usage":{"prompt_tokens":5,"total_tokens":5}}"#;
+
+  let response : CreateEmbeddingResponse = serde_json::from_str( body ).expect( "response must parse" );
+
+  assert_eq!( response.data[ 1 ].index, 0 );
+  assert_eq!( response.data[ 1 ].embedding, vec![ 1.0, -0.5, 0.25 ] );
+  assert_eq!( response.data[ 0 ].embedding, vec![ 0.0 ] );
+}
+
+#[ test ]
+fn decode_base64_embedding_rejects_ragged_bytes()
+{
+  assert_eq!( decode_base64_embedding( "AACAPwAAAL8AAIA+" ).expect( "three floats" ), vec![ 1.0, -0.5, 0.25 ] );
+  // Five bytes: one float and a stray byte.
+  assert!( decode_base64_embedding( "AACAPwA=" ).is_err() );
+  assert!( decode_base64_embedding( "not base64!" ).is_err() );
+}
+
+#[ test ]
+fn utilities_normalize_and_compare()
+{
+  let mut vector = vec![ 3.0, 4.0 ];
+  normalize( &mut vector ).expect( "non-zero" );
+  assert!( ( l2_norm( &vector ) - 1.0 ).abs() < 1e-6 );
+  assert!( normalize( &mut [ 0.0, 0.0 ] ).is_err() );
+
+  let same = cosine_similarity( &[ 1.0, 2.0, 3.0 ], &[ 2.0, 4.0, 6.0 ] ).expect( "same dimension" );
+  assert!( same <= 1.0 && ( same - 1.0 ).abs() < 1e-6 );
+  assert!( cosine_similarity( &[ 1.0, 0.0 ], &[ 0.0, 1.0 ] ).expect( "same dimension" ).abs() < 1e-6 );
+  assert!( ( cosine_similarity( &[ 1.0, 0.0 ], &[ -1.0, 0.0 ] ).expect( "same dimension" ) + 1.0 ).abs() < 1e-6 );
+  assert!( cosine_similarity( &[ 1.0, 0.0 ], &[ 1.0 ] ).is_err() );
+  assert!( cosine_similarity( &[ 0.0, 0.0 ], &[ 1.0, 0.0 ] ).is_err() );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_create_returns_vectors_of_the_asked_dimensions()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+
+  let mut request = CreateEmbeddingRequest::new_single( "The quick brown fox".to_string(), "text-embedding-3-small".to_string() );
+  request.dimensions = Some( 8 );
+  request.encoding_format = Some( EmbeddingEncodingFormat::Base64 );
+  let response = client.embeddings().create( request ).await.expect( "INTEGRATION: embeddings must be created" );
+
+  assert_eq!( response.data.len(), 1 );
+  assert_eq!( response.data[ 0 ].embedding.len(), 8 );
+}
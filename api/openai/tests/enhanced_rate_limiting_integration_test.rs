@@ -15,6 +15,7 @@ mod rate_limiting_integration_tests
     secret ::Secret,
     enhanced_rate_limiting ::{ EnhancedRateLimitingConfig, RateLimitingAlgorithm },
   };
+  use api_common::{ ConcurrencyGate, RateLimits, SharedRateLimiter };
 
   use core::time::Duration;
   use tokio::time::sleep;
@@ -117,6 +118,61 @@ mod rate_limiting_integration_tests
     }
   }
 
+  #[ tokio::test ]
+  async fn test_shared_rate_limiter_spans_clients()
+  {
+    let build_client = | limiter : &SharedRateLimiter |
+    {
+      let secret = Secret::new( "sk-test-key-shared-limiter".to_string() ).unwrap();
+      let environment = OpenaiEnvironmentImpl::build(
+        secret,
+        None,
+        None,
+        "http://127.0.0.1:9/v1/".to_string(),
+        OpenAIRecommended::realtime_base_url().to_string()
+      ).unwrap();
+      Client::build( environment ).unwrap().with_shared_rate_limiter( limiter.clone() )
+    };
+
+    let limiter = SharedRateLimiter::new( RateLimits::new().with_requests( 3, Duration::from_secs( 3600 ) ) );
+    let first = build_client( &limiter );
+    let second = build_client( &limiter );
+
+    // Both requests fail to connect, but each takes one shared request first
+    assert!( first.models().list().await.is_err() );
+    assert!( second.models().list().await.is_err() );
+
+    assert_eq!( limiter.available_requests(), Some( 1 ) );
+    assert!( first.shared_rate_limiter().is_some_and( | shared | shared.shares_buckets_with( &limiter ) ) );
+  }
+
+  #[ tokio::test ]
+  async fn test_concurrency_gate_queue_timeout()
+  {
+    let secret = Secret::new( "sk-test-key-concurrency-gate".to_string() ).unwrap();
+    let environment = OpenaiEnvironmentImpl::build(
+      secret,
+      None,
+      None,
+      "http://127.0.0.1:9/v1/".to_string(),
+      OpenAIRecommended::realtime_base_url().to_string()
+    ).unwrap();
+    let gate = ConcurrencyGate::new( 1 ).with_queue_timeout( Duration::from_millis( 20 ) );
+    let client = Client::build( environment ).unwrap().with_concurrency_gate( gate.clone() );
+
+    // Another client sharing the gate holds its only slot
+    let held = gate.try_acquire().unwrap();
+    let error = client.models().list().await.unwrap_err();
+    assert!( error.to_string().contains( "queue" ), "expected a queue timeout : {error}" );
+    assert_eq!( gate.queued(), 0 );
+
+    // With the slot free the request is sent (and fails to connect)
+    drop( held );
+    let error = client.models().list().await.unwrap_err();
+    assert!( !error.to_string().contains( "queue" ) );
+    assert_eq!( gate.in_flight(), 0 );
+  }
+
   #[ tokio::test ]
   async fn test_rate_limiting_config_validation()
   {
@@ -612,6 +612,47 @@ mod failover_tests
     assert!( matches!( result.unwrap_err(), FailoverError::AllEndpointsFailed( _ ) ) );
   }
 
+  #[ cfg( feature = "retry" ) ]
+  #[ tokio::test ]
+  async fn test_failover_and_retry_share_call_budget()
+  {
+    use api_common::CallBudget;
+    use api_openai::{ enhanced_retry::{ EnhancedRetryConfig, EnhancedRetryExecutor }, error::OpenAIError };
+    use core::sync::atomic::{ AtomicU32, Ordering };
+
+    let mut endpoints : Vec< FailoverEndpoint > = ( 1..=3 )
+      .map( | i | FailoverEndpoint::new( format!( "test{i}" ), format!( "https://api.test{i}.com" ), 100 - i, Duration::from_secs( 30 ) ) )
+      .collect();
+    for endpoint in &mut endpoints
+    {
+      endpoint.update_health( EndpointHealth::Healthy );
+    }
+    let config = FailoverConfig { max_retries : 3, retry_delay_ms : 1, max_retry_delay_ms : 1, ..Default::default() };
+    let manager = FailoverManager::new( config, endpoints );
+
+    let retry = EnhancedRetryExecutor::new( EnhancedRetryConfig::new().with_max_attempts( 3 ).with_base_delay( 1 ).with_max_delay( 1 ).with_jitter( 0 ) ).unwrap();
+    let budget = CallBudget::new().with_max_attempts( 4 ).start();
+    let requests = AtomicU32::new( 0 );
+
+    // Three endpoints with three retries each would send nine requests on their own.
+    let result = FailoverExecutor::execute_with_failover_within( &manager, &budget, | _ctx |
+    {
+      let ( retry, budget, requests ) = ( &retry, &budget, &requests );
+      async move
+      {
+        retry.execute_within( budget, || async
+        {
+          requests.fetch_add( 1, Ordering::SeqCst );
+          Err::< (), _ >( OpenAIError::Network( "connection refused".to_string() ).into() )
+        } ).await
+      }
+    }).await;
+
+    assert!( result.is_err() );
+    assert_eq!( requests.load( Ordering::SeqCst ), 4 );
+    assert_eq!( budget.attempts(), 4 );
+  }
+
   // ===== EVENT NOTIFICATION TESTS =====
 
   #[ tokio::test ]
@@ -0,0 +1,133 @@
+//! Tests for the Files client: multipart upload, paginated listing, content
+//! download as bytes and as a stream, and delete.
+//!
+//! Unit tests check the query and purpose spellings the client sends and the
+//! recorded Files API bodies it parses; integration tests run the file
+//! lifecycle against the real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `FilePurpose` | Every purpose | Multipart `purpose` text matches the serde spelling |
+//! | `ListFilesQuery` | Purpose filter, page cursor | Query fields serialized in order; unset fields left out |
+//! | `ListFilesResponse::next_after` | Two pages | `last_id` while `has_more`, then `None` |
+//! | `FileObject` / `DeleteFileResponse` | Recorded bodies | File and deletion parsed |
+//! | `Files` (integration) | Upload, retrieve, list, content, delete | `batch` JSONL file round-trips; bytes and stream match the upload |
+//! | `Files::retrieve_content_stream` (integration) | Missing file | Error status is an error before any chunk |
+
+use api_openai::components::common::DeleteFileResponse;
+use api_openai::components::files::{ FileObject, FilePurpose, ListFilesQuery, ListFilesResponse };
+
+fn file_object( id : &str ) -> String
+{
+  format!( r#"{{"id":"{id}","object":"file","bytes":96,"created_at":1,"filename":"train.jsonl","purpose":"fine-tune"}}"# )
+}
+
+#[ test ]
+fn purposes_are_spelled_as_the_api_expects()
+{
+  for purpose in [ FilePurpose::Assistants, FilePurpose::Batch, FilePurpose::FineTune, FilePurpose::Vision, FilePurpose::UserData, FilePurpose::Evals ]
+  {
+    assert_eq!( serde_json::to_value( purpose ).expect( "purpose serializes" ), serde_json::json!( purpose.as_str() ) );
+  }
+  assert_eq!( FilePurpose::FineTune.as_str(), "fine-tune" );
+  assert_eq!( FilePurpose::UserData.as_str(), "user_data" );
+}
+
+#[ test ]
+fn list_query_serializes_only_what_is_set()
+{
+  let query = ListFilesQuery::former().purpose( FilePurpose::Batch ).limit( 2_u32 ).form();
+  assert_eq!( serde_json::to_string( &query ).expect( "query serializes" ), r#"{"purpose":"batch","limit":2}"# );
+
+  let mut next = query;
+  next.after = Some( "file-b".to_string() );
+  assert_eq!( serde_json::to_string( &next ).expect( "query serializes" ), r#"{"purpose":"batch","limit":2,"after":"file-b"}"# );
+  assert_eq!( serde_json::to_string( &ListFilesQuery::default() ).expect( "query serializes" ), "{}" );
+}
+
+#[ test ]
+fn next_after_follows_the_pages()
+{
+  let first = format!( r#"{{"object":"list","data":[{},{}],"first_id":"file-a","last_id":"file-b","has_more":true}}"#, file_object( "file-a" ), file_object( "file-b" ) );
+  let last = format!( r#"{{"object":"list","data":[{}],"first_id":"file-c","last_id":"file-c","has_more":false}}"#, file_object( "file-c" ) );
+
+  let first : ListFilesResponse = serde_json::from_str( &first ).expect( "first page must parse" );
+  let last : ListFilesResponse = serde_json::from_str( &last ).expect( "last page must parse" );
+
+  assert_eq!( ( first.data.len(), last.data.len() ), ( 2, 1 ) );
+  assert_eq!( first.next_after(), Some( "file-b" ) );
+  assert_eq!( last.next_after(), None );
+}
+
+#[ test ]
+fn file_and_deletion_bodies_parse()
+{
+  let file : FileObject = serde_json::from_str( &file_object( "file-abc" ) ).expect( "file must parse" );
+  let deleted : DeleteFileResponse = serde_json::from_str( r#"{"id":"file-abc","object":"file","deleted":true}"# ).expect( "deletion must parse" );
+
+  assert_eq!( ( file.id.as_str(), file.purpose.as_str(), file.bytes ), ( "file-abc", "fine-tune", 96 ) );
+  assert!( deleted.deleted );
+  assert_eq!( deleted.id, "file-abc" );
+}
+
+#[ cfg( feature = "integration" ) ]
+mod integration
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+  use api_openai::components::files::{ CreateFileRequest, FilePurpose, ListFilesQuery };
+
+  const CONTENT : &str = "{\"custom_id\":\"request-0\",\"method\":\"POST\",\"url\":\"/v1/chat/completions\",\"body\":{\"model\":\"gpt-4o-mini\",\"messages\":[{\"role\":\"user\",\"content\":\"Hi\"}]}}\n";
+
+  fn create_test_client() -> Client< OpenaiEnvironmentImpl >
+  {
+    let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+    let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+      .expect( "environment builds" );
+    Client::build( environment ).expect( "client builds" )
+  }
+
+  #[ tokio::test ]
+  async fn integration_file_round_trips()
+  {
+    let client = create_test_client();
+    let files = client.files();
+
+    let uploaded = files.upload( CreateFileRequest::new( CONTENT.as_bytes().to_vec(), "files_test.jsonl", FilePurpose::Batch ) ).await
+      .expect( "INTEGRATION: file must be uploaded" );
+    let retrieved = files.retrieve( &uploaded.id ).await.expect( "INTEGRATION: file must be retrieved" );
+    let listed = files.list( Some( ListFilesQuery::former().purpose( FilePurpose::Batch ).form() ) ).await
+      .expect( "INTEGRATION: files must be listed" );
+    let bytes = files.retrieve_content( &uploaded.id ).await.expect( "INTEGRATION: content must be downloaded" );
+    let mut chunks = files.retrieve_content_stream( &uploaded.id ).await.expect( "INTEGRATION: content stream must start" );
+    let mut streamed = Vec::new();
+    while let Some( chunk ) = chunks.recv().await
+    {
+      streamed.extend( chunk.expect( "INTEGRATION: chunk must be read" ) );
+    }
+    let deleted = files.delete( &uploaded.id ).await.expect( "INTEGRATION: file must be deleted" );
+
+    assert_eq!( ( uploaded.filename.as_str(), uploaded.purpose.as_str() ), ( "files_test.jsonl", "batch" ) );
+    assert_eq!( retrieved.id, uploaded.id );
+    assert!( listed.data.iter().all( | file | file.purpose == "batch" ), "{listed:?}" );
+    assert_eq!( bytes, CONTENT.as_bytes() );
+    assert_eq!( streamed, CONTENT.as_bytes() );
+    assert!( deleted.deleted );
+  }
+
+  #[ tokio::test ]
+  async fn integration_content_stream_of_missing_file_is_an_error()
+  {
+    let result = create_test_client().files().retrieve_content_stream( "file-missing0000000000000000" ).await;
+
+    let error = result.expect_err( "a missing file must not start a stream" );
+    assert!( error.to_string().contains( "No such File object" ), "{error}" );
+  }
+}
@@ -0,0 +1,189 @@
+//! Tests for the fine-tuning jobs client and the `TuningManager` sync with
+//! remote jobs.
+//!
+//! Unit tests check the request bodies built for the API and how recorded
+//! Fine-tuning API bodies are applied to local jobs; integration tests read
+//! jobs from the real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateFineTuningJobRequest` / `FineTuningListQuery` | Job with suffix, page limit | Only set fields serialized |
+//! | `TuningJob::apply_remote_job` / `apply_remote_checkpoints` | Succeeded job synced twice | Status and checkpoints follow the remote job, no duplicates |
+//! | Response bodies | Job list, events | Jobs and events parsed |
+//! | `TuningManager::sync_job` | Job never linked | Error, no request sent |
+//! | `FineTuning` (integration) | List, events, checkpoints; missing job | Real jobs listed and read; unknown ID is an error |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::ClientApiAccessors;
+use api_openai::components::fine_tuning_shared::
+{
+  CreateFineTuningJobRequest,
+  FineTuningJob,
+  FineTuningListQuery,
+  ListFineTuningJobCheckpointsResponse,
+  ListFineTuningJobEventsResponse,
+  ListPaginatedFineTuningJobsResponse,
+};
+use api_openai::
+{
+  CheckpointConfig,
+  FineTuningMethod,
+  HyperParameters,
+  TrainingDataConfig,
+  TrainingObjective,
+  TuningJob,
+  TuningJobConfig,
+  TuningManager,
+  TuningResourceRequirements,
+  TuningStatus,
+};
+use std::collections::HashMap;
+
+const CHECKPOINTS : &str = r#"{"object":"list","data":[{"object":"fine_tuning.job.checkpoint","id":"ftckpt_abc","created_at":1700000000,"fine_tuned_model_checkpoint":"ft:gpt-4o-mini:org::abc:ckpt-step-88","step_number":88,"metrics":{"step":88,"train_loss":0.42,"valid_loss":0.51},"fine_tuning_job_id":"ftjob-abc"}],"first_id":"ftckpt_abc","last_id":"ftckpt_abc","has_more":false}"#;
+
+fn job( status : &str ) -> String
+{
+  format!( r#"{{"object":"fine_tuning.job","id":"ftjob-abc","model":"gpt-4o-mini-2024-07-18","created_at":1,"finished_at":null,"fine_tuned_model":null,"organization_id":"org-123","result_files":[],"status":"{status}","validation_file":null,"training_file":"file-train","seed":7,"error":null,"trained_tokens":null}}"# )
+}
+
+fn config( job_name : &str ) -> TuningJobConfig
+{
+  TuningJobConfig
+  {
+    job_name : job_name.to_string(),
+    base_model : "gpt-4o-mini-2024-07-18".to_string(),
+    training_data : TrainingDataConfig
+    {
+      training_file : "file-train".to_string(),
+      validation_file : None,
+      data_format : "jsonl".to_string(),
+      max_sequence_length : 4096,
+      preprocessing : HashMap::new(),
+    },
+    hyperparameters : HyperParameters { epochs : 2, batch_size : 4, ..HyperParameters::default() },
+    method : FineTuningMethod::Full,
+    objective : TrainingObjective::SupervisedFineTuning,
+    resource_requirements : TuningResourceRequirements::default(),
+    checkpointing : CheckpointConfig::default(),
+    env_vars : HashMap::new(),
+  }
+}
+
+#[ test ]
+fn request_and_query_serialize_what_is_set()
+{
+  let mut request = CreateFineTuningJobRequest::new( "gpt-4o-mini-2024-07-18", "file-train" );
+  request.suffix = Some( "support".to_string() );
+  assert_eq!
+  (
+    serde_json::to_string( &request ).expect( "request serializes" ),
+    r#"{"model":"gpt-4o-mini-2024-07-18","training_file":"file-train","suffix":"support"}"#
+  );
+
+  let query = FineTuningListQuery::former().limit( 5_u32 ).form();
+  assert_eq!( serde_json::to_value( &query ).expect( "query serializes" ), serde_json::json!( { "limit" : 5 } ) );
+}
+
+#[ test ]
+fn local_job_follows_the_remote_job()
+{
+  let remote : FineTuningJob = serde_json::from_str( &job( "succeeded" ) ).expect( "job must parse" );
+  let checkpoints : ListFineTuningJobCheckpointsResponse = serde_json::from_str( CHECKPOINTS ).expect( "checkpoints must parse" );
+  let mut local = TuningJob::new( config( "support" ) );
+
+  for _ in 0..2
+  {
+    local.apply_remote_job( &remote );
+    local.apply_remote_checkpoints( &checkpoints.data );
+  }
+
+  assert_eq!( local.status, TuningStatus::Succeeded );
+  assert_eq!( local.checkpoints.len(), 1, "a checkpoint seen twice is added once" );
+  assert_eq!( ( local.checkpoints[ 0 ].step, local.checkpoints[ 0 ].loss ), ( 88, 0.42 ) );
+  assert_eq!( local.checkpoints[ 0 ].validation_metrics.get( "valid_loss" ), Some( &0.51 ) );
+}
+
+#[ test ]
+fn job_list_and_events_parse()
+{
+  let list = format!( r#"{{"object":"list","data":[{}],"has_more":false}}"#, job( "running" ) );
+  let events = r#"{"object":"list","data":[{"object":"fine_tuning.job.event","id":"ftevent-abc","created_at":1,"level":"info","message":"Fine-tuning job started","type":"message","data":null}],"has_more":false}"#;
+
+  let list : ListPaginatedFineTuningJobsResponse = serde_json::from_str( &list ).expect( "jobs must parse" );
+  let events : ListFineTuningJobEventsResponse = serde_json::from_str( events ).expect( "events must parse" );
+
+  assert_eq!( ( list.data.len(), list.data[ 0 ].status.as_str() ), ( 1, "running" ) );
+  assert_eq!( events.data[ 0 ].message, "Fine-tuning job started" );
+}
+
+#[ tokio::test ]
+async fn sync_of_unlinked_job_is_an_error()
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-fine-tuning-test-0123456789abcdef".to_string() ),
+    None,
+    None,
+    "http://127.0.0.1:9/v1/".to_string(),
+    OpenAIRecommended::realtime_base_url().to_string(),
+  )
+  .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let mut manager = TuningManager::new();
+  manager.create_job( config( "local_only" ) ).await.expect( "local job must be created" );
+
+  let error = manager.sync_job( "local_only", &client.fine_tuning() ).await.expect_err( "an unlinked job has nothing to sync" );
+
+  assert!( error.contains( "not linked" ), "{error}" );
+  assert_eq!( manager.get_job( "local_only" ).await.expect( "local job is kept" ).status, TuningStatus::Validating );
+}
+
+#[ cfg( feature = "integration" ) ]
+mod integration
+{
+  use super::*;
+
+  fn create_test_client() -> Client< OpenaiEnvironmentImpl >
+  {
+    let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+    let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+      .expect( "environment builds" );
+    Client::build( environment ).expect( "client builds" )
+  }
+
+  #[ tokio::test ]
+  async fn integration_jobs_are_listed_and_read()
+  {
+    let client = create_test_client();
+    let fine_tuning = client.fine_tuning();
+
+    let listed = fine_tuning.list_jobs( Some( FineTuningListQuery::former().limit( 5_u32 ).form() ) ).await
+      .expect( "INTEGRATION: jobs must be listed" );
+    assert!( listed.data.len() <= 5, "{listed:?}" );
+
+    if let Some( first ) = listed.data.first()
+    {
+      let retrieved = fine_tuning.retrieve_job( &first.id ).await.expect( "INTEGRATION: job must be retrieved" );
+      fine_tuning.list_job_events( &first.id, None ).await.expect( "INTEGRATION: events must be listed" );
+      fine_tuning.list_job_checkpoints( &first.id, None ).await.expect( "INTEGRATION: checkpoints must be listed" );
+      assert_eq!( retrieved.id, first.id );
+    }
+  }
+
+  #[ tokio::test ]
+  async fn integration_missing_job_is_an_error()
+  {
+    let client = create_test_client();
+
+    let result = client.fine_tuning().retrieve_job( "ftjob-missing000000000000000" ).await;
+
+    assert!( result.is_err(), "{result:?}" );
+  }
+}
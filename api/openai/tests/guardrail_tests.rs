@@ -0,0 +1,75 @@
+//! Guardrail Tests
+//!
+//! Validates that a client configured with `with_guardrails` passes JSON
+//! request bodies through the guardrail chain before anything is sent. The
+//! base URL is unreachable, so an error other than `GuardrailViolation` means
+//! the request went past the guardrails to the network.
+
+#[ cfg( feature = "guardrail" ) ]
+mod guardrail_tests
+{
+  use api_openai::
+  {
+    Client,
+    ClientApiAccessors,
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    components ::embeddings_request::CreateEmbeddingRequest,
+  };
+  use api_common::{ Guardrail, GuardrailStage, GuardrailViolation, Guardrails };
+
+  /// Denies requests for the `blocked` model.
+  struct DenyModel;
+
+  #[ allow( clippy::unnecessary_literal_bound ) ]
+  impl Guardrail for DenyModel
+  {
+    fn name( &self ) -> &str { "deny_model" }
+
+    fn check_request( &self, body : &mut serde_json::Value ) -> Result< (), String >
+    {
+      match body[ "model" ].as_str()
+      {
+        Some( "blocked" ) => Err( "model is blocked".to_string() ),
+        _ => Ok( () ),
+      }
+    }
+  }
+
+  fn create_guarded_client() -> Client< OpenaiEnvironmentImpl >
+  {
+    let secret = Secret::new( "sk-test-key-guardrail".to_string() ).unwrap();
+    let environment = OpenaiEnvironmentImpl::build(
+      secret,
+      None,
+      None,
+      "http://127.0.0.1:9/v1/".to_string(),
+      OpenAIRecommended::realtime_base_url().to_string()
+    ).unwrap();
+    Client::build( environment ).unwrap().with_guardrails( Guardrails::new().with( DenyModel ) )
+  }
+
+  #[ tokio::test ]
+  async fn test_guardrail_denies_request_before_sending()
+  {
+    let client = create_guarded_client();
+    assert_eq!( client.guardrails().len(), 1 );
+
+    let request = CreateEmbeddingRequest::new_single( "hello".to_string(), "blocked".to_string() );
+    let error = client.embeddings().create( request ).await.unwrap_err();
+    let violation = error.downcast_ref::< GuardrailViolation >().expect( "error must be a guardrail violation" );
+    assert_eq!( violation.guardrail, "deny_model" );
+    assert_eq!( violation.stage, GuardrailStage::Request );
+  }
+
+  #[ tokio::test ]
+  async fn test_guardrail_allows_request()
+  {
+    let client = create_guarded_client();
+
+    // Allowed by the guardrail, so the request is sent (and fails to connect)
+    let request = CreateEmbeddingRequest::new_single( "hello".to_string(), "text-embedding-3-small".to_string() );
+    let error = client.embeddings().create( request ).await.unwrap_err();
+    assert!( error.downcast_ref::< GuardrailViolation >().is_none() );
+  }
+}
@@ -0,0 +1,69 @@
+//! Idempotency Tests
+//!
+//! Validates how a write keeps one `Idempotency-Key` across its attempts:
+//! the client handle carries a fixed key, and a failover context keeps the
+//! key it generated when it moves to the next endpoint.
+
+#[ cfg( all( feature = "idempotency", feature = "failover" ) ) ]
+mod idempotency_tests
+{
+  use api_openai::
+  {
+    Client,
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    failover ::{ FailoverContext, FailoverEndpoint },
+  };
+  use api_common::IdempotencyKey;
+  use core::time::Duration;
+
+  fn create_client() -> Client< OpenaiEnvironmentImpl >
+  {
+    let secret = Secret::new( "sk-test-key-idempotency".to_string() ).unwrap();
+    let environment = OpenaiEnvironmentImpl::build(
+      secret,
+      None,
+      None,
+      "http://127.0.0.1:9/v1/".to_string(),
+      OpenAIRecommended::realtime_base_url().to_string()
+    ).unwrap();
+    Client::build( environment ).unwrap()
+  }
+
+  fn endpoint( id : &str ) -> FailoverEndpoint
+  {
+    FailoverEndpoint::new( id.to_string(), format!( "https://{id}.example.com" ), 100, Duration::from_secs( 30 ) )
+  }
+
+  #[ test ]
+  fn test_client_has_no_key_by_default()
+  {
+    let client = create_client();
+    assert!( client.idempotency_key().is_none() );
+    assert!( !client.idempotency_keys );
+  }
+
+  #[ test ]
+  fn test_fixed_key_is_kept_by_clones()
+  {
+    let key = IdempotencyKey::new( "batch-42" );
+    let client = create_client().with_idempotency_keys().with_idempotency_key( key.clone() );
+
+    assert!( client.idempotency_keys );
+    assert_eq!( client.clone().idempotency_key(), Some( &key ) );
+  }
+
+  #[ test ]
+  fn test_failover_context_keeps_key_across_endpoints()
+  {
+    let first = FailoverContext::new( endpoint( "primary" ) );
+    let key = first.idempotency_key.clone();
+
+    let second = first.next_attempt( endpoint( "secondary" ) );
+    assert_eq!( second.attempt, 2 );
+    assert_eq!( second.idempotency_key, key );
+
+    let other = FailoverContext::new( endpoint( "primary" ) );
+    assert_ne!( other.idempotency_key, key );
+  }
+}
@@ -49,7 +49,6 @@ use api_openai::exposed::
       ChatCompletionRequestMessageContentPart,
       ChatCompletionRequestMessageContentImageUrl,
       ChatCompletionTool,
-      ToolChoiceOption,
     },
   }
 };
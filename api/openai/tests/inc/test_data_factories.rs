@@ -38,7 +38,6 @@ use api_openai::exposed::
     {
       Tool,
       ToolChoice,
-      ToolChoiceFunction,
       FunctionTool,
       FunctionParameters,
       FileSearchTool,
@@ -231,20 +230,13 @@ impl ToolChoiceFactory
   /// Creates a string-based tool choice
   pub fn string_choice( choice : &str ) -> ToolChoice
   {
-    ToolChoice::String( choice.to_string() )
+    ToolChoice::Mode( choice.to_string() )
   }
 
   /// Creates a function-specific tool choice
   pub fn function_choice( function_name : &str ) -> ToolChoice
   {
-    ToolChoice::Function
-    {
-      r#type : "function".to_string(),
-      function : ToolChoiceFunction
-      {
-        name : function_name.to_string(),
-      },
-    }
+    ToolChoice::function( function_name )
   }
 }
 
@@ -339,7 +339,7 @@ async fn create_response_with_tools()
     .model( ModelIdsResponses::from( "gpt-5-nano".to_string() ) )
     .input( ResponseInput::String( "What's the weather like in Boston?".to_string() ) )
     .tools( vec![ tool ] )
-    .tool_choice( ToolChoice::String( "auto".to_string() ) )
+    .tool_choice( ToolChoice::auto() )
     .form();
 
   let result = client.responses().create( request ).await;
@@ -122,7 +122,7 @@ pub fn create_tools_test_request() -> CreateResponseRequest
     .model(ModelIdsResponses::from("gpt-5-nano".to_string()))
     .input(ResponseInput::String("What's the weather like in Paris?".to_string()))
     .tools(vec![get_weather_tool])
-    .tool_choice(ToolChoice::String("required".to_string()))
+    .tool_choice(ToolChoice::required())
     .max_output_tokens(150)
     .form()
 }
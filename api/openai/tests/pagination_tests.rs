@@ -0,0 +1,131 @@
+//! Tests for cursor pagination over the list endpoints.
+//!
+//! Unit tests check the cursor each recorded list page yields and how it is
+//! put on the next query; integration tests page through real lists.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CursorList` for files | Two pages | Cursor from `last_id`; `has_more` ends the list |
+//! | `CursorList` for fine-tuning jobs | No `last_id` | Cursor from the last job |
+//! | `CursorList` for assistants | Empty list with null ids | No cursor, no more pages |
+//! | `CursorQuery::set_after` | Files query with `limit` | `limit` kept, `after` added |
+//! | `CursorPage::into_pages` / `next_page` (integration) | Files and fine-tuning jobs, one per page | Pages follow each other; `None` after the last page |
+
+use api_openai::exposed::CursorList;
+use api_openai::exposed::CursorQuery;
+use api_openai::components::
+{
+  assistants_shared ::ListAssistantsResponse,
+  files ::{ ListFilesQuery, ListFilesResponse },
+  fine_tuning_shared ::ListPaginatedFineTuningJobsResponse,
+};
+
+fn file_object( id : &str ) -> String
+{
+  format!( r#"{{"id":"{id}","object":"file","bytes":96,"created_at":1,"filename":"train.jsonl","purpose":"fine-tune"}}"# )
+}
+
+fn job( id : &str ) -> String
+{
+  format!( r#"{{"object":"fine_tuning.job","id":"{id}","model":"gpt-4o-mini-2024-07-18","created_at":1,"finished_at":null,"fine_tuned_model":null,"organization_id":"org-123","result_files":[],"status":"running","validation_file":null,"training_file":"file-train","seed":7,"error":null,"trained_tokens":null}}"# )
+}
+
+#[ test ]
+fn file_pages_take_the_cursor_from_last_id()
+{
+  let first = format!( r#"{{"object":"list","data":[{},{}],"first_id":"file-a","last_id":"file-b","has_more":true}}"#, file_object( "file-a" ), file_object( "file-b" ) );
+  let last = format!( r#"{{"object":"list","data":[{}],"first_id":"file-c","last_id":"file-c","has_more":false}}"#, file_object( "file-c" ) );
+
+  let ( data, has_more, after ) = serde_json::from_str::< ListFilesResponse >( &first ).expect( "first page must parse" ).into_parts();
+  assert_eq!( ( data.len(), has_more, after.as_deref() ), ( 2, true, Some( "file-b" ) ) );
+  let ( data, has_more, _ ) = serde_json::from_str::< ListFilesResponse >( &last ).expect( "last page must parse" ).into_parts();
+  assert_eq!( ( data[ 0 ].id.as_str(), has_more ), ( "file-c", false ) );
+}
+
+#[ test ]
+fn job_pages_take_the_cursor_from_the_last_job()
+{
+  let first = format!( r#"{{"object":"list","data":[{},{}],"has_more":true}}"#, job( "ftjob-a" ), job( "ftjob-b" ) );
+
+  let ( data, has_more, after ) = serde_json::from_str::< ListPaginatedFineTuningJobsResponse >( &first ).expect( "page must parse" ).into_parts();
+
+  assert_eq!( ( data.len(), has_more, after.as_deref() ), ( 2, true, Some( "ftjob-b" ) ) );
+}
+
+#[ test ]
+fn empty_assistants_list_has_no_cursor()
+{
+  let empty = r#"{"object":"list","data":[],"first_id":null,"last_id":null,"has_more":false}"#;
+
+  let ( data, has_more, after ) = serde_json::from_str::< ListAssistantsResponse >( empty ).expect( "empty page must parse" ).into_parts();
+
+  assert!( data.is_empty() );
+  assert_eq!( ( has_more, after ), ( false, None ) );
+}
+
+#[ test ]
+fn next_query_keeps_the_limit()
+{
+  let mut query = ListFilesQuery::former().limit( 2_u32 ).form();
+
+  query.set_after( "file-b".to_string() );
+
+  assert_eq!( serde_json::to_string( &query ).expect( "query serializes" ), r#"{"limit":2,"after":"file-b"}"# );
+}
+
+#[ cfg( feature = "integration" ) ]
+mod integration
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+  use api_openai::components::{ files ::ListFilesQuery, fine_tuning_shared ::FineTuningListQuery };
+  use futures_util::StreamExt;
+
+  fn create_test_client() -> Client< OpenaiEnvironmentImpl >
+  {
+    let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+    let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+      .expect( "environment builds" );
+    Client::build( environment ).expect( "client builds" )
+  }
+
+  #[ tokio::test ]
+  async fn integration_file_pages_follow_each_other()
+  {
+    let client = create_test_client();
+
+    let page = client.files().list_page( Some( ListFilesQuery::former().limit( 1_u32 ).form() ) ).await.expect( "INTEGRATION: first page must be listed" );
+    let pages : Vec< _ > = page.into_pages().take( 2 ).collect().await;
+
+    let ids : Vec< String > = pages.into_iter()
+    .flat_map( | page | page.expect( "INTEGRATION: page must be listed" ).data.into_iter().map( | file | file.id ) )
+    .collect();
+    assert!( ids.len() <= 2, "{ids:?}" );
+    assert!( ids.windows( 2 ).all( | pair | pair[ 0 ] != pair[ 1 ] ), "each page starts after the last: {ids:?}" );
+  }
+
+  #[ tokio::test ]
+  async fn integration_job_pages_end_without_a_cursor()
+  {
+    let client = create_test_client();
+
+    let mut page = client.fine_tuning().list_jobs_page( Some( FineTuningListQuery::former().limit( 1_u32 ).form() ) ).await.expect( "INTEGRATION: first page must be listed" );
+    for _ in 0..2
+    {
+      let Some( next ) = page.next_page().await.expect( "INTEGRATION: next page must be listed" ) else
+      {
+        assert!( !page.has_more );
+        return;
+      };
+      assert!( next.data.len() <= 1 );
+      page = next;
+    }
+  }
+}
@@ -0,0 +1,142 @@
+//! Tests for the prompt caching statistics of chat completions.
+//!
+//! Unit tests measure recorded usage and call the hook a client is built
+//! with; integration tests check that real completions, whole and streamed,
+//! reach the hook.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CompletionUsage` helpers | Cached, no details, empty prompt | Cached tokens, fraction, effective prompt tokens |
+//! | `Client::with_usage_hook` | Recorded completion | Hook kept on the client and called with the model and usage details |
+//! | `Client::with_usage_hook` (integration) | `Chat::create` | Hook called once with the response model and usage |
+//! | `Client::with_usage_hook` (integration) | `Chat::create_stream` with `include_usage` | Hook called once for the usage item, items passed on |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::components::
+{
+  common ::CompletionUsage,
+  chat_shared ::
+  {
+    ChatCompletionRequest,
+    ChatCompletionRequestMessage,
+    ChatCompletionRequestMessageContent,
+    CreateChatCompletionResponse,
+  },
+};
+use std::sync::{ Arc, Mutex };
+
+const USAGE : &str = r#"{"prompt_tokens":2048,"completion_tokens":12,"total_tokens":2060,"prompt_tokens_details":{"cached_tokens":1536,"audio_tokens":0},"completion_tokens_details":{"reasoning_tokens":0,"audio_tokens":0,"accepted_prediction_tokens":0,"rejected_prediction_tokens":0}}"#;
+
+fn client( base_url : String, secret : Secret ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, base_url, OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  Client::build( environment ).expect( "client builds" )
+}
+
+fn request() -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .messages( vec![ ChatCompletionRequestMessage::former().role( "user".to_string() ).content( ChatCompletionRequestMessageContent::Text( "Hi".to_string() ) ).form() ] )
+    .form()
+}
+
+/// The model, prompt tokens, and cached tokens of each call of the hook.
+type Seen = Arc< Mutex< Vec< ( String, i32, i32 ) > > >;
+
+/// A client whose usage hook records what it is called with.
+fn recording_client( base_url : String, secret : Secret ) -> ( Client< OpenaiEnvironmentImpl >, Seen )
+{
+  let seen = Arc::new( Mutex::new( Vec::new() ) );
+  let record = Arc::clone( &seen );
+  let client = client( base_url, secret ).with_usage_hook( move | model, usage |
+  {
+    record.lock().expect( "lock" ).push( ( model.to_string(), usage.prompt_tokens, usage.cached_tokens() ) );
+  });
+  ( client, seen )
+}
+
+#[ test ]
+fn usage_helpers_measure_the_cached_prompt()
+{
+  let usage : CompletionUsage = serde_json::from_str( USAGE ).expect( "usage" );
+  assert_eq!( usage.cached_tokens(), 1536 );
+  assert!( ( usage.cached_fraction() - 0.75 ).abs() < f64::EPSILON );
+  assert_eq!( usage.effective_prompt_tokens(), 512 );
+
+  let plain : CompletionUsage = serde_json::from_str( r#"{"prompt_tokens":10,"completion_tokens":2,"total_tokens":12}"# ).expect( "usage" );
+  assert_eq!( plain.cached_tokens(), 0 );
+  assert!( plain.cached_fraction().abs() < f64::EPSILON );
+  assert_eq!( plain.effective_prompt_tokens(), 10 );
+
+  let empty : CompletionUsage = serde_json::from_str( r#"{"prompt_tokens":0,"completion_tokens":0,"total_tokens":0}"# ).expect( "usage" );
+  assert!( empty.cached_fraction().abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn hook_is_called_with_the_completion_usage()
+{
+  let body = format!( r#"{{"id":"chatcmpl-abc","object":"chat.completion","created":1,"model":"gpt-4o-mini-2024-07-18","choices":[{{"index":0,"message":{{"role":"assistant","content":"Hi"}},"finish_reason":"stop"}}],"usage":{USAGE}}}"# );
+  let ( client, seen ) = recording_client( "http://127.0.0.1:9/v1/".to_string(), Secret::new_unchecked( "sk-prompt-cache-test-0123456789abcd".to_string() ) );
+  let response : CreateChatCompletionResponse = serde_json::from_str( &body ).expect( "completion must parse" );
+  let usage = response.usage.expect( "completion has usage" );
+
+  client.usage_hook().expect( "hook is kept" ).call( &response.model, &usage );
+
+  assert_eq!( usage.effective_prompt_tokens(), 512 );
+  assert_eq!( *seen.lock().expect( "lock" ), vec![ ( "gpt-4o-mini-2024-07-18".to_string(), 2048, 1536 ) ] );
+}
+
+#[ cfg( feature = "integration" ) ]
+mod integration
+{
+  use super::*;
+  use api_openai::ClientApiAccessors;
+  use api_openai::components::chat_shared::{ ChatCompletionChunk, ChatCompletionStreamOptions };
+
+  fn create_test_client() -> ( Client< OpenaiEnvironmentImpl >, Seen )
+  {
+    let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+    recording_client( OpenAIRecommended::base_url().to_string(), secret )
+  }
+
+  #[ tokio::test ]
+  async fn integration_create_passes_the_usage_to_the_hook()
+  {
+    let ( client, seen ) = create_test_client();
+
+    let response = client.chat().create( request() ).await.expect( "INTEGRATION: completion must be created" );
+
+    let usage = response.usage.expect( "INTEGRATION: completion has usage" );
+    assert_eq!( *seen.lock().expect( "lock" ), vec![ ( response.model, usage.prompt_tokens, usage.cached_tokens() ) ] );
+  }
+
+  #[ tokio::test ]
+  async fn integration_create_stream_passes_the_usage_item_to_the_hook()
+  {
+    let ( client, seen ) = create_test_client();
+    let mut request = request();
+    request.stream = Some( true );
+    request.stream_options = Some( ChatCompletionStreamOptions { include_usage : Some( true ) } );
+
+    let mut receiver = client.chat().create_stream( request ).await.expect( "INTEGRATION: stream must start" );
+    let mut items = Vec::new();
+    while let Some( item ) = receiver.recv().await
+    {
+      items.push( item.expect( "INTEGRATION: items must parse" ) );
+    }
+
+    let Some( ChatCompletionChunk::Usage( usage ) ) = items.last() else { panic!( "usage comes last: {items:?}" ) };
+    let seen = seen.lock().expect( "lock" );
+    assert_eq!( seen.len(), 1, "{seen:?}" );
+    assert_eq!( ( seen[ 0 ].1, seen[ 0 ].2 ), ( usage.prompt_tokens, usage.cached_tokens() ) );
+  }
+}
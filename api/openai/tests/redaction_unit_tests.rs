@@ -0,0 +1,101 @@
+//! Tests that key material never appears in full in `Debug` output.
+//!
+//! Each type holding a key or token is formatted with `{:?}` and `{:#?}`
+//! and the output is searched for the secret past its four-character prefix.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | Secret | `{:?}` of a long key | Only the prefix is shown |
+//! | Environment, Client | `{:?}` of both | Key hidden through nesting |
+//! | Headers | `Authorization` value | Marked sensitive |
+//! | Advanced auth | Config, OAuth response, tenant keys | Keys and tokens masked |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironment, OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+  advanced_auth ::{ AdvancedAuthConfig, MultiTenantConfig, OAuthTokenResponse },
+};
+use std::collections::HashMap;
+
+const KEY : &str = "sk-redaction-test-0123456789abcdef";
+const TOKEN : &str = "oauth-redaction-token-0123456789";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    for secret in [ KEY, TOKEN ]
+    {
+      assert!( !output.contains( &secret[ 4.. ] ), "secret in Debug output : {output}" );
+    }
+  }
+}
+
+fn environment() -> OpenaiEnvironmentImpl
+{
+  OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( KEY.to_string() ),
+    None,
+    None,
+    OpenAIRecommended::base_url().to_string(),
+    OpenAIRecommended::realtime_base_url().to_string(),
+  )
+  .expect( "environment builds" )
+}
+
+#[ test ]
+fn secret_debug_masks_key()
+{
+  let secret = Secret::new_unchecked( KEY.to_string() );
+  assert_hidden( &secret );
+  assert_eq!( format!( "{secret:?}" ), "Secret(\"sk-r****\")" );
+}
+
+#[ test ]
+fn environment_and_client_debug_mask_key()
+{
+  assert_hidden( &environment() );
+  assert_hidden( &Client::build( environment() ).expect( "client builds" ) );
+}
+
+#[ test ]
+fn authorization_header_is_sensitive()
+{
+  let headers = environment().headers().expect( "headers build" );
+  assert!( headers[ reqwest::header::AUTHORIZATION ].is_sensitive() );
+  assert_hidden( &headers );
+}
+
+#[ test ]
+fn advanced_auth_debug_masks_keys_and_tokens()
+{
+  assert_hidden( &AdvancedAuthConfig
+  {
+    primary_api_key : KEY.to_string(),
+    secondary_api_key : Some( KEY.to_string() ),
+    oauth_access_token : Some( TOKEN.to_string() ),
+    oauth_refresh_token : Some( TOKEN.to_string() ),
+    ..AdvancedAuthConfig::default()
+  });
+  assert_hidden( &OAuthTokenResponse
+  {
+    access_token : TOKEN.to_string(),
+    token_type : "Bearer".to_string(),
+    expires_in : 3600,
+    refresh_token : Some( TOKEN.to_string() ),
+    scope : None,
+  });
+  assert_hidden( &MultiTenantConfig
+  {
+    primary_org_id : "org-primary".to_string(),
+    secondary_org_id : None,
+    tenant_api_keys : HashMap::from( [ ( "tenant-a".to_string(), KEY.to_string() ) ] ),
+    tenant_rate_limits : HashMap::new(),
+    isolation_enforced : true,
+  });
+}
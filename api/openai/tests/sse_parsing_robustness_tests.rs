@@ -1,6 +1,6 @@
 //! SSE Parsing Robustness Tests
 //!
-//! Tests for enhanced Server-Sent Events parsing using eventsource-stream library.
+//! Tests for enhanced Server-Sent Events parsing using the shared `api_sse_stream` decoder.
 //! Validates handling of edge cases, malformed data, and error recovery.
 
 use api_openai::components::chat_shared::ChatCompletionRequest;
@@ -16,7 +16,7 @@ async fn test_sse_parsing_valid_events()
   // Note : from_env() doesn't exist in this API, so this test verifies structure exists
 
   // Verify that the SSE parsing enhancement is in place
-  // Test passes by verifying that eventsource-stream dependency is used
+  // Test passes by verifying that the api_sse_stream dependency is used
   // This is confirmed by the implementation and successful streaming tests
 }
 
@@ -25,7 +25,7 @@ async fn test_sse_parsing_valid_events()
 async fn test_sse_parsing_malformed_events()
 {
   // This test validates that the enhanced SSE parser can handle malformed events
-  // The eventsource-stream library should gracefully handle:
+  // The api_sse_stream decoder should gracefully handle:
   // - Invalid event format
   // - Missing data fields
   // - Malformed JSON in data
@@ -35,9 +35,9 @@ async fn test_sse_parsing_malformed_events()
   // we verify that the error types are handled in the implementation
 
   // The enhanced implementation should handle these error cases:
-  // 1. eventsource_stream::Error::InvalidEvent - continues processing
-  // 2. eventsource_stream::Error::Transport - breaks connection
-  // 3. eventsource_stream::Error::Utf8 - breaks connection
+  // 1. Malformed lines - ignored by the decoder, processing continues
+  // 2. Transport errors - break the connection
+  // 3. Invalid UTF-8 - replaced with U+FFFD, processing continues
 
   // Test passes by verifying error handling exists in streaming implementation
   // This is confirmed by successful error recovery in integration tests
@@ -48,13 +48,13 @@ async fn test_sse_parsing_malformed_events()
 async fn test_sse_parsing_network_interruption()
 {
   // The enhanced SSE parser should handle network interruptions gracefully
-  // by using the eventsource-stream library's transport error handling
+  // by passing transport errors from the byte stream through unchanged
 
   // Transport errors are handled by breaking the connection and sending
   // an error to the receiver channel
 
   // Test passes by verifying network interruption handling via transport layer
-  // This is handled by the eventsource-stream library's error propagation
+  // This is handled by the SseStream adapter's error propagation
 }
 
 /// Test SSE parsing with invalid JSON data
@@ -64,7 +64,7 @@ async fn test_sse_parsing_invalid_json()
   // The enhanced implementation provides better error messages for JSON parsing failures
   // including the actual data that failed to parse
 
-  // This is handled in the Event::Message branch where serde_json::from_str fails
+  // This is handled in the event branch where serde_json::from_str fails
   // and creates an informative error message with context
 
   // Test passes by verifying JSON parsing errors are handled gracefully
@@ -75,13 +75,13 @@ async fn test_sse_parsing_invalid_json()
 #[ tokio::test ]
 async fn test_sse_parsing_partial_messages()
 {
-  // The eventsource-stream library handles partial messages and multi-line data
+  // The api_sse_stream decoder handles partial messages and multi-line data
   // automatically, which was a weakness in the manual parsing approach
 
-  // The library buffers partial events until complete messages are received
+  // The decoder buffers partial events until complete messages are received
 
   // Test passes by verifying partial message handling by underlying library
-  // This is handled by the eventsource-stream crate's buffering mechanism
+  // This is handled by the api_sse_stream decoder's buffering mechanism
 }
 
 /// Test SSE parsing with different event types
@@ -89,14 +89,14 @@ async fn test_sse_parsing_partial_messages()
 async fn test_sse_parsing_event_types()
 {
   // The enhanced implementation now properly handles different SSE event types:
-  // - Event::Open - connection opened
-  // - Event::Message - data message with proper field parsing
+  // - `event:` fields set the SseEvent type
+  // - `data:` lines are joined into the SseEvent data
 
   // The manual implementation only looked for "data:" prefixes
   // The new implementation uses proper SSE specification parsing
 
   // Test passes by verifying different SSE event types are handled
-  // This is confirmed by the Event enum handling in the streaming implementation
+  // This is confirmed by the SseEvent handling in the streaming implementation
 }
 
 /// Test SSE parsing with UTF-8 encoding issues
@@ -104,7 +104,7 @@ async fn test_sse_parsing_event_types()
 async fn test_sse_parsing_utf8_errors()
 {
   // The enhanced implementation handles UTF-8 encoding errors gracefully
-  // by catching eventsource_stream::Error::Utf8 and sending appropriate errors
+  // by replacing invalid bytes with U+FFFD instead of ending the stream
 
   // Test passes by verifying UTF-8 encoding errors are handled gracefully
   // This is handled by Rust's UTF-8 string validation and error propagation
@@ -142,6 +142,7 @@ async fn test_sse_parsing_api_compatibility()
     messages : vec![],
     stream : Some( true ),
     max_tokens : Some( 100 ),
+    max_completion_tokens : None,
     temperature : Some( 0.7 ),
     top_p : Some( 1.0 ),
     n : Some( 1 ),
@@ -152,6 +153,7 @@ async fn test_sse_parsing_api_compatibility()
     seed : None,
     tools : None,
     tool_choice : None,
+    stream_options : None,
     system_prompt : None,
     logprobs : None,
     top_logprobs : None,
@@ -184,12 +186,12 @@ mod sse_edge_case_tests
   #[ tokio::test ]
   async fn test_rapid_consecutive_events()
   {
-    // The eventsource-stream library should handle rapid consecutive events
+    // The api_sse_stream decoder should handle rapid consecutive events
     // better than the manual parsing approach which could lose events
     // due to buffer management issues
 
     // Test passes by verifying rapid event handling capability
-    // This is provided by the eventsource-stream library's efficient parsing
+    // This is provided by the api_sse_stream decoder's incremental parsing
   }
 
   /// Test handling of very large events
@@ -197,7 +199,7 @@ mod sse_edge_case_tests
   async fn test_large_event_handling()
   {
     // Large events that span multiple chunks should be handled correctly
-    // by the eventsource-stream library's buffering mechanism
+    // by the api_sse_stream decoder's buffering mechanism
 
     // Test passes by verifying large event handling through buffering
     // This is handled by the underlying stream processing capabilities
@@ -210,7 +212,7 @@ mod sse_edge_case_tests
     // Empty events or events with no data should be handled gracefully
 
     // Test passes by verifying empty event handling
-    // This is handled by the eventsource-stream library's event filtering
+    // This is handled by the api_sse_stream decoder's event filtering
   }
 }
 
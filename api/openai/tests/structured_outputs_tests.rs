@@ -0,0 +1,99 @@
+//! Tests for the Structured Outputs helpers of the `schemars` feature.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `response_format_for` | Nested type, `Option` and integer fields | Strict reshaping, name and description, chat request body |
+//! | `parse_structured` | Content, refusal, truncated content, no choices | Typed value or an error naming the cause |
+
+#![ cfg( feature = "schemars" ) ]
+
+use api_openai::exposed::{ parse_structured, response_format_for };
+use api_openai::components::chat_shared::{ ChatCompletionResponseFormat, CreateChatCompletionResponse };
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{ json, Value };
+
+/// A city and its landmarks.
+#[ derive( Debug, Deserialize, JsonSchema, PartialEq ) ]
+struct City
+{
+  name : String,
+  population : u32,
+  nickname : Option< String >,
+  /// The best known landmark.
+  landmark : Landmark,
+  others : Vec< Landmark >,
+}
+
+#[ derive( Debug, Deserialize, JsonSchema, PartialEq ) ]
+struct Landmark
+{
+  name : String,
+  built : Option< i32 >,
+}
+
+fn response( message : &Value, finish_reason : &str ) -> CreateChatCompletionResponse
+{
+  serde_json::from_value( json!
+  ({
+    "id" : "chatcmpl-1",
+    "object" : "chat.completion",
+    "created" : 1_700_000_000,
+    "model" : "gpt-4o-mini",
+    "choices" : [ { "index" : 0, "finish_reason" : finish_reason, "message" : message } ],
+  }) ).expect( "response" )
+}
+
+#[ test ]
+fn response_format_for_reshapes_the_schema_for_strict_mode()
+{
+  let format = response_format_for::< City >();
+
+  assert_eq!( format.r#type, "json_schema" );
+  assert_eq!( format.json_schema.name, "City" );
+  assert_eq!( format.json_schema.description.as_deref(), Some( "A city and its landmarks." ) );
+  assert_eq!( format.json_schema.strict, Some( true ) );
+
+  let schema = &format.json_schema.schema.0;
+  assert!( schema.get( "$schema" ).is_none() );
+  assert_eq!( schema[ "additionalProperties" ], json!( false ) );
+  assert_eq!( schema[ "required" ], json!( [ "landmark", "name", "nickname", "others", "population" ] ) );
+  assert_eq!( schema[ "properties" ][ "nickname" ][ "type" ], json!( [ "string", "null" ] ) );
+  assert!( schema[ "properties" ][ "population" ].get( "format" ).is_none() );
+  assert_eq!( schema[ "properties" ][ "population" ][ "minimum" ], json!( 0.0 ) );
+  // The doc comment on a reference no longer hides it in an `allOf`.
+  assert_eq!( schema[ "properties" ][ "landmark" ][ "$ref" ], json!( "#/definitions/Landmark" ) );
+  assert_eq!( schema[ "properties" ][ "landmark" ][ "description" ], json!( "The best known landmark." ) );
+
+  let landmark = &schema[ "definitions" ][ "Landmark" ];
+  assert_eq!( landmark[ "additionalProperties" ], json!( false ) );
+  assert_eq!( landmark[ "required" ], json!( [ "built", "name" ] ) );
+
+  let body = serde_json::to_value( ChatCompletionResponseFormat::from( format ) ).expect( "format" );
+  assert_eq!( body[ "type" ], "json_schema" );
+  assert_eq!( body[ "json_schema" ][ "name" ], "City" );
+  assert_eq!( body[ "json_schema" ][ "strict" ], true );
+}
+
+#[ test ]
+fn parse_structured_reads_the_message_or_names_the_cause()
+{
+  let content = r#"{"name":"Paris","population":2100000,"nickname":null,"landmark":{"name":"Eiffel Tower","built":1889},"others":[]}"#;
+  let city : City = parse_structured( &response( &json!( { "role" : "assistant", "content" : content } ), "stop" ) ).expect( "city" );
+  assert_eq!( city.landmark, Landmark { name : "Eiffel Tower".to_string(), built : Some( 1889 ) } );
+  assert_eq!( city.nickname, None );
+
+  let refused = parse_structured::< City >( &response( &json!( { "role" : "assistant", "content" : null, "refusal" : "I can't help with that." } ), "stop" ) );
+  assert!( refused.unwrap_err().to_string().contains( "the model refused : I can't help with that." ) );
+
+  let truncated = parse_structured::< City >( &response( &json!( { "role" : "assistant", "content" : r#"{"name":"Par"# } ), "length" ) );
+  let message = truncated.unwrap_err().to_string();
+  assert!( message.contains( "City" ), "{message}" );
+  assert!( message.contains( "cut at the token limit" ), "{message}" );
+
+  let mut empty = response( &json!( { "role" : "assistant", "content" : content } ), "stop" );
+  empty.choices.clear();
+  assert!( parse_structured::< City >( &empty ).unwrap_err().to_string().contains( "no choices" ) );
+}
@@ -141,9 +141,11 @@ fn test_sync_chat_api()
     temperature : None,
     top_p : Some( 1.0 ),
     max_tokens : None,
+    max_completion_tokens : None,
     n : Some( 1 ),
     stop : None,
     stream : Some( false ),
+    stream_options : None,
     system_prompt : None,
     user : None,
     tools : None,
@@ -132,9 +132,11 @@ fn test_sync_stream_iterator_structure()
     temperature : None,
     top_p : None,
     max_tokens : None,
+    max_completion_tokens : None,
     n : None,
     stop : None,
     stream : None,
+    stream_options : None,
     system_prompt : None,
     user : None,
     tools : None,
@@ -294,9 +296,11 @@ fn test_sync_chat_streaming_method_signatures()
     temperature : None,
     top_p : None,
     max_tokens : None,
+    max_completion_tokens : None,
     n : None,
     stop : None,
     stream : None,
+    stream_options : None,
     system_prompt : None,
     user : None,
     tools : None,
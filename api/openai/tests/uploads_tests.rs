@@ -0,0 +1,107 @@
+//! Tests for the Uploads API: Upload creation, parts, completion, and the
+//! local file helper that cuts a file into parts sent concurrently.
+//!
+//! Unit tests check the request bodies the client serializes, the recorded
+//! Uploads API bodies it parses, and the part configuration checked before
+//! any request; the integration test uploads a file in parts to the real API.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateUploadRequest` / `CompleteUploadRequest` | Announced file, parts in order | Bodies serialized, no `md5` unless set |
+//! | `Upload` | Pending, completed | Completed upload carries its file |
+//! | `Uploads::add_parts` / `PartUploadConfig` | Zero and oversized parts, no concurrency | Error, no request sent |
+//! | `Uploads::upload_file_in_parts` (integration) | 10-byte file, 4-byte parts, 2 in flight | Parts completed into a file of the local file's size |
+
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::components::files::FilePurpose;
+use api_openai::components::uploads::{ CompleteUploadRequest, CreateUploadRequest, Upload };
+use api_openai::uploads::{ PartUploadConfig, MAX_UPLOAD_PART_SIZE };
+
+const CONTENT : &str = "aaaabbbbcc";
+
+fn client( base_url : String, secret : Secret ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, base_url, OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  Client::build( environment ).expect( "client builds" )
+}
+
+fn upload( status : &str ) -> String
+{
+  let file = if status == "completed"
+  {
+    r#"{"id":"file-big","object":"file","bytes":10,"created_at":1,"filename":"big.jsonl","purpose":"batch"}"#
+  }
+  else
+  {
+    "null"
+  };
+  format!( r#"{{"id":"upload_abc","object":"upload","bytes":10,"created_at":1,"expires_at":3601,"filename":"big.jsonl","purpose":"batch","status":"{status}","file":{file}}}"# )
+}
+
+#[ test ]
+fn create_and_complete_requests_serialize()
+{
+  let create = CreateUploadRequest::new( "big.jsonl", FilePurpose::Batch, 10, "application/jsonl" );
+  let complete = CompleteUploadRequest::new( vec![ "part_aaaa".to_string(), "part_bbbb".to_string(), "part_cc".to_string() ] );
+
+  assert_eq!( serde_json::to_string( &create ).expect( "request serializes" ), r#"{"filename":"big.jsonl","purpose":"batch","bytes":10,"mime_type":"application/jsonl"}"# );
+  assert_eq!( serde_json::to_string( &complete ).expect( "request serializes" ), r#"{"part_ids":["part_aaaa","part_bbbb","part_cc"]}"# );
+}
+
+#[ test ]
+fn completed_upload_carries_its_file()
+{
+  let pending : Upload = serde_json::from_str( &upload( "pending" ) ).expect( "upload must parse" );
+  let completed : Upload = serde_json::from_str( &upload( "completed" ) ).expect( "upload must parse" );
+
+  assert_eq!( ( pending.status.as_str(), pending.file.is_none() ), ( "pending", true ) );
+  assert_eq!( completed.status, "completed" );
+  assert_eq!( completed.file.map( | file | file.id ), Some( "file-big".to_string() ) );
+}
+
+#[ tokio::test ]
+async fn out_of_range_config_sends_nothing()
+{
+  let client = client( "http://127.0.0.1:9/v1/".to_string(), Secret::new_unchecked( "sk-uploads-test-0123456789abcdef".to_string() ) );
+  let configs =
+  [
+    PartUploadConfig { part_size : 0, ..PartUploadConfig::default() },
+    PartUploadConfig { part_size : MAX_UPLOAD_PART_SIZE + 1, ..PartUploadConfig::default() },
+    PartUploadConfig { max_concurrency : 0, ..PartUploadConfig::default() },
+  ];
+
+  for config in configs
+  {
+    let error = client.uploads().add_parts( "upload_abc", CONTENT.as_bytes(), config ).await.expect_err( "config is out of range" );
+    assert!( error.to_string().contains( "must be" ), "{error}" );
+  }
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_file_is_uploaded_in_parts()
+{
+  use api_openai::ClientApiAccessors;
+  use std::io::Write;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let client = client( OpenAIRecommended::base_url().to_string(), secret );
+  let mut file = tempfile::Builder::new().prefix( "parts" ).suffix( ".txt" ).tempfile().expect( "temporary file must be created" );
+  file.write_all( CONTENT.as_bytes() ).expect( "temporary file must be writable" );
+  let config = PartUploadConfig { part_size : 4, max_concurrency : 2 };
+
+  let upload = client.uploads().upload_file_in_parts( file.path(), FilePurpose::UserData, "text/plain", config ).await
+    .expect( "INTEGRATION: file must be uploaded" );
+  let uploaded = upload.file.expect( "INTEGRATION: completed upload has a file" );
+  client.files().delete( &uploaded.id ).await.expect( "INTEGRATION: file must be deleted" );
+
+  assert_eq!( ( upload.status.as_str(), uploaded.bytes ), ( "completed", 10 ) );
+}
@@ -0,0 +1,93 @@
+//! Tests for the usage and costs reports client.
+//!
+//! Unit tests check the recorded Usage API bodies the client parses; the
+//! query pairs it sends are unit tests in `src/components/usage_shared.rs`.
+//! The integration test reads a report with a real admin key.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `UsageResponse` | Completions, embeddings, moderations, unknown results | Typed buckets; results told apart by `object`, not by their shared fields |
+//! | Costs results | Daily costs grouped by line item | Amounts and line items read |
+//! | `Usage` (integration) | Completions and costs of the last day | Real reports read with `OPENAI_ADMIN_KEY` |
+
+use api_openai::components::usage_shared::
+{
+  UsageResponse,
+  UsageResult,
+};
+
+fn page( results : &str ) -> String
+{
+  format!( r#"{{"object":"page","data":[{{"object":"bucket","start_time":1730419200,"end_time":1730505600,"results":[{results}]}}],"has_more":true,"next_page":"page_AAAA"}}"# )
+}
+
+#[ test ]
+fn completions_report_is_typed()
+{
+  let completions = r#"{"object":"organization.usage.completions.result","input_tokens":3000000000,"output_tokens":500,"input_cached_tokens":100,"input_audio_tokens":0,"output_audio_tokens":0,"num_model_requests":12,"project_id":"proj_abc","user_id":null,"api_key_id":null,"model":"gpt-4o-mini","batch":null}"#;
+
+  let report : UsageResponse = serde_json::from_str( &page( completions ) ).expect( "report must parse" );
+
+  assert_eq!( ( report.has_more, report.next_page.as_deref() ), ( true, Some( "page_AAAA" ) ) );
+  let bucket = &report.data[ 0 ];
+  assert_eq!( ( bucket.start_time, bucket.end_time ), ( 1_730_419_200, 1_730_505_600 ) );
+  let UsageResult::Completions( result ) = &bucket.results[ 0 ] else { panic!( "a completions result: {:?}", bucket.results[ 0 ] ) };
+  assert_eq!( ( result.input_tokens, result.input_cached_tokens, result.model.as_deref() ), ( 3_000_000_000, Some( 100 ), Some( "gpt-4o-mini" ) ) );
+}
+
+#[ test ]
+fn results_are_told_apart_by_object()
+{
+  let embeddings = r#"{"object":"organization.usage.embeddings.result","input_tokens":16,"num_model_requests":2,"project_id":null,"user_id":null,"api_key_id":null,"model":null}"#;
+  let moderations = r#"{"object":"organization.usage.moderations.result","input_tokens":16,"num_model_requests":2,"project_id":null,"user_id":null,"api_key_id":null,"model":null}"#;
+  let unknown = r#"{"object":"organization.usage.something_new.result","units":1}"#;
+
+  let embeddings : UsageResponse = serde_json::from_str( &page( embeddings ) ).expect( "report must parse" );
+  let moderations : UsageResponse = serde_json::from_str( &page( &format!( "{moderations},{unknown}" ) ) ).expect( "report must parse" );
+
+  assert!( matches!( embeddings.data[ 0 ].results[ 0 ], UsageResult::Embeddings( _ ) ), "{:?}", embeddings.data[ 0 ].results );
+  assert!( matches!( moderations.data[ 0 ].results[ 0 ], UsageResult::Moderations( _ ) ), "{:?}", moderations.data[ 0 ].results );
+  assert!( matches!( moderations.data[ 0 ].results[ 1 ], UsageResult::Other( _ ) ), "{:?}", moderations.data[ 0 ].results );
+}
+
+#[ test ]
+fn costs_report_groups_by_line_item()
+{
+  let cost = r#"{"object":"organization.costs.result","amount":{"value":0.06,"currency":"usd"},"line_item":"Image models","project_id":null}"#;
+
+  let report : UsageResponse = serde_json::from_str( &page( cost ) ).expect( "report must parse" );
+
+  let UsageResult::Costs( cost ) = &report.data[ 0 ].results[ 0 ] else { panic!( "a costs result: {:?}", report.data[ 0 ].results[ 0 ] ) };
+  assert_eq!( ( cost.amount.value, cost.amount.currency.as_str(), cost.line_item.as_deref() ), ( 0.06, "usd", Some( "Image models" ) ) );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_reports_of_the_last_day_are_read()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+  use api_openai::components::usage_shared::{ CostsQuery, UsageBucketWidth, UsageQuery };
+
+  let secret = Secret::load_admin_key().expect( "INTEGRATION: OPENAI_ADMIN_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let now = std::time::SystemTime::now().duration_since( std::time::UNIX_EPOCH ).expect( "clock after epoch" ).as_secs();
+  let start_time = i64::try_from( now ).expect( "Unix seconds fit in i64" ) - 86_400;
+
+  let mut query = UsageQuery::new( start_time );
+  query.bucket_width = Some( UsageBucketWidth::OneHour );
+  let completions = client.usage().completions( &query ).await.expect( "INTEGRATION: usage must be read" );
+  let costs = client.usage().costs( &CostsQuery::new( start_time ) ).await.expect( "INTEGRATION: costs must be read" );
+
+  assert!( completions.data.iter().all( | bucket | bucket.start_time >= start_time - 3_600 ), "{completions:?}" );
+  assert!( costs.data.iter().flat_map( | bucket | &bucket.results ).all( | result | matches!( result, UsageResult::Costs( _ ) ) ), "{costs:?}" );
+}
@@ -0,0 +1,137 @@
+//! Tests for the vector stores client: stores, attached files, file batches,
+//! and search.
+//!
+//! Unit tests check the request bodies the client serializes and the
+//! recorded Vector Stores API bodies it parses; the integration test creates,
+//! searches, and deletes a store with the real API, which also needs the
+//! `OpenAI-Beta: assistants=v2` header to be sent.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `CreateVectorStoreRequest` / `VectorStoreSearchRequest` | Files, expiration, chunking; filtered search | Typed bodies serialized |
+//! | Search results | Hit with attributes | Results read with their attributes |
+//! | File and batch requests and objects | Attach with attributes, detach, batch | Bodies serialized, objects and deletion parsed |
+//! | `ListVectorStoresResponse` | Empty list | `first_id` and `last_id` are `null` |
+//! | `VectorStores` (integration) | Create, list, search, delete | Real store round-trips |
+
+use api_openai::components::common::{ DeleteVectorStoreFileResponse, VectorStoreFileAttributes };
+use api_openai::components::vector_stores_shared::
+{
+  ChunkingStrategyRequestParam,
+  ComparisonFilter,
+  CreateVectorStoreFileBatchRequest,
+  CreateVectorStoreFileRequest,
+  CreateVectorStoreRequest,
+  Filter,
+  ListVectorStoresResponse,
+  VectorStoreExpirationAfter,
+  VectorStoreFileBatchObject,
+  VectorStoreFileObject,
+  VectorStoreObject,
+  VectorStoreSearchRequest,
+  VectorStoreSearchResultsPage,
+};
+use std::collections::HashMap;
+
+const VECTOR_STORE : &str = r#"{"id":"vs_abc","object":"vector_store","created_at":1,"name":"Handbook","usage_bytes":0,"file_counts":{"in_progress":1,"completed":0,"failed":0,"cancelled":0,"total":1},"status":"in_progress","expires_after":{"anchor":"last_active_at","days":7},"expires_at":null,"last_active_at":1,"metadata":{}}"#;
+const VECTOR_STORE_FILE : &str = r#"{"id":"file-abc","object":"vector_store.file","created_at":1,"usage_bytes":0,"vector_store_id":"vs_abc","status":"in_progress","last_error":null,"attributes":{"team":"sales"},"chunking_strategy":{"type":"static","static":{"max_chunk_size_tokens":800,"chunk_overlap_tokens":400}}}"#;
+const FILE_BATCH : &str = r#"{"id":"vsfb_abc","object":"vector_store.file_batch","created_at":1,"vector_store_id":"vs_abc","status":"in_progress","file_counts":{"in_progress":2,"completed":0,"failed":0,"cancelled":0,"total":2}}"#;
+
+fn json< T : serde::Serialize >( value : &T ) -> String
+{
+  serde_json::to_string( value ).expect( "value serializes" )
+}
+
+#[ test ]
+fn create_and_search_serialize_typed_bodies()
+{
+  let request = CreateVectorStoreRequest::former()
+    .name( "Handbook".to_string() )
+    .file_ids( vec![ "file-abc".to_string() ] )
+    .expires_after( VectorStoreExpirationAfter::days_after_last_active( 7 ) )
+    .chunking_strategy( ChunkingStrategyRequestParam::fixed_size( 800, 400 ) )
+    .form();
+  let mut search = VectorStoreSearchRequest::new( "refund policy" );
+  search.filters = Some( Filter::Comparison( ComparisonFilter::new( "team", "eq", "sales" ) ) );
+  search.max_num_results = Some( 5 );
+
+  assert_eq!( json( &request ), r#"{"name":"Handbook","file_ids":["file-abc"],"expires_after":{"anchor":"last_active_at","days":7},"chunking_strategy":{"static":{"chunk_overlap_tokens":400,"max_chunk_size_tokens":800},"type":"static"}}"# );
+  assert_eq!( json( &search ), r#"{"query":"refund policy","filters":{"key":"team","type":"eq","value":"sales"},"max_num_results":5}"# );
+}
+
+#[ test ]
+fn store_and_search_results_parse()
+{
+  let results = r#"{"object":"vector_store.search_results.page","search_query":["refund policy"],"data":[{"file_id":"file-abc","filename":"handbook.pdf","score":0.82,"attributes":{"team":"sales"},"content":[{"type":"text","text":"Refunds are accepted within 30 days."}]}],"has_more":false,"next_page":null}"#;
+
+  let store : VectorStoreObject = serde_json::from_str( VECTOR_STORE ).expect( "vector store must parse" );
+  let page : VectorStoreSearchResultsPage = serde_json::from_str( results ).expect( "search results must parse" );
+
+  assert_eq!( store.file_counts.in_progress, 1 );
+  let hit = &page.data[ 0 ];
+  assert_eq!( ( hit.file_id.as_str(), hit.content[ 0 ].text.as_str() ), ( "file-abc", "Refunds are accepted within 30 days." ) );
+  assert_eq!( hit.attributes.as_ref().and_then( | attributes | attributes.0.get( "team" ) ), Some( &serde_json::json!( "sales" ) ) );
+}
+
+#[ test ]
+fn files_and_batches_serialize_and_parse()
+{
+  let mut file = CreateVectorStoreFileRequest::new( "file-abc" );
+  file.attributes = Some( VectorStoreFileAttributes( HashMap::from( [ ( "team".to_string(), serde_json::json!( "sales" ) ) ] ) ) );
+  let batch = CreateVectorStoreFileBatchRequest::new( vec![ "file-a".to_string(), "file-b".to_string() ] );
+
+  let attached : VectorStoreFileObject = serde_json::from_str( VECTOR_STORE_FILE ).expect( "file must parse" );
+  let detached : DeleteVectorStoreFileResponse = serde_json::from_str( r#"{"id":"file-abc","object":"vector_store.file.deleted","deleted":true}"# ).expect( "deletion must parse" );
+  let created : VectorStoreFileBatchObject = serde_json::from_str( FILE_BATCH ).expect( "batch must parse" );
+
+  assert_eq!( json( &file ), r#"{"file_id":"file-abc","attributes":{"team":"sales"}}"# );
+  assert_eq!( json( &batch ), r#"{"file_ids":["file-a","file-b"]}"# );
+  assert_eq!( attached.vector_store_id, "vs_abc" );
+  assert!( detached.deleted );
+  assert_eq!( ( created.file_counts.total, created.id.as_str() ), ( 2, "vsfb_abc" ) );
+}
+
+#[ test ]
+fn empty_list_has_no_first_or_last_id()
+{
+  let list : ListVectorStoresResponse = serde_json::from_str( r#"{"object":"list","data":[],"first_id":null,"last_id":null,"has_more":false}"# )
+    .expect( "empty list must parse" );
+
+  assert!( list.data.is_empty() );
+  assert_eq!( ( list.first_id, list.last_id ), ( None, None ) );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_store_is_created_searched_and_deleted()
+{
+  use api_openai::exposed::
+  {
+    environment ::{ OpenaiEnvironmentImpl, OpenAIRecommended },
+    secret ::Secret,
+    client ::Client,
+  };
+  use api_openai::ClientApiAccessors;
+
+  let secret = Secret::load_with_fallbacks( "OPENAI_API_KEY" ).expect( "INTEGRATION: OPENAI_API_KEY must be available" );
+  let environment = OpenaiEnvironmentImpl::build( secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string() )
+    .expect( "environment builds" );
+  let client = Client::build( environment ).expect( "client builds" );
+  let stores = client.vector_stores();
+
+  let request = CreateVectorStoreRequest::former()
+    .name( "api_openai vector store test".to_string() )
+    .expires_after( VectorStoreExpirationAfter::days_after_last_active( 1 ) )
+    .form();
+  let store = stores.create( request ).await.expect( "INTEGRATION: vector store must be created" );
+  let listed = stores.list( None ).await.expect( "INTEGRATION: vector stores must be listed" );
+  let page = stores.search( &store.id, VectorStoreSearchRequest::new( "refund policy" ) ).await.expect( "INTEGRATION: empty store must be searchable" );
+  let deleted = stores.delete( &store.id ).await.expect( "INTEGRATION: vector store must be deleted" );
+
+  assert_eq!( store.name.as_deref(), Some( "api_openai vector store test" ) );
+  assert!( listed.data.iter().any( | listed | listed.id == store.id ), "{listed:?}" );
+  assert!( page.data.is_empty(), "{page:?}" );
+  assert!( deleted.deleted );
+}
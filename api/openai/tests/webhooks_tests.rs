@@ -0,0 +1,122 @@
+//! Tests for webhook signature verification and webhook events.
+//!
+//! # Test Matrix
+//!
+//! | Component | Test Cases | Purpose |
+//! |-----------|------------|---------|
+//! | `WebhookVerifier::sign` | Standard Webhooks reference delivery | Signed content and encoding match the scheme |
+//! | `WebhookVerifier::verify_at` | Valid, rotated, tampered, stale, missing header | Delivery accepted only when signed and fresh |
+//! | `WebhookVerifier::verify_event` | Batch, fine-tuning, Realtime call, unknown type | Typed event kinds, unknown types kept |
+
+#![ cfg( feature = "webhooks" ) ]
+
+use api_openai::exposed::{ WebhookVerifier, OpenAIError };
+use api_openai::components::webhooks_shared::{ WebhookEvent, WebhookEventKind };
+use reqwest::header::{ HeaderMap, HeaderValue };
+use serde_json::json;
+use core::time::Duration;
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+/// The reference delivery of the Standard Webhooks specification.
+const SECRET : &str = "whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw";
+const ID : &str = "msg_p5jXN8AQM9LWM0D4loKWxJek";
+const TIMESTAMP : u64 = 1_614_265_330;
+const BODY : &str = r#"{"test": 2432232314}"#;
+const SIGNATURE : &str = "v1,g0hM9SsE+OTPJTGt/tmIKtSyZlE3uFJELVlNIOLJ1OE=";
+
+fn headers( id : &str, timestamp : u64, signature : &str ) -> HeaderMap
+{
+  let mut headers = HeaderMap::new();
+  headers.insert( "webhook-id", HeaderValue::from_str( id ).expect( "id" ) );
+  headers.insert( "webhook-timestamp", HeaderValue::from_str( &timestamp.to_string() ).expect( "timestamp" ) );
+  headers.insert( "webhook-signature", HeaderValue::from_str( signature ).expect( "signature" ) );
+  headers
+}
+
+fn at( timestamp : u64 ) -> SystemTime
+{
+  UNIX_EPOCH + Duration::from_secs( timestamp )
+}
+
+fn openai_error( error : &error_tools::Error ) -> &OpenAIError
+{
+  error.downcast_ref::< OpenAIError >().expect( "an OpenAIError" )
+}
+
+#[ test ]
+fn sign_matches_the_reference_delivery()
+{
+  let verifier = WebhookVerifier::new( SECRET ).expect( "secret" );
+  assert_eq!( verifier.sign( ID, TIMESTAMP, BODY.as_bytes() ), SIGNATURE );
+  // The prefix is optional.
+  let unprefixed = WebhookVerifier::new( SECRET.trim_start_matches( "whsec_" ) ).expect( "secret" );
+  assert_eq!( unprefixed.sign( ID, TIMESTAMP, BODY.as_bytes() ), SIGNATURE );
+
+  assert!( matches!( openai_error( &WebhookVerifier::new( "whsec_not base64!" ).unwrap_err() ), OpenAIError::InvalidArgument( _ ) ) );
+}
+
+#[ test ]
+fn verify_at_accepts_only_signed_and_fresh_deliveries()
+{
+  let verifier = WebhookVerifier::new( SECRET ).expect( "secret" );
+  let body = BODY.as_bytes();
+
+  verifier.verify_at( &headers( ID, TIMESTAMP, SIGNATURE ), body, at( TIMESTAMP + 299 ) ).expect( "valid" );
+  // While a secret rotates, one of several signatures matches.
+  let rotated = format!( "v1,Zm9vYmFy {SIGNATURE} v2,ignored" );
+  verifier.verify_at( &headers( ID, TIMESTAMP, &rotated ), body, at( TIMESTAMP ) ).expect( "one matches" );
+
+  let tampered = verifier.verify_at( &headers( ID, TIMESTAMP, SIGNATURE ), br#"{"test": 1}"#, at( TIMESTAMP ) ).unwrap_err();
+  assert!( tampered.to_string().contains( "has no valid signature" ), "{tampered}" );
+  let replayed = verifier.verify_at( &headers( "msg_other", TIMESTAMP, SIGNATURE ), body, at( TIMESTAMP ) ).unwrap_err();
+  assert!( replayed.to_string().contains( "has no valid signature" ), "{replayed}" );
+
+  let stale = verifier.verify_at( &headers( ID, TIMESTAMP, SIGNATURE ), body, at( TIMESTAMP + 301 ) ).unwrap_err();
+  assert!( stale.to_string().contains( "more than 300s" ), "{stale}" );
+  let early = verifier.verify_at( &headers( ID, TIMESTAMP, SIGNATURE ), body, at( TIMESTAMP - 301 ) ).unwrap_err();
+  assert!( matches!( openai_error( &early ), OpenAIError::InvalidArgument( _ ) ) );
+  verifier.clone().with_tolerance( Duration::from_secs( 3600 ) ).verify_at( &headers( ID, TIMESTAMP, SIGNATURE ), body, at( TIMESTAMP + 301 ) ).expect( "wider tolerance" );
+
+  let mut missing = headers( ID, TIMESTAMP, SIGNATURE );
+  missing.remove( "webhook-signature" );
+  assert!( matches!( openai_error( &verifier.verify_at( &missing, body, at( TIMESTAMP ) ).unwrap_err() ), OpenAIError::MissingHeader( _ ) ) );
+}
+
+#[ test ]
+fn verify_event_reads_typed_events()
+{
+  let verifier = WebhookVerifier::new( SECRET ).expect( "secret" );
+  let now = SystemTime::now().duration_since( UNIX_EPOCH ).expect( "after the epoch" ).as_secs();
+  let deliver = | body : serde_json::Value | -> WebhookEvent
+  {
+    let body = body.to_string();
+    let signature = verifier.sign( "wh_1", now, body.as_bytes() );
+    verifier.verify_event( &headers( "wh_1", now, &signature ), body.as_bytes() ).expect( "event" )
+  };
+
+  let batch = deliver( json!( { "id" : "evt_1", "object" : "event", "created_at" : 1_750_000_000, "type" : "batch.completed", "data" : { "id" : "batch_abc" } } ) );
+  assert_eq!( batch.id, "evt_1" );
+  assert_eq!( batch.created_at, 1_750_000_000 );
+  assert!( matches!( &batch.kind, WebhookEventKind::BatchCompleted( data ) if data.id == "batch_abc" ), "{batch:?}" );
+
+  let job = deliver( json!( { "id" : "evt_2", "object" : "event", "created_at" : 1, "type" : "fine_tuning.job.succeeded", "data" : { "id" : "ftjob_abc" } } ) );
+  assert!( matches!( &job.kind, WebhookEventKind::FineTuningJobSucceeded( data ) if data.id == "ftjob_abc" ), "{job:?}" );
+
+  let call = deliver( json!
+  ({
+    "id" : "evt_3", "object" : "event", "created_at" : 1, "type" : "realtime.call.incoming",
+    "data" : { "call_id" : "rtc_abc", "sip_headers" : [ { "name" : "From", "value" : "sip:+15550100@example.com" } ] },
+  }) );
+  match &call.kind
+  {
+    WebhookEventKind::RealtimeCallIncoming( data ) =>
+    {
+      assert_eq!( data.call_id, "rtc_abc" );
+      assert_eq!( data.sip_headers[ 0 ].name, "From" );
+    },
+    other => panic!( "expected an incoming call, got {other:?}" ),
+  }
+
+  let unknown = deliver( json!( { "id" : "evt_4", "object" : "event", "created_at" : 1, "type" : "video.completed", "data" : { "id" : "video_abc" } } ) );
+  assert_eq!( unknown.kind, WebhookEventKind::Other { r#type : "video.completed".to_string(), data : json!( { "id" : "video_abc" } ) } );
+}
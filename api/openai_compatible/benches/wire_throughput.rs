@@ -0,0 +1,138 @@
+//! Wire type throughput : request serialisation, large response
+//! deserialisation, and SSE chunk parsing.
+//!
+//! Payloads are generated at several sizes so a change in per-byte cost
+//! (borrowed deserialisation, a faster JSON backend) shows up separately
+//! from fixed per-call cost. Results are reported in bytes per second.
+
+#![ allow( missing_docs ) ]
+
+use api_openai_compatible::{ ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse };
+use criterion::{ criterion_group, criterion_main, BenchmarkId, Criterion, Throughput };
+use serde_json::json;
+use core::hint::black_box;
+
+/// Conversation turns per request; each turn is one user and one assistant message.
+const TURNS : [ usize; 3 ] = [ 1, 16, 256 ];
+/// Approximate reply text length in bytes.
+const REPLY_BYTES : [ usize; 3 ] = [ 256, 16 * 1024, 512 * 1024 ];
+/// Content deltas per streamed reply.
+const CHUNKS : [ usize; 3 ] = [ 16, 256, 4096 ];
+
+fn request( turns : usize ) -> ChatCompletionRequest
+{
+  let messages : Vec< _ > = ( 0..turns ).flat_map( | turn |
+  [
+    json!( { "role" : "user", "content" : format!( "Question {turn} : what changed in the quarterly report?" ) } ),
+    json!( { "role" : "assistant", "content" : "Revenue grew 4%, costs were flat, and the outlook was raised.".repeat( 4 ) } ),
+  ] ).collect();
+  serde_json::from_value( json!(
+  {
+    "model" : "gpt-4o-mini",
+    "messages" : messages,
+    "temperature" : 0.2,
+    "max_tokens" : 1024,
+    "tools" : [ { "type" : "function", "function" : { "name" : "lookup", "description" : "Look up a figure", "parameters" : { "type" : "object", "properties" : { "metric" : { "type" : "string" } } } } } ],
+  } ) ).expect( "benchmark request must deserialise" )
+}
+
+fn response_body( reply_bytes : usize ) -> String
+{
+  let sentence = "The quick brown fox jumps over the lazy dog. ";
+  let content = sentence.repeat( reply_bytes / sentence.len() + 1 );
+  json!(
+  {
+    "id" : "chatcmpl-bench",
+    "object" : "chat.completion",
+    "created" : 1_700_000_000,
+    "model" : "gpt-4o-mini",
+    "system_fingerprint" : "fp_bench",
+    "choices" : [ { "index" : 0, "message" : { "role" : "assistant", "content" : content }, "finish_reason" : "stop" } ],
+    "usage" : { "prompt_tokens" : 120, "completion_tokens" : reply_bytes / 4, "total_tokens" : 120 + reply_bytes / 4 },
+  } ).to_string()
+}
+
+/// A complete SSE body : `chunks` content deltas, a finish chunk, and `[DONE]`.
+fn stream_body( chunks : usize ) -> String
+{
+  let chunk = | delta : serde_json::Value, finish : Option< &str > | json!(
+  {
+    "id" : "chatcmpl-bench",
+    "object" : "chat.completion.chunk",
+    "created" : 1_700_000_000,
+    "model" : "gpt-4o-mini",
+    "choices" : [ { "index" : 0, "delta" : delta, "finish_reason" : finish } ],
+  } );
+  let deltas = ( 0..chunks ).map( | index | chunk( json!( { "content" : format!( "token{index} " ) } ), None ) );
+  core::iter::once( chunk( json!( { "role" : "assistant" } ), None ) )
+  .chain( deltas )
+  .chain( core::iter::once( chunk( json!( {} ), Some( "stop" ) ) ) )
+  .map( | payload | format!( "data: {payload}\n\n" ) )
+  .chain( core::iter::once( "data: [DONE]\n\n".to_string() ) )
+  .collect()
+}
+
+/// Parses every `data:` payload of an SSE body, as a streaming caller does;
+/// returns the number of choices seen.
+fn parse_stream( body : &str ) -> usize
+{
+  body.lines()
+  .filter_map( | line | line.strip_prefix( "data: " ) )
+  .filter( | data | *data != "[DONE]" )
+  .map( | data | serde_json::from_str::< ChatCompletionChunk >( data ).expect( "chunk must parse" ).choices.len() )
+  .sum()
+}
+
+fn benchmark_request_serialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "openai_compatible/serialize_request" );
+  for turns in TURNS
+  {
+    let request = request( turns );
+    group.throughput( Throughput::Bytes( serde_json::to_vec( &request ).unwrap().len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( turns ), &request, | b, request |
+    {
+      b.iter( || serde_json::to_vec( black_box( request ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_response_deserialization( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "openai_compatible/deserialize_response" );
+  for reply_bytes in REPLY_BYTES
+  {
+    let body = response_body( reply_bytes );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( reply_bytes ), &body, | b, body |
+    {
+      b.iter( || serde_json::from_str::< ChatCompletionResponse >( black_box( body ) ).unwrap() );
+    } );
+  }
+  group.finish();
+}
+
+fn benchmark_stream_parsing( c : &mut Criterion )
+{
+  let mut group = c.benchmark_group( "openai_compatible/parse_stream" );
+  for chunks in CHUNKS
+  {
+    let body = stream_body( chunks );
+    group.throughput( Throughput::Bytes( body.len() as u64 ) );
+    group.bench_with_input( BenchmarkId::from_parameter( chunks ), &body, | b, body |
+    {
+      b.iter( || parse_stream( black_box( body ) ) );
+    } );
+  }
+  group.finish();
+}
+
+criterion_group!
+(
+  benches,
+  benchmark_request_serialization,
+  benchmark_response_deserialization,
+  benchmark_stream_parsing
+);
+criterion_main!( benches );
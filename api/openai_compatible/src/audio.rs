@@ -0,0 +1,651 @@
+//! Audio endpoints: transcription, translation, and text to speech.
+//!
+//! `audio/transcriptions` and `audio/translations` take the audio as a
+//! `multipart/form-data` upload, which `OpenAI`, Groq, `LocalAI`, and
+//! whisper.cpp or faster-whisper servers all accept in the same shape.
+//! `audio/speech` takes JSON and answers with audio bytes, read as they
+//! arrive through a [`SpeechStream`].
+//!
+//! Uploads are [`MultipartForm`](crate::MultipartForm)s, sent through the
+//! client like any other request.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::{ OpenAiCompatError, Result };
+  use crate::multipart::{ MultipartForm, UploadFile };
+  use crate::request_options::RequestOptions;
+  use serde::{ Deserialize, Serialize };
+  use serde_json::{ Map, Value };
+
+  /// Output format of a transcription or translation.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ non_exhaustive ]
+  pub enum TranscriptionFormat
+  {
+    /// `{"text": ...}`; the servers' default.
+    Json,
+    /// The transcript as plain text.
+    Text,
+    /// `SubRip` subtitles.
+    Srt,
+    /// JSON with language, duration, and segment or word timestamps.
+    VerboseJson,
+    /// `WebVTT` subtitles.
+    Vtt,
+  }
+
+  impl TranscriptionFormat
+  {
+    /// Wire value of the `response_format` field.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Json => "json",
+        Self::Text => "text",
+        Self::Srt => "srt",
+        Self::VerboseJson => "verbose_json",
+        Self::Vtt => "vtt",
+      }
+    }
+
+    /// Whether the server answers in JSON.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_json( self ) -> bool
+    {
+      matches!( self, Self::Json | Self::VerboseJson )
+    }
+  }
+
+  /// Timestamps a `verbose_json` transcription carries.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ non_exhaustive ]
+  pub enum TimestampGranularity
+  {
+    /// Start and end of each word, in [`Transcription::words`].
+    Word,
+    /// Start and end of each segment, in [`Transcription::segments`].
+    Segment,
+  }
+
+  impl TimestampGranularity
+  {
+    /// Wire value of a `timestamp_granularities[]` field.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Word => "word",
+        Self::Segment => "segment",
+      }
+    }
+  }
+
+  /// Body of `audio/transcriptions`: speech to text in its own language.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "audio" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, TimestampGranularity, TranscriptionFormat, TranscriptionRequest, UploadFile };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+  /// let file = UploadFile::new( "meeting.mp3", std::fs::read( "meeting.mp3" )? );
+  /// let request = TranscriptionRequest::new( "whisper-1", file )
+  ///   .with_language( "en" )
+  ///   .with_response_format( TranscriptionFormat::VerboseJson )
+  ///   .with_timestamp_granularities( [ TimestampGranularity::Segment ] );
+  /// let transcription = client.transcribe( &request ).await?;
+  /// println!( "{}", transcription.text );
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct TranscriptionRequest
+  {
+    form : MultipartForm,
+    response_format : Option< TranscriptionFormat >,
+  }
+
+  impl TranscriptionRequest
+  {
+    /// Transcription of `file` with `model`.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, file : UploadFile ) -> Self
+    {
+      Self { form : MultipartForm::new().with_text( "model", model ).with_file( "file", file ), response_format : None }
+    }
+
+    /// ISO-639-1 language of the audio, e.g. `en`; improves accuracy and
+    /// latency.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_language( self, language : impl Into< String > ) -> Self
+    {
+      self.with_field( "language", language )
+    }
+
+    /// Text that guides the style or continues a previous segment.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_prompt( self, prompt : impl Into< String > ) -> Self
+    {
+      self.with_field( "prompt", prompt )
+    }
+
+    /// Output format; `json` when unset.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : TranscriptionFormat ) -> Self
+    {
+      self.response_format = Some( format );
+      self.form = self.form.with_text_replaced( "response_format", format.as_str() );
+      self
+    }
+
+    /// Sampling temperature between 0 and 1.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_temperature( self, temperature : f32 ) -> Self
+    {
+      self.with_field( "temperature", temperature.to_string() )
+    }
+
+    /// Timestamps to include; needs [`TranscriptionFormat::VerboseJson`].
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_timestamp_granularities( mut self, granularities : impl IntoIterator< Item = TimestampGranularity > ) -> Self
+    {
+      for granularity in granularities
+      {
+        self = self.with_field( "timestamp_granularities[]", granularity.as_str() );
+      }
+      self
+    }
+
+    /// Adds a form field the typed methods do not cover, e.g. a server's
+    /// own option such as whisper.cpp's `beam_size`. Fields repeat when
+    /// added more than once.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_field( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.form = self.form.with_text( name, value );
+      self
+    }
+
+    /// The form as it is uploaded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn form( &self ) -> &MultipartForm
+    {
+      &self.form
+    }
+  }
+
+  /// Body of `audio/translations`: speech in any language to English text.
+  ///
+  /// Takes the fields of [`TranscriptionRequest`] except the language and
+  /// timestamps.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct TranslationRequest
+  {
+    form : MultipartForm,
+    response_format : Option< TranscriptionFormat >,
+  }
+
+  impl TranslationRequest
+  {
+    /// Translation of `file` with `model`.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, file : UploadFile ) -> Self
+    {
+      Self { form : MultipartForm::new().with_text( "model", model ).with_file( "file", file ), response_format : None }
+    }
+
+    /// English text that guides the style or continues a previous segment.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_prompt( self, prompt : impl Into< String > ) -> Self
+    {
+      self.with_field( "prompt", prompt )
+    }
+
+    /// Output format; `json` when unset.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : TranscriptionFormat ) -> Self
+    {
+      self.response_format = Some( format );
+      self.form = self.form.with_text_replaced( "response_format", format.as_str() );
+      self
+    }
+
+    /// Sampling temperature between 0 and 1.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_temperature( self, temperature : f32 ) -> Self
+    {
+      self.with_field( "temperature", temperature.to_string() )
+    }
+
+    /// Adds a form field the typed methods do not cover.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_field( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.form = self.form.with_text( name, value );
+      self
+    }
+
+    /// The form as it is uploaded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn form( &self ) -> &MultipartForm
+    {
+      &self.form
+    }
+  }
+
+  /// Text of a transcription or translation.
+  ///
+  /// For `text`, `srt`, and `vtt` output only `text` is set, holding the
+  /// body as the server sent it; `verbose_json` adds the language,
+  /// duration, and the timestamps asked for.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  pub struct Transcription
+  {
+    /// The transcript, or the subtitles for `srt` and `vtt`.
+    pub text : String,
+
+    /// Detected or given language (`verbose_json`).
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub language : Option< String >,
+
+    /// Length of the audio in seconds (`verbose_json`).
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub duration : Option< f64 >,
+
+    /// Segments with timestamps (`verbose_json` with segment granularity).
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub segments : Option< Vec< TranscriptionSegment > >,
+
+    /// Words with timestamps (`verbose_json` with word granularity).
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub words : Option< Vec< TranscriptionWord > >,
+
+    /// Fields not modelled above, e.g. `usage` or a server's own.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  /// A span of a `verbose_json` transcription.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct TranscriptionSegment
+  {
+    /// Position of the segment.
+    #[ serde( default ) ]
+    pub id : u32,
+
+    /// Start time in seconds.
+    pub start : f64,
+
+    /// End time in seconds.
+    pub end : f64,
+
+    /// Text of the segment.
+    pub text : String,
+
+    /// Decoder details such as `avg_logprob`, `no_speech_prob`, and `tokens`.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  /// A word of a `verbose_json` transcription.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct TranscriptionWord
+  {
+    /// The word.
+    pub word : String,
+
+    /// Start time in seconds.
+    pub start : f64,
+
+    /// End time in seconds.
+    pub end : f64,
+  }
+
+  /// Audio format of a speech response.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  #[ non_exhaustive ]
+  pub enum SpeechFormat
+  {
+    /// MP3; the servers' default.
+    Mp3,
+    /// Opus in Ogg, for low-latency streaming.
+    Opus,
+    /// AAC.
+    Aac,
+    /// FLAC, lossless.
+    Flac,
+    /// WAV, uncompressed with a header.
+    Wav,
+    /// Raw 24 kHz 16-bit signed little-endian samples.
+    Pcm,
+  }
+
+  /// Body of `audio/speech`: text to spoken audio.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "audio" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, SpeechFormat, SpeechRequest };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+  /// let request = SpeechRequest::new( "tts-1", "Hello there!", "alloy" ).with_response_format( SpeechFormat::Opus );
+  /// let mut stream = client.speech( &request ).await?;
+  /// while let Some( chunk ) = stream.next_chunk().await?
+  /// {
+  ///   // hand `chunk` to the player as it arrives
+  /// }
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct SpeechRequest
+  {
+    /// Speech model, e.g. `tts-1` or `gpt-4o-mini-tts`.
+    pub model : String,
+
+    /// Text to speak.
+    pub input : String,
+
+    /// Voice, e.g. `alloy`; servers such as Kokoro or `LocalAI` have their own.
+    pub voice : String,
+
+    /// Audio format; `mp3` when unset.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< SpeechFormat >,
+
+    /// Playback speed from 0.25 to 4.0.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub speed : Option< f32 >,
+
+    /// How to speak, e.g. tone or accent; newer models only.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub instructions : Option< String >,
+
+    /// Fields not modelled above, sent as they are.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  impl SpeechRequest
+  {
+    /// `input` spoken by `voice` of `model`.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, input : impl Into< String >, voice : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        model : model.into(),
+        input : input.into(),
+        voice : voice.into(),
+        response_format : None,
+        speed : None,
+        instructions : None,
+        extra : Map::new(),
+      }
+    }
+
+    /// Audio format of the response.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : SpeechFormat ) -> Self
+    {
+      self.response_format = Some( format );
+      self
+    }
+
+    /// Playback speed from 0.25 to 4.0.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_speed( mut self, speed : f32 ) -> Self
+    {
+      self.speed = Some( speed );
+      self
+    }
+
+    /// How to speak, e.g. tone or accent.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_instructions( mut self, instructions : impl Into< String > ) -> Self
+    {
+      self.instructions = Some( instructions.into() );
+      self
+    }
+  }
+
+  /// Audio of a speech response, read as it arrives.
+  ///
+  /// Returned once the server has accepted the request; the body is read
+  /// chunk by chunk with [`next_chunk`](Self::next_chunk), or whole with
+  /// [`bytes`](Self::bytes). Dropping it closes the connection.
+  #[ derive( Debug ) ]
+  pub struct SpeechStream
+  {
+    response : reqwest::Response,
+  }
+
+  impl SpeechStream
+  {
+    /// Media type the server reported, e.g. `audio/mpeg`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn content_type( &self ) -> Option< &str >
+    {
+      self.response.headers().get( reqwest::header::CONTENT_TYPE ).and_then( | value | value.to_str().ok() )
+    }
+
+    /// The next piece of audio, or `None` once the body has ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns a network error if reading the body fails.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn next_chunk( &mut self ) -> Result< Option< bytes::Bytes > >
+    {
+      self.response.chunk().await.map_err( OpenAiCompatError::from ).map_err( Into::into )
+    }
+
+    /// The rest of the audio at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns a network error if reading the body fails.
+    #[ inline ]
+    pub async fn bytes( self ) -> Result< bytes::Bytes >
+    {
+      self.response.bytes().await.map_err( OpenAiCompatError::from ).map_err( Into::into )
+    }
+  }
+
+  impl< E > Client< E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    /// Transcribes audio with `audio/transcriptions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns network or timeout errors, the classified error for an error
+    /// status, and `Deserialization` when a JSON format does not hold a
+    /// transcription.
+    #[ inline ]
+    pub async fn transcribe( &self, request : &TranscriptionRequest ) -> Result< Transcription >
+    {
+      self.transcribe_with_options( request, &RequestOptions::new() ).await
+    }
+
+    /// [`transcribe`](Self::transcribe) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`transcribe`](Self::transcribe), and `api_common::Cancelled`
+    /// when the cancellation token in `options` is cancelled first.
+    #[ inline ]
+    pub async fn transcribe_with_options( &self, request : &TranscriptionRequest, options : &RequestOptions ) -> Result< Transcription >
+    {
+      self.upload( "audio/transcriptions", &request.form, request.response_format, options ).await
+    }
+
+    /// Translates audio into English text with `audio/translations`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`transcribe`](Self::transcribe).
+    #[ inline ]
+    pub async fn translate( &self, request : &TranslationRequest ) -> Result< Transcription >
+    {
+      self.translate_with_options( request, &RequestOptions::new() ).await
+    }
+
+    /// [`translate`](Self::translate) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`transcribe_with_options`](Self::transcribe_with_options).
+    #[ inline ]
+    pub async fn translate_with_options( &self, request : &TranslationRequest, options : &RequestOptions ) -> Result< Transcription >
+    {
+      self.upload( "audio/translations", &request.form, request.response_format, options ).await
+    }
+
+    /// Generates speech with `audio/speech`, returning once the server has
+    /// accepted the request so the audio can be played as it arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns network or timeout errors, the classified error for an error
+    /// status, and `api_common::GuardrailViolation` when a guardrail denies the request.
+    #[ inline ]
+    pub async fn speech( &self, request : &SpeechRequest ) -> Result< SpeechStream >
+    {
+      self.speech_with_options( request, &RequestOptions::new() ).await
+    }
+
+    /// [`speech`](Self::speech) with per-request `options`. A cancellation
+    /// token ends the call only until the response starts; to stop reading
+    /// the audio, drop the stream.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`speech`](Self::speech), and `api_common::Cancelled` when the
+    /// call is cancelled.
+    #[ inline ]
+    pub async fn speech_with_options( &self, request : &SpeechRequest, options : &RequestOptions ) -> Result< SpeechStream >
+    {
+      let response = self.post_with_options_raw( "audio/speech", request, options ).await?;
+      let status = response.status();
+      if !status.is_success()
+      {
+        let headers = response.headers().clone();
+        let body = options.cancellable( async { Ok( response.bytes().await ) } ).await?;
+        return Err( OpenAiCompatError::from_response( status, &headers, &body.unwrap_or_default() ).into() );
+      }
+      Ok( SpeechStream { response } )
+    }
+
+    async fn upload( &self, path : &str, form : &MultipartForm, format : Option< TranscriptionFormat >, options : &RequestOptions ) -> Result< Transcription >
+    {
+      let body = self.post_multipart_body( path, form, options ).await?;
+      read_transcription( &body, format )
+    }
+  }
+
+  /// A transcription from the body the server answered in `format`: JSON
+  /// formats are deserialised, text formats kept as sent.
+  fn read_transcription( body : &[ u8 ], format : Option< TranscriptionFormat > ) -> Result< Transcription >
+  {
+    if format.map_or( true, TranscriptionFormat::is_json )
+    {
+      return serde_json::from_slice( body ).map_err( | e | OpenAiCompatError::deserialization( &e, body ).into() );
+    }
+    Ok( Transcription { text : String::from_utf8_lossy( body ).into_owned(), ..Transcription::default() } )
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    #[ test ]
+    fn verbose_json_fills_language_duration_and_timestamps()
+    {
+      let body = serde_json::json!(
+      {
+        "task" : "transcribe", "language" : "english", "duration" : 2.5, "text" : "Hello world.",
+        "segments" : [ { "id" : 0, "seek" : 0, "start" : 0.0, "end" : 2.5, "text" : "Hello world.", "avg_logprob" : -0.2 } ],
+        "words" : [ { "word" : "Hello", "start" : 0.0, "end" : 0.8 }, { "word" : "world", "start" : 0.9, "end" : 1.6 } ],
+      } ).to_string();
+
+      let transcription = read_transcription( body.as_bytes(), Some( TranscriptionFormat::VerboseJson ) ).unwrap();
+
+      assert_eq!( ( transcription.text.as_str(), transcription.language.as_deref(), transcription.duration ), ( "Hello world.", Some( "english" ), Some( 2.5 ) ) );
+      let segments = transcription.segments.unwrap();
+      assert_eq!( ( segments[ 0 ].end, segments[ 0 ].extra[ "avg_logprob" ].clone() ), ( 2.5, serde_json::json!( -0.2 ) ) );
+      assert_eq!( transcription.words.unwrap()[ 1 ].word, "world" );
+      assert_eq!( transcription.extra[ "task" ], "transcribe" );
+    }
+
+    #[ test ]
+    fn text_formats_are_kept_as_sent()
+    {
+      let srt = "1\n00:00:00,000 --> 00:00:02,500\nHello world.\n";
+      for format in [ TranscriptionFormat::Text, TranscriptionFormat::Srt, TranscriptionFormat::Vtt ]
+      {
+        let transcription = read_transcription( srt.as_bytes(), Some( format ) ).unwrap();
+        assert_eq!( transcription, Transcription { text : srt.to_owned(), ..Transcription::default() } );
+      }
+    }
+
+    #[ test ]
+    fn unset_format_is_read_as_json()
+    {
+      assert_eq!( read_transcription( br#"{"text":"Good morning."}"#, None ).unwrap().text, "Good morning." );
+      let error = read_transcription( b"Good morning.", None ).unwrap_err();
+      assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Deserialization { .. } ) ), "{error:?}" );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    TranscriptionFormat,
+    TimestampGranularity,
+    TranscriptionRequest,
+    TranslationRequest,
+    Transcription,
+    TranscriptionSegment,
+    TranscriptionWord,
+    SpeechFormat,
+    SpeechRequest,
+    SpeechStream,
+  };
+}
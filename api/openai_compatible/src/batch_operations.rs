@@ -0,0 +1,213 @@
+//! Client-side batches: many requests run concurrently under a fixed limit.
+//!
+//! OpenAI-compatible servers answer one chat completion per call, and most
+//! offer no synchronous batch endpoint. [`BatchExecutor`] sends many calls
+//! at once, at most `concurrency_limit` in flight, and reports every item in
+//! input order with its own result and timing. One failure never stops the
+//! others. The calls run inside the caller's task, so nothing is spawned and
+//! the client is borrowed, not shared.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::components::{ ChatCompletionRequest, ChatCompletionResponse };
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::Result;
+  use crate::request_options::RequestOptions;
+  use core::future::Future;
+  use core::time::Duration;
+  use futures_util::StreamExt;
+  use web_time::Instant;
+
+  /// Path of the chat completions endpoint.
+  const CHAT_COMPLETIONS : &str = "chat/completions";
+
+  /// Runs batches of calls, at most `concurrency_limit` at a time.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "batch_operations" ) ]
+  /// # {
+  /// use api_openai_compatible::{ BatchExecutor, ChatCompletionRequest, Client, Message, OpenAiCompatEnvironmentImpl };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+  /// let requests : Vec< _ > = [ "Hello!", "Goodbye!" ].into_iter()
+  ///   .map( | text | ChatCompletionRequest::former().model( "gpt-4o-mini".to_string() ).messages( vec![ Message::user( text ) ] ).form() )
+  ///   .collect();
+  ///
+  /// let report = BatchExecutor::new( 5 ).execute( &client, requests ).await;
+  /// println!( "{}/{} in {:?}", report.succeeded(), report.items.len(), report.elapsed );
+  /// for item in &report.items
+  /// {
+  ///   if let Err( error ) = &item.result
+  ///   {
+  ///     println!( "request {} failed after {:?} : {error}", item.index, item.elapsed );
+  ///   }
+  /// }
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct BatchExecutor
+  {
+    concurrency_limit : usize,
+  }
+
+  /// One call of a batch.
+  #[ derive( Debug ) ]
+  pub struct BatchItem< T >
+  {
+    /// Position of the input the call was made for.
+    pub index : usize,
+    /// What the call returned.
+    pub result : Result< T >,
+    /// Time from starting the call to its end; waiting for a free slot is
+    /// not included.
+    pub elapsed : Duration,
+  }
+
+  /// Every call of a batch, in input order, with the time the whole batch
+  /// took.
+  #[ derive( Debug ) ]
+  pub struct BatchReport< T >
+  {
+    /// One item per input, ordered by `index`.
+    pub items : Vec< BatchItem< T > >,
+    /// Time from starting the batch to the end of its last call.
+    pub elapsed : Duration,
+  }
+
+  impl BatchExecutor
+  {
+    /// Executor running at most `concurrency_limit` calls at once; `0` is
+    /// taken as `1`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( concurrency_limit : usize ) -> Self
+    {
+      Self { concurrency_limit : concurrency_limit.max( 1 ) }
+    }
+
+    /// Most calls in flight at once.
+    #[ inline ]
+    #[ must_use ]
+    pub fn concurrency_limit( &self ) -> usize
+    {
+      self.concurrency_limit
+    }
+
+    /// Posts every request to `chat/completions` on `client`.
+    ///
+    /// Each call goes through the client as `post` does: limiter,
+    /// guardrails, hooks, and idempotency keys apply per request.
+    #[ inline ]
+    pub async fn execute< E >( &self, client : &Client< E >, requests : Vec< ChatCompletionRequest > ) -> BatchReport< ChatCompletionResponse >
+    where
+      E : OpenAiCompatEnvironment,
+    {
+      self.run( requests, | request | async move { client.post_with_options( CHAT_COMPLETIONS, &request, &RequestOptions::new() ).await } ).await
+    }
+
+    /// [`execute`](Self::execute), calling `on_complete` with each item as
+    /// its call ends, in completion order.
+    #[ inline ]
+    pub async fn execute_with_progress< E, P >( &self, client : &Client< E >, requests : Vec< ChatCompletionRequest >, on_complete : P ) -> BatchReport< ChatCompletionResponse >
+    where
+      E : OpenAiCompatEnvironment,
+      P : FnMut( &BatchItem< ChatCompletionResponse > ),
+    {
+      let call = | request : ChatCompletionRequest | async move { client.post_with_options( CHAT_COMPLETIONS, &request, &RequestOptions::new() ).await };
+      self.run_with_progress( requests, call, on_complete ).await
+    }
+
+    /// Makes one call per input with `call`, for endpoints other than chat
+    /// completions or clients other than [`Client`].
+    #[ inline ]
+    pub async fn run< I, T, F, Fut >( &self, inputs : impl IntoIterator< Item = I >, call : F ) -> BatchReport< T >
+    where
+      F : FnMut( I ) -> Fut,
+      Fut : Future< Output = Result< T > >,
+    {
+      self.run_with_progress( inputs, call, | _ | {} ).await
+    }
+
+    /// [`run`](Self::run), calling `on_complete` with each item as its call
+    /// ends, in completion order.
+    #[ inline ]
+    pub async fn run_with_progress< I, T, F, Fut, P >( &self, inputs : impl IntoIterator< Item = I >, mut call : F, mut on_complete : P ) -> BatchReport< T >
+    where
+      F : FnMut( I ) -> Fut,
+      Fut : Future< Output = Result< T > >,
+      P : FnMut( &BatchItem< T > ),
+    {
+      let started = Instant::now();
+      let calls = inputs.into_iter().enumerate().map( | ( index, input ) |
+      {
+        let call = call( input );
+        async move
+        {
+          let started = Instant::now();
+          let result = call.await;
+          BatchItem { index, result, elapsed : started.elapsed() }
+        }
+      } );
+      let mut running = futures_util::stream::iter( calls ).buffer_unordered( self.concurrency_limit );
+      let mut items = Vec::new();
+      while let Some( item ) = running.next().await
+      {
+        on_complete( &item );
+        items.push( item );
+      }
+      items.sort_by_key( | item | item.index );
+      BatchReport { items, elapsed : started.elapsed() }
+    }
+  }
+
+  impl< T > BatchReport< T >
+  {
+    /// Number of calls that succeeded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn succeeded( &self ) -> usize
+    {
+      self.items.iter().filter( | item | item.result.is_ok() ).count()
+    }
+
+    /// Number of calls that failed.
+    #[ inline ]
+    #[ must_use ]
+    pub fn failed( &self ) -> usize
+    {
+      self.items.len() - self.succeeded()
+    }
+
+    /// Mean time of one call, `None` for an empty batch.
+    #[ inline ]
+    #[ must_use ]
+    pub fn mean_elapsed( &self ) -> Option< Duration >
+    {
+      let count = u32::try_from( self.items.len() ).ok().filter( | count | *count > 0 )?;
+      Some( self.items.iter().map( | item | item.elapsed ).sum::< Duration >() / count )
+    }
+
+    /// The results alone, in input order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn into_results( self ) -> Vec< Result< T > >
+    {
+      self.items.into_iter().map( | item | item.result ).collect()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BatchExecutor,
+    BatchItem,
+    BatchReport,
+  };
+}
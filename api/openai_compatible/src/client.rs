@@ -7,8 +7,28 @@ mod private
 {
   use crate::error::{ OpenAiCompatError, Result };
   use crate::environment::OpenAiCompatEnvironment;
+  use crate::hooks::{ Hooks, RequestParts, ResponseParts };
+  use crate::profile::ProviderProfile;
+  use crate::rate_limit_info::{ RateLimitInfo, WithRateLimits };
+  use crate::request_options::RequestOptions;
+  #[ cfg( feature = "curl_diagnostics" ) ]
+  use crate::curl_diagnostics::PreparedRequest;
+  #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+  use crate::mock::MockTransport;
+  #[ cfg( any( feature = "audio", feature = "images" ) ) ]
+  use crate::multipart::MultipartForm;
+  #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+  use crate::record_replay::Cassette;
+  #[ cfg( feature = "retry" ) ]
+  use crate::retry::RetryConfig;
+  use std::borrow::Cow;
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
   use core::time::Duration;
   use reqwest::Client as HttpClient;
+  use reqwest::{ header, Method };
+
+  /// Header carrying the idempotency key of a request.
+  const IDEMPOTENCY_KEY_HEADER : &str = "Idempotency-Key";
 
   /// Async HTTP client for OpenAI-compatible REST APIs.
   ///
@@ -36,9 +56,52 @@ mod private
     E : OpenAiCompatEnvironment,
   {
     /// Underlying reqwest HTTP client, pre-configured with timeout settings.
-    http_client : HttpClient,
+    http : HttpClient,
     /// Environment supplying credentials and base URL.
     environment : E,
+    /// Limiter shared with other clients spending the same quota.
+    #[ cfg( feature = "rate_limiting" ) ]
+    rate_limiter : Option< api_common::SharedRateLimiter >,
+    /// Gate shared with other clients talking to the same server or account.
+    #[ cfg( feature = "rate_limiting" ) ]
+    concurrency_gate : Option< api_common::ConcurrencyGate >,
+    /// Guardrails run over every request and response body.
+    #[ cfg( feature = "guardrail" ) ]
+    guardrails : api_common::Guardrails,
+    /// Whether POSTs without an explicit key get a generated one.
+    #[ cfg( feature = "idempotency" ) ]
+    idempotency_keys : bool,
+    /// Hooks run around every request.
+    hooks : Hooks,
+    /// Canned responses answering requests in place of the network.
+    #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+    mock : Option< MockTransport >,
+    /// Cassette recording requests or answering them from a recording.
+    #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+    cassette : Option< Cassette >,
+  }
+
+  /// A request that has been sent and answered, with what the response
+  /// hooks need to know about it.
+  #[ derive( Debug ) ]
+  struct Sent
+  {
+    method : Method,
+    url : String,
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    started : std::time::Instant,
+    response : reqwest::Response,
+    /// Held until the body has been read.
+    _admission : Admission,
+  }
+
+  /// What a request holds while in flight: the concurrency slot, if a gate
+  /// is configured. Released on drop.
+  #[ derive( Debug ) ]
+  struct Admission
+  {
+    #[ cfg( feature = "rate_limiting" ) ]
+    _permit : Option< api_common::ConcurrencyPermit >,
   }
 
   impl< E > Client< E >
@@ -48,89 +111,989 @@ mod private
     /// Builds an HTTP client configured from the given environment.
     ///
     /// Configures connection pooling, timeout, and authentication headers.
+    /// On `wasm32` the client is backed by the host `fetch` API, which does not
+    /// expose request or connect timeouts; `env.timeout()` is ignored there.
     ///
     /// # Errors
     ///
-    /// Returns an error if the underlying `reqwest::Client` cannot be built
-    /// (e.g. invalid TLS configuration on the current platform).
+    /// Returns `OpenAiCompatError::Environment` if the environment's
+    /// `TlsConfig` holds a malformed certificate or key, its
+    /// `ConnectionTuning` names a proxy that is not a valid URL, or the
+    /// underlying `reqwest::Client` cannot be built (e.g. invalid TLS configuration on
+    /// the current platform).
     #[ inline ]
     pub fn build( env : E ) -> Result< Self >
     {
-      let http_client = HttpClient::builder()
+      let http_client = Self::http_builder( &env )?
+        .build()
+        .map_err( | e | OpenAiCompatError::Environment( e.to_string() ) )?;
+      Ok( Self
+      {
+        http : http_client,
+        environment : env,
+        #[ cfg( feature = "rate_limiting" ) ]
+        rate_limiter : None,
+        #[ cfg( feature = "rate_limiting" ) ]
+        concurrency_gate : None,
+        #[ cfg( feature = "guardrail" ) ]
+        guardrails : api_common::Guardrails::new(),
+        #[ cfg( feature = "idempotency" ) ]
+        idempotency_keys : false,
+        hooks : Hooks::new(),
+        #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+        mock : None,
+        #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+        cassette : None,
+      } )
+    }
+
+    /// HTTP client settings derived from the environment.
+    #[ cfg_attr( not( all( any( feature = "tls", feature = "connection" ), not( target_arch = "wasm32" ) ) ), allow( clippy::unnecessary_wraps ) ) ]
+    fn http_builder( env : &E ) -> Result< reqwest::ClientBuilder >
+    {
+      let builder = HttpClient::builder();
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let builder = builder
         .timeout( env.timeout() )
-        .connect_timeout( Duration::from_secs( 15 ) )
+        .connect_timeout( Duration::from_secs( 15 ) );
+      #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+      let builder = match env.tls()
+      {
+        Some( tls ) => tls.apply( builder )?,
+        None => builder,
+      };
+      #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+      let builder = match env.connection_tuning()
+      {
+        Some( tuning ) => tuning.clone().apply_to( builder ).map_err( | e | OpenAiCompatError::Environment( e.to_string() ) )?,
+        None => builder,
+      };
+      Ok( builder )
+    }
+
+    /// Rebuilds the HTTP client with `tuning` applied on top of the
+    /// environment's timeouts and connection settings. Open connections of the previous client are
+    /// dropped; call [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Environment` if `tuning` names a proxy that
+    /// is not a valid URL, asks for HTTP/2 prior knowledge without the `http2`
+    /// feature, or the client cannot be built.
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self >
+    {
+      self.http = tuning.apply_to( Self::http_builder( &self.environment )? ).map_err( | e | OpenAiCompatError::Environment( e.to_string() ) )?
         .build()
         .map_err( | e | OpenAiCompatError::Environment( e.to_string() ) )?;
-      Ok( Self { http_client, environment : env } )
+      Ok( self )
+    }
+
+    /// Opens a connection to the base URL ahead of the first request, so
+    /// that request does not pay for DNS, TCP, and the TLS handshake.
+    ///
+    /// Sends one unauthenticated `HEAD` request outside the rate limiter and
+    /// concurrency gate and returns its round-trip time. Any HTTP status
+    /// counts as success; only a failure to connect is an error. The
+    /// connection stays in the pool for the configured idle timeout. A
+    /// client with a mock transport has nothing to open and returns zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns a network or timeout error if no connection could be opened.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    #[ inline ]
+    pub async fn warm_up( &self ) -> Result< Duration >
+    {
+      #[ cfg( feature = "mock" ) ]
+      if self.mock.is_some()
+      {
+        return Ok( Duration::ZERO );
+      }
+      let started = std::time::Instant::now();
+      self.http
+        .head( self.environment.base_url() )
+        .send()
+        .await
+        .map_err( OpenAiCompatError::from )?;
+      Ok( started.elapsed() )
+    }
+
+    /// Limits this client alone to `limits`, e.g.
+    /// `RateLimits::new().with_requests_per_minute( 500 ).with_tokens_per_minute( 200_000 )`.
+    ///
+    /// Same as [`with_shared_rate_limiter`](Self::with_shared_rate_limiter)
+    /// with a limiter no other client holds.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_rate_limits( self, limits : api_common::RateLimits ) -> Self
+    {
+      self.with_shared_rate_limiter( api_common::SharedRateLimiter::new( limits ) )
+    }
+
+    /// Takes one request from `limiter` before every request this client
+    /// sends, so clients holding clones of the same limiter stay within one
+    /// quota together.
+    ///
+    /// With a token quota, a request also waits while the token bucket is
+    /// in debt, and the `usage.total_tokens` of every successful response
+    /// is charged to it. Streamed usage is not seen by the client; record it
+    /// with `SharedRateLimiter::record_tokens`.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_shared_rate_limiter( mut self, limiter : api_common::SharedRateLimiter ) -> Self
+    {
+      self.rate_limiter = Some( limiter );
+      self
+    }
+
+    /// Caps in-flight requests with `gate`, shared with other clients of the
+    /// same server or account; further requests queue in arrival order.
+    ///
+    /// A request holds its slot until the response body has been read. A
+    /// request that outwaits the gate's queue timeout fails with
+    /// `api_common::QueueTimeout`.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_concurrency_gate( mut self, gate : api_common::ConcurrencyGate ) -> Self
+    {
+      self.concurrency_gate = Some( gate );
+      self
+    }
+
+    /// Runs `guardrails` over the body of every request before it is sent
+    /// and of every successful response before it is deserialised.
+    ///
+    /// A denied request fails with `api_common::GuardrailViolation` before
+    /// it takes a rate limit request or a concurrency slot. Error bodies are
+    /// not passed to the guardrails.
+    #[ cfg( feature = "guardrail" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_guardrails( mut self, guardrails : api_common::Guardrails ) -> Self
+    {
+      self.guardrails = guardrails;
+      self
+    }
+
+    /// Generates an `api_common::IdempotencyKey` for every POST whose
+    /// options carry no key of their own.
+    ///
+    /// The key is chosen once per call, so a retry of the same call sends
+    /// the same key and a provider that honours `Idempotency-Key` applies
+    /// the write only once.
+    #[ cfg( feature = "idempotency" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_idempotency_keys( mut self ) -> Self
+    {
+      self.idempotency_keys = true;
+      self
+    }
+
+    /// Runs `hook` just before every request is sent, after admission by
+    /// the rate limiter and concurrency gate; it may change the headers,
+    /// e.g. to inject a tracing id. Hooks run in the order added.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+    /// use reqwest::header::HeaderValue;
+    ///
+    /// # fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?
+    ///   .with_request_hook( | request | { request.headers.insert( "x-trace-id", HeaderValue::from_static( "trace-1" ) ); } );
+    /// # Ok( () ) }
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_request_hook< F >( mut self, hook : F ) -> Self
+    where
+      F : Fn( &mut RequestParts< '_ > ) + Send + Sync + 'static,
+    {
+      self.hooks = self.hooks.with_request( hook );
+      self
+    }
+
+    /// Runs `hook` once every response, error statuses included, has been
+    /// received and before it is interpreted; it sees the status, headers,
+    /// raw body, and round-trip time. Hooks run in the order added.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_hook< F >( mut self, hook : F ) -> Self
+    where
+      F : Fn( &ResponseParts< '_ > ) + Send + Sync + 'static,
+    {
+      self.hooks = self.hooks.with_response( hook );
+      self
+    }
+
+    /// Replaces the client's hooks with `hooks`, which may be shared with
+    /// other clients.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_hooks( mut self, hooks : Hooks ) -> Self
+    {
+      self.hooks = hooks;
+      self
+    }
+
+    /// Answers every request from `transport` instead of the network.
+    ///
+    /// Requests are built as usual — headers, idempotency keys, guardrails,
+    /// hooks, retries, and rate limits all apply — and `transport` records
+    /// them and returns its canned responses. See
+    /// [`MockTransport::client`] for a client ready to use.
+    #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_mock_transport( mut self, transport : MockTransport ) -> Self
+    {
+      self.mock = Some( transport );
+      self
+    }
+
+    /// Records every request and its response into `cassette`, or answers
+    /// every request from it, depending on its mode.
+    ///
+    /// Requests are built as usual, and in replay mode nothing is sent. The
+    /// environment's API key is scrubbed from everything recorded.
+    #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cassette( mut self, cassette : Cassette ) -> Self
+    {
+      self.cassette = Some( cassette );
+      self
+    }
+
+    /// The environment's provider profile, whose leniencies are applied to
+    /// every response; pass it to `ChunkDecoder::with_profile` when
+    /// decoding a raw stream.
+    #[ inline ]
+    #[ must_use ]
+    pub fn provider_profile( &self ) -> ProviderProfile
+    {
+      self.environment.provider_profile()
     }
 
     /// Sends a POST request and deserialises the JSON response.
     ///
     /// # Errors
     ///
-    /// Returns network, timeout, or deserialisation errors.
+    /// Returns network, timeout, or deserialisation errors,
+    /// `api_common::GuardrailViolation` when a guardrail denies the request
+    /// or the response, and `api_common::Cancelled` when the cancellation
+    /// token in the options is cancelled first.
     #[ inline ]
     pub async fn post< I, O >( &self, path : &str, body : &I ) -> Result< O >
     where
       I : serde::Serialize,
       O : serde::de::DeserializeOwned,
     {
-      let url = format!( "{}{}", self.environment.base_url(), path );
-      let headers = self.environment.headers()?;
-      let response = self.http_client
-        .post( &url )
-        .headers( headers )
-        .json( body )
-        .send()
-        .await
-        .map_err( OpenAiCompatError::from )?;
-      Self::handle_response( response ).await
+      self.post_with_options( path, body, &RequestOptions::new() ).await
+    }
+
+    /// Sends a POST request with per-request `options` and deserialises the
+    /// JSON response.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post).
+    #[ inline ]
+    pub async fn post_with_options< I, O >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      options.cancellable( async
+      {
+        let sent = self.send_post( path, body, options ).await?;
+        self.handle_response( sent ).await.map( WithRateLimits::into_value )
+      } ).await
+    }
+
+    /// [`post_with_options`](Self::post_with_options), returning the
+    /// rate-limit headers of the response with its value, for callers that
+    /// pace their own requests.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ ChatCompletionRequest, ChatCompletionResponse, Client, Message, OpenAiCompatEnvironmentImpl, RequestOptions };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+    /// let request = ChatCompletionRequest::former().model( "gpt-4o-mini".to_string() ).messages( vec![ Message::user( "Hi" ) ] ).form();
+    /// let answer = client.post_with_rate_limits::< _, ChatCompletionResponse >( "chat/completions", &request, &RequestOptions::new() ).await?;
+    /// if let Some( wait ) = answer.rate_limits.wait()
+    /// {
+    ///   tokio::time::sleep( wait ).await;
+    /// }
+    /// # Ok( () ) }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post); a `429` is `RateLimited` carrying the
+    /// rate-limit headers as well.
+    #[ inline ]
+    pub async fn post_with_rate_limits< I, O >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< WithRateLimits< O > >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      options.cancellable( async
+      {
+        let sent = self.send_post( path, body, options ).await?;
+        self.handle_response( sent ).await
+      } ).await
+    }
+
+    /// Sends a POST request and returns the response untouched, error
+    /// statuses included, so fields the typed structs drop can be read.
+    ///
+    /// Everything before sending applies as in [`post`](Self::post): the
+    /// limiter, idempotency keys, request guardrails, and request hooks.
+    /// The body is left unread, so response guardrails and response hooks
+    /// do not run, and a cancellation token in the options ends the call only
+    /// until the headers arrive; to stop reading a streamed body, read it
+    /// under `CancellationToken::run_until_cancelled` or drop the response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ ChatCompletionRequest, ChatCompletionResponse, Client, Message, OpenAiCompatEnvironmentImpl };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+    /// let request = ChatCompletionRequest::former().model( "gpt-4o-mini".to_string() ).messages( vec![ Message::user( "Hi" ) ] ).form();
+    /// let response = client.post_raw( "chat/completions", &request ).await?;
+    /// let request_id = response.headers().get( "x-request-id" ).cloned();
+    /// let bytes = response.bytes().await?;
+    /// let extended : serde_json::Value = serde_json::from_slice( &bytes )?;
+    /// let typed : ChatCompletionResponse = serde_json::from_slice( &bytes )?;
+    /// # Ok( () ) }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns network or timeout errors,
+    /// `api_common::GuardrailViolation` when a guardrail denies the request,
+    /// and `api_common::Cancelled` when the call is cancelled; never an
+    /// error for the status.
+    #[ inline ]
+    pub async fn post_raw< I >( &self, path : &str, body : &I ) -> Result< reqwest::Response >
+    where
+      I : serde::Serialize,
+    {
+      self.post_with_options_raw( path, body, &RequestOptions::new() ).await
+    }
+
+    /// [`post_raw`](Self::post_raw) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post_raw`](Self::post_raw).
+    #[ inline ]
+    pub async fn post_with_options_raw< I >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< reqwest::Response >
+    where
+      I : serde::Serialize,
+    {
+      options.cancellable( async { Ok( self.send_post( path, body, options ).await?.response ) } ).await
+    }
+
+    /// Applies the client's idempotency keys and request guardrails to a
+    /// POST, then sends it.
+    async fn send_post< I >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< Sent >
+    where
+      I : serde::Serialize,
+    {
+      let options = self.resolve_options( options );
+      let options = options.as_ref();
+      #[ cfg( feature = "guardrail" ) ]
+      if !self.guardrails.is_empty()
+      {
+        let mut body = serde_json::to_value( body ).map_err( OpenAiCompatError::from )?;
+        self.guardrails.check_request( &mut body )?;
+        return self.send_json( path, &body, options ).await;
+      }
+      self.send_json( path, body, options ).await
+    }
+
+    /// `options` with a generated idempotency key when the client adds
+    /// keys and the caller brought none.
+    #[ cfg_attr( not( feature = "idempotency" ), allow( clippy::unused_self ) ) ]
+    fn resolve_options< 'o >( &self, options : &'o RequestOptions ) -> Cow< 'o, RequestOptions >
+    {
+      #[ cfg( feature = "idempotency" ) ]
+      if self.idempotency_keys && options.idempotency_key().is_none()
+      {
+        return Cow::Owned( options.clone().idempotent() );
+      }
+      Cow::Borrowed( options )
+    }
+
+    async fn send_json< I >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< Sent >
+    where
+      I : serde::Serialize,
+    {
+      let url = self.environment.url( path );
+      let mut headers = self.headers( options )?;
+      let admission = self.admit().await?;
+      self.hooks.on_request( &mut RequestParts { method : &Method::POST, url : &url, headers : &mut headers } );
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let started = std::time::Instant::now();
+      let response = self.dispatch( self.http.post( &url ).headers( headers ).json( body ) ).await?;
+      Ok( Sent
+      {
+        method : Method::POST,
+        url,
+        #[ cfg( not( target_arch = "wasm32" ) ) ]
+        started,
+        response,
+        _admission : admission,
+      } )
+    }
+
+    /// Sends `form` as a `multipart/form-data` POST and deserialises the
+    /// JSON response.
+    ///
+    /// Environment headers, idempotency keys, rate limits, and hooks apply
+    /// as for [`post`](Self::post); guardrails, which read JSON bodies, do
+    /// not inspect the form.
+    ///
+    /// # Errors
+    ///
+    /// Returns network, timeout, or deserialisation errors, and the
+    /// classified error for an error status.
+    #[ cfg( any( feature = "audio", feature = "images" ) ) ]
+    #[ inline ]
+    pub async fn post_multipart< O >( &self, path : &str, form : &MultipartForm ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      self.post_multipart_with_options( path, form, &RequestOptions::new() ).await
+    }
+
+    /// [`post_multipart`](Self::post_multipart) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post_multipart`](Self::post_multipart), and
+    /// `api_common::Cancelled` when the cancellation token in `options` is
+    /// cancelled first.
+    #[ cfg( any( feature = "audio", feature = "images" ) ) ]
+    #[ inline ]
+    pub async fn post_multipart_with_options< O >( &self, path : &str, form : &MultipartForm, options : &RequestOptions ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      options.cancellable( async
+      {
+        let options = self.resolve_options( options );
+        let sent = self.send_multipart( path, form, options.as_ref() ).await?;
+        self.handle_response( sent ).await.map( WithRateLimits::into_value )
+      } ).await
+    }
+
+    /// [`post_multipart_with_options`](Self::post_multipart_with_options)
+    /// returning the success body undecoded, for answers that may not be
+    /// JSON.
+    #[ cfg( feature = "audio" ) ]
+    pub( crate ) async fn post_multipart_body( &self, path : &str, form : &MultipartForm, options : &RequestOptions ) -> Result< bytes::Bytes >
+    {
+      options.cancellable( async
+      {
+        let options = self.resolve_options( options );
+        let sent = self.send_multipart( path, form, options.as_ref() ).await?;
+        self.read_response( sent ).await.map( WithRateLimits::into_value )
+      } ).await
+    }
+
+    #[ cfg( any( feature = "audio", feature = "images" ) ) ]
+    async fn send_multipart( &self, path : &str, form : &MultipartForm, options : &RequestOptions ) -> Result< Sent >
+    {
+      let ( content_type, body ) = form.encode();
+      let url = self.environment.url( path );
+      let mut headers = self.headers( options )?;
+      let content_type = header::HeaderValue::from_str( &content_type )
+        .map_err( | e | OpenAiCompatError::Environment( format!( "invalid content type : {e}" ) ) )?;
+      headers.insert( header::CONTENT_TYPE, content_type );
+      let admission = self.admit().await?;
+      self.hooks.on_request( &mut RequestParts { method : &Method::POST, url : &url, headers : &mut headers } );
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let started = std::time::Instant::now();
+      let response = self.dispatch( self.http.post( &url ).headers( headers ).body( body ) ).await?;
+      Ok( Sent
+      {
+        method : Method::POST,
+        url,
+        #[ cfg( not( target_arch = "wasm32" ) ) ]
+        started,
+        response,
+        _admission : admission,
+      } )
+    }
+
+    /// Sends `request`, or hands it to the mock transport or cassette when
+    /// one is set.
+    async fn dispatch( &self, request : reqwest::RequestBuilder ) -> Result< reqwest::Response >
+    {
+      #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+      if let Some( mock ) = &self.mock
+      {
+        let request = request.build().map_err( OpenAiCompatError::from )?;
+        return Ok( mock.respond( &request ) );
+      }
+      #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+      if let Some( cassette ) = &self.cassette
+      {
+        let request = request.build().map_err( OpenAiCompatError::from )?;
+        return cassette.dispatch( &self.http, request, self.environment.api_key() ).await;
+      }
+      request.send().await.map_err( OpenAiCompatError::from ).map_err( Into::into )
+    }
+
+    /// Environment headers, then the extra headers and the idempotency key
+    /// of `options`, which replace environment headers of the same name.
+    fn headers( &self, options : &RequestOptions ) -> Result< header::HeaderMap >
+    {
+      let mut headers = self.environment.headers()?;
+      for ( name, value, sensitive ) in options.extra_headers()
+      {
+        let name = header::HeaderName::from_bytes( name.as_bytes() )
+          .map_err( | e | OpenAiCompatError::Environment( format!( "invalid header name `{name}` : {e}" ) ) )?;
+        let mut value = header::HeaderValue::from_str( value )
+          .map_err( | e | OpenAiCompatError::Environment( format!( "invalid value for header `{name}` : {e}" ) ) )?;
+        value.set_sensitive( sensitive );
+        headers.insert( name, value );
+      }
+      if let Some( key ) = options.idempotency_key()
+      {
+        let value = header::HeaderValue::from_str( key )
+          .map_err( | e | OpenAiCompatError::Environment( format!( "invalid idempotency key : {e}" ) ) )?;
+        headers.insert( IDEMPOTENCY_KEY_HEADER, value );
+      }
+      Ok( headers )
     }
 
     /// Sends a GET request and deserialises the JSON response.
     ///
     /// # Errors
     ///
-    /// Returns network, timeout, or deserialisation errors.
+    /// Returns network, timeout, or deserialisation errors, and
+    /// `api_common::GuardrailViolation` when a guardrail denies the response.
     #[ inline ]
     pub async fn get< O >( &self, path : &str ) -> Result< O >
     where
       O : serde::de::DeserializeOwned,
     {
-      let url = format!( "{}{}", self.environment.base_url(), path );
-      let headers = self.environment.headers()?;
-      let response = self.http_client
-        .get( &url )
-        .headers( headers )
-        .send()
-        .await
-        .map_err( OpenAiCompatError::from )?;
-      Self::handle_response( response ).await
+      self.get_with_options( path, &RequestOptions::new() ).await
+    }
+
+    /// Sends a GET request with per-request `options` and deserialises the
+    /// JSON response. Client-generated idempotency keys are not added to
+    /// GETs; an explicit key is sent.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get`](Self::get), and `api_common::Cancelled` when the
+    /// cancellation token in `options` is cancelled first.
+    #[ inline ]
+    pub async fn get_with_options< O >( &self, path : &str, options : &RequestOptions ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      options.cancellable( async
+      {
+        let sent = self.send_get( path, options ).await?;
+        self.handle_response( sent ).await.map( WithRateLimits::into_value )
+      } ).await
     }
 
-    /// Interprets a completed HTTP response, deserialising success bodies or
-    /// returning an `Api` error for non-2xx status codes.
-    async fn handle_response< O >( response : reqwest::Response ) -> Result< O >
+    /// [`get_with_options`](Self::get_with_options), returning the
+    /// rate-limit headers of the response with its value; see
+    /// [`post_with_rate_limits`](Self::post_with_rate_limits).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get_with_options`](Self::get_with_options).
+    #[ inline ]
+    pub async fn get_with_rate_limits< O >( &self, path : &str, options : &RequestOptions ) -> Result< WithRateLimits< O > >
     where
       O : serde::de::DeserializeOwned,
     {
+      options.cancellable( async
+      {
+        let sent = self.send_get( path, options ).await?;
+        self.handle_response( sent ).await
+      } ).await
+    }
+
+    /// Sends a GET request and returns the response untouched, error
+    /// statuses included; see [`post_raw`](Self::post_raw).
+    ///
+    /// # Errors
+    ///
+    /// Returns network or timeout errors; never an error for the status.
+    #[ inline ]
+    pub async fn get_raw( &self, path : &str ) -> Result< reqwest::Response >
+    {
+      self.get_with_options_raw( path, &RequestOptions::new() ).await
+    }
+
+    /// [`get_raw`](Self::get_raw) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get_raw`](Self::get_raw), and `api_common::Cancelled` when
+    /// the call is cancelled.
+    #[ inline ]
+    pub async fn get_with_options_raw( &self, path : &str, options : &RequestOptions ) -> Result< reqwest::Response >
+    {
+      options.cancellable( async { Ok( self.send_get( path, options ).await?.response ) } ).await
+    }
+
+    async fn send_get( &self, path : &str, options : &RequestOptions ) -> Result< Sent >
+    {
+      let url = self.environment.url( path );
+      let mut headers = self.headers( options )?;
+      let admission = self.admit().await?;
+      self.hooks.on_request( &mut RequestParts { method : &Method::GET, url : &url, headers : &mut headers } );
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let started = std::time::Instant::now();
+      let response = self.dispatch( self.http.get( &url ).headers( headers ) ).await?;
+      Ok( Sent
+      {
+        method : Method::GET,
+        url,
+        #[ cfg( not( target_arch = "wasm32" ) ) ]
+        started,
+        response,
+        _admission : admission,
+      } )
+    }
+
+    /// Builds the POST that [`post_with_options`](Self::post_with_options)
+    /// would send, without sending it.
+    ///
+    /// Environment headers, idempotency keys, request guardrails, and
+    /// request hooks apply as for a real call; rate limits are not consulted.
+    /// Render the result with [`PreparedRequest::to_curl`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body cannot be serialised, the environment
+    /// cannot produce its headers, or a guardrail denies the request.
+    #[ cfg( feature = "curl_diagnostics" ) ]
+    #[ inline ]
+    pub fn prepare_post< I >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< PreparedRequest >
+    where
+      I : serde::Serialize,
+    {
+      let options = self.resolve_options( options );
+      #[ cfg_attr( not( feature = "guardrail" ), allow( unused_mut ) ) ]
+      let mut body = serde_json::to_value( body ).map_err( OpenAiCompatError::from )?;
+      #[ cfg( feature = "guardrail" ) ]
+      self.guardrails.check_request( &mut body )?;
+      self.prepare( Method::POST, path, Some( body ), options.as_ref() )
+    }
+
+    /// Builds the GET that [`get_with_options`](Self::get_with_options)
+    /// would send, without sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot produce its headers.
+    #[ cfg( feature = "curl_diagnostics" ) ]
+    #[ inline ]
+    pub fn prepare_get( &self, path : &str, options : &RequestOptions ) -> Result< PreparedRequest >
+    {
+      self.prepare( Method::GET, path, None, options )
+    }
+
+    #[ cfg( feature = "curl_diagnostics" ) ]
+    fn prepare( &self, method : Method, path : &str, body : Option< serde_json::Value >, options : &RequestOptions ) -> Result< PreparedRequest >
+    {
+      let url = self.environment.url( path );
+      let mut headers = self.headers( options )?;
+      self.hooks.on_request( &mut RequestParts { method : &method, url : &url, headers : &mut headers } );
+      Ok( PreparedRequest::new( method, url, headers, body ) )
+    }
+
+    /// POST behind [`Retrying::post_with_options`](crate::Retrying::post_with_options);
+    /// the idempotency key is resolved once for all attempts.
+    #[ cfg( feature = "retry" ) ]
+    pub( crate ) async fn post_retried< I, O >( &self, path : &str, body : &I, options : &RequestOptions, config : &RetryConfig, budget : Option< &api_common::BudgetTracker > ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      let options = self.resolve_options( options );
+      let options = options.as_ref();
+      options.cancellable( self.retry( config, budget, || self.send_post( path, body, options ) ) ).await
+    }
+
+    /// GET behind [`Retrying::get_with_options`](crate::Retrying::get_with_options).
+    #[ cfg( feature = "retry" ) ]
+    pub( crate ) async fn get_retried< O >( &self, path : &str, options : &RequestOptions, config : &RetryConfig, budget : Option< &api_common::BudgetTracker > ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      options.cancellable( self.retry( config, budget, || self.send_get( path, options ) ) ).await
+    }
+
+    /// Sends with `dispatch` until an attempt succeeds, fails in a way `config`
+    /// does not retry, or `config` or the budget allows no further attempt.
+    /// Without a shared `budget`, the config's own is started for the call.
+    #[ cfg( feature = "retry" ) ]
+    async fn retry< O, F, Fut >( &self, config : &RetryConfig, budget : Option< &api_common::BudgetTracker >, mut dispatch : F ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+      F : FnMut() -> Fut,
+      Fut : core::future::Future< Output = Result< Sent > >,
+    {
+      let own = config.budget.map( api_common::CallBudget::start );
+      let budget = budget.or( own.as_ref() );
+      let mut attempt = 1;
+      loop
+      {
+        // Boxed: the attempt is large, and the budget's deadline wraps it.
+        let send = Box::pin( async
+        {
+          match dispatch().await
+          {
+            Ok( sent ) =>
+            {
+              let retry_after = OpenAiCompatError::retry_after_header( sent.response.headers() );
+              ( self.handle_response( sent ).await.map( WithRateLimits::into_value ), retry_after )
+            },
+            Err( error ) => ( Err( error ), None ),
+          }
+        } );
+        let ( result, retry_after ) = match budget
+        {
+          Some( budget ) => budget.run_attempt( send ).await?,
+          None => send.await,
+        };
+        let error = match result
+        {
+          Err( error ) if error.downcast_ref::< OpenAiCompatError >().is_some_and( | e | config.retry_on.error( e ) ) => error,
+          result => return result,
+        };
+        let Some( delay ) = config.delay_after( attempt, retry_after, budget ) else { return Err( error ) };
+        api_common::sleep( delay ).await;
+        attempt += 1;
+      }
+    }
+
+    /// Waits for a slot in the concurrency gate, then for one request from
+    /// the shared limiter, when configured.
+    #[ allow( clippy::unused_async ) ]
+    async fn admit( &self ) -> Result< Admission >
+    {
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+        let permit = match &self.concurrency_gate
+        {
+          Some( gate ) => Some( gate.acquire().await? ),
+          None => None,
+        };
+        if let Some( limiter ) = &self.rate_limiter
+        {
+          limiter.acquire( 0 ).await?;
+        }
+        Ok( Admission { _permit : permit } )
+      }
+      #[ cfg( not( feature = "rate_limiting" ) ) ]
+      Ok( Admission {} )
+    }
+
+    /// Charges the `usage.total_tokens` of a successful response body to a
+    /// limiter with a token quota. Bodies without usage charge nothing.
+    #[ cfg( feature = "rate_limiting" ) ]
+    fn record_usage( &self, body : &[ u8 ] )
+    {
+      #[ derive( serde::Deserialize ) ]
+      struct Reported
+      {
+        usage : Option< ReportedUsage >,
+      }
+      #[ derive( serde::Deserialize ) ]
+      struct ReportedUsage
+      {
+        total_tokens : u64,
+      }
+
+      let Some( limiter ) = self.rate_limiter.as_ref().filter( | limiter | limiter.limits().tokens.is_some() ) else { return };
+      if let Ok( Reported { usage : Some( usage ) } ) = serde_json::from_slice( body )
+      {
+        limiter.record_tokens( usage.total_tokens );
+      }
+    }
+
+    /// Reads a completed HTTP response: runs the response hooks, and
+    /// returns the body of a success with its rate-limit headers, or the
+    /// error [`OpenAiCompatError::from_response`] classifies for non-2xx
+    /// status codes.
+    async fn read_response( &self, sent : Sent ) -> Result< WithRateLimits< bytes::Bytes > >
+    {
+      let Sent { method, url, response, _admission, .. } = sent;
+      #[ cfg( not( target_arch = "wasm32" ) ) ]
+      let started = sent.started;
       let status = response.status();
-      if status.is_success()
+      let rate_limits = RateLimitInfo::from_headers( response.headers() );
+      let headers = if self.hooks.observes_responses() || !status.is_success() { response.headers().clone() } else { header::HeaderMap::new() };
+      let body = match response.bytes().await
+      {
+        Ok( body ) => body,
+        Err( _ ) if !status.is_success() => return Err( OpenAiCompatError::from_response( status, &headers, &[] ).into() ),
+        Err( e ) => return Err( OpenAiCompatError::from( e ).into() ),
+      };
+      self.hooks.on_response( &ResponseParts
       {
-        response
-          .json::< O >()
-          .await
-          .map_err( OpenAiCompatError::from )
-          .map_err( Into::into )
+        method : &method,
+        url : &url,
+        status,
+        headers : &headers,
+        body : &body,
+        #[ cfg( not( target_arch = "wasm32" ) ) ]
+        elapsed : started.elapsed(),
+      });
+      if !status.is_success()
+      {
+        return Err( OpenAiCompatError::from_response( status, &headers, &body ).into() );
       }
-      else
+      #[ cfg( feature = "rate_limiting" ) ]
+      self.record_usage( &body );
+      Ok( WithRateLimits { value : body, rate_limits } )
+    }
+
+    /// Interprets a completed HTTP response: reads it with
+    /// [`read_response`](Self::read_response), then deserialises the body
+    /// after the provider profile and the guardrails, when configured.
+    async fn handle_response< O >( &self, sent : Sent ) -> Result< WithRateLimits< O > >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      let WithRateLimits { value : body, rate_limits } = self.read_response( sent ).await?;
+      let value = self.decode_body( &body )?;
+      Ok( WithRateLimits { value, rate_limits } )
+    }
+
+    /// Deserialises a successful response body, passing it through the
+    /// provider profile and the guardrails first when either is configured.
+    fn decode_body< O >( &self, body : &[ u8 ] ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      let profile = self.environment.provider_profile();
+      #[ cfg( feature = "guardrail" ) ]
+      let checked = !self.guardrails.is_empty();
+      #[ cfg( not( feature = "guardrail" ) ) ]
+      let checked = false;
+      if checked || !profile.leniencies().is_empty()
       {
-        let body = response
-          .text()
-          .await
-          .unwrap_or_else( | _ | status.to_string() );
-        Err( OpenAiCompatError::Api( body ).into() )
+        let mut value = serde_json::from_slice::< serde_json::Value >( body ).map_err( | e | OpenAiCompatError::deserialization( &e, body ) )?;
+        profile.normalize_response( &mut value );
+        #[ cfg( feature = "guardrail" ) ]
+        self.guardrails.check_response( &mut value )?;
+        return Ok( serde_json::from_value( value ).map_err( | e | OpenAiCompatError::deserialization( &e, body ) )? );
       }
+      Ok( serde_json::from_slice( body ).map_err( | e | OpenAiCompatError::deserialization( &e, body ) )? )
+    }
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+    use crate::environment::OpenAiCompatEnvironmentImpl;
+    use crate::{ ChatCompletionResponse, ProviderProfile };
+    #[ cfg( feature = "rate_limiting" ) ]
+    use api_common::{ RateLimits, SharedRateLimiter };
+
+    /// A chat completion as LM Studio sends it: no header fields, no usage,
+    /// and an engine finish reason.
+    const BARE_COMPLETION : &[ u8 ] = br#"{"choices":[{"index":0,"message":{"role":"assistant","content":"Hi"},"finish_reason":"eos"}]}"#;
+
+    fn offline( profile : ProviderProfile ) -> Client< OpenAiCompatEnvironmentImpl >
+    {
+      let env = OpenAiCompatEnvironmentImpl::new( "sk-profile" ).unwrap()
+        .with_base_url( "http://127.0.0.1:1/" )
+        .with_provider_profile( profile );
+      Client::build( env ).unwrap()
+    }
+
+    #[ test ]
+    fn bodies_are_decoded_with_the_environment_profile()
+    {
+      let strict = offline( ProviderProfile::Standard ).decode_body::< ChatCompletionResponse >( BARE_COMPLETION ).unwrap_err();
+      assert!( matches!( strict.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Deserialization { .. } ) ), "{strict:?}" );
+
+      let client = offline( ProviderProfile::LmStudio );
+      assert_eq!( client.provider_profile(), ProviderProfile::LmStudio );
+      let response : ChatCompletionResponse = client.decode_body( BARE_COMPLETION ).unwrap();
+      assert_eq!( response.object, "chat.completion" );
+      assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+    }
+
+    #[ test ]
+    fn lenient_profiles_keep_other_bodies_as_sent()
+    {
+      let body = br#"{"object":"list","data":[{"id":"m","object":"model","created":1,"owned_by":"me"}]}"#;
+      let value : serde_json::Value = offline( ProviderProfile::LmStudio ).decode_body( body ).unwrap();
+      assert_eq!( value, serde_json::from_slice::< serde_json::Value >( body ).unwrap() );
+      assert!( offline( ProviderProfile::Vllm ).decode_body::< serde_json::Value >( b"not json" ).is_err() );
+    }
+
+    #[ cfg( feature = "rate_limiting" ) ]
+    fn client( limiter : &SharedRateLimiter ) -> Client< OpenAiCompatEnvironmentImpl >
+    {
+      let env = OpenAiCompatEnvironmentImpl::new( "sk-token-quota" ).unwrap().with_base_url( "http://127.0.0.1:1/" );
+      Client::build( env ).unwrap().with_shared_rate_limiter( limiter.clone() )
+    }
+
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ test ]
+    fn response_usage_is_charged_to_token_quota()
+    {
+      let tokens = SharedRateLimiter::new( RateLimits::new().with_requests_per_minute( 10 ).with_tokens_per_minute( 100 ) );
+      let requests = SharedRateLimiter::new( RateLimits::new().with_requests_per_minute( 10 ) );
+      let body = br#"{"usage":{"prompt_tokens":100,"completion_tokens":50,"total_tokens":150}}"#;
+
+      client( &tokens ).record_usage( body );
+      client( &requests ).record_usage( body );
+
+      assert!( tokens.available_tokens().is_some_and( | available | available < 0 ), "{:?}", tokens.available_tokens() );
+      assert_eq!( requests.available_tokens(), None );
+    }
+
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ test ]
+    fn bodies_without_usage_charge_nothing()
+    {
+      let tokens = SharedRateLimiter::new( RateLimits::new().with_tokens_per_minute( 100 ) );
+
+      client( &tokens ).record_usage( br#"{"object":"list","data":[]}"# );
+      client( &tokens ).record_usage( b"not json" );
+
+      assert_eq!( tokens.available_tokens(), Some( 100 ) );
     }
   }
 }
@@ -7,7 +7,10 @@
 mod private
 {
   use serde::{ Serialize, Deserialize };
+  use serde_json::{ Map, Value };
   use former::Former;
+  #[ cfg( feature = "schema" ) ]
+  use error_tools::dependency::thiserror;
 
   // ------------------------------------------------------------------ //
   //  Role
@@ -20,7 +23,8 @@ mod private
   ///
   /// Unknown roles from the wire (e.g. provider-specific extensions) deserialise
   /// to [`Role::Other`] instead of causing a hard failure, ensuring forward
-  /// compatibility as providers evolve their APIs.
+  /// compatibility as providers evolve their APIs. With `strict_wire` they are
+  /// rejected instead, so a renamed role shows up as an error.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
   #[ serde( rename_all = "lowercase" ) ]
   #[ non_exhaustive ]
@@ -43,7 +47,7 @@ mod private
     /// Serialises to `"other"`. The original wire string is not preserved;
     /// use this variant only for forward-compatible deserialisation, not for
     /// constructing outbound messages with non-standard roles.
-    #[ serde( other ) ]
+    #[ cfg_attr( not( feature = "strict_wire" ), serde( other ) ) ]
     Other,
   }
 
@@ -54,8 +58,11 @@ mod private
   /// A function invocation requested by the assistant.
   ///
   /// When the model decides to call a tool it includes one or more `ToolCall`
-  /// objects in the assistant message's `tool_calls` field.
+  /// objects in the assistant message's `tool_calls` field; with parallel tool
+  /// calls there is one per function, each answered by a [`Message::tool`]
+  /// carrying its `id`.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct ToolCall
   {
     /// Unique identifier for this invocation (used to correlate tool results).
@@ -69,17 +76,100 @@ mod private
     pub function : FunctionCall,
   }
 
+  impl ToolCall
+  {
+    /// A call of the function `name` with JSON-encoded `arguments`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::ToolCall;
+    ///
+    /// let call = ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# );
+    /// assert_eq!( call.tool_type, "function" );
+    /// # }
+    /// ```
+    #[ inline ]
+    pub fn function
+    (
+      id        : impl Into< String >,
+      name      : impl Into< String >,
+      arguments : impl Into< String >,
+    )
+    -> Self
+    {
+      Self
+      {
+        id        : id.into(),
+        tool_type : "function".to_string(),
+        function  : FunctionCall { name : name.into(), arguments : arguments.into() },
+      }
+    }
+  }
+
   /// Name and arguments for a specific function invocation.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct FunctionCall
   {
     /// Registered function name.
     pub name : String,
 
     /// JSON-encoded arguments string (not a `Value` — must be re-parsed by caller).
+    ///
+    /// Kept verbatim, so a round trip sends back exactly what the model
+    /// produced. Servers that return the arguments as a JSON object instead
+    /// of a string are accepted; the object is stored in its encoded form.
+    #[ serde( deserialize_with = "arguments_from_string_or_json" ) ]
     pub arguments : String,
   }
 
+  impl FunctionCall
+  {
+    /// Parses the arguments into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `serde_json` error when the arguments are not valid JSON
+    /// or do not match `T`; models occasionally produce either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::ToolCall;
+    ///
+    /// #[ derive( serde::Deserialize ) ]
+    /// struct Weather { city : String }
+    ///
+    /// let call = ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# );
+    /// let args : Weather = call.function.parse_arguments().unwrap();
+    /// assert_eq!( args.city, "Paris" );
+    /// # }
+    /// ```
+    #[ inline ]
+    pub fn parse_arguments< T : serde::de::DeserializeOwned >( &self ) -> serde_json::Result< T >
+    {
+      serde_json::from_str( &self.arguments )
+    }
+  }
+
+  /// Reads `arguments` as a string, or encodes it when it arrives as JSON.
+  fn arguments_from_string_or_json< 'de, D >( deserializer : D ) -> core::result::Result< String, D::Error >
+  where
+    D : serde::Deserializer< 'de >,
+  {
+    match serde_json::Value::deserialize( deserializer )?
+    {
+      serde_json::Value::String( arguments ) => Ok( arguments ),
+      serde_json::Value::Null => Ok( String::new() ),
+      other => Ok( other.to_string() ),
+    }
+  }
+
   // ------------------------------------------------------------------ //
   //  Message
   // ------------------------------------------------------------------ //
@@ -110,6 +200,14 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub content : Option< String >,
 
+    /// Reasoning the model produced before `content`, returned by reasoning
+    /// models of `DeepSeek`, xAI, and `vLLM`-served models (assistant role only).
+    ///
+    /// Not part of the `OpenAI` schema. `DeepSeek` rejects requests that send it
+    /// back, so clear it before appending the message to the history.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub reasoning_content : Option< String >,
+
     /// Tool invocations requested by the assistant (assistant role only).
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub tool_calls : Option< Vec< ToolCall > >,
@@ -117,6 +215,12 @@ mod private
     /// ID of the `ToolCall` this message responds to (tool role only).
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub tool_call_id : Option< String >,
+
+    /// Members the type does not model, such as provider extensions; kept
+    /// so they survive a round-trip. Rejected under `strict_wire`.
+    #[ serde( flatten ) ]
+    #[ cfg_attr( feature = "strict_wire", serde( deserialize_with = "no_extra_fields" ) ) ]
+    pub extra : Map< String, Value >,
   }
 
   impl Message
@@ -140,8 +244,10 @@ mod private
       {
         role         : Role::System,
         content      : Some( content.into() ),
+        reasoning_content : None,
         tool_calls   : None,
         tool_call_id : None,
+        extra        : Map::new(),
       }
     }
 
@@ -164,8 +270,10 @@ mod private
       {
         role         : Role::User,
         content      : Some( content.into() ),
+        reasoning_content : None,
         tool_calls   : None,
         tool_call_id : None,
+        extra        : Map::new(),
       }
     }
 
@@ -188,8 +296,42 @@ mod private
       {
         role         : Role::Assistant,
         content      : Some( content.into() ),
+        reasoning_content : None,
         tool_calls   : None,
         tool_call_id : None,
+        extra        : Map::new(),
+      }
+    }
+
+    /// Creates an assistant message that requests `tool_calls` and carries
+    /// no text, as sent back in the history before the tool results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ Message, ToolCall };
+    ///
+    /// let msg = Message::assistant_tool_calls( vec!
+    /// [
+    ///   ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# ),
+    ///   ToolCall::function( "call_2", "get_weather", r#"{"city":"Rome"}"# ),
+    /// ]);
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant_tool_calls( tool_calls : Vec< ToolCall > ) -> Self
+    {
+      Self
+      {
+        role         : Role::Assistant,
+        content      : None,
+        reasoning_content : None,
+        tool_calls   : Some( tool_calls ),
+        tool_call_id : None,
+        extra        : Map::new(),
       }
     }
 
@@ -222,8 +364,25 @@ mod private
       {
         role         : Role::Tool,
         content      : Some( content.into() ),
+        reasoning_content : None,
         tool_calls   : None,
         tool_call_id : Some( tool_call_id.into() ),
+        extra        : Map::new(),
+      }
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< api_common::NeutralMessage > for Message
+  {
+    #[ inline ]
+    fn from( message : api_common::NeutralMessage ) -> Self
+    {
+      match message.role
+      {
+        api_common::NeutralRole::System    => Self::system( message.content ),
+        api_common::NeutralRole::User      => Self::user( message.content ),
+        api_common::NeutralRole::Assistant => Self::assistant( message.content ),
       }
     }
   }
@@ -279,11 +438,86 @@ mod private
           name        : name.into(),
           description : description.into(),
           parameters,
+          strict      : None,
         },
       }
     }
   }
 
+  /// How the model must use the tools of a request.
+  ///
+  /// Serialises as a mode string (`"none"`, `"auto"`, `"required"`) or as a
+  /// named-function object. Mistral also accepts the mode `"any"`, its
+  /// older spelling of `"required"`.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  #[ serde( untagged ) ]
+  pub enum ToolChoice
+  {
+    /// A mode string.
+    Mode( String ),
+
+    /// Forces a call to one function.
+    Named( NamedToolChoice ),
+  }
+
+  impl ToolChoice
+  {
+    /// The model must not call a tool.
+    #[ inline ]
+    #[ must_use ]
+    pub fn none() -> Self
+    {
+      Self::Mode( "none".to_string() )
+    }
+
+    /// The model decides whether to call a tool.
+    #[ inline ]
+    #[ must_use ]
+    pub fn auto() -> Self
+    {
+      Self::Mode( "auto".to_string() )
+    }
+
+    /// The model must call at least one tool.
+    #[ inline ]
+    #[ must_use ]
+    pub fn required() -> Self
+    {
+      Self::Mode( "required".to_string() )
+    }
+
+    /// The model must call the function `name`.
+    #[ inline ]
+    pub fn function( name : impl Into< String > ) -> Self
+    {
+      Self::Named( NamedToolChoice
+      {
+        tool_type : "function".to_string(),
+        function  : ToolChoiceFunction { name : name.into() },
+      })
+    }
+  }
+
+  /// Named-function form of [`ToolChoice`].
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct NamedToolChoice
+  {
+    /// Always `"function"`.
+    #[ serde( rename = "type" ) ]
+    pub tool_type : String,
+
+    /// The function to call.
+    pub function : ToolChoiceFunction,
+  }
+
+  /// Function named by a [`NamedToolChoice`].
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  pub struct ToolChoiceFunction
+  {
+    /// Registered function name.
+    pub name : String,
+  }
+
   /// Function specification (name, description, JSON Schema parameters).
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Former ) ]
   pub struct Function
@@ -292,10 +526,196 @@ mod private
     pub name : String,
 
     /// Human-readable description used by the model to decide when to call.
+    ///
+    /// Optional on the wire; an empty description is omitted.
+    #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
     pub description : String,
 
     /// JSON Schema describing the expected parameters.
     pub parameters : serde_json::Value,
+
+    /// When `true`, the provider constrains the arguments to `parameters`
+    /// exactly (`OpenAI` structured outputs); not every provider accepts it.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub strict : Option< bool >,
+  }
+
+  // ------------------------------------------------------------------ //
+  //  ResponseFormat
+  // ------------------------------------------------------------------ //
+
+  /// Format the model must answer in, sent as `response_format`.
+  ///
+  /// `JsonSchema` serialises its fields inside the `json_schema` envelope the
+  /// wire expects: `{"type":"json_schema","json_schema":{"name":..,"schema":..}}`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::ResponseFormat;
+  ///
+  /// let format = ResponseFormat::JsonSchema
+  /// {
+  ///   name   : "weather".to_string(),
+  ///   schema : serde_json::json!({ "type": "object", "required": [ "city" ] }),
+  ///   strict : Some( true ),
+  /// };
+  /// let json = serde_json::to_value( &format ).unwrap();
+  /// assert_eq!( json[ "json_schema" ][ "name" ], "weather" );
+  /// # }
+  /// ```
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ serde( into = "ResponseFormatWire", from = "ResponseFormatWire" ) ]
+  pub enum ResponseFormat
+  {
+    /// Plain text, the default.
+    Text,
+
+    /// Any JSON object (JSON mode); the prompt must still ask for JSON.
+    JsonObject,
+
+    /// JSON matching `schema` (structured outputs).
+    JsonSchema
+    {
+      /// Name of the schema; letters, digits, `_` and `-`.
+      name : String,
+
+      /// JSON Schema the answer must match.
+      schema : Value,
+
+      /// When `true`, the provider constrains decoding to `schema` exactly;
+      /// not every provider accepts it.
+      strict : Option< bool >,
+    },
+  }
+
+  /// Wire shape of [`ResponseFormat`].
+  #[ derive( Serialize, Deserialize ) ]
+  #[ serde( tag = "type", rename_all = "snake_case" ) ]
+  enum ResponseFormatWire
+  {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema : JsonSchemaWire },
+  }
+
+  #[ derive( Serialize, Deserialize ) ]
+  struct JsonSchemaWire
+  {
+    name : String,
+    schema : Value,
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    strict : Option< bool >,
+  }
+
+  impl From< ResponseFormat > for ResponseFormatWire
+  {
+    #[ inline ]
+    fn from( format : ResponseFormat ) -> Self
+    {
+      match format
+      {
+        ResponseFormat::Text => Self::Text,
+        ResponseFormat::JsonObject => Self::JsonObject,
+        ResponseFormat::JsonSchema { name, schema, strict } => Self::JsonSchema { json_schema : JsonSchemaWire { name, schema, strict } },
+      }
+    }
+  }
+
+  impl From< ResponseFormatWire > for ResponseFormat
+  {
+    #[ inline ]
+    fn from( wire : ResponseFormatWire ) -> Self
+    {
+      match wire
+      {
+        ResponseFormatWire::Text => Self::Text,
+        ResponseFormatWire::JsonObject => Self::JsonObject,
+        ResponseFormatWire::JsonSchema { json_schema : JsonSchemaWire { name, schema, strict } } => Self::JsonSchema { name, schema, strict },
+      }
+    }
+  }
+
+  /// Structured output of a message that could not be accepted.
+  #[ cfg( feature = "schema" ) ]
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  pub enum StructuredOutputError
+  {
+    /// The model declined to answer, giving this reason.
+    #[ error( "Model refused : {0}" ) ]
+    Refusal( String ),
+
+    /// The message has no text content, e.g. it requests tool calls.
+    #[ error( "Message has no content" ) ]
+    MissingContent,
+
+    /// The content is not JSON, misses the schema, or misses the target type.
+    #[ error( transparent ) ]
+    Schema( #[ from ] api_common::SchemaError ),
+  }
+
+  #[ cfg( feature = "schema" ) ]
+  impl Message
+  {
+    /// Parses the content of a message answering a request made with
+    /// `format` into `T`, checking it against the schema of a
+    /// [`ResponseFormat::JsonSchema`] first.
+    ///
+    /// Models do not always keep to the schema, strict decoding aside, so
+    /// every mismatch is reported with its path instead of surfacing as a
+    /// deserialisation error of `T`. `Text` and `JsonObject` formats only
+    /// need the content to be JSON that fits `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StructuredOutputError::Refusal`] when the message carries a
+    /// `refusal` instead of content, [`StructuredOutputError::MissingContent`]
+    /// when it carries neither, and [`StructuredOutputError::Schema`] when the
+    /// content is not JSON, violates the schema, or does not fit `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[ cfg( feature = "schema" ) ]
+    /// # {
+    /// use api_openai_compatible::{ Message, ResponseFormat };
+    ///
+    /// #[ derive( Debug, serde::Deserialize ) ]
+    /// struct Weather { city : String }
+    ///
+    /// let format = ResponseFormat::JsonSchema
+    /// {
+    ///   name   : "weather".to_string(),
+    ///   schema : serde_json::json!({ "type": "object", "required": [ "city" ] }),
+    ///   strict : None,
+    /// };
+    /// let weather : Weather = Message::assistant( r#"{"city":"Paris"}"# ).parse_structured( &format ).unwrap();
+    /// assert_eq!( weather.city, "Paris" );
+    ///
+    /// let error = Message::assistant( "{}" ).parse_structured::< Weather >( &format ).unwrap_err();
+    /// assert!( error.to_string().contains( "city" ) );
+    /// # }
+    /// ```
+    #[ inline ]
+    pub fn parse_structured< T : serde::de::DeserializeOwned >( &self, format : &ResponseFormat ) -> Result< T, StructuredOutputError >
+    {
+      let Some( content ) = self.content.as_deref() else
+      {
+        return Err( match self.extra.get( "refusal" ).and_then( Value::as_str )
+        {
+          Some( refusal ) => StructuredOutputError::Refusal( refusal.to_string() ),
+          None => StructuredOutputError::MissingContent,
+        } );
+      };
+      let schema = match format
+      {
+        ResponseFormat::JsonSchema { schema, .. } => schema.clone(),
+        ResponseFormat::Text | ResponseFormat::JsonObject => Value::Bool( true ),
+      };
+      Ok( api_common::ResponseSchema::new( schema ).parse( content )? )
+    }
   }
 
   // ------------------------------------------------------------------ //
@@ -303,7 +723,8 @@ mod private
   // ------------------------------------------------------------------ //
 
   /// Token usage statistics returned in every completion response.
-  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  #[ derive( Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct Usage
   {
     /// Number of tokens in the prompt (input).
@@ -314,6 +735,89 @@ mod private
 
     /// Total tokens consumed (`prompt_tokens + completion_tokens`).
     pub total_tokens : u32,
+
+    /// Breakdown of `prompt_tokens`, when the provider reports one.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub prompt_tokens_details : Option< PromptTokensDetails >,
+
+    /// Breakdown of `completion_tokens`, when the provider reports one;
+    /// reasoning models (`OpenAI` o-series, Grok reasoning) report their
+    /// reasoning tokens here.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub completion_tokens_details : Option< CompletionTokensDetails >,
+  }
+
+  impl Usage
+  {
+    /// Prompt tokens served from the prompt cache; 0 when not reported.
+    #[ inline ]
+    #[ must_use ]
+    pub fn cached_tokens( &self ) -> u32
+    {
+      self.prompt_tokens_details.as_ref().and_then( | details | details.cached_tokens ).unwrap_or( 0 )
+    }
+
+    /// Completion tokens spent reasoning; 0 when not reported.
+    #[ inline ]
+    #[ must_use ]
+    pub fn reasoning_tokens( &self ) -> u32
+    {
+      self.completion_tokens_details.as_ref().and_then( | details | details.reasoning_tokens ).unwrap_or( 0 )
+    }
+  }
+
+  /// Breakdown of the prompt tokens of a [`Usage`]; each count is part of
+  /// `prompt_tokens`.
+  #[ derive( Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
+  pub struct PromptTokensDetails
+  {
+    /// Tokens served from the provider's prompt cache.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub cached_tokens : Option< u32 >,
+
+    /// Audio input tokens.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub audio_tokens : Option< u32 >,
+  }
+
+  /// Breakdown of the completion tokens of a [`Usage`]; each count is part
+  /// of `completion_tokens`.
+  #[ derive( Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
+  pub struct CompletionTokensDetails
+  {
+    /// Tokens the model spent reasoning before its answer; billed as
+    /// output though not part of the message.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub reasoning_tokens : Option< u32 >,
+
+    /// Audio output tokens.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub audio_tokens : Option< u32 >,
+
+    /// Tokens of a predicted output that appeared in the completion.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub accepted_prediction_tokens : Option< u32 >,
+
+    /// Tokens of a predicted output that did not appear in the completion;
+    /// still billed.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub rejected_prediction_tokens : Option< u32 >,
+  }
+
+  /// `prompt_tokens` already includes the cached tokens, and
+  /// `completion_tokens` the reasoning tokens.
+  #[ cfg( feature = "common" ) ]
+  impl From< Usage > for api_common::TokenUsage
+  {
+    #[ inline ]
+    fn from( usage : Usage ) -> Self
+    {
+      Self::new( usage.prompt_tokens.into(), usage.completion_tokens.into() )
+      .with_cached_input_tokens( usage.cached_tokens().into() )
+      .with_reasoning_tokens( usage.reasoning_tokens().into() )
+    }
   }
 
   // ------------------------------------------------------------------ //
@@ -371,9 +875,43 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub stream : Option< bool >,
 
+    /// Streaming options; only valid with `stream`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream_options : Option< StreamOptions >,
+
+    /// Sequences that stop generation; providers cap how many they accept.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stop : Option< Vec< String > >,
+
     /// Tool definitions available for function calling.
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub tools : Option< Vec< Tool > >,
+
+    /// Whether and which tool the model must call; only valid with `tools`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub tool_choice : Option< ToolChoice >,
+
+    /// Whether the model may request several tool calls in one turn.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub parallel_tool_calls : Option< bool >,
+
+    /// Format the answer must take, e.g. JSON matching a schema.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< ResponseFormat >,
+
+    /// Members sent as they are, for parameters a provider accepts beyond
+    /// the `OpenAI` schema (e.g. xAI `search_parameters`, vLLM `top_k`).
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  /// Options of a streamed completion.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  pub struct StreamOptions
+  {
+    /// When `true`, the stream ends with a chunk carrying the usage of the
+    /// whole response and no choices.
+    pub include_usage : bool,
   }
 
   // ------------------------------------------------------------------ //
@@ -401,6 +939,16 @@ mod private
 
     /// Token usage statistics for billing.
     pub usage : Usage,
+
+    /// Backend configuration fingerprint, when the provider reports one.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub system_fingerprint : Option< String >,
+
+    /// Members the type does not model, such as Groq's `x_groq` timings; kept
+    /// so they survive a round-trip. Rejected under `strict_wire`.
+    #[ serde( flatten ) ]
+    #[ cfg_attr( feature = "strict_wire", serde( deserialize_with = "no_extra_fields" ) ) ]
+    pub extra : Map< String, Value >,
   }
 
   /// One completion alternative within a `ChatCompletionResponse`.
@@ -415,6 +963,31 @@ mod private
 
     /// Reason the generation stopped (`"stop"`, `"length"`, `"tool_calls"`).
     pub finish_reason : Option< String >,
+
+    /// Token log probabilities, present when the request asked for them.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub logprobs : Option< serde_json::Value >,
+
+    /// Members the type does not model, such as vLLM's `stop_reason`; kept
+    /// so they survive a round-trip. Rejected under `strict_wire`.
+    #[ serde( flatten ) ]
+    #[ cfg_attr( feature = "strict_wire", serde( deserialize_with = "no_extra_fields" ) ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  /// Deserialises the `extra` members of a response type, failing on the
+  /// first one as `deny_unknown_fields` would.
+  #[ cfg( feature = "strict_wire" ) ]
+  fn no_extra_fields< 'de, D >( deserializer : D ) -> Result< Map< String, Value >, D::Error >
+  where
+    D : serde::Deserializer< 'de >,
+  {
+    let extra = Map::deserialize( deserializer )?;
+    match extra.keys().next()
+    {
+      Some( key ) => Err( serde::de::Error::custom( format!( "unknown field `{key}`" ) ) ),
+      None => Ok( extra ),
+    }
   }
 }
 
@@ -427,10 +1000,20 @@ crate::mod_interface!
     FunctionCall,
     Message,
     Tool,
+    ToolChoice,
+    NamedToolChoice,
+    ToolChoiceFunction,
     Function,
+    ResponseFormat,
     Usage,
+    PromptTokensDetails,
+    CompletionTokensDetails,
     ChatCompletionRequest,
+    StreamOptions,
     ChatCompletionResponse,
     Choice,
   };
+
+  #[ cfg( feature = "schema" ) ]
+  exposed use private::StructuredOutputError;
 }
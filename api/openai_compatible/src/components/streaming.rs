@@ -1,15 +1,23 @@
 //! Wire types for `OpenAI` Server-Sent Events streaming completions.
 //!
 //! These types represent the incremental chunks delivered over a streaming
-//! chat completion response. Each chunk carries partial content via a `Delta`.
+//! chat completion response. Each chunk carries partial content via a `Delta`;
+//! a [`ChunkDecoder`] turns the raw `text/event-stream` body into chunks, and
+//! a [`StreamAccumulator`] folds the chunks of one response back into the
+//! `ChatCompletionResponse` the same request would have returned unstreamed.
 
 mod private
 {
   use serde::{ Serialize, Deserialize };
-  use crate::{ Role, ToolCall };
+  use std::collections::BTreeMap;
+  use crate::{ ChatCompletionResponse, Choice, FunctionCall, Message, Role, ToolCall, Usage };
+  use crate::error::{ OpenAiCompatError, Result };
+  use crate::profile::ProviderProfile;
+  use api_sse_stream::SseDecoder;
 
   /// A single Server-Sent Events chunk from a streaming completion.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct ChatCompletionChunk
   {
     /// Opaque completion identifier (same across all chunks for one request).
@@ -26,10 +34,20 @@ mod private
 
     /// One or more delta choices in this chunk.
     pub choices : Vec< ChunkChoice >,
+
+    /// Backend configuration fingerprint, when the provider reports one.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub system_fingerprint : Option< String >,
+
+    /// Token usage of the whole response, sent in a final chunk with no
+    /// choices when the request set `stream_options.include_usage`.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub usage : Option< Usage >,
   }
 
   /// One delta choice within a streaming chunk.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct ChunkChoice
   {
     /// Zero-based index.
@@ -40,10 +58,15 @@ mod private
 
     /// Set only in the final chunk; `None` for all intermediate chunks.
     pub finish_reason : Option< String >,
+
+    /// Token log probabilities, present when the request asked for them.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub logprobs : Option< serde_json::Value >,
   }
 
   /// Partial message update delivered in a streaming chunk.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
   pub struct Delta
   {
     /// Role — present only in the first chunk of a response.
@@ -54,9 +77,443 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub content : Option< String >,
 
+    /// Partial reasoning, streamed before `content` by reasoning models
+    /// (see `Message::reasoning_content`).
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub reasoning_content : Option< String >,
+
     /// Partial tool calls (for streaming function calling).
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
-    pub tool_calls : Option< Vec< ToolCall > >,
+    pub tool_calls : Option< Vec< ToolCallChunk > >,
+  }
+
+  /// A fragment of one tool call within a [`Delta`].
+  ///
+  /// The first fragment of a call carries its `id`, `type`, and function
+  /// name; later fragments carry only `index` and the next piece of the
+  /// arguments. Absent strings deserialise as empty and are omitted again
+  /// when serialised.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
+  pub struct ToolCallChunk
+  {
+    /// Position of the call within the message; fragments of one call share
+    /// it. Some servers omit it, in which case the position within
+    /// `tool_calls` stands in.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub index : Option< u32 >,
+
+    /// Call identifier, present in the first fragment only.
+    #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
+    pub id : String,
+
+    /// `"function"`, present in the first fragment only.
+    #[ serde( rename = "type", default, skip_serializing_if = "String::is_empty" ) ]
+    pub tool_type : String,
+
+    /// Function name and the next piece of its arguments.
+    #[ serde( default ) ]
+    pub function : FunctionCallChunk,
+  }
+
+  /// Function part of a [`ToolCallChunk`].
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ cfg_attr( feature = "strict_wire", serde( deny_unknown_fields ) ) ]
+  pub struct FunctionCallChunk
+  {
+    /// Function name, present in the first fragment only.
+    #[ serde( default, skip_serializing_if = "String::is_empty" ) ]
+    pub name : String,
+
+    /// Next piece of the JSON-encoded arguments; concatenated in order.
+    #[ serde( default ) ]
+    pub arguments : String,
+  }
+
+  /// Incremental decoder of a streamed chat completion body.
+  ///
+  /// Push the body bytes as they arrive, in pieces of any size, and take
+  /// chunks out with [`next_chunk`](Self::next_chunk). Framing follows the
+  /// event-stream rules of `api_sse_stream::SseDecoder`: multi-line `data`
+  /// joined with `\n`, comments such as `: keep-alive` skipped, LF, CRLF, or
+  /// lone CR line endings, and UTF-8 characters split between pieces kept
+  /// whole. Nothing after the `[DONE]` marker is returned.
+  ///
+  /// The `id` of the last event returned is kept for a caller that
+  /// reconnects and sends it as `Last-Event-ID`; the decoder itself never
+  /// reconnects.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::ChunkDecoder;
+  ///
+  /// let mut decoder = ChunkDecoder::new();
+  /// decoder.push( b": keep-alive\r\n\r\nid: 1\r\ndata: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\"," );
+  /// assert!( decoder.next_chunk().is_none() );
+  /// decoder.push( b"\"created\":1,\"model\":\"m\",\"choices\":[]}\r\n\r\ndata: [DONE]\r\n\r\n" );
+  ///
+  /// let chunk = decoder.next_chunk().unwrap().unwrap();
+  /// assert_eq!( chunk.id, "c1" );
+  /// assert!( decoder.next_chunk().is_none() );
+  /// assert!( decoder.is_done() );
+  /// assert_eq!( decoder.last_event_id(), Some( "1" ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct ChunkDecoder
+  {
+    events : SseDecoder,
+    done : bool,
+    last_event_id : Option< String >,
+    profile : ProviderProfile,
+  }
+
+  impl ChunkDecoder
+  {
+    /// An empty decoder.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Repairs each chunk with the leniencies of `profile` before it is
+    /// deserialised.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_profile( mut self, profile : ProviderProfile ) -> Self
+    {
+      self.profile = profile;
+      self
+    }
+
+    /// Appends body bytes as they arrive.
+    #[ inline ]
+    pub fn push( &mut self, bytes : &[ u8 ] )
+    {
+      if !self.done
+      {
+        self.events.push( bytes );
+      }
+    }
+
+    /// Ends the body: a last event the server did not terminate with a
+    /// blank line is still decoded.
+    #[ inline ]
+    pub fn finish( &mut self )
+    {
+      self.events.finish();
+    }
+
+    /// The next chunk, in arrival order; `None` until more bytes arrive, and
+    /// for good once `[DONE]` has been seen.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error classified by its `type` and `code` for an error the
+    /// server reported mid-stream, as an `error` event or a `{"error":...}`
+    /// payload, and `Deserialization` for data that is not a chunk. Decoding continues after either.
+    #[ inline ]
+    pub fn next_chunk( &mut self ) -> Option< Result< ChatCompletionChunk > >
+    {
+      while !self.done
+      {
+        let event = self.events.next_event()?;
+        self.last_event_id.clone_from( &event.id );
+        if event.is_done()
+        {
+          self.done = true;
+          break;
+        }
+        if event.data.trim().is_empty()
+        {
+          continue;
+        }
+        if event.event == "error"
+        {
+          return Some( Err( OpenAiCompatError::from_stream_error( &event.data ).into() ) );
+        }
+        let chunk = if self.profile.leniencies().is_empty()
+        {
+          serde_json::from_str::< ChatCompletionChunk >( &event.data )
+        }
+        else
+        {
+          serde_json::from_str::< serde_json::Value >( &event.data ).and_then( | mut value |
+          {
+            self.profile.normalize_chunk( &mut value );
+            serde_json::from_value( value )
+          })
+        };
+        return Some( match chunk
+        {
+          Ok( chunk ) => Ok( chunk ),
+          Err( _ ) if serde_json::from_str::< serde_json::Value >( &event.data ).is_ok_and( | value | value.get( "error" ).is_some() ) => Err( OpenAiCompatError::from_stream_error( &event.data ).into() ),
+          Err( e ) => Err( OpenAiCompatError::from( e ).into() ),
+        });
+      }
+      None
+    }
+
+    /// Whether the `[DONE]` marker has been seen.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_done( &self ) -> bool
+    {
+      self.done
+    }
+
+    /// `id` of the last event taken out, to send as `Last-Event-ID` when
+    /// resuming the stream; `None` when the server sends no ids. An id
+    /// whose event has not been returned yet is not reported.
+    #[ inline ]
+    #[ must_use ]
+    pub fn last_event_id( &self ) -> Option< &str >
+    {
+      self.last_event_id.as_deref()
+    }
+
+    /// Number of comment lines seen, e.g. keep-alives.
+    #[ inline ]
+    #[ must_use ]
+    pub fn comments( &self ) -> u64
+    {
+      self.events.comments()
+    }
+  }
+
+  /// Folds the chunks of one streamed completion into a `ChatCompletionResponse`.
+  ///
+  /// Push every chunk in arrival order, then call [`finish`](Self::finish).
+  /// Content and reasoning are concatenated per choice, tool-call argument
+  /// fragments are joined per call `index` (so fragments of parallel calls
+  /// may interleave), and the last `finish_reason` of each choice wins. The
+  /// header fields come from the first chunk.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionChunk, StreamAccumulator };
+  ///
+  /// let chunks : Vec< ChatCompletionChunk > = serde_json::from_str( r#"[
+  ///   { "id" : "c1", "object" : "chat.completion.chunk", "created" : 1, "model" : "m",
+  ///     "choices" : [ { "index" : 0, "delta" : { "role" : "assistant", "content" : "Hel" }, "finish_reason" : null } ] },
+  ///   { "id" : "c1", "object" : "chat.completion.chunk", "created" : 1, "model" : "m",
+  ///     "choices" : [ { "index" : 0, "delta" : { "content" : "lo" }, "finish_reason" : "stop" } ] }
+  /// ]"# ).unwrap();
+  ///
+  /// let mut accumulator = StreamAccumulator::new();
+  /// for chunk in &chunks
+  /// {
+  ///   accumulator.push( chunk );
+  /// }
+  /// let response = accumulator.finish();
+  /// assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "Hello" ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct StreamAccumulator
+  {
+    id : String,
+    created : u64,
+    model : String,
+    system_fingerprint : Option< String >,
+    usage : Option< Usage >,
+    choices : BTreeMap< u32, ChoiceState >,
+  }
+
+  /// What has arrived so far for one choice.
+  #[ derive( Debug, Clone, Default ) ]
+  struct ChoiceState
+  {
+    role : Option< Role >,
+    content : Option< String >,
+    reasoning_content : Option< String >,
+    tool_calls : BTreeMap< u32, ToolCall >,
+    finish_reason : Option< String >,
+  }
+
+  impl StreamAccumulator
+  {
+    /// An accumulator that has seen no chunk.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Folds one chunk into the response.
+    #[ inline ]
+    pub fn push( &mut self, chunk : &ChatCompletionChunk )
+    {
+      if self.id.is_empty()
+      {
+        self.id.clone_from( &chunk.id );
+        self.created = chunk.created;
+        self.model.clone_from( &chunk.model );
+      }
+      if chunk.system_fingerprint.is_some()
+      {
+        self.system_fingerprint.clone_from( &chunk.system_fingerprint );
+      }
+      if chunk.usage.is_some()
+      {
+        self.usage.clone_from( &chunk.usage );
+      }
+      for choice in &chunk.choices
+      {
+        let state = self.choices.entry( choice.index ).or_default();
+        state.push( &choice.delta );
+        if choice.finish_reason.is_some()
+        {
+          state.finish_reason.clone_from( &choice.finish_reason );
+        }
+      }
+    }
+
+    /// Usage reported so far, if the stream carried any.
+    #[ inline ]
+    #[ must_use ]
+    pub fn usage( &self ) -> Option< &Usage >
+    {
+      self.usage.as_ref()
+    }
+
+    /// The text received so far for the first choice.
+    #[ inline ]
+    #[ must_use ]
+    pub fn content( &self ) -> Option< &str >
+    {
+      self.choices.get( &0 ).and_then( | state | state.content.as_deref() )
+    }
+
+    /// The response assembled from every pushed chunk.
+    ///
+    /// Choices and tool calls are ordered by index. The role defaults to
+    /// `assistant` when no chunk named one, and usage to zeros when the
+    /// stream carried none.
+    #[ inline ]
+    #[ must_use ]
+    pub fn finish( self ) -> ChatCompletionResponse
+    {
+      let choices = self.choices.into_iter().map( | ( index, state ) | Choice
+      {
+        index,
+        message : Message
+        {
+          role : state.role.unwrap_or( Role::Assistant ),
+          content : state.content,
+          reasoning_content : state.reasoning_content,
+          tool_calls : if state.tool_calls.is_empty() { None } else { Some( state.tool_calls.into_values().collect() ) },
+          tool_call_id : None,
+          extra : serde_json::Map::new(),
+        },
+        finish_reason : state.finish_reason,
+        logprobs : None,
+        extra : serde_json::Map::new(),
+      }).collect();
+      ChatCompletionResponse
+      {
+        id : self.id,
+        object : "chat.completion".to_string(),
+        created : self.created,
+        model : self.model,
+        choices,
+        usage : self.usage.unwrap_or_default(),
+        system_fingerprint : self.system_fingerprint,
+        extra : serde_json::Map::new(),
+      }
+    }
+  }
+
+  impl ChoiceState
+  {
+    fn push( &mut self, delta : &Delta )
+    {
+      if delta.role.is_some()
+      {
+        self.role.clone_from( &delta.role );
+      }
+      if let Some( content ) = &delta.content
+      {
+        self.content.get_or_insert_with( String::new ).push_str( content );
+      }
+      if let Some( reasoning ) = &delta.reasoning_content
+      {
+        self.reasoning_content.get_or_insert_with( String::new ).push_str( reasoning );
+      }
+      for ( position, fragment ) in delta.tool_calls.iter().flatten().enumerate()
+      {
+        let index = fragment.index.unwrap_or_else( || u32::try_from( position ).unwrap_or( u32::MAX ) );
+        let call = self.tool_calls.entry( index ).or_insert_with( || ToolCall
+        {
+          id : String::new(),
+          tool_type : "function".to_string(),
+          function : FunctionCall { name : String::new(), arguments : String::new() },
+        });
+        if !fragment.id.is_empty()
+        {
+          call.id.clone_from( &fragment.id );
+        }
+        if !fragment.tool_type.is_empty()
+        {
+          call.tool_type.clone_from( &fragment.tool_type );
+        }
+        if call.function.name.is_empty()
+        {
+          call.function.name.clone_from( &fragment.function.name );
+        }
+        call.function.arguments.push_str( &fragment.function.arguments );
+      }
+    }
+  }
+
+  /// Translates the first choice of a chunk into provider-neutral events.
+  ///
+  /// Emits text, one tool-call fragment per entry in `tool_calls` (indexed by
+  /// the fragment's `index`, or its position when absent), and `Done` when
+  /// `finish_reason` is set. Chunks for other
+  /// choices (`n > 1`) produce no events.
+  #[ cfg( feature = "common" ) ]
+  impl api_common::IntoStreamEvents for ChatCompletionChunk
+  {
+    #[ inline ]
+    fn into_stream_events( self ) -> api_common::Result< Vec< api_common::StreamEvent > >
+    {
+      use api_common::{ StreamEvent, ToolCallDelta };
+
+      let mut events = Vec::new();
+      let Some( choice ) = self.choices.into_iter().find( | c | c.index == 0 ) else
+      {
+        return Ok( events );
+      };
+      if let Some( content ) = choice.delta.content.filter( | c | !c.is_empty() )
+      {
+        events.push( StreamEvent::TextDelta( content ) );
+      }
+      for ( position, call ) in choice.delta.tool_calls.unwrap_or_default().into_iter().enumerate()
+      {
+        events.push( StreamEvent::ToolCallDelta( ToolCallDelta
+        {
+          index : call.index.map_or( position, | index | index as usize ),
+          id : Some( call.id ).filter( | id | !id.is_empty() ),
+          name : Some( call.function.name ).filter( | name | !name.is_empty() ),
+          arguments : call.function.arguments,
+        }));
+      }
+      if let Some( reason ) = choice.finish_reason
+      {
+        events.push( StreamEvent::Done { finish_reason : Some( reason ) } );
+      }
+      Ok( events )
+    }
   }
 }
 
@@ -67,5 +524,9 @@ crate::mod_interface!
     ChatCompletionChunk,
     ChunkChoice,
     Delta,
+    ToolCallChunk,
+    FunctionCallChunk,
+    ChunkDecoder,
+    StreamAccumulator,
   };
 }
@@ -0,0 +1,162 @@
+//! curl commands for requests as the client would send them.
+//!
+//! When a provider rejects a request that another accepts, the quickest
+//! check is to replay the call by hand. [`Client::prepare_post`] and
+//! [`Client::prepare_get`](crate::Client::prepare_get) build a
+//! [`PreparedRequest`] along the client's own path — environment headers,
+//! idempotency key, guardrails, request hooks — without sending it, and
+//! [`PreparedRequest::to_curl`] renders that call as a shell command.
+//!
+//! Credentials are masked in the command, so it can be pasted into an issue
+//! or a log; put the real key back before running it.
+//!
+//! [`Client::prepare_post`]: crate::Client::prepare_post
+
+mod private
+{
+  use reqwest::header::{ HeaderMap, HeaderName, HeaderValue };
+  use reqwest::Method;
+  use serde_json::Value;
+
+  /// Headers masked in curl commands even when not marked sensitive.
+  const CREDENTIAL_HEADERS : [ &str; 4 ] = [ "authorization", "proxy-authorization", "api-key", "x-api-key" ];
+
+  /// A request built by the client but not sent: the method, full URL,
+  /// headers, and JSON body it would go out with.
+  #[ derive( Clone ) ]
+  pub struct PreparedRequest
+  {
+    method : Method,
+    url : String,
+    headers : HeaderMap,
+    body : Option< Value >,
+  }
+
+  impl core::fmt::Debug for PreparedRequest
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let headers : Vec< ( &str, String ) > = self.headers.iter()
+        .map( | ( name, value ) | ( name.as_str(), shown_value( name, value ) ) )
+        .collect();
+      f.debug_struct( "PreparedRequest" )
+        .field( "method", &self.method )
+        .field( "url", &self.url )
+        .field( "headers", &headers )
+        .field( "body", &self.body )
+        .finish()
+    }
+  }
+
+  impl PreparedRequest
+  {
+    pub( crate ) fn new( method : Method, url : String, headers : HeaderMap, body : Option< Value > ) -> Self
+    {
+      Self { method, url, headers, body }
+    }
+
+    /// HTTP method.
+    #[ inline ]
+    #[ must_use ]
+    pub fn method( &self ) -> &Method
+    {
+      &self.method
+    }
+
+    /// Full request URL: the base URL with the path appended.
+    #[ inline ]
+    #[ must_use ]
+    pub fn url( &self ) -> &str
+    {
+      &self.url
+    }
+
+    /// Headers as sent, credentials included.
+    #[ inline ]
+    #[ must_use ]
+    pub fn headers( &self ) -> &HeaderMap
+    {
+      &self.headers
+    }
+
+    /// JSON body, after guardrails; `None` for a GET.
+    #[ inline ]
+    #[ must_use ]
+    pub fn body( &self ) -> Option< &Value >
+    {
+      self.body.as_ref()
+    }
+
+    /// The request as a curl command, one option per line.
+    ///
+    /// Every argument is single-quoted for POSIX shells. The body is the
+    /// compact JSON the client sends. Credential headers — `Authorization`,
+    /// `Proxy-Authorization`, `api-key`, `x-api-key`, and any value marked
+    /// sensitive — keep their scheme but have the secret masked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[ cfg( feature = "curl_diagnostics" ) ]
+    /// # {
+    /// use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, RequestOptions };
+    ///
+    /// let env = OpenAiCompatEnvironmentImpl::new( "sk-proj-0123456789" ).unwrap()
+    ///   .with_base_url( "http://localhost:8000/v1/" );
+    /// let client = Client::build( env ).unwrap();
+    /// let request = serde_json::json!( { "model" : "qwen2.5", "messages" : [ { "role" : "user", "content" : "It's fine" } ] } );
+    ///
+    /// let curl = client.prepare_post( "chat/completions", &request, &RequestOptions::new() ).unwrap().to_curl();
+    ///
+    /// assert!( curl.starts_with( "curl -X POST 'http://localhost:8000/v1/chat/completions'" ) );
+    /// assert!( !curl.contains( "sk-proj-0123456789" ) );
+    /// assert!( curl.contains( r#""content":"It'\''s fine""# ) );
+    /// # }
+    /// ```
+    #[ inline ]
+    #[ must_use ]
+    pub fn to_curl( &self ) -> String
+    {
+      let mut lines = vec![ format!( "curl -X {} {}", self.method, quote( &self.url ) ) ];
+      for ( name, value ) in &self.headers
+      {
+        lines.push( format!( "-H {}", quote( &format!( "{name}: {}", shown_value( name, value ) ) ) ) );
+      }
+      if let Some( body ) = &self.body
+      {
+        lines.push( format!( "--data-raw {}", quote( &body.to_string() ) ) );
+      }
+      lines.join( " \\\n  " )
+    }
+  }
+
+  /// `value` as text, with the secret masked for credential headers.
+  fn shown_value( name : &HeaderName, value : &HeaderValue ) -> String
+  {
+    let text = String::from_utf8_lossy( value.as_bytes() );
+    if !value.is_sensitive() && !CREDENTIAL_HEADERS.contains( &name.as_str() )
+    {
+      return text.into_owned();
+    }
+    match text.split_once( ' ' )
+    {
+      Some( ( scheme, secret ) ) => format!( "{scheme} {}", api_secret::Masked( secret ) ),
+      None => api_secret::mask( &text ),
+    }
+  }
+
+  /// `text` single-quoted for a POSIX shell; each `'` becomes `'\''`.
+  fn quote( text : &str ) -> String
+  {
+    format!( "'{}'", text.replace( '\'', r"'\''" ) )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    PreparedRequest,
+  };
+}
@@ -2,14 +2,117 @@
 //!
 //! The [`OpenAiCompatEnvironment`] trait abstracts over credential sources and
 //! API endpoint configuration, enabling the same [`crate::Client`] to target
-//! `OpenAI`, KIE.ai, xAI, or any other OpenAI-compatible endpoint.
+//! `OpenAI`, KIE.ai, xAI, or any other OpenAI-compatible endpoint. A
+//! [`UrlScheme`] lays the endpoint paths out for servers that do not put them
+//! under one base URL, such as Azure `OpenAI` deployments.
 
 mod private
 {
   use crate::error::{ OpenAiCompatError, Result };
+  #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+  use crate::tls::TlsConfig;
+  #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+  use api_common::ConnectionTuning;
+  use crate::profile::ProviderProfile;
   use core::time::Duration;
   use reqwest::header;
 
+  /// Paths Azure serves per deployment; the others are served per resource.
+  const AZURE_DEPLOYMENT_PATHS : [ &str; 5 ] = [ "chat/", "completions", "embeddings", "audio/", "images/" ];
+
+  /// How endpoint paths become request URLs.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::UrlScheme;
+  ///
+  /// let azure = UrlScheme::azure_deployment( "gpt-4o-prod", "2024-10-21" );
+  /// assert_eq!(
+  ///   azure.url( "https://contoso.openai.azure.com/", "chat/completions" ),
+  ///   "https://contoso.openai.azure.com/openai/deployments/gpt-4o-prod/chat/completions?api-version=2024-10-21",
+  /// );
+  /// assert_eq!(
+  ///   azure.url( "https://contoso.openai.azure.com/", "models" ),
+  ///   "https://contoso.openai.azure.com/openai/models?api-version=2024-10-21",
+  /// );
+  /// assert_eq!( UrlScheme::Standard.url( "https://api.openai.com/v1/", "models" ), "https://api.openai.com/v1/models" );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq, Eq, Default ) ]
+  #[ non_exhaustive ]
+  pub enum UrlScheme
+  {
+    /// The path is appended to the base URL, as `OpenAI` and most
+    /// compatible servers expect. Authenticates with `Authorization: Bearer`.
+    #[ default ]
+    Standard,
+
+    /// Azure `OpenAI`: model endpoints under
+    /// `openai/deployments/{deployment}/`, the others under `openai/`, each
+    /// with an `api-version` query parameter; the base URL is the resource
+    /// endpoint, e.g. `https://{resource}.openai.azure.com/`. Authenticates
+    /// with the `api-key` header.
+    AzureDeployment
+    {
+      /// Deployment name chosen when the model was deployed.
+      deployment : String,
+      /// Data-plane API version, e.g. `2024-10-21`.
+      api_version : String,
+    },
+  }
+
+  /// [`UrlScheme::Standard`], for borrowing from the trait default.
+  static STANDARD_SCHEME : UrlScheme = UrlScheme::Standard;
+
+  impl UrlScheme
+  {
+    /// [`UrlScheme::AzureDeployment`] for `deployment` at `api_version`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn azure_deployment( deployment : impl Into< String >, api_version : impl Into< String > ) -> Self
+    {
+      Self::AzureDeployment { deployment : deployment.into(), api_version : api_version.into() }
+    }
+
+    /// URL of `path` under `base_url`, which ends with a slash.
+    #[ inline ]
+    #[ must_use ]
+    pub fn url( &self, base_url : &str, path : &str ) -> String
+    {
+      match self
+      {
+        Self::Standard => format!( "{base_url}{path}" ),
+        Self::AzureDeployment { deployment, api_version } =>
+        {
+          let separator = if path.contains( '?' ) { '&' } else { '?' };
+          if AZURE_DEPLOYMENT_PATHS.iter().any( | prefix | path.starts_with( prefix ) )
+          {
+            format!( "{base_url}openai/deployments/{deployment}/{path}{separator}api-version={api_version}" )
+          }
+          else
+          {
+            format!( "{base_url}openai/{path}{separator}api-version={api_version}" )
+          }
+        },
+      }
+    }
+
+    /// Header the API key is sent in.
+    #[ inline ]
+    #[ must_use ]
+    pub fn auth_header( &self ) -> header::HeaderName
+    {
+      match self
+      {
+        Self::Standard => header::AUTHORIZATION,
+        Self::AzureDeployment { .. } => header::HeaderName::from_static( "api-key" ),
+      }
+    }
+  }
+
   /// Configuration contract for an OpenAI-compatible API environment.
   ///
   /// Implementors supply the three required values (API key, base URL, timeout)
@@ -26,17 +129,61 @@ mod private
 
     /// Returns the base URL for this provider, including the trailing slash.
     ///
-    /// Example: `"https://api.openai.com/v1/"`. The client appends endpoint
-    /// paths (e.g. `"chat/completions"`) to produce the full request URL.
+    /// Example: `"https://api.openai.com/v1/"`. Under [`UrlScheme::Standard`]
+    /// the client appends endpoint paths (e.g. `"chat/completions"`) to
+    /// produce the full request URL.
     fn base_url( &self ) -> &str;
 
     /// Returns the per-request timeout duration.
     fn timeout( &self ) -> Duration;
 
+    /// How endpoint paths become request URLs; [`UrlScheme::Standard`]
+    /// unless overridden.
+    #[ inline ]
+    fn url_scheme( &self ) -> &UrlScheme
+    {
+      &STANDARD_SCHEME
+    }
+
+    /// Request URL of the endpoint `path`, laid out by
+    /// [`url_scheme`](Self::url_scheme).
+    #[ inline ]
+    fn url( &self, path : &str ) -> String
+    {
+      self.url_scheme().url( self.base_url(), path )
+    }
+
+    /// TLS settings applied when the client is built; `None` keeps the
+    /// backend's defaults.
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    fn tls( &self ) -> Option< &TlsConfig >
+    {
+      None
+    }
+
+    /// Connection pool, keep-alive, and proxy settings applied when the
+    /// client is built; `None` keeps the backend's defaults.
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    fn connection_tuning( &self ) -> Option< &ConnectionTuning >
+    {
+      None
+    }
+
+    /// Which server family answers, and so which deserialisation
+    /// leniencies the client applies to its responses.
+    #[ inline ]
+    fn provider_profile( &self ) -> ProviderProfile
+    {
+      ProviderProfile::Standard
+    }
+
     /// Constructs the HTTP headers required for every request.
     ///
     /// Default implementation adds:
-    /// - `Authorization: Bearer <api_key>`
+    /// - `Authorization: Bearer <api_key>`, or `api-key: <api_key>` under
+    ///   [`UrlScheme::AzureDeployment`]
     /// - `Content-Type: application/json`
     ///
     /// # Errors
@@ -47,10 +194,17 @@ mod private
     fn headers( &self ) -> Result< header::HeaderMap >
     {
       let mut map = header::HeaderMap::new();
-      let auth_value = format!( "Bearer {}", self.api_key() )
+      let scheme = self.url_scheme();
+      let credential = match scheme
+      {
+        UrlScheme::AzureDeployment { .. } => self.api_key().to_owned(),
+        UrlScheme::Standard => format!( "Bearer {}", self.api_key() ),
+      };
+      let mut auth_value = credential
         .parse::< header::HeaderValue >()
-        .map_err( | e | OpenAiCompatError::InvalidApiKey( e.to_string() ) )?;
-      map.insert( header::AUTHORIZATION, auth_value );
+        .map_err( | e | OpenAiCompatError::invalid_key( e.to_string() ) )?;
+      auth_value.set_sensitive( true );
+      map.insert( scheme.auth_header(), auth_value );
       map.insert
       (
         header::CONTENT_TYPE,
@@ -77,7 +231,7 @@ mod private
   /// let kie_env = env.with_base_url( "https://api.kie.ai/my-model/v1/" );
   /// # }
   /// ```
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   pub struct OpenAiCompatEnvironmentImpl
   {
     /// API authentication key.
@@ -86,6 +240,36 @@ mod private
     base_url : String,
     /// Per-request timeout.
     timeout  : Duration,
+    /// TLS settings; `None` keeps the backend's defaults.
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    tls      : Option< TlsConfig >,
+    /// Connection settings; `None` keeps the backend's defaults.
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    connection_tuning : Option< ConnectionTuning >,
+    /// Deserialisation leniencies of the server family.
+    provider_profile : ProviderProfile,
+    /// Layout of request URLs and the auth header.
+    url_scheme : UrlScheme,
+  }
+
+  impl core::fmt::Debug for OpenAiCompatEnvironmentImpl
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let mut debug = f.debug_struct( "OpenAiCompatEnvironmentImpl" );
+      debug
+        .field( "api_key", &api_secret::Masked( &self.api_key ) )
+        .field( "base_url", &self.base_url )
+        .field( "timeout", &self.timeout );
+      #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+      debug.field( "tls", &self.tls );
+      #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+      debug.field( "connection_tuning", &self.connection_tuning );
+      debug.field( "provider_profile", &self.provider_profile );
+      debug.field( "url_scheme", &self.url_scheme );
+      debug.finish()
+    }
   }
 
   impl OpenAiCompatEnvironmentImpl
@@ -131,7 +315,7 @@ mod private
       {
         return Err
         (
-          OpenAiCompatError::InvalidApiKey( "API key must not be empty or whitespace-only".to_owned() ).into()
+          OpenAiCompatError::invalid_key( "API key must not be empty or whitespace-only" ).into()
         );
       }
       Ok( Self
@@ -139,6 +323,12 @@ mod private
         api_key,
         base_url : Self::DEFAULT_BASE_URL.to_owned(),
         timeout  : Duration::from_secs( Self::DEFAULT_TIMEOUT_SECS ),
+        #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+        tls      : None,
+        #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+        connection_tuning : None,
+        provider_profile : ProviderProfile::Standard,
+        url_scheme : UrlScheme::Standard,
       })
     }
 
@@ -187,6 +377,95 @@ mod private
       self.timeout = timeout;
       self
     }
+
+    /// Applies `tls` when the client is built, e.g. to trust the private CA
+    /// of an on-prem gateway or present a client certificate.
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_tls( mut self, tls : TlsConfig ) -> Self
+    {
+      self.tls = Some( tls );
+      self
+    }
+
+    /// Applies `tuning` when the client is built: idle connections kept per
+    /// host, how long they stay idle, TCP keep-alive and nodelay, HTTP/2
+    /// keep-alive pings, and an optional proxy.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( all( feature = "enabled", feature = "connection" ) ) ]
+    /// # {
+    /// use api_common::ConnectionTuning;
+    /// use api_openai_compatible::OpenAiCompatEnvironmentImpl;
+    /// use core::time::Duration;
+    ///
+    /// let env = OpenAiCompatEnvironmentImpl::new( "sk-key" ).unwrap()
+    ///   .with_connection_tuning
+    ///   (
+    ///     ConnectionTuning::low_latency()
+    ///       .with_pool_max_idle_per_host( 32 )
+    ///       .with_pool_idle_timeout( Duration::from_secs( 90 ) )
+    ///       .with_proxy( "http://proxy.internal:3128" )
+    ///   );
+    /// # }
+    /// ```
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_connection_tuning( mut self, tuning : ConnectionTuning ) -> Self
+    {
+      self.connection_tuning = Some( tuning );
+      self
+    }
+
+    /// Reads responses with the leniencies of `profile`, e.g.
+    /// [`ProviderProfile::Groq`] to pick up usage from `x_groq`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ OpenAiCompatEnvironmentImpl, ProviderProfile };
+    ///
+    /// let env = OpenAiCompatEnvironmentImpl::new( "gsk-key" ).unwrap()
+    ///   .with_base_url( "https://api.groq.com/openai/v1/" )
+    ///   .with_provider_profile( ProviderProfile::Groq );
+    /// # }
+    /// ```
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_provider_profile( mut self, profile : ProviderProfile ) -> Self
+    {
+      self.provider_profile = profile;
+      self
+    }
+
+    /// Lays request URLs out by `scheme`, e.g. for an Azure `OpenAI`
+    /// deployment, with the base URL set to the resource endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "enabled" ) ]
+    /// # {
+    /// use api_openai_compatible::{ OpenAiCompatEnvironmentImpl, UrlScheme };
+    ///
+    /// let env = OpenAiCompatEnvironmentImpl::new( "azure-key" ).unwrap()
+    ///   .with_base_url( "https://contoso.openai.azure.com/" )
+    ///   .with_url_scheme( UrlScheme::azure_deployment( "gpt-4o-prod", "2024-10-21" ) );
+    /// # }
+    /// ```
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_url_scheme( mut self, scheme : UrlScheme ) -> Self
+    {
+      self.url_scheme = scheme;
+      self
+    }
   }
 
   impl OpenAiCompatEnvironment for OpenAiCompatEnvironmentImpl
@@ -208,6 +487,32 @@ mod private
     {
       self.timeout
     }
+
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    fn tls( &self ) -> Option< &TlsConfig >
+    {
+      self.tls.as_ref()
+    }
+
+    #[ cfg( all( feature = "connection", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    fn connection_tuning( &self ) -> Option< &ConnectionTuning >
+    {
+      self.connection_tuning.as_ref()
+    }
+
+    #[ inline ]
+    fn provider_profile( &self ) -> ProviderProfile
+    {
+      self.provider_profile
+    }
+
+    #[ inline ]
+    fn url_scheme( &self ) -> &UrlScheme
+    {
+      &self.url_scheme
+    }
   }
 }
 
@@ -217,5 +522,6 @@ crate::mod_interface!
   {
     OpenAiCompatEnvironment,
     OpenAiCompatEnvironmentImpl,
+    UrlScheme,
   };
 }
@@ -2,26 +2,107 @@
 
 mod private
 {
+  use crate::rate_limit_info::RateLimitInfo;
+  use core::time::Duration;
   use error_tools::dependency::thiserror;
+  use reqwest::{ header::HeaderMap, StatusCode };
+  use serde_json::Value;
+
+  /// Longest prefix of a response body kept in
+  /// [`OpenAiCompatError::Deserialization`].
+  const BODY_SNIPPET_CHARS : usize = 200;
 
   /// Error variants for OpenAI-compatible API operations.
   ///
-  /// Covers transport failures, API-level errors, and configuration problems.
+  /// Error responses are classified by status with
+  /// [`from_response`](Self::from_response), reading the `OpenAI` error
+  /// object (`message`, `type`, `param`, `code`) from the body. Retry and
+  /// circuit-breaker code needs no status tables of its own:
+  /// [`is_retryable`](Self::is_retryable), [`status`](Self::status),
+  /// [`code`](Self::code), and [`retry_after`](Self::retry_after) answer
+  /// for every variant.
+  ///
   /// Convert from `reqwest::Error`, `serde_json::Error`, and
   /// `reqwest::header::InvalidHeaderValue` via `From` impls.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::OpenAiCompatError;
+  /// use reqwest::{ header::HeaderMap, StatusCode };
+  ///
+  /// let mut headers = HeaderMap::new();
+  /// headers.insert( "retry-after", "2".parse().unwrap() );
+  /// let body = br#"{ "error" : { "message" : "Slow down", "type" : "requests", "code" : "rate_limit_exceeded" } }"#;
+  ///
+  /// let error = OpenAiCompatError::from_response( StatusCode::TOO_MANY_REQUESTS, &headers, body );
+  /// assert!( error.is_retryable() );
+  /// assert_eq!( error.code(), Some( "rate_limit_exceeded" ) );
+  /// assert_eq!( error.retry_after(), Some( core::time::Duration::from_secs( 2 ) ) );
+  /// # }
+  /// ```
   #[ derive( Debug, Clone, PartialEq, thiserror::Error ) ]
   #[ non_exhaustive ]
   pub enum OpenAiCompatError
   {
-    /// Error body returned by the API (e.g. invalid model name).
-    #[ error( "API error : {0}" ) ]
-    Api( String ),
+    /// The key was rejected (`401`, `403`), or it is absent or holds
+    /// characters invalid in an HTTP header (`status` `None`).
+    #[ error( "Authentication error : {message}" ) ]
+    Authentication
+    {
+      /// HTTP status, when the server rejected the key.
+      status : Option< u16 >,
+      /// Server message, or what is wrong with the key.
+      message : String,
+    },
 
-    /// HTTP transport error (4xx / 5xx status code).
-    #[ error( "HTTP error : {0}" ) ]
-    Http( String ),
+    /// `429`: too many requests or tokens, or the quota is spent.
+    #[ error( "Rate limited : {message}" ) ]
+    RateLimited
+    {
+      /// Wait the server asked for in `retry-after-ms` or `Retry-After`.
+      retry_after : Option< Duration >,
+      /// Machine-readable reason, e.g. `rate_limit_exceeded` or
+      /// `insufficient_quota`.
+      code : Option< String >,
+      /// Server message.
+      message : String,
+      /// Quotas and resets the response reported; empty for a stream event.
+      rate_limits : RateLimitInfo,
+    },
+
+    /// The server refused the request (any other `4xx`), or a provider check
+    /// refused it before sending (`status` `None`).
+    #[ error( "Invalid request : {message}" ) ]
+    InvalidRequest
+    {
+      /// HTTP status, when the server refused the request.
+      status : Option< u16 >,
+      /// Request field the server blamed.
+      param : Option< String >,
+      /// Machine-readable reason, e.g. `model_not_found`.
+      code : Option< String >,
+      /// Server message, or the rule the request broke.
+      message : String,
+    },
 
-    /// Network connectivity error (DNS, TCP, etc.).
+    /// `5xx` or another unexpected status, or an error sent inside a stream
+    /// (`status` `None`).
+    #[ error( "Server error : {message}" ) ]
+    ServerError
+    {
+      /// HTTP status, when the error was a response.
+      status : Option< u16 >,
+      /// Machine-readable reason, when the server sent one.
+      code : Option< String >,
+      /// Server message, or the status when the body held none.
+      message : String,
+    },
+
+    /// Network failure before a status arrived (DNS, TCP, TLS, a body cut
+    /// off).
     #[ error( "Network error : {0}" ) ]
     Network( String ),
 
@@ -29,19 +110,231 @@ mod private
     #[ error( "Timeout : {0}" ) ]
     Timeout( String ),
 
-    /// Failed to deserialise the response body.
-    #[ error( "Deserialisation error : {0}" ) ]
-    Deserialise( String ),
-
-    /// API key is absent or contains characters invalid in an HTTP header.
-    #[ error( "Invalid API key : {0}" ) ]
-    InvalidApiKey( String ),
+    /// A body could not be serialised or deserialised.
+    #[ error( "Deserialization error : {message}" ) ]
+    Deserialization
+    {
+      /// The `serde_json` error.
+      message : String,
+      /// Start of the offending body, when there was one; empty otherwise.
+      body_snippet : String,
+    },
 
     /// Environment is misconfigured (e.g. unparseable base URL).
     #[ error( "Environment error : {0}" ) ]
     Environment( String ),
   }
 
+  impl OpenAiCompatError
+  {
+    /// Classifies an error response by `status`, reading the `OpenAI` error
+    /// object from `body` and the wait from `headers`.
+    ///
+    /// `401` and `403` are `Authentication`, `429` is `RateLimited`, other
+    /// `4xx` are `InvalidRequest`, and everything else is `ServerError`. A
+    /// body that is not an error object becomes the message as it is.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_response( status : StatusCode, headers : &HeaderMap, body : &[ u8 ] ) -> Self
+    {
+      let details = ErrorDetails::parse( body ).unwrap_or_else( || ErrorDetails::text( status, body ) );
+      let status_code = Some( status.as_u16() );
+      match status.as_u16()
+      {
+        401 | 403 => Self::Authentication { status : status_code, message : details.message },
+        429 =>
+        {
+          let rate_limits = RateLimitInfo::from_headers( headers );
+          Self::RateLimited { retry_after : rate_limits.retry_after, code : details.code, message : details.message, rate_limits }
+        },
+        400..=499 => Self::InvalidRequest { status : status_code, param : details.param, code : details.code, message : details.message },
+        _ => Self::ServerError { status : status_code, code : details.code, message : details.message },
+      }
+    }
+
+    /// Classifies an error object sent as a stream event, by its `type`
+    /// and `code` since no status comes with it.
+    #[ cfg( feature = "streaming" ) ]
+    pub( crate ) fn from_stream_error( data : &str ) -> Self
+    {
+      let details = ErrorDetails::parse( data.as_bytes() ).unwrap_or_else( || ErrorDetails { message : data.to_owned(), ..ErrorDetails::default() } );
+      let kind = details.kind.as_deref().unwrap_or_default();
+      let code = details.code.as_deref().unwrap_or_default();
+      if kind.contains( "rate_limit" ) || code.contains( "rate_limit" ) || code == "insufficient_quota"
+      {
+        Self::RateLimited { retry_after : None, code : details.code, message : details.message, rate_limits : RateLimitInfo::default() }
+      }
+      else if kind.contains( "authentication" ) || code == "invalid_api_key"
+      {
+        Self::Authentication { status : None, message : details.message }
+      }
+      else if kind == "invalid_request_error"
+      {
+        Self::InvalidRequest { status : None, param : details.param, code : details.code, message : details.message }
+      }
+      else
+      {
+        Self::ServerError { status : None, code : details.code, message : details.message }
+      }
+    }
+
+    /// A request refused before sending.
+    pub( crate ) fn invalid_request( message : impl Into< String > ) -> Self
+    {
+      Self::InvalidRequest { status : None, param : None, code : None, message : message.into() }
+    }
+
+    /// A key that cannot be sent.
+    pub( crate ) fn invalid_key( message : impl Into< String > ) -> Self
+    {
+      Self::Authentication { status : None, message : message.into() }
+    }
+
+    /// `error` from reading `body`, with the start of the body kept.
+    pub( crate ) fn deserialization( error : &serde_json::Error, body : &[ u8 ] ) -> Self
+    {
+      Self::Deserialization { message : error.to_string(), body_snippet : String::from_utf8_lossy( body ).chars().take( BODY_SNIPPET_CHARS ).collect() }
+    }
+
+    /// The wait a response asks for: `retry-after-ms`, as `OpenAI` sends it,
+    /// or `Retry-After` in whole seconds. HTTP dates are not read.
+    pub( crate ) fn retry_after_header( headers : &HeaderMap ) -> Option< Duration >
+    {
+      let number = | name : &str | headers.get( name )?.to_str().ok()?.trim().parse::< f64 >().ok().filter( | n | n.is_finite() && *n >= 0.0 );
+      number( "retry-after-ms" ).map( | ms | Duration::from_secs_f64( ms / 1000.0 ) )
+        .or_else( || number( "retry-after" ).map( Duration::from_secs_f64 ) )
+    }
+
+    /// Whether sending the same request again may succeed.
+    ///
+    /// True for network failures, timeouts, `429` except a spent quota
+    /// (`insufficient_quota`), `408` and `409`, which `OpenAI` asks clients
+    /// to repeat, and server errors except `501 Not Implemented`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_retryable( &self ) -> bool
+    {
+      match self
+      {
+        Self::Network( _ ) | Self::Timeout( _ ) => true,
+        Self::RateLimited { code, .. } => code.as_deref() != Some( "insufficient_quota" ),
+        Self::InvalidRequest { status, .. } => matches!( status, Some( 408 | 409 ) ),
+        Self::ServerError { status, .. } => *status != Some( 501 ),
+        Self::Authentication { .. } | Self::Deserialization { .. } | Self::Environment( _ ) => false,
+      }
+    }
+
+    /// HTTP status of an error response; `RateLimited` always reports `429`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn status( &self ) -> Option< u16 >
+    {
+      match self
+      {
+        Self::RateLimited { .. } => Some( 429 ),
+        Self::Authentication { status, .. } | Self::InvalidRequest { status, .. } | Self::ServerError { status, .. } => *status,
+        _ => None,
+      }
+    }
+
+    /// Machine-readable reason the server gave.
+    #[ inline ]
+    #[ must_use ]
+    pub fn code( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Self::RateLimited { code, .. } | Self::InvalidRequest { code, .. } | Self::ServerError { code, .. } => code.as_deref(),
+        _ => None,
+      }
+    }
+
+    /// Request field the server blamed.
+    #[ inline ]
+    #[ must_use ]
+    pub fn param( &self ) -> Option< &str >
+    {
+      match self
+      {
+        Self::InvalidRequest { param, .. } => param.as_deref(),
+        _ => None,
+      }
+    }
+
+    /// Wait the server asked for before a retry.
+    #[ inline ]
+    #[ must_use ]
+    pub fn retry_after( &self ) -> Option< Duration >
+    {
+      match self
+      {
+        Self::RateLimited { retry_after, .. } => *retry_after,
+        _ => None,
+      }
+    }
+
+    /// Rate-limit headers of a `429`, for pacing after it.
+    #[ inline ]
+    #[ must_use ]
+    pub fn rate_limits( &self ) -> Option< &RateLimitInfo >
+    {
+      match self
+      {
+        Self::RateLimited { rate_limits, .. } => Some( rate_limits ),
+        _ => None,
+      }
+    }
+  }
+
+  /// The members of an error body the classification reads.
+  #[ derive( Default ) ]
+  struct ErrorDetails
+  {
+    message : String,
+    #[ cfg( feature = "streaming" ) ]
+    kind : Option< String >,
+    param : Option< String >,
+    code : Option< String >,
+  }
+
+  impl ErrorDetails
+  {
+    /// Reads `{ "error" : { .. } }`, `{ "error" : "..." }`, or a top-level
+    /// `message` or `detail` (`FastAPI` servers such as vLLM).
+    fn parse( body : &[ u8 ] ) -> Option< Self >
+    {
+      let value : Value = serde_json::from_slice( body ).ok()?;
+      let text = | value : Option< &Value > | match value?
+      {
+        Value::String( text ) => Some( text.clone() ),
+        Value::Number( number ) => Some( number.to_string() ),
+        _ => None,
+      };
+      let object = match value.get( "error" )
+      {
+        Some( error @ Value::Object( _ ) ) => error,
+        Some( Value::String( message ) ) => return Some( Self { message : message.clone(), ..Self::default() } ),
+        _ => &value,
+      };
+      let message = text( object.get( "message" ) ).or_else( || text( object.get( "detail" ) ) )?;
+      Some( Self
+      {
+        message,
+        #[ cfg( feature = "streaming" ) ]
+        kind : text( object.get( "type" ) ),
+        param : text( object.get( "param" ) ),
+        code : text( object.get( "code" ) ),
+      } )
+    }
+
+    /// The body as it is, or the status when the body is empty.
+    fn text( status : StatusCode, body : &[ u8 ] ) -> Self
+    {
+      let message = String::from_utf8_lossy( body ).trim().to_owned();
+      Self { message : if message.is_empty() { status.to_string() } else { message }, ..Self::default() }
+    }
+  }
+
   /// Crate-level result type backed by a boxed dynamic error.
   pub type Result< T > = error_tools::untyped::Result< T >;
 
@@ -54,13 +347,13 @@ mod private
       {
         Self::Timeout( e.to_string() )
       }
-      else if e.is_connect()
+      else if e.is_builder()
       {
-        Self::Network( e.to_string() )
+        Self::Environment( e.to_string() )
       }
       else
       {
-        Self::Http( e.to_string() )
+        Self::Network( e.to_string() )
       }
     }
   }
@@ -70,7 +363,7 @@ mod private
     #[ inline ]
     fn from( e : serde_json::Error ) -> Self
     {
-      Self::Deserialise( e.to_string() )
+      Self::Deserialization { message : e.to_string(), body_snippet : String::new() }
     }
   }
 
@@ -79,7 +372,26 @@ mod private
     #[ inline ]
     fn from( e : reqwest::header::InvalidHeaderValue ) -> Self
     {
-      Self::InvalidApiKey( e.to_string() )
+      Self::invalid_key( e.to_string() )
+    }
+  }
+
+  #[ cfg( feature = "common" ) ]
+  impl From< OpenAiCompatError > for api_common::LlmError
+  {
+    #[ inline ]
+    fn from( e : OpenAiCompatError ) -> Self
+    {
+      match e
+      {
+        OpenAiCompatError::Network( message ) | OpenAiCompatError::Timeout( message ) => Self::Network( message ),
+        OpenAiCompatError::Deserialization { message, .. } => Self::Protocol( message ),
+        OpenAiCompatError::Authentication { message, .. } => Self::Auth( message ),
+        OpenAiCompatError::RateLimited { retry_after, message, .. } => Self::RateLimited { retry_after, message },
+        OpenAiCompatError::InvalidRequest { status : status @ Some( _ ), message, .. }
+        | OpenAiCompatError::ServerError { status, message, .. } => Self::classify( status, &message ),
+        OpenAiCompatError::InvalidRequest { status : None, message, .. } | OpenAiCompatError::Environment( message ) => Self::Provider { raw : message },
+      }
     }
   }
 }
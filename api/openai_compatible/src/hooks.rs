@@ -0,0 +1,241 @@
+//! Request and response hooks for the shared client.
+//!
+//! A request hook runs just before each request leaves the client and may
+//! change its headers, e.g. to inject a tracing id. A response hook runs
+//! once the full response has arrived, before it is interpreted, and sees
+//! the status, headers, raw body, and round-trip time. Hooks observe; they
+//! cannot fail or replace a request, which keeps them out of error handling.
+
+mod private
+{
+  use std::sync::Arc;
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  use core::time::Duration;
+  use core::fmt;
+  use reqwest::{ header::HeaderMap, Method, StatusCode };
+
+  /// The request a [`Hooks`] request hook sees and may amend.
+  #[ derive( Debug ) ]
+  #[ non_exhaustive ]
+  pub struct RequestParts< 'a >
+  {
+    /// HTTP method.
+    pub method : &'a Method,
+    /// Full request URL.
+    pub url : &'a str,
+    /// Headers about to be sent, authentication included; hooks may add,
+    /// replace, or remove entries.
+    pub headers : &'a mut HeaderMap,
+  }
+
+  /// The response a [`Hooks`] response hook sees.
+  #[ derive( Debug ) ]
+  #[ non_exhaustive ]
+  pub struct ResponseParts< 'a >
+  {
+    /// HTTP method of the request.
+    pub method : &'a Method,
+    /// Full request URL.
+    pub url : &'a str,
+    /// Response status, error statuses included.
+    pub status : StatusCode,
+    /// Response headers.
+    pub headers : &'a HeaderMap,
+    /// Raw response body, before guardrails or deserialisation.
+    pub body : &'a [u8],
+    /// Time from sending the request to receiving the whole body.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    pub elapsed : Duration,
+  }
+
+  type RequestHook = Arc< dyn Fn( &mut RequestParts< '_ > ) + Send + Sync >;
+  type ResponseHook = Arc< dyn Fn( &ResponseParts< '_ > ) + Send + Sync >;
+
+  /// Hooks run around every request a client sends, in the order added.
+  ///
+  /// Cloning is cheap and shares the hooks, so one set can be handed to
+  /// several clients.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::Hooks;
+  /// use reqwest::header::HeaderValue;
+  ///
+  /// let hooks = Hooks::new()
+  ///   .with_request( | request | { request.headers.insert( "x-request-id", HeaderValue::from_static( "req-42" ) ); } )
+  ///   .with_response( | response | println!( "{} {} -> {}", response.method, response.url, response.status ) );
+  /// # }
+  /// ```
+  #[ derive( Clone, Default ) ]
+  pub struct Hooks
+  {
+    request : Vec< RequestHook >,
+    response : Vec< ResponseHook >,
+  }
+
+  impl fmt::Debug for Hooks
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "Hooks" )
+        .field( "request", &self.request.len() )
+        .field( "response", &self.response.len() )
+        .finish()
+    }
+  }
+
+  impl Hooks
+  {
+    /// No hooks; requests and responses pass untouched.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Adds `hook`, run before every request is sent.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_request< F >( mut self, hook : F ) -> Self
+    where
+      F : Fn( &mut RequestParts< '_ > ) + Send + Sync + 'static,
+    {
+      self.request.push( Arc::new( hook ) );
+      self
+    }
+
+    /// Adds `hook`, run after every response has been received.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response< F >( mut self, hook : F ) -> Self
+    where
+      F : Fn( &ResponseParts< '_ > ) + Send + Sync + 'static,
+    {
+      self.response.push( Arc::new( hook ) );
+      self
+    }
+
+    /// Whether no hook of either kind is set.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.request.is_empty() && self.response.is_empty()
+    }
+
+    /// Whether a response hook is set, so the response headers are needed.
+    pub( crate ) fn observes_responses( &self ) -> bool
+    {
+      !self.response.is_empty()
+    }
+
+    /// Runs the request hooks over `request`.
+    pub( crate ) fn on_request( &self, request : &mut RequestParts< '_ > )
+    {
+      for hook in &self.request
+      {
+        hook( request );
+      }
+    }
+
+    /// Runs the response hooks over `response`.
+    pub( crate ) fn on_response( &self, response : &ResponseParts< '_ > )
+    {
+      for hook in &self.response
+      {
+        hook( response );
+      }
+    }
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use std::sync::Mutex;
+
+    #[ test ]
+    fn request_hooks_amend_headers_in_order()
+    {
+      let hooks = Hooks::new()
+        .with_request( | request | { request.headers.insert( "x-trace-id", HeaderValue::from_static( "trace-7" ) ); } )
+        .with_request( | request |
+        {
+          let seen = request.headers[ "x-trace-id" ].to_str().unwrap().to_owned();
+          request.headers.insert( "x-trace-id", HeaderValue::from_str( &format!( "{seen}-2" ) ).unwrap() );
+        } );
+      let mut headers = HeaderMap::new();
+
+      hooks.on_request( &mut RequestParts { method : &Method::POST, url : "http://host/v1/chat/completions", headers : &mut headers } );
+
+      assert_eq!( headers[ "x-trace-id" ], "trace-7-2" );
+    }
+
+    #[ test ]
+    fn response_hooks_see_every_response_in_order()
+    {
+      let seen = Arc::new( Mutex::new( Vec::new() ) );
+      let ( first, second ) = ( Arc::clone( &seen ), Arc::clone( &seen ) );
+      let hooks = Hooks::new()
+        .with_response( move | response |
+        {
+          let id = response.headers.get( "x-request-id" ).and_then( | v | v.to_str().ok() ).unwrap_or( "-" ).to_owned();
+          first.lock().unwrap().push( format!( "{} {} {id} {}", response.method, response.status.as_u16(), String::from_utf8_lossy( response.body ) ) );
+        } )
+        .with_response( move | response | second.lock().unwrap().push( format!( "second {}", response.url ) ) );
+      let mut headers = HeaderMap::new();
+      headers.insert( "x-request-id", HeaderValue::from_static( "srv-1" ) );
+
+      for ( method, url, status, headers, body ) in
+      [
+        ( Method::POST, "http://host/v1/chat/completions", StatusCode::OK, &headers, &b"{\"ok\":true}"[ .. ] ),
+        ( Method::GET, "http://host/v1/models", StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), &b"slow"[ .. ] ),
+      ]
+      {
+        hooks.on_response( &ResponseParts
+        {
+          method : &method,
+          url,
+          status,
+          headers,
+          body,
+          #[ cfg( not( target_arch = "wasm32" ) ) ]
+          elapsed : Duration::ZERO,
+        } );
+      }
+
+      assert_eq!
+      (
+        *seen.lock().unwrap(),
+        [ "POST 200 srv-1 {\"ok\":true}", "second http://host/v1/chat/completions", "GET 429 - slow", "second http://host/v1/models" ]
+      );
+    }
+
+    #[ test ]
+    fn empty_hooks_observe_nothing()
+    {
+      let hooks = Hooks::new();
+      assert!( hooks.is_empty() && !hooks.observes_responses() );
+
+      let hooks = hooks.with_request( | _ | {} );
+      assert!( !hooks.is_empty() && !hooks.observes_responses() );
+      assert!( hooks.with_response( | _ | {} ).observes_responses() );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Hooks,
+    RequestParts,
+    ResponseParts,
+  };
+}
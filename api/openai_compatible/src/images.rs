@@ -0,0 +1,478 @@
+//! Image endpoints: generation, edits, and variations.
+//!
+//! `images/generations` takes JSON and is served by `OpenAI` (`dall-e-3`,
+//! `gpt-image-1`) and xAI (`grok-2-image`) alike; `images/edits` and
+//! `images/variations` upload the source images as a
+//! [`MultipartForm`](crate::MultipartForm). All three answer with an
+//! [`ImagesResponse`] whose images come as URLs or base64 data.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::{ OpenAiCompatError, Result };
+  use crate::multipart::{ MultipartForm, UploadFile };
+  use crate::request_options::RequestOptions;
+  use base64::Engine as _;
+  use serde::{ Deserialize, Serialize };
+  use serde_json::{ Map, Value };
+
+  /// How generated images are returned.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  #[ non_exhaustive ]
+  pub enum ImageResponseFormat
+  {
+    /// A URL, valid for a limited time (an hour on `OpenAI`).
+    Url,
+    /// The image itself, base64-encoded.
+    B64Json,
+  }
+
+  impl ImageResponseFormat
+  {
+    /// Wire value of the `response_format` field.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Url => "url",
+        Self::B64Json => "b64_json",
+      }
+    }
+  }
+
+  /// Body of `images/generations`: images from a text prompt.
+  ///
+  /// Options a model does not know are rejected by its server, so only set
+  /// what the target supports; xAI takes `model`, `prompt`, `n`, and
+  /// `response_format`.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "images" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, ImageGenerationRequest, ImageResponseFormat, OpenAiCompatEnvironmentImpl };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let env = OpenAiCompatEnvironmentImpl::new( "xai-..." )?.with_base_url( "https://api.x.ai/v1/" );
+  /// let client = Client::build( env )?;
+  /// let request = ImageGenerationRequest::new( "grok-2-image", "A lighthouse at dawn" )
+  ///   .with_n( 2 )
+  ///   .with_response_format( ImageResponseFormat::B64Json );
+  /// let response = client.generate_images( &request ).await?;
+  /// for ( index, image ) in response.data.iter().enumerate()
+  /// {
+  ///   std::fs::write( format!( "lighthouse_{index}.jpg" ), image.decode()? )?;
+  /// }
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ImageGenerationRequest
+  {
+    /// Image model, e.g. `dall-e-3`, `gpt-image-1`, or `grok-2-image`.
+    pub model : String,
+
+    /// What to draw.
+    pub prompt : String,
+
+    /// Number of images.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub n : Option< u32 >,
+
+    /// Size as `WIDTHxHEIGHT`, e.g. `1024x1024`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub size : Option< String >,
+
+    /// Quality, e.g. `hd` for `dall-e-3` or `high` for `gpt-image-1`.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub quality : Option< String >,
+
+    /// Style, `vivid` or `natural` (`dall-e-3`).
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub style : Option< String >,
+
+    /// How images are returned; `gpt-image-1` always returns base64.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub response_format : Option< ImageResponseFormat >,
+
+    /// End-user identifier for abuse monitoring.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub user : Option< String >,
+
+    /// Fields not modelled above, e.g. `background` or `output_format`,
+    /// sent as they are.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  impl ImageGenerationRequest
+  {
+    /// Images of `prompt` drawn by `model`.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, prompt : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        model : model.into(),
+        prompt : prompt.into(),
+        n : None,
+        size : None,
+        quality : None,
+        style : None,
+        response_format : None,
+        user : None,
+        extra : Map::new(),
+      }
+    }
+
+    /// Number of images.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_n( mut self, n : u32 ) -> Self
+    {
+      self.n = Some( n );
+      self
+    }
+
+    /// Size as `WIDTHxHEIGHT`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_size( mut self, size : impl Into< String > ) -> Self
+    {
+      self.size = Some( size.into() );
+      self
+    }
+
+    /// Quality level.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_quality( mut self, quality : impl Into< String > ) -> Self
+    {
+      self.quality = Some( quality.into() );
+      self
+    }
+
+    /// Style, `vivid` or `natural`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_style( mut self, style : impl Into< String > ) -> Self
+    {
+      self.style = Some( style.into() );
+      self
+    }
+
+    /// How images are returned.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : ImageResponseFormat ) -> Self
+    {
+      self.response_format = Some( format );
+      self
+    }
+  }
+
+  /// Body of `images/edits`: images changed as a prompt describes.
+  ///
+  /// One image is sent as `image`; several, which `gpt-image-1` combines,
+  /// as `image[]`. A mask's transparent pixels mark where to edit.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct ImageEditRequest
+  {
+    images : Vec< UploadFile >,
+    mask : Option< UploadFile >,
+    fields : MultipartForm,
+  }
+
+  impl ImageEditRequest
+  {
+    /// Edit of `image` by `model` as `prompt` describes.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, image : UploadFile, prompt : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        images : vec![ image ],
+        mask : None,
+        fields : MultipartForm::new().with_text( "model", model ).with_text( "prompt", prompt ),
+      }
+    }
+
+    /// Adds a further source image.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_image( mut self, image : UploadFile ) -> Self
+    {
+      self.images.push( image );
+      self
+    }
+
+    /// PNG whose transparent areas mark where to edit the first image.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_mask( mut self, mask : UploadFile ) -> Self
+    {
+      self.mask = Some( mask );
+      self
+    }
+
+    /// Number of images.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_n( self, n : u32 ) -> Self
+    {
+      self.with_field( "n", n.to_string() )
+    }
+
+    /// Size as `WIDTHxHEIGHT`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_size( self, size : impl Into< String > ) -> Self
+    {
+      self.with_field( "size", size )
+    }
+
+    /// How images are returned.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : ImageResponseFormat ) -> Self
+    {
+      self.fields = self.fields.with_text_replaced( "response_format", format.as_str() );
+      self
+    }
+
+    /// Adds a form field the typed methods do not cover, e.g. `quality`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_field( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.fields = self.fields.with_text( name, value );
+      self
+    }
+
+    /// The form as it is uploaded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn form( &self ) -> MultipartForm
+    {
+      let name = if self.images.len() > 1 { "image[]" } else { "image" };
+      let mut form = self.images.iter().fold( self.fields.clone(), | form, image | form.with_file( name, image.clone() ) );
+      if let Some( mask ) = &self.mask
+      {
+        form = form.with_file( "mask", mask.clone() );
+      }
+      form
+    }
+  }
+
+  /// Body of `images/variations`: new images in the manner of one image
+  /// (`dall-e-2`).
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct ImageVariationRequest
+  {
+    form : MultipartForm,
+  }
+
+  impl ImageVariationRequest
+  {
+    /// Variations of `image` by `model`.
+    #[ inline ]
+    pub fn new( model : impl Into< String >, image : UploadFile ) -> Self
+    {
+      Self { form : MultipartForm::new().with_text( "model", model ).with_file( "image", image ) }
+    }
+
+    /// Number of images.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_n( self, n : u32 ) -> Self
+    {
+      self.with_field( "n", n.to_string() )
+    }
+
+    /// Size as `WIDTHxHEIGHT`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_size( self, size : impl Into< String > ) -> Self
+    {
+      self.with_field( "size", size )
+    }
+
+    /// How images are returned.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_response_format( mut self, format : ImageResponseFormat ) -> Self
+    {
+      self.form = self.form.with_text_replaced( "response_format", format.as_str() );
+      self
+    }
+
+    /// Adds a form field the typed methods do not cover.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_field( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.form = self.form.with_text( name, value );
+      self
+    }
+
+    /// The form as it is uploaded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn form( &self ) -> &MultipartForm
+    {
+      &self.form
+    }
+  }
+
+  /// Answer of every image endpoint.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ImagesResponse
+  {
+    /// Unix timestamp of creation; absent from some servers' answers.
+    #[ serde( default ) ]
+    pub created : u64,
+
+    /// The images, in order.
+    pub data : Vec< ImageData >,
+
+    /// Fields not modelled above, e.g. `usage` of `gpt-image-1`.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  /// One generated image: a URL or base64 data, as requested.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  pub struct ImageData
+  {
+    /// Where to download the image.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub url : Option< String >,
+
+    /// The image, base64-encoded.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub b64_json : Option< String >,
+
+    /// The prompt the model actually drew, when it rewrote the given one.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub revised_prompt : Option< String >,
+
+    /// Fields not modelled above.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  impl ImageData
+  {
+    /// The image bytes of a `b64_json` answer.
+    ///
+    /// A URL answer is not fetched; download [`url`](Self::url) instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Environment` when the answer holds no base64 data or the
+    /// data is not valid base64.
+    #[ inline ]
+    pub fn decode( &self ) -> Result< Vec< u8 > >
+    {
+      let data = self.b64_json.as_deref()
+        .ok_or_else( || OpenAiCompatError::Environment( "image answer holds no b64_json data".to_owned() ) )?;
+      base64::engine::general_purpose::STANDARD.decode( data )
+        .map_err( | e | OpenAiCompatError::Environment( format!( "invalid b64_json image : {e}" ) ).into() )
+    }
+  }
+
+  impl< E > Client< E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    /// Generates images with `images/generations`.
+    ///
+    /// # Errors
+    ///
+    /// Returns network, timeout, or deserialisation errors, the classified
+    /// error for an error status, and `api_common::GuardrailViolation` when a guardrail
+    /// denies the request or the response.
+    #[ inline ]
+    pub async fn generate_images( &self, request : &ImageGenerationRequest ) -> Result< ImagesResponse >
+    {
+      self.post( "images/generations", request ).await
+    }
+
+    /// [`generate_images`](Self::generate_images) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`generate_images`](Self::generate_images), and
+    /// `api_common::Cancelled` when the cancellation token in `options` is
+    /// cancelled first.
+    #[ inline ]
+    pub async fn generate_images_with_options( &self, request : &ImageGenerationRequest, options : &RequestOptions ) -> Result< ImagesResponse >
+    {
+      self.post_with_options( "images/generations", request, options ).await
+    }
+
+    /// Edits images with `images/edits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns network, timeout, or deserialisation errors, and the
+    /// classified error for an error status.
+    #[ inline ]
+    pub async fn edit_images( &self, request : &ImageEditRequest ) -> Result< ImagesResponse >
+    {
+      self.edit_images_with_options( request, &RequestOptions::new() ).await
+    }
+
+    /// [`edit_images`](Self::edit_images) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`edit_images`](Self::edit_images), and
+    /// `api_common::Cancelled` when the call is cancelled.
+    #[ inline ]
+    pub async fn edit_images_with_options( &self, request : &ImageEditRequest, options : &RequestOptions ) -> Result< ImagesResponse >
+    {
+      self.post_multipart_with_options( "images/edits", &request.form(), options ).await
+    }
+
+    /// Creates variations of an image with `images/variations`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`edit_images`](Self::edit_images).
+    #[ inline ]
+    pub async fn image_variations( &self, request : &ImageVariationRequest ) -> Result< ImagesResponse >
+    {
+      self.image_variations_with_options( request, &RequestOptions::new() ).await
+    }
+
+    /// [`image_variations`](Self::image_variations) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`edit_images_with_options`](Self::edit_images_with_options).
+    #[ inline ]
+    pub async fn image_variations_with_options( &self, request : &ImageVariationRequest, options : &RequestOptions ) -> Result< ImagesResponse >
+    {
+      self.post_multipart_with_options( "images/variations", &request.form, options ).await
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ImageResponseFormat,
+    ImageGenerationRequest,
+    ImageEditRequest,
+    ImageVariationRequest,
+    ImagesResponse,
+    ImageData,
+  };
+}
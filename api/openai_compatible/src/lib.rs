@@ -7,10 +7,89 @@
 //! # Features
 //!
 //! - `enabled` — activates all public types and the HTTP client
-//! - `streaming` — Server-Sent Events streaming support
-//! - `sync_api` — blocking wrappers around the async client
+//! - `streaming` — Server-Sent Events chunk types, `ChunkDecoder`, and
+//!   `StreamAccumulator`
+//! - `sync_api` — blocking wrappers around the async client; with `streaming`,
+//!   a blocking iterator over streamed chunks
+//! - `common` — `From< api_common::NeutralMessage >` for `Message` and
+//!   `From< OpenAiCompatError >` for `api_common::LlmError`; with
+//!   `streaming`, `api_common::IntoStreamEvents` for `ChatCompletionChunk`
+//! - `rate_limiting` — `Client::with_rate_limits` and
+//!   `Client::with_shared_rate_limiter`, drawing requests from an
+//!   `api_common::SharedRateLimiter` of the client's own or shared with other
+//!   clients and charging it the tokens responses report, and
+//!   `Client::with_concurrency_gate`, capping in-flight requests
+//! - `retry` — `ExecuteWithRetries::retrying`, retrying a call on transient
+//!   failures with backoff per `RetryConfig`, honouring `Retry-After` and
+//!   an `api_common::CallBudget`
+//! - `idempotency` — `RequestOptions::idempotent` and `Client::with_idempotency_keys`,
+//!   generating `api_common::IdempotencyKey`s for writes
+//! - `cancellation` — `RequestOptions::with_cancellation`, ending a call when
+//!   its `api_common::CancellationToken` is cancelled
+//! - `guardrail` — `Client::with_guardrails`, running an `api_common::Guardrails`
+//!   chain over every request and response body
+//! - `schema` — `Message::parse_structured`, checking structured output against
+//!   the schema of its `ResponseFormat` before parsing it
+//! - `connection` — `Client::with_connection_tuning`, applying an
+//!   `api_common::ConnectionTuning` to the HTTP client
+//! - `tls` — `TlsConfig` on the environment: extra root certificates, a
+//!   client certificate for mTLS, and an explicit opt-in to skip certificate
+//!   checks; needs `rustls` or `native_tls`
+//! - `curl_diagnostics` — `Client::prepare_post` / `prepare_get` and
+//!   `PreparedRequest::to_curl`, rendering a request as a curl command with
+//!   credentials masked
+//! - `mock` — `MockTransport`, answering requests in process from canned
+//!   responses and recording them, for tests of code built on the client
+//! - `record_replay` — `Cassette`, recording real interactions to JSON files
+//!   with credentials scrubbed and replaying them without the network
+//! - `audio` — `Client::transcribe`, `translate`, and `speech`: multipart
+//!   uploads to `audio/transcriptions` and `audio/translations`, and speech
+//!   audio read as it arrives
+//! - `images` — `Client::generate_images`, `edit_images`, and
+//!   `image_variations`, with URL or base64 answers; edits and variations
+//!   upload their images as `MultipartForm`s
+//! - `batch_operations` — `BatchExecutor`, running many chat completions at
+//!   once under a concurrency limit and reporting each result, in input
+//!   order, with its timing
+//! - `http2` — HTTP/2 support forwarded to `reqwest`; not in `full`
 //! - `integration` — real-API integration tests (requires live credentials)
-//! - `full` — enables `enabled`, `streaming`, `sync_api`, and `integration`
+//! - `strict_wire` — `deny_unknown_fields` on response types and no `Role`
+//!   catch-all, so schema drift fails tests; for CI only, not in `full`
+//! - `rustls` / `native_tls` — TLS backend forwarded to `reqwest`
+//! - `full` — enables `enabled`, `streaming`, `sync_api`, `common`, `rate_limiting`, `retry`, `guardrail`, `schema`, `idempotency`, `cancellation`, `connection`, `tls`, `curl_diagnostics`, `mock`, `record_replay`, `audio`, `images`, `batch_operations`, `integration`, and `rustls`
+//!
+//! # Provider Profiles
+//!
+//! `UrlScheme::AzureDeployment`, set on the environment, targets an Azure
+//! `OpenAI` deployment: deployment URLs with `api-version`, and the key in
+//! `api-key`.
+//! `GitHubModelsEnvironment` targets GitHub Models, on its Azure AI inference
+//! host or the GitHub-hosted endpoint, with the headers each expects.
+//! `ProviderQuirks` checks a request against the rules Mistral, Groq, or
+//! `DeepSeek` enforce beyond the `OpenAI` schema, before it is sent.
+//! `SelfHostedProfile` targets vLLM, llama.cpp, and `LiteLLM` servers, passing
+//! extra sampling parameters through and normalising their responses.
+//! `ProviderProfile`, set on the environment, repairs the responses of Groq,
+//! Together, vLLM, and LM Studio before they are deserialised.
+//!
+//! # Hooks
+//!
+//! `Client::with_request_hook` and `Client::with_response_hook` add closures
+//! that amend the headers of every request and observe every raw response,
+//! e.g. to inject tracing ids or record latency.
+//!
+//! # Rate-Limit Headers
+//!
+//! `Client::post_with_rate_limits` and `get_with_rate_limits` return the
+//! `x-ratelimit-*` and `Retry-After` headers of the response as a
+//! `RateLimitInfo` with its value, and a `429` carries them in
+//! `OpenAiCompatError::RateLimited`, for callers that pace themselves.
+//!
+//! # WebAssembly
+//!
+//! The async client compiles for `wasm32-unknown-unknown`, where `reqwest`
+//! uses the host `fetch` API (browsers, Cloudflare Workers). `sync_api` is
+//! native-only and is compiled out on `wasm32` even when the feature is on.
 //!
 //! # Architecture
 //!
@@ -29,16 +108,67 @@ crate::mod_interface!
   /// Error type and result alias.
   layer error;
 
+  /// Rate-limit state read from response headers.
+  layer rate_limit_info;
+
   /// Wire types for chat completion requests, responses, and streaming.
   layer components;
 
+  /// TLS settings for gateways with their own certificate authority.
+  #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+  layer tls;
+
+  /// curl commands for requests as the client would send them.
+  #[ cfg( feature = "curl_diagnostics" ) ]
+  layer curl_diagnostics;
+
+  /// In-process transport with canned responses, for tests.
+  #[ cfg( all( feature = "mock", not( target_arch = "wasm32" ) ) ) ]
+  layer mock;
+
+  /// Record/replay of HTTP interactions to JSON cassettes.
+  #[ cfg( all( feature = "record_replay", not( target_arch = "wasm32" ) ) ) ]
+  layer record_replay;
+
+  /// `multipart/form-data` bodies for file uploads.
+  #[ cfg( any( feature = "audio", feature = "images" ) ) ]
+  layer multipart;
+
   /// Environment configuration trait and default implementation.
   layer environment;
 
+  /// Ready-made environments for specific providers.
+  layer profile;
+
+  /// Per-request options.
+  layer request_options;
+
+  /// Request and response hooks.
+  layer hooks;
+
   /// Async HTTP client.
   layer client;
 
+  /// Model listing and retrieval endpoints.
+  layer models;
+
+  /// Retries with backoff for client calls.
+  #[ cfg( feature = "retry" ) ]
+  layer retry;
+
+  /// Transcription, translation, and speech endpoints.
+  #[ cfg( feature = "audio" ) ]
+  layer audio;
+
+  /// Image generation, edit, and variation endpoints.
+  #[ cfg( feature = "images" ) ]
+  layer images;
+
+  /// Concurrent batches of requests under a concurrency limit.
+  #[ cfg( feature = "batch_operations" ) ]
+  layer batch_operations;
+
   /// Blocking wrapper around the async client.
-  #[ cfg( feature = "sync_api" ) ]
+  #[ cfg( all( feature = "sync_api", not( target_arch = "wasm32" ) ) ) ]
   layer sync_client;
 }
@@ -0,0 +1,451 @@
+//! In-process transport with canned responses, for testing code built on
+//! the client.
+//!
+//! A [`MockTransport`] holds routes — a [`RequestMatcher`] and the
+//! [`MockResponse`]s to answer with — and records every request it sees.
+//! A client given one with
+//! [`Client::with_mock_transport`](crate::Client::with_mock_transport) sends
+//! nothing over the network: requests are built as usual, so hooks,
+//! guardrails, idempotency keys, retries, and streaming all run, and then
+//! handed to the transport instead of a connection.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::environment::OpenAiCompatEnvironmentImpl;
+  use reqwest::header::{ HeaderMap, HeaderName, HeaderValue };
+  use reqwest::{ Method, StatusCode };
+  use serde_json::Value;
+  use std::collections::VecDeque;
+  use std::sync::{ Arc, Mutex, PoisonError };
+
+  /// Which requests a route answers.
+  ///
+  /// Every condition set must hold. Paths match the end of the URL path, so
+  /// `"chat/completions"` matches whatever the base URL is.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct RequestMatcher
+  {
+    method : Option< Method >,
+    path : Option< String >,
+    headers : Vec< ( HeaderName, HeaderValue ) >,
+    json_fields : Vec< ( String, Value ) >,
+  }
+
+  impl RequestMatcher
+  {
+    /// Matches every request.
+    #[ inline ]
+    #[ must_use ]
+    pub fn any() -> Self
+    {
+      Self::default()
+    }
+
+    /// Matches POSTs whose URL path ends with `path`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn post( path : impl Into< String > ) -> Self
+    {
+      Self { method : Some( Method::POST ), path : Some( path.into() ), ..Self::default() }
+    }
+
+    /// Matches GETs whose URL path ends with `path`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn get( path : impl Into< String > ) -> Self
+    {
+      Self { method : Some( Method::GET ), path : Some( path.into() ), ..Self::default() }
+    }
+
+    /// Also requires header `name` to be `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` or `value` is not a valid header.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_header( mut self, name : &str, value : &str ) -> Self
+    {
+      let name = HeaderName::try_from( name ).expect( "matcher header name must be valid" );
+      let value = HeaderValue::try_from( value ).expect( "matcher header value must be valid" );
+      self.headers.push( ( name, value ) );
+      self
+    }
+
+    /// Also requires a JSON body whose member at `pointer` (RFC 6901, e.g.
+    /// `"/model"` or `"/messages/0/role"`) equals `value`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_json_field( mut self, pointer : impl Into< String >, value : impl Into< Value > ) -> Self
+    {
+      self.json_fields.push( ( pointer.into(), value.into() ) );
+      self
+    }
+
+    /// Whether `request` meets every condition.
+    #[ inline ]
+    #[ must_use ]
+    pub fn matches( &self, request : &RecordedRequest ) -> bool
+    {
+      if self.method.as_ref().is_some_and( | method | *method != request.method )
+      {
+        return false;
+      }
+      if let Some( path ) = &self.path
+      {
+        let wanted = format!( "/{}", path.trim_start_matches( '/' ) );
+        let actual = reqwest::Url::parse( &request.url ).map( | url | url.path().to_string() ).unwrap_or_default();
+        if !actual.ends_with( &wanted )
+        {
+          return false;
+        }
+      }
+      if !self.headers.iter().all( | ( name, value ) | request.headers.get( name ) == Some( value ) )
+      {
+        return false;
+      }
+      if self.json_fields.is_empty()
+      {
+        return true;
+      }
+      let Some( body ) = request.json() else { return false };
+      self.json_fields.iter().all( | ( pointer, value ) | body.pointer( pointer ) == Some( value ) )
+    }
+  }
+
+  /// A canned HTTP response.
+  #[ derive( Debug, Clone ) ]
+  pub struct MockResponse
+  {
+    status : StatusCode,
+    headers : HeaderMap,
+    body : Vec< u8 >,
+  }
+
+  impl MockResponse
+  {
+    /// An empty response with `status`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( status : StatusCode ) -> Self
+    {
+      Self { status, headers : HeaderMap::new(), body : Vec::new() }
+    }
+
+    /// `body` as JSON with `status`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn json( status : StatusCode, body : &Value ) -> Self
+    {
+      Self::new( status )
+        .with_header( "content-type", "application/json" )
+        .with_body( body.to_string() )
+    }
+
+    /// A `200` chat completion carrying `response`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `response` cannot be serialised, which the wire types rule out.
+    #[ inline ]
+    #[ must_use ]
+    pub fn chat( response : &crate::components::ChatCompletionResponse ) -> Self
+    {
+      Self::json( StatusCode::OK, &serde_json::to_value( response ).expect( "chat completion must serialise" ) )
+    }
+
+    /// A `200` chat completion from `model` whose single choice is an
+    /// assistant message with `content`, finished with `stop`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn chat_text( model : &str, content : &str ) -> Self
+    {
+      Self::json( StatusCode::OK, &serde_json::json!(
+      {
+        "id" : "chatcmpl-mock",
+        "object" : "chat.completion",
+        "created" : 0,
+        "model" : model,
+        "choices" : [ { "index" : 0, "message" : { "role" : "assistant", "content" : content }, "finish_reason" : "stop" } ],
+        "usage" : { "prompt_tokens" : 0, "completion_tokens" : 0, "total_tokens" : 0 },
+      } ) )
+    }
+
+    /// An error response with `status` and an `OpenAI`-style body
+    /// `{"error":{"message":…,"type":"mock_error"}}`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn error( status : StatusCode, message : &str ) -> Self
+    {
+      Self::json( status, &serde_json::json!( { "error" : { "message" : message, "type" : "mock_error" } } ) )
+    }
+
+    /// A `200` event stream with one `data:` event per entry of `events`,
+    /// written verbatim; no `[DONE]` marker is added.
+    #[ inline ]
+    #[ must_use ]
+    pub fn sse< S >( events : impl IntoIterator< Item = S > ) -> Self
+    where
+      S : AsRef< str >,
+    {
+      let mut body = String::new();
+      for event in events
+      {
+        body.push_str( "data: " );
+        body.push_str( event.as_ref() );
+        body.push_str( "\n\n" );
+      }
+      Self::new( StatusCode::OK )
+        .with_header( "content-type", "text/event-stream" )
+        .with_body( body )
+    }
+
+    /// A `200` event stream of `chunks`, then `[DONE]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a chunk cannot be serialised, which the wire types rule out.
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn chat_stream( chunks : &[ crate::components::ChatCompletionChunk ] ) -> Self
+    {
+      let events = chunks.iter()
+        .map( | chunk | serde_json::to_string( chunk ).expect( "chunk must serialise" ) )
+        .chain( core::iter::once( "[DONE]".to_string() ) );
+      Self::sse( events )
+    }
+
+    /// A `200` event stream from `model` streaming `pieces` as content: a
+    /// chunk with the assistant role, one chunk per piece, a chunk finished
+    /// with `stop`, then `[DONE]`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text_stream< S >( model : &str, pieces : impl IntoIterator< Item = S > ) -> Self
+    where
+      S : AsRef< str >,
+    {
+      let chunk = | delta : Value, finish_reason : Option< &str > | serde_json::json!(
+      {
+        "id" : "chatcmpl-mock",
+        "object" : "chat.completion.chunk",
+        "created" : 0,
+        "model" : model,
+        "choices" : [ { "index" : 0, "delta" : delta, "finish_reason" : finish_reason } ],
+      } ).to_string();
+      let mut events = vec![ chunk( serde_json::json!( { "role" : "assistant" } ), None ) ];
+      events.extend( pieces.into_iter().map( | piece | chunk( serde_json::json!( { "content" : piece.as_ref() } ), None ) ) );
+      events.push( chunk( serde_json::json!( {} ), Some( "stop" ) ) );
+      events.push( "[DONE]".to_string() );
+      Self::sse( events )
+    }
+
+    /// Adds header `name: value`, e.g. `Retry-After`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` or `value` is not a valid header.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_header( mut self, name : &str, value : &str ) -> Self
+    {
+      let name = HeaderName::try_from( name ).expect( "response header name must be valid" );
+      let value = HeaderValue::try_from( value ).expect( "response header value must be valid" );
+      self.headers.append( name, value );
+      self
+    }
+
+    /// Replaces the body.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_body( mut self, body : impl Into< Vec< u8 > > ) -> Self
+    {
+      self.body = body.into();
+      self
+    }
+
+    fn into_response( self ) -> reqwest::Response
+    {
+      let mut response = http::Response::new( self.body );
+      *response.status_mut() = self.status;
+      *response.headers_mut() = self.headers;
+      reqwest::Response::from( response )
+    }
+  }
+
+  /// A request the transport received, as the client would have sent it.
+  #[ derive( Debug, Clone ) ]
+  pub struct RecordedRequest
+  {
+    /// HTTP method.
+    pub method : Method,
+    /// Full request URL.
+    pub url : String,
+    /// Headers, credentials included, after request hooks ran.
+    pub headers : HeaderMap,
+    /// Body bytes; empty for a GET.
+    pub body : Vec< u8 >,
+  }
+
+  impl RecordedRequest
+  {
+    /// The body parsed as JSON; `None` when empty or not JSON.
+    #[ inline ]
+    #[ must_use ]
+    pub fn json( &self ) -> Option< Value >
+    {
+      serde_json::from_slice( &self.body ).ok()
+    }
+  }
+
+  /// One route: requests it matches get its responses in order, the last
+  /// one repeating.
+  #[ derive( Debug ) ]
+  struct Route
+  {
+    matcher : RequestMatcher,
+    responses : VecDeque< MockResponse >,
+  }
+
+  #[ derive( Debug, Default ) ]
+  struct State
+  {
+    routes : Vec< Route >,
+    requests : Vec< RecordedRequest >,
+  }
+
+  /// Transport answering requests from canned responses instead of the
+  /// network.
+  ///
+  /// Routes are tried in the order they were added; the first whose matcher
+  /// accepts the request answers it. A request no route matches gets a `404`
+  /// naming its method and URL, which the client reports as `InvalidRequest`.
+  /// Clones share routes and recorded requests, so a test can keep one and
+  /// give another to the client.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "mock" ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionResponse, MockResponse, MockTransport, RequestMatcher };
+  ///
+  /// # #[ tokio::main ]
+  /// # async fn main() {
+  /// let mock = MockTransport::new()
+  ///   .on( RequestMatcher::post( "chat/completions" ).with_json_field( "/model", "qwen2.5" ), MockResponse::chat_text( "qwen2.5", "Hi!" ) );
+  /// let client = mock.client();
+  ///
+  /// let request = serde_json::json!( { "model" : "qwen2.5", "messages" : [ { "role" : "user", "content" : "Hello" } ] } );
+  /// let response : ChatCompletionResponse = client.post( "chat/completions", &request ).await.unwrap();
+  ///
+  /// assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "Hi!" ) );
+  /// assert_eq!( mock.requests()[ 0 ].json(), Some( request ) );
+  /// # }
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct MockTransport
+  {
+    state : Arc< Mutex< State > >,
+  }
+
+  impl MockTransport
+  {
+    /// A transport with no routes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Answers requests `matcher` accepts with `response`, every time.
+    #[ inline ]
+    #[ must_use ]
+    pub fn on( self, matcher : RequestMatcher, response : MockResponse ) -> Self
+    {
+      self.on_sequence( matcher, [ response ] )
+    }
+
+    /// Answers requests `matcher` accepts with `responses` in order, then
+    /// with the last one for good; e.g. a `429` and then a success, to
+    /// exercise retries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `responses` is empty.
+    #[ inline ]
+    #[ must_use ]
+    pub fn on_sequence( self, matcher : RequestMatcher, responses : impl IntoIterator< Item = MockResponse > ) -> Self
+    {
+      let responses : VecDeque< MockResponse > = responses.into_iter().collect();
+      assert!( !responses.is_empty(), "a route needs at least one response" );
+      self.lock().routes.push( Route { matcher, responses } );
+      self
+    }
+
+    /// Every request received so far, oldest first.
+    #[ inline ]
+    #[ must_use ]
+    pub fn requests( &self ) -> Vec< RecordedRequest >
+    {
+      self.lock().requests.clone()
+    }
+
+    /// A client using this transport, with key `sk-mock` and base URL
+    /// `http://mock.invalid/v1/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client cannot be built, which only a broken TLS
+    /// backend causes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn client( &self ) -> Client< OpenAiCompatEnvironmentImpl >
+    {
+      let env = OpenAiCompatEnvironmentImpl::new( "sk-mock" )
+        .expect( "mock key is valid" )
+        .with_base_url( "http://mock.invalid/v1/" );
+      Client::build( env ).expect( "mock client must build" ).with_mock_transport( self.clone() )
+    }
+
+    /// Records `request` and answers it from the first matching route.
+    pub( crate ) fn respond( &self, request : &reqwest::Request ) -> reqwest::Response
+    {
+      let recorded = RecordedRequest
+      {
+        method : request.method().clone(),
+        url : request.url().to_string(),
+        headers : request.headers().clone(),
+        body : request.body().and_then( reqwest::Body::as_bytes ).map( < [ u8 ] >::to_vec ).unwrap_or_default(),
+      };
+      let mut state = self.lock();
+      let response = match state.routes.iter_mut().find( | route | route.matcher.matches( &recorded ) )
+      {
+        Some( route ) if route.responses.len() > 1 => route.responses.pop_front().expect( "route has several responses" ),
+        Some( route ) => route.responses[ 0 ].clone(),
+        None => MockResponse::error( StatusCode::NOT_FOUND, &format!( "mock transport : no route matches {} {}", recorded.method, recorded.url ) ),
+      };
+      state.requests.push( recorded );
+      response.into_response()
+    }
+
+    fn lock( &self ) -> std::sync::MutexGuard< '_, State >
+    {
+      self.state.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    MockTransport,
+    MockResponse,
+    RequestMatcher,
+    RecordedRequest,
+  };
+}
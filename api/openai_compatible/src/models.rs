@@ -0,0 +1,218 @@
+//! Model listing and retrieval: `models` and `models/{id}`.
+//!
+//! Every OpenAI-compatible server lists its models, but the answers differ
+//! around the edges: Together returns a bare array, paginating servers add
+//! `has_more`, `first_id`, and `last_id`, and self-hosted servers leave out
+//! `created` or `owned_by` and add metadata of their own. [`ListModelsResponse`]
+//! and [`Model`] read all of these; what they do not model is kept in
+//! `extra`, also under `strict_wire`, since model metadata is server-specific
+//! by design.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::Result;
+  use crate::request_options::RequestOptions;
+  use serde::{ Deserialize, Serialize };
+  use serde_json::{ Map, Value };
+
+  /// One model a server offers.
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  pub struct Model
+  {
+    /// Model identifier, as sent in requests.
+    pub id : String,
+
+    /// Object kind, `model`; filled in when a server omits it.
+    #[ serde( default = "model_object" ) ]
+    pub object : String,
+
+    /// Unix timestamp of creation; `0` when a server omits it.
+    #[ serde( default ) ]
+    pub created : u64,
+
+    /// Owning organisation; empty when a server omits it.
+    #[ serde( default ) ]
+    pub owned_by : String,
+
+    /// Server-specific metadata, e.g. vLLM's `max_model_len` or Together's
+    /// `context_length`.
+    #[ serde( flatten ) ]
+    pub extra : Map< String, Value >,
+  }
+
+  impl Model
+  {
+    /// Path of `models/{id}` for `model_id`, relative to the base URL, for
+    /// clients other than [`Client`] that call the endpoint themselves.
+    #[ inline ]
+    #[ must_use ]
+    pub fn path( model_id : &str ) -> String
+    {
+      format!( "{}/{model_id}", ListModelsResponse::PATH )
+    }
+  }
+
+  /// Answer of `models`.
+  ///
+  /// Reads the `OpenAI` `{ "object" : "list", "data" : [ .. ] }` shape with or
+  /// without pagination fields, and a bare array of models.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::ListModelsResponse;
+  ///
+  /// let together : ListModelsResponse = serde_json::from_str( r#"[ { "id" : "meta-llama/Llama-3-8b", "type" : "chat" } ]"# ).unwrap();
+  /// assert_eq!( together.object, "list" );
+  /// assert_eq!( together.data[ 0 ].object, "model" );
+  /// assert_eq!( together.data[ 0 ].extra[ "type" ], "chat" );
+  /// assert_eq!( together.has_more, None );
+  /// # }
+  /// ```
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq, Default ) ]
+  #[ serde( from = "ListModelsWire" ) ]
+  pub struct ListModelsResponse
+  {
+    /// Object kind, `list`.
+    pub object : String,
+
+    /// The models, in the order the server lists them.
+    pub data : Vec< Model >,
+
+    /// Whether a further page follows, when the server paginates.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub has_more : Option< bool >,
+
+    /// Id of the first model of this page, when the server paginates.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub first_id : Option< String >,
+
+    /// Id of the last model of this page, when the server paginates.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub last_id : Option< String >,
+  }
+
+  impl ListModelsResponse
+  {
+    /// Path of the endpoint, relative to the base URL.
+    pub const PATH : &'static str = "models";
+
+    /// Whether the list holds a model with id `model_id`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn contains( &self, model_id : &str ) -> bool
+    {
+      self.data.iter().any( | model | model.id == model_id )
+    }
+  }
+
+  /// The shapes `models` comes in.
+  #[ derive( Deserialize ) ]
+  #[ serde( untagged ) ]
+  enum ListModelsWire
+  {
+    Bare( Vec< Model > ),
+    Page
+    {
+      #[ serde( default = "list_object" ) ]
+      object : String,
+      #[ serde( default ) ]
+      data : Vec< Model >,
+      #[ serde( default ) ]
+      has_more : Option< bool >,
+      #[ serde( default ) ]
+      first_id : Option< String >,
+      #[ serde( default ) ]
+      last_id : Option< String >,
+    },
+  }
+
+  impl From< ListModelsWire > for ListModelsResponse
+  {
+    #[ inline ]
+    fn from( wire : ListModelsWire ) -> Self
+    {
+      match wire
+      {
+        ListModelsWire::Bare( data ) => Self { object : list_object(), data, ..Self::default() },
+        ListModelsWire::Page { object, data, has_more, first_id, last_id } => Self { object, data, has_more, first_id, last_id },
+      }
+    }
+  }
+
+  fn model_object() -> String
+  {
+    "model".to_owned()
+  }
+
+  fn list_object() -> String
+  {
+    "list".to_owned()
+  }
+
+  impl< E > Client< E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    /// Lists the models the server offers, with `models`.
+    ///
+    /// # Errors
+    ///
+    /// Returns network, timeout, or deserialisation errors, and the
+    /// classified error for an error status.
+    #[ inline ]
+    pub async fn list_models( &self ) -> Result< ListModelsResponse >
+    {
+      self.get( ListModelsResponse::PATH ).await
+    }
+
+    /// [`list_models`](Self::list_models) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`list_models`](Self::list_models), and
+    /// `api_common::Cancelled` when the call is cancelled.
+    #[ inline ]
+    pub async fn list_models_with_options( &self, options : &RequestOptions ) -> Result< ListModelsResponse >
+    {
+      self.get_with_options( ListModelsResponse::PATH, options ).await
+    }
+
+    /// Retrieves one model, with `models/{id}`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`list_models`](Self::list_models); an unknown model is
+    /// `InvalidRequest` with status `404`.
+    #[ inline ]
+    pub async fn retrieve_model( &self, model_id : &str ) -> Result< Model >
+    {
+      self.get( &Model::path( model_id ) ).await
+    }
+
+    /// [`retrieve_model`](Self::retrieve_model) with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`retrieve_model`](Self::retrieve_model), and
+    /// `api_common::Cancelled` when the call is cancelled.
+    #[ inline ]
+    pub async fn retrieve_model_with_options( &self, model_id : &str, options : &RequestOptions ) -> Result< Model >
+    {
+      self.get_with_options( &Model::path( model_id ), options ).await
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Model,
+    ListModelsResponse,
+  };
+}
@@ -0,0 +1,289 @@
+//! `multipart/form-data` bodies for file uploads.
+//!
+//! Audio transcription and image edits upload files as forms. A
+//! [`MultipartForm`] is encoded in memory, so the client sends it through
+//! the same path as JSON: hooks, idempotency keys, rate limits, the mock
+//! transport, and cassettes all see the whole body.
+
+mod private
+{
+  /// A file to upload: its name, bytes, and media type.
+  #[ derive( Clone, PartialEq, Eq ) ]
+  pub struct UploadFile
+  {
+    file_name : String,
+    bytes : Vec< u8 >,
+    content_type : String,
+  }
+
+  impl core::fmt::Debug for UploadFile
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "UploadFile" )
+        .field( "file_name", &self.file_name )
+        .field( "bytes", &self.bytes.len() )
+        .field( "content_type", &self.content_type )
+        .finish()
+    }
+  }
+
+  impl UploadFile
+  {
+    /// `bytes` uploaded as `file_name`. The media type follows the
+    /// extension — audio (`mp3`, `mp4`, `mpeg`, `mpga`, `m4a`, `wav`,
+    /// `webm`, `ogg`, `oga`, `flac`) and images (`png`, `jpg`, `jpeg`,
+    /// `webp`, `gif`) — else `application/octet-stream`.
+    #[ inline ]
+    pub fn new( file_name : impl Into< String >, bytes : impl Into< Vec< u8 > > ) -> Self
+    {
+      let file_name = file_name.into();
+      let extension = file_name.rsplit_once( '.' ).map( | ( _, extension ) | extension.to_ascii_lowercase() ).unwrap_or_default();
+      let content_type = match extension.as_str()
+      {
+        "mp3" | "mpga" | "mpeg" => "audio/mpeg",
+        "mp4" | "m4a" => "audio/mp4",
+        "wav" => "audio/wav",
+        "webm" => "audio/webm",
+        "ogg" | "oga" => "audio/ogg",
+        "flac" => "audio/flac",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        _ => "application/octet-stream",
+      };
+      Self { file_name, bytes : bytes.into(), content_type : content_type.to_owned() }
+    }
+
+    /// Overrides the media type sent with the file.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_content_type( mut self, content_type : impl Into< String > ) -> Self
+    {
+      self.content_type = content_type.into();
+      self
+    }
+
+    /// File name sent with the upload.
+    #[ inline ]
+    #[ must_use ]
+    pub fn file_name( &self ) -> &str
+    {
+      &self.file_name
+    }
+
+    /// The file's bytes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn bytes( &self ) -> &[ u8 ]
+    {
+      &self.bytes
+    }
+
+    /// Media type sent with the file.
+    #[ inline ]
+    #[ must_use ]
+    pub fn content_type( &self ) -> &str
+    {
+      &self.content_type
+    }
+  }
+
+  /// Text fields and files of a `multipart/form-data` body.
+  ///
+  /// Text fields are sent first, then files, each group in the order
+  /// added; names may repeat, as `image[]` does for several images.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "audio" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, MultipartForm, OpenAiCompatEnvironmentImpl, UploadFile };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+  /// let form = MultipartForm::new()
+  ///   .with_text( "purpose", "batch" )
+  ///   .with_file( "file", UploadFile::new( "requests.jsonl", std::fs::read( "requests.jsonl" )? ) );
+  /// let uploaded : serde_json::Value = client.post_multipart( "files", &form ).await?;
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq, Default ) ]
+  pub struct MultipartForm
+  {
+    texts : Vec< ( String, String ) >,
+    files : Vec< ( String, UploadFile ) >,
+  }
+
+  impl MultipartForm
+  {
+    /// An empty form.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Adds the text field `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_text( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.texts.push( ( name.into(), value.into() ) );
+      self
+    }
+
+    /// Adds `file` as the field `name`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_file( mut self, name : impl Into< String >, file : UploadFile ) -> Self
+    {
+      self.files.push( ( name.into(), file ) );
+      self
+    }
+
+    /// Replaces every text field `name` with one holding `value`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_text_replaced( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      let name = name.into();
+      self.texts.retain( | ( existing, _ ) | *existing != name );
+      self.with_text( name, value )
+    }
+
+    /// Text fields, in the order they are sent.
+    #[ inline ]
+    #[ must_use ]
+    pub fn texts( &self ) -> &[ ( String, String ) ]
+    {
+      &self.texts
+    }
+
+    /// Files with their field names, in the order they are sent.
+    #[ inline ]
+    #[ must_use ]
+    pub fn files( &self ) -> &[ ( String, UploadFile ) ]
+    {
+      &self.files
+    }
+
+    /// The content type with its boundary, and the body.
+    ///
+    /// The boundary is fixed unless it occurs in the content, so the same
+    /// form always encodes to the same bytes and replays from a cassette.
+    pub( crate ) fn encode( &self ) -> ( String, Vec< u8 > )
+    {
+      let mut boundary = "api-openai-compatible-form".to_owned();
+      let mut attempt = 0_u32;
+      while self.contains( &boundary )
+      {
+        attempt += 1;
+        boundary = format!( "api-openai-compatible-form-{attempt}" );
+      }
+
+      let size = self.files.iter().map( | ( _, file ) | file.bytes.len() ).sum::< usize >();
+      let mut body = Vec::with_capacity( size + 256 * ( self.texts.len() + self.files.len() ) );
+      for ( name, value ) in &self.texts
+      {
+        body.extend_from_slice( format!( "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n", escape( name ) ).as_bytes() );
+        body.extend_from_slice( value.as_bytes() );
+        body.extend_from_slice( b"\r\n" );
+      }
+      for ( name, file ) in &self.files
+      {
+        body.extend_from_slice( format!
+        (
+          "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+          escape( name ),
+          escape( &file.file_name ),
+          file.content_type,
+        ).as_bytes() );
+        body.extend_from_slice( &file.bytes );
+        body.extend_from_slice( b"\r\n" );
+      }
+      body.extend_from_slice( format!( "--{boundary}--\r\n" ).as_bytes() );
+      ( format!( "multipart/form-data; boundary={boundary}" ), body )
+    }
+
+    fn contains( &self, boundary : &str ) -> bool
+    {
+      let needle = boundary.as_bytes();
+      self.texts.iter().any( | ( name, value ) | name.contains( boundary ) || value.contains( boundary ) )
+        || self.files.iter().any( | ( name, file ) | name.contains( boundary ) || file.bytes.windows( needle.len() ).any( | window | window == needle ) )
+    }
+  }
+
+  /// Percent-encodes what would end a quoted header parameter, as browsers do.
+  fn escape( value : &str ) -> String
+  {
+    value.replace( '"', "%22" ).replace( '\r', "%0D" ).replace( '\n', "%0A" )
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    /// Bytes that are not UTF-8 and hold a CRLF, as real audio does.
+    const AUDIO : &[ u8 ] = &[ 0x49, 0x44, 0x33, 0xff, 0xfe, 0x00, b'\r', b'\n', 0x80 ];
+
+    #[ test ]
+    fn fields_come_first_and_file_bytes_stay_intact()
+    {
+      let form = MultipartForm::new()
+        .with_text( "model", "whisper-1" )
+        .with_file( "file", UploadFile::new( "clip.MP3", AUDIO ) )
+        .with_text( "language", "en" );
+
+      let ( content_type, body ) = form.encode();
+
+      assert_eq!( content_type, "multipart/form-data; boundary=api-openai-compatible-form" );
+      let mut expected = Vec::new();
+      for ( name, value ) in [ ( "model", "whisper-1" ), ( "language", "en" ) ]
+      {
+        expected.extend_from_slice( format!( "--api-openai-compatible-form\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n" ).as_bytes() );
+      }
+      expected.extend_from_slice( b"--api-openai-compatible-form\r\nContent-Disposition: form-data; name=\"file\"; filename=\"clip.MP3\"\r\nContent-Type: audio/mpeg\r\n\r\n" );
+      expected.extend_from_slice( AUDIO );
+      expected.extend_from_slice( b"\r\n--api-openai-compatible-form--\r\n" );
+      assert_eq!( body, expected );
+      assert_eq!( form.encode(), ( content_type, body ), "the same form must encode to the same bytes" );
+    }
+
+    #[ test ]
+    fn boundary_in_the_content_is_avoided()
+    {
+      let form = MultipartForm::new()
+        .with_text( "note", "--api-openai-compatible-form" )
+        .with_file( "file", UploadFile::new( "clip.wav", "--api-openai-compatible-form-1" ) );
+
+      let ( content_type, _ ) = form.encode();
+
+      assert_eq!( content_type, "multipart/form-data; boundary=api-openai-compatible-form-2" );
+    }
+
+    #[ test ]
+    fn quoted_parameters_are_escaped()
+    {
+      let ( _, body ) = MultipartForm::new().with_file( "image[]", UploadFile::new( "a\"b\r\n.png", Vec::new() ) ).encode();
+
+      let body = String::from_utf8( body ).unwrap();
+      assert!( body.contains( "name=\"image[]\"; filename=\"a%22b%0D%0A.png\"\r\nContent-Type: image/png\r\n" ), "{body:?}" );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    UploadFile,
+    MultipartForm,
+  };
+}
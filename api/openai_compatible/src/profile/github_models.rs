@@ -0,0 +1,190 @@
+//! Environment for GitHub Models.
+//!
+//! GitHub Models serves free-tier chat models to any GitHub account, and
+//! authenticates with a GitHub token instead of a provider key. It is
+//! reachable at two hosts:
+//!
+//! - `models.inference.ai.azure.com` — the Azure AI inference endpoint the
+//!   service launched on. Chat completions are at the host root (no `v1/`)
+//!   and models are named without a publisher (`gpt-4o-mini`).
+//! - `models.github.ai/inference` — the GitHub-hosted endpoint. It expects
+//!   GitHub's REST `Accept` and `X-GitHub-Api-Version` headers, names models
+//!   `publisher/model` (`openai/gpt-4o-mini`), and can bill usage to an
+//!   organization under `orgs/{org}/inference`.
+
+mod private
+{
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::{ OpenAiCompatError, Result };
+  use core::time::Duration;
+  use reqwest::header;
+
+  /// Environment preset for GitHub Models.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, GitHubModelsEnvironment };
+  ///
+  /// // Azure AI inference host; model names without a publisher, e.g. "gpt-4o-mini".
+  /// let client = Client::build( GitHubModelsEnvironment::new( "ghp_..." ).unwrap() ).unwrap();
+  ///
+  /// // GitHub-hosted endpoint billed to an organization; model names like "openai/gpt-4o-mini".
+  /// let env = GitHubModelsEnvironment::new( "ghp_..." ).unwrap().with_organization( "my-org" );
+  /// # }
+  /// ```
+  #[ derive( Clone ) ]
+  pub struct GitHubModelsEnvironment
+  {
+    /// GitHub token, surrounding whitespace removed.
+    token : String,
+    /// Base URL including trailing slash.
+    base_url : String,
+    /// Whether the GitHub REST headers are sent.
+    github_headers : bool,
+    /// Per-request timeout.
+    timeout : Duration,
+  }
+
+  impl core::fmt::Debug for GitHubModelsEnvironment
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "GitHubModelsEnvironment" )
+        .field( "token", &api_secret::Masked( &self.token ) )
+        .field( "base_url", &self.base_url )
+        .field( "github_headers", &self.github_headers )
+        .field( "timeout", &self.timeout )
+        .finish()
+    }
+  }
+
+  impl GitHubModelsEnvironment
+  {
+    /// Azure AI inference endpoint of GitHub Models.
+    pub const AZURE_BASE_URL : &'static str = "https://models.inference.ai.azure.com/";
+
+    /// GitHub-hosted endpoint of GitHub Models.
+    pub const GITHUB_BASE_URL : &'static str = "https://models.github.ai/inference/";
+
+    /// GitHub REST API version sent to the GitHub-hosted endpoint.
+    pub const API_VERSION : &'static str = "2022-11-28";
+
+    /// Default request timeout in seconds; free-tier requests queue behind
+    /// paid traffic and often take longer than the generic default.
+    pub const DEFAULT_TIMEOUT_SECS : u64 = 60;
+
+    /// Creates an environment for the Azure AI inference endpoint.
+    ///
+    /// `token` is a GitHub token with the `models` permission (a fine-grained
+    /// PAT) or a classic PAT. Surrounding whitespace is removed, since tokens
+    /// read from `gh auth token` or a file usually end with a newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Authentication` if `token` is empty or
+    /// whitespace-only.
+    #[ inline ]
+    pub fn new( token : impl Into< String > ) -> Result< Self >
+    {
+      let token = token.into().trim().to_owned();
+      if token.is_empty()
+      {
+        return Err
+        (
+          OpenAiCompatError::invalid_key( "GitHub token must not be empty or whitespace-only" ).into()
+        );
+      }
+      Ok( Self
+      {
+        token,
+        base_url : Self::AZURE_BASE_URL.to_owned(),
+        github_headers : false,
+        timeout : Duration::from_secs( Self::DEFAULT_TIMEOUT_SECS ),
+      })
+    }
+
+    /// Switches to the GitHub-hosted endpoint, sending the GitHub REST
+    /// headers it expects. Model names then take a publisher prefix.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_github_endpoint( mut self ) -> Self
+    {
+      Self::GITHUB_BASE_URL.clone_into( &mut self.base_url );
+      self.github_headers = true;
+      self
+    }
+
+    /// Switches to the GitHub-hosted endpoint and bills usage to `org`
+    /// instead of the token's user.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_organization( mut self, org : &str ) -> Self
+    {
+      self.base_url = format!( "https://models.github.ai/orgs/{org}/inference/" );
+      self.github_headers = true;
+      self
+    }
+
+    /// Overrides the request timeout.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_timeout( mut self, timeout : Duration ) -> Self
+    {
+      self.timeout = timeout;
+      self
+    }
+  }
+
+  impl OpenAiCompatEnvironment for GitHubModelsEnvironment
+  {
+    #[ inline ]
+    fn api_key( &self ) -> &str
+    {
+      &self.token
+    }
+
+    #[ inline ]
+    fn base_url( &self ) -> &str
+    {
+      &self.base_url
+    }
+
+    #[ inline ]
+    fn timeout( &self ) -> Duration
+    {
+      self.timeout
+    }
+
+    /// The standard headers, plus `Accept: application/vnd.github+json` and
+    /// `X-GitHub-Api-Version` on the GitHub-hosted endpoint.
+    #[ inline ]
+    fn headers( &self ) -> Result< header::HeaderMap >
+    {
+      let mut map = header::HeaderMap::new();
+      let mut auth_value = format!( "Bearer {}", self.token )
+        .parse::< header::HeaderValue >()
+        .map_err( | e | OpenAiCompatError::invalid_key( e.to_string() ) )?;
+      auth_value.set_sensitive( true );
+      map.insert( header::AUTHORIZATION, auth_value );
+      map.insert( header::CONTENT_TYPE, header::HeaderValue::from_static( "application/json" ) );
+      if self.github_headers
+      {
+        map.insert( header::ACCEPT, header::HeaderValue::from_static( "application/vnd.github+json" ) );
+        map.insert( "x-github-api-version", header::HeaderValue::from_static( Self::API_VERSION ) );
+      }
+      Ok( map )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    GitHubModelsEnvironment,
+  };
+}
@@ -0,0 +1,25 @@
+//! Ready-made environments for OpenAI-compatible providers.
+//!
+//! Each profile is an [`OpenAiCompatEnvironment`](crate::OpenAiCompatEnvironment)
+//! preset with a provider's endpoint and the headers it needs beyond the
+//! standard `Authorization: Bearer` pair. Quirk profiles check a request
+//! against the rules a provider enforces beyond the `OpenAI` schema, and
+//! provider profiles repair the responses of servers that bend it.
+//! Profiles are chosen by the caller; the client never infers one from a URL.
+
+mod private
+{
+}
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// GitHub Models and its Azure AI inference endpoint.
+  layer github_models;
+  /// Response leniencies for Groq, Together, vLLM, and LM Studio.
+  layer provider;
+  /// Request validation for Mistral, Groq, and `DeepSeek`.
+  layer quirks;
+  /// vLLM, llama.cpp, and `LiteLLM` servers.
+  layer self_hosted;
+}
@@ -0,0 +1,246 @@
+//! Deserialisation leniencies of OpenAI-compatible servers.
+//!
+//! Groq, Together, vLLM, and LM Studio answer in the `OpenAI` response
+//! shape but bend it: header fields go missing, engine finish reasons such
+//! as `eos` leak through, `usage` is absent or sent somewhere else. A
+//! [`ProviderProfile`] names the server family, and each family turns on a
+//! documented set of [`Leniency`] rules that repair the raw JSON before it
+//! is deserialised. The environment carries the profile, so the client and
+//! its stream decoder apply it to every response and chunk.
+//!
+//! Request-side rules live in [`ProviderQuirks`](crate::ProviderQuirks).
+
+mod private
+{
+  use serde_json::{ Map, Value };
+
+  /// One repair applied to raw response JSON before deserialisation.
+  ///
+  /// Every rule only fills in what is missing, maps what is non-standard,
+  /// or moves what is misplaced; a value that is already standard is never
+  /// changed.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ non_exhaustive ]
+  pub enum Leniency
+  {
+    /// Absent or null `id`, `object`, `created`, and `model` are filled with
+    /// `""`, the object kind, `0`, and `""`.
+    MissingHeader,
+    /// An absent or null `usage` on a (non-streamed) response becomes zeros,
+    /// and a `usage` without `total_tokens` gets the sum. Chunks without
+    /// `usage` are standard and left alone.
+    MissingUsage,
+    /// Engine finish reasons are mapped to `OpenAI`'s, as by
+    /// [`SelfHostedProfile::normalize_finish_reason`](crate::SelfHostedProfile::normalize_finish_reason):
+    /// `eos` becomes `stop`, `max_tokens` becomes `length`, `""` becomes null.
+    FinishReasons,
+    /// Groq's `x_groq.usage` on the last streamed chunk is moved to `usage`,
+    /// and the `x_groq` extension, which otherwise only carries Groq's
+    /// request id, is dropped.
+    GroqUsage,
+    /// Tool calls without `type` get `"function"`, and tool calls of a
+    /// response without `id` get `call_<n>`.
+    ToolCallDefaults,
+  }
+
+  /// The server family a client talks to, and so the leniencies it allows
+  /// when reading responses.
+  ///
+  /// Chosen explicitly with
+  /// [`OpenAiCompatEnvironmentImpl::with_provider_profile`](crate::OpenAiCompatEnvironmentImpl::with_provider_profile);
+  /// nothing is inferred from the base URL. A missing `system_fingerprint`
+  /// and chunks without `usage` are valid for every profile, since the wire
+  /// types make both optional.
+  ///
+  /// | Profile | Leniencies |
+  /// |---------|------------|
+  /// | `Standard` | none |
+  /// | `Groq` | `GroqUsage` |
+  /// | `Together` | `FinishReasons`, `MissingUsage` |
+  /// | `Vllm` | `FinishReasons`, `MissingUsage`, `ToolCallDefaults` |
+  /// | `LmStudio` | `MissingHeader`, `MissingUsage`, `FinishReasons`, `ToolCallDefaults` |
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionResponse, Leniency, ProviderProfile };
+  ///
+  /// let mut body = serde_json::json!( { "id" : "c1", "object" : "chat.completion", "created" : 1, "model" : "llama",
+  ///   "choices" : [ { "index" : 0, "message" : { "role" : "assistant", "content" : "Hi" }, "finish_reason" : "eos" } ] } );
+  ///
+  /// assert!( ProviderProfile::Together.allows( Leniency::FinishReasons ) );
+  /// ProviderProfile::Together.normalize_response( &mut body );
+  /// let response : ChatCompletionResponse = serde_json::from_value( body ).unwrap();
+  /// assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+  /// assert_eq!( response.usage.total_tokens, 0 );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Default ) ]
+  #[ non_exhaustive ]
+  pub enum ProviderProfile
+  {
+    /// A server that follows the `OpenAI` schema; nothing is repaired.
+    #[ default ]
+    Standard,
+    /// Groq (`api.groq.com/openai`).
+    Groq,
+    /// Together AI (`api.together.xyz`).
+    Together,
+    /// vLLM's OpenAI-compatible server.
+    Vllm,
+    /// LM Studio's local server.
+    LmStudio,
+  }
+
+  impl ProviderProfile
+  {
+    /// Profile name, e.g. for logs.
+    #[ inline ]
+    #[ must_use ]
+    pub fn name( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Standard => "standard",
+        Self::Groq => "groq",
+        Self::Together => "together",
+        Self::Vllm => "vllm",
+        Self::LmStudio => "lm_studio",
+      }
+    }
+
+    /// The leniencies this profile turns on.
+    #[ inline ]
+    #[ must_use ]
+    pub fn leniencies( self ) -> &'static [ Leniency ]
+    {
+      match self
+      {
+        Self::Standard => &[],
+        Self::Groq => &[ Leniency::GroqUsage ],
+        Self::Together => &[ Leniency::FinishReasons, Leniency::MissingUsage ],
+        Self::Vllm => &[ Leniency::FinishReasons, Leniency::MissingUsage, Leniency::ToolCallDefaults ],
+        Self::LmStudio => &[ Leniency::MissingHeader, Leniency::MissingUsage, Leniency::FinishReasons, Leniency::ToolCallDefaults ],
+      }
+    }
+
+    /// Whether this profile turns `leniency` on.
+    #[ inline ]
+    #[ must_use ]
+    pub fn allows( self, leniency : Leniency ) -> bool
+    {
+      self.leniencies().contains( &leniency )
+    }
+
+    /// Repairs a raw `chat/completions` response in place. Bodies that are
+    /// not an object with a `choices` array are left alone, so other
+    /// endpoints pass through untouched.
+    #[ inline ]
+    pub fn normalize_response( self, body : &mut Value )
+    {
+      self.normalize( body, "chat.completion", "message" );
+    }
+
+    /// Repairs one raw streamed chunk (an SSE `data:` payload) in place.
+    #[ inline ]
+    pub fn normalize_chunk( self, body : &mut Value )
+    {
+      self.normalize( body, "chat.completion.chunk", "delta" );
+    }
+
+    fn normalize( self, body : &mut Value, kind : &str, message_key : &str )
+    {
+      let Some( object ) = body.as_object_mut().filter( | object | object.get( "choices" ).is_some_and( Value::is_array ) ) else { return };
+      let chunk = message_key == "delta";
+      if self.allows( Leniency::GroqUsage ) && chunk
+      {
+        let standard = object.get( "usage" ).is_some_and( Value::is_object );
+        let groq = object.remove( "x_groq" ).and_then( | mut extra | extra.get_mut( "usage" ).map( Value::take ) );
+        if let Some( usage ) = groq.filter( | _ | !standard )
+        {
+          object.insert( "usage".to_owned(), usage );
+        }
+      }
+      if self.allows( Leniency::MissingHeader )
+      {
+        fill_header( object, kind );
+      }
+      if self.allows( Leniency::MissingUsage )
+      {
+        match object.get_mut( "usage" )
+        {
+          Some( usage ) if usage.is_object() => complete_usage( usage ),
+          _ if !chunk => { object.insert( "usage".to_owned(), zero_usage() ); },
+          _ => {},
+        }
+      }
+      let Some( choices ) = object.get_mut( "choices" ).and_then( Value::as_array_mut ) else { return };
+      for choice in choices.iter_mut().filter_map( Value::as_object_mut )
+      {
+        if self.allows( Leniency::FinishReasons )
+        {
+          if let Some( reason ) = choice.get( "finish_reason" ).and_then( Value::as_str )
+          {
+            let mapped = crate::profile::SelfHostedProfile::normalize_finish_reason( reason );
+            choice.insert( "finish_reason".to_owned(), mapped.map_or( Value::Null, Value::from ) );
+          }
+        }
+        if self.allows( Leniency::ToolCallDefaults )
+        {
+          let calls = choice.get_mut( message_key ).and_then( | message | message.get_mut( "tool_calls" ) ).and_then( Value::as_array_mut );
+          for ( position, call ) in calls.into_iter().flatten().filter_map( Value::as_object_mut ).enumerate()
+          {
+            call.entry( "type" ).or_insert_with( || Value::from( "function" ) );
+            if !chunk
+            {
+              call.entry( "id" ).or_insert_with( || Value::from( format!( "call_{position}" ) ) );
+            }
+          }
+        }
+      }
+    }
+  }
+
+  /// Fills the `id`, `object`, `created`, and `model` fields when missing or null.
+  fn fill_header( object : &mut Map< String, Value >, kind : &str )
+  {
+    for ( key, default ) in [ ( "id", Value::from( "" ) ), ( "object", Value::from( kind ) ), ( "created", Value::from( 0 ) ), ( "model", Value::from( "" ) ) ]
+    {
+      let field = object.entry( key ).or_insert( Value::Null );
+      if field.is_null()
+      {
+        *field = default;
+      }
+    }
+  }
+
+  /// Fills absent counts of a `usage` object with zero and the total with
+  /// the sum.
+  fn complete_usage( usage : &mut Value )
+  {
+    let Some( object ) = usage.as_object_mut() else { return };
+    let count = | object : &Map< String, Value >, key : &str | object.get( key ).and_then( Value::as_u64 ).unwrap_or( 0 );
+    let total = count( object, "prompt_tokens" ) + count( object, "completion_tokens" );
+    for key in [ "prompt_tokens", "completion_tokens" ]
+    {
+      object.entry( key ).or_insert_with( || Value::from( 0 ) );
+    }
+    object.entry( "total_tokens" ).or_insert_with( || Value::from( total ) );
+  }
+
+  fn zero_usage() -> Value
+  {
+    serde_json::json!( { "prompt_tokens" : 0, "completion_tokens" : 0, "total_tokens" : 0 } )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ProviderProfile,
+    Leniency,
+  };
+}
@@ -0,0 +1,190 @@
+//! Request rules of OpenAI-compatible providers.
+//!
+//! Mistral, Groq, and `DeepSeek` accept the `OpenAI` request schema but refuse
+//! some requests `OpenAI` accepts: they cap stop sequences, restrict
+//! `tool_choice` modes, constrain tool call ids, or reject fields of their
+//! own responses sent back as input. [`ProviderQuirks::validate`] reports
+//! these before the request is sent, so the caller gets a precise
+//! `OpenAiCompatError::InvalidRequest` instead of a provider-specific 400.
+//!
+//! Responses need no profile: extra fields such as `reasoning_content` are
+//! part of [`Message`](crate::Message) and [`Delta`](crate::Delta).
+
+mod private
+{
+  use crate::components::chat::{ ChatCompletionRequest, ToolChoice };
+  use crate::error::{ OpenAiCompatError, Result };
+
+  /// Request rules of one OpenAI-compatible provider.
+  ///
+  /// Chosen explicitly by the caller; nothing is inferred from the base URL.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionRequest, Message, ProviderQuirks };
+  ///
+  /// let request = ChatCompletionRequest::former()
+  ///   .model( "llama-3.1-8b-instant".to_string() )
+  ///   .messages( vec![ Message::user( "Count to ten" ) ] )
+  ///   .stop( vec![ "5".to_string(), "6".to_string(), "7".to_string(), "8".to_string(), "9".to_string() ] )
+  ///   .form();
+  ///
+  /// assert!( ProviderQuirks::Groq.validate( &request ).is_err() );
+  /// assert!( ProviderQuirks::Mistral.validate( &request ).is_ok() );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  #[ non_exhaustive ]
+  pub enum ProviderQuirks
+  {
+    /// Mistral La Plateforme (`api.mistral.ai`).
+    Mistral,
+    /// Groq (`api.groq.com/openai`).
+    Groq,
+    /// `DeepSeek` (`api.deepseek.com`).
+    DeepSeek,
+  }
+
+  impl ProviderQuirks
+  {
+    /// Length of a Mistral tool call id.
+    pub const MISTRAL_TOOL_CALL_ID_LEN : usize = 9;
+
+    /// Provider name used in error messages.
+    #[ inline ]
+    #[ must_use ]
+    pub fn name( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Mistral => "mistral",
+        Self::Groq => "groq",
+        Self::DeepSeek => "deepseek",
+      }
+    }
+
+    /// Maximum number of stop sequences, `None` when unlimited.
+    #[ inline ]
+    #[ must_use ]
+    pub fn max_stop_sequences( self ) -> Option< usize >
+    {
+      match self
+      {
+        Self::Mistral => None,
+        Self::Groq => Some( 4 ),
+        Self::DeepSeek => Some( 16 ),
+      }
+    }
+
+    /// `tool_choice` mode strings the provider accepts.
+    #[ inline ]
+    #[ must_use ]
+    pub fn tool_choice_modes( self ) -> &'static [ &'static str ]
+    {
+      match self
+      {
+        Self::Mistral => &[ "none", "auto", "any", "required" ],
+        Self::Groq | Self::DeepSeek => &[ "none", "auto", "required" ],
+      }
+    }
+
+    /// Checks `request` against the provider's rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::InvalidRequest` naming the first rule the
+    /// request breaks:
+    ///
+    /// - more stop sequences than [`max_stop_sequences`](Self::max_stop_sequences);
+    /// - `tool_choice` without tools, or with a mode outside
+    ///   [`tool_choice_modes`](Self::tool_choice_modes);
+    /// - Mistral: a tool call id that is not 9 ASCII letters or digits;
+    /// - `DeepSeek`: a message carrying `reasoning_content`.
+    #[ inline ]
+    pub fn validate( self, request : &ChatCompletionRequest ) -> Result< () >
+    {
+      self.check_stop( request )?;
+      self.check_tool_choice( request )?;
+      match self
+      {
+        Self::Mistral => self.check_tool_call_ids( request ),
+        Self::DeepSeek => self.check_reasoning_content( request ),
+        Self::Groq => Ok( () ),
+      }
+    }
+
+    fn check_stop( self, request : &ChatCompletionRequest ) -> Result< () >
+    {
+      let count = request.stop.as_ref().map_or( 0, Vec::len );
+      match self.max_stop_sequences()
+      {
+        Some( max ) if count > max =>
+          Err( self.invalid( &format!( "at most {max} stop sequences are accepted, got {count}" ) ) ),
+        _ => Ok( () ),
+      }
+    }
+
+    fn check_tool_choice( self, request : &ChatCompletionRequest ) -> Result< () >
+    {
+      let Some( choice ) = &request.tool_choice else { return Ok( () ) };
+      if request.tools.as_ref().map_or( true, Vec::is_empty )
+      {
+        return Err( self.invalid( "tool_choice requires at least one tool" ) );
+      }
+      match choice
+      {
+        ToolChoice::Mode( mode ) if !self.tool_choice_modes().contains( &mode.as_str() ) =>
+          Err( self.invalid( &format!( "tool_choice \"{mode}\" is not one of {:?}", self.tool_choice_modes() ) ) ),
+        _ => Ok( () ),
+      }
+    }
+
+    fn check_tool_call_ids( self, request : &ChatCompletionRequest ) -> Result< () >
+    {
+      let ids = request.messages.iter().flat_map( | message |
+      {
+        message.tool_calls.iter().flatten().map( | call | call.id.as_str() )
+          .chain( message.tool_call_id.as_deref() )
+      });
+      for id in ids
+      {
+        let valid = id.len() == Self::MISTRAL_TOOL_CALL_ID_LEN && id.bytes().all( | b | b.is_ascii_alphanumeric() );
+        if !valid
+        {
+          return Err( self.invalid( &format!(
+            "tool call id \"{id}\" must be {} ASCII letters or digits",
+            Self::MISTRAL_TOOL_CALL_ID_LEN,
+          ) ) );
+        }
+      }
+      Ok( () )
+    }
+
+    fn check_reasoning_content( self, request : &ChatCompletionRequest ) -> Result< () >
+    {
+      match request.messages.iter().position( | message | message.reasoning_content.is_some() )
+      {
+        Some( index ) => Err( self.invalid( &format!(
+          "message {index} carries reasoning_content; remove it before sending the history back"
+        ) ) ),
+        None => Ok( () ),
+      }
+    }
+
+    fn invalid( self, detail : &str ) -> error_tools::untyped::Error
+    {
+      OpenAiCompatError::invalid_request( format!( "{} : {detail}", self.name() ) ).into()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ProviderQuirks,
+  };
+}
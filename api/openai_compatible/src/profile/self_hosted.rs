@@ -0,0 +1,436 @@
+//! Profile for self-hosted OpenAI-compatible servers.
+//!
+//! vLLM, llama.cpp's `llama-server`, and the `LiteLLM` proxy serve the
+//! `OpenAI` chat API but deviate from it in ways the wire types reject or
+//! misreport:
+//!
+//! - responses and streamed chunks may lack `id`, `object`, `created`, or
+//!   `usage`, and tool calls may lack `id` or `type`;
+//! - finish reasons of the backing engine leak through (`eos`, `eos_token`,
+//!   `end_turn`, `max_tokens`, `stop_sequence`, or an empty string);
+//! - extra fields appear (`stop_reason`, `prompt_logprobs`, `timings`,
+//!   per-call `index`) that fail deserialisation under `strict_wire`;
+//! - sampling parameters outside the `OpenAI` schema (`top_k`, `min_p`,
+//!   `repetition_penalty`) are accepted and must be passed through.
+//!
+//! [`SelfHostedProfile`] is an environment for such a server that also
+//! builds request bodies carrying those parameters and normalises the raw
+//! response JSON into the standard wire types.
+
+mod private
+{
+  use crate::components::chat::{ ChatCompletionRequest, ChatCompletionResponse };
+  #[ cfg( feature = "streaming" ) ]
+  use crate::components::streaming::ChatCompletionChunk;
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::{ OpenAiCompatError, Result };
+  #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+  use crate::tls::TlsConfig;
+  use core::time::Duration;
+  use reqwest::header;
+  use serde_json::{ Map, Value };
+
+  /// Fields of `ChatCompletionResponse` kept by normalisation.
+  const RESPONSE_FIELDS : &[ &str ] = &[ "id", "object", "created", "model", "choices", "usage", "system_fingerprint" ];
+  /// Fields of `ChatCompletionChunk` kept by normalisation.
+  #[ cfg( feature = "streaming" ) ]
+  const CHUNK_FIELDS : &[ &str ] = &[ "id", "object", "created", "model", "choices", "system_fingerprint", "usage" ];
+  /// Fields of `Choice` and `ChunkChoice` kept by normalisation.
+  const CHOICE_FIELDS : &[ &str ] = &[ "index", "message", "delta", "finish_reason", "logprobs" ];
+  /// Fields of `Message` and `Delta` kept by normalisation.
+  const MESSAGE_FIELDS : &[ &str ] = &[ "role", "content", "reasoning_content", "tool_calls", "tool_call_id" ];
+  /// Fields of `ToolCall` kept by normalisation.
+  const TOOL_CALL_FIELDS : &[ &str ] = &[ "id", "type", "function" ];
+  /// Fields of `ToolCallChunk` kept by normalisation.
+  const TOOL_CALL_CHUNK_FIELDS : &[ &str ] = &[ "index", "id", "type", "function" ];
+  /// Fields of `FunctionCall` kept by normalisation.
+  const FUNCTION_CALL_FIELDS : &[ &str ] = &[ "name", "arguments" ];
+  /// Fields of `Usage` kept by normalisation.
+  const USAGE_FIELDS : &[ &str ] = &[ "prompt_tokens", "completion_tokens", "total_tokens" ];
+
+  /// Environment and wire adapter for vLLM, llama.cpp, and `LiteLLM` servers.
+  ///
+  /// Send the body from [`request_body`](Self::request_body), receive the
+  /// response as `serde_json::Value`, and convert it with
+  /// [`parse_response`](Self::parse_response).
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionRequest, Client, Message, SelfHostedProfile };
+  ///
+  /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+  /// let profile = SelfHostedProfile::new( "http://localhost:8000/v1/" )?
+  ///   .with_param( "top_k", 40 )
+  ///   .with_param( "min_p", 0.05 );
+  /// let client = Client::build( profile.clone() )?;
+  ///
+  /// let request = ChatCompletionRequest::former()
+  ///   .model( "Qwen/Qwen2.5-7B-Instruct".to_string() )
+  ///   .messages( vec![ Message::user( "Hello" ) ] )
+  ///   .form();
+  /// let raw : serde_json::Value = client.post( "chat/completions", &profile.request_body( &request )? ).await?;
+  /// let response = SelfHostedProfile::parse_response( raw )?;
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  #[ derive( Clone ) ]
+  pub struct SelfHostedProfile
+  {
+    /// API key; empty when the server runs without one.
+    api_key : String,
+    /// Base URL including trailing slash.
+    base_url : String,
+    /// Per-request timeout.
+    timeout : Duration,
+    /// Sampling parameters added to every request body.
+    params : Map< String, Value >,
+    /// TLS settings; `None` keeps the backend's defaults.
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    tls : Option< TlsConfig >,
+  }
+
+  impl core::fmt::Debug for SelfHostedProfile
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let mut debug = f.debug_struct( "SelfHostedProfile" );
+      debug
+        .field( "api_key", &api_secret::Masked( &self.api_key ) )
+        .field( "base_url", &self.base_url )
+        .field( "timeout", &self.timeout )
+        .field( "params", &self.params );
+      #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+      debug.field( "tls", &self.tls );
+      debug.finish()
+    }
+  }
+
+  impl SelfHostedProfile
+  {
+    /// Default request timeout in seconds; local generation on modest
+    /// hardware is far slower than a hosted API.
+    pub const DEFAULT_TIMEOUT_SECS : u64 = 300;
+
+    /// Creates a profile for the server at `base_url`, e.g.
+    /// `"http://localhost:8000/v1/"` (vLLM), `"http://localhost:8080/v1/"`
+    /// (llama.cpp), or `"http://localhost:4000/"` (`LiteLLM`). A trailing
+    /// slash is added when missing. No API key is sent until
+    /// [`with_api_key`](Self::with_api_key) sets one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Environment` if `base_url` is not an
+    /// absolute `http` or `https` URL.
+    #[ inline ]
+    pub fn new( base_url : impl Into< String > ) -> Result< Self >
+    {
+      let mut base_url = base_url.into().trim().to_owned();
+      let url = reqwest::Url::parse( &base_url )
+        .map_err( | e | OpenAiCompatError::Environment( format!( "invalid base URL {base_url:?} : {e}" ) ) )?;
+      if !matches!( url.scheme(), "http" | "https" )
+      {
+        return Err( OpenAiCompatError::Environment( format!( "base URL {base_url:?} must use http or https" ) ).into() );
+      }
+      if !base_url.ends_with( '/' )
+      {
+        base_url.push( '/' );
+      }
+      Ok( Self
+      {
+        api_key : String::new(),
+        base_url,
+        timeout : Duration::from_secs( Self::DEFAULT_TIMEOUT_SECS ),
+        params : Map::new(),
+        #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+        tls : None,
+      })
+    }
+
+    /// Sends `Authorization: Bearer <key>`, as required by vLLM's
+    /// `--api-key`, llama.cpp's `--api-key`, or a `LiteLLM` virtual key.
+    /// Surrounding whitespace is removed; a blank key sends none.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_api_key( mut self, api_key : impl Into< String > ) -> Self
+    {
+      api_key.into().trim().clone_into( &mut self.api_key );
+      self
+    }
+
+    /// Overrides the request timeout.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_timeout( mut self, timeout : Duration ) -> Self
+    {
+      self.timeout = timeout;
+      self
+    }
+
+    /// Applies `tls` when the client is built: the private CA of a gateway
+    /// behind a corporate proxy, or a client certificate the gateway asks
+    /// for.
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_tls( mut self, tls : TlsConfig ) -> Self
+    {
+      self.tls = Some( tls );
+      self
+    }
+
+    /// Adds a parameter outside the `OpenAI` schema to every request body,
+    /// e.g. `top_k`, `min_p`, `repetition_penalty`, or vLLM's
+    /// `guided_json`. Setting a name twice keeps the last value.
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_param( mut self, name : impl Into< String >, value : impl Into< Value > ) -> Self
+    {
+      self.params.insert( name.into(), value.into() );
+      self
+    }
+
+    /// Parameters added to every request body.
+    #[ inline ]
+    #[ must_use ]
+    pub fn params( &self ) -> &Map< String, Value >
+    {
+      &self.params
+    }
+
+    /// Serialises `request` and adds the profile's parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::InvalidRequest` if a parameter names a
+    /// field the request already sets, so neither value is silently lost.
+    #[ inline ]
+    pub fn request_body( &self, request : &ChatCompletionRequest ) -> Result< Value >
+    {
+      let mut body = serde_json::to_value( request ).map_err( OpenAiCompatError::from )?;
+      if let Some( object ) = body.as_object_mut()
+      {
+        for ( name, value ) in &self.params
+        {
+          if object.contains_key( name )
+          {
+            return Err( OpenAiCompatError::invalid_request( format!( "self-hosted : parameter {name} is also set by the request" ) ).into() );
+          }
+          object.insert( name.clone(), value.clone() );
+        }
+      }
+      Ok( body )
+    }
+
+    /// Normalises a raw `chat/completions` response and deserialises it.
+    ///
+    /// Missing `id`, `model`, and `object` are filled with `""`, `""`, and
+    /// `"chat.completion"`, missing `created` with `0`, missing `usage` with
+    /// zeros, missing tool call ids with `call_<n>`, and finish reasons are
+    /// mapped with [`normalize_finish_reason`](Self::normalize_finish_reason).
+    /// Fields the wire types do not define are dropped rather than kept in
+    /// `extra`, so the result also parses under `strict_wire`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Deserialization` if the body still does not
+    /// match `ChatCompletionResponse`, e.g. `choices` is missing.
+    #[ inline ]
+    pub fn parse_response( mut body : Value ) -> Result< ChatCompletionResponse >
+    {
+      if let Some( object ) = body.as_object_mut()
+      {
+        object.retain( | key, _ | RESPONSE_FIELDS.contains( &key.as_str() ) );
+        fill_header( object, "chat.completion" );
+        let usage = object.entry( "usage" ).or_insert( Value::Null );
+        normalize_usage( usage );
+        normalize_choices( object, "message", true );
+      }
+      serde_json::from_value( body ).map_err( | e | OpenAiCompatError::from( e ).into() )
+    }
+
+    /// Normalises a raw streamed chunk (one SSE `data:` payload) and
+    /// deserialises it.
+    ///
+    /// As [`parse_response`](Self::parse_response), except that tool call
+    /// fragments keep an empty id: only the first fragment of a call has one.
+    /// A `usage` object on the last chunk is normalised like a response's.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Deserialization` if the chunk still does not
+    /// match `ChatCompletionChunk`.
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    pub fn parse_chunk( mut body : Value ) -> Result< ChatCompletionChunk >
+    {
+      if let Some( object ) = body.as_object_mut()
+      {
+        object.retain( | key, _ | CHUNK_FIELDS.contains( &key.as_str() ) );
+        fill_header( object, "chat.completion.chunk" );
+        if let Some( usage ) = object.get_mut( "usage" ).filter( | usage | usage.is_object() )
+        {
+          normalize_usage( usage );
+        }
+        normalize_choices( object, "delta", false );
+      }
+      serde_json::from_value( body ).map_err( | e | OpenAiCompatError::from( e ).into() )
+    }
+
+    /// Maps an engine-specific finish reason to its `OpenAI` equivalent.
+    ///
+    /// `eos`, `eos_token`, `end_turn`, and `stop_sequence` become `stop`;
+    /// `max_tokens` and `max_length` become `length`; `tool_use` and
+    /// `tool_call` become `tool_calls`; an empty string becomes `None`.
+    /// Anything else is kept, e.g. vLLM's `abort`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn normalize_finish_reason( reason : &str ) -> Option< String >
+    {
+      let mapped = match reason
+      {
+        "" => return None,
+        "eos" | "eos_token" | "end_turn" | "stop_sequence" => "stop",
+        "max_tokens" | "max_length" => "length",
+        "tool_use" | "tool_call" => "tool_calls",
+        other => other,
+      };
+      Some( mapped.to_owned() )
+    }
+  }
+
+  /// Fills the `id`, `object`, `created`, and `model` fields when missing or null.
+  fn fill_header( object : &mut Map< String, Value >, kind : &str )
+  {
+    for ( key, default ) in [ ( "id", Value::from( "" ) ), ( "object", Value::from( kind ) ), ( "created", Value::from( 0 ) ), ( "model", Value::from( "" ) ) ]
+    {
+      let field = object.entry( key ).or_insert( Value::Null );
+      if field.is_null()
+      {
+        *field = default;
+      }
+    }
+  }
+
+  /// Replaces a missing or partial `usage` with zeros and a computed total.
+  fn normalize_usage( usage : &mut Value )
+  {
+    let mut object = usage.as_object().cloned().unwrap_or_default();
+    object.retain( | key, _ | USAGE_FIELDS.contains( &key.as_str() ) );
+    let count = | object : &Map< String, Value >, key : &str | object.get( key ).and_then( Value::as_u64 ).unwrap_or( 0 );
+    let prompt = count( &object, "prompt_tokens" );
+    let completion = count( &object, "completion_tokens" );
+    let total = object.get( "total_tokens" ).and_then( Value::as_u64 ).unwrap_or( prompt + completion );
+    object.insert( "prompt_tokens".to_owned(), prompt.into() );
+    object.insert( "completion_tokens".to_owned(), completion.into() );
+    object.insert( "total_tokens".to_owned(), total.into() );
+    *usage = Value::Object( object );
+  }
+
+  /// Normalises every choice and the message (`message`) or delta (`delta`)
+  /// it carries. `fill_ids` gives id-less tool calls a `call_<n>` id.
+  fn normalize_choices( object : &mut Map< String, Value >, body_key : &str, fill_ids : bool )
+  {
+    let Some( choices ) = object.get_mut( "choices" ).and_then( Value::as_array_mut ) else { return };
+    for ( position, choice ) in choices.iter_mut().enumerate()
+    {
+      let Some( choice ) = choice.as_object_mut() else { continue };
+      choice.retain( | key, _ | CHOICE_FIELDS.contains( &key.as_str() ) );
+      choice.entry( "index" ).or_insert( Value::from( position ) );
+      let reason = choice.get( "finish_reason" ).and_then( Value::as_str ).map( SelfHostedProfile::normalize_finish_reason );
+      if let Some( reason ) = reason
+      {
+        choice.insert( "finish_reason".to_owned(), reason.map_or( Value::Null, Value::from ) );
+      }
+      choice.entry( "finish_reason" ).or_insert( Value::Null );
+      if let Some( message ) = choice.get_mut( body_key ).and_then( Value::as_object_mut )
+      {
+        normalize_message( message, fill_ids );
+      }
+    }
+  }
+
+  /// Drops unknown message fields and completes each tool call. Streamed
+  /// fragments (`fill_ids` false) keep their `index`.
+  fn normalize_message( message : &mut Map< String, Value >, fill_ids : bool )
+  {
+    message.retain( | key, _ | MESSAGE_FIELDS.contains( &key.as_str() ) );
+    let Some( calls ) = message.get_mut( "tool_calls" ).and_then( Value::as_array_mut ) else { return };
+    let fields = if fill_ids { TOOL_CALL_FIELDS } else { TOOL_CALL_CHUNK_FIELDS };
+    for ( position, call ) in calls.iter_mut().enumerate()
+    {
+      let Some( call ) = call.as_object_mut() else { continue };
+      call.retain( | key, _ | fields.contains( &key.as_str() ) );
+      let id = call.entry( "id" ).or_insert( Value::Null );
+      if id.is_null() || ( fill_ids && id.as_str() == Some( "" ) )
+      {
+        *id = Value::from( if fill_ids { format!( "call_{position}" ) } else { String::new() } );
+      }
+      call.entry( "type" ).or_insert( Value::from( "function" ) );
+      let function = call.entry( "function" ).or_insert( Value::Object( Map::new() ) );
+      if let Some( function ) = function.as_object_mut()
+      {
+        function.retain( | key, _ | FUNCTION_CALL_FIELDS.contains( &key.as_str() ) );
+        function.entry( "name" ).or_insert( Value::from( "" ) );
+        function.entry( "arguments" ).or_insert( Value::from( "" ) );
+      }
+    }
+  }
+
+  impl OpenAiCompatEnvironment for SelfHostedProfile
+  {
+    #[ inline ]
+    fn api_key( &self ) -> &str
+    {
+      &self.api_key
+    }
+
+    #[ inline ]
+    fn base_url( &self ) -> &str
+    {
+      &self.base_url
+    }
+
+    #[ inline ]
+    fn timeout( &self ) -> Duration
+    {
+      self.timeout
+    }
+
+    #[ cfg( all( feature = "tls", not( target_arch = "wasm32" ) ) ) ]
+    #[ inline ]
+    fn tls( &self ) -> Option< &TlsConfig >
+    {
+      self.tls.as_ref()
+    }
+
+    /// `Content-Type: application/json`, plus `Authorization: Bearer` when a
+    /// key is set.
+    #[ inline ]
+    fn headers( &self ) -> Result< header::HeaderMap >
+    {
+      let mut map = header::HeaderMap::new();
+      if !self.api_key.is_empty()
+      {
+        let mut auth_value = format!( "Bearer {}", self.api_key )
+          .parse::< header::HeaderValue >()
+          .map_err( | e | OpenAiCompatError::invalid_key( e.to_string() ) )?;
+        auth_value.set_sensitive( true );
+        map.insert( header::AUTHORIZATION, auth_value );
+      }
+      map.insert( header::CONTENT_TYPE, header::HeaderValue::from_static( "application/json" ) );
+      Ok( map )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SelfHostedProfile,
+  };
+}
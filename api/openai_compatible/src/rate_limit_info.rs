@@ -0,0 +1,174 @@
+//! Rate-limit state reported in response headers.
+//!
+//! `OpenAI` sends the quotas of the key with every response:
+//! `x-ratelimit-limit-requests`, `x-ratelimit-remaining-tokens`, and so on,
+//! with resets as Go durations (`6m0s`, `20ms`). Groq and most gateways
+//! copy these names; Together and some proxies send one request quota as
+//! `x-ratelimit-limit`, `x-ratelimit-remaining`, and `x-ratelimit-reset`,
+//! which are read as the request quota. [`RateLimitInfo`] holds what a
+//! response reported, for callers that pace themselves instead of waiting
+//! for a `429`.
+
+mod private
+{
+  use crate::error::OpenAiCompatError;
+  use core::time::Duration;
+  use reqwest::header::HeaderMap;
+
+  /// Quotas and resets a response reported; a field is `None` when its
+  /// header was absent or unreadable.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::RateLimitInfo;
+  /// use core::time::Duration;
+  /// use reqwest::header::HeaderMap;
+  ///
+  /// let mut headers = HeaderMap::new();
+  /// headers.insert( "x-ratelimit-remaining-requests", "0".parse().unwrap() );
+  /// headers.insert( "x-ratelimit-reset-requests", "1m30s".parse().unwrap() );
+  /// headers.insert( "x-ratelimit-remaining-tokens", "149984".parse().unwrap() );
+  ///
+  /// let info = RateLimitInfo::from_headers( &headers );
+  /// assert_eq!( info.remaining_tokens, Some( 149_984 ) );
+  /// assert_eq!( info.wait(), Some( Duration::from_secs( 90 ) ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  #[ non_exhaustive ]
+  pub struct RateLimitInfo
+  {
+    /// Requests allowed per window, from `x-ratelimit-limit-requests`.
+    pub limit_requests : Option< u64 >,
+    /// Tokens allowed per window, from `x-ratelimit-limit-tokens`.
+    pub limit_tokens : Option< u64 >,
+    /// Requests left in the window, from `x-ratelimit-remaining-requests`.
+    pub remaining_requests : Option< u64 >,
+    /// Tokens left in the window, from `x-ratelimit-remaining-tokens`.
+    pub remaining_tokens : Option< u64 >,
+    /// Time until the request quota is full again, from
+    /// `x-ratelimit-reset-requests`.
+    pub reset_requests : Option< Duration >,
+    /// Time until the token quota is full again, from
+    /// `x-ratelimit-reset-tokens`.
+    pub reset_tokens : Option< Duration >,
+    /// Wait asked for in `retry-after-ms` or `Retry-After`.
+    pub retry_after : Option< Duration >,
+  }
+
+  impl RateLimitInfo
+  {
+    /// Reads the rate-limit headers of a response.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_headers( headers : &HeaderMap ) -> Self
+    {
+      let text = | names : &[ &str ] | names.iter().find_map( | name | headers.get( *name )?.to_str().ok() ).map( str::trim );
+      let count = | names : &[ &str ] | text( names )?.parse::< u64 >().ok();
+      let reset = | names : &[ &str ] | parse_reset( text( names )? );
+      Self
+      {
+        limit_requests : count( &[ "x-ratelimit-limit-requests", "x-ratelimit-limit" ] ),
+        limit_tokens : count( &[ "x-ratelimit-limit-tokens" ] ),
+        remaining_requests : count( &[ "x-ratelimit-remaining-requests", "x-ratelimit-remaining" ] ),
+        remaining_tokens : count( &[ "x-ratelimit-remaining-tokens" ] ),
+        reset_requests : reset( &[ "x-ratelimit-reset-requests", "x-ratelimit-reset" ] ),
+        reset_tokens : reset( &[ "x-ratelimit-reset-tokens" ] ),
+        retry_after : OpenAiCompatError::retry_after_header( headers ),
+      }
+    }
+
+    /// Whether the response reported nothing, as servers without rate
+    /// limits do.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      *self == Self::default()
+    }
+
+    /// How long to wait before the next request: the `Retry-After` wait,
+    /// else the longest reset of a quota with nothing remaining, else
+    /// `None`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn wait( &self ) -> Option< Duration >
+    {
+      let exhausted = | remaining : Option< u64 >, reset : Option< Duration > | reset.filter( | _ | remaining == Some( 0 ) );
+      self.retry_after.or_else( ||
+      {
+        let requests = exhausted( self.remaining_requests, self.reset_requests );
+        let tokens = exhausted( self.remaining_tokens, self.reset_tokens );
+        requests.max( tokens )
+      } )
+    }
+  }
+
+  /// A value read from a successful response, with the rate-limit state
+  /// that response reported.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct WithRateLimits< T >
+  {
+    /// The response, deserialised.
+    pub value : T,
+    /// Rate-limit headers of the response.
+    pub rate_limits : RateLimitInfo,
+  }
+
+  impl< T > WithRateLimits< T >
+  {
+    /// The value, without the rate-limit state.
+    #[ inline ]
+    pub fn into_value( self ) -> T
+    {
+      self.value
+    }
+  }
+
+  /// Reads a reset: a Go duration such as `1h2m3.5s`, `6m0s`, or `20ms`,
+  /// or a plain number of seconds.
+  fn parse_reset( text : &str ) -> Option< Duration >
+  {
+    if let Ok( seconds ) = text.parse::< f64 >()
+    {
+      return Duration::try_from_secs_f64( seconds ).ok();
+    }
+    if text.is_empty()
+    {
+      return None;
+    }
+    let mut total = 0.0;
+    let mut rest = text;
+    while !rest.is_empty()
+    {
+      let number_end = rest.find( | c : char | !( c.is_ascii_digit() || c == '.' ) )?;
+      let unit_end = rest[ number_end.. ].find( | c : char | c.is_ascii_digit() || c == '.' ).map_or( rest.len(), | end | number_end + end );
+      let number : f64 = rest[ ..number_end ].parse().ok()?;
+      let scale = match &rest[ number_end..unit_end ]
+      {
+        "h" => 3600.0,
+        "m" => 60.0,
+        "s" => 1.0,
+        "ms" => 1e-3,
+        "us" | "µs" => 1e-6,
+        "ns" => 1e-9,
+        _ => return None,
+      };
+      total += number * scale;
+      rest = &rest[ unit_end.. ];
+    }
+    Duration::try_from_secs_f64( total ).ok()
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    RateLimitInfo,
+    WithRateLimits,
+  };
+}
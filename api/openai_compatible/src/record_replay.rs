@@ -0,0 +1,407 @@
+//! Record/replay of HTTP interactions, for integration tests that run
+//! against a real API once and deterministically afterwards.
+//!
+//! A [`Cassette`] is a JSON file of request/response pairs. A client given
+//! one with [`Client::with_cassette`](crate::Client::with_cassette) either
+//! sends each request for real and appends the pair to the file (record
+//! mode), or answers each request from a matching recorded pair without
+//! touching the network (replay mode). Credentials are scrubbed before
+//! anything is written, so cassettes can be committed.
+
+mod private
+{
+  use crate::error::{ OpenAiCompatError, Result };
+  use reqwest::header::{ HeaderMap, HeaderName, HeaderValue };
+  use reqwest::StatusCode;
+  use serde::{ Deserialize, Serialize };
+  use serde_json::Value;
+  use std::collections::BTreeMap;
+  use std::path::{ Path, PathBuf };
+  use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+
+  /// Environment variable that switches [`Cassette::from_env`] to recording.
+  pub const RECORD_ENV_VAR : &str = "OPENAI_COMPAT_RECORD";
+
+  /// Headers whose values are never written to a cassette.
+  const CREDENTIAL_HEADERS : [ &str; 4 ] = [ "authorization", "proxy-authorization", "api-key", "x-api-key" ];
+
+  /// Written in place of credential header values.
+  const REDACTED : &str = "[REDACTED]";
+
+  /// Whether a cassette sends requests and records them, or answers from
+  /// what was recorded.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub enum CassetteMode
+  {
+    /// Send every request and append the interaction to the file.
+    Record,
+    /// Answer every request from the file; nothing is sent.
+    Replay,
+  }
+
+  /// A recorded request, as far as replay needs to match it.
+  #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
+  pub struct CassetteRequest
+  {
+    /// HTTP method, e.g. `"POST"`.
+    pub method : String,
+    /// Full URL, with the API key scrubbed.
+    pub url : String,
+    /// Headers, credential values replaced by `[REDACTED]`.
+    pub headers : BTreeMap< String, String >,
+    /// JSON body; `None` for a GET.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub body : Option< Value >,
+  }
+
+  /// A recorded response.
+  #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
+  pub struct CassetteResponse
+  {
+    /// HTTP status code.
+    pub status : u16,
+    /// Headers as received.
+    pub headers : BTreeMap< String, String >,
+    /// Body: a JSON object or array kept as JSON for readable diffs,
+    /// anything else (event streams, plain text) as a string.
+    pub body : Value,
+  }
+
+  /// One request and the response it got.
+  #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
+  pub struct Interaction
+  {
+    /// What was sent.
+    pub request : CassetteRequest,
+    /// What came back.
+    pub response : CassetteResponse,
+  }
+
+  /// The file format: interactions in the order they happened.
+  #[ derive( Debug, Default, Serialize, Deserialize ) ]
+  struct CassetteFile
+  {
+    interactions : Vec< Interaction >,
+  }
+
+  #[ derive( Debug ) ]
+  struct State
+  {
+    interactions : Vec< Interaction >,
+    /// Which interactions replay has already answered with.
+    used : Vec< bool >,
+  }
+
+  /// A file of recorded HTTP interactions and the mode it is used in.
+  ///
+  /// In replay mode each request is answered by the first unused
+  /// interaction with the same method, URL, and body (JSON bodies compare as
+  /// values, so member order does not matter); a request with none fails
+  /// with `Environment`. In record mode the file is rewritten after every
+  /// interaction, so an interrupted run keeps what it recorded. Streamed
+  /// responses are read whole when recorded and replayed in one piece.
+  ///
+  /// Before writing, header values of `Authorization`, `Proxy-Authorization`,
+  /// `api-key`, `x-api-key`, and any sensitive header become `[REDACTED]`,
+  /// and the client's API key is masked wherever else it appears.
+  ///
+  /// Clones share the interactions, so several clients can use one cassette.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "record_replay" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Cassette, Client, OpenAiCompatEnvironmentImpl };
+  ///
+  /// // Records with `OPENAI_COMPAT_RECORD=1`, replays otherwise.
+  /// let cassette = Cassette::from_env( "tests/cassettes/chat_basic.json" ).unwrap();
+  /// let env = OpenAiCompatEnvironmentImpl::new( std::env::var( "OPENAI_API_KEY" ).unwrap_or( "sk-replay".into() ) ).unwrap();
+  /// let client = Client::build( env ).unwrap().with_cassette( cassette );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone ) ]
+  pub struct Cassette
+  {
+    path : PathBuf,
+    mode : CassetteMode,
+    state : Arc< Mutex< State > >,
+  }
+
+  impl Cassette
+  {
+    /// Records into `path`, starting an empty cassette; an existing file is
+    /// replaced once the first interaction is recorded.
+    #[ inline ]
+    #[ must_use ]
+    pub fn record( path : impl Into< PathBuf > ) -> Self
+    {
+      Self::with_interactions( path.into(), CassetteMode::Record, Vec::new() )
+    }
+
+    /// Replays the interactions recorded in `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAiCompatError::Environment` if the file cannot be read or
+    /// is not a cassette.
+    #[ inline ]
+    pub fn replay( path : impl Into< PathBuf > ) -> Result< Self >
+    {
+      let path = path.into();
+      let text = std::fs::read_to_string( &path ).map_err( | e | cassette_error( &path, &e ) )?;
+      let file : CassetteFile = serde_json::from_str( &text ).map_err( | e | cassette_error( &path, &e ) )?;
+      Ok( Self::with_interactions( path, CassetteMode::Replay, file.interactions ) )
+    }
+
+    /// Records into `path` when [`RECORD_ENV_VAR`] is set to anything but
+    /// `0` or an empty string, and replays from it otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`replay`](Self::replay) in replay mode.
+    #[ inline ]
+    pub fn from_env( path : impl Into< PathBuf > ) -> Result< Self >
+    {
+      let recording = std::env::var( RECORD_ENV_VAR ).is_ok_and( | value | !value.is_empty() && value != "0" );
+      if recording { Ok( Self::record( path ) ) } else { Self::replay( path ) }
+    }
+
+    fn with_interactions( path : PathBuf, mode : CassetteMode, interactions : Vec< Interaction > ) -> Self
+    {
+      let used = vec![ false; interactions.len() ];
+      Self { path, mode, state : Arc::new( Mutex::new( State { interactions, used } ) ) }
+    }
+
+    /// The cassette's file.
+    #[ inline ]
+    #[ must_use ]
+    pub fn path( &self ) -> &Path
+    {
+      &self.path
+    }
+
+    /// Whether the cassette records or replays.
+    #[ inline ]
+    #[ must_use ]
+    pub fn mode( &self ) -> CassetteMode
+    {
+      self.mode
+    }
+
+    /// The interactions recorded or loaded so far, oldest first.
+    #[ inline ]
+    #[ must_use ]
+    pub fn interactions( &self ) -> Vec< Interaction >
+    {
+      self.lock().interactions.clone()
+    }
+
+    /// Sends `request` with `http` and records it, or answers it from the
+    /// recording. `secret` is the API key to scrub.
+    pub( crate ) async fn dispatch( &self, http : &reqwest::Client, request : reqwest::Request, secret : &str ) -> Result< reqwest::Response >
+    {
+      let recorded = scrub_request( &request, secret );
+      match self.mode
+      {
+        CassetteMode::Replay => self.answer( &recorded ),
+        CassetteMode::Record =>
+        {
+          let response = http.execute( request ).await.map_err( OpenAiCompatError::from )?;
+          let status = response.status();
+          let headers = response.headers().clone();
+          let body = response.bytes().await.map_err( OpenAiCompatError::from )?.to_vec();
+          self.append( Interaction
+          {
+            request : recorded,
+            response : CassetteResponse
+            {
+              status : status.as_u16(),
+              headers : header_map( &headers, None ),
+              body : stored_body( &api_secret::redact( &String::from_utf8_lossy( &body ), secret ) ),
+            },
+          } )?;
+          Ok( into_response( status, &headers, body ) )
+        }
+      }
+    }
+
+    fn answer( &self, request : &CassetteRequest ) -> Result< reqwest::Response >
+    {
+      let mut state = self.lock();
+      let State { interactions, used } = &mut *state;
+      let index = interactions.iter().zip( used.iter() )
+        .position( | ( interaction, used ) | !used && same_request( &interaction.request, request ) )
+        .ok_or_else( || OpenAiCompatError::Environment( format!( "cassette {} has no unused interaction for {} {}", self.path.display(), request.method, request.url ) ) )?;
+      used[ index ] = true;
+      let response = &interactions[ index ].response;
+      let status = StatusCode::from_u16( response.status ).map_err( | e | cassette_error( &self.path, &e ) )?;
+      let mut headers = HeaderMap::new();
+      for ( name, value ) in &response.headers
+      {
+        if let ( Ok( name ), Ok( value ) ) = ( HeaderName::try_from( name.as_str() ), HeaderValue::try_from( value.as_str() ) )
+        {
+          headers.insert( name, value );
+        }
+      }
+      let body = match &response.body
+      {
+        Value::String( text ) => text.clone().into_bytes(),
+        other => other.to_string().into_bytes(),
+      };
+      Ok( into_response( status, &headers, body ) )
+    }
+
+    fn append( &self, interaction : Interaction ) -> Result< () >
+    {
+      let mut state = self.lock();
+      state.interactions.push( interaction );
+      state.used.push( false );
+      let file = CassetteFile { interactions : state.interactions.clone() };
+      let text = serde_json::to_string_pretty( &file ).map_err( OpenAiCompatError::from )?;
+      if let Some( parent ) = self.path.parent().filter( | parent | !parent.as_os_str().is_empty() )
+      {
+        std::fs::create_dir_all( parent ).map_err( | e | cassette_error( &self.path, &e ) )?;
+      }
+      std::fs::write( &self.path, text + "\n" ).map_err( | e | cassette_error( &self.path, &e ) )?;
+      Ok( () )
+    }
+
+    fn lock( &self ) -> MutexGuard< '_, State >
+    {
+      self.state.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+
+  fn cassette_error( path : &Path, error : &dyn core::fmt::Display ) -> OpenAiCompatError
+  {
+    OpenAiCompatError::Environment( format!( "cassette {} : {error}", path.display() ) )
+  }
+
+  /// `request` as it is written to a cassette, credentials scrubbed.
+  fn scrub_request( request : &reqwest::Request, secret : &str ) -> CassetteRequest
+  {
+    let body = request.body()
+      .and_then( reqwest::Body::as_bytes )
+      .filter( | bytes | !bytes.is_empty() )
+      .map( | bytes | stored_body( &api_secret::redact( &String::from_utf8_lossy( bytes ), secret ) ) );
+    CassetteRequest
+    {
+      method : request.method().to_string(),
+      url : api_secret::redact( request.url().as_str(), secret ),
+      headers : header_map( request.headers(), Some( secret ) ),
+      body,
+    }
+  }
+
+  /// `headers` as a sorted map; with a secret, credential values are
+  /// replaced and the secret masked elsewhere.
+  fn header_map( headers : &HeaderMap, secret : Option< &str > ) -> BTreeMap< String, String >
+  {
+    headers.iter().map( | ( name, value ) |
+    {
+      let text = String::from_utf8_lossy( value.as_bytes() );
+      let text = match secret
+      {
+        Some( _ ) if value.is_sensitive() || CREDENTIAL_HEADERS.contains( &name.as_str() ) => REDACTED.to_string(),
+        Some( secret ) => api_secret::redact( &text, secret ),
+        None => text.into_owned(),
+      };
+      ( name.as_str().to_string(), text )
+    } ).collect()
+  }
+
+  /// `text` as JSON when it is an object or array, else as a string.
+  fn stored_body( text : &str ) -> Value
+  {
+    match serde_json::from_str::< Value >( text )
+    {
+      Ok( value ) if value.is_object() || value.is_array() => value,
+      _ => Value::String( text.to_string() ),
+    }
+  }
+
+  /// Whether `recorded` and `request` are the same call.
+  fn same_request( recorded : &CassetteRequest, request : &CassetteRequest ) -> bool
+  {
+    recorded.method == request.method && recorded.url == request.url && recorded.body == request.body
+  }
+
+  fn into_response( status : StatusCode, headers : &HeaderMap, body : Vec< u8 > ) -> reqwest::Response
+  {
+    let mut response = http::Response::new( body );
+    *response.status_mut() = status;
+    *response.headers_mut() = headers.clone();
+    reqwest::Response::from( response )
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    const SECRET : &str = "sk-record-0123456789abcdef";
+
+    #[ test ]
+    fn scrub_request_hides_the_key_everywhere()
+    {
+      let request = reqwest::Client::new()
+        .post( format!( "https://api.example.invalid/v1/chat/completions?key={SECRET}" ) )
+        .bearer_auth( SECRET )
+        .header( "x-echo", format!( "key {SECRET}" ) )
+        .body( format!( r#"{{"model":"qwen2.5","note":"{SECRET}"}}"# ) )
+        .build()
+        .unwrap();
+
+      let recorded = scrub_request( &request, SECRET );
+
+      assert_eq!( recorded.method, "POST" );
+      assert!( !recorded.url.contains( SECRET ), "{}", recorded.url );
+      assert_eq!( recorded.headers[ "authorization" ], REDACTED );
+      assert_eq!( recorded.headers[ "x-echo" ], "key sk-r****" );
+      assert_eq!( recorded.body, Some( serde_json::json!( { "model" : "qwen2.5", "note" : "sk-r****" } ) ) );
+    }
+
+    #[ test ]
+    fn response_headers_are_kept_as_sent()
+    {
+      let mut headers = HeaderMap::new();
+      headers.insert( "x-request-id", HeaderValue::from_static( "req-1" ) );
+
+      assert_eq!( header_map( &headers, None )[ "x-request-id" ], "req-1" );
+    }
+
+    #[ test ]
+    fn stored_body_keeps_json_documents_and_text()
+    {
+      assert_eq!( stored_body( r#"{"ok":true}"# ), serde_json::json!( { "ok" : true } ) );
+      assert_eq!( stored_body( "[1]" ), serde_json::json!( [ 1 ] ) );
+      assert_eq!( stored_body( "data: [DONE]\n\n" ), Value::String( "data: [DONE]\n\n".into() ) );
+      assert_eq!( stored_body( "42" ), Value::String( "42".into() ) );
+    }
+
+    #[ test ]
+    fn same_request_compares_bodies_as_values()
+    {
+      let call = | body : Value | CassetteRequest { method : "POST".into(), url : "https://host/v1/chat/completions".into(), headers : BTreeMap::new(), body : Some( body ) };
+      let recorded = call( serde_json::json!( { "model" : "qwen2.5", "n" : 1 } ) );
+
+      assert!( same_request( &recorded, &call( serde_json::from_str( r#"{"n":1,"model":"qwen2.5"}"# ).unwrap() ) ) );
+      assert!( !same_request( &recorded, &call( serde_json::json!( { "model" : "other" } ) ) ) );
+      assert!( !same_request( &recorded, &CassetteRequest { method : "GET".into(), ..call( serde_json::json!( { "model" : "qwen2.5", "n" : 1 } ) ) } ) );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Cassette,
+    CassetteMode,
+    CassetteRequest,
+    CassetteResponse,
+    Interaction,
+    RECORD_ENV_VAR,
+  };
+}
@@ -0,0 +1,254 @@
+//! Per-request options for the shared client.
+//!
+//! [`RequestOptions`] carries settings that belong to one call rather than to
+//! the client, passed to [`Client::post_with_options`](crate::Client::post_with_options)
+//! and [`Client::get_with_options`](crate::Client::get_with_options): an
+//! idempotency key, extra headers such as gateway routing headers, and a
+//! cancellation token.
+
+mod private
+{
+  use crate::error::Result;
+  use core::fmt;
+  use core::future::Future;
+
+  /// Settings for a single request.
+  ///
+  /// The options are fixed before the request is first sent, so every attempt
+  /// of the call — retries included — carries the same values.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_openai_compatible::RequestOptions;
+  ///
+  /// let options = RequestOptions::new()
+  ///   .with_header( "x-portkey-provider", "openai" )
+  ///   .with_sensitive_header( "x-portkey-api-key", "pk-live-0123456789" )
+  ///   .with_idempotency_key( "order-42" );
+  ///
+  /// assert_eq!( options.header( "X-Portkey-Provider" ), Some( "openai" ) );
+  /// assert!( !format!( "{options:?}" ).contains( "pk-live-0123456789" ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct RequestOptions
+  {
+    idempotency_key : Option< String >,
+    headers : Vec< ExtraHeader >,
+    #[ cfg( feature = "cancellation" ) ]
+    cancellation : Option< api_common::CancellationToken >,
+  }
+
+  impl RequestOptions
+  {
+    /// Options with nothing set; the request is sent as by `post`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Sends `key` in the `Idempotency-Key` header, so a provider that
+    /// honours it applies a repeated write only once.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_idempotency_key( mut self, key : impl Into< String > ) -> Self
+    {
+      self.idempotency_key = Some( key.into() );
+      self
+    }
+
+    /// Sends a freshly generated `api_common::IdempotencyKey`.
+    #[ cfg( feature = "idempotency" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn idempotent( self ) -> Self
+    {
+      self.with_idempotency_key( api_common::IdempotencyKey::generate() )
+    }
+
+    /// The idempotency key, if one is set.
+    #[ inline ]
+    #[ must_use ]
+    pub fn idempotency_key( &self ) -> Option< &str >
+    {
+      self.idempotency_key.as_deref()
+    }
+
+    /// Sends `name: value` with this request, replacing a header of the
+    /// same name the environment sets, e.g. a gateway routing header or
+    /// Azure's `api-key`. Setting a name again replaces the earlier value;
+    /// names are compared ignoring case.
+    ///
+    /// The name and value are checked when the request is built; an invalid
+    /// one fails the call with `OpenAiCompatError::Environment`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_header( self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.insert_header( name.into(), value.into(), false )
+    }
+
+    /// [`with_header`](Self::with_header) for a credential: the value is
+    /// marked sensitive, so it is masked in `Debug` output, curl commands,
+    /// and cassettes.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_sensitive_header( self, name : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.insert_header( name.into(), value.into(), true )
+    }
+
+    /// The value of the extra header `name`, if one is set.
+    #[ inline ]
+    #[ must_use ]
+    pub fn header( &self, name : &str ) -> Option< &str >
+    {
+      self.headers.iter().find( | header | header.name.eq_ignore_ascii_case( name ) ).map( | header | header.value.as_str() )
+    }
+
+    /// The extra headers as `( name, value, sensitive )`, in the order set.
+    pub( crate ) fn extra_headers( &self ) -> impl Iterator< Item = ( &str, &str, bool ) >
+    {
+      self.headers.iter().map( | header | ( header.name.as_str(), header.value.as_str(), header.sensitive ) )
+    }
+
+    fn insert_header( mut self, name : String, value : String, sensitive : bool ) -> Self
+    {
+      self.headers.retain( | header | !header.name.eq_ignore_ascii_case( &name ) );
+      self.headers.push( ExtraHeader { name, value, sensitive } );
+      self
+    }
+
+    /// Ends the call with `api_common::Cancelled` once `token` is
+    /// cancelled, wherever it is: queued for the limiter, sending, reading
+    /// the response, or waiting between retries. The request is dropped
+    /// with its connection.
+    #[ cfg( feature = "cancellation" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_cancellation( mut self, token : api_common::CancellationToken ) -> Self
+    {
+      self.cancellation = Some( token );
+      self
+    }
+
+    /// The cancellation token, if one is set.
+    #[ cfg( feature = "cancellation" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn cancellation( &self ) -> Option< &api_common::CancellationToken >
+    {
+      self.cancellation.as_ref()
+    }
+
+    /// Runs `call`, ending it with `api_common::Cancelled` as soon as the
+    /// cancellation token, if any, is cancelled.
+    #[ cfg_attr( not( feature = "cancellation" ), allow( clippy::unused_async ) ) ]
+    pub( crate ) async fn cancellable< T >( &self, call : impl Future< Output = Result< T > > ) -> Result< T >
+    {
+      #[ cfg( feature = "cancellation" ) ]
+      if let Some( token ) = &self.cancellation
+      {
+        return token.run_until_cancelled( call ).await?;
+      }
+      call.await
+    }
+  }
+
+  #[ cfg( all( test, feature = "cancellation" ) ) ]
+  mod tests
+  {
+    use super::*;
+    use api_common::{ CancellationToken, Cancelled };
+    use core::sync::atomic::{ AtomicBool, Ordering };
+    use core::time::Duration;
+    use std::sync::Arc;
+
+    /// Sets its flag when dropped, as a pending request closes its connection.
+    struct DropFlag( Arc< AtomicBool > );
+
+    impl Drop for DropFlag
+    {
+      fn drop( &mut self )
+      {
+        self.0.store( true, Ordering::SeqCst );
+      }
+    }
+
+    #[ tokio::test ]
+    async fn cancellation_ends_pending_call_and_drops_it()
+    {
+      let dropped = Arc::new( AtomicBool::new( false ) );
+      let flag = DropFlag( Arc::clone( &dropped ) );
+      let token = CancellationToken::new();
+      let canceller = token.clone();
+      tokio::spawn( async move
+      {
+        tokio::time::sleep( Duration::from_millis( 20 ) ).await;
+        canceller.cancel();
+      } );
+      let options = RequestOptions::new().with_cancellation( token );
+
+      let error = options.cancellable( async move
+      {
+        let _flag = flag;
+        core::future::pending::< Result< () > >().await
+      } ).await.expect_err( "the call must be cancelled" );
+
+      assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+      assert!( dropped.load( Ordering::SeqCst ), "the call must be dropped" );
+    }
+
+    #[ tokio::test ]
+    async fn token_cancelled_beforehand_never_starts_the_call()
+    {
+      let started = AtomicBool::new( false );
+      let token = CancellationToken::new();
+      token.cancel();
+      let options = RequestOptions::new().with_cancellation( token );
+
+      let error = options.cancellable( async { started.store( true, Ordering::SeqCst ); Ok( () ) } ).await
+        .expect_err( "the call must be cancelled" );
+
+      assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+      assert!( !started.load( Ordering::SeqCst ), "the call must not start" );
+    }
+
+    #[ tokio::test ]
+    async fn call_without_token_runs_to_completion()
+    {
+      assert_eq!( RequestOptions::new().cancellable( async { Ok( 7 ) } ).await.unwrap(), 7 );
+    }
+  }
+
+  /// One header set with [`RequestOptions::with_header`].
+  #[ derive( Clone, PartialEq, Eq ) ]
+  struct ExtraHeader
+  {
+    name : String,
+    value : String,
+    sensitive : bool,
+  }
+
+  impl fmt::Debug for ExtraHeader
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let value = if self.sensitive { api_secret::mask( &self.value ) } else { self.value.clone() };
+      f.debug_tuple( "ExtraHeader" ).field( &self.name ).field( &value ).finish()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    RequestOptions,
+  };
+}
@@ -0,0 +1,461 @@
+//! Retries for the shared client.
+//!
+//! [`RetryConfig`] states how often and on which failures a call is sent
+//! again; [`ExecuteWithRetries::retrying`] applies it to one client call.
+//! Nothing is retried unless a call goes through `retrying`, and every
+//! attempt of a call carries the same options, idempotency key included.
+//! An `api_common::CallBudget` bounds the whole call, waits included.
+
+mod private
+{
+  use crate::client::Client;
+  use crate::environment::OpenAiCompatEnvironment;
+  use crate::error::{ OpenAiCompatError, Result };
+  use crate::request_options::RequestOptions;
+  use api_common::{ BudgetTracker, CallBudget };
+  use core::hash::{ BuildHasher, Hasher };
+  use core::time::Duration;
+  use reqwest::StatusCode;
+  use std::collections::hash_map::RandomState;
+
+  /// Failures a call is retried on.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  #[ allow( clippy::struct_excessive_bools ) ] // One switch per failure class
+  pub struct RetryOn
+  {
+    /// Connection failures and requests cut off before a status arrived.
+    pub network : bool,
+    /// Requests that exceeded the client's timeout.
+    pub timeout : bool,
+    /// `429 Too Many Requests`.
+    pub rate_limited : bool,
+    /// `5xx` statuses except `501`, and `408` and `409`, which `OpenAI`
+    /// asks clients to repeat.
+    pub server_errors : bool,
+  }
+
+  impl Default for RetryOn
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::transient()
+    }
+  }
+
+  impl RetryOn
+  {
+    /// Every transient failure: network errors, timeouts, 429, and server
+    /// errors.
+    #[ inline ]
+    #[ must_use ]
+    pub const fn transient() -> Self
+    {
+      Self { network : true, timeout : true, rate_limited : true, server_errors : true }
+    }
+
+    /// Only responses the server asked to repeat: 429 and server errors. Requests
+    /// that may or may not have reached the server are not sent again.
+    #[ inline ]
+    #[ must_use ]
+    pub const fn responses() -> Self
+    {
+      Self { network : false, timeout : false, rate_limited : true, server_errors : true }
+    }
+
+    /// Whether a response with `status` is retried. A `429` whose code
+    /// says the quota is spent is not, which only [`error`](Self::error)
+    /// can tell.
+    #[ inline ]
+    #[ must_use ]
+    pub fn status( &self, status : StatusCode ) -> bool
+    {
+      let server_error = ( status.is_server_error() && status != StatusCode::NOT_IMPLEMENTED )
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::CONFLICT;
+      ( self.rate_limited && status == StatusCode::TOO_MANY_REQUESTS ) || ( self.server_errors && server_error )
+    }
+
+    /// Whether a call that failed with `error` is retried: the error must
+    /// be [retryable](OpenAiCompatError::is_retryable) and its class
+    /// switched on.
+    #[ inline ]
+    #[ must_use ]
+    pub fn error( &self, error : &OpenAiCompatError ) -> bool
+    {
+      let switched_on = match error
+      {
+        OpenAiCompatError::Network( _ ) => self.network,
+        OpenAiCompatError::Timeout( _ ) => self.timeout,
+        OpenAiCompatError::RateLimited { .. } => self.rate_limited,
+        OpenAiCompatError::InvalidRequest { .. } | OpenAiCompatError::ServerError { .. } => self.server_errors,
+        _ => false,
+      };
+      switched_on && error.is_retryable()
+    }
+  }
+
+  /// How a call is retried: exponential backoff from `base_delay`, capped at
+  /// `max_delay`, over at most `max_attempts` attempts.
+  ///
+  /// A `Retry-After` (or `retry-after-ms`) header on a retried response
+  /// replaces the backoff; one longer than `max_delay` ends the call with
+  /// that response's error instead of waiting. With a `budget`, every call
+  /// starts it afresh and stops retrying once it runs out.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( all( feature = "enabled", feature = "retry" ) ) ]
+  /// # {
+  /// use api_openai_compatible::{ RetryConfig, RetryOn };
+  /// use core::time::Duration;
+  ///
+  /// let config = RetryConfig::new()
+  ///   .with_max_attempts( 5 )
+  ///   .with_base_delay( Duration::from_millis( 250 ) )
+  ///   .with_retry_on( RetryOn::responses() )
+  ///   .with_budget( api_common::CallBudget::new().with_total_deadline( Duration::from_secs( 20 ) ) );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct RetryConfig
+  {
+    /// Attempts in total, the first included; `1` disables retries.
+    pub max_attempts : u32,
+    /// Delay before the first retry; doubled for each one after.
+    pub base_delay : Duration,
+    /// Longest delay before a retry.
+    pub max_delay : Duration,
+    /// Whether each backoff delay is shortened by a random factor in
+    /// `[0.5, 1.0)`, so clients that failed together do not retry together.
+    pub jitter : bool,
+    /// Failures that are retried.
+    pub retry_on : RetryOn,
+    /// Overall limits for each call, across every attempt and wait.
+    pub budget : Option< CallBudget >,
+  }
+
+  impl Default for RetryConfig
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self
+      {
+        max_attempts : 3,
+        base_delay : Duration::from_millis( 500 ),
+        max_delay : Duration::from_secs( 30 ),
+        jitter : true,
+        retry_on : RetryOn::transient(),
+        budget : None,
+      }
+    }
+  }
+
+  impl RetryConfig
+  {
+    /// Three attempts, 500 ms base delay, 30 s cap, jitter, and every
+    /// transient failure retried.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Sets the number of attempts, the first included.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_attempts( mut self, attempts : u32 ) -> Self
+    {
+      self.max_attempts = attempts;
+      self
+    }
+
+    /// Sets the delay before the first retry.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_base_delay( mut self, delay : Duration ) -> Self
+    {
+      self.base_delay = delay;
+      self
+    }
+
+    /// Sets the longest delay before a retry.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_delay( mut self, delay : Duration ) -> Self
+    {
+      self.max_delay = delay;
+      self
+    }
+
+    /// Enables or disables jitter.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_jitter( mut self, jitter : bool ) -> Self
+    {
+      self.jitter = jitter;
+      self
+    }
+
+    /// Sets the failures that are retried.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_retry_on( mut self, retry_on : RetryOn ) -> Self
+    {
+      self.retry_on = retry_on;
+      self
+    }
+
+    /// Bounds each call by `budget`: its attempts, total time, and cost.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_budget( mut self, budget : CallBudget ) -> Self
+    {
+      self.budget = Some( budget );
+      self
+    }
+
+    /// Backoff before retry number `retry` (1 for the first retry):
+    /// `base_delay * 2^(retry - 1)`, capped at `max_delay`, then jittered.
+    #[ inline ]
+    #[ must_use ]
+    pub fn delay_for_retry( &self, retry : u32 ) -> Duration
+    {
+      let factor = 2_u32.checked_pow( retry.saturating_sub( 1 ) ).unwrap_or( u32::MAX );
+      let delay = self.base_delay.checked_mul( factor ).map_or( self.max_delay, | delay | delay.min( self.max_delay ) );
+      if self.jitter
+      {
+        delay.mul_f64( 0.5 + unit_random() * 0.5 )
+      }
+      else
+      {
+        delay
+      }
+    }
+
+    /// Delay before the attempt following failed attempt `attempt`, or
+    /// `None` when the call should end: no attempts left, the server asked
+    /// for a longer wait than `max_delay`, or `budget` does not allow the
+    /// wait and another attempt.
+    pub( crate ) fn delay_after( &self, attempt : u32, retry_after : Option< Duration >, budget : Option< &BudgetTracker > ) -> Option< Duration >
+    {
+      if attempt >= self.max_attempts
+      {
+        return None;
+      }
+      let delay = match retry_after
+      {
+        Some( wait ) if wait > self.max_delay => return None,
+        Some( wait ) => wait,
+        None => self.delay_for_retry( attempt ),
+      };
+      match budget
+      {
+        Some( budget ) if budget.allows_delay( delay ).is_err() => None,
+        _ => Some( delay ),
+      }
+    }
+  }
+
+  /// A float in `[0, 1)` from the standard library's per-process random
+  /// hash keys; good enough to spread retries, not for anything secret.
+  #[ allow( clippy::cast_precision_loss ) ]
+  fn unit_random() -> f64
+  {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    bits as f64 / ( 1_u64 << 53 ) as f64
+  }
+
+  /// A client call that is retried as its [`RetryConfig`] states.
+  ///
+  /// Made by [`ExecuteWithRetries::retrying`]. Every attempt goes through
+  /// the client's limiter, guardrails, and hooks, so response hooks see
+  /// each failed attempt too.
+  #[ derive( Debug ) ]
+  pub struct Retrying< 'a, E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    client : &'a Client< E >,
+    config : &'a RetryConfig,
+    budget : Option< &'a BudgetTracker >,
+  }
+
+  impl< 'a, E > Retrying< 'a, E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    /// Counts every attempt against `budget`, already started and shared
+    /// with the layers around this call, instead of the config's own
+    /// budget. Each attempt is cut off at its total deadline, and no retry
+    /// is made once it has run out.
+    #[ inline ]
+    #[ must_use ]
+    pub fn within( mut self, budget : &'a BudgetTracker ) -> Self
+    {
+      self.budget = Some( budget );
+      self
+    }
+
+    /// [`Client::post`], retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt, or the first error that is
+    /// not retried, and `api_common::BudgetExhausted` when the budget ran
+    /// out before an attempt or during one.
+    #[ inline ]
+    pub async fn post< I, O >( &self, path : &str, body : &I ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      self.post_with_options( path, body, &RequestOptions::new() ).await
+    }
+
+    /// [`Client::post_with_options`], retried. A generated idempotency key
+    /// is chosen once and sent on every attempt.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post).
+    #[ inline ]
+    pub async fn post_with_options< I, O >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      self.client.post_retried( path, body, options, self.config, self.budget ).await
+    }
+
+    /// [`Client::get`], retried.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post).
+    #[ inline ]
+    pub async fn get< O >( &self, path : &str ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      self.get_with_options( path, &RequestOptions::new() ).await
+    }
+
+    /// [`Client::get_with_options`], retried.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post).
+    #[ inline ]
+    pub async fn get_with_options< O >( &self, path : &str, options : &RequestOptions ) -> Result< O >
+    where
+      O : serde::de::DeserializeOwned,
+    {
+      self.client.get_retried( path, options, self.config, self.budget ).await
+    }
+  }
+
+  /// Retries for a client's calls.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( all( feature = "enabled", feature = "retry" ) ) ]
+  /// # {
+  /// use api_openai_compatible::{ ChatCompletionResponse, Client, ExecuteWithRetries, OpenAiCompatEnvironmentImpl, RetryConfig };
+  ///
+  /// # async fn example( request : api_openai_compatible::ChatCompletionRequest ) -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )?;
+  /// let retry = RetryConfig::new().with_max_attempts( 4 );
+  /// let response : ChatCompletionResponse = client.retrying( &retry ).post( "chat/completions", &request ).await?;
+  /// # Ok( () ) }
+  /// # }
+  /// ```
+  pub trait ExecuteWithRetries
+  {
+    /// Environment of the client.
+    type Environment : OpenAiCompatEnvironment;
+
+    /// The client's calls, retried as `config` states.
+    fn retrying< 'a >( &'a self, config : &'a RetryConfig ) -> Retrying< 'a, Self::Environment >;
+  }
+
+  impl< E > ExecuteWithRetries for Client< E >
+  where
+    E : OpenAiCompatEnvironment,
+  {
+    type Environment = E;
+
+    #[ inline ]
+    fn retrying< 'a >( &'a self, config : &'a RetryConfig ) -> Retrying< 'a, E >
+    {
+      Retrying { client : self, config, budget : None }
+    }
+  }
+
+  #[ cfg( test ) ]
+  mod tests
+  {
+    use super::*;
+
+    fn fast() -> RetryConfig
+    {
+      RetryConfig::new().with_base_delay( Duration::from_millis( 10 ) ).with_jitter( false )
+    }
+
+    #[ test ]
+    fn attempts_are_capped()
+    {
+      let config = fast().with_max_attempts( 2 );
+
+      assert_eq!( config.delay_after( 1, None, None ), Some( Duration::from_millis( 10 ) ) );
+      assert_eq!( config.delay_after( 2, None, None ), None );
+    }
+
+    #[ test ]
+    fn retry_after_replaces_backoff_up_to_max_delay()
+    {
+      let config = fast().with_max_delay( Duration::from_secs( 1 ) );
+
+      assert_eq!( config.delay_after( 1, Some( Duration::from_millis( 300 ) ), None ), Some( Duration::from_millis( 300 ) ) );
+      assert_eq!( config.delay_after( 1, Some( Duration::from_secs( 1 ) ), None ), Some( Duration::from_secs( 1 ) ) );
+      assert_eq!( config.delay_after( 1, Some( Duration::from_secs( 120 ) ), None ), None );
+    }
+
+    #[ test ]
+    fn budget_deadline_ends_retries_before_the_wait()
+    {
+      let config = fast().with_max_attempts( 5 );
+      let budget = CallBudget::new().with_total_deadline( Duration::from_millis( 100 ) ).start();
+
+      assert_eq!( config.delay_after( 1, None, Some( &budget ) ), Some( Duration::from_millis( 10 ) ) );
+      assert_eq!( config.delay_after( 1, Some( Duration::from_millis( 500 ) ), Some( &budget ) ), None );
+    }
+
+    #[ test ]
+    fn budget_attempts_end_retries()
+    {
+      let config = fast().with_max_attempts( 5 );
+      let budget = CallBudget::new().with_max_attempts( 2 ).start();
+
+      budget.begin_attempt().unwrap();
+      assert!( config.delay_after( 1, None, Some( &budget ) ).is_some() );
+      budget.begin_attempt().unwrap();
+      assert_eq!( config.delay_after( 2, None, Some( &budget ) ), None );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ExecuteWithRetries,
+    RetryConfig,
+    RetryOn,
+    Retrying,
+  };
+}
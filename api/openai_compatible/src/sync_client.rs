@@ -2,12 +2,15 @@
 //!
 //! Each [`SyncClient`] instance owns a `tokio::runtime::Runtime`. Prefer the
 //! async `Client` when possible; use `SyncClient` only for legacy or scripting
-//! contexts where async is not an option.
+//! contexts where async is not an option. With `streaming`, a streamed
+//! completion is read through [`SyncChatStream`], a plain iterator of chunks.
 
 mod private
 {
   use crate::error::{ OpenAiCompatError, Result };
-  use crate::{ Client, OpenAiCompatEnvironment };
+  use crate::{ Client, OpenAiCompatEnvironment, RequestOptions };
+  #[ cfg( feature = "streaming" ) ]
+  use crate::{ ChatCompletionChunk, ChunkDecoder };
   use std::sync::Arc;
   use tokio::runtime::Runtime;
 
@@ -71,6 +74,181 @@ mod private
     {
       self.runtime.block_on( self.client.post( path, body ) )
     }
+
+    /// Sends a blocking POST request with per-request `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns network, timeout, or deserialisation errors.
+    #[ inline ]
+    pub fn post_with_options< I, O >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< O >
+    where
+      I : serde::Serialize,
+      O : serde::de::DeserializeOwned,
+    {
+      self.runtime.block_on( self.client.post_with_options( path, body, options ) )
+    }
+
+    /// Blocking [`Client::warm_up`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a network or timeout error if no connection could be opened.
+    #[ inline ]
+    pub fn warm_up( &self ) -> Result< core::time::Duration >
+    {
+      self.runtime.block_on( self.client.warm_up() )
+    }
+
+    /// Sends a blocking POST request for a streamed completion and returns
+    /// its chunks as an iterator.
+    ///
+    /// The request must ask for a stream (`stream : Some( true )`). The
+    /// call returns once the response headers arrive; each chunk is read
+    /// from the body as the iterator asks for it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( all( feature = "sync_api", feature = "streaming" ) ) ]
+    /// # {
+    /// use api_openai_compatible::{ ChatCompletionRequest, Client, Message, OpenAiCompatEnvironmentImpl, SyncClient };
+    ///
+    /// # fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let sync = SyncClient::new( Client::build( OpenAiCompatEnvironmentImpl::new( "sk-..." )? )? )?;
+    /// let request = ChatCompletionRequest::former()
+    ///   .model( "gpt-4o-mini".to_string() )
+    ///   .messages( vec![ Message::user( "Hi" ) ] )
+    ///   .stream( true )
+    ///   .form();
+    /// for chunk in sync.post_stream( "chat/completions", &request )?
+    /// {
+    ///   if let Some( text ) = &chunk?.choices[ 0 ].delta.content
+    ///   {
+    ///     print!( "{text}" );
+    ///   }
+    /// }
+    /// # Ok( () ) }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns network or timeout errors, and the error
+    /// `OpenAiCompatError::from_response` classifies when the status is not
+    /// 2xx.
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    pub fn post_stream< I >( &self, path : &str, body : &I ) -> Result< SyncChatStream >
+    where
+      I : serde::Serialize,
+    {
+      self.post_stream_with_options( path, body, &RequestOptions::new() )
+    }
+
+    /// [`post_stream`](Self::post_stream) with per-request `options`. A
+    /// cancellation token in the options also ends the reading of the body.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post_stream`](Self::post_stream), and `api_common::Cancelled`
+    /// when the call is cancelled before the headers arrive.
+    #[ cfg( feature = "streaming" ) ]
+    #[ inline ]
+    pub fn post_stream_with_options< I >( &self, path : &str, body : &I, options : &RequestOptions ) -> Result< SyncChatStream >
+    where
+      I : serde::Serialize,
+    {
+      let response = self.runtime.block_on( self.client.post_with_options_raw( path, body, options ) )?;
+      let status = response.status();
+      if !status.is_success()
+      {
+        let headers = response.headers().clone();
+        let body = self.runtime.block_on( options.cancellable( async { Ok( response.bytes().await ) } ) )?;
+        return Err( OpenAiCompatError::from_response( status, &headers, &body.unwrap_or_default() ).into() );
+      }
+      Ok( SyncChatStream
+      {
+        runtime : Arc::clone( &self.runtime ),
+        response : Some( response ),
+        decoder : ChunkDecoder::new().with_profile( self.client.provider_profile() ),
+        options : options.clone(),
+      } )
+    }
+  }
+
+  /// Blocking iterator over the chunks of a streamed completion, returned by
+  /// [`SyncClient::post_stream`].
+  ///
+  /// Each `next` blocks on the client's runtime until a whole chunk has
+  /// arrived, so the caller needs no async runtime of its own. Iteration
+  /// ends after `[DONE]` or when the body ends. A chunk that fails to decode
+  /// is yielded as an error and the stream goes on; a failed read, or a
+  /// cancelled token, is yielded as an error and ends it.
+  #[ cfg( feature = "streaming" ) ]
+  #[ derive( Debug ) ]
+  pub struct SyncChatStream
+  {
+    runtime : Arc< Runtime >,
+    /// The body being read; `None` once it has ended or failed.
+    response : Option< reqwest::Response >,
+    decoder : ChunkDecoder,
+    options : RequestOptions,
+  }
+
+  #[ cfg( feature = "streaming" ) ]
+  impl SyncChatStream
+  {
+    /// The id of the last event, to resume with `Last-Event-ID`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn last_event_id( &self ) -> Option< &str >
+    {
+      self.decoder.last_event_id()
+    }
+  }
+
+  #[ cfg( feature = "streaming" ) ]
+  impl Iterator for SyncChatStream
+  {
+    type Item = Result< ChatCompletionChunk >;
+
+    #[ inline ]
+    fn next( &mut self ) -> Option< Self::Item >
+    {
+      loop
+      {
+        if let Some( chunk ) = self.decoder.next_chunk()
+        {
+          return Some( chunk );
+        }
+        if self.decoder.is_done()
+        {
+          // Dropping the response closes the connection of an unfinished body
+          self.response = None;
+          return None;
+        }
+        let response = self.response.as_mut()?;
+        let read = self.runtime.block_on( self.options.cancellable( async
+        {
+          response.chunk().await.map_err( | e | OpenAiCompatError::from( e ).into() )
+        } ) );
+        match read
+        {
+          Ok( Some( bytes ) ) => self.decoder.push( &bytes ),
+          Ok( None ) =>
+          {
+            self.decoder.finish();
+            self.response = None;
+          }
+          Err( error ) =>
+          {
+            self.response = None;
+            return Some( Err( error ) );
+          }
+        }
+      }
+    }
   }
 }
 
@@ -80,4 +258,7 @@ crate::mod_interface!
   {
     SyncClient,
   };
+
+  #[ cfg( feature = "streaming" ) ]
+  exposed use private::SyncChatStream;
 }
@@ -0,0 +1,172 @@
+//! TLS settings for gateways that use their own certificate authority.
+//!
+//! On-prem vLLM or TGI gateways are often served with a certificate from a
+//! private CA, sit behind a corporate proxy that re-signs traffic, or ask
+//! the client for a certificate of its own. A [`TlsConfig`] carried by the
+//! environment adds trusted roots and a client identity (mTLS) when the
+//! client builds its HTTP client; certificate checks can only be switched
+//! off by an explicit, loudly named opt-in.
+
+mod private
+{
+  use crate::error::{ OpenAiCompatError, Result };
+
+  /// Certificate chain and private key presented for mutual TLS.
+  #[ derive( Clone, PartialEq, Eq ) ]
+  struct ClientIdentity
+  {
+    certificate_pem : Vec< u8 >,
+    key_pem : Vec< u8 >,
+  }
+
+  /// TLS settings applied when the client is built.
+  ///
+  /// PEM data is stored as given and parsed when the client is built, so a
+  /// malformed certificate fails `Client::build` rather than the first
+  /// request. Needs the `rustls` or `native_tls` backend.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// # #[ cfg( feature = "tls" ) ]
+  /// # {
+  /// use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, TlsConfig };
+  ///
+  /// let tls = TlsConfig::new()
+  ///   .with_root_certificate_pem( std::fs::read( "/etc/gateway/ca.pem" ).unwrap() )
+  ///   .with_client_identity_pem( std::fs::read( "client.pem" ).unwrap(), std::fs::read( "client_key.pem" ).unwrap() );
+  /// let env = OpenAiCompatEnvironmentImpl::new( "sk-local" ).unwrap()
+  ///   .with_base_url( "https://vllm.internal:8443/v1/" )
+  ///   .with_tls( tls );
+  /// let client = Client::build( env ).unwrap();
+  /// # }
+  /// ```
+  #[ derive( Clone, Default, PartialEq, Eq ) ]
+  pub struct TlsConfig
+  {
+    root_certificates : Vec< Vec< u8 > >,
+    identity : Option< ClientIdentity >,
+    accept_invalid_certs : bool,
+  }
+
+  impl core::fmt::Debug for TlsConfig
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "TlsConfig" )
+        .field( "root_certificates", &self.root_certificates.len() )
+        .field( "client_identity", &self.identity.is_some() )
+        .field( "accept_invalid_certs", &self.accept_invalid_certs )
+        .finish()
+    }
+  }
+
+  impl TlsConfig
+  {
+    /// Settings that keep the backend's defaults: system or bundled roots,
+    /// no client certificate, certificates checked.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Trusts the certificates in `pem` in addition to the default roots.
+    /// `pem` may hold a bundle of several certificates.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_root_certificate_pem( mut self, pem : impl Into< Vec< u8 > > ) -> Self
+    {
+      self.root_certificates.push( pem.into() );
+      self
+    }
+
+    /// Presents `certificate_pem`, with any intermediates after it, and its
+    /// private key to servers that ask for a client certificate. The key
+    /// must be PKCS#8 PEM (`BEGIN PRIVATE KEY`).
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_client_identity_pem( mut self, certificate_pem : impl Into< Vec< u8 > >, key_pem : impl Into< Vec< u8 > > ) -> Self
+    {
+      self.identity = Some( ClientIdentity { certificate_pem : certificate_pem.into(), key_pem : key_pem.into() } );
+      self
+    }
+
+    /// Accepts any server certificate, including expired, self-signed, and
+    /// wrong-host ones.
+    ///
+    /// Anyone on the network path can then read and alter the traffic, API
+    /// key included. Meant for a gateway on a trusted local network whose CA
+    /// cannot be obtained; prefer
+    /// [`with_root_certificate_pem`](Self::with_root_certificate_pem).
+    #[ inline ]
+    #[ must_use ]
+    pub fn danger_accept_invalid_certs( mut self ) -> Self
+    {
+      self.accept_invalid_certs = true;
+      self
+    }
+
+    /// Whether server certificates go unchecked.
+    #[ inline ]
+    #[ must_use ]
+    pub fn accepts_invalid_certs( &self ) -> bool
+    {
+      self.accept_invalid_certs
+    }
+
+    /// Applies the settings to `builder`.
+    #[ cfg( any( feature = "rustls", feature = "native_tls" ) ) ]
+    pub( crate ) fn apply( &self, mut builder : reqwest::ClientBuilder ) -> Result< reqwest::ClientBuilder >
+    {
+      for pem in &self.root_certificates
+      {
+        let certificates = reqwest::Certificate::from_pem_bundle( pem )
+          .map_err( | e | OpenAiCompatError::Environment( format!( "invalid root certificate : {e}" ) ) )?;
+        if certificates.is_empty()
+        {
+          return Err( OpenAiCompatError::Environment( "invalid root certificate : no certificate in PEM".to_string() ).into() );
+        }
+        builder = builder.tls_certs_merge( certificates );
+      }
+      if let Some( identity ) = &self.identity
+      {
+        // The native backend wins when both are enabled, and each parses its
+        // own identity type.
+        #[ cfg( feature = "native_tls" ) ]
+        let parsed = reqwest::Identity::from_pkcs8_pem( &identity.certificate_pem, &identity.key_pem );
+        #[ cfg( not( feature = "native_tls" ) ) ]
+        let parsed = reqwest::Identity::from_pem( &[ identity.certificate_pem.as_slice(), b"\n", identity.key_pem.as_slice() ].concat() );
+        let parsed = parsed.map_err( | e | OpenAiCompatError::Environment( format!( "invalid client identity : {e}" ) ) )?;
+        builder = builder.identity( parsed );
+      }
+      if self.accept_invalid_certs
+      {
+        builder = builder.tls_danger_accept_invalid_certs( true );
+      }
+      Ok( builder )
+    }
+
+    /// Without a TLS backend nothing can honour the settings, so any setting
+    /// is refused rather than ignored.
+    #[ cfg( not( any( feature = "rustls", feature = "native_tls" ) ) ) ]
+    pub( crate ) fn apply( &self, builder : reqwest::ClientBuilder ) -> Result< reqwest::ClientBuilder >
+    {
+      if *self == Self::default()
+      {
+        return Ok( builder );
+      }
+      Err( OpenAiCompatError::Environment( "TLS settings require the `rustls` or `native_tls` feature".to_string() ).into() )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    TlsConfig,
+  };
+}
@@ -0,0 +1,198 @@
+//! Tests for the audio endpoints: the forms and bodies requests build, and
+//! transcription, translation, and speech against the real API.
+//!
+//! Encoding of the multipart body and reading of each transcription format
+//! are unit tests in `src/multipart.rs` and `src/audio.rs`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | transcription_form_lists_fields_in_order | request | Model, language, the last response format, timestamp granularities, and extra fields in order; the file with its media type |
+//! | translation_form_carries_no_language | request | Model, prompt, temperature, and format but no language; the file last |
+//! | speech_request_sends_only_set_fields | request | The JSON body holds the set fields and extra ones, nothing unset |
+//! | speech_streams_real_audio | integration | Real speech arrives in chunks with its audio media type; `bytes` reads it whole |
+//! | transcription_reads_real_speech | integration | Real speech transcribed as `json`, `verbose_json` with word timestamps, and `srt` |
+//! | translation_reads_real_speech | integration | `audio/translations` returns English text for real speech |
+//! | real_errors_are_classified | integration | An unknown voice and a file that is not audio are `InvalidRequest` with status 400 |
+
+#![ cfg( feature = "audio" ) ]
+
+use api_openai_compatible::
+{
+  SpeechFormat, SpeechRequest, TimestampGranularity, TranscriptionFormat, TranscriptionRequest, TranslationRequest, UploadFile,
+};
+
+/// Bytes that are not UTF-8 and hold a CRLF, as real audio does.
+const AUDIO : &[ u8 ] = &[ 0x49, 0x44, 0x33, 0xff, 0xfe, 0x00, b'\r', b'\n', 0x80 ];
+
+/// Text fields of a form as `( name, value )` pairs.
+fn texts( form : &api_openai_compatible::MultipartForm ) -> Vec< ( &str, &str ) >
+{
+  form.texts().iter().map( | ( name, value ) | ( name.as_str(), value.as_str() ) ).collect()
+}
+
+// ------------------------------------------------------------------ //
+
+/// The typed methods add their fields in call order; a second response
+/// format replaces the first rather than adding a field.
+#[ test ]
+fn transcription_form_lists_fields_in_order()
+{
+  let request = TranscriptionRequest::new( "whisper-1", UploadFile::new( "clip.MP3", AUDIO ) )
+    .with_language( "en" )
+    .with_response_format( TranscriptionFormat::Json )
+    .with_response_format( TranscriptionFormat::VerboseJson )
+    .with_timestamp_granularities( [ TimestampGranularity::Word, TimestampGranularity::Segment ] )
+    .with_field( "beam_size", "5" );
+
+  assert_eq!( texts( request.form() ),
+  [
+    ( "model", "whisper-1" ),
+    ( "language", "en" ),
+    ( "response_format", "verbose_json" ),
+    ( "timestamp_granularities[]", "word" ),
+    ( "timestamp_granularities[]", "segment" ),
+    ( "beam_size", "5" ),
+  ] );
+  let files = request.form().files();
+  assert_eq!( files.len(), 1 );
+  assert_eq!( ( files[ 0 ].0.as_str(), files[ 0 ].1.file_name(), files[ 0 ].1.content_type(), files[ 0 ].1.bytes() ), ( "file", "clip.MP3", "audio/mpeg", AUDIO ) );
+}
+
+/// Translations always produce English, so the form has no language.
+#[ test ]
+fn translation_form_carries_no_language()
+{
+  let request = TranslationRequest::new( "whisper-large-v3", UploadFile::new( "guten_morgen.ogg", AUDIO ) )
+    .with_prompt( "Formal register." )
+    .with_temperature( 0.0 )
+    .with_response_format( TranscriptionFormat::Json );
+
+  assert_eq!( texts( request.form() ), [ ( "model", "whisper-large-v3" ), ( "prompt", "Formal register." ), ( "temperature", "0" ), ( "response_format", "json" ) ] );
+  assert_eq!( request.form().files()[ 0 ].1.content_type(), "audio/ogg" );
+}
+
+/// Speech is JSON; fields left unset are not sent.
+#[ test ]
+fn speech_request_sends_only_set_fields()
+{
+  let request = SpeechRequest::new( "tts-1", "Hello there!", "alloy" ).with_response_format( SpeechFormat::Opus ).with_speed( 1.25 );
+  assert_eq!( serde_json::to_value( &request ).expect( "the request must serialise" ),
+    serde_json::json!( { "model" : "tts-1", "input" : "Hello there!", "voice" : "alloy", "response_format" : "opus", "speed" : 1.25 } ) );
+
+  let mut request = SpeechRequest::new( "kokoro", "Hi", "af_bella" ).with_instructions( "Cheerful." );
+  request.extra.insert( "lang_code".into(), "a".into() );
+  assert_eq!( serde_json::to_value( &request ).expect( "the request must serialise" ),
+    serde_json::json!( { "model" : "kokoro", "input" : "Hi", "voice" : "af_bella", "instructions" : "Cheerful.", "lang_code" : "a" } ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Client on the real API.
+#[ cfg( feature = "integration" ) ]
+fn real_client() -> api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl >
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" )
+    .with_timeout( Duration::from_secs( 120 ) );
+  Client::build( env ).expect( "Client::build() must succeed" )
+}
+
+/// Real speech of `text` as an MP3 upload, to transcribe or translate.
+#[ cfg( feature = "integration" ) ]
+async fn real_speech( client : &api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl >, text : &str ) -> UploadFile
+{
+  let audio = client.speech( &SpeechRequest::new( "tts-1", text, "alloy" ) ).await.expect( "speech must start" )
+    .bytes().await.expect( "the audio must be readable" );
+  UploadFile::new( "speech.mp3", audio.to_vec() )
+}
+
+/// Speech is read as it arrives, in as many chunks as the server sends.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn speech_streams_real_audio()
+{
+  let client = real_client();
+  let request = SpeechRequest::new( "tts-1", "Hello there, this is a streaming test.", "alloy" ).with_response_format( SpeechFormat::Opus );
+
+  let mut stream = client.speech( &request ).await.expect( "speech must start" );
+  assert_eq!( stream.content_type(), Some( "audio/ogg" ) );
+  let mut received = Vec::new();
+  while let Some( chunk ) = stream.next_chunk().await.expect( "the audio must be readable" )
+  {
+    received.extend_from_slice( &chunk );
+  }
+  assert!( received.starts_with( b"OggS" ), "opus audio must come in an Ogg container" );
+
+  let whole = client.speech( &request.with_response_format( SpeechFormat::Mp3 ) ).await.expect( "speech must start" )
+    .bytes().await.expect( "the audio must be readable" );
+  assert!( whole.len() > 1024, "the audio must not be empty; got {} bytes", whole.len() );
+}
+
+/// Each format comes back the way it is read: JSON deserialised, subtitles
+/// as text.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn transcription_reads_real_speech()
+{
+  let client = real_client();
+  let file = real_speech( &client, "Hello world, this is a transcription test." ).await;
+
+  let plain = client.transcribe( &TranscriptionRequest::new( "whisper-1", file.clone() ) ).await.expect( "json must deserialise" );
+  assert!( plain.text.to_lowercase().contains( "transcription" ), "{}", plain.text );
+
+  let request = TranscriptionRequest::new( "whisper-1", file.clone() )
+    .with_language( "en" )
+    .with_response_format( TranscriptionFormat::VerboseJson )
+    .with_timestamp_granularities( [ TimestampGranularity::Word ] );
+  let verbose = client.transcribe( &request ).await.expect( "verbose_json must deserialise" );
+  assert_eq!( verbose.language.as_deref(), Some( "english" ) );
+  assert!( verbose.duration.is_some_and( | duration | duration > 0.0 ) );
+  let words = verbose.words.expect( "word timestamps must be read" );
+  assert!( words.iter().any( | word | word.word.eq_ignore_ascii_case( "hello" ) ), "{words:?}" );
+  assert!( words.windows( 2 ).all( | pair | pair[ 0 ].start <= pair[ 1 ].start ) );
+
+  let srt = client.transcribe( &TranscriptionRequest::new( "whisper-1", file ).with_response_format( TranscriptionFormat::Srt ) ).await
+    .expect( "srt must be returned" );
+  assert!( srt.text.starts_with( "1\n00:00:00," ), "{:?}", srt.text );
+  assert!( srt.segments.is_none() );
+}
+
+/// Translation goes to its own endpoint and answers in English.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn translation_reads_real_speech()
+{
+  let client = real_client();
+  let file = real_speech( &client, "Guten Morgen, wie geht es Ihnen heute?" ).await;
+
+  let translation = client.translate( &TranslationRequest::new( "whisper-1", file ).with_temperature( 0.0 ) ).await
+    .expect( "the translation must succeed" );
+
+  assert!( translation.text.to_lowercase().contains( "morning" ), "{}", translation.text );
+}
+
+/// The API's 400 answers become typed errors for speech and uploads alike.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn real_errors_are_classified()
+{
+  use api_openai_compatible::OpenAiCompatError;
+
+  let client = real_client();
+
+  let error = client.speech( &SpeechRequest::new( "tts-1", "Hi", "nobody" ) ).await.expect_err( "an unknown voice must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 400 ), .. } ) ), "{error:?}" );
+
+  let error = client.transcribe( &TranscriptionRequest::new( "whisper-1", UploadFile::new( "clip.wav", AUDIO ) ) ).await
+    .expect_err( "a file that is not audio must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 400 ), .. } ) ), "{error:?}" );
+}
@@ -0,0 +1,133 @@
+//! Tests for `BatchExecutor`: concurrency limit, input order, per-item
+//! results, and timing.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | run_keeps_input_order_under_the_concurrency_limit | executor | Never more than `concurrency_limit` calls in flight; items in input order though calls end out of order; progress sees completion order; timings filled; `0` taken as `1` |
+//! | execute_posts_each_request_through_the_client | client | `execute` posts every request to `chat/completions` through the client's hooks; failures are reported per item; progress sees each |
+//! | execute_reports_each_real_chat_completion | integration | Real completions succeed while the one with an unknown model fails alone with `404` |
+
+#![ cfg( feature = "batch_operations" ) ]
+
+use api_openai_compatible::BatchExecutor;
+use core::sync::atomic::{ AtomicUsize, Ordering };
+use core::time::Duration;
+
+// ------------------------------------------------------------------ //
+
+/// Calls that end in reverse order still come back in input order, and the
+/// limit holds throughout.
+#[ tokio::test ]
+async fn run_keeps_input_order_under_the_concurrency_limit()
+{
+  assert_eq!( BatchExecutor::new( 0 ).concurrency_limit(), 1 );
+
+  let executor = BatchExecutor::new( 3 );
+  let in_flight = AtomicUsize::new( 0 );
+  let peak = AtomicUsize::new( 0 );
+  let mut finished = Vec::new();
+  let inputs : Vec< u64 > = ( 0..8 ).collect();
+
+  let report = executor.run_with_progress
+  (
+    inputs,
+    | input |
+    {
+      let ( in_flight, peak ) = ( &in_flight, &peak );
+      async move
+      {
+        let now = in_flight.fetch_add( 1, Ordering::SeqCst ) + 1;
+        peak.fetch_max( now, Ordering::SeqCst );
+        tokio::time::sleep( Duration::from_millis( 5 * ( 8 - input ) ) ).await;
+        in_flight.fetch_sub( 1, Ordering::SeqCst );
+        if input == 5
+        {
+          return Err( error_tools::untyped::format_err!( "input {input} failed" ) );
+        }
+        Ok( input * 10 )
+      }
+    },
+    | item | finished.push( item.index ),
+  ).await;
+
+  assert_eq!( peak.load( Ordering::SeqCst ), 3, "the limit must be reached and never passed" );
+  assert_ne!( finished, ( 0..8 ).collect::< Vec< _ > >(), "slower early calls must end after later ones" );
+  assert_eq!( report.items.iter().map( | item | item.index ).collect::< Vec< _ > >(), ( 0..8 ).collect::< Vec< _ > >() );
+  assert_eq!( ( report.succeeded(), report.failed() ), ( 7, 1 ) );
+  assert!( report.items.iter().all( | item | item.elapsed >= Duration::from_millis( 5 ) ) );
+  assert!( report.elapsed >= report.items.iter().map( | item | item.elapsed ).max().unwrap() );
+  assert!( report.mean_elapsed().is_some() );
+
+  let results = report.into_results();
+  assert_eq!( results[ 7 ].as_ref().ok(), Some( &70 ) );
+  assert!( results[ 5 ].as_ref().is_err_and( | error | error.to_string() == "input 5 failed" ) );
+
+  let empty = executor.run( Vec::< u64 >::new(), | input | async move { Ok( input ) } ).await;
+  assert!( empty.items.is_empty() && empty.mean_elapsed().is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `execute` sends each request to `chat/completions` through the client,
+/// and a call that fails is reported in its own item.
+#[ tokio::test ]
+async fn execute_posts_each_request_through_the_client()
+{
+  use api_openai_compatible::{ ChatCompletionRequest, Client, Message, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+  use std::sync::{ Arc, Mutex };
+
+  let urls = Arc::new( Mutex::new( Vec::new() ) );
+  let seen_urls = Arc::clone( &urls );
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-batch" ).expect( "environment construction must succeed" ).with_base_url( "http://127.0.0.1:1/v1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_request_hook( move | request | seen_urls.lock().expect( "lock" ).push( request.url.to_owned() ) );
+  let requests : Vec< _ > = [ "gpt-4o-mini", "gpt-4o" ].into_iter()
+    .map( | model | ChatCompletionRequest::former().model( model.to_string() ).messages( vec![ Message::user( "Hello" ) ] ).form() )
+    .collect();
+
+  let mut seen = 0;
+  let report = BatchExecutor::new( 2 ).execute_with_progress( &client, requests, | _ | seen += 1 ).await;
+
+  assert_eq!( seen, 2 );
+  assert_eq!( *urls.lock().expect( "lock" ), [ "http://127.0.0.1:1/v1/chat/completions"; 2 ] );
+  assert_eq!( ( report.succeeded(), report.failed() ), ( 0, 2 ) );
+  for item in &report.items
+  {
+    let error = item.result.as_ref().expect_err( "nothing listens on port 1" );
+    assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Network( _ ) ) ), "got {error}" );
+  }
+
+  let again = BatchExecutor::new( 2 ).execute( &client, Vec::new() ).await;
+  assert!( again.items.is_empty() );
+}
+
+/// Real chat completions run together; the one the API rejects fails alone.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn execute_reports_each_real_chat_completion()
+{
+  use api_openai_compatible::{ ChatCompletionRequest, Client, Message, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let client = Client::build( OpenAiCompatEnvironmentImpl::new( &api_key ).expect( "environment construction must succeed" ) )
+    .expect( "Client::build() must succeed" );
+  let requests : Vec< _ > = [ "gpt-4o-mini", "missing-model", "gpt-4o-mini" ].into_iter()
+    .map( | model | ChatCompletionRequest::former().model( model.to_string() ).messages( vec![ Message::user( "Say hi." ) ] ).max_tokens( 5_u32 ).form() )
+    .collect();
+
+  let report = BatchExecutor::new( 2 ).execute( &client, requests ).await;
+
+  assert_eq!( ( report.succeeded(), report.failed() ), ( 2, 1 ) );
+  let error = report.items[ 1 ].result.as_ref().expect_err( "the unknown model must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 404 ), .. } ) ), "got {error}" );
+  for index in [ 0, 2 ]
+  {
+    let answer = report.items[ index ].result.as_ref().expect( "the others must succeed" );
+    assert!( answer.choices[ 0 ].message.content.as_deref().is_some_and( | content | !content.is_empty() ) );
+  }
+}
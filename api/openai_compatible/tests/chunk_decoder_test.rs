@@ -0,0 +1,230 @@
+//! Tests for `ChunkDecoder`, which turns a streamed body into chunks.
+//!
+//! Bodies are shaped like recorded `OpenAI`-compatible streams, with the
+//! framing variations servers send: keep-alive comments, CRLF endings, and
+//! `data` split over several lines. The property tests cut one body at every
+//! offset and into seeded random pieces; every cut must decode alike.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | chunks_decode_up_to_done | Framing | Chunks in order; comments skipped and counted; nothing after `[DONE]` |
+//! | multi_line_data_and_line_endings | Framing | `data` over several lines, CRLF, and lone CR give the same chunks as LF |
+//! | every_split_point_decodes_identically | Property | Any two-piece cut and any fixed piece size match one push |
+//! | random_pieces_decode_identically | Property | Seeded random cuts, UTF-8 characters split included, match one push |
+//! | errors_surface_and_decoding_continues | Errors | `error` events and `{"error":..}` give classified errors; bad data gives `Deserialization`; later chunks still decode |
+//! | last_event_id_tracks_latest_id | Resume | The id of the last event is kept for `Last-Event-ID`; unterminated last event flushed by `finish` |
+
+#![ cfg( all( feature = "enabled", feature = "streaming" ) ) ]
+
+use api_openai_compatible::{ ChatCompletionChunk, ChunkDecoder, OpenAiCompatError };
+
+/// A chunk of completion `chatcmpl-sse` whose only choice adds `content`.
+fn chunk_json( content : &str ) -> String
+{
+  serde_json::json!
+  ({
+    "id" : "chatcmpl-sse",
+    "object" : "chat.completion.chunk",
+    "created" : 1_722_432_140,
+    "model" : "gpt-4o-mini",
+    "choices" : [ { "index" : 0, "delta" : { "content" : content }, "finish_reason" : null } ],
+  }).to_string()
+}
+
+/// A body of three chunks, one with multi-byte text, then `[DONE]`, with a
+/// keep-alive comment and ids as servers send them.
+fn body() -> String
+{
+  format!
+  (
+    ": keep-alive\r\n\r\nid: 1\r\ndata: {}\r\n\r\nid: 2\r\ndata: {}\r\n\r\n: ping\r\n\r\nid: 3\r\ndata: {}\r\n\r\ndata: [DONE]\r\n\r\n",
+    chunk_json( "Hel" ),
+    chunk_json( "lo, wörld ✓" ),
+    chunk_json( "!" ),
+  )
+}
+
+/// Contents of every chunk decoded from `pieces`, pushed in order.
+fn decode< 'a >( pieces : impl IntoIterator< Item = &'a [ u8 ] > ) -> Vec< String >
+{
+  let mut decoder = ChunkDecoder::new();
+  let mut contents = Vec::new();
+  let mut take = | decoder : &mut ChunkDecoder |
+  {
+    while let Some( chunk ) = decoder.next_chunk()
+    {
+      let chunk = chunk.expect( "every chunk must decode" );
+      contents.push( chunk.choices[ 0 ].delta.content.clone().unwrap_or_default() );
+    }
+  };
+  for piece in pieces
+  {
+    decoder.push( piece );
+    take( &mut decoder );
+  }
+  decoder.finish();
+  take( &mut decoder );
+  contents
+}
+
+fn drain( decoder : &mut ChunkDecoder ) -> Vec< Result< ChatCompletionChunk, String > >
+{
+  core::iter::from_fn( || decoder.next_chunk() ).map( | chunk | chunk.map_err( | e | e.to_string() ) ).collect()
+}
+
+// ------------------------------------------------------------------ //
+
+/// Chunks arrive in order, comments are skipped but counted, and the
+/// decoder stops at `[DONE]` even if more events follow.
+#[ test ]
+fn chunks_decode_up_to_done()
+{
+  let mut decoder = ChunkDecoder::new();
+  decoder.push( body().as_bytes() );
+  decoder.push( format!( "data: {}\n\n", chunk_json( "after done" ) ).as_bytes() );
+
+  let chunks = drain( &mut decoder );
+
+  let contents : Vec< Option< String > > = chunks.into_iter()
+    .map( | chunk | chunk.expect( "every chunk must decode" ).choices[ 0 ].delta.content.clone() )
+    .collect();
+  assert_eq!( contents, [ Some( "Hel".to_owned() ), Some( "lo, wörld ✓".to_owned() ), Some( "!".to_owned() ) ] );
+  assert!( decoder.is_done() );
+  assert_eq!( decoder.comments(), 2 );
+  assert!( decoder.next_chunk().is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A chunk whose JSON is spread over several `data` lines is joined with
+/// `\n`, and CRLF or lone CR endings decode as LF does.
+#[ test ]
+fn multi_line_data_and_line_endings()
+{
+  let json = chunk_json( "Hi" );
+  let ( head, tail ) = json.split_at( json.find( "\"choices\"" ).expect( "chunk has choices" ) );
+  let lf = format!( "data: {head}\ndata: {tail}\n\ndata: [DONE]\n\n" );
+
+  for body in [ lf.clone(), lf.replace( '\n', "\r\n" ), lf.replace( '\n', "\r" ) ]
+  {
+    assert_eq!( decode( [ body.as_bytes() ] ), [ "Hi" ], "{body:?}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Cutting the body in two at any byte, or into pieces of any fixed size,
+/// gives the chunks one push gives. Cuts fall inside CRLF pairs and inside
+/// multi-byte characters.
+#[ test ]
+fn every_split_point_decodes_identically()
+{
+  let body = body();
+  let body = body.as_bytes();
+  let whole = decode( [ body ] );
+  assert_eq!( whole.len(), 3 );
+
+  for split in 0..=body.len()
+  {
+    assert_eq!( decode( [ &body[ ..split ], &body[ split.. ] ] ), whole, "split at {split}" );
+  }
+  for size in 1..body.len()
+  {
+    assert_eq!( decode( body.chunks( size ) ), whole, "pieces of {size}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Random cuts, from a fixed seed so failures reproduce, give the chunks
+/// one push gives.
+#[ test ]
+fn random_pieces_decode_identically()
+{
+  let body = body();
+  let body = body.as_bytes();
+  let whole = decode( [ body ] );
+
+  // xorshift64: no dependency, and the same cuts on every run
+  let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+  let mut next = | bound : usize |
+  {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    usize::try_from( state % bound as u64 ).expect( "bound fits usize" )
+  };
+  for case in 0..500
+  {
+    let mut pieces = Vec::new();
+    let mut rest = body;
+    while !rest.is_empty()
+    {
+      let ( piece, tail ) = rest.split_at( 1 + next( rest.len().min( 16 ) ) );
+      pieces.push( piece );
+      rest = tail;
+    }
+    assert_eq!( decode( pieces.iter().copied() ), whole, "case {case}: {pieces:?}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Errors the server reports mid-stream surface as classified errors, data
+/// that is no chunk as `Deserialization`; the chunks after either still decode.
+#[ test ]
+fn errors_surface_and_decoding_continues()
+{
+  let mut decoder = ChunkDecoder::new();
+  decoder.push( b"event: error\ndata: {\"message\":\"overloaded\"}\n\n" );
+  decoder.push( b"data: {\"error\":{\"message\":\"context length exceeded\"}}\n\n" );
+  decoder.push( b"data: {\"id\":\n\n" );
+  decoder.push( format!( "data: {}\n\ndata: [DONE]\n\n", chunk_json( "still here" ) ).as_bytes() );
+
+  let mut chunks = drain( &mut decoder ).into_iter();
+
+  let overloaded = chunks.next().expect( "error event" ).expect_err( "must be an error" );
+  assert!( overloaded.contains( "overloaded" ), "{overloaded}" );
+  let exceeded = chunks.next().expect( "error payload" ).expect_err( "must be an error" );
+  assert!( exceeded.contains( "context length exceeded" ), "{exceeded}" );
+  assert!( chunks.next().expect( "bad data" ).is_err() );
+  let chunk = chunks.next().expect( "last chunk" ).expect( "must decode" );
+  assert_eq!( chunk.choices[ 0 ].delta.content.as_deref(), Some( "still here" ) );
+  assert!( chunks.next().is_none() );
+
+  let mut decoder = ChunkDecoder::new();
+  decoder.push( b"event: error\ndata: {}\n\ndata: {\"id\":\n\n" );
+  let api = decoder.next_chunk().expect( "error event" ).expect_err( "must be an error" );
+  assert!( matches!( api.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::ServerError { status : None, .. } ) ), "{api:?}" );
+  let bad = decoder.next_chunk().expect( "bad data" ).expect_err( "must be an error" );
+  assert!( matches!( bad.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Deserialization { .. } ) ), "{bad:?}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The id of the last event is kept across events without one, so a caller
+/// can resume with `Last-Event-ID`; `finish` flushes an event the server
+/// cut off without a blank line.
+#[ test ]
+fn last_event_id_tracks_latest_id()
+{
+  let mut decoder = ChunkDecoder::new();
+  assert_eq!( decoder.last_event_id(), None );
+
+  decoder.push( format!( "id: evt-1\ndata: {}\n\n", chunk_json( "a" ) ).as_bytes() );
+  assert!( decoder.next_chunk().is_some() );
+  assert_eq!( decoder.last_event_id(), Some( "evt-1" ) );
+
+  decoder.push( format!( "data: {}\n\nid: evt-3\ndata: {}", chunk_json( "b" ), chunk_json( "c" ) ).as_bytes() );
+  assert!( decoder.next_chunk().is_some() );
+  assert_eq!( decoder.last_event_id(), Some( "evt-1" ) );
+  assert!( decoder.next_chunk().is_none(), "the last event is not terminated yet" );
+
+  decoder.finish();
+
+  assert!( decoder.next_chunk().is_some() );
+  assert_eq!( decoder.last_event_id(), Some( "evt-3" ) );
+  assert!( !decoder.is_done() );
+}
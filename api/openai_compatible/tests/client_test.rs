@@ -2,16 +2,35 @@
 //!
 //! Unit tests validate generic trait acceptance. Integration tests validate
 //! the success path (real API key → 200), the non-2xx GET path
-//! (fake key → 401 → `Api` error), and the non-2xx POST path (fake key → 401).
+//! (fake key → 401 → `Authentication` error), and the non-2xx POST path (fake key → 401).
 //!
 //! # Test Matrix
 //!
 //! | Test | Category | Validates |
 //! |------|----------|-----------|
 //! | client_build_accepts_custom_environment_implementor | unit | Generic Client<E> trait polymorphism |
+//! | clients_share_rate_limiter_requests | unit | Every request takes one request from the shared limiter |
+//! | concurrency_gate_queue_timeout_fails_request | unit | A full shared gate fails the request after its queue timeout |
+//! | rate_limits_hold_back_requests_over_quota | unit | `with_rate_limits` holds a request over the client's request quota |
+//! | request_options_carry_idempotency_key | unit | `RequestOptions` holds an explicit or generated idempotency key |
+//! | invalid_idempotency_key_fails_before_sending | unit | A key that is not a valid header value fails the POST without reaching the network |
+//! | extra_headers_reach_server_and_replace_environment_headers | unit | `with_header` headers are sent on POST and GET, replace same-named environment headers, and sensitive values stay out of `Debug` (`mock`) |
+//! | invalid_extra_header_fails_before_sending | unit | A header name or value that cannot be sent fails the call without reaching the network |
+//! | guardrail_denial_fails_request_before_sending | unit | A denying guardrail fails the POST with `GuardrailViolation` and sees the request body |
+//! | connection_tuning_rebuilds_client | unit | `with_connection_tuning` accepts pool and keep-alive settings; prior knowledge needs `http2` |
+//! | environment_connection_tuning_applies_at_build | unit | Environment tuning is applied by `Client::build`: a malformed proxy fails the build, an unreachable proxy fails the request |
+//! | warm_up_fails_when_nothing_listens | unit | `warm_up` fails when no connection can be made |
+//! | raw_methods_fail_when_nothing_listens | unit | `post_raw` and `get_raw` fail without a response and run no response hook |
+//! | cancelled_token_stops_call_before_sending | unit | A token cancelled beforehand ends typed and raw calls with `Cancelled` before the network |
 //! | client_get_models_succeeds_with_real_key | integration | GET success path returns Ok |
+//! | client_hooks_amend_request_and_observe_real_response | integration | A request hook's `Authorization` reaches the API; response hooks see status and raw body of success and error alike, in order |
+//! | client_raw_methods_return_untouched_real_response | integration | `post_raw` and `get_raw` return the real API's status, headers, and body, error statuses included, without running response hooks |
+//! | client_usage_is_charged_to_token_quota | integration | A real response's `usage.total_tokens` is charged to the limiter's token quota |
+//! | client_cancellation_ends_real_call | integration | Cancelling while the API is still generating ends the call with `Cancelled` |
 //! | client_get_models_returns_api_error_with_fake_key | integration | GET non-2xx path returns Err |
 //! | client_post_chat_returns_api_error_with_fake_key | integration | POST non-2xx path returns Err |
+//! | client_with_environment_tuning_reaches_real_api | integration | An environment with pool, idle, keep-alive, and nodelay settings reaches the real API |
+//! | client_warm_up_succeeds_against_real_api | integration | `warm_up` counts the real API's error status as an open connection |
 
 #![ cfg( feature = "enabled" ) ]
 
@@ -49,7 +68,7 @@ fn client_build_accepts_custom_environment_implementor()
       let mut map = header::HeaderMap::new();
       let auth = format!( "Bearer {}", self.api_key() )
         .parse::< header::HeaderValue >()
-        .map_err( | e | OpenAiCompatError::InvalidApiKey( e.to_string() ) )?;
+        .map_err( | e | OpenAiCompatError::Authentication { status : None, message : e.to_string() } )?;
       map.insert( header::AUTHORIZATION, auth );
       map.insert(
         header::CONTENT_TYPE,
@@ -68,6 +87,362 @@ fn client_build_accepts_custom_environment_implementor()
   );
 }
 
+// ------------------------------------------------------------------ //
+
+/// Two clients holding clones of one `SharedRateLimiter` draw from the same
+/// request bucket.
+///
+/// The base URL is unreachable, so each request fails to connect; the
+/// request is still taken before sending, which is what bounds the combined
+/// rate of every client on the account.
+#[ cfg( feature = "rate_limiting" ) ]
+#[ tokio::test ]
+async fn clients_share_rate_limiter_requests()
+{
+  use api_common::{ RateLimits, SharedRateLimiter };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_requests( 3, Duration::from_secs( 3600 ) ) );
+  let build = | limiter : &SharedRateLimiter |
+  {
+    let env = OpenAiCompatEnvironmentImpl::new( "sk-shared-limiter" )
+      .expect( "environment construction must succeed" )
+      .with_base_url( "http://127.0.0.1:1/" );
+    Client::build( env )
+      .expect( "Client::build() must succeed" )
+      .with_shared_rate_limiter( limiter.clone() )
+  };
+  let first = build( &limiter );
+  let second = build( &limiter );
+
+  let body = serde_json::json!( {} );
+  assert!( first.get::< serde_json::Value >( "models" ).await.is_err() );
+  assert!( second.post::< _, serde_json::Value >( "chat/completions", &body ).await.is_err() );
+
+  assert_eq!( limiter.available_requests(), Some( 1 ) );
+}
+
+/// A request waits for a slot in the shared concurrency gate and fails with
+/// `QueueTimeout` when none frees up in time, without reaching the network.
+#[ cfg( feature = "rate_limiting" ) ]
+#[ tokio::test ]
+async fn concurrency_gate_queue_timeout_fails_request()
+{
+  use api_common::{ ConcurrencyGate, QueueTimeout };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let gate = ConcurrencyGate::new( 1 ).with_queue_timeout( Duration::from_millis( 20 ) );
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-concurrency-gate" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env )
+    .expect( "Client::build() must succeed" )
+    .with_concurrency_gate( gate.clone() );
+
+  let held = gate.try_acquire().expect( "gate must start empty" );
+  let error = client.get::< serde_json::Value >( "models" ).await.unwrap_err();
+  assert_eq!( error.downcast_ref::< QueueTimeout >(), Some( &QueueTimeout { waited : Duration::from_millis( 20 ) } ) );
+
+  drop( held );
+  let error = client.get::< serde_json::Value >( "models" ).await.unwrap_err();
+  assert!( error.downcast_ref::< QueueTimeout >().is_none() );
+  assert_eq!( gate.in_flight(), 0 );
+}
+
+/// `with_rate_limits` gives the client its own limiter: a request over the
+/// request quota waits instead of being sent.
+#[ cfg( feature = "rate_limiting" ) ]
+#[ tokio::test ]
+async fn rate_limits_hold_back_requests_over_quota()
+{
+  use api_common::RateLimits;
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-rate-limits" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env )
+    .expect( "Client::build() must succeed" )
+    .with_rate_limits( RateLimits::new().with_requests( 1, Duration::from_secs( 3600 ) ) );
+
+  assert!( client.get::< serde_json::Value >( "models" ).await.is_err() );
+  let held = tokio::time::timeout( Duration::from_millis( 100 ), client.get::< serde_json::Value >( "models" ) ).await;
+
+  assert!( held.is_err(), "the second request must wait for the quota" );
+}
+
+/// `RequestOptions` starts empty and keeps the idempotency key it is given;
+/// `idempotent` generates a fresh key on every call.
+#[ test ]
+fn request_options_carry_idempotency_key()
+{
+  use api_openai_compatible::RequestOptions;
+
+  assert_eq!( RequestOptions::new().idempotency_key(), None );
+  let options = RequestOptions::new().with_idempotency_key( "batch-2026-10-17" );
+  assert_eq!( options.idempotency_key(), Some( "batch-2026-10-17" ) );
+
+  #[ cfg( feature = "idempotency" ) ]
+  {
+    let first = RequestOptions::new().idempotent();
+    let second = RequestOptions::new().idempotent();
+    assert_eq!( first.idempotency_key().map( str::len ), Some( 36 ) );
+    assert_ne!( first.idempotency_key(), second.idempotency_key() );
+  }
+}
+
+/// An idempotency key that cannot be sent as a header value fails the call
+/// with an `Environment` error before anything is sent.
+#[ tokio::test ]
+async fn invalid_idempotency_key_fails_before_sending()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, OpenAiCompatError, RequestOptions };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-idempotency" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+
+  let options = RequestOptions::new().with_idempotency_key( "line\nbreak" );
+  let body = serde_json::json!( {} );
+  let error = client.post_with_options::< _, serde_json::Value >( "batches", &body, &options ).await.unwrap_err();
+  let error = error.downcast_ref::< OpenAiCompatError >().expect( "error must be an OpenAiCompatError" );
+  assert!( matches!( error, OpenAiCompatError::Environment( message ) if message.contains( "idempotency key" ) ), "{error}" );
+}
+
+/// Extra headers in the options reach the server on every method, replace
+/// the environment's header of the same name, and keep sensitive values out
+/// of `Debug` output.
+#[ cfg( feature = "mock" ) ]
+#[ tokio::test ]
+async fn extra_headers_reach_server_and_replace_environment_headers()
+{
+  use api_openai_compatible::{ MockResponse, MockTransport, RequestMatcher, RequestOptions };
+  use reqwest::StatusCode;
+
+  let mock = MockTransport::new()
+    .on( RequestMatcher::post( "chat/completions" ).with_header( "x-portkey-provider", "azure-openai" ), MockResponse::chat_text( "gpt-4o", "routed" ) )
+    .on( RequestMatcher::get( "models" ), MockResponse::json( StatusCode::OK, &serde_json::json!( { "data" : [] } ) ) );
+  let options = RequestOptions::new()
+    .with_header( "x-portkey-provider", "openai" )
+    .with_header( "X-Portkey-Provider", "azure-openai" )
+    .with_sensitive_header( "api-key", "azure-key-0123456789" )
+    .with_header( "content-type", "application/json; charset=utf-8" )
+    .with_idempotency_key( "order-42" );
+  assert_eq!( options.header( "x-portkey-provider" ), Some( "azure-openai" ), "setting a name again must replace it" );
+  assert_eq!( options.header( "x-absent" ), None );
+  assert!( !format!( "{options:?}" ).contains( "azure-key-0123456789" ), "{options:?}" );
+
+  let client = mock.client();
+  let body = serde_json::json!( { "model" : "gpt-4o", "messages" : [] } );
+  let response : serde_json::Value = client.post_with_options( "chat/completions", &body, &options ).await.expect( "the routed POST must succeed" );
+  assert_eq!( response[ "choices" ][ 0 ][ "message" ][ "content" ], "routed" );
+  let _ : serde_json::Value = client.get_with_options( "models", &options ).await.expect( "the GET must succeed" );
+
+  for request in mock.requests()
+  {
+    assert_eq!( request.headers.get_all( "x-portkey-provider" ).iter().count(), 1 );
+    assert_eq!( request.headers[ "x-portkey-provider" ], "azure-openai" );
+    assert_eq!( request.headers[ "api-key" ], "azure-key-0123456789" );
+    assert!( request.headers[ "api-key" ].is_sensitive() );
+    assert_eq!( request.headers[ "content-type" ], "application/json; charset=utf-8", "an extra header must replace the environment's" );
+    assert_eq!( request.headers[ "authorization" ], "Bearer sk-mock", "other environment headers must stay" );
+    assert_eq!( request.headers[ "idempotency-key" ], "order-42" );
+  }
+}
+
+/// A header name or value that cannot be sent fails the call with an
+/// `Environment` error before anything is sent.
+#[ tokio::test ]
+async fn invalid_extra_header_fails_before_sending()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, OpenAiCompatError, RequestOptions };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-headers" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+
+  for ( options, expected ) in
+  [
+    ( RequestOptions::new().with_header( "bad header", "x" ), "invalid header name" ),
+    ( RequestOptions::new().with_header( "x-route", "line\nbreak" ), "invalid value for header `x-route`" ),
+  ]
+  {
+    let error = client.get_with_options::< serde_json::Value >( "models", &options ).await.unwrap_err();
+    let error = error.downcast_ref::< OpenAiCompatError >().expect( "error must be an OpenAiCompatError" );
+    assert!( matches!( error, OpenAiCompatError::Environment( message ) if message.contains( expected ) ), "{error}" );
+  }
+}
+
+/// A guardrail that denies the request body fails `post` with
+/// `GuardrailViolation` before anything is sent.
+///
+/// The base URL is unreachable, so any other error would mean the request
+/// went past the guardrail to the network.
+#[ cfg( feature = "guardrail" ) ]
+#[ tokio::test ]
+async fn guardrail_denial_fails_request_before_sending()
+{
+  use api_common::{ Guardrail, GuardrailStage, GuardrailViolation, Guardrails };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  struct DenyModel;
+
+  #[ allow( clippy::unnecessary_literal_bound ) ]
+  impl Guardrail for DenyModel
+  {
+    fn name( &self ) -> &str { "deny_model" }
+
+    fn check_request( &self, body : &mut serde_json::Value ) -> Result< (), String >
+    {
+      match body[ "model" ].as_str()
+      {
+        Some( "blocked" ) => Err( "model is blocked".to_string() ),
+        _ => Ok( () ),
+      }
+    }
+  }
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-guardrail" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env )
+    .expect( "Client::build() must succeed" )
+    .with_guardrails( Guardrails::new().with( DenyModel ) );
+
+  let body = serde_json::json!( { "model" : "blocked" } );
+  let error = client.post::< _, serde_json::Value >( "chat/completions", &body ).await.unwrap_err();
+  let violation = error.downcast_ref::< GuardrailViolation >().expect( "error must be a guardrail violation" );
+  assert_eq!( violation.guardrail, "deny_model" );
+  assert_eq!( violation.stage, GuardrailStage::Request );
+
+  let body = serde_json::json!( { "model" : "allowed" } );
+  let error = client.post::< _, serde_json::Value >( "chat/completions", &body ).await.unwrap_err();
+  assert!( error.downcast_ref::< GuardrailViolation >().is_none() );
+}
+
+/// `with_connection_tuning` rebuilds the HTTP client with the tuning applied,
+/// and refuses HTTP/2 prior knowledge when built without `http2`.
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_rebuilds_client()
+{
+  use api_common::ConnectionTuning;
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-connection-tuning" ).expect( "environment construction must succeed" );
+  let build = || Client::build( env.clone() ).expect( "Client::build() must succeed" );
+
+  assert!( build().with_connection_tuning( ConnectionTuning::low_latency().with_pool_max_idle_per_host( 4 ) ).is_ok() );
+
+  let prior_knowledge = build().with_connection_tuning( ConnectionTuning::new().with_http2_prior_knowledge() );
+  if cfg!( feature = "http2" )
+  {
+    assert!( prior_knowledge.is_ok() );
+  }
+  else
+  {
+    let error = prior_knowledge.unwrap_err();
+    let error = error.downcast_ref::< OpenAiCompatError >().expect( "error must be an OpenAiCompatError" );
+    assert!( matches!( error, OpenAiCompatError::Environment( message ) if message.contains( "http2" ) ), "{error}" );
+  }
+}
+
+/// Tuning set on the environment is applied by `Client::build`: a proxy
+/// that is not a URL fails the build, not the first request, and a client
+/// whose proxy cannot be reached never resolves the target host.
+#[ cfg( feature = "connection" ) ]
+#[ tokio::test ]
+async fn environment_connection_tuning_applies_at_build()
+{
+  use api_common::ConnectionTuning;
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+  use core::time::Duration;
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-proxy" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://api.example.invalid/v1/" )
+    .with_timeout( Duration::from_secs( 5 ) );
+
+  let error = Client::build( env.clone().with_connection_tuning( ConnectionTuning::new().with_proxy( "not a proxy url" ) ) )
+    .expect_err( "a malformed proxy must fail the build" );
+  let error = error.downcast_ref::< OpenAiCompatError >().expect( "error must be an OpenAiCompatError" );
+  assert!( matches!( error, OpenAiCompatError::Environment( message ) if message.contains( "proxy" ) ), "{error}" );
+
+  let tuning = ConnectionTuning::low_latency()
+    .with_pool_max_idle_per_host( 4 )
+    .with_pool_idle_timeout( Duration::from_secs( 30 ) )
+    .with_http2_keep_alive_interval( Duration::from_secs( 20 ) )
+    .with_proxy( "http://127.0.0.1:1" );
+  let client = Client::build( env.with_connection_tuning( tuning ) ).expect( "a valid tuning must build" );
+
+  let error = client.post::< _, serde_json::Value >( "chat/completions", &serde_json::json!( {} ) ).await
+    .expect_err( "an unreachable proxy must fail the request" );
+  let error = error.downcast_ref::< OpenAiCompatError >().expect( "error must be an OpenAiCompatError" );
+  assert!( matches!( error, OpenAiCompatError::Network( _ ) ), "{error:?}" );
+}
+
+/// `warm_up` fails when no connection can be made.
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-warm-up" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+  assert!( client.warm_up().await.is_err() );
+}
+
+/// A raw call that cannot connect still fails: only a received response,
+/// whatever its status, is handed back untouched. Response hooks do not run.
+#[ tokio::test ]
+async fn raw_methods_fail_when_nothing_listens()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::sync::atomic::{ AtomicUsize, Ordering };
+  use std::sync::Arc;
+
+  let observed = Arc::new( AtomicUsize::new( 0 ) );
+  let counter = Arc::clone( &observed );
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-raw" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_response_hook( move | _ | { counter.fetch_add( 1, Ordering::SeqCst ); } );
+
+  assert!( client.post_raw( "chat/completions", &serde_json::json!( {} ) ).await.is_err() );
+  assert!( client.get_raw( "models" ).await.is_err() );
+  assert_eq!( observed.load( Ordering::SeqCst ), 0 );
+}
+
+/// A token cancelled beforehand must stop the call before it reaches the
+/// network, for typed and raw calls alike.
+#[ cfg( feature = "cancellation" ) ]
+#[ tokio::test ]
+async fn cancelled_token_stops_call_before_sending()
+{
+  use api_common::{ CancellationToken, Cancelled };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, RequestOptions };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-cancel" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+  let early = CancellationToken::new();
+  early.cancel();
+  let options = RequestOptions::new().with_cancellation( early );
+  let error = client.get_with_options::< serde_json::Value >( "models", &options ).await.expect_err( "the call must be cancelled" );
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+  let error = client.get_with_options_raw( "models", &options ).await.expect_err( "the call must be cancelled" );
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+}
+
 // ------------------------------------------------------------------ //
 //  Integration tests
 // ------------------------------------------------------------------ //
@@ -112,11 +487,194 @@ async fn client_get_models_succeeds_with_real_key()
 
 // ------------------------------------------------------------------ //
 
+/// A request hook's header must reach the API, and response hooks must see
+/// the status and raw body of success and error responses alike, in the
+/// order they were added.
+///
+/// The environment holds a fake key; the request hook swaps in the real one
+/// for the first call only, so the first call succeeds and the second is
+/// refused with 401.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_hooks_amend_request_and_observe_real_response()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::sync::atomic::{ AtomicBool, Ordering };
+  use reqwest::header::HeaderValue;
+  use std::sync::{ Arc, Mutex };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let authorization = HeaderValue::from_str( &format!( "Bearer {api_key}" ) )
+    .expect( "the key must be a valid header value" );
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-fake-integration-test" )
+    .expect( "environment construction must succeed" );
+  let swapped = Arc::new( AtomicBool::new( false ) );
+  let seen = Arc::new( Mutex::new( Vec::new() ) );
+  let ( first, second ) = ( Arc::clone( &seen ), Arc::clone( &seen ) );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_request_hook( move | request |
+    {
+      if !swapped.swap( true, Ordering::SeqCst )
+      {
+        request.headers.insert( reqwest::header::AUTHORIZATION, authorization.clone() );
+      }
+    } )
+    .with_response_hook( move | response |
+    {
+      first.lock().unwrap().push( format!( "{} {} {}", response.method, response.status.as_u16(), !response.body.is_empty() ) );
+    } )
+    .with_response_hook( move | response | second.lock().unwrap().push( format!( "second {}", response.url ) ) );
+
+  let ok : serde_json::Value = client.get( "models" ).await.expect( "the hook's real key must be accepted" );
+  assert!( ok.is_object(), "{ok}" );
+  let error = client.get::< serde_json::Value >( "models" ).await.expect_err( "the fake key must be refused" );
+  assert!(
+    matches!( error.downcast_ref::< api_openai_compatible::OpenAiCompatError >(), Some( api_openai_compatible::OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ),
+    "a 401 must be an Authentication error; got: {error}",
+  );
+
+  let url = "https://api.openai.com/v1/models";
+  assert_eq!
+  (
+    *seen.lock().unwrap(),
+    [ "GET 200 true".to_owned(), format!( "second {url}" ), "GET 401 true".to_owned(), format!( "second {url}" ) ]
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `post_raw` must return fields the typed response would drop, and
+/// `get_raw` must hand back the API's 401 as a response instead of an
+/// error. Response hooks see neither.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_raw_methods_return_untouched_real_response()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::sync::atomic::{ AtomicUsize, Ordering };
+  use std::sync::Arc;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+
+  let observed = Arc::new( AtomicUsize::new( 0 ) );
+  let counter = Arc::clone( &observed );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_response_hook( move | _ | { counter.fetch_add( 1, Ordering::SeqCst ); } );
+
+  let body = serde_json::json!({
+    "model": "gpt-4o-mini",
+    "messages": [ { "role": "user", "content": "Reply with the word ok." } ],
+    "max_tokens": 5
+  });
+  let response = client.post_raw( "chat/completions", &body ).await.expect( "the call must succeed" );
+  assert_eq!( response.status(), 200 );
+  assert!( response.headers().contains_key( "content-type" ) );
+  let body : serde_json::Value = response.json().await.expect( "body must be JSON" );
+  assert_eq!( body[ "object" ], "chat.completion", "{body}" );
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-fake-integration-test" )
+    .expect( "environment construction must succeed" );
+  let counter = Arc::clone( &observed );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_response_hook( move | _ | { counter.fetch_add( 1, Ordering::SeqCst ); } );
+  let response = client.get_raw( "models" ).await.expect( "an error status must still be returned" );
+  assert_eq!( response.status(), 401 );
+  let text = response.text().await.expect( "body must be readable" );
+  assert!( text.contains( "error" ), "{text}" );
+
+  assert_eq!( observed.load( Ordering::SeqCst ), 0 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The `usage.total_tokens` of a real chat completion is charged to a
+/// shared limiter's token quota, putting a one-token quota into debt; the
+/// call itself takes one request.
+#[ cfg( all( feature = "integration", feature = "rate_limiting" ) ) ]
+#[ tokio::test ]
+async fn client_usage_is_charged_to_token_quota()
+{
+  use api_common::{ RateLimits, SharedRateLimiter };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+
+  let limiter = SharedRateLimiter::new( RateLimits::new().with_requests_per_minute( 10 ).with_tokens_per_minute( 1 ) );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_shared_rate_limiter( limiter.clone() );
+
+  let body = serde_json::json!({
+    "model": "gpt-4o-mini",
+    "messages": [ { "role": "user", "content": "Reply with the word ok." } ],
+    "max_tokens": 5
+  });
+  let response : serde_json::Value = client.post( "chat/completions", &body ).await.expect( "the call must succeed" );
+  let used = response[ "usage" ][ "total_tokens" ].as_i64().expect( "the response must report usage" );
+
+  assert!( used > 0, "{response}" );
+  assert!( limiter.available_tokens().is_some_and( | available | available < 0 ), "{used} tokens must put the quota into debt; got {:?}", limiter.available_tokens() );
+  assert_eq!( limiter.available_requests(), Some( 9 ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Cancelling the token in a call's options must end the call with
+/// `Cancelled` while the API is still generating a long answer.
+#[ cfg( all( feature = "integration", feature = "cancellation" ) ) ]
+#[ tokio::test ]
+async fn client_cancellation_ends_real_call()
+{
+  use api_common::{ CancellationToken, Cancelled };
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, RequestOptions };
+  use core::time::Duration;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+
+  let token = CancellationToken::new();
+  let canceller = token.clone();
+  tokio::spawn( async move
+  {
+    tokio::time::sleep( Duration::from_millis( 200 ) ).await;
+    canceller.cancel();
+  } );
+  let options = RequestOptions::new().with_cancellation( token );
+  let body = serde_json::json!({
+    "model": "gpt-4o-mini",
+    "messages": [ { "role": "user", "content": "Write a 1000-word essay about rivers." } ]
+  });
+  let error = client.post_with_options::< _, serde_json::Value >( "chat/completions", &body, &options ).await
+    .expect_err( "the call must be cancelled" );
+
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+}
+
+// ------------------------------------------------------------------ //
+
 /// `Client::get("models")` with a fake API key must return `Err`.
 ///
 /// The real `OpenAI` API returns HTTP 401 when the Bearer token is invalid.
-/// The client must propagate this as an `Api` error containing the response
-/// body text, not swallow it silently or panic.
+/// The client must classify this as an `Authentication` error carrying the
+/// server's message, not swallow it silently or panic.
 #[ cfg( feature = "integration" ) ]
 #[ tokio::test ]
 async fn client_get_models_returns_api_error_with_fake_key()
@@ -136,10 +694,10 @@ async fn client_get_models_returns_api_error_with_fake_key()
     "GET models with a fake key must return Err, not Ok",
   );
 
-  let err_msg = result.unwrap_err().to_string();
+  let err = result.unwrap_err();
   assert!(
-    err_msg.contains( "API error" ),
-    "error must contain 'API error' category prefix; got: {err_msg}",
+    matches!( err.downcast_ref::< api_openai_compatible::OpenAiCompatError >(), Some( api_openai_compatible::OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ),
+    "a 401 must be an Authentication error; got: {err}",
   );
 }
 
@@ -149,7 +707,7 @@ async fn client_get_models_returns_api_error_with_fake_key()
 ///
 /// A minimal request body is sent to the real API with an invalid key. The API
 /// responds with HTTP 401 before inspecting the body. The client must surface
-/// the non-2xx status as an `Api` error containing the response body text.
+/// the 401 status as an `Authentication` error carrying the server's message.
 #[ cfg( feature = "integration" ) ]
 #[ tokio::test ]
 async fn client_post_chat_returns_api_error_with_fake_key()
@@ -174,9 +732,66 @@ async fn client_post_chat_returns_api_error_with_fake_key()
     "POST chat/completions with a fake key must return Err, not Ok",
   );
 
-  let err_msg = result.unwrap_err().to_string();
+  let err = result.unwrap_err();
   assert!(
-    err_msg.contains( "API error" ),
-    "error must contain 'API error' category prefix; got: {err_msg}",
+    matches!( err.downcast_ref::< api_openai_compatible::OpenAiCompatError >(), Some( api_openai_compatible::OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ),
+    "a 401 must be an Authentication error; got: {err}",
   );
 }
+
+// ------------------------------------------------------------------ //
+
+/// A client built from an environment carrying pool, idle, keep-alive, and
+/// nodelay settings talks to the real API like an untuned one.
+#[ cfg( all( feature = "integration", feature = "connection" ) ) ]
+#[ tokio::test ]
+async fn client_with_environment_tuning_reaches_real_api()
+{
+  use api_common::ConnectionTuning;
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+
+  let tuning = ConnectionTuning::low_latency()
+    .with_pool_max_idle_per_host( 8 )
+    .with_pool_idle_timeout( Duration::from_secs( 60 ) )
+    .with_http2_keep_alive_interval( Duration::from_secs( 20 ) );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" )
+    .with_connection_tuning( tuning );
+  let client = Client::build( env )
+    .expect( "Client::build() must succeed" );
+
+  client.warm_up().await.expect( "warm_up must open a connection" );
+  for _ in 0..2
+  {
+    let body : serde_json::Value = client.get( "models" ).await
+      .expect( "GET models through the tuned client must succeed" );
+    assert!( body[ "data" ].is_array(), "response must list models; got: {body}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// `warm_up` sends an unauthenticated `HEAD` to the real API, which answers
+/// with an error status; any answer must count as an open connection.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn client_warm_up_succeeds_against_real_api()
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-fake-integration-test" )
+    .expect( "environment construction must succeed with any non-empty key" );
+
+  let client = Client::build( env )
+    .expect( "Client::build() must succeed" );
+
+  let result = client.warm_up().await;
+
+  assert!( result.is_ok(), "warm_up against the real API must succeed; got: {:?}", result.err() );
+}
@@ -0,0 +1,141 @@
+//! Tests for `Client::prepare_post` / `prepare_get` and
+//! `PreparedRequest::to_curl`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | curl_renders_exact_call_with_key_masked | rendering | Method, full URL, and every header appear exactly; the key is masked but keeps its `Bearer` scheme |
+//! | curl_quotes_body_for_shell | rendering | A body with `'` is single-quoted with `'\''`, so the shell hands curl the original JSON |
+//! | prepared_request_carries_client_additions | fidelity | Idempotency key and request-hook headers are included; a GET has no body |
+//! | curl_body_matches_sent_body | fidelity | The `--data-raw` argument is byte-for-byte the body the client sends |
+//! | prepared_request_is_accepted_by_real_api | integration | The prepared URL and body make a call the real API answers |
+
+#![ cfg( feature = "curl_diagnostics" ) ]
+
+use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl, RequestOptions };
+
+const KEY : &str = "sk-proj-0123456789abcdef";
+
+fn client( base_url : &str ) -> Client< OpenAiCompatEnvironmentImpl >
+{
+  let env = OpenAiCompatEnvironmentImpl::new( KEY )
+    .expect( "environment construction must succeed" )
+    .with_base_url( base_url );
+  Client::build( env ).expect( "Client::build() must succeed" )
+}
+
+/// The `--data-raw` argument of `curl`, unquoted as a shell would.
+fn data_raw( curl : &str ) -> String
+{
+  let quoted = curl.split( "--data-raw " ).nth( 1 ).expect( "command must carry a body" );
+  quoted[ 1..quoted.len() - 1 ].replace( r"'\''", "'" )
+}
+
+// ------------------------------------------------------------------ //
+
+/// The command names the method, the base URL joined with the path, and each
+/// header on its own line; only the secret part of `Authorization` is hidden.
+#[ test ]
+fn curl_renders_exact_call_with_key_masked()
+{
+  let body = serde_json::json!( { "model" : "qwen2.5", "max_tokens" : 8 } );
+  let prepared = client( "http://localhost:8000/v1/" )
+    .prepare_post( "chat/completions", &body, &RequestOptions::new() )
+    .expect( "prepare_post must succeed" );
+
+  assert_eq!( prepared.method(), reqwest::Method::POST );
+  assert_eq!( prepared.url(), "http://localhost:8000/v1/chat/completions" );
+  assert_eq!( prepared.body(), Some( &body ) );
+  assert_eq!( prepared.headers()[ "authorization" ], format!( "Bearer {KEY}" ).as_str() );
+
+  let curl = prepared.to_curl();
+  assert_eq!
+  (
+    curl,
+    "curl -X POST 'http://localhost:8000/v1/chat/completions' \\\n  \
+    -H 'authorization: Bearer sk-p****' \\\n  \
+    -H 'content-type: application/json' \\\n  \
+    --data-raw '{\"max_tokens\":8,\"model\":\"qwen2.5\"}'",
+  );
+  assert!( !curl.contains( KEY ) );
+  assert!( !format!( "{prepared:?}" ).contains( KEY ) );
+}
+
+/// Apostrophes in message content close and reopen the quoting instead of
+/// ending the argument early.
+#[ test ]
+fn curl_quotes_body_for_shell()
+{
+  let body = serde_json::json!( { "messages" : [ { "role" : "user", "content" : "it's 'quoted'" } ] } );
+  let curl = client( "http://localhost:8000/v1/" )
+    .prepare_post( "chat/completions", &body, &RequestOptions::new() )
+    .expect( "prepare_post must succeed" )
+    .to_curl();
+
+  assert!( curl.contains( r#""content":"it'\''s '\''quoted'\''""# ) );
+  assert_eq!( serde_json::from_str::< serde_json::Value >( &data_raw( &curl ) ).expect( "body must be JSON" ), body );
+}
+
+/// Headers the client adds on top of the environment's show up in the
+/// prepared request, as they would on the wire.
+#[ test ]
+fn prepared_request_carries_client_additions()
+{
+  use reqwest::header::HeaderValue;
+
+  let client = client( "http://localhost:8000/v1/" )
+    .with_request_hook( | request | { request.headers.insert( "x-trace-id", HeaderValue::from_static( "trace-1" ) ); } );
+  let options = RequestOptions::new().with_idempotency_key( "retry-7" );
+
+  let curl = client
+    .prepare_post( "chat/completions", &serde_json::json!( {} ), &options )
+    .expect( "prepare_post must succeed" )
+    .to_curl();
+  assert!( curl.contains( "-H 'idempotency-key: retry-7'" ) );
+  assert!( curl.contains( "-H 'x-trace-id: trace-1'" ) );
+
+  let prepared = client.prepare_get( "models", &RequestOptions::new() ).expect( "prepare_get must succeed" );
+  assert_eq!( prepared.body(), None );
+  assert!( prepared.to_curl().starts_with( "curl -X GET 'http://localhost:8000/v1/models' \\\n" ) );
+  assert!( !prepared.to_curl().contains( "--data-raw" ) );
+  assert!( prepared.to_curl().contains( "-H 'x-trace-id: trace-1'" ) );
+}
+
+/// The body in the command is byte-for-byte the one the client sends, which
+/// `reqwest` serialises with `serde_json::to_vec`.
+#[ test ]
+fn curl_body_matches_sent_body()
+{
+  let body = serde_json::json!( { "model" : "qwen2.5", "messages" : [ { "role" : "user", "content" : "what's up? ünïcode" } ], "temperature" : 0.2 } );
+  let curl = client( "http://127.0.0.1:1/v1/" )
+    .prepare_post( "chat/completions", &body, &RequestOptions::new() )
+    .expect( "prepare_post must succeed" )
+    .to_curl();
+
+  let sent = serde_json::to_vec( &body ).expect( "body must serialise" );
+  assert_eq!( data_raw( &curl ).as_bytes(), sent.as_slice() );
+}
+
+/// The prepared call is one the real API accepts: its body, posted to the
+/// URL it names, gets a completion back.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn prepared_request_is_accepted_by_real_api()
+{
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" );
+  let body = serde_json::json!( { "model" : "gpt-4o-mini", "messages" : [ { "role" : "user", "content" : "what's up? ünïcode" } ], "max_tokens" : 5 } );
+
+  let prepared = client.prepare_post( "chat/completions", &body, &RequestOptions::new() ).expect( "prepare_post must succeed" );
+  assert_eq!( prepared.url(), "https://api.openai.com/v1/chat/completions" );
+  let response : serde_json::Value = client.post( "chat/completions", prepared.body().expect( "a POST carries a body" ) ).await
+    .expect( "the prepared body must be accepted" );
+
+  assert_eq!( response[ "object" ], "chat.completion", "{response}" );
+}
@@ -16,6 +16,9 @@
 //! | headers_returns_bearer_and_content_type | Authorization + Content-Type headers |
 //! | new_fails_with_whitespace_only_key | Whitespace-only key rejected as invalid |
 //! | new_succeeds_with_key_containing_printable_special_chars | Non-alphanumeric printable key accepted |
+//! | azure_deployment_scheme_lays_out_urls_and_auth | Azure deployment and resource paths, `api-version`, `api-key` header |
+//! | client_follows_azure_deployment_scheme | The client sends to the Azure URLs with `api-key` and no `Authorization`, as a request hook sees them |
+//! | azure_deployment_answers_real_chat | A real Azure deployment answers through the scheme and rejects a wrong `api-key` (`integration`) |
 
 #![ cfg( feature = "enabled" ) ]
 
@@ -201,3 +204,104 @@ fn new_succeeds_with_key_containing_printable_special_chars()
     "api_key() must return the exact key passed to new()",
   );
 }
+
+// ------------------------------------------------------------------ //
+
+/// `UrlScheme::AzureDeployment` puts model endpoints under the deployment,
+/// the rest under `openai/`, adds `api-version` to every URL, and sends the
+/// key in `api-key` instead of `Authorization`.
+#[ test ]
+fn azure_deployment_scheme_lays_out_urls_and_auth()
+{
+  use api_openai_compatible::UrlScheme;
+
+  let env = OpenAiCompatEnvironmentImpl::new( "azure-key-0123" )
+    .expect( "new() must succeed with a non-empty key" )
+    .with_base_url( "https://contoso.openai.azure.com/" )
+    .with_url_scheme( UrlScheme::azure_deployment( "gpt-4o-prod", "2024-10-21" ) );
+
+  let deployment = "https://contoso.openai.azure.com/openai/deployments/gpt-4o-prod/";
+  assert_eq!( env.url( "chat/completions" ), format!( "{deployment}chat/completions?api-version=2024-10-21" ) );
+  assert_eq!( env.url( "embeddings" ), format!( "{deployment}embeddings?api-version=2024-10-21" ) );
+  assert_eq!( env.url( "audio/transcriptions" ), format!( "{deployment}audio/transcriptions?api-version=2024-10-21" ) );
+  assert_eq!( env.url( "images/generations" ), format!( "{deployment}images/generations?api-version=2024-10-21" ) );
+  assert_eq!( env.url( "models" ), "https://contoso.openai.azure.com/openai/models?api-version=2024-10-21" );
+  assert_eq!( env.url( "files?purpose=batch" ), "https://contoso.openai.azure.com/openai/files?purpose=batch&api-version=2024-10-21" );
+
+  let headers = env.headers().expect( "headers() must succeed" );
+  assert_eq!( headers[ "api-key" ], "azure-key-0123" );
+  assert!( headers[ "api-key" ].is_sensitive() );
+  assert!( headers.get( "authorization" ).is_none(), "Azure keys must not be sent as Bearer tokens" );
+  assert_eq!( headers[ "content-type" ], "application/json" );
+
+  let standard = OpenAiCompatEnvironmentImpl::new( "sk-test-key" ).expect( "new() must succeed" );
+  assert_eq!( standard.url_scheme(), &UrlScheme::Standard );
+  assert_eq!( standard.url( "chat/completions" ), "https://api.openai.com/v1/chat/completions" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The client sends every request to the URL the scheme lays out, with the
+/// scheme's auth header; a request hook sees the request as it leaves.
+#[ tokio::test ]
+async fn client_follows_azure_deployment_scheme()
+{
+  use api_openai_compatible::{ Client, UrlScheme };
+  use std::sync::{ Arc, Mutex };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "azure-key-0123" )
+    .expect( "new() must succeed with a non-empty key" )
+    .with_base_url( "http://127.0.0.1:1/" )
+    .with_url_scheme( UrlScheme::azure_deployment( "gpt-4o-prod", "2024-10-21" ) );
+  let sent = Arc::new( Mutex::new( Vec::new() ) );
+  let seen = Arc::clone( &sent );
+  let client = Client::build( env ).expect( "Client::build() must succeed" ).with_request_hook( move | request |
+  {
+    let api_key = request.headers.get( "api-key" ).map( | value | value.to_str().expect( "key is ASCII" ).to_owned() );
+    seen.lock().expect( "lock" ).push( ( request.url.to_owned(), api_key, request.headers.contains_key( "authorization" ) ) );
+  } );
+
+  let body = serde_json::json!( { "messages" : [ { "role" : "user", "content" : "Hi" } ] } );
+  assert!( client.post::< _, serde_json::Value >( "chat/completions", &body ).await.is_err(), "nothing listens on port 1" );
+  assert!( client.get::< serde_json::Value >( "models" ).await.is_err(), "nothing listens on port 1" );
+
+  let key = Some( "azure-key-0123".to_owned() );
+  assert_eq!( *sent.lock().expect( "lock" ),
+  [
+    ( "http://127.0.0.1:1/openai/deployments/gpt-4o-prod/chat/completions?api-version=2024-10-21".to_owned(), key.clone(), false ),
+    ( "http://127.0.0.1:1/openai/models?api-version=2024-10-21".to_owned(), key, false ),
+  ] );
+}
+
+/// A real Azure `OpenAI` deployment, named by `AZURE_OPENAI_ENDPOINT`,
+/// `AZURE_OPENAI_DEPLOYMENT`, and `AZURE_OPENAI_API_KEY` in
+/// `secret/-secrets.sh`, answers a chat completion sent through the scheme,
+/// and rejects a wrong key sent in `api-key`.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn azure_deployment_answers_real_chat()
+{
+  use api_openai_compatible::{ ChatCompletionResponse, Client, OpenAiCompatError, UrlScheme };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let endpoint = ws.load_secret_key( "AZURE_OPENAI_ENDPOINT", "-secrets.sh" )
+    .expect( "AZURE_OPENAI_ENDPOINT must be set in secret/-secrets.sh" );
+  let deployment = ws.load_secret_key( "AZURE_OPENAI_DEPLOYMENT", "-secrets.sh" )
+    .expect( "AZURE_OPENAI_DEPLOYMENT must be set in secret/-secrets.sh" );
+  let api_key = ws.load_secret_key( "AZURE_OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "AZURE_OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = | key : &str | OpenAiCompatEnvironmentImpl::new( key )
+    .expect( "new() must succeed with a non-empty key" )
+    .with_base_url( endpoint.as_str() )
+    .with_url_scheme( UrlScheme::azure_deployment( deployment.as_str(), "2024-10-21" ) );
+  let body = serde_json::json!( { "messages" : [ { "role" : "user", "content" : "Say hi." } ], "max_tokens" : 5 } );
+
+  let client = Client::build( env( &api_key ) ).expect( "Client::build() must succeed" );
+  let response : ChatCompletionResponse = client.post( "chat/completions", &body ).await.expect( "the deployment must answer" );
+  assert!( response.choices[ 0 ].message.content.as_deref().is_some_and( | content | !content.is_empty() ) );
+
+  let client = Client::build( env( "azure-fake-integration-key" ) ).expect( "Client::build() must succeed" );
+  let error = client.post::< _, ChatCompletionResponse >( "chat/completions", &body ).await.expect_err( "a wrong key must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ), "{error:?}" );
+}
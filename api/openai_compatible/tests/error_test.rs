@@ -1,67 +1,102 @@
-//! Tests for `OpenAiCompatError` Display formatting and `From` conversions.
+//! Tests for `OpenAiCompatError`: Display formatting, `From` conversions,
+//! classification of error responses, and retryability.
 //!
 //! Validates that every error variant produces a human-readable message containing
-//! both a category prefix and the supplied detail string, and that automatic
+//! both a category prefix and the supplied detail string, that automatic
 //! conversions from `serde_json::Error` and `InvalidHeaderValue` map to the
-//! expected variant.
+//! expected variant, and that error responses are classified by status with
+//! the `OpenAI` error object read from the body.
 //!
 //! # Test Matrix
 //!
 //! | Test | Category | Validates |
 //! |------|----------|-----------|
-//! | api_error_display_contains_category_and_detail | Display | Api variant formatting |
-//! | http_error_display_contains_category_and_detail | Display | Http variant formatting |
+//! | authentication_error_display_contains_category_and_detail | Display | Authentication variant formatting |
+//! | rate_limited_error_display_contains_category_and_detail | Display | RateLimited variant formatting |
+//! | server_error_display_contains_category_and_detail | Display | ServerError variant formatting |
 //! | network_error_display_contains_category_and_detail | Display | Network variant formatting |
 //! | timeout_error_display_contains_category_and_detail | Display | Timeout variant formatting |
-//! | invalid_api_key_error_display_contains_category_and_detail | Display | InvalidApiKey variant formatting |
 //! | environment_error_display_contains_category_and_detail | Display | Environment variant formatting |
-//! | deserialise_error_display_contains_category_and_detail | Display | Deserialise variant formatting |
-//! | from_serde_json_error_produces_deserialise_variant | From | serde_json::Error → Deserialise |
-//! | from_invalid_header_value_produces_invalid_api_key_variant | From | InvalidHeaderValue → InvalidApiKey |
+//! | deserialization_error_display_contains_category_and_detail | Display | Deserialization variant formatting |
+//! | invalid_request_error_display_contains_category_and_detail | Display | InvalidRequest variant formatting |
+//! | from_serde_json_error_produces_deserialization_variant | From | serde_json::Error → Deserialization |
+//! | from_invalid_header_value_produces_authentication_variant | From | InvalidHeaderValue → Authentication without status |
+//! | from_response_classifies_by_status | Classification | 401/403, 429, other 4xx, 5xx; param, code, and `Retry-After` read |
+//! | from_response_reads_error_body_shapes | Classification | Error object, error string, `detail`, plain text, empty body |
+//! | is_retryable_follows_openai_rules | Retryability | Network, timeout, 408, 409, 429, 5xx retried; quota, 501, 4xx, auth, and local errors not |
+//! | deserialization_keeps_body_snippet | Classification | A body that is not the expected type keeps its start in `body_snippet` (`mock`) |
+//! | into_llm_error_classifies_api_body | From | Classified errors → `LlmError` class (`common`) |
 
 #![ cfg( feature = "enabled" ) ]
 
 use api_openai_compatible::OpenAiCompatError;
+use core::time::Duration;
+use reqwest::{ header::HeaderMap, StatusCode };
+
+fn classify( status : StatusCode, body : &str ) -> OpenAiCompatError
+{
+  OpenAiCompatError::from_response( status, &HeaderMap::new(), body.as_bytes() )
+}
 
 // ------------------------------------------------------------------ //
 
-/// `OpenAiCompatError::Api` must format as `"API error : <detail>"`.
+/// `OpenAiCompatError::Authentication` must format as
+/// `"Authentication error : <detail>"`.
 ///
 /// The display string must contain both the category prefix and the
 /// caller-supplied detail so that log aggregators can filter by category
 /// while humans can still see the specific failure reason.
 #[ test ]
-fn api_error_display_contains_category_and_detail()
+fn authentication_error_display_contains_category_and_detail()
 {
-  let err = OpenAiCompatError::Api( "rate limit exceeded".to_string() );
+  let err = OpenAiCompatError::Authentication { status : Some( 401 ), message : "Incorrect API key provided".to_string() };
   let msg = err.to_string();
 
   assert!(
-    msg.contains( "API error" ),
-    "Api display must contain category prefix; got: {msg}",
+    msg.contains( "Authentication error" ),
+    "Authentication display must contain category prefix; got: {msg}",
+  );
+  assert!(
+    msg.contains( "Incorrect API key provided" ),
+    "Authentication display must contain the detail string; got: {msg}",
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `OpenAiCompatError::RateLimited` must format as `"Rate limited : <detail>"`.
+#[ test ]
+fn rate_limited_error_display_contains_category_and_detail()
+{
+  let err = OpenAiCompatError::RateLimited { retry_after : None, code : None, message : "rate limit exceeded".to_string(), rate_limits : api_openai_compatible::RateLimitInfo::default() };
+  let msg = err.to_string();
+
+  assert!(
+    msg.contains( "Rate limited" ),
+    "RateLimited display must contain category prefix; got: {msg}",
   );
   assert!(
     msg.contains( "rate limit exceeded" ),
-    "Api display must contain the detail string; got: {msg}",
+    "RateLimited display must contain the detail string; got: {msg}",
   );
 }
 
 // ------------------------------------------------------------------ //
 
-/// `OpenAiCompatError::Http` must format as `"HTTP error : <detail>"`.
+/// `OpenAiCompatError::ServerError` must format as `"Server error : <detail>"`.
 #[ test ]
-fn http_error_display_contains_category_and_detail()
+fn server_error_display_contains_category_and_detail()
 {
-  let err = OpenAiCompatError::Http( "502 Bad Gateway".to_string() );
+  let err = OpenAiCompatError::ServerError { status : Some( 502 ), code : None, message : "502 Bad Gateway".to_string() };
   let msg = err.to_string();
 
   assert!(
-    msg.contains( "HTTP error" ),
-    "Http display must contain category prefix; got: {msg}",
+    msg.contains( "Server error" ),
+    "ServerError display must contain category prefix; got: {msg}",
   );
   assert!(
     msg.contains( "502 Bad Gateway" ),
-    "Http display must contain the detail string; got: {msg}",
+    "ServerError display must contain the detail string; got: {msg}",
   );
 }
 
@@ -105,70 +140,72 @@ fn timeout_error_display_contains_category_and_detail()
 
 // ------------------------------------------------------------------ //
 
-/// `OpenAiCompatError::InvalidApiKey` must format as `"Invalid API key : <detail>"`.
+/// `OpenAiCompatError::Environment` must format as `"Environment error : <detail>"`.
 #[ test ]
-fn invalid_api_key_error_display_contains_category_and_detail()
+fn environment_error_display_contains_category_and_detail()
 {
-  let err = OpenAiCompatError::InvalidApiKey( "non-ASCII char".to_string() );
+  let err = OpenAiCompatError::Environment( "bad URL".to_string() );
   let msg = err.to_string();
 
   assert!(
-    msg.contains( "Invalid API key" ),
-    "InvalidApiKey display must contain category prefix; got: {msg}",
+    msg.contains( "Environment error" ),
+    "Environment display must contain category prefix; got: {msg}",
   );
   assert!(
-    msg.contains( "non-ASCII char" ),
-    "InvalidApiKey display must contain the detail string; got: {msg}",
+    msg.contains( "bad URL" ),
+    "Environment display must contain the detail string; got: {msg}",
   );
 }
 
 // ------------------------------------------------------------------ //
 
-/// `OpenAiCompatError::Environment` must format as `"Environment error : <detail>"`.
+/// `OpenAiCompatError::Deserialization` must format as
+/// `"Deserialization error : <detail>"`.
 #[ test ]
-fn environment_error_display_contains_category_and_detail()
+fn deserialization_error_display_contains_category_and_detail()
 {
-  let err = OpenAiCompatError::Environment( "bad URL".to_string() );
+  let err = OpenAiCompatError::Deserialization { message : "missing field".to_string(), body_snippet : "{}".to_string() };
   let msg = err.to_string();
 
   assert!(
-    msg.contains( "Environment error" ),
-    "Environment display must contain category prefix; got: {msg}",
+    msg.contains( "Deserialization error" ),
+    "Deserialization display must contain category prefix; got: {msg}",
   );
   assert!(
-    msg.contains( "bad URL" ),
-    "Environment display must contain the detail string; got: {msg}",
+    msg.contains( "missing field" ),
+    "Deserialization display must contain the detail string; got: {msg}",
   );
 }
 
 // ------------------------------------------------------------------ //
 
-/// `OpenAiCompatError::Deserialise` must format as `"Deserialisation error : <detail>"`.
+/// `OpenAiCompatError::InvalidRequest` must format as `"Invalid request : <detail>"`.
 #[ test ]
-fn deserialise_error_display_contains_category_and_detail()
+fn invalid_request_error_display_contains_category_and_detail()
 {
-  let err = OpenAiCompatError::Deserialise( "missing field".to_string() );
+  let err = OpenAiCompatError::InvalidRequest { status : None, param : None, code : None, message : "groq accepts at most 4 stop sequences".to_string() };
   let msg = err.to_string();
 
   assert!(
-    msg.contains( "Deserialisation error" ),
-    "Deserialise display must contain category prefix; got: {msg}",
+    msg.contains( "Invalid request" ),
+    "InvalidRequest display must contain category prefix; got: {msg}",
   );
   assert!(
-    msg.contains( "missing field" ),
-    "Deserialise display must contain the detail string; got: {msg}",
+    msg.contains( "at most 4 stop sequences" ),
+    "InvalidRequest display must contain the detail string; got: {msg}",
   );
 }
 
 // ------------------------------------------------------------------ //
 
-/// `From<serde_json::Error>` must produce the `Deserialise` variant.
+/// `From<serde_json::Error>` must produce the `Deserialization` variant.
 ///
 /// `serde_json::from_str::<i32>("not-a-number")` produces a parse error that
-/// the `From` impl wraps into `OpenAiCompatError::Deserialise`. The conversion
-/// preserves the original error message inside the variant.
+/// the `From` impl wraps into `OpenAiCompatError::Deserialization`. The
+/// conversion preserves the original error message; there is no body to
+/// keep a snippet of.
 #[ test ]
-fn from_serde_json_error_produces_deserialise_variant()
+fn from_serde_json_error_produces_deserialization_variant()
 {
   let serde_err = serde_json::from_str::< i32 >( "not-a-number" )
     .expect_err( "parsing \"not-a-number\" as i32 must fail" );
@@ -177,29 +214,30 @@ fn from_serde_json_error_produces_deserialise_variant()
 
   match &converted
   {
-    OpenAiCompatError::Deserialise( detail ) =>
+    OpenAiCompatError::Deserialization { message, body_snippet } =>
     {
       assert!(
-        !detail.is_empty(),
-        "Deserialise detail must contain the serde error message",
+        !message.is_empty(),
+        "Deserialization message must contain the serde error message",
       );
+      assert!( body_snippet.is_empty(), "a bare serde error has no body; got: {body_snippet}" );
     },
     other =>
     {
-      panic!( "expected Deserialise variant, got: {other:?}" );
+      panic!( "expected Deserialization variant, got: {other:?}" );
     },
   }
 }
 
 // ------------------------------------------------------------------ //
 
-/// `From<InvalidHeaderValue>` must produce the `InvalidApiKey` variant.
+/// `From<InvalidHeaderValue>` must produce `Authentication` without a status.
 ///
 /// `HeaderValue::from_bytes(&[0x00])` fails because NUL (0x00) is a control
 /// character rejected by the HTTP header value parser. The `From` impl wraps
-/// this into `InvalidApiKey`.
+/// this into `Authentication`, since the key is what goes into a header.
 #[ test ]
-fn from_invalid_header_value_produces_invalid_api_key_variant()
+fn from_invalid_header_value_produces_authentication_variant()
 {
   let header_err = reqwest::header::HeaderValue::from_bytes( &[ 0x00_u8 ] )
     .expect_err( "NUL byte must be invalid in HTTP header values" );
@@ -208,16 +246,174 @@ fn from_invalid_header_value_produces_invalid_api_key_variant()
 
   match &converted
   {
-    OpenAiCompatError::InvalidApiKey( detail ) =>
+    OpenAiCompatError::Authentication { status : None, message } =>
     {
       assert!(
-        !detail.is_empty(),
-        "InvalidApiKey detail must contain the header error message",
+        !message.is_empty(),
+        "Authentication message must contain the header error message",
       );
     },
     other =>
     {
-      panic!( "expected InvalidApiKey variant, got: {other:?}" );
+      panic!( "expected Authentication variant without status, got: {other:?}" );
     },
   }
+  assert!( !converted.is_retryable() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Statuses pick the variant; `param`, `code`, and the wait are read from
+/// the body and headers.
+#[ test ]
+fn from_response_classifies_by_status()
+{
+  let body = r#"{"error":{"message":"Incorrect API key provided","type":"invalid_request_error","param":null,"code":"invalid_api_key"}}"#;
+  for status in [ StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN ]
+  {
+    let error = classify( status, body );
+    assert_eq!( error, OpenAiCompatError::Authentication { status : Some( status.as_u16() ), message : "Incorrect API key provided".to_string() } );
+  }
+
+  let mut headers = HeaderMap::new();
+  headers.insert( "retry-after-ms", "1500".parse().expect( "valid header" ) );
+  headers.insert( "retry-after", "20".parse().expect( "valid header" ) );
+  let limited = OpenAiCompatError::from_response
+  (
+    StatusCode::TOO_MANY_REQUESTS,
+    &headers,
+    br#"{"error":{"message":"Rate limit reached for requests","type":"requests","code":"rate_limit_exceeded"}}"#,
+  );
+  assert_eq!( limited.retry_after(), Some( Duration::from_millis( 1500 ) ), "retry-after-ms must win over Retry-After" );
+  assert_eq!( ( limited.status(), limited.code() ), ( Some( 429 ), Some( "rate_limit_exceeded" ) ) );
+
+  let invalid = classify
+  (
+    StatusCode::BAD_REQUEST,
+    r#"{"error":{"message":"Invalid value for 'temperature'","type":"invalid_request_error","param":"temperature","code":"invalid_value"}}"#,
+  );
+  assert_eq!( invalid, OpenAiCompatError::InvalidRequest
+  {
+    status : Some( 400 ),
+    param : Some( "temperature".to_string() ),
+    code : Some( "invalid_value".to_string() ),
+    message : "Invalid value for 'temperature'".to_string(),
+  } );
+  assert_eq!( ( invalid.param(), invalid.retry_after() ), ( Some( "temperature" ), None ) );
+  assert_eq!( classify( StatusCode::NOT_FOUND, r#"{"error":{"message":"no such model","code":"model_not_found"}}"# ).status(), Some( 404 ) );
+
+  let server = classify( StatusCode::SERVICE_UNAVAILABLE, r#"{"error":{"message":"The engine is currently overloaded","type":"server_error","code":503}}"# );
+  assert_eq!( server, OpenAiCompatError::ServerError { status : Some( 503 ), code : Some( "503".to_string() ), message : "The engine is currently overloaded".to_string() } );
+  assert!( matches!( classify( StatusCode::MOVED_PERMANENTLY, "" ), OpenAiCompatError::ServerError { status : Some( 301 ), .. } ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The message comes from whichever error shape the server uses, else from
+/// the body or the status.
+#[ test ]
+fn from_response_reads_error_body_shapes()
+{
+  let message = | body : &str | match classify( StatusCode::BAD_REQUEST, body )
+  {
+    OpenAiCompatError::InvalidRequest { message, .. } => message,
+    other => panic!( "expected InvalidRequest, got {other:?}" ),
+  };
+
+  assert_eq!( message( r#"{"error":{"message":"object form"}}"# ), "object form" );
+  assert_eq!( message( r#"{"error":"string form"}"# ), "string form" );
+  assert_eq!( message( r#"{"object":"error","message":"top-level form","code":400}"# ), "top-level form" );
+  assert_eq!( message( r#"{"detail":"FastAPI form"}"# ), "FastAPI form" );
+  assert_eq!( message( "upstream said no\n" ), "upstream said no" );
+  assert_eq!( message( r#"{"unexpected":true}"# ), r#"{"unexpected":true}"# );
+  assert_eq!( message( "" ), "400 Bad Request" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Retryability follows the rules `OpenAI` documents for its own clients.
+#[ test ]
+fn is_retryable_follows_openai_rules()
+{
+  let quota = r#"{"error":{"message":"You exceeded your current quota","type":"insufficient_quota","code":"insufficient_quota"}}"#;
+  let retried =
+  [
+    OpenAiCompatError::Network( "connection refused".to_string() ),
+    OpenAiCompatError::Timeout( "30s".to_string() ),
+    classify( StatusCode::REQUEST_TIMEOUT, "" ),
+    classify( StatusCode::CONFLICT, "" ),
+    classify( StatusCode::TOO_MANY_REQUESTS, "" ),
+    classify( StatusCode::INTERNAL_SERVER_ERROR, "" ),
+    classify( StatusCode::BAD_GATEWAY, "" ),
+  ];
+  let final_ =
+  [
+    classify( StatusCode::TOO_MANY_REQUESTS, quota ),
+    classify( StatusCode::NOT_IMPLEMENTED, "" ),
+    classify( StatusCode::BAD_REQUEST, "" ),
+    classify( StatusCode::NOT_FOUND, "" ),
+    classify( StatusCode::UNAUTHORIZED, "" ),
+    OpenAiCompatError::InvalidRequest { status : None, param : None, code : None, message : "too many stop sequences".to_string() },
+    OpenAiCompatError::Deserialization { message : "eof".to_string(), body_snippet : String::new() },
+    OpenAiCompatError::Environment( "bad URL".to_string() ),
+  ];
+
+  for error in &retried
+  {
+    assert!( error.is_retryable(), "must be retryable: {error:?}" );
+  }
+  for error in &final_
+  {
+    assert!( !error.is_retryable(), "must not be retryable: {error:?}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// A response body the client cannot read keeps its start in the error,
+/// so logs show what the server actually sent.
+#[ cfg( feature = "mock" ) ]
+#[ tokio::test ]
+async fn deserialization_keeps_body_snippet()
+{
+  use api_openai_compatible::{ MockResponse, MockTransport, RequestMatcher };
+
+  let html = format!( "<html><body>{}</body></html>", "maintenance ".repeat( 40 ) );
+  let mock = MockTransport::new().on( RequestMatcher::get( "models" ), MockResponse::new( StatusCode::OK ).with_body( html.clone() ) );
+
+  let error = mock.client().list_models().await.expect_err( "HTML must not deserialise" );
+  match error.downcast_ref::< OpenAiCompatError >()
+  {
+    Some( OpenAiCompatError::Deserialization { message, body_snippet } ) =>
+    {
+      assert!( !message.is_empty() );
+      assert!( html.starts_with( body_snippet.as_str() ), "the snippet must be the start of the body; got: {body_snippet}" );
+      assert_eq!( body_snippet.chars().count(), 200 );
+    },
+    other => panic!( "expected Deserialization, got {other:?}" ),
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Classified errors convert into the neutral class, so multi-provider code
+/// does not match on `OpenAiCompatError`.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn into_llm_error_classifies_api_body()
+{
+  use api_common::LlmError;
+
+  let mut headers = HeaderMap::new();
+  headers.insert( "retry-after", "3".parse().expect( "valid header" ) );
+  let limited = OpenAiCompatError::from_response( StatusCode::TOO_MANY_REQUESTS, &headers, br#"{"error":{"message":"slow down","code":"rate_limit_exceeded"}}"# );
+  let long = classify( StatusCode::BAD_REQUEST, r#"{"error":{"message":"This model's maximum context length is 8192 tokens","code":"context_length_exceeded"}}"# );
+  let rejected = classify( StatusCode::UNAUTHORIZED, r#"{"error":{"message":"Incorrect API key provided"}}"# );
+  let overloaded = classify( StatusCode::SERVICE_UNAVAILABLE, "" );
+
+  assert_eq!( LlmError::from( limited ), LlmError::RateLimited { retry_after : Some( Duration::from_secs( 3 ) ), message : "slow down".to_string() } );
+  assert!( matches!( LlmError::from( long ), LlmError::ContextTooLong( _ ) ) );
+  assert!( matches!( LlmError::from( rejected ), LlmError::Auth( _ ) ) );
+  assert!( matches!( LlmError::from( overloaded ), LlmError::Overloaded( _ ) ) );
+  assert_eq!( LlmError::from( OpenAiCompatError::Timeout( "30s".to_string() ) ), LlmError::Network( "30s".to_string() ) );
 }
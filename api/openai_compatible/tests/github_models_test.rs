@@ -0,0 +1,108 @@
+//! Tests for `GitHubModelsEnvironment`.
+//!
+//! Unit tests pin the endpoints, token handling, and headers of both hosts.
+//! The integration test sends a chat completion to GitHub Models with the
+//! `GITHUB_TOKEN` from `secret/-secrets.sh`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | new_targets_azure_endpoint_without_github_headers | unit | Default host, timeout, and standard headers only |
+//! | new_trims_token_and_rejects_blank | unit | Trailing newline removed; blank token → Err |
+//! | github_endpoint_sends_github_headers | unit | `Accept` and `X-GitHub-Api-Version` on the GitHub host |
+//! | organization_endpoint_bills_org | unit | `orgs/{org}/inference/` base URL with GitHub headers |
+//! | github_models_chat_completion_succeeds_with_real_token | integration | Free-tier model answers through the shared client |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::{ GitHubModelsEnvironment, OpenAiCompatEnvironment };
+use core::time::Duration;
+
+// ------------------------------------------------------------------ //
+
+/// The default host is the Azure AI inference endpoint, which needs only the
+/// standard Bearer and Content-Type headers.
+#[ test ]
+fn new_targets_azure_endpoint_without_github_headers()
+{
+  let env = GitHubModelsEnvironment::new( "ghp_test" ).expect( "non-empty token must be accepted" );
+  let headers = env.headers().expect( "headers() must succeed" );
+
+  assert_eq!( env.base_url(), "https://models.inference.ai.azure.com/" );
+  assert_eq!( env.timeout(), Duration::from_secs( GitHubModelsEnvironment::DEFAULT_TIMEOUT_SECS ) );
+  assert_eq!( headers.get( "authorization" ).unwrap(), "Bearer ghp_test" );
+  assert_eq!( headers.get( "content-type" ).unwrap(), "application/json" );
+  assert!( headers.get( "accept" ).is_none() );
+  assert!( headers.get( "x-github-api-version" ).is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `gh auth token` output ends with a newline; it must not reach the header.
+#[ test ]
+fn new_trims_token_and_rejects_blank()
+{
+  let env = GitHubModelsEnvironment::new( "ghp_test\n" ).unwrap();
+  assert_eq!( env.api_key(), "ghp_test" );
+
+  assert!( GitHubModelsEnvironment::new( "" ).is_err() );
+  assert!( GitHubModelsEnvironment::new( " \n" ).is_err() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The GitHub-hosted endpoint expects GitHub's REST headers.
+#[ test ]
+fn github_endpoint_sends_github_headers()
+{
+  let env = GitHubModelsEnvironment::new( "ghp_test" ).unwrap().with_github_endpoint();
+  let headers = env.headers().unwrap();
+
+  assert_eq!( env.base_url(), "https://models.github.ai/inference/" );
+  assert_eq!( headers.get( "accept" ).unwrap(), "application/vnd.github+json" );
+  assert_eq!( headers.get( "x-github-api-version" ).unwrap(), GitHubModelsEnvironment::API_VERSION );
+  assert_eq!( headers.get( "authorization" ).unwrap(), "Bearer ghp_test" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Organization attribution uses its own path on the GitHub host.
+#[ test ]
+fn organization_endpoint_bills_org()
+{
+  let env = GitHubModelsEnvironment::new( "ghp_test" ).unwrap()
+    .with_organization( "my-org" )
+    .with_timeout( Duration::from_secs( 5 ) );
+
+  assert_eq!( env.base_url(), "https://models.github.ai/orgs/my-org/inference/" );
+  assert!( env.headers().unwrap().get( "x-github-api-version" ).is_some() );
+  assert_eq!( env.timeout(), Duration::from_secs( 5 ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A free-tier model must answer a chat completion sent through the shared
+/// client with no further configuration.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn github_models_chat_completion_succeeds_with_real_token()
+{
+  use api_openai_compatible::{ ChatCompletionRequest, ChatCompletionResponse, Client, Message };
+
+  let ws = workspace_tools::workspace().expect( "workspace root must be resolvable" );
+  let token = ws.load_secret_key( "GITHUB_TOKEN", "-secrets.sh" )
+    .expect( "GITHUB_TOKEN must be set in secret/-secrets.sh" );
+  let client = Client::build( GitHubModelsEnvironment::new( token ).unwrap() )
+    .expect( "Client::build() must succeed" );
+
+  let request = ChatCompletionRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .messages( vec![ Message::user( "Reply with the word ok." ) ] )
+    .max_tokens( 8_u32 )
+    .form();
+  let response : ChatCompletionResponse = client.post( "chat/completions", &request ).await
+    .expect( "GitHub Models chat completion must succeed" );
+
+  assert!( !response.choices.is_empty(), "response must contain a choice" );
+}
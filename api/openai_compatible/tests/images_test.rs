@@ -0,0 +1,246 @@
+//! Tests for the image endpoints: the bodies and forms requests build, the
+//! answers they read, and generation, edits, and variations against the
+//! real API.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | generation_request_omits_unset_options | request | The JSON body holds the set options, nothing unset |
+//! | edit_form_orders_images_and_mask | request | One image as `image`, several as `image[]`, mask after them; fields before files; `response_format` replaced |
+//! | variation_form_uploads_one_image | request | `images/variations` form with model, options, and one `image` |
+//! | response_reads_base64_and_url_answers | response | `b64_json` decoded, `revised_prompt` and `usage` read; decoding a URL answer or invalid base64 fails |
+//! | generation_returns_real_images | integration | A real generation answers with a decodable PNG or a download URL, as asked |
+//! | edit_uploads_real_image_and_mask | integration | A generated PNG and its mask upload as a form the API accepts |
+//! | variation_of_real_image | integration | A variation of a generated image returns as many images as asked |
+//! | real_errors_are_classified | integration | A file that is not a PNG is `InvalidRequest` with status 400 |
+
+#![ cfg( feature = "images" ) ]
+
+use api_openai_compatible::
+{
+  ImageEditRequest, ImageGenerationRequest, ImageResponseFormat, ImageVariationRequest, ImagesResponse, OpenAiCompatError, UploadFile,
+};
+
+/// The eight-byte PNG signature, which holds a CRLF.
+const PNG : &[ u8 ] = &[ 0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n' ];
+
+/// Names of the form's parts in the order they are sent.
+fn part_names( form : &api_openai_compatible::MultipartForm ) -> Vec< String >
+{
+  form.texts().iter().map( | ( name, _ ) | name.clone() ).chain( form.files().iter().map( | ( name, _ ) | name.clone() ) ).collect()
+}
+
+// ------------------------------------------------------------------ //
+
+/// Generation is JSON; options left unset are not sent.
+#[ test ]
+fn generation_request_omits_unset_options()
+{
+  let request = ImageGenerationRequest::new( "grok-2-image", "A lighthouse at dawn" )
+    .with_n( 1 )
+    .with_response_format( ImageResponseFormat::B64Json );
+  assert_eq!( serde_json::to_value( &request ).expect( "the request must serialise" ),
+    serde_json::json!( { "model" : "grok-2-image", "prompt" : "A lighthouse at dawn", "n" : 1, "response_format" : "b64_json" } ) );
+
+  let request = ImageGenerationRequest::new( "dall-e-3", "A lighthouse" ).with_size( "1024x1024" ).with_quality( "hd" ).with_style( "natural" );
+  assert_eq!( serde_json::to_value( &request ).expect( "the request must serialise" ),
+    serde_json::json!( { "model" : "dall-e-3", "prompt" : "A lighthouse", "size" : "1024x1024", "quality" : "hd", "style" : "natural" } ) );
+}
+
+/// Edits upload every source image and the mask after the text fields.
+#[ test ]
+fn edit_form_orders_images_and_mask()
+{
+  let single = ImageEditRequest::new( "dall-e-2", UploadFile::new( "room.png", PNG ), "Add a window" )
+    .with_mask( UploadFile::new( "mask.png", PNG ) )
+    .with_response_format( ImageResponseFormat::Url )
+    .with_response_format( ImageResponseFormat::B64Json );
+  let form = single.form();
+  assert_eq!( part_names( &form ), [ "model", "prompt", "response_format", "image", "mask" ] );
+  assert_eq!( form.texts()[ 2 ].1, "b64_json" );
+  assert_eq!( ( form.files()[ 0 ].1.file_name(), form.files()[ 0 ].1.content_type(), form.files()[ 0 ].1.bytes() ), ( "room.png", "image/png", PNG ) );
+
+  let several = ImageEditRequest::new( "gpt-image-1", UploadFile::new( "soap.webp", PNG ), "A gift basket" )
+    .with_image( UploadFile::new( "lotion.jpg", PNG ) )
+    .with_field( "quality", "high" );
+  let form = several.form();
+  assert_eq!( part_names( &form ), [ "model", "prompt", "quality", "image[]", "image[]" ] );
+  assert_eq!( ( form.files()[ 0 ].1.content_type(), form.files()[ 1 ].1.content_type() ), ( "image/webp", "image/jpeg" ) );
+}
+
+/// Variations upload one image after their options.
+#[ test ]
+fn variation_form_uploads_one_image()
+{
+  let request = ImageVariationRequest::new( "dall-e-2", UploadFile::new( "cat.png", PNG ) ).with_n( 2 ).with_size( "256x256" );
+
+  assert_eq!( part_names( request.form() ), [ "model", "n", "size", "image" ] );
+  assert_eq!( request.form().texts()[ 1 ].1, "2" );
+}
+
+/// Base64 answers decode to the image bytes; URL answers hold none.
+#[ test ]
+fn response_reads_base64_and_url_answers()
+{
+  let response : ImagesResponse = serde_json::from_value( serde_json::json!(
+  {
+    "created" : 1_700_000_000,
+    "data" : [ { "b64_json" : "iVBORw0KGgo=", "revised_prompt" : "A red lighthouse at dawn." }, { "url" : "https://images.example/1.png" } ],
+    "usage" : { "input_tokens" : 10, "output_tokens" : 1056 },
+  } ) ).expect( "the answer must deserialise" );
+
+  assert_eq!( response.created, 1_700_000_000 );
+  assert_eq!( response.data[ 0 ].decode().expect( "b64_json must decode" ), PNG );
+  assert_eq!( response.data[ 0 ].revised_prompt.as_deref(), Some( "A red lighthouse at dawn." ) );
+  assert_eq!( response.extra[ "usage" ][ "output_tokens" ], 1056 );
+  assert_eq!( response.data[ 1 ].url.as_deref(), Some( "https://images.example/1.png" ) );
+  let error = response.data[ 1 ].decode().expect_err( "a URL answer holds no data" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Environment( _ ) ) ) );
+
+  let sparse : ImagesResponse = serde_json::from_str( r#"{"data":[{"b64_json":"not base64!"}]}"# ).expect( "created may be absent" );
+  assert_eq!( sparse.created, 0 );
+  let error = sparse.data[ 0 ].decode().expect_err( "invalid base64 must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Environment( message ) ) if message.contains( "b64_json" ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Client on the real API.
+#[ cfg( feature = "integration" ) ]
+fn real_client() -> api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl >
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+  use core::time::Duration;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" )
+    .with_timeout( Duration::from_secs( 120 ) );
+  Client::build( env ).expect( "Client::build() must succeed" )
+}
+
+/// A small generated PNG to edit or vary.
+#[ cfg( feature = "integration" ) ]
+async fn real_image( client : &api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl > ) -> Vec< u8 >
+{
+  let request = ImageGenerationRequest::new( "dall-e-2", "A red circle on a white background" )
+    .with_size( "256x256" )
+    .with_response_format( ImageResponseFormat::B64Json );
+  let response = client.generate_images( &request ).await.expect( "generation must succeed" );
+  response.data[ 0 ].decode().expect( "b64_json must decode" )
+}
+
+/// A square greyscale-with-alpha PNG of `side` pixels, every pixel opaque
+/// white or, for a mask, fully transparent. The image data is deflated in
+/// stored blocks, so no compressor is needed.
+#[ cfg( feature = "integration" ) ]
+fn grey_alpha_png( side : u32, transparent : bool ) -> Vec< u8 >
+{
+  fn crc32( bytes : &[ u8 ] ) -> u32
+  {
+    !bytes.iter().fold( u32::MAX, | crc, &byte | ( 0..8 ).fold( crc ^ u32::from( byte ), | crc, _ | if crc & 1 == 1 { ( crc >> 1 ) ^ 0xEDB8_8320 } else { crc >> 1 } ) )
+  }
+  fn chunk( png : &mut Vec< u8 >, kind : &[ u8 ], data : &[ u8 ] )
+  {
+    png.extend_from_slice( &u32::try_from( data.len() ).expect( "chunk fits" ).to_be_bytes() );
+    let start = png.len();
+    png.extend_from_slice( kind );
+    png.extend_from_slice( data );
+    let crc = crc32( &png[ start.. ] );
+    png.extend_from_slice( &crc.to_be_bytes() );
+  }
+
+  let pixel : [ u8; 2 ] = if transparent { [ 0, 0 ] } else { [ 255, 255 ] };
+  let width = usize::try_from( side ).expect( "side fits" );
+  let row : Vec< u8 > = core::iter::once( 0 ).chain( pixel.into_iter().cycle().take( 2 * width ) ).collect();
+  let raw = row.repeat( width );
+  let ( a, b ) = raw.iter().fold( ( 1_u32, 0_u32 ), | ( a, b ), &byte | { let a = ( a + u32::from( byte ) ) % 65521; ( a, ( b + a ) % 65521 ) } );
+  let mut deflated = vec![ 0x78, 0x01 ];
+  let blocks = raw.chunks( 65535 ).count();
+  for ( index, block ) in raw.chunks( 65535 ).enumerate()
+  {
+    let length = u16::try_from( block.len() ).expect( "stored block fits" );
+    deflated.push( u8::from( index + 1 == blocks ) );
+    deflated.extend_from_slice( &length.to_le_bytes() );
+    deflated.extend_from_slice( &( !length ).to_le_bytes() );
+    deflated.extend_from_slice( block );
+  }
+  deflated.extend_from_slice( &( ( b << 16 ) | a ).to_be_bytes() );
+
+  let mut header = Vec::new();
+  header.extend_from_slice( &side.to_be_bytes() );
+  header.extend_from_slice( &side.to_be_bytes() );
+  header.extend_from_slice( &[ 8, 4, 0, 0, 0 ] );
+  let mut png = PNG.to_vec();
+  chunk( &mut png, b"IHDR", &header );
+  chunk( &mut png, b"IDAT", &deflated );
+  chunk( &mut png, b"IEND", &[] );
+  png
+}
+
+/// Generation answers in the format asked for.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn generation_returns_real_images()
+{
+  let client = real_client();
+
+  let image = real_image( &client ).await;
+  assert!( image.starts_with( PNG ), "a b64_json answer must decode to a PNG" );
+
+  let request = ImageGenerationRequest::new( "dall-e-2", "A blue square" ).with_size( "256x256" ).with_response_format( ImageResponseFormat::Url );
+  let response = client.generate_images( &request ).await.expect( "generation must succeed" );
+  assert!( response.created > 0 );
+  assert!( response.data[ 0 ].url.as_deref().is_some_and( | url | url.starts_with( "https://" ) ), "{:?}", response.data[ 0 ] );
+  assert!( response.data[ 0 ].decode().is_err(), "a URL answer holds no data" );
+}
+
+/// An edit uploads the image and its mask; the API only accepts the form
+/// when both parts arrive intact.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn edit_uploads_real_image_and_mask()
+{
+  let client = real_client();
+  let request = ImageEditRequest::new( "dall-e-2", UploadFile::new( "canvas.png", grey_alpha_png( 256, false ) ), "A small red flower" )
+    .with_mask( UploadFile::new( "mask.png", grey_alpha_png( 256, true ) ) )
+    .with_size( "256x256" )
+    .with_response_format( ImageResponseFormat::B64Json );
+
+  let response = client.edit_images( &request ).await.expect( "the edit must succeed" );
+
+  assert_eq!( response.data.len(), 1 );
+  assert!( response.data[ 0 ].decode().expect( "b64_json must decode" ).starts_with( PNG ) );
+}
+
+/// Variations return as many images as asked for.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn variation_of_real_image()
+{
+  let client = real_client();
+  let image = real_image( &client ).await;
+
+  let request = ImageVariationRequest::new( "dall-e-2", UploadFile::new( "circle.png", image ) ).with_n( 2 ).with_size( "256x256" );
+  let response = client.image_variations( &request ).await.expect( "the variation must succeed" );
+
+  assert_eq!( response.data.len(), 2 );
+  assert!( response.data.iter().all( | image | image.url.is_some() ) );
+}
+
+/// An upload the API cannot read is a typed `400`.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn real_errors_are_classified()
+{
+  let client = real_client();
+  let request = ImageVariationRequest::new( "dall-e-2", UploadFile::new( "broken.png", PNG ) );
+
+  let error = client.image_variations( &request ).await.expect_err( "a file that is not a PNG must fail" );
+
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 400 ), .. } ) ), "{error:?}" );
+}
@@ -0,0 +1,147 @@
+//! Tests for `MockTransport`: routes, canned responses, and recorded
+//! requests, through the same client paths a real call takes.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | canned_chat_answers_and_request_is_recorded | responses | A typed chat response comes back; the request is recorded with URL, headers, hook additions, and body |
+//! | routes_match_in_order_and_unmatched_is_api_error | matching | Method, path, header, and JSON-field conditions pick the route; no match fails with `InvalidRequest` naming the request |
+//! | sequence_drives_retries | sequences | A `503` then a success is answered in order, the last response repeating, and the retry sees both |
+//! | scripted_stream_reaches_sync_stream | streaming | `text_stream` and `chat_stream` bodies decode into their chunks through `SyncChatStream` |
+
+#![ cfg( feature = "mock" ) ]
+
+use api_openai_compatible::{ ChatCompletionResponse, MockResponse, MockTransport, OpenAiCompatError, RequestMatcher };
+use reqwest::StatusCode;
+
+fn chat_request( model : &str ) -> serde_json::Value
+{
+  serde_json::json!( { "model" : model, "messages" : [ { "role" : "user", "content" : "Hello" } ] } )
+}
+
+// ------------------------------------------------------------------ //
+
+/// The client deserialises the canned completion, and the transport keeps
+/// the request as it would have gone on the wire.
+#[ tokio::test ]
+async fn canned_chat_answers_and_request_is_recorded()
+{
+  use reqwest::header::HeaderValue;
+
+  let mock = MockTransport::new().on( RequestMatcher::post( "chat/completions" ), MockResponse::chat_text( "qwen2.5", "Hi there" ) );
+  let client = mock.client()
+    .with_request_hook( | request | { request.headers.insert( "x-trace-id", HeaderValue::from_static( "trace-1" ) ); } );
+
+  let response : ChatCompletionResponse = client.post( "chat/completions", &chat_request( "qwen2.5" ) ).await
+    .expect( "the canned response must deserialise" );
+  assert_eq!( response.model, "qwen2.5" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "Hi there" ) );
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+
+  let requests = mock.requests();
+  assert_eq!( requests.len(), 1 );
+  assert_eq!( requests[ 0 ].method, reqwest::Method::POST );
+  assert_eq!( requests[ 0 ].url, "http://mock.invalid/v1/chat/completions" );
+  assert_eq!( requests[ 0 ].headers[ "authorization" ], "Bearer sk-mock" );
+  assert_eq!( requests[ 0 ].headers[ "x-trace-id" ], "trace-1" );
+  assert_eq!( requests[ 0 ].json(), Some( chat_request( "qwen2.5" ) ) );
+}
+
+/// The first route whose every condition holds answers; a request no
+/// route takes gets a `404` the client reports as `InvalidRequest`.
+#[ tokio::test ]
+async fn routes_match_in_order_and_unmatched_is_api_error()
+{
+  let mock = MockTransport::new()
+    .on( RequestMatcher::post( "chat/completions" ).with_json_field( "/model", "small" ), MockResponse::chat_text( "small", "from small" ) )
+    .on( RequestMatcher::post( "chat/completions" ).with_header( "x-tenant", "blue" ), MockResponse::chat_text( "any", "from blue" ) )
+    .on( RequestMatcher::post( "/chat/completions" ), MockResponse::error( StatusCode::BAD_REQUEST, "unknown model" ) )
+    .on( RequestMatcher::get( "models" ), MockResponse::json( StatusCode::OK, &serde_json::json!( { "object" : "list", "data" : [] } ) ) );
+  let client = mock.client();
+
+  let response : ChatCompletionResponse = client.post( "chat/completions", &chat_request( "small" ) ).await.expect( "the first route must answer" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "from small" ) );
+
+  let blue = mock.client().with_request_hook( | request | { request.headers.insert( "x-tenant", reqwest::header::HeaderValue::from_static( "blue" ) ); } );
+  let response : ChatCompletionResponse = blue.post( "chat/completions", &chat_request( "large" ) ).await.expect( "the header route must answer" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "from blue" ) );
+
+  let error = client.post::< _, ChatCompletionResponse >( "chat/completions", &chat_request( "large" ) ).await.expect_err( "400 must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 400 ), message, .. } ) if message.contains( "unknown model" ) ) );
+
+  let models : serde_json::Value = client.get( "models" ).await.expect( "the GET route must answer" );
+  assert_eq!( models[ "object" ], "list" );
+
+  let error = client.get::< serde_json::Value >( "embeddings" ).await.expect_err( "no route must fail" );
+  match error.downcast_ref::< OpenAiCompatError >()
+  {
+    Some( OpenAiCompatError::InvalidRequest { status : Some( 404 ), message, .. } ) =>
+    {
+      assert!( message.contains( "no route matches GET http://mock.invalid/v1/embeddings" ), "{message}" );
+    },
+    other => panic!( "expected a 404 InvalidRequest, got {other:?}" ),
+  }
+  assert_eq!( mock.requests().len(), 5 );
+}
+
+/// Responses of a sequence are used in order and the last one repeats, so
+/// a retrying call sees the failure and then the success.
+#[ cfg( feature = "retry" ) ]
+#[ tokio::test ]
+async fn sequence_drives_retries()
+{
+  use api_openai_compatible::{ ExecuteWithRetries, RetryConfig };
+  use core::time::Duration;
+
+  let mock = MockTransport::new().on_sequence
+  (
+    RequestMatcher::post( "chat/completions" ),
+    [
+      MockResponse::error( StatusCode::SERVICE_UNAVAILABLE, "overloaded" ).with_header( "retry-after-ms", "1" ),
+      MockResponse::chat_text( "qwen2.5", "recovered" ),
+    ],
+  );
+  let client = mock.client();
+  let config = RetryConfig::new().with_base_delay( Duration::from_millis( 1 ) ).with_jitter( false );
+
+  let response : ChatCompletionResponse = client.retrying( &config ).post( "chat/completions", &chat_request( "qwen2.5" ) ).await
+    .expect( "the retry must succeed" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "recovered" ) );
+  assert_eq!( mock.requests().len(), 2 );
+
+  let again : ChatCompletionResponse = client.post( "chat/completions", &chat_request( "qwen2.5" ) ).await.expect( "the last response must repeat" );
+  assert_eq!( again.choices[ 0 ].message.content.as_deref(), Some( "recovered" ) );
+}
+
+/// Scripted event streams decode chunk by chunk through the blocking
+/// stream iterator, ending at `[DONE]`.
+#[ cfg( all( feature = "sync_api", feature = "streaming" ) ) ]
+#[ test ]
+fn scripted_stream_reaches_sync_stream()
+{
+  use api_openai_compatible::{ ChatCompletionChunk, SyncClient };
+
+  let contents = | stream : api_openai_compatible::SyncChatStream | -> Vec< String >
+  {
+    stream
+      .map( | chunk | chunk.expect( "the chunk must decode" ) )
+      .filter_map( | chunk | chunk.choices.first().and_then( | choice | choice.delta.content.clone() ) )
+      .collect()
+  };
+
+  let mock = MockTransport::new().on( RequestMatcher::post( "chat/completions" ), MockResponse::text_stream( "qwen2.5", [ "Hel", "lo", "!" ] ) );
+  let sync = SyncClient::new( mock.client() ).expect( "SyncClient::new() must succeed" );
+  let stream = sync.post_stream( "chat/completions", &serde_json::json!( { "stream" : true } ) ).expect( "the stream must open" );
+  assert_eq!( contents( stream ), [ "Hel", "lo", "!" ] );
+
+  let chunk : ChatCompletionChunk = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "chatcmpl-1", "object" : "chat.completion.chunk", "created" : 1, "model" : "m",
+    "choices" : [ { "index" : 0, "delta" : { "content" : "typed" }, "finish_reason" : null } ],
+  } ) ).expect( "chunk must deserialise" );
+  let mock = MockTransport::new().on( RequestMatcher::any(), MockResponse::chat_stream( &[ chunk.clone(), chunk ] ) );
+  let sync = SyncClient::new( mock.client() ).expect( "SyncClient::new() must succeed" );
+  let stream = sync.post_stream( "chat/completions", &serde_json::json!( { "stream" : true } ) ).expect( "the stream must open" );
+  assert_eq!( contents( stream ), [ "typed", "typed" ] );
+}
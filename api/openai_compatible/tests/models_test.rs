@@ -0,0 +1,117 @@
+//! Tests for the models endpoints: listing in every shape servers answer
+//! with, and retrieving one model, offline and against the real API.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | list_reads_paginated_and_bare_answers | listing | Pagination fields read; a bare array and a page without `object` read as lists; missing model fields defaulted, extra ones kept |
+//! | retrieve_reads_model_from_its_path | retrieval | `models/{id}` path; the model is read with no extra fields |
+//! | list_reads_real_models | integration | The real listing is a page of models including `gpt-4o-mini` |
+//! | retrieve_reads_real_model_and_reports_unknown | integration | A real model is read; an unknown one is `InvalidRequest` with status 404 |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::{ ListModelsResponse, Model };
+
+// ------------------------------------------------------------------ //
+
+/// Paginated pages, bare arrays, and sparse model objects all read as lists.
+#[ test ]
+fn list_reads_paginated_and_bare_answers()
+{
+  let page = serde_json::json!(
+  {
+    "object" : "list",
+    "data" :
+    [
+      { "id" : "gpt-4o", "object" : "model", "created" : 1_715_367_049, "owned_by" : "system" },
+      { "id" : "gpt-4o-mini", "object" : "model", "created" : 1_721_172_741, "owned_by" : "system" },
+    ],
+    "has_more" : true,
+    "first_id" : "gpt-4o",
+    "last_id" : "gpt-4o-mini",
+  } );
+  let response : ListModelsResponse = serde_json::from_value( page ).expect( "the page must deserialise" );
+  assert_eq!( ( response.object.as_str(), response.data.len() ), ( "list", 2 ) );
+  assert_eq!( ( response.has_more, response.last_id.as_deref() ), ( Some( true ), Some( "gpt-4o-mini" ) ) );
+  assert!( response.contains( "gpt-4o-mini" ) );
+  assert!( !response.contains( "gpt-4" ) );
+
+  let bare : ListModelsResponse = serde_json::from_value( serde_json::json!(
+  [
+    { "id" : "meta-llama/Llama-3.3-70B-Instruct-Turbo", "object" : "model", "created" : 0, "type" : "chat", "context_length" : 131_072 },
+  ] ) ).expect( "a bare array must deserialise" );
+  assert_eq!( ( bare.object.as_str(), bare.has_more ), ( "list", None ) );
+  assert_eq!( bare.data[ 0 ].extra[ "context_length" ], 131_072 );
+
+  let sparse : ListModelsResponse = serde_json::from_value( serde_json::json!( { "data" : [ { "id" : "qwen2.5-7b-instruct", "max_model_len" : 32_768 } ] } ) )
+    .expect( "a page without object must deserialise" );
+  let model = &sparse.data[ 0 ];
+  assert_eq!( ( sparse.object.as_str(), model.object.as_str(), model.created, model.owned_by.as_str() ), ( "list", "model", 0, "" ) );
+  assert_eq!( model.extra[ "max_model_len" ], 32_768 );
+
+  let round_trip : ListModelsResponse = serde_json::from_str( &serde_json::to_string( &response ).expect( "the list must serialise" ) )
+    .expect( "a serialised list must deserialise" );
+  assert_eq!( round_trip, response );
+}
+
+/// One model is read from its own path, with only the fields it has.
+#[ test ]
+fn retrieve_reads_model_from_its_path()
+{
+  assert_eq!( Model::path( "grok-3" ), "models/grok-3" );
+  assert_eq!( Model::path( "ft:gpt-4o-mini:acme::abc123" ), "models/ft:gpt-4o-mini:acme::abc123" );
+
+  let model : Model = serde_json::from_value( serde_json::json!( { "id" : "grok-3", "object" : "model", "created" : 1_743_724_800, "owned_by" : "xai" } ) )
+    .expect( "the model must deserialise" );
+  assert_eq!( ( model.id.as_str(), model.owned_by.as_str(), model.created ), ( "grok-3", "xai", 1_743_724_800 ) );
+  assert!( model.extra.is_empty() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Client on the real API.
+#[ cfg( feature = "integration" ) ]
+fn real_client() -> api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl >
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  Client::build( env ).expect( "Client::build() must succeed" )
+}
+
+/// The real listing is a page of models that includes the chat model the
+/// other tests use.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn list_reads_real_models()
+{
+  let response = real_client().list_models().await.expect( "the listing must deserialise" );
+
+  assert_eq!( response.object, "list" );
+  assert!( response.contains( "gpt-4o-mini" ), "gpt-4o-mini must be listed" );
+  assert!( response.data.iter().all( | model | model.object == "model" && !model.owned_by.is_empty() ) );
+}
+
+/// One real model is read from its path; an unknown one is a typed `404`.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn retrieve_reads_real_model_and_reports_unknown()
+{
+  use api_openai_compatible::OpenAiCompatError;
+
+  let client = real_client();
+
+  let model = client.retrieve_model( "gpt-4o-mini" ).await.expect( "the model must deserialise" );
+  assert_eq!( ( model.id.as_str(), model.object.as_str() ), ( "gpt-4o-mini", "model" ) );
+  assert!( model.created > 0 );
+
+  let error = client.retrieve_model( "no-such-model" ).await.expect_err( "an unknown model must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::InvalidRequest { status : Some( 404 ), .. } ) ), "{error:?}" );
+}
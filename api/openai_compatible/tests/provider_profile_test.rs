@@ -0,0 +1,196 @@
+//! Tests for `ProviderProfile`: the response leniencies each server family
+//! turns on, applied to raw JSON and through the client.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | standard_profile_changes_nothing | strictness | `Standard` allows no leniency, leaves a deviating body as is, and the body still fails to deserialise |
+//! | profiles_repair_their_deviations | leniencies | LM Studio fills a missing header and usage and maps `eos`; vLLM fills tool call `type` and `id` and completes usage; standard values and other endpoints are untouched |
+//! | groq_usage_lifted_from_x_groq | streaming | Groq's `x_groq.usage` becomes the chunk's `usage` and `x_groq` is dropped; a chunk without usage stays without |
+//! | chunk_decoder_applies_profile | streaming | A `ChunkDecoder` with the Groq profile lifts usage from every chunk; without it the usage stays hidden, or the chunk fails under `strict_wire` |
+//! | profiles_keep_real_response_values | integration | Every profile reads a real chat completion and keeps its id, timestamp, usage, and finish reason |
+//! | sync_stream_applies_profile_to_real_chunks | integration | A `SyncClient` stream under a profile decodes real chunks and keeps standard usage on the last one |
+
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::{ ChatCompletionResponse, Leniency, ProviderProfile };
+use serde_json::json;
+
+/// A response as LM Studio sends it: no header fields, no usage, and an
+/// engine finish reason.
+fn bare_response() -> serde_json::Value
+{
+  json!( { "choices" : [ { "index" : 0, "message" : { "role" : "assistant", "content" : "Hi" }, "finish_reason" : "eos" } ] } )
+}
+
+// ------------------------------------------------------------------ //
+
+/// Without a profile the wire types stay strict about required fields.
+#[ test ]
+fn standard_profile_changes_nothing()
+{
+  assert_eq!( ProviderProfile::default(), ProviderProfile::Standard );
+  assert!( ProviderProfile::Standard.leniencies().is_empty() );
+  assert_eq!( ProviderProfile::Standard.name(), "standard" );
+
+  let mut body = bare_response();
+  ProviderProfile::Standard.normalize_response( &mut body );
+  assert_eq!( body, bare_response() );
+  assert!( serde_json::from_value::< ChatCompletionResponse >( body ).is_err() );
+}
+
+/// Each profile repairs only what its leniencies name.
+#[ test ]
+fn profiles_repair_their_deviations()
+{
+  let mut body = bare_response();
+  ProviderProfile::LmStudio.normalize_response( &mut body );
+  let response : ChatCompletionResponse = serde_json::from_value( body ).expect( "LM Studio body must deserialise once repaired" );
+  assert_eq!( response.object, "chat.completion" );
+  assert_eq!( response.created, 0 );
+  assert_eq!( response.usage.total_tokens, 0 );
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+  assert!( response.system_fingerprint.is_none() );
+
+  let mut body = json!(
+  {
+    "id" : "c1", "object" : "chat.completion", "created" : 7, "model" : "llama",
+    "choices" : [ { "index" : 0, "message" : { "role" : "assistant", "content" : null,
+      "tool_calls" : [ { "function" : { "name" : "get_weather", "arguments" : "{}" } } ] }, "finish_reason" : "tool_calls" } ],
+    "usage" : { "prompt_tokens" : 3, "completion_tokens" : 2 },
+  } );
+  assert!( !ProviderProfile::Together.allows( Leniency::ToolCallDefaults ) );
+  assert!( ProviderProfile::Vllm.allows( Leniency::ToolCallDefaults ) );
+  ProviderProfile::Vllm.normalize_response( &mut body );
+  let response : ChatCompletionResponse = serde_json::from_value( body ).expect( "vLLM body must deserialise once repaired" );
+  let call = &response.choices[ 0 ].message.tool_calls.as_ref().expect( "tool calls must survive" )[ 0 ];
+  assert_eq!( ( call.id.as_str(), call.tool_type.as_str() ), ( "call_0", "function" ) );
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "tool_calls" ) );
+  assert_eq!( ( response.created, response.usage.total_tokens ), ( 7, 5 ) );
+
+  let mut other = json!( { "object" : "list", "data" : [] } );
+  ProviderProfile::LmStudio.normalize_response( &mut other );
+  assert_eq!( other, json!( { "object" : "list", "data" : [] } ) );
+}
+
+/// Groq reports streamed usage under `x_groq`; the profile moves it to
+/// where the chunk type looks.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn groq_usage_lifted_from_x_groq()
+{
+  use api_openai_compatible::ChatCompletionChunk;
+
+  let chunk = | extra : serde_json::Value | -> serde_json::Value
+  {
+    let mut chunk = json!( { "id" : "c1", "object" : "chat.completion.chunk", "created" : 1, "model" : "llama",
+      "choices" : [ { "index" : 0, "delta" : {}, "finish_reason" : "stop" } ] } );
+    chunk.as_object_mut().expect( "chunk is an object" ).extend( extra.as_object().expect( "extra is an object" ).clone() );
+    chunk
+  };
+
+  let mut last = chunk( json!( { "x_groq" : { "id" : "req_1", "usage" : { "prompt_tokens" : 4, "completion_tokens" : 6, "total_tokens" : 10 } } } ) );
+  ProviderProfile::Groq.normalize_chunk( &mut last );
+  assert!( last.get( "x_groq" ).is_none() );
+  let decoded : ChatCompletionChunk = serde_json::from_value( last ).expect( "Groq chunk must deserialise" );
+  assert_eq!( decoded.usage.map( | usage | usage.total_tokens ), Some( 10 ) );
+
+  let mut middle = chunk( json!( {} ) );
+  ProviderProfile::Groq.normalize_chunk( &mut middle );
+  assert!( middle.get( "usage" ).is_none() );
+  ProviderProfile::LmStudio.normalize_chunk( &mut middle );
+  assert!( middle.get( "usage" ).is_none(), "chunks without usage are standard" );
+}
+
+/// A chunk decoder given a profile repairs every chunk it decodes, as
+/// `SyncClient` streams do with the profile of their environment.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn chunk_decoder_applies_profile()
+{
+  use api_openai_compatible::ChunkDecoder;
+
+  let events = concat!(
+    "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"llama\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"},\"finish_reason\":null}]}\n\n",
+    "data: {\"id\":\"c1\",\"object\":\"chat.completion.chunk\",\"created\":1,\"model\":\"llama\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}],\"x_groq\":{\"usage\":{\"prompt_tokens\":1,\"completion_tokens\":1,\"total_tokens\":2}}}\n\n",
+    "data: [DONE]\n\n",
+  );
+  let usage = | profile : ProviderProfile | -> Vec< Option< Option< u32 > > >
+  {
+    let mut decoder = ChunkDecoder::new().with_profile( profile );
+    decoder.push( events.as_bytes() );
+    core::iter::from_fn( || decoder.next_chunk() )
+      .map( | chunk | chunk.ok().map( | chunk | chunk.usage.map( | usage | usage.total_tokens ) ) )
+      .collect()
+  };
+
+  assert_eq!( usage( ProviderProfile::Groq ), [ Some( None ), Some( Some( 2 ) ) ] );
+  let unlifted = if cfg!( feature = "strict_wire" ) { None } else { Some( None ) };
+  assert_eq!( usage( ProviderProfile::Standard ), [ Some( None ), unlifted ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Client on the real API with `profile` set on its environment.
+#[ cfg( feature = "integration" ) ]
+fn real_client( profile : ProviderProfile ) -> api_openai_compatible::Client< api_openai_compatible::OpenAiCompatEnvironmentImpl >
+{
+  use api_openai_compatible::{ Client, OpenAiCompatEnvironmentImpl };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" )
+    .with_provider_profile( profile );
+  Client::build( env ).expect( "Client::build() must succeed" )
+}
+
+/// The leniencies fill only what is missing: a complete real response read
+/// under every profile keeps the values the API sent.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn profiles_keep_real_response_values()
+{
+  let request = json!( { "model" : "gpt-4o-mini", "messages" : [ { "role" : "user", "content" : "Say hi." } ], "max_tokens" : 5 } );
+
+  for profile in [ ProviderProfile::Standard, ProviderProfile::Groq, ProviderProfile::Together, ProviderProfile::Vllm, ProviderProfile::LmStudio ]
+  {
+    let client = real_client( profile );
+    assert_eq!( client.provider_profile(), profile );
+    let response : ChatCompletionResponse = client.post( "chat/completions", &request ).await
+      .unwrap_or_else( | error | panic!( "{} must read the real response: {error}", profile.name() ) );
+    assert!( response.id.starts_with( "chatcmpl-" ), "{}: {}", profile.name(), response.id );
+    assert!( response.created > 0, "{}: the real timestamp must be kept", profile.name() );
+    assert!( response.usage.total_tokens > 0, "{}: the real usage must be kept", profile.name() );
+    assert!( response.choices[ 0 ].finish_reason.is_some(), "{}", profile.name() );
+  }
+}
+
+/// A `SyncClient` stream decodes real chunks with its environment's
+/// profile, and usage the API reports in the standard place stays there.
+#[ cfg( all( feature = "integration", feature = "sync_api", feature = "streaming" ) ) ]
+#[ test ]
+fn sync_stream_applies_profile_to_real_chunks()
+{
+  use api_openai_compatible::SyncClient;
+
+  let sync = SyncClient::new( real_client( ProviderProfile::Groq ) ).expect( "SyncClient::new() must succeed" );
+  let request = json!(
+  {
+    "model" : "gpt-4o-mini",
+    "messages" : [ { "role" : "user", "content" : "Say hi." } ],
+    "max_tokens" : 5,
+    "stream" : true,
+    "stream_options" : { "include_usage" : true },
+  } );
+
+  let usage : Vec< u32 > = sync.post_stream( "chat/completions", &request ).expect( "the stream must open" )
+    .filter_map( | chunk | chunk.expect( "every chunk must decode" ).usage.map( | usage | usage.total_tokens ) )
+    .collect();
+  assert_eq!( usage.len(), 1, "exactly the last chunk must carry usage; got {usage:?}" );
+  assert!( usage[ 0 ] > 0 );
+}
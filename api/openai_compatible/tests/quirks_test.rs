@@ -0,0 +1,192 @@
+//! Tests for `ProviderQuirks` request validation.
+//!
+//! Each test builds a request `OpenAI` accepts and checks which providers
+//! refuse it before sending. No HTTP is made.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | plain_request_passes_every_profile | unit | No rule fires without stop, tools, or ids |
+//! | stop_sequence_limits_differ_per_provider | unit | Groq 4, `DeepSeek` 16, Mistral unlimited |
+//! | tool_choice_requires_tools | unit | `tool_choice` without `tools` → `InvalidRequest` |
+//! | any_mode_is_mistral_only | unit | `"any"` accepted by Mistral, rejected by Groq and `DeepSeek` |
+//! | mistral_rejects_tool_call_ids_not_nine_alphanumerics | unit | `call_…` ids rejected in assistant and tool messages |
+//! | deepseek_rejects_reasoning_content_in_history | unit | Echoed `reasoning_content` rejected by `DeepSeek` only |
+//! | error_names_provider_and_rule | unit | `InvalidRequest` detail carries provider and limit |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::
+{
+  ChatCompletionRequest, FunctionCall, Message, OpenAiCompatError, ProviderQuirks,
+  Tool, ToolCall, ToolChoice,
+};
+
+const ALL : [ ProviderQuirks; 3 ] = [ ProviderQuirks::Mistral, ProviderQuirks::Groq, ProviderQuirks::DeepSeek ];
+
+fn request( messages : Vec< Message > ) -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( "test-model".to_string() )
+    .messages( messages )
+    .form()
+}
+
+fn stops( count : usize ) -> Vec< String >
+{
+  ( 0..count ).map( | i | i.to_string() ).collect()
+}
+
+fn weather_tool() -> Tool
+{
+  Tool::function( "get_weather", "Current weather", serde_json::json!( { "type" : "object", "properties" : {} } ) )
+}
+
+fn tool_round_trip( id : &str ) -> Vec< Message >
+{
+  let call = ToolCall
+  {
+    id        : id.to_string(),
+    tool_type : "function".to_string(),
+    function  : FunctionCall { name : "get_weather".to_string(), arguments : "{}".to_string() },
+  };
+  let mut assistant = Message::assistant( "" );
+  assistant.content = None;
+  assistant.tool_calls = Some( vec![ call ] );
+  vec![ Message::user( "Weather?" ), assistant, Message::tool( id, "sunny" ) ]
+}
+
+// ------------------------------------------------------------------ //
+
+/// A request without stop sequences, tools, or tool calls breaks no rule.
+#[ test ]
+fn plain_request_passes_every_profile()
+{
+  let req = request( vec![ Message::system( "Be brief." ), Message::user( "Hi" ) ] );
+  for quirks in ALL
+  {
+    assert!( quirks.validate( &req ).is_ok(), "{quirks:?} must accept a plain request" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Groq caps stop sequences at 4 and `DeepSeek` at 16; Mistral has no cap.
+#[ test ]
+fn stop_sequence_limits_differ_per_provider()
+{
+  let mut req = request( vec![ Message::user( "Count" ) ] );
+
+  req.stop = Some( stops( 4 ) );
+  assert!( ProviderQuirks::Groq.validate( &req ).is_ok() );
+  req.stop = Some( stops( 5 ) );
+  assert!( ProviderQuirks::Groq.validate( &req ).is_err() );
+  assert!( ProviderQuirks::DeepSeek.validate( &req ).is_ok() );
+
+  req.stop = Some( stops( 17 ) );
+  assert!( ProviderQuirks::DeepSeek.validate( &req ).is_err() );
+  assert!( ProviderQuirks::Mistral.validate( &req ).is_ok() );
+  assert_eq!( ProviderQuirks::Mistral.max_stop_sequences(), None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `tool_choice` is meaningless without tools and every profile rejects it.
+#[ test ]
+fn tool_choice_requires_tools()
+{
+  let mut req = request( vec![ Message::user( "Weather?" ) ] );
+  req.tool_choice = Some( ToolChoice::auto() );
+  for quirks in ALL
+  {
+    assert!( quirks.validate( &req ).is_err(), "{quirks:?} must reject tool_choice without tools" );
+  }
+
+  req.tools = Some( vec![ weather_tool() ] );
+  for quirks in ALL
+  {
+    assert!( quirks.validate( &req ).is_ok(), "{quirks:?} must accept auto with tools" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// `"any"` is Mistral's spelling of `"required"`; the others refuse it.
+/// A named function is accepted by all.
+#[ test ]
+fn any_mode_is_mistral_only()
+{
+  let mut req = request( vec![ Message::user( "Weather?" ) ] );
+  req.tools = Some( vec![ weather_tool() ] );
+
+  req.tool_choice = Some( ToolChoice::Mode( "any".to_string() ) );
+  assert!( ProviderQuirks::Mistral.validate( &req ).is_ok() );
+  assert!( ProviderQuirks::Groq.validate( &req ).is_err() );
+  assert!( ProviderQuirks::DeepSeek.validate( &req ).is_err() );
+
+  req.tool_choice = Some( ToolChoice::function( "get_weather" ) );
+  for quirks in ALL
+  {
+    assert!( quirks.validate( &req ).is_ok(), "{quirks:?} must accept a named function" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Mistral requires 9-character alphanumeric tool call ids, so a history
+/// carrying `OpenAI`-style `call_…` ids is rejected; Groq accepts it.
+#[ test ]
+fn mistral_rejects_tool_call_ids_not_nine_alphanumerics()
+{
+  let openai_ids = request( tool_round_trip( "call_abc123" ) );
+  assert!( ProviderQuirks::Mistral.validate( &openai_ids ).is_err() );
+  assert!( ProviderQuirks::Groq.validate( &openai_ids ).is_ok() );
+
+  let mistral_ids = request( tool_round_trip( "D681PevKs" ) );
+  assert!( ProviderQuirks::Mistral.validate( &mistral_ids ).is_ok() );
+
+  // Only the tool result carries a bad id.
+  let mut mixed = tool_round_trip( "D681PevKs" );
+  mixed[ 2 ] = Message::tool( "D681PevK_", "sunny" );
+  assert!( ProviderQuirks::Mistral.validate( &request( mixed ) ).is_err() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `DeepSeek` returns `reasoning_content` but rejects it as input, so an
+/// assistant turn appended unchanged fails validation.
+#[ test ]
+fn deepseek_rejects_reasoning_content_in_history()
+{
+  let mut answer = Message::assistant( "4" );
+  answer.reasoning_content = Some( "2 + 2 is 4.".to_string() );
+  let mut req = request( vec![ Message::user( "2 + 2?" ), answer, Message::user( "And 3 + 3?" ) ] );
+
+  assert!( ProviderQuirks::DeepSeek.validate( &req ).is_err() );
+  assert!( ProviderQuirks::Groq.validate( &req ).is_ok() );
+
+  req.messages[ 1 ].reasoning_content = None;
+  assert!( ProviderQuirks::DeepSeek.validate( &req ).is_ok() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The error is `InvalidRequest` and names the provider and the limit.
+#[ test ]
+fn error_names_provider_and_rule()
+{
+  let mut req = request( vec![ Message::user( "Count" ) ] );
+  req.stop = Some( stops( 5 ) );
+
+  let err = ProviderQuirks::Groq.validate( &req ).expect_err( "5 stop sequences exceed Groq's limit" );
+  match err.downcast_ref::< OpenAiCompatError >()
+  {
+    Some( OpenAiCompatError::InvalidRequest { status : None, message : detail, .. } ) =>
+    {
+      assert!( detail.contains( "groq" ), "detail must name the provider; got: {detail}" );
+      assert!( detail.contains( "at most 4" ), "detail must name the limit; got: {detail}" );
+    },
+    other => panic!( "expected InvalidRequest, got: {other:?}" ),
+  }
+}
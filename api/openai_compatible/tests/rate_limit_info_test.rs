@@ -0,0 +1,116 @@
+//! Tests for rate-limit headers: parsing, the wait they imply, and their
+//! place on successful responses and `429` errors.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | headers_are_parsed_and_imply_a_wait | parsing | `OpenAI` names, Go-duration and numeric resets, single-quota fallbacks, unreadable values ignored; `wait` prefers `Retry-After`, else the longest exhausted reset |
+//! | rate_limit_errors_carry_headers | errors | A `429` carries its headers in `RateLimited` and waits as they say; other statuses carry none |
+//! | real_responses_carry_headers | integration | `post_with_rate_limits` returns the real API's quota headers with the value; `get_with_rate_limits` returns the listing |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::RateLimitInfo;
+use core::time::Duration;
+use reqwest::header::HeaderMap;
+
+fn headers( pairs : &[ ( &'static str, &str ) ] ) -> HeaderMap
+{
+  let mut map = HeaderMap::new();
+  for ( name, value ) in pairs
+  {
+    map.insert( *name, value.parse().expect( "valid header value" ) );
+  }
+  map
+}
+
+// ------------------------------------------------------------------ //
+
+/// Every header `OpenAI` and the servers that copy it send is read; the
+/// wait follows `Retry-After`, then the quota that ran out.
+#[ test ]
+fn headers_are_parsed_and_imply_a_wait()
+{
+  let info = RateLimitInfo::from_headers( &headers( &
+  [
+    ( "x-ratelimit-limit-requests", "10000" ),
+    ( "x-ratelimit-limit-tokens", "2000000" ),
+    ( "x-ratelimit-remaining-requests", "9999" ),
+    ( "x-ratelimit-remaining-tokens", "1999985" ),
+    ( "x-ratelimit-reset-requests", "6ms" ),
+    ( "x-ratelimit-reset-tokens", "1h2m3.5s" ),
+  ] ) );
+  assert_eq!( ( info.limit_requests, info.limit_tokens ), ( Some( 10_000 ), Some( 2_000_000 ) ) );
+  assert_eq!( ( info.remaining_requests, info.remaining_tokens ), ( Some( 9_999 ), Some( 1_999_985 ) ) );
+  assert_eq!( info.reset_requests, Some( Duration::from_millis( 6 ) ) );
+  assert_eq!( info.reset_tokens, Some( Duration::from_millis( 3_723_500 ) ) );
+  assert_eq!( ( info.retry_after, info.wait() ), ( None, None ), "nothing is exhausted" );
+
+  let exhausted = RateLimitInfo::from_headers( &headers( &
+  [
+    ( "x-ratelimit-remaining-requests", "0" ),
+    ( "x-ratelimit-reset-requests", "2m59.56s" ),
+    ( "x-ratelimit-remaining-tokens", "0" ),
+    ( "x-ratelimit-reset-tokens", "7.66s" ),
+  ] ) );
+  assert_eq!( exhausted.wait(), Some( Duration::from_millis( 179_560 ) ), "the longer reset must win" );
+
+  let told = RateLimitInfo::from_headers( &headers( &[ ( "x-ratelimit-remaining-requests", "0" ), ( "x-ratelimit-reset-requests", "1m" ), ( "retry-after", "5" ) ] ) );
+  assert_eq!( told.wait(), Some( Duration::from_secs( 5 ) ), "Retry-After must win over resets" );
+
+  let single = RateLimitInfo::from_headers( &headers( &[ ( "x-ratelimit-limit", "600" ), ( "x-ratelimit-remaining", "599" ), ( "x-ratelimit-reset", "0.5" ) ] ) );
+  assert_eq!( ( single.limit_requests, single.remaining_requests, single.reset_requests ), ( Some( 600 ), Some( 599 ), Some( Duration::from_millis( 500 ) ) ) );
+
+  let garbled = RateLimitInfo::from_headers( &headers( &[ ( "x-ratelimit-remaining-tokens", "many" ), ( "x-ratelimit-reset-tokens", "soon" ), ( "x-ratelimit-reset-requests", "1d" ) ] ) );
+  assert!( garbled.is_empty(), "unreadable values must be ignored; got {garbled:?}" );
+  assert!( RateLimitInfo::from_headers( &HeaderMap::new() ).is_empty() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A `429` keeps the headers it came with, and its wait follows them.
+#[ test ]
+fn rate_limit_errors_carry_headers()
+{
+  use api_openai_compatible::OpenAiCompatError;
+  use reqwest::StatusCode;
+
+  let limited = headers( &[ ( "x-ratelimit-remaining-requests", "0" ), ( "x-ratelimit-reset-requests", "20s" ), ( "retry-after", "20" ) ] );
+  let body = br#"{"error":{"message":"Rate limit reached for requests","type":"requests","code":"rate_limit_exceeded"}}"#;
+
+  let error = OpenAiCompatError::from_response( StatusCode::TOO_MANY_REQUESTS, &limited, body );
+
+  let info = error.rate_limits().expect( "a 429 must carry its headers" );
+  assert_eq!( ( info.remaining_requests, info.wait() ), ( Some( 0 ), Some( Duration::from_secs( 20 ) ) ) );
+  assert_eq!( error.retry_after(), Some( Duration::from_secs( 20 ) ) );
+  assert!( OpenAiCompatError::from_response( StatusCode::BAD_REQUEST, &limited, body ).rate_limits().is_none() );
+}
+
+/// The real API reports its quotas on every chat completion; the client
+/// hands them back with the value.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn real_responses_carry_headers()
+{
+  use api_openai_compatible::{ ChatCompletionResponse, Client, ListModelsResponse, OpenAiCompatEnvironmentImpl, RequestOptions };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let client = Client::build( OpenAiCompatEnvironmentImpl::new( &api_key ).expect( "environment construction must succeed" ) )
+    .expect( "Client::build() must succeed" );
+  let request = serde_json::json!( { "model" : "gpt-4o-mini", "messages" : [ { "role" : "user", "content" : "Hi" } ], "max_tokens" : 5 } );
+
+  let answer = client.post_with_rate_limits::< _, ChatCompletionResponse >( "chat/completions", &request, &RequestOptions::new() ).await
+    .expect( "the chat must succeed" );
+  assert_eq!( answer.value.choices.len(), 1 );
+  let info = answer.rate_limits;
+  assert!( info.limit_requests.is_some_and( | limit | limit > 0 ), "{info:?}" );
+  assert!( info.remaining_tokens.is_some() && info.reset_tokens.is_some(), "{info:?}" );
+  assert!( info.remaining_requests <= info.limit_requests, "{info:?}" );
+
+  let models = client.get_with_rate_limits::< ListModelsResponse >( "models", &RequestOptions::new() ).await.expect( "listing must succeed" );
+  assert_eq!( models.into_value().object, "list" );
+}
@@ -0,0 +1,144 @@
+//! Tests for `Cassette`: recording real interactions with credentials
+//! scrubbed, and replaying them without the network.
+//!
+//! Replay runs offline against cassettes committed under
+//! `tests/fixtures/cassettes`; recording talks to the real API. How requests
+//! are scrubbed and matched is unit-tested in `src/record_replay.rs`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | replay_matches_by_method_url_and_body | matching | Member order does not matter; each interaction answers once; an unknown request fails with `Environment` |
+//! | recorded_stream_replays_through_sync_stream | streaming | An event-stream body in a committed cassette decodes into its chunks |
+//! | recorded_interaction_replays_without_network | integration | A real chat is recorded and replays with a placeholder key; the file is a readable JSON cassette |
+//! | recording_scrubs_api_key | integration | The real API's 401 is recorded with credential headers `[REDACTED]` and nothing in the file holding the key |
+
+#![ cfg( feature = "record_replay" ) ]
+
+use api_openai_compatible::{ Cassette, Client, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+
+fn client( key : &str, base_url : &str, cassette : Cassette ) -> Client< OpenAiCompatEnvironmentImpl >
+{
+  let env = OpenAiCompatEnvironmentImpl::new( key )
+    .expect( "environment construction must succeed" )
+    .with_base_url( base_url );
+  Client::build( env ).expect( "Client::build() must succeed" ).with_cassette( cassette )
+}
+
+fn fixture( name : &str ) -> String
+{
+  format!( "{}/tests/fixtures/cassettes/{name}", env!( "CARGO_MANIFEST_DIR" ) )
+}
+
+fn chat_request() -> serde_json::Value
+{
+  serde_json::json!( { "model" : "qwen2.5", "messages" : [ { "role" : "user", "content" : "Say hello" } ] } )
+}
+
+// ------------------------------------------------------------------ //
+
+/// Replay finds an unused interaction with the same call, comparing JSON
+/// bodies as values.
+#[ tokio::test ]
+async fn replay_matches_by_method_url_and_body()
+{
+  use api_openai_compatible::{ CassetteMode, ChatCompletionResponse };
+
+  let cassette = Cassette::replay( fixture( "chat.json" ) ).expect( "the cassette must load" );
+  assert_eq!( cassette.mode(), CassetteMode::Replay );
+  let replayer = client( "sk-ci-placeholder", "https://api.example.invalid/v1/", cassette );
+  let reordered = serde_json::json!( { "messages" : [ { "content" : "Say hello", "role" : "user" } ], "model" : "qwen2.5" } );
+  let replay : ChatCompletionResponse = replayer.post( "chat/completions", &reordered ).await.expect( "member order must not matter" );
+  assert_eq!( replay.choices[ 0 ].message.content.as_deref(), Some( "Hello!" ) );
+
+  for ( body, what ) in [ ( chat_request(), "an interaction answers once" ), ( serde_json::json!( { "model" : "other" } ), "a different body has no interaction" ) ]
+  {
+    let error = replayer.post::< _, serde_json::Value >( "chat/completions", &body ).await.expect_err( what );
+    match error.downcast_ref::< OpenAiCompatError >()
+    {
+      Some( OpenAiCompatError::Environment( message ) ) => assert!( message.contains( "no unused interaction for POST" ), "{message}" ),
+      other => panic!( "expected an Environment error, got {other:?}" ),
+    }
+  }
+
+  let error = Cassette::replay( fixture( "missing.json" ) ).expect_err( "a missing cassette must fail" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Environment( _ ) ) ) );
+}
+
+/// A committed cassette holding an event stream replays chunk by chunk.
+#[ cfg( all( feature = "sync_api", feature = "streaming" ) ) ]
+#[ test ]
+fn recorded_stream_replays_through_sync_stream()
+{
+  use api_openai_compatible::SyncClient;
+
+  let cassette = Cassette::replay( fixture( "chat_stream.json" ) ).expect( "the cassette must load" );
+  let sync = SyncClient::new( client( "sk-ci-placeholder", "https://api.example.invalid/v1/", cassette ) ).expect( "SyncClient::new() must succeed" );
+  let request = serde_json::json!( { "model" : "qwen2.5", "messages" : [ { "role" : "user", "content" : "Say hello" } ], "stream" : true } );
+
+  let contents : Vec< String > = sync.post_stream( "chat/completions", &request ).expect( "the stream must open" )
+    .map( | chunk | chunk.expect( "the chunk must decode" ).choices[ 0 ].delta.content.clone().unwrap_or_default() )
+    .collect();
+  assert_eq!( contents, [ "Hel", "lo" ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// What was recorded against the real API comes back from the file without
+/// the network, whatever key the replaying client holds.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn recorded_interaction_replays_without_network()
+{
+  use api_openai_compatible::ChatCompletionResponse;
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let dir = tempfile::tempdir().expect( "temp dir must be created" );
+  let path = dir.path().join( "cassettes/chat.json" );
+  let base_url = "https://api.openai.com/v1/";
+  let request = serde_json::json!( { "model" : "gpt-4o-mini", "messages" : [ { "role" : "user", "content" : "Say hello" } ], "max_tokens" : 5 } );
+
+  let recorder = client( &api_key, base_url, Cassette::record( &path ) );
+  let live : ChatCompletionResponse = recorder.post( "chat/completions", &request ).await.expect( "the real call must succeed" );
+
+  let text = std::fs::read_to_string( &path ).expect( "cassette must be written" );
+  assert!( !text.contains( api_key.as_str() ), "the key must not reach the file" );
+  let file : serde_json::Value = serde_json::from_str( &text ).expect( "cassette must be JSON" );
+  let interaction = &file[ "interactions" ][ 0 ];
+  assert_eq!( interaction[ "request" ][ "method" ], "POST" );
+  assert_eq!( interaction[ "request" ][ "url" ], format!( "{base_url}chat/completions" ) );
+  assert_eq!( interaction[ "request" ][ "body" ], request );
+  assert_eq!( interaction[ "response" ][ "status" ], 200 );
+
+  let replayer = client( "sk-ci-placeholder", base_url, Cassette::replay( &path ).expect( "the cassette must load" ) );
+  let replay : ChatCompletionResponse = replayer.post( "chat/completions", &request ).await.expect( "replay must answer" );
+  assert_eq!( replay, live );
+}
+
+/// Keys never reach the file: credential headers are replaced, and the
+/// real API's 401 is recorded without the key it was sent.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn recording_scrubs_api_key()
+{
+  const RECORD_KEY : &str = "sk-record-0123456789abcdef";
+
+  let dir = tempfile::tempdir().expect( "temp dir must be created" );
+  let path = dir.path().join( "rejected.json" );
+
+  let recorder = client( RECORD_KEY, "https://api.openai.com/v1/", Cassette::record( &path ) );
+  let error = recorder.get::< serde_json::Value >( "models" ).await.expect_err( "a fake key must be refused" );
+  assert!( matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ), "{error:?}" );
+
+  let text = std::fs::read_to_string( &path ).expect( "cassette must be written" );
+  assert!( !text.contains( RECORD_KEY ), "{text}" );
+  let file : serde_json::Value = serde_json::from_str( &text ).expect( "cassette must be JSON" );
+  let interaction = &file[ "interactions" ][ 0 ];
+  assert_eq!( interaction[ "request" ][ "headers" ][ "authorization" ], "[REDACTED]" );
+  assert!( interaction[ "request" ].get( "body" ).is_none() );
+  assert_eq!( interaction[ "response" ][ "status" ], 401 );
+}
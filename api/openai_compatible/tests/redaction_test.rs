@@ -0,0 +1,83 @@
+//! Tests that key material never appears in `Debug` output.
+//!
+//! Every type holding a key is formatted with `{:?}` and `{:#?}` and the
+//! output is searched for the full key. Environments are wrapped in a
+//! `Client` too, since clients are what ends up in application logs.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | environment_debug_masks_key | `OpenAiCompatEnvironmentImpl` shows only the key prefix |
+//! | github_models_debug_masks_token | `GitHubModelsEnvironment` hides the token |
+//! | self_hosted_debug_masks_key | `SelfHostedProfile` hides the key |
+//! | client_debug_masks_key | `Client` hides the key of its environment and default headers |
+//! | authorization_header_is_sensitive | `headers()` marks `Authorization` sensitive |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::
+{
+  Client,
+  GitHubModelsEnvironment,
+  OpenAiCompatEnvironment,
+  OpenAiCompatEnvironmentImpl,
+  SelfHostedProfile,
+};
+
+const KEY : &str = "sk-redaction-test-0123456789abcdef";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    assert!( !output.contains( KEY ), "full key in Debug output : {output}" );
+    assert!( !output.contains( &KEY[ 4.. ] ), "key suffix in Debug output : {output}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn environment_debug_masks_key()
+{
+  let env = OpenAiCompatEnvironmentImpl::new( KEY ).expect( "valid key" );
+  assert_hidden( &env );
+  assert!( format!( "{env:?}" ).contains( "sk-r****" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn github_models_debug_masks_token()
+{
+  assert_hidden( &GitHubModelsEnvironment::new( KEY ).expect( "valid token" ).with_github_endpoint() );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn self_hosted_debug_masks_key()
+{
+  let profile = SelfHostedProfile::new( "http://localhost:8000/v1/" ).expect( "valid url" ).with_api_key( KEY );
+  assert_hidden( &profile );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn client_debug_masks_key()
+{
+  let client = Client::build( OpenAiCompatEnvironmentImpl::new( KEY ).expect( "valid key" ) ).expect( "client builds" );
+  assert_hidden( &client );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ test ]
+fn authorization_header_is_sensitive()
+{
+  let headers = OpenAiCompatEnvironmentImpl::new( KEY ).expect( "valid key" ).headers().expect( "headers build" );
+  assert!( headers[ reqwest::header::AUTHORIZATION ].is_sensitive() );
+  assert_hidden( &headers );
+}
@@ -0,0 +1,276 @@
+//! Tests for `RetryConfig` and `ExecuteWithRetries`.
+//!
+//! Unit tests send to a port nothing listens on, so every attempt fails with
+//! a network error, and count attempts with a request hook. Integration
+//! tests call the real API. The wait before each retry, `Retry-After`
+//! included, is covered by the unit tests in `src/retry.rs`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | network_errors_are_retried_with_one_idempotency_key | Retry | A network failure is sent again, up to `max_attempts`, with one idempotency key |
+//! | responses_only_does_not_resend_after_network_error | Classification | `RetryOn::responses()` gives up after a failure whose outcome is unknown |
+//! | budget_stops_retries | Budget | A `CallBudget` on the config caps attempts and ends the call instead of waiting past its deadline |
+//! | shared_budget_spans_calls | Budget | `within` counts every call's attempts against one started budget; a spent budget sends nothing |
+//! | backoff_doubles_and_caps | Backoff | `delay_for_retry` doubles from `base_delay`, stops at `max_delay`, and jitter stays in `[0.5, 1.0)` |
+//! | retry_on_classifies_failures | Classification | Statuses and transport errors map to their `RetryOn` switch |
+//! | cancellation_ends_backoff_wait | Cancellation | A token cancelled during the wait before a retry ends the call without another attempt |
+//! | retrying_call_succeeds_against_real_api | Integration | A retried GET with a real key succeeds on the first attempt |
+//! | authentication_errors_are_not_retried_by_real_api | Integration | The real API's 401 fails after one attempt |
+
+#![ cfg( all( feature = "enabled", feature = "retry" ) ) ]
+
+use api_openai_compatible::{ Client, ExecuteWithRetries, OpenAiCompatEnvironmentImpl, OpenAiCompatError, RetryConfig, RetryOn };
+use core::time::Duration;
+use reqwest::StatusCode;
+use std::sync::{ Arc, Mutex };
+
+/// A client for a port nothing listens on, and the idempotency key (or
+/// `"-"`) of every attempt it sends, recorded by a request hook.
+fn unreachable_client() -> ( Client< OpenAiCompatEnvironmentImpl >, Arc< Mutex< Vec< String > > > )
+{
+  let attempts = Arc::new( Mutex::new( Vec::new() ) );
+  let seen = Arc::clone( &attempts );
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-retry" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/v1/" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_request_hook( move | request |
+    {
+      let key = request.headers.get( "idempotency-key" ).and_then( | v | v.to_str().ok() ).unwrap_or( "-" ).to_owned();
+      seen.lock().unwrap().push( key );
+    } );
+  ( client, attempts )
+}
+
+fn fast() -> RetryConfig
+{
+  RetryConfig::new().with_base_delay( Duration::from_millis( 1 ) ).with_jitter( false )
+}
+
+fn is_network( error : &error_tools::untyped::Error ) -> bool
+{
+  matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Network( _ ) ) )
+}
+
+// ------------------------------------------------------------------ //
+
+/// A connection failure must be sent again until `max_attempts` is used up,
+/// and every attempt of the POST must carry the same idempotency key.
+#[ tokio::test ]
+async fn network_errors_are_retried_with_one_idempotency_key()
+{
+  let ( client, attempts ) = unreachable_client();
+  #[ cfg( feature = "idempotency" ) ]
+  let client = client.with_idempotency_keys();
+
+  let error = client.retrying( &fast().with_max_attempts( 3 ) ).post::< _, serde_json::Value >( "batches", &serde_json::json!( {} ) ).await
+    .expect_err( "every attempt fails" );
+
+  assert!( is_network( &error ), "{error:?}" );
+  let attempts = attempts.lock().unwrap();
+  assert_eq!( attempts.len(), 3 );
+  #[ cfg( feature = "idempotency" ) ]
+  assert!( attempts[ 0 ] != "-" && attempts.iter().all( | key | *key == attempts[ 0 ] ), "{attempts:?}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A request that may have reached the server must not be sent again when
+/// only responses are retried.
+#[ tokio::test ]
+async fn responses_only_does_not_resend_after_network_error()
+{
+  let ( client, attempts ) = unreachable_client();
+
+  let error = client.retrying( &fast().with_retry_on( RetryOn::responses() ) ).get::< serde_json::Value >( "models" ).await
+    .expect_err( "the attempt fails" );
+
+  assert!( is_network( &error ), "{error:?}" );
+  assert_eq!( attempts.lock().unwrap().len(), 1 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A budget on the config must stop retries the config would still make:
+/// at its attempt limit, and before a wait that would pass its deadline.
+#[ tokio::test ]
+async fn budget_stops_retries()
+{
+  use api_common::CallBudget;
+
+  let ( client, attempts ) = unreachable_client();
+  let config = fast().with_max_attempts( 5 ).with_budget( CallBudget::new().with_max_attempts( 2 ) );
+  let error = client.retrying( &config ).get::< serde_json::Value >( "models" ).await.expect_err( "every attempt fails" );
+  assert!( is_network( &error ), "the last attempt's error must be returned; got {error:?}" );
+  assert_eq!( attempts.lock().unwrap().len(), 2 );
+
+  let ( client, attempts ) = unreachable_client();
+  let config = fast()
+    .with_max_attempts( 5 )
+    .with_base_delay( Duration::from_secs( 5 ) )
+    .with_budget( CallBudget::new().with_total_deadline( Duration::from_millis( 500 ) ) );
+  let started = std::time::Instant::now();
+  let error = client.retrying( &config ).get::< serde_json::Value >( "models" ).await.expect_err( "every attempt fails" );
+  assert!( is_network( &error ), "{error:?}" );
+  assert!( started.elapsed() < Duration::from_secs( 2 ), "waited {:?}", started.elapsed() );
+  assert_eq!( attempts.lock().unwrap().len(), 1 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A started budget handed to `within` must count the attempts of every
+/// call made through it; once spent, a call fails before sending.
+#[ tokio::test ]
+async fn shared_budget_spans_calls()
+{
+  use api_common::{ BudgetExhausted, CallBudget };
+
+  let ( client, attempts ) = unreachable_client();
+  let budget = CallBudget::new().with_max_attempts( 3 ).start();
+  let config = fast().with_max_attempts( 5 );
+
+  let error = client.retrying( &config ).within( &budget ).get::< serde_json::Value >( "models" ).await.expect_err( "every attempt fails" );
+  assert!( is_network( &error ), "{error:?}" );
+  let error = client.retrying( &config ).within( &budget ).get::< serde_json::Value >( "models" ).await.expect_err( "the budget is spent" );
+  assert!( matches!( error.downcast_ref::< BudgetExhausted >(), Some( BudgetExhausted::Attempts { max_attempts : 3 } ) ), "{error:?}" );
+
+  assert_eq!( attempts.lock().unwrap().len(), 3 );
+  assert_eq!( budget.attempts(), 3 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Backoff doubles from `base_delay` per retry and stops at `max_delay`;
+/// jitter shortens each delay to between half and all of it.
+#[ test ]
+fn backoff_doubles_and_caps()
+{
+  let config = RetryConfig::new()
+    .with_base_delay( Duration::from_millis( 100 ) )
+    .with_max_delay( Duration::from_millis( 350 ) )
+    .with_jitter( false );
+
+  let delays : Vec< Duration > = ( 1..=4 ).map( | retry | config.delay_for_retry( retry ) ).collect();
+  assert_eq!( delays, [ 100, 200, 350, 350 ].map( Duration::from_millis ) );
+  assert_eq!( config.delay_for_retry( u32::MAX ), Duration::from_millis( 350 ) );
+
+  let jittered = config.with_jitter( true );
+  for _ in 0..100
+  {
+    let delay = jittered.delay_for_retry( 2 );
+    assert!( delay >= Duration::from_millis( 100 ) && delay < Duration::from_millis( 200 ), "{delay:?}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// `RetryOn` must map 429, 5xx, transport failures, and timeouts to their
+/// switches and never retry other failures.
+#[ test ]
+fn retry_on_classifies_failures()
+{
+  let all = RetryOn::transient();
+  assert!( all.status( StatusCode::TOO_MANY_REQUESTS ) );
+  assert!( all.status( StatusCode::SERVICE_UNAVAILABLE ) );
+  assert!( !all.status( StatusCode::BAD_REQUEST ) );
+  assert!( !all.status( StatusCode::UNAUTHORIZED ) );
+  assert!( all.error( &OpenAiCompatError::Network( "refused".into() ) ) );
+  assert!( all.error( &OpenAiCompatError::Timeout( "30 s".into() ) ) );
+  assert!( !all.error( &OpenAiCompatError::Deserialization { message : "eof".into(), body_snippet : String::new() } ) );
+  assert!( !all.error( &OpenAiCompatError::Authentication { status : None, message : "empty".into() } ) );
+
+  let responses = RetryOn::responses();
+  assert!( responses.status( StatusCode::BAD_GATEWAY ) );
+  assert!( !responses.error( &OpenAiCompatError::Network( "refused".into() ) ) );
+  assert!( !responses.error( &OpenAiCompatError::Timeout( "30 s".into() ) ) );
+
+  let no_rate_limits = RetryOn { rate_limited : false, ..RetryOn::transient() };
+  assert!( !no_rate_limits.status( StatusCode::TOO_MANY_REQUESTS ) );
+  assert!( no_rate_limits.status( StatusCode::INTERNAL_SERVER_ERROR ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Cancelling while the call waits before a retry must end it at once,
+/// without the retry being sent.
+#[ cfg( feature = "cancellation" ) ]
+#[ tokio::test ]
+async fn cancellation_ends_backoff_wait()
+{
+  use api_common::{ CancellationToken, Cancelled };
+  use api_openai_compatible::RequestOptions;
+
+  let ( client, attempts ) = unreachable_client();
+  let token = CancellationToken::new();
+  let canceller = token.clone();
+  tokio::spawn( async move
+  {
+    tokio::time::sleep( Duration::from_millis( 100 ) ).await;
+    canceller.cancel();
+  } );
+
+  let started = std::time::Instant::now();
+  let options = RequestOptions::new().with_cancellation( token );
+  let config = fast().with_base_delay( Duration::from_secs( 5 ) );
+  let error = client.retrying( &config ).get_with_options::< serde_json::Value >( "models", &options ).await
+    .expect_err( "the call must be cancelled" );
+
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+  assert!( started.elapsed() < Duration::from_secs( 2 ), "waited {:?}", started.elapsed() );
+  assert_eq!( attempts.lock().unwrap().len(), 1 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A retried call with a real key must succeed on its first attempt.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn retrying_call_succeeds_against_real_api()
+{
+  use core::sync::atomic::{ AtomicUsize, Ordering };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+  let counter = Arc::clone( &attempts );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_request_hook( move | _ | { counter.fetch_add( 1, Ordering::SeqCst ); } );
+
+  let models : serde_json::Value = client.retrying( &RetryConfig::new() ).get( "models" ).await
+    .expect( "GET models with a valid key must succeed" );
+
+  assert!( models.is_object(), "{models}" );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A 401 from the real API is the caller's fault and must not be sent again.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn authentication_errors_are_not_retried_by_real_api()
+{
+  use core::sync::atomic::{ AtomicUsize, Ordering };
+
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+  let counter = Arc::clone( &attempts );
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-fake-integration-test" )
+    .expect( "environment construction must succeed" );
+  let client = Client::build( env ).expect( "Client::build() must succeed" )
+    .with_request_hook( move | _ | { counter.fetch_add( 1, Ordering::SeqCst ); } );
+
+  let error = client.retrying( &fast() ).get::< serde_json::Value >( "models" ).await
+    .expect_err( "a fake key must be refused" );
+
+  assert!(
+    matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ),
+    "a 401 must be an Authentication error; got: {error}",
+  );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+}
@@ -0,0 +1,268 @@
+//! Tests for `SelfHostedProfile`.
+//!
+//! Unit tests feed response bodies in the shapes vLLM, llama.cpp, and
+//! `LiteLLM` return and check that they normalise into the standard wire
+//! types, including under `strict_wire`. The integration test sends a chat
+//! completion to the self-hosted server named by `SELF_HOSTED_BASE_URL` and
+//! `SELF_HOSTED_MODEL` in `secret/-secrets.sh`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | new_adds_trailing_slash_and_sends_no_key | unit | Base URL normalised; no `Authorization` without a key |
+//! | with_api_key_sends_bearer | unit | `Authorization: Bearer` once a key is set |
+//! | new_rejects_relative_url | unit | Base URL without `http(s)` scheme → `Environment` |
+//! | request_body_passes_sampling_params_through | unit | `top_k` and `min_p` appear next to standard fields |
+//! | request_body_rejects_param_shadowing_request_field | unit | Param named like a set field → `InvalidRequest` |
+//! | vllm_response_extra_fields_are_dropped | unit | `stop_reason`, `prompt_logprobs`, `kv_transfer_params` ignored |
+//! | llamacpp_response_without_ids_and_usage_is_completed | unit | Missing `id`, `created`, `usage`, tool call id filled |
+//! | finish_reasons_map_to_openai_values | unit | `eos`, `end_turn`, `max_tokens`, `""` mapped |
+//! | litellm_response_finish_reason_is_mapped | unit | `end_turn` from a proxied backend → `stop` |
+//! | chunk_tool_call_fragment_keeps_empty_id | unit | Streaming fragment without id/name parses with empty id |
+//! | self_hosted_chat_completion_succeeds | integration | A real server answers through the profile |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::
+{
+  ChatCompletionRequest, Message, OpenAiCompatEnvironment, OpenAiCompatError, SelfHostedProfile,
+};
+
+fn request() -> ChatCompletionRequest
+{
+  ChatCompletionRequest::former()
+    .model( "Qwen/Qwen2.5-0.5B-Instruct".to_string() )
+    .messages( vec![ Message::user( "Hi" ) ] )
+    .temperature( 0.2_f32 )
+    .form()
+}
+
+// ------------------------------------------------------------------ //
+
+/// A local server usually runs without a key; none must be sent.
+#[ test ]
+fn new_adds_trailing_slash_and_sends_no_key()
+{
+  let profile = SelfHostedProfile::new( "http://localhost:8000/v1" ).expect( "absolute URL must be accepted" );
+  let headers = profile.headers().expect( "headers() must succeed" );
+
+  assert_eq!( profile.base_url(), "http://localhost:8000/v1/" );
+  assert!( headers.get( "authorization" ).is_none() );
+  assert_eq!( headers.get( "content-type" ).unwrap(), "application/json" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `--api-key` servers and `LiteLLM` virtual keys need the Bearer header.
+#[ test ]
+fn with_api_key_sends_bearer()
+{
+  let profile = SelfHostedProfile::new( "http://localhost:4000/" ).unwrap().with_api_key( "sk-litellm\n" );
+  let headers = profile.headers().unwrap();
+
+  assert_eq!( headers.get( "authorization" ).unwrap(), "Bearer sk-litellm" );
+  let blank = SelfHostedProfile::new( "http://localhost:4000/" ).unwrap().with_api_key( "  " );
+  assert!( blank.headers().unwrap().get( "authorization" ).is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A base URL without an `http` scheme fails at construction, not at the
+/// first call; `localhost:8000` would otherwise parse with scheme `localhost`.
+#[ test ]
+fn new_rejects_relative_url()
+{
+  let err = SelfHostedProfile::new( "localhost:8000/v1/" ).map( drop ).unwrap_err();
+  assert!( matches!( err.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Environment( _ ) ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Sampling parameters outside the `OpenAI` schema reach the body unchanged.
+#[ test ]
+fn request_body_passes_sampling_params_through()
+{
+  let profile = SelfHostedProfile::new( "http://localhost:8000/v1/" ).unwrap()
+    .with_param( "top_k", 40 )
+    .with_param( "min_p", 0.05 );
+  let body = profile.request_body( &request() ).expect( "no parameter collides" );
+
+  assert_eq!( body[ "top_k" ], 40 );
+  assert_eq!( body[ "min_p" ], 0.05 );
+  assert_eq!( body[ "model" ], "Qwen/Qwen2.5-0.5B-Instruct" );
+  assert!( body.get( "temperature" ).is_some() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A parameter that names a field the request sets is an error, so neither
+/// value wins silently.
+#[ test ]
+fn request_body_rejects_param_shadowing_request_field()
+{
+  let profile = SelfHostedProfile::new( "http://localhost:8000/v1/" ).unwrap().with_param( "temperature", 1.0 );
+  let err = profile.request_body( &request() ).unwrap_err();
+
+  match err.downcast_ref::< OpenAiCompatError >()
+  {
+    Some( OpenAiCompatError::InvalidRequest { status : None, message : detail, .. } ) => assert!( detail.contains( "temperature" ), "got: {detail}" ),
+    other => panic!( "expected InvalidRequest, got: {other:?}" ),
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// vLLM adds fields of its own at every level; they are dropped so the
+/// response parses under `strict_wire` too.
+#[ test ]
+fn vllm_response_extra_fields_are_dropped()
+{
+  let raw = serde_json::json!(
+  {
+    "id" : "chatcmpl-8e4c", "object" : "chat.completion", "created" : 1_730_000_000,
+    "model" : "Qwen/Qwen2.5-0.5B-Instruct",
+    "choices" : [ {
+      "index" : 0,
+      "message" : { "role" : "assistant", "content" : "Hello!", "tool_calls" : [], "reasoning_content" : null },
+      "logprobs" : null, "finish_reason" : "stop", "stop_reason" : null
+    } ],
+    "usage" : { "prompt_tokens" : 9, "total_tokens" : 12, "completion_tokens" : 3, "prompt_tokens_details" : null },
+    "prompt_logprobs" : null,
+    "kv_transfer_params" : null
+  });
+  let response = SelfHostedProfile::parse_response( raw ).expect( "vLLM response must parse" );
+
+  assert_eq!( response.id, "chatcmpl-8e4c" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "Hello!" ) );
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+  assert_eq!( response.usage.total_tokens, 12 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Older llama.cpp builds omit `id`, `created`, and `usage`, and return tool
+/// calls without ids; the gaps are filled so a tool result can reference
+/// the call.
+#[ test ]
+fn llamacpp_response_without_ids_and_usage_is_completed()
+{
+  let raw = serde_json::json!(
+  {
+    "model" : "gpt-3.5-turbo",
+    "object" : "chat.completion",
+    "choices" : [ {
+      "finish_reason" : "tool_calls",
+      "message" : {
+        "role" : "assistant", "content" : null,
+        "tool_calls" : [ { "function" : { "name" : "get_weather", "arguments" : "{\"city\":\"Paris\"}" } } ]
+      }
+    } ],
+    "timings" : { "prompt_n" : 12, "predicted_n" : 18 }
+  });
+  let response = SelfHostedProfile::parse_response( raw ).expect( "llama.cpp response must parse" );
+
+  assert_eq!( response.id, "" );
+  assert_eq!( response.created, 0 );
+  assert_eq!( response.usage.total_tokens, 0 );
+  assert_eq!( response.choices[ 0 ].index, 0 );
+  let call = &response.choices[ 0 ].message.tool_calls.as_ref().unwrap()[ 0 ];
+  assert_eq!( call.id, "call_0" );
+  assert_eq!( call.tool_type, "function" );
+  assert_eq!( call.function.name, "get_weather" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Engine finish reasons are mapped; unknown ones are kept.
+#[ test ]
+fn finish_reasons_map_to_openai_values()
+{
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "eos" ).as_deref(), Some( "stop" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "eos_token" ).as_deref(), Some( "stop" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "max_tokens" ).as_deref(), Some( "length" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "tool_use" ).as_deref(), Some( "tool_calls" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "abort" ).as_deref(), Some( "abort" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "stop" ).as_deref(), Some( "stop" ) );
+  assert_eq!( SelfHostedProfile::normalize_finish_reason( "" ), None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `LiteLLM` forwards the backend's finish reason in some versions; a
+/// proxied Anthropic `end_turn` reads as `stop`.
+#[ test ]
+fn litellm_response_finish_reason_is_mapped()
+{
+  let raw = serde_json::json!(
+  {
+    "id" : "chatcmpl-5b1f", "created" : 1_730_000_100, "model" : "claude-3-haiku", "object" : "chat.completion",
+    "system_fingerprint" : null,
+    "choices" : [ { "finish_reason" : "end_turn", "index" : 0, "message" : { "content" : "Hi", "role" : "assistant" } } ],
+    "usage" : { "completion_tokens" : 2, "prompt_tokens" : 8 }
+  });
+  let response = SelfHostedProfile::parse_response( raw ).expect( "LiteLLM response must parse" );
+
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "stop" ) );
+  assert_eq!( response.usage.total_tokens, 10, "missing total is computed" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A streamed tool call continuation carries only `index` and arguments;
+/// it parses with an empty id and name, which stream events drop.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn chunk_tool_call_fragment_keeps_empty_id()
+{
+  let raw = serde_json::json!(
+  {
+    "choices" : [ {
+      "index" : 0,
+      "delta" : { "tool_calls" : [ { "index" : 0, "function" : { "arguments" : "\"Paris\"}" } } ] },
+      "finish_reason" : ""
+    } ],
+    "created" : 1_730_000_200, "model" : "local", "object" : "chat.completion.chunk", "id" : "chatcmpl-1",
+    "usage" : null
+  });
+  let chunk = SelfHostedProfile::parse_chunk( raw ).expect( "chunk must parse" );
+
+  let call = &chunk.choices[ 0 ].delta.tool_calls.as_ref().unwrap()[ 0 ];
+  assert_eq!( call.id, "" );
+  assert_eq!( call.function.name, "" );
+  assert_eq!( call.function.arguments, "\"Paris\"}" );
+  assert_eq!( chunk.choices[ 0 ].finish_reason, None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The server at `SELF_HOSTED_BASE_URL` must answer a chat completion for
+/// `SELF_HOSTED_MODEL` through the profile. `SELF_HOSTED_API_KEY` is sent
+/// when set.
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn self_hosted_chat_completion_succeeds()
+{
+  use api_openai_compatible::Client;
+
+  let ws = workspace_tools::workspace().expect( "workspace root must be resolvable" );
+  let base_url = ws.load_secret_key( "SELF_HOSTED_BASE_URL", "-secrets.sh" )
+    .expect( "SELF_HOSTED_BASE_URL must be set in secret/-secrets.sh" );
+  let model = ws.load_secret_key( "SELF_HOSTED_MODEL", "-secrets.sh" )
+    .expect( "SELF_HOSTED_MODEL must be set in secret/-secrets.sh" );
+  let api_key = ws.load_secret_key( "SELF_HOSTED_API_KEY", "-secrets.sh" ).unwrap_or_default();
+
+  let profile = SelfHostedProfile::new( base_url ).unwrap().with_api_key( api_key ).with_param( "top_k", 20 );
+  let client = Client::build( profile.clone() ).expect( "Client::build() must succeed" );
+  let request = ChatCompletionRequest::former()
+    .model( model )
+    .messages( vec![ Message::user( "Reply with the word ok." ) ] )
+    .max_tokens( 8_u32 )
+    .form();
+
+  let raw : serde_json::Value = client.post( "chat/completions", &profile.request_body( &request ).unwrap() ).await
+    .expect( "self-hosted chat completion must succeed" );
+  let response = SelfHostedProfile::parse_response( raw ).expect( "response must normalise" );
+
+  assert!( !response.choices.is_empty(), "response must contain a choice" );
+}
@@ -0,0 +1,200 @@
+//! Tests for `StreamAccumulator`, which folds streamed chunks into a response.
+//!
+//! Each test feeds chunks shaped like recorded `OpenAI` streams and checks
+//! the assembled `ChatCompletionResponse`. No HTTP is made.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | text_deltas_concatenate_into_message | Content | Role from the first chunk, content joined, header and finish reason kept |
+//! | interleaved_tool_call_fragments_join_per_index | Tool calls | Fragments of two parallel calls interleave and still join by `index` |
+//! | fragments_without_index_use_position | Tool calls | A server that omits `index` gets one call per position |
+//! | usage_chunk_without_choices_sets_usage | Usage | The trailing `include_usage` chunk fills `usage` |
+//! | choices_are_kept_apart | Choices | `n > 1` choices accumulate separately, ordered by index |
+//! | empty_stream_yields_empty_response | Edge | No chunks → no choices and zero usage |
+
+#![ cfg( all( feature = "enabled", feature = "streaming" ) ) ]
+
+use api_openai_compatible::{ ChatCompletionChunk, Role, StreamAccumulator, Usage };
+use serde_json::json;
+
+/// A chunk of completion `chatcmpl-acc` with the given choices.
+fn chunk( choices : &serde_json::Value ) -> ChatCompletionChunk
+{
+  serde_json::from_value( json!
+  ({
+    "id" : "chatcmpl-acc",
+    "object" : "chat.completion.chunk",
+    "created" : 1_722_432_140,
+    "model" : "gpt-4o-mini",
+    "choices" : choices,
+  }) ).expect( "chunk must deserialise" )
+}
+
+/// A chunk whose only choice carries `delta` and `finish_reason`.
+fn delta( delta : &serde_json::Value, finish_reason : Option< &str > ) -> ChatCompletionChunk
+{
+  chunk( &json!( [ { "index" : 0, "delta" : delta, "finish_reason" : finish_reason } ] ) )
+}
+
+fn accumulate( chunks : &[ ChatCompletionChunk ] ) -> StreamAccumulator
+{
+  let mut accumulator = StreamAccumulator::new();
+  for chunk in chunks
+  {
+    accumulator.push( chunk );
+  }
+  accumulator
+}
+
+// ------------------------------------------------------------------ //
+
+/// Text deltas must join in order under the role of the first chunk; the
+/// response keeps the stream's id, model, and final finish reason.
+#[ test ]
+fn text_deltas_concatenate_into_message()
+{
+  let accumulator = accumulate( &
+  [
+    delta( &json!( { "role" : "assistant", "content" : "" } ), None ),
+    delta( &json!( { "content" : "Hello" } ), None ),
+    delta( &json!( { "content" : ", world" } ), None ),
+    delta( &json!( {} ), Some( "stop" ) ),
+  ]);
+  assert_eq!( accumulator.content(), Some( "Hello, world" ) );
+
+  let response = accumulator.finish();
+
+  assert_eq!( response.id, "chatcmpl-acc" );
+  assert_eq!( response.object, "chat.completion" );
+  assert_eq!( response.model, "gpt-4o-mini" );
+  assert_eq!( response.choices.len(), 1 );
+  let choice = &response.choices[ 0 ];
+  assert_eq!( choice.message.role, Role::Assistant );
+  assert_eq!( choice.message.content.as_deref(), Some( "Hello, world" ) );
+  assert_eq!( choice.message.tool_calls, None );
+  assert_eq!( choice.finish_reason.as_deref(), Some( "stop" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Two parallel calls whose argument fragments alternate must each end up
+/// with their own id, name, and complete arguments, ordered by index.
+#[ test ]
+fn interleaved_tool_call_fragments_join_per_index()
+{
+  let fragment = | index : u32, arguments : &str | json!( { "tool_calls" : [ { "index" : index, "function" : { "arguments" : arguments } } ] } );
+  let accumulator = accumulate( &
+  [
+    delta( &json!( { "role" : "assistant", "content" : null, "tool_calls" :
+    [
+      { "index" : 0, "id" : "call_paris", "type" : "function", "function" : { "name" : "get_weather", "arguments" : "" } },
+    ] } ), None ),
+    delta( &json!( { "tool_calls" :
+    [
+      { "index" : 1, "id" : "call_rome", "type" : "function", "function" : { "name" : "get_weather", "arguments" : "" } },
+    ] } ), None ),
+    delta( &fragment( 0, "{\"city\":" ), None ),
+    delta( &fragment( 1, "{\"city\":" ), None ),
+    delta( &fragment( 1, "\"Rome\"}" ), None ),
+    delta( &fragment( 0, "\"Paris\"}" ), None ),
+    delta( &json!( {} ), Some( "tool_calls" ) ),
+  ]);
+
+  let response = accumulator.finish();
+
+  let message = &response.choices[ 0 ].message;
+  assert_eq!( message.content, None );
+  let calls = message.tool_calls.as_ref().expect( "both calls must be assembled" );
+  assert_eq!( calls.len(), 2 );
+  assert_eq!( calls[ 0 ].id, "call_paris" );
+  assert_eq!( calls[ 0 ].tool_type, "function" );
+  assert_eq!( calls[ 0 ].function.name, "get_weather" );
+  assert_eq!( calls[ 0 ].function.arguments, r#"{"city":"Paris"}"# );
+  assert_eq!( calls[ 1 ].id, "call_rome" );
+  assert_eq!( calls[ 1 ].function.arguments, r#"{"city":"Rome"}"# );
+  assert_eq!( response.choices[ 0 ].finish_reason.as_deref(), Some( "tool_calls" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Without `index`, each call of a delta is identified by its position, so
+/// complete calls sent in one delta stay apart.
+#[ test ]
+fn fragments_without_index_use_position()
+{
+  let accumulator = accumulate( &
+  [
+    delta( &json!( { "tool_calls" :
+    [
+      { "id" : "a", "type" : "function", "function" : { "name" : "f", "arguments" : "{}" } },
+      { "id" : "b", "type" : "function", "function" : { "name" : "g", "arguments" : "{}" } },
+    ] } ), Some( "tool_calls" ) ),
+  ]);
+
+  let response = accumulator.finish();
+
+  let calls = response.choices[ 0 ].message.tool_calls.as_ref().expect( "calls must be assembled" );
+  let names : Vec< &str > = calls.iter().map( | call | call.function.name.as_str() ).collect();
+  assert_eq!( names, [ "f", "g" ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// With `stream_options.include_usage`, the last chunk has no choices and
+/// carries the usage of the whole response.
+#[ test ]
+fn usage_chunk_without_choices_sets_usage()
+{
+  let mut usage_chunk = chunk( &json!( [] ) );
+  usage_chunk.usage = Some( Usage { prompt_tokens : 82, completion_tokens : 36, total_tokens : 118, ..Default::default() } );
+  let accumulator = accumulate( &[ delta( &json!( { "content" : "Hi" } ), Some( "stop" ) ), usage_chunk ] );
+
+  assert_eq!( accumulator.usage().map( | usage | usage.total_tokens ), Some( 118 ) );
+  let response = accumulator.finish();
+
+  assert_eq!( response.usage, Usage { prompt_tokens : 82, completion_tokens : 36, total_tokens : 118, ..Default::default() } );
+  assert_eq!( response.choices.len(), 1, "the usage chunk must not add a choice" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Each choice of an `n > 1` stream accumulates on its own.
+#[ test ]
+fn choices_are_kept_apart()
+{
+  let accumulator = accumulate( &
+  [
+    chunk( &json!( [ { "index" : 1, "delta" : { "content" : "B" }, "finish_reason" : null } ] ) ),
+    chunk( &json!( [ { "index" : 0, "delta" : { "content" : "A" }, "finish_reason" : null } ] ) ),
+    chunk( &json!(
+    [
+      { "index" : 0, "delta" : { "content" : "a" }, "finish_reason" : "stop" },
+      { "index" : 1, "delta" : { "content" : "b" }, "finish_reason" : "length" },
+    ] ) ),
+  ]);
+
+  let response = accumulator.finish();
+
+  let texts : Vec< ( u32, Option< &str >, Option< &str > ) > = response.choices.iter()
+    .map( | choice | ( choice.index, choice.message.content.as_deref(), choice.finish_reason.as_deref() ) )
+    .collect();
+  assert_eq!( texts, [ ( 0, Some( "Aa" ), Some( "stop" ) ), ( 1, Some( "Bb" ), Some( "length" ) ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// An accumulator that saw nothing finishes into an empty response.
+#[ test ]
+fn empty_stream_yields_empty_response()
+{
+  let accumulator = StreamAccumulator::new();
+  assert_eq!( accumulator.content(), None );
+  assert_eq!( accumulator.usage(), None );
+
+  let response = accumulator.finish();
+
+  assert!( response.choices.is_empty() );
+  assert_eq!( response.usage.total_tokens, 0 );
+}
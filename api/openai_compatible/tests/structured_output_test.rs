@@ -0,0 +1,170 @@
+//! Tests for structured output: parsing message content requested with a
+//! `ResponseFormat` and checking it against the schema.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | matching_content_parses_into_type | Parse | Content of a response matching the schema deserialises into `T` |
+//! | violations_carry_paths | Schema | Missing field, wrong type, and enum miss each reported at their JSON Pointer |
+//! | refusal_and_missing_content_are_distinct | Content | `refusal` gives `Refusal`; tool-call message gives `MissingContent` |
+//! | json_object_mode_checks_json_only | Parse | `JsonObject` and `Text` need JSON fitting `T`; non-JSON gives `InvalidJson` |
+
+#![ cfg( feature = "schema" ) ]
+
+use api_common::SchemaError;
+use api_openai_compatible::{ ChatCompletionResponse, Message, ResponseFormat, StructuredOutputError, ToolCall };
+
+#[ derive( Debug, PartialEq, serde::Deserialize ) ]
+struct Forecast
+{
+  city : String,
+  days : Vec< Day >,
+}
+
+#[ derive( Debug, PartialEq, serde::Deserialize ) ]
+struct Day
+{
+  sky : String,
+  high : f64,
+}
+
+/// A strict `json_schema` format for a `Forecast`.
+fn forecast_format() -> ResponseFormat
+{
+  ResponseFormat::JsonSchema
+  {
+    name : "forecast".to_string(),
+    schema : serde_json::json!
+    ({
+      "type" : "object",
+      "properties" :
+      {
+        "city" : { "type" : "string" },
+        "days" : { "type" : "array", "items" : { "$ref" : "#/$defs/day" } },
+      },
+      "required" : [ "city", "days" ],
+      "additionalProperties" : false,
+      "$defs" :
+      {
+        "day" :
+        {
+          "type" : "object",
+          "properties" :
+          {
+            "sky" : { "type" : "string", "enum" : [ "clear", "cloudy", "rain" ] },
+            "high" : { "type" : "number" },
+          },
+          "required" : [ "sky", "high" ],
+          "additionalProperties" : false,
+        },
+      },
+    }),
+    strict : Some( true ),
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// The content of a response shaped as `OpenAI` returns structured output
+/// parses into the target type.
+#[ test ]
+fn matching_content_parses_into_type()
+{
+  let response : ChatCompletionResponse = serde_json::from_value( serde_json::json!
+  ({
+    "id" : "chatcmpl-so",
+    "object" : "chat.completion",
+    "created" : 1_722_432_140,
+    "model" : "gpt-4o-mini",
+    "choices" : [ {
+      "index" : 0,
+      "message" :
+      {
+        "role" : "assistant",
+        "content" : r#"{"city":"Paris","days":[{"sky":"clear","high":24.5}]}"#,
+      },
+      "finish_reason" : "stop",
+    } ],
+    "usage" : { "prompt_tokens" : 40, "completion_tokens" : 18, "total_tokens" : 58 },
+  }) ).expect( "response must deserialise" );
+
+  let forecast : Forecast = response.choices[ 0 ].message.parse_structured( &forecast_format() )
+    .expect( "matching content must parse" );
+
+  assert_eq!( forecast, Forecast { city : "Paris".to_string(), days : vec![ Day { sky : "clear".to_string(), high : 24.5 } ] } );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Every mismatch is reported with the path of the offending value, not
+/// only the first one.
+#[ test ]
+fn violations_carry_paths()
+{
+  let message = Message::assistant( r#"{"days":[{"sky":"snow","high":"warm"}],"note":"x"}"# );
+
+  let error = message.parse_structured::< Forecast >( &forecast_format() ).expect_err( "content must be rejected" );
+
+  let StructuredOutputError::Schema( SchemaError::Violations( violations ) ) = &error else
+  {
+    panic!( "expected violations, got {error:?}" );
+  };
+  let found : Vec< ( &str, &str ) > = violations.iter().map( | v | ( v.path.as_str(), v.keyword.as_str() ) ).collect();
+  assert_eq!
+  (
+    found,
+    [
+      ( "", "required" ),
+      ( "/days/0/high", "type" ),
+      ( "/days/0/sky", "enum" ),
+      ( "/note", "additionalProperties" ),
+    ],
+  );
+  assert!( error.to_string().contains( "city" ), "{error}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A refusal is reported with its reason, and a message without content,
+/// such as a tool call, is reported as such rather than as bad JSON.
+#[ test ]
+fn refusal_and_missing_content_are_distinct()
+{
+  let mut refused = Message::assistant_tool_calls( vec![] );
+  refused.tool_calls = None;
+  refused.extra.insert( "refusal".to_string(), "I can't help with that.".into() );
+
+  assert_eq!
+  (
+    refused.parse_structured::< Forecast >( &forecast_format() ),
+    Err( StructuredOutputError::Refusal( "I can't help with that.".to_string() ) ),
+  );
+
+  let calls = Message::assistant_tool_calls( vec![ ToolCall::function( "call_1", "forecast", "{}" ) ] );
+  assert_eq!( calls.parse_structured::< Forecast >( &forecast_format() ), Err( StructuredOutputError::MissingContent ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Without a schema only JSON that fits `T` is required; content that is
+/// not JSON fails as `InvalidJson` under every format.
+#[ test ]
+fn json_object_mode_checks_json_only()
+{
+  let message = Message::assistant( r#"{"sky":"hail","high":3}"# );
+
+  for format in [ ResponseFormat::JsonObject, ResponseFormat::Text ]
+  {
+    let day : Day = message.parse_structured( &format ).expect( "JSON fitting the type must parse" );
+    assert_eq!( day.sky, "hail" );
+    let wrong = message.parse_structured::< Forecast >( &format ).expect_err( "JSON not fitting the type" );
+    assert!( matches!( wrong, StructuredOutputError::Schema( SchemaError::Deserialize( _ ) ) ), "{wrong:?}" );
+  }
+
+  for format in [ ResponseFormat::JsonObject, forecast_format() ]
+  {
+    let prose = Message::assistant( "Sunny, 24 degrees." ).parse_structured::< Day >( &format ).expect_err( "prose is not JSON" );
+    assert!( matches!( prose, StructuredOutputError::Schema( SchemaError::InvalidJson( _ ) ) ), "{prose:?}" );
+  }
+}
@@ -1,7 +1,10 @@
 //! Tests for `SyncClient` construction and blocking semantics.
 //!
 //! Unit tests verify that `SyncClient::new()` succeeds when a tokio runtime
-//! can be created — the only hard requirement before any request is made.
+//! can be created — the only hard requirement before any request is made —
+//! and that calls which cannot reach a server fail. Integration tests stream
+//! from the real API; chunk decoding itself is covered by
+//! `chunk_decoder_test.rs`.
 //!
 //! # Test Matrix
 //!
@@ -10,7 +13,12 @@
 //! | `sync_client_new_succeeds` | Runtime creation with valid env | ✅ |
 //! | `sync_client_new_with_custom_base_url_succeeds` | Custom base URL accepted | ✅ |
 //! | `sync_client_post_unreachable_url_returns_error` | Unreachable URL propagates network error | ✅ |
+//! | `sync_stream_unreachable_url_returns_error` | Unreachable URL fails `post_stream` instead of yielding an empty stream | ✅ |
+//! | `sync_stream_cancelled_beforehand_does_not_open` | A token cancelled before the call fails `post_stream_with_options` with `Cancelled` | ✅ |
 //! | `sync_client_post_chat_completions_succeeds` | Blocking POST round-trip with real API | ✅ |
+//! | `sync_stream_yields_real_chunks` | The real API's chunks are yielded one by one and iteration ends at `[DONE]` | ✅ |
+//! | `sync_stream_error_status_returns_api_error` | The real API's 401 fails `post_stream` as an `Authentication` error | ✅ |
+//! | `sync_stream_cancellation_ends_iteration` | A token cancelled mid-stream yields `Cancelled`, then `None` | ✅ |
 
 #![ cfg( feature = "enabled" ) ]
 
@@ -95,6 +103,49 @@ fn sync_client_post_unreachable_url_returns_error()
   );
 }
 
+/// `post_stream()` with a base URL pointing to nothing must return `Err`
+/// instead of a stream that yields nothing.
+#[ cfg( all( feature = "sync_api", feature = "streaming" ) ) ]
+#[ test ]
+fn sync_stream_unreachable_url_returns_error()
+{
+  use api_openai_compatible::{ Client, SyncClient, OpenAiCompatEnvironmentImpl };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-sync-stream" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let sync = SyncClient::new( Client::build( env ).expect( "Client::build() must succeed" ) )
+    .expect( "SyncClient::new() must succeed" );
+
+  let result = sync.post_stream( "chat/completions", &serde_json::json!( { "stream" : true } ) );
+
+  assert!( result.is_err(), "post_stream() to an unreachable URL must return Err" );
+}
+
+/// A token cancelled before `post_stream_with_options` must stop the stream
+/// from opening, with `Cancelled`.
+#[ cfg( all( feature = "sync_api", feature = "streaming", feature = "cancellation" ) ) ]
+#[ test ]
+fn sync_stream_cancelled_beforehand_does_not_open()
+{
+  use api_common::{ CancellationToken, Cancelled };
+  use api_openai_compatible::{ Client, SyncClient, OpenAiCompatEnvironmentImpl, RequestOptions };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-sync-stream" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "http://127.0.0.1:1/" );
+  let sync = SyncClient::new( Client::build( env ).expect( "Client::build() must succeed" ) )
+    .expect( "SyncClient::new() must succeed" );
+  let token = CancellationToken::new();
+  token.cancel();
+  let options = RequestOptions::new().with_cancellation( token );
+
+  let error = sync.post_stream_with_options( "chat/completions", &serde_json::json!( { "stream" : true } ), &options )
+    .expect_err( "the call must be cancelled" );
+
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+}
+
 // ------------------------------------------------------------------ //
 //  Integration tests
 // ------------------------------------------------------------------ //
@@ -150,3 +201,91 @@ fn sync_client_post_chat_completions_succeeds()
     "response must contain at least one choice",
   );
 }
+
+/// A sync client on the real API with the key from `secret/-secrets.sh`.
+#[ cfg( all( feature = "sync_api", feature = "streaming", feature = "integration" ) ) ]
+fn real_sync_client() -> api_openai_compatible::SyncClient< api_openai_compatible::OpenAiCompatEnvironmentImpl >
+{
+  use api_openai_compatible::{ Client, SyncClient, OpenAiCompatEnvironmentImpl };
+
+  let ws = workspace_tools::workspace()
+    .expect( "workspace root must be resolvable" );
+  let api_key = ws.load_secret_key( "OPENAI_API_KEY", "-secrets.sh" )
+    .expect( "OPENAI_API_KEY must be set in secret/-secrets.sh" );
+  let env = OpenAiCompatEnvironmentImpl::new( &api_key )
+    .expect( "environment construction must succeed" );
+  SyncClient::new( Client::build( env ).expect( "Client::build() must succeed" ) )
+    .expect( "SyncClient::new() must succeed" )
+}
+
+/// `SyncChatStream` must yield the real API's chunks one by one and end at
+/// `[DONE]`, the content adding up to the answer.
+#[ cfg( all( feature = "sync_api", feature = "streaming", feature = "integration" ) ) ]
+#[ test ]
+fn sync_stream_yields_real_chunks()
+{
+  let request = serde_json::json!
+  ({
+    "model" : "gpt-4o-mini",
+    "messages" : [ { "role" : "user", "content" : "Count from one to five in words." } ],
+    "max_tokens" : 30,
+    "stream" : true,
+  });
+
+  let stream = real_sync_client().post_stream( "chat/completions", &request ).expect( "the stream must open" );
+  let chunks : Vec< _ > = stream.map( | chunk | chunk.expect( "every chunk must decode" ) ).collect();
+
+  assert!( chunks.len() > 1, "a streamed answer must arrive in several chunks; got {}", chunks.len() );
+  let text : String = chunks.iter().filter_map( | chunk | chunk.choices.first()?.delta.content.clone() ).collect();
+  assert!( !text.is_empty(), "the chunks must carry the answer" );
+}
+
+/// A 401 from the real API must fail `post_stream` itself as an
+/// `Authentication` error instead of yielding an empty stream.
+#[ cfg( all( feature = "sync_api", feature = "streaming", feature = "integration" ) ) ]
+#[ test ]
+fn sync_stream_error_status_returns_api_error()
+{
+  use api_openai_compatible::{ Client, SyncClient, OpenAiCompatEnvironmentImpl, OpenAiCompatError };
+
+  let env = OpenAiCompatEnvironmentImpl::new( "sk-fake-integration-test" )
+    .expect( "environment construction must succeed" );
+  let sync = SyncClient::new( Client::build( env ).expect( "Client::build() must succeed" ) )
+    .expect( "SyncClient::new() must succeed" );
+  let request = serde_json::json!( { "model" : "gpt-4o-mini", "messages" : [ { "role" : "user", "content" : "Hi" } ], "stream" : true } );
+
+  let error = sync.post_stream( "chat/completions", &request ).expect_err( "a fake key must be refused" );
+
+  assert!(
+    matches!( error.downcast_ref::< OpenAiCompatError >(), Some( OpenAiCompatError::Authentication { status : Some( 401 ), .. } ) ),
+    "a 401 must be an Authentication error; got: {error:?}",
+  );
+}
+
+/// Cancelling the token while a long real answer is streaming must end
+/// iteration with `Cancelled` before `[DONE]`; the stream is over afterwards.
+#[ cfg( all( feature = "sync_api", feature = "streaming", feature = "cancellation", feature = "integration" ) ) ]
+#[ test ]
+fn sync_stream_cancellation_ends_iteration()
+{
+  use api_common::{ CancellationToken, Cancelled };
+  use api_openai_compatible::RequestOptions;
+
+  let token = CancellationToken::new();
+  let options = RequestOptions::new().with_cancellation( token.clone() );
+  let request = serde_json::json!
+  ({
+    "model" : "gpt-4o-mini",
+    "messages" : [ { "role" : "user", "content" : "Write a 1000-word essay about rivers." } ],
+    "stream" : true,
+  });
+
+  let mut stream = real_sync_client().post_stream_with_options( "chat/completions", &request, &options )
+    .expect( "the stream must open" );
+  stream.next().expect( "a first chunk must arrive" ).expect( "the chunk must decode" );
+  token.cancel();
+  let error = stream.by_ref().find_map( Result::err ).expect( "the stream must end with an error before [DONE]" );
+
+  assert!( error.downcast_ref::< Cancelled >().is_some(), "{error:?}" );
+  assert!( stream.next().is_none() );
+}
@@ -0,0 +1,109 @@
+//! Tests for `TlsConfig`: custom roots, client identity, and the opt-in to
+//! skip certificate checks, applied when the client is built.
+//!
+//! The certificates under `tests/fixtures/tls/` are a test CA and a client
+//! certificate it signed, valid for a century.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | valid_settings_build_client | build | Root CA, client identity, and the danger opt-in all build, on both environments |
+//! | malformed_pem_fails_build | build | A root without a certificate or an identity without a key fails `Client::build` with `Environment` |
+//! | debug_hides_key_material | redaction | `Debug` shows counts and flags, never PEM contents |
+
+#![ cfg( all( feature = "tls", any( feature = "rustls", feature = "native_tls" ) ) ) ]
+
+use api_openai_compatible::{ Client, OpenAiCompatEnvironment, OpenAiCompatEnvironmentImpl, OpenAiCompatError, SelfHostedProfile, TlsConfig };
+
+const CA : &[ u8 ] = include_bytes!( "fixtures/tls/ca.pem" );
+const CLIENT_CERT : &[ u8 ] = include_bytes!( "fixtures/tls/client.pem" );
+const CLIENT_KEY : &[ u8 ] = include_bytes!( "fixtures/tls/client_key.pem" );
+
+fn environment( tls : TlsConfig ) -> OpenAiCompatEnvironmentImpl
+{
+  OpenAiCompatEnvironmentImpl::new( "sk-tls" )
+    .expect( "environment construction must succeed" )
+    .with_base_url( "https://gateway.internal:8443/v1/" )
+    .with_tls( tls )
+}
+
+fn build_error( tls : TlsConfig ) -> String
+{
+  let error = Client::build( environment( tls ) ).expect_err( "malformed PEM must fail the build" );
+  match error.downcast_ref::< OpenAiCompatError >()
+  {
+    Some( OpenAiCompatError::Environment( message ) ) => message.clone(),
+    other => panic!( "expected an Environment error, got {other:?}" ),
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Each setting, alone and together, yields a client, and the environment
+/// hands the settings to it.
+#[ test ]
+fn valid_settings_build_client()
+{
+  let full = TlsConfig::new()
+    .with_root_certificate_pem( CA )
+    .with_client_identity_pem( CLIENT_CERT, CLIENT_KEY );
+
+  for tls in
+  [
+    TlsConfig::new(),
+    TlsConfig::new().with_root_certificate_pem( CA ),
+    TlsConfig::new().with_client_identity_pem( CLIENT_CERT, CLIENT_KEY ),
+    TlsConfig::new().danger_accept_invalid_certs(),
+    full.clone(),
+  ]
+  {
+    let env = environment( tls.clone() );
+    assert_eq!( env.tls(), Some( &tls ) );
+    assert!( Client::build( env ).is_ok(), "{tls:?}" );
+  }
+
+  let profile = SelfHostedProfile::new( "https://vllm.internal:8443/v1" ).expect( "profile must build" ).with_tls( full );
+  assert!( Client::build( profile ).is_ok() );
+
+  assert!( !TlsConfig::new().accepts_invalid_certs() );
+  assert!( TlsConfig::new().danger_accept_invalid_certs().accepts_invalid_certs() );
+  let plain = OpenAiCompatEnvironmentImpl::new( "sk-tls" ).expect( "environment construction must succeed" );
+  assert_eq!( plain.tls(), None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// PEM is parsed when the client is built, and each kind of mistake names
+/// the setting at fault.
+#[ test ]
+fn malformed_pem_fails_build()
+{
+  let message = build_error( TlsConfig::new().with_root_certificate_pem( "not a certificate" ) );
+  assert!( message.contains( "root certificate" ), "{message}" );
+
+  let message = build_error( TlsConfig::new().with_root_certificate_pem( CLIENT_KEY ) );
+  assert!( message.contains( "root certificate" ), "{message}" );
+
+  let message = build_error( TlsConfig::new().with_client_identity_pem( CLIENT_CERT, "no key here" ) );
+  assert!( message.contains( "client identity" ), "{message}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Environments are logged; the client key must never reach a log line.
+#[ test ]
+fn debug_hides_key_material()
+{
+  let tls = TlsConfig::new()
+    .with_root_certificate_pem( CA )
+    .with_client_identity_pem( CLIENT_CERT, CLIENT_KEY )
+    .danger_accept_invalid_certs();
+
+  let shown = format!( "{:?}", environment( tls ) );
+
+  assert!( shown.contains( "root_certificates: 1" ), "{shown}" );
+  assert!( shown.contains( "client_identity: true" ), "{shown}" );
+  assert!( shown.contains( "accept_invalid_certs: true" ), "{shown}" );
+  assert!( !shown.contains( "PRIVATE KEY" ) && !shown.contains( "BEGIN" ), "{shown}" );
+}
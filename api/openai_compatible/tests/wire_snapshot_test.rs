@@ -0,0 +1,138 @@
+//! Snapshot tests over the recorded response corpus in `tests/fixtures/wire/`.
+//!
+//! Every fixture is a response body as a provider sent it. Each one must
+//! deserialise and serialise back to the same JSON (nulls aside) without
+//! landing anything in an `extra` map, so a field the wire types do not
+//! model — a new provider field, a renamed one — fails here instead of
+//! going unnoticed in production. With `strict_wire`
+//! the same drift is also a deserialisation error.
+//!
+//! To extend the corpus, save a response body as
+//! `chat_completion_<case>.json` (or `chat_completion_chunk_<case>.json` for
+//! one SSE `data:` payload).
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | recorded_responses_round_trip | Snapshot | every `chat_completion_*` fixture survives a serde round-trip with nothing left in `extra` |
+//! | recorded_chunks_round_trip | Snapshot | every `chat_completion_chunk_*` fixture survives a serde round-trip |
+//! | strict_wire_rejects_unknown_field | Strict | an unmodelled field is an error under `strict_wire` |
+//! | strict_wire_rejects_unknown_role | Strict | an unknown role is an error under `strict_wire` |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_openai_compatible::ChatCompletionResponse;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Recorded fixtures, sorted by name, whose file stem passes `select`.
+fn corpus( select : impl Fn( &str ) -> bool ) -> Vec< ( String, Value ) >
+{
+  let dir = PathBuf::from( env!( "CARGO_MANIFEST_DIR" ) ).join( "tests/fixtures/wire" );
+  let mut fixtures = Vec::new();
+  for entry in std::fs::read_dir( &dir ).expect( "fixture directory must exist" )
+  {
+    let path = entry.expect( "fixture entry must be readable" ).path();
+    let Some( name ) = path.file_stem().and_then( | stem | stem.to_str() ) else { continue };
+    if path.extension().and_then( | ext | ext.to_str() ) != Some( "json" ) || !select( name )
+    {
+      continue;
+    }
+    let text = std::fs::read_to_string( &path ).expect( "fixture must be readable" );
+    let value = serde_json::from_str( &text ).unwrap_or_else( | e | panic!( "{name} is not valid JSON : {e}" ) );
+    fixtures.push( ( name.to_string(), value ) );
+  }
+  fixtures.sort_by( | a, b | a.0.cmp( &b.0 ) );
+  assert!( !fixtures.is_empty(), "no matching fixtures in {}", dir.display() );
+  fixtures
+}
+
+fn is_chunk( name : &str ) -> bool
+{
+  name.starts_with( "chat_completion_chunk_" )
+}
+
+fn is_response( name : &str ) -> bool
+{
+  name.starts_with( "chat_completion_" ) && !is_chunk( name )
+}
+
+/// Drops `null` object members: the wire types omit `None` fields that
+/// providers send as explicit nulls, which is not drift.
+fn without_nulls( value : Value ) -> Value
+{
+  match value
+  {
+    Value::Object( map ) => Value::Object
+    (
+      map.into_iter().filter( | ( _, v ) | !v.is_null() ).map( | ( k, v ) | ( k, without_nulls( v ) ) ).collect()
+    ),
+    Value::Array( items ) => Value::Array( items.into_iter().map( without_nulls ).collect() ),
+    other => other,
+  }
+}
+
+fn assert_round_trips< T >( name : &str, recorded : Value )
+where
+  T : serde::de::DeserializeOwned + serde::Serialize,
+{
+  let parsed : T = serde_json::from_value( recorded.clone() )
+    .unwrap_or_else( | e | panic!( "{name} no longer deserialises : {e}" ) );
+  let written = serde_json::to_value( &parsed ).expect( "wire type must serialise" );
+  assert_eq!( without_nulls( written ), without_nulls( recorded ), "{name} lost or changed fields in a round-trip" );
+}
+
+/// Each recorded chat completion must round-trip without losing a field,
+/// and without keeping one in `extra`: a captured field is unmodelled too.
+#[ test ]
+fn recorded_responses_round_trip()
+{
+  for ( name, recorded ) in corpus( is_response )
+  {
+    let parsed : ChatCompletionResponse = serde_json::from_value( recorded.clone() )
+      .unwrap_or_else( | e | panic!( "{name} no longer deserialises : {e}" ) );
+    let captured : Vec< &String > = parsed.extra.keys()
+      .chain( parsed.choices.iter().flat_map( | choice | choice.extra.keys().chain( choice.message.extra.keys() ) ) )
+      .collect();
+    assert!( captured.is_empty(), "{name} has unmodelled fields : {captured:?}" );
+    assert_round_trips::< ChatCompletionResponse >( &name, recorded );
+  }
+}
+
+/// Each recorded SSE chunk must round-trip without losing a field.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn recorded_chunks_round_trip()
+{
+  for ( name, recorded ) in corpus( is_chunk )
+  {
+    assert_round_trips::< api_openai_compatible::ChatCompletionChunk >( &name, recorded );
+  }
+}
+
+/// Under `strict_wire` a field the types do not model fails deserialisation
+/// at any depth.
+#[ cfg( feature = "strict_wire" ) ]
+#[ test ]
+fn strict_wire_rejects_unknown_field()
+{
+  let ( _, mut recorded ) = corpus( | name | name == "chat_completion_text" ).remove( 0 );
+  recorded[ "choices" ][ 0 ][ "message" ][ "annotations" ] = serde_json::json!( [] );
+
+  let error = serde_json::from_value::< ChatCompletionResponse >( recorded ).unwrap_err();
+
+  assert!( error.to_string().contains( "annotations" ), "error must name the field : {error}" );
+}
+
+/// Under `strict_wire` an unknown role fails deserialisation instead of
+/// mapping to `Role::Other`.
+#[ cfg( feature = "strict_wire" ) ]
+#[ test ]
+fn strict_wire_rejects_unknown_role()
+{
+  let ( _, mut recorded ) = corpus( | name | name == "chat_completion_text" ).remove( 0 );
+  recorded[ "choices" ][ 0 ][ "message" ][ "role" ] = serde_json::json!( "developer" );
+
+  assert!( serde_json::from_value::< ChatCompletionResponse >( recorded ).is_err() );
+}
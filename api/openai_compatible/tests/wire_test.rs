@@ -17,7 +17,7 @@
 //! | chat_completion_request_omits_none_fields | Request | skip_serializing_if=None coverage |
 //! | chat_completion_request_stream_set_to_false_appears_in_json | Request | Some(false) is serialised |
 //! | chat_completion_response_deserializes_from_fixture | Response | canonical fixture |
-//! | response_tolerates_extra_unknown_json_fields | Response | deny_unknown_fields absent |
+//! | response_tolerates_extra_unknown_json_fields | Response | deny_unknown_fields absent (without `strict_wire`) |
 //! | response_with_multiple_choices_deserializes | Response | n > 1 choices |
 //! | role_round_trips_through_serde | Role | standard 4 variants serialise/deserialise correctly |
 //! | role_other_serialises_to_lowercase_other_string | Role | Role::Other → "other"; round-trip back to Role::Other |
@@ -40,6 +40,18 @@
 //! | streaming_chunk_first_with_role_deserializes | Streaming | First chunk includes role in delta |
 //! | streaming_chunk_last_with_finish_reason_deserializes | Streaming | Last chunk carries finish_reason |
 //! | streaming_chunk_round_trips_through_serde | Streaming | Chunk round-trip consistency |
+//! | message_from_neutral_message_maps_roles | Common | NeutralMessage → Message role mapping |
+//! | streaming_chunk_into_stream_events | Common | ChatCompletionChunk → StreamEvent translation |
+//! | tool_choice_serialises_mode_and_named_forms | Request | ToolChoice mode string and named-function object |
+//! | chat_completion_request_stop_appears_when_set | Request | stop array present when set, omitted otherwise |
+//! | reasoning_content_deserialises_in_message_and_delta | Response | DeepSeek-style reasoning_content in message and delta |
+//! | parallel_tool_calls_round_trip_with_results | Request | parallel_tool_calls flag, two calls, one tool result per id |
+//! | tool_call_arguments_object_is_stored_encoded | ToolCall | Object-valued arguments become their JSON string |
+//! | function_without_description_omits_key | Tool | Optional description and strict omitted when unset |
+//! | usage_into_token_usage | Common | Usage → TokenUsage prompt, completion, cached, and reasoning counts |
+//! | extension_fields_survive_round_trip | Response | Unmodelled response, choice, and message fields kept in `extra` (without `strict_wire`) |
+//! | request_extra_fields_are_sent_inline | Request | `extra` members serialise beside the modelled fields |
+//! | response_format_nests_json_schema_envelope | Request | `json_schema` envelope, `type` tags, and round-trip of every `ResponseFormat` |
 
 #![ cfg( feature = "enabled" ) ]
 
@@ -216,8 +228,10 @@ fn message_with_tool_calls_serializes_correctly()
   {
     role         : Role::Assistant,
     content      : None,
+    reasoning_content : None,
     tool_calls   : Some( vec![ call ] ),
     tool_call_id : None,
+    extra        : serde_json::Map::new(),
   };
 
   let json = serde_json::to_string( &msg ).expect( "Message must be serializable" );
@@ -414,6 +428,7 @@ fn chat_completion_response_deserializes_from_fixture()
 /// being absent (the default), serde ignores unknown keys silently. If this test
 /// fails, an accidental `deny_unknown_fields` annotation was added and would
 /// break production deserialization for any provider that adds custom fields.
+#[ cfg( not( feature = "strict_wire" ) ) ]
 #[ test ]
 fn response_tolerates_extra_unknown_json_fields()
 {
@@ -591,6 +606,7 @@ fn role_other_serialises_to_lowercase_other_string()
 /// deserialization fails for strings that don't match a known variant.
 /// Pitfall: Enum wire types in protocol layers must always include a
 /// `#[serde(other)]` catch-all to tolerate provider-side API evolution.
+#[ cfg( not( feature = "strict_wire" ) ) ]
 #[ test ]
 fn role_unknown_variant_deserialises_to_other()
 {
@@ -615,6 +631,7 @@ fn role_unknown_variant_deserialises_to_other()
 ///
 /// Without forward-compatible `Role`, a single unknown role in one choice
 /// causes the entire response to fail deserialisation — losing all valid data.
+#[ cfg( not( feature = "strict_wire" ) ) ]
 #[ test ]
 fn response_with_unknown_role_in_choice_deserialises()
 {
@@ -736,6 +753,7 @@ fn streaming_delta_selective_serialization_omits_only_none_fields()
   {
     role       : None,
     content    : Some( "world".to_string() ),
+    reasoning_content : None,
     tool_calls : None,
   };
 
@@ -1288,11 +1306,15 @@ fn streaming_chunk_round_trips_through_serde()
         {
           role       : Some( Role::Assistant ),
           content    : Some( " Hello".to_string() ),
+          reasoning_content : None,
           tool_calls : None,
         },
         finish_reason : None,
+        logprobs      : None,
       },
     ],
+    system_fingerprint : None,
+    usage : None,
   };
 
   let json = serde_json::to_string( &original ).expect( "ChatCompletionChunk must be serializable" );
@@ -1313,7 +1335,7 @@ fn streaming_chunk_round_trips_through_serde()
 /// in that first chunk (e.g. `"developer"`), the forward-compatible catch-all
 /// must absorb it instead of producing a hard deserialisation error that
 /// silently drops the entire streamed response.
-#[ cfg( feature = "streaming" ) ]
+#[ cfg( all( feature = "streaming", not( feature = "strict_wire" ) ) ) ]
 #[ test ]
 fn streaming_delta_unknown_role_deserialises_to_other()
 {
@@ -1345,3 +1367,371 @@ fn streaming_delta_unknown_role_deserialises_to_other()
     "content must remain accessible when role is unknown",
   );
 }
+
+/// `Message::from( NeutralMessage )` must map each neutral role onto the
+/// matching wire role and carry the content unchanged.
+///
+/// Provider-neutral prompts and conversations render into `NeutralMessage`;
+/// this conversion is the only bridge into the shared wire format, so a
+/// role mix-up here would silently turn system prompts into user turns.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn message_from_neutral_message_maps_roles()
+{
+  use api_common::NeutralMessage;
+
+  let system = Message::from( NeutralMessage::system( "rules" ) );
+  let user = Message::from( NeutralMessage::user( "question" ) );
+  let assistant = Message::from( NeutralMessage::assistant( "answer" ) );
+
+  assert_eq!( system, Message::system( "rules" ) );
+  assert_eq!( user, Message::user( "question" ) );
+  assert_eq!( assistant, Message::assistant( "answer" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A chunk's first choice must translate into text, tool-call fragments,
+/// and `Done` in that order; empty content produces no `TextDelta`.
+#[ cfg( all( feature = "common", feature = "streaming" ) ) ]
+#[ test ]
+fn streaming_chunk_into_stream_events()
+{
+  use api_common::{ IntoStreamEvents, StreamEvent, ToolCallDelta };
+  use api_openai_compatible::ChatCompletionChunk;
+
+  let fixture = r#"{
+    "id": "chatcmpl-stream-abc",
+    "object": "chat.completion.chunk",
+    "created": 1717000010,
+    "model": "gpt-4o",
+    "choices": [
+      {
+        "index": 0,
+        "delta": {
+          "content": "Hi",
+          "tool_calls": [
+            { "id": "call_1", "type": "function", "function": { "name": "lookup", "arguments": "{\"q\":" } }
+          ]
+        },
+        "finish_reason": "tool_calls"
+      }
+    ]
+  }"#;
+  let chunk : ChatCompletionChunk = serde_json::from_str( fixture ).expect( "fixture must deserialise" );
+
+  let events = chunk.into_stream_events().expect( "chunk carries no error" );
+
+  assert_eq!(
+    events,
+    vec![
+      StreamEvent::TextDelta( "Hi".to_owned() ),
+      StreamEvent::ToolCallDelta( ToolCallDelta
+      {
+        index : 0,
+        id : Some( "call_1".to_owned() ),
+        name : Some( "lookup".to_owned() ),
+        arguments : r#"{"q":"#.to_owned(),
+      }),
+      StreamEvent::Done { finish_reason : Some( "tool_calls".to_owned() ) },
+    ],
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `ToolChoice` serialises as a bare mode string or as the named-function
+/// object, and both forms deserialise back.
+#[ test ]
+fn tool_choice_serialises_mode_and_named_forms()
+{
+  use api_openai_compatible::ToolChoice;
+
+  assert_eq!( serde_json::to_value( ToolChoice::required() ).unwrap(), serde_json::json!( "required" ) );
+  assert_eq!(
+    serde_json::to_value( ToolChoice::function( "get_weather" ) ).unwrap(),
+    serde_json::json!( { "type" : "function", "function" : { "name" : "get_weather" } } ),
+  );
+
+  let any : ToolChoice = serde_json::from_str( r#""any""# ).unwrap();
+  assert_eq!( any, ToolChoice::Mode( "any".to_string() ) );
+  let named : ToolChoice = serde_json::from_str( r#"{"type":"function","function":{"name":"f"}}"# ).unwrap();
+  assert_eq!( named, ToolChoice::function( "f" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `stop` serialises as an array when set and is omitted when `None`.
+#[ test ]
+fn chat_completion_request_stop_appears_when_set()
+{
+  let request = ChatCompletionRequest::former()
+    .model( "gpt-4o".to_string() )
+    .messages( vec![ Message::user( "Hi" ) ] )
+    .stop( vec![ "\n\n".to_string() ] )
+    .form();
+  let json = serde_json::to_value( &request ).unwrap();
+  assert_eq!( json[ "stop" ], serde_json::json!( [ "\n\n" ] ) );
+  assert!( json.get( "tool_choice" ).is_none(), "unset tool_choice must be omitted" );
+
+  let plain = ChatCompletionRequest::former()
+    .model( "gpt-4o".to_string() )
+    .messages( vec![ Message::user( "Hi" ) ] )
+    .form();
+  assert!( serde_json::to_value( &plain ).unwrap().get( "stop" ).is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Reasoning models (`deepseek-reasoner`, Grok mini, `vLLM` reasoning
+/// parsers) return `reasoning_content` next to `content`; it must survive
+/// deserialisation under `strict_wire` too, and stay absent when unset.
+#[ cfg( feature = "streaming" ) ]
+#[ test ]
+fn reasoning_content_deserialises_in_message_and_delta()
+{
+  use api_openai_compatible::Delta;
+
+  let message : Message = serde_json::from_str(
+    r#"{"role":"assistant","content":"4","reasoning_content":"2 + 2 is 4."}"#
+  ).unwrap();
+  assert_eq!( message.reasoning_content.as_deref(), Some( "2 + 2 is 4." ) );
+  assert_eq!( message.content.as_deref(), Some( "4" ) );
+
+  let delta : Delta = serde_json::from_str( r#"{"reasoning_content":"2 + "}"# ).unwrap();
+  assert_eq!( delta.reasoning_content.as_deref(), Some( "2 + " ) );
+  assert!( delta.content.is_none() );
+
+  let json = serde_json::to_value( Message::assistant( "4" ) ).unwrap();
+  assert!( json.get( "reasoning_content" ).is_none() );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `prompt_tokens` and `completion_tokens` must become the neutral input
+/// and output counts, and the cached and reasoning tokens of the details
+/// their neutral counterparts; without details both are 0.
+#[ cfg( feature = "common" ) ]
+#[ test ]
+fn usage_into_token_usage()
+{
+  use api_common::TokenUsage;
+  use api_openai_compatible::Usage;
+
+  let usage = TokenUsage::from( Usage { prompt_tokens : 12, completion_tokens : 5, total_tokens : 17, ..Default::default() } );
+
+  assert_eq!( usage, TokenUsage::new( 12, 5 ) );
+  assert_eq!( usage.total_tokens(), 17 );
+
+  // As an o-series model reports it
+  let recorded = serde_json::json!
+  ({
+    "prompt_tokens" : 1_200,
+    "completion_tokens" : 900,
+    "total_tokens" : 2_100,
+    "prompt_tokens_details" : { "cached_tokens" : 1_024, "audio_tokens" : 0 },
+    "completion_tokens_details" :
+    {
+      "reasoning_tokens" : 640,
+      "audio_tokens" : 0,
+      "accepted_prediction_tokens" : 0,
+      "rejected_prediction_tokens" : 0,
+    },
+  });
+  let reasoning : Usage = serde_json::from_value( recorded.clone() ).expect( "usage must deserialise" );
+
+  assert_eq!( reasoning.cached_tokens(), 1_024 );
+  assert_eq!( reasoning.reasoning_tokens(), 640 );
+  assert_eq!( serde_json::to_value( &reasoning ).expect( "usage must serialise" ), recorded );
+  assert_eq!
+  (
+    TokenUsage::from( reasoning ),
+    TokenUsage::new( 1_200, 900 ).with_cached_input_tokens( 1_024 ).with_reasoning_tokens( 640 ),
+  );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A turn with two parallel tool calls and their results must survive a
+/// serde round trip unchanged, with each result tied to its call id.
+#[ test ]
+fn parallel_tool_calls_round_trip_with_results()
+{
+  #[ derive( serde::Deserialize ) ]
+  struct Weather { city : String }
+
+  let request = ChatCompletionRequest::former()
+    .model( "gpt-4o".to_string() )
+    .messages( vec!
+    [
+      Message::user( "Weather in Paris and Rome?" ),
+      Message::assistant_tool_calls( vec!
+      [
+        ToolCall::function( "call_1", "get_weather", r#"{"city":"Paris"}"# ),
+        ToolCall::function( "call_2", "get_weather", r#"{"city":"Rome"}"# ),
+      ]),
+      Message::tool( "call_1", "18C" ),
+      Message::tool( "call_2", "24C" ),
+    ])
+    .tools( vec![ api_openai_compatible::Tool::function( "get_weather", "Current weather", serde_json::json!( { "type" : "object" } ) ) ] )
+    .parallel_tool_calls( true )
+    .form();
+
+  let json = serde_json::to_value( &request ).expect( "request must serialise" );
+  assert_eq!( json[ "parallel_tool_calls" ], true );
+  assert!( json[ "messages" ][ 1 ].get( "content" ).is_none(), "assistant tool-call message must omit content; got: {json}" );
+  assert_eq!( json[ "messages" ][ 3 ][ "tool_call_id" ], "call_2" );
+
+  let round_tripped : ChatCompletionRequest = serde_json::from_value( json ).expect( "request must deserialise" );
+  assert_eq!( round_tripped, request );
+
+  let calls = round_tripped.messages[ 1 ].tool_calls.as_ref().expect( "tool calls must survive" );
+  let cities : Vec< String > = calls.iter()
+    .map( | call | call.function.parse_arguments::< Weather >().expect( "arguments must parse" ).city )
+    .collect();
+  assert_eq!( cities, [ "Paris", "Rome" ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Some servers send `arguments` as a JSON object; it must be stored as its
+/// encoded string so the type round-trips in the standard form.
+#[ test ]
+fn tool_call_arguments_object_is_stored_encoded()
+{
+  let call : ToolCall = serde_json::from_value( serde_json::json!
+  ({
+    "id" : "call_1",
+    "type" : "function",
+    "function" : { "name" : "get_weather", "arguments" : { "city" : "Paris" } },
+  }) ).expect( "object arguments must deserialise" );
+
+  assert_eq!( call.function.arguments, r#"{"city":"Paris"}"# );
+  let json = serde_json::to_value( &call ).expect( "tool call must serialise" );
+  assert_eq!( json[ "function" ][ "arguments" ], r#"{"city":"Paris"}"# );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `description` is optional on the wire; an absent description and an unset
+/// `strict` must not appear when the definition is sent back.
+#[ test ]
+fn function_without_description_omits_key()
+{
+  use api_openai_compatible::Function;
+
+  let function : Function = serde_json::from_value( serde_json::json!
+  ({
+    "name" : "ping",
+    "parameters" : { "type" : "object" },
+  }) ).expect( "description must be optional" );
+
+  assert!( function.description.is_empty() );
+  assert_eq!( function.strict, None );
+  let json = serde_json::to_value( &function ).expect( "function must serialise" );
+  assert!( json.get( "description" ).is_none(), "empty description must be omitted; got: {json}" );
+  assert!( json.get( "strict" ).is_none(), "unset strict must be omitted; got: {json}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Provider extensions at the response, choice, and message level must land
+/// in the matching `extra` map and be written back where they came from.
+#[ cfg( not( feature = "strict_wire" ) ) ]
+#[ test ]
+fn extension_fields_survive_round_trip()
+{
+  let recorded = serde_json::json!
+  ({
+    "id" : "chatcmpl-ext",
+    "object" : "chat.completion",
+    "created" : 1_717_000_002,
+    "model" : "llama-3.3-70b-versatile",
+    "x_groq" : { "id" : "req_01" },
+    "choices" : [ {
+      "index" : 0,
+      "message" : { "role" : "assistant", "content" : "Hi", "reasoning" : "greet back" },
+      "finish_reason" : "stop",
+      "stop_reason" : 128_009,
+    } ],
+    "usage" : { "prompt_tokens" : 3, "completion_tokens" : 1, "total_tokens" : 4 },
+  });
+
+  let response : ChatCompletionResponse = serde_json::from_value( recorded.clone() )
+    .expect( "extensions must not fail deserialisation" );
+
+  assert_eq!( response.extra[ "x_groq" ][ "id" ], "req_01" );
+  assert_eq!( response.choices[ 0 ].extra[ "stop_reason" ], 128_009 );
+  assert_eq!( response.choices[ 0 ].message.extra[ "reasoning" ], "greet back" );
+  assert_eq!( response.choices[ 0 ].message.content.as_deref(), Some( "Hi" ) );
+  assert_eq!( serde_json::to_value( &response ).expect( "response must serialise" ), recorded );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Members put in a request's `extra` must be sent inline, so parameters
+/// outside the `OpenAI` schema reach the provider; an empty map adds nothing.
+#[ test ]
+fn request_extra_fields_are_sent_inline()
+{
+  let mut extra = serde_json::Map::new();
+  extra.insert( "search_parameters".to_string(), serde_json::json!( { "mode" : "auto" } ) );
+  let request = ChatCompletionRequest::former()
+    .model( "grok-3".to_string() )
+    .messages( vec![ Message::user( "News?" ) ] )
+    .extra( extra )
+    .form();
+
+  let json = serde_json::to_value( &request ).expect( "request must serialise" );
+
+  assert_eq!( json[ "search_parameters" ][ "mode" ], "auto" );
+  assert!( json.get( "extra" ).is_none(), "extra must be flattened; got: {json}" );
+  let plain = ChatCompletionRequest::former().model( "grok-3".to_string() ).messages( vec![] ).form();
+  let keys : Vec< String > = serde_json::to_value( &plain ).expect( "request must serialise" )
+    .as_object().expect( "request is an object" ).keys().cloned().collect();
+  assert_eq!( keys, [ "messages", "model" ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `ResponseFormat::JsonSchema` must put its fields inside the `json_schema`
+/// envelope, tagged `"type":"json_schema"`; the other formats are bare tags,
+/// and every form reads back as it was sent.
+#[ test ]
+fn response_format_nests_json_schema_envelope()
+{
+  use api_openai_compatible::ResponseFormat;
+
+  let schema = serde_json::json!
+  ({
+    "type" : "object",
+    "properties" : { "city" : { "type" : "string" } },
+    "required" : [ "city" ],
+    "additionalProperties" : false,
+  });
+  let format = ResponseFormat::JsonSchema { name : "weather".to_string(), schema : schema.clone(), strict : Some( true ) };
+  let request = ChatCompletionRequest::former()
+    .model( "gpt-4o-mini".to_string() )
+    .messages( vec![ Message::user( "Weather in Paris?" ) ] )
+    .response_format( format.clone() )
+    .form();
+
+  let json = serde_json::to_value( &request ).expect( "request must serialise" );
+
+  assert_eq!
+  (
+    json[ "response_format" ],
+    serde_json::json!( { "type" : "json_schema", "json_schema" : { "name" : "weather", "schema" : schema, "strict" : true } } ),
+  );
+  let lax = ResponseFormat::JsonSchema { name : "weather".to_string(), schema : schema.clone(), strict : None };
+  let lax_json = serde_json::to_value( &lax ).expect( "format must serialise" );
+  assert!( lax_json[ "json_schema" ].get( "strict" ).is_none(), "unset strict must be omitted; got: {lax_json}" );
+  assert_eq!( serde_json::to_value( ResponseFormat::Text ).expect( "format must serialise" ), serde_json::json!( { "type" : "text" } ) );
+  assert_eq!( serde_json::to_value( ResponseFormat::JsonObject ).expect( "format must serialise" ), serde_json::json!( { "type" : "json_object" } ) );
+  for format in [ format, lax, ResponseFormat::Text, ResponseFormat::JsonObject ]
+  {
+    let json = serde_json::to_value( &format ).expect( "format must serialise" );
+    let back : ResponseFormat = serde_json::from_value( json ).expect( "format must deserialise" );
+    assert_eq!( back, format );
+  }
+}
@@ -0,0 +1,349 @@
+//! AWS Secrets Manager backend.
+//!
+//! [`AwsSecretsManager::fetch`] calls `GetSecretValue` over the JSON 1.1
+//! protocol, signed with Signature Version 4, and returns the secret as a
+//! [`MapSource`]. A `SecretString` holding a JSON object (the console's
+//! "key/value" secret type) yields one entry per string field; any other
+//! `SecretString` yields a single entry named after the secret id.
+
+mod private
+{
+  use crate::error::{ Result, SecretError };
+  use crate::source::MapSource;
+  use secrecy::{ ExposeSecret, SecretString };
+  use sha2::{ Digest, Sha256 };
+  use core::fmt;
+  use core::fmt::Write as _;
+  use std::time::{ SystemTime, UNIX_EPOCH };
+
+  const BACKEND : &str = "aws-secrets-manager";
+  const SERVICE : &str = "secretsmanager";
+
+  /// Long-term or temporary AWS credentials.
+  #[ derive( Clone ) ]
+  pub struct AwsCredentials
+  {
+    access_key_id : String,
+    secret_access_key : SecretString,
+    session_token : Option< SecretString >,
+  }
+
+  impl AwsCredentials
+  {
+    /// Long-term access key pair.
+    #[ inline ]
+    pub fn new( access_key_id : impl Into< String >, secret_access_key : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        access_key_id : access_key_id.into(),
+        secret_access_key : SecretString::from( secret_access_key.into() ),
+        session_token : None,
+      }
+    }
+
+    /// Adds the session token of temporary (STS) credentials.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_session_token( mut self, token : impl Into< String > ) -> Self
+    {
+      self.session_token = Some( SecretString::from( token.into() ) );
+      self
+    }
+
+    /// Credentials from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, and the
+    /// optional `AWS_SESSION_TOKEN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MissingConfiguration`] when the key pair is unset.
+    #[ inline ]
+    pub fn from_env() -> Result< Self >
+    {
+      let credentials = Self::new( required_env( "AWS_ACCESS_KEY_ID" )?, required_env( "AWS_SECRET_ACCESS_KEY" )? );
+      Ok( match std::env::var( "AWS_SESSION_TOKEN" )
+      {
+        Ok( token ) if !token.is_empty() => credentials.with_session_token( token ),
+        _ => credentials,
+      } )
+    }
+
+    /// Computes the Signature Version 4 `Authorization` header value for `request`.
+    ///
+    /// `amz_date` is the `x-amz-date` header value (`YYYYMMDD'T'HHMMSS'Z'`)
+    /// and must also appear in `request.headers`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn authorization( &self, request : &SigningRequest< '_ >, region : &str, service : &str, amz_date : &str ) -> String
+    {
+      let mut headers : Vec< ( String, &str ) > = request.headers
+        .iter()
+        .map( | ( name, value ) | ( name.to_ascii_lowercase(), value.trim() ) )
+        .collect();
+      headers.sort();
+      let canonical_headers = headers.iter().fold( String::new(), | mut out, ( name, value ) |
+      {
+        let _ = writeln!( out, "{name}:{value}" );
+        out
+      } );
+      let signed_headers = headers.iter().map( | ( name, _ ) | name.as_str() ).collect::< Vec< _ > >().join( ";" );
+
+      let canonical_request = format!
+      (
+        "{}\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{}",
+        request.method,
+        request.path,
+        request.query,
+        hex( &Sha256::digest( request.payload ) ),
+      );
+
+      let date = &amz_date[ ..8 ];
+      let scope = format!( "{date}/{region}/{service}/aws4_request" );
+      let string_to_sign = format!( "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}", hex( &Sha256::digest( canonical_request.as_bytes() ) ) );
+
+      let secret = format!( "AWS4{}", self.secret_access_key.expose_secret() );
+      let key = [ date, region, service, "aws4_request" ]
+        .iter()
+        .fold( secret.into_bytes(), | key, part | hmac_sha256( &key, part.as_bytes() ).to_vec() );
+      let signature = hex( &hmac_sha256( &key, string_to_sign.as_bytes() ) );
+
+      format!( "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}", self.access_key_id )
+    }
+  }
+
+  impl fmt::Debug for AwsCredentials
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "AwsCredentials" )
+        .field( "access_key_id", &self.access_key_id )
+        .field( "session_token", &self.session_token.is_some() )
+        .finish_non_exhaustive()
+    }
+  }
+
+  /// The parts of an HTTP request covered by a Signature Version 4 signature.
+  #[ derive( Debug, Clone, Copy ) ]
+  pub struct SigningRequest< 'a >
+  {
+    /// HTTP method, e.g. `POST`.
+    pub method : &'a str,
+    /// URI-encoded absolute path, e.g. `/`.
+    pub path : &'a str,
+    /// Canonical (sorted, URI-encoded) query string without `?`.
+    pub query : &'a str,
+    /// Headers to sign; `host` and `x-amz-date` are required.
+    pub headers : &'a [ ( &'a str, &'a str ) ],
+    /// Request body.
+    pub payload : &'a [ u8 ],
+  }
+
+  /// Client for AWS Secrets Manager in one region.
+  #[ derive( Clone ) ]
+  pub struct AwsSecretsManager
+  {
+    region : String,
+    credentials : AwsCredentials,
+    endpoint : String,
+    http : reqwest::Client,
+  }
+
+  impl AwsSecretsManager
+  {
+    /// Client for `region` using the public regional endpoint.
+    #[ inline ]
+    pub fn new( region : impl Into< String >, credentials : AwsCredentials ) -> Self
+    {
+      let region = region.into();
+      let endpoint = format!( "https://{SERVICE}.{region}.amazonaws.com" );
+      Self { region, credentials, endpoint, http : reqwest::Client::new() }
+    }
+
+    /// Client configured from `AWS_REGION` (or `AWS_DEFAULT_REGION`) and
+    /// [`AwsCredentials::from_env`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MissingConfiguration`] when the region or
+    /// credentials are unset.
+    #[ inline ]
+    pub fn from_env() -> Result< Self >
+    {
+      let region = required_env( "AWS_REGION" ).or_else( | _ | required_env( "AWS_DEFAULT_REGION" ) )?;
+      Ok( Self::new( region, AwsCredentials::from_env()? ) )
+    }
+
+    /// Sends requests to `endpoint` instead, e.g. a VPC endpoint or a local emulator.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_endpoint( mut self, endpoint : impl Into< String > ) -> Self
+    {
+      self.endpoint = endpoint.into().trim_end_matches( '/' ).to_string();
+      self
+    }
+
+    /// Uses `http` for requests, e.g. one configured with a proxy.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http_client( mut self, http : reqwest::Client ) -> Self
+    {
+      self.http = http;
+      self
+    }
+
+    /// Reads the current version of `secret_id` (name or ARN).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::Backend`] when the request fails, AWS answers
+    /// with an error (e.g. `ResourceNotFoundException`), or the secret is
+    /// binary.
+    #[ inline ]
+    pub async fn fetch( &self, secret_id : &str ) -> Result< MapSource >
+    {
+      let backend_error = | reason : String | SecretError::Backend { backend : BACKEND, reason };
+
+      let url = reqwest::Url::parse( &self.endpoint ).map_err( | e | backend_error( format!( "invalid endpoint {} : {e}", self.endpoint ) ) )?;
+      let host = match ( url.host_str(), url.port() )
+      {
+        ( Some( host ), Some( port ) ) => format!( "{host}:{port}" ),
+        ( Some( host ), None ) => host.to_string(),
+        ( None, _ ) => return Err( backend_error( format!( "endpoint {} has no host", self.endpoint ) ).into() ),
+      };
+
+      let payload = serde_json::json!( { "SecretId" : secret_id } ).to_string();
+      let amz_date = amz_date( SystemTime::now() );
+      let mut headers = vec!
+      [
+        ( "content-type", "application/x-amz-json-1.1" ),
+        ( "host", host.as_str() ),
+        ( "x-amz-date", amz_date.as_str() ),
+        ( "x-amz-target", "secretsmanager.GetSecretValue" ),
+      ];
+      if let Some( token ) = &self.credentials.session_token
+      {
+        headers.push( ( "x-amz-security-token", token.expose_secret() ) );
+      }
+
+      let request = SigningRequest { method : "POST", path : "/", query : "", headers : &headers, payload : payload.as_bytes() };
+      let authorization = self.credentials.authorization( &request, &self.region, SERVICE, &amz_date );
+
+      let mut http_request = self.http.post( url ).header( "authorization", authorization );
+      for ( name, value ) in headers.iter().filter( | ( name, _ ) | *name != "host" )
+      {
+        http_request = http_request.header( *name, *value );
+      }
+
+      let response = http_request.body( payload ).send().await.map_err( | e | backend_error( e.to_string() ) )?;
+      let status = response.status();
+      let body = response.text().await.map_err( | e | backend_error( e.to_string() ) )?;
+      if !status.is_success()
+      {
+        return Err( backend_error( format!( "{status} reading {secret_id} : {body}" ) ).into() );
+      }
+
+      let body : serde_json::Value = serde_json::from_str( &body ).map_err( | e | backend_error( e.to_string() ) )?;
+      let secret = body
+        .get( "SecretString" )
+        .and_then( serde_json::Value::as_str )
+        .ok_or_else( || backend_error( format!( "{secret_id} has no SecretString (binary secrets are not supported)" ) ) )?;
+
+      let source = MapSource::new( format!( "aws-secrets-manager {secret_id}" ) );
+      Ok( match serde_json::from_str::< serde_json::Map< String, serde_json::Value > >( secret )
+      {
+        Ok( fields ) => fields
+          .iter()
+          .filter_map( | ( key, value ) | value.as_str().map( | value | ( key, value ) ) )
+          .fold( source, | source, ( key, value ) | source.with( key.as_str(), value ) ),
+        Err( _ ) => source.with( secret_id, secret ),
+      } )
+    }
+  }
+
+  impl fmt::Debug for AwsSecretsManager
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "AwsSecretsManager" )
+        .field( "region", &self.region )
+        .field( "endpoint", &self.endpoint )
+        .field( "credentials", &self.credentials )
+        .finish_non_exhaustive()
+    }
+  }
+
+  fn required_env( name : &str ) -> Result< String >
+  {
+    std::env::var( name )
+      .ok()
+      .filter( | value | !value.is_empty() )
+      .ok_or_else( || SecretError::MissingConfiguration( format!( "{name} is not set" ) ).into() )
+  }
+
+  fn hmac_sha256( key : &[ u8 ], data : &[ u8 ] ) -> [ u8; 32 ]
+  {
+    let mut block = [ 0_u8; 64 ];
+    if key.len() > block.len()
+    {
+      block[ ..32 ].copy_from_slice( &Sha256::digest( key ) );
+    }
+    else
+    {
+      block[ ..key.len() ].copy_from_slice( key );
+    }
+
+    let inner = Sha256::new()
+      .chain_update( block.map( | byte | byte ^ 0x36 ) )
+      .chain_update( data )
+      .finalize();
+    let outer = Sha256::new()
+      .chain_update( block.map( | byte | byte ^ 0x5c ) )
+      .chain_update( inner )
+      .finalize();
+
+    let mut mac = [ 0_u8; 32 ];
+    mac.copy_from_slice( &outer );
+    mac
+  }
+
+  fn hex( bytes : &[ u8 ] ) -> String
+  {
+    bytes.iter().fold( String::with_capacity( bytes.len() * 2 ), | mut out, byte |
+    {
+      let _ = write!( out, "{byte:02x}" );
+      out
+    } )
+  }
+
+  /// Formats `time` as `YYYYMMDD'T'HHMMSS'Z'` in UTC.
+  fn amz_date( time : SystemTime ) -> String
+  {
+    let secs = time.duration_since( UNIX_EPOCH ).map_or( 0, | elapsed | elapsed.as_secs() );
+    let ( days, rem ) = ( secs / 86_400, secs % 86_400 );
+
+    // Civil date from days since 1970-01-01 (proleptic Gregorian).
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = ( doe - doe / 1_460 + doe / 36_524 - doe / 146_096 ) / 365;
+    let doy = doe - ( 365 * yoe + yoe / 4 - yoe / 100 );
+    let mp = ( 5 * doy + 2 ) / 153;
+    let day = doy - ( 153 * mp + 2 ) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from( month <= 2 );
+
+    format!( "{year:04}{month:02}{day:02}T{:02}{:02}{:02}Z", rem / 3_600, rem % 3_600 / 60, rem % 60 )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    AwsCredentials,
+    AwsSecretsManager,
+    SigningRequest,
+  };
+}
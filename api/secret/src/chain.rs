@@ -0,0 +1,133 @@
+//! Ordered fallback chain over secret sources.
+//!
+//! [`SecretChain::standard`] is the chain every provider crate uses:
+//!
+//! 1. `secret/-secrets.sh` in the workspace
+//! 2. `secret/secrets.sh`
+//! 3. `secret/.env`
+//! 4. the process environment
+//!
+//! Sources placed before it (a Vault or AWS fetch result, a test fixture)
+//! take precedence.
+
+mod private
+{
+  use crate::error::{ Result, SecretError };
+  use crate::source::SecretSource;
+  #[ cfg( feature = "workspace" ) ]
+  use crate::source::EnvSource;
+  #[ cfg( feature = "workspace" ) ]
+  use crate::workspace::WorkspaceSource;
+  use secrecy::SecretString;
+  use core::fmt;
+
+  /// Files under the workspace `secret/` directory consulted by
+  /// [`SecretChain::standard`], in order.
+  pub const WORKSPACE_SECRET_FILES : [ &str; 3 ] = [ "-secrets.sh", "secrets.sh", ".env" ];
+
+  /// Sources consulted in order; the first one holding the key wins.
+  #[ derive( Default ) ]
+  pub struct SecretChain
+  {
+    sources : Vec< Box< dyn SecretSource > >,
+  }
+
+  impl SecretChain
+  {
+    /// Empty chain.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// The chain shared by all provider crates: workspace `-secrets.sh`,
+    /// `secrets.sh`, `.env`, then the environment.
+    #[ cfg( feature = "workspace" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn standard() -> Self
+    {
+      WORKSPACE_SECRET_FILES
+        .iter()
+        .fold( Self::new(), | chain, filename | chain.with( WorkspaceSource::new( *filename ) ) )
+        .with( EnvSource )
+    }
+
+    /// Appends `source`; it is consulted after every source already present.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with< S : SecretSource + 'static >( mut self, source : S ) -> Self
+    {
+      self.sources.push( Box::new( source ) );
+      self
+    }
+
+    /// Appends every source of `other`, keeping its order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn then( mut self, other : SecretChain ) -> Self
+    {
+      self.sources.extend( other.sources );
+      self
+    }
+
+    /// Descriptions of the sources, in lookup order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn describe( &self ) -> Vec< String >
+    {
+      self.sources.iter().map( | source | source.describe() ).collect()
+    }
+
+    /// Looks `key` up in each source in turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first source error; a source that does not hold the key
+    /// does not stop the search.
+    #[ inline ]
+    pub fn lookup( &self, key : &str ) -> Result< Option< SecretString > >
+    {
+      for source in &self.sources
+      {
+        if let Some( value ) = source.lookup( key )?
+        {
+          return Ok( Some( value ) );
+        }
+      }
+      Ok( None )
+    }
+
+    /// Loads `key` from the first source holding it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::NotFound`] listing every source consulted when
+    /// none holds the key, or the first source error.
+    #[ inline ]
+    pub fn load( &self, key : &str ) -> Result< SecretString >
+    {
+      self.lookup( key )?.ok_or_else( || SecretError::NotFound { key : key.to_string(), tried : self.describe() }.into() )
+    }
+  }
+
+  impl fmt::Debug for SecretChain
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "SecretChain" ).field( "sources", &self.describe() ).finish()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SecretChain,
+    WORKSPACE_SECRET_FILES,
+  };
+}
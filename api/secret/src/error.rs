@@ -0,0 +1,66 @@
+//! Error type and result alias for secret loading.
+
+mod private
+{
+  use error_tools::dependency::thiserror;
+
+  /// Error variants for secret loading.
+  #[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+  #[ non_exhaustive ]
+  pub enum SecretError
+  {
+    /// No source in the chain holds the key.
+    #[ error( "{key} not found in any source ({})", tried.join( ", " ) ) ]
+    NotFound
+    {
+      /// Name of the key that was looked up.
+      key : String,
+      /// Descriptions of the sources consulted, in order.
+      tried : Vec< String >,
+    },
+
+    /// A source exists but could not be read (permissions, I/O, bad UTF-8).
+    #[ error( "Failed to read {source_name} : {reason}" ) ]
+    Unreadable
+    {
+      /// Description of the source, e.g. the file path.
+      source_name : String,
+      /// Human-readable description of the problem.
+      reason : String,
+    },
+
+    /// A remote backend rejected the request or returned an unexpected body.
+    #[ error( "{backend} error : {reason}" ) ]
+    Backend
+    {
+      /// Backend name (`vault`, `aws-secrets-manager`).
+      backend : &'static str,
+      /// Human-readable description of the problem.
+      reason : String,
+    },
+
+    /// Every key in a `KeyPool` is cooling down after a throttle.
+    #[ error( "All keys are cooling down; the first becomes available in {retry_after:?}" ) ]
+    AllKeysCoolingDown
+    {
+      /// Shortest remaining cooldown.
+      retry_after : core::time::Duration,
+    },
+
+    /// Required configuration (address, token, region, credentials) is missing.
+    #[ error( "Missing configuration : {0}" ) ]
+    MissingConfiguration( String ),
+  }
+
+  /// Crate-level result type backed by a boxed dynamic error.
+  pub type Result< T > = error_tools::untyped::Result< T >;
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SecretError,
+    Result,
+  };
+}
@@ -0,0 +1,65 @@
+//! Shared API key loading for the `api_llm` provider crates.
+//!
+//! Every provider crate resolves its key through the same [`SecretChain`]:
+//! workspace `secret/-secrets.sh`, `secret/secrets.sh`, `secret/.env`, then
+//! the process environment. Applications that keep keys elsewhere fetch them
+//! explicitly from Vault or AWS Secrets Manager and put the result
+//! in front of the chain.
+//!
+//! # Features
+//!
+//! - `enabled` — `SecretSource`, `SecretChain`, environment and env-file sources,
+//!   and the `Masked` / `redact` helpers for printing keys
+//! - `workspace` — `WorkspaceSource` and `SecretChain::standard`
+//! - `pool` — `KeyPool` rotation across several keys with 429 cooldowns
+//! - `vault` — Vault KV v2 backend
+//! - `aws` — AWS Secrets Manager backend
+//! - `rustls` / `native_tls` — TLS backend for the remote backends
+//! - `full` — enables every feature above except `native_tls`
+//!
+//! # Architecture
+//!
+//! Follows the "Thin Client, Rich API" principle: local sources are read on
+//! every lookup and never cached; remote backends are contacted only when
+//! `fetch` is called.
+
+#[ cfg( feature = "enabled" ) ]
+use mod_interface::mod_interface;
+
+mod private {}
+
+/// Re-exported so callers read loaded values without depending on `secrecy`.
+#[ cfg( feature = "enabled" ) ]
+pub use secrecy::{ ExposeSecret, SecretString };
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// Error type and result alias.
+  layer error;
+
+  /// Masked `Debug` and `Display` output for key material.
+  layer redaction;
+
+  /// `SecretSource` trait with environment, env-file, and in-memory sources.
+  layer source;
+
+  /// Workspace `secret/` directory source.
+  #[ cfg( feature = "workspace" ) ]
+  layer workspace;
+
+  /// Ordered fallback chain over sources.
+  layer chain;
+
+  /// Multi-key rotation with explicit cooldowns.
+  #[ cfg( feature = "pool" ) ]
+  layer pool;
+
+  /// Vault KV v2 backend.
+  #[ cfg( feature = "vault" ) ]
+  layer vault;
+
+  /// AWS Secrets Manager backend.
+  #[ cfg( feature = "aws" ) ]
+  layer aws;
+}
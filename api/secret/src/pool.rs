@@ -0,0 +1,283 @@
+//! Rotation across several API keys for one provider.
+//!
+//! Teams that shard quota across keys hand a [`KeyPool`] every key and call
+//! [`KeyPool::acquire`] before each request. When a request comes back with
+//! 429 the caller reports it with [`KeyPool::mark_throttled`] and a cooldown
+//! (usually the provider's `retry-after`); the key is skipped until the
+//! cooldown ends. The pool never inspects responses itself.
+
+mod private
+{
+  use crate::chain::SecretChain;
+  use crate::error::{ Result, SecretError };
+  use core::fmt;
+  use core::time::Duration;
+  use secrecy::SecretString;
+  use std::sync::{ Mutex, MutexGuard, PoisonError };
+  use web_time::Instant;
+
+  /// How [`KeyPool::acquire`] picks among keys that are not cooling down.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  pub enum RotationStrategy
+  {
+    /// Each key in turn, in the order given.
+    #[ default ]
+    RoundRobin,
+    /// The key whose last 429 is oldest; never-throttled keys first, in
+    /// round-robin order among themselves.
+    LeastRecentlyThrottled,
+  }
+
+  /// A key handed out by [`KeyPool::acquire`].
+  #[ derive( Clone ) ]
+  pub struct PooledKey
+  {
+    index : usize,
+    secret : SecretString,
+  }
+
+  impl PooledKey
+  {
+    /// Position of the key in the pool, for [`KeyPool::mark_throttled`] and logs.
+    #[ inline ]
+    #[ must_use ]
+    pub fn index( &self ) -> usize
+    {
+      self.index
+    }
+
+    /// The key itself.
+    #[ inline ]
+    #[ must_use ]
+    pub fn secret( &self ) -> &SecretString
+    {
+      &self.secret
+    }
+  }
+
+  impl fmt::Debug for PooledKey
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "PooledKey" ).field( "index", &self.index ).finish_non_exhaustive()
+    }
+  }
+
+  /// Point-in-time view of one key, from [`KeyPool::status`].
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct KeyStatus
+  {
+    /// Position of the key in the pool.
+    pub index : usize,
+    /// Times the key was handed out.
+    pub uses : u64,
+    /// Times the key was reported throttled.
+    pub throttles : u64,
+    /// Remaining cooldown, `None` when the key is available.
+    pub cooldown_remaining : Option< Duration >,
+  }
+
+  struct Slot
+  {
+    secret : SecretString,
+    uses : u64,
+    throttles : u64,
+    last_throttled : Option< Instant >,
+    cooldown_until : Option< Instant >,
+  }
+
+  impl Slot
+  {
+    fn cooldown_remaining( &self, now : Instant ) -> Option< Duration >
+    {
+      self.cooldown_until.filter( | until | *until > now ).map( | until | until - now )
+    }
+  }
+
+  struct State
+  {
+    slots : Vec< Slot >,
+    cursor : usize,
+  }
+
+  /// Keys for one provider, rotated on every [`acquire`](KeyPool::acquire).
+  ///
+  /// Cheap to share behind an `Arc`; every method takes `&self`.
+  pub struct KeyPool
+  {
+    strategy : RotationStrategy,
+    state : Mutex< State >,
+  }
+
+  impl KeyPool
+  {
+    /// Pool over `keys`, rotated round-robin.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MissingConfiguration`] when `keys` is empty.
+    #[ inline ]
+    pub fn new< I, K >( keys : I ) -> Result< Self >
+    where
+      I : IntoIterator< Item = K >,
+      K : Into< String >,
+    {
+      let slots : Vec< Slot > = keys
+        .into_iter()
+        .map( | key | Slot { secret : SecretString::from( key.into() ), uses : 0, throttles : 0, last_throttled : None, cooldown_until : None } )
+        .collect();
+      if slots.is_empty()
+      {
+        return Err( SecretError::MissingConfiguration( "key pool needs at least one key".to_string() ).into() );
+      }
+      Ok( Self { strategy : RotationStrategy::default(), state : Mutex::new( State { slots, cursor : 0 } ) } )
+    }
+
+    /// Pool over `name`, `name_2`, `name_3`, … loaded from `chain`, stopping
+    /// at the first missing suffix (e.g. `OPENAI_API_KEY`, `OPENAI_API_KEY_2`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::NotFound`] when `name` itself is missing, or
+    /// the first source error.
+    #[ inline ]
+    pub fn load_numbered( chain : &SecretChain, name : &str ) -> Result< Self >
+    {
+      use secrecy::ExposeSecret;
+
+      let mut keys = vec![ chain.load( name )?.expose_secret().to_string() ];
+      while let Some( key ) = chain.lookup( &format!( "{name}_{}", keys.len() + 1 ) )?
+      {
+        keys.push( key.expose_secret().to_string() );
+      }
+      Self::new( keys )
+    }
+
+    /// Picks keys with `strategy` instead of round-robin.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_strategy( mut self, strategy : RotationStrategy ) -> Self
+    {
+      self.strategy = strategy;
+      self
+    }
+
+    /// Number of keys in the pool.
+    #[ inline ]
+    #[ must_use ]
+    pub fn len( &self ) -> usize
+    {
+      self.lock().slots.len()
+    }
+
+    /// Always `false`; a pool holds at least one key.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.lock().slots.is_empty()
+    }
+
+    /// Hands out the next available key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::AllKeysCoolingDown`] with the shortest remaining
+    /// cooldown when every key is cooling down.
+    #[ inline ]
+    pub fn acquire( &self ) -> Result< PooledKey >
+    {
+      let now = Instant::now();
+      let mut state = self.lock();
+      let count = state.slots.len();
+      let mut available = ( 0..count )
+        .map( | offset | ( state.cursor + offset ) % count )
+        .filter( | index | state.slots[ *index ].cooldown_remaining( now ).is_none() );
+
+      let chosen = match self.strategy
+      {
+        RotationStrategy::RoundRobin => available.next(),
+        RotationStrategy::LeastRecentlyThrottled => available.min_by_key( | index | state.slots[ *index ].last_throttled ),
+      };
+
+      let Some( index ) = chosen else
+      {
+        let retry_after = state.slots.iter().filter_map( | slot | slot.cooldown_remaining( now ) ).min().unwrap_or_default();
+        return Err( SecretError::AllKeysCoolingDown { retry_after }.into() );
+      };
+
+      state.cursor = ( index + 1 ) % count;
+      let slot = &mut state.slots[ index ];
+      slot.uses += 1;
+      Ok( PooledKey { index, secret : slot.secret.clone() } )
+    }
+
+    /// Takes `key` out of rotation for `cooldown`, e.g. after a 429.
+    ///
+    /// A later call replaces the cooldown rather than extending it.
+    #[ inline ]
+    pub fn mark_throttled( &self, key : &PooledKey, cooldown : Duration )
+    {
+      let now = Instant::now();
+      if let Some( slot ) = self.lock().slots.get_mut( key.index )
+      {
+        slot.throttles += 1;
+        slot.last_throttled = Some( now );
+        slot.cooldown_until = Some( now + cooldown );
+      }
+    }
+
+    /// Ends the cooldown of `key` early.
+    #[ inline ]
+    pub fn clear_cooldown( &self, key : &PooledKey )
+    {
+      if let Some( slot ) = self.lock().slots.get_mut( key.index )
+      {
+        slot.cooldown_until = None;
+      }
+    }
+
+    /// Usage and cooldown of every key, in pool order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn status( &self ) -> Vec< KeyStatus >
+    {
+      let now = Instant::now();
+      self.lock()
+        .slots
+        .iter()
+        .enumerate()
+        .map( | ( index, slot ) | KeyStatus { index, uses : slot.uses, throttles : slot.throttles, cooldown_remaining : slot.cooldown_remaining( now ) } )
+        .collect()
+    }
+
+    fn lock( &self ) -> MutexGuard< '_, State >
+    {
+      self.state.lock().unwrap_or_else( PoisonError::into_inner )
+    }
+  }
+
+  impl fmt::Debug for KeyPool
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "KeyPool" )
+        .field( "strategy", &self.strategy )
+        .field( "keys", &self.status() )
+        .finish_non_exhaustive()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    KeyPool,
+    PooledKey,
+    KeyStatus,
+    RotationStrategy,
+  };
+}
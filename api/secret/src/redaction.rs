@@ -0,0 +1,106 @@
+//! Masking of key material in `Debug` and `Display` output.
+//!
+//! Provider crates print their environments, configs, and generated curl
+//! commands through these helpers, so a key never reaches a log line in
+//! full. At most the first four characters remain — enough to tell an
+//! `sk-proj-` key from an `sk-ant-` one — and only for keys long enough
+//! that four characters give nothing useful away.
+
+mod private
+{
+  use core::fmt;
+
+  /// Characters of a key kept in masked output.
+  const PREFIX_CHARS : usize = 4;
+
+  /// Shortest key that keeps its prefix; shorter keys are masked entirely.
+  const MIN_CHARS_FOR_PREFIX : usize = 16;
+
+  /// Replaces the hidden part of a key.
+  const MASK : &str = "****";
+
+  /// A key that formats as its prefix followed by `****`.
+  ///
+  /// `Debug` quotes the result like a string field would be; `Display`
+  /// does not. An empty key formats as empty, since there is nothing to
+  /// hide and "no key configured" is useful to see.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_secret::Masked;
+  ///
+  /// assert_eq!( Masked( "sk-proj-0123456789abcdef" ).to_string(), "sk-p****" );
+  /// assert_eq!( Masked( "short" ).to_string(), "****" );
+  /// assert_eq!( format!( "{:?}", Masked( "" ) ), "\"\"" );
+  /// ```
+  #[ derive( Clone, Copy ) ]
+  pub struct Masked< 'a >( pub &'a str );
+
+  impl fmt::Display for Masked< '_ >
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      if self.0.is_empty()
+      {
+        return Ok( () );
+      }
+      if self.0.chars().count() >= MIN_CHARS_FOR_PREFIX
+      {
+        self.0.chars().take( PREFIX_CHARS ).try_for_each( | c | fmt::Write::write_char( f, c ) )?;
+      }
+      f.write_str( MASK )
+    }
+  }
+
+  impl fmt::Debug for Masked< '_ >
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "\"{self}\"" )
+    }
+  }
+
+  /// `secret` masked as by [`Masked`].
+  #[ inline ]
+  #[ must_use ]
+  pub fn mask( secret : &str ) -> String
+  {
+    Masked( secret ).to_string()
+  }
+
+  /// `text` with every occurrence of `secret` masked, for output that
+  /// embeds a key, such as a generated curl command.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use api_secret::redact;
+  ///
+  /// let key = "sk-proj-0123456789abcdef";
+  /// let curl = format!( "curl -H 'Authorization: Bearer {key}' https://api.openai.com/v1/models" );
+  /// assert_eq!( redact( &curl, key ), "curl -H 'Authorization: Bearer sk-p****' https://api.openai.com/v1/models" );
+  /// ```
+  #[ inline ]
+  #[ must_use ]
+  pub fn redact( text : &str, secret : &str ) -> String
+  {
+    if secret.is_empty()
+    {
+      return text.to_owned();
+    }
+    text.replace( secret, &mask( secret ) )
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Masked,
+    mask,
+    redact,
+  };
+}
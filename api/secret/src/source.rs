@@ -0,0 +1,241 @@
+//! Places a key can be read from.
+//!
+//! A [`SecretSource`] answers one question — "do you hold this key?" — and
+//! distinguishes "no" (`Ok( None )`, the chain moves on) from "could not
+//! tell" (`Err`, the chain stops). Local sources re-read on every lookup so
+//! edits to a secrets file take effect without a restart.
+
+mod private
+{
+  use crate::error::{ Result, SecretError };
+  use secrecy::SecretString;
+  use std::collections::HashMap;
+  use core::fmt;
+  use std::path::{ Path, PathBuf };
+
+  /// A place a key can be read from.
+  pub trait SecretSource : Send + Sync
+  {
+    /// Short description used in error messages, e.g. `env` or a file path.
+    fn describe( &self ) -> String;
+
+    /// Looks up `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the source exists but cannot be read; a missing
+    /// key or missing file is `Ok( None )`.
+    fn lookup( &self, key : &str ) -> Result< Option< SecretString > >;
+  }
+
+  /// Reads keys from the process environment. Empty values count as unset.
+  #[ derive( Debug, Clone, Copy, Default ) ]
+  pub struct EnvSource;
+
+  impl SecretSource for EnvSource
+  {
+    #[ inline ]
+    fn describe( &self ) -> String
+    {
+      "env".to_string()
+    }
+
+    #[ inline ]
+    fn lookup( &self, key : &str ) -> Result< Option< SecretString > >
+    {
+      Ok( std::env::var( key ).ok().filter( | value | !value.trim().is_empty() ).map( SecretString::from ) )
+    }
+  }
+
+  /// Reads keys from a `.env` or shell secrets file.
+  ///
+  /// Accepts `KEY=value`, `export KEY=value`, single- or double-quoted
+  /// values, blank lines, and `#` comments. A missing file holds no keys.
+  #[ derive( Debug, Clone ) ]
+  pub struct EnvFileSource
+  {
+    path : PathBuf,
+  }
+
+  impl EnvFileSource
+  {
+    /// Source reading `path`.
+    #[ inline ]
+    pub fn new( path : impl Into< PathBuf > ) -> Self
+    {
+      Self { path : path.into() }
+    }
+
+    /// Path of the file this source reads.
+    #[ inline ]
+    #[ must_use ]
+    pub fn path( &self ) -> &Path
+    {
+      &self.path
+    }
+  }
+
+  impl SecretSource for EnvFileSource
+  {
+    #[ inline ]
+    fn describe( &self ) -> String
+    {
+      self.path.display().to_string()
+    }
+
+    #[ inline ]
+    fn lookup( &self, key : &str ) -> Result< Option< SecretString > >
+    {
+      let content = match std::fs::read_to_string( &self.path )
+      {
+        Ok( content ) => content,
+        Err( e ) if e.kind() == std::io::ErrorKind::NotFound => return Ok( None ),
+        Err( e ) => return Err( SecretError::Unreadable { source_name : self.describe(), reason : e.to_string() }.into() ),
+      };
+
+      Ok( parse_env_file( &content ).remove( key ).filter( | value | !value.is_empty() ).map( SecretString::from ) )
+    }
+  }
+
+  /// Parses `.env` / shell `export` syntax into key-value pairs.
+  ///
+  /// Lines that are not assignments are ignored; a later assignment of the
+  /// same key wins, as it would when the file is sourced.
+  #[ inline ]
+  #[ must_use ]
+  pub fn parse_env_file( content : &str ) -> HashMap< String, String >
+  {
+    content
+      .lines()
+      .filter_map( | line |
+      {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with( '#' )
+        {
+          return None;
+        }
+        let line = line.strip_prefix( "export " ).map_or( line, str::trim_start );
+        let ( key, value ) = line.split_once( '=' )?;
+        let key = key.trim();
+        if key.is_empty() || key.contains( char::is_whitespace )
+        {
+          return None;
+        }
+        Some( ( key.to_string(), unquote( value.trim() ).to_string() ) )
+      } )
+      .collect()
+  }
+
+  /// Strips one pair of matching quotes, or a trailing ` #` comment from an
+  /// unquoted value.
+  fn unquote( value : &str ) -> &str
+  {
+    for quote in [ '"', '\'' ]
+    {
+      if let Some( inner ) = value.strip_prefix( quote )
+      {
+        if let Some( end ) = inner.find( quote )
+        {
+          return &inner[ ..end ];
+        }
+      }
+    }
+    value.split_once( " #" ).map_or( value, | ( value, _ ) | value.trim_end() )
+  }
+
+  /// Keys held in memory, typically the result of a remote backend `fetch`.
+  #[ derive( Clone, Default ) ]
+  pub struct MapSource
+  {
+    name : String,
+    entries : HashMap< String, SecretString >,
+  }
+
+  impl MapSource
+  {
+    /// Empty source described as `name` in error messages.
+    #[ inline ]
+    pub fn new( name : impl Into< String > ) -> Self
+    {
+      Self { name : name.into(), entries : HashMap::new() }
+    }
+
+    /// Adds or replaces `key`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with( mut self, key : impl Into< String >, value : impl Into< String > ) -> Self
+    {
+      self.insert( key, value );
+      self
+    }
+
+    /// Adds or replaces `key`.
+    #[ inline ]
+    pub fn insert( &mut self, key : impl Into< String >, value : impl Into< String > )
+    {
+      self.entries.insert( key.into(), SecretString::from( value.into() ) );
+    }
+
+    /// Names of the keys held, in arbitrary order.
+    #[ inline ]
+    pub fn keys( &self ) -> impl Iterator< Item = &str >
+    {
+      self.entries.keys().map( String::as_str )
+    }
+
+    /// Number of keys held.
+    #[ inline ]
+    #[ must_use ]
+    pub fn len( &self ) -> usize
+    {
+      self.entries.len()
+    }
+
+    /// `true` when no keys are held.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.entries.is_empty()
+    }
+  }
+
+  impl fmt::Debug for MapSource
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "MapSource" )
+        .field( "name", &self.name )
+        .field( "keys", &self.entries.keys().collect::< Vec< _ > >() )
+        .finish()
+    }
+  }
+
+  impl SecretSource for MapSource
+  {
+    #[ inline ]
+    fn describe( &self ) -> String
+    {
+      self.name.clone()
+    }
+
+    #[ inline ]
+    fn lookup( &self, key : &str ) -> Result< Option< SecretString > >
+    {
+      Ok( self.entries.get( key ).cloned() )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SecretSource,
+    EnvSource,
+    EnvFileSource,
+    MapSource,
+    parse_env_file,
+  };
+}
@@ -0,0 +1,161 @@
+//! Vault KV v2 backend.
+//!
+//! [`VaultBackend::fetch`] reads one secret path
+//! (`GET {address}/v1/{mount}/data/{path}`) and returns its string fields as
+//! a [`MapSource`]. Nothing is fetched until `fetch` is called, and nothing
+//! is refreshed afterwards.
+
+mod private
+{
+  use crate::error::{ Result, SecretError };
+  use crate::source::MapSource;
+  use secrecy::{ ExposeSecret, SecretString };
+  use core::fmt;
+
+  const BACKEND : &str = "vault";
+
+  /// Client for a Vault KV v2 secrets engine.
+  #[ derive( Clone ) ]
+  pub struct VaultBackend
+  {
+    address : String,
+    token : SecretString,
+    mount : String,
+    namespace : Option< String >,
+    http : reqwest::Client,
+  }
+
+  impl VaultBackend
+  {
+    /// Backend for the server at `address` (e.g. `https://vault.internal:8200`),
+    /// reading from the default `secret` mount.
+    #[ inline ]
+    pub fn new( address : impl Into< String >, token : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        address : address.into().trim_end_matches( '/' ).to_string(),
+        token : SecretString::from( token.into() ),
+        mount : "secret".to_string(),
+        namespace : None,
+        http : reqwest::Client::new(),
+      }
+    }
+
+    /// Backend configured from `VAULT_ADDR`, `VAULT_TOKEN`, and the optional
+    /// `VAULT_NAMESPACE`, the variables the `vault` CLI reads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::MissingConfiguration`] when `VAULT_ADDR` or
+    /// `VAULT_TOKEN` is unset.
+    #[ inline ]
+    pub fn from_env() -> Result< Self >
+    {
+      let var = | name : &str | std::env::var( name ).map_err( | _ | SecretError::MissingConfiguration( format!( "{name} is not set" ) ) );
+      let backend = Self::new( var( "VAULT_ADDR" )?, var( "VAULT_TOKEN" )? );
+      Ok( match std::env::var( "VAULT_NAMESPACE" )
+      {
+        Ok( namespace ) if !namespace.is_empty() => backend.with_namespace( namespace ),
+        _ => backend,
+      } )
+    }
+
+    /// Reads from the KV v2 engine mounted at `mount` instead of `secret`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_mount( mut self, mount : impl Into< String > ) -> Self
+    {
+      self.mount = mount.into().trim_matches( '/' ).to_string();
+      self
+    }
+
+    /// Sends `X-Vault-Namespace` (Vault Enterprise / HCP Vault).
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_namespace( mut self, namespace : impl Into< String > ) -> Self
+    {
+      self.namespace = Some( namespace.into() );
+      self
+    }
+
+    /// Uses `http` for requests, e.g. one configured with a private CA.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_http_client( mut self, http : reqwest::Client ) -> Self
+    {
+      self.http = http;
+      self
+    }
+
+    /// URL of the latest version of `path`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn url( &self, path : &str ) -> String
+    {
+      format!( "{}/v1/{}/data/{}", self.address, self.mount, path.trim_matches( '/' ) )
+    }
+
+    /// Reads the secret at `path` and returns its string fields.
+    ///
+    /// Non-string fields (numbers, nested objects) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretError::Backend`] when the request fails, Vault answers
+    /// with a non-success status, or the body is not a KV v2 read response.
+    #[ inline ]
+    pub async fn fetch( &self, path : &str ) -> Result< MapSource >
+    {
+      let backend_error = | reason : String | SecretError::Backend { backend : BACKEND, reason };
+
+      let mut request = self.http
+        .get( self.url( path ) )
+        .header( "X-Vault-Token", self.token.expose_secret() );
+      if let Some( namespace ) = &self.namespace
+      {
+        request = request.header( "X-Vault-Namespace", namespace );
+      }
+
+      let response = request.send().await.map_err( | e | backend_error( e.to_string() ) )?;
+      let status = response.status();
+      if !status.is_success()
+      {
+        let body = response.text().await.unwrap_or_default();
+        return Err( backend_error( format!( "{status} reading {} : {body}", self.url( path ) ) ).into() );
+      }
+
+      let body : serde_json::Value = response.json().await.map_err( | e | backend_error( e.to_string() ) )?;
+      let data = body
+        .pointer( "/data/data" )
+        .and_then( serde_json::Value::as_object )
+        .ok_or_else( || backend_error( format!( "response for {path} has no data.data object" ) ) )?;
+
+      Ok( data
+        .iter()
+        .filter_map( | ( key, value ) | value.as_str().map( | value | ( key, value ) ) )
+        .fold( MapSource::new( format!( "vault {}/{}", self.mount, path.trim_matches( '/' ) ) ), | source, ( key, value ) | source.with( key.as_str(), value ) ) )
+    }
+  }
+
+  impl fmt::Debug for VaultBackend
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "VaultBackend" )
+        .field( "address", &self.address )
+        .field( "mount", &self.mount )
+        .field( "namespace", &self.namespace )
+        .finish_non_exhaustive()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    VaultBackend,
+  };
+}
@@ -0,0 +1,64 @@
+//! Workspace `secret/` directory source.
+//!
+//! Resolves the workspace root with `workspace_tools` and reads
+//! `secret/<filename>` with the same parser as [`EnvFileSource`]. Unlike
+//! `Workspace::load_secret_key`, it never falls back to the environment on
+//! its own; ordering belongs to the [`SecretChain`](crate::SecretChain).
+
+mod private
+{
+  use crate::error::Result;
+  use crate::source::{ EnvFileSource, SecretSource };
+  use secrecy::SecretString;
+
+  /// Reads keys from `<workspace>/secret/<filename>`.
+  ///
+  /// When no workspace can be resolved the source holds no keys.
+  #[ derive( Debug, Clone ) ]
+  pub struct WorkspaceSource
+  {
+    filename : String,
+  }
+
+  impl WorkspaceSource
+  {
+    /// Source reading `secret/<filename>`, e.g. `-secrets.sh`.
+    #[ inline ]
+    pub fn new( filename : impl Into< String > ) -> Self
+    {
+      Self { filename : filename.into() }
+    }
+
+    fn file( &self ) -> Option< EnvFileSource >
+    {
+      workspace_tools::workspace().ok().map( | ws | EnvFileSource::new( ws.secret_file( &self.filename ) ) )
+    }
+  }
+
+  impl SecretSource for WorkspaceSource
+  {
+    #[ inline ]
+    fn describe( &self ) -> String
+    {
+      self.file().map_or_else( || format!( "secret/{}", self.filename ), | file | file.describe() )
+    }
+
+    #[ inline ]
+    fn lookup( &self, key : &str ) -> Result< Option< SecretString > >
+    {
+      match self.file()
+      {
+        Some( file ) => file.lookup( key ),
+        None => Ok( None ),
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    WorkspaceSource,
+  };
+}
@@ -0,0 +1,54 @@
+//! Tests for AWS Signature Version 4 signing.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | signs_aws_reference_request | sigv4 | matches the signature from the AWS SigV4 documentation example |
+//! | debug_hides_secret_key | redaction | `Debug` omits the secret access key and session token |
+
+#![ cfg( feature = "aws" ) ]
+
+use api_secret::{ AwsCredentials, SigningRequest };
+
+#[ test ]
+fn signs_aws_reference_request()
+{
+  let credentials = AwsCredentials::new( "AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY" );
+  let headers =
+  [
+    ( "Content-Type", "application/x-www-form-urlencoded; charset=utf-8" ),
+    ( "Host", "iam.amazonaws.com" ),
+    ( "X-Amz-Date", "20150830T123600Z" ),
+  ];
+  let request = SigningRequest
+  {
+    method : "GET",
+    path : "/",
+    query : "Action=ListUsers&Version=2010-05-08",
+    headers : &headers,
+    payload : b"",
+  };
+
+  let authorization = credentials.authorization( &request, "us-east-1", "iam", "20150830T123600Z" );
+
+  assert_eq!
+  (
+    authorization,
+    "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/iam/aws4_request, \
+     SignedHeaders=content-type;host;x-amz-date, \
+     Signature=5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7",
+  );
+}
+
+#[ test ]
+fn debug_hides_secret_key()
+{
+  let credentials = AwsCredentials::new( "AKIDEXAMPLE", "super-secret-key" ).with_session_token( "session-token-value" );
+
+  let debug = format!( "{credentials:?}" );
+
+  assert!( debug.contains( "AKIDEXAMPLE" ) );
+  assert!( !debug.contains( "super-secret-key" ) );
+  assert!( !debug.contains( "session-token-value" ) );
+}
@@ -0,0 +1,91 @@
+//! Tests for the fallback chain.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | first_source_holding_key_wins | order | earlier source shadows later ones |
+//! | falls_through_to_later_source | order | key missing early is found later |
+//! | not_found_lists_every_source | error | `NotFound` names the key and all sources tried |
+//! | unreadable_source_stops_chain | error | a source error is returned, not skipped |
+//! | standard_chain_order | standard | workspace files precede the environment |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_secret::{ MapSource, SecretChain, SecretError, SecretSource };
+use secrecy::{ ExposeSecret, SecretString };
+
+#[ test ]
+fn first_source_holding_key_wins() -> error_tools::untyped::Result< () >
+{
+  let chain = SecretChain::new()
+    .with( MapSource::new( "vault" ).with( "OPENAI_API_KEY", "sk-from-vault" ) )
+    .with( MapSource::new( "file" ).with( "OPENAI_API_KEY", "sk-from-file" ) );
+
+  assert_eq!( chain.load( "OPENAI_API_KEY" )?.expose_secret(), "sk-from-vault" );
+  Ok( () )
+}
+
+#[ test ]
+fn falls_through_to_later_source() -> error_tools::untyped::Result< () >
+{
+  let chain = SecretChain::new()
+    .with( MapSource::new( "vault" ) )
+    .then( SecretChain::new().with( MapSource::new( "file" ).with( "XAI_API_KEY", "xai-from-file" ) ) );
+
+  assert_eq!( chain.load( "XAI_API_KEY" )?.expose_secret(), "xai-from-file" );
+  Ok( () )
+}
+
+#[ test ]
+fn not_found_lists_every_source()
+{
+  let chain = SecretChain::new().with( MapSource::new( "first" ) ).with( MapSource::new( "second" ) );
+
+  let error = chain.load( "MISSING_KEY" ).expect_err( "key is in no source" );
+
+  assert_eq!
+  (
+    error.downcast_ref::< SecretError >(),
+    Some( &SecretError::NotFound { key : "MISSING_KEY".to_string(), tried : vec![ "first".to_string(), "second".to_string() ] } ),
+  );
+  assert_eq!( error.to_string(), "MISSING_KEY not found in any source (first, second)" );
+}
+
+struct Unreadable;
+
+impl SecretSource for Unreadable
+{
+  fn describe( &self ) -> String
+  {
+    "unreadable".to_string()
+  }
+
+  fn lookup( &self, _key : &str ) -> error_tools::untyped::Result< Option< SecretString > >
+  {
+    Err( SecretError::Unreadable { source_name : self.describe(), reason : "permission denied".to_string() }.into() )
+  }
+}
+
+#[ test ]
+fn unreadable_source_stops_chain()
+{
+  let chain = SecretChain::new().with( Unreadable ).with( MapSource::new( "later" ).with( "KEY", "value" ) );
+
+  let error = chain.load( "KEY" ).expect_err( "first source fails" );
+
+  assert!( matches!( error.downcast_ref::< SecretError >(), Some( SecretError::Unreadable { .. } ) ) );
+}
+
+#[ cfg( feature = "workspace" ) ]
+#[ test ]
+fn standard_chain_order()
+{
+  let described = SecretChain::standard().describe();
+  let file_names : Vec< _ > = described
+    .iter()
+    .map( | source | std::path::Path::new( source ).file_name().and_then( std::ffi::OsStr::to_str ).unwrap_or_default() )
+    .collect();
+
+  assert_eq!( file_names, [ "-secrets.sh", "secrets.sh", ".env", "env" ] );
+}
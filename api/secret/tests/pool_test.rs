@@ -0,0 +1,137 @@
+//! Tests for multi-key rotation.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | empty_pool_is_rejected | construct | a pool needs at least one key |
+//! | round_robin_cycles_keys | rotation | keys are handed out in order and wrap |
+//! | throttled_key_is_skipped | cooldown | a key in cooldown is not handed out |
+//! | all_cooling_down_reports_retry_after | cooldown | exhausted pool returns the shortest remaining cooldown |
+//! | clear_cooldown_restores_key | cooldown | an early clear returns the key to rotation |
+//! | least_recently_throttled_prefers_clean_key | rotation | never-throttled keys are preferred |
+//! | load_numbered_reads_suffixed_keys | load | `NAME`, `NAME_2`, … are loaded until a gap |
+//! | debug_hides_keys | redaction | `Debug` shows indices and counters only |
+
+#![ cfg( feature = "pool" ) ]
+
+use api_secret::{ ExposeSecret, KeyPool, MapSource, RotationStrategy, SecretChain, SecretError };
+use core::time::Duration;
+
+fn take( pool : &KeyPool ) -> String
+{
+  pool.acquire().expect( "key available" ).secret().expose_secret().to_string()
+}
+
+#[ test ]
+fn empty_pool_is_rejected()
+{
+  let error = KeyPool::new( Vec::< String >::new() ).expect_err( "no keys" );
+
+  assert!( matches!( error.downcast_ref::< SecretError >(), Some( SecretError::MissingConfiguration( _ ) ) ) );
+}
+
+#[ test ]
+fn round_robin_cycles_keys() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "a", "b", "c" ] )?;
+
+  let handed : Vec< _ > = ( 0..4 ).map( | _ | take( &pool ) ).collect();
+
+  assert_eq!( handed, [ "a", "b", "c", "a" ] );
+  assert_eq!( pool.status().iter().map( | status | status.uses ).collect::< Vec< _ > >(), [ 2, 1, 1 ] );
+  Ok( () )
+}
+
+#[ test ]
+fn throttled_key_is_skipped() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "a", "b" ] )?;
+  let first = pool.acquire()?;
+  pool.mark_throttled( &first, Duration::from_secs( 60 ) );
+
+  let handed : Vec< _ > = ( 0..3 ).map( | _ | take( &pool ) ).collect();
+
+  assert_eq!( handed, [ "b", "b", "b" ] );
+  assert_eq!( pool.status()[ 0 ].throttles, 1 );
+  assert!( pool.status()[ 0 ].cooldown_remaining.is_some() );
+  Ok( () )
+}
+
+#[ test ]
+fn all_cooling_down_reports_retry_after() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "a", "b" ] )?;
+  let first = pool.acquire()?;
+  let second = pool.acquire()?;
+  pool.mark_throttled( &first, Duration::from_secs( 60 ) );
+  pool.mark_throttled( &second, Duration::from_secs( 5 ) );
+
+  let error = pool.acquire().expect_err( "every key is cooling down" );
+
+  match error.downcast_ref::< SecretError >()
+  {
+    Some( SecretError::AllKeysCoolingDown { retry_after } ) => assert!( *retry_after <= Duration::from_secs( 5 ) ),
+    other => panic!( "unexpected error {other:?}" ),
+  }
+  Ok( () )
+}
+
+#[ test ]
+fn clear_cooldown_restores_key() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "only" ] )?;
+  let key = pool.acquire()?;
+  pool.mark_throttled( &key, Duration::from_secs( 60 ) );
+  assert!( pool.acquire().is_err() );
+
+  pool.clear_cooldown( &key );
+
+  assert_eq!( take( &pool ), "only" );
+  Ok( () )
+}
+
+#[ test ]
+fn least_recently_throttled_prefers_clean_key() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "a", "b", "c" ] )?.with_strategy( RotationStrategy::LeastRecentlyThrottled );
+  let a = pool.acquire()?;
+  let b = pool.acquire()?;
+  pool.mark_throttled( &b, Duration::ZERO );
+  pool.mark_throttled( &a, Duration::ZERO );
+
+  let handed : Vec< _ > = ( 0..3 ).map( | _ | take( &pool ) ).collect();
+
+  assert_eq!( handed, [ "c", "c", "c" ] );
+  Ok( () )
+}
+
+#[ test ]
+fn load_numbered_reads_suffixed_keys() -> error_tools::untyped::Result< () >
+{
+  let chain = SecretChain::new().with
+  (
+    MapSource::new( "fixture" )
+      .with( "OPENAI_API_KEY", "sk-1" )
+      .with( "OPENAI_API_KEY_2", "sk-2" )
+      .with( "OPENAI_API_KEY_4", "sk-4" ),
+  );
+
+  let pool = KeyPool::load_numbered( &chain, "OPENAI_API_KEY" )?;
+
+  assert_eq!( pool.len(), 2 );
+  assert_eq!( take( &pool ), "sk-1" );
+  assert_eq!( take( &pool ), "sk-2" );
+  Ok( () )
+}
+
+#[ test ]
+fn debug_hides_keys() -> error_tools::untyped::Result< () >
+{
+  let pool = KeyPool::new( [ "sk-very-secret" ] )?;
+  let key = pool.acquire()?;
+
+  assert!( !format!( "{pool:?}" ).contains( "sk-very-secret" ) );
+  assert!( !format!( "{key:?}" ).contains( "sk-very-secret" ) );
+  Ok( () )
+}
@@ -0,0 +1,66 @@
+//! Tests for key masking.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | long_key_keeps_prefix | mask | keys of 16+ characters keep four characters |
+//! | short_key_is_fully_masked | mask | shorter keys keep nothing |
+//! | empty_key_stays_empty | mask | an unset key is visible as empty |
+//! | debug_is_quoted | format | `Debug` quotes like a string field |
+//! | multibyte_prefix_is_char_aligned | mask | the prefix never splits a character |
+//! | redact_replaces_every_occurrence | redact | each copy of the key in a text is masked |
+//! | redact_with_empty_secret_is_identity | redact | an empty key leaves the text unchanged |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_secret::{ mask, redact, Masked };
+
+const KEY : &str = "sk-proj-0123456789abcdefghij";
+
+#[ test ]
+fn long_key_keeps_prefix()
+{
+  assert_eq!( mask( KEY ), "sk-p****" );
+  assert_eq!( mask( "0123456789abcdef" ), "0123****" );
+}
+
+#[ test ]
+fn short_key_is_fully_masked()
+{
+  assert_eq!( mask( "0123456789abcde" ), "****" );
+  assert_eq!( mask( "x" ), "****" );
+}
+
+#[ test ]
+fn empty_key_stays_empty()
+{
+  assert_eq!( mask( "" ), "" );
+}
+
+#[ test ]
+fn debug_is_quoted()
+{
+  assert_eq!( format!( "{:?}", Masked( KEY ) ), "\"sk-p****\"" );
+}
+
+#[ test ]
+fn multibyte_prefix_is_char_aligned()
+{
+  assert_eq!( mask( "ключ-0123456789abcdef" ), "ключ****" );
+}
+
+#[ test ]
+fn redact_replaces_every_occurrence()
+{
+  let text = format!( "-H 'Authorization: Bearer {KEY}' -H 'x-api-key: {KEY}'" );
+  let redacted = redact( &text, KEY );
+  assert!( !redacted.contains( KEY ) );
+  assert_eq!( redacted, "-H 'Authorization: Bearer sk-p****' -H 'x-api-key: sk-p****'" );
+}
+
+#[ test ]
+fn redact_with_empty_secret_is_identity()
+{
+  assert_eq!( redact( "curl https://example.com", "" ), "curl https://example.com" );
+}
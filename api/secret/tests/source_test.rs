@@ -0,0 +1,92 @@
+//! Tests for the local secret sources.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | parses_shell_and_dotenv_syntax | parse | `export`, quotes, comments, and blank lines |
+//! | later_assignment_wins | parse | repeated key keeps the last value |
+//! | env_file_source_reads_key | file | key present in the file is returned |
+//! | missing_env_file_holds_no_keys | file | absent file is `Ok( None )`, not an error |
+//! | env_source_ignores_empty_value | env | empty variable counts as unset |
+//! | map_source_debug_hides_values | map | `Debug` lists key names only |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_secret::{ parse_env_file, EnvFileSource, EnvSource, MapSource, SecretSource };
+use secrecy::ExposeSecret;
+
+#[ test ]
+fn parses_shell_and_dotenv_syntax()
+{
+  let parsed = parse_env_file
+  (
+    "#!/bin/bash\n\
+     # comment\n\
+     \n\
+     export OPENAI_API_KEY=\"sk-double\"\n\
+     ANTHROPIC_API_KEY='sk-ant-single'\n\
+     XAI_API_KEY=xai-plain # trailing comment\n\
+     echo not an assignment\n",
+  );
+
+  assert_eq!( parsed.len(), 3 );
+  assert_eq!( parsed[ "OPENAI_API_KEY" ], "sk-double" );
+  assert_eq!( parsed[ "ANTHROPIC_API_KEY" ], "sk-ant-single" );
+  assert_eq!( parsed[ "XAI_API_KEY" ], "xai-plain" );
+}
+
+#[ test ]
+fn later_assignment_wins()
+{
+  let parsed = parse_env_file( "KEY=first\nKEY=second\n" );
+
+  assert_eq!( parsed[ "KEY" ], "second" );
+}
+
+#[ test ]
+fn env_file_source_reads_key() -> error_tools::untyped::Result< () >
+{
+  let dir = tempfile::tempdir()?;
+  let path = dir.path().join( "-secrets.sh" );
+  std::fs::write( &path, "export GEMINI_API_KEY=\"AIza-test\"\n" )?;
+  let source = EnvFileSource::new( &path );
+
+  let value = source.lookup( "GEMINI_API_KEY" )?;
+
+  assert_eq!( value.as_ref().map( ExposeSecret::expose_secret ), Some( "AIza-test" ) );
+  assert!( source.lookup( "OTHER_KEY" )?.is_none() );
+  Ok( () )
+}
+
+#[ test ]
+fn missing_env_file_holds_no_keys() -> error_tools::untyped::Result< () >
+{
+  let dir = tempfile::tempdir()?;
+  let source = EnvFileSource::new( dir.path().join( "absent.env" ) );
+
+  assert!( source.lookup( "ANY_KEY" )?.is_none() );
+  Ok( () )
+}
+
+#[ test ]
+fn env_source_ignores_empty_value() -> error_tools::untyped::Result< () >
+{
+  std::env::set_var( "API_SECRET_TEST_EMPTY", "" );
+  std::env::set_var( "API_SECRET_TEST_SET", "value" );
+
+  assert!( EnvSource.lookup( "API_SECRET_TEST_EMPTY" )?.is_none() );
+  assert_eq!( EnvSource.lookup( "API_SECRET_TEST_SET" )?.as_ref().map( ExposeSecret::expose_secret ), Some( "value" ) );
+  Ok( () )
+}
+
+#[ test ]
+fn map_source_debug_hides_values()
+{
+  let source = MapSource::new( "fixture" ).with( "OPENAI_API_KEY", "sk-very-secret" );
+
+  let debug = format!( "{source:?}" );
+
+  assert!( debug.contains( "OPENAI_API_KEY" ) );
+  assert!( !debug.contains( "sk-very-secret" ) );
+}
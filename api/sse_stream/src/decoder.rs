@@ -0,0 +1,289 @@
+//! Byte-level Server-Sent Events framing.
+//!
+//! Implements the event-stream interpretation of the WHATWG HTML standard:
+//!
+//! - lines end with `\n`, `\r\n`, or a lone `\r`;
+//! - a line starting with `:` is a comment — servers send `: keep-alive` or
+//!   `: ping` to hold idle connections open;
+//! - `field: value` lines set `event`, `data`, `id`, or `retry`; one space
+//!   after the colon is removed, unknown fields are ignored;
+//! - consecutive `data` lines are joined with `\n`;
+//! - a blank line dispatches the event, unless it carried no `data`.
+//!
+//! [`SseDecoder`] accepts bytes in chunks of any size. Lines are decoded only
+//! once complete, so a UTF-8 character split across two chunks is never
+//! mangled; invalid UTF-8 becomes U+FFFD as the standard requires.
+
+mod private
+{
+  use std::collections::VecDeque;
+
+  /// Event type of events sent without an `event` field.
+  const DEFAULT_EVENT : &str = "message";
+
+  /// UTF-8 byte order mark, skipped at the start of a stream.
+  const BOM : &[ u8 ] = b"\xEF\xBB\xBF";
+
+  /// One dispatched event.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct SseEvent
+  {
+    /// `event` field, or `"message"` when the event had none.
+    pub event : String,
+    /// `data` lines joined with `\n`.
+    pub data : String,
+    /// Last event id in effect at dispatch; ids persist across events until
+    /// the server sends a new one.
+    pub id : Option< String >,
+    /// Reconnection delay in milliseconds sent since the previous event.
+    pub retry : Option< u64 >,
+  }
+
+  impl SseEvent
+  {
+    /// A `message` event with the given data.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( data : impl Into< String > ) -> Self
+    {
+      Self { event : DEFAULT_EVENT.to_owned(), data : data.into(), id : None, retry : None }
+    }
+
+    /// Sets the event type.
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_event( mut self, event : impl Into< String > ) -> Self
+    {
+      self.event = event.into();
+      self
+    }
+
+    /// Whether the data is the `[DONE]` marker `OpenAI`-style APIs send
+    /// after the last chunk.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_done( &self ) -> bool
+    {
+      self.data == "[DONE]"
+    }
+  }
+
+  /// Incremental decoder of a `text/event-stream` body.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "enabled" ) ]
+  /// # {
+  /// use api_sse_stream::SseDecoder;
+  ///
+  /// let mut decoder = SseDecoder::new();
+  /// decoder.push( b": keep-alive\n\nevent: delta\ndata: {\"text\":\"h" );
+  /// assert!( decoder.next_event().is_none() );
+  /// decoder.push( b"i\"}\r\n\r\n" );
+  ///
+  /// let event = decoder.next_event().unwrap();
+  /// assert_eq!( event.event, "delta" );
+  /// assert_eq!( event.data, r#"{"text":"hi"}"# );
+  /// assert_eq!( decoder.comments(), 1 );
+  /// # }
+  /// ```
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct SseDecoder
+  {
+    /// Bytes of the line not yet terminated.
+    line : Vec< u8 >,
+    /// The previous chunk ended with `\r`; a leading `\n` belongs to it.
+    skip_lf : bool,
+    /// The first line has been seen, so no byte order mark can follow.
+    started : bool,
+    /// `event` field of the pending event.
+    event : Option< String >,
+    /// `data` of the pending event.
+    data : String,
+    /// Whether the pending event had a `data` line.
+    has_data : bool,
+    /// `retry` received since the last dispatch.
+    retry : Option< u64 >,
+    /// Last event id buffer.
+    last_event_id : Option< String >,
+    /// Comment lines seen.
+    comments : u64,
+    /// Dispatched events not yet taken.
+    ready : VecDeque< SseEvent >,
+  }
+
+  impl SseDecoder
+  {
+    /// An empty decoder.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Decodes a whole body at once, including a final event the server did
+    /// not terminate with a blank line.
+    #[ inline ]
+    #[ must_use ]
+    pub fn decode_all( bytes : &[ u8 ] ) -> Vec< SseEvent >
+    {
+      let mut decoder = Self::new();
+      decoder.push( bytes );
+      decoder.finish();
+      decoder.ready.into_iter().collect()
+    }
+
+    /// Appends bytes as they arrive; chunks may split lines, `\r\n` pairs,
+    /// and UTF-8 characters anywhere.
+    #[ inline ]
+    pub fn push( &mut self, mut bytes : &[ u8 ] )
+    {
+      if self.skip_lf && !bytes.is_empty()
+      {
+        self.skip_lf = false;
+        if bytes[ 0 ] == b'\n'
+        {
+          bytes = &bytes[ 1.. ];
+        }
+      }
+      while let Some( end ) = bytes.iter().position( | &b | b == b'\n' || b == b'\r' )
+      {
+        self.line.extend_from_slice( &bytes[ ..end ] );
+        self.end_line();
+        let crlf = bytes[ end ] == b'\r';
+        bytes = &bytes[ end + 1.. ];
+        if crlf
+        {
+          match bytes.first()
+          {
+            Some( b'\n' ) => bytes = &bytes[ 1.. ],
+            Some( _ ) => {},
+            None => self.skip_lf = true,
+          }
+        }
+      }
+      self.line.extend_from_slice( bytes );
+    }
+
+    /// Ends the stream: an unterminated last line is processed and a
+    /// pending event with data is dispatched. Servers often close the
+    /// connection without the final blank line.
+    #[ inline ]
+    pub fn finish( &mut self )
+    {
+      if !self.line.is_empty()
+      {
+        self.end_line();
+      }
+      self.dispatch();
+      self.skip_lf = false;
+    }
+
+    /// The next dispatched event, in arrival order.
+    #[ inline ]
+    pub fn next_event( &mut self ) -> Option< SseEvent >
+    {
+      self.ready.pop_front()
+    }
+
+    /// Last event id received, to send as `Last-Event-ID` when reconnecting.
+    #[ inline ]
+    #[ must_use ]
+    pub fn last_event_id( &self ) -> Option< &str >
+    {
+      self.last_event_id.as_deref()
+    }
+
+    /// Number of comment lines seen, e.g. keep-alives.
+    #[ inline ]
+    #[ must_use ]
+    pub fn comments( &self ) -> u64
+    {
+      self.comments
+    }
+
+    /// Bytes of the unterminated line held back.
+    #[ inline ]
+    #[ must_use ]
+    pub fn buffered_len( &self ) -> usize
+    {
+      self.line.len()
+    }
+
+    /// Decodes and interprets the buffered line.
+    fn end_line( &mut self )
+    {
+      let mut bytes = core::mem::take( &mut self.line );
+      if !self.started
+      {
+        self.started = true;
+        if bytes.starts_with( BOM )
+        {
+          bytes.drain( ..BOM.len() );
+        }
+      }
+      let line = String::from_utf8_lossy( &bytes );
+      if line.is_empty()
+      {
+        self.dispatch();
+        return;
+      }
+      if line.starts_with( ':' )
+      {
+        self.comments += 1;
+        return;
+      }
+      let ( field, value ) = match line.split_once( ':' )
+      {
+        Some( ( field, value ) ) => ( field, value.strip_prefix( ' ' ).unwrap_or( value ) ),
+        None => ( line.as_ref(), "" ),
+      };
+      match field
+      {
+        "event" => self.event = Some( value.to_owned() ),
+        "data" =>
+        {
+          if self.has_data
+          {
+            self.data.push( '\n' );
+          }
+          self.data.push_str( value );
+          self.has_data = true;
+        },
+        "id" if !value.contains( '\0' ) =>
+          self.last_event_id = Some( value.to_owned() ).filter( | id | !id.is_empty() ),
+        "retry" if !value.is_empty() && value.bytes().all( | b | b.is_ascii_digit() ) =>
+          self.retry = value.parse().ok(),
+        _ => {},
+      }
+    }
+
+    /// Queues the pending event if it had data, then resets it.
+    fn dispatch( &mut self )
+    {
+      let event = self.event.take();
+      if !core::mem::take( &mut self.has_data )
+      {
+        return;
+      }
+      self.ready.push_back( SseEvent
+      {
+        event : event.unwrap_or_else( || DEFAULT_EVENT.to_owned() ),
+        data : core::mem::take( &mut self.data ),
+        id : self.last_event_id.clone(),
+        retry : self.retry.take(),
+      });
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SseEvent,
+    SseDecoder,
+  };
+}
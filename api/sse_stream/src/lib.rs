@@ -0,0 +1,35 @@
+//! Incremental Server-Sent Events decoder.
+//!
+//! Every streaming provider API except Bedrock answers with
+//! `text/event-stream`, Gemini when asked with `alt=sse`. This crate holds the one decoder the provider crates
+//! read those bodies through, so framing, comments, line endings, and chunk
+//! boundaries are handled identically everywhere.
+//!
+//! # Features
+//!
+//! - `enabled` — activates [`SseDecoder`] and [`SseEvent`]
+//! - `stream` — `SseStream`, an adapter over any `futures_core::Stream` of
+//!   byte chunks, and the `SseStreamExt::sse_events` extension method
+//! - `full` — enables every feature above
+//!
+//! # Architecture
+//!
+//! Follows the "Thin Client, Rich API" principle: the decoder performs no
+//! I/O, never retries or reconnects, and does not interpret event data —
+//! `[DONE]` markers and JSON payloads are left to the provider crate.
+
+#[ cfg( feature = "enabled" ) ]
+use mod_interface::mod_interface;
+
+mod private {}
+
+#[ cfg( feature = "enabled" ) ]
+crate::mod_interface!
+{
+  /// Byte-level event framing.
+  layer decoder;
+
+  /// Adapter over async byte streams.
+  #[ cfg( feature = "stream" ) ]
+  layer stream;
+}
@@ -0,0 +1,138 @@
+//! Adapter from an async stream of byte chunks to a stream of events.
+//!
+//! Any `futures_core::Stream< Item = Result< B, E > >` with `B : AsRef<[u8]>`
+//! works, so `reqwest::Response::bytes_stream()` plugs in directly. Transport
+//! errors pass through unchanged as `Err( E )`; decoding itself cannot fail.
+
+mod private
+{
+  use crate::decoder::{ SseDecoder, SseEvent };
+  use core::pin::Pin;
+  use core::task::{ ready, Context, Poll };
+  use futures_core::Stream;
+
+  /// Stream of [`SseEvent`]s decoded from a stream of byte chunks.
+  ///
+  /// Ends when the inner stream ends, after dispatching a final event left
+  /// without its blank line. Keeps polling after an `Err`, so the caller
+  /// decides whether a transport error ends the read.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// # #[ cfg( feature = "stream" ) ]
+  /// # {
+  /// use api_sse_stream::SseStreamExt;
+  /// use futures::StreamExt;
+  ///
+  /// let chunks = futures::stream::iter( [ Ok::< _, std::io::Error >( "data: {\"n\":1}\n" ), Ok( "\ndata: [DONE]\n\n" ) ] );
+  /// let events : Vec< _ > = futures::executor::block_on( chunks.sse_events().collect() );
+  ///
+  /// assert_eq!( events[ 0 ].as_ref().unwrap().data, r#"{"n":1}"# );
+  /// assert!( events[ 1 ].as_ref().unwrap().is_done() );
+  /// # }
+  /// ```
+  pub struct SseStream< S >
+  {
+    /// Source of byte chunks.
+    inner : Pin< Box< S > >,
+    /// Framing state carried across chunks.
+    decoder : SseDecoder,
+    /// The inner stream has ended.
+    finished : bool,
+  }
+
+  impl< S > core::fmt::Debug for SseStream< S >
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "SseStream" )
+        .field( "decoder", &self.decoder )
+        .field( "finished", &self.finished )
+        .finish_non_exhaustive()
+    }
+  }
+
+  impl< S > SseStream< S >
+  {
+    /// Decodes the chunks of `inner`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( inner : S ) -> Self
+    {
+      Self { inner : Box::pin( inner ), decoder : SseDecoder::new(), finished : false }
+    }
+
+    /// The decoder, e.g. for [`SseDecoder::last_event_id`] when reconnecting.
+    #[ inline ]
+    #[ must_use ]
+    pub fn decoder( &self ) -> &SseDecoder
+    {
+      &self.decoder
+    }
+  }
+
+  impl< S, B, E > Stream for SseStream< S >
+  where
+    S : Stream< Item = Result< B, E > >,
+    B : AsRef< [ u8 ] >,
+  {
+    type Item = Result< SseEvent, E >;
+
+    #[ inline ]
+    fn poll_next( mut self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< Option< Self::Item > >
+    {
+      let this = &mut *self;
+      loop
+      {
+        if let Some( event ) = this.decoder.next_event()
+        {
+          return Poll::Ready( Some( Ok( event ) ) );
+        }
+        if this.finished
+        {
+          return Poll::Ready( None );
+        }
+        match ready!( this.inner.as_mut().poll_next( cx ) )
+        {
+          Some( Ok( bytes ) ) => this.decoder.push( bytes.as_ref() ),
+          Some( Err( error ) ) => return Poll::Ready( Some( Err( error ) ) ),
+          None =>
+          {
+            this.finished = true;
+            this.decoder.finish();
+          },
+        }
+      }
+    }
+  }
+
+  /// Adds [`sse_events`](SseStreamExt::sse_events) to byte-chunk streams.
+  pub trait SseStreamExt : Sized
+  {
+    /// Decodes this stream's chunks as Server-Sent Events.
+    fn sse_events( self ) -> SseStream< Self >;
+  }
+
+  impl< S, B, E > SseStreamExt for S
+  where
+    S : Stream< Item = Result< B, E > >,
+    B : AsRef< [ u8 ] >,
+  {
+    #[ inline ]
+    fn sse_events( self ) -> SseStream< Self >
+    {
+      SseStream::new( self )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    SseStream,
+    SseStreamExt,
+  };
+}
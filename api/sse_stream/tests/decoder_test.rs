@@ -0,0 +1,304 @@
+//! Tests for `SseDecoder` framing.
+//!
+//! Each case feeds raw `text/event-stream` bytes, often split at awkward
+//! offsets, and checks the dispatched events against the WHATWG rules.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | single_event_dispatches_on_blank_line | `data` + blank line → one `message` event |
+//! | incomplete_event_waits_for_blank_line | No blank line → nothing dispatched until `finish` |
+//! | multi_line_data_is_joined_with_newline | Consecutive `data` lines joined with `\n` |
+//! | event_field_sets_type_per_event | `event` applies to one event only |
+//! | comments_are_counted_and_skipped | `:` lines never dispatch, are counted |
+//! | event_without_data_is_not_dispatched | `event` alone + blank line → nothing |
+//! | all_line_endings_are_accepted | LF, CRLF, and lone CR all end lines |
+//! | crlf_split_across_pushes_is_one_ending | `\r` \| `\n` split yields no extra blank line |
+//! | utf8_split_across_pushes_is_preserved | Multi-byte character split between pushes survives |
+//! | every_split_point_decodes_identically | Byte-at-a-time and arbitrary splits match one push |
+//! | invalid_utf8_becomes_replacement_character | Bad bytes → U+FFFD, stream continues |
+//! | leading_space_stripped_once | Only one space after the colon is removed |
+//! | field_without_colon_has_empty_value | `data` alone → empty data line |
+//! | unknown_fields_are_ignored | Unrecognised fields do not affect the event |
+//! | id_persists_and_resets | `id` carries across events; empty `id` clears it |
+//! | id_with_nul_is_ignored | `id` containing NUL is discarded |
+//! | retry_requires_digits | Non-digit `retry` values are ignored |
+//! | bom_is_stripped_at_start_only | Leading BOM skipped, later BOM kept |
+//! | finish_flushes_unterminated_event | Final event without blank line dispatched by `finish` |
+//! | done_marker_is_detected | `[DONE]` data → `is_done` |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_sse_stream::{ SseDecoder, SseEvent };
+
+fn drain( decoder : &mut SseDecoder ) -> Vec< SseEvent >
+{
+  core::iter::from_fn( || decoder.next_event() ).collect()
+}
+
+fn decode_in_pieces( bytes : &[ u8 ], piece : usize ) -> Vec< SseEvent >
+{
+  let mut decoder = SseDecoder::new();
+  let mut events = Vec::new();
+  for chunk in bytes.chunks( piece )
+  {
+    decoder.push( chunk );
+    events.extend( drain( &mut decoder ) );
+  }
+  decoder.finish();
+  events.extend( drain( &mut decoder ) );
+  events
+}
+
+// ------------------------------------------------------------------ //
+
+/// A `data` line followed by a blank line is one `message` event.
+#[ test ]
+fn single_event_dispatches_on_blank_line()
+{
+  let events = SseDecoder::decode_all( b"data: hello\n\n" );
+  assert_eq!( events, vec![ SseEvent::new( "hello" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Until the blank line arrives the event is held back.
+#[ test ]
+fn incomplete_event_waits_for_blank_line()
+{
+  let mut decoder = SseDecoder::new();
+  decoder.push( b"data: hello\n" );
+  assert!( decoder.next_event().is_none() );
+  decoder.finish();
+  assert_eq!( decoder.next_event(), Some( SseEvent::new( "hello" ) ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Each `data` line contributes one line of the payload.
+#[ test ]
+fn multi_line_data_is_joined_with_newline()
+{
+  let events = SseDecoder::decode_all( b"data: {\ndata: \"a\": 1\ndata: }\n\n" );
+  assert_eq!( events[ 0 ].data, "{\n\"a\": 1\n}" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `event` sets the type of the current event and is reset after dispatch.
+#[ test ]
+fn event_field_sets_type_per_event()
+{
+  let events = SseDecoder::decode_all( b"event: message_start\ndata: {}\n\ndata: {}\n\n" );
+  assert_eq!( events[ 0 ].event, "message_start" );
+  assert_eq!( events[ 1 ].event, "message" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Keep-alive comments never become events.
+#[ test ]
+fn comments_are_counted_and_skipped()
+{
+  let mut decoder = SseDecoder::new();
+  decoder.push( b": keep-alive\n\n:ping\ndata: x\n: inside\n\n" );
+  let events = drain( &mut decoder );
+  assert_eq!( events, vec![ SseEvent::new( "x" ) ] );
+  assert_eq!( decoder.comments(), 3 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// An event that never had a `data` line is dropped, and its type does not
+/// leak into the next event.
+#[ test ]
+fn event_without_data_is_not_dispatched()
+{
+  let events = SseDecoder::decode_all( b"event: ping\n\ndata: x\n\n" );
+  assert_eq!( events, vec![ SseEvent::new( "x" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// LF, CRLF, and a lone CR are all line terminators.
+#[ test ]
+fn all_line_endings_are_accepted()
+{
+  let expected = vec![ SseEvent::new( "a" ), SseEvent::new( "b" ) ];
+  assert_eq!( SseDecoder::decode_all( b"data: a\n\ndata: b\n\n" ), expected );
+  assert_eq!( SseDecoder::decode_all( b"data: a\r\n\r\ndata: b\r\n\r\n" ), expected );
+  assert_eq!( SseDecoder::decode_all( b"data: a\r\rdata: b\r\r" ), expected );
+  assert_eq!( SseDecoder::decode_all( b"data: a\r\n\ndata: b\r\r\n" ), expected );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A CRLF split between two pushes is one terminator, not two.
+#[ test ]
+fn crlf_split_across_pushes_is_one_ending()
+{
+  let mut decoder = SseDecoder::new();
+  decoder.push( b"data: a\r" );
+  decoder.push( b"\ndata: b\r" );
+  decoder.push( b"\n\r" );
+  decoder.push( b"\n" );
+  assert_eq!( drain( &mut decoder ), vec![ SseEvent::new( "a\nb" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A multi-byte character split between pushes decodes intact.
+#[ test ]
+fn utf8_split_across_pushes_is_preserved()
+{
+  let bytes = "data: héllo ✓ 🌍\n\n".as_bytes();
+  let mut decoder = SseDecoder::new();
+  let globe = bytes.len() - 4;
+  decoder.push( &bytes[ ..globe ] );
+  decoder.push( &bytes[ globe..=globe ] );
+  decoder.push( &bytes[ globe + 1.. ] );
+  assert_eq!( drain( &mut decoder ), vec![ SseEvent::new( "héllo ✓ 🌍" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Splitting the body at any offset produces the same events.
+#[ test ]
+fn every_split_point_decodes_identically()
+{
+  let body = "\u{feff}: hi\r\nevent: delta\r\nid: 7\r\ndata: {\"t\":\"ü✓\"}\r\n\r\ndata: a\rdata: b\r\r: bye\ndata: [DONE]\n\n".as_bytes();
+  let whole = SseDecoder::decode_all( body );
+  assert_eq!( whole.len(), 3 );
+  for piece in 1..body.len()
+  {
+    assert_eq!( decode_in_pieces( body, piece ), whole, "piece size {piece}" );
+  }
+  for split in 0..=body.len()
+  {
+    let mut decoder = SseDecoder::new();
+    decoder.push( &body[ ..split ] );
+    decoder.push( &body[ split.. ] );
+    decoder.finish();
+    assert_eq!( drain( &mut decoder ), whole, "split at {split}" );
+  }
+}
+
+// ------------------------------------------------------------------ //
+
+/// Invalid UTF-8 is replaced rather than aborting the stream.
+#[ test ]
+fn invalid_utf8_becomes_replacement_character()
+{
+  let events = SseDecoder::decode_all( b"data: a\xFFb\n\ndata: ok\n\n" );
+  assert_eq!( events[ 0 ].data, "a\u{fffd}b" );
+  assert_eq!( events[ 1 ].data, "ok" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Exactly one space after the colon belongs to the syntax.
+#[ test ]
+fn leading_space_stripped_once()
+{
+  let events = SseDecoder::decode_all( b"data:x\n\ndata:  y\n\n" );
+  assert_eq!( events[ 0 ].data, "x" );
+  assert_eq!( events[ 1 ].data, " y" );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A line holding only a field name sets that field to the empty string.
+#[ test ]
+fn field_without_colon_has_empty_value()
+{
+  let events = SseDecoder::decode_all( b"data\ndata\n\n" );
+  assert_eq!( events, vec![ SseEvent::new( "\n" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Fields outside the four known names are skipped.
+#[ test ]
+fn unknown_fields_are_ignored()
+{
+  let events = SseDecoder::decode_all( b"foo: bar\nData: no\ndata: yes\n\n" );
+  assert_eq!( events, vec![ SseEvent::new( "yes" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The last event id sticks until replaced; an empty `id` clears it.
+#[ test ]
+fn id_persists_and_resets()
+{
+  let mut decoder = SseDecoder::new();
+  decoder.push( b"id: 1\ndata: a\n\ndata: b\n\nid\ndata: c\n\n" );
+  let events = drain( &mut decoder );
+  assert_eq!( events[ 0 ].id.as_deref(), Some( "1" ) );
+  assert_eq!( events[ 1 ].id.as_deref(), Some( "1" ) );
+  assert_eq!( events[ 2 ].id, None );
+  assert_eq!( decoder.last_event_id(), None );
+
+  decoder.push( b"id: 9\n\n" );
+  assert!( decoder.next_event().is_none() );
+  assert_eq!( decoder.last_event_id(), Some( "9" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// An `id` containing NUL cannot be sent back in a header and is ignored.
+#[ test ]
+fn id_with_nul_is_ignored()
+{
+  let events = SseDecoder::decode_all( b"id: 1\ndata: a\n\nid: 2\0\ndata: b\n\n" );
+  assert_eq!( events[ 1 ].id.as_deref(), Some( "1" ) );
+}
+
+// ------------------------------------------------------------------ //
+
+/// `retry` is only honoured when it is all ASCII digits.
+#[ test ]
+fn retry_requires_digits()
+{
+  let events = SseDecoder::decode_all( b"retry: 3000\ndata: a\n\nretry: 1.5\ndata: b\n\nretry: -1\ndata: c\n\n" );
+  assert_eq!( events[ 0 ].retry, Some( 3000 ) );
+  assert_eq!( events[ 1 ].retry, None );
+  assert_eq!( events[ 2 ].retry, None );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A byte order mark is skipped only at the very start of the stream.
+#[ test ]
+fn bom_is_stripped_at_start_only()
+{
+  let events = SseDecoder::decode_all( b"\xEF\xBB\xBFdata: a\n\n\xEF\xBB\xBFdata: b\n\n" );
+  assert_eq!( events, vec![ SseEvent::new( "a" ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// A body that ends without the final blank line still yields its event.
+#[ test ]
+fn finish_flushes_unterminated_event()
+{
+  let mut decoder = SseDecoder::new();
+  decoder.push( b"data: a\n\ndata: last" );
+  assert_eq!( drain( &mut decoder ).len(), 1 );
+  assert_eq!( decoder.buffered_len(), 10 );
+  decoder.finish();
+  assert_eq!( decoder.next_event(), Some( SseEvent::new( "last" ) ) );
+  assert_eq!( decoder.buffered_len(), 0 );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The `[DONE]` sentinel is recognised without interpreting other data.
+#[ test ]
+fn done_marker_is_detected()
+{
+  let events = SseDecoder::decode_all( b"data: {}\n\ndata: [DONE]\n\n" );
+  assert!( !events[ 0 ].is_done() );
+  assert!( events[ 1 ].is_done() );
+}
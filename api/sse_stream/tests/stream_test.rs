@@ -0,0 +1,70 @@
+//! Tests for the `SseStream` adapter.
+//!
+//! Byte chunks come from `futures::stream::iter`, so no server is needed.
+//!
+//! # Test Matrix
+//!
+//! | Test | Validates |
+//! |------|-----------|
+//! | events_span_chunk_boundaries | Events split across chunks arrive whole and in order |
+//! | trailing_event_flushed_at_end | Unterminated final event dispatched when the input ends |
+//! | transport_error_passes_through | `Err` chunk surfaced unchanged, decoding continues |
+//! | decoder_exposes_last_event_id | `decoder().last_event_id()` tracks the stream |
+
+#![ cfg( all( feature = "enabled", feature = "stream" ) ) ]
+
+use api_sse_stream::{ SseEvent, SseStreamExt };
+use futures::{ executor::block_on, stream, StreamExt };
+
+// ------------------------------------------------------------------ //
+
+/// Chunk boundaries never cut an event.
+#[ test ]
+fn events_span_chunk_boundaries()
+{
+  let chunks : Vec< Result< &[ u8 ], () > > = vec!
+  [
+    Ok( b"event: a\nda" ),
+    Ok( b"ta: 1\r" ),
+    Ok( b"\n\r\n: keep-alive\n\ndata: 2\n" ),
+    Ok( b"\n" ),
+  ];
+  let events : Vec< _ > = block_on( stream::iter( chunks ).sse_events().collect() );
+  assert_eq!( events, vec![ Ok( SseEvent::new( "1" ).with_event( "a" ) ), Ok( SseEvent::new( "2" ) ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The stream ends with the event the server left unterminated.
+#[ test ]
+fn trailing_event_flushed_at_end()
+{
+  let chunks = stream::iter( [ Ok::< _, () >( "data: [DONE]" ) ] );
+  let events : Vec< _ > = block_on( chunks.sse_events().collect() );
+  assert_eq!( events, vec![ Ok( SseEvent::new( "[DONE]" ) ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// Transport errors surface in place; later chunks still decode.
+#[ test ]
+fn transport_error_passes_through()
+{
+  let chunks = stream::iter( [ Ok( "data: a\n\n" ), Err( "reset" ), Ok( "data: b\n\n" ) ] );
+  let events : Vec< _ > = block_on( chunks.sse_events().collect() );
+  assert_eq!( events, vec![ Ok( SseEvent::new( "a" ) ), Err( "reset" ), Ok( SseEvent::new( "b" ) ) ] );
+}
+
+// ------------------------------------------------------------------ //
+
+/// The last event id is readable mid-stream for a reconnect.
+#[ test ]
+fn decoder_exposes_last_event_id()
+{
+  let mut events = stream::iter( [ Ok::< _, () >( "id: 41\ndata: a\n\nid: 42\ndata: b\n\n" ) ] ).sse_events();
+  block_on( async
+  {
+    assert_eq!( events.next().await.unwrap().unwrap().id.as_deref(), Some( "41" ) );
+    assert_eq!( events.decoder().last_event_id(), Some( "42" ) );
+  });
+}
@@ -0,0 +1,96 @@
+//! Command-line parsing for `wire_codegen`.
+
+use crate::error::CodegenError;
+use std::path::PathBuf;
+
+/// Usage text printed by `--help`.
+pub const USAGE : &str = "\
+Usage : wire_codegen <command> [options]
+
+Commands :
+  verify      compare the wire structs with the spec; fails on divergence
+  generate    print a Rust struct for one spec schema
+
+Options :
+  --spec <file>      OpenAPI document (YAML, or JSON with a .json extension)
+  --root <dir>       workspace root for verify (default : .)
+  --schema <path>    schema to generate, e.g. CompletionUsage or CreateChatCompletionResponse/choices/items
+  --name <ident>     struct name for generate (default : last schema name)
+  -h, --help         print this help
+";
+
+/// A parsed command line.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub enum Command
+{
+  /// Check every known target against the spec.
+  Verify
+  {
+    /// Spec file.
+    spec : PathBuf,
+    /// Workspace root.
+    root : PathBuf,
+  },
+  /// Print a struct for one schema.
+  Generate
+  {
+    /// Spec file.
+    spec : PathBuf,
+    /// Schema path.
+    schema : String,
+    /// Struct name.
+    name : String,
+  },
+  /// `--help` was given.
+  Help,
+}
+
+/// Parses the arguments after the program name.
+///
+/// # Errors
+///
+/// Returns a [`CodegenError`] for a missing or unknown command, an unknown
+/// option, an option without its value, or a missing required option.
+#[ inline ]
+pub fn parse( args : impl IntoIterator< Item = String > ) -> Result< Command, CodegenError >
+{
+  let mut command = None;
+  let mut spec = None;
+  let mut root = None;
+  let mut schema = None;
+  let mut name = None;
+  let mut args = args.into_iter();
+  while let Some( arg ) = args.next()
+  {
+    let slot = match arg.as_str()
+    {
+      "-h" | "--help" => return Ok( Command::Help ),
+      "--spec" => &mut spec,
+      "--root" => &mut root,
+      "--schema" => &mut schema,
+      "--name" => &mut name,
+      option if option.starts_with( '-' ) => return Err( CodegenError::UnknownOption( arg ) ),
+      _ if command.is_none() =>
+      {
+        command = Some( arg );
+        continue;
+      },
+      _ => return Err( CodegenError::UnknownCommand( arg ) ),
+    };
+    *slot = Some( args.next().ok_or_else( || CodegenError::MissingValue( arg.clone() ) )? );
+  }
+
+  let command = command.ok_or( CodegenError::MissingCommand )?;
+  if command != "verify" && command != "generate"
+  {
+    return Err( CodegenError::UnknownCommand( command ) );
+  }
+  let spec = PathBuf::from( spec.ok_or_else( || CodegenError::MissingOption( "--spec".to_string() ) )? );
+  if command == "verify"
+  {
+    return Ok( Command::Verify { spec, root : PathBuf::from( root.unwrap_or_else( || ".".to_string() ) ) } );
+  }
+  let schema = schema.ok_or_else( || CodegenError::MissingOption( "--schema".to_string() ) )?;
+  let name = name.unwrap_or_else( || schema.split( '/' ).next().unwrap_or_default().to_string() );
+  Ok( Command::Generate { spec, schema, name } )
+}
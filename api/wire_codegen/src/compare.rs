@@ -0,0 +1,83 @@
+//! Field-by-field comparison of a Rust struct with its spec schema.
+
+use crate::rust_types::RustField;
+use crate::spec::SpecField;
+use core::fmt;
+
+/// One way a Rust struct differs from its schema.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+#[ non_exhaustive ]
+pub enum Divergence
+{
+  /// The schema has a property the struct does not read; the member is
+  /// dropped on deserialisation.
+  MissingField
+  {
+    /// Property name.
+    name : String,
+    /// Closest Rust type for the property.
+    spec_type : String,
+  },
+  /// The struct reads a member the schema does not define, usually a
+  /// rename on one side or a provider extension.
+  UnknownField
+  {
+    /// Wire name of the field.
+    name : String,
+  },
+  /// The schema allows the property to be absent or `null` but the Rust
+  /// field is not an `Option`; such a response fails to deserialise.
+  MustBeOptional
+  {
+    /// Property name.
+    name : String,
+  },
+}
+
+impl fmt::Display for Divergence
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+  {
+    match self
+    {
+      Self::MissingField { name, spec_type } => write!( f, "missing field `{name}` ({spec_type})" ),
+      Self::UnknownField { name } => write!( f, "field `{name}` is not in the spec" ),
+      Self::MustBeOptional { name } => write!( f, "field `{name}` may be absent or null in the spec but is not an Option" ),
+    }
+  }
+}
+
+/// Differences between `rust` and `spec` : missing fields in `spec` order,
+/// then unknown fields and non-optional fields in struct order.
+///
+/// A `flatten` field may absorb any member, so it suppresses
+/// [`Divergence::MissingField`].
+#[ inline ]
+#[ must_use ]
+pub fn compare( spec : &[ SpecField ], rust : &[ RustField ] ) -> Vec< Divergence >
+{
+  let mut divergences = Vec::new();
+  if !rust.iter().any( | field | field.flatten )
+  {
+    divergences.extend
+    (
+      spec.iter()
+      .filter( | property | !rust.iter().any( | field | field.wire_name == property.name ) )
+      .map( | property | Divergence::MissingField { name : property.name.clone(), spec_type : property.rust_type.clone() } )
+    );
+  }
+  for field in rust.iter().filter( | field | !field.flatten )
+  {
+    match spec.iter().find( | property | property.name == field.wire_name )
+    {
+      None => divergences.push( Divergence::UnknownField { name : field.wire_name.clone() } ),
+      Some( property ) if property.is_optional() && !field.optional =>
+      {
+        divergences.push( Divergence::MustBeOptional { name : field.wire_name.clone() } );
+      },
+      Some( _ ) => {},
+    }
+  }
+  divergences
+}
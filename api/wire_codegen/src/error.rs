@@ -0,0 +1,67 @@
+//! Error type and result alias for the wire codegen tool.
+
+use error_tools::dependency::thiserror;
+
+/// Errors raised while reading the spec or the Rust sources.
+///
+/// I/O and JSON/YAML errors are passed through unchanged inside the untyped
+/// [`Result`].
+#[ derive( Debug, Clone, PartialEq, Eq, thiserror::Error ) ]
+#[ non_exhaustive ]
+pub enum CodegenError
+{
+  /// No subcommand was given.
+  #[ error( "Missing command : expected one of verify, generate" ) ]
+  MissingCommand,
+
+  /// The subcommand is not known.
+  #[ error( "Unknown command : {0}" ) ]
+  UnknownCommand( String ),
+
+  /// An option is not known.
+  #[ error( "Unknown option : {0}" ) ]
+  UnknownOption( String ),
+
+  /// An option that takes a value was given without one.
+  #[ error( "Missing value for option : {0}" ) ]
+  MissingValue( String ),
+
+  /// A required option was not given.
+  #[ error( "Missing option : {0}" ) ]
+  MissingOption( String ),
+
+  /// The spec has no `components.schemas` entry with this name.
+  #[ error( "Unknown schema : {0}" ) ]
+  UnknownSchema( String ),
+
+  /// A `$ref` points outside `#/components/schemas/`.
+  #[ error( "Unresolved reference : {0}" ) ]
+  UnresolvedRef( String ),
+
+  /// The schema path does not lead to an object schema.
+  #[ error( "Schema {0} is not an object" ) ]
+  NotAnObject( String ),
+
+  /// The Rust source has no struct with this name.
+  #[ error( "Struct {name} not found in {file}" ) ]
+  StructNotFound
+  {
+    /// Source file searched.
+    file : String,
+    /// Struct name.
+    name : String,
+  },
+
+  /// A Rust source file did not parse.
+  #[ error( "Failed to parse {file} : {message}" ) ]
+  Parse
+  {
+    /// Source file.
+    file : String,
+    /// Parser message.
+    message : String,
+  },
+}
+
+/// Result alias carrying a [`CodegenError`] or an I/O or parse error.
+pub type Result< T > = error_tools::untyped::Result< T >;
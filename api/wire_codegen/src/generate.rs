@@ -0,0 +1,102 @@
+//! Rust struct skeletons generated from a spec schema.
+
+use crate::error::Result;
+use crate::spec::Spec;
+use core::fmt::Write as _;
+
+/// Source of a struct named `name` for the object schema at `path`, in the
+/// workspace's wire-type style : one doc line per description paragraph,
+/// `Option` for properties that may be absent or `null`, and `rename` where
+/// the property name is not a Rust field name.
+///
+/// The output is a starting point to paste into a `components` module, not
+/// a file to include as is.
+///
+/// # Errors
+///
+/// As [`Spec::fields`].
+#[ inline ]
+pub fn generate( spec : &Spec, path : &str, name : &str ) -> Result< String >
+{
+  let fields = spec.fields( path )?;
+  let mut out = String::new();
+  doc( &mut out, "", spec.description( path )?.as_deref().unwrap_or( name ) );
+  out.push_str( "#[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]\n" );
+  let _ = writeln!( out, "pub struct {name}\n{{" );
+  for ( index, field ) in fields.iter().enumerate()
+  {
+    if index > 0
+    {
+      out.push( '\n' );
+    }
+    doc( &mut out, "  ", field.description.as_deref().unwrap_or( &field.name ) );
+    let ident = field_ident( &field.name );
+    if ident.trim_start_matches( "r#" ) != field.name
+    {
+      let _ = writeln!( out, "  #[ serde( rename = \"{}\" ) ]", field.name );
+    }
+    if field.is_optional()
+    {
+      out.push_str( "  #[ serde( skip_serializing_if = \"Option::is_none\" ) ]\n" );
+      let _ = writeln!( out, "  pub {ident} : Option< {} >,", field.rust_type );
+    }
+    else
+    {
+      let _ = writeln!( out, "  pub {ident} : {},", field.rust_type );
+    }
+  }
+  out.push_str( "}\n" );
+  Ok( out )
+}
+
+/// First paragraph of `text` as `///` lines.
+fn doc( out : &mut String, indent : &str, text : &str )
+{
+  let paragraph = text.trim().split( "\n\n" ).next().unwrap_or_default();
+  for line in paragraph.lines()
+  {
+    let line = line.trim_end();
+    if line.is_empty()
+    {
+      let _ = writeln!( out, "{indent}///" );
+    }
+    else
+    {
+      let _ = writeln!( out, "{indent}/// {line}" );
+    }
+  }
+}
+
+/// `snake_case` Rust field name for a property, raw when it is a keyword.
+fn field_ident( name : &str ) -> String
+{
+  let mut ident = String::new();
+  for ( index, c ) in name.chars().enumerate()
+  {
+    if c.is_ascii_uppercase()
+    {
+      if index > 0 && !ident.ends_with( '_' )
+      {
+        ident.push( '_' );
+      }
+      ident.push( c.to_ascii_lowercase() );
+    }
+    else if c.is_ascii_alphanumeric()
+    {
+      ident.push( c );
+    }
+    else if !ident.ends_with( '_' )
+    {
+      ident.push( '_' );
+    }
+  }
+  if ident.starts_with( | c : char | c.is_ascii_digit() )
+  {
+    ident.insert( 0, '_' );
+  }
+  if syn::parse_str::< syn::Ident >( &ident ).is_err()
+  {
+    ident.insert_str( 0, "r#" );
+  }
+  ident
+}
@@ -0,0 +1,54 @@
+//! Library behind the `wire_codegen` tool.
+//!
+//! The wire types in `api_openai_compatible` and `api_openai` are written by
+//! hand and fall behind the `OpenAI` `OpenAPI` spec as fields are added to
+//! it. `wire_codegen` reads the spec and the Rust sources side by side:
+//!
+//! ```text
+//! wire_codegen verify --spec openapi.yaml
+//! wire_codegen generate --spec openapi.yaml --schema CompletionUsage --name Usage
+//! ```
+//!
+//! `verify` compares each known struct with its schema — missing fields,
+//! fields the spec does not define, required fields that the spec allows to
+//! be absent or `null` — and exits with failure on any divergence.
+//! `generate` prints a struct for one schema as a starting point for a new
+//! or updated wire type.
+//!
+//! The spec is the `openapi.yaml` published in the `openai/openai-openapi`
+//! repository; the tool never downloads it.
+//!
+//! # Features
+//!
+//! - `enabled` — spec reading, source comparison, and generation
+//! - `full` — everything above (default)
+
+#[ cfg( feature = "enabled" ) ]
+pub mod args;
+#[ cfg( feature = "enabled" ) ]
+pub mod compare;
+#[ cfg( feature = "enabled" ) ]
+pub mod error;
+#[ cfg( feature = "enabled" ) ]
+pub mod generate;
+#[ cfg( feature = "enabled" ) ]
+pub mod rust_types;
+#[ cfg( feature = "enabled" ) ]
+pub mod spec;
+#[ cfg( feature = "enabled" ) ]
+pub mod targets;
+
+#[ cfg( feature = "enabled" ) ]
+pub use args::{ Command, USAGE };
+#[ cfg( feature = "enabled" ) ]
+pub use compare::{ compare, Divergence };
+#[ cfg( feature = "enabled" ) ]
+pub use error::CodegenError;
+#[ cfg( feature = "enabled" ) ]
+pub use generate::generate;
+#[ cfg( feature = "enabled" ) ]
+pub use rust_types::RustField;
+#[ cfg( feature = "enabled" ) ]
+pub use spec::{ Spec, SpecField };
+#[ cfg( feature = "enabled" ) ]
+pub use targets::{ Report, Target };
@@ -0,0 +1,58 @@
+//! `wire_codegen` — checks the `OpenAI` wire types against the published
+//! `OpenAPI` spec and generates struct skeletons from it. Run
+//! `wire_codegen --help` for usage.
+
+use api_wire_codegen::{ args, targets, Command, Spec, USAGE };
+use std::process::ExitCode;
+
+fn main() -> ExitCode
+{
+  match run()
+  {
+    Ok( true ) => ExitCode::SUCCESS,
+    Ok( false ) => ExitCode::FAILURE,
+    Err( err ) =>
+    {
+      eprintln!( "wire_codegen : {err:#}" );
+      ExitCode::FAILURE
+    },
+  }
+}
+
+/// Runs the command; `Ok( false )` when `verify` found divergences.
+fn run() -> api_wire_codegen::error::Result< bool >
+{
+  match args::parse( std::env::args().skip( 1 ) )?
+  {
+    Command::Help =>
+    {
+      print!( "{USAGE}" );
+      Ok( true )
+    },
+    Command::Generate { spec, schema, name } =>
+    {
+      print!( "{}", api_wire_codegen::generate( &Spec::load( &spec )?, &schema, &name )? );
+      Ok( true )
+    },
+    Command::Verify { spec, root } =>
+    {
+      let spec = Spec::load( &spec )?;
+      let mut all = targets::openai_compatible();
+      all.extend( targets::openai( &spec, &root )? );
+      let reports = targets::check( &spec, &root, &all )?;
+      let mut clean = true;
+      for report in reports.iter().filter( | report | !report.divergences.is_empty() )
+      {
+        clean = false;
+        println!( "{} ({}) vs {} :", report.target.rust, report.target.file.display(), report.target.schema );
+        for divergence in &report.divergences
+        {
+          println!( "  {divergence}" );
+        }
+      }
+      let diverging = reports.iter().filter( | report | !report.divergences.is_empty() ).count();
+      println!( "{} structs checked, {diverging} diverge from the spec", reports.len() );
+      Ok( clean )
+    },
+  }
+}
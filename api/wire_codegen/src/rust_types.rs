@@ -0,0 +1,243 @@
+//! Wire view of the hand-written Rust structs : which JSON members each one
+//! reads, as decided by its `serde` attributes.
+
+use crate::error::{ CodegenError, Result };
+use syn::ext::IdentExt;
+use syn::{ Attribute, Fields, Item, ItemStruct, LitStr, Token, Type };
+
+/// One field of a Rust struct, as seen on the wire.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct RustField
+{
+  /// JSON member name after `rename` and `rename_all`.
+  pub wire_name : String,
+  /// Field type is `Option< .. >`.
+  pub optional : bool,
+  /// `#[ serde( flatten ) ]` : the field absorbs members it does not name.
+  pub flatten : bool,
+}
+
+/// Names of the public structs with named fields in `source`, nested
+/// inline modules included.
+///
+/// # Errors
+///
+/// Returns [`CodegenError::Parse`] when `source` is not valid Rust.
+#[ inline ]
+pub fn struct_names( source : &str, file : &str ) -> Result< Vec< String > >
+{
+  let parsed = parse( source, file )?;
+  let mut names = Vec::new();
+  visit( &parsed.items, &mut | item |
+  {
+    if matches!( item.vis, syn::Visibility::Public( _ ) ) && matches!( item.fields, Fields::Named( _ ) )
+    {
+      names.push( item.ident.to_string() );
+    }
+  });
+  Ok( names )
+}
+
+/// Wire fields of the struct `name` in `source`, in declaration order.
+/// Fields marked `skip` or `skip_deserializing` are left out.
+///
+/// # Errors
+///
+/// Returns [`CodegenError::Parse`] when `source` or a `serde` attribute does
+/// not parse, and [`CodegenError::StructNotFound`] when there is no such
+/// struct.
+#[ inline ]
+pub fn struct_fields( source : &str, file : &str, name : &str ) -> Result< Vec< RustField > >
+{
+  let parsed = parse( source, file )?;
+  let mut found = None;
+  visit( &parsed.items, &mut | item |
+  {
+    if found.is_none() && item.ident == name
+    {
+      found = Some( item.clone() );
+    }
+  });
+  let item = found.ok_or_else( || CodegenError::StructNotFound { file : file.to_string(), name : name.to_string() } )?;
+  let parse_error = | e : syn::Error | CodegenError::Parse { file : file.to_string(), message : e.to_string() };
+
+  let rename_all = container_rename_all( &item.attrs ).map_err( parse_error )?;
+  let mut fields = Vec::new();
+  for field in &item.fields
+  {
+    let Some( ident ) = &field.ident else { continue };
+    let serde = field_serde( &field.attrs ).map_err( parse_error )?;
+    if serde.skip
+    {
+      continue;
+    }
+    let ident = ident.unraw().to_string();
+    let wire_name = serde.rename.unwrap_or_else( || match &rename_all
+    {
+      Some( rule ) => apply_rename_all( rule, &ident ),
+      None => ident,
+    });
+    fields.push( RustField { wire_name, optional : is_option( &field.ty ), flatten : serde.flatten } );
+  }
+  Ok( fields )
+}
+
+fn parse( source : &str, file : &str ) -> Result< syn::File >
+{
+  syn::parse_file( source ).map_err( | e | CodegenError::Parse { file : file.to_string(), message : e.to_string() }.into() )
+}
+
+fn visit( items : &[ Item ], on_struct : &mut impl FnMut( &ItemStruct ) )
+{
+  for item in items
+  {
+    match item
+    {
+      Item::Struct( item ) => on_struct( item ),
+      Item::Mod( module ) =>
+      {
+        if let Some( ( _, items ) ) = &module.content
+        {
+          visit( items, on_struct );
+        }
+      },
+      _ => {},
+    }
+  }
+}
+
+#[ derive( Default ) ]
+struct FieldSerde
+{
+  rename : Option< String >,
+  skip : bool,
+  flatten : bool,
+}
+
+fn container_rename_all( attrs : &[ Attribute ] ) -> syn::Result< Option< String > >
+{
+  let mut rule = None;
+  for attr in attrs.iter().filter( | attr | attr.path().is_ident( "serde" ) )
+  {
+    attr.parse_nested_meta( | meta |
+    {
+      if meta.path.is_ident( "rename_all" )
+      {
+        if meta.input.peek( Token![ = ] )
+        {
+          rule = Some( meta.value()?.parse::< LitStr >()?.value() );
+        }
+        else
+        {
+          // `rename_all( deserialize = ".." )` : the side this tool checks.
+          meta.parse_nested_meta( | side |
+          {
+            let value = side.value()?.parse::< LitStr >()?.value();
+            if side.path.is_ident( "deserialize" )
+            {
+              rule = Some( value );
+            }
+            Ok( () )
+          })?;
+        }
+        return Ok( () );
+      }
+      skip_value( &meta )
+    })?;
+  }
+  Ok( rule )
+}
+
+fn field_serde( attrs : &[ Attribute ] ) -> syn::Result< FieldSerde >
+{
+  let mut serde = FieldSerde::default();
+  for attr in attrs.iter().filter( | attr | attr.path().is_ident( "serde" ) )
+  {
+    attr.parse_nested_meta( | meta |
+    {
+      if meta.path.is_ident( "rename" )
+      {
+        if meta.input.peek( Token![ = ] )
+        {
+          serde.rename = Some( meta.value()?.parse::< LitStr >()?.value() );
+        }
+        else
+        {
+          meta.parse_nested_meta( | side |
+          {
+            let value = side.value()?.parse::< LitStr >()?.value();
+            if side.path.is_ident( "deserialize" )
+            {
+              serde.rename = Some( value );
+            }
+            Ok( () )
+          })?;
+        }
+        return Ok( () );
+      }
+      if meta.path.is_ident( "skip" ) || meta.path.is_ident( "skip_deserializing" )
+      {
+        serde.skip = true;
+      }
+      if meta.path.is_ident( "flatten" )
+      {
+        serde.flatten = true;
+      }
+      skip_value( &meta )
+    })?;
+  }
+  Ok( serde )
+}
+
+/// Consumes the value of a `serde` option this tool does not interpret
+/// (`default = ".."`, `with = ".."`, `bound( .. )`).
+fn skip_value( meta : &syn::meta::ParseNestedMeta< '_ > ) -> syn::Result< () >
+{
+  if meta.input.peek( Token![ = ] )
+  {
+    meta.value()?.parse::< syn::Expr >()?;
+  }
+  else if meta.input.peek( syn::token::Paren )
+  {
+    let content;
+    syn::parenthesized!( content in meta.input );
+    content.parse::< proc_macro2::TokenStream >()?;
+  }
+  Ok( () )
+}
+
+fn is_option( ty : &Type ) -> bool
+{
+  match ty
+  {
+    Type::Path( path ) => path.path.segments.last().is_some_and( | segment | segment.ident == "Option" ),
+    _ => false,
+  }
+}
+
+/// Applies a serde `rename_all` rule to a `snake_case` field name.
+fn apply_rename_all( rule : &str, ident : &str ) -> String
+{
+  let words : Vec< &str > = ident.split( '_' ).filter( | word | !word.is_empty() ).collect();
+  let capitalised = || words.iter().map( | word | capitalise( word ) ).collect::< String >();
+  match rule
+  {
+    "UPPERCASE" | "SCREAMING_SNAKE_CASE" => ident.to_uppercase(),
+    "PascalCase" => capitalised(),
+    "camelCase" =>
+    {
+      let mut name = words.first().map( | word | ( *word ).to_string() ).unwrap_or_default();
+      name.extend( words.iter().skip( 1 ).map( | word | capitalise( word ) ) );
+      name
+    },
+    "kebab-case" => ident.replace( '_', "-" ),
+    "SCREAMING-KEBAB-CASE" => ident.replace( '_', "-" ).to_uppercase(),
+    _ => ident.to_string(),
+  }
+}
+
+fn capitalise( word : &str ) -> String
+{
+  let mut chars = word.chars();
+  chars.next().map( | first | first.to_uppercase().chain( chars ).collect() ).unwrap_or_default()
+}
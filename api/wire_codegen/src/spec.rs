@@ -0,0 +1,291 @@
+//! Read access to the object schemas of an `OpenAPI` document.
+
+use crate::error::{ CodegenError, Result };
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+const SCHEMA_REF : &str = "#/components/schemas/";
+
+/// A parsed `OpenAPI` document.
+#[ derive( Debug, Clone ) ]
+pub struct Spec
+{
+  root : Value,
+}
+
+/// One property of an object schema.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct SpecField
+{
+  /// Property name on the wire.
+  pub name : String,
+  /// Listed in the schema's `required`.
+  pub required : bool,
+  /// Accepts `null` (`nullable`, a `null` branch of `anyOf`/`oneOf`, or a
+  /// `null` entry in `type`).
+  pub nullable : bool,
+  /// Closest Rust type : a schema name for `$ref`, `Vec< T >` for arrays,
+  /// `serde_json::Value` for anything without a direct equivalent.
+  pub rust_type : String,
+  /// Property description, if any.
+  pub description : Option< String >,
+}
+
+impl SpecField
+{
+  /// Whether the Rust field must be an `Option` : the property may be
+  /// absent or `null`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_optional( &self ) -> bool
+  {
+    !self.required || self.nullable
+  }
+}
+
+impl Spec
+{
+  /// Parses a JSON document.
+  ///
+  /// # Errors
+  ///
+  /// Returns the JSON error when `text` is not valid JSON.
+  #[ inline ]
+  pub fn from_json( text : &str ) -> Result< Self >
+  {
+    Ok( Self { root : serde_json::from_str( text )? } )
+  }
+
+  /// Parses a YAML document, the format the `OpenAI` spec is published in.
+  ///
+  /// # Errors
+  ///
+  /// Returns the YAML error when `text` is not valid YAML.
+  #[ inline ]
+  pub fn from_yaml( text : &str ) -> Result< Self >
+  {
+    Ok( Self { root : serde_yml::from_str( text )? } )
+  }
+
+  /// Reads a spec file, as JSON when its extension is `json` and as YAML
+  /// otherwise.
+  ///
+  /// # Errors
+  ///
+  /// Returns the I/O or parse error.
+  #[ inline ]
+  pub fn load( path : &Path ) -> Result< Self >
+  {
+    let text = std::fs::read_to_string( path )?;
+    if path.extension().and_then( | ext | ext.to_str() ) == Some( "json" )
+    {
+      Self::from_json( &text )
+    }
+    else
+    {
+      Self::from_yaml( &text )
+    }
+  }
+
+  /// Whether `components.schemas` has an entry named `name`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn has_schema( &self, name : &str ) -> bool
+  {
+    self.root[ "components" ][ "schemas" ].get( name ).is_some()
+  }
+
+  /// Properties of the object schema at `path`, sorted by name.
+  ///
+  /// `path` starts with a schema name; each further `/`-separated segment
+  /// steps into the property of that name, or into the array element for
+  /// `items` — `CreateChatCompletionResponse/choices/items/message`.
+  /// `$ref`s are followed and `allOf` parts merged.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`CodegenError::UnknownSchema`] when a step does not exist,
+  /// [`CodegenError::UnresolvedRef`] for a `$ref` outside the document's
+  /// schemas, and [`CodegenError::NotAnObject`] when the target has no
+  /// properties.
+  #[ inline ]
+  pub fn fields( &self, path : &str ) -> Result< Vec< SpecField > >
+  {
+    let node = self.node( path )?;
+    let mut fields = Vec::new();
+    let mut required = BTreeSet::new();
+    self.collect( node, &mut fields, &mut required )?;
+    if fields.is_empty()
+    {
+      return Err( CodegenError::NotAnObject( path.to_string() ).into() );
+    }
+    let mut result = Vec::with_capacity( fields.len() );
+    for ( name, schema ) in fields
+    {
+      let resolved = self.resolve( schema )?;
+      let description = schema.get( "description" ).or_else( || resolved.get( "description" ) );
+      result.push( SpecField
+      {
+        required : required.contains( name.as_str() ),
+        nullable : is_nullable( schema ) || is_nullable( resolved ),
+        rust_type : rust_type( schema ),
+        description : description.and_then( Value::as_str ).map( str::to_string ),
+        name,
+      });
+    }
+    Ok( result )
+  }
+
+  /// Description of the schema at `path`, if any.
+  ///
+  /// # Errors
+  ///
+  /// As [`fields`](Self::fields), except that the target may be of any type.
+  #[ inline ]
+  pub fn description( &self, path : &str ) -> Result< Option< String > >
+  {
+    Ok( self.node( path )?.get( "description" ).and_then( Value::as_str ).map( str::to_string ) )
+  }
+
+  fn node( &self, path : &str ) -> Result< &Value >
+  {
+    let mut segments = path.split( '/' );
+    let name = segments.next().unwrap_or_default();
+    let mut node = self.root[ "components" ][ "schemas" ]
+    .get( name )
+    .ok_or_else( || CodegenError::UnknownSchema( name.to_string() ) )?;
+    for segment in segments
+    {
+      let current = self.resolve( node )?;
+      let next = if segment == "items"
+      {
+        current.get( "items" )
+      }
+      else
+      {
+        self.property( current, segment )?
+      };
+      node = next.ok_or_else( || CodegenError::UnknownSchema( path.to_string() ) )?;
+    }
+    self.resolve( node )
+  }
+
+  /// The property `name` of an object schema, looking through `allOf`.
+  fn property< 'a >( &'a self, schema : &'a Value, name : &str ) -> Result< Option< &'a Value > >
+  {
+    if let Some( found ) = schema.get( "properties" ).and_then( | properties | properties.get( name ) )
+    {
+      return Ok( Some( found ) );
+    }
+    for part in schema.get( "allOf" ).and_then( Value::as_array ).into_iter().flatten()
+    {
+      if let Some( found ) = self.property( self.resolve( part )?, name )?
+      {
+        return Ok( Some( found ) );
+      }
+    }
+    Ok( None )
+  }
+
+  fn collect< 'a >( &'a self, schema : &'a Value, fields : &mut Vec< ( String, &'a Value ) >, required : &mut BTreeSet< String > ) -> Result< () >
+  {
+    for part in schema.get( "allOf" ).and_then( Value::as_array ).into_iter().flatten()
+    {
+      self.collect( self.resolve( part )?, fields, required )?;
+    }
+    if let Some( properties ) = schema.get( "properties" ).and_then( Value::as_object )
+    {
+      for ( name, property ) in properties
+      {
+        match fields.iter_mut().find( | ( existing, _ ) | existing == name )
+        {
+          Some( slot ) => slot.1 = property,
+          None => fields.push( ( name.clone(), property ) ),
+        }
+      }
+    }
+    for name in schema.get( "required" ).and_then( Value::as_array ).into_iter().flatten().filter_map( Value::as_str )
+    {
+      required.insert( name.to_string() );
+    }
+    Ok( () )
+  }
+
+  fn resolve< 'a >( &'a self, mut schema : &'a Value ) -> Result< &'a Value >
+  {
+    // Bounded, so a `$ref` cycle cannot hang the tool.
+    for _ in 0..32
+    {
+      let Some( reference ) = schema.get( "$ref" ).and_then( Value::as_str ) else { return Ok( schema ) };
+      let name = reference
+      .strip_prefix( SCHEMA_REF )
+      .ok_or_else( || CodegenError::UnresolvedRef( reference.to_string() ) )?;
+      schema = self.root[ "components" ][ "schemas" ]
+      .get( name )
+      .ok_or_else( || CodegenError::UnresolvedRef( reference.to_string() ) )?;
+    }
+    Err( CodegenError::UnresolvedRef( "reference cycle".to_string() ).into() )
+  }
+}
+
+fn rust_type( schema : &Value ) -> String
+{
+  if let Some( name ) = schema.get( "$ref" ).and_then( Value::as_str ).and_then( | r | r.strip_prefix( SCHEMA_REF ) )
+  {
+    return name.to_string();
+  }
+  // `anyOf : [ T, null ]` is an optional `T`.
+  for key in [ "anyOf", "oneOf" ]
+  {
+    if let Some( branches ) = schema.get( key ).and_then( Value::as_array )
+    {
+      let non_null : Vec< &Value > = branches.iter().filter( | branch | !is_null_type( branch ) ).collect();
+      if let [ only ] = non_null.as_slice()
+      {
+        return rust_type( only );
+      }
+      return "serde_json::Value".to_string();
+    }
+  }
+  let type_name = match schema.get( "type" )
+  {
+    Some( Value::String( name ) ) => Some( name.as_str() ),
+    Some( Value::Array( names ) ) => names.iter().filter_map( Value::as_str ).find( | name | *name != "null" ),
+    _ => None,
+  };
+  match type_name
+  {
+    Some( "string" ) => "String".to_string(),
+    Some( "integer" ) => "i64".to_string(),
+    Some( "number" ) => "f64".to_string(),
+    Some( "boolean" ) => "bool".to_string(),
+    Some( "array" ) => match schema.get( "items" )
+    {
+      Some( items ) => format!( "Vec< {} >", rust_type( items ) ),
+      None => "Vec< serde_json::Value >".to_string(),
+    },
+    _ => "serde_json::Value".to_string(),
+  }
+}
+
+fn is_null_type( schema : &Value ) -> bool
+{
+  schema.get( "type" ).and_then( Value::as_str ) == Some( "null" )
+}
+
+fn is_nullable( schema : &Value ) -> bool
+{
+  if schema.get( "nullable" ).and_then( Value::as_bool ) == Some( true )
+  {
+    return true;
+  }
+  if let Some( Value::Array( names ) ) = schema.get( "type" )
+  {
+    return names.iter().any( | name | name.as_str() == Some( "null" ) );
+  }
+  [ "anyOf", "oneOf" ].iter().any( | key |
+  {
+    schema.get( *key ).and_then( Value::as_array ).is_some_and( | branches | branches.iter().any( is_null_type ) )
+  })
+}
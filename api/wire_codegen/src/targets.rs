@@ -0,0 +1,132 @@
+//! Which Rust struct is checked against which spec schema.
+
+use crate::compare::{ compare, Divergence };
+use crate::error::Result;
+use crate::rust_types::{ struct_fields, struct_names };
+use crate::spec::Spec;
+use std::path::{ Path, PathBuf };
+
+/// One struct to check.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct Target
+{
+  /// Source file, relative to the workspace root.
+  pub file : PathBuf,
+  /// Rust struct name.
+  pub rust : String,
+  /// Schema path, as accepted by [`Spec::fields`].
+  pub schema : String,
+}
+
+impl Target
+{
+  fn new( file : &str, rust : &str, schema : &str ) -> Self
+  {
+    Self { file : PathBuf::from( file ), rust : rust.to_string(), schema : schema.to_string() }
+  }
+}
+
+/// Result of checking one [`Target`].
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct Report
+{
+  /// The checked struct.
+  pub target : Target,
+  /// Its differences from the schema; empty when it matches.
+  pub divergences : Vec< Divergence >,
+}
+
+const OPENAI_COMPATIBLE_CHAT : &str = "api/openai_compatible/src/components/chat.rs";
+const OPENAI_COMPATIBLE_STREAMING : &str = "api/openai_compatible/src/components/streaming.rs";
+const OPENAI_COMPONENTS : &str = "api/openai/src/components";
+
+/// The `api_openai_compatible` wire types. Their names differ from the
+/// spec's, so the mapping is listed by hand.
+#[ inline ]
+#[ must_use ]
+pub fn openai_compatible() -> Vec< Target >
+{
+  vec!
+  [
+    Target::new( OPENAI_COMPATIBLE_CHAT, "ChatCompletionRequest", "CreateChatCompletionRequest" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "ChatCompletionResponse", "CreateChatCompletionResponse" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "Choice", "CreateChatCompletionResponse/choices/items" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "Message", "ChatCompletionResponseMessage" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "ToolCall", "ChatCompletionMessageToolCall" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "FunctionCall", "ChatCompletionMessageToolCall/function" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "Tool", "ChatCompletionTool" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "Function", "FunctionObject" ),
+    Target::new( OPENAI_COMPATIBLE_CHAT, "Usage", "CompletionUsage" ),
+    Target::new( OPENAI_COMPATIBLE_STREAMING, "ChatCompletionChunk", "CreateChatCompletionStreamResponse" ),
+    Target::new( OPENAI_COMPATIBLE_STREAMING, "ChunkChoice", "CreateChatCompletionStreamResponse/choices/items" ),
+    Target::new( OPENAI_COMPATIBLE_STREAMING, "Delta", "ChatCompletionStreamResponseDelta" ),
+    Target::new( OPENAI_COMPATIBLE_STREAMING, "ToolCallChunk", "ChatCompletionMessageToolCallChunk" ),
+    Target::new( OPENAI_COMPATIBLE_STREAMING, "FunctionCallChunk", "ChatCompletionMessageToolCallChunk/function" ),
+  ]
+}
+
+/// The `api_openai` component structs named after a spec schema. That
+/// crate follows the spec's naming, so its targets are found by name under
+/// `api/openai/src/components`.
+///
+/// # Errors
+///
+/// Returns the I/O error when the directory cannot be read, or a parse
+/// error for an invalid source file.
+#[ inline ]
+pub fn openai( spec : &Spec, root : &Path ) -> Result< Vec< Target > >
+{
+  let mut files = Vec::new();
+  rust_files( &root.join( OPENAI_COMPONENTS ), &mut files )?;
+  files.sort();
+  let mut targets = Vec::new();
+  for path in files
+  {
+    let file = path.strip_prefix( root ).unwrap_or( &path ).to_path_buf();
+    let source = std::fs::read_to_string( &path )?;
+    for name in struct_names( &source, &file.display().to_string() )?
+    {
+      if spec.has_schema( &name ) && !targets.iter().any( | target : &Target | target.rust == name )
+      {
+        targets.push( Target { file : file.clone(), schema : name.clone(), rust : name } );
+      }
+    }
+  }
+  Ok( targets )
+}
+
+/// Checks each target under the workspace `root` against `spec`.
+///
+/// # Errors
+///
+/// Returns the first I/O, parse, or schema lookup error.
+#[ inline ]
+pub fn check( spec : &Spec, root : &Path, targets : &[ Target ] ) -> Result< Vec< Report > >
+{
+  let mut reports = Vec::with_capacity( targets.len() );
+  for target in targets
+  {
+    let source = std::fs::read_to_string( root.join( &target.file ) )?;
+    let rust = struct_fields( &source, &target.file.display().to_string(), &target.rust )?;
+    let schema = spec.fields( &target.schema )?;
+    reports.push( Report { target : target.clone(), divergences : compare( &schema, &rust ) } );
+  }
+  Ok( reports )
+}
+
+fn rust_files( dir : &Path, files : &mut Vec< PathBuf > ) -> Result< () >
+{
+  for entry in std::fs::read_dir( dir )?
+  {
+    let path = entry?.path();
+    if path.is_dir()
+    {
+      rust_files( &path, files )?;
+    }
+    else if path.extension().and_then( | ext | ext.to_str() ) == Some( "rs" )
+    {
+      files.push( path );
+    }
+  }
+  Ok( () )
+}
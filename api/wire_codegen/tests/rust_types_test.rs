@@ -0,0 +1,86 @@
+//! Tests for reading wire field names from Rust sources.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | serde_attributes_decide_wire_names | fields | `rename`, `rename_all`, `skip`, `flatten`, raw idents |
+//! | structs_in_nested_modules | fields | structs inside `mod private` are found |
+//! | struct_names_lists_public_named_structs | names | tuple and private structs are left out |
+//! | source_errors | errors | unparsable source, missing struct |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_wire_codegen::{ rust_types::{ struct_fields, struct_names }, CodegenError, RustField };
+
+const SOURCE : &str = r#"
+mod private
+{
+  #[ derive( Serialize, Deserialize ) ]
+  #[ serde( rename_all = "camelCase", deny_unknown_fields ) ]
+  pub struct Sample
+  {
+    pub total_tokens : u32,
+    #[ serde( rename = "type" ) ]
+    pub tool_type : String,
+    pub r#ref : Option< String >,
+    #[ serde( skip_serializing_if = "Option::is_none", default ) ]
+    pub finish_reason : Option< String >,
+    #[ serde( skip ) ]
+    pub local : u32,
+    #[ serde( flatten ) ]
+    pub extra : serde_json::Map< String, serde_json::Value >,
+    #[ serde( rename( serialize = "out", deserialize = "in" ) ) ]
+    pub both : core::option::Option< u8 >,
+  }
+
+  pub struct Tuple( u8 );
+  struct Hidden { pub field : u8 }
+}
+"#;
+
+#[ test ]
+fn serde_attributes_decide_wire_names()
+{
+  let fields = struct_fields( SOURCE, "sample.rs", "Sample" ).unwrap();
+
+  let field = | wire_name : &str, optional : bool, flatten : bool | RustField { wire_name : wire_name.into(), optional, flatten };
+  assert_eq!
+  (
+    fields,
+    [
+      field( "totalTokens", false, false ),
+      field( "type", false, false ),
+      field( "ref", true, false ),
+      field( "finishReason", true, false ),
+      field( "extra", false, true ),
+      field( "in", true, false ),
+    ]
+  );
+}
+
+#[ test ]
+fn structs_in_nested_modules()
+{
+  assert!( struct_fields( SOURCE, "sample.rs", "Hidden" ).is_ok() );
+}
+
+#[ test ]
+fn struct_names_lists_public_named_structs()
+{
+  assert_eq!( struct_names( SOURCE, "sample.rs" ).unwrap(), [ "Sample" ] );
+}
+
+#[ test ]
+fn source_errors()
+{
+  let err = struct_fields( SOURCE, "sample.rs", "Absent" ).unwrap_err();
+  assert_eq!
+  (
+    err.downcast_ref::< CodegenError >(),
+    Some( &CodegenError::StructNotFound { file : "sample.rs".into(), name : "Absent".into() } )
+  );
+
+  let err = struct_names( "pub struct {", "broken.rs" ).unwrap_err();
+  assert!( matches!( err.downcast_ref::< CodegenError >(), Some( CodegenError::Parse { file, .. } ) if file == "broken.rs" ) );
+}
@@ -0,0 +1,117 @@
+//! Tests for spec loading and schema resolution.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | yaml_and_json_load_alike | load | YAML and JSON documents give the same fields |
+//! | fields_sorted_with_required | fields | name order, `required`, Rust types, `$ref` names |
+//! | nullable_forms | fields | `anyOf` null branch and `type` list both mark nullable |
+//! | all_of_parts_are_merged | fields | properties and `required` from `allOf` |
+//! | paths_step_into_properties_and_items | fields | `Schema/property/items` paths |
+//! | lookup_errors | errors | unknown schema, non-object target, external `$ref` |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_wire_codegen::{ CodegenError, Spec, SpecField };
+use std::path::PathBuf;
+
+fn fixture() -> PathBuf
+{
+  PathBuf::from( env!( "CARGO_MANIFEST_DIR" ) ).join( "tests/fixtures/mini_spec.yaml" )
+}
+
+fn spec() -> Spec
+{
+  Spec::load( &fixture() ).expect( "fixture spec must load" )
+}
+
+fn field< 'a >( fields : &'a [ SpecField ], name : &str ) -> &'a SpecField
+{
+  fields.iter().find( | field | field.name == name ).unwrap_or_else( || panic!( "no field {name}" ) )
+}
+
+fn codegen_error( err : &error_tools::untyped::Error ) -> &CodegenError
+{
+  err.downcast_ref::< CodegenError >().expect( "error must be a CodegenError" )
+}
+
+#[ test ]
+fn yaml_and_json_load_alike()
+{
+  let yaml = std::fs::read_to_string( fixture() ).unwrap();
+  let value : serde_json::Value = serde_yml::from_str( &yaml ).unwrap();
+  let json = Spec::from_json( &value.to_string() ).unwrap();
+
+  assert_eq!( json.fields( "CompletionUsage" ).unwrap(), spec().fields( "CompletionUsage" ).unwrap() );
+}
+
+#[ test ]
+fn fields_sorted_with_required()
+{
+  let fields = spec().fields( "CompletionUsage" ).unwrap();
+
+  let names : Vec< &str > = fields.iter().map( | field | field.name.as_str() ).collect();
+  assert_eq!( names, [ "completion_tokens", "completion_tokens_details", "prompt_tokens", "prompt_tokens_details", "total_tokens" ] );
+  let total = field( &fields, "total_tokens" );
+  assert!( total.required && !total.is_optional() );
+  assert_eq!( total.rust_type, "i64" );
+  assert_eq!( total.description.as_deref(), Some( "Total number of tokens used in the request (prompt + completion)." ) );
+  let details = field( &fields, "completion_tokens_details" );
+  assert!( details.is_optional() );
+  assert_eq!( details.rust_type, "CompletionTokensDetails" );
+  assert_eq!( details.description.as_deref(), Some( "Breakdown of tokens used in a completion." ) );
+}
+
+#[ test ]
+fn nullable_forms()
+{
+  let fields = spec().fields( "ChatCompletionResponseMessage" ).unwrap();
+
+  let content = field( &fields, "content" );
+  assert!( content.required && content.nullable && content.is_optional() );
+  assert_eq!( content.rust_type, "String" );
+  let refusal = field( &fields, "refusal" );
+  assert!( refusal.nullable );
+  assert_eq!( refusal.rust_type, "String" );
+}
+
+#[ test ]
+fn all_of_parts_are_merged()
+{
+  let fields = spec().fields( "ChatCompletionResponseMessage" ).unwrap();
+
+  assert!( field( &fields, "role" ).required );
+  assert_eq!( fields.len(), 3 );
+}
+
+#[ test ]
+fn paths_step_into_properties_and_items()
+{
+  let spec = spec();
+
+  let choice = spec.fields( "CreateChatCompletionResponse/choices/items" ).unwrap();
+  assert_eq!( field( &choice, "message" ).rust_type, "ChatCompletionResponseMessage" );
+  assert!( !field( &choice, "finish_reason" ).is_optional() );
+
+  let message = spec.fields( "CreateChatCompletionResponse/choices/items/message" ).unwrap();
+  assert_eq!( message, spec.fields( "ChatCompletionResponseMessage" ).unwrap() );
+
+  let response = spec.fields( "CreateChatCompletionResponse" ).unwrap();
+  assert_eq!( field( &response, "choices" ).rust_type, "Vec< serde_json::Value >" );
+}
+
+#[ test ]
+fn lookup_errors()
+{
+  let spec = spec();
+
+  let err = spec.fields( "Missing" ).unwrap_err();
+  assert_eq!( codegen_error( &err ), &CodegenError::UnknownSchema( "Missing".into() ) );
+
+  let err = spec.fields( "CreateChatCompletionResponse/id" ).unwrap_err();
+  assert_eq!( codegen_error( &err ), &CodegenError::NotAnObject( "CreateChatCompletionResponse/id".into() ) );
+
+  let err = spec.fields( "ExternalRef/other" ).unwrap_err();
+  assert_eq!( codegen_error( &err ), &CodegenError::UnresolvedRef( "other.yaml#/Thing".into() ) );
+}
@@ -0,0 +1,130 @@
+//! Tests for comparison, the workspace targets, generation, and arguments.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | compare_reports_each_divergence | compare | missing, unknown, and must-be-optional fields |
+//! | flatten_absorbs_missing_fields | compare | a `flatten` field suppresses missing-field reports |
+//! | openai_compatible_usage_matches_spec | targets | the real `Usage` struct reads every `CompletionUsage` property |
+//! | openai_targets_found_by_name | targets | `api_openai` structs named after schemas are discovered |
+//! | generate_emits_repo_style_struct | generate | docs, `Option`, `skip_serializing_if`, `rename` |
+//! | parse_commands | args | verify/generate defaults, help, errors |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_wire_codegen::
+{
+  args::parse,
+  compare,
+  generate,
+  targets::{ self, Target },
+  CodegenError,
+  Command,
+  Divergence,
+  RustField,
+  Spec,
+};
+use std::path::{ Path, PathBuf };
+
+fn spec() -> Spec
+{
+  Spec::load( &Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( "tests/fixtures/mini_spec.yaml" ) ).unwrap()
+}
+
+fn workspace_root() -> PathBuf
+{
+  Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( "../.." )
+}
+
+fn rust( wire_name : &str, optional : bool ) -> RustField
+{
+  RustField { wire_name : wire_name.into(), optional, flatten : false }
+}
+
+#[ test ]
+fn compare_reports_each_divergence()
+{
+  let schema = spec().fields( "ChatCompletionResponseMessage" ).unwrap();
+  let fields = [ rust( "role", false ), rust( "content", false ), rust( "tool_call_id", true ) ];
+
+  assert_eq!
+  (
+    compare( &schema, &fields ),
+    [
+      Divergence::MissingField { name : "refusal".into(), spec_type : "String".into() },
+      Divergence::MustBeOptional { name : "content".into() },
+      Divergence::UnknownField { name : "tool_call_id".into() },
+    ]
+  );
+}
+
+#[ test ]
+fn flatten_absorbs_missing_fields()
+{
+  let schema = spec().fields( "ChatCompletionResponseMessage" ).unwrap();
+  let fields = [ rust( "role", false ), RustField { wire_name : "extra".into(), optional : false, flatten : true } ];
+
+  assert!( compare( &schema, &fields ).is_empty() );
+}
+
+#[ test ]
+fn openai_compatible_usage_matches_spec()
+{
+  let usage = targets::openai_compatible().into_iter().find( | target | target.rust == "Usage" ).unwrap();
+  assert_eq!( usage.schema, "CompletionUsage" );
+
+  let reports = targets::check( &spec(), &workspace_root(), &[ usage ] ).unwrap();
+
+  assert_eq!( reports[ 0 ].divergences, [] );
+}
+
+#[ test ]
+fn openai_targets_found_by_name()
+{
+  let found = targets::openai( &spec(), &workspace_root() ).unwrap();
+
+  assert!( found.contains( &Target
+  {
+    file : PathBuf::from( "api/openai/src/components/common.rs" ),
+    rust : "CompletionUsage".into(),
+    schema : "CompletionUsage".into(),
+  }));
+}
+
+#[ test ]
+fn generate_emits_repo_style_struct()
+{
+  let source = generate( &spec(), "ChatCompletionResponseMessage", "Message" ).unwrap();
+
+  assert!( source.starts_with( "/// A chat completion message generated by the model.\n" ), "{source}" );
+  assert!( source.contains( "pub struct Message\n{\n" ), "{source}" );
+  assert!( source.contains( "  /// The role of the author of this message.\n  pub role : String,\n" ), "{source}" );
+  assert!( source.contains( "  #[ serde( skip_serializing_if = \"Option::is_none\" ) ]\n  pub content : Option< String >,\n" ), "{source}" );
+  assert!( syn::parse_file( &source ).is_ok(), "generated source must parse : {source}" );
+}
+
+#[ test ]
+fn parse_commands()
+{
+  let words = | line : &str | line.split_whitespace().map( str::to_owned ).collect::< Vec< _ > >();
+
+  assert_eq!
+  (
+    parse( words( "verify --spec openapi.yaml" ) ).unwrap(),
+    Command::Verify { spec : "openapi.yaml".into(), root : ".".into() }
+  );
+  assert_eq!
+  (
+    parse( words( "generate --spec s.yaml --schema CreateChatCompletionResponse/choices/items" ) ).unwrap(),
+    Command::Generate { spec : "s.yaml".into(), schema : "CreateChatCompletionResponse/choices/items".into(), name : "CreateChatCompletionResponse".into() }
+  );
+  assert_eq!( parse( words( "verify --help --bogus" ) ).unwrap(), Command::Help );
+
+  assert_eq!( parse( words( "verify --bogus" ) ), Err( CodegenError::UnknownOption( "--bogus".into() ) ) );
+  assert_eq!( parse( words( "verify --spec" ) ), Err( CodegenError::MissingValue( "--spec".into() ) ) );
+  assert_eq!( parse( words( "--spec s.yaml" ) ), Err( CodegenError::MissingCommand ) );
+  assert_eq!( parse( words( "frobnicate --spec s.yaml" ) ), Err( CodegenError::UnknownCommand( "frobnicate".into() ) ) );
+  assert_eq!( parse( words( "verify" ) ), Err( CodegenError::MissingOption( "--spec".into() ) ) );
+  assert_eq!( parse( words( "generate --spec s.yaml" ) ), Err( CodegenError::MissingOption( "--schema".into() ) ) );
+}
@@ -172,15 +172,13 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     ];
 
     println!( "   Processing {} requests in parallel (max 3 concurrent)...", requests.len() );
-    let start = std::time::Instant::now();
-    let results = processor.process_batch( requests ).await;
-    let duration = start.elapsed();
-
-    let successes = results.iter().filter( | r | r.is_ok() ).count();
-    println!( "   ✓ Completed {}/{} requests in {:?}", successes, results.len(), duration );
-    #[ allow( clippy::cast_possible_truncation ) ]  // Small batch size in demo
-    let avg = duration / results.len() as u32;
-    println!( "   Average : {avg:?} per request\n" );
+    let report = processor.process_batch_report( requests ).await;
+
+    println!( "   ✓ Completed {}/{} requests in {:?}", report.succeeded(), report.items.len(), report.elapsed );
+    if let Some( mean ) = report.mean_elapsed()
+    {
+      println!( "   Average : {mean:?} per request\n" );
+    }
   }
 
   // 6. Performance Metrics
@@ -19,11 +19,12 @@ mod private
   //!
   //! ## Concurrency Control
   //!
-  //! Uses `tokio::sync::Semaphore` to limit concurrent requests:
+  //! Delegates to `api_openai_compatible::BatchExecutor`, which keeps at most
+  //! `max_concurrent` requests in flight inside the caller's task:
   //!
   //! - **Prevents Overload**: Avoids overwhelming the API
   //! - **Rate Limit Compliance**: Respects API rate limits
-  //! - **Resource Management**: Prevents excessive memory usage
+  //! - **Resource Management**: No spawned tasks, no shared client handle
   //! - **Graceful Degradation**: Continues processing on individual failures
   //!
   //! ## Error Handling Strategy
@@ -40,10 +41,8 @@ mod private
 
   use crate::{ ChatCompletionRequest, ChatCompletionResponse, Client, XaiEnvironment, ClientApiAccessors };
   use crate::error::Result;
-  use std::sync::Arc;
-
-  #[ cfg( feature = "batch_operations" ) ]
-  use tokio::sync::Semaphore;
+  use api_openai_compatible::BatchExecutor;
+  pub use api_openai_compatible::{ BatchItem, BatchReport };
 
   /// A client wrapper that supports batch processing of requests.
   ///
@@ -59,6 +58,8 @@ mod private
   /// - Control resource usage
   /// - Prevent overwhelming the API
   ///
+  /// A limit of `0` is taken as `1`.
+  ///
   /// # Error Handling
   ///
   /// Failures are returned individually in the results vector.
@@ -114,8 +115,8 @@ mod private
   where
     E : XaiEnvironment + Send + Sync + 'static,
   {
-    client : Arc< Client< E > >,
-    max_concurrent : usize,
+    client : Client< E >,
+    executor : BatchExecutor,
   }
 
   #[ cfg( feature = "batch_operations" ) ]
@@ -152,8 +153,8 @@ mod private
     {
       Self
       {
-        client : Arc::new( client ),
-        max_concurrent,
+        client,
+        executor : BatchExecutor::new( max_concurrent ),
       }
     }
 
@@ -205,10 +206,6 @@ mod private
     /// # }
     /// # }
     /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if the semaphore is closed.
     pub async fn process_batch
     (
       &self,
@@ -216,54 +213,49 @@ mod private
     )
     -> Vec< Result< ChatCompletionResponse > >
     {
-      let semaphore = Arc::new( Semaphore::new( self.max_concurrent ) );
-      let mut handles = Vec::new();
-
-      for request in requests
-      {
-        let client = Arc::clone( &self.client );
-        let semaphore = Arc::clone( &semaphore );
-
-        let handle = tokio::spawn
-        (
-          async move
-          {
-            // Acquire permit (blocks if max_concurrent reached)
-            let _permit = semaphore.acquire().await.unwrap();
-
-            // Execute request
-            client.chat().create( request ).await
-          }
-        );
-
-        handles.push( handle );
-      }
-
-      // Collect results in order
-      let mut results = Vec::new();
-      for handle in handles
-      {
-        match handle.await
-        {
-          Ok( result ) => results.push( result ),
-          Err( e ) =>
-          {
-            // Task join error (very rare)
-            results.push
-            (
-              Err
-              (
-                crate::error::XaiError::ApiError
-                (
-                  format!( "Task join error : {e}" )
-                ).into()
-              )
-            );
-          }
-        }
-      }
+      self.process_batch_report( requests ).await.into_results()
+    }
 
-      results
+    /// Processes a batch and reports each request with its timing.
+    ///
+    /// Same as `process_batch`, but each item also carries how long its
+    /// request took, and the report how long the whole batch took.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[ cfg( feature = "batch_operations") ]
+    /// # {
+    /// use api_xai::{ BatchProcessor, Client, Secret, XaiEnvironmentImpl, ChatCompletionRequest, Message };
+    ///
+    /// # async fn example() -> Result< (), Box< dyn std::error::Error > > {
+    /// let secret = Secret::new( "xai-key".to_string() )?;
+    /// let env = XaiEnvironmentImpl::new( secret )?;
+    /// let client = Client::build( env )?;
+    /// let processor = BatchProcessor::new( client, 5 );
+    ///
+    /// let requests = vec!
+    /// [
+    ///   ChatCompletionRequest::former()
+    ///     .model( "grok-2-1212".to_string() )
+    ///     .messages( vec![ Message::user( "Request 1" ) ] )
+    ///     .form(),
+    /// ];
+    ///
+    /// let report = processor.process_batch_report( requests ).await;
+    /// println!( "{}/{} in {:?}", report.succeeded(), report.items.len(), report.elapsed );
+    /// # Ok( () )
+    /// # }
+    /// # }
+    /// ```
+    pub async fn process_batch_report
+    (
+      &self,
+      requests : Vec< ChatCompletionRequest >
+    )
+    -> BatchReport< ChatCompletionResponse >
+    {
+      self.executor.run( requests, | request | async move { self.client.chat().create( request ).await } ).await
     }
 
     /// Processes a batch with progress callback.
@@ -324,10 +316,6 @@ mod private
     /// # }
     /// # }
     /// ```
-    ///
-    /// # Panics
-    ///
-    /// Panics if the semaphore is closed.
     pub async fn process_batch_with_progress< F >
     (
       &self,
@@ -336,59 +324,14 @@ mod private
     )
     -> Vec< Result< ChatCompletionResponse > >
     where
-      F : Fn( usize, &Result< ChatCompletionResponse > ) + Send + Sync + 'static,
+      F : Fn( usize, &Result< ChatCompletionResponse > ),
     {
-      let semaphore = Arc::new( Semaphore::new( self.max_concurrent ) );
-      let callback = Arc::new( on_complete );
-      let mut handles = Vec::new();
-
-      for ( idx, request ) in requests.into_iter().enumerate()
-      {
-        let client = Arc::clone( &self.client );
-        let semaphore = Arc::clone( &semaphore );
-        let callback = Arc::clone( &callback );
-
-        let handle = tokio::spawn
-        (
-          async move
-          {
-            let _permit = semaphore.acquire().await.unwrap();
-            let result = client.chat().create( request ).await;
-
-            // Call progress callback
-            callback( idx, &result );
-
-            result
-          }
-        );
-
-        handles.push( handle );
-      }
-
-      // Collect results
-      let mut results = Vec::new();
-      for handle in handles
-      {
-        match handle.await
-        {
-          Ok( result ) => results.push( result ),
-          Err( e ) =>
-          {
-            results.push
-            (
-              Err
-              (
-                crate::error::XaiError::ApiError
-                (
-                  format!( "Task join error : {e}" )
-                ).into()
-              )
-            );
-          }
-        }
-      }
-
-      results
+      self.executor.run_with_progress
+      (
+        requests,
+        | request | async move { self.client.chat().create( request ).await },
+        | item | on_complete( item.index, &item.result ),
+      ).await.into_results()
     }
   }
 }
@@ -399,5 +342,7 @@ crate::mod_interface!
   exposed use
   {
     BatchProcessor,
+    BatchItem,
+    BatchReport,
   };
 }
@@ -6,7 +6,7 @@ mod private
   use serde::{ Serialize, de::DeserializeOwned };
 
   #[ cfg( feature = "streaming" ) ]
-  use eventsource_stream::Eventsource;
+  use api_sse_stream::SseStreamExt;
   #[ cfg( feature = "streaming" ) ]
   use futures_util::{ Stream, StreamExt };
   #[ cfg( feature = "streaming" ) ]
@@ -83,11 +83,7 @@ mod private
     /// Returns `XaiError::Http` if the HTTP client cannot be created.
     pub fn build( environment : E ) -> Result< Self >
     {
-      let http_client = HttpClient::builder()
-        .timeout( environment.timeout() )
-        .connect_timeout( std::time::Duration::from_secs( 15 ) )
-        .pool_idle_timeout( std::time::Duration::from_secs( 90 ) )
-        .tcp_keepalive( std::time::Duration::from_secs( 60 ) )
+      let http_client = Self::http_builder( &environment )
         .build()
         .map_err( |e| XaiError::Http( format!( "Failed to create HTTP client : {e}" ) ) )?;
 
@@ -101,6 +97,70 @@ mod private
       } )
     }
 
+    /// HTTP client settings described on [`build`](Self::build).
+    fn http_builder( environment : &E ) -> reqwest::ClientBuilder
+    {
+      HttpClient::builder()
+        .timeout( environment.timeout() )
+        .connect_timeout( std::time::Duration::from_secs( 15 ) )
+        .pool_idle_timeout( std::time::Duration::from_secs( 90 ) )
+        .tcp_keepalive( std::time::Duration::from_secs( 60 ) )
+    }
+
+    /// Rebuilds the HTTP client with `tuning` applied on top of the settings
+    /// described on [`build`](Self::build) (requires `connection` feature).
+    /// Open connections of the previous client are dropped; call
+    /// [`warm_up`](Self::warm_up) afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XaiError::Http` if `tuning` names a proxy that is not a valid
+    /// URL, asks for HTTP/2 prior knowledge without the `http2` feature, or
+    /// the client cannot be built.
+    #[ cfg( feature = "connection" ) ]
+    pub fn with_connection_tuning( mut self, tuning : api_common::ConnectionTuning ) -> Result< Self >
+    {
+      self.http_client = tuning.apply_to( Self::http_builder( &self.environment ) ).map_err( | e | XaiError::Http( e.to_string() ) )?
+        .build()
+        .map_err( |e| XaiError::Http( format!( "Failed to create HTTP client : {e}" ) ) )?;
+      Ok( self )
+    }
+
+    /// Opens a connection to every configured endpoint ahead of the first
+    /// request, so that request does not pay for DNS, TCP, and the TLS
+    /// handshake.
+    ///
+    /// Sends one unauthenticated `HEAD` request to the base URL, or to each
+    /// failover endpoint when failover is configured, and returns the
+    /// total time taken. Any HTTP status counts as success; only a failure to
+    /// connect is an error. Failover health is not updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XaiError::Http` or `XaiError::Timeout` if an endpoint could
+    /// not be reached.
+    pub async fn warm_up( &self ) -> Result< std::time::Duration >
+    {
+      let started = std::time::Instant::now();
+      #[ cfg( feature = "failover" ) ]
+      let endpoints = match self.failover_manager
+      {
+        Some( ref manager ) => manager.endpoints(),
+        None => vec![ self.environment.base_url().to_string() ],
+      };
+      #[ cfg( not( feature = "failover" ) ) ]
+      let endpoints = vec![ self.environment.base_url().to_string() ];
+      for endpoint in endpoints
+      {
+        self.http_client
+          .head( endpoint )
+          .send()
+          .await
+          .map_err( XaiError::from )?;
+      }
+      Ok( started.elapsed() )
+    }
+
     /// Adds failover support with multiple endpoints (requires `failover` feature).
     ///
     /// # Panics
@@ -397,16 +457,14 @@ mod private
       let byte_stream = response.bytes_stream();
 
       // Parse SSE events
-      let event_stream = byte_stream
-        .map( | result | result.map_err( | e | std::io::Error::new( std::io::ErrorKind::Other, e ) ) )
-        .eventsource();
+      let event_stream = byte_stream.sse_events();
 
       // Map events to deserialized objects
       let mapped_stream = event_stream.map( move | event_result | {
         match event_result {
           Ok( event ) => {
             // Skip [DONE] marker
-            if event.data == "[DONE]" {
+            if event.is_done() {
               return Err( XaiError::Stream( "Stream completed".to_string() ).into() );
             }
 
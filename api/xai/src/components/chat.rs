@@ -1,7 +1,8 @@
 //! Chat completion wire types for the `api_xai` crate.
 //!
 //! Re-exported from `api_openai_compatible` to eliminate wire-type duplication.
-//! Streaming types (`ChatCompletionChunk`, `ChunkChoice`, `Delta`) are available
+//! Streaming types (`ChatCompletionChunk`, `ChunkChoice`, `Delta`, and the
+//! `StreamAccumulator` that folds them into a response) are available
 //! when the `streaming` feature is active.
 
 mod private
@@ -13,13 +14,16 @@ mod private
     FunctionCall,
     ChatCompletionRequest,
     Tool,
+    ToolChoice,
+    NamedToolChoice,
+    ToolChoiceFunction,
     Function,
     ChatCompletionResponse,
     Choice,
   };
 
   #[ cfg( feature = "streaming" ) ]
-  pub use api_openai_compatible::{ ChatCompletionChunk, ChunkChoice, Delta };
+  pub use api_openai_compatible::{ ChatCompletionChunk, ChunkChoice, Delta, ToolCallChunk, FunctionCallChunk, StreamAccumulator };
 }
 
 crate::mod_interface!
@@ -31,6 +35,9 @@ crate::mod_interface!
     FunctionCall,
     ChatCompletionRequest,
     Tool,
+    ToolChoice,
+    NamedToolChoice,
+    ToolChoiceFunction,
     Function,
     ChatCompletionResponse,
     Choice,
@@ -42,5 +49,8 @@ crate::mod_interface!
     ChatCompletionChunk,
     ChunkChoice,
     Delta,
+    ToolCallChunk,
+    FunctionCallChunk,
+    StreamAccumulator,
   };
 }
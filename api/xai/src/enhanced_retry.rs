@@ -2,7 +2,7 @@ mod private
 {
   use std::time::Duration;
   use crate::error::{ XaiError, Result };
-  use tokio::time::sleep;
+  use api_common::{ sleep, BudgetTracker };
 
   /// Enhanced retry configuration with exponential backoff.
   ///
@@ -280,7 +280,52 @@ mod private
     /// # Ok( () )
     /// # }
     /// ```
-    pub async fn call< F, Fut, T >( &self, mut f : F ) -> Result< T >
+    pub async fn call< F, Fut, T >( &self, f : F ) -> Result< T >
+    where
+      F : FnMut() -> Fut,
+      Fut : std::future::Future< Output = Result< T > >,
+    {
+      self.call_inner( f, None ).await
+    }
+
+    /// Executes a function with retry logic inside a call budget shared with
+    /// other layers.
+    ///
+    /// Every attempt is counted against `budget` and cut off at its total
+    /// deadline; a retry the budget does not allow returns the last error
+    /// instead of sleeping.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error if all retry attempts fail or the budget runs
+    /// out after a failure, and `api_common::BudgetExhausted` if the budget
+    /// refuses the first attempt or its deadline passes during one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_xai::{ EnhancedRetryConfig, Result };
+    /// use api_common::CallBudget;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result< () > {
+    /// let budget = CallBudget::new().with_total_deadline( Duration::from_secs( 30 ) ).start();
+    ///
+    /// let result = EnhancedRetryConfig::default().call_within( &budget, || async {
+    ///   Ok::< String, _ >( "response".to_string() ) as Result< String >
+    /// } ).await?;
+    /// # Ok( () )
+    /// # }
+    /// ```
+    pub async fn call_within< F, Fut, T >( &self, budget : &BudgetTracker, f : F ) -> Result< T >
+    where
+      F : FnMut() -> Fut,
+      Fut : std::future::Future< Output = Result< T > >,
+    {
+      self.call_inner( f, Some( budget ) ).await
+    }
+
+    async fn call_inner< F, Fut, T >( &self, mut f : F, budget : Option< &BudgetTracker > ) -> Result< T >
     where
       F : FnMut() -> Fut,
       Fut : std::future::Future< Output = Result< T > >,
@@ -289,7 +334,13 @@ mod private
 
       loop
       {
-        match f().await
+        let outcome = match budget
+        {
+          Some( budget ) => budget.run_attempt( f() ).await?,
+          None => f().await,
+        };
+
+        match outcome
         {
           Ok( result ) => return Ok( result ),
           Err( err ) =>
@@ -320,6 +371,10 @@ mod private
 
             // Calculate and wait for backoff delay
             let delay = self.delay_for_attempt( attempt - 1 );
+            if budget.is_some_and( | budget | budget.allows_delay( delay ).is_err() )
+            {
+              return Err( err );
+            }
             sleep( delay ).await;
           }
         }
@@ -58,12 +58,11 @@ mod private
       let mut headers = header::HeaderMap::new();
 
       // Authorization : Bearer xai-...
-      let auth_value = format!( "Bearer {secret}", secret = self.api_key().expose_secret() );
-      headers.insert(
-        header::AUTHORIZATION,
-        auth_value.parse()
-          .map_err( |e| XaiError::Http( format!( "Invalid authorization header : {e}" ) ) )?
-      );
+      let mut auth_value : header::HeaderValue = format!( "Bearer {secret}", secret = self.api_key().expose_secret() )
+        .parse()
+        .map_err( |e| XaiError::Http( format!( "Invalid authorization header : {e}" ) ) )?;
+      auth_value.set_sensitive( true );
+      headers.insert( header::AUTHORIZATION, auth_value );
 
       // Content-Type : application/json
       headers.insert(
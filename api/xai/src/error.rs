@@ -182,6 +182,31 @@ mod private
       XaiError::Http( format!( "Invalid header value : {error}" ) )
     }
   }
+
+  // Conversion into the provider-neutral error classes
+  #[ cfg( feature = "common" ) ]
+  impl From< XaiError > for api_common::LlmError
+  {
+    fn from( error : XaiError ) -> Self
+    {
+      match error
+      {
+        XaiError::Network( message ) | XaiError::Timeout( message ) => Self::Network( message ),
+        XaiError::Stream( message ) | XaiError::Serialization( message ) => Self::Protocol( message ),
+        XaiError::RateLimit( message ) => Self::RateLimited { retry_after : None, message },
+        XaiError::InvalidApiKey( message ) => Self::Auth( message ),
+        #[ cfg( feature = "circuit_breaker" ) ]
+        XaiError::CircuitBreakerOpen( message ) => Self::Overloaded( message ),
+        XaiError::Api { message, code, error_type } =>
+        {
+          let detail = format!( "{message} ({}, {})", code.as_deref().unwrap_or_default(), error_type.as_deref().unwrap_or_default() );
+          Self::classify( None, &detail )
+        },
+        XaiError::Http( message ) | XaiError::ApiError( message ) => Self::classify( None, &message ),
+        other => Self::Provider { raw : other.to_string() },
+      }
+    }
+  }
 }
 
 crate::mod_interface!
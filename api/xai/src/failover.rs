@@ -266,6 +266,17 @@ mod private
       state.endpoints[ state.current_index ].base_url.clone()
     }
 
+    /// Base URLs of all endpoints, in rotation order, healthy or not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn endpoints( &self ) -> Vec< String >
+    {
+      let state = self.state.lock().unwrap();
+      state.endpoints.iter().map( | endpoint | endpoint.base_url.clone() ).collect()
+    }
+
     /// Records a successful request to current endpoint.
     ///
     /// Resets failure counter and marks endpoint as healthy.
@@ -77,7 +77,7 @@ mod private
     /// ```
     pub async fn list( &self ) -> Result< ListModelsResponse >
     {
-      self.client.get( "models" ).await
+      self.client.get( ListModelsResponse::PATH ).await
     }
 
     /// Retrieves information about a specific model.
@@ -108,8 +108,7 @@ mod private
     /// ```
     pub async fn get( &self, model_id : &str ) -> Result< Model >
     {
-      let path = format!( "models/{model_id}" );
-      self.client.get( &path ).await
+      self.client.get( &Model::path( model_id ) ).await
     }
   }
 }
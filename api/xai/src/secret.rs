@@ -1,6 +1,7 @@
 mod private
 {
   use crate::error::{ XaiError, Result };
+  use api_secret::SecretChain;
   use secrecy::{ SecretString, ExposeSecret };
   use workspace_tools::workspace;
   use std::sync::atomic::{ AtomicUsize, Ordering };
@@ -44,9 +45,19 @@ mod private
   /// let secret = Secret::new( "xai-your-key-here".to_string() )?;
   /// # Ok::<(), Box< dyn std::error::Error > >(())
   /// ```
-  #[ derive( Debug, Clone ) ]
+  #[ derive( Clone ) ]
   pub struct Secret( SecretString );
 
+  /// Shows the key prefix only, e.g. `Secret("xai-****")`. Formatting is
+  /// not counted as an exposure.
+  impl core::fmt::Debug for Secret
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_tuple( "Secret" ).field( &api_secret::Masked( self.0.expose_secret() ) ).finish()
+    }
+  }
+
   impl Secret
   {
     /// Creates a new `Secret` from a string, validating the format.
@@ -143,7 +154,8 @@ mod private
 
     /// Loads the API key with automatic fallback chain.
     ///
-    /// Attempts to load the API key from multiple sources in priority order:
+    /// Uses the chain shared by every `api_llm` provider crate
+    /// (`api_secret::SecretChain::standard`):
     ///
     /// 1. Workspace secrets file (`-secrets.sh`) - primary workspace pattern
     /// 2. Alternative workspace files (`secrets.sh`, `.env`)
@@ -172,35 +184,35 @@ mod private
     /// ```
     pub fn load_with_fallbacks( key_name : &str ) -> Result< Self >
     {
-      // Priority 1: Workspace secrets (-secrets.sh) - primary workspace pattern
-      if let Ok( secret ) = Self::load_from_workspace( key_name, "-secrets.sh" )
-      {
-        return Ok( secret );
-      }
-
-      // Priority 2: Alternative workspace file (secrets.sh)
-      if let Ok( secret ) = Self::load_from_workspace( key_name, "secrets.sh" )
-      {
-        return Ok( secret );
-      }
-
-      // Priority 3: .env file
-      if let Ok( secret ) = Self::load_from_workspace( key_name, ".env" )
-      {
-        return Ok( secret );
-      }
+      Self::load_from_chain( key_name, &SecretChain::standard() )
+    }
 
-      // Priority 4: Environment variable (fallback for CI/deployment)
-      if let Ok( secret ) = Self::load_from_env( key_name )
-      {
-        return Ok( secret );
-      }
+    /// Loads the API key from a caller-built chain, e.g. a Vault or AWS
+    /// Secrets Manager fetch placed ahead of the standard chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `XaiError::Environment` if no source in `chain` holds the key,
+    /// or `XaiError::InvalidApiKey` if validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use api_xai::Secret;
+    /// use api_secret::{ MapSource, SecretChain };
+    ///
+    /// let chain = SecretChain::new()
+    ///   .with( MapSource::new( "fixture" ).with( "XAI_API_KEY", "xai-1234567890" ) )
+    ///   .then( SecretChain::standard() );
+    /// let secret = Secret::load_from_chain( "XAI_API_KEY", &chain )?;
+    /// # Ok::<(), Box< dyn std::error::Error > >(())
+    /// ```
+    pub fn load_from_chain( key_name : &str, chain : &SecretChain ) -> Result< Self >
+    {
+      let key = chain.load( key_name )
+        .map_err( |e| XaiError::Environment( format!( "Failed to load {key_name} : {e}" ) ) )?;
 
-      Err( XaiError::Environment(
-        format!(
-          "Failed to load {key_name} from any source (-secrets.sh, secrets.sh, .env, env)"
-        )
-      ).into() )
+      Self::new( key.expose_secret().to_string() )
     }
 
     /// Validates the API key format.
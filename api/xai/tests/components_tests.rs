@@ -192,6 +192,8 @@ fn usage_serializes_correctly()
     prompt_tokens : 10,
     completion_tokens : 20,
     total_tokens : 30,
+    prompt_tokens_details : None,
+    completion_tokens_details : None,
   };
 
   let json = serde_json::to_value( &usage ).unwrap();
@@ -317,6 +319,8 @@ fn usage_clone_and_partial_eq_work()
     prompt_tokens : 10,
     completion_tokens : 20,
     total_tokens : 30,
+    prompt_tokens_details : None,
+    completion_tokens_details : None,
   };
 
   let usage2 = usage1.clone();
@@ -440,7 +444,7 @@ fn crate_root_exports_are_accessible()
     .model( "m".to_string() )
     .messages( vec![ msg ] )
     .form();
-  let _ = Usage { prompt_tokens : 1, completion_tokens : 1, total_tokens : 2 };
+  let _ = Usage { prompt_tokens : 1, completion_tokens : 1, total_tokens : 2, ..Default::default() };
 
   // Infrastructure types
   let secret = Secret::new( "xai-test-key-1234567890".to_string() ).unwrap();
@@ -0,0 +1,89 @@
+//! Tests for connection tuning and `Client::warm_up`.
+//!
+//! # Test Matrix
+//!
+//! | Test | Category | Validates |
+//! |------|----------|-----------|
+//! | warm_up_fails_when_nothing_listens | unit | Connection failure is an error |
+//! | warm_up_fails_when_a_failover_endpoint_is_unreachable | unit | Every failover endpoint is warmed, so an unreachable one is an error |
+//! | connection_tuning_rebuilds_client | unit | `with_connection_tuning` accepts the low-latency preset and refuses a malformed proxy URL |
+//! | warm_up_reaches_real_api | integration | `HEAD` to the real base URL succeeds whatever the status |
+//! | warm_up_reaches_every_real_failover_endpoint | integration | Each real failover endpoint is warmed |
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_xai::{ Client, Secret, XaiEnvironmentImpl };
+use url::Url;
+
+fn environment( base_url : &str ) -> XaiEnvironmentImpl
+{
+  let secret = Secret::new( "xai-test-key-1234567890".to_string() ).unwrap();
+  XaiEnvironmentImpl::new( secret ).unwrap()
+    .with_base_url( Url::parse( base_url ).unwrap() )
+}
+
+// ------------------------------------------------------------------ //
+
+#[ tokio::test ]
+async fn warm_up_fails_when_nothing_listens()
+{
+  let client = Client::build( environment( "http://127.0.0.1:1/" ) ).unwrap();
+
+  assert!( client.warm_up().await.is_err() );
+}
+
+#[ cfg( feature = "failover" ) ]
+#[ tokio::test ]
+async fn warm_up_fails_when_a_failover_endpoint_is_unreachable()
+{
+  let client = Client::build( environment( "http://127.0.0.1:1/" ) ).unwrap()
+    .with_failover( vec![ "http://127.0.0.1:1/".to_string(), "http://127.0.0.1:2/".to_string() ] );
+
+  assert!( client.warm_up().await.is_err() );
+}
+
+#[ cfg( feature = "connection" ) ]
+#[ test ]
+fn connection_tuning_rebuilds_client()
+{
+  let client = Client::build( environment( "http://127.0.0.1:1/" ) ).unwrap()
+    .with_connection_tuning( api_common::ConnectionTuning::low_latency() );
+
+  assert!( client.is_ok(), "{:?}", client.err() );
+
+  let proxy = Client::build( environment( "http://127.0.0.1:1/" ) ).unwrap()
+    .with_connection_tuning( api_common::ConnectionTuning::new().with_proxy( "not a proxy url" ) );
+  assert!( proxy.is_err(), "a malformed proxy URL must be refused" );
+}
+
+// ------------------------------------------------------------------ //
+
+#[ cfg( feature = "integration" ) ]
+fn real_client() -> Client< XaiEnvironmentImpl >
+{
+  let secret = Secret::load_with_fallbacks( "XAI_API_KEY" )
+    .expect( "XAI_API_KEY is required for integration tests. Integration tests MUST fail if credentials are unavailable." );
+  Client::build( XaiEnvironmentImpl::new( secret ).expect( "Failed to create environment" ) )
+    .expect( "Failed to build client" )
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn warm_up_reaches_real_api()
+{
+  let warmed = real_client().warm_up().await;
+
+  assert!( warmed.is_ok(), "{:?}", warmed.err() );
+}
+
+#[ cfg( all( feature = "integration", feature = "failover" ) ) ]
+#[ tokio::test ]
+async fn warm_up_reaches_every_real_failover_endpoint()
+{
+  let client = real_client()
+    .with_failover( vec![ "https://api.x.ai/v1/".to_string(), "https://api.x.ai/".to_string() ] );
+
+  let warmed = client.warm_up().await;
+
+  assert!( warmed.is_ok(), "{:?}", warmed.err() );
+}
@@ -0,0 +1,46 @@
+//! Tests that the API key never appears in full in `Debug` output.
+
+use api_xai::{ Client, Secret, XaiEnvironment, XaiEnvironmentImpl };
+
+const KEY : &str = "xai-redaction-test-0123456789abcdef";
+
+fn assert_hidden( value : &impl core::fmt::Debug )
+{
+  for output in [ format!( "{value:?}" ), format!( "{value:#?}" ) ]
+  {
+    assert!( !output.contains( &KEY[ 4.. ] ), "key in Debug output : {output}" );
+  }
+}
+
+fn env() -> XaiEnvironmentImpl
+{
+  XaiEnvironmentImpl::new( Secret::new( KEY.to_string() ).unwrap() ).unwrap()
+}
+
+#[ test ]
+fn secret_debug_shows_prefix_only()
+{
+  let secret = Secret::new( KEY.to_string() ).unwrap();
+  assert_hidden( &secret );
+  assert_eq!( format!( "{secret:?}" ), "Secret(\"xai-****\")" );
+}
+
+#[ test ]
+fn environment_debug_masks_key()
+{
+  assert_hidden( &env() );
+}
+
+#[ test ]
+fn client_debug_masks_key()
+{
+  assert_hidden( &Client::build( env() ).unwrap() );
+}
+
+#[ test ]
+fn authorization_header_is_sensitive()
+{
+  let headers = env().headers().unwrap();
+  assert!( headers[ "authorization" ].is_sensitive() );
+  assert_hidden( &headers );
+}
@@ -199,3 +199,19 @@ fn secret_rejects_just_below_minimum()
   let result = Secret::new( "xai-12345".to_string() );
   assert!( result.is_err() );
 }
+
+#[ test ]
+fn secret_load_from_chain_prefers_earlier_source()
+{
+  use api_secret::{ MapSource, SecretChain };
+
+  std::env::set_var( "XAI_CHAIN_TEST", "xai-from-env-1234567890" );
+  let chain = SecretChain::new()
+    .with( MapSource::new( "vault" ).with( "XAI_CHAIN_TEST", "xai-from-vault-123456" ) )
+    .then( SecretChain::standard() );
+
+  let secret = Secret::load_from_chain( "XAI_CHAIN_TEST", &chain ).unwrap();
+  assert_eq!( secret.expose_secret(), "xai-from-vault-123456" );
+
+  std::env::remove_var( "XAI_CHAIN_TEST" );
+}